@@ -25,6 +25,7 @@ use std::{
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     // avoid rerunning build if the file has not changed
     println!("cargo:rerun-if-changed=../format/Flight.proto");
+    println!("cargo:rerun-if-changed=../format/FlightSql.proto");
 
     // override the build location, in order to check in the changes to proto files
     env::set_var("OUT_DIR", "src");
@@ -34,21 +35,29 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let path = Path::new("../format/Flight.proto");
     if path.exists() {
         tonic_build::compile_protos("../format/Flight.proto")?;
-        // read file contents to string
-        let mut file = OpenOptions::new()
-            .read(true)
-            .open("src/arrow.flight.protocol.rs")?;
-        let mut buffer = String::new();
-        file.read_to_string(&mut buffer)?;
-        // append warning that file was auto-generate
-        let mut file = OpenOptions::new()
-            .write(true)
-            .truncate(true)
-            .open("src/arrow.flight.protocol.rs")?;
-        file.write_all("// This file was automatically generated through the build.rs script, and should not be edited.\n\n".as_bytes())?;
-        file.write_all(buffer.as_bytes())?;
+        prepend_generated_header("src/arrow.flight.protocol.rs")?;
+    }
+
+    let path = Path::new("../format/FlightSql.proto");
+    if path.exists() {
+        tonic_build::configure()
+            .out_dir("src/flight_sql")
+            .compile(&["../format/FlightSql.proto"], &["../format"])?;
+        prepend_generated_header("src/flight_sql/arrow.flight.protocol.sql.rs")?;
     }
 
     // As the proto file is checked in, the build should not fail if the file is not found
     Ok(())
 }
+
+fn prepend_generated_header(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    // read file contents to string
+    let mut file = OpenOptions::new().read(true).open(path)?;
+    let mut buffer = String::new();
+    file.read_to_string(&mut buffer)?;
+    // append warning that file was auto-generate
+    let mut file = OpenOptions::new().write(true).truncate(true).open(path)?;
+    file.write_all("// This file was automatically generated through the build.rs script, and should not be edited.\n\n".as_bytes())?;
+    file.write_all(buffer.as_bytes())?;
+    Ok(())
+}
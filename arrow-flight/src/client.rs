@@ -0,0 +1,406 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A high level client for Arrow Flight services
+
+use std::convert::TryFrom;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use arrow::datatypes::Schema;
+use arrow::ipc::CompressionType;
+use futures::{future, ready, stream, stream::BoxStream, Stream, StreamExt};
+use tonic::metadata::{AsciiMetadataKey, AsciiMetadataValue, MetadataMap};
+use tonic::transport::{Channel, Endpoint};
+use tonic::Request;
+
+use crate::compression::{accept_compression_header_value, ACCEPT_COMPRESSION_HEADER};
+use crate::decode::FlightRecordBatchStream;
+use crate::error::{FlightError, Result};
+use crate::exchange::FlightExchangeStream;
+use crate::flight_service_client::FlightServiceClient;
+use crate::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
+    HandshakeRequest, PutResult, Ticket,
+};
+
+/// A client for an Arrow Flight service
+///
+/// Wraps the generated [`FlightServiceClient`], adding the configured gRPC
+/// metadata, such as an authorization token, to every request, and decoding the
+/// responses into Arrow types.
+///
+/// ```no_run
+/// # use arrow_flight::client::FlightClient;
+/// # use arrow_flight::Ticket;
+/// # use futures::StreamExt;
+/// # async fn f() -> arrow_flight::error::Result<()> {
+/// let mut client = FlightClient::connect("http://localhost:50051").await?;
+/// client.authenticate_basic("user", "password").await?;
+/// client.add_header("x-request-source", "example")?;
+///
+/// let ticket = Ticket { ticket: b"query".to_vec() };
+/// let mut batches = client.do_get(ticket).await?;
+/// while let Some(batch) = batches.next().await {
+///     let batch = batch?;
+///     // process the batch
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FlightClient {
+    /// Metadata added to every request
+    metadata: MetadataMap,
+    /// The underlying client
+    inner: FlightServiceClient<Channel>,
+}
+
+impl FlightClient {
+    /// Create a new client using the given channel
+    pub fn new(channel: Channel) -> Self {
+        Self::new_from_inner(FlightServiceClient::new(channel))
+    }
+
+    /// Create a new client wrapping an existing [`FlightServiceClient`]
+    pub fn new_from_inner(inner: FlightServiceClient<Channel>) -> Self {
+        Self {
+            metadata: MetadataMap::new(),
+            inner,
+        }
+    }
+
+    /// Connect to the Flight service at `uri`
+    pub async fn connect(uri: impl Into<String>) -> Result<Self> {
+        Self::connect_endpoint(endpoint(uri)?).await
+    }
+
+    /// Connect to the Flight service at `uri` using TLS
+    #[cfg(feature = "tls")]
+    pub async fn connect_with_tls(
+        uri: impl Into<String>,
+        tls_config: tonic::transport::ClientTlsConfig,
+    ) -> Result<Self> {
+        Self::connect_endpoint(endpoint(uri)?.tls_config(tls_config)).await
+    }
+
+    /// Connect to the Flight service at `endpoint`, which allows configuring
+    /// the timeouts, TLS and other options of the connection
+    pub async fn connect_endpoint(endpoint: Endpoint) -> Result<Self> {
+        let channel = endpoint
+            .connect()
+            .await
+            .map_err(|e| FlightError::ExternalError(Box::new(e)))?;
+        Ok(Self::new(channel))
+    }
+
+    /// The metadata added to every request
+    pub fn metadata(&self) -> &MetadataMap {
+        &self.metadata
+    }
+
+    /// Mutable access to the metadata added to every request
+    pub fn metadata_mut(&mut self) -> &mut MetadataMap {
+        &mut self.metadata
+    }
+
+    /// Add a header, sent as gRPC metadata with every request, replacing any
+    /// existing value of `key`
+    pub fn add_header(&mut self, key: &str, value: &str) -> Result<()> {
+        let key = AsciiMetadataKey::from_bytes(key.as_bytes()).map_err(|e| {
+            FlightError::ProtocolError(format!("Invalid header key {}: {}", key, e))
+        })?;
+        let value = value.parse::<AsciiMetadataValue>().map_err(|e| {
+            FlightError::ProtocolError(format!("Invalid header value {}: {}", value, e))
+        })?;
+        self.metadata.insert(key, value);
+        Ok(())
+    }
+
+    /// Set the bearer token sent in the `authorization` header of every request
+    pub fn set_bearer_token(&mut self, token: &str) -> Result<()> {
+        self.add_header("authorization", &format!("Bearer {}", token))
+    }
+
+    /// Advertise the IPC compression codecs this client can decode, most
+    /// preferred first, in the [`ACCEPT_COMPRESSION_HEADER`] of every request
+    ///
+    /// Servers may then compress the batches they send, see [`crate::compression`].
+    pub fn set_accepted_compression(
+        &mut self,
+        accepted: &[CompressionType],
+    ) -> Result<()> {
+        self.add_header(
+            ACCEPT_COMPRESSION_HEADER,
+            &accept_compression_header_value(accepted),
+        )
+    }
+
+    /// The underlying [`FlightServiceClient`]
+    pub fn inner(&self) -> &FlightServiceClient<Channel> {
+        &self.inner
+    }
+
+    /// Mutable access to the underlying [`FlightServiceClient`]
+    pub fn inner_mut(&mut self) -> &mut FlightServiceClient<Channel> {
+        &mut self.inner
+    }
+
+    /// Consume self and return the underlying [`FlightServiceClient`]
+    pub fn into_inner(self) -> FlightServiceClient<Channel> {
+        self.inner
+    }
+
+    /// Perform a handshake with the server, sending `payload` and returning the
+    /// payload of the server's response
+    pub async fn handshake(&mut self, payload: Vec<u8>) -> Result<Vec<u8>> {
+        let request = self.make_request(handshake_request(payload));
+        let mut response = self.inner.handshake(request).await?.into_inner();
+        let response = response.message().await?.ok_or_else(|| {
+            FlightError::ProtocolError("No response received from handshake".to_string())
+        })?;
+        Ok(response.payload)
+    }
+
+    /// Authenticate with `username` and `password` using HTTP basic authentication
+    /// during the handshake
+    ///
+    /// If the server returns a bearer token in the `authorization` header of the
+    /// response, it is sent with all subsequent requests, and returned.
+    pub async fn authenticate_basic(
+        &mut self,
+        username: &str,
+        password: &str,
+    ) -> Result<Option<String>> {
+        let credentials = base64::encode(format!("{}:{}", username, password));
+        let value = format!("Basic {}", credentials)
+            .parse::<AsciiMetadataValue>()
+            .map_err(|e| {
+                FlightError::ProtocolError(format!("Invalid credentials: {}", e))
+            })?;
+
+        let mut request = self.make_request(handshake_request(vec![]));
+        request.metadata_mut().insert("authorization", value);
+        let response = self.inner.handshake(request).await?;
+
+        let token = match response.metadata().get("authorization") {
+            Some(value) => {
+                let value = value.to_str().map_err(|e| {
+                    FlightError::ProtocolError(format!(
+                        "Invalid authorization header: {}",
+                        e
+                    ))
+                })?;
+                value.strip_prefix("Bearer ").map(|token| token.to_string())
+            }
+            None => None,
+        };
+        // drain the handshake responses
+        let mut response = response.into_inner();
+        while response.message().await?.is_some() {}
+
+        if let Some(token) = &token {
+            self.set_bearer_token(token)?;
+        }
+        Ok(token)
+    }
+
+    /// List the flights available on the server matching `criteria`
+    pub async fn list_flights(
+        &mut self,
+        criteria: Criteria,
+    ) -> Result<BoxStream<'static, Result<FlightInfo>>> {
+        let request = self.make_request(criteria);
+        let response = self.inner.list_flights(request).await?.into_inner();
+        Ok(response.map(|r| r.map_err(FlightError::from)).boxed())
+    }
+
+    /// Get the [`FlightInfo`] describing how to fetch the flight identified by
+    /// `descriptor`
+    pub async fn get_flight_info(
+        &mut self,
+        descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo> {
+        let request = self.make_request(descriptor);
+        Ok(self.inner.get_flight_info(request).await?.into_inner())
+    }
+
+    /// Get the schema of the flight identified by `descriptor`
+    pub async fn get_schema(&mut self, descriptor: FlightDescriptor) -> Result<Schema> {
+        let request = self.make_request(descriptor);
+        let response = self.inner.get_schema(request).await?.into_inner();
+        Ok(Schema::try_from(&response)?)
+    }
+
+    /// Fetch the record batches associated with `ticket`
+    pub async fn do_get(&mut self, ticket: Ticket) -> Result<FlightRecordBatchStream> {
+        let request = self.make_request(ticket);
+        let response = self.inner.do_get(request).await?.into_inner();
+        Ok(FlightRecordBatchStream::new_from_flight_data(response))
+    }
+
+    /// Send a stream of [`FlightData`], typically produced by a
+    /// [`FlightDataEncoder`](crate::encode::FlightDataEncoder) with a
+    /// [`FlightDescriptor`], to the server
+    ///
+    /// An error in the input stream ends the request, and is returned from the
+    /// response stream once the server's responses are exhausted.
+    pub async fn do_put<S>(
+        &mut self,
+        input: S,
+    ) -> Result<BoxStream<'static, Result<PutResult>>>
+    where
+        S: Stream<Item = Result<FlightData>> + Send + Sync + 'static,
+    {
+        let (input, error) = FallibleRequestStream::new(input);
+        let request = self.make_request(input);
+        let response = match self.inner.do_put(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) => {
+                return Err(take_error(&error).unwrap_or_else(|| status.into()))
+            }
+        };
+        Ok(with_request_error(response, error))
+    }
+
+    /// Open a bidirectional `DoExchange` call, sending `input` and decoding the
+    /// server's responses
+    ///
+    /// An error in the input stream ends the request, and is returned from the
+    /// response stream once the server's responses are exhausted.
+    pub async fn do_exchange<S>(&mut self, input: S) -> Result<FlightExchangeStream>
+    where
+        S: Stream<Item = Result<FlightData>> + Send + Sync + 'static,
+    {
+        let (input, error) = FallibleRequestStream::new(input);
+        let request = self.make_request(input);
+        let response = match self.inner.do_exchange(request).await {
+            Ok(response) => response.into_inner(),
+            Err(status) => {
+                return Err(take_error(&error).unwrap_or_else(|| status.into()))
+            }
+        };
+        Ok(FlightExchangeStream::new(with_request_error(
+            response, error,
+        )))
+    }
+
+    /// Perform `action` on the server, returning the bodies of its results
+    pub async fn do_action(
+        &mut self,
+        action: Action,
+    ) -> Result<BoxStream<'static, Result<Vec<u8>>>> {
+        let request = self.make_request(action);
+        let response = self.inner.do_action(request).await?.into_inner();
+        Ok(response
+            .map(|r| r.map(|result| result.body).map_err(FlightError::from))
+            .boxed())
+    }
+
+    /// List the actions available on the server
+    pub async fn list_actions(
+        &mut self,
+    ) -> Result<BoxStream<'static, Result<ActionType>>> {
+        let request = self.make_request(Empty {});
+        let response = self.inner.list_actions(request).await?.into_inner();
+        Ok(response.map(|r| r.map_err(FlightError::from)).boxed())
+    }
+
+    /// Wraps `message` into a request with the configured metadata
+    fn make_request<T>(&self, message: T) -> Request<T> {
+        let mut request = Request::new(message);
+        *request.metadata_mut() = self.metadata.clone();
+        request
+    }
+}
+
+fn endpoint(uri: impl Into<String>) -> Result<Endpoint> {
+    Endpoint::from_shared(uri.into()).map_err(|e| FlightError::ExternalError(Box::new(e)))
+}
+
+fn handshake_request(
+    payload: Vec<u8>,
+) -> impl Stream<Item = HandshakeRequest> + Send + Sync + 'static {
+    stream::iter(vec![HandshakeRequest {
+        protocol_version: 0,
+        payload,
+    }])
+}
+
+type RequestError = Arc<Mutex<Option<FlightError>>>;
+
+fn take_error(error: &RequestError) -> Option<FlightError> {
+    error.lock().unwrap().take()
+}
+
+/// Appends the error of the request stream, if any, to the response stream
+fn with_request_error<S, T>(
+    response: S,
+    error: RequestError,
+) -> BoxStream<'static, Result<T>>
+where
+    S: Stream<Item = std::result::Result<T, tonic::Status>> + Send + 'static,
+    T: Send + 'static,
+{
+    let request_error = stream::once(future::lazy(move |_| take_error(&error)))
+        .filter_map(|e| future::ready(e.map(Err)));
+    response
+        .map(|r| r.map_err(FlightError::from))
+        .chain(request_error)
+        .boxed()
+}
+
+/// Adapts a stream of `Result<FlightData>` into the stream of [`FlightData`]
+/// expected by the gRPC client, ending the stream at the first error, which is
+/// saved to be reported with the response
+struct FallibleRequestStream {
+    inner: Pin<Box<dyn Stream<Item = Result<FlightData>> + Send + Sync + 'static>>,
+    error: RequestError,
+}
+
+impl FallibleRequestStream {
+    fn new<S>(inner: S) -> (Self, RequestError)
+    where
+        S: Stream<Item = Result<FlightData>> + Send + Sync + 'static,
+    {
+        let error = RequestError::default();
+        let stream = Self {
+            inner: Box::pin(inner),
+            error: error.clone(),
+        };
+        (stream, error)
+    }
+}
+
+impl Stream for FallibleRequestStream {
+    type Item = FlightData;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        match ready!(self.inner.poll_next_unpin(cx)) {
+            Some(Ok(data)) => Poll::Ready(Some(data)),
+            Some(Err(e)) => {
+                *self.error.lock().unwrap() = Some(e);
+                Poll::Ready(None)
+            }
+            None => Poll::Ready(None),
+        }
+    }
+}
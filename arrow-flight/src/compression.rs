@@ -0,0 +1,103 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Negotiation of IPC buffer compression between Flight clients and servers
+//!
+//! Clients list the compression codecs they are able to decode in the
+//! [`ACCEPT_COMPRESSION_HEADER`] of their requests, see
+//! [`FlightClient::set_accepted_compression`](crate::client::FlightClient::set_accepted_compression).
+//! Servers pick one of them with [`negotiate_compression`] and configure their
+//! encoder accordingly:
+//!
+//! ```no_run
+//! # use arrow::ipc::writer::IpcWriteOptions;
+//! # use arrow::ipc::CompressionType;
+//! # use arrow_flight::compression::negotiate_compression;
+//! # use arrow_flight::encode::FlightDataEncoderBuilder;
+//! # fn f(request: tonic::Request<arrow_flight::Ticket>) -> arrow_flight::error::Result<()> {
+//! let supported = [CompressionType::ZSTD, CompressionType::LZ4_FRAME];
+//! let compression = negotiate_compression(request.metadata(), &supported);
+//! let options = IpcWriteOptions::default().try_with_compression(compression)?;
+//! let builder = FlightDataEncoderBuilder::new().with_options(options);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Compressed batches record their codec in their IPC message, so decoders such
+//! as [`FlightRecordBatchStream`](crate::decode::FlightRecordBatchStream)
+//! decompress them without further configuration. Compressing and decompressing
+//! requires the `ipc_compression` feature.
+
+use arrow::ipc::CompressionType;
+use tonic::metadata::MetadataMap;
+
+/// The gRPC metadata key listing the compression codecs a client can decode,
+/// most preferred first
+pub const ACCEPT_COMPRESSION_HEADER: &str = "arrow-ipc-accept-compression";
+
+/// The name of `compression` in the [`ACCEPT_COMPRESSION_HEADER`]
+pub fn compression_name(compression: CompressionType) -> Option<&'static str> {
+    match compression {
+        CompressionType::LZ4_FRAME => Some("lz4_frame"),
+        CompressionType::ZSTD => Some("zstd"),
+        _ => None,
+    }
+}
+
+/// Parses a compression codec name of the [`ACCEPT_COMPRESSION_HEADER`]
+pub fn parse_compression_name(name: &str) -> Option<CompressionType> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "lz4_frame" | "lz4" => Some(CompressionType::LZ4_FRAME),
+        "zstd" => Some(CompressionType::ZSTD),
+        _ => None,
+    }
+}
+
+/// Formats the value of the [`ACCEPT_COMPRESSION_HEADER`] listing `accepted`
+pub fn accept_compression_header_value(accepted: &[CompressionType]) -> String {
+    accepted
+        .iter()
+        .filter_map(|compression| compression_name(*compression))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// The compression codecs accepted by the client that sent `metadata`, in the
+/// client's order of preference
+pub fn accepted_compression(metadata: &MetadataMap) -> Vec<CompressionType> {
+    metadata
+        .get_all(ACCEPT_COMPRESSION_HEADER)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(parse_compression_name)
+        .collect()
+}
+
+/// Chooses the compression of a response to the client that sent `metadata`,
+/// returning the first codec accepted by the client that is also in `supported`
+///
+/// Returns `None`, i.e. no compression, if the client did not accept any of the
+/// `supported` codecs.
+pub fn negotiate_compression(
+    metadata: &MetadataMap,
+    supported: &[CompressionType],
+) -> Option<CompressionType> {
+    accepted_compression(metadata)
+        .into_iter()
+        .find(|compression| supported.contains(compression))
+}
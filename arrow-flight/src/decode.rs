@@ -0,0 +1,283 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decoding of streams of [`FlightData`] into streams of [`RecordBatch`]es
+
+use std::convert::TryFrom;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use arrow::array::ArrayRef;
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::ipc::{self, reader};
+use arrow::record_batch::RecordBatch;
+use futures::{ready, stream::BoxStream, Stream, StreamExt};
+
+use crate::error::{FlightError, Result};
+use crate::utils::flight_data_to_arrow_batch;
+use crate::FlightData;
+
+/// Decodes a stream of [`FlightData`], such as the response of `do_get`, into a
+/// stream of [`RecordBatch`]es
+///
+/// The schema and dictionary messages are handled internally. Use
+/// [`FlightDataDecoder`] to also access the `app_metadata` of each message.
+///
+/// ```no_run
+/// # use arrow_flight::{flight_service_client::FlightServiceClient, Ticket};
+/// # use arrow_flight::decode::FlightRecordBatchStream;
+/// # use futures::StreamExt;
+/// # async fn f(mut client: FlightServiceClient<tonic::transport::Channel>, ticket: Ticket) {
+/// let response = client.do_get(ticket).await.unwrap().into_inner();
+/// let mut batches = FlightRecordBatchStream::new_from_flight_data(response);
+///
+/// while let Some(batch) = batches.next().await {
+///     let batch = batch.unwrap();
+///     // process the batch
+/// }
+/// # }
+/// ```
+pub struct FlightRecordBatchStream {
+    inner: FlightDataDecoder,
+}
+
+impl FlightRecordBatchStream {
+    /// Create a new [`FlightRecordBatchStream`] from a decoder
+    pub fn new(inner: FlightDataDecoder) -> Self {
+        Self { inner }
+    }
+
+    /// Create a new [`FlightRecordBatchStream`] from a stream of [`FlightData`]
+    pub fn new_from_flight_data<S, E>(inner: S) -> Self
+    where
+        S: Stream<Item = std::result::Result<FlightData, E>> + Send + 'static,
+        E: Into<FlightError>,
+    {
+        Self::new(FlightDataDecoder::new(inner))
+    }
+
+    /// The schema of the stream, available once the schema message was received
+    pub fn schema(&self) -> Option<&SchemaRef> {
+        self.inner.schema()
+    }
+
+    /// Consume self and return the wrapped [`FlightDataDecoder`]
+    pub fn into_inner(self) -> FlightDataDecoder {
+        self.inner
+    }
+}
+
+impl Stream for FlightRecordBatchStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            let data = match ready!(self.inner.poll_next_unpin(cx)) {
+                None => return Poll::Ready(None),
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                Some(Ok(data)) => data,
+            };
+            match data.payload {
+                DecodedPayload::RecordBatch(batch) => {
+                    return Poll::Ready(Some(Ok(batch)))
+                }
+                DecodedPayload::None | DecodedPayload::Schema(_) => {}
+            }
+        }
+    }
+}
+
+/// Decodes a stream of [`FlightData`] into [`DecodedFlightData`], which pairs
+/// each message with its decoded payload
+///
+/// Dictionary batches are applied to the decoder state and not returned.
+pub struct FlightDataDecoder {
+    /// Underlying data stream
+    response: BoxStream<'static, Result<FlightData>>,
+    /// Decoding state, set once the schema message was received
+    state: Option<FlightStreamState>,
+    /// Whether the stream is exhausted or an error was returned
+    done: bool,
+}
+
+impl FlightDataDecoder {
+    /// Create a new decoder from a stream of [`FlightData`]
+    pub fn new<S, E>(response: S) -> Self
+    where
+        S: Stream<Item = std::result::Result<FlightData, E>> + Send + 'static,
+        E: Into<FlightError>,
+    {
+        Self {
+            response: response.map(|data| data.map_err(Into::into)).boxed(),
+            state: None,
+            done: false,
+        }
+    }
+
+    /// The schema of the stream, available once the schema message was received
+    pub fn schema(&self) -> Option<&SchemaRef> {
+        self.state.as_ref().map(|state| &state.schema)
+    }
+
+    /// Decodes `data`, returning `None` for dictionary batches
+    fn extract_message(&mut self, data: FlightData) -> Result<Option<DecodedFlightData>> {
+        // messages carrying only app_metadata have no header
+        if data.data_header.is_empty() {
+            return Ok(Some(DecodedFlightData::new_none(data)));
+        }
+
+        let message = ipc::root_as_message(&data.data_header[..]).map_err(|e| {
+            FlightError::DecodeError(format!("Error decoding root message: {}", e))
+        })?;
+
+        match message.header_type() {
+            ipc::MessageHeader::NONE => Ok(Some(DecodedFlightData::new_none(data))),
+            ipc::MessageHeader::Schema => {
+                let schema = Arc::new(Schema::try_from(&data)?);
+                self.state = Some(FlightStreamState {
+                    dictionaries_by_field: vec![None; schema.fields().len()],
+                    schema: schema.clone(),
+                });
+                Ok(Some(DecodedFlightData::new_schema(data, schema)))
+            }
+            ipc::MessageHeader::DictionaryBatch => {
+                let state = self.state.as_mut().ok_or_else(|| {
+                    FlightError::ProtocolError(
+                        "Received DictionaryBatch prior to Schema".to_string(),
+                    )
+                })?;
+                let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+                    FlightError::DecodeError(
+                        "Unable to convert flight data header to a dictionary batch"
+                            .to_string(),
+                    )
+                })?;
+                reader::read_dictionary(
+                    &data.data_body,
+                    batch,
+                    &state.schema,
+                    &mut state.dictionaries_by_field,
+                )?;
+                Ok(None)
+            }
+            ipc::MessageHeader::RecordBatch => {
+                let state = self.state.as_ref().ok_or_else(|| {
+                    FlightError::ProtocolError(
+                        "Received RecordBatch prior to Schema".to_string(),
+                    )
+                })?;
+                let batch = flight_data_to_arrow_batch(
+                    &data,
+                    state.schema.clone(),
+                    &state.dictionaries_by_field,
+                )?;
+                Ok(Some(DecodedFlightData::new_record_batch(data, batch)))
+            }
+            other => Err(FlightError::NotYetImplemented(format!(
+                "Unsupported message type: {:?}",
+                other
+            ))),
+        }
+    }
+}
+
+impl Stream for FlightDataDecoder {
+    type Item = Result<DecodedFlightData>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+        loop {
+            let result = match ready!(self.response.poll_next_unpin(cx)) {
+                None => {
+                    self.done = true;
+                    return Poll::Ready(None);
+                }
+                Some(data) => data.and_then(|data| self.extract_message(data)),
+            };
+            match result {
+                Ok(Some(decoded)) => return Poll::Ready(Some(Ok(decoded))),
+                // dictionary batch, keep going
+                Ok(None) => {}
+                Err(e) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
+
+/// The decoding state of a [`FlightDataDecoder`]
+struct FlightStreamState {
+    schema: SchemaRef,
+    dictionaries_by_field: Vec<Option<ArrayRef>>,
+}
+
+/// A [`FlightData`] message together with its decoded payload
+#[derive(Debug)]
+pub struct DecodedFlightData {
+    pub inner: FlightData,
+    pub payload: DecodedPayload,
+}
+
+impl DecodedFlightData {
+    pub fn new_none(inner: FlightData) -> Self {
+        Self {
+            inner,
+            payload: DecodedPayload::None,
+        }
+    }
+
+    pub fn new_schema(inner: FlightData, schema: SchemaRef) -> Self {
+        Self {
+            inner,
+            payload: DecodedPayload::Schema(schema),
+        }
+    }
+
+    pub fn new_record_batch(inner: FlightData, batch: RecordBatch) -> Self {
+        Self {
+            inner,
+            payload: DecodedPayload::RecordBatch(batch),
+        }
+    }
+
+    /// The `app_metadata` of the message
+    pub fn app_metadata(&self) -> &[u8] {
+        &self.inner.app_metadata
+    }
+}
+
+/// The decoded payload of a [`FlightData`] message
+#[derive(Debug)]
+pub enum DecodedPayload {
+    /// The message has no payload, e.g. it only carries `app_metadata`
+    None,
+    /// A schema message, which resets the decoding state
+    Schema(SchemaRef),
+    /// A record batch
+    RecordBatch(RecordBatch),
+}
@@ -0,0 +1,415 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Encoding of streams of [`RecordBatch`]es into streams of [`FlightData`]
+
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use arrow::array::{make_array, ArrayData, ArrayRef, MutableArrayData};
+use arrow::datatypes::{DataType, SchemaRef};
+use arrow::ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
+use arrow::record_batch::RecordBatch;
+use futures::{ready, Stream, StreamExt};
+
+use crate::error::{FlightError, Result};
+use crate::exchange::ExchangeMessage;
+use crate::{FlightData, FlightDescriptor};
+
+/// Default target size for the encoded [`FlightData`] messages.
+///
+/// The default maximum message size of gRPC is 4MB, so this leaves plenty of room
+/// for the IPC metadata and any other overhead.
+pub const GRPC_TARGET_MAX_FLIGHT_SIZE_BYTES: usize = 2 * 1024 * 1024;
+
+/// Creates a [`FlightDataEncoder`], which converts a stream of [`RecordBatch`]es
+/// into a stream of [`FlightData`] ready to be sent over Flight
+///
+/// The encoder emits a schema message before the first batch, followed by any
+/// dictionary batches required by each record batch, and then the record batch
+/// itself. Dictionaries are only resent when they change.
+///
+/// Batches larger than the maximum message size, see
+/// [`FlightDataEncoderBuilder::with_max_flight_data_size`], are split row-wise into
+/// several messages.
+///
+/// ```no_run
+/// # use std::sync::Arc;
+/// # use arrow::array::{ArrayRef, UInt32Array};
+/// # use arrow::record_batch::RecordBatch;
+/// # use arrow_flight::encode::FlightDataEncoderBuilder;
+/// # use futures::stream::{self, StreamExt};
+/// # async fn f() {
+/// let a: ArrayRef = Arc::new(UInt32Array::from(vec![1, 2]));
+/// let batch = RecordBatch::try_from_iter(vec![("a", a)]).unwrap();
+///
+/// let mut flight_data_stream = FlightDataEncoderBuilder::new()
+///     .build(stream::iter(vec![Ok(batch)]));
+///
+/// while let Some(flight_data) = flight_data_stream.next().await {
+///     // send flight_data to the client, e.g. as the response of `do_get`
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FlightDataEncoderBuilder {
+    /// The maximum approximate target message size in bytes
+    max_flight_data_size: usize,
+    /// Options for the IPC encoding of the messages
+    options: IpcWriteOptions,
+    /// Metadata added to the `app_metadata` of the first message
+    app_metadata: Vec<u8>,
+    /// Schema sent before any batch, even if the input stream is empty
+    schema: Option<SchemaRef>,
+    /// Descriptor set on the first message
+    descriptor: Option<FlightDescriptor>,
+}
+
+impl Default for FlightDataEncoderBuilder {
+    fn default() -> Self {
+        Self {
+            max_flight_data_size: GRPC_TARGET_MAX_FLIGHT_SIZE_BYTES,
+            options: IpcWriteOptions::default(),
+            app_metadata: vec![],
+            schema: None,
+            descriptor: None,
+        }
+    }
+}
+
+impl FlightDataEncoderBuilder {
+    /// Create a new builder with the default IPC options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the (approximate) maximum size, in bytes, of the [`FlightData`]
+    /// produced by this encoder. Defaults to 2MB.
+    ///
+    /// Batches whose buffers are larger than this are split row-wise into
+    /// several messages. As the size of the encoded message also includes the
+    /// IPC metadata, this should be set comfortably below the maximum message
+    /// size accepted by the gRPC transport, 4MB by default. Dictionaries are
+    /// sent as a single message regardless of their size.
+    pub fn with_max_flight_data_size(mut self, max_flight_data_size: usize) -> Self {
+        self.max_flight_data_size = max_flight_data_size;
+        self
+    }
+
+    /// Specify the IPC options used to encode the messages
+    pub fn with_options(mut self, options: IpcWriteOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Specify application specific metadata included in the `app_metadata`
+    /// of the first `FlightData` message, containing the schema
+    pub fn with_metadata(mut self, app_metadata: Vec<u8>) -> Self {
+        self.app_metadata = app_metadata;
+        self
+    }
+
+    /// Specify the schema of the stream. It is sent before the first batch, or on
+    /// its own if the input stream is empty.
+    ///
+    /// If not specified, the schema of the first batch is used.
+    pub fn with_schema(mut self, schema: SchemaRef) -> Self {
+        self.schema = Some(schema);
+        self
+    }
+
+    /// Specify a [`FlightDescriptor`] set on the first `FlightData` message, as
+    /// required when the stream is sent with `do_put`
+    pub fn with_flight_descriptor(
+        mut self,
+        descriptor: Option<FlightDescriptor>,
+    ) -> Self {
+        self.descriptor = descriptor;
+        self
+    }
+
+    /// Returns a [`FlightDataEncoder`] encoding the batches of `input`
+    pub fn build<S>(self, input: S) -> FlightDataEncoder
+    where
+        S: Stream<Item = Result<RecordBatch>> + Send + Sync + 'static,
+    {
+        self.build_exchange(input.map(|batch| batch.map(ExchangeMessage::from)))
+    }
+
+    /// Returns a [`FlightDataEncoder`] encoding the [`ExchangeMessage`]s of
+    /// `input`, sending the `app_metadata` of each message along with its batch
+    pub fn build_exchange<S>(self, input: S) -> FlightDataEncoder
+    where
+        S: Stream<Item = Result<ExchangeMessage>> + Send + Sync + 'static,
+    {
+        let Self {
+            max_flight_data_size,
+            options,
+            app_metadata,
+            schema,
+            descriptor,
+        } = self;
+
+        let mut encoder = FlightDataEncoder {
+            inner: Box::pin(input),
+            schema: None,
+            queue: VecDeque::new(),
+            encoder: FlightIpcEncoder::new(options),
+            app_metadata: Some(app_metadata),
+            descriptor,
+            max_flight_data_size,
+            done: false,
+        };
+
+        if let Some(schema) = schema {
+            encoder.encode_schema(&schema);
+        }
+        encoder
+    }
+}
+
+/// Stream that encodes a stream of [`RecordBatch`]es, or of [`ExchangeMessage`]s,
+/// into a stream of [`FlightData`], see [`FlightDataEncoderBuilder`]
+///
+/// As required by the generated Flight service, the stream is `Send + Sync` and
+/// can be returned, once mapped to `Result<FlightData, Status>`, from `do_get`.
+pub struct FlightDataEncoder {
+    /// Input stream
+    inner: Pin<Box<dyn Stream<Item = Result<ExchangeMessage>> + Send + Sync + 'static>>,
+    /// The schema of the stream, set once it has been sent
+    schema: Option<SchemaRef>,
+    /// Messages encoded but not yet returned
+    queue: VecDeque<FlightData>,
+    /// Encodes the schema and the batches
+    encoder: FlightIpcEncoder,
+    /// Metadata for the first message, taken once sent
+    app_metadata: Option<Vec<u8>>,
+    /// Descriptor for the first message, taken once sent
+    descriptor: Option<FlightDescriptor>,
+    /// The maximum approximate target message size in bytes
+    max_flight_data_size: usize,
+    /// Whether the input stream is exhausted or an error was returned
+    done: bool,
+}
+
+impl FlightDataEncoder {
+    /// Queues `data`, setting the descriptor and metadata if it is the first message
+    fn queue_message(&mut self, mut data: FlightData) {
+        if let Some(descriptor) = self.descriptor.take() {
+            data.flight_descriptor = Some(descriptor);
+        }
+        if let Some(app_metadata) = self.app_metadata.take() {
+            if data.app_metadata.is_empty() {
+                data.app_metadata = app_metadata;
+            } else if !app_metadata.is_empty() {
+                // the message has its own metadata, send ours first on its own
+                self.queue.push_back(FlightData {
+                    flight_descriptor: data.flight_descriptor.take(),
+                    app_metadata,
+                    ..Default::default()
+                });
+            }
+        }
+        self.queue.push_back(data);
+    }
+
+    fn encode_schema(&mut self, schema: &SchemaRef) {
+        let data = self.encoder.encode_schema(schema);
+        self.queue_message(data);
+        self.schema = Some(schema.clone());
+    }
+
+    fn encode_message(&mut self, message: ExchangeMessage) -> Result<()> {
+        let ExchangeMessage {
+            batch,
+            app_metadata,
+        } = message;
+        match batch {
+            Some(batch) => self.encode_batch(batch, app_metadata),
+            None => {
+                self.queue_message(FlightData {
+                    app_metadata,
+                    ..Default::default()
+                });
+                Ok(())
+            }
+        }
+    }
+
+    /// Encodes `batch`, setting `app_metadata` on its first message
+    fn encode_batch(&mut self, batch: RecordBatch, app_metadata: Vec<u8>) -> Result<()> {
+        match self.schema.clone() {
+            None => self.encode_schema(&batch.schema()),
+            Some(schema) if schema.fields() != batch.schema().fields() => {
+                return Err(FlightError::ProtocolError(format!(
+                    "Batch schema {:?} does not match the schema of the stream {:?}",
+                    batch.schema(),
+                    schema
+                )));
+            }
+            Some(_) => {}
+        }
+
+        let mut app_metadata = Some(app_metadata);
+        for batch in split_batch_for_grpc_response(batch, self.max_flight_data_size)? {
+            let (dictionaries, mut batch) = self.encoder.encode_batch(&batch)?;
+            for dictionary in dictionaries {
+                self.queue_message(dictionary);
+            }
+            if let Some(app_metadata) = app_metadata.take() {
+                batch.app_metadata = app_metadata;
+            }
+            self.queue_message(batch);
+        }
+        Ok(())
+    }
+}
+
+impl Stream for FlightDataEncoder {
+    type Item = Result<FlightData>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(data) = self.queue.pop_front() {
+                return Poll::Ready(Some(Ok(data)));
+            }
+            if self.done {
+                return Poll::Ready(None);
+            }
+
+            match ready!(self.inner.poll_next_unpin(cx)) {
+                None => self.done = true,
+                Some(Ok(message)) => {
+                    if let Err(e) = self.encode_message(message) {
+                        self.done = true;
+                        self.queue.clear();
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+                Some(Err(e)) => {
+                    self.done = true;
+                    self.queue.clear();
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
+
+/// Splits `batch` row-wise into batches whose buffers are approximately at most
+/// `max_flight_data_size` bytes
+///
+/// The IPC writer encodes the whole buffers of an array, regardless of its offset
+/// and length, so the split batches are copied into new, compact arrays.
+fn split_batch_for_grpc_response(
+    batch: RecordBatch,
+    max_flight_data_size: usize,
+) -> Result<Vec<RecordBatch>> {
+    let size: usize = batch
+        .columns()
+        .iter()
+        .map(|column| encoded_buffer_size(column.data()))
+        .sum();
+    let max_flight_data_size = max_flight_data_size.max(1);
+    let num_rows = batch.num_rows();
+    let n_batches = (size / max_flight_data_size
+        + usize::from(size % max_flight_data_size != 0))
+    .min(num_rows);
+    if n_batches <= 1 {
+        return Ok(vec![batch]);
+    }
+
+    let rows_per_batch = (num_rows + n_batches - 1) / n_batches;
+    let mut out = Vec::with_capacity(n_batches);
+    let mut offset = 0;
+    while offset < num_rows {
+        let length = rows_per_batch.min(num_rows - offset);
+        let columns = batch
+            .columns()
+            .iter()
+            .map(|column| compact_slice(column, offset, length))
+            .collect();
+        out.push(RecordBatch::try_new(batch.schema(), columns)?);
+        offset += length;
+    }
+    Ok(out)
+}
+
+/// The size of the buffers the IPC writer encodes for `data`, excluding the
+/// values of dictionaries, which are sent separately
+fn encoded_buffer_size(data: &ArrayData) -> usize {
+    let mut size: usize = data.buffers().iter().map(|buffer| buffer.len()).sum();
+    if let Some(buffer) = data.null_buffer() {
+        size += buffer.len();
+    }
+    if !matches!(data.data_type(), DataType::Dictionary(_, _)) {
+        size += data
+            .child_data()
+            .iter()
+            .map(encoded_buffer_size)
+            .sum::<usize>();
+    }
+    size
+}
+
+/// Copies `length` rows of `array` starting at `offset` into a new array
+fn compact_slice(array: &ArrayRef, offset: usize, length: usize) -> ArrayRef {
+    let mut data = MutableArrayData::new(vec![array.data()], false, length);
+    data.extend(0, offset, offset + length);
+    make_array(data.freeze())
+}
+
+/// Encodes schemas and batches into [`FlightData`], keeping track of the
+/// dictionaries already sent
+struct FlightIpcEncoder {
+    options: IpcWriteOptions,
+    data_gen: IpcDataGenerator,
+    dictionary_tracker: DictionaryTracker,
+}
+
+impl FlightIpcEncoder {
+    fn new(options: IpcWriteOptions) -> Self {
+        Self {
+            options,
+            data_gen: IpcDataGenerator::default(),
+            dictionary_tracker: DictionaryTracker::new(false),
+        }
+    }
+
+    fn encode_schema(&self, schema: &SchemaRef) -> FlightData {
+        self.data_gen.schema_to_bytes(schema, &self.options).into()
+    }
+
+    /// Returns the dictionaries that changed since the previous batch, followed by
+    /// the encoded batch
+    fn encode_batch(
+        &mut self,
+        batch: &RecordBatch,
+    ) -> Result<(Vec<FlightData>, FlightData)> {
+        let (dictionaries, batch) = self.data_gen.encoded_batch(
+            batch,
+            &mut self.dictionary_tracker,
+            &self.options,
+        )?;
+        let dictionaries = dictionaries.into_iter().map(Into::into).collect();
+        Ok((dictionaries, batch.into()))
+    }
+}
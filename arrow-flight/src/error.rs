@@ -0,0 +1,101 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines `FlightError` for representing failures in Flight operations
+
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use arrow::error::ArrowError;
+
+/// Errors that can occur while encoding, decoding or transporting Flight data
+#[derive(Debug)]
+pub enum FlightError {
+    /// Returned when functionality is not yet available.
+    NotYetImplemented(String),
+    /// Error from the underlying Arrow library
+    Arrow(ArrowError),
+    /// Error returned by the gRPC transport
+    Tonic(tonic::Status),
+    /// Some unexpected message was received
+    ProtocolError(String),
+    /// An error occurred during decoding
+    DecodeError(String),
+    /// Some other (opaque) error
+    ExternalError(Box<dyn Error + Send + Sync>),
+}
+
+impl FlightError {
+    /// Wraps an external error in a `FlightError`
+    pub fn from_external_error(error: Box<dyn Error + Send + Sync>) -> Self {
+        FlightError::ExternalError(error)
+    }
+}
+
+impl Display for FlightError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlightError::NotYetImplemented(source) => {
+                write!(f, "Not yet implemented: {}", source)
+            }
+            FlightError::Arrow(source) => write!(f, "Arrow error: {}", source),
+            FlightError::Tonic(source) => write!(f, "Tonic error: {}", source),
+            FlightError::ProtocolError(desc) => write!(f, "Protocol error: {}", desc),
+            FlightError::DecodeError(desc) => write!(f, "Decode error: {}", desc),
+            FlightError::ExternalError(source) => write!(f, "External error: {}", source),
+        }
+    }
+}
+
+impl Error for FlightError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FlightError::Arrow(source) => Some(source),
+            FlightError::Tonic(source) => Some(source),
+            FlightError::ExternalError(source) => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<ArrowError> for FlightError {
+    fn from(error: ArrowError) -> Self {
+        FlightError::Arrow(error)
+    }
+}
+
+impl From<tonic::Status> for FlightError {
+    fn from(status: tonic::Status) -> Self {
+        FlightError::Tonic(status)
+    }
+}
+
+impl From<FlightError> for tonic::Status {
+    fn from(error: FlightError) -> Self {
+        match error {
+            FlightError::NotYetImplemented(e) => tonic::Status::unimplemented(e),
+            FlightError::Tonic(status) => status,
+            FlightError::ProtocolError(e) | FlightError::DecodeError(e) => {
+                tonic::Status::invalid_argument(e)
+            }
+            e => tonic::Status::internal(e.to_string()),
+        }
+    }
+}
+
+/// Result type for Flight operations
+pub type Result<T> = std::result::Result<T, FlightError>;
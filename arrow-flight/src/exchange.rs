@@ -0,0 +1,219 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Utilities for bidirectional `DoExchange` calls
+//!
+//! Both sides of an exchange send [`ExchangeMessage`]s, pairing an optional
+//! [`RecordBatch`] with application specific metadata:
+//!
+//! * [`FlightExchangeStream`] decodes the incoming [`FlightData`]
+//! * [`FlightDataEncoderBuilder::build_exchange`] encodes the outgoing messages
+//! * [`exchange`] pairs the two for a server implementing `do_exchange`
+//!
+//! A client sends its messages with a [`FlightDescriptor`] set on the encoder,
+//! and decodes the response with [`FlightExchangeStream`].
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use arrow::record_batch::RecordBatch;
+use futures::{ready, Stream, StreamExt};
+use tokio::sync::mpsc;
+
+use crate::decode::{DecodedPayload, FlightDataDecoder};
+use crate::encode::{FlightDataEncoder, FlightDataEncoderBuilder};
+use crate::error::{FlightError, Result};
+use crate::{FlightData, FlightDescriptor};
+
+/// Number of outgoing messages buffered by [`exchange`] before applying
+/// backpressure to the handler
+const EXCHANGE_CHANNEL_CAPACITY: usize = 2;
+
+/// A message of a `DoExchange` call: a record batch, application specific
+/// metadata, or both
+#[derive(Debug, Clone, Default)]
+pub struct ExchangeMessage {
+    /// The record batch carried by the message, if any
+    pub batch: Option<RecordBatch>,
+    /// Application specific metadata, empty if none
+    pub app_metadata: Vec<u8>,
+}
+
+impl ExchangeMessage {
+    /// Create a message carrying `batch` and `app_metadata`
+    pub fn new(batch: RecordBatch, app_metadata: Vec<u8>) -> Self {
+        Self {
+            batch: Some(batch),
+            app_metadata,
+        }
+    }
+
+    /// Create a message carrying only `app_metadata`
+    pub fn new_metadata(app_metadata: Vec<u8>) -> Self {
+        Self {
+            batch: None,
+            app_metadata,
+        }
+    }
+}
+
+impl From<RecordBatch> for ExchangeMessage {
+    fn from(batch: RecordBatch) -> Self {
+        Self {
+            batch: Some(batch),
+            app_metadata: vec![],
+        }
+    }
+}
+
+/// Decodes a stream of [`FlightData`] received in a `DoExchange` call into a
+/// stream of [`ExchangeMessage`]s
+///
+/// Schema and dictionary messages are handled internally, and are only returned
+/// if they carry `app_metadata`.
+pub struct FlightExchangeStream {
+    inner: FlightDataDecoder,
+    descriptor: Option<FlightDescriptor>,
+}
+
+impl FlightExchangeStream {
+    /// Create a new [`FlightExchangeStream`] from a stream of [`FlightData`]
+    pub fn new<S, E>(input: S) -> Self
+    where
+        S: Stream<Item = std::result::Result<FlightData, E>> + Send + 'static,
+        E: Into<FlightError>,
+    {
+        Self {
+            inner: FlightDataDecoder::new(input),
+            descriptor: None,
+        }
+    }
+
+    /// The [`FlightDescriptor`] sent by the client, available once the first
+    /// message was received
+    pub fn descriptor(&self) -> Option<&FlightDescriptor> {
+        self.descriptor.as_ref()
+    }
+
+    /// Consume self and return the wrapped [`FlightDataDecoder`]
+    pub fn into_inner(self) -> FlightDataDecoder {
+        self.inner
+    }
+}
+
+impl Stream for FlightExchangeStream {
+    type Item = Result<ExchangeMessage>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            let mut data = match ready!(self.inner.poll_next_unpin(cx)) {
+                None => return Poll::Ready(None),
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
+                Some(Ok(data)) => data,
+            };
+            if let Some(descriptor) = data.inner.flight_descriptor.take() {
+                self.descriptor.get_or_insert(descriptor);
+            }
+            let app_metadata = std::mem::take(&mut data.inner.app_metadata);
+            match data.payload {
+                DecodedPayload::RecordBatch(batch) => {
+                    return Poll::Ready(Some(Ok(ExchangeMessage::new(
+                        batch,
+                        app_metadata,
+                    ))))
+                }
+                DecodedPayload::None | DecodedPayload::Schema(_) => {
+                    if !app_metadata.is_empty() {
+                        return Poll::Ready(Some(Ok(ExchangeMessage::new_metadata(
+                            app_metadata,
+                        ))));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Pairs the decoded input of a `DoExchange` call with an encoder for its output
+///
+/// `handler` receives the incoming messages and returns the outgoing ones, which
+/// are encoded with `encoder`. As the response stream of the Flight service must
+/// be `Sync`, the handler's stream is driven by a task spawned on the current
+/// tokio runtime, and its messages forwarded to the returned encoder. An error
+/// returned by the handler ends the exchange.
+///
+/// ```no_run
+/// # use std::pin::Pin;
+/// # use arrow_flight::FlightData;
+/// # use arrow_flight::encode::FlightDataEncoderBuilder;
+/// # use arrow_flight::exchange::exchange;
+/// # use futures::{Stream, StreamExt};
+/// # use tonic::{Request, Response, Status, Streaming};
+/// # type DoExchangeStream =
+/// #     Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send + Sync + 'static>>;
+/// async fn do_exchange(
+///     request: Request<Streaming<FlightData>>,
+/// ) -> Result<Response<DoExchangeStream>, Status> {
+///     // echo back the batches and metadata sent by the client
+///     let encoder = FlightDataEncoderBuilder::new();
+///     let output = exchange(request.into_inner(), encoder, |input| input);
+///     Ok(Response::new(Box::pin(output.map(|data| data.map_err(Status::from)))))
+/// }
+/// ```
+pub fn exchange<S, E, H, O>(
+    input: S,
+    encoder: FlightDataEncoderBuilder,
+    handler: H,
+) -> FlightDataEncoder
+where
+    S: Stream<Item = std::result::Result<FlightData, E>> + Send + 'static,
+    E: Into<FlightError>,
+    H: FnOnce(FlightExchangeStream) -> O,
+    O: Stream<Item = Result<ExchangeMessage>> + Send + 'static,
+{
+    let output = handler(FlightExchangeStream::new(input));
+    let (sender, receiver) = mpsc::channel(EXCHANGE_CHANNEL_CAPACITY);
+
+    tokio::spawn(async move {
+        let mut output = Box::pin(output);
+        while let Some(message) = output.next().await {
+            let is_err = message.is_err();
+            // stop if the response was dropped, e.g. the client went away
+            if sender.send(message).await.is_err() || is_err {
+                break;
+            }
+        }
+    });
+
+    encoder.build_exchange(ReceiverStream { receiver })
+}
+
+/// Adapts a [`mpsc::Receiver`] into a [`Stream`]
+struct ReceiverStream<T> {
+    receiver: mpsc::Receiver<T>,
+}
+
+impl<T> Stream for ReceiverStream<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        self.receiver.poll_recv(cx)
+    }
+}
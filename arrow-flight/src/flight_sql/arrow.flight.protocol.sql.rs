@@ -0,0 +1,529 @@
+// This file was automatically generated through the build.rs script, and should not be edited.
+
+///
+/// Represents a metadata request. Used in the command member of FlightDescriptor
+/// for the following RPC calls:
+///  - GetSchema: return the Arrow schema of the query.
+///  - GetFlightInfo: execute the metadata request.
+///
+/// The returned Arrow schema will be:
+/// <
+///  info_name: uint32 not null,
+///  value: dense_union<
+///              string_value: utf8,
+///              bool_value: bool,
+///              bigint_value: int64,
+///              int32_bitmask: int32,
+///              string_list: list<string_data: utf8>
+///              int32_to_int32_list_map: map<key: int32, value: list<$data$: int32>>
+/// >
+/// where there is one row per requested piece of metadata information.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetSqlInfo {
+    ///
+    /// Values are modelled after ODBC's SQLGetInfo() function. This information is intended to provide
+    /// Flight SQL clients with basic, SQL syntax and SQL functions related information.
+    /// More information types can be added in future releases.
+    ///
+    /// Note that the set of metadata may expand.
+    ///
+    /// Initially, Flight SQL will support the following information types:
+    /// - Server Information - Range [0-500)
+    /// - Syntax Information - Range [500-1000)
+    /// Range [0-10,000) is reserved for defaults (see SqlInfo enum for default options).
+    /// Custom options should start at 10,000.
+    ///
+    /// If omitted, then all metadata will be retrieved.
+    #[prost(uint32, repeated, tag = "1")]
+    pub info: ::prost::alloc::vec::Vec<u32>,
+}
+///
+/// Represents a request to retrieve the list of catalogs on a Flight SQL enabled backend.
+/// The definition of a catalog depends on vendor/implementation. It is usually the database itself
+/// Used in the command member of FlightDescriptor for the following RPC calls:
+///  - GetSchema: return the Arrow schema of the query.
+///  - GetFlightInfo: execute the catalog metadata request.
+///
+/// The returned Arrow schema will be:
+/// <
+///  catalog_name: utf8 not null
+/// >
+/// The returned data should be ordered by catalog_name.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetCatalogs {}
+///
+/// Represents a request to retrieve the list of database schemas on a Flight SQL enabled backend.
+/// The definition of a database schema depends on vendor/implementation. It is usually a collection of tables.
+/// Used in the command member of FlightDescriptor for the following RPC calls:
+///  - GetSchema: return the Arrow schema of the query.
+///  - GetFlightInfo: execute the catalog metadata request.
+///
+/// The returned Arrow schema will be:
+/// <
+///  catalog_name: utf8,
+///  db_schema_name: utf8 not null
+/// >
+/// The returned data should be ordered by catalog_name, then db_schema_name.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetDbSchemas {
+    ///
+    /// Specifies the Catalog to search for the tables.
+    /// An empty string retrieves those without a catalog.
+    /// If omitted the catalog name should not be used to narrow the search.
+    #[prost(string, optional, tag = "1")]
+    pub catalog: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// Specifies a filter pattern for schemas to search for.
+    /// When no db_schema_filter_pattern is provided, the pattern will not be used to narrow the search.
+    /// In the pattern string, two special characters can be used to denote matching rules:
+    ///    - "%" means to match any substring with 0 or more characters.
+    ///    - "_" means to match any one character.
+    #[prost(string, optional, tag = "2")]
+    pub db_schema_filter_pattern: ::core::option::Option<::prost::alloc::string::String>,
+}
+///
+/// Represents a request to retrieve the list of tables, and optionally their schemas, on a Flight SQL enabled backend.
+/// Used in the command member of FlightDescriptor for the following RPC calls:
+///  - GetSchema: return the Arrow schema of the query.
+///  - GetFlightInfo: execute the catalog metadata request.
+///
+/// The returned Arrow schema will be:
+/// <
+///  catalog_name: utf8,
+///  db_schema_name: utf8,
+///  table_name: utf8 not null,
+///  table_type: utf8 not null,
+///  [optional] table_schema: bytes not null (schema of the table as described in Schema.fbs::Schema,
+///                                           it is serialized as an IPC message.)
+/// >
+/// The returned data should be ordered by catalog_name, db_schema_name, table_name, then table_type, followed by table_schema if requested.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetTables {
+    ///
+    /// Specifies the Catalog to search for the tables.
+    /// An empty string retrieves those without a catalog.
+    /// If omitted the catalog name should not be used to narrow the search.
+    #[prost(string, optional, tag = "1")]
+    pub catalog: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// Specifies a filter pattern for schemas to search for.
+    /// When no db_schema_filter_pattern is provided, all schemas matching other filters are searched.
+    /// In the pattern string, two special characters can be used to denote matching rules:
+    ///    - "%" means to match any substring with 0 or more characters.
+    ///    - "_" means to match any one character.
+    #[prost(string, optional, tag = "2")]
+    pub db_schema_filter_pattern: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// Specifies a filter pattern for tables to search for.
+    /// When no table_name_filter_pattern is provided, all tables matching other filters are searched.
+    /// In the pattern string, two special characters can be used to denote matching rules:
+    ///    - "%" means to match any substring with 0 or more characters.
+    ///    - "_" means to match any one character.
+    #[prost(string, optional, tag = "3")]
+    pub table_name_filter_pattern: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// Specifies a filter of table types which must match.
+    /// The table types depend on vendor/implementation. It is usually used to separate tables from views or system tables.
+    /// TABLE, VIEW, and SYSTEM TABLE are commonly supported.
+    #[prost(string, repeated, tag = "4")]
+    pub table_types: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Specifies if the Arrow schema should be returned for found tables.
+    #[prost(bool, tag = "5")]
+    pub include_schema: bool,
+}
+///
+/// Represents a request to retrieve the list of table types on a Flight SQL enabled backend.
+/// The table types depend on vendor/implementation. It is usually used to separate tables from views or system tables.
+/// TABLE, VIEW, and SYSTEM TABLE are commonly supported.
+/// Used in the command member of FlightDescriptor for the following RPC calls:
+///  - GetSchema: return the Arrow schema of the query.
+///  - GetFlightInfo: execute the catalog metadata request.
+///
+/// The returned Arrow schema will be:
+/// <
+///  table_type: utf8 not null
+/// >
+/// The returned data should be ordered by table_type.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetTableTypes {}
+///
+/// Represents a request to retrieve the primary keys of a table on a Flight SQL enabled backend.
+/// Used in the command member of FlightDescriptor for the following RPC calls:
+///  - GetSchema: return the Arrow schema of the query.
+///  - GetFlightInfo: execute the catalog metadata request.
+///
+/// The returned Arrow schema will be:
+/// <
+///  catalog_name: utf8,
+///  db_schema_name: utf8,
+///  table_name: utf8 not null,
+///  column_name: utf8 not null,
+///  key_name: utf8,
+///  key_sequence: int not null
+/// >
+/// The returned data should be ordered by catalog_name, db_schema_name, table_name, key_name, then key_sequence.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetPrimaryKeys {
+    ///
+    /// Specifies the catalog to search for the table.
+    /// An empty string retrieves those without a catalog.
+    /// If omitted the catalog name should not be used to narrow the search.
+    #[prost(string, optional, tag = "1")]
+    pub catalog: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// Specifies the schema to search for the table.
+    /// An empty string retrieves those without a schema.
+    /// If omitted the schema name should not be used to narrow the search.
+    #[prost(string, optional, tag = "2")]
+    pub db_schema: ::core::option::Option<::prost::alloc::string::String>,
+    /// Specifies the table to get the primary keys for.
+    #[prost(string, tag = "3")]
+    pub table: ::prost::alloc::string::String,
+}
+///
+/// Represents a request to retrieve a description of the foreign key columns that reference the given table's
+/// primary key columns (the foreign keys exported by a table) of a table on a Flight SQL enabled backend.
+/// Used in the command member of FlightDescriptor for the following RPC calls:
+///  - GetSchema: return the Arrow schema of the query.
+///  - GetFlightInfo: execute the catalog metadata request.
+///
+/// The returned Arrow schema will be:
+/// <
+///  pk_catalog_name: utf8,
+///  pk_db_schema_name: utf8,
+///  pk_table_name: utf8 not null,
+///  pk_column_name: utf8 not null,
+///  fk_catalog_name: utf8,
+///  fk_db_schema_name: utf8,
+///  fk_table_name: utf8 not null,
+///  fk_column_name: utf8 not null,
+///  key_sequence: int not null,
+///  fk_key_name: utf8,
+///  pk_key_name: utf8,
+///  update_rule: uint1 not null,
+///  delete_rule: uint1 not null
+/// >
+/// The returned data should be ordered by fk_catalog_name, fk_db_schema_name, fk_table_name, fk_key_name, then key_sequence.
+/// update_rule and delete_rule returns a byte that is equivalent to actions declared on UpdateDeleteRules enum.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetExportedKeys {
+    ///
+    /// Specifies the catalog to search for the foreign key table.
+    /// An empty string retrieves those without a catalog.
+    /// If omitted the catalog name should not be used to narrow the search.
+    #[prost(string, optional, tag = "1")]
+    pub catalog: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// Specifies the schema to search for the foreign key table.
+    /// An empty string retrieves those without a schema.
+    /// If omitted the schema name should not be used to narrow the search.
+    #[prost(string, optional, tag = "2")]
+    pub db_schema: ::core::option::Option<::prost::alloc::string::String>,
+    /// Specifies the foreign key table to get the foreign keys for.
+    #[prost(string, tag = "3")]
+    pub table: ::prost::alloc::string::String,
+}
+///
+/// Represents a request to retrieve the foreign keys of a table on a Flight SQL enabled backend.
+/// Used in the command member of FlightDescriptor for the following RPC calls:
+///  - GetSchema: return the Arrow schema of the query.
+///  - GetFlightInfo: execute the catalog metadata request.
+///
+/// The returned Arrow schema will be the same as for CommandGetExportedKeys.
+/// The returned data should be ordered by pk_catalog_name, pk_db_schema_name, pk_table_name, pk_key_name, then key_sequence.
+/// update_rule and delete_rule returns a byte that is equivalent to actions declared on UpdateDeleteRules enum.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetImportedKeys {
+    ///
+    /// Specifies the catalog to search for the primary key table.
+    /// An empty string retrieves those without a catalog.
+    /// If omitted the catalog name should not be used to narrow the search.
+    #[prost(string, optional, tag = "1")]
+    pub catalog: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// Specifies the schema to search for the primary key table.
+    /// An empty string retrieves those without a schema.
+    /// If omitted the schema name should not be used to narrow the search.
+    #[prost(string, optional, tag = "2")]
+    pub db_schema: ::core::option::Option<::prost::alloc::string::String>,
+    /// Specifies the primary key table to get the foreign keys for.
+    #[prost(string, tag = "3")]
+    pub table: ::prost::alloc::string::String,
+}
+///
+/// Represents a request to retrieve a description of the foreign key columns in the given foreign key table that
+/// reference the primary key or the columns representing a unique constraint of the parent table (could be the same
+/// or a different table) on a Flight SQL enabled backend.
+/// Used in the command member of FlightDescriptor for the following RPC calls:
+///  - GetSchema: return the Arrow schema of the query.
+///  - GetFlightInfo: execute the catalog metadata request.
+///
+/// The returned Arrow schema will be the same as for CommandGetExportedKeys.
+/// The returned data should be ordered by fk_catalog_name, fk_db_schema_name, fk_table_name, fk_key_name, then key_sequence.
+/// update_rule and delete_rule returns a byte that is equivalent to actions declared on UpdateDeleteRules enum.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetCrossReference {
+    ///
+    /// The catalog name where the parent table is.
+    /// An empty string retrieves those without a catalog.
+    /// If omitted the catalog name should not be used to narrow the search.
+    #[prost(string, optional, tag = "1")]
+    pub pk_catalog: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// The Schema name where the parent table is.
+    /// An empty string retrieves those without a schema.
+    /// If omitted the schema name should not be used to narrow the search.
+    #[prost(string, optional, tag = "2")]
+    pub pk_db_schema: ::core::option::Option<::prost::alloc::string::String>,
+    /// The parent table name. It cannot be null.
+    #[prost(string, tag = "3")]
+    pub pk_table: ::prost::alloc::string::String,
+    ///
+    /// The catalog name where the foreign table is.
+    /// An empty string retrieves those without a catalog.
+    /// If omitted the catalog name should not be used to narrow the search.
+    #[prost(string, optional, tag = "4")]
+    pub fk_catalog: ::core::option::Option<::prost::alloc::string::String>,
+    ///
+    /// The schema name where the foreign table is.
+    /// An empty string retrieves those without a schema.
+    /// If omitted the schema name should not be used to narrow the search.
+    #[prost(string, optional, tag = "5")]
+    pub fk_db_schema: ::core::option::Option<::prost::alloc::string::String>,
+    /// The foreign table name. It cannot be null.
+    #[prost(string, tag = "6")]
+    pub fk_table: ::prost::alloc::string::String,
+}
+///
+/// Request message for the "CreatePreparedStatement" action on a Flight SQL enabled backend.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionCreatePreparedStatementRequest {
+    /// The valid SQL string to create a prepared statement for.
+    #[prost(string, tag = "1")]
+    pub query: ::prost::alloc::string::String,
+}
+///
+/// Wrap the result of a "GetPreparedStatement" action.
+///
+/// The resultant PreparedStatement can be closed either:
+/// - Manually, through the "ClosePreparedStatement" action;
+/// - Automatically, by a server timeout.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionCreatePreparedStatementResult {
+    /// Opaque handle for the prepared statement on the server.
+    #[prost(bytes = "vec", tag = "1")]
+    pub prepared_statement_handle: ::prost::alloc::vec::Vec<u8>,
+    /// If a result set generating query was provided, dataset_schema contains the
+    /// schema of the dataset as described in Schema.fbs::Schema, it is serialized as an IPC message.
+    #[prost(bytes = "vec", tag = "2")]
+    pub dataset_schema: ::prost::alloc::vec::Vec<u8>,
+    /// If the query provided contained parameters, parameter_schema contains the
+    /// schema of the expected parameters as described in Schema.fbs::Schema, it is serialized as an IPC message.
+    #[prost(bytes = "vec", tag = "3")]
+    pub parameter_schema: ::prost::alloc::vec::Vec<u8>,
+}
+///
+/// Request message for the "ClosePreparedStatement" action on a Flight SQL enabled backend.
+/// Closes server resources associated with the prepared statement handle.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionClosePreparedStatementRequest {
+    /// Opaque handle for the prepared statement on the server.
+    #[prost(bytes = "vec", tag = "1")]
+    pub prepared_statement_handle: ::prost::alloc::vec::Vec<u8>,
+}
+///
+/// Represents a SQL query. Used in the command member of FlightDescriptor
+/// for the following RPC calls:
+///  - GetSchema: return the Arrow schema of the query.
+///  - GetFlightInfo: execute the query.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandStatementQuery {
+    /// The SQL syntax.
+    #[prost(string, tag = "1")]
+    pub query: ::prost::alloc::string::String,
+}
+///
+/// Represents a ticket resulting from GetFlightInfo with a CommandStatementQuery.
+/// This should be used only once and treated as an opaque value, that is, clients should not attempt to parse this.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TicketStatementQuery {
+    /// Unique identifier for the instance of the statement to execute.
+    #[prost(bytes = "vec", tag = "1")]
+    pub statement_handle: ::prost::alloc::vec::Vec<u8>,
+}
+///
+/// Represents an instance of executing a prepared statement. Used in the command member of FlightDescriptor for
+/// the following RPC calls:
+///  - DoPut: bind parameter values. All of the bound parameter sets will be executed as a single atomic execution.
+///  - GetFlightInfo: execute the prepared statement instance.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandPreparedStatementQuery {
+    /// Opaque handle for the prepared statement on the server.
+    #[prost(bytes = "vec", tag = "1")]
+    pub prepared_statement_handle: ::prost::alloc::vec::Vec<u8>,
+}
+///
+/// Represents a SQL update query. Used in the command member of FlightDescriptor
+/// for the the RPC call DoPut to cause the server to execute the included SQL update.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandStatementUpdate {
+    /// The SQL syntax.
+    #[prost(string, tag = "1")]
+    pub query: ::prost::alloc::string::String,
+}
+///
+/// Represents a SQL update query. Used in the command member of FlightDescriptor
+/// for the the RPC call DoPut to cause the server to execute the included
+/// prepared statement handle as an update.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandPreparedStatementUpdate {
+    /// Opaque handle for the prepared statement on the server.
+    #[prost(bytes = "vec", tag = "1")]
+    pub prepared_statement_handle: ::prost::alloc::vec::Vec<u8>,
+}
+///
+/// Returned from the RPC call DoPut when a CommandStatementUpdate
+/// CommandPreparedStatementUpdate was in the request, containing
+/// results from the update.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DoPutUpdateResult {
+    /// The number of records updated. A return value of -1 represents
+    /// an unknown updated record count.
+    #[prost(int64, tag = "1")]
+    pub record_count: i64,
+}
+/// Options for CommandGetSqlInfo.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum SqlInfo {
+    /// Retrieves a UTF-8 string with the name of the Flight SQL Server.
+    FlightSqlServerName = 0,
+    /// Retrieves a UTF-8 string with the native version of the Flight SQL Server.
+    FlightSqlServerVersion = 1,
+    /// Retrieves a UTF-8 string with the Arrow format version of the Flight SQL Server.
+    FlightSqlServerArrowVersion = 2,
+    ///
+    /// Retrieves a boolean value indicating whether the Flight SQL Server is read only.
+    ///
+    /// Returns:
+    /// - false: if read-write
+    /// - true: if read only
+    FlightSqlServerReadOnly = 3,
+    ///
+    /// Retrieves a boolean value indicating whether the Flight SQL Server supports CREATE and DROP of catalogs.
+    ///
+    /// Returns:
+    /// - false: if it doesn't support CREATE and DROP of catalogs.
+    /// - true: if it supports CREATE and DROP of catalogs.
+    SqlDdlCatalog = 500,
+    ///
+    /// Retrieves a boolean value indicating whether the Flight SQL Server supports CREATE and DROP of schemas.
+    ///
+    /// Returns:
+    /// - false: if it doesn't support CREATE and DROP of schemas.
+    /// - true: if it supports CREATE and DROP of schemas.
+    SqlDdlSchema = 501,
+    ///
+    /// Indicates whether the Flight SQL Server supports CREATE and DROP of tables.
+    ///
+    /// Returns:
+    /// - false: if it doesn't support CREATE and DROP of tables.
+    /// - true: if it supports CREATE and DROP of tables.
+    SqlDdlTable = 502,
+    ///
+    /// Retrieves a uint32 value representing the enum uint32 ordinal for the case sensitivity of catalog, table, schema and table names.
+    ///
+    /// The possible values are listed in `arrow.flight.protocol.sql.SqlSupportedCaseSensitivity`.
+    SqlIdentifierCase = 503,
+    /// Retrieves a UTF-8 string with the supported character(s) used to surround a delimited identifier.
+    SqlIdentifierQuoteChar = 504,
+    ///
+    /// Retrieves a uint32 value representing the enum uint32 ordinal for the case sensitivity of quoted identifiers.
+    ///
+    /// The possible values are listed in `arrow.flight.protocol.sql.SqlSupportedCaseSensitivity`.
+    SqlQuotedIdentifierCase = 505,
+    ///
+    /// Retrieves a boolean value indicating whether all tables are selectable.
+    ///
+    /// Returns:
+    /// - false: if not all tables are selectable or if none are;
+    /// - true: if all tables are selectable.
+    SqlAllTablesAreSelectable = 506,
+    ///
+    /// Retrieves the null ordering.
+    ///
+    /// Returns a uint32 ordinal for the null ordering being used, as described in
+    /// `arrow.flight.protocol.sql.SqlNullOrdering`.
+    SqlNullOrdering = 507,
+    /// Retrieves a UTF-8 string list with values of the supported keywords.
+    SqlKeywords = 508,
+    /// Retrieves a UTF-8 string list with values of the supported numeric functions.
+    SqlNumericFunctions = 509,
+    /// Retrieves a UTF-8 string list with values of the supported string functions.
+    SqlStringFunctions = 510,
+    /// Retrieves a UTF-8 string list with values of the supported system functions.
+    SqlSystemFunctions = 511,
+    /// Retrieves a UTF-8 string list with values of the supported datetime functions.
+    SqlDatetimeFunctions = 512,
+    ///
+    /// Retrieves the UTF-8 string that can be used to escape wildcard characters.
+    /// This is the string that can be used to escape '_' or '%' in the catalog search parameters that are a pattern
+    /// (and therefore use one of the wildcard characters).
+    /// The '_' character represents any single character; the '%' character represents any sequence of zero or more
+    /// characters.
+    SqlSearchStringEscape = 513,
+    ///
+    /// Retrieves a UTF-8 string with all the "extra" characters that can be used in unquoted identifier names
+    /// (those beyond a-z, A-Z, 0-9 and _).
+    SqlExtraNameCharacters = 514,
+    ///
+    /// Retrieves a boolean value indicating whether column aliasing is supported.
+    /// If so, the SQL AS clause can be used to provide names for computed columns or to provide alias names for columns
+    /// as required.
+    ///
+    /// Returns:
+    /// - false: if column aliasing is unsupported;
+    /// - true: if column aliasing is supported.
+    SqlSupportsColumnAliasing = 515,
+    ///
+    /// Retrieves a boolean value indicating whether concatenations between null and non-null values being
+    /// null are supported.
+    ///
+    /// - Returns:
+    /// - false: if concatenations between null and non-null values being null are unsupported;
+    /// - true: if concatenations between null and non-null values being null are supported.
+    SqlNullPlusNullIsNull = 516,
+}
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum SqlSupportedCaseSensitivity {
+    SqlCaseSensitivityUnknown = 0,
+    SqlCaseSensitivityCaseInsensitive = 1,
+    SqlCaseSensitivityUppercase = 2,
+    SqlCaseSensitivityLowercase = 3,
+}
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum SqlNullOrdering {
+    SqlNullsSortedHigh = 0,
+    SqlNullsSortedLow = 1,
+    SqlNullsSortedAtStart = 2,
+    SqlNullsSortedAtEnd = 3,
+}
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration,
+)]
+#[repr(i32)]
+pub enum UpdateDeleteRules {
+    Cascade = 0,
+    Restrict = 1,
+    SetNull = 2,
+    NoAction = 3,
+    SetDefault = 4,
+}
@@ -0,0 +1,422 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A typed client for Flight SQL servers
+
+use arrow::datatypes::Schema;
+use arrow::error::{ArrowError, Result};
+use arrow::ipc::writer::IpcWriteOptions;
+use arrow::record_batch::RecordBatch;
+use futures::stream;
+use prost::Message;
+use tonic::metadata::AsciiMetadataValue;
+use tonic::transport::Channel;
+use tonic::{Request, Streaming};
+
+use super::server::{CLOSE_PREPARED_STATEMENT, CREATE_PREPARED_STATEMENT};
+use super::{
+    decode_any, encode_message, ActionClosePreparedStatementRequest,
+    ActionCreatePreparedStatementRequest, ActionCreatePreparedStatementResult,
+    CommandGetCatalogs, CommandGetCrossReference, CommandGetDbSchemas,
+    CommandGetExportedKeys, CommandGetImportedKeys, CommandGetPrimaryKeys,
+    CommandGetSqlInfo, CommandGetTableTypes, CommandGetTables,
+    CommandPreparedStatementQuery, CommandPreparedStatementUpdate, CommandStatementQuery,
+    CommandStatementUpdate, DoPutUpdateResult, ProstAnyExt, ProstMessageExt,
+};
+use crate::flight_descriptor::DescriptorType;
+use crate::flight_service_client::FlightServiceClient;
+use crate::utils::{
+    arrow_schema_from_ipc_message, flight_data_from_arrow_batch,
+    flight_data_from_arrow_schema,
+};
+use crate::{Action, FlightData, FlightDescriptor, FlightInfo, Ticket};
+
+/// A client for a Flight SQL server
+///
+/// Wraps a [`FlightServiceClient`], encoding the Flight SQL commands into the
+/// underlying Flight requests.
+#[derive(Debug, Clone)]
+pub struct FlightSqlServiceClient {
+    token: Option<String>,
+    flight_client: FlightServiceClient<Channel>,
+}
+
+impl FlightSqlServiceClient {
+    /// Creates a new client using the given channel
+    pub fn new(channel: Channel) -> Self {
+        Self::new_from_inner(FlightServiceClient::new(channel))
+    }
+
+    /// Creates a new client wrapping an existing [`FlightServiceClient`]
+    pub fn new_from_inner(flight_client: FlightServiceClient<Channel>) -> Self {
+        Self {
+            token: None,
+            flight_client,
+        }
+    }
+
+    /// Sets a bearer token sent in the `authorization` header of every request
+    pub fn set_token(&mut self, token: String) {
+        self.token = Some(token);
+    }
+
+    /// Returns the underlying [`FlightServiceClient`]
+    pub fn inner(&mut self) -> &mut FlightServiceClient<Channel> {
+        &mut self.flight_client
+    }
+
+    /// Execute a query on the server, returning the [`FlightInfo`] to fetch its results
+    pub async fn execute(&mut self, query: String) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(CommandStatementQuery { query })
+            .await
+    }
+
+    /// Execute an update query on the server, returning the number of affected
+    /// records, or -1 if it is unknown
+    pub async fn execute_update(&mut self, query: String) -> Result<i64> {
+        let cmd = CommandStatementUpdate { query };
+        let descriptor = command_descriptor(&cmd);
+        self.do_put_update(vec![FlightData {
+            flight_descriptor: Some(descriptor),
+            ..Default::default()
+        }])
+        .await
+    }
+
+    /// Request a list of catalogs
+    pub async fn get_catalogs(&mut self) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(CommandGetCatalogs {})
+            .await
+    }
+
+    /// Request a list of database schemas
+    pub async fn get_db_schemas(
+        &mut self,
+        request: CommandGetDbSchemas,
+    ) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(request).await
+    }
+
+    /// Request a list of tables
+    pub async fn get_tables(&mut self, request: CommandGetTables) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(request).await
+    }
+
+    /// Request the primary keys of a table
+    pub async fn get_primary_keys(
+        &mut self,
+        request: CommandGetPrimaryKeys,
+    ) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(request).await
+    }
+
+    /// Request the foreign keys referencing the primary key of a table
+    pub async fn get_exported_keys(
+        &mut self,
+        request: CommandGetExportedKeys,
+    ) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(request).await
+    }
+
+    /// Request the foreign keys of a table
+    pub async fn get_imported_keys(
+        &mut self,
+        request: CommandGetImportedKeys,
+    ) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(request).await
+    }
+
+    /// Request the foreign keys of a table referencing another table
+    pub async fn get_cross_reference(
+        &mut self,
+        request: CommandGetCrossReference,
+    ) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(request).await
+    }
+
+    /// Request a list of table types
+    pub async fn get_table_types(&mut self) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(CommandGetTableTypes {})
+            .await
+    }
+
+    /// Request a list of SQL information
+    pub async fn get_sql_info(
+        &mut self,
+        request: CommandGetSqlInfo,
+    ) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(request).await
+    }
+
+    /// Fetch the data of a [`Ticket`], as returned in the endpoints of a [`FlightInfo`]
+    pub async fn do_get(&mut self, ticket: Ticket) -> Result<Streaming<FlightData>> {
+        let request = self.request(ticket)?;
+        let response = self
+            .flight_client
+            .do_get(request)
+            .await
+            .map_err(status_to_arrow_error)?;
+        Ok(response.into_inner())
+    }
+
+    /// Create a prepared statement on the server
+    pub async fn prepare(&mut self, query: String) -> Result<PreparedStatement> {
+        let cmd = ActionCreatePreparedStatementRequest { query };
+        let action = Action {
+            r#type: CREATE_PREPARED_STATEMENT.to_string(),
+            body: encode_message(&cmd.as_any()),
+        };
+        let request = self.request(action)?;
+        let mut results = self
+            .flight_client
+            .do_action(request)
+            .await
+            .map_err(status_to_arrow_error)?
+            .into_inner();
+        let result = results
+            .message()
+            .await
+            .map_err(status_to_arrow_error)?
+            .ok_or_else(|| {
+                ArrowError::IoError(
+                    "No result returned from CreatePreparedStatement".to_string(),
+                )
+            })?;
+        let any = decode_any(&result.body)?;
+        let result = any
+            .unpack::<ActionCreatePreparedStatementResult>()?
+            .ok_or_else(|| {
+                ArrowError::ParseError(format!(
+                    "Expected ActionCreatePreparedStatementResult, got {}",
+                    any.type_url
+                ))
+            })?;
+        let dataset_schema = arrow_schema_from_ipc_message(&result.dataset_schema)?;
+        let parameter_schema = arrow_schema_from_ipc_message(&result.parameter_schema)?;
+        Ok(PreparedStatement::new(
+            self.clone(),
+            result.prepared_statement_handle,
+            dataset_schema,
+            parameter_schema,
+        ))
+    }
+
+    async fn get_flight_info_for_command<M: ProstMessageExt>(
+        &mut self,
+        cmd: M,
+    ) -> Result<FlightInfo> {
+        let request = self.request(command_descriptor(&cmd))?;
+        let response = self
+            .flight_client
+            .get_flight_info(request)
+            .await
+            .map_err(status_to_arrow_error)?;
+        Ok(response.into_inner())
+    }
+
+    async fn do_put_update(&mut self, data: Vec<FlightData>) -> Result<i64> {
+        let mut results = self.do_put(data).await?;
+        let result = results
+            .message()
+            .await
+            .map_err(status_to_arrow_error)?
+            .ok_or_else(|| {
+                ArrowError::IoError("No result returned from DoPut".to_string())
+            })?;
+        let result = DoPutUpdateResult::decode(&*result.app_metadata).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to decode DoPutUpdateResult: {}", err))
+        })?;
+        Ok(result.record_count)
+    }
+
+    async fn do_put(
+        &mut self,
+        data: Vec<FlightData>,
+    ) -> Result<Streaming<crate::PutResult>> {
+        let request = self.request(stream::iter(data))?;
+        let response = self
+            .flight_client
+            .do_put(request)
+            .await
+            .map_err(status_to_arrow_error)?;
+        Ok(response.into_inner())
+    }
+
+    async fn do_action(&mut self, action: Action) -> Result<()> {
+        let request = self.request(action)?;
+        let mut results = self
+            .flight_client
+            .do_action(request)
+            .await
+            .map_err(status_to_arrow_error)?
+            .into_inner();
+        // drain the results so that the server can complete the action
+        while results
+            .message()
+            .await
+            .map_err(status_to_arrow_error)?
+            .is_some()
+        {}
+        Ok(())
+    }
+
+    /// Wraps `message` into a request, setting the authorization header if a
+    /// token was provided
+    fn request<T>(&self, message: T) -> Result<Request<T>> {
+        let mut request = Request::new(message);
+        if let Some(token) = &self.token {
+            let value = format!("Bearer {}", token)
+                .parse::<AsciiMetadataValue>()
+                .map_err(|err| {
+                    ArrowError::InvalidArgumentError(format!("Invalid token: {}", err))
+                })?;
+            request.metadata_mut().insert("authorization", value);
+        }
+        Ok(request)
+    }
+}
+
+/// A prepared statement created on a Flight SQL server by
+/// [`FlightSqlServiceClient::prepare`]
+///
+/// The statement should be closed with [`PreparedStatement::close`] to release the
+/// server resources associated with it.
+#[derive(Debug, Clone)]
+pub struct PreparedStatement {
+    client: FlightSqlServiceClient,
+    handle: Vec<u8>,
+    dataset_schema: Schema,
+    parameter_schema: Schema,
+    parameters: Option<RecordBatch>,
+}
+
+impl PreparedStatement {
+    fn new(
+        client: FlightSqlServiceClient,
+        handle: Vec<u8>,
+        dataset_schema: Schema,
+        parameter_schema: Schema,
+    ) -> Self {
+        Self {
+            client,
+            handle,
+            dataset_schema,
+            parameter_schema,
+            parameters: None,
+        }
+    }
+
+    /// The schema of the results of this statement, empty if it does not
+    /// return results
+    pub fn dataset_schema(&self) -> &Schema {
+        &self.dataset_schema
+    }
+
+    /// The schema of the parameters of this statement, empty if it has no
+    /// parameters
+    pub fn parameter_schema(&self) -> &Schema {
+        &self.parameter_schema
+    }
+
+    /// Sets the parameters bound to this statement when it is next executed
+    ///
+    /// Returns an error if `parameters` does not match the parameter schema
+    pub fn set_parameters(&mut self, parameters: RecordBatch) -> Result<()> {
+        if parameters.schema().fields() != self.parameter_schema.fields() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Parameters do not match the parameter schema of the prepared statement, expected {:?} got {:?}",
+                self.parameter_schema,
+                parameters.schema()
+            )));
+        }
+        self.parameters = Some(parameters);
+        Ok(())
+    }
+
+    /// Execute the prepared statement, returning the [`FlightInfo`] to fetch its
+    /// results
+    pub async fn execute(&mut self) -> Result<FlightInfo> {
+        let cmd = CommandPreparedStatementQuery {
+            prepared_statement_handle: self.handle.clone(),
+        };
+        if let Some(data) = self.parameter_data(&cmd) {
+            let mut results = self.client.do_put(data).await?;
+            while results
+                .message()
+                .await
+                .map_err(status_to_arrow_error)?
+                .is_some()
+            {}
+        }
+        self.client.get_flight_info_for_command(cmd).await
+    }
+
+    /// Execute the prepared statement as an update, returning the number of
+    /// affected records, or -1 if it is unknown
+    pub async fn execute_update(&mut self) -> Result<i64> {
+        let cmd = CommandPreparedStatementUpdate {
+            prepared_statement_handle: self.handle.clone(),
+        };
+        let data = self.parameter_data(&cmd).unwrap_or_else(|| {
+            vec![FlightData {
+                flight_descriptor: Some(command_descriptor(&cmd)),
+                ..Default::default()
+            }]
+        });
+        self.client.do_put_update(data).await
+    }
+
+    /// Close the prepared statement, releasing its resources on the server
+    pub async fn close(mut self) -> Result<()> {
+        let cmd = ActionClosePreparedStatementRequest {
+            prepared_statement_handle: self.handle.clone(),
+        };
+        let action = Action {
+            r#type: CLOSE_PREPARED_STATEMENT.to_string(),
+            body: encode_message(&cmd.as_any()),
+        };
+        self.client.do_action(action).await
+    }
+
+    /// Encodes the bound parameters as the `DoPut` stream for `cmd`
+    fn parameter_data<M: ProstMessageExt>(&self, cmd: &M) -> Option<Vec<FlightData>> {
+        let parameters = self.parameters.as_ref()?;
+        let options = IpcWriteOptions::default();
+
+        let mut schema = flight_data_from_arrow_schema(&parameters.schema(), &options);
+        schema.flight_descriptor = Some(command_descriptor(cmd));
+
+        let (dictionaries, batch) = flight_data_from_arrow_batch(parameters, &options);
+        let mut data = vec![schema];
+        data.extend(dictionaries);
+        data.push(batch);
+        Some(data)
+    }
+}
+
+/// Creates a command [`FlightDescriptor`] for `cmd`
+fn command_descriptor<M: ProstMessageExt>(cmd: &M) -> FlightDescriptor {
+    FlightDescriptor {
+        r#type: DescriptorType::Cmd as i32,
+        cmd: encode_message(&cmd.as_any()),
+        path: vec![],
+    }
+}
+
+fn status_to_arrow_error(status: tonic::Status) -> ArrowError {
+    ArrowError::IoError(format!("{:?}", status))
+}
@@ -0,0 +1,188 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for the [Arrow Flight SQL] protocol, built on top of Arrow Flight.
+//!
+//! The protocol messages are generated from `format/FlightSql.proto`. Commands are
+//! sent to the server packed as [`prost_types::Any`], either in the `cmd` of a
+//! [`FlightDescriptor`](crate::FlightDescriptor), in a [`Ticket`](crate::Ticket) or
+//! in the body of an [`Action`](crate::Action).
+//!
+//! [`server::FlightSqlService`] implements the Flight service on top of these
+//! messages, and [`client::FlightSqlServiceClient`] provides a typed client.
+//!
+//! [Arrow Flight SQL]: https://arrow.apache.org/docs/format/FlightSql.html
+
+use std::convert::TryFrom;
+
+use arrow::error::{ArrowError, Result};
+use prost::Message;
+use prost_types::Any;
+
+include!("arrow.flight.protocol.sql.rs");
+
+pub mod client;
+pub mod server;
+
+/// Extension methods for the Flight SQL protocol messages
+pub trait ProstMessageExt: Message + Default {
+    /// The `type_url` identifying this message when packed into an [`Any`]
+    fn type_url() -> &'static str;
+
+    /// Pack this message into an [`Any`]
+    fn as_any(&self) -> Any;
+}
+
+/// Extension methods to pack and unpack Flight SQL messages from an [`Any`]
+pub trait ProstAnyExt {
+    /// Returns true if this [`Any`] contains a message of type `M`
+    fn is<M: ProstMessageExt>(&self) -> bool;
+
+    /// Unpacks a message of type `M`, returning `None` if this [`Any`]
+    /// contains a different type of message
+    fn unpack<M: ProstMessageExt>(&self) -> Result<Option<M>>;
+
+    /// Packs `message` into an [`Any`]
+    fn pack<M: ProstMessageExt>(message: &M) -> Self;
+}
+
+impl ProstAnyExt for Any {
+    fn is<M: ProstMessageExt>(&self) -> bool {
+        M::type_url() == self.type_url
+    }
+
+    fn unpack<M: ProstMessageExt>(&self) -> Result<Option<M>> {
+        if !self.is::<M>() {
+            return Ok(None);
+        }
+        let message = M::decode(&*self.value).map_err(|err| {
+            ArrowError::ParseError(format!("Unable to decode {}: {}", self.type_url, err))
+        })?;
+        Ok(Some(message))
+    }
+
+    fn pack<M: ProstMessageExt>(message: &M) -> Self {
+        message.as_any()
+    }
+}
+
+/// Encodes `message` into a new buffer
+pub(crate) fn encode_message<M: Message>(message: &M) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(message.encoded_len());
+    message
+        .encode(&mut buf)
+        .expect("buffer is allocated with the message's encoded length");
+    buf
+}
+
+/// Decodes an [`Any`] from `bytes`
+pub(crate) fn decode_any(bytes: &[u8]) -> Result<Any> {
+    Any::decode(bytes)
+        .map_err(|err| ArrowError::ParseError(format!("Unable to decode Any: {}", err)))
+}
+
+macro_rules! flight_sql_commands {
+    ($($name:ident),*) => {
+        $(
+            impl ProstMessageExt for $name {
+                fn type_url() -> &'static str {
+                    concat!(
+                        "type.googleapis.com/arrow.flight.protocol.sql.",
+                        stringify!($name)
+                    )
+                }
+
+                fn as_any(&self) -> Any {
+                    Any {
+                        type_url: Self::type_url().to_string(),
+                        value: encode_message(self),
+                    }
+                }
+            }
+        )*
+
+        /// A decoded Flight SQL message, see [`Command::try_from`]
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum Command {
+            $($name($name),)*
+        }
+
+        impl Command {
+            /// Pack this command into an [`Any`]
+            pub fn into_any(self) -> Any {
+                match self {
+                    $(Command::$name(cmd) => cmd.as_any(),)*
+                }
+            }
+
+            /// The `type_url` of the packed command
+            pub fn type_url(&self) -> &'static str {
+                match self {
+                    $(Command::$name(_) => $name::type_url(),)*
+                }
+            }
+        }
+
+        impl TryFrom<Any> for Command {
+            type Error = ArrowError;
+
+            /// Decodes the Flight SQL message packed in `any`, returning an error
+            /// if it is not a known Flight SQL message
+            fn try_from(any: Any) -> Result<Self> {
+                $(
+                    if let Some(cmd) = any.unpack::<$name>()? {
+                        return Ok(Command::$name(cmd));
+                    }
+                )*
+                Err(ArrowError::InvalidArgumentError(format!(
+                    "Unknown Flight SQL command: {}",
+                    any.type_url
+                )))
+            }
+        }
+
+        impl TryFrom<&[u8]> for Command {
+            type Error = ArrowError;
+
+            /// Decodes an [`Any`] from `bytes` and then the Flight SQL message it contains
+            fn try_from(bytes: &[u8]) -> Result<Self> {
+                Command::try_from(decode_any(bytes)?)
+            }
+        }
+    };
+}
+
+flight_sql_commands!(
+    CommandGetSqlInfo,
+    CommandGetCatalogs,
+    CommandGetDbSchemas,
+    CommandGetTables,
+    CommandGetTableTypes,
+    CommandGetPrimaryKeys,
+    CommandGetExportedKeys,
+    CommandGetImportedKeys,
+    CommandGetCrossReference,
+    ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult,
+    ActionClosePreparedStatementRequest,
+    CommandStatementQuery,
+    TicketStatementQuery,
+    CommandPreparedStatementQuery,
+    CommandStatementUpdate,
+    CommandPreparedStatementUpdate,
+    DoPutUpdateResult
+);
@@ -0,0 +1,578 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A [`FlightService`] implementation for Flight SQL servers
+
+use std::convert::TryFrom;
+use std::pin::Pin;
+
+use futures::{future, stream, Stream, StreamExt};
+use tonic::{Request, Response, Status, Streaming};
+
+use super::{
+    decode_any, encode_message, ActionClosePreparedStatementRequest,
+    ActionCreatePreparedStatementRequest, ActionCreatePreparedStatementResult, Command,
+    CommandGetCatalogs, CommandGetCrossReference, CommandGetDbSchemas,
+    CommandGetExportedKeys, CommandGetImportedKeys, CommandGetPrimaryKeys,
+    CommandGetSqlInfo, CommandGetTableTypes, CommandGetTables,
+    CommandPreparedStatementQuery, CommandPreparedStatementUpdate, CommandStatementQuery,
+    CommandStatementUpdate, DoPutUpdateResult, ProstAnyExt, ProstMessageExt,
+    TicketStatementQuery,
+};
+use crate::{
+    flight_service_server::FlightService, Action, ActionType, Criteria, Empty,
+    FlightData, FlightDescriptor, FlightInfo, HandshakeRequest, HandshakeResponse,
+    PutResult, SchemaResult, Ticket,
+};
+
+/// The name of the action creating a prepared statement
+pub const CREATE_PREPARED_STATEMENT: &str = "CreatePreparedStatement";
+/// The name of the action closing a prepared statement
+pub const CLOSE_PREPARED_STATEMENT: &str = "ClosePreparedStatement";
+
+/// A boxed stream of responses, as returned by the streaming Flight SQL methods
+pub type BoxedFlightStream<T> =
+    Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + Sync + 'static>>;
+
+/// The stream of [`FlightData`] sent by a client to `DoPut`, including the first
+/// message that carried the Flight SQL command
+pub type FlightDataStream =
+    Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send + 'static>>;
+
+/// Implements [`FlightService`] for a Flight SQL server
+///
+/// Each method receives the decoded Flight SQL command together with the original
+/// request, so that implementations can access the request metadata. Methods that
+/// are not overridden return [`Status::unimplemented`].
+#[tonic::async_trait]
+pub trait FlightSqlService: Sync + Send + Sized + 'static {
+    /// Accept a handshake from a client, e.g. to perform authentication
+    async fn do_handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<BoxedFlightStream<HandshakeResponse>>, Status> {
+        Err(Status::unimplemented(
+            "Handshake has no default implementation",
+        ))
+    }
+
+    /// Get a FlightInfo for executing a SQL query
+    async fn get_flight_info_statement(
+        &self,
+        _query: CommandStatementQuery,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(unimplemented_command::<CommandStatementQuery>())
+    }
+
+    /// Get a FlightInfo for executing an already created prepared statement
+    async fn get_flight_info_prepared_statement(
+        &self,
+        _query: CommandPreparedStatementQuery,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(unimplemented_command::<CommandPreparedStatementQuery>())
+    }
+
+    /// Get a FlightInfo for listing catalogs
+    async fn get_flight_info_catalogs(
+        &self,
+        _query: CommandGetCatalogs,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(unimplemented_command::<CommandGetCatalogs>())
+    }
+
+    /// Get a FlightInfo for listing schemas
+    async fn get_flight_info_schemas(
+        &self,
+        _query: CommandGetDbSchemas,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(unimplemented_command::<CommandGetDbSchemas>())
+    }
+
+    /// Get a FlightInfo for listing tables
+    async fn get_flight_info_tables(
+        &self,
+        _query: CommandGetTables,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(unimplemented_command::<CommandGetTables>())
+    }
+
+    /// Get a FlightInfo to extract information about the table types
+    async fn get_flight_info_table_types(
+        &self,
+        _query: CommandGetTableTypes,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(unimplemented_command::<CommandGetTableTypes>())
+    }
+
+    /// Get a FlightInfo for retrieving other information (See SqlInfo)
+    async fn get_flight_info_sql_info(
+        &self,
+        _query: CommandGetSqlInfo,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(unimplemented_command::<CommandGetSqlInfo>())
+    }
+
+    /// Get a FlightInfo to extract information about primary and foreign keys
+    async fn get_flight_info_primary_keys(
+        &self,
+        _query: CommandGetPrimaryKeys,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(unimplemented_command::<CommandGetPrimaryKeys>())
+    }
+
+    /// Get a FlightInfo to extract information about exported keys
+    async fn get_flight_info_exported_keys(
+        &self,
+        _query: CommandGetExportedKeys,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(unimplemented_command::<CommandGetExportedKeys>())
+    }
+
+    /// Get a FlightInfo to extract information about imported keys
+    async fn get_flight_info_imported_keys(
+        &self,
+        _query: CommandGetImportedKeys,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(unimplemented_command::<CommandGetImportedKeys>())
+    }
+
+    /// Get a FlightInfo to extract information about cross reference
+    async fn get_flight_info_cross_reference(
+        &self,
+        _query: CommandGetCrossReference,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        Err(unimplemented_command::<CommandGetCrossReference>())
+    }
+
+    /// Get a FlightDataStream containing the query results
+    async fn do_get_statement(
+        &self,
+        _ticket: TicketStatementQuery,
+        _request: Request<Ticket>,
+    ) -> Result<Response<BoxedFlightStream<FlightData>>, Status> {
+        Err(unimplemented_command::<TicketStatementQuery>())
+    }
+
+    /// Get a FlightDataStream containing the prepared statement query results
+    async fn do_get_prepared_statement(
+        &self,
+        _query: CommandPreparedStatementQuery,
+        _request: Request<Ticket>,
+    ) -> Result<Response<BoxedFlightStream<FlightData>>, Status> {
+        Err(unimplemented_command::<CommandPreparedStatementQuery>())
+    }
+
+    /// Get a FlightDataStream containing the list of catalogs
+    async fn do_get_catalogs(
+        &self,
+        _query: CommandGetCatalogs,
+        _request: Request<Ticket>,
+    ) -> Result<Response<BoxedFlightStream<FlightData>>, Status> {
+        Err(unimplemented_command::<CommandGetCatalogs>())
+    }
+
+    /// Get a FlightDataStream containing the list of schemas
+    async fn do_get_schemas(
+        &self,
+        _query: CommandGetDbSchemas,
+        _request: Request<Ticket>,
+    ) -> Result<Response<BoxedFlightStream<FlightData>>, Status> {
+        Err(unimplemented_command::<CommandGetDbSchemas>())
+    }
+
+    /// Get a FlightDataStream containing the list of tables
+    async fn do_get_tables(
+        &self,
+        _query: CommandGetTables,
+        _request: Request<Ticket>,
+    ) -> Result<Response<BoxedFlightStream<FlightData>>, Status> {
+        Err(unimplemented_command::<CommandGetTables>())
+    }
+
+    /// Get a FlightDataStream containing the data related to the table types
+    async fn do_get_table_types(
+        &self,
+        _query: CommandGetTableTypes,
+        _request: Request<Ticket>,
+    ) -> Result<Response<BoxedFlightStream<FlightData>>, Status> {
+        Err(unimplemented_command::<CommandGetTableTypes>())
+    }
+
+    /// Get a FlightDataStream containing the list of SqlInfo results
+    async fn do_get_sql_info(
+        &self,
+        _query: CommandGetSqlInfo,
+        _request: Request<Ticket>,
+    ) -> Result<Response<BoxedFlightStream<FlightData>>, Status> {
+        Err(unimplemented_command::<CommandGetSqlInfo>())
+    }
+
+    /// Get a FlightDataStream containing the data related to the primary keys
+    async fn do_get_primary_keys(
+        &self,
+        _query: CommandGetPrimaryKeys,
+        _request: Request<Ticket>,
+    ) -> Result<Response<BoxedFlightStream<FlightData>>, Status> {
+        Err(unimplemented_command::<CommandGetPrimaryKeys>())
+    }
+
+    /// Get a FlightDataStream containing the data related to the exported keys
+    async fn do_get_exported_keys(
+        &self,
+        _query: CommandGetExportedKeys,
+        _request: Request<Ticket>,
+    ) -> Result<Response<BoxedFlightStream<FlightData>>, Status> {
+        Err(unimplemented_command::<CommandGetExportedKeys>())
+    }
+
+    /// Get a FlightDataStream containing the data related to the imported keys
+    async fn do_get_imported_keys(
+        &self,
+        _query: CommandGetImportedKeys,
+        _request: Request<Ticket>,
+    ) -> Result<Response<BoxedFlightStream<FlightData>>, Status> {
+        Err(unimplemented_command::<CommandGetImportedKeys>())
+    }
+
+    /// Get a FlightDataStream containing the data related to the cross reference
+    async fn do_get_cross_reference(
+        &self,
+        _query: CommandGetCrossReference,
+        _request: Request<Ticket>,
+    ) -> Result<Response<BoxedFlightStream<FlightData>>, Status> {
+        Err(unimplemented_command::<CommandGetCrossReference>())
+    }
+
+    /// Execute an update SQL statement, returning the number of affected records
+    /// or -1 if it is unknown
+    async fn do_put_statement_update(
+        &self,
+        _query: CommandStatementUpdate,
+        _request: Request<FlightDataStream>,
+    ) -> Result<i64, Status> {
+        Err(unimplemented_command::<CommandStatementUpdate>())
+    }
+
+    /// Bind parameters to a prepared statement
+    async fn do_put_prepared_statement_query(
+        &self,
+        _query: CommandPreparedStatementQuery,
+        _request: Request<FlightDataStream>,
+    ) -> Result<Response<BoxedFlightStream<PutResult>>, Status> {
+        Err(unimplemented_command::<CommandPreparedStatementQuery>())
+    }
+
+    /// Execute an update prepared statement, returning the number of affected
+    /// records or -1 if it is unknown
+    async fn do_put_prepared_statement_update(
+        &self,
+        _query: CommandPreparedStatementUpdate,
+        _request: Request<FlightDataStream>,
+    ) -> Result<i64, Status> {
+        Err(unimplemented_command::<CommandPreparedStatementUpdate>())
+    }
+
+    /// Create a prepared statement from the given SQL statement
+    async fn do_action_create_prepared_statement(
+        &self,
+        _query: ActionCreatePreparedStatementRequest,
+        _request: Request<Action>,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        Err(unimplemented_command::<ActionCreatePreparedStatementRequest>())
+    }
+
+    /// Close a prepared statement
+    async fn do_action_close_prepared_statement(
+        &self,
+        _query: ActionClosePreparedStatementRequest,
+        _request: Request<Action>,
+    ) -> Result<(), Status> {
+        Err(unimplemented_command::<ActionClosePreparedStatementRequest>())
+    }
+}
+
+fn unimplemented_command<M: ProstMessageExt>() -> Status {
+    Status::unimplemented(format!("{} is not implemented", M::type_url()))
+}
+
+fn invalid_argument(err: impl std::fmt::Display) -> Status {
+    Status::invalid_argument(err.to_string())
+}
+
+#[tonic::async_trait]
+impl<T: FlightSqlService> FlightService for T {
+    type HandshakeStream = BoxedFlightStream<HandshakeResponse>;
+    type ListFlightsStream = BoxedFlightStream<FlightInfo>;
+    type DoGetStream = BoxedFlightStream<FlightData>;
+    type DoPutStream = BoxedFlightStream<PutResult>;
+    type DoActionStream = BoxedFlightStream<crate::Result>;
+    type ListActionsStream = BoxedFlightStream<ActionType>;
+    type DoExchangeStream = BoxedFlightStream<FlightData>;
+
+    async fn handshake(
+        &self,
+        request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        self.do_handshake(request).await
+    }
+
+    async fn list_flights(
+        &self,
+        _request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let command =
+            Command::try_from(&request.get_ref().cmd[..]).map_err(invalid_argument)?;
+
+        match command {
+            Command::CommandStatementQuery(cmd) => {
+                self.get_flight_info_statement(cmd, request).await
+            }
+            Command::CommandPreparedStatementQuery(cmd) => {
+                self.get_flight_info_prepared_statement(cmd, request).await
+            }
+            Command::CommandGetCatalogs(cmd) => {
+                self.get_flight_info_catalogs(cmd, request).await
+            }
+            Command::CommandGetDbSchemas(cmd) => {
+                self.get_flight_info_schemas(cmd, request).await
+            }
+            Command::CommandGetTables(cmd) => {
+                self.get_flight_info_tables(cmd, request).await
+            }
+            Command::CommandGetTableTypes(cmd) => {
+                self.get_flight_info_table_types(cmd, request).await
+            }
+            Command::CommandGetSqlInfo(cmd) => {
+                self.get_flight_info_sql_info(cmd, request).await
+            }
+            Command::CommandGetPrimaryKeys(cmd) => {
+                self.get_flight_info_primary_keys(cmd, request).await
+            }
+            Command::CommandGetExportedKeys(cmd) => {
+                self.get_flight_info_exported_keys(cmd, request).await
+            }
+            Command::CommandGetImportedKeys(cmd) => {
+                self.get_flight_info_imported_keys(cmd, request).await
+            }
+            Command::CommandGetCrossReference(cmd) => {
+                self.get_flight_info_cross_reference(cmd, request).await
+            }
+            cmd => Err(Status::invalid_argument(format!(
+                "{} is not a valid GetFlightInfo command",
+                cmd.type_url()
+            ))),
+        }
+    }
+
+    async fn get_schema(
+        &self,
+        _request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let command =
+            Command::try_from(&request.get_ref().ticket[..]).map_err(invalid_argument)?;
+
+        match command {
+            Command::TicketStatementQuery(cmd) => {
+                self.do_get_statement(cmd, request).await
+            }
+            Command::CommandPreparedStatementQuery(cmd) => {
+                self.do_get_prepared_statement(cmd, request).await
+            }
+            Command::CommandGetCatalogs(cmd) => self.do_get_catalogs(cmd, request).await,
+            Command::CommandGetDbSchemas(cmd) => self.do_get_schemas(cmd, request).await,
+            Command::CommandGetTables(cmd) => self.do_get_tables(cmd, request).await,
+            Command::CommandGetTableTypes(cmd) => {
+                self.do_get_table_types(cmd, request).await
+            }
+            Command::CommandGetSqlInfo(cmd) => self.do_get_sql_info(cmd, request).await,
+            Command::CommandGetPrimaryKeys(cmd) => {
+                self.do_get_primary_keys(cmd, request).await
+            }
+            Command::CommandGetExportedKeys(cmd) => {
+                self.do_get_exported_keys(cmd, request).await
+            }
+            Command::CommandGetImportedKeys(cmd) => {
+                self.do_get_imported_keys(cmd, request).await
+            }
+            Command::CommandGetCrossReference(cmd) => {
+                self.do_get_cross_reference(cmd, request).await
+            }
+            cmd => Err(Status::invalid_argument(format!(
+                "{} is not a valid DoGet ticket",
+                cmd.type_url()
+            ))),
+        }
+    }
+
+    async fn do_put(
+        &self,
+        mut request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        // the command is carried in the descriptor of the first message, which is
+        // chained back in front of the stream handed to the implementation
+        let first = request
+            .get_mut()
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("DoPut stream is empty"))?;
+        let cmd = first
+            .flight_descriptor
+            .as_ref()
+            .map(|descriptor| descriptor.cmd.clone())
+            .ok_or_else(|| {
+                Status::invalid_argument("DoPut requires a flight descriptor")
+            })?;
+        let command = Command::try_from(&cmd[..]).map_err(invalid_argument)?;
+
+        let metadata = request.metadata().clone();
+        let stream = stream::once(future::ready(Ok(first))).chain(request.into_inner());
+        let mut request = Request::new(Box::pin(stream) as FlightDataStream);
+        *request.metadata_mut() = metadata;
+
+        match command {
+            Command::CommandStatementUpdate(cmd) => {
+                let record_count = self.do_put_statement_update(cmd, request).await?;
+                Ok(update_result_response(record_count))
+            }
+            Command::CommandPreparedStatementQuery(cmd) => {
+                self.do_put_prepared_statement_query(cmd, request).await
+            }
+            Command::CommandPreparedStatementUpdate(cmd) => {
+                let record_count =
+                    self.do_put_prepared_statement_update(cmd, request).await?;
+                Ok(update_result_response(record_count))
+            }
+            cmd => Err(Status::invalid_argument(format!(
+                "{} is not a valid DoPut command",
+                cmd.type_url()
+            ))),
+        }
+    }
+
+    async fn do_action(
+        &self,
+        request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        let action_type = request.get_ref().r#type.clone();
+        let any = decode_any(&request.get_ref().body).map_err(invalid_argument)?;
+
+        match action_type.as_str() {
+            CREATE_PREPARED_STATEMENT => {
+                let cmd = any
+                    .unpack::<ActionCreatePreparedStatementRequest>()
+                    .map_err(invalid_argument)?
+                    .ok_or_else(|| {
+                        Status::invalid_argument(format!(
+                            "Unable to unpack ActionCreatePreparedStatementRequest from {}",
+                            any.type_url
+                        ))
+                    })?;
+                let result = self
+                    .do_action_create_prepared_statement(cmd, request)
+                    .await?;
+                let output = crate::Result {
+                    body: encode_message(&result.as_any()),
+                };
+                Ok(Response::new(Box::pin(stream::iter(vec![Ok(output)]))))
+            }
+            CLOSE_PREPARED_STATEMENT => {
+                let cmd = any
+                    .unpack::<ActionClosePreparedStatementRequest>()
+                    .map_err(invalid_argument)?
+                    .ok_or_else(|| {
+                        Status::invalid_argument(format!(
+                            "Unable to unpack ActionClosePreparedStatementRequest from {}",
+                            any.type_url
+                        ))
+                    })?;
+                self.do_action_close_prepared_statement(cmd, request)
+                    .await?;
+                Ok(Response::new(Box::pin(stream::empty())))
+            }
+            _ => Err(Status::invalid_argument(format!(
+                "Unknown action type: {}",
+                action_type
+            ))),
+        }
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        let actions = vec![
+            Ok(ActionType {
+                r#type: CREATE_PREPARED_STATEMENT.to_string(),
+                description:
+                    "Creates a reusable prepared statement resource on the server. \
+                    Request Message: ActionCreatePreparedStatementRequest. \
+                    Response Message: ActionCreatePreparedStatementResult."
+                        .to_string(),
+            }),
+            Ok(ActionType {
+                r#type: CLOSE_PREPARED_STATEMENT.to_string(),
+                description:
+                    "Closes a reusable prepared statement resource on the server. \
+                    Request Message: ActionClosePreparedStatementRequest. \
+                    Response Message: N/A."
+                        .to_string(),
+            }),
+        ];
+        Ok(Response::new(Box::pin(stream::iter(actions))))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("Not yet implemented"))
+    }
+}
+
+/// Wraps the number of records affected by an update into the `DoPut` response
+fn update_result_response(record_count: i64) -> Response<BoxedFlightStream<PutResult>> {
+    let result = DoPutUpdateResult { record_count };
+    let output = PutResult {
+        app_metadata: encode_message(&result),
+    };
+    Response::new(Box::pin(stream::iter(vec![Ok(output)])))
+}
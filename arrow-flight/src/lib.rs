@@ -17,4 +17,13 @@
 
 include!("arrow.flight.protocol.rs");
 
+pub mod client;
+pub mod compression;
+pub mod decode;
+pub mod encode;
+pub mod error;
+pub mod exchange;
+pub mod flight_sql;
 pub mod utils;
+
+pub use error::FlightError;
@@ -90,6 +90,36 @@ pub fn ipc_message_from_arrow_schema(
     Ok(schema)
 }
 
+/// Convert bytes in the format of `FlightInfo.schema`, as produced by
+/// [`ipc_message_from_arrow_schema`], into an Arrow `Schema`
+///
+/// An empty buffer is interpreted as an empty schema.
+pub fn arrow_schema_from_ipc_message(data: &[u8]) -> Result<Schema> {
+    if data.is_empty() {
+        return Ok(Schema::empty());
+    }
+    if data.len() < 4 {
+        return Err(ArrowError::ParseError(
+            "Unable to read IPC message length".to_string(),
+        ));
+    }
+    // skip the continuation marker, if present, and the message length
+    let data = if data[..4] == [0xff; 4] {
+        data.get(8..)
+    } else {
+        data.get(4..)
+    };
+    let data = data.ok_or_else(|| {
+        ArrowError::ParseError("Unable to read IPC message length".to_string())
+    })?;
+    convert::schema_from_bytes(data).map_err(|err| {
+        ArrowError::ParseError(format!(
+            "Unable to convert IPC message to Arrow schema: {}",
+            err
+        ))
+    })
+}
+
 fn flight_schema_as_flatbuffer(
     arrow_schema: &Schema,
     options: &IpcWriteOptions,
@@ -23,13 +23,15 @@ use std::fmt;
 use std::sync::Arc;
 
 use pyo3::exceptions::PyOSError;
+use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
-use pyo3::{libc::uintptr_t, prelude::*};
 
-use arrow::array::{make_array_from_raw, ArrayRef, Int64Array};
+use arrow::array::{make_array, ArrayData, ArrayRef, Int64Array};
 use arrow::compute::kernels;
+use arrow::datatypes::{DataType, Field, Schema};
 use arrow::error::ArrowError;
-use arrow::ffi;
+use arrow::pyarrow::{FromPyArrow, ToPyArrow};
+use arrow::record_batch::RecordBatch;
 
 /// an error that bridges ArrowError with a Python error
 #[derive(Debug)]
@@ -69,34 +71,12 @@ impl From<PyO3ArrowError> for PyErr {
 }
 
 fn to_rust(ob: PyObject, py: Python) -> PyResult<ArrayRef> {
-    // prepare a pointer to receive the Array struct
-    let (array_pointer, schema_pointer) =
-        ffi::ArrowArray::into_raw(unsafe { ffi::ArrowArray::empty() });
-
-    // make the conversion through PyArrow's private API
-    // this changes the pointer's memory and is thus unsafe. In particular, `_export_to_c` can go out of bounds
-    ob.call_method1(
-        py,
-        "_export_to_c",
-        (array_pointer as uintptr_t, schema_pointer as uintptr_t),
-    )?;
-
-    let array = unsafe { make_array_from_raw(array_pointer, schema_pointer) }
-        .map_err(|e| PyO3ArrowError::from(e))?;
-    Ok(array)
+    let data = ArrayData::from_pyarrow(ob.as_ref(py))?;
+    Ok(make_array(data))
 }
 
 fn to_py(array: ArrayRef, py: Python) -> PyResult<PyObject> {
-    let (array_pointer, schema_pointer) =
-        array.to_raw().map_err(|e| PyO3ArrowError::from(e))?;
-
-    let pa = py.import("pyarrow")?;
-
-    let array = pa.getattr("Array")?.call_method1(
-        "_import_from_c",
-        (array_pointer as uintptr_t, schema_pointer as uintptr_t),
-    )?;
-    Ok(array.to_object(py))
+    array.data().to_pyarrow(py)
 }
 
 /// Returns `array + array` of an int64 array.
@@ -177,6 +157,30 @@ fn round_trip(array: PyObject, py: Python) -> PyResult<PyObject> {
     to_py(array, py)
 }
 
+/// Converts a data type to rust and back to python
+#[pyfunction]
+fn round_trip_type(datatype: PyObject, py: Python) -> PyResult<PyObject> {
+    DataType::from_pyarrow(datatype.as_ref(py))?.to_pyarrow(py)
+}
+
+/// Converts a field to rust and back to python
+#[pyfunction]
+fn round_trip_field(field: PyObject, py: Python) -> PyResult<PyObject> {
+    Field::from_pyarrow(field.as_ref(py))?.to_pyarrow(py)
+}
+
+/// Converts a schema to rust and back to python
+#[pyfunction]
+fn round_trip_schema(schema: PyObject, py: Python) -> PyResult<PyObject> {
+    Schema::from_pyarrow(schema.as_ref(py))?.to_pyarrow(py)
+}
+
+/// Converts a record batch to rust and back to python
+#[pyfunction]
+fn round_trip_record_batch(batch: PyObject, py: Python) -> PyResult<PyObject> {
+    RecordBatch::from_pyarrow(batch.as_ref(py))?.to_pyarrow(py)
+}
+
 #[pymodule]
 fn arrow_pyarrow_integration_testing(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(double))?;
@@ -184,5 +188,9 @@ fn arrow_pyarrow_integration_testing(_py: Python, m: &PyModule) -> PyResult<()>
     m.add_wrapped(wrap_pyfunction!(substring))?;
     m.add_wrapped(wrap_pyfunction!(concatenate))?;
     m.add_wrapped(wrap_pyfunction!(round_trip))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_type))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_field))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_schema))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_record_batch))?;
     Ok(())
 }
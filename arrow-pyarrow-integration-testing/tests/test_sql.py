@@ -95,5 +95,49 @@ class TestCase(unittest.TestCase):
         # No leak of C++ memory
         self.assertEqual(old_allocated, pyarrow.total_allocated_bytes())
 
+    def test_type_roundtrip(self):
+        """
+        Python -> Rust -> Python
+        """
+        for pyarrow_type in [
+            pyarrow.int32(),
+            pyarrow.string(),
+            pyarrow.list_(pyarrow.float64()),
+            pyarrow.struct([("a", pyarrow.bool_())]),
+        ]:
+            restored = arrow_pyarrow_integration_testing.round_trip_type(pyarrow_type)
+            assert restored == pyarrow_type
+
+    def test_field_roundtrip(self):
+        """
+        Python -> Rust -> Python
+        """
+        field = pyarrow.field("a", pyarrow.int64(), nullable=False)
+        restored = arrow_pyarrow_integration_testing.round_trip_field(field)
+        assert restored == field
 
+    def test_schema_roundtrip(self):
+        """
+        Python -> Rust -> Python
+        """
+        schema = pyarrow.schema(
+            [("ints", pyarrow.int64()), ("strings", pyarrow.string())]
+        )
+        restored = arrow_pyarrow_integration_testing.round_trip_schema(schema)
+        assert restored == schema
 
+    def test_record_batch_roundtrip(self):
+        """
+        Python -> Rust -> Python
+        """
+        old_allocated = pyarrow.total_allocated_bytes()
+        batch = pyarrow.record_batch(
+            [pyarrow.array([1, None, 3]), pyarrow.array(["a", "b", None])],
+            names=["ints", "strings"],
+        )
+        restored = arrow_pyarrow_integration_testing.round_trip_record_batch(batch)
+        assert restored.equals(batch)
+        del batch
+        del restored
+        # No leak of C++ memory
+        self.assertEqual(old_allocated, pyarrow.total_allocated_bytes())
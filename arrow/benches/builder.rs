@@ -96,7 +96,7 @@ fn bench_string(c: &mut Criterion) {
     ));
     group.bench_function("bench_string", |b| {
         b.iter(|| {
-            let mut builder = StringBuilder::new(64);
+            let mut builder = StringBuilder::default();
             for _ in 0..NUM_BATCHES * BATCH_SIZE {
                 let _ = black_box(builder.append_value(SAMPLE_STRING));
             }
@@ -48,7 +48,15 @@ fn bench_take(values: &dyn Array, indices: &UInt32Array) {
 
 fn bench_take_bounds_check(values: &dyn Array, indices: &UInt32Array) {
     criterion::black_box(
-        take(values, &indices, Some(TakeOptions { check_bounds: true })).unwrap(),
+        take(
+            values,
+            &indices,
+            Some(TakeOptions {
+                check_bounds: true,
+                ..Default::default()
+            }),
+        )
+        .unwrap(),
     );
 }
 
@@ -26,31 +26,68 @@ use std::{
 };
 
 mod alignment;
+mod pool;
 mod types;
 
 pub use alignment::ALIGNMENT;
+pub use pool::{MemoryPool, MemoryPoolStatistics, TrackingMemoryPool};
 pub use types::NativeType;
 
+/// The owner of a memory region allocated outside of Arrow, such as by `mmap`, a GPU
+/// runtime or a C library, that releases the region when dropped.
+///
+/// This is implemented for every `Send + Sync` type, so any value whose [`Drop`]
+/// implementation frees the region can be used as its owner.
+/// See [`Buffer::from_custom_allocation`](crate::buffer::Buffer::from_custom_allocation).
+pub trait Allocation: Send + Sync {}
+
+impl<T: Send + Sync> Allocation for T {}
+
 // If this number is not zero after all objects have been `drop`, there is a memory leak
 pub static mut ALLOCATIONS: AtomicIsize = AtomicIsize::new(0);
 
 #[inline]
-unsafe fn null_pointer<T: NativeType>() -> NonNull<T> {
-    NonNull::new_unchecked(ALIGNMENT as *mut T)
+unsafe fn null_pointer<T: NativeType>(alignment: usize) -> NonNull<T> {
+    NonNull::new_unchecked(alignment as *mut T)
+}
+
+/// Returns whether `alignment` can be used to allocate memory regions with the
+/// `*_with_alignment` functions of this module, i.e. whether it is a power of two
+/// at least as large as [ALIGNMENT].
+#[inline]
+pub fn is_valid_alignment(alignment: usize) -> bool {
+    alignment.is_power_of_two() && alignment >= ALIGNMENT
 }
 
 /// Allocates a cache-aligned memory region of `size` bytes with uninitialized values.
 /// This is more performant than using [allocate_aligned_zeroed] when all bytes will have
 /// an unknown or non-zero value and is semantically similar to `malloc`.
 pub fn allocate_aligned<T: NativeType>(size: usize) -> NonNull<T> {
+    allocate_aligned_with_alignment(size, ALIGNMENT)
+}
+
+/// Like [allocate_aligned], but aligns the region along `alignment` bytes instead of [ALIGNMENT].
+///
+/// # Panics
+///
+/// Panics if `alignment` is not a [valid alignment](is_valid_alignment).
+pub fn allocate_aligned_with_alignment<T: NativeType>(
+    size: usize,
+    alignment: usize,
+) -> NonNull<T> {
+    assert!(
+        is_valid_alignment(alignment),
+        "invalid alignment {}",
+        alignment
+    );
     unsafe {
         if size == 0 {
-            null_pointer()
+            null_pointer(alignment)
         } else {
             let size = size * size_of::<T>();
             ALLOCATIONS.fetch_add(size as isize, std::sync::atomic::Ordering::SeqCst);
 
-            let layout = Layout::from_size_align_unchecked(size, ALIGNMENT);
+            let layout = Layout::from_size_align_unchecked(size, alignment);
             let raw_ptr = std::alloc::alloc(layout) as *mut T;
             NonNull::new(raw_ptr).unwrap_or_else(|| handle_alloc_error(layout))
         }
@@ -61,14 +98,32 @@ pub fn allocate_aligned<T: NativeType>(size: usize) -> NonNull<T> {
 /// This is more performant than using [allocate_aligned] and setting all bytes to zero
 /// and is semantically similar to `calloc`.
 pub fn allocate_aligned_zeroed<T: NativeType>(size: usize) -> NonNull<T> {
+    allocate_aligned_zeroed_with_alignment(size, ALIGNMENT)
+}
+
+/// Like [allocate_aligned_zeroed], but aligns the region along `alignment` bytes instead
+/// of [ALIGNMENT].
+///
+/// # Panics
+///
+/// Panics if `alignment` is not a [valid alignment](is_valid_alignment).
+pub fn allocate_aligned_zeroed_with_alignment<T: NativeType>(
+    size: usize,
+    alignment: usize,
+) -> NonNull<T> {
+    assert!(
+        is_valid_alignment(alignment),
+        "invalid alignment {}",
+        alignment
+    );
     unsafe {
         if size == 0 {
-            null_pointer()
+            null_pointer(alignment)
         } else {
             let size = size * size_of::<T>();
             ALLOCATIONS.fetch_add(size as isize, std::sync::atomic::Ordering::SeqCst);
 
-            let layout = Layout::from_size_align_unchecked(size, ALIGNMENT);
+            let layout = Layout::from_size_align_unchecked(size, alignment);
             let raw_ptr = std::alloc::alloc_zeroed(layout) as *mut T;
             NonNull::new(raw_ptr).unwrap_or_else(|| handle_alloc_error(layout))
         }
@@ -84,12 +139,24 @@ pub fn allocate_aligned_zeroed<T: NativeType>(size: usize) -> NonNull<T> {
 ///
 /// * size must be the same size that was used to allocate that block of memory,
 pub unsafe fn free_aligned<T: NativeType>(ptr: NonNull<T>, size: usize) {
-    if ptr != null_pointer() {
+    free_aligned_with_alignment(ptr, size, ALIGNMENT)
+}
+
+/// # Safety
+///
+/// Same as [free_aligned], and `alignment` must be the alignment that was used to
+/// allocate that block of memory.
+pub unsafe fn free_aligned_with_alignment<T: NativeType>(
+    ptr: NonNull<T>,
+    size: usize,
+    alignment: usize,
+) {
+    if ptr != null_pointer(alignment) {
         let size = size * size_of::<T>();
         ALLOCATIONS.fetch_sub(size as isize, std::sync::atomic::Ordering::SeqCst);
         std::alloc::dealloc(
             ptr.as_ptr() as *mut u8,
-            Layout::from_size_align_unchecked(size, ALIGNMENT),
+            Layout::from_size_align_unchecked(size, alignment),
         );
     }
 }
@@ -109,16 +176,29 @@ pub unsafe fn reallocate<T: NativeType>(
     ptr: NonNull<T>,
     old_size: usize,
     new_size: usize,
+) -> NonNull<T> {
+    reallocate_with_alignment(ptr, old_size, new_size, ALIGNMENT)
+}
+
+/// # Safety
+///
+/// Same as [reallocate], and `alignment` must be the alignment that was used to
+/// allocate `ptr`. The reallocated region keeps that alignment.
+pub unsafe fn reallocate_with_alignment<T: NativeType>(
+    ptr: NonNull<T>,
+    old_size: usize,
+    new_size: usize,
+    alignment: usize,
 ) -> NonNull<T> {
     let old_size = old_size * size_of::<T>();
     let new_size = new_size * size_of::<T>();
-    if ptr == null_pointer() {
-        return allocate_aligned(new_size);
+    if ptr == null_pointer(alignment) {
+        return allocate_aligned_with_alignment(new_size, alignment);
     }
 
     if new_size == 0 {
-        free_aligned(ptr, old_size);
-        return null_pointer();
+        free_aligned_with_alignment(ptr, old_size, alignment);
+        return null_pointer(alignment);
     }
 
     ALLOCATIONS.fetch_add(
@@ -127,10 +207,10 @@ pub unsafe fn reallocate<T: NativeType>(
     );
     let raw_ptr = std::alloc::realloc(
         ptr.as_ptr() as *mut u8,
-        Layout::from_size_align_unchecked(old_size, ALIGNMENT),
+        Layout::from_size_align_unchecked(old_size, alignment),
         new_size,
     ) as *mut T;
     NonNull::new(raw_ptr).unwrap_or_else(|| {
-        handle_alloc_error(Layout::from_size_align_unchecked(new_size, ALIGNMENT))
+        handle_alloc_error(Layout::from_size_align_unchecked(new_size, alignment))
     })
 }
@@ -0,0 +1,197 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines [`MemoryPool`], an accounting hook consulted by [`MutableBuffer`](crate::buffer::MutableBuffer)
+//! whenever it allocates, grows, shrinks or frees its memory region.
+//!
+//! Buffers created without a pool are accounted for by nobody and use the global
+//! allocator directly, as before. Buffers created with
+//! [`MutableBuffer::with_capacity_in`](crate::buffer::MutableBuffer::with_capacity_in)
+//! report every change in their capacity to their pool, including after they are
+//! frozen into a [`Buffer`](crate::buffer::Buffer), until the memory is released.
+
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::error::{ArrowError, Result};
+
+/// A pool that accounts for the memory allocated by the buffers created from it.
+///
+/// The memory itself is always allocated by Arrow's aligned allocator; a pool
+/// only decides whether an allocation is allowed and keeps track of it.
+pub trait MemoryPool: Debug + Send + Sync {
+    /// Records the allocation of `size` additional bytes, returning an error if
+    /// the pool cannot accommodate them. Nothing is recorded on error.
+    fn try_grow(&self, size: usize) -> Result<()>;
+
+    /// Records that `size` bytes previously recorded by [`MemoryPool::try_grow`]
+    /// were released.
+    fn shrink(&self, size: usize);
+
+    /// Returns the number of bytes currently allocated from this pool.
+    fn used(&self) -> usize {
+        self.statistics().used
+    }
+
+    /// Returns a snapshot of the statistics of this pool.
+    fn statistics(&self) -> MemoryPoolStatistics;
+}
+
+/// A snapshot of the statistics of a [`MemoryPool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoryPoolStatistics {
+    /// The number of bytes currently allocated from the pool
+    pub used: usize,
+    /// The highest number of bytes allocated from the pool at any one time
+    pub peak: usize,
+    /// The maximum number of bytes the pool allows to be allocated, if any
+    pub limit: Option<usize>,
+    /// The number of allocations that were rejected because they would exceed the limit
+    pub rejected: usize,
+}
+
+/// A [`MemoryPool`] that tracks the number of bytes allocated from it and
+/// optionally rejects allocations above a limit.
+///
+/// # Example
+/// ```
+/// # use std::sync::Arc;
+/// # use arrow::alloc::{MemoryPool, TrackingMemoryPool};
+/// # use arrow::buffer::MutableBuffer;
+/// let pool = Arc::new(TrackingMemoryPool::with_limit(128));
+///
+/// let mut buffer = MutableBuffer::with_capacity_in(64, pool.clone()).unwrap();
+/// assert_eq!(pool.used(), 64);
+///
+/// buffer.extend_from_slice(&[0u8; 64]);
+/// assert!(buffer.try_reserve(128).is_err());
+///
+/// drop(buffer);
+/// assert_eq!(pool.used(), 0);
+/// assert_eq!(pool.statistics().peak, 64);
+/// ```
+#[derive(Debug, Default)]
+pub struct TrackingMemoryPool {
+    used: AtomicUsize,
+    peak: AtomicUsize,
+    rejected: AtomicUsize,
+    limit: Option<usize>,
+}
+
+impl TrackingMemoryPool {
+    /// Creates a new pool without a limit.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new pool that rejects allocations once `limit` bytes are in use.
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the limit of this pool, if any.
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+}
+
+impl MemoryPool for TrackingMemoryPool {
+    fn try_grow(&self, size: usize) -> Result<()> {
+        let limit = self.limit.unwrap_or(usize::MAX);
+        let result = self
+            .used
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |used| {
+                used.checked_add(size).filter(|new_used| *new_used <= limit)
+            });
+        match result {
+            Ok(used) => {
+                self.peak.fetch_max(used + size, Ordering::SeqCst);
+                Ok(())
+            }
+            Err(used) => {
+                self.rejected.fetch_add(1, Ordering::SeqCst);
+                Err(ArrowError::MemoryError(match self.limit {
+                    Some(limit) => format!(
+                        "Failed to allocate {} bytes: {} of {} bytes already in use",
+                        size, used, limit
+                    ),
+                    None => format!(
+                        "Failed to allocate {} bytes: {} bytes already in use",
+                        size, used
+                    ),
+                }))
+            }
+        }
+    }
+
+    fn shrink(&self, size: usize) {
+        let previous = self.used.fetch_sub(size, Ordering::SeqCst);
+        debug_assert!(previous >= size, "released more memory than was allocated");
+    }
+
+    fn used(&self) -> usize {
+        self.used.load(Ordering::SeqCst)
+    }
+
+    fn statistics(&self) -> MemoryPoolStatistics {
+        MemoryPoolStatistics {
+            used: self.used.load(Ordering::SeqCst),
+            peak: self.peak.load(Ordering::SeqCst),
+            limit: self.limit,
+            rejected: self.rejected.load(Ordering::SeqCst),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracking_pool_limit() {
+        let pool = TrackingMemoryPool::with_limit(100);
+        pool.try_grow(60).unwrap();
+        pool.try_grow(40).unwrap();
+        assert!(pool.try_grow(1).is_err());
+        pool.shrink(50);
+        pool.try_grow(10).unwrap();
+
+        let stats = pool.statistics();
+        assert_eq!(
+            stats,
+            MemoryPoolStatistics {
+                used: 60,
+                peak: 100,
+                limit: Some(100),
+                rejected: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn test_tracking_pool_unbounded() {
+        let pool = TrackingMemoryPool::new();
+        pool.try_grow(usize::MAX / 2).unwrap();
+        assert!(pool.try_grow(usize::MAX).is_err());
+        pool.shrink(usize::MAX / 2);
+        assert_eq!(pool.used(), 0);
+        assert_eq!(pool.statistics().limit, None);
+    }
+}
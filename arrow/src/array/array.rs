@@ -287,6 +287,7 @@ pub fn make_array(data: ArrayData) -> ArrayRef {
         DataType::LargeList(_) => Arc::new(LargeListArray::from(data)) as ArrayRef,
         DataType::Struct(_) => Arc::new(StructArray::from(data)) as ArrayRef,
         DataType::Union(_) => Arc::new(UnionArray::from(data)) as ArrayRef,
+        DataType::Map(_, _) => Arc::new(MapArray::from(data)) as ArrayRef,
         DataType::FixedSizeList(_, _) => {
             Arc::new(FixedSizeListArray::from(data)) as ArrayRef
         }
@@ -417,6 +418,9 @@ pub fn new_null_array(data_type: &DataType, length: usize) -> ArrayRef {
         DataType::LargeList(field) => {
             new_null_list_array::<i64>(data_type, field.data_type(), length)
         }
+        DataType::Map(field, _) => {
+            new_null_list_array::<i32>(data_type, field.data_type(), length)
+        }
         DataType::FixedSizeList(field, value_len) => make_array(ArrayData::new(
             data_type.clone(),
             length,
@@ -439,7 +443,7 @@ pub fn new_null_array(data_type: &DataType, length: usize) -> ArrayRef {
             vec![],
             fields
                 .iter()
-                .map(|field| ArrayData::new_empty(field.data_type()))
+                .map(|field| new_null_array(field.data_type(), length).data().clone())
                 .collect(),
         )),
         DataType::Union(_) => {
@@ -459,9 +463,15 @@ pub fn new_null_array(data_type: &DataType, length: usize) -> ArrayRef {
                 vec![new_empty_array(value.as_ref()).data().clone()],
             ))
         }
-        DataType::Decimal(_, _) => {
-            unimplemented!("Creating null Decimal array not yet supported")
-        }
+        DataType::Decimal(_, _) => make_array(ArrayData::new(
+            data_type.clone(),
+            length,
+            Some(length),
+            Some(MutableBuffer::new_null(length).into()),
+            0,
+            vec![Buffer::from(vec![0u8; 16 * length])],
+            vec![],
+        )),
     }
 }
 
@@ -646,6 +656,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_null_struct() {
+        let struct_type =
+            DataType::Struct(vec![Field::new("data", DataType::Int64, true)]);
+        let array = new_null_array(&struct_type, 9);
+
+        let a = array.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(a.len(), 9);
+        assert_eq!(a.column(0).len(), 9);
+        for i in 0..9 {
+            assert!(a.is_null(i));
+        }
+    }
+
     #[test]
     fn test_null_dictionary() {
         let values = vec![None, None, None, None, None, None, None, None, None]
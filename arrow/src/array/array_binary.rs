@@ -339,6 +339,21 @@ impl FixedSizeBinaryArray {
         }
     }
 
+    /// Returns the element at index `i` as a fixed-size array of bytes, e.g. a UUID as
+    /// `[u8; 16]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` differs from the size of the elements, or if `i` is out of bounds.
+    pub fn value_as_array<const N: usize>(&self, i: usize) -> [u8; N] {
+        self.value(i).try_into().unwrap_or_else(|_| {
+            panic!(
+                "Can't read an element of size {} as an array of size {}",
+                self.length, N
+            )
+        })
+    }
+
     /// Returns the offset for the element at index `i`.
     ///
     /// Note this doesn't do any bound checking, for performance reason.
@@ -360,11 +375,85 @@ impl FixedSizeBinaryArray {
         self.data.buffers()[0].clone()
     }
 
+    /// Create an array of elements of `size` bytes from an iterable argument of sparse byte
+    /// slices. Unlike [FixedSizeBinaryArray::try_from_sparse_iter], the argument can be
+    /// empty or contain only `None` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrow::array::{Array, FixedSizeBinaryArray};
+    /// let input_arg: Vec<Option<&[u8]>> = vec![None, None];
+    /// let array =
+    ///     FixedSizeBinaryArray::try_from_sparse_iter_with_size(input_arg.into_iter(), 16)
+    ///         .unwrap();
+    /// assert_eq!(array.value_length(), 16);
+    /// assert_eq!(array.null_count(), 2);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if `size` is negative, or the size of a nested slice is not `size`.
+    pub fn try_from_sparse_iter_with_size<T, U>(
+        mut iter: T,
+        size: i32,
+    ) -> Result<Self, ArrowError>
+    where
+        T: Iterator<Item = Option<U>>,
+        U: AsRef<[u8]>,
+    {
+        if size < 0 {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The size of the elements can't be negative, got {}",
+                size
+            )));
+        }
+        let (lower, _) = iter.size_hint();
+        let mut len = 0;
+        let mut null_buf = MutableBuffer::new(bit_util::ceil(lower, 8));
+        let mut buffer = MutableBuffer::new(lower * size as usize);
+        iter.try_for_each(|item| -> Result<(), ArrowError> {
+            // extend null bitmask by one byte per each 8 items
+            if len % 8 == 0 {
+                null_buf.push(0u8);
+            }
+            if let Some(slice) = item {
+                let slice = slice.as_ref();
+                if slice.len() != size as usize {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "Nested array size mismatch: expected {}, got {}",
+                        size,
+                        slice.len()
+                    )));
+                }
+                bit_util::set_bit(null_buf.as_slice_mut(), len);
+                buffer.extend_from_slice(slice);
+            } else {
+                buffer.extend_zeros(size as usize);
+            }
+
+            len += 1;
+
+            Ok(())
+        })?;
+
+        let array_data = ArrayData::new(
+            DataType::FixedSizeBinary(size),
+            len,
+            None,
+            Some(null_buf.into()),
+            0,
+            vec![buffer.into()],
+            vec![],
+        );
+        Ok(FixedSizeBinaryArray::from(array_data))
+    }
+
     /// Create an array from an iterable argument of sparse byte slices.
     /// Sparsity means that items returned by the iterator are optional, i.e input argument can
     /// contain `None` items.
     ///
-    /// # Examles
+    /// # Examples
     ///
     /// ```
     /// use arrow::array::FixedSizeBinaryArray;
@@ -449,7 +538,7 @@ impl FixedSizeBinaryArray {
 
     /// Create an array from an iterable argument of byte slices.
     ///
-    /// # Examles
+    /// # Examples
     ///
     /// ```
     /// use arrow::array::FixedSizeBinaryArray;
@@ -532,6 +621,25 @@ impl From<ArrayData> for FixedSizeBinaryArray {
     }
 }
 
+/// Creates a `FixedSizeBinaryArray` of elements of `N` bytes, e.g. UUIDs.
+///
+/// # Examples
+///
+/// ```
+/// use arrow::array::{Array, FixedSizeBinaryArray};
+/// let uuids = vec![Some([1u8; 16]), None, Some([2u8; 16])];
+/// let array = uuids.into_iter().collect::<FixedSizeBinaryArray>();
+/// assert_eq!(array.value_length(), 16);
+/// assert!(array.is_null(1));
+/// assert_eq!(array.value_as_array::<16>(2), [2u8; 16]);
+/// ```
+impl<const N: usize> FromIterator<Option<[u8; N]>> for FixedSizeBinaryArray {
+    fn from_iter<I: IntoIterator<Item = Option<[u8; N]>>>(iter: I) -> Self {
+        Self::try_from_sparse_iter_with_size(iter.into_iter(), N as i32)
+            .expect("all the elements have the same size")
+    }
+}
+
 /// Creates a `FixedSizeBinaryArray` from `FixedSizeList<u8>` array
 impl From<FixedSizeListArray> for FixedSizeBinaryArray {
     fn from(v: FixedSizeListArray) -> Self {
@@ -1066,6 +1174,68 @@ mod tests {
         assert_eq!(10, fixed_size_binary_array.value_offset(1));
     }
 
+    #[test]
+    fn test_fixed_size_binary_array_with_size() {
+        let array = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some(vec![1, 2, 3]), None, Some(vec![4, 5, 6])].into_iter(),
+            3,
+        )
+        .unwrap();
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.null_count(), 1);
+        assert_eq!(array.value(0), &[1, 2, 3]);
+        assert_eq!(array.value(2), &[4, 5, 6]);
+
+        let array = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            Vec::<Option<Vec<u8>>>::new().into_iter(),
+            3,
+        )
+        .unwrap();
+        assert_eq!(array.len(), 0);
+        assert_eq!(array.data_type(), &DataType::FixedSizeBinary(3));
+
+        let err = FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+            vec![Some(vec![1, 2])].into_iter(),
+            3,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("expected 3, got 2"));
+    }
+
+    #[test]
+    fn test_fixed_size_binary_array_uuids() {
+        let uuids = (0..10u8)
+            .map(|i| if i % 3 == 0 { None } else { Some([i; 16]) })
+            .collect::<Vec<_>>();
+        let array = uuids.iter().copied().collect::<FixedSizeBinaryArray>();
+
+        assert_eq!(array.len(), 10);
+        assert_eq!(array.value_length(), 16);
+        assert_eq!(array.null_count(), 4);
+        for (i, uuid) in uuids.iter().enumerate() {
+            match uuid {
+                Some(uuid) => assert_eq!(&array.value_as_array::<16>(i), uuid),
+                None => assert!(array.is_null(i)),
+            }
+        }
+
+        let sliced = array.slice(8, 2);
+        let sliced = sliced
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        assert_eq!(sliced.value_as_array::<16>(0), [8; 16]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't read an element of size 16 as an array of size 4")]
+    fn test_fixed_size_binary_array_value_as_array_size_mismatch() {
+        let array = vec![Some([0u8; 16])]
+            .into_iter()
+            .collect::<FixedSizeBinaryArray>();
+        array.value_as_array::<4>(0);
+    }
+
     #[test]
     #[should_panic(
         expected = "FixedSizeBinaryArray can only be created from list array of u8 values \
@@ -23,7 +23,7 @@ use std::{any::Any, fmt};
 
 use super::*;
 use super::{array::print_long_array, raw_pointer::RawPtrBox};
-use crate::buffer::{Buffer, MutableBuffer};
+use crate::buffer::{BooleanBuffer, Buffer, MutableBuffer, NullBuffer};
 use crate::util::bit_util;
 
 /// Array of bools
@@ -66,6 +66,29 @@ impl fmt::Debug for BooleanArray {
 }
 
 impl BooleanArray {
+    /// Creates a boolean array of `values`, null where `nulls` is unset, without copying
+    /// the bits of either when their offsets are byte-aligned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nulls` and `values` have different lengths.
+    pub fn new(values: BooleanBuffer, nulls: Option<NullBuffer>) -> Self {
+        if let Some(nulls) = &nulls {
+            assert_eq!(
+                nulls.len(),
+                values.len(),
+                "the validity bitmap of a BooleanArray must have the length of its values"
+            );
+        }
+        let data = ArrayData::builder(DataType::Boolean)
+            .len(values.len())
+            .offset(values.offset())
+            .add_buffer(values.into_inner())
+            .nulls(nulls)
+            .build();
+        Self::from(data)
+    }
+
     /// Returns the length of this array.
     pub fn len(&self) -> usize {
         self.data.len()
@@ -76,6 +99,12 @@ impl BooleanArray {
         self.data.is_empty()
     }
 
+    /// Creates a [Scalar] of `value`, to compare or combine it with every element of
+    /// an array in kernels accepting a [Datum].
+    pub fn new_scalar(value: bool) -> Scalar<Self> {
+        Scalar::new(Self::from(vec![value]))
+    }
+
     // Returns a new boolean array builder
     pub fn builder(capacity: usize) -> BooleanBuilder {
         BooleanBuilder::new(capacity)
@@ -88,6 +117,12 @@ impl BooleanArray {
         &self.data.buffers()[0]
     }
 
+    /// Returns the values of this array as a [BooleanBuffer], which, unlike
+    /// [`values`](Self::values), takes the offset of this array into account.
+    pub fn boolean_buffer(&self) -> BooleanBuffer {
+        BooleanBuffer::new(self.values().clone(), self.offset(), self.len())
+    }
+
     /// Returns the boolean value at index `i`.
     ///
     /// # Safety
@@ -145,6 +180,24 @@ impl From<Vec<bool>> for BooleanArray {
     }
 }
 
+impl From<BooleanBuffer> for BooleanArray {
+    fn from(values: BooleanBuffer) -> Self {
+        Self::new(values, None)
+    }
+}
+
+impl From<&BooleanArray> for NullBuffer {
+    /// Returns a validity bitmap that is valid where `array` is true, and null where it
+    /// is false or null. This doesn't copy the values of an array without nulls.
+    fn from(array: &BooleanArray) -> Self {
+        let values = array.boolean_buffer();
+        match array.data().nulls() {
+            Some(nulls) => NullBuffer::new(&values & nulls.inner()),
+            None => NullBuffer::new(values),
+        }
+    }
+}
+
 impl From<Vec<Option<bool>>> for BooleanArray {
     fn from(data: Vec<Option<bool>>) -> Self {
         BooleanArray::from_iter(data.iter())
@@ -224,6 +277,42 @@ mod tests {
     use crate::buffer::Buffer;
     use crate::datatypes::DataType;
 
+    #[test]
+    fn test_boolean_buffer() {
+        let array = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        let sliced = array.slice(1, 3);
+        let sliced = sliced.as_any().downcast_ref::<BooleanArray>().unwrap();
+
+        let values = sliced.boolean_buffer();
+        assert_eq!(values.offset(), 1);
+        assert_eq!(values.inner().as_ptr(), array.values().as_ptr());
+        assert_eq!(values.iter().collect::<Vec<_>>(), vec![false, false, true]);
+
+        let array = BooleanArray::new(values, sliced.data().nulls());
+        assert_eq!(&array, sliced);
+        assert_eq!(
+            BooleanArray::from(BooleanBuffer::new_with_value(true, 2)),
+            BooleanArray::from(vec![true, true])
+        );
+    }
+
+    #[test]
+    fn test_boolean_array_to_nulls() {
+        let array = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        let nulls = NullBuffer::from(&array);
+        assert_eq!(nulls.null_count(), 2);
+        assert_eq!(
+            nulls.inner().iter().collect::<Vec<_>>(),
+            vec![true, false, false, true]
+        );
+
+        // the values of an array without nulls aren't copied
+        let array = BooleanArray::from(vec![false, true, true]);
+        let nulls = NullBuffer::from(&array);
+        assert_eq!(nulls.null_count(), 1);
+        assert_eq!(nulls.inner().inner().as_ptr(), array.values().as_ptr());
+    }
+
     #[test]
     fn test_boolean_fmt_debug() {
         let arr = BooleanArray::from(vec![true, false, false]);
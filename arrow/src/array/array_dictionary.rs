@@ -22,8 +22,9 @@ use std::mem;
 use std::{convert::From, iter::FromIterator};
 
 use super::{
-    make_array, Array, ArrayData, ArrayRef, PrimitiveArray, PrimitiveBuilder,
-    StringArray, StringBuilder, StringDictionaryBuilder,
+    make_array, Array, ArrayData, ArrayRef, BinaryOffsetSizeTrait, GenericBinaryArray,
+    GenericStringArray, PrimitiveArray, PrimitiveBuilder, StringArray, StringBuilder,
+    StringDictionaryBuilder, StringOffsetSizeTrait,
 };
 use crate::datatypes::ArrowNativeType;
 use crate::datatypes::{ArrowDictionaryKeyType, ArrowPrimitiveType, DataType};
@@ -125,6 +126,173 @@ impl<'a, K: ArrowPrimitiveType> DictionaryArray<K> {
     pub fn is_ordered(&self) -> bool {
         self.is_ordered
     }
+
+    /// Returns a new dictionary with the same keys as this one over `values`, e.g. the
+    /// result of a kernel applied to the values of this dictionary. The keys are not copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is shorter than the values of this dictionary, as the keys could
+    /// then be out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow::array::{Array, DictionaryArray, StringArray};
+    /// use arrow::datatypes::Int8Type;
+    /// let array: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+    /// let upper = array.with_values(Arc::new(StringArray::from(vec!["A", "B"])));
+    /// let upper = upper.downcast_dict::<StringArray>().unwrap();
+    /// assert_eq!(upper.value(2), "A");
+    /// ```
+    pub fn with_values(&self, values: ArrayRef) -> Self {
+        assert!(
+            values.len() >= self.values.len(),
+            "The new values of a dictionary must have at least {} elements, got {}",
+            self.values.len(),
+            values.len()
+        );
+        let data = self.data_ref();
+        let data = ArrayData::new(
+            DataType::Dictionary(
+                Box::new(K::DATA_TYPE),
+                Box::new(values.data_type().clone()),
+            ),
+            data.len(),
+            Some(data.null_count()),
+            data.null_buffer().cloned(),
+            data.offset(),
+            data.buffers().to_vec(),
+            vec![values.data().clone()],
+        );
+        let mut array = Self::from(data);
+        array.is_ordered = self.is_ordered;
+        array
+    }
+
+    /// Returns a view of this dictionary with values of type `V`, or `None` if the
+    /// values of this dictionary are not a `V`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arrow::array::{DictionaryArray, StringArray};
+    /// use arrow::datatypes::Int8Type;
+    /// let array: DictionaryArray<Int8Type> = vec!["a", "b", "a"].into_iter().collect();
+    /// let typed = array.downcast_dict::<StringArray>().unwrap();
+    /// assert_eq!(typed.value(1), "b");
+    /// assert_eq!(typed.value(2), "a");
+    /// ```
+    pub fn downcast_dict<V: 'static>(&self) -> Option<TypedDictionaryArray<'_, K, V>> {
+        let values = self.values.as_any().downcast_ref::<V>()?;
+        Some(TypedDictionaryArray {
+            dictionary: self,
+            values,
+        })
+    }
+}
+
+/// A view of a [DictionaryArray] with values of the concrete array type `V`, whose
+/// `value(i)` resolves the key at index `i` to its value.
+///
+/// Created with [DictionaryArray::downcast_dict].
+pub struct TypedDictionaryArray<'a, K: ArrowPrimitiveType, V> {
+    /// The dictionary of this view
+    dictionary: &'a DictionaryArray<K>,
+    /// The values of `dictionary`, downcast to `V`
+    values: &'a V,
+}
+
+impl<'a, K: ArrowPrimitiveType, V> TypedDictionaryArray<'a, K, V> {
+    /// Returns the dictionary of this view
+    pub fn dictionary(&self) -> &'a DictionaryArray<K> {
+        self.dictionary
+    }
+
+    /// Returns the keys of the dictionary
+    pub fn keys(&self) -> &'a PrimitiveArray<K> {
+        self.dictionary.keys()
+    }
+
+    /// Returns the values of the dictionary
+    pub fn values(&self) -> &'a V {
+        self.values
+    }
+
+    /// The length of the dictionary
+    pub fn len(&self) -> usize {
+        self.dictionary.len()
+    }
+
+    /// Whether the dictionary is empty
+    pub fn is_empty(&self) -> bool {
+        self.dictionary.is_empty()
+    }
+
+    /// Returns whether the element at index `i` is null
+    pub fn is_null(&self, i: usize) -> bool {
+        self.dictionary.is_null(i)
+    }
+
+    /// Returns whether the element at index `i` is not null
+    pub fn is_valid(&self, i: usize) -> bool {
+        self.dictionary.is_valid(i)
+    }
+
+    /// Returns the index of the value of the element at index `i` in the values.
+    ///
+    /// Note this doesn't check whether the element is null.
+    #[inline]
+    fn value_index(&self, i: usize) -> usize {
+        self.dictionary.keys().value(i).to_usize().unwrap()
+    }
+}
+
+impl<'a, K: ArrowPrimitiveType, T: ArrowPrimitiveType>
+    TypedDictionaryArray<'a, K, PrimitiveArray<T>>
+{
+    /// Returns the value of the element at index `i`.
+    ///
+    /// Note this doesn't check whether the element is null.
+    pub fn value(&self, i: usize) -> T::Native {
+        self.values.value(self.value_index(i))
+    }
+}
+
+impl<'a, K: ArrowPrimitiveType, OffsetSize: StringOffsetSizeTrait>
+    TypedDictionaryArray<'a, K, GenericStringArray<OffsetSize>>
+{
+    /// Returns the value of the element at index `i`.
+    ///
+    /// Note this doesn't check whether the element is null.
+    pub fn value(&self, i: usize) -> &'a str {
+        self.values.value(self.value_index(i))
+    }
+}
+
+impl<'a, K: ArrowPrimitiveType, OffsetSize: BinaryOffsetSizeTrait>
+    TypedDictionaryArray<'a, K, GenericBinaryArray<OffsetSize>>
+{
+    /// Returns the value of the element at index `i`.
+    ///
+    /// Note this doesn't check whether the element is null.
+    pub fn value(&self, i: usize) -> &'a [u8] {
+        self.values.value(self.value_index(i))
+    }
+}
+
+impl<'a, K: ArrowPrimitiveType, V: fmt::Debug> fmt::Debug
+    for TypedDictionaryArray<'a, K, V>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "TypedDictionaryArray {{keys: {:?} values: {:?}}}",
+            self.dictionary.keys(),
+            self.values
+        )
+    }
 }
 
 /// Constructs a `DictionaryArray` from an array data reference.
@@ -176,7 +344,7 @@ impl<'a, T: ArrowPrimitiveType + ArrowDictionaryKeyType> FromIterator<Option<&'a
         let it = iter.into_iter();
         let (lower, _) = it.size_hint();
         let key_builder = PrimitiveBuilder::<T>::new(lower);
-        let value_builder = StringBuilder::new(256);
+        let value_builder = StringBuilder::default();
         let mut builder = StringDictionaryBuilder::new(key_builder, value_builder);
         it.for_each(|i| {
             if let Some(i) = i {
@@ -204,7 +372,7 @@ impl<'a, T: ArrowPrimitiveType + ArrowDictionaryKeyType> FromIterator<&'a str>
         let it = iter.into_iter();
         let (lower, _) = it.size_hint();
         let key_builder = PrimitiveBuilder::<T>::new(lower);
-        let value_builder = StringBuilder::new(256);
+        let value_builder = StringBuilder::default();
         let mut builder = StringDictionaryBuilder::new(key_builder, value_builder);
         it.for_each(|i| {
             builder
@@ -252,14 +420,16 @@ impl<T: ArrowPrimitiveType> fmt::Debug for DictionaryArray<T> {
 mod tests {
     use super::*;
 
-    use crate::{
-        array::Int16Array,
-        datatypes::{Int32Type, Int8Type, UInt32Type, UInt8Type},
-    };
+    use std::sync::Arc;
+
     use crate::{
         array::Int16DictionaryArray, array::PrimitiveDictionaryBuilder,
         datatypes::DataType,
     };
+    use crate::{
+        array::{Int16Array, Int32Array, UInt32Array},
+        datatypes::{Int32Type, Int8Type, UInt32Type, UInt8Type},
+    };
     use crate::{buffer::Buffer, datatypes::ToByteSlice};
 
     #[test]
@@ -374,6 +544,75 @@ mod tests {
         assert_eq!(array.lookup_key("non-existent"), None);
     }
 
+    #[test]
+    fn test_dictionary_downcast_dict() {
+        let test = vec![Some("a"), None, Some("b"), Some("a")];
+        let array: DictionaryArray<Int8Type> = test.into_iter().collect();
+
+        assert!(array.downcast_dict::<Int16Array>().is_none());
+        let typed = array.downcast_dict::<StringArray>().unwrap();
+        assert_eq!(4, typed.len());
+        assert_eq!("a", typed.value(0));
+        assert!(typed.is_null(1));
+        assert_eq!("b", typed.value(2));
+        assert_eq!("a", typed.value(3));
+
+        let sliced = array.slice(2, 2);
+        let sliced = sliced
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        let typed = sliced.downcast_dict::<StringArray>().unwrap();
+        assert_eq!(2, typed.len());
+        assert_eq!("b", typed.value(0));
+        assert_eq!("a", typed.value(1));
+
+        let key_builder = PrimitiveBuilder::<UInt8Type>::new(3);
+        let value_builder = PrimitiveBuilder::<UInt32Type>::new(2);
+        let mut builder = PrimitiveDictionaryBuilder::new(key_builder, value_builder);
+        builder.append(12345678).unwrap();
+        builder.append(22345678).unwrap();
+        builder.append(12345678).unwrap();
+        let array = builder.finish();
+        let typed = array.downcast_dict::<UInt32Array>().unwrap();
+        assert_eq!(12345678, typed.value(0));
+        assert_eq!(22345678, typed.value(1));
+        assert_eq!(12345678, typed.value(2));
+    }
+
+    #[test]
+    fn test_dictionary_with_values() {
+        let test = vec![Some("a"), None, Some("b"), Some("a")];
+        let array: DictionaryArray<Int8Type> = test.into_iter().collect();
+        let array = array.slice(1, 3);
+        let array = array
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+
+        let lengths = array.with_values(Arc::new(Int32Array::from(vec![1, 2])));
+        assert_eq!(
+            &DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Int32)),
+            lengths.data_type()
+        );
+        assert_eq!(array.keys(), lengths.keys());
+        assert_eq!(1, lengths.null_count());
+
+        let typed = lengths.downcast_dict::<Int32Array>().unwrap();
+        assert!(typed.is_null(0));
+        assert_eq!(2, typed.value(1));
+        assert_eq!(1, typed.value(2));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "The new values of a dictionary must have at least 2 elements, got 1"
+    )]
+    fn test_dictionary_with_values_too_short() {
+        let array: DictionaryArray<Int8Type> = vec!["a", "b"].into_iter().collect();
+        array.with_values(Arc::new(Int32Array::from(vec![1])));
+    }
+
     #[test]
     fn test_dictionary_keys_as_primitive_array() {
         let test = vec!["a", "b", "c", "a"];
@@ -26,7 +26,7 @@ use super::{
     ArrayRef, BooleanBufferBuilder, GenericListArrayIter, PrimitiveArray,
 };
 use crate::{
-    buffer::MutableBuffer,
+    buffer::{Buffer, MutableBuffer},
     datatypes::{ArrowNativeType, ArrowPrimitiveType, DataType, Field},
     error::ArrowError,
 };
@@ -188,6 +188,91 @@ impl<OffsetSize: OffsetSizeTrait> GenericListArray<OffsetSize> {
             .build();
         Self::from(data)
     }
+
+    /// Creates a [`GenericListArray`] whose list `i` contains the `values` from
+    /// `offsets[i]` to `offsets[i + 1]`, and is null where the bit `i` of `nulls` is unset.
+    ///
+    /// Returns an error when the offsets do not start at zero, decrease, exceed the length
+    /// of `values`, or when `nulls` is too short.
+    /// # Example
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use arrow::array::{Array, ArrayRef, Int32Array, ListArray};
+    /// # use arrow::buffer::Buffer;
+    /// let values: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+    /// let offsets = Buffer::from_slice_ref(&[0, 2, 2, 3]);
+    /// let nulls = Buffer::from([0b00000101]);
+    ///
+    /// let list_array = ListArray::try_new(offsets, values, Some(nulls)).unwrap();
+    ///
+    /// assert_eq!(list_array.len(), 3);
+    /// assert!(list_array.is_null(1));
+    /// assert_eq!(list_array.value_length(2), 1);
+    /// ```
+    pub fn try_new(
+        offsets: Buffer,
+        values: ArrayRef,
+        nulls: Option<Buffer>,
+    ) -> Result<Self, ArrowError> {
+        if offsets.len() % mem::size_of::<OffsetSize>() != 0
+            || offsets.as_ptr().align_offset(mem::align_of::<OffsetSize>()) != 0
+        {
+            return Err(ArrowError::InvalidArgumentError(
+                "the offsets buffer is not aligned with its offset type".to_string(),
+            ));
+        }
+        // Soundness: the alignment and length of the buffer are checked above
+        let offset_slice = unsafe { offsets.typed_data::<OffsetSize>() };
+        if offset_slice.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "the offsets of a list array must have at least one value".to_string(),
+            ));
+        }
+        if !offset_slice[0].is_zero() {
+            return Err(ArrowError::InvalidArgumentError(
+                "offsets do not start at zero".to_string(),
+            ));
+        }
+        if offset_slice.windows(2).any(|w| w[0] > w[1]) {
+            return Err(ArrowError::InvalidArgumentError(
+                "the offsets of a list array must be monotonically increasing"
+                    .to_string(),
+            ));
+        }
+        let last = offset_slice[offset_slice.len() - 1].to_usize().unwrap();
+        if last > values.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "the last offset {} exceeds the length {} of the values",
+                last,
+                values.len()
+            )));
+        }
+        let len = offset_slice.len() - 1;
+        if let Some(nulls) = &nulls {
+            if nulls.len() * 8 < len {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "the null buffer of {} bytes is too short for {} lists",
+                    nulls.len(),
+                    len
+                )));
+            }
+        }
+
+        let field = Box::new(Field::new("item", values.data_type().clone(), true));
+        let data_type = if OffsetSize::is_large() {
+            DataType::LargeList(field)
+        } else {
+            DataType::List(field)
+        };
+        let mut builder = ArrayData::builder(data_type)
+            .len(len)
+            .add_buffer(offsets)
+            .add_child_data(values.data().clone());
+        if let Some(nulls) = nulls {
+            builder = builder.null_bit_buffer(nulls);
+        }
+        Self::try_new_from_array_data(builder.build())
+    }
 }
 
 impl<OffsetSize: OffsetSizeTrait> From<ArrayData> for GenericListArray<OffsetSize> {
@@ -419,6 +504,7 @@ mod tests {
         datatypes::{Int32Type, ToByteSlice},
         util::bit_util,
     };
+    use std::sync::Arc;
 
     use super::*;
 
@@ -1003,6 +1089,51 @@ mod tests {
         ListArray::from(list_data);
     }
 
+    #[test]
+    fn test_list_array_try_new() {
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![0, 1, 2, 3, 4]));
+        let nulls = Buffer::from([0b00001011]);
+
+        let list_array = ListArray::try_new(
+            Buffer::from_slice_ref(&[0, 2, 2, 2, 5]),
+            values.clone(),
+            Some(nulls),
+        )
+        .unwrap();
+        assert_eq!(list_array.len(), 4);
+        assert_eq!(list_array.null_count(), 1);
+        assert!(list_array.is_null(2));
+        assert_eq!(list_array.value(0).as_ref(), &Int32Array::from(vec![0, 1]));
+        assert_eq!(list_array.value_length(1), 0);
+        assert_eq!(
+            list_array.value(3).as_ref(),
+            &Int32Array::from(vec![2, 3, 4])
+        );
+
+        let large_list_array =
+            LargeListArray::try_new(Buffer::from_slice_ref(&[0_i64, 5]), values, None)
+                .unwrap();
+        assert_eq!(large_list_array.len(), 1);
+        assert_eq!(large_list_array.null_count(), 0);
+    }
+
+    #[test]
+    fn test_list_array_try_new_invalid() {
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![0, 1, 2]));
+        let try_new = |offsets: &[i32], nulls: Option<Buffer>| {
+            ListArray::try_new(Buffer::from_slice_ref(&offsets), values.clone(), nulls)
+                .map(|_| ())
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert!(try_new(&[], None).contains("at least one value"));
+        assert!(try_new(&[1, 2], None).contains("offsets do not start at zero"));
+        assert!(try_new(&[0, 2, 1], None).contains("monotonically increasing"));
+        assert!(try_new(&[0, 4], None).contains("exceeds the length 3"));
+        assert!(try_new(&[0; 10], Some(Buffer::from([0xff]))).contains("too short"));
+    }
+
     #[test]
     #[should_panic(expected = "offsets do not start at zero")]
     fn test_list_array_invalid_value_offset_start() {
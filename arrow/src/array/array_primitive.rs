@@ -25,36 +25,23 @@ use std::mem;
 use chrono::{prelude::*, Duration};
 
 use super::array::print_long_array;
-use super::raw_pointer::RawPtrBox;
 use super::*;
 use crate::temporal_conversions;
 use crate::util::bit_util;
 use crate::{
-    buffer::{Buffer, MutableBuffer},
+    buffer::{Buffer, MutableBuffer, ScalarBuffer},
     util::trusted_len_unzip,
 };
 
-/// Number of seconds in a day
-const SECONDS_IN_DAY: i64 = 86_400;
-/// Number of milliseconds in a second
-const MILLISECONDS: i64 = 1_000;
-/// Number of microseconds in a second
-const MICROSECONDS: i64 = 1_000_000;
-/// Number of nanoseconds in a second
-const NANOSECONDS: i64 = 1_000_000_000;
-
 /// Array whose elements are of primitive types.
 pub struct PrimitiveArray<T: ArrowPrimitiveType> {
     /// Underlying ArrayData
     /// # Safety
     /// must have exactly one buffer, aligned to type T
     data: ArrayData,
-    /// Pointer to the value array. The lifetime of this must be <= to the value buffer
-    /// stored in `data`, so it's safe to store.
-    /// # Safety
-    /// raw_values must have a value equivalent to `data.buffers()[0].raw_data()`
-    /// raw_values must have alignment for type T::NativeType
-    raw_values: RawPtrBox<T::Native>,
+    /// The values of the array, i.e. the values of `data.buffers()[0]` from the offset
+    /// of `data`
+    values: ScalarBuffer<T::Native>,
 }
 
 impl<T: ArrowPrimitiveType> PrimitiveArray<T> {
@@ -72,15 +59,13 @@ impl<T: ArrowPrimitiveType> PrimitiveArray<T> {
     /// Returns a slice of the values of this array
     #[inline]
     pub fn values(&self) -> &[T::Native] {
-        // Soundness
-        //     raw_values alignment & location is ensured by fn from(ArrayDataRef)
-        //     buffer bounds/offset is ensured by the ArrayData instance.
-        unsafe {
-            std::slice::from_raw_parts(
-                self.raw_values.as_ptr().add(self.data.offset()),
-                self.len(),
-            )
-        }
+        &self.values
+    }
+
+    /// Returns the values of this array as a [ScalarBuffer], which shares the buffer of
+    /// this array.
+    pub fn scalar_buffer(&self) -> &ScalarBuffer<T::Native> {
+        &self.values
     }
 
     // Returns a new primitive array builder
@@ -95,8 +80,7 @@ impl<T: ArrowPrimitiveType> PrimitiveArray<T> {
     /// caller must ensure that the passed in offset is less than the array len()
     #[inline]
     pub unsafe fn value_unchecked(&self, i: usize) -> T::Native {
-        let offset = i + self.offset();
-        *self.raw_values.as_ptr().add(offset)
+        *self.values.get_unchecked(i)
     }
 
     /// Returns the primitive value at index `i`.
@@ -158,27 +142,20 @@ impl<T: ArrowPrimitiveType> Array for PrimitiveArray<T> {
 
     /// Returns the total number of bytes of memory occupied physically by this [PrimitiveArray].
     fn get_array_memory_size(&self) -> usize {
-        self.data.get_array_memory_size() + mem::size_of::<RawPtrBox<T::Native>>()
+        self.data.get_array_memory_size() + mem::size_of::<ScalarBuffer<T::Native>>()
     }
 }
 
+/// Converts `v` to a [`NaiveDateTime`] if `T` is a date or timestamp type, returning `None`
+/// for other types or if `v` is out of range.
 fn as_datetime<T: ArrowPrimitiveType>(v: i64) -> Option<NaiveDateTime> {
     match T::DATA_TYPE {
-        DataType::Date32 => Some(temporal_conversions::date32_to_datetime(v as i32)),
-        DataType::Date64 => Some(temporal_conversions::date64_to_datetime(v)),
+        DataType::Date32 => temporal_conversions::date32_to_datetime_opt(v as i32),
+        DataType::Date64 => temporal_conversions::date64_to_datetime_opt(v),
         DataType::Time32(_) | DataType::Time64(_) => None,
-        DataType::Timestamp(unit, _) => match unit {
-            TimeUnit::Second => Some(temporal_conversions::timestamp_s_to_datetime(v)),
-            TimeUnit::Millisecond => {
-                Some(temporal_conversions::timestamp_ms_to_datetime(v))
-            }
-            TimeUnit::Microsecond => {
-                Some(temporal_conversions::timestamp_us_to_datetime(v))
-            }
-            TimeUnit::Nanosecond => {
-                Some(temporal_conversions::timestamp_ns_to_datetime(v))
-            }
-        },
+        DataType::Timestamp(unit, _) => {
+            temporal_conversions::timestamp_to_datetime_opt(v, &unit)
+        }
         // interval is not yet fully documented [ARROW-3097]
         DataType::Interval(_) => None,
         _ => None,
@@ -189,22 +166,18 @@ fn as_date<T: ArrowPrimitiveType>(v: i64) -> Option<NaiveDate> {
     as_datetime::<T>(v).map(|datetime| datetime.date())
 }
 
+/// Converts `v` to a [`NaiveTime`] if `T` is a temporal type, returning `None` for other
+/// types or if `v` is out of range, e.g. a negative time.
 fn as_time<T: ArrowPrimitiveType>(v: i64) -> Option<NaiveTime> {
     match T::DATA_TYPE {
-        DataType::Time32(unit) => {
-            // safe to immediately cast to u32 as `self.value(i)` is positive i32
-            let v = v as u32;
-            match unit {
-                TimeUnit::Second => Some(temporal_conversions::time32s_to_time(v as i32)),
-                TimeUnit::Millisecond => {
-                    Some(temporal_conversions::time32ms_to_time(v as i32))
-                }
-                _ => None,
-            }
-        }
+        DataType::Time32(unit) => match unit {
+            TimeUnit::Second => temporal_conversions::time32s_to_time_opt(v as i32),
+            TimeUnit::Millisecond => temporal_conversions::time32ms_to_time_opt(v as i32),
+            _ => None,
+        },
         DataType::Time64(unit) => match unit {
-            TimeUnit::Microsecond => Some(temporal_conversions::time64us_to_time(v)),
-            TimeUnit::Nanosecond => Some(temporal_conversions::time64ns_to_time(v)),
+            TimeUnit::Microsecond => temporal_conversions::time64us_to_time_opt(v),
+            TimeUnit::Nanosecond => temporal_conversions::time64ns_to_time_opt(v),
             _ => None,
         },
         DataType::Timestamp(_, _) => as_datetime::<T>(v).map(|datetime| datetime.time()),
@@ -217,7 +190,7 @@ fn as_time<T: ArrowPrimitiveType>(v: i64) -> Option<NaiveTime> {
 fn as_duration<T: ArrowPrimitiveType>(v: i64) -> Option<Duration> {
     match T::DATA_TYPE {
         DataType::Duration(unit) => match unit {
-            TimeUnit::Second => Some(temporal_conversions::duration_s_to_duration(v)),
+            TimeUnit::Second => temporal_conversions::duration_s_to_duration_opt(v),
             TimeUnit::Millisecond => {
                 Some(temporal_conversions::duration_ms_to_duration(v))
             }
@@ -238,7 +211,8 @@ where
 {
     /// Returns value as a chrono `NaiveDateTime`, handling time resolution
     ///
-    /// If a data type cannot be converted to `NaiveDateTime`, a `None` is returned.
+    /// If a data type cannot be converted to `NaiveDateTime`, or the value is out of the
+    /// range of `NaiveDateTime`, a `None` is returned.
     /// A valid value is expected, thus the user should first check for validity.
     pub fn value_as_datetime(&self, i: usize) -> Option<NaiveDateTime> {
         as_datetime::<T>(i64::from(self.value(i)))
@@ -253,7 +227,8 @@ where
 
     /// Returns a value as a chrono `NaiveTime`
     ///
-    /// `Date32` and `Date64` return UTC midnight as they do not have time resolution
+    /// `Date32` and `Date64` return UTC midnight as they do not have time resolution.
+    /// Times that are negative or not less than a day return `None`.
     pub fn value_as_time(&self, i: usize) -> Option<NaiveTime> {
         as_time::<T>(i64::from(self.value(i)))
     }
@@ -373,6 +348,36 @@ impl<T: ArrowPrimitiveType> PrimitiveArray<T> {
     }
 }
 
+impl<T: ArrowPrimitiveType> PrimitiveArray<T> {
+    /// Creates a [`PrimitiveArray`] without nulls from the memory of `values`, without
+    /// copying it. Unlike `PrimitiveArray::from`, the values are only aligned along
+    /// `T::Native`, and [`Buffer::into_vec`] can convert them back into a `Vec`.
+    /// # Example
+    /// ```
+    /// use arrow::array::{Array, Int32Array};
+    /// let array = Int32Array::from_owned_vec(vec![1, 2, 3]);
+    /// assert_eq!(array.values(), &[1, 2, 3]);
+    /// ```
+    pub fn from_owned_vec(values: Vec<T::Native>) -> Self {
+        let data = ArrayData::new(
+            T::DATA_TYPE,
+            values.len(),
+            Some(0),
+            None,
+            0,
+            vec![Buffer::from_vec(values)],
+            vec![],
+        );
+        PrimitiveArray::from(data)
+    }
+
+    /// Creates a [`Scalar`] of `value`, to compare or combine it with every element of
+    /// an array in kernels accepting a [`Datum`].
+    pub fn new_scalar(value: T::Native) -> Scalar<Self> {
+        Scalar::new(Self::from_iter(std::iter::once(Some(value))))
+    }
+}
+
 // TODO: the macro is needed here because we'd get "conflicting implementations" error
 // otherwise with both `From<Vec<T::Native>>` and `From<Vec<Option<T::Native>>>`.
 // We should revisit this in future.
@@ -478,11 +483,9 @@ impl<T: ArrowPrimitiveType> From<ArrayData> for PrimitiveArray<T> {
             "PrimitiveArray data should contain a single buffer only (values buffer)"
         );
 
-        let ptr = data.buffers()[0].as_ptr();
-        Self {
-            data,
-            raw_values: unsafe { RawPtrBox::new(ptr) },
-        }
+        let values =
+            ScalarBuffer::new(data.buffers()[0].clone(), data.offset(), data.len());
+        Self { data, values }
     }
 }
 
@@ -510,7 +513,21 @@ mod tests {
         }
 
         assert_eq!(64, arr.get_buffer_memory_size());
-        assert_eq!(136, arr.get_array_memory_size());
+        assert_eq!(152, arr.get_array_memory_size());
+    }
+
+    #[test]
+    fn test_primitive_array_from_owned_vec() {
+        let values = vec![0, 1, 2, 3, 4];
+        let ptr = values.as_ptr();
+        let arr = Int32Array::from_owned_vec(values);
+        assert_eq!(ptr, arr.values().as_ptr());
+        assert_eq!(arr, Int32Array::from(vec![0, 1, 2, 3, 4]));
+        assert_eq!(0, arr.null_count());
+
+        let buffer = arr.data().buffers()[0].clone();
+        drop(arr);
+        assert_eq!(vec![0, 1, 2, 3, 4], buffer.into_vec::<i32>().unwrap());
     }
 
     #[test]
@@ -532,7 +549,7 @@ mod tests {
         }
 
         assert_eq!(128, arr.get_buffer_memory_size());
-        assert_eq!(216, arr.get_array_memory_size());
+        assert_eq!(232, arr.get_array_memory_size());
     }
 
     #[test]
@@ -864,17 +881,37 @@ mod tests {
     }
 
     #[test]
-    #[should_panic(expected = "invalid time")]
     fn test_time32second_invalid_neg() {
-        // The panic should come from chrono, not from arrow
         let arr: PrimitiveArray<Time32SecondType> = vec![-7201, -60054].into();
-        println!("{:?}", arr);
+        assert_eq!(
+            "PrimitiveArray<Time32(Second)>\n[\n  null,\n  null,\n]",
+            format!("{:?}", arr)
+        );
+        assert_eq!(None, arr.value_as_time(0));
+    }
+
+    #[test]
+    fn test_temporal_values_out_of_range() {
+        let arr = TimestampSecondArray::from(vec![i64::MAX, 0]);
+        assert_eq!(None, arr.value_as_datetime(0));
+        assert_eq!(None, arr.value_as_date(0));
+        assert_eq!(None, arr.value_as_time(0));
+        assert_eq!(Some(0), arr.value_as_datetime(1).map(|v| v.timestamp()));
+
+        let arr = Time32SecondArray::from(vec![100_000]);
+        assert_eq!(None, arr.value_as_time(0));
+        let arr = Time64NanosecondArray::from(vec![-1]);
+        assert_eq!(None, arr.value_as_time(0));
+        let arr = Date32Array::from(vec![i32::MAX]);
+        assert_eq!(None, arr.value_as_date(0));
+        let arr = DurationSecondArray::from(vec![i64::MAX]);
+        assert_eq!(None, arr.value_as_duration(0));
     }
 
     #[test]
     fn test_primitive_array_builder() {
         // Test building a primitive array with ArrayData builder and offset
-        let buf = Buffer::from_slice_ref(&[0, 1, 2, 3, 4]);
+        let buf = Buffer::from_slice_ref(&[0, 1, 2, 3, 4, 5, 6]);
         let buf2 = buf.clone();
         let data = ArrayData::builder(DataType::Int32)
             .len(5)
@@ -885,7 +922,7 @@ mod tests {
         assert_eq!(buf2, arr.data.buffers()[0]);
         assert_eq!(5, arr.len());
         assert_eq!(0, arr.null_count());
-        for i in 0..3 {
+        for i in 0..5 {
             assert_eq!((i + 2) as i32, arr.value(i));
         }
     }
@@ -22,7 +22,7 @@ use std::{any::Any, iter::FromIterator};
 
 use super::{
     array::print_long_array, raw_pointer::RawPtrBox, Array, ArrayData, GenericListArray,
-    GenericStringIter, OffsetSizeTrait,
+    GenericStringIter, OffsetSizeTrait, Scalar,
 };
 use crate::buffer::Buffer;
 use crate::util::bit_util;
@@ -175,6 +175,12 @@ impl<OffsetSize: StringOffsetSizeTrait> GenericStringArray<OffsetSize> {
         v.into_iter().collect()
     }
 
+    /// Creates a [Scalar] of `value`, to compare or combine it with every element of
+    /// an array in kernels accepting a [Datum](super::Datum).
+    pub fn new_scalar<S: AsRef<str>>(value: S) -> Scalar<Self> {
+        Scalar::new(Self::from_iter_values(std::iter::once(value)))
+    }
+
     /// Creates a `GenericStringArray` based on an iterator of values without nulls
     pub fn from_iter_values<Ptr, I: IntoIterator<Item = Ptr>>(iter: I) -> Self
     where
@@ -429,7 +435,7 @@ mod tests {
 
     #[test]
     fn test_nested_string_array() {
-        let string_builder = StringBuilder::new(3);
+        let string_builder = StringBuilder::default();
         let mut list_of_string_builder = ListBuilder::new(string_builder);
 
         list_of_string_builder.values().append_value("foo").unwrap();
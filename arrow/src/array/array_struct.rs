@@ -24,8 +24,9 @@ use std::mem;
 use super::{make_array, Array, ArrayData, ArrayRef};
 use crate::datatypes::DataType;
 use crate::error::{ArrowError, Result};
+use crate::util::bit_util;
 use crate::{
-    buffer::{buffer_bin_or, Buffer},
+    buffer::{buffer_bin_or, Buffer, MutableBuffer},
     datatypes::Field,
 };
 
@@ -79,6 +80,47 @@ impl StructArray {
             .position(|c| c == &column_name)
             .map(|pos| self.column(pos))
     }
+
+    /// Returns the fields of this struct array with the nulls of the struct merged into
+    /// the nulls of each field, so that they can be used independently of the struct.
+    ///
+    /// The values of the fields are not copied.
+    pub fn flatten(&self) -> Vec<ArrayRef> {
+        (0..self.num_columns())
+            .map(|pos| self.flatten_column(pos))
+            .collect()
+    }
+
+    /// Returns the field at `pos` with the nulls of the struct merged into its nulls, like
+    /// [StructArray::flatten] does for all the fields.
+    pub fn flatten_column(&self, pos: usize) -> ArrayRef {
+        let field = &self.boxed_fields[pos];
+        if self.data.null_count() == 0 {
+            return field.clone();
+        }
+        let data = field.data_ref();
+        // the bitmap keeps the offset of the field, as its buffers are not copied
+        let offset = data.offset();
+        let mut nulls = MutableBuffer::new_null(offset + data.len());
+        let null_slice = nulls.as_slice_mut();
+        let mut null_count = 0;
+        (0..data.len()).for_each(|i| {
+            if self.is_valid(i) && data.is_valid(i) {
+                bit_util::set_bit(null_slice, offset + i);
+            } else {
+                null_count += 1;
+            }
+        });
+        make_array(ArrayData::new(
+            data.data_type().clone(),
+            data.len(),
+            Some(null_count),
+            Some(nulls.into()),
+            offset,
+            data.buffers().to_vec(),
+            data.child_data().to_vec(),
+        ))
+    }
 }
 
 impl From<ArrayData> for StructArray {
@@ -512,6 +554,53 @@ mod tests {
         assert!(sliced_c1.is_null(2));
     }
 
+    #[test]
+    fn test_struct_array_flatten() {
+        let strings: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("joe"),
+            None,
+            Some("mark"),
+            Some("doe"),
+        ]));
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
+        let struct_array = StructArray::from((
+            vec![
+                (Field::new("f1", DataType::Utf8, true), strings.clone()),
+                (Field::new("f2", DataType::Int32, false), ints.clone()),
+            ],
+            Buffer::from(&[0b00001011]),
+        ));
+
+        let fields = struct_array.flatten();
+        assert_eq!(
+            fields[0].as_ref(),
+            &StringArray::from(vec![Some("joe"), None, None, Some("doe")])
+        );
+        assert_eq!(
+            fields[1].as_ref(),
+            &Int32Array::from(vec![Some(1), Some(2), None, Some(4)])
+        );
+
+        let sliced = struct_array.slice(1, 3);
+        let sliced = sliced.as_any().downcast_ref::<StructArray>().unwrap();
+        let fields = sliced.flatten();
+        assert_eq!(
+            fields[0].as_ref(),
+            &StringArray::from(vec![None, None, Some("doe")])
+        );
+        assert_eq!(
+            fields[1].as_ref(),
+            &Int32Array::from(vec![Some(2), None, Some(4)])
+        );
+
+        // without nulls in the struct, the fields are returned as they are
+        let struct_array =
+            StructArray::try_from(vec![("f1", strings), ("f2", ints)]).unwrap();
+        let fields = struct_array.flatten();
+        assert_eq!(fields[0].null_count(), 1);
+        assert_eq!(fields[1].null_count(), 0);
+    }
+
     #[test]
     #[should_panic(
         expected = "all child arrays of a StructArray must have the same length"
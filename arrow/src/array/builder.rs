@@ -281,6 +281,47 @@ impl<T: ArrowNativeType> BufferBuilder<T> {
         self.len = 0;
         buf.into()
     }
+
+    /// Shrinks the capacity of the internal buffer as much as possible,
+    /// releasing the memory reserved for elements that were never appended.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use arrow::array::UInt8BufferBuilder;
+    ///
+    /// let mut builder = UInt8BufferBuilder::new(1000);
+    /// builder.append_slice(&[42, 44, 46]);
+    /// builder.shrink_to_fit();
+    ///
+    /// assert_eq!(builder.capacity(), 64);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.buffer.shrink_to_fit();
+    }
+
+    /// Like [`BufferBuilder::finish`], but shrinks the capacity of the returned
+    /// [`Buffer`](crate::buffer::Buffer) to fit its contents. This is useful for buffers
+    /// that are kept around, as they otherwise keep the excess capacity of the builder
+    /// allocated.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use arrow::array::UInt8BufferBuilder;
+    ///
+    /// let mut builder = UInt8BufferBuilder::new(1000);
+    /// builder.append_slice(&[42, 44, 46]);
+    ///
+    /// let buffer = builder.finish_shrunk();
+    ///
+    /// assert_eq!(buffer.len(), 3);
+    /// assert_eq!(buffer.capacity(), 64);
+    /// ```
+    pub fn finish_shrunk(&mut self) -> Buffer {
+        self.shrink_to_fit();
+        self.finish()
+    }
 }
 
 #[derive(Debug)]
@@ -360,11 +401,7 @@ impl BooleanBufferBuilder {
         self.advance(additional);
 
         let offset = self.len() - additional;
-        for (i, v) in slice.iter().enumerate() {
-            if *v {
-                unsafe { bit_util::set_bit_raw(self.buffer.as_mut_ptr(), offset + i) }
-            }
-        }
+        bit_util::pack_bools(self.buffer.as_slice_mut(), offset, slice);
     }
 
     #[inline]
@@ -373,6 +410,20 @@ impl BooleanBufferBuilder {
         self.len = 0;
         buf.into()
     }
+
+    /// Shrinks the capacity of the internal buffer as much as possible,
+    /// discarding the zeroed bytes past the last appended bit.
+    pub fn shrink_to_fit(&mut self) {
+        self.buffer.resize(bit_util::ceil(self.len, 8), 0);
+        self.buffer.shrink_to_fit();
+    }
+
+    /// Like [`BooleanBufferBuilder::finish`], but shrinks the capacity of the
+    /// returned [`Buffer`] to fit its contents.
+    pub fn finish_shrunk(&mut self) -> Buffer {
+        self.shrink_to_fit();
+        self.finish()
+    }
 }
 
 impl From<BooleanBufferBuilder> for Buffer {
@@ -419,7 +470,8 @@ pub struct BooleanBuilder {
 }
 
 impl BooleanBuilder {
-    /// Creates a new primitive array builder
+    /// Creates a new boolean array builder, `capacity` is the number of values to
+    /// pre-allocate space for in this builder
     pub fn new(capacity: usize) -> Self {
         Self {
             values_builder: BooleanBufferBuilder::new(capacity),
@@ -427,6 +479,12 @@ impl BooleanBuilder {
         }
     }
 
+    /// Creates a new boolean array builder, `capacity` is the number of values to
+    /// pre-allocate space for in this builder, like [BooleanBuilder::new]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::new(capacity)
+    }
+
     /// Returns the capacity of this builder measured in slots of type `T`
     pub fn capacity(&self) -> usize {
         self.values_builder.capacity()
@@ -495,6 +553,12 @@ impl BooleanBuilder {
     }
 }
 
+impl Default for BooleanBuilder {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
 impl ArrayBuilder for BooleanBuilder {
     /// Returns the builder as a non-mutable `Any` reference.
     fn as_any(&self) -> &Any {
@@ -536,6 +600,12 @@ pub struct PrimitiveBuilder<T: ArrowPrimitiveType> {
     bitmap_builder: Option<BooleanBufferBuilder>,
 }
 
+impl<T: ArrowPrimitiveType> Default for PrimitiveBuilder<T> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
 impl<T: ArrowPrimitiveType> ArrayBuilder for PrimitiveBuilder<T> {
     /// Returns the builder as a non-mutable `Any` reference.
     fn as_any(&self) -> &Any {
@@ -569,7 +639,8 @@ impl<T: ArrowPrimitiveType> ArrayBuilder for PrimitiveBuilder<T> {
 }
 
 impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
-    /// Creates a new primitive array builder
+    /// Creates a new primitive array builder, `capacity` is the number of values to
+    /// pre-allocate space for in this builder
     pub fn new(capacity: usize) -> Self {
         Self {
             values_builder: BufferBuilder::<T::Native>::new(capacity),
@@ -577,6 +648,12 @@ impl<T: ArrowPrimitiveType> PrimitiveBuilder<T> {
         }
     }
 
+    /// Creates a new primitive array builder, `capacity` is the number of values to
+    /// pre-allocate space for in this builder, like [PrimitiveBuilder::new]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::new(capacity)
+    }
+
     /// Returns the capacity of this builder measured in slots of type `T`
     pub fn capacity(&self) -> usize {
         self.values_builder.capacity()
@@ -727,6 +804,14 @@ impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> GenericListBuilder<OffsetSize
     }
 }
 
+impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder + Default> Default
+    for GenericListBuilder<OffsetSize, T>
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
 impl<OffsetSize: OffsetSizeTrait, T: ArrayBuilder> ArrayBuilder
     for GenericListBuilder<OffsetSize, T>
 where
@@ -821,6 +906,73 @@ where
     }
 }
 
+impl<OffsetSize: OffsetSizeTrait, T: ArrowPrimitiveType>
+    GenericListBuilder<OffsetSize, PrimitiveBuilder<T>>
+{
+    /// Appends a list of `values`, `None` for a null value, and finishes its slot.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arrow::array::{Array, Int32Builder, ListBuilder};
+    ///
+    /// let mut builder = ListBuilder::new(Int32Builder::new(4));
+    /// builder.append_value(vec![Some(1), None, Some(3)]).unwrap();
+    /// builder.append(false).unwrap();
+    /// builder.append_value(vec![]).unwrap();
+    /// let list = builder.finish();
+    ///
+    /// assert_eq!(list.value_length(0), 3);
+    /// assert!(list.is_null(1));
+    /// assert_eq!(list.value_length(2), 0);
+    /// ```
+    #[inline]
+    pub fn append_value<I>(&mut self, values: I) -> Result<()>
+    where
+        I: IntoIterator<Item = Option<T::Native>>,
+    {
+        for value in values {
+            self.values_builder.append_option(value)?;
+        }
+        self.append(true)
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait, StringOffsetSize: StringOffsetSizeTrait>
+    GenericListBuilder<OffsetSize, GenericStringBuilder<StringOffsetSize>>
+{
+    /// Appends a list of the strings `values`, `None` for a null string, and finishes its
+    /// slot.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arrow::array::{Array, ListBuilder, StringArray, StringBuilder};
+    ///
+    /// let mut builder = ListBuilder::new(StringBuilder::default());
+    /// builder.append_value(vec![Some("a"), None]).unwrap();
+    /// let list = builder.finish();
+    ///
+    /// let values = list.value(0);
+    /// let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+    /// assert_eq!(values, &StringArray::from(vec![Some("a"), None]));
+    /// ```
+    #[inline]
+    pub fn append_value<V, I>(&mut self, values: I) -> Result<()>
+    where
+        V: AsRef<str>,
+        I: IntoIterator<Item = Option<V>>,
+    {
+        for value in values {
+            match value {
+                Some(value) => self.values_builder.append_value(value)?,
+                None => self.values_builder.append_null()?,
+            }
+        }
+        self.append(true)
+    }
+}
+
 pub type ListBuilder<T> = GenericListBuilder<i32, T>;
 pub type LargeListBuilder<T> = GenericListBuilder<i64, T>;
 
@@ -980,6 +1132,18 @@ pub struct DecimalBuilder {
     scale: usize,
 }
 
+impl<OffsetSize: BinaryOffsetSizeTrait> Default for GenericBinaryBuilder<OffsetSize> {
+    fn default() -> Self {
+        Self::with_capacity(0, 0)
+    }
+}
+
+impl<OffsetSize: StringOffsetSizeTrait> Default for GenericStringBuilder<OffsetSize> {
+    fn default() -> Self {
+        Self::with_capacity(0, 0)
+    }
+}
+
 impl<OffsetSize: BinaryOffsetSizeTrait> ArrayBuilder
     for GenericBinaryBuilder<OffsetSize>
 {
@@ -1116,10 +1280,21 @@ impl ArrayBuilder for DecimalBuilder {
 impl<OffsetSize: BinaryOffsetSizeTrait> GenericBinaryBuilder<OffsetSize> {
     /// Creates a new `GenericBinaryBuilder`, `capacity` is the number of bytes in the values
     /// array
+    #[deprecated(
+        since = "5.0.0",
+        note = "`capacity` is a number of bytes rather than of values, use `with_capacity` or `default` instead"
+    )]
     pub fn new(capacity: usize) -> Self {
-        let values_builder = UInt8Builder::new(capacity);
+        Self::with_capacity(0, capacity)
+    }
+
+    /// Creates a new `GenericBinaryBuilder`,
+    /// `item_capacity` is the number of values to pre-allocate space for in this builder
+    /// `data_capacity` is the number of bytes of the values to pre-allocate space for in this builder
+    pub fn with_capacity(item_capacity: usize, data_capacity: usize) -> Self {
+        let values_builder = UInt8Builder::new(data_capacity);
         Self {
-            builder: GenericListBuilder::new(values_builder),
+            builder: GenericListBuilder::with_capacity(values_builder, item_capacity),
         }
     }
 
@@ -1165,11 +1340,12 @@ impl<OffsetSize: BinaryOffsetSizeTrait> GenericBinaryBuilder<OffsetSize> {
 impl<OffsetSize: StringOffsetSizeTrait> GenericStringBuilder<OffsetSize> {
     /// Creates a new `StringBuilder`,
     /// `capacity` is the number of bytes of string data to pre-allocate space for in this builder
+    #[deprecated(
+        since = "5.0.0",
+        note = "`capacity` is a number of bytes rather than of values, use `with_capacity` or `default` instead"
+    )]
     pub fn new(capacity: usize) -> Self {
-        let values_builder = UInt8Builder::new(capacity);
-        Self {
-            builder: GenericListBuilder::new(values_builder),
-        }
+        Self::with_capacity(0, capacity)
     }
 
     /// Creates a new `StringBuilder`,
@@ -1214,8 +1390,12 @@ impl<OffsetSize: StringOffsetSizeTrait> GenericStringBuilder<OffsetSize> {
 }
 
 impl FixedSizeBinaryBuilder {
-    /// Creates a new `BinaryBuilder`, `capacity` is the number of bytes in the values
-    /// array
+    /// Creates a new `FixedSizeBinaryBuilder`, `capacity` is the number of bytes in the
+    /// values array
+    #[deprecated(
+        since = "5.0.0",
+        note = "`capacity` is a number of bytes rather than of values, use `with_capacity` instead"
+    )]
     pub fn new(capacity: usize, byte_width: i32) -> Self {
         let values_builder = UInt8Builder::new(capacity);
         Self {
@@ -1223,6 +1403,19 @@ impl FixedSizeBinaryBuilder {
         }
     }
 
+    /// Creates a new `FixedSizeBinaryBuilder` of values of `byte_width` bytes,
+    /// `item_capacity` is the number of values to pre-allocate space for in this builder
+    pub fn with_capacity(item_capacity: usize, byte_width: i32) -> Self {
+        let values_builder = UInt8Builder::new(item_capacity * byte_width as usize);
+        Self {
+            builder: FixedSizeListBuilder::with_capacity(
+                values_builder,
+                byte_width,
+                item_capacity,
+            ),
+        }
+    }
+
     /// Appends a byte slice into the builder.
     ///
     /// Automatically calls the `append` method to delimit the slice appended in as a
@@ -1253,8 +1446,12 @@ impl FixedSizeBinaryBuilder {
 }
 
 impl DecimalBuilder {
-    /// Creates a new `BinaryBuilder`, `capacity` is the number of bytes in the values
+    /// Creates a new `DecimalBuilder`, `capacity` is the number of bytes in the values
     /// array
+    #[deprecated(
+        since = "5.0.0",
+        note = "`capacity` is a number of bytes rather than of values, use `with_capacity` instead"
+    )]
     pub fn new(capacity: usize, precision: usize, scale: usize) -> Self {
         let values_builder = UInt8Builder::new(capacity);
         let byte_width = 16;
@@ -1265,6 +1462,22 @@ impl DecimalBuilder {
         }
     }
 
+    /// Creates a new `DecimalBuilder` of decimals of `precision` and `scale`,
+    /// `item_capacity` is the number of values to pre-allocate space for in this builder
+    pub fn with_capacity(item_capacity: usize, precision: usize, scale: usize) -> Self {
+        let byte_width = 16;
+        let values_builder = UInt8Builder::new(item_capacity * byte_width as usize);
+        Self {
+            builder: FixedSizeListBuilder::with_capacity(
+                values_builder,
+                byte_width,
+                item_capacity,
+            ),
+            precision,
+            scale,
+        }
+    }
+
     /// Appends a byte slice into the builder.
     ///
     /// Automatically calls the `append` method to delimit the slice appended in as a
@@ -1317,8 +1530,10 @@ impl DecimalBuilder {
 
 /// Array builder for Struct types.
 ///
-/// Note that callers should make sure that methods of all the child field builders are
-/// properly called to maintain the consistency of the data structure.
+/// The values of a slot are appended to each of the child field builders before the slot is
+/// appended to the struct with [StructBuilder::append], which returns an error if a child
+/// field builder has fewer values than the struct has slots. [StructBuilder::try_finish]
+/// returns an error if they don't all have as many values as the struct.
 pub struct StructBuilder {
     fields: Vec<Field>,
     field_builders: Vec<Box<ArrayBuilder>>,
@@ -1339,9 +1554,8 @@ impl fmt::Debug for StructBuilder {
 impl ArrayBuilder for StructBuilder {
     /// Returns the number of array slots in the builder.
     ///
-    /// Note that this always return the first child field builder's length, and it is
-    /// the caller's responsibility to maintain the consistency that all the child field
-    /// builder should have the equal number of elements.
+    /// This is the number of slots appended to the struct, which the child field builders
+    /// must all have when the array is built.
     fn len(&self) -> usize {
         self.len
     }
@@ -1397,14 +1611,14 @@ pub fn make_builder(datatype: &DataType, capacity: usize) -> Box<ArrayBuilder> {
         DataType::UInt64 => Box::new(UInt64Builder::new(capacity)),
         DataType::Float32 => Box::new(Float32Builder::new(capacity)),
         DataType::Float64 => Box::new(Float64Builder::new(capacity)),
-        DataType::Binary => Box::new(BinaryBuilder::new(capacity)),
+        DataType::Binary => Box::new(BinaryBuilder::with_capacity(capacity, capacity)),
         DataType::FixedSizeBinary(len) => {
-            Box::new(FixedSizeBinaryBuilder::new(capacity, *len))
+            Box::new(FixedSizeBinaryBuilder::with_capacity(capacity, *len))
         }
         DataType::Decimal(precision, scale) => {
-            Box::new(DecimalBuilder::new(capacity, *precision, *scale))
+            Box::new(DecimalBuilder::with_capacity(capacity, *precision, *scale))
         }
-        DataType::Utf8 => Box::new(StringBuilder::new(capacity)),
+        DataType::Utf8 => Box::new(StringBuilder::with_capacity(capacity, capacity)),
         DataType::Date32 => Box::new(Date32Builder::new(capacity)),
         DataType::Date64 => Box::new(Date64Builder::new(capacity)),
         DataType::Time32(TimeUnit::Second) => {
@@ -1487,9 +1701,14 @@ impl StructBuilder {
     }
 
     /// Appends an element (either null or non-null) to the struct. The actual elements
-    /// should be appended for each child sub-array in a consistent way.
+    /// should be appended for each child sub-array in a consistent way, before this is
+    /// called.
+    ///
+    /// Returns an error, without appending the element, if a child field builder has fewer
+    /// values than the struct would have slots.
     #[inline]
     pub fn append(&mut self, is_valid: bool) -> Result<()> {
+        self.validate_field_lengths(self.len + 1, |len, expected| len >= expected)?;
         self.bitmap_builder.append(is_valid);
         self.len += 1;
         Ok(())
@@ -1502,7 +1721,24 @@ impl StructBuilder {
     }
 
     /// Builds the `StructArray` and reset this builder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the child field builders don't all have as many values as the struct has
+    /// slots, see [StructBuilder::try_finish].
     pub fn finish(&mut self) -> StructArray {
+        match self.try_finish() {
+            Ok(array) => array,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Builds the `StructArray` and reset this builder, or returns an error, leaving this
+    /// builder unchanged, if the child field builders don't all have as many values as the
+    /// struct has slots.
+    pub fn try_finish(&mut self) -> Result<StructArray> {
+        self.validate_field_lengths(self.len, |len, expected| len == expected)?;
+
         let mut child_data = Vec::with_capacity(self.field_builders.len());
         for f in &mut self.field_builders {
             let arr = f.finish();
@@ -1520,7 +1756,29 @@ impl StructBuilder {
 
         self.len = 0;
 
-        StructArray::from(builder.build())
+        Ok(StructArray::from(builder.build()))
+    }
+
+    /// Returns an error naming the first child field builder whose length doesn't satisfy
+    /// `is_valid` with `expected`.
+    fn validate_field_lengths<F>(&self, expected: usize, is_valid: F) -> Result<()>
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        match self
+            .fields
+            .iter()
+            .zip(&self.field_builders)
+            .find(|(_, builder)| !is_valid(builder.len(), expected))
+        {
+            Some((field, builder)) => Err(ArrowError::InvalidArgumentError(format!(
+                "The builder of the field \"{}\" of the struct has {} values, but the struct has {} slots",
+                field.name(),
+                builder.len(),
+                expected
+            ))),
+            None => Ok(()),
+        }
     }
 }
 
@@ -1918,7 +2176,7 @@ where
 /// // It can thus hold up to 256 distinct string values.
 ///
 /// let key_builder = PrimitiveBuilder::<Int8Type>::new(100);
-/// let value_builder = StringBuilder::new(100);
+/// let value_builder = StringBuilder::default();
 /// let mut builder = StringDictionaryBuilder::new(key_builder, value_builder);
 ///
 /// // The builder builds the dictionary value by value
@@ -2174,6 +2432,28 @@ mod tests {
         assert_eq!(32, b.capacity());
     }
 
+    #[test]
+    fn test_finish_shrunk() {
+        let mut b = Int32BufferBuilder::new(1000);
+        assert_eq!(1008, b.capacity());
+        b.append_slice(&[1, 2, 3]);
+        b.shrink_to_fit();
+        assert_eq!(16, b.capacity());
+
+        // growing after shrinking still works
+        b.append_slice(&[4; 20]);
+        let buffer = b.finish_shrunk();
+        assert_eq!(92, buffer.len());
+        assert_eq!(128, buffer.capacity());
+        assert_eq!(0, b.capacity());
+
+        let mut b = BooleanBufferBuilder::new(10000);
+        b.append_n(10, true);
+        let buffer = b.finish_shrunk();
+        assert_eq!(2, buffer.len());
+        assert_eq!(64, buffer.capacity());
+    }
+
     #[test]
     fn test_append_slice() {
         let mut b = UInt8BufferBuilder::new(0);
@@ -2649,6 +2929,42 @@ mod tests {
         assert_eq!(0, builder.len());
     }
 
+    #[test]
+    fn test_list_array_builder_append_value() {
+        let mut builder = LargeListBuilder::new(Int64Builder::new(8));
+        builder.append_value(vec![Some(1), Some(2)]).unwrap();
+        builder.append(false).unwrap();
+        builder.append_value((3..5).map(Some)).unwrap();
+        builder.append_value(vec![None]).unwrap();
+        let list = builder.finish();
+
+        let expected = LargeListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3), Some(4)]),
+            Some(vec![None]),
+        ]);
+        assert_eq!(list, expected);
+
+        let mut builder = ListBuilder::new(LargeStringBuilder::default());
+        builder
+            .append_value(vec![Some("a"), None, Some("bc")])
+            .unwrap();
+        builder.append_value(Vec::<Option<String>>::new()).unwrap();
+        builder.append(false).unwrap();
+        let list = builder.finish();
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.value_offsets(), &[0, 3, 3, 3]);
+        assert!(list.is_null(2));
+        let values = list.values();
+        let values = values.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        assert_eq!(
+            values,
+            &LargeStringArray::from(vec![Some("a"), None, Some("bc")])
+        );
+    }
+
     #[test]
     fn test_list_list_array_builder() {
         let primitive_builder = Int32Builder::new(10);
@@ -2706,7 +3022,7 @@ mod tests {
 
     #[test]
     fn test_binary_array_builder() {
-        let mut builder = BinaryBuilder::new(20);
+        let mut builder = BinaryBuilder::default();
 
         builder.append_byte(b'h').unwrap();
         builder.append_byte(b'e').unwrap();
@@ -2735,7 +3051,7 @@ mod tests {
 
     #[test]
     fn test_large_binary_array_builder() {
-        let mut builder = LargeBinaryBuilder::new(20);
+        let mut builder = LargeBinaryBuilder::default();
 
         builder.append_byte(b'h').unwrap();
         builder.append_byte(b'e').unwrap();
@@ -2764,7 +3080,7 @@ mod tests {
 
     #[test]
     fn test_string_array_builder() {
-        let mut builder = StringBuilder::new(20);
+        let mut builder = StringBuilder::default();
 
         builder.append_value("hello").unwrap();
         builder.append(true).unwrap();
@@ -2781,9 +3097,32 @@ mod tests {
         assert_eq!(5, string_array.value_length(2));
     }
 
+    #[test]
+    fn test_builder_with_capacity() {
+        let mut builder = StringBuilder::with_capacity(2, 8);
+        builder.append_value("arrow").unwrap();
+        builder.append_null().unwrap();
+        assert_eq!(
+            builder.finish(),
+            StringArray::from(vec![Some("arrow"), None])
+        );
+
+        let mut builder = Int32Builder::default();
+        builder.append_value(1).unwrap();
+        assert_eq!(builder.finish(), Int32Array::from(vec![1]));
+
+        let mut builder = BooleanBuilder::with_capacity(4);
+        assert!(builder.capacity() >= 4);
+        builder.append_value(true).unwrap();
+        assert_eq!(builder.finish(), BooleanArray::from(vec![true]));
+
+        let builder = ListBuilder::<Int32Builder>::default();
+        assert_eq!(builder.len(), 0);
+    }
+
     #[test]
     fn test_fixed_size_binary_builder() {
-        let mut builder = FixedSizeBinaryBuilder::new(15, 5);
+        let mut builder = FixedSizeBinaryBuilder::with_capacity(15, 5);
 
         //  [b"hello", null, "arrow"]
         builder.append_value(b"hello").unwrap();
@@ -2803,7 +3142,7 @@ mod tests {
 
     #[test]
     fn test_decimal_builder() {
-        let mut builder = DecimalBuilder::new(30, 23, 6);
+        let mut builder = DecimalBuilder::with_capacity(30, 23, 6);
 
         builder.append_value(8_887_000_000).unwrap();
         builder.append_null().unwrap();
@@ -2819,7 +3158,7 @@ mod tests {
 
     #[test]
     fn test_string_array_builder_finish() {
-        let mut builder = StringBuilder::new(10);
+        let mut builder = StringBuilder::default();
 
         builder.append_value("hello").unwrap();
         builder.append_value("world").unwrap();
@@ -2836,7 +3175,7 @@ mod tests {
 
     #[test]
     fn test_string_array_builder_append_string() {
-        let mut builder = StringBuilder::new(20);
+        let mut builder = StringBuilder::default();
 
         let var = "hello".to_owned();
         builder.append_value(&var).unwrap();
@@ -2856,7 +3195,7 @@ mod tests {
 
     #[test]
     fn test_struct_array_builder() {
-        let string_builder = StringBuilder::new(4);
+        let string_builder = StringBuilder::default();
         let int_builder = Int32Builder::new(4);
 
         let mut fields = Vec::new();
@@ -2999,6 +3338,66 @@ mod tests {
         assert_eq!(0, builder.len());
     }
 
+    #[test]
+    fn test_struct_array_builder_invalid_lengths() {
+        let fields = vec![
+            Field::new("f1", DataType::Int32, false),
+            Field::new("f2", DataType::Boolean, false),
+        ];
+        let mut builder = StructBuilder::from_fields(fields, 2);
+
+        // neither child has a value
+        assert!(builder.append(true).is_err());
+        assert_eq!(0, builder.len());
+
+        builder
+            .field_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(1)
+            .unwrap();
+        let err = builder.append(true).unwrap_err();
+        assert!(err.to_string().contains("\"f2\""), "{}", err);
+
+        builder
+            .field_builder::<BooleanBuilder>(1)
+            .unwrap()
+            .append_value(true)
+            .unwrap();
+        builder.append(true).unwrap();
+
+        // the children have a value the struct doesn't have
+        builder
+            .field_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(2)
+            .unwrap();
+        assert!(builder.try_finish().is_err());
+        assert_eq!(1, builder.len());
+
+        builder
+            .field_builder::<BooleanBuilder>(1)
+            .unwrap()
+            .append_value(false)
+            .unwrap();
+        builder.append_null().unwrap();
+        let array = builder.try_finish().unwrap();
+        assert_eq!(2, array.len());
+        assert!(array.is_null(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "but the struct has 0 slots")]
+    fn test_struct_array_builder_finish_invalid_lengths() {
+        let fields = vec![Field::new("f1", DataType::Int32, false)];
+        let mut builder = StructBuilder::from_fields(fields, 1);
+        builder
+            .field_builder::<Int32Builder>(0)
+            .unwrap()
+            .append_value(1)
+            .unwrap();
+        builder.finish();
+    }
+
     #[test]
     fn test_struct_array_builder_from_schema() {
         let mut fields = Vec::new();
@@ -3074,7 +3473,7 @@ mod tests {
     #[test]
     fn test_string_dictionary_builder() {
         let key_builder = PrimitiveBuilder::<Int8Type>::new(5);
-        let value_builder = StringBuilder::new(2);
+        let value_builder = StringBuilder::default();
         let mut builder = StringDictionaryBuilder::new(key_builder, value_builder);
         builder.append("abc").unwrap();
         builder.append_null().unwrap();
@@ -24,7 +24,7 @@ use std::sync::Arc;
 use crate::datatypes::{DataType, IntervalUnit};
 use crate::{bitmap::Bitmap, datatypes::ArrowNativeType};
 use crate::{
-    buffer::{Buffer, MutableBuffer},
+    buffer::{BooleanBuffer, Buffer, MutableBuffer, NullBuffer},
     util::bit_util,
 };
 
@@ -126,7 +126,7 @@ pub(crate) fn new_buffers(data_type: &DataType, capacity: usize) -> [MutableBuff
             buffer.push(0i64);
             [buffer, MutableBuffer::new(capacity * mem::size_of::<u8>())]
         }
-        DataType::List(_) => {
+        DataType::List(_) | DataType::Map(_, _) => {
             // offset buffer always starts with a zero
             let mut buffer = MutableBuffer::new((1 + capacity) * mem::size_of::<i32>());
             buffer.push(0i32);
@@ -305,6 +305,16 @@ impl ArrayData {
         self.null_bitmap().as_ref().map(|b| b.buffer_ref())
     }
 
+    /// Returns the validity bitmap of this array data, taking its offset into account,
+    /// if any. This doesn't copy the bitmap.
+    pub fn nulls(&self) -> Option<NullBuffer> {
+        self.null_buffer().map(|buffer| {
+            let buffer = BooleanBuffer::new(buffer.clone(), self.offset, self.len);
+            // the null count was computed from the bitmap, if not given
+            unsafe { NullBuffer::new_unchecked(buffer, self.null_count) }
+        })
+    }
+
     /// Returns whether the element at index `i` is not null
     pub fn is_valid(&self, i: usize) -> bool {
         if let Some(ref b) = self.null_bitmap {
@@ -452,6 +462,9 @@ impl ArrayData {
             DataType::LargeList(field) => {
                 vec![Self::new_empty(field.data_type())]
             }
+            DataType::Map(field, _) => {
+                vec![Self::new_empty(field.data_type())]
+            }
             DataType::Struct(fields) => fields
                 .iter()
                 .map(|field| Self::new_empty(field.data_type()))
@@ -480,6 +493,7 @@ pub struct ArrayDataBuilder {
     len: usize,
     null_count: Option<usize>,
     null_bit_buffer: Option<Buffer>,
+    nulls: Option<NullBuffer>,
     offset: usize,
     buffers: Vec<Buffer>,
     child_data: Vec<ArrayData>,
@@ -493,6 +507,7 @@ impl ArrayDataBuilder {
             len: 0,
             null_count: None,
             null_bit_buffer: None,
+            nulls: None,
             offset: 0,
             buffers: vec![],
             child_data: vec![],
@@ -508,6 +523,18 @@ impl ArrayDataBuilder {
 
     pub fn null_bit_buffer(mut self, buf: Buffer) -> Self {
         self.null_bit_buffer = Some(buf);
+        self.nulls = None;
+        self
+    }
+
+    /// Sets the validity bitmap of the array data, replacing any
+    /// [`null_bit_buffer`](Self::null_bit_buffer).
+    ///
+    /// The bits of `nulls` aren't copied when its offset and the offset of the array
+    /// data are a multiple of 8 bits apart, see [BooleanBuffer::bit_buffer_at].
+    pub fn nulls(mut self, nulls: Option<NullBuffer>) -> Self {
+        self.nulls = nulls;
+        self.null_bit_buffer = None;
         self
     }
 
@@ -538,11 +565,18 @@ impl ArrayDataBuilder {
     }
 
     pub fn build(self) -> ArrayData {
+        let (null_count, null_bit_buffer) = match self.nulls {
+            Some(nulls) => (
+                Some(nulls.null_count()),
+                Some(nulls.inner().bit_buffer_at(self.offset)),
+            ),
+            None => (self.null_count, self.null_bit_buffer),
+        };
         ArrayData::new(
             self.data_type,
             self.len,
-            self.null_count,
-            self.null_bit_buffer,
+            null_count,
+            null_bit_buffer,
             self.offset,
             self.buffers,
             self.child_data,
@@ -668,6 +702,34 @@ mod tests {
         assert_ne!(int_data, float_data);
     }
 
+    #[test]
+    fn test_builder_nulls() {
+        let nulls = NullBuffer::new(BooleanBuffer::new(Buffer::from([0b01101101]), 2, 5));
+        let values = Buffer::from_slice_ref(&[1i32, 2, 3, 4, 5, 6]);
+        for offset in 0..2 {
+            let data = ArrayData::builder(DataType::Int32)
+                .len(5)
+                .offset(offset)
+                .add_buffer(values.clone())
+                .nulls(Some(nulls.clone()))
+                .build();
+            assert_eq!(data.null_count(), 1);
+            assert_eq!(data.nulls(), Some(nulls.clone()));
+            assert!(data.is_null(2));
+            assert!(data.is_valid(3));
+        }
+
+        let data = ArrayData::builder(DataType::Int32)
+            .len(5)
+            .offset(1)
+            .add_buffer(values)
+            .null_bit_buffer(Buffer::from([0b11111101]))
+            .nulls(None)
+            .build();
+        assert_eq!(data.null_count(), 0);
+        assert_eq!(data.nulls(), None);
+    }
+
     #[test]
     fn test_count_nulls() {
         let null_buffer = Some(Buffer::from(vec![0b00010110, 0b10011111]));
@@ -17,9 +17,8 @@
 
 use crate::array::{data::count_nulls, ArrayData};
 use crate::buffer::Buffer;
-use crate::util::bit_util::get_bit;
 
-use super::utils::{equal_bits, equal_len};
+use super::utils::{equal_bits, equal_len, is_valid};
 
 pub(super) fn boolean_equal(
     lhs: &ArrayData,
@@ -33,8 +32,8 @@ pub(super) fn boolean_equal(
     let lhs_values = lhs.buffers()[0].as_slice();
     let rhs_values = rhs.buffers()[0].as_slice();
 
-    let lhs_null_count = count_nulls(lhs_nulls, lhs_start, len);
-    let rhs_null_count = count_nulls(rhs_nulls, rhs_start, len);
+    let lhs_null_count = count_nulls(lhs_nulls, lhs_start + lhs.offset(), len);
+    let rhs_null_count = count_nulls(rhs_nulls, rhs_start + rhs.offset(), len);
 
     if lhs_null_count == 0 && rhs_null_count == 0 {
         // Optimize performance for starting offset at u8 boundary.
@@ -72,18 +71,14 @@ pub(super) fn boolean_equal(
             len,
         )
     } else {
-        // get a ref of the null buffer bytes, to use in testing for nullness
-        let lhs_null_bytes = lhs_nulls.as_ref().unwrap().as_slice();
-        let rhs_null_bytes = rhs_nulls.as_ref().unwrap().as_slice();
-
         let lhs_start = lhs.offset() + lhs_start;
         let rhs_start = rhs.offset() + rhs_start;
 
         (0..len).all(|i| {
             let lhs_pos = lhs_start + i;
             let rhs_pos = rhs_start + i;
-            let lhs_is_null = !get_bit(lhs_null_bytes, lhs_pos);
-            let rhs_is_null = !get_bit(rhs_null_bytes, rhs_pos);
+            let lhs_is_null = !is_valid(lhs_nulls, lhs_pos);
+            let rhs_is_null = !is_valid(rhs_nulls, rhs_pos);
 
             lhs_is_null
                 || (lhs_is_null == rhs_is_null)
@@ -18,9 +18,8 @@
 use crate::array::{data::count_nulls, ArrayData};
 use crate::buffer::Buffer;
 use crate::datatypes::DataType;
-use crate::util::bit_util::get_bit;
 
-use super::utils::equal_len;
+use super::utils::{equal_len, is_valid};
 
 pub(super) fn decimal_equal(
     lhs: &ArrayData,
@@ -39,8 +38,8 @@ pub(super) fn decimal_equal(
     let lhs_values = &lhs.buffers()[0].as_slice()[lhs.offset() * size..];
     let rhs_values = &rhs.buffers()[0].as_slice()[rhs.offset() * size..];
 
-    let lhs_null_count = count_nulls(lhs_nulls, lhs_start, len);
-    let rhs_null_count = count_nulls(rhs_nulls, rhs_start, len);
+    let lhs_null_count = count_nulls(lhs_nulls, lhs_start + lhs.offset(), len);
+    let rhs_null_count = count_nulls(rhs_nulls, rhs_start + rhs.offset(), len);
 
     if lhs_null_count == 0 && rhs_null_count == 0 {
         equal_len(
@@ -51,16 +50,13 @@ pub(super) fn decimal_equal(
             size * len,
         )
     } else {
-        // get a ref of the null buffer bytes, to use in testing for nullness
-        let lhs_null_bytes = lhs_nulls.as_ref().unwrap().as_slice();
-        let rhs_null_bytes = rhs_nulls.as_ref().unwrap().as_slice();
         // with nulls, we need to compare item by item whenever it is not null
         (0..len).all(|i| {
             let lhs_pos = lhs_start + i;
             let rhs_pos = rhs_start + i;
 
-            let lhs_is_null = !get_bit(lhs_null_bytes, lhs_pos + lhs.offset());
-            let rhs_is_null = !get_bit(rhs_null_bytes, rhs_pos + rhs.offset());
+            let lhs_is_null = !is_valid(lhs_nulls, lhs_pos + lhs.offset());
+            let rhs_is_null = !is_valid(rhs_nulls, rhs_pos + rhs.offset());
 
             lhs_is_null
                 || (lhs_is_null == rhs_is_null)
@@ -18,9 +18,8 @@
 use crate::array::{data::count_nulls, ArrayData};
 use crate::buffer::Buffer;
 use crate::datatypes::DataType;
-use crate::util::bit_util::get_bit;
 
-use super::equal_range;
+use super::{equal_range, utils::is_valid};
 
 pub(super) fn fixed_list_equal(
     lhs: &ArrayData,
@@ -39,8 +38,12 @@ pub(super) fn fixed_list_equal(
     let lhs_values = &lhs.child_data()[0];
     let rhs_values = &rhs.child_data()[0];
 
-    let lhs_null_count = count_nulls(lhs_nulls, lhs_start, len);
-    let rhs_null_count = count_nulls(rhs_nulls, rhs_start, len);
+    // the values of a fixed size list are not sliced with it
+    let lhs_values_start = (lhs_start + lhs.offset()) * size;
+    let rhs_values_start = (rhs_start + rhs.offset()) * size;
+
+    let lhs_null_count = count_nulls(lhs_nulls, lhs_start + lhs.offset(), len);
+    let rhs_null_count = count_nulls(rhs_nulls, rhs_start + rhs.offset(), len);
 
     if lhs_null_count == 0 && rhs_null_count == 0 {
         equal_range(
@@ -48,33 +51,31 @@ pub(super) fn fixed_list_equal(
             rhs_values,
             lhs_values.null_buffer(),
             rhs_values.null_buffer(),
-            size * lhs_start,
-            size * rhs_start,
+            lhs_values_start,
+            rhs_values_start,
             size * len,
         )
     } else {
-        // get a ref of the null buffer bytes, to use in testing for nullness
-        let lhs_null_bytes = lhs_nulls.as_ref().unwrap().as_slice();
-        let rhs_null_bytes = rhs_nulls.as_ref().unwrap().as_slice();
         // with nulls, we need to compare item by item whenever it is not null
         (0..len).all(|i| {
             let lhs_pos = lhs_start + i;
             let rhs_pos = rhs_start + i;
 
-            let lhs_is_null = !get_bit(lhs_null_bytes, lhs_pos + lhs.offset());
-            let rhs_is_null = !get_bit(rhs_null_bytes, rhs_pos + rhs.offset());
+            let lhs_is_null = !is_valid(lhs_nulls, lhs_pos + lhs.offset());
+            let rhs_is_null = !is_valid(rhs_nulls, rhs_pos + rhs.offset());
 
-            lhs_is_null
-                || (lhs_is_null == rhs_is_null)
-                    && equal_range(
-                        lhs_values,
-                        rhs_values,
-                        lhs_values.null_buffer(),
-                        rhs_values.null_buffer(),
-                        lhs_pos * size,
-                        rhs_pos * size,
-                        size, // 1 * size since we are comparing a single entry
-                    )
+            if lhs_is_null || rhs_is_null {
+                return lhs_is_null == rhs_is_null;
+            }
+            equal_range(
+                lhs_values,
+                rhs_values,
+                lhs_values.null_buffer(),
+                rhs_values.null_buffer(),
+                lhs_values_start + i * size,
+                rhs_values_start + i * size,
+                size, // 1 * size since we are comparing a single entry
+            )
         })
     }
 }
@@ -19,10 +19,9 @@ use crate::{
     array::ArrayData,
     array::{data::count_nulls, OffsetSizeTrait},
     buffer::Buffer,
-    util::bit_util::get_bit,
 };
 
-use super::{equal_range, utils::child_logical_null_buffer};
+use super::{equal_range, utils::is_valid};
 
 fn lengths_equal<T: OffsetSizeTrait>(lhs: &[T], rhs: &[T]) -> bool {
     // invariant from `base_equal`
@@ -46,13 +45,10 @@ fn lengths_equal<T: OffsetSizeTrait>(lhs: &[T], rhs: &[T]) -> bool {
         })
 }
 
-#[allow(clippy::too_many_arguments)]
 #[inline]
 fn offset_value_equal<T: OffsetSizeTrait>(
     lhs_values: &ArrayData,
     rhs_values: &ArrayData,
-    lhs_nulls: Option<&Buffer>,
-    rhs_nulls: Option<&Buffer>,
     lhs_offsets: &[T],
     rhs_offsets: &[T],
     lhs_pos: usize,
@@ -68,14 +64,21 @@ fn offset_value_equal<T: OffsetSizeTrait>(
         && equal_range(
             lhs_values,
             rhs_values,
-            lhs_nulls,
-            rhs_nulls,
+            lhs_values.null_buffer(),
+            rhs_values.null_buffer(),
             lhs_start,
             rhs_start,
             lhs_len.to_usize().unwrap(),
         )
 }
 
+/// Compares the lists of two [ArrayData] starting at `lhs_start` and `rhs_start`
+/// respectively for `len` slots.
+///
+/// The values of a list are only compared when its slot is valid on both sides, using
+/// the null buffers of the values themselves: the values referenced by a null slot
+/// are undefined, and the values of a list may be a sliced or only partially
+/// referenced array.
 pub(super) fn list_equal<T: OffsetSizeTrait>(
     lhs: &ArrayData,
     rhs: &ArrayData,
@@ -88,85 +91,46 @@ pub(super) fn list_equal<T: OffsetSizeTrait>(
     let lhs_offsets = lhs.buffer::<T>(0);
     let rhs_offsets = rhs.buffer::<T>(0);
 
-    // There is an edge-case where a n-length list that has 0 children, results in panics.
-    // For example; an array with offsets [0, 0, 0, 0, 0] has 4 slots, but will have
-    // no valid children.
-    // Under logical equality, the child null bitmap will be an empty buffer, as there are
-    // no child values. This causes panics when trying to count set bits.
-    //
-    // We caught this by chance from an accidental test-case, but due to the nature of this
-    // crash only occuring on list equality checks, we are adding a check here, instead of
-    // on the buffer/bitmap utilities, as a length check would incur a penalty for almost all
-    // other use-cases.
-    //
-    // The solution is to check the number of child values from offsets, and return `true` if
-    // they = 0. Empty arrays are equal, so this is correct.
-    //
-    // It's unlikely that one would create a n-length list array with no values, where n > 0,
-    // however, one is more likely to slice into a list array and get a region that has 0
-    // child values.
-    // The test that triggered this behaviour had [4, 4] as a slice of 1 value slot.
-    let lhs_child_length = lhs_offsets.get(len).unwrap().to_usize().unwrap()
-        - lhs_offsets.first().unwrap().to_usize().unwrap();
-    let rhs_child_length = rhs_offsets.get(len).unwrap().to_usize().unwrap()
-        - rhs_offsets.first().unwrap().to_usize().unwrap();
-
-    if lhs_child_length == 0 && lhs_child_length == rhs_child_length {
-        return true;
-    }
-
     let lhs_values = &lhs.child_data()[0];
     let rhs_values = &rhs.child_data()[0];
 
-    let lhs_null_count = count_nulls(lhs_nulls, lhs_start, len);
-    let rhs_null_count = count_nulls(rhs_nulls, rhs_start, len);
-
-    // compute the child logical bitmap
-    let child_lhs_nulls =
-        child_logical_null_buffer(lhs, lhs_nulls, lhs.child_data().get(0).unwrap());
-    let child_rhs_nulls =
-        child_logical_null_buffer(rhs, rhs_nulls, rhs.child_data().get(0).unwrap());
+    let lhs_null_count = count_nulls(lhs_nulls, lhs_start + lhs.offset(), len);
+    let rhs_null_count = count_nulls(rhs_nulls, rhs_start + rhs.offset(), len);
 
     if lhs_null_count == 0 && rhs_null_count == 0 {
         lengths_equal(
-            &lhs_offsets[lhs_start..lhs_start + len],
-            &rhs_offsets[rhs_start..rhs_start + len],
-        ) && equal_range(
+            &lhs_offsets[lhs_start..=lhs_start + len],
+            &rhs_offsets[rhs_start..=rhs_start + len],
+        ) && offset_value_equal::<T>(
             lhs_values,
             rhs_values,
-            child_lhs_nulls.as_ref(),
-            child_rhs_nulls.as_ref(),
-            lhs_offsets[lhs_start].to_usize().unwrap(),
-            rhs_offsets[rhs_start].to_usize().unwrap(),
-            (lhs_offsets[len] - lhs_offsets[lhs_start])
-                .to_usize()
-                .unwrap(),
+            lhs_offsets,
+            rhs_offsets,
+            lhs_start,
+            rhs_start,
+            len,
         )
     } else {
-        // get a ref of the parent null buffer bytes, to use in testing for nullness
-        let lhs_null_bytes = lhs_nulls.unwrap().as_slice();
-        let rhs_null_bytes = rhs_nulls.unwrap().as_slice();
         // with nulls, we need to compare item by item whenever it is not null
         (0..len).all(|i| {
             let lhs_pos = lhs_start + i;
             let rhs_pos = rhs_start + i;
 
-            let lhs_is_null = !get_bit(lhs_null_bytes, lhs_pos + lhs.offset());
-            let rhs_is_null = !get_bit(rhs_null_bytes, rhs_pos + rhs.offset());
-
-            lhs_is_null
-                || (lhs_is_null == rhs_is_null)
-                    && offset_value_equal::<T>(
-                        lhs_values,
-                        rhs_values,
-                        child_lhs_nulls.as_ref(),
-                        child_rhs_nulls.as_ref(),
-                        lhs_offsets,
-                        rhs_offsets,
-                        lhs_pos,
-                        rhs_pos,
-                        1,
-                    )
+            let lhs_is_null = !is_valid(lhs_nulls, lhs_pos + lhs.offset());
+            let rhs_is_null = !is_valid(rhs_nulls, rhs_pos + rhs.offset());
+
+            if lhs_is_null || rhs_is_null {
+                return lhs_is_null == rhs_is_null;
+            }
+            offset_value_equal::<T>(
+                lhs_values,
+                rhs_values,
+                lhs_offsets,
+                rhs_offsets,
+                lhs_pos,
+                rhs_pos,
+                1,
+            )
         })
     }
 }
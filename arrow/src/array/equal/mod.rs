@@ -205,7 +205,7 @@ fn equal_values(
         DataType::Decimal(_, _) => {
             decimal_equal(lhs, rhs, lhs_nulls, rhs_nulls, lhs_start, rhs_start, len)
         }
-        DataType::List(_) => {
+        DataType::List(_) | DataType::Map(_, _) => {
             list_equal::<i32>(lhs, rhs, lhs_nulls, rhs_nulls, lhs_start, rhs_start, len)
         }
         DataType::LargeList(_) => {
@@ -258,11 +258,27 @@ fn equal_range(
     rhs_start: usize,
     len: usize,
 ) -> bool {
-    utils::base_equal(lhs, rhs)
+    // only the ranges are compared, the arrays themselves may have different lengths
+    lhs.data_type() == rhs.data_type()
         && utils::equal_nulls(lhs, rhs, lhs_nulls, rhs_nulls, lhs_start, rhs_start, len)
         && equal_values(lhs, rhs, lhs_nulls, rhs_nulls, lhs_start, rhs_start, len)
 }
 
+/// Logically compares the value at `lhs_index` of `lhs` with the value at `rhs_index` of
+/// `rhs`, including their nested values. Two null values are equal.
+pub(crate) fn equal_at(
+    lhs: &ArrayData,
+    rhs: &ArrayData,
+    lhs_index: usize,
+    rhs_index: usize,
+) -> bool {
+    let lhs_nulls = lhs.null_buffer();
+    let rhs_nulls = rhs.null_buffer();
+    lhs.data_type() == rhs.data_type()
+        && utils::equal_nulls(lhs, rhs, lhs_nulls, rhs_nulls, lhs_index, rhs_index, 1)
+        && equal_values(lhs, rhs, lhs_nulls, rhs_nulls, lhs_index, rhs_index, 1)
+}
+
 /// Logically compares two [ArrayData].
 /// Two arrays are logically equal if and only if:
 /// * their data types are equal
@@ -722,7 +738,7 @@ mod tests {
     fn create_fixed_size_binary_array<U: AsRef<[u8]>, T: AsRef<[Option<U>]>>(
         data: T,
     ) -> ArrayData {
-        let mut builder = FixedSizeBinaryBuilder::new(15, 5);
+        let mut builder = FixedSizeBinaryBuilder::with_capacity(15, 5);
 
         for d in data.as_ref() {
             if let Some(v) = d {
@@ -796,7 +812,7 @@ mod tests {
     }
 
     fn create_decimal_array(data: &[Option<i128>]) -> ArrayData {
-        let mut builder = DecimalBuilder::new(20, 23, 6);
+        let mut builder = DecimalBuilder::with_capacity(20, 23, 6);
 
         for d in data {
             if let Some(v) = d {
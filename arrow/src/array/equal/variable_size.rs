@@ -16,13 +16,12 @@
 // under the License.
 
 use crate::buffer::Buffer;
-use crate::util::bit_util::get_bit;
 use crate::{
     array::data::count_nulls,
     array::{ArrayData, OffsetSizeTrait},
 };
 
-use super::utils::equal_len;
+use super::utils::{equal_len, is_valid};
 
 fn offset_value_equal<T: OffsetSizeTrait>(
     lhs_values: &[u8],
@@ -64,8 +63,8 @@ pub(super) fn variable_sized_equal<T: OffsetSizeTrait>(
     let lhs_values = lhs.buffers()[1].as_slice();
     let rhs_values = rhs.buffers()[1].as_slice();
 
-    let lhs_null_count = count_nulls(lhs_nulls, lhs_start, len);
-    let rhs_null_count = count_nulls(rhs_nulls, rhs_start, len);
+    let lhs_null_count = count_nulls(lhs_nulls, lhs_start + lhs.offset(), len);
+    let rhs_null_count = count_nulls(rhs_nulls, rhs_start + rhs.offset(), len);
 
     if lhs_null_count == 0
         && rhs_null_count == 0
@@ -86,13 +85,8 @@ pub(super) fn variable_sized_equal<T: OffsetSizeTrait>(
             let lhs_pos = lhs_start + i;
             let rhs_pos = rhs_start + i;
 
-            // the null bits can still be `None`, so we don't unwrap
-            let lhs_is_null = !lhs_nulls
-                .map(|v| get_bit(v.as_slice(), lhs.offset() + lhs_pos))
-                .unwrap_or(false);
-            let rhs_is_null = !rhs_nulls
-                .map(|v| get_bit(v.as_slice(), rhs.offset() + rhs_pos))
-                .unwrap_or(false);
+            let lhs_is_null = !is_valid(lhs_nulls, lhs.offset() + lhs_pos);
+            let rhs_is_null = !is_valid(rhs_nulls, rhs.offset() + rhs_pos);
 
             lhs_is_null
                 || (lhs_is_null == rhs_is_null)
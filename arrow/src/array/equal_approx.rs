@@ -0,0 +1,478 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Configurable equality of arrays, schemas and record batches, allowing approximate
+//! comparisons of floating point values and ignoring some differences of schemas.
+
+use super::equal::equal;
+use super::*;
+use crate::datatypes::*;
+use crate::record_batch::RecordBatch;
+
+/// Compares arrays, schemas and [RecordBatch]es with configurable strictness.
+///
+/// By default, the comparison is the same as the one of `PartialEq`. Floating point values
+/// can be compared with an absolute tolerance ([ArrayEq::with_epsilon]) or a maximum
+/// distance in units in the last place ([ArrayEq::with_max_ulps]), and the metadata and the
+/// nullability of fields can be ignored. Two NaN are always considered equal.
+///
+/// # Example
+/// ```
+/// use arrow::array::{ArrayEq, Float64Array};
+///
+/// let a = Float64Array::from(vec![0.1 + 0.2, 1.0]);
+/// let b = Float64Array::from(vec![0.3, 1.0]);
+///
+/// assert!(!ArrayEq::new().equal(&a, &b));
+/// assert!(ArrayEq::new().with_max_ulps(1).equal(&a, &b));
+/// assert!(ArrayEq::new().with_epsilon(1e-9).equal(&a, &b));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ArrayEq {
+    epsilon: Option<f64>,
+    max_ulps: Option<u64>,
+    ignore_metadata: bool,
+    ignore_nullability: bool,
+}
+
+impl ArrayEq {
+    /// Creates an exact comparison.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Considers two floating point values equal when they differ by at most `epsilon`.
+    pub fn with_epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = Some(epsilon);
+        self
+    }
+
+    /// Considers two floating point values equal when there are at most `max_ulps`
+    /// representable values between them.
+    pub fn with_max_ulps(mut self, max_ulps: u64) -> Self {
+        self.max_ulps = Some(max_ulps);
+        self
+    }
+
+    /// Whether to ignore the metadata of schemas and fields.
+    pub fn with_ignore_metadata(mut self, ignore_metadata: bool) -> Self {
+        self.ignore_metadata = ignore_metadata;
+        self
+    }
+
+    /// Whether to ignore the nullability of fields, including the fields of nested types.
+    pub fn with_ignore_nullability(mut self, ignore_nullability: bool) -> Self {
+        self.ignore_nullability = ignore_nullability;
+        self
+    }
+
+    /// Returns whether the two arrays are equal.
+    pub fn equal(&self, lhs: &dyn Array, rhs: &dyn Array) -> bool {
+        self.equal_data(lhs.data_ref(), rhs.data_ref())
+    }
+
+    /// Returns whether the two schemas are equal.
+    pub fn equal_schemas(&self, lhs: &Schema, rhs: &Schema) -> bool {
+        lhs.fields().len() == rhs.fields().len()
+            && lhs
+                .fields()
+                .iter()
+                .zip(rhs.fields())
+                .all(|(lhs, rhs)| self.equal_fields(lhs, rhs))
+            && (self.ignore_metadata || lhs.metadata() == rhs.metadata())
+    }
+
+    /// Returns whether the two record batches have equal schemas and columns.
+    pub fn equal_batches(&self, lhs: &RecordBatch, rhs: &RecordBatch) -> bool {
+        self.equal_schemas(lhs.schema().as_ref(), rhs.schema().as_ref())
+            && lhs
+                .columns()
+                .iter()
+                .zip(rhs.columns())
+                .all(|(lhs, rhs)| self.equal(lhs.as_ref(), rhs.as_ref()))
+    }
+
+    /// Returns whether the two fields are equal.
+    pub fn equal_fields(&self, lhs: &Field, rhs: &Field) -> bool {
+        lhs.name() == rhs.name()
+            && self.equal_data_types(lhs.data_type(), rhs.data_type())
+            && (self.ignore_nullability || lhs.is_nullable() == rhs.is_nullable())
+            && (self.ignore_metadata || lhs.metadata() == rhs.metadata())
+            && lhs.dict_id() == rhs.dict_id()
+            && lhs.dict_is_ordered() == rhs.dict_is_ordered()
+    }
+
+    /// Returns whether the two data types are equal, comparing their nested fields with
+    /// [ArrayEq::equal_fields].
+    pub fn equal_data_types(&self, lhs: &DataType, rhs: &DataType) -> bool {
+        match (lhs, rhs) {
+            (DataType::List(lhs), DataType::List(rhs))
+            | (DataType::LargeList(lhs), DataType::LargeList(rhs)) => {
+                self.equal_fields(lhs, rhs)
+            }
+            (
+                DataType::FixedSizeList(lhs, lhs_size),
+                DataType::FixedSizeList(rhs, rhs_size),
+            ) => lhs_size == rhs_size && self.equal_fields(lhs, rhs),
+            (DataType::Map(lhs, lhs_sorted), DataType::Map(rhs, rhs_sorted)) => {
+                lhs_sorted == rhs_sorted && self.equal_fields(lhs, rhs)
+            }
+            (DataType::Struct(lhs), DataType::Struct(rhs))
+            | (DataType::Union(lhs), DataType::Union(rhs)) => {
+                lhs.len() == rhs.len()
+                    && lhs
+                        .iter()
+                        .zip(rhs)
+                        .all(|(lhs, rhs)| self.equal_fields(lhs, rhs))
+            }
+            (
+                DataType::Dictionary(lhs_key, lhs_value),
+                DataType::Dictionary(rhs_key, rhs_value),
+            ) => lhs_key == rhs_key && self.equal_data_types(lhs_value, rhs_value),
+            (lhs, rhs) => lhs == rhs,
+        }
+    }
+
+    fn is_exact(&self) -> bool {
+        self.epsilon.is_none() && self.max_ulps.is_none()
+    }
+
+    fn equal_data(&self, lhs: &ArrayData, rhs: &ArrayData) -> bool {
+        if lhs.len() != rhs.len()
+            || lhs.null_count() != rhs.null_count()
+            || !self.equal_data_types(lhs.data_type(), rhs.data_type())
+        {
+            return false;
+        }
+        if self.is_exact() && lhs.data_type() == rhs.data_type() {
+            return equal(lhs, rhs);
+        }
+        match lhs.data_type() {
+            DataType::Float32 => self.equal_floats::<Float32Type>(lhs, rhs),
+            DataType::Float64 => self.equal_floats::<Float64Type>(lhs, rhs),
+            DataType::List(_) | DataType::Map(_, _) => self.equal_lists::<i32>(lhs, rhs),
+            DataType::LargeList(_) => self.equal_lists::<i64>(lhs, rhs),
+            DataType::FixedSizeList(_, size) => {
+                self.equal_fixed_size_lists(lhs, rhs, *size as usize)
+            }
+            DataType::Struct(_) => self.equal_structs(lhs, rhs),
+            DataType::Dictionary(key_type, _) => match key_type.as_ref() {
+                DataType::Int8 => self.equal_dictionaries::<Int8Type>(lhs, rhs),
+                DataType::Int16 => self.equal_dictionaries::<Int16Type>(lhs, rhs),
+                DataType::Int32 => self.equal_dictionaries::<Int32Type>(lhs, rhs),
+                DataType::Int64 => self.equal_dictionaries::<Int64Type>(lhs, rhs),
+                DataType::UInt8 => self.equal_dictionaries::<UInt8Type>(lhs, rhs),
+                DataType::UInt16 => self.equal_dictionaries::<UInt16Type>(lhs, rhs),
+                DataType::UInt32 => self.equal_dictionaries::<UInt32Type>(lhs, rhs),
+                DataType::UInt64 => self.equal_dictionaries::<UInt64Type>(lhs, rhs),
+                _ => unreachable!(),
+            },
+            // the other types have no nested fields nor floating point values
+            _ => equal(lhs, rhs),
+        }
+    }
+
+    /// Returns whether the slots at `i` of both arrays have the same validity, and, when
+    /// they are valid, whether `equal_values` returns true.
+    fn equal_slots<F>(lhs: &ArrayData, rhs: &ArrayData, equal_values: F) -> bool
+    where
+        F: Fn(usize) -> bool,
+    {
+        (0..lhs.len()).all(|i| match (lhs.is_valid(i), rhs.is_valid(i)) {
+            (true, true) => equal_values(i),
+            (false, false) => true,
+            _ => false,
+        })
+    }
+
+    fn equal_floats<T>(&self, lhs: &ArrayData, rhs: &ArrayData) -> bool
+    where
+        T: ArrowPrimitiveType,
+        T::Native: ApproxFloat,
+    {
+        let lhs_values = PrimitiveArray::<T>::from(lhs.clone());
+        let rhs_values = PrimitiveArray::<T>::from(rhs.clone());
+        Self::equal_slots(lhs, rhs, |i| {
+            let lhs = lhs_values.value(i);
+            let rhs = rhs_values.value(i);
+            lhs == rhs
+                || (lhs.is_nan() && rhs.is_nan())
+                || self.epsilon.map_or(false, |epsilon| {
+                    (lhs.to_f64() - rhs.to_f64()).abs() <= epsilon
+                })
+                || self
+                    .max_ulps
+                    .map_or(false, |max_ulps| lhs.ulps(rhs) <= max_ulps)
+        })
+    }
+
+    fn equal_lists<T: OffsetSizeTrait>(&self, lhs: &ArrayData, rhs: &ArrayData) -> bool {
+        let lhs_offsets = lhs.buffer::<T>(0);
+        let rhs_offsets = rhs.buffer::<T>(0);
+        let lhs_values = &lhs.child_data()[0];
+        let rhs_values = &rhs.child_data()[0];
+        Self::equal_slots(lhs, rhs, |i| {
+            let lhs_start = lhs_offsets[i].to_usize().unwrap();
+            let lhs_len = lhs_offsets[i + 1].to_usize().unwrap() - lhs_start;
+            let rhs_start = rhs_offsets[i].to_usize().unwrap();
+            let rhs_len = rhs_offsets[i + 1].to_usize().unwrap() - rhs_start;
+            lhs_len == rhs_len
+                && self.equal_data(
+                    &lhs_values.slice(lhs_start, lhs_len),
+                    &rhs_values.slice(rhs_start, rhs_len),
+                )
+        })
+    }
+
+    fn equal_fixed_size_lists(
+        &self,
+        lhs: &ArrayData,
+        rhs: &ArrayData,
+        size: usize,
+    ) -> bool {
+        let lhs_values = &lhs.child_data()[0];
+        let rhs_values = &rhs.child_data()[0];
+        Self::equal_slots(lhs, rhs, |i| {
+            self.equal_data(
+                &lhs_values.slice((lhs.offset() + i) * size, size),
+                &rhs_values.slice((rhs.offset() + i) * size, size),
+            )
+        })
+    }
+
+    fn equal_structs(&self, lhs: &ArrayData, rhs: &ArrayData) -> bool {
+        // the children of a struct are not sliced together with it
+        lhs.child_data()
+            .iter()
+            .zip(rhs.child_data())
+            .all(|(lhs_field, rhs_field)| {
+                if lhs.null_count() == 0 {
+                    self.equal_data(
+                        &lhs_field.slice(lhs.offset(), lhs.len()),
+                        &rhs_field.slice(rhs.offset(), rhs.len()),
+                    )
+                } else {
+                    // the values of a field are irrelevant where the struct is null
+                    Self::equal_slots(lhs, rhs, |i| {
+                        self.equal_data(
+                            &lhs_field.slice(lhs.offset() + i, 1),
+                            &rhs_field.slice(rhs.offset() + i, 1),
+                        )
+                    })
+                }
+            })
+    }
+
+    fn equal_dictionaries<K: ArrowDictionaryKeyType>(
+        &self,
+        lhs: &ArrayData,
+        rhs: &ArrayData,
+    ) -> bool {
+        let lhs_keys = DictionaryArray::<K>::from(lhs.clone()).keys_array();
+        let rhs_keys = DictionaryArray::<K>::from(rhs.clone()).keys_array();
+        let lhs_values = &lhs.child_data()[0];
+        let rhs_values = &rhs.child_data()[0];
+        Self::equal_slots(lhs, rhs, |i| {
+            self.equal_data(
+                &lhs_values.slice(lhs_keys.value(i).to_usize().unwrap(), 1),
+                &rhs_values.slice(rhs_keys.value(i).to_usize().unwrap(), 1),
+            )
+        })
+    }
+}
+
+/// Floating point values that can be compared approximately.
+trait ApproxFloat: Copy + PartialEq {
+    fn is_nan(self) -> bool;
+
+    fn to_f64(self) -> f64;
+
+    /// Returns the number of representable values between `self` and `other`.
+    fn ulps(self, other: Self) -> u64;
+}
+
+impl ApproxFloat for f32 {
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn ulps(self, other: Self) -> u64 {
+        // maps the bits to integers ordered like the floats, with both zeros at 0
+        let ordered = |v: f32| {
+            let bits = v.to_bits() as i32 as i64;
+            if bits < 0 {
+                i32::MIN as i64 - bits
+            } else {
+                bits
+            }
+        };
+        (ordered(self) - ordered(other)).abs() as u64
+    }
+}
+
+impl ApproxFloat for f64 {
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn ulps(self, other: Self) -> u64 {
+        // maps the bits to integers ordered like the floats, with both zeros at 0
+        let ordered = |v: f64| {
+            let bits = v.to_bits() as i64 as i128;
+            if bits < 0 {
+                i64::MIN as i128 - bits
+            } else {
+                bits
+            }
+        };
+        (ordered(self) - ordered(other)).abs() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::{BTreeMap, HashMap};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_exact() {
+        let a = Float64Array::from(vec![Some(1.0), None, Some(f64::NAN)]);
+        let b = Float64Array::from(vec![Some(1.0), None, Some(f64::NAN)]);
+        assert!(ArrayEq::new().equal(&a, &b));
+
+        let b = Float64Array::from(vec![Some(1.0 + f64::EPSILON), None, Some(f64::NAN)]);
+        assert!(!ArrayEq::new().equal(&a, &b));
+    }
+
+    #[test]
+    fn test_float_tolerance() {
+        let a = Float32Array::from(vec![Some(1.0), None, Some(-0.0), Some(f32::NAN)]);
+        let b = Float32Array::from(vec![Some(1.0001), None, Some(0.0), Some(f32::NAN)]);
+
+        assert!(!ArrayEq::new().equal(&a, &b));
+        assert!(ArrayEq::new().with_epsilon(1e-3).equal(&a, &b));
+        assert!(!ArrayEq::new().with_epsilon(1e-5).equal(&a, &b));
+
+        let one_ulp = f32::from_bits(1.0_f32.to_bits() + 1);
+        let b = Float32Array::from(vec![Some(one_ulp), None, Some(0.0), Some(f32::NAN)]);
+        assert!(ArrayEq::new().with_max_ulps(1).equal(&a, &b));
+        assert!(!ArrayEq::new().with_max_ulps(0).equal(&a, &b));
+
+        // validity must still match
+        let b = Float32Array::from(vec![Some(1.0), Some(1.0), Some(0.0), Some(f32::NAN)]);
+        assert!(!ArrayEq::new().with_epsilon(1.0).equal(&a, &b));
+    }
+
+    #[test]
+    fn test_ulps_across_zero() {
+        let smallest = f64::from_bits(1);
+        assert_eq!((-smallest).ulps(smallest), 2);
+        assert_eq!((-0.0_f64).ulps(0.0), 0);
+        assert_eq!(1.0_f64.ulps(f64::from_bits(1.0_f64.to_bits() + 3)), 3);
+    }
+
+    #[test]
+    fn test_nested_floats() {
+        let a = ListArray::from_iter_primitive::<Float64Type, _, _>(vec![
+            Some(vec![Some(0.1 + 0.2), None]),
+            None,
+            Some(vec![]),
+        ]);
+        let b = ListArray::from_iter_primitive::<Float64Type, _, _>(vec![
+            Some(vec![Some(0.3), None]),
+            None,
+            Some(vec![]),
+        ]);
+        assert!(!ArrayEq::new().equal(&a, &b));
+        assert!(ArrayEq::new().with_max_ulps(1).equal(&a, &b));
+
+        let a = StructArray::from(vec![(
+            Field::new("f", DataType::Float64, false),
+            Arc::new(Float64Array::from(vec![1.0, 0.1 + 0.2, 2.0])) as ArrayRef,
+        )]);
+        let b = StructArray::from(vec![(
+            Field::new("f", DataType::Float64, false),
+            Arc::new(Float64Array::from(vec![0.0, 1.0, 0.3])) as ArrayRef,
+        )]);
+        let a = a.slice(1, 1);
+        let b = b.slice(2, 1);
+        assert!(ArrayEq::new()
+            .with_epsilon(1e-9)
+            .equal(a.as_ref(), b.as_ref()));
+    }
+
+    #[test]
+    fn test_schemas() {
+        let mut metadata = BTreeMap::new();
+        metadata.insert("k".to_string(), "v".to_string());
+        let mut field = Field::new(
+            "a",
+            DataType::List(Box::new(Field::new("item", DataType::Int32, false))),
+            false,
+        );
+        field.set_metadata(Some(metadata));
+        let mut schema_metadata = HashMap::new();
+        schema_metadata.insert("k".to_string(), "v".to_string());
+        let lhs = Schema::new_with_metadata(vec![field], schema_metadata);
+
+        let rhs = Schema::new(vec![Field::new(
+            "a",
+            DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+            true,
+        )]);
+
+        assert!(!ArrayEq::new().equal_schemas(&lhs, &rhs));
+        assert!(!ArrayEq::new()
+            .with_ignore_metadata(true)
+            .equal_schemas(&lhs, &rhs));
+        assert!(!ArrayEq::new()
+            .with_ignore_nullability(true)
+            .equal_schemas(&lhs, &rhs));
+        assert!(ArrayEq::new()
+            .with_ignore_metadata(true)
+            .with_ignore_nullability(true)
+            .equal_schemas(&lhs, &rhs));
+    }
+
+    #[test]
+    fn test_batches() {
+        let lhs = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("f", DataType::Float64, false)])),
+            vec![Arc::new(Float64Array::from(vec![0.1 + 0.2]))],
+        )
+        .unwrap();
+        let rhs = RecordBatch::try_new(
+            Arc::new(Schema::new(vec![Field::new("f", DataType::Float64, true)])),
+            vec![Arc::new(Float64Array::from(vec![0.3]))],
+        )
+        .unwrap();
+
+        let eq = ArrayEq::new().with_max_ulps(1);
+        assert!(!eq.equal_batches(&lhs, &rhs));
+        assert!(eq.with_ignore_nullability(true).equal_batches(&lhs, &rhs));
+    }
+}
@@ -113,6 +113,38 @@ impl<OffsetSize: OffsetSizeTrait> PartialEq<GenericListArray<OffsetSize>> for Va
     }
 }
 
+impl JsonEqual for MapArray {
+    fn equals_json(&self, json: &[&Value]) -> bool {
+        if self.len() != json.len() {
+            return false;
+        }
+
+        (0..self.len()).all(|i| match json[i] {
+            Value::Array(v) => self.is_valid(i) && self.value(i).equals_json_values(v),
+            Value::Null => self.is_null(i) || self.value_length(i) == 0,
+            _ => false,
+        })
+    }
+}
+
+impl PartialEq<Value> for MapArray {
+    fn eq(&self, json: &Value) -> bool {
+        match json {
+            Value::Array(json_array) => self.equals_json_values(json_array),
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<MapArray> for Value {
+    fn eq(&self, arrow: &MapArray) -> bool {
+        match self {
+            Value::Array(json_array) => arrow.equals_json_values(json_array),
+            _ => false,
+        }
+    }
+}
+
 impl<T: ArrowPrimitiveType> JsonEqual for DictionaryArray<T> {
     fn equals_json(&self, json: &[&Value]) -> bool {
         // todo: this is wrong: we must test the values also
@@ -719,7 +751,7 @@ mod tests {
     #[test]
     fn test_binary_json_equal() {
         // Test the equal case
-        let mut builder = BinaryBuilder::new(6);
+        let mut builder = BinaryBuilder::default();
         builder.append_value(b"hello").unwrap();
         builder.append_null().unwrap();
         builder.append_null().unwrap();
@@ -818,7 +850,7 @@ mod tests {
     #[test]
     fn test_fixed_size_binary_json_equal() {
         // Test the equal case
-        let mut builder = FixedSizeBinaryBuilder::new(15, 5);
+        let mut builder = FixedSizeBinaryBuilder::with_capacity(15, 5);
         builder.append_value(b"hello").unwrap();
         builder.append_null().unwrap();
         builder.append_value(b"world").unwrap();
@@ -899,7 +931,7 @@ mod tests {
     #[test]
     fn test_decimal_json_equal() {
         // Test the equal case
-        let mut builder = DecimalBuilder::new(30, 23, 6);
+        let mut builder = DecimalBuilder::with_capacity(30, 23, 6);
         builder.append_value(1_000).unwrap();
         builder.append_null().unwrap();
         builder.append_value(-250).unwrap();
@@ -87,6 +87,7 @@ mod array_binary;
 mod array_boolean;
 mod array_dictionary;
 mod array_list;
+mod array_map;
 mod array_primitive;
 mod array_string;
 mod array_struct;
@@ -95,12 +96,15 @@ mod builder;
 mod cast;
 mod data;
 mod equal;
+mod equal_approx;
 mod equal_json;
 mod ffi;
 mod iterator;
 mod null;
 mod ord;
 mod raw_pointer;
+mod scalar;
+mod to_vec;
 mod transform;
 
 use crate::datatypes::*;
@@ -119,9 +123,11 @@ pub use self::array_binary::FixedSizeBinaryArray;
 pub use self::array_binary::LargeBinaryArray;
 pub use self::array_boolean::BooleanArray;
 pub use self::array_dictionary::DictionaryArray;
+pub use self::array_dictionary::TypedDictionaryArray;
 pub use self::array_list::FixedSizeListArray;
 pub use self::array_list::LargeListArray;
 pub use self::array_list::ListArray;
+pub use self::array_map::MapArray;
 pub use self::array_primitive::PrimitiveArray;
 pub use self::array_string::LargeStringArray;
 pub use self::array_string::StringArray;
@@ -264,17 +270,27 @@ pub use self::iterator::*;
 
 // --------------------- Array Equality ---------------------
 
+pub use self::equal_approx::ArrayEq;
 pub use self::equal_json::JsonEqual;
+pub(crate) use self::equal::equal_at;
 
 // --------------------- Array's values comparison ---------------------
 
 pub use self::ord::{build_compare, DynComparator};
 
+// --------------------- Kernel arguments ---------------------
+
+pub use self::scalar::{Datum, Scalar};
+
+// --------------------- Conversion to Rust values ---------------------
+
+pub use self::to_vec::ToVec;
+
 // --------------------- Array downcast helper functions ---------------------
 
 pub use self::cast::{
     as_boolean_array, as_dictionary_array, as_generic_list_array, as_large_list_array,
-    as_largestring_array, as_list_array, as_null_array, as_primitive_array,
+    as_largestring_array, as_list_array, as_map_array, as_null_array, as_primitive_array,
     as_string_array, as_struct_array,
 };
 
@@ -39,75 +39,199 @@ fn cmp_nans_last<T: Float>(a: &T, b: &T) -> Ordering {
     }
 }
 
-fn compare_primitives<'a, T: ArrowPrimitiveType>(
-    left: &'a Array,
-    right: &'a Array,
-) -> DynComparator<'a>
+fn compare_primitives<T: ArrowPrimitiveType>(
+    left: &ArrayData,
+    right: &ArrayData,
+) -> DynComparator<'static>
 where
     T::Native: Ord,
 {
-    let left = left.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
-    let right = right.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let left = PrimitiveArray::<T>::from(left.clone());
+    let right = PrimitiveArray::<T>::from(right.clone());
     Box::new(move |i, j| left.value(i).cmp(&right.value(j)))
 }
 
-fn compare_boolean<'a>(left: &'a Array, right: &'a Array) -> DynComparator<'a> {
-    let left = left.as_any().downcast_ref::<BooleanArray>().unwrap();
-    let right = right.as_any().downcast_ref::<BooleanArray>().unwrap();
+fn compare_boolean(left: &ArrayData, right: &ArrayData) -> DynComparator<'static> {
+    let left = BooleanArray::from(left.clone());
+    let right = BooleanArray::from(right.clone());
     Box::new(move |i, j| left.value(i).cmp(&right.value(j)))
 }
 
-fn compare_float<'a, T: ArrowPrimitiveType>(
-    left: &'a Array,
-    right: &'a Array,
-) -> DynComparator<'a>
+fn compare_float<T: ArrowPrimitiveType>(
+    left: &ArrayData,
+    right: &ArrayData,
+) -> DynComparator<'static>
 where
     T::Native: Float,
 {
-    let left = left.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
-    let right = right.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let left = PrimitiveArray::<T>::from(left.clone());
+    let right = PrimitiveArray::<T>::from(right.clone());
     Box::new(move |i, j| cmp_nans_last(&left.value(i), &right.value(j)))
 }
 
-fn compare_string<'a, T>(left: &'a Array, right: &'a Array) -> DynComparator<'a>
+fn compare_decimal(left: &ArrayData, right: &ArrayData) -> DynComparator<'static> {
+    let left = DecimalArray::from(left.clone());
+    let right = DecimalArray::from(right.clone());
+    Box::new(move |i, j| left.value(i).cmp(&right.value(j)))
+}
+
+fn compare_string<T>(left: &ArrayData, right: &ArrayData) -> DynComparator<'static>
 where
     T: StringOffsetSizeTrait,
 {
-    let left = left
-        .as_any()
-        .downcast_ref::<GenericStringArray<T>>()
-        .unwrap();
-    let right = right
-        .as_any()
-        .downcast_ref::<GenericStringArray<T>>()
-        .unwrap();
+    let left = GenericStringArray::<T>::from(left.clone());
+    let right = GenericStringArray::<T>::from(right.clone());
     Box::new(move |i, j| left.value(i).cmp(&right.value(j)))
 }
 
-fn compare_dict_string<'a, T>(left: &'a Array, right: &'a Array) -> DynComparator<'a>
+fn compare_binary<T>(left: &ArrayData, right: &ArrayData) -> DynComparator<'static>
+where
+    T: BinaryOffsetSizeTrait,
+{
+    let left = GenericBinaryArray::<T>::from(left.clone());
+    let right = GenericBinaryArray::<T>::from(right.clone());
+    Box::new(move |i, j| left.value(i).cmp(&right.value(j)))
+}
+
+fn compare_fixed_size_binary(
+    left: &ArrayData,
+    right: &ArrayData,
+) -> DynComparator<'static> {
+    let left = FixedSizeBinaryArray::from(left.clone());
+    let right = FixedSizeBinaryArray::from(right.clone());
+    Box::new(move |i, j| left.value(i).cmp(&right.value(j)))
+}
+
+fn compare_dict<T>(left: &ArrayData, right: &ArrayData) -> Result<DynComparator<'static>>
 where
     T: ArrowDictionaryKeyType,
 {
-    let left = left.as_any().downcast_ref::<DictionaryArray<T>>().unwrap();
-    let right = right.as_any().downcast_ref::<DictionaryArray<T>>().unwrap();
+    let left = DictionaryArray::<T>::from(left.clone());
+    let right = DictionaryArray::<T>::from(right.clone());
     let left_keys = left.keys_array();
     let right_keys = right.keys_array();
 
-    let left_values = StringArray::from(left.values().data().clone());
-    let right_values = StringArray::from(right.values().data().clone());
+    let values = compare_nullable(left.values().data_ref(), right.values().data_ref())?;
 
-    Box::new(move |i: usize, j: usize| {
+    Ok(Box::new(move |i: usize, j: usize| {
         let key_left = left_keys.value(i).to_usize().unwrap();
         let key_right = right_keys.value(j).to_usize().unwrap();
-        let left = left_values.value(key_left);
-        let right = right_values.value(key_right);
-        left.cmp(&right)
-    })
+        (values)(key_left, key_right)
+    }))
+}
+
+/// compares two ranges of values, given as `(start, len)`, element-wise, falling back to
+/// comparing their lengths when all common values are equal.
+fn compare_ranges(
+    values: &DynComparator,
+    left: (usize, usize),
+    right: (usize, usize),
+) -> Ordering {
+    let (left_start, left_len) = left;
+    let (right_start, right_len) = right;
+    (0..left_len.min(right_len))
+        .map(|k| (values)(left_start + k, right_start + k))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or_else(|| left_len.cmp(&right_len))
+}
+
+fn compare_list<T>(left: &ArrayData, right: &ArrayData) -> Result<DynComparator<'static>>
+where
+    T: OffsetSizeTrait,
+{
+    let values = compare_nullable(&left.child_data()[0], &right.child_data()[0])?;
+    let left = left.clone();
+    let right = right.clone();
+
+    Ok(Box::new(move |i, j| {
+        let left_offsets = left.buffer::<T>(0);
+        let right_offsets = right.buffer::<T>(0);
+        let left_start = left_offsets[i].to_usize().unwrap();
+        let left_end = left_offsets[i + 1].to_usize().unwrap();
+        let right_start = right_offsets[j].to_usize().unwrap();
+        let right_end = right_offsets[j + 1].to_usize().unwrap();
+        compare_ranges(
+            &values,
+            (left_start, left_end - left_start),
+            (right_start, right_end - right_start),
+        )
+    }))
+}
+
+fn compare_fixed_size_list(
+    left: &ArrayData,
+    right: &ArrayData,
+    size: usize,
+) -> Result<DynComparator<'static>> {
+    let values = compare_nullable(&left.child_data()[0], &right.child_data()[0])?;
+    let left_offset = left.offset();
+    let right_offset = right.offset();
+
+    Ok(Box::new(move |i, j| {
+        compare_ranges(
+            &values,
+            ((left_offset + i) * size, size),
+            ((right_offset + j) * size, size),
+        )
+    }))
+}
+
+fn compare_struct(left: &ArrayData, right: &ArrayData) -> Result<DynComparator<'static>> {
+    // the children of a struct are not sliced together with it
+    let fields = left
+        .child_data()
+        .iter()
+        .zip(right.child_data())
+        .map(|(l, r)| {
+            compare_nullable(
+                &l.slice(left.offset(), left.len()),
+                &r.slice(right.offset(), right.len()),
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Box::new(move |i, j| {
+        fields
+            .iter()
+            .map(|field| (field)(i, j))
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
+/// returns a comparison function that, unlike [build_compare], also compares null values,
+/// placing them before any valid value. This is used for the values nested in other arrays,
+/// whose validity is not checked by the callers of [build_compare].
+fn compare_nullable(
+    left: &ArrayData,
+    right: &ArrayData,
+) -> Result<DynComparator<'static>> {
+    let values = compare_data(left, right)?;
+    if left.null_count() == 0 && right.null_count() == 0 {
+        return Ok(values);
+    }
+    let left = left.clone();
+    let right = right.clone();
+
+    Ok(Box::new(move |i, j| {
+        match (left.is_valid(i), right.is_valid(j)) {
+            (true, true) => (values)(i, j),
+            (false, true) => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (false, false) => Ordering::Equal,
+        }
+    }))
 }
 
 /// returns a comparison function that compares two values at two different positions
 /// between the two arrays.
-/// The arrays' types must be equal.
+/// The arrays' types must be equal, except for the timezone of timestamps, since their
+/// values are always relative to UTC.
+///
+/// Nulls are not checked on the arrays themselves, and comparing a null slot returns
+/// an unspecified ordering; values nested in lists, structs and dictionaries are compared
+/// with nulls first.
+///
 /// # Example
 /// ```
 /// use arrow::array::{build_compare, Int32Array};
@@ -126,15 +250,32 @@ where
 // This is a factory of comparisons.
 // The lifetime 'a enforces that we cannot use the closure beyond any of the array's lifetime.
 pub fn build_compare<'a>(left: &'a Array, right: &'a Array) -> Result<DynComparator<'a>> {
+    compare_data(left.data_ref(), right.data_ref())
+}
+
+fn compare_data(left: &ArrayData, right: &ArrayData) -> Result<DynComparator<'static>> {
     use DataType::*;
     use IntervalUnit::*;
     use TimeUnit::*;
     Ok(match (left.data_type(), right.data_type()) {
+        (Timestamp(Second, _), Timestamp(Second, _)) => {
+            compare_primitives::<TimestampSecondType>(left, right)
+        }
+        (Timestamp(Millisecond, _), Timestamp(Millisecond, _)) => {
+            compare_primitives::<TimestampMillisecondType>(left, right)
+        }
+        (Timestamp(Microsecond, _), Timestamp(Microsecond, _)) => {
+            compare_primitives::<TimestampMicrosecondType>(left, right)
+        }
+        (Timestamp(Nanosecond, _), Timestamp(Nanosecond, _)) => {
+            compare_primitives::<TimestampNanosecondType>(left, right)
+        }
         (a, b) if a != b => {
             return Err(ArrowError::InvalidArgumentError(
                 "Can't compare arrays of different types".to_string(),
             ));
         }
+        (Null, Null) => Box::new(|_, _| Ordering::Equal),
         (Boolean, Boolean) => compare_boolean(left, right),
         (UInt8, UInt8) => compare_primitives::<UInt8Type>(left, right),
         (UInt16, UInt16) => compare_primitives::<UInt16Type>(left, right),
@@ -146,6 +287,7 @@ pub fn build_compare<'a>(left: &'a Array, right: &'a Array) -> Result<DynCompara
         (Int64, Int64) => compare_primitives::<Int64Type>(left, right),
         (Float32, Float32) => compare_float::<Float32Type>(left, right),
         (Float64, Float64) => compare_float::<Float64Type>(left, right),
+        (Decimal(_, _), Decimal(_, _)) => compare_decimal(left, right),
         (Date32, Date32) => compare_primitives::<Date32Type>(left, right),
         (Date64, Date64) => compare_primitives::<Date64Type>(left, right),
         (Time32(Second), Time32(Second)) => {
@@ -160,18 +302,6 @@ pub fn build_compare<'a>(left: &'a Array, right: &'a Array) -> Result<DynCompara
         (Time64(Nanosecond), Time64(Nanosecond)) => {
             compare_primitives::<Time64NanosecondType>(left, right)
         }
-        (Timestamp(Second, _), Timestamp(Second, _)) => {
-            compare_primitives::<TimestampSecondType>(left, right)
-        }
-        (Timestamp(Millisecond, _), Timestamp(Millisecond, _)) => {
-            compare_primitives::<TimestampMillisecondType>(left, right)
-        }
-        (Timestamp(Microsecond, _), Timestamp(Microsecond, _)) => {
-            compare_primitives::<TimestampMicrosecondType>(left, right)
-        }
-        (Timestamp(Nanosecond, _), Timestamp(Nanosecond, _)) => {
-            compare_primitives::<TimestampNanosecondType>(left, right)
-        }
         (Interval(YearMonth), Interval(YearMonth)) => {
             compare_primitives::<IntervalYearMonthType>(left, right)
         }
@@ -192,32 +322,27 @@ pub fn build_compare<'a>(left: &'a Array, right: &'a Array) -> Result<DynCompara
         }
         (Utf8, Utf8) => compare_string::<i32>(left, right),
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
-        (
-            Dictionary(key_type_lhs, value_type_lhs),
-            Dictionary(key_type_rhs, value_type_rhs),
-        ) => {
-            if value_type_lhs.as_ref() != &DataType::Utf8
-                || value_type_rhs.as_ref() != &DataType::Utf8
-            {
-                return Err(ArrowError::InvalidArgumentError(
-                    "Arrow still does not support comparisons of non-string dictionary arrays"
-                        .to_string(),
-                ));
-            }
+        (Binary, Binary) => compare_binary::<i32>(left, right),
+        (LargeBinary, LargeBinary) => compare_binary::<i64>(left, right),
+        (FixedSizeBinary(_), FixedSizeBinary(_)) => {
+            compare_fixed_size_binary(left, right)
+        }
+        (List(_), List(_)) | (Map(_, _), Map(_, _)) => compare_list::<i32>(left, right)?,
+        (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right)?,
+        (FixedSizeList(_, size), FixedSizeList(_, _)) => {
+            compare_fixed_size_list(left, right, *size as usize)?
+        }
+        (Struct(_), Struct(_)) => compare_struct(left, right)?,
+        (Dictionary(key_type_lhs, _), Dictionary(key_type_rhs, _)) => {
             match (key_type_lhs.as_ref(), key_type_rhs.as_ref()) {
-                (a, b) if a != b => {
-                    return Err(ArrowError::InvalidArgumentError(
-                        "Can't compare arrays of different types".to_string(),
-                    ));
-                }
-                (UInt8, UInt8) => compare_dict_string::<UInt8Type>(left, right),
-                (UInt16, UInt16) => compare_dict_string::<UInt16Type>(left, right),
-                (UInt32, UInt32) => compare_dict_string::<UInt32Type>(left, right),
-                (UInt64, UInt64) => compare_dict_string::<UInt64Type>(left, right),
-                (Int8, Int8) => compare_dict_string::<Int8Type>(left, right),
-                (Int16, Int16) => compare_dict_string::<Int16Type>(left, right),
-                (Int32, Int32) => compare_dict_string::<Int32Type>(left, right),
-                (Int64, Int64) => compare_dict_string::<Int64Type>(left, right),
+                (UInt8, UInt8) => compare_dict::<UInt8Type>(left, right)?,
+                (UInt16, UInt16) => compare_dict::<UInt16Type>(left, right)?,
+                (UInt32, UInt32) => compare_dict::<UInt32Type>(left, right)?,
+                (UInt64, UInt64) => compare_dict::<UInt64Type>(left, right)?,
+                (Int8, Int8) => compare_dict::<Int8Type>(left, right)?,
+                (Int16, Int16) => compare_dict::<Int16Type>(left, right)?,
+                (Int32, Int32) => compare_dict::<Int32Type>(left, right)?,
+                (Int64, Int64) => compare_dict::<Int64Type>(left, right)?,
                 (lhs, _) => {
                     return Err(ArrowError::InvalidArgumentError(format!(
                         "Dictionaries do not support keys of type {:?}",
@@ -239,9 +364,12 @@ pub fn build_compare<'a>(left: &'a Array, right: &'a Array) -> Result<DynCompara
 pub mod tests {
     use super::*;
     use crate::array::{Float64Array, Int32Array};
+    use crate::buffer::Buffer;
     use crate::error::Result;
     use std::cmp::Ordering;
+    use std::convert::TryFrom;
     use std::iter::FromIterator;
+    use std::sync::Arc;
 
     #[test]
     fn test_i32() -> Result<()> {
@@ -322,4 +450,137 @@ pub mod tests {
         assert_eq!(Ordering::Greater, (cmp)(1, 3));
         Ok(())
     }
+
+    #[test]
+    fn test_dict_non_string_values() -> Result<()> {
+        let values = Int32Array::from(vec![Some(30), None, Some(10)]);
+        let data = ArrayData::builder(DataType::Dictionary(
+            Box::new(DataType::Int8),
+            Box::new(DataType::Int32),
+        ))
+        .len(4)
+        .add_buffer(Buffer::from_slice_ref(&[0_i8, 1, 2, 0]))
+        .add_child_data(values.data().clone())
+        .build();
+        let array = DictionaryArray::<Int8Type>::from(data);
+
+        let cmp = build_compare(&array, &array)?;
+
+        assert_eq!(Ordering::Greater, (cmp)(0, 2));
+        assert_eq!(Ordering::Equal, (cmp)(0, 3));
+        // null values sort first
+        assert_eq!(Ordering::Less, (cmp)(1, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal() -> Result<()> {
+        let mut builder = DecimalBuilder::with_capacity(3, 10, 2);
+        builder.append_value(-200)?;
+        builder.append_value(150)?;
+        builder.append_value(150)?;
+        let array = builder.finish();
+
+        let cmp = build_compare(&array, &array)?;
+
+        assert_eq!(Ordering::Less, (cmp)(0, 1));
+        assert_eq!(Ordering::Equal, (cmp)(1, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_timezones() -> Result<()> {
+        let array1 = TimestampSecondArray::from_opt_vec(
+            vec![Some(1), Some(3)],
+            Some("+01:00".to_string()),
+        );
+        let array2 = TimestampSecondArray::from_opt_vec(vec![Some(2)], None);
+
+        let cmp = build_compare(&array1, &array2)?;
+
+        assert_eq!(Ordering::Less, (cmp)(0, 0));
+        assert_eq!(Ordering::Greater, (cmp)(1, 0));
+
+        let array3 = TimestampMillisecondArray::from_opt_vec(vec![Some(2)], None);
+        assert!(build_compare(&array1, &array3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary() -> Result<()> {
+        let array = BinaryArray::from(vec![&b"ab"[..], &b"b"[..], &b"a"[..]]);
+
+        let cmp = build_compare(&array, &array)?;
+
+        assert_eq!(Ordering::Less, (cmp)(0, 1));
+        assert_eq!(Ordering::Greater, (cmp)(0, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list() -> Result<()> {
+        let data = vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1), Some(2), Some(0)]),
+            Some(vec![None, Some(5)]),
+            Some(vec![]),
+            Some(vec![Some(1), Some(2)]),
+        ];
+        let array = ListArray::from_iter_primitive::<Int32Type, _, _>(data);
+
+        let cmp = build_compare(&array, &array)?;
+
+        // a prefix sorts first
+        assert_eq!(Ordering::Less, (cmp)(0, 1));
+        // nested nulls sort first
+        assert_eq!(Ordering::Less, (cmp)(2, 0));
+        assert_eq!(Ordering::Less, (cmp)(3, 2));
+        assert_eq!(Ordering::Equal, (cmp)(0, 4));
+
+        let sliced = array.slice(1, 4);
+        let cmp = build_compare(sliced.as_ref(), &array)?;
+        assert_eq!(Ordering::Equal, (cmp)(3, 0));
+        assert_eq!(Ordering::Greater, (cmp)(0, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct() -> Result<()> {
+        let strings: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("a"),
+            None,
+            Some("b"),
+        ]));
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![2, 1, 3, 0]));
+        let array = StructArray::try_from(vec![("s", strings), ("i", ints)])?;
+
+        let cmp = build_compare(&array, &array)?;
+
+        assert_eq!(Ordering::Greater, (cmp)(0, 1));
+        assert_eq!(Ordering::Less, (cmp)(2, 1));
+        assert_eq!(Ordering::Less, (cmp)(0, 3));
+
+        let sliced = array.slice(1, 3);
+        let cmp = build_compare(sliced.as_ref(), &array)?;
+        assert_eq!(Ordering::Equal, (cmp)(0, 1));
+        assert_eq!(Ordering::Equal, (cmp)(2, 3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_union_has_no_order() {
+        let array = UnionArray::try_new(
+            Buffer::from_slice_ref(&[0_i8]),
+            None,
+            vec![(
+                Field::new("a", DataType::Int32, false),
+                Arc::new(Int32Array::from(vec![1])) as ArrayRef,
+            )],
+            None,
+        )
+        .unwrap();
+
+        assert!(build_compare(&array, &array).is_err());
+    }
 }
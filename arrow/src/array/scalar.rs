@@ -0,0 +1,138 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains the [Datum] trait and the [Scalar] type, the arguments of kernels that accept
+//! either arrays or single values.
+
+use super::{Array, ArrayRef};
+
+/// An argument of a kernel that is either an array or a [Scalar], i.e. a single value
+/// applied to every element of the other arguments.
+///
+/// This is implemented for all arrays, [ArrayRef] and `&dyn Array`, which are never
+/// scalars, and for [Scalar].
+pub trait Datum {
+    /// Returns the array of this datum, and whether it is a scalar, in which case the
+    /// array has a single element.
+    fn get(&self) -> (&dyn Array, bool);
+}
+
+impl<T: Array> Datum for T {
+    fn get(&self) -> (&dyn Array, bool) {
+        (self, false)
+    }
+}
+
+impl Datum for dyn Array {
+    fn get(&self) -> (&dyn Array, bool) {
+        (self, false)
+    }
+}
+
+impl Datum for &dyn Array {
+    fn get(&self) -> (&dyn Array, bool) {
+        (*self, false)
+    }
+}
+
+impl Datum for ArrayRef {
+    fn get(&self) -> (&dyn Array, bool) {
+        (self.as_ref(), false)
+    }
+}
+
+/// A single value, stored as an array of one element of any type, that kernels accepting
+/// a [Datum] apply to every element of their other arguments. A null scalar is an array
+/// of one null element.
+///
+/// # Example
+///
+/// ```
+/// use arrow::array::{BooleanArray, Int32Array};
+/// use arrow::compute::kernels::cmp::lt;
+/// let array = Int32Array::from(vec![1, 2, 3]);
+/// let two = Int32Array::new_scalar(2);
+/// let less = lt(&array, &two).unwrap();
+/// assert_eq!(less, BooleanArray::from(vec![true, false, false]));
+/// // the scalar can be on either side
+/// let greater = lt(&two, &array).unwrap();
+/// assert_eq!(greater, BooleanArray::from(vec![false, false, true]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Scalar<T>(T);
+
+impl<T: Datum> Scalar<T> {
+    /// Creates a scalar from an array of one element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `array` is not 1.
+    pub fn new(array: T) -> Self {
+        let len = array.get().0.len();
+        assert_eq!(len, 1, "A scalar must have a single element, got {}", len);
+        Self(array)
+    }
+
+    /// Returns the array of one element of this scalar.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Datum> Datum for Scalar<T> {
+    fn get(&self) -> (&dyn Array, bool) {
+        (self.0.get().0, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::{new_null_array, Int32Array, StringArray};
+    use crate::datatypes::DataType;
+
+    #[test]
+    fn test_datum() {
+        let array = Int32Array::from(vec![1, 2]);
+        let (datum, is_scalar) = array.get();
+        assert_eq!(datum.len(), 2);
+        assert!(!is_scalar);
+
+        let array: ArrayRef = Arc::new(array);
+        let (datum, is_scalar) = array.get();
+        assert_eq!(datum.len(), 2);
+        assert!(!is_scalar);
+
+        let scalar = StringArray::new_scalar("a");
+        let (datum, is_scalar) = scalar.get();
+        assert_eq!(datum.data_type(), &DataType::Utf8);
+        assert!(is_scalar);
+
+        let scalar = Scalar::new(new_null_array(&DataType::Int32, 1));
+        let (datum, is_scalar) = scalar.get();
+        assert!(datum.is_null(0));
+        assert!(is_scalar);
+    }
+
+    #[test]
+    #[should_panic(expected = "A scalar must have a single element, got 2")]
+    fn test_scalar_of_many_elements() {
+        Scalar::new(Int32Array::from(vec![1, 2]));
+    }
+}
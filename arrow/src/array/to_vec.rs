@@ -0,0 +1,191 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains the [ToVec] trait, converting typed arrays to `Vec`s of owned Rust values.
+
+use crate::datatypes::ArrowPrimitiveType;
+use crate::error::{ArrowError, Result};
+
+use super::{
+    Array, BinaryOffsetSizeTrait, BooleanArray, FixedSizeBinaryArray, GenericBinaryArray,
+    GenericListArray, GenericStringArray, OffsetSizeTrait, PrimitiveArray,
+    StringOffsetSizeTrait,
+};
+
+/// Converts a typed array to a `Vec` of its values as owned Rust values, with `None` for
+/// the null values.
+///
+/// # Example
+/// ```
+/// use arrow::array::{Int32Array, StringArray, ToVec};
+///
+/// let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+/// assert_eq!(array.to_vec(), vec![Some(1), None, Some(3)]);
+///
+/// let array = StringArray::from(vec![Some("a"), None]);
+/// assert_eq!(array.to_vec(), vec![Some("a".to_string()), None]);
+/// ```
+pub trait ToVec {
+    /// The Rust type of the values of the array
+    type Item;
+
+    /// Returns the values of this array, `None` where it is null.
+    fn to_vec(&self) -> Vec<Option<Self::Item>>;
+}
+
+impl<T: ArrowPrimitiveType> ToVec for PrimitiveArray<T> {
+    type Item = T::Native;
+
+    fn to_vec(&self) -> Vec<Option<T::Native>> {
+        self.iter().collect()
+    }
+}
+
+impl ToVec for BooleanArray {
+    type Item = bool;
+
+    fn to_vec(&self) -> Vec<Option<bool>> {
+        self.iter().collect()
+    }
+}
+
+impl<OffsetSize: StringOffsetSizeTrait> ToVec for GenericStringArray<OffsetSize> {
+    type Item = String;
+
+    fn to_vec(&self) -> Vec<Option<String>> {
+        self.iter().map(|v| v.map(|v| v.to_string())).collect()
+    }
+}
+
+impl<OffsetSize: BinaryOffsetSizeTrait> ToVec for GenericBinaryArray<OffsetSize> {
+    type Item = Vec<u8>;
+
+    fn to_vec(&self) -> Vec<Option<Vec<u8>>> {
+        self.iter().map(|v| v.map(|v| v.to_vec())).collect()
+    }
+}
+
+impl ToVec for FixedSizeBinaryArray {
+    type Item = Vec<u8>;
+
+    fn to_vec(&self) -> Vec<Option<Vec<u8>>> {
+        (0..self.len())
+            .map(|i| {
+                if self.is_valid(i) {
+                    Some(self.value(i).to_vec())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+impl<OffsetSize: OffsetSizeTrait> GenericListArray<OffsetSize> {
+    /// Returns the lists of this array as `Vec`s of the values of the array of type `A` of
+    /// the lists, `None` where the array is null.
+    ///
+    /// Returns an error if the values of the lists are not an `A`.
+    ///
+    /// # Example
+    /// ```
+    /// use arrow::array::{Int32Array, ListArray};
+    /// use arrow::datatypes::Int32Type;
+    ///
+    /// let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+    ///     Some(vec![Some(1), None]),
+    ///     None,
+    ///     Some(vec![]),
+    /// ]);
+    /// assert_eq!(
+    ///     array.try_to_vec::<Int32Array>().unwrap(),
+    ///     vec![Some(vec![Some(1), None]), None, Some(vec![])]
+    /// );
+    /// ```
+    pub fn try_to_vec<A>(&self) -> Result<Vec<Option<Vec<Option<A::Item>>>>>
+    where
+        A: ToVec + Array + 'static,
+    {
+        let values = self.values();
+        if values.as_any().downcast_ref::<A>().is_none() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Can't convert the values of type {:?} of the lists to a Vec of {}",
+                values.data_type(),
+                std::any::type_name::<A>()
+            )));
+        }
+        Ok(self
+            .iter()
+            .map(|list| {
+                list.map(|list| list.as_any().downcast_ref::<A>().unwrap().to_vec())
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{
+        Float64Array, LargeBinaryArray, LargeListArray, LargeStringArray, ListArray,
+        StringArray,
+    };
+    use crate::datatypes::Int64Type;
+
+    #[test]
+    fn test_primitive_to_vec() {
+        let array = Float64Array::from(vec![Some(1.5), None]);
+        assert_eq!(array.to_vec(), vec![Some(1.5), None]);
+
+        let array = BooleanArray::from(vec![Some(true), None, Some(false)]);
+        assert_eq!(array.to_vec(), vec![Some(true), None, Some(false)]);
+    }
+
+    #[test]
+    fn test_string_binary_to_vec() {
+        let array = LargeStringArray::from(vec![None, Some("arrow")]);
+        assert_eq!(array.to_vec(), vec![None, Some("arrow".to_string())]);
+
+        let array = LargeBinaryArray::from(vec![Some(&b"ab"[..]), None]);
+        assert_eq!(array.to_vec(), vec![Some(b"ab".to_vec()), None]);
+
+        let array = FixedSizeBinaryArray::try_from_sparse_iter(
+            vec![Some(vec![1u8, 2]), None].into_iter(),
+        )
+        .unwrap();
+        assert_eq!(array.to_vec(), vec![Some(vec![1, 2]), None]);
+    }
+
+    #[test]
+    fn test_list_to_vec() {
+        let array = LargeListArray::from_iter_primitive::<Int64Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![None]),
+            None,
+        ]);
+        let sliced = array.slice(1, 2);
+        let sliced = sliced.as_any().downcast_ref::<LargeListArray>().unwrap();
+        assert_eq!(
+            sliced.try_to_vec::<PrimitiveArray<Int64Type>>().unwrap(),
+            vec![Some(vec![None]), None]
+        );
+
+        let array =
+            ListArray::from_iter_primitive::<Int64Type, _, _>(vec![Some(vec![Some(1)])]);
+        assert!(array.try_to_vec::<StringArray>().is_err());
+    }
+}
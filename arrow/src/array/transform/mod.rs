@@ -261,7 +261,7 @@ fn build_extend(array: &ArrayData) -> Extend {
         DataType::LargeUtf8 | DataType::LargeBinary => {
             variable_size::build_extend::<i64>(array)
         }
-        DataType::List(_) => list::build_extend::<i32>(array),
+        DataType::List(_) | DataType::Map(_, _) => list::build_extend::<i32>(array),
         DataType::LargeList(_) => list::build_extend::<i64>(array),
         DataType::Dictionary(_, _) => unreachable!("should use build_extend_dictionary"),
         DataType::Struct(_) => structure::build_extend(array),
@@ -300,7 +300,7 @@ fn build_extend_nulls(data_type: &DataType) -> ExtendNulls {
         | DataType::Interval(IntervalUnit::DayTime) => primitive::extend_nulls::<i64>,
         DataType::Utf8 | DataType::Binary => variable_size::extend_nulls::<i32>,
         DataType::LargeUtf8 | DataType::LargeBinary => variable_size::extend_nulls::<i64>,
-        DataType::List(_) => list::extend_nulls::<i32>,
+        DataType::List(_) | DataType::Map(_, _) => list::extend_nulls::<i32>,
         DataType::LargeList(_) => list::extend_nulls::<i64>,
         DataType::Dictionary(child_data_type, _) => match child_data_type.as_ref() {
             DataType::UInt8 => primitive::extend_nulls::<u8>,
@@ -368,7 +368,7 @@ impl<'a> MutableArrayData<'a> {
             | DataType::LargeBinary
             | DataType::Interval(_)
             | DataType::FixedSizeBinary(_) => vec![],
-            DataType::List(_) | DataType::LargeList(_) => {
+            DataType::List(_) | DataType::LargeList(_) | DataType::Map(_, _) => {
                 let childs = arrays
                     .iter()
                     .map(|array| &array.child_data()[0])
@@ -479,6 +479,8 @@ impl<'a> MutableArrayData<'a> {
 
     /// Extends this [MutableArrayData] with null elements, disregarding the bound arrays
     pub fn extend_nulls(&mut self, len: usize) {
+        // the buffer is zero-filled, so the null bits of the new elements are unset
+        utils::resize_for_bits(&mut self.data.null_buffer, self.data.len + len);
         self.data.null_count += len;
         (self.extend_nulls)(&mut self.data, len);
         self.data.len += len;
@@ -500,8 +502,8 @@ mod tests {
         array::{
             Array, ArrayData, ArrayRef, BooleanArray, DictionaryArray,
             FixedSizeBinaryArray, Int16Array, Int16Type, Int32Array, Int64Array,
-            Int64Builder, ListBuilder, NullArray, PrimitiveBuilder, StringArray,
-            StringDictionaryBuilder, StructArray, UInt8Array,
+            Int64Builder, LargeStringArray, ListBuilder, NullArray, PrimitiveBuilder,
+            StringArray, StringDictionaryBuilder, StructArray, UInt8Array,
         },
         buffer::Buffer,
         datatypes::Field,
@@ -1090,7 +1092,7 @@ mod tests {
     #[test]
     fn test_list_of_strings_append() -> Result<()> {
         // [["alpha", "beta", None]]
-        let mut builder = ListBuilder::new(StringBuilder::new(32));
+        let mut builder = ListBuilder::new(StringBuilder::default());
         builder.values().append_value("Hello")?;
         builder.values().append_value("Arrow")?;
         builder.values().append_null()?;
@@ -1098,7 +1100,7 @@ mod tests {
         let a = builder.finish();
 
         // [["alpha", "beta"], [None], ["gamma", "delta", None]]
-        let mut builder = ListBuilder::new(StringBuilder::new(32));
+        let mut builder = ListBuilder::new(StringBuilder::default());
         builder.values().append_value("alpha")?;
         builder.values().append_value("beta")?;
         builder.append(true)?;
@@ -1208,6 +1210,172 @@ mod tests {
         assert_eq!(&result, expected.data());
     }
 
+    /// Extends a [MutableArrayData] from every range of every slice of `array`, alone and
+    /// after another slice, and compares the result with extending it from the same
+    /// ranges of `array` itself.
+    fn assert_extend_from_slices(array: &ArrayRef) {
+        let len = array.len();
+        for offset in 0..=len {
+            for sliced_len in 0..=len - offset {
+                let sliced = array.slice(offset, sliced_len);
+                for start in 0..=sliced_len {
+                    for end in start..=sliced_len {
+                        let mut mutable =
+                            MutableArrayData::new(vec![sliced.data()], false, 0);
+                        mutable.extend(0, start, end);
+                        let result = mutable.freeze();
+
+                        let mut mutable =
+                            MutableArrayData::new(vec![array.data()], false, 0);
+                        mutable.extend(0, offset + start, offset + end);
+                        let expected = mutable.freeze();
+
+                        assert_eq!(
+                            result, expected,
+                            "{:?} sliced at {} with length {}, extended from {} to {}",
+                            array, offset, sliced_len, start, end
+                        );
+
+                        let mut mutable = MutableArrayData::new(
+                            vec![sliced.data(), array.data()],
+                            true,
+                            0,
+                        );
+                        mutable.extend(1, 1, len);
+                        mutable.extend(0, start, end);
+                        mutable.extend_nulls(1);
+                        let result = mutable.freeze();
+
+                        let mut mutable =
+                            MutableArrayData::new(vec![array.data()], true, 0);
+                        mutable.extend(0, 1, len);
+                        mutable.extend(0, offset + start, offset + end);
+                        mutable.extend_nulls(1);
+                        let expected = mutable.freeze();
+
+                        assert_eq!(
+                            result, expected,
+                            "{:?} sliced at {} with length {}, extended from {} to {} after another array",
+                            array, offset, sliced_len, start, end
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_extend_from_slices() -> Result<()> {
+        let primitive: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(1),
+            None,
+            Some(3),
+            Some(4),
+            None,
+            None,
+            Some(7),
+            Some(8),
+            Some(9),
+        ]));
+        assert_extend_from_slices(&primitive);
+
+        let boolean: ArrayRef = Arc::new(BooleanArray::from(vec![
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            Some(true),
+            None,
+            Some(false),
+            Some(false),
+            Some(true),
+        ]));
+        assert_extend_from_slices(&boolean);
+
+        let values = vec![
+            Some("a"),
+            None,
+            Some(""),
+            Some("bcd"),
+            None,
+            Some("e"),
+            Some("fg"),
+            None,
+            Some("hij"),
+        ];
+        let string: ArrayRef = Arc::new(StringArray::from(values.clone()));
+        assert_extend_from_slices(&string);
+        let large_string: ArrayRef = Arc::new(LargeStringArray::from(values));
+        assert_extend_from_slices(&large_string);
+
+        let fixed_size_binary: ArrayRef =
+            Arc::new(FixedSizeBinaryArray::try_from_sparse_iter(
+                vec![
+                    Some(vec![1, 2]),
+                    None,
+                    Some(vec![3, 4]),
+                    Some(vec![5, 6]),
+                    None,
+                    Some(vec![7, 8]),
+                ]
+                .into_iter(),
+            )?);
+        assert_extend_from_slices(&fixed_size_binary);
+
+        let mut builder = ListBuilder::<Int64Builder>::new(Int64Builder::new(16));
+        builder.values().append_slice(&[1, 2])?;
+        builder.append(true)?;
+        builder.append(false)?;
+        builder.values().append_null()?;
+        builder.values().append_value(3)?;
+        builder.append(true)?;
+        builder.append(true)?;
+        builder.values().append_slice(&[4, 5, 6])?;
+        builder.append(true)?;
+        builder.values().append_value(7)?;
+        builder.append(false)?;
+        builder.values().append_value(8)?;
+        builder.append(true)?;
+        let list: ArrayRef = Arc::new(builder.finish());
+        assert_extend_from_slices(&list);
+
+        let strings: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("joe"),
+            None,
+            None,
+            Some("mark"),
+            Some("doe"),
+            Some("ann"),
+            None,
+        ]));
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![
+            Some(1),
+            Some(2),
+            None,
+            Some(4),
+            Some(5),
+            None,
+            Some(7),
+        ]));
+        let structure: ArrayRef = Arc::new(StructArray::from((
+            vec![
+                (Field::new("f1", DataType::Utf8, true), strings),
+                (Field::new("f2", DataType::Int32, true), ints),
+            ],
+            Buffer::from(&[0b01011011]),
+        )));
+        assert_extend_from_slices(&structure);
+
+        let dictionary: ArrayRef =
+            Arc::new(DictionaryArray::<Int16Type>::from(create_dictionary_array(
+                &["a", "b", "c"],
+                &[Some("a"), Some("b"), None, Some("c"), Some("a"), None],
+            )));
+        assert_extend_from_slices(&dictionary);
+
+        Ok(())
+    }
+
     /*
     // this is an old test used on a meanwhile removed dead code
     // that is still useful when `MutableArrayData` supports fixed-size lists.
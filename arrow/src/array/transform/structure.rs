@@ -41,8 +41,11 @@ pub(super) fn build_extend(array: &ArrayData) -> Extend {
                   index: usize,
                   start: usize,
                   len: usize| {
-                (array.offset() + start..array.offset() + start + len).for_each(|i| {
+                (start..start + len).for_each(|i| {
+                    // `is_valid` accounts for the offset of the array, but the child
+                    // arrays are not sliced with it
                     if array.is_valid(i) {
+                        let i = array.offset() + i;
                         mutable
                             .child_data
                             .iter_mut()
@@ -0,0 +1,29 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Transfer data from Avro Object Container Files to the Arrow memory format.
+//! See the module level documentation of the [`reader`] for usage examples,
+//! and of the [`schema`] for the conversion of Avro schemas to Arrow.
+//!
+//! Note this module is not available unless `feature = "avro"` is enabled.
+
+pub mod reader;
+pub mod schema;
+
+pub use self::reader::Reader;
+pub use self::reader::ReaderBuilder;
+pub use self::schema::{to_arrow_field, to_arrow_schema};
@@ -0,0 +1,796 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! # Avro Reader
+//!
+//! This Avro reader allows Avro Object Container Files to be read into the Arrow memory
+//! model. The schema of the batches is converted from the schema of the file, see
+//! [`schema`](super::schema). Records are loaded in batches and are then converted from
+//! row-based data to columnar data.
+//!
+//! Example:
+//!
+//! ```no_run
+//! use arrow::avro;
+//! use std::fs::File;
+//!
+//! let file = File::open("test/data/example.avro").unwrap();
+//!
+//! let mut avro = avro::ReaderBuilder::new()
+//!     .with_batch_size(1024)
+//!     .build(file)
+//!     .unwrap();
+//! let batch = avro.next().unwrap().unwrap();
+//! ```
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::io::Read;
+use std::sync::Arc;
+
+use avro_rs::schema::UnionSchema;
+use avro_rs::types::Value;
+use avro_rs::Schema as AvroSchema;
+
+use crate::array::*;
+use crate::buffer::Buffer;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+use super::schema::{nullable_union_variant, to_arrow_field, union_variant_name};
+
+/// The value of the fields missing from a record
+static NULL: Value = Value::Null;
+
+/// Avro file reader
+pub struct Reader<R: Read> {
+    reader: avro_rs::Reader<'static, R>,
+    /// The schema of the records of the file
+    avro_schema: AvroSchema,
+    /// The schema of the batches, with the projected fields of the records
+    schema: SchemaRef,
+    /// The indices of the projected fields of the records
+    projection: Vec<usize>,
+    batch_size: usize,
+}
+
+impl<R: Read> fmt::Debug for Reader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Reader")
+            .field("schema", &self.schema)
+            .field("projection", &self.projection)
+            .field("batch_size", &self.batch_size)
+            .finish()
+    }
+}
+
+impl<R: Read> Reader<R> {
+    /// Create a new Avro Reader from any value that implements the `Read` trait,
+    /// reading the header of the file.
+    ///
+    /// The names of the fields to read can be set with `projection`, all the fields
+    /// are read if `None`.
+    pub fn new(
+        reader: R,
+        batch_size: usize,
+        projection: Option<Vec<String>>,
+    ) -> Result<Self> {
+        let reader = avro_rs::Reader::new(reader)?;
+        let avro_schema = reader.writer_schema().clone();
+        let fields = match &avro_schema {
+            AvroSchema::Record { fields, .. } => fields,
+            _ => {
+                return Err(ArrowError::AvroError(
+                    "The schema of the records of an Avro file must be a record"
+                        .to_string(),
+                ))
+            }
+        };
+
+        let projection = match projection {
+            Some(names) => names
+                .iter()
+                .map(|name| {
+                    fields
+                        .iter()
+                        .position(|field| &field.name == name)
+                        .ok_or_else(|| {
+                            ArrowError::AvroError(format!(
+                                "The Avro file has no field named {}",
+                                name
+                            ))
+                        })
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => (0..fields.len()).collect(),
+        };
+        let schema = Schema::new(
+            projection
+                .iter()
+                .map(|i| to_arrow_field(&fields[*i].name, &fields[*i].schema))
+                .collect::<Result<Vec<_>>>()?,
+        );
+
+        Ok(Self {
+            reader,
+            avro_schema,
+            schema: Arc::new(schema),
+            projection,
+            batch_size,
+        })
+    }
+
+    /// Returns the schema of the reader, useful for getting the schema without reading
+    /// record batches
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Read the next batch of records
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<Option<RecordBatch>> {
+        let rows = (&mut self.reader)
+            .take(self.batch_size)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        if rows.is_empty() {
+            return Ok(None);
+        }
+
+        let fields = match &self.avro_schema {
+            AvroSchema::Record { fields, .. } => fields,
+            _ => unreachable!(),
+        };
+        let rows = rows.iter().collect::<Vec<_>>();
+        let columns = self
+            .projection
+            .iter()
+            .map(|i| {
+                let field = &fields[*i];
+                let values = rows
+                    .iter()
+                    .map(|row| record_field(row, *i, &field.name))
+                    .collect::<Result<Vec<_>>>()?;
+                build_array(&field.schema, &values)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        RecordBatch::try_new(self.schema.clone(), columns).map(Some)
+    }
+}
+
+/// Avro file reader builder
+#[derive(Debug)]
+pub struct ReaderBuilder {
+    /// Batch size (number of records to load each time)
+    ///
+    /// The default batch size when using the `ReaderBuilder` is 1024 records
+    batch_size: usize,
+    /// Optional projection for which fields of the records to load
+    projection: Option<Vec<String>>,
+}
+
+impl Default for ReaderBuilder {
+    fn default() -> Self {
+        Self {
+            batch_size: 1024,
+            projection: None,
+        }
+    }
+}
+
+impl ReaderBuilder {
+    /// Create a new builder for configuring Avro reading options.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use arrow::avro;
+    /// use std::fs::File;
+    ///
+    /// fn example() -> avro::Reader<File> {
+    ///     let file = File::open("test/data/example.avro").unwrap();
+    ///
+    ///     // create a builder, reading only the `id` field of the records
+    ///     let builder = avro::ReaderBuilder::new().with_projection(vec!["id".to_string()]);
+    ///
+    ///     builder.build(file).unwrap()
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the batch size (number of records to load at one time)
+    pub fn with_batch_size(mut self, batch_size: usize) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    /// Set the reader's projection, the names of the fields of the records to load
+    pub fn with_projection(mut self, projection: Vec<String>) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    /// Create a new `Reader` from the `ReaderBuilder`
+    pub fn build<R: Read>(self, source: R) -> Result<Reader<R>> {
+        Reader::new(source, self.batch_size, self.projection)
+    }
+}
+
+/// Returns the value of the field at index `i`, named `name`, of a record, or null if
+/// the record is null or has no such field
+fn record_field<'a>(record: &'a Value, i: usize, name: &str) -> Result<&'a Value> {
+    match unwrap_union(record) {
+        Value::Record(fields) => Ok(fields
+            .get(i)
+            .filter(|(field_name, _)| field_name == name)
+            .or_else(|| fields.iter().find(|(field_name, _)| field_name == name))
+            .map(|(_, value)| value)
+            .unwrap_or(&NULL)),
+        Value::Null => Ok(&NULL),
+        other => Err(unexpected_value("record", other)),
+    }
+}
+
+/// Returns the value of a variant of a union
+fn unwrap_union(value: &Value) -> &Value {
+    match value {
+        Value::Union(value) => unwrap_union(value),
+        value => value,
+    }
+}
+
+fn unexpected_value(expected: &str, value: &Value) -> ArrowError {
+    ArrowError::AvroError(format!("Expected an Avro {}, got {:?}", expected, value))
+}
+
+/// Converts the Avro values of type `expected` with `convert`, returning `None` for
+/// nulls and an error for values of other types
+fn convert_values<'a, T>(
+    expected: &str,
+    values: &[&'a Value],
+    convert: impl Fn(&'a Value) -> Option<T>,
+) -> Result<Vec<Option<T>>> {
+    values
+        .iter()
+        .map(|&value| match unwrap_union(value) {
+            Value::Null => Ok(None),
+            value => convert(value)
+                .map(Some)
+                .ok_or_else(|| unexpected_value(expected, value)),
+        })
+        .collect()
+}
+
+fn build_primitive_array<T: ArrowPrimitiveType>(
+    expected: &str,
+    values: &[&Value],
+    convert: impl Fn(&Value) -> Option<T::Native>,
+) -> Result<ArrayRef> {
+    let values = convert_values(expected, values, convert)?;
+    Ok(Arc::new(values.into_iter().collect::<PrimitiveArray<T>>()))
+}
+
+/// Returns a validity bitmap of `values`, which are valid if they are not null
+fn null_buffer(values: &[&Value]) -> Buffer {
+    let mut nulls = BooleanBufferBuilder::new(values.len());
+    for value in values {
+        nulls.append(!matches!(unwrap_union(value), Value::Null));
+    }
+    nulls.finish()
+}
+
+/// Builds an array of the Avro values of type `schema`
+fn build_array(schema: &AvroSchema, values: &[&Value]) -> Result<ArrayRef> {
+    match schema {
+        AvroSchema::Null => Ok(Arc::new(NullArray::new(values.len()))),
+        AvroSchema::Boolean => {
+            let values = convert_values("boolean", values, |value| match value {
+                Value::Boolean(v) => Some(*v),
+                _ => None,
+            })?;
+            Ok(Arc::new(BooleanArray::from(values)))
+        }
+        AvroSchema::Int => {
+            build_primitive_array::<Int32Type>("int", values, |value| match value {
+                Value::Int(v) => Some(*v),
+                _ => None,
+            })
+        }
+        AvroSchema::Long => {
+            build_primitive_array::<Int64Type>("long", values, |value| match value {
+                Value::Long(v) => Some(*v),
+                Value::Int(v) => Some(*v as i64),
+                _ => None,
+            })
+        }
+        AvroSchema::Float => {
+            build_primitive_array::<Float32Type>("float", values, |value| match value {
+                Value::Float(v) => Some(*v),
+                _ => None,
+            })
+        }
+        AvroSchema::Double => {
+            build_primitive_array::<Float64Type>("double", values, |value| match value {
+                Value::Double(v) => Some(*v),
+                Value::Float(v) => Some(*v as f64),
+                _ => None,
+            })
+        }
+        AvroSchema::Date => {
+            build_primitive_array::<Date32Type>("date", values, |value| match value {
+                Value::Date(v) | Value::Int(v) => Some(*v),
+                _ => None,
+            })
+        }
+        AvroSchema::TimeMillis => build_primitive_array::<Time32MillisecondType>(
+            "time-millis",
+            values,
+            |value| match value {
+                Value::TimeMillis(v) | Value::Int(v) => Some(*v),
+                _ => None,
+            },
+        ),
+        AvroSchema::TimeMicros => build_primitive_array::<Time64MicrosecondType>(
+            "time-micros",
+            values,
+            |value| match value {
+                Value::TimeMicros(v) | Value::Long(v) => Some(*v),
+                _ => None,
+            },
+        ),
+        AvroSchema::TimestampMillis => build_primitive_array::<TimestampMillisecondType>(
+            "timestamp-millis",
+            values,
+            |value| match value {
+                Value::TimestampMillis(v) | Value::Long(v) => Some(*v),
+                _ => None,
+            },
+        ),
+        AvroSchema::TimestampMicros => build_primitive_array::<TimestampMicrosecondType>(
+            "timestamp-micros",
+            values,
+            |value| match value {
+                Value::TimestampMicros(v) | Value::Long(v) => Some(*v),
+                _ => None,
+            },
+        ),
+        AvroSchema::String => {
+            let values = convert_values("string", values, |value| match value {
+                Value::String(v) => Some(v.as_str()),
+                _ => None,
+            })?;
+            Ok(Arc::new(StringArray::from(values)))
+        }
+        AvroSchema::Uuid => {
+            let values = convert_values("uuid", values, |value| match value {
+                Value::Uuid(v) => Some(v.to_string()),
+                Value::String(v) => Some(v.clone()),
+                _ => None,
+            })?;
+            Ok(Arc::new(values.into_iter().collect::<StringArray>()))
+        }
+        AvroSchema::Bytes => {
+            let values = convert_values("bytes", values, |value| match value {
+                Value::Bytes(v) => Some(v.as_slice()),
+                _ => None,
+            })?;
+            Ok(Arc::new(BinaryArray::from(values)))
+        }
+        AvroSchema::Fixed { size, .. } => {
+            let values = convert_values("fixed", values, |value| match value {
+                Value::Fixed(_, v) => Some(v.as_slice()),
+                _ => None,
+            })?;
+            Ok(Arc::new(
+                FixedSizeBinaryArray::try_from_sparse_iter_with_size(
+                    values.into_iter(),
+                    *size as i32,
+                )?,
+            ))
+        }
+        AvroSchema::Enum { .. } => {
+            let values = convert_values("enum", values, |value| match value {
+                Value::Enum(_, v) => Some(v.as_str()),
+                _ => None,
+            })?;
+            Ok(Arc::new(
+                values.into_iter().collect::<DictionaryArray<Int32Type>>(),
+            ))
+        }
+        AvroSchema::Decimal {
+            precision, scale, ..
+        } => {
+            let values = convert_values("decimal", values, |value| match value {
+                Value::Decimal(v) => Vec::<u8>::try_from(v).ok(),
+                Value::Bytes(v) | Value::Fixed(_, v) => Some(v.clone()),
+                _ => None,
+            })?;
+            let mut builder =
+                DecimalBuilder::with_capacity(values.len(), *precision, *scale);
+            for value in values {
+                match value {
+                    Some(bytes) => builder.append_value(decimal_to_i128(&bytes)?)?,
+                    None => builder.append_null()?,
+                }
+            }
+            Ok(Arc::new(builder.finish()))
+        }
+        AvroSchema::Array(items) => {
+            let lists = convert_values("array", values, |value| match value {
+                Value::Array(v) => Some(v),
+                _ => None,
+            })?;
+            let mut offsets = Vec::with_capacity(lists.len() + 1);
+            offsets.push(0_i32);
+            let mut item_values = Vec::new();
+            for list in &lists {
+                item_values.extend(list.iter().flat_map(|v| v.iter()));
+                offsets.push(item_values.len() as i32);
+            }
+            let items_array = build_array(items, &item_values)?;
+            let data = ArrayData::builder(DataType::List(Box::new(to_arrow_field(
+                "item", items,
+            )?)))
+            .len(lists.len())
+            .add_buffer(Buffer::from_slice_ref(&offsets))
+            .add_child_data(items_array.data().clone())
+            .null_bit_buffer(null_buffer(values))
+            .build();
+            Ok(make_array(data))
+        }
+        AvroSchema::Map(value_schema) => {
+            let maps = convert_values("map", values, |value| match value {
+                Value::Map(v) => Some(v),
+                _ => None,
+            })?;
+            let mut offsets = Vec::with_capacity(maps.len() + 1);
+            offsets.push(0_i32);
+            let mut entries = Vec::new();
+            for map in &maps {
+                if let Some(map) = map {
+                    let start = entries.len();
+                    entries.extend(map.iter());
+                    // the entries of a map are sorted by key for a deterministic order
+                    entries[start..].sort_by(|a, b| a.0.cmp(b.0));
+                }
+                offsets.push(entries.len() as i32);
+            }
+            let keys = entries
+                .iter()
+                .map(|(key, _)| Some(key.as_str()))
+                .collect::<StringArray>();
+            let map_values = entries.iter().map(|(_, value)| *value).collect::<Vec<_>>();
+            let map_values = build_array(value_schema, &map_values)?;
+
+            let data_type = to_arrow_field("", schema)?.data_type().clone();
+            let entries_type = match &data_type {
+                DataType::Map(field, _) => field.data_type().clone(),
+                _ => unreachable!(),
+            };
+            let entries = ArrayData::builder(entries_type)
+                .len(keys.len())
+                .add_child_data(keys.data().clone())
+                .add_child_data(map_values.data().clone())
+                .build();
+            let data = ArrayData::builder(data_type)
+                .len(maps.len())
+                .add_buffer(Buffer::from_slice_ref(&offsets))
+                .add_child_data(entries)
+                .null_bit_buffer(null_buffer(values))
+                .build();
+            Ok(make_array(data))
+        }
+        AvroSchema::Record { fields, .. } => {
+            let mut children = Vec::with_capacity(fields.len());
+            for (i, field) in fields.iter().enumerate() {
+                let field_values = values
+                    .iter()
+                    .map(|value| record_field(value, i, &field.name))
+                    .collect::<Result<Vec<_>>>()?;
+                children.push((
+                    to_arrow_field(&field.name, &field.schema)?,
+                    build_array(&field.schema, &field_values)?,
+                ));
+            }
+            Ok(Arc::new(StructArray::from((children, null_buffer(values)))))
+        }
+        AvroSchema::Union(union) => match nullable_union_variant(union) {
+            Some(variant) => build_array(variant, values),
+            None => build_union_array(union, values),
+        },
+        AvroSchema::Duration => Err(ArrowError::NotYetImplemented(
+            "Reading the Avro duration logical type is not supported".to_string(),
+        )),
+    }
+}
+
+/// Builds a dense union array of the values of an Avro union of several types other
+/// than `null`
+fn build_union_array(union: &UnionSchema, values: &[&Value]) -> Result<ArrayRef> {
+    let variants = union.variants();
+    let mut type_ids = Vec::<i8>::with_capacity(values.len());
+    let mut offsets = Vec::<i32>::with_capacity(values.len());
+    let mut variant_values = vec![Vec::new(); variants.len()];
+    for value in values {
+        let value = unwrap_union(value);
+        let type_id = variants
+            .iter()
+            .position(|variant| value.validate(variant))
+            .ok_or_else(|| unexpected_value("union", value))?;
+        type_ids.push(type_id as i8);
+        offsets.push(variant_values[type_id].len() as i32);
+        variant_values[type_id].push(value);
+    }
+
+    let children = variants
+        .iter()
+        .zip(variant_values)
+        .map(|(variant, values)| {
+            Ok((
+                to_arrow_field(&union_variant_name(variant), variant)?,
+                build_array(variant, &values)?,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Arc::new(UnionArray::try_new(
+        Buffer::from_slice_ref(&type_ids),
+        Some(Buffer::from_slice_ref(&offsets)),
+        children,
+        None,
+    )?))
+}
+
+/// Converts the big-endian two's-complement bytes of an Avro decimal to an `i128`
+fn decimal_to_i128(bytes: &[u8]) -> Result<i128> {
+    if bytes.len() > 16 {
+        return Err(ArrowError::AvroError(format!(
+            "Avro decimals of more than 16 bytes are not supported, got {} bytes",
+            bytes.len()
+        )));
+    }
+    let negative = bytes.first().map_or(false, |byte| byte & 0x80 != 0);
+    let mut buffer = if negative { [0xFF; 16] } else { [0; 16] };
+    buffer[16 - bytes.len()..].copy_from_slice(bytes);
+    Ok(i128::from_be_bytes(buffer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+    use std::io::Cursor;
+
+    fn write_avro(schema: &str, records: Vec<Vec<(&str, Value)>>) -> Vec<u8> {
+        let schema = AvroSchema::parse_str(schema).unwrap();
+        let mut writer = avro_rs::Writer::new(&schema, Vec::new());
+        for record in records {
+            let record = record
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect();
+            writer.append(Value::Record(record)).unwrap();
+        }
+        writer.into_inner().unwrap()
+    }
+
+    fn union(value: Value) -> Value {
+        Value::Union(Box::new(value))
+    }
+
+    const SCHEMA: &str = r#"{
+        "type": "record",
+        "name": "test",
+        "fields": [
+            {"name": "id", "type": "long"},
+            {"name": "name", "type": ["null", "string"]},
+            {"name": "scores", "type": {"type": "array", "items": "double"}},
+            {"name": "kind", "type": {"type": "enum", "name": "kind", "symbols": ["A", "B"]}},
+            {"name": "ts", "type": {"type": "long", "logicalType": "timestamp-millis"}}
+        ]
+    }"#;
+
+    fn records() -> Vec<Vec<(&'static str, Value)>> {
+        vec![
+            vec![
+                ("id", Value::Long(1)),
+                ("name", union(Value::String("a".to_string()))),
+                (
+                    "scores",
+                    Value::Array(vec![Value::Double(1.5), Value::Double(2.5)]),
+                ),
+                ("kind", Value::Enum(1, "B".to_string())),
+                ("ts", Value::TimestampMillis(1_600_000_000_000)),
+            ],
+            vec![
+                ("id", Value::Long(2)),
+                ("name", union(Value::Null)),
+                ("scores", Value::Array(vec![])),
+                ("kind", Value::Enum(0, "A".to_string())),
+                ("ts", Value::TimestampMillis(1_600_000_001_000)),
+            ],
+            vec![
+                ("id", Value::Long(3)),
+                ("name", union(Value::String("c".to_string()))),
+                ("scores", Value::Array(vec![Value::Double(3.0)])),
+                ("kind", Value::Enum(1, "B".to_string())),
+                ("ts", Value::TimestampMillis(1_600_000_002_000)),
+            ],
+        ]
+    }
+
+    #[test]
+    fn test_read_avro() {
+        let data = write_avro(SCHEMA, records());
+        let mut reader = Reader::new(Cursor::new(data), 2, None).unwrap();
+
+        let schema = reader.schema();
+        assert_eq!(schema.fields().len(), 5);
+        assert_eq!(schema.field(1), &Field::new("name", DataType::Utf8, true));
+
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema(), schema);
+        assert_eq!(
+            batch.column(0).as_ref(),
+            &Int64Array::from(vec![1, 2]) as &dyn Array
+        );
+        assert_eq!(
+            batch.column(1).as_ref(),
+            &StringArray::from(vec![Some("a"), None]) as &dyn Array
+        );
+        let scores = as_list_array(batch.column(2));
+        assert_eq!(scores.value_offsets(), &[0, 2, 2]);
+        assert_eq!(
+            scores.values().as_ref(),
+            &Float64Array::from(vec![1.5, 2.5]) as &dyn Array
+        );
+        let kind = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int32Type>>()
+            .unwrap();
+        assert_eq!(kind.keys(), &Int32Array::from(vec![0, 1]));
+        assert_eq!(
+            kind.values().as_ref(),
+            &StringArray::from(vec!["B", "A"]) as &dyn Array
+        );
+        assert_eq!(
+            batch.column(4).as_ref(),
+            &TimestampMillisecondArray::from_vec(
+                vec![1_600_000_000_000, 1_600_000_001_000],
+                None
+            ) as &dyn Array
+        );
+
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 1);
+        assert!(reader.next().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_read_avro_projection() {
+        let data = write_avro(SCHEMA, records());
+        let mut reader = ReaderBuilder::new()
+            .with_projection(vec!["name".to_string(), "id".to_string()])
+            .build(Cursor::new(data))
+            .unwrap();
+
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.schema().field(0).name(), "name");
+        assert_eq!(
+            batch.column(1).as_ref(),
+            &Int64Array::from(vec![1, 2, 3]) as &dyn Array
+        );
+
+        let data = write_avro(SCHEMA, records());
+        let projection = vec!["missing".to_string()];
+        assert!(Reader::new(Cursor::new(data), 1024, Some(projection)).is_err());
+    }
+
+    #[test]
+    fn test_read_avro_nested() {
+        let schema = r#"{
+            "type": "record",
+            "name": "test",
+            "fields": [
+                {"name": "point", "type": ["null", {"type": "record", "name": "point", "fields": [
+                    {"name": "x", "type": "int"},
+                    {"name": "y", "type": ["null", "int"]}
+                ]}]},
+                {"name": "tags", "type": {"type": "map", "values": "string"}},
+                {"name": "value", "type": ["int", "string"]}
+            ]
+        }"#;
+        let mut tags = HashMap::new();
+        tags.insert("b".to_string(), Value::String("2".to_string()));
+        tags.insert("a".to_string(), Value::String("1".to_string()));
+        let records = vec![
+            vec![
+                (
+                    "point",
+                    union(Value::Record(vec![
+                        ("x".to_string(), Value::Int(1)),
+                        ("y".to_string(), union(Value::Int(2))),
+                    ])),
+                ),
+                ("tags", Value::Map(tags)),
+                ("value", union(Value::Int(10))),
+            ],
+            vec![
+                ("point", union(Value::Null)),
+                ("tags", Value::Map(HashMap::new())),
+                ("value", union(Value::String("ten".to_string()))),
+            ],
+        ];
+        let data = write_avro(schema, records);
+        let mut reader = Reader::new(Cursor::new(data), 1024, None).unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        let point = as_struct_array(batch.column(0));
+        assert!(point.is_valid(0));
+        assert!(point.is_null(1));
+        assert_eq!(
+            point.column(0).as_ref(),
+            &Int32Array::from(vec![Some(1), None]) as &dyn Array
+        );
+        assert_eq!(
+            point.column(1).as_ref(),
+            &Int32Array::from(vec![Some(2), None]) as &dyn Array
+        );
+
+        let tags = batch.column(1).as_any().downcast_ref::<MapArray>().unwrap();
+        assert_eq!(tags.value_offsets(), &[0, 2, 2]);
+        assert_eq!(
+            tags.keys().as_ref(),
+            &StringArray::from(vec!["a", "b"]) as &dyn Array
+        );
+        assert_eq!(
+            tags.values().as_ref(),
+            &StringArray::from(vec!["1", "2"]) as &dyn Array
+        );
+
+        let value = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<UnionArray>()
+            .unwrap();
+        assert_eq!(value.type_id(0), 0);
+        assert_eq!(value.type_id(1), 1);
+        assert_eq!(
+            value.value(0).as_ref(),
+            &Int32Array::from(vec![10]) as &dyn Array
+        );
+        assert_eq!(
+            value.value(1).as_ref(),
+            &StringArray::from(vec!["ten"]) as &dyn Array
+        );
+    }
+
+    #[test]
+    fn test_decimal_to_i128() {
+        assert_eq!(decimal_to_i128(&[0x01, 0x00]).unwrap(), 256);
+        assert_eq!(decimal_to_i128(&[0xFF, 0x00]).unwrap(), -256);
+        assert_eq!(decimal_to_i128(&[]).unwrap(), 0);
+        assert!(decimal_to_i128(&[0; 17]).is_err());
+    }
+}
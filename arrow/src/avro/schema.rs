@@ -0,0 +1,249 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversion of Avro schemas to Arrow schemas.
+//!
+//! | Avro                                   | Arrow                                              |
+//! |----------------------------------------|----------------------------------------------------|
+//! | `null`                                 | `Null`                                             |
+//! | `boolean`                              | `Boolean`                                          |
+//! | `int`, `long`                          | `Int32`, `Int64`                                   |
+//! | `float`, `double`                      | `Float32`, `Float64`                               |
+//! | `bytes`, `string`                      | `Binary`, `Utf8`                                   |
+//! | `fixed`                                | `FixedSizeBinary`                                  |
+//! | `enum`                                 | `Dictionary(Int32, Utf8)`                          |
+//! | `array`                                | `List`                                             |
+//! | `map`                                  | `Map` with `Utf8` keys                             |
+//! | `record`                               | `Struct`                                           |
+//! | `union` of `null` and another type     | the other type, nullable                           |
+//! | other `union`                          | dense `Union`                                      |
+//! | `decimal`                              | `Decimal`                                          |
+//! | `uuid`                                 | `Utf8`                                             |
+//! | `date`                                 | `Date32`                                           |
+//! | `time-millis`, `time-micros`           | `Time32(Millisecond)`, `Time64(Microsecond)`       |
+//! | `timestamp-millis`, `timestamp-micros` | `Timestamp(Millisecond)`, `Timestamp(Microsecond)` |
+//!
+//! The `duration` logical type is not supported.
+
+use avro_rs::schema::{SchemaKind, UnionSchema};
+use avro_rs::Schema as AvroSchema;
+
+use crate::datatypes::{DataType, Field, Schema, TimeUnit};
+use crate::error::{ArrowError, Result};
+
+/// Converts the schema of Avro records to an Arrow schema, with a field per field of
+/// the records.
+pub fn to_arrow_schema(schema: &AvroSchema) -> Result<Schema> {
+    match schema {
+        AvroSchema::Record { fields, .. } => {
+            let fields = fields
+                .iter()
+                .map(|field| to_arrow_field(&field.name, &field.schema))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Schema::new(fields))
+        }
+        other => Err(ArrowError::AvroError(format!(
+            "The schema of Avro records must be a record, got {:?}",
+            SchemaKind::from(other)
+        ))),
+    }
+}
+
+/// Converts an Avro schema to an Arrow field named `name`, which is nullable if the
+/// schema is `null` or a union with `null`.
+pub fn to_arrow_field(name: &str, schema: &AvroSchema) -> Result<Field> {
+    let (data_type, nullable) = to_data_type(schema)?;
+    Ok(Field::new(name, data_type, nullable))
+}
+
+/// Returns the name of the field of a variant of an Avro union converted to an
+/// Arrow union: the name of named types, and the name of the type otherwise.
+pub(super) fn union_variant_name(schema: &AvroSchema) -> String {
+    match schema {
+        AvroSchema::Record { name, .. }
+        | AvroSchema::Enum { name, .. }
+        | AvroSchema::Fixed { name, .. } => name.name.clone(),
+        other => format!("{:?}", SchemaKind::from(other)).to_lowercase(),
+    }
+}
+
+/// Returns the variant of an Avro union of `null` and a single other type, or `None`
+/// if the union has several variants that are not `null`.
+pub(super) fn nullable_union_variant(union: &UnionSchema) -> Option<&AvroSchema> {
+    let mut variants = union
+        .variants()
+        .iter()
+        .filter(|variant| !matches!(variant, AvroSchema::Null));
+    match (variants.next(), variants.next()) {
+        (None, _) => Some(&AvroSchema::Null),
+        (Some(variant), None) => Some(variant),
+        _ => None,
+    }
+}
+
+fn to_data_type(schema: &AvroSchema) -> Result<(DataType, bool)> {
+    let data_type = match schema {
+        AvroSchema::Null => return Ok((DataType::Null, true)),
+        AvroSchema::Boolean => DataType::Boolean,
+        AvroSchema::Int => DataType::Int32,
+        AvroSchema::Long => DataType::Int64,
+        AvroSchema::Float => DataType::Float32,
+        AvroSchema::Double => DataType::Float64,
+        AvroSchema::Bytes => DataType::Binary,
+        AvroSchema::String | AvroSchema::Uuid => DataType::Utf8,
+        AvroSchema::Fixed { size, .. } => DataType::FixedSizeBinary(*size as i32),
+        AvroSchema::Enum { .. } => {
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8))
+        }
+        AvroSchema::Array(items) => {
+            DataType::List(Box::new(to_arrow_field("item", items)?))
+        }
+        AvroSchema::Map(values) => {
+            let entries = DataType::Struct(vec![
+                Field::new("keys", DataType::Utf8, false),
+                to_arrow_field("values", values)?,
+            ]);
+            DataType::Map(Box::new(Field::new("entries", entries, false)), false)
+        }
+        AvroSchema::Record { fields, .. } => DataType::Struct(
+            fields
+                .iter()
+                .map(|field| to_arrow_field(&field.name, &field.schema))
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        AvroSchema::Union(union) => {
+            let nullable = union
+                .variants()
+                .iter()
+                .any(|variant| matches!(variant, AvroSchema::Null));
+            return match nullable_union_variant(union) {
+                Some(variant) => Ok((to_data_type(variant)?.0, nullable)),
+                None => {
+                    let fields = union
+                        .variants()
+                        .iter()
+                        .map(|variant| {
+                            to_arrow_field(&union_variant_name(variant), variant)
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok((DataType::Union(fields), nullable))
+                }
+            };
+        }
+        AvroSchema::Decimal {
+            precision, scale, ..
+        } => DataType::Decimal(*precision, *scale),
+        AvroSchema::Date => DataType::Date32,
+        AvroSchema::TimeMillis => DataType::Time32(TimeUnit::Millisecond),
+        AvroSchema::TimeMicros => DataType::Time64(TimeUnit::Microsecond),
+        AvroSchema::TimestampMillis => DataType::Timestamp(TimeUnit::Millisecond, None),
+        AvroSchema::TimestampMicros => DataType::Timestamp(TimeUnit::Microsecond, None),
+        AvroSchema::Duration => {
+            return Err(ArrowError::NotYetImplemented(
+                "Reading the Avro duration logical type is not supported".to_string(),
+            ))
+        }
+    };
+    Ok((data_type, false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_arrow_schema() {
+        let schema = AvroSchema::parse_str(
+            r#"{
+                "type": "record",
+                "name": "test",
+                "fields": [
+                    {"name": "id", "type": "long"},
+                    {"name": "name", "type": ["null", "string"]},
+                    {"name": "scores", "type": {"type": "array", "items": "double"}},
+                    {"name": "tags", "type": {"type": "map", "values": "int"}},
+                    {"name": "kind", "type": {"type": "enum", "name": "kind", "symbols": ["A", "B"]}},
+                    {"name": "ts", "type": {"type": "long", "logicalType": "timestamp-micros"}},
+                    {"name": "price", "type": {"type": "bytes", "logicalType": "decimal", "precision": 10, "scale": 2}},
+                    {"name": "value", "type": ["null", "int", "string"]},
+                    {"name": "point", "type": {"type": "record", "name": "point", "fields": [
+                        {"name": "x", "type": "float"}
+                    ]}}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let expected = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new(
+                "scores",
+                DataType::List(Box::new(Field::new("item", DataType::Float64, false))),
+                false,
+            ),
+            Field::new(
+                "tags",
+                DataType::Map(
+                    Box::new(Field::new(
+                        "entries",
+                        DataType::Struct(vec![
+                            Field::new("keys", DataType::Utf8, false),
+                            Field::new("values", DataType::Int32, false),
+                        ]),
+                        false,
+                    )),
+                    false,
+                ),
+                false,
+            ),
+            Field::new(
+                "kind",
+                DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+                false,
+            ),
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                false,
+            ),
+            Field::new("price", DataType::Decimal(10, 2), false),
+            Field::new(
+                "value",
+                DataType::Union(vec![
+                    Field::new("null", DataType::Null, true),
+                    Field::new("int", DataType::Int32, false),
+                    Field::new("string", DataType::Utf8, false),
+                ]),
+                true,
+            ),
+            Field::new(
+                "point",
+                DataType::Struct(vec![Field::new("x", DataType::Float32, false)]),
+                false,
+            ),
+        ]);
+
+        assert_eq!(to_arrow_schema(&schema).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_to_arrow_schema_not_record() {
+        let schema = AvroSchema::parse_str(r#""long""#).unwrap();
+        assert!(to_arrow_schema(&schema).is_err());
+    }
+}
@@ -0,0 +1,264 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::ops::{BitAnd, BitOr, Not};
+
+use crate::util::bit_util;
+
+use super::ops::{buffer_bin_and, buffer_bin_or, buffer_unary_not};
+use super::{Buffer, MutableBuffer};
+
+/// A slice of bit-packed boolean values of a [Buffer], starting at a bit offset, such
+/// as the values of a [BooleanArray](crate::array::BooleanArray).
+///
+/// Unlike a [Buffer], it knows the offset and the number of its bits, so that it can be
+/// passed around, e.g. from the output of a comparison kernel to the validity bitmap of
+/// another array (see [NullBuffer](super::NullBuffer)), without copying the bits.
+///
+/// # Example
+/// ```
+/// use arrow::array::{Array, BooleanArray};
+/// use arrow::buffer::BooleanBuffer;
+///
+/// let array = BooleanArray::from(vec![true, false, true, true]);
+/// let values = array.slice(1, 3);
+/// let values = values.as_any().downcast_ref::<BooleanArray>().unwrap();
+/// let buffer = values.boolean_buffer();
+/// assert_eq!(buffer.offset(), 1);
+/// assert_eq!(buffer.count_set_bits(), 2);
+/// assert_eq!((!&buffer).iter().collect::<Vec<_>>(), vec![true, false, false]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BooleanBuffer {
+    buffer: Buffer,
+    offset: usize,
+    len: usize,
+}
+
+impl BooleanBuffer {
+    /// Creates a boolean buffer of the `len` bits of `buffer` starting at bit `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` has fewer than `offset + len` bits.
+    pub fn new(buffer: Buffer, offset: usize, len: usize) -> Self {
+        assert!(
+            offset + len <= buffer.len() * 8,
+            "the offset and length of a boolean buffer must be within its buffer"
+        );
+        Self {
+            buffer,
+            offset,
+            len,
+        }
+    }
+
+    /// Creates a boolean buffer of `len` bits all equal to `value`.
+    pub fn new_with_value(value: bool, len: usize) -> Self {
+        let mut buffer = MutableBuffer::new_null(len);
+        if value {
+            buffer
+                .as_slice_mut()
+                .iter_mut()
+                .for_each(|byte| *byte = u8::MAX);
+        }
+        Self::new(buffer.into(), 0, len)
+    }
+
+    /// Returns the number of bits of this buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether this buffer has no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the offset in bits of this buffer within its [Buffer].
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the [Buffer] holding the bits of this buffer, starting at
+    /// [`offset`](Self::offset).
+    pub fn inner(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Returns the [Buffer] holding the bits of this buffer, starting at
+    /// [`offset`](Self::offset).
+    pub fn into_inner(self) -> Buffer {
+        self.buffer
+    }
+
+    /// Returns the bit at index `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn value(&self, i: usize) -> bool {
+        assert!(i < self.len, "index out of bounds of a boolean buffer");
+        bit_util::get_bit(self.buffer.as_slice(), self.offset + i)
+    }
+
+    /// Returns the number of set bits of this buffer.
+    pub fn count_set_bits(&self) -> usize {
+        self.buffer.count_set_bits_offset(self.offset, self.len)
+    }
+
+    /// Returns a zero-copy slice of this buffer, with `len` bits from `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + len` is greater than the length of this buffer.
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        assert!(
+            offset + len <= self.len,
+            "the offset and length of the slice of a boolean buffer must be in bounds"
+        );
+        Self {
+            buffer: self.buffer.clone(),
+            offset: self.offset + offset,
+            len,
+        }
+    }
+
+    /// Returns a [Buffer] whose bits from bit `offset` are the bits of this buffer, e.g.
+    /// to use them as a bitmap of an array with `offset`.
+    ///
+    /// This doesn't copy the bits when the offsets of both are a multiple of 8 bits
+    /// apart, and otherwise copies them by bytes when `offset` is zero.
+    pub fn bit_buffer_at(&self, offset: usize) -> Buffer {
+        if self.offset >= offset && (self.offset - offset) % 8 == 0 {
+            return self.buffer.slice((self.offset - offset) / 8);
+        }
+        if offset == 0 {
+            return self.buffer.bit_slice(self.offset, self.len);
+        }
+        let mut buffer = MutableBuffer::new_null(offset + self.len);
+        let bits = buffer.as_slice_mut();
+        (0..self.len)
+            .filter(|i| self.value(*i))
+            .for_each(|i| bit_util::set_bit(bits, offset + i));
+        buffer.into()
+    }
+
+    /// Returns an iterator over the bits of this buffer.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len)
+            .map(move |i| bit_util::get_bit(self.buffer.as_slice(), self.offset + i))
+    }
+}
+
+impl PartialEq for BooleanBuffer {
+    /// Two boolean buffers are equal if their bits are equal, whatever their offsets.
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<'a, 'b> BitAnd<&'b BooleanBuffer> for &'a BooleanBuffer {
+    type Output = BooleanBuffer;
+
+    /// Returns the bitwise AND of two boolean buffers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffers have different lengths.
+    fn bitand(self, rhs: &'b BooleanBuffer) -> BooleanBuffer {
+        assert_eq!(
+            self.len, rhs.len,
+            "boolean buffers must have the same length"
+        );
+        let buffer =
+            buffer_bin_and(&self.buffer, self.offset, &rhs.buffer, rhs.offset, self.len);
+        BooleanBuffer::new(buffer, 0, self.len)
+    }
+}
+
+impl<'a, 'b> BitOr<&'b BooleanBuffer> for &'a BooleanBuffer {
+    type Output = BooleanBuffer;
+
+    /// Returns the bitwise OR of two boolean buffers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffers have different lengths.
+    fn bitor(self, rhs: &'b BooleanBuffer) -> BooleanBuffer {
+        assert_eq!(
+            self.len, rhs.len,
+            "boolean buffers must have the same length"
+        );
+        let buffer =
+            buffer_bin_or(&self.buffer, self.offset, &rhs.buffer, rhs.offset, self.len);
+        BooleanBuffer::new(buffer, 0, self.len)
+    }
+}
+
+impl Not for &BooleanBuffer {
+    type Output = BooleanBuffer;
+
+    fn not(self) -> BooleanBuffer {
+        let buffer = buffer_unary_not(&self.buffer, self.offset, self.len);
+        BooleanBuffer::new(buffer, 0, self.len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boolean_buffer_slice() {
+        // 0b10110110, 0b00000011
+        let buffer = BooleanBuffer::new(Buffer::from([0b10110110, 0b00000011]), 1, 10);
+        assert_eq!(buffer.count_set_bits(), 7);
+        let sliced = buffer.slice(2, 5);
+        assert_eq!(sliced.offset(), 3);
+        assert_eq!(
+            sliced.iter().collect::<Vec<_>>(),
+            vec![false, true, true, false, true]
+        );
+
+        // the bits at the same position within a byte aren't copied
+        let bits = sliced.bit_buffer_at(3);
+        assert_eq!(bits.as_ptr(), sliced.inner().as_ptr());
+        assert_eq!(BooleanBuffer::new(bits, 3, 5), sliced);
+        for offset in 0..3 {
+            let bits = sliced.bit_buffer_at(offset);
+            let copy = BooleanBuffer::new(bits, offset, 5);
+            assert_eq!(
+                copy.iter().collect::<Vec<_>>(),
+                sliced.iter().collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_boolean_buffer_ops() {
+        let a = BooleanBuffer::new(Buffer::from([0b00001101]), 1, 3);
+        let b = BooleanBuffer::new_with_value(true, 3);
+        assert_eq!(
+            (&a & &b).iter().collect::<Vec<_>>(),
+            vec![false, true, true]
+        );
+        assert_eq!((&a | &b).count_set_bits(), 3);
+        assert_eq!((!&a).iter().collect::<Vec<_>>(), vec![true, false, false]);
+        assert_eq!(BooleanBuffer::new_with_value(false, 9).count_set_bits(), 0);
+    }
+}
@@ -23,8 +23,10 @@ use std::{convert::AsRef, usize};
 
 use crate::util::bit_chunk_iterator::BitChunks;
 use crate::{
+    alloc::{self, Allocation},
     bytes::{Bytes, Deallocation},
     datatypes::ArrowNativeType,
+    error::{ArrowError, Result},
     ffi,
 };
 
@@ -61,6 +63,39 @@ impl Buffer {
         buffer.into()
     }
 
+    /// Creates a [Buffer] from the memory of `vec`, without copying it.
+    ///
+    /// Unlike `Buffer::from`, which copies its argument into memory aligned along
+    /// [`alloc::ALIGNMENT`], the buffer is only aligned along `T`.
+    /// # Example
+    /// ```
+    /// # use arrow::buffer::Buffer;
+    /// let buffer = Buffer::from_vec(vec![1i32, 2, 3]);
+    /// assert_eq!(unsafe { buffer.typed_data::<i32>() }, &[1, 2, 3]);
+    /// let vec: Vec<i32> = buffer.into_vec().unwrap();
+    /// assert_eq!(vec, vec![1, 2, 3]);
+    /// ```
+    pub fn from_vec<T: ArrowNativeType>(vec: Vec<T>) -> Self {
+        MutableBuffer::from_vec(vec).into()
+    }
+
+    /// Converts this buffer into a `Vec<T>` without copying its memory.
+    ///
+    /// Only buffers whose memory was allocated with the layout of a `Vec<T>`, such as
+    /// those created with [Buffer::from_vec], can be converted. Any other buffer is
+    /// returned unchanged as `Err(self)`, as are buffers that are shared with clones or
+    /// slices, and slices themselves.
+    pub fn into_vec<T: ArrowNativeType>(self) -> std::result::Result<Vec<T>, Self> {
+        if self.offset != 0 {
+            return Err(self);
+        }
+        let bytes = match Arc::try_unwrap(self.data) {
+            Ok(bytes) => bytes,
+            Err(data) => return Err(Buffer { data, offset: 0 }),
+        };
+        bytes.try_into_vec().map_err(Buffer::from_bytes)
+    }
+
     /// Creates a buffer from an existing memory region (must already be byte-aligned), this
     /// `Buffer` will free this piece of memory when dropped.
     ///
@@ -76,7 +111,11 @@ impl Buffer {
     /// bytes. If the `ptr` and `capacity` come from a `Buffer`, then this is guaranteed.
     pub unsafe fn from_raw_parts(ptr: NonNull<u8>, len: usize, capacity: usize) -> Self {
         assert!(len <= capacity);
-        Buffer::build_with_arguments(ptr, len, Deallocation::Native(capacity))
+        Buffer::build_with_arguments(
+            ptr,
+            len,
+            Deallocation::Native(capacity, alloc::ALIGNMENT),
+        )
     }
 
     /// Creates a buffer from an existing memory region (must already be byte-aligned), this
@@ -100,6 +139,55 @@ impl Buffer {
         Buffer::build_with_arguments(ptr, len, Deallocation::Foreign(data))
     }
 
+    /// Creates a buffer from memory owned by `owner`, without copying it.
+    /// The memory is released when `owner` is dropped, which happens once this buffer
+    /// and all its clones and slices are dropped.
+    ///
+    /// The region should be aligned for the types that are read from it, as typed
+    /// access to this buffer panics otherwise.
+    /// # Example
+    /// ```
+    /// # use arrow::buffer::Buffer;
+    /// let buffer = Buffer::from_external(vec![1u8, 2, 3]);
+    /// assert_eq!(buffer.as_slice(), &[1, 2, 3]);
+    /// assert_eq!(buffer.capacity(), 0);
+    /// ```
+    pub fn from_external<T>(owner: T) -> Self
+    where
+        T: AsRef<[u8]> + Send + Sync + 'static,
+    {
+        let owner = Arc::new(owner);
+        // the region is valid for as long as `owner` is, which `Deallocation::Custom`
+        // keeps alive; it does not move, as `owner` lives behind an `Arc`.
+        let slice = owner.as_ref().as_ref();
+        let ptr = NonNull::from(slice).cast::<u8>();
+        let len = slice.len();
+        unsafe { Buffer::from_custom_allocation(ptr, len, owner) }
+    }
+
+    /// Creates a buffer from an existing memory region that is released by dropping
+    /// `owner`, e.g. a handle whose [`Drop`] implementation calls `munmap` or a foreign
+    /// deallocator. This buffer **does not** free the region itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `ptr` - Pointer to raw parts
+    /// * `len` - Length of raw parts in **bytes**
+    /// * `owner` - The owner of the region, dropped once this buffer, its clones and
+    ///   its slices are dropped
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe as there is no guarantee that the given pointer is valid for `len`
+    /// bytes for as long as `owner` is alive, nor that the region is not mutated meanwhile.
+    pub unsafe fn from_custom_allocation(
+        ptr: NonNull<u8>,
+        len: usize,
+        owner: Arc<dyn Allocation>,
+    ) -> Self {
+        Buffer::build_with_arguments(ptr, len, Deallocation::Custom(owner))
+    }
+
     /// Auxiliary method to create a new Buffer
     unsafe fn build_with_arguments(
         ptr: NonNull<u8>,
@@ -149,6 +237,51 @@ impl Buffer {
         }
     }
 
+    /// Returns a new [Buffer] that is a slice of this buffer starting at `offset`, like
+    /// [Buffer::slice], erroring if the start of the slice is not aligned along `alignment` bytes.
+    ///
+    /// This allows integrations that require aligned pointers to share this buffer's memory
+    /// without checking its address themselves, copying it only when this errors.
+    /// # Panics
+    /// Panics iff `offset` is larger than `len`.
+    /// # Example
+    /// ```
+    /// # use arrow::buffer::{Buffer, MutableBuffer};
+    /// let buffer: Buffer = MutableBuffer::with_capacity_and_alignment(8192, 4096)
+    ///     .with_bitset(8192, true)
+    ///     .into();
+    /// assert!(buffer.slice_aligned(4096, 4096).is_ok());
+    /// assert!(buffer.slice_aligned(64, 4096).is_err());
+    /// ```
+    pub fn slice_aligned(&self, offset: usize, alignment: usize) -> Result<Self> {
+        let slice = self.slice(offset);
+        if slice.alignment() < alignment {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "the slice at offset {} is aligned along {} bytes, expected {}",
+                offset,
+                slice.alignment(),
+                alignment
+            )));
+        }
+        Ok(slice)
+    }
+
+    /// Returns the largest power of two, in bytes, the start of this buffer is aligned along.
+    ///
+    /// For buffers created from a [MutableBuffer] this is at least the alignment of the
+    /// [MutableBuffer], unless this buffer is a slice of it starting at an unaligned offset.
+    pub fn alignment(&self) -> usize {
+        1 << (self.as_ptr() as usize).trailing_zeros()
+    }
+
+    /// Returns the alignment this buffer's memory region was allocated with, or `None`
+    /// when the region was not allocated by Arrow, e.g. when imported via FFI.
+    ///
+    /// Unlike [Buffer::alignment], this does not depend on the offset of this buffer.
+    pub fn allocation_alignment(&self) -> Option<usize> {
+        self.data.alignment()
+    }
+
     /// Returns a pointer to the start of this buffer.
     ///
     /// Note that this should be used cautiously, and the returned pointer should not be
@@ -167,7 +300,8 @@ impl Buffer {
     ///
     /// Also `typed_data::<bool>` is unsafe as `0x00` and `0x01` are the only valid values for
     /// `bool` in Rust.  However, `bool` arrays in Arrow are bit-packed which breaks this condition.
-    /// View buffer as typed slice.
+    ///
+    /// See [ScalarBuffer](super::ScalarBuffer) for a view that validates the values once.
     pub unsafe fn typed_data<T: ArrowNativeType + num::Num>(&self) -> &[T] {
         // JUSTIFICATION
         //  Benefit
@@ -366,6 +500,47 @@ mod tests {
         assert_eq!([0, 1, 2, 3, 4], buf.as_slice());
     }
 
+    #[test]
+    fn test_from_external() {
+        let buf = Buffer::from_external(vec![0u8, 1, 2, 3, 4]);
+        assert_eq!(5, buf.len());
+        assert_eq!(0, buf.capacity());
+        assert_eq!([0, 1, 2, 3, 4], buf.as_slice());
+        assert_eq!([2, 3, 4], buf.slice(2).as_slice());
+        assert_eq!(buf, Buffer::from(&[0, 1, 2, 3, 4]));
+
+        // inline storage is moved behind the buffer before it is referenced
+        let buf = Buffer::from_external([7u8; 16]);
+        assert_eq!([7u8; 16], buf.as_slice());
+    }
+
+    #[test]
+    fn test_from_custom_allocation() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        static RELEASED: AtomicBool = AtomicBool::new(false);
+
+        struct Owner(Vec<u32>);
+
+        impl Drop for Owner {
+            fn drop(&mut self) {
+                RELEASED.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let owner = Arc::new(Owner(vec![1, 2, 3]));
+        let ptr = NonNull::new(owner.0.as_ptr() as *mut u8).unwrap();
+        let buf = unsafe { Buffer::from_custom_allocation(ptr, 12, owner) };
+        assert_eq!(&[1u32, 2, 3], unsafe { buf.typed_data::<u32>() });
+
+        let sliced = buf.slice(4);
+        drop(buf);
+        assert!(!RELEASED.load(Ordering::SeqCst));
+        assert_eq!(&[2u32, 3], unsafe { sliced.typed_data::<u32>() });
+        drop(sliced);
+        assert!(RELEASED.load(Ordering::SeqCst));
+    }
+
     #[test]
     fn test_from_vec() {
         let buf = Buffer::from(&[0, 1, 2, 3, 4]);
@@ -415,6 +590,28 @@ mod tests {
         buf.slice(6);
     }
 
+    #[test]
+    fn test_slice_aligned() {
+        let buf: Buffer = MutableBuffer::from_len_zeroed(256).into();
+        assert_eq!(buf.allocation_alignment(), Some(crate::alloc::ALIGNMENT));
+        assert!(buf.alignment() >= crate::alloc::ALIGNMENT);
+
+        let slice = buf.slice_aligned(crate::alloc::ALIGNMENT, 64).unwrap();
+        assert_eq!(slice.len(), 256 - crate::alloc::ALIGNMENT);
+        assert!(slice.alignment() >= 64);
+
+        assert_eq!(buf.slice(3).alignment(), 1);
+        assert_eq!(buf.slice(4).alignment(), 4);
+        let err = buf.slice_aligned(3, 8).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: the slice at offset 3 is aligned along 1 bytes, expected 8"
+        );
+
+        let buf = Buffer::from_external(vec![1u8, 2, 3]);
+        assert_eq!(buf.allocation_alignment(), None);
+    }
+
     #[test]
     fn test_access_concurrently() {
         let buffer = Buffer::from(vec![1, 2, 3, 4, 5]);
@@ -454,6 +651,40 @@ mod tests {
         check_as_typed_data!(&[1f64, 3f64, 6f64], f64);
     }
 
+    #[test]
+    fn test_from_vec_into_vec() {
+        let vec = vec![1i32, 2, 3];
+        let ptr = vec.as_ptr() as *const u8;
+        let buffer = Buffer::from_vec(vec);
+        assert_eq!(buffer.as_ptr(), ptr);
+        assert_eq!(buffer.len(), 12);
+        assert_eq!(&[1i32, 2, 3], unsafe { buffer.typed_data::<i32>() });
+
+        // shared or sliced buffers can't be converted
+        let cloned = buffer.clone();
+        let buffer = buffer.into_vec::<i32>().unwrap_err();
+        drop(cloned);
+        let sliced = buffer.slice(4);
+        drop(buffer);
+        assert!(sliced.into_vec::<i32>().is_err());
+
+        let vec = vec![1i32, 2, 3];
+        let ptr = vec.as_ptr() as *const u8;
+        let buffer = Buffer::from_vec(vec);
+        // the layout of the region must be the one of a `Vec<T>`
+        let buffer = buffer.into_vec::<i64>().unwrap_err();
+        let vec = buffer.into_vec::<i32>().unwrap();
+        assert_eq!(vec.as_ptr() as *const u8, ptr);
+        assert_eq!(vec, vec![1, 2, 3]);
+
+        // buffers allocated by arrow are aligned along cache lines
+        let buffer = Buffer::from_slice_ref(&[1i32, 2, 3]);
+        assert!(buffer.into_vec::<i32>().is_err());
+
+        let buffer = Buffer::from_vec(Vec::<i32>::new());
+        assert!(buffer.is_empty());
+    }
+
     #[test]
     fn test_count_bits() {
         assert_eq!(0, Buffer::from(&[0b00000000]).count_set_bits());
@@ -22,6 +22,12 @@ mod immutable;
 pub use immutable::*;
 mod mutable;
 pub use mutable::*;
+mod boolean;
+pub use boolean::*;
+mod null;
+pub use null::*;
+mod scalar;
+pub use scalar::*;
 mod ops;
 pub(super) use ops::*;
 
@@ -1,9 +1,11 @@
 use std::ptr::NonNull;
+use std::sync::Arc;
 
 use crate::{
-    alloc,
+    alloc::{self, MemoryPool},
     bytes::{Bytes, Deallocation},
     datatypes::{ArrowNativeType, ToByteSlice},
+    error::Result,
     util::bit_util,
 };
 
@@ -47,6 +49,10 @@ pub struct MutableBuffer {
     // invariant: len <= capacity
     len: usize,
     capacity: usize,
+    // the alignment `data` was allocated with
+    alignment: usize,
+    // the pool `capacity` is accounted against, if any
+    pool: Option<Arc<dyn MemoryPool>>,
 }
 
 impl MutableBuffer {
@@ -59,12 +65,87 @@ impl MutableBuffer {
     /// Allocate a new [MutableBuffer] with initial capacity to be at least `capacity`.
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_alignment(capacity, alloc::ALIGNMENT)
+    }
+
+    /// Allocate a new [MutableBuffer] with initial capacity to be at least `capacity`,
+    /// whose memory is aligned along `alignment` bytes instead of [`alloc::ALIGNMENT`],
+    /// e.g. along pages for `O_DIRECT` IO.
+    ///
+    /// The alignment is kept when the buffer grows and when it is converted into a [`Buffer`].
+    /// # Panics
+    /// Panics if `alignment` is not a power of two or is smaller than [`alloc::ALIGNMENT`].
+    /// # Example
+    /// ```
+    /// # use arrow::buffer::{Buffer, MutableBuffer};
+    /// let mut buffer = MutableBuffer::with_capacity_and_alignment(64, 4096);
+    /// buffer.extend_from_slice(&[0u8; 100]);
+    /// assert_eq!(buffer.as_ptr() as usize % 4096, 0);
+    /// let buffer: Buffer = buffer.into();
+    /// assert!(buffer.alignment() >= 4096);
+    /// ```
+    pub fn with_capacity_and_alignment(capacity: usize, alignment: usize) -> Self {
         let capacity = bit_util::round_upto_multiple_of_64(capacity);
-        let ptr = alloc::allocate_aligned(capacity);
+        let ptr = alloc::allocate_aligned_with_alignment(capacity, alignment);
         Self {
             data: ptr,
             len: 0,
             capacity,
+            alignment,
+            pool: None,
+        }
+    }
+
+    /// Allocate a new [MutableBuffer] with initial capacity to be at least `capacity`,
+    /// accounting its memory against `pool`.
+    ///
+    /// Every subsequent change in capacity, including those of the [`Buffer`] this is
+    /// converted into, is reported to `pool`. Errors if `pool` rejects the allocation.
+    pub fn with_capacity_in(capacity: usize, pool: Arc<dyn MemoryPool>) -> Result<Self> {
+        let capacity = bit_util::round_upto_multiple_of_64(capacity);
+        pool.try_grow(capacity)?;
+        let ptr = alloc::allocate_aligned(capacity);
+        Ok(Self {
+            data: ptr,
+            len: 0,
+            capacity,
+            alignment: alloc::ALIGNMENT,
+            pool: Some(pool),
+        })
+    }
+
+    /// Creates a [MutableBuffer] from the memory of `vec`, without copying it.
+    ///
+    /// The buffer keeps the alignment `vec` was allocated with, which is the alignment of
+    /// `T` rather than [`alloc::ALIGNMENT`], so that the region is released with its
+    /// original layout. [`Buffer::into_vec`] converts the buffer back into a `Vec<T>`.
+    /// # Example
+    /// ```
+    /// # use arrow::buffer::{Buffer, MutableBuffer};
+    /// let mut buffer = MutableBuffer::from_vec(vec![1u32, 2]);
+    /// buffer.push(3u32);
+    /// let buffer: Buffer = buffer.into();
+    /// assert_eq!(unsafe { buffer.typed_data::<u32>() }, &[1, 2, 3]);
+    /// ```
+    pub fn from_vec<T: ArrowNativeType>(vec: Vec<T>) -> Self {
+        if vec.capacity() == 0 {
+            return Self::new(0);
+        }
+        let mut vec = std::mem::ManuallyDrop::new(vec);
+        let size = std::mem::size_of::<T>();
+        let capacity = vec.capacity() * size;
+        // the region is now accounted as if it was allocated by Arrow, as it is freed by it
+        unsafe {
+            alloc::ALLOCATIONS
+                .fetch_add(capacity as isize, std::sync::atomic::Ordering::SeqCst)
+        };
+        Self {
+            // a `Vec` with a non-zero capacity holds a non-null pointer
+            data: NonNull::new(vec.as_mut_ptr() as *mut u8).unwrap(),
+            len: vec.len() * size,
+            capacity,
+            alignment: std::mem::align_of::<T>(),
+            pool: None,
         }
     }
 
@@ -86,6 +167,8 @@ impl MutableBuffer {
             data: ptr,
             len,
             capacity: new_capacity,
+            alignment: alloc::ALIGNMENT,
+            pool: None,
         }
     }
 
@@ -141,18 +224,60 @@ impl MutableBuffer {
     pub fn reserve(&mut self, additional: usize) {
         let required_cap = self.len + additional;
         if required_cap > self.capacity {
-            // JUSTIFICATION
-            //  Benefit
-            //      necessity
-            //  Soundness
-            //      `self.data` is valid for `self.capacity`.
-            let (ptr, new_capacity) =
-                unsafe { reallocate(self.data, self.capacity, required_cap) };
-            self.data = ptr;
-            self.capacity = new_capacity;
+            if let Err(e) = self.reallocate(required_cap) {
+                panic!("MutableBuffer::reserve: {}", e)
+            }
         }
     }
 
+    /// Like [MutableBuffer::reserve], but returns an error instead of panicking
+    /// when the [`MemoryPool`] of this buffer rejects the allocation.
+    /// The buffer is left unchanged on error.
+    #[inline]
+    pub fn try_reserve(&mut self, additional: usize) -> Result<()> {
+        let required_cap = self.len + additional;
+        if required_cap > self.capacity {
+            self.reallocate(required_cap)?;
+        }
+        Ok(())
+    }
+
+    /// Grows the capacity of this buffer to at least `required_cap`, accounting the growth
+    /// against the pool of this buffer, if any.
+    #[cold]
+    fn reallocate(&mut self, required_cap: usize) -> Result<()> {
+        if self.capacity == 0 && self.alignment < alloc::ALIGNMENT {
+            // the memory of a `Vec` released by `shrink_to_fit`; Arrow can't allocate
+            // along the alignment of the `Vec`, so start over from a dangling pointer.
+            self.data = alloc::allocate_aligned(0);
+            self.alignment = alloc::ALIGNMENT;
+        }
+        let required_cap = bit_util::round_upto_multiple_of_64(required_cap);
+        let mut new_capacity = std::cmp::max(required_cap, self.capacity * 2);
+        if let Some(pool) = &self.pool {
+            // when doubling does not fit in the pool, fall back to what is strictly needed
+            if pool.try_grow(new_capacity - self.capacity).is_err() {
+                pool.try_grow(required_cap - self.capacity)?;
+                new_capacity = required_cap;
+            }
+        }
+        // JUSTIFICATION
+        //  Benefit
+        //      necessity
+        //  Soundness
+        //      `self.data` is valid for `self.capacity`.
+        self.data = unsafe {
+            alloc::reallocate_with_alignment(
+                self.data,
+                self.capacity,
+                new_capacity,
+                self.alignment,
+            )
+        };
+        self.capacity = new_capacity;
+        Ok(())
+    }
+
     /// Resizes the buffer, either truncating its contents (with no change in capacity), or
     /// growing it (potentially reallocating it) and writing `value` in the newly available bytes.
     /// # Example
@@ -199,9 +324,18 @@ impl MutableBuffer {
             //      necessity
             //  Soundness
             //      `self.data` is valid for `self.capacity`.
-            let ptr =
-                unsafe { alloc::reallocate(self.data, self.capacity, new_capacity) };
-
+            let ptr = unsafe {
+                alloc::reallocate_with_alignment(
+                    self.data,
+                    self.capacity,
+                    new_capacity,
+                    self.alignment,
+                )
+            };
+
+            if let Some(pool) = &self.pool {
+                pool.shrink(self.capacity - new_capacity);
+            }
             self.data = ptr;
             self.capacity = new_capacity;
         }
@@ -227,6 +361,12 @@ impl MutableBuffer {
         self.capacity
     }
 
+    /// Returns the alignment, in bytes, of this buffer's memory region.
+    #[inline]
+    pub const fn alignment(&self) -> usize {
+        self.alignment
+    }
+
     /// Clear all existing data from this buffer.
     pub fn clear(&mut self) {
         self.len = 0
@@ -265,11 +405,18 @@ impl MutableBuffer {
         self.into_buffer()
     }
 
+    /// Returns the [`MemoryPool`] this buffer is accounted against, if any.
+    pub fn memory_pool(&self) -> Option<&Arc<dyn MemoryPool>> {
+        self.pool.as_ref()
+    }
+
     #[inline]
-    pub(super) fn into_buffer(self) -> Buffer {
-        let bytes = unsafe {
-            Bytes::new(self.data, self.len, Deallocation::Native(self.capacity))
+    pub(super) fn into_buffer(mut self) -> Buffer {
+        let deallocation = match self.pool.take() {
+            Some(pool) => Deallocation::Pooled(self.capacity, self.alignment, pool),
+            None => Deallocation::Native(self.capacity, self.alignment),
         };
+        let bytes = unsafe { Bytes::new(self.data, self.len, deallocation) };
         std::mem::forget(self);
         Buffer::from_bytes(bytes)
     }
@@ -352,20 +499,6 @@ impl MutableBuffer {
     }
 }
 
-/// # Safety
-/// `ptr` must be allocated for `old_capacity`.
-#[inline]
-unsafe fn reallocate(
-    ptr: NonNull<u8>,
-    old_capacity: usize,
-    new_capacity: usize,
-) -> (NonNull<u8>, usize) {
-    let new_capacity = bit_util::round_upto_multiple_of_64(new_capacity);
-    let new_capacity = std::cmp::max(new_capacity, old_capacity * 2);
-    let ptr = alloc::reallocate(ptr, old_capacity, new_capacity);
-    (ptr, new_capacity)
-}
-
 impl<A: ArrowNativeType> Extend<A> for MutableBuffer {
     #[inline]
     fn extend<T: IntoIterator<Item = A>>(&mut self, iter: T) {
@@ -556,7 +689,12 @@ impl std::ops::DerefMut for MutableBuffer {
 
 impl Drop for MutableBuffer {
     fn drop(&mut self) {
-        unsafe { alloc::free_aligned(self.data, self.capacity) };
+        unsafe {
+            alloc::free_aligned_with_alignment(self.data, self.capacity, self.alignment)
+        };
+        if let Some(pool) = &self.pool {
+            pool.shrink(self.capacity);
+        }
     }
 }
 
@@ -598,6 +736,13 @@ impl Drop for SetLenOnDrop<'_> {
 }
 
 /// Creating a `MutableBuffer` instance by setting bits according to the boolean values
+impl<T: ArrowNativeType> From<Vec<T>> for MutableBuffer {
+    #[inline]
+    fn from(vec: Vec<T>) -> Self {
+        Self::from_vec(vec)
+    }
+}
+
 impl std::iter::FromIterator<bool> for MutableBuffer {
     fn from_iter<I>(iter: I) -> Self
     where
@@ -788,4 +933,103 @@ mod tests {
         buffer.shrink_to_fit();
         assert!(buffer.capacity() >= 64 && buffer.capacity() < 128);
     }
+
+    #[test]
+    fn test_mutable_with_alignment() {
+        let mut buffer = MutableBuffer::with_capacity_and_alignment(0, 4096);
+        assert_eq!(buffer.alignment(), 4096);
+        assert_eq!(buffer.as_ptr() as usize % 4096, 0);
+
+        buffer.extend_from_slice(&[1u8; 10000]);
+        assert_eq!(buffer.as_ptr() as usize % 4096, 0);
+        buffer.resize(100, 0);
+        buffer.shrink_to_fit();
+        assert_eq!(buffer.as_ptr() as usize % 4096, 0);
+
+        let buffer: Buffer = buffer.into();
+        assert_eq!(buffer.allocation_alignment(), Some(4096));
+        assert!(buffer.alignment() >= 4096);
+        assert_eq!(buffer.as_slice(), &[1u8; 100][..]);
+    }
+
+    #[test]
+    fn test_mutable_from_vec() {
+        let vec = vec![1u64, 2, 3];
+        let ptr = vec.as_ptr() as *const u8;
+        let mut buffer = MutableBuffer::from(vec);
+        assert_eq!(buffer.as_ptr(), ptr);
+        assert_eq!(buffer.len(), 24);
+        assert_eq!(buffer.alignment(), std::mem::align_of::<u64>());
+
+        buffer.extend_from_slice(&[4u64; 100]);
+        assert_eq!(&buffer.typed_data_mut::<u64>()[..4], &[1, 2, 3, 4]);
+        assert_eq!(buffer.len(), 824);
+
+        buffer.clear();
+        buffer.shrink_to_fit();
+        assert_eq!(buffer.capacity(), 0);
+        buffer.push(5u64);
+        assert_eq!(buffer.alignment(), alloc::ALIGNMENT);
+        assert_eq!(buffer.as_slice(), 5u64.to_byte_slice());
+
+        let buffer = MutableBuffer::from(Vec::<u32>::new());
+        assert_eq!(buffer.capacity(), 0);
+        assert_eq!(buffer.alignment(), alloc::ALIGNMENT);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid alignment 100")]
+    fn test_mutable_with_invalid_alignment() {
+        MutableBuffer::with_capacity_and_alignment(64, 100);
+    }
+
+    #[test]
+    fn test_mutable_memory_pool() {
+        use crate::alloc::TrackingMemoryPool;
+
+        let pool = Arc::new(TrackingMemoryPool::with_limit(256));
+        let mut buffer = MutableBuffer::with_capacity_in(64, pool.clone()).unwrap();
+        assert_eq!(pool.used(), 64);
+
+        // doubling to 128 fits in the pool
+        buffer.extend_from_slice(&[1u8; 65]);
+        assert_eq!(buffer.capacity(), 128);
+        assert_eq!(pool.used(), 128);
+
+        // doubling to 256 does not fit once another buffer holds memory from the pool,
+        // but the strictly required capacity does
+        let other = MutableBuffer::with_capacity_in(64, pool.clone()).unwrap();
+        buffer.try_reserve(100).unwrap();
+        assert_eq!(buffer.capacity(), 192);
+        assert_eq!(pool.used(), 256);
+
+        // nothing is left in the pool
+        assert!(buffer.try_reserve(200).is_err());
+        assert_eq!(buffer.capacity(), 192);
+        assert!(MutableBuffer::with_capacity_in(1, pool.clone()).is_err());
+        // every rejected request counts, including the doublings retried with less
+        assert_eq!(pool.statistics().rejected, 4);
+
+        buffer.shrink_to_fit();
+        assert_eq!(buffer.capacity(), 128);
+        assert_eq!(pool.used(), 192);
+
+        // the memory is accounted until the frozen buffer is dropped
+        let buffer: Buffer = buffer.into();
+        drop(other);
+        assert_eq!(pool.used(), 128);
+        drop(buffer);
+        assert_eq!(pool.used(), 0);
+        assert_eq!(pool.statistics().peak, 256);
+    }
+
+    #[test]
+    #[should_panic(expected = "MutableBuffer::reserve")]
+    fn test_mutable_memory_pool_reserve_panics() {
+        use crate::alloc::TrackingMemoryPool;
+
+        let pool = Arc::new(TrackingMemoryPool::with_limit(64));
+        let mut buffer = MutableBuffer::with_capacity_in(64, pool).unwrap();
+        buffer.reserve(65);
+    }
 }
@@ -0,0 +1,155 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use super::BooleanBuffer;
+
+/// A validity bitmap, i.e. a [BooleanBuffer] whose set bits are the valid (non-null)
+/// slots of an array, along with its number of nulls.
+///
+/// It can be created from the output of a kernel without copying its bits, e.g. to null
+/// the slots of an array where a comparison is false, see
+/// [ArrayDataBuilder::nulls](crate::array::ArrayDataBuilder::nulls).
+///
+/// # Example
+/// ```
+/// use arrow::array::{Array, ArrayData, BooleanArray, Int32Array};
+/// use arrow::buffer::NullBuffer;
+/// use arrow::datatypes::DataType;
+///
+/// let array = Int32Array::from(vec![1, 2, 3]);
+/// let positive = BooleanArray::from(vec![true, false, true]);
+///
+/// let nulls = NullBuffer::from(&positive);
+/// assert_eq!(nulls.null_count(), 1);
+/// let data = ArrayData::builder(DataType::Int32)
+///     .len(3)
+///     .add_buffer(array.data().buffers()[0].clone())
+///     .nulls(Some(nulls))
+///     .build();
+/// let array = Int32Array::from(data);
+/// assert_eq!(array, Int32Array::from(vec![Some(1), None, Some(3)]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct NullBuffer {
+    buffer: BooleanBuffer,
+    null_count: usize,
+}
+
+impl NullBuffer {
+    /// Creates a validity bitmap whose valid slots are the set bits of `buffer`.
+    pub fn new(buffer: BooleanBuffer) -> Self {
+        let null_count = buffer.len() - buffer.count_set_bits();
+        Self { buffer, null_count }
+    }
+
+    /// Creates a validity bitmap of `buffer` with `null_count` nulls, without counting
+    /// them.
+    ///
+    /// # Safety
+    ///
+    /// `null_count` must be the number of unset bits of `buffer`.
+    pub unsafe fn new_unchecked(buffer: BooleanBuffer, null_count: usize) -> Self {
+        Self { buffer, null_count }
+    }
+
+    /// Creates a validity bitmap of `len` null slots.
+    pub fn new_null(len: usize) -> Self {
+        Self {
+            buffer: BooleanBuffer::new_with_value(false, len),
+            null_count: len,
+        }
+    }
+
+    /// Returns the number of slots of this bitmap.
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Returns whether this bitmap has no slots.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Returns the number of null slots of this bitmap.
+    pub fn null_count(&self) -> usize {
+        self.null_count
+    }
+
+    /// Returns whether the slot at index `i` is valid.
+    pub fn is_valid(&self, i: usize) -> bool {
+        self.buffer.value(i)
+    }
+
+    /// Returns whether the slot at index `i` is null.
+    pub fn is_null(&self, i: usize) -> bool {
+        !self.is_valid(i)
+    }
+
+    /// Returns the bits of this bitmap.
+    pub fn inner(&self) -> &BooleanBuffer {
+        &self.buffer
+    }
+
+    /// Returns the bits of this bitmap.
+    pub fn into_inner(self) -> BooleanBuffer {
+        self.buffer
+    }
+
+    /// Returns a zero-copy slice of this bitmap, with `len` slots from `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + len` is greater than the length of this bitmap.
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        Self::new(self.buffer.slice(offset, len))
+    }
+}
+
+impl From<BooleanBuffer> for NullBuffer {
+    fn from(buffer: BooleanBuffer) -> Self {
+        Self::new(buffer)
+    }
+}
+
+impl From<NullBuffer> for BooleanBuffer {
+    fn from(nulls: NullBuffer) -> Self {
+        nulls.buffer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::Buffer;
+
+    #[test]
+    fn test_null_buffer() {
+        let nulls = NullBuffer::new(BooleanBuffer::new(Buffer::from([0b01101101]), 2, 5));
+        assert_eq!(nulls.len(), 5);
+        assert_eq!(nulls.null_count(), 1);
+        assert!(nulls.is_null(2));
+        assert!(nulls.is_valid(3));
+
+        let sliced = nulls.slice(3, 2);
+        assert_eq!(sliced.null_count(), 0);
+        assert_eq!(sliced.inner().offset(), 5);
+
+        let nulls = NullBuffer::new_null(3);
+        assert_eq!(nulls.null_count(), 3);
+        assert_eq!(BooleanBuffer::from(nulls).count_set_bits(), 0);
+    }
+}
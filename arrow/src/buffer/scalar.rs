@@ -0,0 +1,162 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Deref;
+
+use crate::datatypes::ArrowNativeType;
+
+use super::Buffer;
+
+/// A slice of values of type `T` of a [Buffer], such as the values of a
+/// [PrimitiveArray](crate::array::PrimitiveArray).
+///
+/// Unlike [Buffer::typed_data], which is `unsafe`, the length and the alignment of the
+/// values are validated once when it is created, after which it can be used as a `&[T]`.
+///
+/// # Example
+/// ```
+/// use arrow::buffer::{Buffer, ScalarBuffer};
+///
+/// let buffer = Buffer::from_slice_ref(&[1i32, 2, 3, 4]);
+/// let values = ScalarBuffer::<i32>::new(buffer, 1, 2);
+/// assert_eq!(&*values, &[2, 3]);
+/// assert_eq!(values.iter().sum::<i32>(), 5);
+/// ```
+#[derive(Clone)]
+pub struct ScalarBuffer<T: ArrowNativeType> {
+    /// The bytes of the values, starting at the first one
+    buffer: Buffer,
+    len: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ArrowNativeType> ScalarBuffer<T> {
+    /// Creates a scalar buffer of the `len` values of `buffer` starting at the value at
+    /// `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` has fewer than `offset + len` values, or if the values aren't
+    /// aligned to `T`.
+    pub fn new(buffer: Buffer, offset: usize, len: usize) -> Self {
+        let size = mem::size_of::<T>();
+        assert!(
+            (offset + len) * size <= buffer.len(),
+            "the offset and length of a scalar buffer must be within its buffer"
+        );
+        let buffer = buffer.slice(offset * size);
+        assert_eq!(
+            buffer.as_ptr().align_offset(mem::align_of::<T>()),
+            0,
+            "memory is not aligned"
+        );
+        Self {
+            buffer,
+            len,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns a zero-copy slice of this buffer with `len` values starting at `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + len` is greater than the number of values.
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        assert!(
+            offset + len <= self.len,
+            "the offset and length of the slice of a scalar buffer must be in bounds"
+        );
+        Self::new(self.buffer.clone(), offset, len)
+    }
+
+    /// Returns the buffer of the values, which starts at the first value and may be
+    /// longer than the values.
+    pub fn inner(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Returns the buffer of the values, like [ScalarBuffer::inner].
+    pub fn into_inner(self) -> Buffer {
+        self.buffer
+    }
+}
+
+impl<T: ArrowNativeType> Deref for ScalarBuffer<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // Soundness
+        //     the bounds and the alignment of the values are checked in `new`, and the
+        //     buffer is immutable.
+        unsafe { std::slice::from_raw_parts(self.buffer.as_ptr() as *const T, self.len) }
+    }
+}
+
+impl<T: ArrowNativeType> AsRef<[T]> for ScalarBuffer<T> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: ArrowNativeType> fmt::Debug for ScalarBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("ScalarBuffer").field(&self.as_ref()).finish()
+    }
+}
+
+impl<T: ArrowNativeType> From<Vec<T>> for ScalarBuffer<T> {
+    fn from(values: Vec<T>) -> Self {
+        let len = values.len();
+        Self::new(Buffer::from_slice_ref(&values), 0, len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scalar_buffer() {
+        let values = ScalarBuffer::<i64>::from(vec![1, 2, 3, 4, 5]);
+        assert_eq!(values.len(), 5);
+        assert_eq!(&*values, &[1, 2, 3, 4, 5]);
+
+        let sliced = values.slice(1, 3);
+        assert_eq!(&*sliced, &[2, 3, 4]);
+        assert_eq!(sliced.slice(2, 1).as_ref(), &[4]);
+        assert!(sliced.slice(3, 0).is_empty());
+        assert_eq!(format!("{:?}", sliced), "ScalarBuffer([2, 3, 4])");
+    }
+
+    #[test]
+    #[should_panic(expected = "must be within its buffer")]
+    fn test_scalar_buffer_out_of_bounds() {
+        let buffer = Buffer::from_slice_ref(&[1i32, 2, 3]);
+        ScalarBuffer::<i32>::new(buffer, 1, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "memory is not aligned")]
+    #[cfg_attr(miri, ignore)]
+    fn test_scalar_buffer_unaligned() {
+        let buffer = Buffer::from_slice_ref(&[1i32, 2, 3]).slice(1);
+        ScalarBuffer::<i32>::new(buffer, 0, 2);
+    }
+}
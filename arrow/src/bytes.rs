@@ -24,25 +24,46 @@ use std::ptr::NonNull;
 use std::sync::Arc;
 use std::{fmt::Debug, fmt::Formatter};
 
+use crate::alloc::{Allocation, MemoryPool};
+use crate::datatypes::ArrowNativeType;
 use crate::{alloc, ffi};
 
 /// Mode of deallocating memory regions
 pub enum Deallocation {
-    /// Native deallocation, using Rust deallocator with Arrow-specific memory aligment
-    Native(usize),
+    /// Native deallocation, using Rust deallocator with Arrow-specific memory aligment,
+    /// of a region of the given capacity and alignment
+    Native(usize, usize),
+    /// Native deallocation whose capacity is released back to a [`MemoryPool`]
+    Pooled(usize, usize, Arc<dyn MemoryPool>),
     /// Foreign interface, via a callback
     Foreign(Arc<ffi::FFI_ArrowArray>),
+    /// Custom deallocation, by dropping the owner of the region
+    Custom(Arc<dyn Allocation>),
 }
 
 impl Debug for Deallocation {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            Deallocation::Native(capacity) => {
-                write!(f, "Deallocation::Native {{ capacity: {} }}", capacity)
+            Deallocation::Native(capacity, alignment) => {
+                write!(
+                    f,
+                    "Deallocation::Native {{ capacity: {}, alignment: {} }}",
+                    capacity, alignment
+                )
+            }
+            Deallocation::Pooled(capacity, alignment, pool) => {
+                write!(
+                    f,
+                    "Deallocation::Pooled {{ capacity: {}, alignment: {}, pool: {:?} }}",
+                    capacity, alignment, pool
+                )
             }
             Deallocation::Foreign(_) => {
                 write!(f, "Deallocation::Foreign {{ capacity: unknown }}")
             }
+            Deallocation::Custom(_) => {
+                write!(f, "Deallocation::Custom {{ capacity: unknown }}")
+            }
         }
     }
 }
@@ -85,6 +106,9 @@ impl Bytes {
         len: usize,
         deallocation: Deallocation,
     ) -> Bytes {
+        if let Deallocation::Foreign(_) = deallocation {
+            ffi::track_foreign_bytes(len);
+        }
         Bytes {
             ptr,
             len,
@@ -113,10 +137,54 @@ impl Bytes {
 
     pub fn capacity(&self) -> usize {
         match self.deallocation {
-            Deallocation::Native(capacity) => capacity,
+            Deallocation::Native(capacity, _) | Deallocation::Pooled(capacity, _, _) => {
+                capacity
+            }
             // we cannot determine this in general,
             // and thus we state that this is externally-owned memory
-            Deallocation::Foreign(_) => 0,
+            Deallocation::Foreign(_) | Deallocation::Custom(_) => 0,
+        }
+    }
+
+    /// Returns the alignment this region was allocated with, or `None` when it
+    /// was not allocated by Arrow.
+    pub fn alignment(&self) -> Option<usize> {
+        match self.deallocation {
+            Deallocation::Native(_, alignment)
+            | Deallocation::Pooled(_, alignment, _) => Some(alignment),
+            Deallocation::Foreign(_) | Deallocation::Custom(_) => None,
+        }
+    }
+}
+
+impl Bytes {
+    /// Converts this region into a `Vec<T>` without copying it, or returns it unchanged
+    /// when it was not allocated by Rust's allocator with the layout of a `Vec<T>`, e.g.
+    /// when it was allocated along [`alloc::ALIGNMENT`].
+    pub(crate) fn try_into_vec<T: ArrowNativeType>(self) -> Result<Vec<T>, Self> {
+        let size = std::mem::size_of::<T>();
+        match self.deallocation {
+            Deallocation::Native(capacity, alignment)
+                if alignment == std::mem::align_of::<T>()
+                    && capacity % size == 0
+                    && self.len % size == 0 =>
+            {
+                // the region is no longer freed by Arrow
+                unsafe {
+                    alloc::ALLOCATIONS
+                        .fetch_sub(capacity as isize, std::sync::atomic::Ordering::SeqCst)
+                };
+                let vec = unsafe {
+                    Vec::from_raw_parts(
+                        self.ptr.as_ptr() as *mut T,
+                        self.len / size,
+                        capacity / size,
+                    )
+                };
+                std::mem::forget(self);
+                Ok(vec)
+            }
+            _ => Err(self),
         }
     }
 }
@@ -125,11 +193,25 @@ impl Drop for Bytes {
     #[inline]
     fn drop(&mut self) {
         match &self.deallocation {
-            Deallocation::Native(capacity) => {
-                unsafe { alloc::free_aligned::<u8>(self.ptr, *capacity) };
+            Deallocation::Native(capacity, alignment) => {
+                unsafe {
+                    alloc::free_aligned_with_alignment::<u8>(
+                        self.ptr, *capacity, *alignment,
+                    )
+                };
+            }
+            Deallocation::Pooled(capacity, alignment, pool) => {
+                unsafe {
+                    alloc::free_aligned_with_alignment::<u8>(
+                        self.ptr, *capacity, *alignment,
+                    )
+                };
+                pool.shrink(*capacity);
             }
             // foreign interface knows how to deallocate itself.
-            Deallocation::Foreign(_) => (),
+            Deallocation::Foreign(_) => ffi::untrack_foreign_bytes(self.len),
+            // the owner releases the region when its last reference is dropped.
+            Deallocation::Custom(_) => {}
         }
     }
 }
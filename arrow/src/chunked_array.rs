@@ -0,0 +1,409 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A column made of several arrays of the same type, which are not concatenated.
+
+use crate::array::*;
+use crate::compute::kernels::{concat::concat, filter::filter};
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// A column of a single [DataType] made of a sequence of arrays, its chunks, e.g. the
+/// columns of several [RecordBatch](crate::record_batch::RecordBatch)es, which can be
+/// larger than a single array without concatenating them.
+///
+/// Kernels of arrays are applied to each chunk with [ChunkedArray::try_map] and
+/// [ChunkedArray::try_zip_map].
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{Array, ArrayRef, Int32Array};
+/// use arrow::chunked_array::ChunkedArray;
+///
+/// # fn main() -> arrow::error::Result<()> {
+/// let chunks: Vec<ArrayRef> = vec![
+///     Arc::new(Int32Array::from(vec![1, 2, 3])),
+///     Arc::new(Int32Array::from(vec![4, 5])),
+/// ];
+/// let chunked = ChunkedArray::try_new(chunks)?;
+/// assert_eq!(chunked.len(), 5);
+///
+/// // the slice spans both chunks
+/// let sliced = chunked.slice(2, 2);
+/// assert_eq!(sliced.num_chunks(), 2);
+/// assert_eq!(sliced.concat()?.as_ref(), &Int32Array::from(vec![3, 4]));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChunkedArray {
+    data_type: DataType,
+    chunks: Vec<ArrayRef>,
+    len: usize,
+}
+
+impl ChunkedArray {
+    /// Creates a chunked array from its chunks, which must have the same data type.
+    ///
+    /// Returns an error if there are no chunks, as the data type of the array is unknown,
+    /// or if they don't have the same data type.
+    pub fn try_new(chunks: Vec<ArrayRef>) -> Result<Self> {
+        let data_type = chunks
+            .first()
+            .ok_or_else(|| {
+                ArrowError::InvalidArgumentError(
+                    "A chunked array needs a data type or at least one chunk".to_string(),
+                )
+            })?
+            .data_type()
+            .clone();
+        Self::try_new_with_type(data_type, chunks)
+    }
+
+    /// Creates a chunked array of `data_type` from its chunks, which may be empty.
+    ///
+    /// Returns an error if a chunk is not of `data_type`.
+    pub fn try_new_with_type(data_type: DataType, chunks: Vec<ArrayRef>) -> Result<Self> {
+        if let Some(chunk) = chunks.iter().find(|c| c.data_type() != &data_type) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The chunks of a chunked array must be of type {:?}, found {:?}",
+                data_type,
+                chunk.data_type()
+            )));
+        }
+        let len = chunks.iter().map(|c| c.len()).sum();
+        Ok(Self {
+            data_type,
+            chunks,
+            len,
+        })
+    }
+
+    /// Creates a chunked array of `data_type` without chunks.
+    pub fn new_empty(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            chunks: vec![],
+            len: 0,
+        }
+    }
+
+    /// Returns the data type of the chunks of this array.
+    pub fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    /// Returns the number of values of this array, the sum of the lengths of its chunks.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether this array has no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of null values of this array.
+    pub fn null_count(&self) -> usize {
+        self.chunks.iter().map(|c| c.null_count()).sum()
+    }
+
+    /// Returns the number of chunks of this array.
+    pub fn num_chunks(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Returns the chunk at `index`.
+    pub fn chunk(&self, index: usize) -> &ArrayRef {
+        &self.chunks[index]
+    }
+
+    /// Returns the chunks of this array.
+    pub fn chunks(&self) -> &[ArrayRef] {
+        &self.chunks
+    }
+
+    /// Returns a zero-copy slice of this array, with the chunks or parts of chunks of the
+    /// `length` values from `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + length` is greater than the length of the array.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.len,
+            "the offset and length of the slice of a chunked array must be in bounds"
+        );
+        let mut chunks = vec![];
+        let mut offset = offset;
+        let mut remaining = length;
+        for chunk in &self.chunks {
+            if remaining == 0 {
+                break;
+            }
+            if offset >= chunk.len() {
+                offset -= chunk.len();
+                continue;
+            }
+            let len = (chunk.len() - offset).min(remaining);
+            chunks.push(chunk.slice(offset, len));
+            remaining -= len;
+            offset = 0;
+        }
+        Self {
+            data_type: self.data_type.clone(),
+            chunks,
+            len: length,
+        }
+    }
+
+    /// Returns the values of this array as a single array, concatenating the chunks. A
+    /// single chunk is returned without copying it.
+    pub fn concat(&self) -> Result<ArrayRef> {
+        match self.chunks.as_slice() {
+            [] => Ok(new_empty_array(&self.data_type)),
+            [chunk] => Ok(chunk.clone()),
+            chunks => {
+                let chunks = chunks.iter().map(|c| c.as_ref()).collect::<Vec<_>>();
+                concat(&chunks)
+            }
+        }
+    }
+
+    /// Returns the values of this array where `predicate` is true, as
+    /// [filter](crate::compute::kernels::filter::filter) does. `predicate` must be a
+    /// boolean array of the length of this array, and its chunks don't need to match the
+    /// chunks of this array.
+    pub fn filter(&self, predicate: &ChunkedArray) -> Result<Self> {
+        if predicate.data_type() != &DataType::Boolean {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Can't filter with a predicate of type {:?}",
+                predicate.data_type()
+            )));
+        }
+        self.try_zip_map(predicate, |array, predicate| {
+            let predicate = predicate.as_any().downcast_ref::<BooleanArray>().unwrap();
+            filter(array, predicate)
+        })
+    }
+
+    /// Applies `op`, e.g. a unary kernel, to each chunk of this array, returning the chunked
+    /// array of the results, which must be of the same data type.
+    ///
+    /// When this array has no chunks, `op` is applied to an empty array to find the data
+    /// type of the result.
+    pub fn try_map<F>(&self, op: F) -> Result<Self>
+    where
+        F: Fn(&dyn Array) -> Result<ArrayRef>,
+    {
+        let chunks = self
+            .chunks
+            .iter()
+            .map(|chunk| op(chunk.as_ref()))
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_results(chunks, || op(new_empty_array(&self.data_type).as_ref()))
+    }
+
+    /// Applies `op`, e.g. a binary kernel, to the pairs of arrays of the same length of the
+    /// values of this array and of `other`, returning the chunked array of the results,
+    /// which must be of the same data type. The chunks of both arrays are sliced where
+    /// either of them starts a new chunk.
+    ///
+    /// Returns an error if both arrays don't have the same length.
+    pub fn try_zip_map<F>(&self, other: &ChunkedArray, op: F) -> Result<Self>
+    where
+        F: Fn(&dyn Array, &dyn Array) -> Result<ArrayRef>,
+    {
+        if self.len != other.len {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Can't combine chunked arrays of different lengths {} and {}",
+                self.len, other.len
+            )));
+        }
+        let mut left_chunks = self.chunks.iter().filter(|c| !c.is_empty());
+        let mut right_chunks = other.chunks.iter().filter(|c| !c.is_empty());
+        let (mut left, mut right) = (left_chunks.next(), right_chunks.next());
+        let (mut left_offset, mut right_offset) = (0, 0);
+
+        let mut chunks = vec![];
+        while let (Some(l), Some(r)) = (left, right) {
+            let len = (l.len() - left_offset).min(r.len() - right_offset);
+            chunks.push(op(
+                l.slice(left_offset, len).as_ref(),
+                r.slice(right_offset, len).as_ref(),
+            )?);
+            left_offset += len;
+            right_offset += len;
+            if left_offset == l.len() {
+                left = left_chunks.next();
+                left_offset = 0;
+            }
+            if right_offset == r.len() {
+                right = right_chunks.next();
+                right_offset = 0;
+            }
+        }
+        Self::from_results(chunks, || {
+            op(
+                new_empty_array(&self.data_type).as_ref(),
+                new_empty_array(&other.data_type).as_ref(),
+            )
+        })
+    }
+
+    /// Creates a chunked array of the results of a kernel, calling `empty` to find their
+    /// data type when there are none.
+    fn from_results<F>(chunks: Vec<ArrayRef>, empty: F) -> Result<Self>
+    where
+        F: FnOnce() -> Result<ArrayRef>,
+    {
+        let data_type = match chunks.first() {
+            Some(chunk) => chunk.data_type().clone(),
+            None => empty()?.data_type().clone(),
+        };
+        Self::try_new_with_type(data_type, chunks)
+    }
+}
+
+impl From<ArrayRef> for ChunkedArray {
+    fn from(array: ArrayRef) -> Self {
+        Self {
+            data_type: array.data_type().clone(),
+            len: array.len(),
+            chunks: vec![array],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::compute::kernels::arithmetic::add;
+
+    fn int32_chunked(chunks: Vec<Vec<Option<i32>>>) -> ChunkedArray {
+        let chunks = chunks
+            .into_iter()
+            .map(|c| Arc::new(Int32Array::from(c)) as ArrayRef)
+            .collect();
+        ChunkedArray::try_new_with_type(DataType::Int32, chunks).unwrap()
+    }
+
+    #[test]
+    fn test_chunked_array() {
+        let chunked = int32_chunked(vec![vec![Some(1), None], vec![], vec![Some(3)]]);
+        assert_eq!(chunked.len(), 3);
+        assert_eq!(chunked.null_count(), 1);
+        assert_eq!(chunked.num_chunks(), 3);
+        assert_eq!(
+            chunked.concat().unwrap().as_ref(),
+            &Int32Array::from(vec![Some(1), None, Some(3)])
+        );
+
+        let empty = ChunkedArray::new_empty(DataType::Utf8);
+        assert!(empty.is_empty());
+        assert_eq!(empty.concat().unwrap().data_type(), &DataType::Utf8);
+    }
+
+    #[test]
+    fn test_chunked_array_invalid() {
+        assert!(ChunkedArray::try_new(vec![]).is_err());
+        let chunks: Vec<ArrayRef> = vec![
+            Arc::new(Int32Array::from(vec![1])),
+            Arc::new(Int64Array::from(vec![1])),
+        ];
+        assert!(ChunkedArray::try_new(chunks).is_err());
+    }
+
+    #[test]
+    fn test_chunked_array_slice() {
+        let chunked = int32_chunked(vec![
+            vec![Some(1), Some(2)],
+            vec![Some(3), Some(4), Some(5)],
+            vec![Some(6)],
+        ]);
+
+        let sliced = chunked.slice(1, 4);
+        assert_eq!(sliced.len(), 4);
+        assert_eq!(sliced.num_chunks(), 2);
+        assert_eq!(
+            sliced.concat().unwrap().as_ref(),
+            &Int32Array::from(vec![2, 3, 4, 5])
+        );
+
+        let sliced = chunked.slice(5, 1);
+        assert_eq!(sliced.num_chunks(), 1);
+        assert_eq!(sliced.chunk(0).as_ref(), &Int32Array::from(vec![6]));
+
+        assert_eq!(chunked.slice(6, 0).num_chunks(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "must be in bounds")]
+    fn test_chunked_array_slice_out_of_bounds() {
+        int32_chunked(vec![vec![Some(1)]]).slice(1, 1);
+    }
+
+    #[test]
+    fn test_chunked_array_filter() {
+        let chunked = int32_chunked(vec![
+            vec![Some(1), Some(2)],
+            vec![Some(3), Some(4), Some(5)],
+        ]);
+        let predicate = ChunkedArray::try_new(vec![
+            Arc::new(BooleanArray::from(vec![true, false, true])) as ArrayRef,
+            Arc::new(BooleanArray::from(vec![Some(false), None])) as ArrayRef,
+        ])
+        .unwrap();
+
+        let filtered = chunked.filter(&predicate).unwrap();
+        assert_eq!(filtered.num_chunks(), 3);
+        assert_eq!(
+            filtered.concat().unwrap().as_ref(),
+            &Int32Array::from(vec![1, 3])
+        );
+
+        assert!(chunked.filter(&chunked).is_err());
+        assert!(chunked.filter(&predicate.slice(0, 4)).is_err());
+    }
+
+    #[test]
+    fn test_chunked_array_map() {
+        let left = int32_chunked(vec![vec![Some(1), Some(2), Some(3)]]);
+        let right = int32_chunked(vec![vec![Some(10)], vec![Some(20), None]]);
+
+        let sum = left
+            .try_zip_map(&right, |l, r| {
+                let l = l.as_any().downcast_ref::<Int32Array>().unwrap();
+                let r = r.as_any().downcast_ref::<Int32Array>().unwrap();
+                Ok(Arc::new(add(l, r)?))
+            })
+            .unwrap();
+        assert_eq!(
+            sum.concat().unwrap().as_ref(),
+            &Int32Array::from(vec![Some(11), Some(22), None])
+        );
+
+        let lengths = ChunkedArray::new_empty(DataType::Utf8)
+            .try_map(crate::compute::kernels::length::length)
+            .unwrap();
+        assert_eq!(lengths.data_type(), &DataType::Int32);
+        assert_eq!(lengths.num_chunks(), 0);
+    }
+}
@@ -33,7 +33,7 @@ fn is_nan<T: ArrowNativeType + PartialOrd + Copy>(a: T) -> bool {
 }
 
 /// Helper macro to perform min/max of strings
-fn min_max_string<T: StringOffsetSizeTrait, F: Fn(&str, &str) -> bool>(
+fn min_max_string_helper<T: StringOffsetSizeTrait, F: Fn(&str, &str) -> bool>(
     array: &GenericStringArray<T>,
     cmp: F,
 ) -> Option<&str> {
@@ -93,14 +93,106 @@ where
 pub fn max_string<T: StringOffsetSizeTrait>(
     array: &GenericStringArray<T>,
 ) -> Option<&str> {
-    min_max_string(array, |a, b| a < b)
+    min_max_string_helper(array, |a, b| a < b)
 }
 
 /// Returns the minimum value in the string array, according to the natural order.
 pub fn min_string<T: StringOffsetSizeTrait>(
     array: &GenericStringArray<T>,
 ) -> Option<&str> {
-    min_max_string(array, |a, b| a > b)
+    min_max_string_helper(array, |a, b| a > b)
+}
+
+/// Returns the minimum and the maximum values in the string array, according to the
+/// natural order, in a single pass over the array.
+///
+/// ```
+/// use arrow::{array::StringArray, compute::min_max_string};
+///
+/// let a = StringArray::from(vec![Some("b"), None, Some("a"), Some("c")]);
+/// assert_eq!(min_max_string(&a), (Some("a"), Some("c")));
+/// ```
+pub fn min_max_string<T: StringOffsetSizeTrait>(
+    array: &GenericStringArray<T>,
+) -> (Option<&str>, Option<&str>) {
+    let mut bounds: Option<(&str, &str)> = None;
+    for i in 0..array.len() {
+        if array.is_valid(i) {
+            let item = array.value(i);
+            bounds = Some(match bounds {
+                Some((min, max)) => (min.min(item), max.max(item)),
+                None => (item, item),
+            });
+        }
+    }
+    match bounds {
+        Some((min, max)) => (Some(min), Some(max)),
+        None => (None, None),
+    }
+}
+
+/// Returns the minimum and the maximum values in the array, in a single pass over the
+/// array. They are the values returned by [min] and [max] respectively, so NaN values are
+/// considered to be greater than any other non-null value.
+///
+/// ```
+/// use arrow::{array::Int32Array, compute::min_max};
+///
+/// let a = Int32Array::from(vec![Some(5), None, Some(2), Some(8)]);
+/// assert_eq!(min_max(&a), (Some(2), Some(8)));
+/// ```
+#[cfg(not(feature = "simd"))]
+pub fn min_max<T>(array: &PrimitiveArray<T>) -> (Option<T::Native>, Option<T::Native>)
+where
+    T: ArrowNumericType,
+    T::Native: ArrowNativeType,
+{
+    match min_max_pair_helper(array) {
+        Some((min, max)) => (Some(min), Some(max)),
+        None => (None, None),
+    }
+}
+
+/// Helper function to compute both the minimum and the maximum of a numeric array.
+#[multiversion]
+#[clone(target = "x86_64+avx")]
+fn min_max_pair_helper<T>(array: &PrimitiveArray<T>) -> Option<(T::Native, T::Native)>
+where
+    T: ArrowNumericType,
+{
+    let null_count = array.null_count();
+
+    // Includes case array.len() == 0
+    if null_count == array.len() {
+        return None;
+    }
+
+    // NaN is greater than any other value
+    let is_less = |a: &T::Native, b: &T::Native| (!is_nan(*a) & is_nan(*b)) || a < b;
+    let update = |(min, max): (T::Native, T::Native), item: &T::Native| {
+        (
+            if is_less(item, &min) { *item } else { min },
+            if is_less(&max, item) { *item } else { max },
+        )
+    };
+
+    let data = array.data();
+    let m = array.values();
+    if null_count == 0 {
+        // optimized path for arrays without null values
+        Some(m[1..].iter().fold((m[0], m[0]), update))
+    } else {
+        let mut bounds = None;
+        for (i, item) in m.iter().enumerate() {
+            if data.is_valid(i) {
+                bounds = Some(match bounds {
+                    Some(bounds) => update(bounds, item),
+                    None => (*item, *item),
+                });
+            }
+        }
+        bounds
+    }
 }
 
 /// Helper function to perform min/max lambda function on values from a numeric array.
@@ -190,6 +282,72 @@ pub fn max_boolean(array: &BooleanArray) -> Option<bool> {
         .or(Some(false))
 }
 
+/// Returns whether any non-null value of the boolean array is true, like the SQL `bool_or`
+/// aggregate (also known as `any`).
+///
+/// Returns `None` if the array is empty or only contains null values. The values are
+/// checked 64 at a time, stopping at the first true value.
+///
+/// ```
+/// use arrow::{array::BooleanArray, compute::bool_or};
+///
+/// let a = BooleanArray::from(vec![Some(false), None, Some(true)]);
+/// assert_eq!(bool_or(&a), Some(true));
+/// let a = BooleanArray::from(vec![Some(false), None]);
+/// assert_eq!(bool_or(&a), Some(false));
+/// ```
+pub fn bool_or(array: &BooleanArray) -> Option<bool> {
+    any_bool_chunk(array, |values, valid| values & valid != 0)
+}
+
+/// Returns whether all the non-null values of the boolean array are true, like the SQL
+/// `bool_and` aggregate (also known as `every`).
+///
+/// Returns `None` if the array is empty or only contains null values. The values are
+/// checked 64 at a time, stopping at the first false value.
+///
+/// ```
+/// use arrow::{array::BooleanArray, compute::bool_and};
+///
+/// let a = BooleanArray::from(vec![Some(true), None, Some(true)]);
+/// assert_eq!(bool_and(&a), Some(true));
+/// let a = BooleanArray::from(vec![Some(true), None, Some(false)]);
+/// assert_eq!(bool_and(&a), Some(false));
+/// ```
+pub fn bool_and(array: &BooleanArray) -> Option<bool> {
+    any_bool_chunk(array, |values, valid| !values & valid != 0).map(|found| !found)
+}
+
+/// Returns whether `found` holds for any chunk of 64 values of the boolean array and their
+/// validity bits, or `None` if the array is empty or only contains null values.
+fn any_bool_chunk<F>(array: &BooleanArray, found: F) -> Option<bool>
+where
+    F: Fn(u64, u64) -> bool,
+{
+    // short circuit if all nulls / zero length array
+    if array.null_count() == array.len() {
+        return None;
+    }
+
+    let values = array.values().bit_chunks(array.offset(), array.len());
+    let any = match array.data_ref().null_buffer() {
+        None => {
+            let remainder_valid = (1u64 << values.remainder_len()) - 1;
+            values.iter().any(|chunk| found(chunk, u64::MAX))
+                || found(values.remainder_bits(), remainder_valid)
+        }
+        Some(buffer) => {
+            let valid = buffer.bit_chunks(array.offset(), array.len());
+            values
+                .iter()
+                .zip(valid.iter())
+                .any(|(chunk, valid)| found(chunk, valid))
+                || found(values.remainder_bits(), valid.remainder_bits())
+        }
+    };
+    Some(any)
+}
+
 /// Returns the sum of values in the array.
 ///
 /// Returns `None` if the array is empty or only contains null values.
@@ -258,6 +416,7 @@ mod simd {
     pub(super) trait SimdAggregate<T: ArrowNumericType> {
         type ScalarAccumulator;
         type SimdAccumulator;
+        type Output;
 
         /// Returns the accumulator for aggregating scalar values
         fn init_accumulator_scalar() -> Self::ScalarAccumulator;
@@ -285,7 +444,7 @@ mod simd {
         fn reduce(
             simd_accumulator: Self::SimdAccumulator,
             scalar_accumulator: Self::ScalarAccumulator,
-        ) -> Option<T::Native>;
+        ) -> Option<Self::Output>;
     }
 
     pub(super) struct SumAggregate<T: ArrowNumericType> {
@@ -298,6 +457,7 @@ mod simd {
     {
         type ScalarAccumulator = T::Native;
         type SimdAccumulator = T::Simd;
+        type Output = T::Native;
 
         fn init_accumulator_scalar() -> Self::ScalarAccumulator {
             T::default_value()
@@ -329,7 +489,7 @@ mod simd {
         fn reduce(
             simd_accumulator: Self::SimdAccumulator,
             scalar_accumulator: Self::ScalarAccumulator,
-        ) -> Option<T::Native> {
+        ) -> Option<Self::Output> {
             // we can't use T::lanes() as the slice len because it is not const,
             // instead always reserve the maximum number of lanes
             let mut tmp = [T::default_value(); 64];
@@ -358,6 +518,7 @@ mod simd {
     {
         type ScalarAccumulator = (T::Native, bool);
         type SimdAccumulator = (T::Simd, T::SimdMask);
+        type Output = T::Native;
 
         fn init_accumulator_scalar() -> Self::ScalarAccumulator {
             (T::default_value(), false)
@@ -410,7 +571,7 @@ mod simd {
         fn reduce(
             simd_accumulator: Self::SimdAccumulator,
             scalar_accumulator: Self::ScalarAccumulator,
-        ) -> Option<T::Native> {
+        ) -> Option<Self::Output> {
             // we can't use T::lanes() as the slice len because it is not const,
             // instead always reserve the maximum number of lanes
             let mut tmp = [T::default_value(); 64];
@@ -446,6 +607,7 @@ mod simd {
     {
         type ScalarAccumulator = (T::Native, bool);
         type SimdAccumulator = (T::Simd, T::SimdMask);
+        type Output = T::Native;
 
         fn init_accumulator_scalar() -> Self::ScalarAccumulator {
             (T::default_value(), false)
@@ -498,7 +660,7 @@ mod simd {
         fn reduce(
             simd_accumulator: Self::SimdAccumulator,
             scalar_accumulator: Self::ScalarAccumulator,
-        ) -> Option<T::Native> {
+        ) -> Option<Self::Output> {
             // we can't use T::lanes() as the slice len because it is not const,
             // instead always reserve the maximum number of lanes
             let mut tmp = [T::default_value(); 64];
@@ -524,9 +686,86 @@ mod simd {
         }
     }
 
+    pub(super) struct MinMaxAggregate<T: ArrowNumericType> {
+        phantom: PhantomData<T>,
+    }
+
+    impl<T: ArrowNumericType> SimdAggregate<T> for MinMaxAggregate<T>
+    where
+        T::Native: PartialOrd,
+    {
+        type ScalarAccumulator = (
+            <MinAggregate<T> as SimdAggregate<T>>::ScalarAccumulator,
+            <MaxAggregate<T> as SimdAggregate<T>>::ScalarAccumulator,
+        );
+        type SimdAccumulator = (
+            <MinAggregate<T> as SimdAggregate<T>>::SimdAccumulator,
+            <MaxAggregate<T> as SimdAggregate<T>>::SimdAccumulator,
+        );
+        type Output = (T::Native, T::Native);
+
+        fn init_accumulator_scalar() -> Self::ScalarAccumulator {
+            (
+                MinAggregate::<T>::init_accumulator_scalar(),
+                MaxAggregate::<T>::init_accumulator_scalar(),
+            )
+        }
+
+        fn init_accumulator_chunk() -> Self::SimdAccumulator {
+            (
+                MinAggregate::<T>::init_accumulator_chunk(),
+                MaxAggregate::<T>::init_accumulator_chunk(),
+            )
+        }
+
+        fn accumulate_chunk_non_null(
+            accumulator: &mut Self::SimdAccumulator,
+            chunk: T::Simd,
+        ) {
+            MinAggregate::<T>::accumulate_chunk_non_null(&mut accumulator.0, chunk);
+            MaxAggregate::<T>::accumulate_chunk_non_null(&mut accumulator.1, chunk);
+        }
+
+        fn accumulate_chunk_nullable(
+            accumulator: &mut Self::SimdAccumulator,
+            chunk: T::Simd,
+            vecmask: T::SimdMask,
+        ) {
+            MinAggregate::<T>::accumulate_chunk_nullable(
+                &mut accumulator.0,
+                chunk,
+                vecmask,
+            );
+            MaxAggregate::<T>::accumulate_chunk_nullable(
+                &mut accumulator.1,
+                chunk,
+                vecmask,
+            );
+        }
+
+        fn accumulate_scalar(
+            accumulator: &mut Self::ScalarAccumulator,
+            value: T::Native,
+        ) {
+            MinAggregate::<T>::accumulate_scalar(&mut accumulator.0, value);
+            MaxAggregate::<T>::accumulate_scalar(&mut accumulator.1, value);
+        }
+
+        fn reduce(
+            simd_accumulator: Self::SimdAccumulator,
+            scalar_accumulator: Self::ScalarAccumulator,
+        ) -> Option<Self::Output> {
+            let min =
+                MinAggregate::<T>::reduce(simd_accumulator.0, scalar_accumulator.0)?;
+            let max =
+                MaxAggregate::<T>::reduce(simd_accumulator.1, scalar_accumulator.1)?;
+            Some((min, max))
+        }
+    }
+
     pub(super) fn simd_aggregation<T: ArrowNumericType, A: SimdAggregate<T>>(
         array: &PrimitiveArray<T>,
-    ) -> Option<T::Native> {
+    ) -> Option<A::Output> {
         let null_count = array.null_count();
 
         if null_count == array.len() {
@@ -626,6 +865,24 @@ where
     simd::simd_aggregation::<T, MaxAggregate<T>>(&array)
 }
 
+#[cfg(feature = "simd")]
+/// Returns the minimum and the maximum values in the array, in a single pass over the
+/// array. They are the values returned by [min] and [max] respectively, so NaN values are
+/// considered to be greater than any other non-null value.
+pub fn min_max<T: ArrowNumericType>(
+    array: &PrimitiveArray<T>,
+) -> (Option<T::Native>, Option<T::Native>)
+where
+    T::Native: PartialOrd,
+{
+    use simd::*;
+
+    match simd::simd_aggregation::<T, MinMaxAggregate<T>>(&array) {
+        Some((min, max)) => (Some(min), Some(max)),
+        None => (None, None),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -899,6 +1156,61 @@ mod tests {
         assert!(max(&a).unwrap().is_nan());
     }
 
+    #[test]
+    fn test_primitive_min_max_single_pass() {
+        let a = Int32Array::from(vec![5, 6, 7, 8, 9]);
+        assert_eq!(min_max(&a), (Some(5), Some(9)));
+
+        let a = Int32Array::from(vec![None, Some(8), None, Some(-2)]);
+        assert_eq!(min_max(&a), (Some(-2), Some(8)));
+
+        let a = Int32Array::from(vec![None, None]);
+        assert_eq!(min_max(&a), (None, None));
+
+        // larger than a chunk, and sliced
+        let a: Int64Array = (0..300)
+            .map(|i| {
+                if i % 7 == 0 {
+                    None
+                } else {
+                    Some((i * 37) % 101)
+                }
+            })
+            .collect();
+        let a = a.slice(3, 250);
+        let a = a.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(min_max(a), (min(a), max(a)));
+    }
+
+    #[test]
+    fn test_primitive_min_max_single_pass_float() {
+        let a = Float64Array::from(vec![Some(1.0), None, Some(f64::NAN), Some(-1.0)]);
+        let (min, max) = min_max(&a);
+        assert_eq!(min, Some(-1.0));
+        assert!(max.unwrap().is_nan());
+
+        let a: Float32Array = (0..100).map(|i| Some(i as f32 - 50.0)).collect();
+        assert_eq!(min_max(&a), (Some(-50.0), Some(49.0)));
+    }
+
+    #[test]
+    fn test_temporal_min_max_single_pass() {
+        let a = Date32Array::from(vec![Some(18000), None, Some(17000), Some(19000)]);
+        assert_eq!(min_max(&a), (Some(17000), Some(19000)));
+
+        let a = TimestampMillisecondArray::from_opt_vec(vec![Some(5), Some(3)], None);
+        assert_eq!(min_max(&a), (Some(3), Some(5)));
+    }
+
+    #[test]
+    fn test_string_min_max_single_pass() {
+        let a = StringArray::from(vec![Some("b"), None, None, Some("a"), Some("c")]);
+        assert_eq!(min_max_string(&a), (Some("a"), Some("c")));
+
+        let a = LargeStringArray::from(vec![None, None]);
+        assert_eq!(min_max_string(&a), (None, None));
+    }
+
     #[test]
     fn test_string_min_max_with_nulls() {
         let a = StringArray::from(vec![Some("b"), None, None, Some("a"), Some("c")]);
@@ -920,6 +1232,55 @@ mod tests {
         assert_eq!(Some("b"), max_string(&a));
     }
 
+    #[test]
+    fn test_bool_or_and() {
+        let a = BooleanArray::from(vec![Some(true), None, Some(false)]);
+        assert_eq!(bool_or(&a), Some(true));
+        assert_eq!(bool_and(&a), Some(false));
+
+        let a = BooleanArray::from(vec![Some(true), None, Some(true)]);
+        assert_eq!(bool_or(&a), Some(true));
+        assert_eq!(bool_and(&a), Some(true));
+
+        let a = BooleanArray::from(vec![Some(false), None, Some(false)]);
+        assert_eq!(bool_or(&a), Some(false));
+        assert_eq!(bool_and(&a), Some(false));
+
+        let a = BooleanArray::from(vec![None, None]);
+        assert_eq!(bool_or(&a), None);
+        assert_eq!(bool_and(&a), None);
+
+        let a = BooleanArray::from(vec![] as Vec<bool>);
+        assert_eq!(bool_or(&a), None);
+        assert_eq!(bool_and(&a), None);
+    }
+
+    #[test]
+    fn test_bool_or_and_large() {
+        // the only false value is null
+        let a: BooleanArray = (0..200)
+            .map(|i| if i == 150 { None } else { Some(true) })
+            .collect();
+        assert_eq!(bool_and(&a), Some(true));
+        assert_eq!(bool_or(&a), Some(true));
+
+        // the only true value is in the remainder
+        let a: BooleanArray = (0..130).map(|i| Some(i == 129)).collect();
+        assert_eq!(bool_or(&a), Some(true));
+        assert_eq!(bool_and(&a), Some(false));
+        let a = BooleanArray::from(vec![false; 130]);
+        assert_eq!(bool_or(&a), Some(false));
+        let a = BooleanArray::from(vec![true; 130]);
+        assert_eq!(bool_and(&a), Some(true));
+
+        // the offset of the slice skips the only false value
+        let a: BooleanArray = (0..100).map(|i| Some(i != 2)).collect();
+        let sliced = a.slice(3, 90);
+        let sliced = sliced.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(bool_and(sliced), Some(true));
+        assert_eq!(bool_and(&a), Some(false));
+    }
+
     #[test]
     fn test_boolean_min_max_empty() {
         let a = BooleanArray::from(vec![] as Vec<Option<bool>>);
@@ -17,9 +17,12 @@
 
 //! Defines kernels suitable to perform operations to primitive arrays.
 
-use crate::array::{Array, ArrayData, PrimitiveArray};
+use std::sync::Arc;
+
+use crate::array::{Array, ArrayData, ArrayRef, DictionaryArray, PrimitiveArray};
 use crate::buffer::Buffer;
-use crate::datatypes::ArrowPrimitiveType;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
 
 #[inline]
 fn into_primitive_array_data<I: ArrowPrimitiveType, O: ArrowPrimitiveType>(
@@ -72,3 +75,125 @@ where
     let data = into_primitive_array_data::<_, O>(array, buffer);
     PrimitiveArray::<O>::from(data)
 }
+
+/// Applies `op`, a kernel of arrays, to the values of a dictionary array, returning the
+/// dictionary of the results over the same keys, which are not copied.
+///
+/// `op` is called once, on the values of the dictionary, so its cost depends on the number
+/// of distinct values rather than on the length of the array. It is applied to all the
+/// values, including those that no key references, and must return one value per value.
+///
+/// # Example
+/// ```rust
+/// # use arrow::array::{Array, DictionaryArray, Int32Array};
+/// # use arrow::datatypes::Int8Type;
+/// # use arrow::compute::kernels::arity::unary_dict;
+/// # use arrow::compute::kernels::length::length;
+/// let array: DictionaryArray<Int8Type> = vec!["a", "bb", "a"].into_iter().collect();
+/// let lengths = unary_dict(&array, length).unwrap();
+/// let lengths = lengths
+///     .as_any()
+///     .downcast_ref::<DictionaryArray<Int8Type>>()
+///     .unwrap();
+/// let lengths = lengths.downcast_dict::<Int32Array>().unwrap();
+/// assert_eq!(lengths.value(1), 2);
+/// assert_eq!(lengths.value(2), 1);
+/// ```
+pub fn unary_dict<K, F>(array: &DictionaryArray<K>, op: F) -> Result<ArrayRef>
+where
+    K: ArrowPrimitiveType,
+    F: Fn(&dyn Array) -> Result<ArrayRef>,
+{
+    let values = op(array.values().as_ref())?;
+    if values.len() != array.values().len() {
+        return Err(ArrowError::ComputeError(format!(
+            "A kernel applied to the {} values of a dictionary returned {} values",
+            array.values().len(),
+            values.len()
+        )));
+    }
+    Ok(Arc::new(array.with_values(values)))
+}
+
+macro_rules! unary_dict_key {
+    ($array:expr, $op:expr, $key_type:ty) => {
+        unary_dict(
+            $array
+                .as_any()
+                .downcast_ref::<DictionaryArray<$key_type>>()
+                .unwrap(),
+            $op,
+        )
+    };
+}
+
+/// Applies `op`, a kernel of arrays, to `array`, or, when it is a dictionary array, to its
+/// values with [unary_dict], so that kernels support dictionaries of the types they support.
+pub fn unary_dyn<F>(array: &dyn Array, op: F) -> Result<ArrayRef>
+where
+    F: Fn(&dyn Array) -> Result<ArrayRef>,
+{
+    match array.data_type() {
+        DataType::Dictionary(key_type, _) => match key_type.as_ref() {
+            DataType::Int8 => unary_dict_key!(array, op, Int8Type),
+            DataType::Int16 => unary_dict_key!(array, op, Int16Type),
+            DataType::Int32 => unary_dict_key!(array, op, Int32Type),
+            DataType::Int64 => unary_dict_key!(array, op, Int64Type),
+            DataType::UInt8 => unary_dict_key!(array, op, UInt8Type),
+            DataType::UInt16 => unary_dict_key!(array, op, UInt16Type),
+            DataType::UInt32 => unary_dict_key!(array, op, UInt32Type),
+            DataType::UInt64 => unary_dict_key!(array, op, UInt64Type),
+            t => Err(ArrowError::ComputeError(format!(
+                "Dictionaries with keys of type {:?} are not supported",
+                t
+            ))),
+        },
+        _ => op(array),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::{Int32Array, StringArray};
+    use crate::compute::kernels::length::length;
+
+    #[test]
+    fn test_unary_dict() {
+        let array: DictionaryArray<UInt16Type> =
+            vec![Some("a"), None, Some("ccc"), Some("a")]
+                .into_iter()
+                .collect();
+        let sliced = array.slice(1, 3);
+
+        let lengths = unary_dyn(sliced.as_ref(), length).unwrap();
+        assert_eq!(
+            lengths.data_type(),
+            &DataType::Dictionary(Box::new(DataType::UInt16), Box::new(DataType::Int32))
+        );
+        let lengths = lengths
+            .as_any()
+            .downcast_ref::<DictionaryArray<UInt16Type>>()
+            .unwrap();
+        assert_eq!(lengths.len(), 3);
+        assert!(lengths.is_null(0));
+        let lengths = lengths.downcast_dict::<Int32Array>().unwrap();
+        assert_eq!(lengths.value(1), 3);
+        assert_eq!(lengths.value(2), 1);
+    }
+
+    #[test]
+    fn test_unary_dyn_not_dictionary() {
+        let array = StringArray::from(vec!["ab"]);
+        let lengths = unary_dyn(&array, length).unwrap();
+        assert_eq!(lengths.as_ref(), &Int32Array::from(vec![2]));
+    }
+
+    #[test]
+    fn test_unary_dict_invalid() {
+        let array: DictionaryArray<Int8Type> = vec!["a", "b"].into_iter().collect();
+        let result = unary_dict(&array, |values| Ok(values.slice(0, 1)));
+        assert!(result.is_err());
+        assert!(unary_dict(&array, length).is_ok());
+    }
+}
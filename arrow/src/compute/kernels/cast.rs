@@ -35,15 +35,22 @@
 //! assert_eq!(7.0, c.value(2));
 //! ```
 
+use std::convert::TryFrom;
 use std::str;
 use std::sync::Arc;
 
 use crate::buffer::MutableBuffer;
 use crate::compute::kernels::arithmetic::{divide, multiply};
 use crate::compute::kernels::arity::unary;
-use crate::compute::kernels::cast_utils::string_to_timestamp_nanos;
+use crate::compute::kernels::cast_utils::{
+    string_to_interval_day_time, string_to_interval_year_month, string_to_timestamp_nanos,
+};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::temporal_conversions::{
+    date_to_date32, time_unit_multiple, MILLISECONDS, MILLISECONDS_IN_DAY, SECONDS_IN_DAY,
+};
+use crate::util::decimal::parse_decimal;
 use crate::{array::*, compute::take};
 use crate::{buffer::Buffer, util::serialization::lexical_to_string};
 use num::{NumCast, ToPrimitive};
@@ -53,9 +60,28 @@ use num::{NumCast, ToPrimitive};
 pub struct CastOptions {
     /// how to handle cast failures, either return NULL (safe=true) or return ERR (safe=false)
     pub safe: bool,
+    /// how to round the digits that don't fit in the scale of a decimal, or in an integer
+    pub rounding: RoundingMode,
 }
 
-pub const DEFAULT_CAST_OPTIONS: CastOptions = CastOptions { safe: true };
+pub const DEFAULT_CAST_OPTIONS: CastOptions = CastOptions {
+    safe: true,
+    rounding: RoundingMode::HalfUp,
+};
+
+/// How to round a number to fewer fractional digits, e.g. when a decimal is cast to a
+/// smaller scale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Drop the extra digits, e.g. `2.57` to `2.5` and `-2.57` to `-2.5`
+    Truncate,
+    /// Round to the nearest value, and halves away from zero, e.g. `2.25` to `2.3` and
+    /// `-2.25` to `-2.3`
+    HalfUp,
+    /// Round to the nearest value, and halves to the even value, e.g. `2.25` to `2.2`
+    /// and `2.35` to `2.4`
+    HalfEven,
+}
 
 /// Return true if a value of type `from_type` can be cast into a
 /// value of `to_type`. Note that such as cast may be lossy.
@@ -68,6 +94,9 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
     }
 
     match (from_type, to_type) {
+        // a null array can be cast to an array of nulls of any type
+        (Null, Float16) | (Null, Union(_)) => false,
+        (Null, _) => true,
         (Struct(_), _) => false,
         (_, Struct(_)) => false,
         (LargeList(list_from), LargeList(list_to)) => {
@@ -88,6 +117,16 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Dictionary(_, value_type), _) => can_cast_types(value_type, to_type),
         (_, Dictionary(_, value_type)) => can_cast_types(from_type, value_type),
 
+        (Decimal(_, _), Decimal(_, _)) => true,
+        (Decimal(_, _), _) => {
+            DataType::is_numeric(to_type) || to_type == &Utf8 || to_type == &LargeUtf8
+        }
+        (_, Decimal(_, _)) => {
+            DataType::is_numeric(from_type)
+                || from_type == &Utf8
+                || from_type == &LargeUtf8
+        }
+
         (_, Boolean) => DataType::is_numeric(from_type),
         (Boolean, _) => DataType::is_numeric(to_type) || to_type == &Utf8,
 
@@ -96,10 +135,12 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Utf8, Date32) => true,
         (Utf8, Date64) => true,
         (Utf8, Timestamp(TimeUnit::Nanosecond, None)) => true,
+        (Utf8, Interval(_)) => true,
         (Utf8, _) => DataType::is_numeric(to_type),
         (LargeUtf8, Date32) => true,
         (LargeUtf8, Date64) => true,
         (LargeUtf8, Timestamp(TimeUnit::Nanosecond, None)) => true,
+        (LargeUtf8, Interval(_)) => true,
         (LargeUtf8, _) => DataType::is_numeric(to_type),
         (_, Utf8) | (_, LargeUtf8) => {
             DataType::is_numeric(from_type) || from_type == &Binary
@@ -235,9 +276,17 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
         (Timestamp(_, _), Timestamp(_, _)) => true,
         (Timestamp(_, _), Date32) => true,
         (Timestamp(_, _), Date64) => true,
-        // date64 to timestamp might not make sense,
+        (Timestamp(_, _), Time32(to_unit)) => {
+            matches!(to_unit, TimeUnit::Second | TimeUnit::Millisecond)
+        }
+        (Timestamp(_, _), Time64(to_unit)) => {
+            matches!(to_unit, TimeUnit::Microsecond | TimeUnit::Nanosecond)
+        }
+        (Date32, Timestamp(_, _)) => true,
+        (Date64, Timestamp(_, _)) => true,
+        (Time32(_), Timestamp(_, _)) => true,
+        (Time64(_), Timestamp(_, _)) => true,
         (Int64, Duration(_)) => true,
-        (Null, Int32) => true,
         (_, _) => false,
     }
 }
@@ -255,13 +304,24 @@ pub fn can_cast_types(from_type: &DataType, to_type: &DataType) -> bool {
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
+/// * Timestamp to Time32 and Time64: the time of day of the timestamp in UTC
+/// * Date{32|64} to Timestamp: the timestamp of midnight UTC of the date
+/// * Time32 and Time64 to Timestamp: the timestamp of the time on 1970-01-01 UTC
+/// * Temporal to temporal: values that overflow the target type return null, or an
+///   error if `CastOptions::safe` is false
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Utf8 to interval: PostgreSQL-style intervals, e.g. `1 year 2 months` or
+///   `3 days 04:05:06`, other strings return null
+/// * Decimal to decimal, integer and float: rounded with `CastOptions::rounding`, values
+///   that overflow the precision or the type return null
+/// * Utf8 to decimal: strings such as `-12.345` or `1.2e3`, as read by the CSV and JSON
+///   readers, rounded with `CastOptions::rounding`, other strings return null
 ///
 /// Unsupported Casts
 /// * To or from `StructArray`
 /// * List to primitive
 /// * Utf8 to boolean
-/// * Interval and duration
+/// * Interval and duration, other than from Utf8 to interval
 pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
     cast_with_options(array, to_type, &DEFAULT_CAST_OPTIONS)
 }
@@ -280,13 +340,24 @@ pub fn cast(array: &ArrayRef, to_type: &DataType) -> Result<ArrayRef> {
 /// * Date32 and Date64: precision lost when going to higher interval
 /// * Time32 and Time64: precision lost when going to higher interval
 /// * Timestamp and Date{32|64}: precision lost when going to higher interval
+/// * Timestamp to Time32 and Time64: the time of day of the timestamp in UTC
+/// * Date{32|64} to Timestamp: the timestamp of midnight UTC of the date
+/// * Time32 and Time64 to Timestamp: the timestamp of the time on 1970-01-01 UTC
+/// * Temporal to temporal: values that overflow the target type return null, or an
+///   error if `CastOptions::safe` is false
 /// * Temporal to/from backing primitive: zero-copy with data type change
+/// * Utf8 to interval: PostgreSQL-style intervals, e.g. `1 year 2 months` or
+///   `3 days 04:05:06`, other strings return null
+/// * Decimal to decimal, integer and float: rounded with `CastOptions::rounding`, values
+///   that overflow the precision or the type return null
+/// * Utf8 to decimal: strings such as `-12.345` or `1.2e3`, as read by the CSV and JSON
+///   readers, rounded with `CastOptions::rounding`, other strings return null
 ///
 /// Unsupported Casts
 /// * To or from `StructArray`
 /// * List to primitive
 /// * Utf8 to boolean
-/// * Interval and duration
+/// * Interval and duration, other than from Utf8 to interval
 pub fn cast_with_options(
     array: &ArrayRef,
     to_type: &DataType,
@@ -300,6 +371,11 @@ pub fn cast_with_options(
         return Ok(array.clone());
     }
     match (from_type, to_type) {
+        (Null, Float16) | (Null, Union(_)) => Err(ArrowError::CastError(format!(
+            "Casting from {:?} to {:?} not supported",
+            from_type, to_type,
+        ))),
+        (Null, _) => Ok(new_null_array(to_type, array.len())),
         (Struct(_), _) => Err(ArrowError::CastError(
             "Cannot cast from struct to other types".to_string(),
         )),
@@ -389,6 +465,102 @@ pub fn cast_with_options(
                 from_type, to_type,
             ))),
         },
+        (Decimal(_, _), Decimal(precision, scale)) => {
+            cast_decimal_to_decimal(array, *precision, *scale, cast_options)
+        }
+        (Decimal(_, _), _) => match to_type {
+            UInt8 => cast_decimal_to_integer::<UInt8Type>(array, cast_options),
+            UInt16 => cast_decimal_to_integer::<UInt16Type>(array, cast_options),
+            UInt32 => cast_decimal_to_integer::<UInt32Type>(array, cast_options),
+            UInt64 => cast_decimal_to_integer::<UInt64Type>(array, cast_options),
+            Int8 => cast_decimal_to_integer::<Int8Type>(array, cast_options),
+            Int16 => cast_decimal_to_integer::<Int16Type>(array, cast_options),
+            Int32 => cast_decimal_to_integer::<Int32Type>(array, cast_options),
+            Int64 => cast_decimal_to_integer::<Int64Type>(array, cast_options),
+            Float32 => cast_decimal_to_float::<Float32Type>(array),
+            Float64 => cast_decimal_to_float::<Float64Type>(array),
+            Utf8 => cast_decimal_to_string::<i32>(array),
+            LargeUtf8 => cast_decimal_to_string::<i64>(array),
+            _ => Err(ArrowError::CastError(format!(
+                "Casting from {:?} to {:?} not supported",
+                from_type, to_type,
+            ))),
+        },
+        (_, Decimal(precision, scale)) => {
+            let (precision, scale) = (*precision, *scale);
+            match from_type {
+                UInt8 => cast_integer_to_decimal::<UInt8Type>(
+                    array,
+                    precision,
+                    scale,
+                    cast_options,
+                ),
+                UInt16 => cast_integer_to_decimal::<UInt16Type>(
+                    array,
+                    precision,
+                    scale,
+                    cast_options,
+                ),
+                UInt32 => cast_integer_to_decimal::<UInt32Type>(
+                    array,
+                    precision,
+                    scale,
+                    cast_options,
+                ),
+                UInt64 => cast_integer_to_decimal::<UInt64Type>(
+                    array,
+                    precision,
+                    scale,
+                    cast_options,
+                ),
+                Int8 => cast_integer_to_decimal::<Int8Type>(
+                    array,
+                    precision,
+                    scale,
+                    cast_options,
+                ),
+                Int16 => cast_integer_to_decimal::<Int16Type>(
+                    array,
+                    precision,
+                    scale,
+                    cast_options,
+                ),
+                Int32 => cast_integer_to_decimal::<Int32Type>(
+                    array,
+                    precision,
+                    scale,
+                    cast_options,
+                ),
+                Int64 => cast_integer_to_decimal::<Int64Type>(
+                    array,
+                    precision,
+                    scale,
+                    cast_options,
+                ),
+                Float32 => cast_float_to_decimal::<Float32Type>(
+                    array,
+                    precision,
+                    scale,
+                    cast_options,
+                ),
+                Float64 => cast_float_to_decimal::<Float64Type>(
+                    array,
+                    precision,
+                    scale,
+                    cast_options,
+                ),
+                Utf8 => {
+                    cast_string_to_decimal::<i32>(array, precision, scale, cast_options)
+                }
+                LargeUtf8 => {
+                    cast_string_to_decimal::<i64>(array, precision, scale, cast_options)
+                }
+                _ => Err(ArrowError::CastError(format!(
+                    "Casting from {:?} to {:?} not supported",
+                    from_type, to_type,
+                ))),
+            }
+        }
         (_, Boolean) => match from_type {
             UInt8 => cast_numeric_to_bool::<UInt8Type>(array),
             UInt16 => cast_numeric_to_bool::<UInt16Type>(array),
@@ -451,6 +623,20 @@ pub fn cast_with_options(
             Timestamp(TimeUnit::Nanosecond, None) => {
                 cast_string_to_timestamp_ns::<i32>(&**array, cast_options)
             }
+            Interval(IntervalUnit::YearMonth) => {
+                cast_string_to_interval::<IntervalYearMonthType, i32>(
+                    &**array,
+                    string_to_interval_year_month,
+                    cast_options,
+                )
+            }
+            Interval(IntervalUnit::DayTime) => {
+                cast_string_to_interval::<IntervalDayTimeType, i32>(
+                    &**array,
+                    string_to_interval_day_time,
+                    cast_options,
+                )
+            }
             _ => Err(ArrowError::CastError(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -553,6 +739,20 @@ pub fn cast_with_options(
             Timestamp(TimeUnit::Nanosecond, None) => {
                 cast_string_to_timestamp_ns::<i64>(&**array, cast_options)
             }
+            Interval(IntervalUnit::YearMonth) => {
+                cast_string_to_interval::<IntervalYearMonthType, i64>(
+                    &**array,
+                    string_to_interval_year_month,
+                    cast_options,
+                )
+            }
+            Interval(IntervalUnit::DayTime) => {
+                cast_string_to_interval::<IntervalDayTimeType, i64>(
+                    &**array,
+                    string_to_interval_day_time,
+                    cast_options,
+                )
+            }
             _ => Err(ArrowError::CastError(format!(
                 "Casting from {:?} to {:?} not supported",
                 from_type, to_type,
@@ -721,13 +921,9 @@ pub fn cast_with_options(
             Ok(Arc::new(values) as ArrayRef)
         }
         (Time32(TimeUnit::Second), Time32(TimeUnit::Millisecond)) => {
-            let time_array = array.as_any().downcast_ref::<Time32SecondArray>().unwrap();
-
-            let values = unary::<_, _, Time32MillisecondType>(time_array, |x| {
-                x * MILLISECONDS as i32
-            });
-
-            Ok(Arc::new(values) as ArrayRef)
+            cast_temporal::<Int32Type, _>(array, to_type, cast_options, |x| {
+                x.checked_mul(MILLISECONDS)
+            })
         }
         (Time32(TimeUnit::Millisecond), Time32(TimeUnit::Second)) => {
             let time_array = array
@@ -754,26 +950,19 @@ pub fn cast_with_options(
             let array_ref = Arc::new(converted) as ArrayRef;
             use TimeUnit::*;
             match to_unit {
-                Microsecond => cast_array_data::<TimestampMicrosecondType>(
-                    &array_ref,
-                    to_type.clone(),
-                ),
-                Nanosecond => cast_array_data::<TimestampNanosecondType>(
-                    &array_ref,
-                    to_type.clone(),
-                ),
+                Microsecond => {
+                    cast_array_data::<Time64MicrosecondType>(&array_ref, to_type.clone())
+                }
+                Nanosecond => {
+                    cast_array_data::<Time64NanosecondType>(&array_ref, to_type.clone())
+                }
                 _ => unreachable!("array type not supported"),
             }
         }
         (Time64(TimeUnit::Microsecond), Time64(TimeUnit::Nanosecond)) => {
-            let time_array = array
-                .as_any()
-                .downcast_ref::<Time64MicrosecondArray>()
-                .unwrap();
-
-            let values =
-                unary::<_, _, Time64NanosecondType>(time_array, |x| x * MILLISECONDS);
-            Ok(Arc::new(values) as ArrayRef)
+            cast_temporal::<Int64Type, _>(array, to_type, cast_options, |x| {
+                x.checked_mul(MILLISECONDS)
+            })
         }
         (Time64(TimeUnit::Nanosecond), Time64(TimeUnit::Microsecond)) => {
             let time_array = array
@@ -901,7 +1090,34 @@ pub fn cast_with_options(
                 }
             }
         }
-        // date64 to timestamp might not make sense,
+        (Timestamp(from_unit, _), Time32(to_unit))
+        | (Timestamp(from_unit, _), Time64(to_unit)) => {
+            let day = time_unit_multiple(from_unit) * SECONDS_IN_DAY;
+            cast_temporal::<Int64Type, _>(array, to_type, cast_options, |x| {
+                convert_time_unit(x.rem_euclid(day), from_unit, to_unit)
+            })
+        }
+        (Date32, Timestamp(to_unit, _)) => {
+            cast_temporal::<Int32Type, _>(array, to_type, cast_options, |x| {
+                x.checked_mul(SECONDS_IN_DAY)
+                    .and_then(|x| convert_time_unit(x, &TimeUnit::Second, to_unit))
+            })
+        }
+        (Date64, Timestamp(to_unit, _)) => {
+            cast_temporal::<Int64Type, _>(array, to_type, cast_options, |x| {
+                convert_time_unit(x, &TimeUnit::Millisecond, to_unit)
+            })
+        }
+        (Time32(from_unit), Timestamp(to_unit, _)) => {
+            cast_temporal::<Int32Type, _>(array, to_type, cast_options, |x| {
+                convert_time_unit(x, from_unit, to_unit)
+            })
+        }
+        (Time64(from_unit), Timestamp(to_unit, _)) => {
+            cast_temporal::<Int64Type, _>(array, to_type, cast_options, |x| {
+                convert_time_unit(x, from_unit, to_unit)
+            })
+        }
         (Int64, Duration(to_unit)) => {
             use TimeUnit::*;
             match to_unit {
@@ -918,9 +1134,6 @@ pub fn cast_with_options(
             }
         }
 
-        // null to primitive/flat types
-        (Null, Int32) => Ok(Arc::new(Int32Array::from(vec![None; array.len()]))),
-
         (_, _) => Err(ArrowError::CastError(format!(
             "Casting from {:?} to {:?} not supported",
             from_type, to_type,
@@ -928,29 +1141,6 @@ pub fn cast_with_options(
     }
 }
 
-/// Get the time unit as a multiple of a second
-const fn time_unit_multiple(unit: &TimeUnit) -> i64 {
-    match unit {
-        TimeUnit::Second => 1,
-        TimeUnit::Millisecond => MILLISECONDS,
-        TimeUnit::Microsecond => MICROSECONDS,
-        TimeUnit::Nanosecond => NANOSECONDS,
-    }
-}
-
-/// Number of seconds in a day
-const SECONDS_IN_DAY: i64 = 86_400;
-/// Number of milliseconds in a second
-const MILLISECONDS: i64 = 1_000;
-/// Number of microseconds in a second
-const MICROSECONDS: i64 = 1_000_000;
-/// Number of nanoseconds in a second
-const NANOSECONDS: i64 = 1_000_000_000;
-/// Number of milliseconds in a day
-const MILLISECONDS_IN_DAY: i64 = SECONDS_IN_DAY * MILLISECONDS;
-/// Number of days between 0001-01-01 and 1970-01-01
-const EPOCH_DAYS_FROM_CE: i32 = 719_163;
-
 /// Cast an array by changing its array_data type to the desired type
 ///
 /// Arrays should have the same primitive data type, otherwise this should fail.
@@ -973,6 +1163,101 @@ where
     Ok(Arc::new(PrimitiveArray::<TO>::from(data)) as ArrayRef)
 }
 
+/// Casts the temporal `array`, whose values are of the primitive type `T`, to the temporal
+/// type `to_type` by applying `op` to the values as `i64`s. `op` returns `None` for a
+/// value that overflows, which is null or an error depending on `cast_options`, as is a
+/// result that overflows the values of `to_type`.
+fn cast_temporal<T, F>(
+    array: &ArrayRef,
+    to_type: &DataType,
+    cast_options: &CastOptions,
+    op: F,
+) -> Result<ArrayRef>
+where
+    T: ArrowNumericType,
+    i64: From<T::Native>,
+    F: Fn(i64) -> Option<i64>,
+{
+    let is_32_bit = matches!(to_type, DataType::Date32 | DataType::Time32(_));
+    let from = PrimitiveArray::<T>::from(array.data().clone());
+    let values = from
+        .iter()
+        .map(|value| {
+            let value = match value {
+                Some(value) => <i64 as From<T::Native>>::from(value),
+                None => return Ok(None),
+            };
+            let cast = op(value)
+                .filter(|x| !is_32_bit || i32::try_from(*x).is_ok())
+                .ok_or_else(|| {
+                    ArrowError::CastError(format!(
+                        "Cannot cast {} of type {:?} to {:?}, it overflows",
+                        value,
+                        array.data_type(),
+                        to_type
+                    ))
+                });
+            cast_value(cast, cast_options)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let values = if is_32_bit {
+        let values: Int32Array =
+            values.into_iter().map(|x| x.map(|x| x as i32)).collect();
+        Arc::new(values) as ArrayRef
+    } else {
+        Arc::new(Int64Array::from(values)) as ArrayRef
+    };
+
+    use TimeUnit::*;
+    match to_type {
+        DataType::Time32(Second) => {
+            cast_array_data::<Time32SecondType>(&values, to_type.clone())
+        }
+        DataType::Time32(Millisecond) => {
+            cast_array_data::<Time32MillisecondType>(&values, to_type.clone())
+        }
+        DataType::Time64(Microsecond) => {
+            cast_array_data::<Time64MicrosecondType>(&values, to_type.clone())
+        }
+        DataType::Time64(Nanosecond) => {
+            cast_array_data::<Time64NanosecondType>(&values, to_type.clone())
+        }
+        DataType::Timestamp(Second, _) => {
+            cast_array_data::<TimestampSecondType>(&values, to_type.clone())
+        }
+        DataType::Timestamp(Millisecond, _) => {
+            cast_array_data::<TimestampMillisecondType>(&values, to_type.clone())
+        }
+        DataType::Timestamp(Microsecond, _) => {
+            cast_array_data::<TimestampMicrosecondType>(&values, to_type.clone())
+        }
+        DataType::Timestamp(Nanosecond, _) => {
+            cast_array_data::<TimestampNanosecondType>(&values, to_type.clone())
+        }
+        _ => Err(ArrowError::CastError(format!(
+            "Casting from {:?} to {:?} not supported",
+            array.data_type(),
+            to_type,
+        ))),
+    }
+}
+
+/// Converts `value` from `from_unit` to `to_unit`, truncating the units that are lost,
+/// or returns `None` if it overflows.
+fn convert_time_unit(
+    value: i64,
+    from_unit: &TimeUnit,
+    to_unit: &TimeUnit,
+) -> Option<i64> {
+    let from_size = time_unit_multiple(from_unit);
+    let to_size = time_unit_multiple(to_unit);
+    if from_size >= to_size {
+        Some(value / (from_size / to_size))
+    } else {
+        value.checked_mul(to_size / from_size)
+    }
+}
+
 /// Convert Array into a PrimitiveArray of type, and apply numeric cast
 #[allow(clippy::unnecessary_wraps)]
 fn cast_numeric_arrays<FROM, TO>(from: &ArrayRef) -> Result<ArrayRef>
@@ -1106,7 +1391,6 @@ fn cast_string_to_date32<Offset: StringOffsetSizeTrait>(
     array: &dyn Array,
     cast_options: &CastOptions,
 ) -> Result<ArrayRef> {
-    use chrono::Datelike;
     let string_array = array
         .as_any()
         .downcast_ref::<GenericStringArray<Offset>>()
@@ -1120,7 +1404,7 @@ fn cast_string_to_date32<Offset: StringOffsetSizeTrait>(
                 string_array
                     .value(i)
                     .parse::<chrono::NaiveDate>()
-                    .map(|date| date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+                    .map(date_to_date32)
                     .ok()
             }
         });
@@ -1141,7 +1425,7 @@ fn cast_string_to_date32<Offset: StringOffsetSizeTrait>(
 
                     let result = string
                         .parse::<chrono::NaiveDate>()
-                        .map(|date| date.num_days_from_ce() - EPOCH_DAYS_FROM_CE);
+                        .map(date_to_date32);
 
                     Some(result.map_err(|_| {
                         ArrowError::CastError(
@@ -1271,6 +1555,340 @@ fn cast_string_to_timestamp_ns<Offset: StringOffsetSizeTrait>(
     Ok(Arc::new(array) as ArrayRef)
 }
 
+/// Casts generic string arrays to an interval array of type `T`, parsing the
+/// strings with `parse`
+fn cast_string_to_interval<T, Offset>(
+    array: &dyn Array,
+    parse: fn(&str) -> Result<T::Native>,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
+where
+    T: ArrowPrimitiveType,
+    Offset: StringOffsetSizeTrait,
+{
+    let string_array = array
+        .as_any()
+        .downcast_ref::<GenericStringArray<Offset>>()
+        .unwrap();
+
+    let array = if cast_options.safe {
+        string_array
+            .iter()
+            .map(|v| v.and_then(|v| parse(v).ok()))
+            .collect::<PrimitiveArray<T>>()
+    } else {
+        string_array
+            .iter()
+            .map(|v| v.map(parse).transpose())
+            .collect::<Result<PrimitiveArray<T>>>()?
+    };
+
+    Ok(Arc::new(array) as ArrayRef)
+}
+
+/// Returns `10^exp`, or `None` if it overflows an `i128`.
+fn pow10(exp: usize) -> Option<i128> {
+    10i128.checked_pow(exp as u32)
+}
+
+/// Divides `value` by `divisor`, a positive power of ten, rounding the quotient with
+/// `rounding`.
+fn div_round(value: i128, divisor: i128, rounding: RoundingMode) -> i128 {
+    let quotient = value / divisor;
+    let remainder = (value % divisor).abs();
+    if remainder == 0 {
+        return quotient;
+    }
+    // compared without `2 * remainder`, which can overflow
+    let half = remainder.cmp(&(divisor - remainder));
+    let away_from_zero = match rounding {
+        RoundingMode::Truncate => false,
+        RoundingMode::HalfUp => half != std::cmp::Ordering::Less,
+        RoundingMode::HalfEven => {
+            half == std::cmp::Ordering::Greater
+                || (half == std::cmp::Ordering::Equal && quotient % 2 != 0)
+        }
+    };
+    if away_from_zero {
+        quotient + value.signum()
+    } else {
+        quotient
+    }
+}
+
+/// Rescales the decimal `value` of `from_scale` to `to_scale`, or returns `None` if it
+/// overflows an `i128`.
+fn rescale_decimal(
+    value: i128,
+    from_scale: usize,
+    to_scale: usize,
+    rounding: RoundingMode,
+) -> Option<i128> {
+    if to_scale >= from_scale {
+        pow10(to_scale - from_scale).and_then(|m| value.checked_mul(m))
+    } else {
+        match pow10(from_scale - to_scale) {
+            Some(divisor) => Some(div_round(value, divisor, rounding)),
+            // the divisor is larger than any `i128`
+            None => Some(0),
+        }
+    }
+}
+
+/// Returns the decimal `value` of `scale` as a string, e.g. `-1.05` for `-105` of scale 2.
+fn format_decimal(value: i128, scale: usize) -> String {
+    let sign = if value < 0 { "-" } else { "" };
+    let digits = value.unsigned_abs().to_string();
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let digits = format!("{:0>width$}", digits, width = scale + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale);
+    format!("{}{}.{}", sign, integer, fraction)
+}
+
+/// Returns `Ok(None)` instead of the error of a value that can't be cast when
+/// `cast_options.safe`.
+fn cast_value<T>(value: Result<T>, cast_options: &CastOptions) -> Result<Option<T>> {
+    match value {
+        Ok(value) => Ok(Some(value)),
+        Err(_) if cast_options.safe => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Builds a `Decimal(precision, scale)` array of `values`, where a value that can't be
+/// cast or that overflows `precision` is null or an error, depending on `cast_options`.
+fn build_decimal_array<I>(
+    values: I,
+    precision: usize,
+    scale: usize,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
+where
+    I: Iterator<Item = Option<Result<i128>>>,
+{
+    let max = pow10(precision);
+    let mut builder =
+        DecimalBuilder::with_capacity(values.size_hint().0, precision, scale);
+    for value in values {
+        let value = match value {
+            Some(value) => value,
+            None => {
+                builder.append_null()?;
+                continue;
+            }
+        };
+        let value = value.and_then(|value| match max {
+            Some(max) if value.unsigned_abs() >= max as u128 => {
+                Err(ArrowError::CastError(format!(
+                    "Cannot cast {} to {:?}, it overflows the precision",
+                    format_decimal(value, scale),
+                    DataType::Decimal(precision, scale)
+                )))
+            }
+            _ => Ok(value),
+        });
+        match cast_value(value, cast_options)? {
+            Some(value) => builder.append_value(value)?,
+            None => builder.append_null()?,
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Returns the values of a `DecimalArray`, `None` where it is null.
+fn decimal_values(array: &DecimalArray) -> impl Iterator<Item = Option<i128>> + '_ {
+    (0..array.len()).map(move |i| {
+        if array.is_null(i) {
+            None
+        } else {
+            Some(array.value(i))
+        }
+    })
+}
+
+/// Cast a decimal array to another precision and scale
+fn cast_decimal_to_decimal(
+    array: &ArrayRef,
+    precision: usize,
+    scale: usize,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    let array = array.as_any().downcast_ref::<DecimalArray>().unwrap();
+    let from_scale = array.scale();
+    let values = decimal_values(array).map(|value| {
+        value.map(|value| {
+            rescale_decimal(value, from_scale, scale, cast_options.rounding).ok_or_else(
+                || {
+                    ArrowError::CastError(format!(
+                        "Cannot cast {} to {:?}, it overflows",
+                        format_decimal(value, from_scale),
+                        DataType::Decimal(precision, scale)
+                    ))
+                },
+            )
+        })
+    });
+    build_decimal_array(values, precision, scale, cast_options)
+}
+
+/// Cast a decimal array to integers, rounding their fractional digits
+fn cast_decimal_to_integer<T>(
+    array: &ArrayRef,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
+where
+    T: ArrowNumericType,
+    T::Native: NumCast,
+{
+    let array = array.as_any().downcast_ref::<DecimalArray>().unwrap();
+    let scale = array.scale();
+    let values = decimal_values(array)
+        .map(|value| match value {
+            Some(value) => {
+                let integer = rescale_decimal(value, scale, 0, cast_options.rounding)
+                    .and_then(num::cast::cast::<i128, T::Native>)
+                    .ok_or_else(|| {
+                        ArrowError::CastError(format!(
+                            "Cannot cast {} to {:?}, it overflows",
+                            format_decimal(value, scale),
+                            T::DATA_TYPE
+                        ))
+                    });
+                cast_value(integer, cast_options)
+            }
+            None => Ok(None),
+        })
+        .collect::<Result<PrimitiveArray<T>>>()?;
+    Ok(Arc::new(values))
+}
+
+/// Cast a decimal array to floats
+#[allow(clippy::unnecessary_wraps)]
+fn cast_decimal_to_float<T>(array: &ArrayRef) -> Result<ArrayRef>
+where
+    T: ArrowNumericType,
+    T::Native: NumCast,
+{
+    let array = array.as_any().downcast_ref::<DecimalArray>().unwrap();
+    let divisor = 10f64.powi(array.scale() as i32);
+    let values = decimal_values(array)
+        .map(|value| value.and_then(|value| num::cast::cast(value as f64 / divisor)))
+        .collect::<PrimitiveArray<T>>();
+    Ok(Arc::new(values))
+}
+
+/// Cast a decimal array to strings such as `-1.05`
+#[allow(clippy::unnecessary_wraps)]
+fn cast_decimal_to_string<OffsetSize>(array: &ArrayRef) -> Result<ArrayRef>
+where
+    OffsetSize: StringOffsetSizeTrait,
+{
+    let array = array.as_any().downcast_ref::<DecimalArray>().unwrap();
+    let scale = array.scale();
+    let values = decimal_values(array)
+        .map(|value| value.map(|value| format_decimal(value, scale)))
+        .collect::<GenericStringArray<OffsetSize>>();
+    Ok(Arc::new(values))
+}
+
+/// Cast an integer array to decimals
+fn cast_integer_to_decimal<T>(
+    array: &ArrayRef,
+    precision: usize,
+    scale: usize,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive + std::fmt::Display,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let values = array.iter().map(|value| {
+        value.map(|value| {
+            value
+                .to_i128()
+                .and_then(|v| rescale_decimal(v, 0, scale, cast_options.rounding))
+                .ok_or_else(|| {
+                    ArrowError::CastError(format!(
+                        "Cannot cast {} to {:?}, it overflows",
+                        value,
+                        DataType::Decimal(precision, scale)
+                    ))
+                })
+        })
+    });
+    build_decimal_array(values, precision, scale, cast_options)
+}
+
+/// Cast a float array to decimals, rounding the digits that don't fit in the scale
+fn cast_float_to_decimal<T>(
+    array: &ArrayRef,
+    precision: usize,
+    scale: usize,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef>
+where
+    T: ArrowNumericType,
+    T::Native: ToPrimitive + std::fmt::Display,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let multiplier = 10f64.powi(scale as i32);
+    let values = array.iter().map(|value| {
+        value.map(|value| {
+            value
+                .to_f64()
+                .map(|v| {
+                    let v = v * multiplier;
+                    match cast_options.rounding {
+                        RoundingMode::Truncate => v.trunc(),
+                        RoundingMode::HalfUp => v.round(),
+                        RoundingMode::HalfEven if (v - v.trunc()).abs() == 0.5 => {
+                            2.0 * (v / 2.0).round()
+                        }
+                        RoundingMode::HalfEven => v.round(),
+                    }
+                })
+                .and_then(|v| v.to_i128())
+                .ok_or_else(|| {
+                    ArrowError::CastError(format!(
+                        "Cannot cast {} to {:?}",
+                        value,
+                        DataType::Decimal(precision, scale)
+                    ))
+                })
+        })
+    });
+    build_decimal_array(values, precision, scale, cast_options)
+}
+
+/// Cast a string array of decimal numbers such as `-12.345` to decimals
+fn cast_string_to_decimal<Offset: StringOffsetSizeTrait>(
+    array: &ArrayRef,
+    precision: usize,
+    scale: usize,
+    cast_options: &CastOptions,
+) -> Result<ArrayRef> {
+    let array = array
+        .as_any()
+        .downcast_ref::<GenericStringArray<Offset>>()
+        .unwrap();
+    let values = array.iter().map(|value| {
+        value.map(|value| {
+            let rounding = Some(cast_options.rounding);
+            parse_decimal(value, precision, scale, rounding).map_err(|_| {
+                ArrowError::CastError(format!(
+                    "Cannot cast string '{}' to {:?}",
+                    value,
+                    DataType::Decimal(precision, scale)
+                ))
+            })
+        })
+    });
+    build_decimal_array(values, precision, scale, cast_options)
+}
+
 /// Cast numeric types to Boolean
 ///
 /// Any zero value returns `false` while non-zero returns `true`
@@ -1571,7 +2189,7 @@ where
     let values = cast_values.as_any().downcast_ref::<StringArray>().unwrap();
 
     let keys_builder = PrimitiveBuilder::<K>::new(values.len());
-    let values_builder = StringBuilder::new(values.len());
+    let values_builder = StringBuilder::with_capacity(values.len(), values.len());
     let mut b = StringDictionaryBuilder::new(keys_builder, values_builder);
 
     // copy each element one at a time
@@ -1935,8 +2553,14 @@ mod tests {
     fn test_cast_with_options_utf8_to_i32() {
         let a = StringArray::from(vec!["5", "6", "seven", "8", "9.1"]);
         let array = Arc::new(a) as ArrayRef;
-        let result =
-            cast_with_options(&array, &DataType::Int32, &CastOptions { safe: false });
+        let result = cast_with_options(
+            &array,
+            &DataType::Int32,
+            &CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            },
+        );
         match result {
             Ok(_) => panic!("expected error"),
             Err(e) => {
@@ -2115,6 +2739,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_cast_string_to_interval() {
+        let a1 = Arc::new(StringArray::from(vec![
+            Some("1 year 2 months"),
+            Some("3 days"),
+            None,
+        ])) as ArrayRef;
+        let a2 = Arc::new(LargeStringArray::from(vec![
+            Some("1 year 2 months"),
+            Some("3 days"),
+            None,
+        ])) as ArrayRef;
+        for array in &[a1, a2] {
+            let b = cast(array, &DataType::Interval(IntervalUnit::YearMonth)).unwrap();
+            let c = b.as_any().downcast_ref::<IntervalYearMonthArray>().unwrap();
+            assert_eq!(14, c.value(0));
+            assert!(c.is_null(1));
+            assert!(c.is_null(2));
+
+            let b = cast(array, &DataType::Interval(IntervalUnit::DayTime)).unwrap();
+            let c = b.as_any().downcast_ref::<IntervalDayTimeArray>().unwrap();
+            assert!(c.is_null(0));
+            assert_eq!(3 << 32, c.value(1));
+            assert!(c.is_null(2));
+
+            let options = CastOptions {
+                safe: false,
+                ..DEFAULT_CAST_OPTIONS
+            };
+            let to_type = DataType::Interval(IntervalUnit::DayTime);
+            assert!(cast_with_options(array, &to_type, &options).is_err());
+        }
+    }
+
     #[test]
     fn test_cast_date32_to_int32() {
         let a = Date32Array::from(vec![10000, 17890]);
@@ -2163,6 +2821,86 @@ mod tests {
         assert!(c.is_null(2));
     }
 
+    #[test]
+    fn test_cast_timestamp_to_time() {
+        let a = TimestampMillisecondArray::from_opt_vec(
+            vec![Some(86_400_000 + 3_723_004), Some(-1), None],
+            Some("UTC".to_string()),
+        );
+        let array = Arc::new(a) as ArrayRef;
+
+        let b = cast(&array, &DataType::Time32(TimeUnit::Second)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32SecondArray>().unwrap();
+        assert_eq!(
+            c,
+            &Time32SecondArray::from(vec![Some(3_723), Some(86_399), None])
+        );
+
+        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
+        assert_eq!(3_723_004_000_000, c.value(0));
+        assert_eq!(86_399_999_000_000, c.value(1));
+        assert!(c.is_null(2));
+    }
+
+    #[test]
+    fn test_cast_date_time_to_timestamp() {
+        let array = Arc::new(Date32Array::from(vec![Some(1), None])) as ArrayRef;
+        let to_type = DataType::Timestamp(TimeUnit::Second, Some("UTC".to_string()));
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(b.data_type(), &to_type);
+        let c = b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
+        assert_eq!(86_400, c.value(0));
+        assert!(c.is_null(1));
+
+        let array = Arc::new(Time32MillisecondArray::from(vec![1_500])) as ArrayRef;
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Microsecond, None)).unwrap();
+        let c = b
+            .as_any()
+            .downcast_ref::<TimestampMicrosecondArray>()
+            .unwrap();
+        assert_eq!(1_500_000, c.value(0));
+
+        // the timestamp of 1970-01-01 00:00:01.5 in seconds is truncated
+        let b = cast(&array, &DataType::Timestamp(TimeUnit::Second, None)).unwrap();
+        let c = b.as_any().downcast_ref::<TimestampSecondArray>().unwrap();
+        assert_eq!(1, c.value(0));
+    }
+
+    #[test]
+    fn test_cast_temporal_overflow() {
+        let array = Arc::new(Date64Array::from(vec![i64::MAX])) as ArrayRef;
+        let to_type = DataType::Timestamp(TimeUnit::Nanosecond, None);
+        let b = cast(&array, &to_type).unwrap();
+        assert!(b.is_null(0));
+
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert!(err.to_string().contains("overflows"), "{}", err);
+
+        let array = Arc::new(Time32SecondArray::from(vec![3_600, i32::MAX])) as ArrayRef;
+        let b = cast(&array, &DataType::Time32(TimeUnit::Millisecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time32MillisecondArray>().unwrap();
+        assert_eq!(
+            c,
+            &Time32MillisecondArray::from(vec![Some(3_600_000), None])
+        );
+        assert!(cast_with_options(
+            &array,
+            &DataType::Time32(TimeUnit::Millisecond),
+            &options
+        )
+        .is_err());
+
+        // time32 to time64 can't overflow
+        let b = cast(&array, &DataType::Time64(TimeUnit::Nanosecond)).unwrap();
+        let c = b.as_any().downcast_ref::<Time64NanosecondArray>().unwrap();
+        assert_eq!(3_600_000_000_000, c.value(0));
+    }
+
     #[test]
     fn test_cast_timestamp_to_i64() {
         let a = TimestampMillisecondArray::from_opt_vec(
@@ -3255,7 +3993,7 @@ mod tests {
         use DataType::*;
 
         let keys_builder = PrimitiveBuilder::<Int8Type>::new(10);
-        let values_builder = StringBuilder::new(10);
+        let values_builder = StringBuilder::default();
         let mut builder = StringDictionaryBuilder::new(keys_builder, values_builder);
         builder.append("one").unwrap();
         builder.append_null().unwrap();
@@ -3347,7 +4085,7 @@ mod tests {
         // string values (and encode the expected behavior here);
 
         let keys_builder = PrimitiveBuilder::<Int32Type>::new(10);
-        let values_builder = StringBuilder::new(10);
+        let values_builder = StringBuilder::default();
         let mut builder = StringDictionaryBuilder::new(keys_builder, values_builder);
 
         // add 200 distinct values (which can be stored by a
@@ -3452,6 +4190,26 @@ mod tests {
         assert_eq!(cast_array, &expected);
     }
 
+    #[test]
+    fn test_cast_null_array_to_other_types() {
+        let array = Arc::new(NullArray::new(3)) as ArrayRef;
+        let types = vec![
+            DataType::Utf8,
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+            DataType::List(Box::new(Field::new("item", DataType::Int8, true))),
+            DataType::Struct(vec![Field::new("a", DataType::Int32, true)]),
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+            DataType::Decimal(10, 2),
+        ];
+        for cast_type in types {
+            let cast_array = cast(&array, &cast_type).expect("cast failed");
+            assert_eq!(cast_array.data_type(), &cast_type);
+            assert_eq!(cast_array.len(), 3);
+            assert_eq!(cast_array.null_count(), 3);
+        }
+        assert!(cast(&array, &DataType::Float16).is_err());
+    }
+
     /// Print the `DictionaryArray` `array` as a vector of strings
     fn array_to_strings(array: &ArrayRef) -> Vec<String> {
         (0..array.len())
@@ -3527,6 +4285,167 @@ mod tests {
         assert_eq!(false, c.is_valid(5)); // "2000-01-01"
     }
 
+    #[test]
+    fn test_cast_decimal_to_decimal() {
+        let array = make_decimal_array(vec![Some(12345), Some(-12355), None], 10, 3);
+
+        let b = cast(&array, &DataType::Decimal(10, 5)).unwrap();
+        assert_eq!(b.data_type(), &DataType::Decimal(10, 5));
+        assert_eq!(
+            decimal_values(&b),
+            vec![Some(1234500), Some(-1235500), None]
+        );
+
+        // 12.345 and -12.355 to one fractional digit
+        let to_type = DataType::Decimal(10, 1);
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(decimal_values(&b), vec![Some(123), Some(-124), None]);
+
+        let options = CastOptions {
+            rounding: RoundingMode::Truncate,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &to_type, &options).unwrap();
+        assert_eq!(decimal_values(&b), vec![Some(123), Some(-123), None]);
+
+        let array = make_decimal_array(vec![Some(1225), Some(1235), Some(1236)], 10, 3);
+        let options = CastOptions {
+            rounding: RoundingMode::HalfEven,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &DataType::Decimal(10, 2), &options).unwrap();
+        assert_eq!(decimal_values(&b), vec![Some(122), Some(124), Some(124)]);
+    }
+
+    #[test]
+    fn test_cast_decimal_overflow() {
+        let array = make_decimal_array(vec![Some(99999), Some(-100000), Some(1)], 10, 3);
+
+        // 99.999 rounds to 100.00, which overflows a precision of 4
+        let to_type = DataType::Decimal(4, 2);
+        let b = cast(&array, &to_type).unwrap();
+        assert_eq!(decimal_values(&b), vec![None, None, Some(0)]);
+
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let err = cast_with_options(&array, &to_type, &options).unwrap_err();
+        assert!(err.to_string().contains("Cannot cast 100.00"));
+
+        let b = cast(&array, &DataType::Decimal(38, 38)).unwrap();
+        assert_eq!(decimal_values(&b), vec![None, None, Some(10i128.pow(35))]);
+        let b = cast(&array, &DataType::Int8).unwrap();
+        let c = b.as_any().downcast_ref::<Int8Array>().unwrap();
+        assert_eq!(c, &Int8Array::from(vec![100, -100, 0]));
+    }
+
+    #[test]
+    fn test_cast_decimal_to_numeric_and_string() {
+        let array = make_decimal_array(vec![Some(-1250), Some(5), None], 10, 3);
+
+        let b = cast(&array, &DataType::Int64).unwrap();
+        let c = b.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(c, &Int64Array::from(vec![Some(-1), Some(0), None]));
+
+        let b = cast(&array, &DataType::Float64).unwrap();
+        let c = b.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(c, &Float64Array::from(vec![Some(-1.25), Some(0.005), None]));
+
+        let b = cast(&array, &DataType::Utf8).unwrap();
+        let c = b.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(
+            c,
+            &StringArray::from(vec![Some("-1.250"), Some("0.005"), None])
+        );
+
+        let array = make_decimal_array(vec![Some(-3)], 5, 0);
+        let b = cast(&array, &DataType::LargeUtf8).unwrap();
+        let c = b.as_any().downcast_ref::<LargeStringArray>().unwrap();
+        assert_eq!(c, &LargeStringArray::from(vec!["-3"]));
+        let b = cast(&array, &DataType::UInt32).unwrap();
+        assert!(b.is_null(0));
+    }
+
+    #[test]
+    fn test_cast_numeric_to_decimal() {
+        let array =
+            Arc::new(Int32Array::from(vec![Some(1), None, Some(-1000)])) as ArrayRef;
+        let b = cast(&array, &DataType::Decimal(5, 2)).unwrap();
+        assert_eq!(decimal_values(&b), vec![Some(100), None, None]);
+
+        let array = Arc::new(UInt64Array::from(vec![u64::MAX])) as ArrayRef;
+        let b = cast(&array, &DataType::Decimal(20, 2)).unwrap();
+        assert_eq!(b.null_count(), 1);
+
+        let array = Arc::new(Float64Array::from(vec![
+            Some(1.125),
+            Some(-0.125),
+            Some(f64::NAN),
+            None,
+        ])) as ArrayRef;
+        let b = cast(&array, &DataType::Decimal(10, 2)).unwrap();
+        assert_eq!(decimal_values(&b), vec![Some(113), Some(-13), None, None]);
+        let options = CastOptions {
+            rounding: RoundingMode::HalfEven,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &DataType::Decimal(10, 2), &options).unwrap();
+        assert_eq!(decimal_values(&b), vec![Some(112), Some(-12), None, None]);
+    }
+
+    #[test]
+    fn test_cast_string_to_decimal() {
+        let array = Arc::new(StringArray::from(vec![
+            Some("1.23"),
+            Some("-0.5"),
+            Some(" 42 "),
+            Some(".125"),
+            Some("1.2.3"),
+            Some("1e3"),
+            Some(""),
+            None,
+        ])) as ArrayRef;
+        let b = cast(&array, &DataType::Decimal(10, 2)).unwrap();
+        assert_eq!(
+            decimal_values(&b),
+            vec![
+                Some(123),
+                Some(-50),
+                Some(4200),
+                Some(13),
+                None,
+                Some(100000),
+                None,
+                None
+            ]
+        );
+
+        let options = CastOptions {
+            safe: false,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let err =
+            cast_with_options(&array, &DataType::Decimal(10, 2), &options).unwrap_err();
+        assert!(err.to_string().contains("Cannot cast string '1.2.3'"));
+
+        let array = Arc::new(LargeStringArray::from(vec![
+            "0.125",
+            "0.135",
+            "0.1250001",
+            "-0.125",
+        ])) as ArrayRef;
+        let options = CastOptions {
+            rounding: RoundingMode::HalfEven,
+            ..DEFAULT_CAST_OPTIONS
+        };
+        let b = cast_with_options(&array, &DataType::Decimal(10, 2), &options).unwrap();
+        assert_eq!(
+            decimal_values(&b),
+            vec![Some(12), Some(14), Some(13), Some(-12)]
+        );
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)] // running forever
     fn test_can_cast_types() {
@@ -3680,9 +4599,30 @@ mod tests {
             Arc::new(DurationMillisecondArray::from(vec![1000, 2000])),
             Arc::new(DurationMicrosecondArray::from(vec![1000, 2000])),
             Arc::new(DurationNanosecondArray::from(vec![1000, 2000])),
+            make_decimal_array(vec![Some(123), None, Some(-4567)], 10, 2),
         ]
     }
 
+    fn make_decimal_array(
+        values: Vec<Option<i128>>,
+        precision: usize,
+        scale: usize,
+    ) -> ArrayRef {
+        let mut builder = DecimalBuilder::with_capacity(values.len(), precision, scale);
+        for value in values {
+            match value {
+                Some(value) => builder.append_value(value).unwrap(),
+                None => builder.append_null().unwrap(),
+            }
+        }
+        Arc::new(builder.finish())
+    }
+
+    fn decimal_values(array: &ArrayRef) -> Vec<Option<i128>> {
+        let array = array.as_any().downcast_ref::<DecimalArray>().unwrap();
+        super::decimal_values(array).collect()
+    }
+
     fn make_list_array() -> ListArray {
         // Construct a value array
         let value_data = ArrayData::builder(DataType::Int32)
@@ -3778,7 +4718,7 @@ mod tests {
     fn make_dictionary_utf8<K: ArrowDictionaryKeyType>() -> ArrayRef {
         let keys_builder = PrimitiveBuilder::<K>::new(2);
         // Pick Int32 arbitrarily for dictionary values
-        let values_builder = StringBuilder::new(2);
+        let values_builder = StringBuilder::default();
         let mut b = StringDictionaryBuilder::new(keys_builder, values_builder);
         b.append("foo").unwrap();
         b.append("bar").unwrap();
@@ -3846,6 +4786,8 @@ mod tests {
             Dictionary(Box::new(DataType::Int8), Box::new(DataType::Int32)),
             Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8)),
             Dictionary(Box::new(DataType::UInt32), Box::new(DataType::Utf8)),
+            Decimal(38, 10),
+            Decimal(5, 0),
         ]
     }
 
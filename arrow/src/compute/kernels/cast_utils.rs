@@ -17,6 +17,7 @@
 
 use crate::error::{ArrowError, Result};
 use chrono::{prelude::*, LocalResult};
+use std::convert::TryFrom;
 
 /// Accepts a string in RFC3339 / ISO8601 standard format and some
 /// variants and converts it to a nanosecond precision timestamp.
@@ -154,6 +155,144 @@ fn naive_datetime_to_timestamp(s: &str, datetime: NaiveDateTime) -> Result<i64>
     }
 }
 
+/// Accepts a PostgreSQL-style interval string and converts it to a
+/// number of months, the value of an `Interval(YearMonth)`.
+///
+/// See [`string_to_interval_day_time`] for the accepted format. The
+/// interval must not have any days or time.
+///
+/// Examples of accepted inputs:
+/// * `1 year 2 months`
+/// * `-3 mons`
+/// * `1 years 2 mons 0 days 0 hours 0 mins 0.000 secs`
+pub fn string_to_interval_year_month(s: &str) -> Result<i32> {
+    let interval = parse_interval(s)?;
+    if interval.days != 0 || interval.millis != 0 {
+        return Err(ArrowError::CastError(format!(
+            "Error parsing '{}' as interval of years and months: it has days or time",
+            s
+        )));
+    }
+    to_i32(s, interval.months)
+}
+
+/// Accepts a PostgreSQL-style interval string and converts it to the
+/// value of an `Interval(DayTime)`, i.e. a number of days in the upper
+/// 32 bits and a number of milliseconds in the lower 32 bits.
+///
+/// The interval is a sequence of numbers followed by a unit, `year`,
+/// `month`/`mon`, `week`, `day`, `hour`, `minute`/`min`, `second`/`sec`
+/// or `millisecond`, in singular or plural, and of times `[-]HH:MM[:SS[.fff]]`.
+/// Only seconds can have a fractional part, which is rounded to
+/// milliseconds. The interval must not have any years or months, as
+/// their number of days is not fixed.
+///
+/// Examples of accepted inputs:
+/// * `3 days 04:05:06`
+/// * `1 day -2 hours 30 mins`
+/// * `1.5 secs`
+/// * `0 years 0 mons 3 days 4 hours 5 mins 6.000 secs`
+pub fn string_to_interval_day_time(s: &str) -> Result<i64> {
+    let interval = parse_interval(s)?;
+    if interval.months != 0 {
+        return Err(ArrowError::CastError(format!(
+            "Error parsing '{}' as interval of days and time: it has years or months",
+            s
+        )));
+    }
+    let days = to_i32(s, interval.days)?;
+    let millis = to_i32(s, interval.millis)?;
+    Ok(((days as i64) << 32) | (millis as u32 as i64))
+}
+
+/// The components of an interval parsed by [`parse_interval`]
+#[derive(Debug, Default)]
+struct Interval {
+    months: i64,
+    days: i64,
+    millis: i64,
+}
+
+fn parse_interval(s: &str) -> Result<Interval> {
+    let error = || ArrowError::CastError(format!("Error parsing '{}' as interval", s));
+
+    let mut interval = Interval::default();
+    let mut tokens = s.split_whitespace().peekable();
+    if tokens.peek().is_none() {
+        return Err(error());
+    }
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            let millis = parse_interval_time(token).ok_or_else(error)?;
+            interval.millis = interval.millis.checked_add(millis).ok_or_else(error)?;
+            continue;
+        }
+
+        let unit = tokens.next().ok_or_else(error)?.to_lowercase();
+        let unit = unit.strip_suffix('s').unwrap_or(&unit);
+        let (component, factor) = match unit {
+            "year" => (&mut interval.months, 12),
+            "month" | "mon" => (&mut interval.months, 1),
+            "week" => (&mut interval.days, 7),
+            "day" => (&mut interval.days, 1),
+            "hour" => (&mut interval.millis, 3_600_000),
+            "minute" | "min" => (&mut interval.millis, 60_000),
+            "millisecond" => (&mut interval.millis, 1),
+            "second" | "sec" => {
+                let seconds = token.parse::<f64>().map_err(|_| error())?;
+                let millis = (seconds * 1000.0).round();
+                if !millis.is_finite() || millis.abs() > i64::MAX as f64 {
+                    return Err(error());
+                }
+                interval.millis = interval
+                    .millis
+                    .checked_add(millis as i64)
+                    .ok_or_else(error)?;
+                continue;
+            }
+            _ => return Err(error()),
+        };
+        let value = token.parse::<i64>().map_err(|_| error())?;
+        *component = value
+            .checked_mul(factor)
+            .and_then(|value| component.checked_add(value))
+            .ok_or_else(error)?;
+    }
+    Ok(interval)
+}
+
+/// Parses a time `[-]HH:MM[:SS[.fff]]` into a number of milliseconds
+fn parse_interval_time(s: &str) -> Option<i64> {
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => (-1, s),
+        None => (1, s),
+    };
+    let mut parts = s.split(':');
+    let hours = parts.next()?.parse::<i64>().ok()?;
+    let minutes = parts.next()?.parse::<i64>().ok()?;
+    let seconds = match parts.next() {
+        Some(seconds) => seconds.parse::<f64>().ok()?,
+        None => 0.0,
+    };
+    if parts.next().is_some()
+        || hours < 0
+        || !(0..60).contains(&minutes)
+        || !(0.0..60.0).contains(&seconds)
+    {
+        return None;
+    }
+    let millis = hours
+        .checked_mul(3_600_000)?
+        .checked_add(minutes * 60_000 + (seconds * 1000.0).round() as i64)?;
+    Some(sign * millis)
+}
+
+fn to_i32(s: &str, value: i64) -> Result<i32> {
+    i32::try_from(value).map_err(|_| {
+        ArrowError::CastError(format!("Error parsing '{}' as interval: overflow", s))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -275,6 +414,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn string_to_interval() -> Result<()> {
+        assert_eq!(string_to_interval_year_month("1 year 2 months")?, 14);
+        assert_eq!(string_to_interval_year_month("-3 mons")?, -3);
+        assert_eq!(
+            string_to_interval_year_month(
+                "1 years 2 mons 0 days 0 hours 0 mins 0.000 secs"
+            )?,
+            14
+        );
+
+        let day_time = |days: i64, millis: i64| (days << 32) | (millis as u32 as i64);
+        assert_eq!(
+            string_to_interval_day_time("3 days 04:05:06")?,
+            day_time(3, 14_706_000)
+        );
+        assert_eq!(
+            string_to_interval_day_time("1 week 1 DAY -2 hours 30 mins")?,
+            day_time(8, -5_400_000)
+        );
+        assert_eq!(string_to_interval_day_time("1.5 secs")?, day_time(0, 1500));
+        assert_eq!(
+            string_to_interval_day_time("-00:00:01.25")?,
+            day_time(0, -1250)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn string_to_interval_invalid() {
+        assert!(string_to_interval_year_month("").is_err());
+        assert!(string_to_interval_year_month("1 year 1 day").is_err());
+        assert!(string_to_interval_year_month("1 decade").is_err());
+        assert!(string_to_interval_year_month("1.5 years").is_err());
+        assert!(string_to_interval_day_time("1 month").is_err());
+        assert!(string_to_interval_day_time("3 days 04:65").is_err());
+        assert!(string_to_interval_day_time("3 days 4").is_err());
+        assert!(string_to_interval_day_time("25 days 0 hours").is_ok());
+        assert!(string_to_interval_day_time("600 hours").is_err());
+    }
+
     // Parse a timestamp to timestamp int with a useful human readable error message
     fn parse_timestamp(s: &str) -> Result<i64> {
         let result = string_to_timestamp_nanos(s);
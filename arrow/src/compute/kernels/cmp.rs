@@ -0,0 +1,459 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines comparison kernels of [`Datum`]s, i.e. of two arrays, or of an array and a
+//! [`Scalar`] on either side.
+//!
+//! Unlike the kernels of [`comparison`](super::comparison), these accept arrays of any
+//! type that has an order, and dispatch on the type at runtime. Primitive and string
+//! arrays use the kernels of [`comparison`](super::comparison), and nested arrays are
+//! compared for (in)equality by deep equality of their values.
+//!
+//! The result is null where either side is null.
+
+use std::cmp::Ordering;
+
+use crate::array::*;
+use crate::compute::kernels::comparison;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+
+/// A comparison operator
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+impl Op {
+    /// Returns the operator `op` such that `a op b` is `b self a`.
+    fn swap(self) -> Self {
+        match self {
+            Op::Less => Op::Greater,
+            Op::LessEqual => Op::GreaterEqual,
+            Op::Greater => Op::Less,
+            Op::GreaterEqual => Op::LessEqual,
+            op => op,
+        }
+    }
+
+    fn matches(self, ordering: Ordering) -> bool {
+        match self {
+            Op::Equal => ordering == Ordering::Equal,
+            Op::NotEqual => ordering != Ordering::Equal,
+            Op::Less => ordering == Ordering::Less,
+            Op::LessEqual => ordering != Ordering::Greater,
+            Op::Greater => ordering == Ordering::Greater,
+            Op::GreaterEqual => ordering != Ordering::Less,
+        }
+    }
+}
+
+/// Perform `left == right` operation on two [`Datum`]s.
+pub fn eq(left: &dyn Datum, right: &dyn Datum) -> Result<BooleanArray> {
+    compare(Op::Equal, left, right)
+}
+
+/// Perform `left != right` operation on two [`Datum`]s.
+pub fn neq(left: &dyn Datum, right: &dyn Datum) -> Result<BooleanArray> {
+    compare(Op::NotEqual, left, right)
+}
+
+/// Perform `left < right` operation on two [`Datum`]s.
+pub fn lt(left: &dyn Datum, right: &dyn Datum) -> Result<BooleanArray> {
+    compare(Op::Less, left, right)
+}
+
+/// Perform `left <= right` operation on two [`Datum`]s.
+pub fn lt_eq(left: &dyn Datum, right: &dyn Datum) -> Result<BooleanArray> {
+    compare(Op::LessEqual, left, right)
+}
+
+/// Perform `left > right` operation on two [`Datum`]s.
+pub fn gt(left: &dyn Datum, right: &dyn Datum) -> Result<BooleanArray> {
+    compare(Op::Greater, left, right)
+}
+
+/// Perform `left >= right` operation on two [`Datum`]s.
+pub fn gt_eq(left: &dyn Datum, right: &dyn Datum) -> Result<BooleanArray> {
+    compare(Op::GreaterEqual, left, right)
+}
+
+fn compare(op: Op, left: &dyn Datum, right: &dyn Datum) -> Result<BooleanArray> {
+    let (left, left_scalar) = left.get();
+    let (right, right_scalar) = right.get();
+    if left.data_type() != right.data_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot compare arrays of different types: {:?} and {:?}",
+            left.data_type(),
+            right.data_type()
+        )));
+    }
+
+    // put the scalar, if any, on the right
+    let (op, left, right, scalar) = match (left_scalar, right_scalar) {
+        (true, false) => (op.swap(), right, left, true),
+        (false, true) => (op, left, right, true),
+        _ => {
+            if left.len() != right.len() {
+                return Err(ArrowError::ComputeError(
+                    "Cannot perform comparison operation on arrays of different length"
+                        .to_string(),
+                ));
+            }
+            (op, left, right, false)
+        }
+    };
+    if scalar && right.is_null(0) {
+        return Ok(BooleanArray::from(vec![None; left.len()]));
+    }
+
+    match left.data_type() {
+        DataType::Int8 => compare_primitive::<Int8Type>(op, left, right, scalar),
+        DataType::Int16 => compare_primitive::<Int16Type>(op, left, right, scalar),
+        DataType::Int32 => compare_primitive::<Int32Type>(op, left, right, scalar),
+        DataType::Int64 => compare_primitive::<Int64Type>(op, left, right, scalar),
+        DataType::UInt8 => compare_primitive::<UInt8Type>(op, left, right, scalar),
+        DataType::UInt16 => compare_primitive::<UInt16Type>(op, left, right, scalar),
+        DataType::UInt32 => compare_primitive::<UInt32Type>(op, left, right, scalar),
+        DataType::UInt64 => compare_primitive::<UInt64Type>(op, left, right, scalar),
+        DataType::Float32 => compare_primitive::<Float32Type>(op, left, right, scalar),
+        DataType::Float64 => compare_primitive::<Float64Type>(op, left, right, scalar),
+        DataType::Date32 => compare_primitive::<Date32Type>(op, left, right, scalar),
+        DataType::Date64 => compare_primitive::<Date64Type>(op, left, right, scalar),
+        DataType::Time32(TimeUnit::Second) => {
+            compare_primitive::<Time32SecondType>(op, left, right, scalar)
+        }
+        DataType::Time32(TimeUnit::Millisecond) => {
+            compare_primitive::<Time32MillisecondType>(op, left, right, scalar)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            compare_primitive::<Time64MicrosecondType>(op, left, right, scalar)
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            compare_primitive::<Time64NanosecondType>(op, left, right, scalar)
+        }
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            compare_primitive::<TimestampSecondType>(op, left, right, scalar)
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            compare_primitive::<TimestampMillisecondType>(op, left, right, scalar)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            compare_primitive::<TimestampMicrosecondType>(op, left, right, scalar)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            compare_primitive::<TimestampNanosecondType>(op, left, right, scalar)
+        }
+        DataType::Duration(TimeUnit::Second) => {
+            compare_primitive::<DurationSecondType>(op, left, right, scalar)
+        }
+        DataType::Duration(TimeUnit::Millisecond) => {
+            compare_primitive::<DurationMillisecondType>(op, left, right, scalar)
+        }
+        DataType::Duration(TimeUnit::Microsecond) => {
+            compare_primitive::<DurationMicrosecondType>(op, left, right, scalar)
+        }
+        DataType::Duration(TimeUnit::Nanosecond) => {
+            compare_primitive::<DurationNanosecondType>(op, left, right, scalar)
+        }
+        DataType::Utf8 => compare_string::<i32>(op, left, right, scalar),
+        DataType::LargeUtf8 => compare_string::<i64>(op, left, right, scalar),
+        DataType::List(_)
+        | DataType::LargeList(_)
+        | DataType::FixedSizeList(_, _)
+        | DataType::Struct(_)
+        | DataType::Map(_, _)
+            if matches!(op, Op::Equal | Op::NotEqual) =>
+        {
+            compare_nested(op, left, right, scalar)
+        }
+        _ => compare_any(op, left, right, scalar),
+    }
+}
+
+fn compare_primitive<T: ArrowNumericType>(
+    op: Op,
+    left: &dyn Array,
+    right: &dyn Array,
+    scalar: bool,
+) -> Result<BooleanArray> {
+    let left = left.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let right = right.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    if scalar {
+        let right = right.value(0);
+        match op {
+            Op::Equal => comparison::eq_scalar(left, right),
+            Op::NotEqual => comparison::neq_scalar(left, right),
+            Op::Less => comparison::lt_scalar(left, right),
+            Op::LessEqual => comparison::lt_eq_scalar(left, right),
+            Op::Greater => comparison::gt_scalar(left, right),
+            Op::GreaterEqual => comparison::gt_eq_scalar(left, right),
+        }
+    } else {
+        match op {
+            Op::Equal => comparison::eq(left, right),
+            Op::NotEqual => comparison::neq(left, right),
+            Op::Less => comparison::lt(left, right),
+            Op::LessEqual => comparison::lt_eq(left, right),
+            Op::Greater => comparison::gt(left, right),
+            Op::GreaterEqual => comparison::gt_eq(left, right),
+        }
+    }
+}
+
+fn compare_string<OffsetSize: StringOffsetSizeTrait>(
+    op: Op,
+    left: &dyn Array,
+    right: &dyn Array,
+    scalar: bool,
+) -> Result<BooleanArray> {
+    let left = left
+        .as_any()
+        .downcast_ref::<GenericStringArray<OffsetSize>>()
+        .unwrap();
+    let right = right
+        .as_any()
+        .downcast_ref::<GenericStringArray<OffsetSize>>()
+        .unwrap();
+    if scalar {
+        let right = right.value(0);
+        match op {
+            Op::Equal => comparison::eq_utf8_scalar(left, right),
+            Op::NotEqual => comparison::neq_utf8_scalar(left, right),
+            Op::Less => comparison::lt_utf8_scalar(left, right),
+            Op::LessEqual => comparison::lt_eq_utf8_scalar(left, right),
+            Op::Greater => comparison::gt_utf8_scalar(left, right),
+            Op::GreaterEqual => comparison::gt_eq_utf8_scalar(left, right),
+        }
+    } else {
+        match op {
+            Op::Equal => comparison::eq_utf8(left, right),
+            Op::NotEqual => comparison::neq_utf8(left, right),
+            Op::Less => comparison::lt_utf8(left, right),
+            Op::LessEqual => comparison::lt_eq_utf8(left, right),
+            Op::Greater => comparison::gt_utf8(left, right),
+            Op::GreaterEqual => comparison::gt_eq_utf8(left, right),
+        }
+    }
+}
+
+/// Compares arrays of any type supported by [`build_compare`], one element at a time.
+/// Compares nested arrays for (in)equality, comparing their nested values deeply. Unlike
+/// [compare_any], this doesn't need an order of the nested values.
+fn compare_nested(
+    op: Op,
+    left: &dyn Array,
+    right: &dyn Array,
+    scalar: bool,
+) -> Result<BooleanArray> {
+    let (left, right) = (left.data_ref(), right.data_ref());
+    Ok((0..left.len())
+        .map(|i| {
+            let j = if scalar { 0 } else { i };
+            if left.is_null(i) || right.is_null(j) {
+                None
+            } else {
+                Some(equal_at(left, right, i, j) == (op == Op::Equal))
+            }
+        })
+        .collect())
+}
+
+fn compare_any(
+    op: Op,
+    left: &dyn Array,
+    right: &dyn Array,
+    scalar: bool,
+) -> Result<BooleanArray> {
+    let cmp = build_compare(left, right)?;
+    Ok((0..left.len())
+        .map(|i| {
+            let j = if scalar { 0 } else { i };
+            if left.is_null(i) || right.is_null(j) {
+                None
+            } else {
+                Some(op.matches(cmp(i, j)))
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::buffer::Buffer;
+
+    #[test]
+    fn test_cmp_primitive() {
+        let array = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let other = Int32Array::from(vec![Some(3), Some(2), Some(1)]);
+        let two = Int32Array::new_scalar(2);
+
+        assert_eq!(
+            lt(&array, &other).unwrap(),
+            BooleanArray::from(vec![Some(true), None, Some(false)])
+        );
+        assert_eq!(
+            gt_eq(&array, &two).unwrap(),
+            BooleanArray::from(vec![Some(false), None, Some(true)])
+        );
+        assert_eq!(
+            gt_eq(&two, &array).unwrap(),
+            BooleanArray::from(vec![Some(true), None, Some(false)])
+        );
+        assert_eq!(eq(&two, &two).unwrap(), BooleanArray::from(vec![true]));
+
+        let null = Scalar::new(new_null_array(&DataType::Int32, 1));
+        assert_eq!(
+            neq(&null, &array).unwrap(),
+            BooleanArray::from(vec![None, None, None])
+        );
+    }
+
+    #[test]
+    fn test_cmp_string() {
+        let array: ArrayRef =
+            Arc::new(StringArray::from(vec![Some("a"), Some("b"), None]));
+        let b = StringArray::new_scalar("b");
+
+        assert_eq!(
+            eq(&array, &b).unwrap(),
+            BooleanArray::from(vec![Some(false), Some(true), None])
+        );
+        assert_eq!(
+            lt(&b, &array).unwrap(),
+            BooleanArray::from(vec![Some(false), Some(false), None])
+        );
+        assert_eq!(
+            lt_eq(&b, &array).unwrap(),
+            BooleanArray::from(vec![Some(false), Some(true), None])
+        );
+    }
+
+    #[test]
+    fn test_cmp_any() {
+        let array = BooleanArray::from(vec![Some(true), Some(false), None]);
+        let other = BooleanArray::from(vec![Some(false), Some(false), Some(true)]);
+        assert_eq!(
+            gt(&array, &other).unwrap(),
+            BooleanArray::from(vec![Some(true), Some(false), None])
+        );
+        assert_eq!(
+            eq(&array, &BooleanArray::new_scalar(false)).unwrap(),
+            BooleanArray::from(vec![Some(false), Some(true), None])
+        );
+
+        let array = BinaryArray::from(vec![&b"a"[..], &b"bc"[..], &b"b"[..]]);
+        let scalar = Scalar::new(BinaryArray::from(vec![&b"b"[..]]));
+        assert_eq!(
+            gt(&array, &scalar).unwrap(),
+            BooleanArray::from(vec![false, true, false])
+        );
+    }
+
+    #[test]
+    fn test_cmp_nested() {
+        let array = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3)]),
+            None,
+            Some(vec![]),
+        ]);
+        let other = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(3), None]),
+            Some(vec![Some(1)]),
+            Some(vec![]),
+        ]);
+        assert_eq!(
+            eq(&array, &other).unwrap(),
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(true)])
+        );
+        let scalar = Scalar::new(array.slice(1, 1));
+        assert_eq!(
+            neq(&scalar, &array).unwrap(),
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(true)])
+        );
+
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(1)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "x"]));
+        let array = StructArray::from(vec![
+            (Field::new("a", DataType::Int32, true), a),
+            (Field::new("b", DataType::Utf8, false), b),
+        ]);
+        let sliced = array.slice(1, 2);
+        assert_eq!(
+            eq(&array.slice(0, 2), &sliced).unwrap(),
+            BooleanArray::from(vec![false, false])
+        );
+        assert_eq!(
+            eq(&array.slice(0, 1), &sliced.slice(1, 1)).unwrap(),
+            BooleanArray::from(vec![true])
+        );
+        // the order of structs is still supported
+        assert_eq!(
+            lt(&array.slice(0, 2), &sliced).unwrap(),
+            BooleanArray::from(vec![false, true])
+        );
+    }
+
+    #[test]
+    fn test_cmp_map() {
+        // [{1: "a"}, null, {1: "a"}, {1: "b"}]
+        let keys: ArrayRef = Arc::new(Int32Array::from(vec![1, 1, 1]));
+        let values: ArrayRef = Arc::new(StringArray::from(vec!["a", "a", "b"]));
+        let entries = StructArray::from(vec![
+            (Field::new("keys", DataType::Int32, false), keys),
+            (Field::new("values", DataType::Utf8, true), values),
+        ]);
+        let data_type = DataType::Map(
+            Box::new(Field::new("entries", entries.data_type().clone(), false)),
+            false,
+        );
+        let data = ArrayData::builder(data_type)
+            .len(4)
+            .add_buffer(Buffer::from_slice_ref(&[0, 1, 1, 2, 3]))
+            .null_bit_buffer(Buffer::from([0b00001101]))
+            .add_child_data(entries.data().clone())
+            .build();
+        let map = MapArray::from(data);
+
+        assert_eq!(
+            eq(&map, &Scalar::new(map.slice(0, 1))).unwrap(),
+            BooleanArray::from(vec![Some(true), None, Some(true), Some(false)])
+        );
+        assert_eq!(
+            neq(&map.slice(0, 2), &map.slice(2, 2)).unwrap(),
+            BooleanArray::from(vec![Some(false), None])
+        );
+    }
+
+    #[test]
+    fn test_cmp_invalid() {
+        let array = Int32Array::from(vec![1, 2]);
+        assert!(eq(&array, &Int64Array::from(vec![1, 2])).is_err());
+        assert!(eq(&array, &Int32Array::from(vec![1])).is_err());
+        assert!(eq(&array, &StringArray::new_scalar("a")).is_err());
+    }
+}
@@ -541,6 +541,198 @@ pub fn gt_eq_utf8_scalar<OffsetSize: StringOffsetSizeTrait>(
     compare_op_scalar!(left, right, |a, b| a >= b)
 }
 
+/// Perform `left == right` operation on [`BinaryArray`] / [`LargeBinaryArray`].
+pub fn eq_binary<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &GenericBinaryArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a == b)
+}
+
+/// Perform `left == right` operation on [`BinaryArray`] / [`LargeBinaryArray`] and a scalar.
+pub fn eq_binary_scalar<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a == b)
+}
+
+/// Perform `left != right` operation on [`BinaryArray`] / [`LargeBinaryArray`].
+pub fn neq_binary<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &GenericBinaryArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a != b)
+}
+
+/// Perform `left != right` operation on [`BinaryArray`] / [`LargeBinaryArray`] and a scalar.
+pub fn neq_binary_scalar<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a != b)
+}
+
+/// Perform `left < right` operation on [`BinaryArray`] / [`LargeBinaryArray`].
+pub fn lt_binary<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &GenericBinaryArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a < b)
+}
+
+/// Perform `left < right` operation on [`BinaryArray`] / [`LargeBinaryArray`] and a scalar.
+pub fn lt_binary_scalar<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a < b)
+}
+
+/// Perform `left <= right` operation on [`BinaryArray`] / [`LargeBinaryArray`].
+pub fn lt_eq_binary<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &GenericBinaryArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a <= b)
+}
+
+/// Perform `left <= right` operation on [`BinaryArray`] / [`LargeBinaryArray`] and a scalar.
+pub fn lt_eq_binary_scalar<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a <= b)
+}
+
+/// Perform `left > right` operation on [`BinaryArray`] / [`LargeBinaryArray`].
+pub fn gt_binary<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &GenericBinaryArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a > b)
+}
+
+/// Perform `left > right` operation on [`BinaryArray`] / [`LargeBinaryArray`] and a scalar.
+pub fn gt_binary_scalar<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a > b)
+}
+
+/// Perform `left >= right` operation on [`BinaryArray`] / [`LargeBinaryArray`].
+pub fn gt_eq_binary<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &GenericBinaryArray<OffsetSize>,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a >= b)
+}
+
+/// Perform `left >= right` operation on [`BinaryArray`] / [`LargeBinaryArray`] and a scalar.
+pub fn gt_eq_binary_scalar<OffsetSize: BinaryOffsetSizeTrait>(
+    left: &GenericBinaryArray<OffsetSize>,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a >= b)
+}
+
+/// Perform `left == right` operation on [`FixedSizeBinaryArray`].
+pub fn eq_fixed_size_binary(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a == b)
+}
+
+/// Perform `left == right` operation on [`FixedSizeBinaryArray`] and a scalar.
+pub fn eq_fixed_size_binary_scalar(
+    left: &FixedSizeBinaryArray,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a == b)
+}
+
+/// Perform `left != right` operation on [`FixedSizeBinaryArray`].
+pub fn neq_fixed_size_binary(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a != b)
+}
+
+/// Perform `left != right` operation on [`FixedSizeBinaryArray`] and a scalar.
+pub fn neq_fixed_size_binary_scalar(
+    left: &FixedSizeBinaryArray,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a != b)
+}
+
+/// Perform `left < right` operation on [`FixedSizeBinaryArray`].
+pub fn lt_fixed_size_binary(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a < b)
+}
+
+/// Perform `left < right` operation on [`FixedSizeBinaryArray`] and a scalar.
+pub fn lt_fixed_size_binary_scalar(
+    left: &FixedSizeBinaryArray,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a < b)
+}
+
+/// Perform `left <= right` operation on [`FixedSizeBinaryArray`].
+pub fn lt_eq_fixed_size_binary(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a <= b)
+}
+
+/// Perform `left <= right` operation on [`FixedSizeBinaryArray`] and a scalar.
+pub fn lt_eq_fixed_size_binary_scalar(
+    left: &FixedSizeBinaryArray,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a <= b)
+}
+
+/// Perform `left > right` operation on [`FixedSizeBinaryArray`].
+pub fn gt_fixed_size_binary(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a > b)
+}
+
+/// Perform `left > right` operation on [`FixedSizeBinaryArray`] and a scalar.
+pub fn gt_fixed_size_binary_scalar(
+    left: &FixedSizeBinaryArray,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a > b)
+}
+
+/// Perform `left >= right` operation on [`FixedSizeBinaryArray`].
+pub fn gt_eq_fixed_size_binary(
+    left: &FixedSizeBinaryArray,
+    right: &FixedSizeBinaryArray,
+) -> Result<BooleanArray> {
+    compare_op!(left, right, |a, b| a >= b)
+}
+
+/// Perform `left >= right` operation on [`FixedSizeBinaryArray`] and a scalar.
+pub fn gt_eq_fixed_size_binary_scalar(
+    left: &FixedSizeBinaryArray,
+    right: &[u8],
+) -> Result<BooleanArray> {
+    compare_op_scalar!(left, right, |a, b| a >= b)
+}
+
 /// Helper function to perform boolean lambda function on values from two arrays using
 /// SIMD.
 #[cfg(feature = "simd")]
@@ -1332,7 +1524,7 @@ mod tests {
     // contains(null, null) = false
     #[test]
     fn test_contains_utf8() {
-        let values_builder = StringBuilder::new(10);
+        let values_builder = StringBuilder::default();
         let mut builder = ListBuilder::new(values_builder);
 
         builder.values().append_value("Lorem").unwrap();
@@ -1616,4 +1808,143 @@ mod tests {
         gt_eq_utf8_scalar,
         vec![false, false, true, true]
     );
+
+    macro_rules! test_binary {
+        ($test_name:ident, $left:expr, $right:expr, $op:ident, $fixed_op:ident, $expected:expr) => {
+            #[test]
+            fn $test_name() {
+                let expected = BooleanArray::from($expected);
+
+                let left = BinaryArray::from($left);
+                let right = BinaryArray::from($right);
+                assert_eq!($op(&left, &right).unwrap(), expected);
+
+                let left = LargeBinaryArray::from($left);
+                let right = LargeBinaryArray::from($right);
+                assert_eq!($op(&left, &right).unwrap(), expected);
+
+                let left =
+                    FixedSizeBinaryArray::try_from_iter($left.into_iter()).unwrap();
+                let right =
+                    FixedSizeBinaryArray::try_from_iter($right.into_iter()).unwrap();
+                assert_eq!($fixed_op(&left, &right).unwrap(), expected);
+            }
+        };
+    }
+
+    macro_rules! test_binary_scalar {
+        ($test_name:ident, $left:expr, $right:expr, $op:ident, $fixed_op:ident, $expected:expr) => {
+            #[test]
+            fn $test_name() {
+                let expected = BooleanArray::from($expected);
+
+                let left = BinaryArray::from($left);
+                assert_eq!($op(&left, $right).unwrap(), expected);
+
+                let left = LargeBinaryArray::from($left);
+                assert_eq!($op(&left, $right).unwrap(), expected);
+
+                let left =
+                    FixedSizeBinaryArray::try_from_iter($left.into_iter()).unwrap();
+                assert_eq!($fixed_op(&left, $right).unwrap(), expected);
+            }
+        };
+    }
+
+    test_binary!(
+        test_binary_array_eq,
+        vec![&b"ab"[..], b"ab", b"ba", b"aa"],
+        vec![&b"ab"[..], b"ba", b"ab", b"ab"],
+        eq_binary,
+        eq_fixed_size_binary,
+        vec![true, false, false, false]
+    );
+    test_binary!(
+        test_binary_array_neq,
+        vec![&b"ab"[..], b"ab", b"ba", b"aa"],
+        vec![&b"ab"[..], b"ba", b"ab", b"ab"],
+        neq_binary,
+        neq_fixed_size_binary,
+        vec![false, true, true, true]
+    );
+    test_binary!(
+        test_binary_array_lt,
+        vec![&b"ab"[..], b"ab", b"ba", b"aa"],
+        vec![&b"ab"[..], b"ba", b"ab", b"ab"],
+        lt_binary,
+        lt_fixed_size_binary,
+        vec![false, true, false, true]
+    );
+    test_binary!(
+        test_binary_array_lt_eq,
+        vec![&b"ab"[..], b"ab", b"ba", b"aa"],
+        vec![&b"ab"[..], b"ba", b"ab", b"ab"],
+        lt_eq_binary,
+        lt_eq_fixed_size_binary,
+        vec![true, true, false, true]
+    );
+    test_binary!(
+        test_binary_array_gt,
+        vec![&b"ab"[..], b"ab", b"ba", b"aa"],
+        vec![&b"ab"[..], b"ba", b"ab", b"ab"],
+        gt_binary,
+        gt_fixed_size_binary,
+        vec![false, false, true, false]
+    );
+    test_binary!(
+        test_binary_array_gt_eq,
+        vec![&b"ab"[..], b"ab", b"ba", b"aa"],
+        vec![&b"ab"[..], b"ba", b"ab", b"ab"],
+        gt_eq_binary,
+        gt_eq_fixed_size_binary,
+        vec![true, false, true, false]
+    );
+    test_binary_scalar!(
+        test_binary_array_eq_scalar,
+        vec![&b"ab"[..], b"ab", b"ba", b"aa"],
+        &b"ab"[..],
+        eq_binary_scalar,
+        eq_fixed_size_binary_scalar,
+        vec![true, true, false, false]
+    );
+    test_binary_scalar!(
+        test_binary_array_neq_scalar,
+        vec![&b"ab"[..], b"ab", b"ba", b"aa"],
+        &b"ab"[..],
+        neq_binary_scalar,
+        neq_fixed_size_binary_scalar,
+        vec![false, false, true, true]
+    );
+    test_binary_scalar!(
+        test_binary_array_lt_scalar,
+        vec![&b"ab"[..], b"ab", b"ba", b"aa"],
+        &b"ab"[..],
+        lt_binary_scalar,
+        lt_fixed_size_binary_scalar,
+        vec![false, false, false, true]
+    );
+    test_binary_scalar!(
+        test_binary_array_lt_eq_scalar,
+        vec![&b"ab"[..], b"ab", b"ba", b"aa"],
+        &b"ab"[..],
+        lt_eq_binary_scalar,
+        lt_eq_fixed_size_binary_scalar,
+        vec![true, true, false, true]
+    );
+    test_binary_scalar!(
+        test_binary_array_gt_scalar,
+        vec![&b"ab"[..], b"ab", b"ba", b"aa"],
+        &b"ab"[..],
+        gt_binary_scalar,
+        gt_fixed_size_binary_scalar,
+        vec![false, false, true, false]
+    );
+    test_binary_scalar!(
+        test_binary_array_gt_eq_scalar,
+        vec![&b"ab"[..], b"ab", b"ba", b"aa"],
+        &b"ab"[..],
+        gt_eq_binary_scalar,
+        gt_eq_fixed_size_binary_scalar,
+        vec![true, true, true, false]
+    );
 }
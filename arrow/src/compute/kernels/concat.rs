@@ -71,6 +71,14 @@ mod tests {
     use crate::datatypes::*;
     use std::sync::Arc;
 
+    #[test]
+    fn test_concat_null_arrays() {
+        let arr = concat(&[&NullArray::new(3), &NullArray::new(2)]).unwrap();
+        assert_eq!(arr.data_type(), &DataType::Null);
+        assert_eq!(arr.len(), 5);
+        assert_eq!(arr.null_count(), 5);
+    }
+
     #[test]
     fn test_concat_empty_vec() {
         let re = concat(&[]);
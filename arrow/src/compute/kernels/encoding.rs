@@ -0,0 +1,328 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to encode the values of binary arrays as text, such as hex or base64,
+//! into string arrays, and to decode them back.
+
+use crate::array::*;
+use crate::buffer::MutableBuffer;
+use crate::datatypes::DataType;
+use crate::error::{ArrowError, Result};
+
+/// An encoding of bytes as text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Hexadecimal, with two lowercase digits per byte. Decoding accepts both cases.
+    Hex,
+    /// Base64 with the standard alphabet and padding.
+    Base64,
+}
+
+impl Encoding {
+    /// Returns the length of the encoding of `len` bytes.
+    fn encoded_len(self, len: usize) -> usize {
+        match self {
+            Encoding::Hex => len * 2,
+            Encoding::Base64 => (len + 2) / 3 * 4,
+        }
+    }
+
+    /// Returns the maximum length of the decoding of `len` bytes of text.
+    fn max_decoded_len(self, len: usize) -> usize {
+        match self {
+            Encoding::Hex => len / 2,
+            Encoding::Base64 => (len + 3) / 4 * 3,
+        }
+    }
+
+    /// Encodes `input` into `output`, which is at least as long as its encoding, returning
+    /// the length of the encoding.
+    fn encode_to_slice(self, input: &[u8], output: &mut [u8]) -> usize {
+        let len = self.encoded_len(input.len());
+        match self {
+            // the output has the length of the encoding, so this can't fail
+            Encoding::Hex => hex::encode_to_slice(input, &mut output[..len]).unwrap(),
+            Encoding::Base64 => {
+                base64::encode_config_slice(input, base64::STANDARD, output);
+            }
+        }
+        len
+    }
+
+    /// Decodes `input` into `output`, which is at least as long as the maximum length of
+    /// its decoding, returning the length of the decoding.
+    fn decode_to_slice(self, input: &[u8], output: &mut [u8]) -> Result<usize> {
+        let result = match self {
+            Encoding::Hex => {
+                let len = input.len() / 2;
+                hex::decode_to_slice(input, &mut output[..len])
+                    .map(|_| len)
+                    .map_err(|e| e.to_string())
+            }
+            Encoding::Base64 => {
+                base64::decode_config_slice(input, base64::STANDARD, output)
+                    .map_err(|e| e.to_string())
+            }
+        };
+        result.map_err(|e| {
+            ArrowError::ComputeError(format!("Failed to decode {:?}: {}", self, e))
+        })
+    }
+}
+
+/// Encodes each value of a `Binary` or `LargeBinary` array with `encoding`, returning a
+/// `Utf8` or `LargeUtf8` array respectively. Nulls remain null.
+///
+/// The offsets of the result are computed while the values are encoded in place, without
+/// allocating a value per row.
+///
+/// # Example
+/// ```
+/// use arrow::array::{BinaryArray, StringArray};
+/// use arrow::compute::kernels::encoding::{encode, Encoding};
+///
+/// let array = BinaryArray::from(vec![Some(&b"arrow"[..]), None, Some(&[0xff, 0x01][..])]);
+///
+/// let hex = encode(&array, Encoding::Hex).unwrap();
+/// assert_eq!(
+///     hex.as_any().downcast_ref::<StringArray>().unwrap(),
+///     &StringArray::from(vec![Some("6172726f77"), None, Some("ff01")])
+/// );
+///
+/// let base64 = encode(&array, Encoding::Base64).unwrap();
+/// assert_eq!(
+///     base64.as_any().downcast_ref::<StringArray>().unwrap(),
+///     &StringArray::from(vec![Some("YXJyb3c="), None, Some("/wE=")])
+/// );
+/// ```
+pub fn encode(array: &dyn Array, encoding: Encoding) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+            transform(
+                array.data_ref(),
+                array.value_offsets(),
+                array.value_data().as_slice(),
+                DataType::Utf8,
+                |len| encoding.encoded_len(len),
+                |input, output| Ok(encoding.encode_to_slice(input, output)),
+            )
+        }
+        DataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<LargeBinaryArray>().unwrap();
+            transform(
+                array.data_ref(),
+                array.value_offsets(),
+                array.value_data().as_slice(),
+                DataType::LargeUtf8,
+                |len| encoding.encoded_len(len),
+                |input, output| Ok(encoding.encode_to_slice(input, output)),
+            )
+        }
+        other => Err(ArrowError::ComputeError(format!(
+            "encode not supported for {:?}",
+            other
+        ))),
+    }
+}
+
+/// Decodes each value of a `Utf8` or `LargeUtf8` array with `encoding`, returning a
+/// `Binary` or `LargeBinary` array respectively. Nulls remain null.
+///
+/// Returns an error naming the encoding when a value is not a valid encoding.
+///
+/// # Example
+/// ```
+/// use arrow::array::{BinaryArray, StringArray};
+/// use arrow::compute::kernels::encoding::{decode, Encoding};
+///
+/// let array = StringArray::from(vec![Some("6172726F77"), None, Some("")]);
+///
+/// let decoded = decode(&array, Encoding::Hex).unwrap();
+/// assert_eq!(
+///     decoded.as_any().downcast_ref::<BinaryArray>().unwrap(),
+///     &BinaryArray::from(vec![Some(&b"arrow"[..]), None, Some(&b""[..])])
+/// );
+///
+/// let array = StringArray::from(vec!["arrow"]);
+/// assert!(decode(&array, Encoding::Base64).is_err());
+/// ```
+pub fn decode(array: &dyn Array, encoding: Encoding) -> Result<ArrayRef> {
+    match array.data_type() {
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+            transform(
+                array.data_ref(),
+                array.value_offsets(),
+                array.value_data().as_slice(),
+                DataType::Binary,
+                |len| encoding.max_decoded_len(len),
+                |input, output| encoding.decode_to_slice(input, output),
+            )
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
+            transform(
+                array.data_ref(),
+                array.value_offsets(),
+                array.value_data().as_slice(),
+                DataType::LargeBinary,
+                |len| encoding.max_decoded_len(len),
+                |input, output| encoding.decode_to_slice(input, output),
+            )
+        }
+        other => Err(ArrowError::ComputeError(format!(
+            "decode not supported for {:?}",
+            other
+        ))),
+    }
+}
+
+/// Applies `op` to the valid values of a variable sized array of `offsets` into `values`,
+/// returning an array of `data_type` with the same offset type.
+///
+/// The values buffer of the result is allocated once, with the sum of `max_len` of the
+/// lengths of the valid values. `op` writes each result into it, returning its length,
+/// from which the offsets of the result are computed.
+fn transform<O, L, F>(
+    data: &ArrayData,
+    offsets: &[O],
+    values: &[u8],
+    data_type: DataType,
+    max_len: L,
+    op: F,
+) -> Result<ArrayRef>
+where
+    O: OffsetSizeTrait,
+    L: Fn(usize) -> usize,
+    F: Fn(&[u8], &mut [u8]) -> Result<usize>,
+{
+    let len = data.len();
+    let ranges = offsets
+        .windows(2)
+        .map(|w| (w[0].to_usize().unwrap(), w[1].to_usize().unwrap()));
+
+    let capacity = ranges
+        .clone()
+        .enumerate()
+        .filter(|(i, _)| data.is_valid(*i))
+        .map(|(_, (start, end))| max_len(end - start))
+        .sum();
+    let mut new_values = MutableBuffer::from_len_zeroed(capacity);
+    let mut new_offsets = MutableBuffer::new((len + 1) * std::mem::size_of::<O>());
+
+    let mut length = 0;
+    new_offsets.push(O::zero());
+    for (i, (start, end)) in ranges.enumerate() {
+        if data.is_valid(i) {
+            length += op(
+                &values[start..end],
+                &mut new_values.as_slice_mut()[length..],
+            )?;
+        }
+        let offset = O::from_usize(length).ok_or_else(|| {
+            ArrowError::ComputeError(format!(
+                "The values of the result exceed the offsets of {:?}",
+                data_type
+            ))
+        })?;
+        new_offsets.push(offset);
+    }
+    new_values.resize(length, 0);
+
+    let null_bit_buffer = data.null_buffer().map(|b| b.bit_slice(data.offset(), len));
+
+    let mut builder = ArrayData::builder(data_type)
+        .len(len)
+        .add_buffer(new_offsets.into())
+        .add_buffer(new_values.into());
+    if let Some(buffer) = null_bit_buffer {
+        builder = builder.null_bit_buffer(buffer);
+    }
+    Ok(make_array(builder.build()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_hex() {
+        let array = BinaryArray::from(vec![
+            Some(&[0x00, 0xab, 0x10][..]),
+            None,
+            Some(&[][..]),
+            Some(&[0xff][..]),
+        ]);
+        let encoded = encode(&array, Encoding::Hex).unwrap();
+        assert_eq!(
+            encoded.as_any().downcast_ref::<StringArray>().unwrap(),
+            &StringArray::from(vec![Some("00ab10"), None, Some(""), Some("ff")])
+        );
+
+        let decoded = decode(encoded.as_ref(), Encoding::Hex).unwrap();
+        assert_eq!(
+            decoded.as_any().downcast_ref::<BinaryArray>().unwrap(),
+            &array
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_base64() {
+        let values: Vec<&[u8]> =
+            vec![&b""[..], &b"a"[..], &b"ab"[..], &b"abc"[..], &b"abcd"[..]];
+        let array = LargeBinaryArray::from(values);
+        let encoded = encode(&array, Encoding::Base64).unwrap();
+        assert_eq!(encoded.data_type(), &DataType::LargeUtf8);
+        assert_eq!(
+            encoded.as_any().downcast_ref::<LargeStringArray>().unwrap(),
+            &LargeStringArray::from(vec!["", "YQ==", "YWI=", "YWJj", "YWJjZA=="])
+        );
+
+        let decoded = decode(encoded.as_ref(), Encoding::Base64).unwrap();
+        assert_eq!(
+            decoded.as_any().downcast_ref::<LargeBinaryArray>().unwrap(),
+            &array
+        );
+    }
+
+    #[test]
+    fn test_decode_sliced() {
+        let array = StringArray::from(vec![Some("zz"), None, Some("YQ=="), Some("YWI=")]);
+        let sliced = array.slice(1, 3);
+
+        let decoded = decode(sliced.as_ref(), Encoding::Base64).unwrap();
+        let values: Vec<Option<&[u8]>> = vec![None, Some(&b"a"[..]), Some(&b"ab"[..])];
+        assert_eq!(
+            decoded.as_any().downcast_ref::<BinaryArray>().unwrap(),
+            &BinaryArray::from(values)
+        );
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        let array = StringArray::from(vec!["abc"]);
+        assert!(decode(&array, Encoding::Hex).is_err());
+        let array = StringArray::from(vec!["zz"]);
+        assert!(decode(&array, Encoding::Hex).is_err());
+        let array = StringArray::from(vec!["Y*=="]);
+        assert!(decode(&array, Encoding::Base64).is_err());
+
+        assert!(encode(&array, Encoding::Hex).is_err());
+        assert!(decode(&BinaryArray::from(vec![&b"00"[..]]), Encoding::Hex).is_err());
+    }
+}
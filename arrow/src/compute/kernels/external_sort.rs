@@ -0,0 +1,675 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the external sort, which sorts more [RecordBatch]es than fit in memory by
+//! spilling sorted runs to IPC files and merging them back.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{self, AtomicUsize};
+use std::sync::Arc;
+
+use crate::alloc::MemoryPool;
+use crate::array::*;
+use crate::compute::kernels::concat::concat;
+use crate::compute::kernels::sort::{
+    lexsort_to_indices, sort_record_batch, LexicographicalComparator, SortColumn,
+    SortOptions,
+};
+use crate::compute::kernels::take::take_record_batch;
+use crate::datatypes::SchemaRef;
+use crate::error::{ArrowError, Result};
+use crate::ipc::reader::FileReader;
+use crate::ipc::writer::FileWriter;
+use crate::record_batch::{RecordBatch, RecordBatchReader};
+
+/// A counter making the names of the spill files of a process unique.
+static SPILL_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Sorts the rows of `batches`, all of schema `schema`, lexicographically by the columns at
+/// the indices given in `sort_columns`, each with its [SortOptions], like
+/// [sort_record_batch](super::sort::sort_record_batch) on all the rows at once.
+///
+/// The batches are buffered while `pool` accepts their memory. When it doesn't, the
+/// buffered batches are sorted into a run that is written to an IPC file in `spill_dir`,
+/// and their memory is released. The returned [SortedBatches] then merges the runs, reading
+/// one batch of each at a time, and removes the files when it is dropped. When all the
+/// batches fit in the pool, they are sorted in memory and nothing is spilled.
+///
+/// The sort is not stable, and the merge keeps about one batch per run in memory, which is
+/// not accounted in `pool`.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow::alloc::TrackingMemoryPool;
+/// use arrow::array::{ArrayRef, Int32Array};
+/// use arrow::compute::external_sort;
+/// use arrow::record_batch::RecordBatch;
+///
+/// # fn main() -> arrow::error::Result<()> {
+/// let batches = (0..4)
+///     .map(|i| {
+///         let a: ArrayRef = Arc::new(Int32Array::from(vec![4 - i, 8 - i]));
+///         RecordBatch::try_from_iter(vec![("a", a)])
+///     })
+///     .collect::<Vec<_>>();
+/// let schema = batches[0].as_ref().unwrap().schema();
+///
+/// // a pool too small for a single batch, spilling each of them
+/// let pool = Arc::new(TrackingMemoryPool::with_limit(8));
+/// let sorted = external_sort(schema, batches, &[(0, None)], pool, &std::env::temp_dir())?;
+/// assert_eq!(sorted.spill_count(), 4);
+///
+/// let values = sorted
+///     .map(|batch| {
+///         let batch = batch?;
+///         let a = batch.column(0).as_any().downcast_ref::<Int32Array>().unwrap();
+///         Ok(a.values().to_vec())
+///     })
+///     .collect::<arrow::error::Result<Vec<_>>>()?;
+/// assert_eq!(values.concat(), vec![1, 2, 3, 4, 5, 6, 7, 8]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn external_sort<I>(
+    schema: SchemaRef,
+    batches: I,
+    sort_columns: &[(usize, Option<SortOptions>)],
+    pool: Arc<dyn MemoryPool>,
+    spill_dir: &Path,
+) -> Result<SortedBatches>
+where
+    I: IntoIterator<Item = Result<RecordBatch>>,
+{
+    if sort_columns.is_empty() {
+        return Err(ArrowError::InvalidArgumentError(
+            "Sort requires at least one column".to_string(),
+        ));
+    }
+    if let Some((i, _)) = sort_columns
+        .iter()
+        .find(|(i, _)| *i >= schema.fields().len())
+    {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Can't sort by the column {} of a batch of {} columns",
+            i,
+            schema.fields().len()
+        )));
+    }
+
+    let mut sorted = SortedBatches {
+        schema: schema.clone(),
+        sort_columns: sort_columns.to_vec(),
+        spill_dir: spill_dir.to_path_buf(),
+        runs: vec![],
+        spill_files: vec![],
+        pending: None,
+        reservation: Reservation { pool, size: 0 },
+        finished: false,
+    };
+
+    let mut buffered = vec![];
+    for batch in batches {
+        let batch = batch?;
+        if batch.schema() != schema {
+            return Err(ArrowError::InvalidArgumentError(
+                "All the batches to sort must have the schema of the sort".to_string(),
+            ));
+        }
+        if batch.num_rows() == 0 {
+            continue;
+        }
+        let size = batch
+            .columns()
+            .iter()
+            .map(|c| c.get_array_memory_size())
+            .sum();
+        if !sorted.reservation.try_grow(size) {
+            if !buffered.is_empty() {
+                sorted.spill(&buffered)?;
+                buffered.clear();
+                sorted.reservation.free();
+            }
+            if !sorted.reservation.try_grow(size) {
+                // a batch larger than the pool on its own is a run by itself
+                sorted.spill(&[batch])?;
+                continue;
+            }
+        }
+        buffered.push(batch);
+    }
+
+    if sorted.spill_files.is_empty() {
+        if !buffered.is_empty() {
+            let batch = concat_batches(&schema, &buffered)?;
+            sorted.pending = Some(sort_record_batch(&batch, sort_columns, None)?);
+        }
+    } else {
+        if !buffered.is_empty() {
+            sorted.spill(&buffered)?;
+            buffered.clear();
+        }
+        sorted.reservation.free();
+        sorted.start_merge()?;
+    }
+    Ok(sorted)
+}
+
+/// The sorted batches returned by [external_sort].
+///
+/// When runs were spilled, each batch holds the rows that are known to precede all the rows
+/// not read yet, so the batches can be of any size.
+pub struct SortedBatches {
+    schema: SchemaRef,
+    sort_columns: Vec<(usize, Option<SortOptions>)>,
+    spill_dir: PathBuf,
+    /// The readers of the spilled runs
+    runs: Vec<SpilledRun>,
+    /// The spill files, removed on drop
+    spill_files: Vec<PathBuf>,
+    /// The sorted rows that were read and not returned yet
+    pending: Option<RecordBatch>,
+    /// The memory of the batches sorted in memory
+    reservation: Reservation,
+    finished: bool,
+}
+
+/// A sorted run spilled to an IPC file.
+struct SpilledRun {
+    reader: FileReader<BufReader<File>>,
+    /// The last row of the batch read last, or `None` once all the batches were read. The
+    /// rows not read yet are not less than it.
+    last: Option<RecordBatch>,
+}
+
+impl SpilledRun {
+    /// Reads the next non-empty batch of this run.
+    fn read_next(&mut self) -> Result<Option<RecordBatch>> {
+        for batch in &mut self.reader {
+            let batch = batch?;
+            if batch.num_rows() > 0 {
+                self.last = Some(batch.slice(batch.num_rows() - 1, 1));
+                return Ok(Some(batch));
+            }
+        }
+        self.last = None;
+        Ok(None)
+    }
+}
+
+/// The bytes recorded in a [MemoryPool], released on drop.
+#[derive(Debug)]
+struct Reservation {
+    pool: Arc<dyn MemoryPool>,
+    size: usize,
+}
+
+impl Reservation {
+    /// Records `size` more bytes in the pool, returning whether the pool accepted them.
+    fn try_grow(&mut self, size: usize) -> bool {
+        let accepted = self.pool.try_grow(size).is_ok();
+        if accepted {
+            self.size += size;
+        }
+        accepted
+    }
+
+    /// Releases all the bytes of this reservation.
+    fn free(&mut self) {
+        self.pool.shrink(self.size);
+        self.size = 0;
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+impl SortedBatches {
+    /// Returns the number of sorted runs that were spilled to files.
+    pub fn spill_count(&self) -> usize {
+        self.spill_files.len()
+    }
+
+    /// Sorts `batches` into a run written to a new spill file, in batches of the size of
+    /// the largest one so that the merge can read it a batch at a time.
+    fn spill(&mut self, batches: &[RecordBatch]) -> Result<()> {
+        let batch_size = batches.iter().map(|b| b.num_rows()).max().unwrap_or(1);
+        let batch = concat_batches(&self.schema, batches)?;
+        let indices =
+            lexsort_to_indices(&sort_columns(&batch, &self.sort_columns), None)?;
+
+        let path = self.spill_dir.join(format!(
+            "arrow-sort-{}-{}.arrow",
+            std::process::id(),
+            SPILL_COUNTER.fetch_add(1, atomic::Ordering::SeqCst)
+        ));
+        let file = File::create(&path)?;
+        // record the file before writing to it, so that it is removed on error
+        self.spill_files.push(path);
+        let mut writer = FileWriter::try_new(file, &self.schema)?;
+        for chunk in indices.values().chunks(batch_size) {
+            let indices = UInt32Array::from(chunk.to_vec());
+            writer.write(&take_record_batch(&batch, &indices, None)?)?;
+        }
+        writer.finish()
+    }
+
+    /// Opens the spilled runs and reads their first batch.
+    fn start_merge(&mut self) -> Result<()> {
+        let mut pending: Option<RecordBatch> = None;
+        for path in &self.spill_files {
+            let reader = FileReader::try_new(BufReader::new(File::open(path)?))?;
+            let mut run = SpilledRun { reader, last: None };
+            if let Some(batch) = run.read_next()? {
+                pending = Some(match pending {
+                    Some(pending) => merge_sorted(&pending, &batch, &self.sort_columns)?,
+                    None => batch,
+                });
+            }
+            self.runs.push(run);
+        }
+        self.pending = pending;
+        Ok(())
+    }
+
+    /// Returns the next sorted batch of the merge of the runs.
+    ///
+    /// All the rows not read yet are not less than the smallest last row read from a run,
+    /// so the pending rows up to it are returned before reading the next batch of its run.
+    fn next_merged(&mut self) -> Result<Option<RecordBatch>> {
+        loop {
+            let bound = match self.smallest_run()? {
+                Some(bound) => bound,
+                None => return Ok(self.pending.take()),
+            };
+            let pending = match self.pending.take() {
+                Some(pending) => pending,
+                None => {
+                    self.pending = self.runs[bound].read_next()?;
+                    continue;
+                }
+            };
+
+            let last = self.runs[bound].last.clone().unwrap();
+            let len = {
+                let columns = sort_columns(&pending, &self.sort_columns);
+                let last_columns = self
+                    .sort_columns
+                    .iter()
+                    .map(|(i, _)| last.column(*i).clone())
+                    .collect::<Vec<_>>();
+                let comparator =
+                    LexicographicalComparator::try_new_pair(&columns, &last_columns)?;
+                partition_point(pending.num_rows(), |i| {
+                    comparator.compare(i, 0) != Ordering::Greater
+                })
+            };
+            let rest = pending.slice(len, pending.num_rows() - len);
+            self.pending = match self.runs[bound].read_next()? {
+                Some(batch) if rest.num_rows() > 0 => {
+                    Some(merge_sorted(&rest, &batch, &self.sort_columns)?)
+                }
+                Some(batch) => Some(batch),
+                None if rest.num_rows() > 0 => Some(rest),
+                None => None,
+            };
+            if len > 0 {
+                return Ok(Some(pending.slice(0, len)));
+            }
+        }
+    }
+
+    /// Returns the index of the run whose last row read is the smallest, or `None` when
+    /// all the runs were read.
+    fn smallest_run(&self) -> Result<Option<usize>> {
+        let runs = self
+            .runs
+            .iter()
+            .enumerate()
+            .filter_map(|(i, run)| run.last.as_ref().map(|last| (i, last)))
+            .collect::<Vec<_>>();
+        if runs.len() <= 1 {
+            return Ok(runs.first().map(|(i, _)| *i));
+        }
+        let lasts = runs
+            .iter()
+            .map(|(_, last)| (*last).clone())
+            .collect::<Vec<_>>();
+        let lasts = concat_batches(&self.schema, &lasts)?;
+        let columns = sort_columns(&lasts, &self.sort_columns);
+        let comparator = LexicographicalComparator::try_new(&columns)?;
+        let smallest = (1..runs.len()).fold(0, |smallest, i| {
+            if comparator.compare(i, smallest) == Ordering::Less {
+                i
+            } else {
+                smallest
+            }
+        });
+        Ok(Some(runs[smallest].0))
+    }
+}
+
+impl Iterator for SortedBatches {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let result = if self.runs.is_empty() {
+            Ok(self.pending.take())
+        } else {
+            self.next_merged()
+        };
+        match result {
+            Ok(Some(batch)) => Some(Ok(batch)),
+            Ok(None) => {
+                self.finished = true;
+                self.reservation.free();
+                None
+            }
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl fmt::Debug for SortedBatches {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortedBatches")
+            .field("schema", &self.schema)
+            .field("sort_columns", &self.sort_columns)
+            .field("spill_files", &self.spill_files)
+            .finish()
+    }
+}
+
+impl RecordBatchReader for SortedBatches {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+impl Drop for SortedBatches {
+    fn drop(&mut self) {
+        // the readers must be closed before the files are removed on some platforms
+        self.runs.clear();
+        for path in &self.spill_files {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// Returns the [SortColumn]s of `batch` at the indices of `sort_columns`.
+fn sort_columns(
+    batch: &RecordBatch,
+    sort_columns: &[(usize, Option<SortOptions>)],
+) -> Vec<SortColumn> {
+    sort_columns
+        .iter()
+        .map(|(i, options)| SortColumn {
+            values: batch.column(*i).clone(),
+            options: *options,
+        })
+        .collect()
+}
+
+/// Concatenates the rows of `batches` into a single batch.
+fn concat_batches(schema: &SchemaRef, batches: &[RecordBatch]) -> Result<RecordBatch> {
+    if batches.len() == 1 {
+        return Ok(batches[0].clone());
+    }
+    let columns = (0..schema.fields().len())
+        .map(|i| {
+            let arrays = batches
+                .iter()
+                .map(|batch| batch.column(i).as_ref())
+                .collect::<Vec<_>>();
+            concat(&arrays)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    RecordBatch::try_new(schema.clone(), columns)
+}
+
+/// Merges two batches sorted by `sort_columns` into a sorted batch.
+fn merge_sorted(
+    left: &RecordBatch,
+    right: &RecordBatch,
+    sort_columns: &[(usize, Option<SortOptions>)],
+) -> Result<RecordBatch> {
+    let left_columns = self::sort_columns(left, sort_columns);
+    let right_columns = sort_columns
+        .iter()
+        .map(|(i, _)| right.column(*i).clone())
+        .collect::<Vec<_>>();
+    let comparator =
+        LexicographicalComparator::try_new_pair(&left_columns, &right_columns)?;
+
+    // the indices of the rows in the concatenation of `left` and `right`
+    let (left_len, right_len) = (left.num_rows(), right.num_rows());
+    let mut indices = Vec::with_capacity(left_len + right_len);
+    let (mut l, mut r) = (0, 0);
+    while l < left_len && r < right_len {
+        if comparator.compare(l, r) == Ordering::Greater {
+            indices.push((left_len + r) as u32);
+            r += 1;
+        } else {
+            indices.push(l as u32);
+            l += 1;
+        }
+    }
+    indices.extend((l..left_len).map(|i| i as u32));
+    indices.extend((left_len + r..left_len + right_len).map(|i| i as u32));
+
+    let batch = concat_batches(&left.schema(), &[left.clone(), right.clone()])?;
+    take_record_batch(&batch, &UInt32Array::from(indices), None)
+}
+
+/// Returns the number of the first `len` indices for which `pred` holds, given that it
+/// holds for all the indices before the first one for which it doesn't.
+fn partition_point<F: Fn(usize) -> bool>(len: usize, pred: F) -> usize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alloc::TrackingMemoryPool;
+    use crate::datatypes::{DataType, Field, Schema};
+
+    fn schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, false),
+        ]))
+    }
+
+    /// Returns `num_batches` batches of `batch_size` rows of the same memory size, with
+    /// the same value of `b` for the same value of `a`.
+    fn batches(num_batches: usize, batch_size: usize) -> Vec<RecordBatch> {
+        (0..num_batches)
+            .map(|i| {
+                let values = (0..batch_size)
+                    .map(|j| {
+                        if j % 10 == 0 {
+                            None
+                        } else {
+                            Some(((i * batch_size + j) * 7919 % 1009) as i64)
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let names = values
+                    .iter()
+                    .map(|v| v.map_or("----".to_string(), |v| format!("{:04}", v)))
+                    .collect::<Vec<_>>();
+                let a: ArrayRef = Arc::new(Int64Array::from(values));
+                let b: ArrayRef = Arc::new(StringArray::from(
+                    names.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
+                ));
+                RecordBatch::try_new(schema(), vec![a, b]).unwrap()
+            })
+            .collect()
+    }
+
+    fn spill_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "arrow-external-sort-{}-{}",
+            name,
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn check_sorted(
+        input: &[RecordBatch],
+        sorted: SortedBatches,
+        sort_columns: &[(usize, Option<SortOptions>)],
+    ) {
+        let output = sorted.collect::<Result<Vec<_>>>().unwrap();
+        let output = concat_batches(&schema(), &output).unwrap();
+        let expected = concat_batches(&schema(), input).unwrap();
+        let expected = sort_record_batch(&expected, sort_columns, None).unwrap();
+        assert_eq!(output.num_rows(), expected.num_rows());
+        // the sort is not stable, but the rows with equal keys are equal
+        assert_eq!(output.column(0).as_ref(), expected.column(0).as_ref());
+        assert_eq!(output.column(1).as_ref(), expected.column(1).as_ref());
+    }
+
+    #[test]
+    fn test_external_sort_in_memory() {
+        let input = batches(5, 100);
+        let pool = Arc::new(TrackingMemoryPool::new());
+        let dir = spill_dir("in-memory");
+        let sorted = external_sort(
+            schema(),
+            input.clone().into_iter().map(Ok),
+            &[(0, None)],
+            pool.clone(),
+            &dir,
+        )
+        .unwrap();
+
+        assert_eq!(sorted.spill_count(), 0);
+        assert!(pool.used() > 0);
+        check_sorted(&input, sorted, &[(0, None)]);
+        assert_eq!(pool.used(), 0);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_external_sort_spill() {
+        let input = batches(20, 100);
+        let batch_size = input[0]
+            .columns()
+            .iter()
+            .map(|c| c.get_array_memory_size())
+            .sum::<usize>();
+        // three batches fit in the pool
+        let pool = Arc::new(TrackingMemoryPool::with_limit(batch_size * 3 + 1));
+        let dir = spill_dir("spill");
+        let sort_columns = [(
+            0,
+            Some(SortOptions {
+                descending: true,
+                nulls_first: false,
+            }),
+        )];
+        let sorted = external_sort(
+            schema(),
+            input.clone().into_iter().map(Ok),
+            &sort_columns,
+            pool.clone(),
+            &dir,
+        )
+        .unwrap();
+
+        assert_eq!(sorted.spill_count(), 7);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 7);
+        assert_eq!(pool.used(), 0);
+        check_sorted(&input, sorted, &sort_columns);
+        assert!(pool.statistics().peak <= batch_size * 3);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 0);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_external_sort_batches_larger_than_pool() {
+        let input = batches(4, 50);
+        let pool = Arc::new(TrackingMemoryPool::with_limit(1));
+        let dir = spill_dir("larger");
+        let sort_columns = [(1, None), (0, None)];
+        let sorted = external_sort(
+            schema(),
+            input.clone().into_iter().map(Ok),
+            &sort_columns,
+            pool,
+            &dir,
+        )
+        .unwrap();
+
+        assert_eq!(sorted.spill_count(), 4);
+        check_sorted(&input, sorted, &sort_columns);
+        fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_external_sort_empty() {
+        let pool = Arc::new(TrackingMemoryPool::new());
+        let mut sorted =
+            external_sort(schema(), vec![], &[(0, None)], pool, &std::env::temp_dir())
+                .unwrap();
+        assert_eq!(sorted.schema(), schema());
+        assert!(sorted.next().is_none());
+    }
+
+    #[test]
+    fn test_external_sort_invalid() {
+        let pool: Arc<dyn MemoryPool> = Arc::new(TrackingMemoryPool::new());
+        let dir = std::env::temp_dir();
+        assert!(external_sort(schema(), vec![], &[], pool.clone(), &dir).is_err());
+        assert!(
+            external_sort(schema(), vec![], &[(2, None)], pool.clone(), &dir).is_err()
+        );
+
+        let other = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let a: ArrayRef = Arc::new(Int64Array::from(vec![1]));
+        let batch = RecordBatch::try_new(other, vec![a]).unwrap();
+        assert!(
+            external_sort(schema(), vec![Ok(batch)], &[(0, None)], pool, &dir).is_err()
+        );
+    }
+}
@@ -18,8 +18,9 @@
 //! Defines miscellaneous array kernels.
 
 use crate::buffer::buffer_bin_and;
+use crate::compute::util::map_record_batch;
 use crate::datatypes::DataType;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
 use crate::{array::*, util::bit_chunk_iterator::BitChunkIterator};
 use std::iter::Enumerate;
@@ -225,18 +226,7 @@ pub fn filter(array: &Array, filter: &BooleanArray) -> Result<ArrayRef> {
     if filter.null_count() > 0 {
         // this greatly simplifies subsequent filtering code
         // now we only have a boolean mask to deal with
-        let array_data = filter.data_ref();
-        let null_bitmap = array_data.null_buffer().unwrap();
-        let mask = filter.values();
-        let offset = filter.offset();
-
-        let new_mask = buffer_bin_and(mask, offset, null_bitmap, offset, filter.len());
-
-        let array_data = ArrayData::builder(DataType::Boolean)
-            .len(filter.len())
-            .add_buffer(new_mask)
-            .build();
-        let filter = BooleanArray::from(array_data);
+        let filter = prep_null_mask_filter(filter);
         // fully qualified syntax, because we have an argument with the same name
         return crate::compute::kernels::filter::filter(array, &filter);
     }
@@ -250,20 +240,45 @@ pub fn filter(array: &Array, filter: &BooleanArray) -> Result<ArrayRef> {
     Ok(make_array(data))
 }
 
+/// Returns a boolean mask whose slots are true where `filter` is true and not null.
+fn prep_null_mask_filter(filter: &BooleanArray) -> BooleanArray {
+    let array_data = filter.data_ref();
+    let null_bitmap = array_data.null_buffer().unwrap();
+    let mask = filter.values();
+    let offset = filter.offset();
+
+    let new_mask = buffer_bin_and(mask, offset, null_bitmap, offset, filter.len());
+
+    let array_data = ArrayData::builder(DataType::Boolean)
+        .len(filter.len())
+        .add_buffer(new_mask)
+        .build();
+    BooleanArray::from(array_data)
+}
+
 /// Returns a new [RecordBatch] with arrays containing only values matching the filter.
-/// WARNING: the nulls of `filter` are ignored and the value on its slot is considered.
-/// Therefore, it is considered undefined behavior to pass `filter` with null values.
+/// As with [filter], the rows where `filter` is null are filtered out.
+///
+/// Returns an error when `filter` does not have as many slots as the batch has rows, or
+/// when a column can't be filtered, naming the column.
 pub fn filter_record_batch(
     record_batch: &RecordBatch,
     filter: &BooleanArray,
 ) -> Result<RecordBatch> {
+    if filter.len() != record_batch.num_rows() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Can't filter a batch of {} rows with a filter of {} slots",
+            record_batch.num_rows(),
+            filter.len()
+        )));
+    }
+    if filter.null_count() > 0 {
+        return filter_record_batch(record_batch, &prep_null_mask_filter(filter));
+    }
     let filter = build_filter(filter)?;
-    let filtered_arrays = record_batch
-        .columns()
-        .iter()
-        .map(|a| make_array(filter(&a.data())))
-        .collect();
-    RecordBatch::try_new(record_batch.schema(), filtered_arrays)
+    map_record_batch(record_batch, |array| {
+        Ok(make_array(filter(array.data_ref())))
+    })
 }
 
 #[cfg(test)]
@@ -274,6 +289,7 @@ mod tests {
         buffer::Buffer,
         datatypes::{DataType, Field},
     };
+    use std::sync::Arc;
 
     macro_rules! def_temporal_test {
         ($test:ident, $array_type: ident, $data: expr) => {
@@ -376,6 +392,16 @@ mod tests {
         assert_eq!(9, d.value(1));
     }
 
+    #[test]
+    fn test_filter_null_array() {
+        let a = NullArray::new(4);
+        let b = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        let c = filter(&a, &b).unwrap();
+        assert_eq!(c.data_type(), &DataType::Null);
+        assert_eq!(c.len(), 2);
+        assert_eq!(c.null_count(), 2);
+    }
+
     #[test]
     fn test_filter_array_low_density() {
         // this test exercises the all 0's branch of the filter algorithm
@@ -625,4 +651,26 @@ mod tests {
         assert_eq!(out_arr0, out_arr1);
         Ok(())
     }
+
+    #[test]
+    fn test_filter_record_batch() -> Result<()> {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c", "d"]));
+        let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)])?;
+
+        let predicate =
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        let filtered = filter_record_batch(&batch, &predicate)?;
+
+        assert_eq!(filtered.schema(), batch.schema());
+        assert_eq!(filtered.column(0).as_ref(), &Int32Array::from(vec![1, 4]));
+        assert_eq!(
+            filtered.column(1).as_ref(),
+            &StringArray::from(vec!["a", "d"])
+        );
+
+        let predicate = BooleanArray::from(vec![true, false]);
+        assert!(filter_record_batch(&batch, &predicate).is_err());
+        Ok(())
+    }
 }
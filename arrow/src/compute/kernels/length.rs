@@ -17,6 +17,7 @@
 
 //! Defines kernel for length of a string array
 
+use crate::compute::kernels::arity::unary_dyn;
 use crate::{
     array::*,
     buffer::Buffer,
@@ -101,8 +102,10 @@ where
 /// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8
 /// * length of null is null.
 /// * length is in number of bytes
+/// * the length of a dictionary of strings is a dictionary of the lengths of its values
 pub fn length(array: &Array) -> Result<ArrayRef> {
     match array.data_type() {
+        DataType::Dictionary(_, _) => unary_dyn(array, length),
         DataType::Utf8 => Ok(octet_length::<i32, Int32Type>(array)),
         DataType::LargeUtf8 => Ok(octet_length::<i64, Int64Type>(array)),
         _ => Err(ArrowError::ComputeError(format!(
@@ -117,8 +120,10 @@ pub fn length(array: &Array) -> Result<ArrayRef> {
 /// * this only accepts StringArray/Utf8 and LargeString/LargeUtf8
 /// * bit_length of null is null.
 /// * bit_length is in number of bits
+/// * the bit_length of a dictionary of strings is a dictionary of the bit lengths of its values
 pub fn bit_length(array: &Array) -> Result<ArrayRef> {
     match array.data_type() {
+        DataType::Dictionary(_, _) => unary_dyn(array, bit_length),
         DataType::Utf8 => Ok(bit_length_impl::<i32, Int32Type>(array)),
         DataType::LargeUtf8 => Ok(bit_length_impl::<i64, Int64Type>(array)),
         _ => Err(ArrowError::ComputeError(format!(
@@ -130,7 +135,10 @@ pub fn bit_length(array: &Array) -> Result<ArrayRef> {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use super::*;
+    use crate::datatypes::Int8Type;
 
     fn length_cases() -> Vec<(Vec<&'static str>, usize, Vec<i32>)> {
         fn double_vec<T: Clone>(v: Vec<T>) -> Vec<T> {
@@ -384,4 +392,32 @@ mod tests {
 
         Ok(())
     }
+
+    /// Tests that the lengths of a dictionary are computed on its values
+    #[test]
+    fn length_dictionary() -> Result<()> {
+        let array: DictionaryArray<Int8Type> = vec![Some("hello"), None, Some("hello")]
+            .into_iter()
+            .collect();
+
+        let result = length(&array)?;
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        assert_eq!(result.keys(), array.keys());
+        assert_eq!(result.values().as_ref(), &Int32Array::from(vec![5]));
+
+        let result = bit_length(&array)?;
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        assert_eq!(result.values().as_ref(), &Int32Array::from(vec![40]));
+
+        let array = array.with_values(Arc::new(Int32Array::from(vec![1])));
+        assert!(length(&array).is_err());
+
+        Ok(())
+    }
 }
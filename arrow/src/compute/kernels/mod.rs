@@ -23,15 +23,23 @@ pub mod arity;
 pub mod boolean;
 pub mod cast;
 pub mod cast_utils;
+pub mod cmp;
 pub mod comparison;
 pub mod concat;
+pub mod encoding;
+#[cfg(feature = "ipc")]
+pub mod external_sort;
 pub mod filter;
 pub mod length;
 pub mod limit;
+pub mod nested;
+pub mod numeric;
 pub mod regexp;
 pub mod sort;
+pub mod statistics;
 pub mod substring;
 pub mod take;
 pub mod temporal;
+pub mod unnest;
 pub mod window;
 pub mod zip;
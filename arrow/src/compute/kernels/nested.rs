@@ -0,0 +1,433 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to extract values from nested arrays: the fields of a [StructArray],
+//! the values of the keys of a [MapArray] and the values of the types of a [UnionArray].
+
+use std::cmp::Ordering;
+
+use crate::array::*;
+use crate::compute::take;
+use crate::error::{ArrowError, Result};
+use crate::util::bit_util;
+
+/// Returns the field named `name` of a [StructArray], null where the struct is null.
+///
+/// When several fields have the name, the first one is returned.
+///
+/// # Example
+/// ```
+/// use std::convert::TryFrom;
+/// use std::sync::Arc;
+/// use arrow::array::{Array, ArrayRef, Int32Array, StructArray};
+/// use arrow::compute::get_field;
+///
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+/// let array = StructArray::try_from(vec![("a", a)]).unwrap();
+///
+/// let field = get_field(&array, "a").unwrap();
+/// assert_eq!(field.as_ref(), &Int32Array::from(vec![1, 2]));
+/// assert!(get_field(&array, "b").is_err());
+/// ```
+pub fn get_field(array: &dyn Array, name: &str) -> Result<ArrayRef> {
+    let array = array
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "Can't get the field \"{}\" of an array of type {:?}",
+                name,
+                array.data_type()
+            ))
+        })?;
+    let pos = array
+        .column_names()
+        .iter()
+        .position(|n| *n == name)
+        .ok_or_else(|| {
+            ArrowError::InvalidArgumentError(format!(
+                "The struct has no field \"{}\", its fields are {:?}",
+                name,
+                array.column_names()
+            ))
+        })?;
+    Ok(array.flatten_column(pos))
+}
+
+/// Returns the field at `path` of nested [StructArray]s, e.g. `&["a", "b"]` for the field
+/// `b` of the field `a` of `array`, null where any of the structs is null.
+pub fn get_field_path(array: &dyn Array, path: &[&str]) -> Result<ArrayRef> {
+    let (first, rest) = path.split_first().ok_or_else(|| {
+        ArrowError::InvalidArgumentError("The path of a field can't be empty".to_string())
+    })?;
+    rest.iter()
+        .try_fold(get_field(array, first)?, |field, name| {
+            get_field(field.as_ref(), name)
+        })
+}
+
+/// Returns the value of the key `key` in each map of a [MapArray], null where the map is
+/// null, the key is null, or the map doesn't have the key.
+///
+/// `key` is either a [Scalar] looked up in every map, or an array of the length of `array`
+/// of the key to look up in each map. It must be of the type of the keys of the maps.
+/// When a map has the key several times, the value of the first one is returned.
+///
+/// # Example
+/// ```
+/// use arrow::array::{Array, StringArray};
+/// # use arrow::array::{ArrayData, ArrayRef, Int32Array, MapArray, StructArray};
+/// # use arrow::buffer::Buffer;
+/// # use arrow::datatypes::{DataType, Field};
+/// # use std::sync::Arc;
+/// use arrow::compute::map_get;
+///
+/// // [{"a": 1, "b": 2}, {"b": 3}]
+/// # let keys: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "b"]));
+/// # let values: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+/// # let entries = StructArray::from(vec![
+/// #     (Field::new("keys", DataType::Utf8, false), keys),
+/// #     (Field::new("values", DataType::Int32, true), values),
+/// # ]);
+/// # let data_type = DataType::Map(
+/// #     Box::new(Field::new("entries", entries.data_type().clone(), false)),
+/// #     false,
+/// # );
+/// # let data = ArrayData::builder(data_type)
+/// #     .len(2)
+/// #     .add_buffer(Buffer::from_slice_ref(&[0, 2, 3]))
+/// #     .add_child_data(entries.data().clone())
+/// #     .build();
+/// # let map = MapArray::from(data);
+/// let values = map_get(&map, &StringArray::new_scalar("a")).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from(vec![Some(1), None]));
+///
+/// let values = map_get(&map, &StringArray::from(vec!["b", "b"])).unwrap();
+/// assert_eq!(values.as_ref(), &Int32Array::from(vec![2, 3]));
+/// ```
+pub fn map_get(array: &dyn Array, key: &dyn Datum) -> Result<ArrayRef> {
+    let map = array.as_any().downcast_ref::<MapArray>().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "Can't look up keys in an array of type {:?}",
+            array.data_type()
+        ))
+    })?;
+    let (key, key_scalar) = key.get();
+    if key.data_type() != &map.key_type() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Can't look up keys of type {:?} in a map of keys of type {:?}",
+            key.data_type(),
+            map.key_type()
+        )));
+    }
+    if !key_scalar && key.len() != map.len() {
+        return Err(ArrowError::InvalidArgumentError(
+            "The keys to look up must have the length of the map array".to_string(),
+        ));
+    }
+
+    let keys = map.keys();
+    let comparator = build_compare(keys.as_ref(), key)?;
+    let offsets = map.value_offsets();
+    let indices = (0..map.len())
+        .map(|i| {
+            let key_index = if key_scalar { 0 } else { i };
+            if map.is_null(i) || key.is_null(key_index) {
+                return None;
+            }
+            (offsets[i] as usize..offsets[i + 1] as usize)
+                .find(|j| comparator(*j, key_index) == Ordering::Equal)
+                .map(|j| j as u32)
+        })
+        .collect::<Vec<_>>();
+    take(map.values().as_ref(), &UInt32Array::from(indices), None)
+}
+
+/// Returns the values of the type `type_id` of a [UnionArray], as an array of the type of
+/// its child, null where the union is null or one of its other types.
+///
+/// # Example
+/// ```
+/// use arrow::array::{Array, Float64Array, Int32Array, UnionBuilder};
+/// use arrow::compute::union_extract;
+/// use arrow::datatypes::{Float64Type, Int32Type};
+///
+/// let mut builder = UnionBuilder::new_dense(3);
+/// builder.append::<Int32Type>("a", 1).unwrap();
+/// builder.append::<Float64Type>("b", 3.0).unwrap();
+/// builder.append::<Int32Type>("a", 4).unwrap();
+/// let union = builder.build().unwrap();
+///
+/// let a = union_extract(&union, 0).unwrap();
+/// assert_eq!(a.as_ref(), &Int32Array::from(vec![Some(1), None, Some(4)]));
+/// let b = union_extract(&union, 1).unwrap();
+/// assert_eq!(b.as_ref(), &Float64Array::from(vec![None, Some(3.0), None]));
+/// ```
+pub fn union_extract(array: &dyn Array, type_id: i8) -> Result<ArrayRef> {
+    let union = as_union(array)?;
+    let data = union.data();
+    if type_id < 0 || type_id as usize >= data.child_data().len() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "The union has no type id {}, it has {} types",
+            type_id,
+            data.child_data().len()
+        )));
+    }
+
+    let type_ids = data.buffers()[0].as_slice();
+    let offsets: Option<&[i32]> =
+        data.buffers().get(1).map(|b| unsafe { b.typed_data() });
+    let nulls = data.null_buffer().map(|b| b.as_slice());
+    // the offsets of dense unions skip the null slots, and the slots of sparse unions
+    // are the slots of their children
+    let mut dense_index = 0;
+    let mut indices = Vec::with_capacity(data.len());
+    for i in 0..data.offset() + data.len() {
+        let is_valid = nulls.map(|n| bit_util::get_bit(n, i)).unwrap_or(true);
+        let index = match offsets {
+            Some(offsets) if is_valid => {
+                dense_index += 1;
+                offsets[dense_index - 1] as u32
+            }
+            Some(_) => 0,
+            None => i as u32,
+        };
+        if i >= data.offset() {
+            let is_type = is_valid && type_ids[i] as i8 == type_id;
+            indices.push(if is_type { Some(index) } else { None });
+        }
+    }
+    take(
+        union.child(type_id).as_ref(),
+        &UInt32Array::from(indices),
+        None,
+    )
+}
+
+/// Returns the type id of each slot of a [UnionArray], null where the union is null.
+///
+/// # Example
+/// ```
+/// use arrow::array::{Int8Array, UnionBuilder};
+/// use arrow::compute::union_type_ids;
+/// use arrow::datatypes::{Float64Type, Int32Type};
+///
+/// let mut builder = UnionBuilder::new_sparse(3);
+/// builder.append::<Int32Type>("a", 1).unwrap();
+/// builder.append_null().unwrap();
+/// builder.append::<Float64Type>("b", 3.0).unwrap();
+/// let union = builder.build().unwrap();
+///
+/// let type_ids = union_type_ids(&union).unwrap();
+/// assert_eq!(type_ids, Int8Array::from(vec![Some(0), None, Some(1)]));
+/// ```
+pub fn union_type_ids(array: &dyn Array) -> Result<Int8Array> {
+    let union = as_union(array)?;
+    Ok((0..union.len())
+        .map(|i| {
+            if union.is_valid(i) {
+                Some(union.type_id(union.offset() + i))
+            } else {
+                None
+            }
+        })
+        .collect())
+}
+
+fn as_union(array: &dyn Array) -> Result<&UnionArray> {
+    array.as_any().downcast_ref::<UnionArray>().ok_or_else(|| {
+        ArrowError::InvalidArgumentError(format!(
+            "Expected a union array, found an array of type {:?}",
+            array.data_type()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::buffer::Buffer;
+    use crate::datatypes::{DataType, Field, Float64Type, Int32Type};
+
+    fn struct_array() -> StructArray {
+        let b: ArrayRef = Arc::new(StringArray::from(vec![Some("x"), None, Some("z")]));
+        let c: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let inner = StructArray::try_from(vec![("b", b), ("c", c)]).unwrap();
+        let fields = vec![
+            (
+                Field::new("a", inner.data_type().clone(), true),
+                Arc::new(inner) as ArrayRef,
+            ),
+            (
+                Field::new("d", DataType::Boolean, true),
+                Arc::new(BooleanArray::from(vec![true, false, true])) as ArrayRef,
+            ),
+        ];
+        // the second struct is null
+        StructArray::from((fields, Buffer::from([0b00000101])))
+    }
+
+    /// [{1: "a", 2: "b"}, null, {}, {2: "c", 3: null}]
+    fn map_array() -> MapArray {
+        let keys: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 2, 3]));
+        let values: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            Some("c"),
+            None,
+        ]));
+        let entries = StructArray::from(vec![
+            (Field::new("keys", DataType::Int32, false), keys),
+            (Field::new("values", DataType::Utf8, true), values),
+        ]);
+        let data_type = DataType::Map(
+            Box::new(Field::new("entries", entries.data_type().clone(), false)),
+            false,
+        );
+        let data = ArrayData::builder(data_type)
+            .len(4)
+            .add_buffer(Buffer::from_slice_ref(&[0, 2, 2, 2, 4]))
+            .null_bit_buffer(Buffer::from([0b00001101]))
+            .add_child_data(entries.data().clone())
+            .build();
+        MapArray::from(data)
+    }
+
+    #[test]
+    fn test_get_field() {
+        let array = struct_array();
+
+        let d = get_field(&array, "d").unwrap();
+        assert_eq!(
+            d.as_ref(),
+            &BooleanArray::from(vec![Some(true), None, Some(true)])
+        );
+
+        let b = get_field_path(&array, &["a", "b"]).unwrap();
+        assert_eq!(
+            b.as_ref(),
+            &StringArray::from(vec![Some("x"), None, Some("z")])
+        );
+        let c = get_field_path(&array, &["a", "c"]).unwrap();
+        assert_eq!(c.as_ref(), &Int32Array::from(vec![Some(1), None, Some(3)]));
+    }
+
+    #[test]
+    fn test_get_field_invalid() {
+        let array = struct_array();
+        assert!(get_field(&array, "b").is_err());
+        assert!(get_field_path(&array, &[]).is_err());
+        assert!(get_field_path(&array, &["d", "a"]).is_err());
+        assert!(get_field(&Int32Array::from(vec![1]), "a").is_err());
+    }
+
+    #[test]
+    fn test_map_get() {
+        let map = map_array();
+
+        let values = map_get(&map, &Int32Array::new_scalar(2)).unwrap();
+        assert_eq!(
+            values.as_ref(),
+            &StringArray::from(vec![Some("b"), None, None, Some("c")])
+        );
+
+        let values = map_get(&map, &Int32Array::new_scalar(3)).unwrap();
+        assert_eq!(values.as_ref(), &StringArray::from(vec![None::<&str>; 4]));
+
+        let keys = Int32Array::from(vec![Some(1), Some(1), Some(1), None]);
+        let values = map_get(&map, &keys).unwrap();
+        assert_eq!(
+            values.as_ref(),
+            &StringArray::from(vec![Some("a"), None, None, None])
+        );
+
+        let sliced = map.slice(2, 2);
+        let values = map_get(sliced.as_ref(), &Int32Array::new_scalar(2)).unwrap();
+        assert_eq!(values.as_ref(), &StringArray::from(vec![None, Some("c")]));
+    }
+
+    #[test]
+    fn test_map_get_invalid() {
+        let map = map_array();
+        assert!(map_get(&map, &Int64Array::new_scalar(1)).is_err());
+        assert!(map_get(&map, &Int32Array::from(vec![1, 2])).is_err());
+        assert!(map_get(&struct_array(), &Int32Array::new_scalar(1)).is_err());
+    }
+
+    /// [a: 1, b: 2.5, null, a: 3, b: 4.5]
+    fn union_array(dense: bool) -> UnionArray {
+        let mut builder = if dense {
+            UnionBuilder::new_dense(5)
+        } else {
+            UnionBuilder::new_sparse(5)
+        };
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append::<Float64Type>("b", 2.5).unwrap();
+        builder.append_null().unwrap();
+        builder.append::<Int32Type>("a", 3).unwrap();
+        builder.append::<Float64Type>("b", 4.5).unwrap();
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn test_union_extract() {
+        for dense in [true, false].iter() {
+            let union = union_array(*dense);
+
+            let a = union_extract(&union, 0).unwrap();
+            assert_eq!(
+                a.as_ref(),
+                &Int32Array::from(vec![Some(1), None, None, Some(3), None])
+            );
+            let b = union_extract(&union, 1).unwrap();
+            assert_eq!(
+                b.as_ref(),
+                &Float64Array::from(vec![None, Some(2.5), None, None, Some(4.5)])
+            );
+
+            let sliced = union.slice(2, 3);
+            let b = union_extract(sliced.as_ref(), 1).unwrap();
+            assert_eq!(b.as_ref(), &Float64Array::from(vec![None, None, Some(4.5)]));
+        }
+    }
+
+    #[test]
+    fn test_union_type_ids() {
+        let union = union_array(true);
+        assert_eq!(
+            union_type_ids(&union).unwrap(),
+            Int8Array::from(vec![Some(0), Some(1), None, Some(0), Some(1)])
+        );
+        let sliced = union.slice(1, 2);
+        assert_eq!(
+            union_type_ids(sliced.as_ref()).unwrap(),
+            Int8Array::from(vec![Some(1), None])
+        );
+    }
+
+    #[test]
+    fn test_union_invalid() {
+        let union = union_array(false);
+        assert!(union_extract(&union, 2).is_err());
+        assert!(union_extract(&union, -1).is_err());
+        assert!(union_extract(&Int32Array::from(vec![1]), 0).is_err());
+        assert!(union_type_ids(&Int32Array::from(vec![1])).is_err());
+    }
+}
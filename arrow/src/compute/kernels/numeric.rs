@@ -0,0 +1,272 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines arithmetic kernels of [`Datum`]s, i.e. of two arrays, or of an array and a
+//! [`Scalar`] on either side, of the same numeric type.
+//!
+//! Two arrays use the kernels of [`arithmetic`](super::arithmetic). The result is null
+//! where either side is null.
+
+use std::sync::Arc;
+
+use num::{One, Zero};
+
+use crate::array::*;
+use crate::compute::kernels::{arithmetic, arity::unary};
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+
+/// An arithmetic operator
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Op {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+/// Perform `left + right` operation on two [`Datum`]s.
+pub fn add(left: &dyn Datum, right: &dyn Datum) -> Result<ArrayRef> {
+    arithmetic_op(Op::Add, left, right)
+}
+
+/// Perform `left - right` operation on two [`Datum`]s.
+pub fn sub(left: &dyn Datum, right: &dyn Datum) -> Result<ArrayRef> {
+    arithmetic_op(Op::Subtract, left, right)
+}
+
+/// Perform `left * right` operation on two [`Datum`]s.
+pub fn mul(left: &dyn Datum, right: &dyn Datum) -> Result<ArrayRef> {
+    arithmetic_op(Op::Multiply, left, right)
+}
+
+/// Perform `left / right` operation on two [`Datum`]s. If any valid right hand value is
+/// zero then the result of this operation will be `Err(ArrowError::DivideByZero)`.
+pub fn div(left: &dyn Datum, right: &dyn Datum) -> Result<ArrayRef> {
+    arithmetic_op(Op::Divide, left, right)
+}
+
+fn arithmetic_op(op: Op, left: &dyn Datum, right: &dyn Datum) -> Result<ArrayRef> {
+    let (left, left_scalar) = left.get();
+    let (right, right_scalar) = right.get();
+    if left.data_type() != right.data_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot perform math operation on arrays of types {:?} and {:?}",
+            left.data_type(),
+            right.data_type()
+        )));
+    }
+    if !left_scalar && !right_scalar && left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform math operation on arrays of different length".to_string(),
+        ));
+    }
+
+    match left.data_type() {
+        DataType::Int8 => {
+            arithmetic_primitive::<Int8Type>(op, left, left_scalar, right, right_scalar)
+        }
+        DataType::Int16 => {
+            arithmetic_primitive::<Int16Type>(op, left, left_scalar, right, right_scalar)
+        }
+        DataType::Int32 => {
+            arithmetic_primitive::<Int32Type>(op, left, left_scalar, right, right_scalar)
+        }
+        DataType::Int64 => {
+            arithmetic_primitive::<Int64Type>(op, left, left_scalar, right, right_scalar)
+        }
+        DataType::UInt8 => {
+            arithmetic_primitive::<UInt8Type>(op, left, left_scalar, right, right_scalar)
+        }
+        DataType::UInt16 => {
+            arithmetic_primitive::<UInt16Type>(op, left, left_scalar, right, right_scalar)
+        }
+        DataType::UInt32 => {
+            arithmetic_primitive::<UInt32Type>(op, left, left_scalar, right, right_scalar)
+        }
+        DataType::UInt64 => {
+            arithmetic_primitive::<UInt64Type>(op, left, left_scalar, right, right_scalar)
+        }
+        DataType::Float32 => arithmetic_primitive::<Float32Type>(
+            op,
+            left,
+            left_scalar,
+            right,
+            right_scalar,
+        ),
+        DataType::Float64 => arithmetic_primitive::<Float64Type>(
+            op,
+            left,
+            left_scalar,
+            right,
+            right_scalar,
+        ),
+        other => Err(ArrowError::ComputeError(format!(
+            "Arithmetic operations are not supported for type {:?}",
+            other
+        ))),
+    }
+}
+
+fn arithmetic_primitive<T>(
+    op: Op,
+    left: &dyn Array,
+    left_scalar: bool,
+    right: &dyn Array,
+    right_scalar: bool,
+) -> Result<ArrayRef>
+where
+    T: ArrowNumericType,
+    T::Native: std::ops::Add<Output = T::Native>
+        + std::ops::Sub<Output = T::Native>
+        + std::ops::Mul<Output = T::Native>
+        + std::ops::Div<Output = T::Native>
+        + Zero
+        + One,
+{
+    let left = left.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    let right = right.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+
+    let result = match (left_scalar, right_scalar) {
+        (false, true) if right.is_null(0) => {
+            return Ok(new_null_array(&T::DATA_TYPE, left.len()))
+        }
+        (true, false) if left.is_null(0) => {
+            return Ok(new_null_array(&T::DATA_TYPE, right.len()))
+        }
+        (false, true) => {
+            let r = right.value(0);
+            match op {
+                Op::Add => unary::<T, _, T>(left, |l| l + r),
+                Op::Subtract => unary::<T, _, T>(left, |l| l - r),
+                Op::Multiply => unary::<T, _, T>(left, |l| l * r),
+                Op::Divide => arithmetic::divide_scalar(left, r)?,
+            }
+        }
+        (true, false) => {
+            let l = left.value(0);
+            match op {
+                Op::Add => unary::<T, _, T>(right, |r| l + r),
+                Op::Subtract => unary::<T, _, T>(right, |r| l - r),
+                Op::Multiply => unary::<T, _, T>(right, |r| l * r),
+                Op::Divide => {
+                    if (0..right.len())
+                        .any(|i| right.is_valid(i) && right.value(i).is_zero())
+                    {
+                        return Err(ArrowError::DivideByZero);
+                    }
+                    // the values of null slots may be zero
+                    unary::<T, _, T>(right, |r| {
+                        if r.is_zero() {
+                            T::Native::zero()
+                        } else {
+                            l / r
+                        }
+                    })
+                }
+            }
+        }
+        _ => match op {
+            Op::Add => arithmetic::add(left, right)?,
+            Op::Subtract => arithmetic::subtract(left, right)?,
+            Op::Multiply => arithmetic::multiply(left, right)?,
+            Op::Divide => arithmetic::divide(left, right)?,
+        },
+    };
+    Ok(Arc::new(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_arrays() {
+        let a = Int32Array::from(vec![Some(5), None, Some(7)]);
+        let b = Int32Array::from(vec![Some(1), Some(2), Some(3)]);
+        assert_eq!(
+            add(&a, &b).unwrap().as_ref(),
+            &Int32Array::from(vec![Some(6), None, Some(10)])
+        );
+        assert_eq!(
+            div(&a, &b).unwrap().as_ref(),
+            &Int32Array::from(vec![Some(5), None, Some(2)])
+        );
+    }
+
+    #[test]
+    fn test_numeric_scalars() {
+        let a = Int32Array::from(vec![Some(5), None, Some(7)]);
+        let two = Int32Array::new_scalar(2);
+        assert_eq!(
+            sub(&a, &two).unwrap().as_ref(),
+            &Int32Array::from(vec![Some(3), None, Some(5)])
+        );
+        assert_eq!(
+            sub(&two, &a).unwrap().as_ref(),
+            &Int32Array::from(vec![Some(-3), None, Some(-5)])
+        );
+        assert_eq!(
+            mul(&two, &a).unwrap().as_ref(),
+            &Int32Array::from(vec![Some(10), None, Some(14)])
+        );
+        assert_eq!(
+            div(&a, &two).unwrap().as_ref(),
+            &Int32Array::from(vec![Some(2), None, Some(3)])
+        );
+
+        let ten = Float64Array::new_scalar(10.0);
+        let b = Float64Array::from(vec![Some(4.0), None, Some(5.0)]);
+        assert_eq!(
+            div(&ten, &b).unwrap().as_ref(),
+            &Float64Array::from(vec![Some(2.5), None, Some(2.0)])
+        );
+
+        let null = Scalar::new(new_null_array(&DataType::Int32, 1));
+        assert_eq!(
+            add(&a, &null).unwrap().as_ref(),
+            &Int32Array::from(vec![None, None, None])
+        );
+    }
+
+    #[test]
+    fn test_numeric_divide_by_zero() {
+        let a = Int32Array::from(vec![Some(5), Some(0), None]);
+        let zero = Int32Array::new_scalar(0);
+        assert!(matches!(div(&a, &zero), Err(ArrowError::DivideByZero)));
+        assert!(matches!(
+            div(&Int32Array::new_scalar(1), &a),
+            Err(ArrowError::DivideByZero)
+        ));
+
+        let b = Int32Array::from(vec![Some(5), Some(1), None]);
+        assert_eq!(
+            div(&Int32Array::new_scalar(10), &b).unwrap().as_ref(),
+            &Int32Array::from(vec![Some(2), Some(10), None])
+        );
+    }
+
+    #[test]
+    fn test_numeric_invalid() {
+        let a = Int32Array::from(vec![1, 2]);
+        assert!(add(&a, &Int64Array::from(vec![1, 2])).is_err());
+        assert!(add(&a, &Int32Array::from(vec![1])).is_err());
+        assert!(
+            add(&StringArray::new_scalar("a"), &StringArray::new_scalar("b")).is_err()
+        );
+    }
+}
@@ -21,9 +21,10 @@ use std::cmp::Ordering;
 
 use crate::array::*;
 use crate::buffer::MutableBuffer;
-use crate::compute::take;
+use crate::compute::{take, take_record_batch};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
 
 use TimeUnit::*;
 
@@ -817,29 +818,175 @@ pub fn lexsort_to_indices(
         ));
     };
 
-    // map to data and DynComparator
-    let flat_columns = columns
+    let lexicographical_comparator = LexicographicalComparator::try_new(columns)?;
+
+    let mut value_indices = (0..row_count).collect::<Vec<usize>>();
+    let mut len = value_indices.len();
+
+    if let Some(limit) = limit {
+        len = limit.min(len);
+    }
+    sort_by(&mut value_indices, len, |a, b| {
+        lexicographical_comparator.compare(*a, *b)
+    });
+
+    Ok(UInt32Array::from(
+        (&value_indices)[0..len]
+            .iter()
+            .map(|i| *i as u32)
+            .collect::<Vec<u32>>(),
+    ))
+}
+
+/// Sorts the rows of `record_batch` lexicographically by the columns at the indices given
+/// in `sort_columns`, each with its [SortOptions], returning the first `limit` rows if set.
+///
+/// Returns an error when an index is out of bounds, or naming the column when one of them
+/// can't be sorted or taken.
+///
+/// Example:
+///
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, Int32Array, StringArray};
+/// use arrow::compute::kernels::sort::{sort_record_batch, SortOptions};
+/// use arrow::record_batch::RecordBatch;
+///
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![2, 1, 2]));
+/// let b: ArrayRef = Arc::new(StringArray::from(vec!["x", "y", "z"]));
+/// let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).unwrap();
+///
+/// let descending = SortOptions {
+///     descending: true,
+///     nulls_first: false,
+/// };
+/// let sorted = sort_record_batch(&batch, &[(0, None), (1, Some(descending))], None).unwrap();
+///
+/// assert_eq!(sorted.column(1).as_ref(), &StringArray::from(vec!["y", "z", "x"]));
+/// ```
+pub fn sort_record_batch(
+    record_batch: &RecordBatch,
+    sort_columns: &[(usize, Option<SortOptions>)],
+    limit: Option<usize>,
+) -> Result<RecordBatch> {
+    let schema = record_batch.schema();
+    let columns = sort_columns
         .iter()
-        .map(
-            |column| -> Result<(&ArrayData, DynComparator, SortOptions)> {
-                // flatten and convert build comparators
-                // use ArrayData for is_valid checks later to avoid dynamic call
+        .map(|(i, options)| {
+            if *i >= record_batch.num_columns() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Can't sort by the column {} of a batch of {} columns",
+                    i,
+                    record_batch.num_columns()
+                )));
+            }
+            Ok(SortColumn {
+                values: record_batch.column(*i).clone(),
+                options: *options,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let indices = lexsort_to_indices(&columns, limit).map_err(|e| {
+        let names = sort_columns
+            .iter()
+            .map(|(i, _)| format!("\"{}\"", schema.field(*i).name()))
+            .collect::<Vec<_>>();
+        ArrowError::ComputeError(format!(
+            "Failed to sort by the columns {}: {}",
+            names.join(", "),
+            e
+        ))
+    })?;
+    take_record_batch(record_batch, &indices, None)
+}
+
+/// A comparator of rows made of several columns, ordering them lexicographically by the
+/// [SortOptions] of each column. It can be used to sort, merge or partition rows.
+///
+/// Example:
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, Int32Array, StringArray};
+/// use arrow::compute::kernels::sort::{LexicographicalComparator, SortColumn};
+///
+/// let columns = vec![
+///     SortColumn {
+///         values: Arc::new(Int32Array::from(vec![1, 1, 0])) as ArrayRef,
+///         options: None,
+///     },
+///     SortColumn {
+///         values: Arc::new(StringArray::from(vec!["b", "a", "c"])) as ArrayRef,
+///         options: None,
+///     },
+/// ];
+/// let comparator = LexicographicalComparator::try_new(&columns).unwrap();
+///
+/// assert_eq!(comparator.compare(0, 1), Ordering::Greater);
+/// assert_eq!(comparator.compare(2, 1), Ordering::Less);
+/// ```
+pub struct LexicographicalComparator<'a> {
+    compare_items: Vec<LexicographicalCompareItem<'a>>,
+}
+
+/// A column compared by a [LexicographicalComparator]. The [ArrayData] of both sides is kept
+/// to check the validity of the rows without a dynamic call.
+type LexicographicalCompareItem<'a> =
+    (&'a ArrayData, &'a ArrayData, DynComparator<'a>, SortOptions);
+
+impl<'a> LexicographicalComparator<'a> {
+    /// Creates a comparator of the rows of `columns` with each other.
+    pub fn try_new(columns: &'a [SortColumn]) -> Result<Self> {
+        let compare_items = columns
+            .iter()
+            .map(|column| {
                 let values = column.values.as_ref();
                 let data = values.data_ref();
                 Ok((
+                    data,
                     data,
                     build_compare(values, values)?,
                     column.options.unwrap_or_default(),
                 ))
-            },
-        )
-        .collect::<Result<Vec<(&ArrayData, DynComparator, SortOptions)>>>()?;
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { compare_items })
+    }
+
+    /// Creates a comparator of the rows of `left` with the rows of `right`, e.g. to merge
+    /// two sorted batches. The columns of `right` are ordered by the options of `left`.
+    pub fn try_new_pair(left: &'a [SortColumn], right: &'a [ArrayRef]) -> Result<Self> {
+        if left.len() != right.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Can't compare rows of {} columns with rows of {} columns",
+                left.len(),
+                right.len()
+            )));
+        }
+        let compare_items = left
+            .iter()
+            .zip(right)
+            .map(|(left, right)| {
+                Ok((
+                    left.values.data_ref(),
+                    right.data_ref(),
+                    build_compare(left.values.as_ref(), right.as_ref())?,
+                    left.options.unwrap_or_default(),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { compare_items })
+    }
 
-    let lex_comparator = |a_idx: &usize, b_idx: &usize| -> Ordering {
-        for (data, comparator, sort_option) in flat_columns.iter() {
-            match (data.is_valid(*a_idx), data.is_valid(*b_idx)) {
+    /// Compares the row at `a_idx` of the left columns with the row at `b_idx` of the
+    /// right columns.
+    pub fn compare(&self, a_idx: usize, b_idx: usize) -> Ordering {
+        for (left, right, comparator, sort_option) in self.compare_items.iter() {
+            match (left.is_valid(a_idx), right.is_valid(b_idx)) {
                 (true, true) => {
-                    match (comparator)(*a_idx, *b_idx) {
+                    match (comparator)(a_idx, b_idx) {
                         // equal, move on to next column
                         Ordering::Equal => continue,
                         order => {
@@ -871,22 +1018,7 @@ pub fn lexsort_to_indices(
         }
 
         Ordering::Equal
-    };
-
-    let mut value_indices = (0..row_count).collect::<Vec<usize>>();
-    let mut len = value_indices.len();
-
-    if let Some(limit) = limit {
-        len = limit.min(len);
     }
-    sort_by(&mut value_indices, len, lex_comparator);
-
-    Ok(UInt32Array::from(
-        (&value_indices)[0..len]
-            .iter()
-            .map(|i| *i as u32)
-            .collect::<Vec<u32>>(),
-    ))
 }
 
 /// It's unstable_sort, may not preserve the order of equal elements
@@ -2236,7 +2368,7 @@ mod tests {
                 nulls_first: true,
             }),
             Some(3),
-            vec![None, None, Some(vec![Some(2)])],
+            vec![None, None, Some(vec![Some(1)])],
             None,
         );
 
@@ -2566,4 +2698,72 @@ mod tests {
         partial_sort(&mut before, last, |a, b| a.cmp(b));
         assert_eq!(&d[0..last], &before[0..last]);
     }
+
+    #[test]
+    fn test_lexicographical_comparator_pair() {
+        let left = vec![
+            SortColumn {
+                values: Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]))
+                    as ArrayRef,
+                options: Some(SortOptions {
+                    descending: false,
+                    nulls_first: true,
+                }),
+            },
+            SortColumn {
+                values: Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef,
+                options: Some(SortOptions {
+                    descending: true,
+                    nulls_first: true,
+                }),
+            },
+        ];
+        let right = vec![
+            Arc::new(Int32Array::from(vec![Some(1), Some(0)])) as ArrayRef,
+            Arc::new(StringArray::from(vec!["b", "a"])) as ArrayRef,
+        ];
+        let comparator = LexicographicalComparator::try_new_pair(&left, &right).unwrap();
+
+        assert_eq!(comparator.compare(0, 0), Ordering::Greater);
+        assert_eq!(comparator.compare(1, 1), Ordering::Less);
+        assert_eq!(comparator.compare(2, 0), Ordering::Greater);
+
+        assert!(LexicographicalComparator::try_new_pair(&left, &right[..1]).is_err());
+    }
+
+    #[test]
+    fn test_sort_record_batch() {
+        let a: ArrayRef =
+            Arc::new(Int32Array::from(vec![Some(2), None, Some(1), Some(2)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["w", "x", "y", "z"]));
+        let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).unwrap();
+
+        let sorted = sort_record_batch(
+            &batch,
+            &[
+                (0, None),
+                (
+                    1,
+                    Some(SortOptions {
+                        descending: true,
+                        nulls_first: false,
+                    }),
+                ),
+            ],
+            Some(3),
+        )
+        .unwrap();
+
+        assert_eq!(sorted.schema(), batch.schema());
+        assert_eq!(
+            sorted.column(0).as_ref(),
+            &Int32Array::from(vec![None, Some(1), Some(2)])
+        );
+        assert_eq!(
+            sorted.column(1).as_ref(),
+            &StringArray::from(vec!["x", "y", "z"])
+        );
+
+        assert!(sort_record_batch(&batch, &[(2, None)], None).is_err());
+    }
 }
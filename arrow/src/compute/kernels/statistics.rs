@@ -0,0 +1,293 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Defines [column_statistics], which computes the statistics of a column, such as the
+//! ones a file writer or a catalog records, in a single pass over its values.
+
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::array::*;
+use crate::datatypes::{DataType, IntervalUnit};
+use crate::record_batch::RecordBatch;
+
+/// The statistics of a column, see [column_statistics].
+#[derive(Debug, Clone)]
+pub struct ColumnStatistics {
+    /// The number of null values
+    pub null_count: usize,
+    /// The minimum valid value, or `None` if all the values are null or the values of
+    /// the type of the column can't be compared
+    pub min: Option<Scalar<ArrayRef>>,
+    /// The maximum valid value, like `min`
+    pub max: Option<Scalar<ArrayRef>>,
+    /// An estimate of the number of distinct valid values, or `None` if the values of
+    /// the type of the column can't be hashed, e.g. lists and structs
+    pub distinct_count: Option<usize>,
+    /// The number of bytes of the buffers of the column
+    pub byte_size: usize,
+}
+
+/// Computes the [ColumnStatistics] of `array` in a single pass over its values.
+///
+/// The minimum and maximum values are those of [build_compare], and are copied so that
+/// they don't keep the buffers of `array` alive. The number of distinct values is
+/// estimated with a HyperLogLog sketch, whose standard error is about 1.6%.
+///
+/// # Example
+/// ```
+/// use arrow::array::{Array, Int32Array};
+/// use arrow::compute::column_statistics;
+///
+/// let array = Int32Array::from(vec![Some(3), None, Some(1), Some(3)]);
+/// let statistics = column_statistics(&array);
+/// assert_eq!(statistics.null_count, 1);
+/// assert_eq!(statistics.distinct_count, Some(2));
+///
+/// let min = statistics.min.unwrap().into_inner();
+/// assert_eq!(min.as_ref(), &Int32Array::from(vec![1]) as &dyn Array);
+/// ```
+pub fn column_statistics(array: &dyn Array) -> ColumnStatistics {
+    let compare = build_compare(array, array).ok();
+    let hash = build_hash(array);
+
+    let mut min_max: Option<(usize, usize)> = None;
+    let mut distinct = DistinctCounter::new();
+    for i in (0..array.len()).filter(|i| array.is_valid(*i)) {
+        if let Some(compare) = &compare {
+            min_max = Some(match min_max {
+                None => (i, i),
+                Some((min, max)) => (
+                    if (compare)(i, min) == Ordering::Less {
+                        i
+                    } else {
+                        min
+                    },
+                    if (compare)(i, max) == Ordering::Greater {
+                        i
+                    } else {
+                        max
+                    },
+                ),
+            });
+        }
+        if let Some(hash) = &hash {
+            let mut hasher = DefaultHasher::new();
+            (hash)(i, &mut hasher);
+            distinct.add(hasher.finish());
+        }
+    }
+
+    ColumnStatistics {
+        null_count: array.null_count(),
+        min: min_max.map(|(min, _)| copy_value(array, min)),
+        max: min_max.map(|(_, max)| copy_value(array, max)),
+        distinct_count: hash.map(|_| distinct.estimate()),
+        byte_size: array.get_buffer_memory_size(),
+    }
+}
+
+/// Computes the [ColumnStatistics] of each column of `batch`, see [column_statistics].
+pub fn record_batch_statistics(batch: &RecordBatch) -> Vec<ColumnStatistics> {
+    batch
+        .columns()
+        .iter()
+        .map(|column| column_statistics(column.as_ref()))
+        .collect()
+}
+
+/// Returns a scalar of a copy of the value at `index` of `array`.
+fn copy_value(array: &dyn Array, index: usize) -> Scalar<ArrayRef> {
+    let mut data = MutableArrayData::new(vec![array.data_ref()], false, 1);
+    data.extend(0, index, index + 1);
+    Scalar::new(make_array(data.freeze()))
+}
+
+type HashFn<'a> = Box<dyn Fn(usize, &mut DefaultHasher) + 'a>;
+
+/// Returns a function that hashes the value at an index of `array`, or `None` if the
+/// values of its type aren't hashed.
+fn build_hash(array: &dyn Array) -> Option<HashFn> {
+    let width = match array.data_type() {
+        DataType::Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            return Some(Box::new(move |i: usize, hasher: &mut DefaultHasher| {
+                array.value(i).hash(hasher)
+            }));
+        }
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+            return Some(Box::new(move |i: usize, hasher: &mut DefaultHasher| {
+                array.value(i).hash(hasher)
+            }));
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
+            return Some(Box::new(move |i: usize, hasher: &mut DefaultHasher| {
+                array.value(i).hash(hasher)
+            }));
+        }
+        DataType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+            return Some(Box::new(move |i: usize, hasher: &mut DefaultHasher| {
+                array.value(i).hash(hasher)
+            }));
+        }
+        DataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<LargeBinaryArray>().unwrap();
+            return Some(Box::new(move |i: usize, hasher: &mut DefaultHasher| {
+                array.value(i).hash(hasher)
+            }));
+        }
+        DataType::Int8 | DataType::UInt8 => 1,
+        DataType::Int16 | DataType::UInt16 | DataType::Float16 => 2,
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => 4,
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => 8,
+        DataType::Decimal(_, _) => 16,
+        DataType::FixedSizeBinary(width) => *width as usize,
+        _ => return None,
+    };
+    // the values of fixed width types are hashed as their bytes
+    let data = array.data_ref();
+    let values = data.buffers()[0].as_slice();
+    let offset = data.offset();
+    Some(Box::new(move |i: usize, hasher: &mut DefaultHasher| {
+        let start = (offset + i) * width;
+        values[start..start + width].hash(hasher)
+    }))
+}
+
+/// The number of bits of a hash that select a register of a [DistinctCounter].
+const DISTINCT_COUNTER_BITS: u32 = 12;
+
+/// A HyperLogLog sketch of `2^DISTINCT_COUNTER_BITS` registers, which estimates the
+/// number of distinct hashes added to it.
+struct DistinctCounter {
+    registers: Vec<u8>,
+}
+
+impl DistinctCounter {
+    fn new() -> Self {
+        Self {
+            registers: vec![0; 1 << DISTINCT_COUNTER_BITS],
+        }
+    }
+
+    fn add(&mut self, hash: u64) {
+        let index = (hash >> (64 - DISTINCT_COUNTER_BITS)) as usize;
+        // the position of the first set bit of the rest of the hash, which is at most
+        // the number of its bits
+        let rest = (hash << DISTINCT_COUNTER_BITS) | (1 << (DISTINCT_COUNTER_BITS - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    fn estimate(&self) -> usize {
+        let m = self.registers.len() as f64;
+        let sum: f64 = self.registers.iter().map(|r| 2f64.powi(-(*r as i32))).sum();
+        let estimate = 0.7213 / (1.0 + 1.079 / m) * m * m / sum;
+        let zeros = self.registers.iter().filter(|r| **r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            // linear counting is more accurate for small numbers of distinct values
+            (m * (m / zeros as f64).ln()).round() as usize
+        } else {
+            estimate.round() as usize
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::datatypes::Int32Type;
+
+    #[test]
+    fn test_column_statistics() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(5), None, Some(-2), None]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["b", "c", "a", "c"]));
+        let c: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                Some(vec![Some(1)]),
+                None,
+                Some(vec![]),
+                Some(vec![Some(0)]),
+            ]));
+        let batch =
+            RecordBatch::try_from_iter(vec![("a", a), ("b", b), ("c", c)]).unwrap();
+
+        let statistics = record_batch_statistics(&batch);
+        assert_eq!(statistics.len(), 3);
+
+        let a = &statistics[0];
+        assert_eq!(a.null_count, 2);
+        assert_eq!(a.distinct_count, Some(2));
+        assert_eq!(a.byte_size, batch.column(0).get_buffer_memory_size());
+        let min = a.min.clone().unwrap().into_inner();
+        assert_eq!(min.as_ref(), &Int32Array::from(vec![-2]) as &dyn Array);
+        let max = a.max.clone().unwrap().into_inner();
+        assert_eq!(max.as_ref(), &Int32Array::from(vec![5]) as &dyn Array);
+
+        let b = &statistics[1];
+        assert_eq!(b.null_count, 0);
+        assert_eq!(b.distinct_count, Some(3));
+        let min = b.min.clone().unwrap().into_inner();
+        assert_eq!(min.as_ref(), &StringArray::from(vec!["a"]) as &dyn Array);
+        let max = b.max.clone().unwrap().into_inner();
+        assert_eq!(max.as_ref(), &StringArray::from(vec!["c"]) as &dyn Array);
+
+        // lists are compared, but not hashed
+        let c = &statistics[2];
+        assert_eq!(c.null_count, 1);
+        assert_eq!(c.distinct_count, None);
+        let min = c.min.clone().unwrap().into_inner();
+        assert_eq!(min.len(), 1);
+        assert_eq!(min.as_ref(), batch.column(2).slice(2, 1).as_ref());
+    }
+
+    #[test]
+    fn test_column_statistics_all_null() {
+        let array = Int32Array::from(vec![None, None]);
+        let statistics = column_statistics(array.slice(1, 1).as_ref());
+        assert_eq!(statistics.null_count, 1);
+        assert!(statistics.min.is_none());
+        assert!(statistics.max.is_none());
+        assert_eq!(statistics.distinct_count, Some(0));
+    }
+
+    #[test]
+    fn test_distinct_count_estimate() {
+        let array =
+            Int32Array::from((0..100_000).map(|i| i % 20_000).collect::<Vec<_>>());
+        let statistics = column_statistics(array.slice(10, 50_000).as_ref());
+        let distinct_count = statistics.distinct_count.unwrap() as f64;
+        assert!((distinct_count - 20_000.0).abs() < 20_000.0 * 0.05);
+    }
+}
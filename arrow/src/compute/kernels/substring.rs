@@ -17,6 +17,7 @@
 
 //! Defines kernel to extract a substring of a \[Large\]StringArray
 
+use crate::compute::kernels::arity::unary_dyn;
 use crate::{array::*, buffer::Buffer};
 use crate::{
     datatypes::DataType,
@@ -91,9 +92,13 @@ fn generic_substring<OffsetSize: StringOffsetSizeTrait>(
 
 /// Returns an ArrayRef with a substring starting from `start` and with optional length `length` of each of the elements in `array`.
 /// `start` can be negative, in which case the start counts from the end of the string.
-/// this function errors when the passed array is not a \[Large\]String array.
+/// this function errors when the passed array is not a \[Large\]String array, or a dictionary of them,
+/// whose values are then replaced with their substrings.
 pub fn substring(array: &Array, start: i64, length: &Option<u64>) -> Result<ArrayRef> {
     match array.data_type() {
+        DataType::Dictionary(_, _) => {
+            unary_dyn(array, |values| substring(values, start, length))
+        }
         DataType::LargeUtf8 => generic_substring(
             array
                 .as_any()
@@ -120,6 +125,7 @@ pub fn substring(array: &Array, start: i64, length: &Option<u64>) -> Result<Arra
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::datatypes::Int16Type;
 
     fn with_nulls<T: 'static + Array + PartialEq + From<Vec<Option<&'static str>>>>(
     ) -> Result<()> {
@@ -266,4 +272,24 @@ mod tests {
     fn without_nulls_large_string() -> Result<()> {
         without_nulls::<LargeStringArray>()
     }
+
+    #[test]
+    fn substring_dictionary() -> Result<()> {
+        let array: DictionaryArray<Int16Type> =
+            vec![Some("hello"), None, Some("world"), Some("hello")]
+                .into_iter()
+                .collect();
+
+        let result = substring(&array, 1, &Some(3))?;
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int16Type>>()
+            .unwrap();
+        assert_eq!(result.keys(), array.keys());
+        assert_eq!(
+            result.values().as_ref(),
+            &StringArray::from(vec!["ell", "orl"])
+        );
+        Ok(())
+    }
 }
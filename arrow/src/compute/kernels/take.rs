@@ -21,10 +21,12 @@ use std::{ops::AddAssign, sync::Arc};
 
 use crate::buffer::{Buffer, MutableBuffer};
 use crate::compute::util::{
-    take_value_indices_from_fixed_size_list, take_value_indices_from_list,
+    map_record_batch, take_value_indices_from_fixed_size_list,
+    take_value_indices_from_list,
 };
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
 use crate::util::bit_util;
 use crate::{array::*, buffer::buffer_bin_and};
 
@@ -42,12 +44,12 @@ macro_rules! downcast_take {
 }
 
 macro_rules! downcast_dict_take {
-    ($type: ty, $values: expr, $indices: expr) => {{
+    ($type: ty, $values: expr, $indices: expr, $options: expr) => {{
         let values = $values
             .as_any()
             .downcast_ref::<DictionaryArray<$type>>()
             .expect("Unable to downcast to a dictionary array");
-        Ok(Arc::new(take_dict::<$type, _>(values, $indices)?))
+        Ok(Arc::new(take_dict::<$type, _>(values, $indices, $options)?))
     }};
 }
 
@@ -197,6 +199,10 @@ where
                 .unwrap();
             Ok(Arc::new(take_string::<i64, _>(values, indices)?))
         }
+        DataType::Decimal(_, _) => {
+            let values = values.as_any().downcast_ref::<DecimalArray>().unwrap();
+            Ok(Arc::new(take_decimal(values, indices)?))
+        }
         DataType::List(_) => {
             let values = values
                 .as_any()
@@ -236,16 +242,26 @@ where
             Ok(Arc::new(StructArray::from(pairs)) as ArrayRef)
         }
         DataType::Dictionary(key_type, _) => match key_type.as_ref() {
-            DataType::Int8 => downcast_dict_take!(Int8Type, values, indices),
-            DataType::Int16 => downcast_dict_take!(Int16Type, values, indices),
-            DataType::Int32 => downcast_dict_take!(Int32Type, values, indices),
-            DataType::Int64 => downcast_dict_take!(Int64Type, values, indices),
-            DataType::UInt8 => downcast_dict_take!(UInt8Type, values, indices),
-            DataType::UInt16 => downcast_dict_take!(UInt16Type, values, indices),
-            DataType::UInt32 => downcast_dict_take!(UInt32Type, values, indices),
-            DataType::UInt64 => downcast_dict_take!(UInt64Type, values, indices),
+            DataType::Int8 => downcast_dict_take!(Int8Type, values, indices, &options),
+            DataType::Int16 => downcast_dict_take!(Int16Type, values, indices, &options),
+            DataType::Int32 => downcast_dict_take!(Int32Type, values, indices, &options),
+            DataType::Int64 => downcast_dict_take!(Int64Type, values, indices, &options),
+            DataType::UInt8 => downcast_dict_take!(UInt8Type, values, indices, &options),
+            DataType::UInt16 => {
+                downcast_dict_take!(UInt16Type, values, indices, &options)
+            }
+            DataType::UInt32 => {
+                downcast_dict_take!(UInt32Type, values, indices, &options)
+            }
+            DataType::UInt64 => {
+                downcast_dict_take!(UInt64Type, values, indices, &options)
+            }
             t => unimplemented!("Take not supported for dictionary key type {:?}", t),
         },
+        DataType::Null => {
+            // all the values are null, whether the indices are or not
+            Ok(new_null_array(&DataType::Null, indices.len()))
+        }
         t => unimplemented!("Take not supported for data type {:?}", t),
     }
 }
@@ -257,16 +273,59 @@ pub struct TakeOptions {
     /// If enabled, an `ArrowError` is returned if the indices are out of bounds.
     /// If not enabled, and indices exceed bounds, the kernel will panic.
     pub check_bounds: bool,
+    /// Compact the values of a taken dictionary array to the values its keys reference,
+    /// if they are less than this fraction of the values, e.g. `Some(0.5)` to compact
+    /// the values when fewer than half of them are referenced.
+    /// If not set, the taken dictionary array shares the values of the original one.
+    pub dictionary_compaction_threshold: Option<f64>,
 }
 
 impl Default for TakeOptions {
     fn default() -> Self {
         Self {
             check_bounds: false,
+            dictionary_compaction_threshold: None,
         }
     }
 }
 
+/// Takes the rows at `indices` of all the columns of `record_batch`, creating a new
+/// [RecordBatch] with the same schema. See [take] for the behavior of `options`.
+///
+/// Returns an error naming the column when one of them can't be taken.
+///
+/// # Examples
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, Int32Array, StringArray, UInt32Array};
+/// use arrow::compute::take_record_batch;
+/// use arrow::record_batch::RecordBatch;
+/// # fn main() -> arrow::error::Result<()> {
+/// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+/// let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+/// let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)])?;
+///
+/// let taken = take_record_batch(&batch, &UInt32Array::from(vec![2, 0]), None)?;
+///
+/// assert_eq!(taken.column(0).as_ref(), &Int32Array::from(vec![3, 1]));
+/// assert_eq!(taken.column(1).as_ref(), &StringArray::from(vec!["c", "a"]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn take_record_batch<IndexType>(
+    record_batch: &RecordBatch,
+    indices: &PrimitiveArray<IndexType>,
+    options: Option<TakeOptions>,
+) -> Result<RecordBatch>
+where
+    IndexType: ArrowNumericType,
+    IndexType::Native: ToPrimitive,
+{
+    map_record_batch(record_batch, |array| {
+        take_impl(array.as_ref(), indices, options.clone())
+    })
+}
+
 #[inline(always)]
 fn maybe_usize<I: ArrowPrimitiveType>(index: I::Native) -> Result<usize> {
     index
@@ -650,6 +709,36 @@ where
     Ok(GenericStringArray::<OffsetSize>::from(data.build()))
 }
 
+/// `take` implementation for decimal arrays
+fn take_decimal<IndexType>(
+    values: &DecimalArray,
+    indices: &PrimitiveArray<IndexType>,
+) -> Result<DecimalArray>
+where
+    IndexType: ArrowNumericType,
+    IndexType::Native: ToPrimitive,
+{
+    let mut builder =
+        DecimalBuilder::new(indices.len(), values.precision(), values.scale());
+    for index in indices.iter() {
+        let index = match index {
+            Some(index) => ToPrimitive::to_usize(&index).ok_or_else(|| {
+                ArrowError::ComputeError("Cast to usize failed".to_string())
+            })?,
+            None => {
+                builder.append_null()?;
+                continue;
+            }
+        };
+        if values.is_valid(index) {
+            builder.append_value(values.value(index))?;
+        } else {
+            builder.append_null()?;
+        }
+    }
+    Ok(builder.finish())
+}
+
 /// `take` implementation for list arrays
 ///
 /// Calculates the index and indexed offset for the inner array,
@@ -744,10 +833,12 @@ where
 /// `take` implementation for dictionary arrays
 ///
 /// applies `take` to the keys of the dictionary array and returns a new dictionary array
-/// with the same dictionary values and reordered keys
+/// with the same dictionary values and reordered keys, unless the values are compacted
+/// as set by [TakeOptions::dictionary_compaction_threshold]
 fn take_dict<T, I>(
     values: &DictionaryArray<T>,
     indices: &PrimitiveArray<I>,
+    options: &TakeOptions,
 ) -> Result<DictionaryArray<T>>
 where
     T: ArrowPrimitiveType,
@@ -756,6 +847,11 @@ where
     I::Native: ToPrimitive,
 {
     let new_keys = take_primitive::<T, I>(&values.keys_array(), indices)?;
+    if let Some(threshold) = options.dictionary_compaction_threshold {
+        if let Some(compacted) = compact_dict(values, &new_keys, threshold)? {
+            return Ok(compacted);
+        }
+    }
     let new_keys_data = new_keys.data_ref();
 
     let data = ArrayData::new(
@@ -771,6 +867,67 @@ where
     Ok(DictionaryArray::<T>::from(data))
 }
 
+/// Creates a dictionary array of `keys` into the values of `dict` that are referenced
+/// by them, if they are less than `threshold` of the values. Returns `None` otherwise.
+fn compact_dict<T>(
+    dict: &DictionaryArray<T>,
+    keys: &PrimitiveArray<T>,
+    threshold: f64,
+) -> Result<Option<DictionaryArray<T>>>
+where
+    T: ArrowPrimitiveType,
+{
+    let key_to_usize = |key: T::Native| {
+        ArrowNativeType::to_usize(&key)
+            .ok_or_else(|| ArrowError::ComputeError("Cast to usize failed".to_string()))
+    };
+    let values_len = dict.values().len();
+    let mut referenced = vec![false; values_len];
+    for key in keys.iter().flatten() {
+        referenced[key_to_usize(key)?] = true;
+    }
+    let referenced_len = referenced.iter().filter(|r| **r).count();
+    if referenced_len as f64 >= threshold * values_len as f64 {
+        return Ok(None);
+    }
+
+    // the new position of each referenced value, and the values in their new order
+    let mut new_positions = vec![0; values_len];
+    let mut value_indices = Vec::with_capacity(referenced_len);
+    for (i, _) in referenced.iter().enumerate().filter(|(_, r)| **r) {
+        new_positions[i] = value_indices.len();
+        value_indices.push(i as u32);
+    }
+    let new_values = take_impl(
+        dict.values().as_ref(),
+        &UInt32Array::from(value_indices),
+        None,
+    )?;
+    let new_keys = keys
+        .iter()
+        .map(|key| {
+            key.map(|key| {
+                let position = new_positions[key_to_usize(key)?];
+                // the new keys are smaller than the old ones, so they always fit
+                Ok(T::Native::from_usize(position).unwrap())
+            })
+            .transpose()
+        })
+        .collect::<Result<PrimitiveArray<T>>>()?;
+    let new_keys_data = new_keys.data_ref();
+
+    let data = ArrayData::new(
+        dict.data_type().clone(),
+        new_keys.len(),
+        Some(new_keys_data.null_count()),
+        new_keys_data.null_buffer().cloned(),
+        0,
+        new_keys_data.buffers().to_vec(),
+        vec![new_values.data().clone()],
+    );
+    Ok(Some(DictionaryArray::<T>::from(data)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -876,6 +1033,16 @@ mod tests {
         .unwrap();
     }
 
+    #[test]
+    fn test_take_null_array() {
+        let values = NullArray::new(5);
+        let index = UInt32Array::from(vec![Some(4), None, Some(0)]);
+        let taken = take(&values, &index, None).unwrap();
+        assert_eq!(taken.data_type(), &DataType::Null);
+        assert_eq!(taken.len(), 3);
+        assert_eq!(taken.null_count(), 3);
+    }
+
     #[test]
     fn test_take_primitive() {
         let index = UInt32Array::from(vec![Some(3), None, Some(1), Some(3), Some(2)]);
@@ -1111,6 +1278,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_take_decimal() {
+        let mut builder = DecimalBuilder::new(5, 10, 2);
+        builder.append_value(100).unwrap();
+        builder.append_null().unwrap();
+        builder.append_value(-250).unwrap();
+        builder.append_value(12345).unwrap();
+        let array = builder.finish();
+        let index = UInt32Array::from(vec![Some(3), None, Some(1), Some(0), Some(3)]);
+
+        let actual = take(&array, &index, None).unwrap();
+        let actual = actual.as_any().downcast_ref::<DecimalArray>().unwrap();
+        assert_eq!(&DataType::Decimal(10, 2), actual.data_type());
+        assert_eq!(5, actual.len());
+        assert_eq!(12345, actual.value(0));
+        assert!(actual.is_null(1));
+        assert!(actual.is_null(2));
+        assert_eq!(100, actual.value(3));
+        assert_eq!(12345, actual.value(4));
+    }
+
     fn _test_take_string<'a, K: 'static>()
     where
         K: Array + PartialEq + From<Vec<Option<&'a str>>>,
@@ -1551,7 +1739,10 @@ mod tests {
     #[test]
     fn test_take_out_of_bounds() {
         let index = UInt32Array::from(vec![Some(3), None, Some(1), Some(3), Some(6)]);
-        let take_opt = TakeOptions { check_bounds: true };
+        let take_opt = TakeOptions {
+            check_bounds: true,
+            ..Default::default()
+        };
 
         // int64
         let result = test_take_primitive_arrays::<Int64Type>(
@@ -1580,7 +1771,7 @@ mod tests {
     #[test]
     fn test_take_dict() {
         let keys_builder = Int16Builder::new(8);
-        let values_builder = StringBuilder::new(4);
+        let values_builder = StringBuilder::default();
 
         let mut dict_builder = StringDictionaryBuilder::new(keys_builder, values_builder);
 
@@ -1631,4 +1822,84 @@ mod tests {
         ]);
         assert_eq!(result.keys(), &expected_keys);
     }
+
+    #[test]
+    fn test_take_dict_compaction() {
+        let mut dict_builder =
+            StringDictionaryBuilder::new(Int8Builder::new(6), StringBuilder::default());
+        for value in &[Some("a"), Some("b"), Some("c"), None, Some("d"), Some("b")] {
+            match value {
+                Some(value) => dict_builder.append(value).map(drop).unwrap(),
+                None => dict_builder.append_null().unwrap(),
+            }
+        }
+        let array = dict_builder.finish();
+        let indices = UInt32Array::from(vec![Some(5), None, Some(3), Some(4), Some(1)]);
+
+        // half of the values are referenced, which isn't less than the threshold
+        let options = TakeOptions {
+            dictionary_compaction_threshold: Some(0.5),
+            ..Default::default()
+        };
+        let result = take(&array, &indices, Some(options)).unwrap();
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        assert_eq!(result.values().len(), 4);
+        assert_eq!(
+            result.keys(),
+            &Int8Array::from(vec![Some(1), None, None, Some(3), Some(1)])
+        );
+
+        let options = TakeOptions {
+            dictionary_compaction_threshold: Some(0.75),
+            ..Default::default()
+        };
+        let result = take(&array, &indices, Some(options)).unwrap();
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        assert_eq!(result.data_type(), array.data_type());
+        assert_eq!(
+            result.values().as_ref(),
+            &StringArray::from(vec!["b", "d"]) as &dyn Array
+        );
+        assert_eq!(
+            result.keys(),
+            &Int8Array::from(vec![Some(0), None, None, Some(1), Some(0)])
+        );
+    }
+
+    #[test]
+    fn test_take_record_batch() {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).unwrap();
+
+        let taken =
+            take_record_batch(&batch, &UInt32Array::from(vec![1, 2, 1]), None).unwrap();
+
+        assert_eq!(taken.schema(), batch.schema());
+        assert_eq!(
+            taken.column(0).as_ref(),
+            &Int32Array::from(vec![None, Some(3), None])
+        );
+        assert_eq!(
+            taken.column(1).as_ref(),
+            &StringArray::from(vec!["b", "c", "b"])
+        );
+
+        let err = take_record_batch(
+            &batch,
+            &UInt32Array::from(vec![3]),
+            Some(TakeOptions {
+                check_bounds: true,
+                ..Default::default()
+            }),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("\"a\""), "{}", err);
+    }
 }
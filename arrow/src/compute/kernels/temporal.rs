@@ -17,11 +17,56 @@
 
 //! Defines temporal kernels for time and date related functions.
 
-use chrono::{Datelike, Timelike};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{Datelike, Duration, FixedOffset, TimeZone, Timelike};
+
+use std::fmt::Write;
+use std::sync::Arc;
 
 use crate::array::*;
+use crate::compute::kernels::arity::unary_dyn;
+use crate::compute::kernels::cast::{
+    cast, cast_with_options, CastOptions, DEFAULT_CAST_OPTIONS,
+};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+
+macro_rules! temporal_dyn {
+    ($array:expr, $kernel:ident, $dyn_kernel:ident) => {{
+        let array: &dyn Array = $array;
+        macro_rules! apply {
+            ($t:ty) => {
+                $kernel(array.as_any().downcast_ref::<PrimitiveArray<$t>>().unwrap())
+                    .map(|a| Arc::new(a) as ArrayRef)
+            };
+        }
+        match array.data_type() {
+            DataType::Dictionary(_, _) => unary_dyn(array, $dyn_kernel),
+            DataType::Date32 => apply!(Date32Type),
+            DataType::Date64 => apply!(Date64Type),
+            DataType::Time32(TimeUnit::Second) => apply!(Time32SecondType),
+            DataType::Time32(TimeUnit::Millisecond) => apply!(Time32MillisecondType),
+            DataType::Time64(TimeUnit::Microsecond) => apply!(Time64MicrosecondType),
+            DataType::Time64(TimeUnit::Nanosecond) => apply!(Time64NanosecondType),
+            DataType::Timestamp(TimeUnit::Second, _) => apply!(TimestampSecondType),
+            DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                apply!(TimestampMillisecondType)
+            }
+            DataType::Timestamp(TimeUnit::Microsecond, _) => {
+                apply!(TimestampMicrosecondType)
+            }
+            DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+                apply!(TimestampNanosecondType)
+            }
+            dt => Err(ArrowError::ComputeError(format!(
+                "{} does not support type {:?}",
+                stringify!($kernel),
+                dt
+            ))),
+        }
+    }};
+}
+
 /// Extracts the hours of a given temporal array as an array of integers
 pub fn hour<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
 where
@@ -100,6 +145,267 @@ where
     Ok(b.finish())
 }
 
+/// Extracts the hours of a temporal array of any type, or of the values of a dictionary of
+/// them, returning a dictionary of the hours of its values.
+pub fn hour_dyn(array: &dyn Array) -> Result<ArrayRef> {
+    temporal_dyn!(array, hour, hour_dyn)
+}
+
+/// Extracts the years of a temporal array of any type, or of the values of a dictionary of
+/// them, returning a dictionary of the years of its values.
+pub fn year_dyn(array: &dyn Array) -> Result<ArrayRef> {
+    temporal_dyn!(array, year, year_dyn)
+}
+
+/// Combines the dates of `date`, a `Date32` or `Date64` array, with the times of day of
+/// `time`, a `Time32` or `Time64` array, into a `Timestamp(unit, None)` array, which is
+/// null where either of them is null.
+///
+/// Returns an error if the arrays are not of these types or of the same length, or if a
+/// timestamp overflows.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, Date32Array, Time32SecondArray, TimestampSecondArray};
+/// use arrow::compute::combine_date_time;
+/// use arrow::datatypes::TimeUnit;
+///
+/// # fn main() -> arrow::error::Result<()> {
+/// let date = Arc::new(Date32Array::from(vec![Some(1), None])) as ArrayRef;
+/// let time = Arc::new(Time32SecondArray::from(vec![3_600, 0])) as ArrayRef;
+/// let timestamps = combine_date_time(&date, &time, TimeUnit::Second)?;
+/// assert_eq!(
+///     timestamps.as_any().downcast_ref::<TimestampSecondArray>().unwrap(),
+///     &TimestampSecondArray::from_opt_vec(vec![Some(90_000), None], None)
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn combine_date_time(
+    date: &ArrayRef,
+    time: &ArrayRef,
+    unit: TimeUnit,
+) -> Result<ArrayRef> {
+    if !matches!(date.data_type(), DataType::Date32 | DataType::Date64)
+        || !matches!(time.data_type(), DataType::Time32(_) | DataType::Time64(_))
+    {
+        return Err(ArrowError::ComputeError(format!(
+            "combine_date_time requires a date and a time array, found {:?} and {:?}",
+            date.data_type(),
+            time.data_type()
+        )));
+    }
+    if date.len() != time.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot combine date and time arrays of different lengths".to_string(),
+        ));
+    }
+    let to_type = DataType::Timestamp(unit, None);
+    let cast_options = CastOptions {
+        safe: false,
+        ..DEFAULT_CAST_OPTIONS
+    };
+    let date = cast_with_options(date, &to_type, &cast_options)?;
+    let time = cast_with_options(time, &to_type, &cast_options)?;
+    let date = Int64Array::from(date.data().clone());
+    let time = Int64Array::from(time.data().clone());
+    let values = date
+        .iter()
+        .zip(time.iter())
+        .map(|(date, time)| match (date, time) {
+            (Some(date), Some(time)) => {
+                date.checked_add(time).map(Some).ok_or_else(|| {
+                    ArrowError::ComputeError(format!(
+                        "Combining a date and a time overflows {:?}",
+                        to_type
+                    ))
+                })
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    cast(&(Arc::new(Int64Array::from(values)) as ArrayRef), &to_type)
+}
+
+/// Formats the values of a date, time or timestamp array as strings with `format`, which
+/// is made of [chrono's format specifiers](chrono::format::strftime), e.g.
+/// `"%Y-%m-%d %H:%M:%S"`.
+///
+/// Timestamps are formatted in the time zone `tz` if it is set, or else in the time zone
+/// of the array's type, if any. Only fixed offsets, such as `"+02:00"` or `"-0130"`, and
+/// `"UTC"` are supported.
+///
+/// The format is parsed once for the whole array. Returns an error if it is invalid, or if
+/// it has specifiers that don't apply to the values, such as `%H` for dates or `%z` for
+/// timestamps without a time zone.
+///
+/// Values that are out of the range of chrono's dates and times, such as times of more
+/// than a day, are formatted as null.
+///
+/// # Example
+/// ```
+/// use arrow::array::{StringArray, TimestampSecondArray};
+/// use arrow::compute::format_datetime;
+///
+/// # fn main() -> arrow::error::Result<()> {
+/// let array = TimestampSecondArray::from_opt_vec(vec![Some(1_000_000_000), None], None);
+/// let formatted = format_datetime(&array, "%Y-%m-%d %H:%M:%S %:z", Some("+02:00"))?;
+/// assert_eq!(
+///     formatted,
+///     StringArray::from(vec![Some("2001-09-09 03:46:40 +02:00"), None])
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn format_datetime(
+    array: &dyn Array,
+    format: &str,
+    tz: Option<&str>,
+) -> Result<StringArray> {
+    let items = StrftimeItems::new(format).collect::<Vec<_>>();
+    if items.iter().any(|item| matches!(item, Item::Error)) {
+        return Err(ArrowError::ComputeError(format!(
+            "Invalid date and time format {:?}",
+            format
+        )));
+    }
+    let offset = match (array.data_type(), tz) {
+        (DataType::Timestamp(_, _), Some(tz)) => Some(parse_fixed_offset(tz)?),
+        (DataType::Timestamp(_, Some(tz)), None) => Some(parse_fixed_offset(tz)?),
+        _ => None,
+    };
+    let formatter = DateTimeFormatter {
+        format,
+        items,
+        offset,
+    };
+
+    macro_rules! apply {
+        ($t:ty) => {
+            formatter.format(array.as_any().downcast_ref::<PrimitiveArray<$t>>().unwrap())
+        };
+    }
+    match array.data_type() {
+        DataType::Date32 => apply!(Date32Type),
+        DataType::Date64 => apply!(Date64Type),
+        DataType::Time32(TimeUnit::Second) => apply!(Time32SecondType),
+        DataType::Time32(TimeUnit::Millisecond) => apply!(Time32MillisecondType),
+        DataType::Time64(TimeUnit::Microsecond) => apply!(Time64MicrosecondType),
+        DataType::Time64(TimeUnit::Nanosecond) => apply!(Time64NanosecondType),
+        DataType::Timestamp(TimeUnit::Second, _) => apply!(TimestampSecondType),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            apply!(TimestampMillisecondType)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            apply!(TimestampMicrosecondType)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            apply!(TimestampNanosecondType)
+        }
+        dt => Err(ArrowError::ComputeError(format!(
+            "format_datetime does not support type {:?}",
+            dt
+        ))),
+    }
+}
+
+/// A parsed format of [format_datetime], and the time zone of the timestamps it formats.
+struct DateTimeFormatter<'a> {
+    format: &'a str,
+    items: Vec<Item<'a>>,
+    offset: Option<FixedOffset>,
+}
+
+impl DateTimeFormatter<'_> {
+    fn format<T>(&self, array: &PrimitiveArray<T>) -> Result<StringArray>
+    where
+        T: ArrowTemporalType + ArrowNumericType,
+        i64: std::convert::From<T::Native>,
+    {
+        let is_time = matches!(T::DATA_TYPE, DataType::Time32(_) | DataType::Time64(_));
+        let items = self.items.iter();
+        let mut builder =
+            StringBuilder::with_capacity(array.len(), array.len() * self.format.len());
+        // the formatted value, which is reused for all the values
+        let mut value = String::new();
+        for i in 0..array.len() {
+            if array.is_null(i) {
+                builder.append_null()?;
+                continue;
+            }
+            value.clear();
+            let written = if is_time {
+                array.value_as_time(i).map(|time| {
+                    write!(value, "{}", time.format_with_items(items.clone()))
+                })
+            } else {
+                array
+                    .value_as_datetime(i)
+                    .and_then(|datetime| match self.offset {
+                        Some(offset) => {
+                            // the local datetime may overflow the range of chrono
+                            let local =
+                                Duration::seconds(offset.local_minus_utc() as i64);
+                            datetime.checked_add_signed(local)?;
+                            Some(write!(
+                                value,
+                                "{}",
+                                offset
+                                    .from_utc_datetime(&datetime)
+                                    .format_with_items(items.clone())
+                            ))
+                        }
+                        None => Some(write!(
+                            value,
+                            "{}",
+                            datetime.format_with_items(items.clone())
+                        )),
+                    })
+            };
+            match written {
+                Some(Ok(())) => builder.append_value(&value)?,
+                Some(Err(_)) => {
+                    return Err(ArrowError::ComputeError(format!(
+                        "Cannot format values of type {:?} with {:?}",
+                        array.data_type(),
+                        self.format
+                    )))
+                }
+                None => builder.append_null()?,
+            }
+        }
+        Ok(builder.finish())
+    }
+}
+
+/// Parses a time zone of fixed offset, such as `"+02:00"`, `"-0130"` or `"UTC"`.
+pub fn parse_fixed_offset(tz: &str) -> Result<FixedOffset> {
+    let invalid = || {
+        ArrowError::ComputeError(format!(
+            "Unsupported time zone {:?}, only fixed offsets such as \"+02:00\" are supported",
+            tz
+        ))
+    };
+    if tz == "UTC" || tz == "Z" {
+        return Ok(FixedOffset::east(0));
+    }
+    let (sign, offset) = match tz.as_bytes().first() {
+        Some(b'+') => (1, &tz[1..]),
+        Some(b'-') => (-1, &tz[1..]),
+        _ => return Err(invalid()),
+    };
+    let digits = offset.replace(':', "");
+    if !(digits.len() == 2 || digits.len() == 4)
+        || !digits.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    let hours: i32 = digits[..2].parse().map_err(|_| invalid())?;
+    let minutes: i32 = digits[2..].parse().unwrap_or(0);
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(invalid)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,4 +490,138 @@ mod tests {
         assert_eq!(false, b.is_valid(1));
         assert_eq!(2024, b.value(2));
     }
+
+    #[test]
+    fn test_temporal_dyn() {
+        let a: PrimitiveArray<Date64Type> =
+            vec![Some(1514764800000), None, Some(1550636625000)].into();
+
+        let b = hour_dyn(&a).unwrap();
+        assert_eq!(b.as_ref(), &Int32Array::from(vec![Some(0), None, Some(4)]));
+        let b = year_dyn(&a).unwrap();
+        assert_eq!(
+            b.as_ref(),
+            &Int32Array::from(vec![Some(2018), None, Some(2019)])
+        );
+
+        let a = Time32SecondArray::from(vec![37800]);
+        assert!(year_dyn(&a).is_err());
+        assert!(hour_dyn(&Int32Array::from(vec![1])).is_err());
+    }
+
+    #[test]
+    fn test_temporal_dyn_dictionary() {
+        let a: DictionaryArray<Int8Type> =
+            vec![Some("a"), None, Some("a")].into_iter().collect();
+        let values: TimestampMicrosecondArray = vec![37800000000].into();
+        let a = a.with_values(Arc::new(values));
+
+        let b = hour_dyn(&a).unwrap();
+        let b = b
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        assert_eq!(b.keys(), a.keys());
+        assert_eq!(b.values().as_ref(), &Int32Array::from(vec![10]));
+    }
+
+    #[test]
+    fn test_format_datetime() {
+        let array = TimestampMillisecondArray::from_opt_vec(
+            vec![Some(1_500), None, Some(-86_400_000)],
+            Some("-01:30".to_string()),
+        );
+        let format = "%Y-%m-%d %H:%M:%S%.3f %z";
+        let formatted = format_datetime(&array, format, None).unwrap();
+        assert_eq!(
+            formatted,
+            StringArray::from(vec![
+                Some("1969-12-31 22:30:01.500 -0130"),
+                None,
+                Some("1969-12-30 22:30:00.000 -0130")
+            ])
+        );
+        let formatted = format_datetime(&array, format, Some("UTC")).unwrap();
+        assert_eq!(formatted.value(0), "1970-01-01 00:00:01.500 +0000");
+
+        let array = Date32Array::from(vec![18_000]);
+        let formatted = format_datetime(&array, "%d/%m/%Y", Some("+05:00")).unwrap();
+        assert_eq!(formatted, StringArray::from(vec!["14/04/2019"]));
+
+        let array = Time64MicrosecondArray::from(vec![3_723_000_001]);
+        let formatted = format_datetime(&array, "%H:%M:%S%.6f", None).unwrap();
+        assert_eq!(formatted, StringArray::from(vec!["01:02:03.000001"]));
+    }
+
+    #[test]
+    fn test_format_datetime_errors() {
+        let array = TimestampSecondArray::from_vec(vec![0], None);
+        // a timestamp without a time zone has no offset to format
+        assert!(format_datetime(&array, "%z", None).is_err());
+        assert!(format_datetime(&array, "%Q", None).is_err());
+        assert!(format_datetime(&array, "%H", Some("Europe/Paris")).is_err());
+        assert!(format_datetime(&array, "%H", Some("+2")).is_err());
+
+        // times have no date
+        let array = Time32SecondArray::from(vec![0]);
+        assert!(format_datetime(&array, "%Y", None).is_err());
+
+        let array = Int32Array::from(vec![0]);
+        assert!(format_datetime(&array, "%Y", None).is_err());
+    }
+
+    #[test]
+    fn test_format_datetime_out_of_range() {
+        let array = TimestampSecondArray::from(vec![i64::MAX, 0]);
+        let formatted = format_datetime(&array, "%Y-%m-%d", None).unwrap();
+        assert_eq!(formatted, StringArray::from(vec![None, Some("1970-01-01")]));
+
+        let array = Time32SecondArray::from(vec![100_000, -1, 3_600]);
+        let formatted = format_datetime(&array, "%H:%M", None).unwrap();
+        assert_eq!(
+            formatted,
+            StringArray::from(vec![None, None, Some("01:00")])
+        );
+
+        // the last representable day, whose local time overflows at a positive offset
+        let max = chrono::naive::MAX_DATE.and_hms(23, 0, 0).timestamp();
+        let array = TimestampSecondArray::from(vec![max]);
+        let formatted = format_datetime(&array, "%Y", Some("+02:00")).unwrap();
+        assert!(formatted.is_null(0));
+        let formatted = format_datetime(&array, "%Y", Some("-02:00")).unwrap();
+        assert_eq!(
+            formatted.value(0),
+            chrono::naive::MAX_DATE.format("%Y").to_string()
+        );
+    }
+
+    #[test]
+    fn test_combine_date_time() {
+        let date = Arc::new(Date64Array::from(vec![Some(86_400_000), Some(0), None]))
+            as ArrayRef;
+        let time = Arc::new(Time64NanosecondArray::from(vec![
+            Some(1_000_001),
+            None,
+            Some(0),
+        ])) as ArrayRef;
+        let timestamps = combine_date_time(&date, &time, TimeUnit::Microsecond).unwrap();
+        let timestamps = timestamps
+            .as_any()
+            .downcast_ref::<TimestampMicrosecondArray>()
+            .unwrap();
+        assert_eq!(
+            timestamps,
+            &TimestampMicrosecondArray::from_opt_vec(
+                vec![Some(86_400_001_000), None, None],
+                None
+            )
+        );
+
+        assert!(combine_date_time(&time, &date, TimeUnit::Second).is_err());
+        assert!(combine_date_time(&date, &time.slice(0, 1), TimeUnit::Second).is_err());
+
+        let date = Arc::new(Date32Array::from(vec![106_751])) as ArrayRef;
+        let time = Arc::new(Time32SecondArray::from(vec![86_399])) as ArrayRef;
+        assert!(combine_date_time(&date, &time, TimeUnit::Nanosecond).is_err());
+    }
 }
@@ -0,0 +1,255 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the unnest kernel, which explodes a list column of a [RecordBatch] into rows.
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::compute::take;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+/// Returns a [RecordBatch] with one row per value of the lists of the column at index
+/// `column` of `batch`. The column is replaced by the values of the lists, and the other
+/// columns repeat the row of each list once per value.
+///
+/// Null and empty lists produce no rows. The column must be a `List`, `LargeList` or
+/// `FixedSizeList`.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, Int32Array, ListArray, StringArray};
+/// use arrow::compute::unnest;
+/// use arrow::datatypes::Int32Type;
+/// use arrow::record_batch::RecordBatch;
+///
+/// # fn main() -> arrow::error::Result<()> {
+/// let id: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+/// let values: ArrayRef = Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+///     Some(vec![Some(1), Some(2)]),
+///     None,
+///     Some(vec![Some(3)]),
+/// ]));
+/// let batch = RecordBatch::try_from_iter(vec![("id", id), ("values", values)])?;
+///
+/// let unnested = unnest(&batch, 1)?;
+///
+/// assert_eq!(unnested.column(0).as_ref(), &StringArray::from(vec!["a", "a", "c"]));
+/// assert_eq!(unnested.column(1).as_ref(), &Int32Array::from(vec![1, 2, 3]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn unnest(batch: &RecordBatch, column: usize) -> Result<RecordBatch> {
+    if column >= batch.num_columns() {
+        return Err(ArrowError::InvalidArgumentError(format!(
+            "Can't unnest the column {} of a batch of {} columns",
+            column,
+            batch.num_columns()
+        )));
+    }
+    let schema = batch.schema();
+    let list = batch.column(column).data_ref();
+    let (field, ranges) = match list.data_type() {
+        DataType::List(field) => (field, list_ranges::<i32>(list)),
+        DataType::LargeList(field) => (field, list_ranges::<i64>(list)),
+        DataType::FixedSizeList(field, size) => {
+            let size = *size as usize;
+            let ranges = (0..list.len())
+                .map(|i| {
+                    let start = (list.offset() + i) * size;
+                    (start, start + size)
+                })
+                .collect();
+            (field, ranges)
+        }
+        other => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Can't unnest a column of type {:?}",
+                other
+            )))
+        }
+    };
+
+    // the rows of the lists with values, and the indices of their values
+    let mut row_indices = Vec::<u32>::new();
+    let mut value_indices = Vec::<u64>::new();
+    for (i, (start, end)) in ranges.iter().enumerate() {
+        if list.is_valid(i) {
+            row_indices.extend(std::iter::repeat(i as u32).take(end - start));
+            value_indices.extend(*start as u64..*end as u64);
+        }
+    }
+
+    let values = make_array(list.child_data()[0].clone());
+    let values = match (ranges.first(), ranges.last()) {
+        // without nulls, the values of the lists are contiguous and do not need to be copied
+        (Some((start, _)), Some((_, end))) if list.null_count() == 0 => {
+            values.slice(*start, end - start)
+        }
+        _ => take(values.as_ref(), &UInt64Array::from(value_indices), None)?,
+    };
+
+    let row_indices = UInt32Array::from(row_indices);
+    let columns = batch
+        .columns()
+        .iter()
+        .enumerate()
+        .map(|(i, array)| {
+            if i == column {
+                Ok(values.clone())
+            } else {
+                take(array.as_ref(), &row_indices, None)
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut fields = schema.fields().clone();
+    let list_field = &fields[column];
+    let mut unnested_field = Field::new(
+        list_field.name(),
+        field.data_type().clone(),
+        field.is_nullable(),
+    );
+    unnested_field.set_metadata(list_field.metadata().clone());
+    fields[column] = unnested_field;
+
+    RecordBatch::try_new(
+        Arc::new(Schema::new_with_metadata(fields, schema.metadata().clone())),
+        columns,
+    )
+}
+
+/// Returns the range of the values of each list of a list array.
+fn list_ranges<T: OffsetSizeTrait>(list: &ArrayData) -> Vec<(usize, usize)> {
+    if list.is_empty() {
+        return vec![];
+    }
+    let offsets = list.buffer::<T>(0);
+    offsets[..list.len() + 1]
+        .windows(2)
+        .map(|w| (w[0].to_usize().unwrap(), w[1].to_usize().unwrap()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_batch() -> RecordBatch {
+        let id: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5]));
+        let values: ArrayRef =
+            Arc::new(ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+                Some(vec![Some(10), None]),
+                None,
+                Some(vec![]),
+                Some(vec![Some(40)]),
+                Some(vec![Some(50), Some(51), Some(52)]),
+            ]));
+        let name: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            Some("b"),
+            None,
+            Some("d"),
+            Some("e"),
+        ]));
+        RecordBatch::try_from_iter(vec![("id", id), ("values", values), ("name", name)])
+            .unwrap()
+    }
+
+    #[test]
+    fn test_unnest_list() {
+        let unnested = unnest(&list_batch(), 1).unwrap();
+
+        assert_eq!(
+            unnested.schema().field(1),
+            &Field::new("values", DataType::Int32, true)
+        );
+        assert_eq!(
+            unnested.column(0).as_ref(),
+            &Int32Array::from(vec![1, 1, 4, 5, 5, 5])
+        );
+        assert_eq!(
+            unnested.column(1).as_ref(),
+            &Int32Array::from(vec![
+                Some(10),
+                None,
+                Some(40),
+                Some(50),
+                Some(51),
+                Some(52)
+            ])
+        );
+        assert_eq!(
+            unnested.column(2).as_ref(),
+            &StringArray::from(vec!["a", "a", "d", "e", "e", "e"])
+        );
+    }
+
+    #[test]
+    fn test_unnest_sliced_list_without_nulls() {
+        let batch = list_batch();
+        let columns = batch.columns().iter().map(|c| c.slice(2, 3)).collect();
+        let batch = RecordBatch::try_new(batch.schema(), columns).unwrap();
+
+        let unnested = unnest(&batch, 1).unwrap();
+
+        assert_eq!(
+            unnested.column(0).as_ref(),
+            &Int32Array::from(vec![4, 5, 5, 5])
+        );
+        assert_eq!(
+            unnested.column(1).as_ref(),
+            &Int32Array::from(vec![40, 50, 51, 52])
+        );
+    }
+
+    #[test]
+    fn test_unnest_fixed_size_list() {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(6), 2);
+        builder.values().append_slice(&[1, 2]).unwrap();
+        builder.append(true).unwrap();
+        builder.values().append_slice(&[3, 4]).unwrap();
+        builder.append(false).unwrap();
+        builder.values().append_slice(&[5, 6]).unwrap();
+        builder.append(true).unwrap();
+        let values: ArrayRef = Arc::new(builder.finish());
+        let id: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let batch =
+            RecordBatch::try_from_iter(vec![("values", values), ("id", id)]).unwrap();
+
+        let unnested = unnest(&batch, 0).unwrap();
+
+        assert_eq!(
+            unnested.column(0).as_ref(),
+            &Int32Array::from(vec![1, 2, 5, 6])
+        );
+        assert_eq!(
+            unnested.column(1).as_ref(),
+            &Int32Array::from(vec![1, 1, 3, 3])
+        );
+    }
+
+    #[test]
+    fn test_unnest_invalid_column() {
+        let batch = list_batch();
+        assert!(unnest(&batch, 0).is_err());
+        assert!(unnest(&batch, 3).is_err());
+    }
+}
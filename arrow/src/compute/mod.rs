@@ -28,10 +28,15 @@ pub use self::kernels::boolean::*;
 pub use self::kernels::cast::*;
 pub use self::kernels::comparison::*;
 pub use self::kernels::concat::*;
+#[cfg(feature = "ipc")]
+pub use self::kernels::external_sort::*;
 pub use self::kernels::filter::*;
 pub use self::kernels::limit::*;
+pub use self::kernels::nested::*;
 pub use self::kernels::regexp::*;
 pub use self::kernels::sort::*;
+pub use self::kernels::statistics::*;
 pub use self::kernels::take::*;
 pub use self::kernels::temporal::*;
+pub use self::kernels::unnest::*;
 pub use self::kernels::window::*;
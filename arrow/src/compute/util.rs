@@ -21,9 +21,34 @@ use crate::array::*;
 use crate::buffer::{buffer_bin_and, buffer_bin_or, Buffer};
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
 use num::{One, ToPrimitive, Zero};
 use std::ops::Add;
 
+/// Creates a [RecordBatch] with the schema of `batch` and the result of `op` on each of its
+/// columns. The errors of `op` are reported with the name of their column.
+pub(super) fn map_record_batch<F>(batch: &RecordBatch, op: F) -> Result<RecordBatch>
+where
+    F: Fn(&ArrayRef) -> Result<ArrayRef>,
+{
+    let schema = batch.schema();
+    let columns = batch
+        .columns()
+        .iter()
+        .zip(schema.fields())
+        .map(|(column, field)| {
+            op(column).map_err(|e| {
+                ArrowError::ComputeError(format!(
+                    "Failed to compute the column \"{}\": {}",
+                    field.name(),
+                    e
+                ))
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    RecordBatch::try_new(schema, columns)
+}
+
 /// Combines the null bitmaps of two arrays using a bitwise `and` operation.
 ///
 /// This function is useful when implementing operations on higher level arrays.
@@ -50,11 +50,14 @@ use std::io::{Read, Seek, SeekFrom};
 use std::sync::Arc;
 
 use crate::array::{
-    ArrayRef, BooleanArray, DictionaryArray, PrimitiveArray, StringArray,
+    ArrayRef, BooleanArray, DecimalBuilder, DictionaryArray, NullArray, PrimitiveArray,
+    PrimitiveBuilder, PrimitiveDictionaryBuilder, StringArray,
 };
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
+use crate::temporal_conversions;
+use crate::util::decimal::parse_decimal;
 
 use csv_crate::{ByteRecord, StringRecord};
 
@@ -257,6 +260,9 @@ pub struct Reader<R: Read> {
     end: usize,
     /// Number of records per batch
     batch_size: usize,
+    /// Optional approximate size in bytes of the batches, see
+    /// [ReaderBuilder::with_batch_byte_size]
+    batch_byte_size: Option<usize>,
     /// Vector that can hold the `StringRecord`s of the batches
     batch_records: Vec<StringRecord>,
 }
@@ -361,6 +367,7 @@ impl<R: Read> Reader<R> {
             reader: csv_reader,
             line_number: if has_header { start + 1 } else { start },
             batch_size,
+            batch_byte_size: None,
             end,
             batch_records,
         }
@@ -374,10 +381,15 @@ impl<R: Read> Iterator for Reader<R> {
         let remaining = self.end - self.line_number;
 
         let mut read_records = 0;
+        let mut read_bytes = 0;
         for i in 0..min(self.batch_size, remaining) {
+            if matches!(self.batch_byte_size, Some(size) if read_bytes >= size) {
+                break;
+            }
             match self.reader.read_record(&mut self.batch_records[i]) {
                 Ok(true) => {
                     read_records += 1;
+                    read_bytes += estimated_record_size(&self.batch_records[i]);
                 }
                 Ok(false) => break,
                 Err(e) => {
@@ -410,6 +422,12 @@ impl<R: Read> Iterator for Reader<R> {
     }
 }
 
+/// Returns the approximate number of bytes a record takes in a record batch: the bytes of
+/// the text of its fields, plus 8 bytes per field for their offsets or fixed size values.
+fn estimated_record_size(record: &StringRecord) -> usize {
+    record.as_slice().len() + record.len() * 8
+}
+
 /// parses a slice of [csv_crate::StringRecord] into a [array::record_batch::RecordBatch].
 fn parse(
     rows: &[StringRecord],
@@ -429,6 +447,7 @@ fn parse(
             let i = *i;
             let field = &fields[i];
             match field.data_type() {
+                DataType::Null => build_null_array(line_number, rows, i),
                 DataType::Boolean => build_boolean_array(line_number, rows, i),
                 DataType::Int8 => build_primitive_array::<Int8Type>(line_number, rows, i),
                 DataType::Int16 => {
@@ -475,56 +494,63 @@ fn parse(
                 DataType::Utf8 => Ok(Arc::new(
                     rows.iter().map(|row| row.get(i)).collect::<StringArray>(),
                 ) as ArrayRef),
-                DataType::Dictionary(key_type, value_type)
-                    if value_type.as_ref() == &DataType::Utf8 =>
-                {
-                    match key_type.as_ref() {
-                        DataType::Int8 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<Int8Type>>(),
-                        ) as ArrayRef),
-                        DataType::Int16 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<Int16Type>>(),
-                        ) as ArrayRef),
-                        DataType::Int32 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<Int32Type>>(),
-                        ) as ArrayRef),
-                        DataType::Int64 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<Int64Type>>(),
-                        ) as ArrayRef),
-                        DataType::UInt8 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<UInt8Type>>(),
-                        ) as ArrayRef),
-                        DataType::UInt16 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<UInt16Type>>(),
-                        ) as ArrayRef),
-                        DataType::UInt32 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<UInt32Type>>(),
-                        ) as ArrayRef),
-                        DataType::UInt64 => Ok(Arc::new(
-                            rows.iter()
-                                .map(|row| row.get(i))
-                                .collect::<DictionaryArray<UInt64Type>>(),
-                        ) as ArrayRef),
-                        _ => Err(ArrowError::ParseError(format!(
-                            "Unsupported dictionary key type {:?}",
-                            key_type
-                        ))),
-                    }
+                DataType::Decimal(precision, scale) => {
+                    build_decimal_array(line_number, rows, i, *precision, *scale)
                 }
+                DataType::Dictionary(key_type, value_type) => match key_type.as_ref() {
+                    DataType::Int8 => build_dictionary_array::<Int8Type>(
+                        line_number,
+                        rows,
+                        i,
+                        value_type,
+                    ),
+                    DataType::Int16 => build_dictionary_array::<Int16Type>(
+                        line_number,
+                        rows,
+                        i,
+                        value_type,
+                    ),
+                    DataType::Int32 => build_dictionary_array::<Int32Type>(
+                        line_number,
+                        rows,
+                        i,
+                        value_type,
+                    ),
+                    DataType::Int64 => build_dictionary_array::<Int64Type>(
+                        line_number,
+                        rows,
+                        i,
+                        value_type,
+                    ),
+                    DataType::UInt8 => build_dictionary_array::<UInt8Type>(
+                        line_number,
+                        rows,
+                        i,
+                        value_type,
+                    ),
+                    DataType::UInt16 => build_dictionary_array::<UInt16Type>(
+                        line_number,
+                        rows,
+                        i,
+                        value_type,
+                    ),
+                    DataType::UInt32 => build_dictionary_array::<UInt32Type>(
+                        line_number,
+                        rows,
+                        i,
+                        value_type,
+                    ),
+                    DataType::UInt64 => build_dictionary_array::<UInt64Type>(
+                        line_number,
+                        rows,
+                        i,
+                        value_type,
+                    ),
+                    _ => Err(ArrowError::ParseError(format!(
+                        "Unsupported dictionary key type {:?}",
+                        key_type
+                    ))),
+                },
                 other => Err(ArrowError::ParseError(format!(
                     "Unsupported data type {:?}",
                     other
@@ -579,17 +605,12 @@ impl Parser for Int16Type {}
 
 impl Parser for Int8Type {}
 
-/// Number of days between 0001-01-01 and 1970-01-01
-const EPOCH_DAYS_FROM_CE: i32 = 719_163;
-
 impl Parser for Date32Type {
     fn parse(string: &str) -> Option<i32> {
-        use chrono::Datelike;
-
         match Self::DATA_TYPE {
             DataType::Date32 => {
                 let date = string.parse::<chrono::NaiveDate>().ok()?;
-                Self::Native::from_i32(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+                Self::Native::from_i32(temporal_conversions::date_to_date32(date))
             }
             _ => None,
         }
@@ -601,7 +622,9 @@ impl Parser for Date64Type {
         match Self::DATA_TYPE {
             DataType::Date64 => {
                 let date_time = string.parse::<chrono::NaiveDateTime>().ok()?;
-                Self::Native::from_i64(date_time.timestamp_millis())
+                Self::Native::from_i64(temporal_conversions::datetime_to_date64(
+                    date_time,
+                ))
             }
             _ => None,
         }
@@ -613,7 +636,10 @@ impl Parser for TimestampNanosecondType {
         match Self::DATA_TYPE {
             DataType::Timestamp(TimeUnit::Nanosecond, None) => {
                 let date_time = string.parse::<chrono::NaiveDateTime>().ok()?;
-                Self::Native::from_i64(date_time.timestamp_nanos())
+                temporal_conversions::datetime_to_timestamp_opt(
+                    date_time,
+                    &TimeUnit::Nanosecond,
+                )
             }
             _ => None,
         }
@@ -625,7 +651,9 @@ impl Parser for TimestampMicrosecondType {
         match Self::DATA_TYPE {
             DataType::Timestamp(TimeUnit::Microsecond, None) => {
                 let date_time = string.parse::<chrono::NaiveDateTime>().ok()?;
-                Self::Native::from_i64(date_time.timestamp_nanos() / 1000)
+                Self::Native::from_i64(temporal_conversions::datetime_to_timestamp_us(
+                    date_time,
+                ))
             }
             _ => None,
         }
@@ -680,6 +708,129 @@ fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
         .map(|e| Arc::new(e) as ArrayRef)
 }
 
+// parses a specific column (col_idx) into a dictionary array with keys of type `K` and
+// values of `value_type`, parsing each value as it would be parsed for a column of that
+// type.
+fn build_dictionary_array<K: ArrowDictionaryKeyType>(
+    line_number: usize,
+    rows: &[StringRecord],
+    col_idx: usize,
+    value_type: &DataType,
+) -> Result<ArrayRef> {
+    match value_type {
+        DataType::Utf8 => Ok(Arc::new(
+            rows.iter()
+                .map(|row| row.get(col_idx))
+                .collect::<DictionaryArray<K>>(),
+        ) as ArrayRef),
+        DataType::Int8 => {
+            build_primitive_dictionary_array::<K, Int8Type>(line_number, rows, col_idx)
+        }
+        DataType::Int16 => {
+            build_primitive_dictionary_array::<K, Int16Type>(line_number, rows, col_idx)
+        }
+        DataType::Int32 => {
+            build_primitive_dictionary_array::<K, Int32Type>(line_number, rows, col_idx)
+        }
+        DataType::Int64 => {
+            build_primitive_dictionary_array::<K, Int64Type>(line_number, rows, col_idx)
+        }
+        DataType::UInt8 => {
+            build_primitive_dictionary_array::<K, UInt8Type>(line_number, rows, col_idx)
+        }
+        DataType::UInt16 => {
+            build_primitive_dictionary_array::<K, UInt16Type>(line_number, rows, col_idx)
+        }
+        DataType::UInt32 => {
+            build_primitive_dictionary_array::<K, UInt32Type>(line_number, rows, col_idx)
+        }
+        DataType::UInt64 => {
+            build_primitive_dictionary_array::<K, UInt64Type>(line_number, rows, col_idx)
+        }
+        DataType::Float32 => {
+            build_primitive_dictionary_array::<K, Float32Type>(line_number, rows, col_idx)
+        }
+        DataType::Float64 => {
+            build_primitive_dictionary_array::<K, Float64Type>(line_number, rows, col_idx)
+        }
+        DataType::Date32 => {
+            build_primitive_dictionary_array::<K, Date32Type>(line_number, rows, col_idx)
+        }
+        DataType::Date64 => {
+            build_primitive_dictionary_array::<K, Date64Type>(line_number, rows, col_idx)
+        }
+        other => Err(ArrowError::ParseError(format!(
+            "Unsupported dictionary value type {:?}",
+            other
+        ))),
+    }
+}
+
+// parses a specific column (col_idx) into a dictionary array of primitive values.
+fn build_primitive_dictionary_array<K, V>(
+    line_number: usize,
+    rows: &[StringRecord],
+    col_idx: usize,
+) -> Result<ArrayRef>
+where
+    K: ArrowDictionaryKeyType,
+    V: ArrowPrimitiveType + Parser,
+{
+    let mut builder = PrimitiveDictionaryBuilder::new(
+        PrimitiveBuilder::<K>::new(rows.len()),
+        PrimitiveBuilder::<V>::new(rows.len()),
+    );
+    for (row_index, row) in rows.iter().enumerate() {
+        match row.get(col_idx) {
+            Some(s) if !s.is_empty() => match parse_item::<V>(s) {
+                Some(value) => {
+                    builder.append(value)?;
+                }
+                None => {
+                    return Err(ArrowError::ParseError(format!(
+                        "Error while parsing value {} for column {} at line {}",
+                        s,
+                        col_idx,
+                        line_number + row_index
+                    )))
+                }
+            },
+            _ => builder.append_null()?,
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+// parses a specific column (col_idx) into a decimal array of `precision` and `scale`.
+fn build_decimal_array(
+    line_number: usize,
+    rows: &[StringRecord],
+    col_idx: usize,
+    precision: usize,
+    scale: usize,
+) -> Result<ArrayRef> {
+    let mut builder = DecimalBuilder::with_capacity(rows.len(), precision, scale);
+    for (row_index, row) in rows.iter().enumerate() {
+        match row.get(col_idx) {
+            Some(s) if !s.is_empty() => match parse_decimal(s, precision, scale, None) {
+                Ok(value) => builder.append_value(value)?,
+                Err(_) => {
+                    return Err(ArrowError::ParseError(format!(
+                        "Error while parsing value {} as Decimal({}, {}) for column {} at line {}",
+                        s,
+                        precision,
+                        scale,
+                        col_idx,
+                        line_number + row_index
+                    )))
+                }
+            },
+            _ => builder.append_null()?,
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
 // parses a specific column (col_idx) into an Arrow Array.
 fn build_boolean_array(
     line_number: usize,
@@ -714,6 +865,29 @@ fn build_boolean_array(
         .map(|e| Arc::new(e) as ArrayRef)
 }
 
+// parses a specific column (col_idx) into an Arrow Array of nulls, whose values must be empty.
+fn build_null_array(
+    line_number: usize,
+    rows: &[StringRecord],
+    col_idx: usize,
+) -> Result<ArrayRef> {
+    for (row_index, row) in rows.iter().enumerate() {
+        match row.get(col_idx) {
+            Some(s) if !s.is_empty() => {
+                return Err(ArrowError::ParseError(format!(
+                    "Error while parsing value {} for column {} at line {}: \
+                     the values of a Null column must be empty",
+                    s,
+                    col_idx,
+                    line_number + row_index
+                )))
+            }
+            _ => {}
+        }
+    }
+    Ok(Arc::new(NullArray::new(rows.len())))
+}
+
 /// CSV file reader builder
 #[derive(Debug)]
 pub struct ReaderBuilder {
@@ -737,6 +911,8 @@ pub struct ReaderBuilder {
     ///
     /// The default batch size when using the `ReaderBuilder` is 1024 records
     batch_size: usize,
+    /// Optional approximate size in bytes of each batch
+    batch_byte_size: Option<usize>,
     /// The bounds over which to scan the reader. `None` starts from 0 and runs until EOF.
     bounds: Bounds,
     /// Optional projection for which columns to load (zero-based column indices)
@@ -751,6 +927,7 @@ impl Default for ReaderBuilder {
             delimiter: None,
             max_records: None,
             batch_size: 1024,
+            batch_byte_size: None,
             bounds: None,
             projection: None,
         }
@@ -817,6 +994,17 @@ impl ReaderBuilder {
         self
     }
 
+    /// Set the approximate size in bytes of the batches, so that rows with large values
+    /// produce batches of fewer rows than the batch size.
+    ///
+    /// The size of each row is estimated from the length of its text as it is read, and a
+    /// batch ends with the first row that reaches `batch_byte_size`. A batch has at least one
+    /// row, and at most the batch size of rows.
+    pub fn with_batch_byte_size(mut self, batch_byte_size: usize) -> Self {
+        self.batch_byte_size = Some(batch_byte_size);
+        self
+    }
+
     /// Set the reader's column projection
     pub fn with_projection(mut self, projection: Vec<usize>) -> Self {
         self.projection = Some(projection);
@@ -840,7 +1028,7 @@ impl ReaderBuilder {
                 Arc::new(inferred_schema)
             }
         };
-        Ok(Reader::from_reader(
+        let mut reader = Reader::from_reader(
             reader,
             schema,
             self.has_header,
@@ -848,7 +1036,9 @@ impl ReaderBuilder {
             self.batch_size,
             None,
             self.projection.clone(),
-        ))
+        );
+        reader.batch_byte_size = self.batch_byte_size;
+        Ok(reader)
     }
 }
 
@@ -1114,6 +1304,73 @@ mod tests {
         assert_eq!(strings.value(29), "Uckfield, East Sussex, UK");
     }
 
+    #[test]
+    fn test_csv_with_decimal_and_primitive_dictionary() {
+        let schema = Schema::new(vec![
+            Field::new("price", DataType::Decimal(10, 2), true),
+            Field::new(
+                "code",
+                DataType::Dictionary(
+                    Box::new(DataType::Int8),
+                    Box::new(DataType::UInt32),
+                ),
+                true,
+            ),
+        ]);
+        let data = "1.25,7\n-3.1,\n,7\n400,9\n";
+        let mut csv = Reader::new(
+            Cursor::new(data.as_bytes()),
+            Arc::new(schema.clone()),
+            false,
+            None,
+            1024,
+            None,
+            None,
+        );
+        let batch = csv.next().unwrap().unwrap();
+        assert_eq!(Arc::new(schema.clone()), batch.schema());
+
+        let price = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<DecimalArray>()
+            .unwrap();
+        assert_eq!(125, price.value(0));
+        assert_eq!(-310, price.value(1));
+        assert!(price.is_null(2));
+        assert_eq!(40000, price.value(3));
+
+        let code = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        assert_eq!(1, code.null_count());
+        assert_eq!(
+            code.keys(),
+            &Int8Array::from(vec![Some(0), None, Some(0), Some(1)])
+        );
+        assert_eq!(
+            code.values().as_ref(),
+            &UInt32Array::from(vec![7, 9]) as &dyn Array
+        );
+
+        // the value has more digits than the decimal's scale
+        let mut csv = Reader::new(
+            Cursor::new("1.255,7\n".as_bytes()),
+            Arc::new(schema),
+            false,
+            None,
+            1024,
+            None,
+            None,
+        );
+        assert_eq!(
+            "Parser error: Error while parsing value 1.255 as Decimal(10, 2) for column 0 at line 0",
+            csv.next().unwrap().unwrap_err().to_string()
+        );
+    }
+
     #[test]
     fn test_nulls() {
         let schema = Schema::new(vec![
@@ -1255,6 +1512,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_timestamp_nanos() {
+        assert_eq!(
+            parse_item::<TimestampNanosecondType>("1970-01-01T00:00:01.5").unwrap(),
+            1_500_000_000
+        );
+        // nanoseconds overflow outside of the years 1677 to 2262
+        assert_eq!(
+            parse_item::<TimestampNanosecondType>("3000-01-01T00:00:00"),
+            None
+        );
+    }
+
     #[test]
     fn test_infer_schema_from_multiple_files() -> Result<()> {
         let mut csv1 = NamedTempFile::new()?;
@@ -1343,6 +1613,40 @@ mod tests {
         assert!(csv.next().is_none());
     }
 
+    #[test]
+    fn test_null_column() {
+        let schema = Schema::new(vec![
+            Field::new("int", DataType::UInt32, false),
+            Field::new("null", DataType::Null, true),
+        ]);
+        let data = "1,\n2,\n";
+        let mut csv = Reader::new(
+            std::io::Cursor::new(data.as_bytes()),
+            Arc::new(schema.clone()),
+            false,
+            None,
+            1024,
+            None,
+            None,
+        );
+        let batch = csv.next().unwrap().unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.column(1).data_type(), &DataType::Null);
+        assert_eq!(batch.column(1).null_count(), 2);
+
+        let data = "1,\n2,a\n";
+        let mut csv = Reader::new(
+            std::io::Cursor::new(data.as_bytes()),
+            Arc::new(schema),
+            false,
+            None,
+            1024,
+            None,
+            None,
+        );
+        assert!(csv.next().unwrap().is_err());
+    }
+
     #[test]
     fn test_parsing_bool() {
         // Encode the expected behavior of boolean parsing
@@ -1381,4 +1685,26 @@ mod tests {
         assert_eq!(None, parse_item::<Float64Type>("dd"));
         assert_eq!(None, parse_item::<Float64Type>("12.34.56"));
     }
+
+    #[test]
+    fn test_csv_batch_byte_size() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("text", DataType::Utf8, false),
+        ]));
+        let large = "x".repeat(1000);
+        let data = format!("1,a\n2,{}\n3,b\n4,c\n5,d\n", large);
+
+        let reader = ReaderBuilder::new()
+            .with_schema(schema)
+            .with_batch_size(3)
+            .with_batch_byte_size(100)
+            .build(Cursor::new(data))
+            .unwrap();
+        let rows = reader
+            .map(|batch| batch.unwrap().num_rows())
+            .collect::<Vec<_>>();
+        // the batch ends with the large row, and is otherwise limited by the batch size
+        assert_eq!(rows, vec![2, 3]);
+    }
 }
@@ -70,6 +70,7 @@ use std::io::Write;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
+use crate::util::display::{interval_day_time_to_string, interval_year_month_to_string};
 use crate::{array::*, util::serialization::lexical_to_string};
 const DEFAULT_DATE_FORMAT: &str = "%F";
 const DEFAULT_TIME_FORMAT: &str = "%T";
@@ -242,6 +243,17 @@ impl<W: Write> Writer<W> {
                     };
                     format!("{}", datetime.format(&self.timestamp_format))
                 }
+                DataType::Interval(IntervalUnit::YearMonth) => {
+                    let c = col
+                        .as_any()
+                        .downcast_ref::<IntervalYearMonthArray>()
+                        .unwrap();
+                    interval_year_month_to_string(c.value(row_index))
+                }
+                DataType::Interval(IntervalUnit::DayTime) => {
+                    let c = col.as_any().downcast_ref::<IntervalDayTimeArray>().unwrap();
+                    interval_day_time_to_string(c.value(row_index))
+                }
                 t => {
                     // List and Struct arrays not supported by the writer, any
                     // other type needs to be implemented
@@ -557,6 +569,30 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555000000,23:46:03,foo
         );
     }
 
+    #[test]
+    fn test_write_csv_intervals() {
+        let schema = Schema::new(vec![
+            Field::new("ym", DataType::Interval(IntervalUnit::YearMonth), true),
+            Field::new("dt", DataType::Interval(IntervalUnit::DayTime), true),
+        ]);
+        let ym = IntervalYearMonthArray::from(vec![Some(14), None]);
+        let dt = IntervalDayTimeArray::from(vec![Some((3 << 32) | 14_706_000), None]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(ym), Arc::new(dt)])
+                .unwrap();
+
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let mut writer = Writer::new(&mut buffer);
+            writer.write(&batch).unwrap();
+        }
+
+        assert_eq!(
+            "ym,dt\n1 years 2 mons 0 days 0 hours 0 mins 0.000 secs,0 years 0 mons 3 days 4 hours 5 mins 6.000 secs\n,\n",
+            String::from_utf8(buffer).unwrap()
+        );
+    }
+
     #[test]
     fn test_export_csv_string() {
         let schema = Schema::new(vec![
@@ -253,6 +253,35 @@ impl Field {
                             ));
                         }
                     },
+                    // Map children should be a single struct of keys and values
+                    DataType::Map(_, keys_sorted) => match map.get("children") {
+                        Some(Value::Array(values)) if values.len() == 1 => {
+                            let child = Self::from(&values[0])?;
+                            // child must be a struct
+                            match child.data_type() {
+                                DataType::Struct(map_fields) if map_fields.len() == 2 => {
+                                    DataType::Map(Box::new(child), keys_sorted)
+                                }
+                                t => {
+                                    return Err(ArrowError::ParseError(format!(
+                                        "Map children should be a struct with 2 fields, found {:?}",
+                                        t
+                                    )))
+                                }
+                            }
+                        }
+                        Some(_) => {
+                            return Err(ArrowError::ParseError(
+                                "Field 'children' must be an array with 1 element"
+                                    .to_string(),
+                            ))
+                        }
+                        None => {
+                            return Err(ArrowError::ParseError(
+                                "Field missing 'children' attribute".to_string(),
+                            ));
+                        }
+                    },
                     DataType::Struct(mut fields) => match map.get("children") {
                         Some(Value::Array(values)) => {
                             let struct_fields: Result<Vec<Field>> =
@@ -329,6 +358,7 @@ impl Field {
             DataType::List(field) => vec![field.to_json()],
             DataType::LargeList(field) => vec![field.to_json()],
             DataType::FixedSizeList(field, _) => vec![field.to_json()],
+            DataType::Map(field, _) => vec![field.to_json()],
             _ => vec![],
         };
         match self.data_type() {
@@ -473,7 +503,8 @@ impl Field {
             | DataType::FixedSizeBinary(_)
             | DataType::Utf8
             | DataType::LargeUtf8
-            | DataType::Decimal(_, _) => {
+            | DataType::Decimal(_, _)
+            | DataType::Map(_, _) => {
                 if self.data_type != from.data_type {
                     return Err(ArrowError::SchemaError(
                         "Fail to merge schema Field due to conflicting datatype"
@@ -0,0 +1,525 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversions of [`DataType`]s to and from SQL type names.
+
+use std::fmt;
+
+use super::{DataType, Field, IntervalUnit, TimeUnit};
+use crate::error::{ArrowError, Result};
+
+/// A dialect of SQL, which [`DataType::to_sql_type`] produces type names of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqlDialect {
+    /// ANSI SQL
+    Ansi,
+    /// PostgreSQL
+    PostgreSql,
+    /// MySQL
+    MySql,
+    /// SQLite
+    Sqlite,
+}
+
+impl fmt::Display for SqlDialect {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl DataType {
+    /// Returns the name of the SQL type of `dialect` that holds the values of this
+    /// type, e.g. `DECIMAL(38, 10)` or `TIMESTAMP(6) WITH TIME ZONE`.
+    ///
+    /// Unsigned integers map to wider signed integers in dialects without unsigned
+    /// types, and dictionaries to the type of their values. Returns an error for
+    /// types without a SQL equivalent in `dialect`, such as structs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arrow::datatypes::{DataType, SqlDialect, TimeUnit};
+    ///
+    /// let data_type = DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".to_string()));
+    /// assert_eq!(
+    ///     data_type.to_sql_type(SqlDialect::PostgreSql).unwrap(),
+    ///     "TIMESTAMP(3) WITH TIME ZONE"
+    /// );
+    /// assert_eq!(data_type.to_sql_type(SqlDialect::MySql).unwrap(), "TIMESTAMP(3)");
+    /// ```
+    pub fn to_sql_type(&self, dialect: SqlDialect) -> Result<String> {
+        use SqlDialect::*;
+
+        let unsupported = || {
+            Err(ArrowError::InvalidArgumentError(format!(
+                "{:?} has no SQL type in the {} dialect",
+                self, dialect
+            )))
+        };
+
+        let sql_type = match (self, dialect) {
+            (DataType::Boolean, _) => "BOOLEAN".to_string(),
+            (DataType::Int8, MySql) => "TINYINT".to_string(),
+            (DataType::UInt8, MySql) => "TINYINT UNSIGNED".to_string(),
+            (DataType::UInt16, MySql) => "SMALLINT UNSIGNED".to_string(),
+            (DataType::UInt32, MySql) => "INT UNSIGNED".to_string(),
+            (DataType::UInt64, MySql) => "BIGINT UNSIGNED".to_string(),
+            (DataType::Int8, Sqlite)
+            | (DataType::Int16, Sqlite)
+            | (DataType::Int32, Sqlite)
+            | (DataType::Int64, Sqlite)
+            | (DataType::UInt8, Sqlite)
+            | (DataType::UInt16, Sqlite)
+            | (DataType::UInt32, Sqlite) => "INTEGER".to_string(),
+            (DataType::Int8, _) | (DataType::Int16, _) | (DataType::UInt8, _) => {
+                "SMALLINT".to_string()
+            }
+            (DataType::Int32, _) | (DataType::UInt16, _) => "INTEGER".to_string(),
+            (DataType::Int64, _) | (DataType::UInt32, _) => "BIGINT".to_string(),
+            (DataType::UInt64, _) => "DECIMAL(20, 0)".to_string(),
+            (DataType::Float16, MySql) | (DataType::Float32, MySql) => {
+                "FLOAT".to_string()
+            }
+            (DataType::Float16, _) | (DataType::Float32, _) => "REAL".to_string(),
+            (DataType::Float64, MySql) => "DOUBLE".to_string(),
+            (DataType::Float64, Sqlite) => "REAL".to_string(),
+            (DataType::Float64, _) => "DOUBLE PRECISION".to_string(),
+            (DataType::Decimal(precision, scale), PostgreSql) => {
+                format!("NUMERIC({}, {})", precision, scale)
+            }
+            (DataType::Decimal(precision, scale), _) => {
+                format!("DECIMAL({}, {})", precision, scale)
+            }
+            (DataType::Utf8, Ansi) | (DataType::LargeUtf8, Ansi) => "VARCHAR".to_string(),
+            (DataType::LargeUtf8, MySql) => "LONGTEXT".to_string(),
+            (DataType::Utf8, _) | (DataType::LargeUtf8, _) => "TEXT".to_string(),
+            (DataType::Binary, PostgreSql)
+            | (DataType::LargeBinary, PostgreSql)
+            | (DataType::FixedSizeBinary(_), PostgreSql) => "BYTEA".to_string(),
+            (DataType::FixedSizeBinary(_), Sqlite) => "BLOB".to_string(),
+            (DataType::FixedSizeBinary(size), _) => format!("BINARY({})", size),
+            (DataType::Binary, Ansi) | (DataType::LargeBinary, Ansi) => {
+                "VARBINARY".to_string()
+            }
+            (DataType::LargeBinary, MySql) => "LONGBLOB".to_string(),
+            (DataType::Binary, _) | (DataType::LargeBinary, _) => "BLOB".to_string(),
+            (DataType::Date32, _) | (DataType::Date64, _) => "DATE".to_string(),
+            (DataType::Time32(unit), _) | (DataType::Time64(unit), _) => {
+                format!("TIME({})", time_precision(unit, dialect))
+            }
+            (DataType::Timestamp(unit, None), MySql) => {
+                format!("DATETIME({})", time_precision(unit, dialect))
+            }
+            (DataType::Timestamp(unit, Some(_)), MySql)
+            | (DataType::Timestamp(unit, None), _) => {
+                format!("TIMESTAMP({})", time_precision(unit, dialect))
+            }
+            (DataType::Timestamp(unit, Some(_)), _) => format!(
+                "TIMESTAMP({}) WITH TIME ZONE",
+                time_precision(unit, dialect)
+            ),
+            (DataType::Interval(_), PostgreSql) | (DataType::Duration(_), PostgreSql) => {
+                "INTERVAL".to_string()
+            }
+            (DataType::Interval(IntervalUnit::YearMonth), Ansi) => {
+                "INTERVAL YEAR TO MONTH".to_string()
+            }
+            (DataType::Interval(IntervalUnit::DayTime), Ansi) => {
+                "INTERVAL DAY TO SECOND(3)".to_string()
+            }
+            (DataType::Duration(unit), Ansi) => {
+                format!("INTERVAL DAY TO SECOND({})", time_precision(unit, dialect))
+            }
+            (DataType::List(field), PostgreSql)
+            | (DataType::LargeList(field), PostgreSql)
+            | (DataType::FixedSizeList(field, _), PostgreSql) => {
+                format!("{}[]", field.data_type().to_sql_type(dialect)?)
+            }
+            (DataType::List(field), Ansi)
+            | (DataType::LargeList(field), Ansi)
+            | (DataType::FixedSizeList(field, _), Ansi) => {
+                format!("{} ARRAY", field.data_type().to_sql_type(dialect)?)
+            }
+            (DataType::Dictionary(_, value_type), _) => {
+                return value_type.to_sql_type(dialect)
+            }
+            _ => return unsupported(),
+        };
+        Ok(sql_type)
+    }
+
+    /// Parses the name of a SQL type, as in `CREATE TABLE` statements or the catalogs
+    /// of databases, e.g. `DECIMAL(38,10)`, `TIMESTAMP WITH TIME ZONE`,
+    /// `int unsigned` or `text[]`, into the data type that holds its values.
+    ///
+    /// The names are case-insensitive and include the types of ANSI SQL and the
+    /// common aliases of PostgreSQL, MySQL and SQLite. Some types have defaults:
+    /// * `DECIMAL` without precision is `Decimal(38, 10)`
+    /// * `TIME` and `TIMESTAMP` without precision have microseconds
+    /// * `TIMESTAMP WITH TIME ZONE` has the time zone `UTC`
+    /// * `INTERVAL` is `Interval(YearMonth)` if it has years or months only, and
+    ///   `Interval(DayTime)` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arrow::datatypes::{DataType, TimeUnit};
+    ///
+    /// assert_eq!(
+    ///     DataType::from_sql_type("decimal(38,10)").unwrap(),
+    ///     DataType::Decimal(38, 10)
+    /// );
+    /// assert_eq!(
+    ///     DataType::from_sql_type("TIMESTAMP WITH TIME ZONE").unwrap(),
+    ///     DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".to_string()))
+    /// );
+    /// ```
+    pub fn from_sql_type(sql_type: &str) -> Result<DataType> {
+        let normalized = sql_type
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_uppercase()
+            .replace(" (", "(")
+            .replace("( ", "(")
+            .replace(" )", ")")
+            .replace(" ,", ",")
+            .replace(", ", ",");
+        parse_sql_type(&normalized).ok_or_else(|| {
+            ArrowError::ParseError(format!("Unsupported SQL type {}", sql_type))
+        })
+    }
+}
+
+/// Returns the number of digits of the fractional seconds of `unit`, at most 6 in
+/// dialects without nanoseconds
+fn time_precision(unit: &TimeUnit, dialect: SqlDialect) -> u8 {
+    let precision = match unit {
+        TimeUnit::Second => 0,
+        TimeUnit::Millisecond => 3,
+        TimeUnit::Microsecond => 6,
+        TimeUnit::Nanosecond => 9,
+    };
+    match dialect {
+        SqlDialect::PostgreSql | SqlDialect::MySql => precision.min(6),
+        _ => precision,
+    }
+}
+
+/// Returns the time unit of the fractional seconds of a number of digits
+fn time_unit(precision: Option<u64>) -> Option<TimeUnit> {
+    match precision {
+        Some(0) => Some(TimeUnit::Second),
+        Some(1..=3) => Some(TimeUnit::Millisecond),
+        None | Some(4..=6) => Some(TimeUnit::Microsecond),
+        Some(7..=9) => Some(TimeUnit::Nanosecond),
+        _ => None,
+    }
+}
+
+/// Parses a normalized SQL type name: in upper case, with single spaces and without
+/// spaces around parentheses and commas
+fn parse_sql_type(sql_type: &str) -> Option<DataType> {
+    let list = |item: &str| {
+        let item = parse_sql_type(item)?;
+        Some(DataType::List(Box::new(Field::new("item", item, true))))
+    };
+    if let Some(item) = sql_type.strip_suffix("[]") {
+        return list(item);
+    }
+    if let Some(item) = sql_type.strip_suffix(" ARRAY") {
+        return list(item);
+    }
+    if let Some(item) = sql_type
+        .strip_prefix("ARRAY<")
+        .and_then(|item| item.strip_suffix('>'))
+    {
+        return list(item);
+    }
+
+    if let Some(qualifier) = sql_type.strip_prefix("INTERVAL") {
+        let year_month = !qualifier.is_empty()
+            && ["DAY", "HOUR", "MINUTE", "SECOND"]
+                .iter()
+                .all(|field| !qualifier.contains(field));
+        return Some(DataType::Interval(if year_month {
+            IntervalUnit::YearMonth
+        } else {
+            IntervalUnit::DayTime
+        }));
+    }
+
+    let (sql_type, with_time_zone) = match sql_type.strip_suffix(" WITH TIME ZONE") {
+        Some(sql_type) => (sql_type, true),
+        None => (
+            sql_type
+                .strip_suffix(" WITHOUT TIME ZONE")
+                .unwrap_or(sql_type),
+            false,
+        ),
+    };
+    let (sql_type, unsigned) = match sql_type.strip_suffix(" UNSIGNED") {
+        Some(sql_type) => (sql_type, true),
+        None => (sql_type, false),
+    };
+    let (name, parameters) = match sql_type.find('(') {
+        Some(start) => {
+            let parameters = sql_type[start + 1..].strip_suffix(')')?;
+            let parameters = parameters
+                .split(',')
+                .map(|p| p.parse::<u64>().ok())
+                .collect::<Option<Vec<_>>>()?;
+            (&sql_type[..start], parameters)
+        }
+        None => (sql_type, vec![]),
+    };
+    let precision = parameters.first().copied();
+    let utc = || Some("UTC".to_string());
+
+    let data_type = match (name, unsigned) {
+        ("BOOLEAN", false) | ("BOOL", false) => DataType::Boolean,
+        ("TINYINT", false) => DataType::Int8,
+        ("TINYINT", true) => DataType::UInt8,
+        ("SMALLINT", false) | ("INT2", false) | ("SMALLSERIAL", false) => DataType::Int16,
+        ("SMALLINT", true) => DataType::UInt16,
+        ("MEDIUMINT", _)
+        | ("INT", false)
+        | ("INTEGER", false)
+        | ("INT4", false)
+        | ("SERIAL", false) => DataType::Int32,
+        ("INT", true) | ("INTEGER", true) => DataType::UInt32,
+        ("BIGINT", false) | ("INT8", false) | ("BIGSERIAL", false) => DataType::Int64,
+        ("BIGINT", true) => DataType::UInt64,
+        ("REAL", false) | ("FLOAT4", false) => DataType::Float32,
+        ("FLOAT", false) => match precision {
+            Some(precision) if precision <= 24 => DataType::Float32,
+            _ => DataType::Float64,
+        },
+        ("DOUBLE", false) | ("DOUBLE PRECISION", false) | ("FLOAT8", false) => {
+            DataType::Float64
+        }
+        ("DECIMAL", false) | ("DEC", false) | ("NUMERIC", false) => {
+            match parameters.as_slice() {
+                [] => DataType::Decimal(38, 10),
+                [precision] => DataType::Decimal(*precision as usize, 0),
+                [precision, scale] => {
+                    DataType::Decimal(*precision as usize, *scale as usize)
+                }
+                _ => return None,
+            }
+        }
+        ("CHAR", false)
+        | ("CHARACTER", false)
+        | ("NCHAR", false)
+        | ("VARCHAR", false)
+        | ("CHARACTER VARYING", false)
+        | ("NVARCHAR", false)
+        | ("VARCHAR2", false)
+        | ("TEXT", false)
+        | ("TINYTEXT", false)
+        | ("MEDIUMTEXT", false)
+        | ("STRING", false)
+        | ("CLOB", false)
+        | ("UUID", false)
+        | ("JSON", false)
+        | ("JSONB", false) => DataType::Utf8,
+        ("LONGTEXT", false) => DataType::LargeUtf8,
+        ("BINARY", false) => DataType::FixedSizeBinary(precision.unwrap_or(1) as i32),
+        ("VARBINARY", false)
+        | ("BINARY VARYING", false)
+        | ("BYTEA", false)
+        | ("BLOB", false)
+        | ("TINYBLOB", false)
+        | ("MEDIUMBLOB", false) => DataType::Binary,
+        ("LONGBLOB", false) => DataType::LargeBinary,
+        ("DATE", false) => DataType::Date32,
+        ("TIME", false) | ("TIMETZ", false) => match time_unit(precision)? {
+            unit @ TimeUnit::Second | unit @ TimeUnit::Millisecond => {
+                DataType::Time32(unit)
+            }
+            unit => DataType::Time64(unit),
+        },
+        ("TIMESTAMP", false) => {
+            let time_zone = if with_time_zone { utc() } else { None };
+            DataType::Timestamp(time_unit(precision)?, time_zone)
+        }
+        ("DATETIME", false) | ("TIMESTAMP_NTZ", false) => {
+            DataType::Timestamp(time_unit(precision)?, None)
+        }
+        ("TIMESTAMPTZ", false) | ("TIMESTAMP_TZ", false) | ("TIMESTAMP_LTZ", false) => {
+            DataType::Timestamp(time_unit(precision)?, utc())
+        }
+        _ => return None,
+    };
+    Some(data_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_sql_type() {
+        let cases = vec![
+            (DataType::Int8, SqlDialect::Ansi, "SMALLINT"),
+            (DataType::UInt32, SqlDialect::PostgreSql, "BIGINT"),
+            (DataType::UInt32, SqlDialect::MySql, "INT UNSIGNED"),
+            (DataType::UInt64, SqlDialect::Sqlite, "DECIMAL(20, 0)"),
+            (DataType::Float64, SqlDialect::Ansi, "DOUBLE PRECISION"),
+            (
+                DataType::Decimal(38, 10),
+                SqlDialect::PostgreSql,
+                "NUMERIC(38, 10)",
+            ),
+            (DataType::LargeUtf8, SqlDialect::MySql, "LONGTEXT"),
+            (
+                DataType::FixedSizeBinary(16),
+                SqlDialect::Ansi,
+                "BINARY(16)",
+            ),
+            (DataType::Binary, SqlDialect::PostgreSql, "BYTEA"),
+            (
+                DataType::Time32(TimeUnit::Millisecond),
+                SqlDialect::Ansi,
+                "TIME(3)",
+            ),
+            (
+                DataType::Timestamp(TimeUnit::Nanosecond, None),
+                SqlDialect::PostgreSql,
+                "TIMESTAMP(6)",
+            ),
+            (
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".to_string())),
+                SqlDialect::Ansi,
+                "TIMESTAMP(9) WITH TIME ZONE",
+            ),
+            (
+                DataType::Timestamp(TimeUnit::Second, None),
+                SqlDialect::MySql,
+                "DATETIME(0)",
+            ),
+            (
+                DataType::Interval(IntervalUnit::YearMonth),
+                SqlDialect::Ansi,
+                "INTERVAL YEAR TO MONTH",
+            ),
+            (
+                DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+                SqlDialect::PostgreSql,
+                "INTEGER[]",
+            ),
+            (
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+                SqlDialect::Sqlite,
+                "TEXT",
+            ),
+        ];
+        for (data_type, dialect, expected) in cases {
+            assert_eq!(data_type.to_sql_type(dialect).unwrap(), expected);
+        }
+
+        assert!(DataType::Struct(vec![])
+            .to_sql_type(SqlDialect::Ansi)
+            .is_err());
+        assert!(DataType::Interval(IntervalUnit::DayTime)
+            .to_sql_type(SqlDialect::MySql)
+            .is_err());
+    }
+
+    #[test]
+    fn test_from_sql_type() {
+        let cases = vec![
+            ("DECIMAL(38,10)", DataType::Decimal(38, 10)),
+            ("numeric ( 10 )", DataType::Decimal(10, 0)),
+            ("int unsigned", DataType::UInt32),
+            ("BIGINT", DataType::Int64),
+            ("double precision", DataType::Float64),
+            ("float(10)", DataType::Float32),
+            ("character varying(255)", DataType::Utf8),
+            ("BINARY(16)", DataType::FixedSizeBinary(16)),
+            ("bytea", DataType::Binary),
+            ("TIME(3)", DataType::Time32(TimeUnit::Millisecond)),
+            (
+                "TIMESTAMP WITH TIME ZONE",
+                DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".to_string())),
+            ),
+            (
+                "timestamp(9) without time zone",
+                DataType::Timestamp(TimeUnit::Nanosecond, None),
+            ),
+            (
+                "timestamptz",
+                DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".to_string())),
+            ),
+            (
+                "DATETIME(3)",
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+            ),
+            (
+                "INTERVAL YEAR TO MONTH",
+                DataType::Interval(IntervalUnit::YearMonth),
+            ),
+            ("interval", DataType::Interval(IntervalUnit::DayTime)),
+            (
+                "text[]",
+                DataType::List(Box::new(Field::new("item", DataType::Utf8, true))),
+            ),
+            (
+                "ARRAY<INT>",
+                DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+            ),
+        ];
+        for (sql_type, expected) in cases {
+            assert_eq!(DataType::from_sql_type(sql_type).unwrap(), expected);
+        }
+
+        assert!(DataType::from_sql_type("geometry").is_err());
+        assert!(DataType::from_sql_type("DECIMAL(a,b)").is_err());
+        assert!(DataType::from_sql_type("TIMESTAMP(12)").is_err());
+        assert!(DataType::from_sql_type("boolean unsigned").is_err());
+    }
+
+    #[test]
+    fn test_sql_type_round_trip() {
+        let data_types = vec![
+            DataType::Boolean,
+            DataType::Int16,
+            DataType::Int32,
+            DataType::Int64,
+            DataType::Float32,
+            DataType::Float64,
+            DataType::Decimal(12, 2),
+            DataType::Utf8,
+            DataType::Date32,
+            DataType::Time64(TimeUnit::Microsecond),
+            DataType::Timestamp(TimeUnit::Millisecond, None),
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".to_string())),
+        ];
+        for dialect in &[SqlDialect::Ansi, SqlDialect::PostgreSql] {
+            for data_type in &data_types {
+                let sql_type = data_type.to_sql_type(*dialect).unwrap();
+                assert_eq!(
+                    &DataType::from_sql_type(&sql_type).unwrap(),
+                    data_type,
+                    "{} in {}",
+                    sql_type,
+                    dialect
+                );
+            }
+        }
+    }
+}
@@ -35,6 +35,7 @@ pub enum ArrowError {
     DivideByZero,
     CsvError(String),
     JsonError(String),
+    AvroError(String),
     IoError(String),
     InvalidArgumentError(String),
     ParquetError(String),
@@ -91,6 +92,13 @@ impl From<serde_json::Error> for ArrowError {
     }
 }
 
+#[cfg(feature = "avro")]
+impl From<avro_rs::Error> for ArrowError {
+    fn from(error: avro_rs::Error) -> Self {
+        ArrowError::AvroError(error.to_string())
+    }
+}
+
 impl<W: Write> From<::std::io::IntoInnerError<W>> for ArrowError {
     fn from(error: std::io::IntoInnerError<W>) -> Self {
         ArrowError::IoError(error.to_string())
@@ -112,6 +120,7 @@ impl Display for ArrowError {
             ArrowError::DivideByZero => write!(f, "Divide by zero error"),
             ArrowError::CsvError(desc) => write!(f, "Csv error: {}", desc),
             ArrowError::JsonError(desc) => write!(f, "Json error: {}", desc),
+            ArrowError::AvroError(desc) => write!(f, "Avro error: {}", desc),
             ArrowError::IoError(desc) => write!(f, "Io error: {}", desc),
             ArrowError::InvalidArgumentError(desc) => {
                 write!(f, "Invalid argument error: {}", desc)
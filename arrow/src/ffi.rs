@@ -77,14 +77,17 @@ To export an array, create an `ArrowArray` using [ArrowArray::try_new].
 */
 
 use std::{
+    collections::{BTreeMap, HashSet},
     ffi::CStr,
     ffi::CString,
-    iter,
     mem::size_of,
     ptr::{self, NonNull},
-    sync::Arc,
+    sync::atomic::{AtomicUsize, Ordering},
+    sync::{Arc, Mutex},
 };
 
+use lazy_static::lazy_static;
+
 use crate::array::ArrayData;
 use crate::buffer::Buffer;
 use crate::datatypes::{DataType, Field, TimeUnit};
@@ -95,8 +98,15 @@ use crate::util::bit_util;
 struct SchemaPrivateData {
     field: Field,
     children_ptr: Box<[*mut FFI_ArrowSchema]>,
+    dictionary: *mut FFI_ArrowSchema,
+    metadata: Option<Vec<u8>>,
 }
 
+// flags of [FFI_ArrowSchema], see <https://arrow.apache.org/docs/format/CDataInterface.html#c.ArrowSchema.flags>
+const ARROW_FLAG_DICTIONARY_ORDERED: i64 = 1;
+const ARROW_FLAG_NULLABLE: i64 = 2;
+const ARROW_FLAG_MAP_KEYS_SORTED: i64 = 4;
+
 /// ABI-compatible struct for `ArrowSchema` from C Data Interface
 /// See <https://arrow.apache.org/docs/format/CDataInterface.html#structure-definitions>
 /// This was created by bindgen
@@ -120,6 +130,10 @@ unsafe extern "C" fn release_schema(schema: *mut FFI_ArrowSchema) {
         return;
     }
     let schema = &mut *schema;
+    if schema.release.is_none() {
+        invalid_release();
+        return;
+    }
 
     // take ownership back to release it.
     CString::from_raw(schema.format as *mut std::os::raw::c_char);
@@ -128,60 +142,111 @@ unsafe extern "C" fn release_schema(schema: *mut FFI_ArrowSchema) {
     for child in private.children_ptr.iter() {
         let _ = Box::from_raw(*child);
     }
+    if !private.dictionary.is_null() {
+        let _ = Box::from_raw(private.dictionary);
+    }
 
     schema.release = None;
 }
 
 impl FFI_ArrowSchema {
     /// create a new [`Ffi_ArrowSchema`]. This fails if the fields' [`DataType`] is not supported.
-    fn try_new(field: Field) -> Result<FFI_ArrowSchema> {
-        let format = to_format(field.data_type())?;
+    pub(crate) fn try_new(field: Field) -> Result<FFI_ArrowSchema> {
+        Self::try_new_with_data(field, None)
+    }
+
+    /// create a new [`Ffi_ArrowSchema`] of an array with `data`, if known.
+    /// The data is required to tell sparse and dense unions apart.
+    fn try_new_with_data(
+        field: Field,
+        data: Option<&ArrayData>,
+    ) -> Result<FFI_ArrowSchema> {
+        let format = to_format(field.data_type(), data)?;
         let name = field.name().clone();
+        let child_data = |i: usize| data.and_then(|data| data.child_data().get(i));
 
         // allocate (and hold) the children
         let children_vec = match field.data_type() {
-            DataType::List(field) => {
-                vec![Box::new(FFI_ArrowSchema::try_new(field.as_ref().clone())?)]
-            }
-            DataType::LargeList(field) => {
-                vec![Box::new(FFI_ArrowSchema::try_new(field.as_ref().clone())?)]
+            DataType::List(field)
+            | DataType::LargeList(field)
+            | DataType::FixedSizeList(field, _)
+            | DataType::Map(field, _) => {
+                vec![Box::new(FFI_ArrowSchema::try_new_with_data(
+                    field.as_ref().clone(),
+                    child_data(0),
+                )?)]
             }
-            DataType::Struct(fields) => fields
+            DataType::Struct(fields) | DataType::Union(fields) => fields
                 .iter()
-                .map(|field| Ok(Box::new(FFI_ArrowSchema::try_new(field.clone())?)))
+                .enumerate()
+                .map(|(i, field)| {
+                    Ok(Box::new(FFI_ArrowSchema::try_new_with_data(
+                        field.clone(),
+                        child_data(i),
+                    )?))
+                })
                 .collect::<Result<Vec<_>>>()?,
             _ => vec![],
         };
+        // the values of a dictionary are described by the dictionary, not a child
+        let dictionary = match field.data_type() {
+            DataType::Dictionary(_, value_type) => {
+                let value_field = Field::new("", value_type.as_ref().clone(), true);
+                Some(Box::new(FFI_ArrowSchema::try_new_with_data(
+                    value_field,
+                    child_data(0),
+                )?))
+            }
+            _ => None,
+        };
         // note: this cannot be done along with the above because the above is fallible and this op leaks.
         let children_ptr = children_vec
             .into_iter()
             .map(Box::into_raw)
             .collect::<Box<_>>();
         let n_children = children_ptr.len() as i64;
+        let dictionary = dictionary.map_or(ptr::null_mut(), Box::into_raw);
 
-        let flags = field.is_nullable() as i64 * 2;
+        let mut flags = 0;
+        if field.is_nullable() {
+            flags |= ARROW_FLAG_NULLABLE;
+        }
+        if field.dict_is_ordered() == Some(true) {
+            flags |= ARROW_FLAG_DICTIONARY_ORDERED;
+        }
+        if let DataType::Map(_, true) = field.data_type() {
+            flags |= ARROW_FLAG_MAP_KEYS_SORTED;
+        }
+
+        let metadata = field.metadata().as_ref().map(metadata_to_bytes);
 
         let mut private = Box::new(SchemaPrivateData {
             field,
             children_ptr,
+            dictionary,
+            metadata,
         });
+        let metadata = private
+            .metadata
+            .as_ref()
+            .map_or(std::ptr::null(), |metadata| metadata.as_ptr() as *const _);
 
         // <https://arrow.apache.org/docs/format/CDataInterface.html#c.ArrowSchema>
         Ok(FFI_ArrowSchema {
             format: CString::new(format).unwrap().into_raw(),
             name: CString::new(name).unwrap().into_raw(),
-            metadata: std::ptr::null_mut(),
+            metadata,
             flags,
             n_children,
             children: private.children_ptr.as_mut_ptr(),
-            dictionary: std::ptr::null_mut(),
+            dictionary,
             release: Some(release_schema),
             private_data: Box::into_raw(private) as *mut ::std::os::raw::c_void,
         })
     }
 
     /// create an empty [FFI_ArrowSchema]
-    fn empty() -> Self {
+    pub(crate) fn empty() -> Self {
         Self {
             format: std::ptr::null_mut(),
             name: std::ptr::null_mut(),
@@ -217,8 +282,44 @@ impl FFI_ArrowSchema {
         unsafe { self.children.add(index).as_ref().unwrap().as_ref().unwrap() }
     }
 
+    /// returns the custom metadata of this schema, if any.
+    pub fn metadata(&self) -> Result<Option<BTreeMap<String, String>>> {
+        if self.metadata.is_null() {
+            return Ok(None);
+        }
+        // safe because the lifetime of `self.metadata` equals `self`, and its
+        // length is given by the length prefixes it contains
+        unsafe {
+            let mut pos = self.metadata as *const u8;
+            let n_entries = read_metadata_i32(&mut pos);
+            let mut metadata = BTreeMap::new();
+            for _ in 0..n_entries {
+                let key = read_metadata_string(&mut pos)?;
+                let value = read_metadata_string(&mut pos)?;
+                metadata.insert(key, value);
+            }
+            Ok(Some(metadata))
+        }
+    }
+
+    /// returns the schema of the values of this dictionary-encoded schema, if any.
+    pub fn dictionary(&self) -> Option<&Self> {
+        // safe because the lifetime of `self.dictionary` equals `self`
+        unsafe { self.dictionary.as_ref() }
+    }
+
     pub fn nullable(&self) -> bool {
-        (self.flags / 2) & 1 == 1
+        self.flags & ARROW_FLAG_NULLABLE != 0
+    }
+
+    /// whether the dictionary of this schema is ordered
+    pub fn dictionary_ordered(&self) -> bool {
+        self.flags & ARROW_FLAG_DICTIONARY_ORDERED != 0
+    }
+
+    /// whether the keys of this map schema are sorted
+    pub fn map_keys_sorted(&self) -> bool {
+        self.flags & ARROW_FLAG_MAP_KEYS_SORTED != 0
     }
 }
 
@@ -228,11 +329,15 @@ impl Drop for FFI_ArrowSchema {
             None => (),
             Some(release) => unsafe { release(self) },
         };
+        debug_assert!(
+            self.release.is_none(),
+            "The release callback of a FFI_ArrowSchema must mark it as released"
+        );
     }
 }
 
 /// See https://arrow.apache.org/docs/format/CDataInterface.html#data-type-description-format-strings
-fn to_field(schema: &FFI_ArrowSchema) -> Result<Field> {
+pub(crate) fn to_field(schema: &FFI_ArrowSchema) -> Result<Field> {
     let data_type = match schema.format() {
         "n" => DataType::Null,
         "b" => DataType::Boolean,
@@ -265,24 +370,106 @@ fn to_field(schema: &FFI_ArrowSchema) -> Result<Field> {
             let child = schema.child(0);
             DataType::LargeList(Box::new(to_field(child)?))
         }
-        "+s" => {
-            let children = (0..schema.n_children as usize)
-                .map(|x| to_field(schema.child(x)))
-                .collect::<Result<Vec<_>>>()?;
-            DataType::Struct(children)
+        "+s" => DataType::Struct(to_children(schema)?),
+        "+m" => {
+            let child = schema.child(0);
+            DataType::Map(Box::new(to_field(child)?), schema.map_keys_sorted())
         }
         other => {
-            return Err(ArrowError::CDataInterface(format!(
-                "The datatype \"{:?}\" is still not supported in Rust implementation",
-                other
-            )))
+            if let Some(size) = other.strip_prefix("+w:") {
+                let size = size.parse::<i32>().map_err(|_| {
+                    ArrowError::CDataInterface(format!(
+                        "The fixed size list format \"{}\" has an invalid size",
+                        other
+                    ))
+                })?;
+                let child = schema.child(0);
+                DataType::FixedSizeList(Box::new(to_field(child)?), size)
+            } else if let Some(type_ids) = other
+                .strip_prefix("+ud:")
+                .or_else(|| other.strip_prefix("+us:"))
+            {
+                // the union type ids are assumed to be the indices of its children
+                let is_sequential = type_ids
+                    .split(',')
+                    .enumerate()
+                    .all(|(i, type_id)| type_id.parse::<usize>() == Ok(i));
+                if !is_sequential
+                    || type_ids.split(',').count() != schema.n_children as usize
+                {
+                    return Err(ArrowError::CDataInterface(format!(
+                        "The union format \"{}\" has type ids that differ from its child indices, which is not supported in Rust implementation",
+                        other
+                    )));
+                }
+                DataType::Union(to_children(schema)?)
+            } else {
+                return Err(ArrowError::CDataInterface(format!(
+                    "The datatype \"{:?}\" is still not supported in Rust implementation",
+                    other
+                )));
+            }
+        }
+    };
+
+    let mut field = match schema.dictionary() {
+        Some(dictionary) => {
+            let value_type = to_field(dictionary)?.data_type().clone();
+            Field::new_dict(
+                schema.name(),
+                DataType::Dictionary(Box::new(data_type), Box::new(value_type)),
+                schema.nullable(),
+                0,
+                schema.dictionary_ordered(),
+            )
         }
+        None => Field::new(schema.name(), data_type, schema.nullable()),
     };
-    Ok(Field::new(schema.name(), data_type, schema.nullable()))
+    field.set_metadata(schema.metadata()?);
+    Ok(field)
+}
+
+/// reads a native-endian `i32` of the metadata of a [FFI_ArrowSchema], advancing `pos`
+unsafe fn read_metadata_i32(pos: &mut *const u8) -> i32 {
+    let value = ptr::read_unaligned(*pos as *const i32);
+    *pos = pos.add(size_of::<i32>());
+    value
+}
+
+/// reads a length-prefixed string of the metadata of a [FFI_ArrowSchema], advancing `pos`
+unsafe fn read_metadata_string(pos: &mut *const u8) -> Result<String> {
+    let len = read_metadata_i32(pos) as usize;
+    let bytes = std::slice::from_raw_parts(*pos, len);
+    *pos = pos.add(len);
+    String::from_utf8(bytes.to_vec()).map_err(|_| {
+        ArrowError::CDataInterface("The external API has a non-utf8 metadata".to_string())
+    })
+}
+
+/// Encodes `metadata` as expected by [FFI_ArrowSchema]: the number of entries followed by
+/// the length and bytes of each key and value, with lengths as native-endian `i32`.
+/// See <https://arrow.apache.org/docs/format/CDataInterface.html#c.ArrowSchema.metadata>
+fn metadata_to_bytes(metadata: &BTreeMap<String, String>) -> Vec<u8> {
+    let mut bytes = (metadata.len() as i32).to_ne_bytes().to_vec();
+    for (key, value) in metadata {
+        for string in &[key, value] {
+            bytes.extend_from_slice(&(string.len() as i32).to_ne_bytes());
+            bytes.extend_from_slice(string.as_bytes());
+        }
+    }
+    bytes
+}
+
+fn to_children(schema: &FFI_ArrowSchema) -> Result<Vec<Field>> {
+    (0..schema.n_children as usize)
+        .map(|x| to_field(schema.child(x)))
+        .collect()
 }
 
 /// See https://arrow.apache.org/docs/format/CDataInterface.html#data-type-description-format-strings
-fn to_format(data_type: &DataType) -> Result<String> {
+///
+/// `data` is only used to tell sparse and dense unions apart.
+fn to_format(data_type: &DataType, data: Option<&ArrayData>) -> Result<String> {
     Ok(match data_type {
         DataType::Null => "n",
         DataType::Boolean => "b",
@@ -310,6 +497,26 @@ fn to_format(data_type: &DataType) -> Result<String> {
         DataType::List(_) => "+l",
         DataType::LargeList(_) => "+L",
         DataType::Struct(_) => "+s",
+        DataType::Map(_, _) => "+m",
+        DataType::FixedSizeList(_, size) => return Ok(format!("+w:{}", size)),
+        DataType::Union(fields) => {
+            let is_dense =
+                match data {
+                    Some(data) => data.buffers().len() == 2,
+                    None => return Err(ArrowError::CDataInterface(
+                        "The mode of a union can only be exported along with its data"
+                            .to_string(),
+                    )),
+                };
+            let type_ids = (0..fields.len())
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            let mode = if is_dense { "d" } else { "s" };
+            return Ok(format!("+u{}:{}", mode, type_ids));
+        }
+        // dictionaries are described by the format of their keys
+        DataType::Dictionary(key_type, _) => return to_format(key_type, None),
         z => {
             return Err(ArrowError::CDataInterface(format!(
                 "The datatype \"{:?}\" is still not supported in Rust implementation",
@@ -324,6 +531,17 @@ fn to_format(data_type: &DataType) -> Result<String> {
 // This is set by the Arrow specification
 fn bit_width(data_type: &DataType, i: usize) -> Result<usize> {
     Ok(match (data_type, i) {
+        // unions have no null buffer: the first buffer holds the type ids, the second the offsets
+        (DataType::Union(_), 0) => size_of::<i8>() * 8,
+        (DataType::Union(_), 1) => size_of::<i32>() * 8,
+        (DataType::Union(_), _) => {
+            return Err(ArrowError::CDataInterface(format!(
+                "The datatype \"{:?}\" expects at most 2 buffers, but requested {}. Please verify that the C data interface is correctly implemented.",
+                data_type, i
+            )))
+        }
+        // dictionaries' buffers are the buffers of their keys
+        (DataType::Dictionary(key_type, _), _) => return bit_width(key_type, i),
         // the null buffer is bit sized
         (_, 0) => 1,
         // primitive types first buffer's size is given by the native types
@@ -357,7 +575,7 @@ fn bit_width(data_type: &DataType, i: usize) -> Result<usize> {
         }
         // Variable-sized binaries: have two buffers.
         // "small": first buffer is i32, second is in bytes
-        (DataType::Utf8, 1) | (DataType::Binary, 1) | (DataType::List(_), 1) => size_of::<i32>() * 8,
+        (DataType::Utf8, 1) | (DataType::Binary, 1) | (DataType::List(_), 1) | (DataType::Map(_, _), 1) => size_of::<i32>() * 8,
         (DataType::Utf8, 2) | (DataType::Binary, 2) | (DataType::List(_), 2) => size_of::<u8>() * 8,
         (DataType::Utf8, _) | (DataType::Binary, _) | (DataType::List(_), _)=> {
             return Err(ArrowError::CDataInterface(format!(
@@ -384,6 +602,11 @@ fn bit_width(data_type: &DataType, i: usize) -> Result<usize> {
     })
 }
 
+/// whether the arrays of `data_type` have a null buffer as their first buffer in the C data interface
+fn has_validity_bitmap(data_type: &DataType) -> bool {
+    !matches!(data_type, DataType::Null | DataType::Union(_))
+}
+
 /// ABI-compatible struct for ArrowArray from C Data Interface
 /// See <https://arrow.apache.org/docs/format/CDataInterface.html#structure-definitions>
 /// This was created by bindgen
@@ -413,6 +636,11 @@ impl Drop for FFI_ArrowArray {
             None => (),
             Some(release) => unsafe { release(self) },
         };
+        debug_assert!(
+            self.release.is_none(),
+            "The release callback of a FFI_ArrowArray must mark it as released"
+        );
+        untrack_import(self);
     }
 }
 
@@ -422,12 +650,19 @@ unsafe extern "C" fn release_array(array: *mut FFI_ArrowArray) {
         return;
     }
     let array = &mut *array;
+    if array.release.is_none() {
+        invalid_release();
+        return;
+    }
 
     // take ownership of `private_data`, therefore dropping it`
     let private = Box::from_raw(array.private_data as *mut PrivateData);
     for child in private.children.iter() {
         let _ = Box::from_raw(*child);
     }
+    if !private.dictionary.is_null() {
+        let _ = Box::from_raw(private.dictionary);
+    }
 
     array.release = None;
 }
@@ -436,6 +671,7 @@ struct PrivateData {
     buffers: Vec<Option<Buffer>>,
     buffers_ptr: Box<[*const std::os::raw::c_void]>,
     children: Box<[*mut FFI_ArrowArray]>,
+    dictionary: *mut FFI_ArrowArray,
 }
 
 impl FFI_ArrowArray {
@@ -443,10 +679,16 @@ impl FFI_ArrowArray {
     /// # Safety
     /// This method releases `buffers`. Consumers of this struct *must* call `release` before
     /// releasing this struct, or contents in `buffers` leak.
-    fn new(data: &ArrayData) -> Self {
-        // * insert the null buffer at the start
+    pub(crate) fn new(data: &ArrayData) -> Self {
+        // * insert the null buffer at the start, unless the type has none (e.g. unions)
         // * make all others `Option<Buffer>`.
-        let buffers = iter::once(data.null_buffer().cloned())
+        let null_buffer = if has_validity_bitmap(data.data_type()) {
+            Some(data.null_buffer().cloned())
+        } else {
+            None
+        };
+        let buffers = null_buffer
+            .into_iter()
             .chain(data.buffers().iter().map(|b| Some(b.clone())))
             .collect::<Vec<_>>();
         let n_buffers = buffers.len() as i64;
@@ -460,11 +702,20 @@ impl FFI_ArrowArray {
             })
             .collect::<Box<[_]>>();
 
-        let children = data
-            .child_data()
-            .iter()
-            .map(|child| Box::into_raw(Box::new(FFI_ArrowArray::new(child))))
-            .collect::<Box<_>>();
+        // the values of a dictionary are exported as its dictionary, not as a child
+        let (children, dictionary) = match data.data_type() {
+            DataType::Dictionary(_, _) => (
+                Box::new([]) as Box<[_]>,
+                Box::into_raw(Box::new(FFI_ArrowArray::new(&data.child_data()[0]))),
+            ),
+            _ => (
+                data.child_data()
+                    .iter()
+                    .map(|child| Box::into_raw(Box::new(FFI_ArrowArray::new(child))))
+                    .collect::<Box<_>>(),
+                std::ptr::null_mut(),
+            ),
+        };
         let n_children = children.len() as i64;
 
         // create the private data owning everything.
@@ -473,6 +724,7 @@ impl FFI_ArrowArray {
             buffers,
             buffers_ptr,
             children,
+            dictionary,
         });
 
         Self {
@@ -483,14 +735,14 @@ impl FFI_ArrowArray {
             n_children,
             buffers: private_data.buffers_ptr.as_mut_ptr(),
             children: private_data.children.as_mut_ptr(),
-            dictionary: std::ptr::null_mut(),
+            dictionary,
             release: Some(release_array),
             private_data: Box::into_raw(private_data) as *mut ::std::os::raw::c_void,
         }
     }
 
     // create an empty `FFI_ArrowArray`, which can be used to import data into
-    fn empty() -> Self {
+    pub(crate) fn empty() -> Self {
         Self {
             length: 0,
             null_count: 0,
@@ -524,6 +776,76 @@ impl FFI_ArrowArray {
     pub fn null_count(&self) -> usize {
         self.null_count as usize
     }
+
+    /// whether the array has been released, or was never populated
+    pub fn is_released(&self) -> bool {
+        self.release.is_none()
+    }
+}
+
+lazy_static! {
+    // the addresses of the imported arrays that were not yet released
+    static ref IMPORTED_ARRAYS: Mutex<HashSet<usize>> = Mutex::new(HashSet::new());
+}
+// the number of bytes of imported buffers referenced by Rust
+static FOREIGN_BYTES_HELD: AtomicUsize = AtomicUsize::new(0);
+// the number of calls to release callbacks of already released structs
+static INVALID_RELEASES: AtomicUsize = AtomicUsize::new(0);
+
+/// Accounting of the memory imported through the C Data Interface, to help
+/// tracking down leaks and invalid releases when exchanging data with other
+/// implementations. See [`foreign_memory_usage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForeignMemoryUsage {
+    /// The number of imported arrays that were not yet released
+    pub arrays_alive: usize,
+    /// The number of bytes of imported buffers referenced by Rust.
+    /// Buffers referenced more than once, e.g. via slices, are counted each time.
+    pub bytes_held: usize,
+    /// The number of times that the release callback of a struct exported by Rust
+    /// was called after the struct was already released
+    pub invalid_releases: usize,
+}
+
+/// Returns the current [`ForeignMemoryUsage`] of this process
+pub fn foreign_memory_usage() -> ForeignMemoryUsage {
+    ForeignMemoryUsage {
+        arrays_alive: IMPORTED_ARRAYS.lock().unwrap().len(),
+        bytes_held: FOREIGN_BYTES_HELD.load(Ordering::SeqCst),
+        invalid_releases: INVALID_RELEASES.load(Ordering::SeqCst),
+    }
+}
+
+/// records that `array` was imported, until it is dropped
+fn track_import(array: &FFI_ArrowArray) {
+    if !array.is_released() {
+        let address = array as *const FFI_ArrowArray as usize;
+        IMPORTED_ARRAYS.lock().unwrap().insert(address);
+    }
+}
+
+fn untrack_import(array: &FFI_ArrowArray) {
+    let address = array as *const FFI_ArrowArray as usize;
+    if let Ok(mut imported) = IMPORTED_ARRAYS.lock() {
+        imported.remove(&address);
+    }
+}
+
+/// records that a buffer of `len` bytes of an imported array is referenced by Rust
+pub(crate) fn track_foreign_bytes(len: usize) {
+    FOREIGN_BYTES_HELD.fetch_add(len, Ordering::SeqCst);
+}
+
+/// records that a buffer of `len` bytes of an imported array is no longer referenced
+pub(crate) fn untrack_foreign_bytes(len: usize) {
+    FOREIGN_BYTES_HELD.fetch_sub(len, Ordering::SeqCst);
+}
+
+// called when the release callback of an already released struct is called, which
+// would otherwise free its private data twice. This cannot panic, as it is called from C,
+// so the call is only counted in `ForeignMemoryUsage::invalid_releases`.
+fn invalid_release() {
+    INVALID_RELEASES.fetch_add(1, Ordering::SeqCst);
 }
 
 /// returns a new buffer corresponding to the index `i` of the FFI array. It may not exist (null pointer).
@@ -570,6 +892,22 @@ fn create_child(
     }
 }
 
+fn create_dictionary(
+    owner: Arc<FFI_ArrowArray>,
+    array: &FFI_ArrowArray,
+    schema: &FFI_ArrowSchema,
+) -> Option<ArrowArrayChild<'static>> {
+    if array.dictionary.is_null() {
+        return None;
+    }
+    assert!(!schema.dictionary.is_null());
+    unsafe {
+        let arr_ptr = &*array.dictionary;
+        let schema_ptr = &*schema.dictionary;
+        Some(ArrowArrayChild::from_raw(arr_ptr, schema_ptr, owner))
+    }
+}
+
 pub trait ArrowArrayRef {
     fn to_data(&self) -> Result<ArrayData> {
         let data_type = self.data_type()?;
@@ -577,15 +915,19 @@ pub trait ArrowArrayRef {
         let offset = self.array().offset();
         let null_count = self.array().null_count();
         let buffers = self.buffers()?;
-        let null_bit_buffer = self.null_bit_buffer();
+        let null_bit_buffer = if has_validity_bitmap(&data_type) {
+            self.null_bit_buffer()
+        } else {
+            None
+        };
 
-        let child_data = (0..self.array().n_children as usize)
-            .map(|i| {
-                let child = self.child(i);
-                child.to_data()
-            })
-            .map(|d| d.unwrap())
-            .collect();
+        // the values of a dictionary are stored as its only child
+        let child_data = match self.dictionary() {
+            Some(dictionary) => vec![dictionary.to_data()?],
+            None => (0..self.array().n_children as usize)
+                .map(|i| self.child(i).to_data())
+                .collect::<Result<_>>()?,
+        };
 
         Ok(ArrayData::new(
             data_type,
@@ -600,18 +942,17 @@ pub trait ArrowArrayRef {
 
     /// returns all buffers, as organized by Rust (i.e. null buffer is skipped)
     fn buffers(&self) -> Result<Vec<Buffer>> {
-        (0..self.array().n_buffers - 1)
+        // skip null buffer, if the data type has one
+        let first = has_validity_bitmap(&self.data_type()?) as usize;
+        (first..self.array().n_buffers as usize)
             .map(|index| {
-                // + 1: skip null buffer
-                let index = (index + 1) as usize;
-
                 let len = self.buffer_len(index)?;
 
                 unsafe { create_buffer(self.owner().clone(), self.array(), index, len) }
                     .ok_or_else(|| {
                         ArrowError::CDataInterface(format!(
                             "The external buffer at position {} is null.",
-                            index - 1
+                            index - first
                         ))
                     })
             })
@@ -624,7 +965,11 @@ pub trait ArrowArrayRef {
     // to fetch offset buffer's len to build the second buffer.
     fn buffer_len(&self, i: usize) -> Result<usize> {
         // Inner type is not important for buffer length.
-        let data_type = &self.data_type()?;
+        // The buffers of a dictionary are the buffers of its keys.
+        let data_type = &match self.data_type()? {
+            DataType::Dictionary(key_type, _) => *key_type,
+            data_type => data_type,
+        };
 
         Ok(match (data_type, i) {
             (DataType::Utf8, 1)
@@ -632,7 +977,8 @@ pub trait ArrowArrayRef {
             | (DataType::Binary, 1)
             | (DataType::LargeBinary, 1)
             | (DataType::List(_), 1)
-            | (DataType::LargeList(_), 1) => {
+            | (DataType::LargeList(_), 1)
+            | (DataType::Map(_, _), 1) => {
                 // the len of the offset buffer (buffer 1) equals length + 1
                 let bits = bit_width(data_type, i)?;
                 debug_assert_eq!(bits % 8, 0);
@@ -686,6 +1032,11 @@ pub trait ArrowArrayRef {
         create_child(self.owner().clone(), self.array(), self.schema(), index)
     }
 
+    /// returns the values of this dictionary array, if it is one.
+    fn dictionary(&self) -> Option<ArrowArrayChild> {
+        create_dictionary(self.owner().clone(), self.array(), self.schema())
+    }
+
     fn owner(&self) -> &Arc<FFI_ArrowArray>;
     fn array(&self) -> &FFI_ArrowArray;
     fn schema(&self) -> &FFI_ArrowSchema;
@@ -769,8 +1120,19 @@ impl ArrowArray {
     #[allow(clippy::too_many_arguments)]
     pub unsafe fn try_new(data: ArrayData) -> Result<Self> {
         let field = Field::new("", data.data_type().clone(), data.null_count() != 0);
+        Self::try_new_with_field(data, field)
+    }
+
+    /// creates a new `ArrowArray` whose schema is described by `field`, which must
+    /// have the data type of `data`. This is used to export to the C Data Interface.
+    /// # Safety
+    /// See safety of [ArrowArray]
+    pub(crate) unsafe fn try_new_with_field(
+        data: ArrayData,
+        field: Field,
+    ) -> Result<Self> {
         let array = Arc::new(FFI_ArrowArray::new(&data));
-        let schema = Arc::new(FFI_ArrowSchema::try_new(field)?);
+        let schema = Arc::new(FFI_ArrowSchema::try_new_with_data(field, Some(&data))?);
 
         Ok(ArrowArray { array, schema })
     }
@@ -790,8 +1152,10 @@ impl ArrowArray {
                     .to_string(),
             ));
         };
+        let array = Arc::from_raw(array as *mut FFI_ArrowArray);
+        track_import(&array);
         Ok(Self {
-            array: Arc::from_raw(array as *mut FFI_ArrowArray),
+            array,
             schema: Arc::from_raw(schema as *mut FFI_ArrowSchema),
         })
     }
@@ -805,6 +1169,15 @@ impl ArrowArray {
         ArrowArray { array, schema }
     }
 
+    /// creates a new [ArrowArray] from structs that were populated by an external call
+    pub(crate) fn from_ffi(
+        array: Arc<FFI_ArrowArray>,
+        schema: Arc<FFI_ArrowSchema>,
+    ) -> Self {
+        track_import(&array);
+        ArrowArray { array, schema }
+    }
+
     /// exports [ArrowArray] to the C Data Interface
     pub fn into_raw(this: ArrowArray) -> (*const FFI_ArrowArray, *const FFI_ArrowSchema) {
         (Arc::into_raw(this.array), Arc::into_raw(this.schema))
@@ -829,12 +1202,14 @@ impl<'a> ArrowArrayChild<'a> {
 mod tests {
     use super::*;
     use crate::array::{
-        make_array, Array, ArrayData, BinaryOffsetSizeTrait, BooleanArray,
-        GenericBinaryArray, GenericListArray, GenericStringArray, Int32Array,
-        OffsetSizeTrait, StringOffsetSizeTrait, Time32MillisecondArray,
+        make_array, Array, ArrayData, ArrayRef, BinaryOffsetSizeTrait, BooleanArray,
+        DictionaryArray, FixedSizeListArray, GenericBinaryArray, GenericListArray,
+        GenericStringArray, Int32Array, Int8Array, MapArray, OffsetSizeTrait,
+        StringArray, StringOffsetSizeTrait, StructArray, Time32MillisecondArray,
+        UInt32Array, UnionArray, UnionBuilder,
     };
     use crate::compute::kernels;
-    use crate::datatypes::Field;
+    use crate::datatypes::{Field, Float64Type, Int32Type, Int8Type, ToByteSlice};
     use std::convert::TryFrom;
     use std::iter::FromIterator;
 
@@ -1079,4 +1454,140 @@ mod tests {
         // (drop/release)
         Ok(())
     }
+
+    fn round_trip(data: &ArrayData) -> Result<ArrayData> {
+        let array = ArrowArray::try_from(data.clone())?;
+        let (array, schema) = ArrowArray::into_raw(array);
+        let array = unsafe { ArrowArray::try_from_raw(array, schema) }?;
+        ArrayData::try_from(array)
+    }
+
+    #[test]
+    fn test_dictionary() -> Result<()> {
+        let array: DictionaryArray<Int8Type> =
+            vec![Some("a"), None, Some("b"), Some("a")]
+                .into_iter()
+                .collect();
+
+        let data = round_trip(array.data())?;
+        assert_eq!(&data, array.data());
+
+        let array = DictionaryArray::<Int8Type>::from(data);
+        assert_eq!(
+            array.keys(),
+            &Int8Array::from(vec![Some(0), None, Some(1), Some(0)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_size_list() -> Result<()> {
+        let value_data = ArrayData::builder(DataType::Int32)
+            .len(6)
+            .add_buffer(Buffer::from_slice_ref(&[0, 1, 2, 3, 4, 5]))
+            .build();
+        let list_data_type = DataType::FixedSizeList(
+            Box::new(Field::new("item", DataType::Int32, false)),
+            2,
+        );
+        let list_data = ArrayData::builder(list_data_type)
+            .len(3)
+            .add_child_data(value_data)
+            .build();
+        let array = FixedSizeListArray::from(list_data);
+
+        let data = round_trip(array.data())?;
+        assert_eq!(&data, array.data());
+        Ok(())
+    }
+
+    #[test]
+    fn test_map() -> Result<()> {
+        let keys = Arc::new(StringArray::from(vec!["a", "b", "c"])) as ArrayRef;
+        let values =
+            Arc::new(UInt32Array::from(vec![Some(1), None, Some(3)])) as ArrayRef;
+        let entries = StructArray::from(vec![
+            (Field::new("keys", DataType::Utf8, false), keys),
+            (Field::new("values", DataType::UInt32, true), values),
+        ]);
+        let map_data_type = DataType::Map(
+            Box::new(Field::new("entries", entries.data_type().clone(), false)),
+            true,
+        );
+        let map_data = ArrayData::builder(map_data_type)
+            .len(2)
+            .add_buffer(Buffer::from(&[0, 2, 3].to_byte_slice()))
+            .add_child_data(entries.data().clone())
+            .build();
+        let array = MapArray::from(map_data);
+
+        let data = round_trip(array.data())?;
+        assert_eq!(&data, array.data());
+        Ok(())
+    }
+
+    fn test_union(mut builder: UnionBuilder) -> Result<()> {
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append::<Float64Type>("b", 3.0).unwrap();
+        builder.append::<Int32Type>("a", 4).unwrap();
+        let array = builder.build().unwrap();
+
+        let data = round_trip(array.data())?;
+        assert_eq!(data.data_type(), array.data_type());
+        assert_eq!(data.buffers(), array.data().buffers());
+
+        let union = UnionArray::from(data);
+        assert_eq!(union.len(), 3);
+        for i in 0..union.len() {
+            assert_eq!(union.type_id(i), array.type_id(i));
+            assert_eq!(&union.value(i), &array.value(i));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_dense_union() -> Result<()> {
+        test_union(UnionBuilder::new_dense(3))
+    }
+
+    #[test]
+    fn test_sparse_union() -> Result<()> {
+        test_union(UnionBuilder::new_sparse(3))
+    }
+
+    #[test]
+    fn test_foreign_memory_usage() -> Result<()> {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let (array, schema) =
+            ArrowArray::into_raw(ArrowArray::try_from(array.data().clone())?);
+        let address = array as usize;
+
+        let imported = unsafe { ArrowArray::try_from_raw(array, schema) }?;
+        assert!(IMPORTED_ARRAYS.lock().unwrap().contains(&address));
+
+        // the imported buffers keep the array alive
+        let data = ArrayData::try_from(imported)?;
+        assert!(IMPORTED_ARRAYS.lock().unwrap().contains(&address));
+        assert!(foreign_memory_usage().bytes_held >= 3 * size_of::<i32>());
+
+        drop(data);
+        assert!(!IMPORTED_ARRAYS.lock().unwrap().contains(&address));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_release() {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let mut array = FFI_ArrowArray::new(array.data());
+        let release = array.release.unwrap();
+        let invalid_releases = foreign_memory_usage().invalid_releases;
+
+        unsafe { release(&mut array) };
+        assert!(array.is_released());
+        assert_eq!(foreign_memory_usage().invalid_releases, invalid_releases);
+
+        // releasing it again does not free its private data twice
+        unsafe { release(&mut array) };
+        assert!(foreign_memory_usage().invalid_releases > invalid_releases);
+    }
 }
@@ -0,0 +1,476 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains declarations to bind to the [C Stream Interface](https://arrow.apache.org/docs/format/CStreamInterface.html).
+//!
+//! This module has two main interfaces:
+//! One interface maps C ABI to native Rust types, i.e. convert c-pointers, c_char, to native rust.
+//! This is handled by [FFI_ArrowArrayStream].
+//!
+//! The second interface is used to import `FFI_ArrowArrayStream` as Rust implementation `RecordBatch` reader.
+//! This is handled by `ArrowArrayStreamReader`.
+//!
+//! ```ignore
+//! # use std::fs::File;
+//! # use std::sync::Arc;
+//! # use arrow::error::Result;
+//! # use arrow::ffi_stream::{export_reader_into_raw, ArrowArrayStreamReader, FFI_ArrowArrayStream};
+//! # use arrow::ipc::reader::FileReader;
+//! # use arrow::record_batch::RecordBatchReader;
+//! # fn main() -> Result<()> {
+//! // create an record batch reader natively
+//! let file = File::open("arrow_file").unwrap();
+//! let reader = Box::new(FileReader::try_new(file).unwrap());
+//!
+//! // export it
+//! let stream = Box::new(FFI_ArrowArrayStream::empty());
+//! let stream_ptr = Box::into_raw(stream) as *mut FFI_ArrowArrayStream;
+//! unsafe { export_reader_into_raw(reader, stream_ptr) };
+//!
+//! // consumed and used by something else...
+//!
+//! // import it
+//! let stream_reader = unsafe { ArrowArrayStreamReader::from_raw(stream_ptr).unwrap() };
+//! let imported_schema = stream_reader.schema();
+//!
+//! let mut produced_batches = vec![];
+//! for batch in stream_reader {
+//!      produced_batches.push(batch.unwrap());
+//! }
+//!
+//! // (drop/release)
+//! unsafe {
+//!   drop(Box::from_raw(stream_ptr));
+//! }
+//! Ok(())
+//! }
+//! ```
+
+use std::{
+    convert::TryFrom,
+    ffi::{CStr, CString},
+    os::raw::{c_char, c_int, c_void},
+    ptr,
+    sync::Arc,
+};
+
+use crate::array::{Array, ArrayData, StructArray};
+use crate::datatypes::{DataType, Field, Schema, SchemaRef};
+use crate::error::{ArrowError, Result};
+use crate::ffi::{to_field, ArrowArray, FFI_ArrowArray, FFI_ArrowSchema};
+use crate::record_batch::{RecordBatch, RecordBatchReader};
+
+const ENOMEM: c_int = 12;
+const EIO: c_int = 5;
+const EINVAL: c_int = 22;
+
+/// ABI-compatible struct for `ArrowArrayStream` from C Stream Interface
+/// See <https://arrow.apache.org/docs/format/CStreamInterface.html#structure-definitions>
+/// This was created by bindgen
+#[repr(C)]
+#[derive(Debug)]
+pub struct FFI_ArrowArrayStream {
+    pub get_schema: Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_ArrowArrayStream,
+            out: *mut FFI_ArrowSchema,
+        ) -> c_int,
+    >,
+    pub get_next: Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_ArrowArrayStream,
+            out: *mut FFI_ArrowArray,
+        ) -> c_int,
+    >,
+    pub get_last_error:
+        Option<unsafe extern "C" fn(arg1: *mut FFI_ArrowArrayStream) -> *const c_char>,
+    pub release: Option<unsafe extern "C" fn(arg1: *mut FFI_ArrowArrayStream)>,
+    pub private_data: *mut c_void,
+}
+
+// callback used to drop [FFI_ArrowArrayStream] when it is exported.
+unsafe extern "C" fn release_stream(stream: *mut FFI_ArrowArrayStream) {
+    if stream.is_null() {
+        return;
+    }
+    let stream = &mut *stream;
+
+    stream.get_schema = None;
+    stream.get_next = None;
+    stream.get_last_error = None;
+
+    // take ownership of `private_data`, therefore dropping it
+    let _ = Box::from_raw(stream.private_data as *mut StreamPrivateData);
+
+    stream.release = None;
+}
+
+struct StreamPrivateData {
+    batch_reader: Box<dyn RecordBatchReader>,
+    last_error: Option<CString>,
+}
+
+// The callback used to get array schema
+unsafe extern "C" fn get_schema(
+    stream: *mut FFI_ArrowArrayStream,
+    schema: *mut FFI_ArrowSchema,
+) -> c_int {
+    ExportedArrayStream { stream }.get_schema(schema)
+}
+
+// The callback used to get next array
+unsafe extern "C" fn get_next(
+    stream: *mut FFI_ArrowArrayStream,
+    array: *mut FFI_ArrowArray,
+) -> c_int {
+    ExportedArrayStream { stream }.get_next(array)
+}
+
+// The callback used to get the error from last operation on the `FFI_ArrowArrayStream`
+unsafe extern "C" fn get_last_error(stream: *mut FFI_ArrowArrayStream) -> *const c_char {
+    let mut exported = ExportedArrayStream { stream };
+    match exported.get_last_error() {
+        None => ptr::null(),
+        Some(error) => error.as_ptr(),
+    }
+}
+
+impl Drop for FFI_ArrowArrayStream {
+    fn drop(&mut self) {
+        match self.release {
+            None => (),
+            Some(release) => unsafe { release(self) },
+        };
+    }
+}
+
+impl FFI_ArrowArrayStream {
+    /// Creates a new [`FFI_ArrowArrayStream`] exporting `batch_reader`.
+    pub fn new(batch_reader: Box<dyn RecordBatchReader>) -> Self {
+        let private_data = Box::new(StreamPrivateData {
+            batch_reader,
+            last_error: None,
+        });
+
+        Self {
+            get_schema: Some(get_schema),
+            get_next: Some(get_next),
+            get_last_error: Some(get_last_error),
+            release: Some(release_stream),
+            private_data: Box::into_raw(private_data) as *mut c_void,
+        }
+    }
+
+    /// Creates an empty [`FFI_ArrowArrayStream`], which can be used to import a stream into
+    pub fn empty() -> Self {
+        Self {
+            get_schema: None,
+            get_next: None,
+            get_last_error: None,
+            release: None,
+            private_data: ptr::null_mut(),
+        }
+    }
+}
+
+struct ExportedArrayStream {
+    stream: *mut FFI_ArrowArrayStream,
+}
+
+impl ExportedArrayStream {
+    fn get_private_data(&mut self) -> &mut StreamPrivateData {
+        unsafe { &mut *((*self.stream).private_data as *mut StreamPrivateData) }
+    }
+
+    fn set_last_error(&mut self, error: &ArrowError) {
+        // the message is only unrepresentable if it contains a nul byte
+        let message = error.to_string().replace('\0', " ");
+        self.get_private_data().last_error = CString::new(message).ok();
+    }
+
+    pub fn get_schema(&mut self, out: *mut FFI_ArrowSchema) -> c_int {
+        let schema = self.get_private_data().batch_reader.schema();
+        let field = Field::new("", DataType::Struct(schema.fields().clone()), false);
+
+        match FFI_ArrowSchema::try_new(field) {
+            Ok(schema) => {
+                unsafe { ptr::write(out, schema) };
+                0
+            }
+            Err(error) => {
+                self.set_last_error(&error);
+                match error {
+                    ArrowError::MemoryError(_) => ENOMEM,
+                    _ => EINVAL,
+                }
+            }
+        }
+    }
+
+    pub fn get_next(&mut self, out: *mut FFI_ArrowArray) -> c_int {
+        match self.get_private_data().batch_reader.next() {
+            None => {
+                // the end of the stream is marked by a released array
+                unsafe { ptr::write(out, FFI_ArrowArray::empty()) };
+                0
+            }
+            Some(Ok(batch)) => {
+                let struct_array = StructArray::from(batch);
+                unsafe { ptr::write(out, FFI_ArrowArray::new(struct_array.data())) };
+                0
+            }
+            Some(Err(error)) => {
+                self.set_last_error(&error);
+                EIO
+            }
+        }
+    }
+
+    pub fn get_last_error(&mut self) -> Option<&CString> {
+        self.get_private_data().last_error.as_ref()
+    }
+}
+
+/// A [`RecordBatchReader`] which imports Arrays from `FFI_ArrowArrayStream`.
+///
+/// Struct used to fetch `RecordBatch` from the C Stream Interface.
+/// Its main responsibility is to expose `RecordBatchReader` functionality
+/// that requires [FFI_ArrowArrayStream].
+#[derive(Debug)]
+pub struct ArrowArrayStreamReader {
+    stream: FFI_ArrowArrayStream,
+    schema: SchemaRef,
+    ffi_schema: Arc<FFI_ArrowSchema>,
+}
+
+impl ArrowArrayStreamReader {
+    /// Creates a new `ArrowArrayStreamReader` from a `FFI_ArrowArrayStream`.
+    /// This is used to import from the C Stream Interface.
+    pub fn try_new(mut stream: FFI_ArrowArrayStream) -> Result<Self> {
+        if stream.release.is_none() {
+            return Err(ArrowError::CDataInterface(
+                "input stream is already released".to_string(),
+            ));
+        }
+        let get_schema = stream.get_schema.ok_or_else(|| {
+            ArrowError::CDataInterface(
+                "input stream has no get_schema callback".to_string(),
+            )
+        })?;
+
+        let mut ffi_schema = FFI_ArrowSchema::empty();
+        let ret_code = unsafe { get_schema(&mut stream, &mut ffi_schema) };
+        if ret_code != 0 {
+            return Err(ArrowError::CDataInterface(format!(
+                "Cannot get schema from input stream. Error code: {}, error: {:?}",
+                ret_code,
+                get_stream_error(&mut stream)
+            )));
+        }
+
+        let schema = match to_field(&ffi_schema)?.data_type() {
+            DataType::Struct(fields) => Schema::new(fields.clone()),
+            other => {
+                return Err(ArrowError::CDataInterface(format!(
+                    "Expected a struct schema from input stream, got {:?}",
+                    other
+                )))
+            }
+        };
+
+        Ok(Self {
+            stream,
+            schema: Arc::new(schema),
+            ffi_schema: Arc::new(ffi_schema),
+        })
+    }
+
+    /// Creates a new `ArrowArrayStreamReader` from a raw pointer of `FFI_ArrowArrayStream`,
+    /// taking ownership of the stream and leaving a released stream in its place.
+    ///
+    /// # Safety
+    /// `raw_stream` must be a valid, non-null pointer to a `FFI_ArrowArrayStream`
+    /// that follows the C Stream Interface.
+    pub unsafe fn from_raw(raw_stream: *mut FFI_ArrowArrayStream) -> Result<Self> {
+        if raw_stream.is_null() {
+            return Err(ArrowError::MemoryError(
+                "The pointer passed to `from_raw` is null".to_string(),
+            ));
+        }
+        let stream = ptr::replace(raw_stream, FFI_ArrowArrayStream::empty());
+        Self::try_new(stream)
+    }
+
+    fn next_batch(&mut self) -> Result<Option<RecordBatch>> {
+        let get_next = self.stream.get_next.ok_or_else(|| {
+            ArrowError::CDataInterface(
+                "input stream has no get_next callback".to_string(),
+            )
+        })?;
+
+        let mut ffi_array = FFI_ArrowArray::empty();
+        let ret_code = unsafe { get_next(&mut self.stream, &mut ffi_array) };
+        if ret_code != 0 {
+            return Err(ArrowError::CDataInterface(format!(
+                "Cannot get next array from input stream. Error code: {}, error: {:?}",
+                ret_code,
+                get_stream_error(&mut self.stream)
+            )));
+        }
+
+        // a released array marks the end of the stream
+        if ffi_array.is_released() {
+            return Ok(None);
+        }
+
+        let array = ArrowArray::from_ffi(Arc::new(ffi_array), self.ffi_schema.clone());
+        let data = ArrayData::try_from(array)?;
+        let struct_array = StructArray::from(data);
+        let columns = (0..struct_array.num_columns())
+            .map(|i| struct_array.column(i).clone())
+            .collect();
+        RecordBatch::try_new(self.schema.clone(), columns).map(Some)
+    }
+}
+
+impl Iterator for ArrowArrayStreamReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_batch().transpose()
+    }
+}
+
+impl RecordBatchReader for ArrowArrayStreamReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+/// Returns the last error reported by `stream`, if any
+fn get_stream_error(stream: &mut FFI_ArrowArrayStream) -> Option<String> {
+    let get_last_error = stream.get_last_error?;
+    let error = unsafe { get_last_error(stream) };
+    if error.is_null() {
+        return None;
+    }
+    Some(
+        unsafe { CStr::from_ptr(error) }
+            .to_string_lossy()
+            .into_owned(),
+    )
+}
+
+/// Exports a record batch reader to raw pointer of the C Stream Interface provided by the consumer.
+///
+/// # Safety
+/// Assumes that the pointer represents valid C Stream Interfaces, both in memory
+/// representation and lifetime via the `release` mechanism.
+pub unsafe fn export_reader_into_raw(
+    reader: Box<dyn RecordBatchReader>,
+    out_stream: *mut FFI_ArrowArrayStream,
+) {
+    let stream = FFI_ArrowArrayStream::new(reader);
+
+    ptr::write(out_stream, stream);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::array::{ArrayRef, Int32Array, StringArray};
+
+    struct TestRecordBatchReader {
+        schema: SchemaRef,
+        iter: Box<dyn Iterator<Item = Result<RecordBatch>>>,
+    }
+
+    impl TestRecordBatchReader {
+        fn new(
+            schema: SchemaRef,
+            iter: Box<dyn Iterator<Item = Result<RecordBatch>>>,
+        ) -> Box<TestRecordBatchReader> {
+            Box::new(TestRecordBatchReader { schema, iter })
+        }
+    }
+
+    impl Iterator for TestRecordBatchReader {
+        type Item = Result<RecordBatch>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.iter.next()
+        }
+    }
+
+    impl RecordBatchReader for TestRecordBatchReader {
+        fn schema(&self) -> SchemaRef {
+            self.schema.clone()
+        }
+    }
+
+    fn test_batch() -> RecordBatch {
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let strings: ArrayRef =
+            Arc::new(StringArray::from(vec![Some("a"), Some("b"), None]));
+        RecordBatch::try_from_iter(vec![("ints", ints), ("strings", strings)]).unwrap()
+    }
+
+    #[test]
+    fn test_stream_round_trip() -> Result<()> {
+        let batch = test_batch();
+        let schema = batch.schema();
+        let iter = Box::new(vec![Ok(batch.clone()), Ok(batch.clone())].into_iter());
+        let reader = TestRecordBatchReader::new(schema.clone(), iter);
+
+        let stream_ptr = Box::into_raw(Box::new(FFI_ArrowArrayStream::empty()));
+        unsafe { export_reader_into_raw(reader, stream_ptr) };
+
+        let stream_reader = unsafe { ArrowArrayStreamReader::from_raw(stream_ptr)? };
+        assert_eq!(stream_reader.schema(), schema);
+
+        let batches = stream_reader.collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches.len(), 2);
+        for read_batch in batches {
+            assert_eq!(read_batch.schema(), schema);
+            assert_eq!(read_batch.columns(), batch.columns());
+        }
+
+        // the stream was moved out of the pointer, which only holds a released stream
+        unsafe {
+            assert!((*stream_ptr).release.is_none());
+            drop(Box::from_raw(stream_ptr));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_error() -> Result<()> {
+        let schema = test_batch().schema();
+        let iter = Box::new(
+            vec![Err(ArrowError::ComputeError("failed".to_string()))].into_iter(),
+        );
+        let reader = TestRecordBatchReader::new(schema, iter);
+
+        let stream = FFI_ArrowArrayStream::new(reader);
+        let mut stream_reader = ArrowArrayStreamReader::try_new(stream)?;
+
+        let error = stream_reader.next().unwrap().unwrap_err();
+        assert!(error.to_string().contains("failed"));
+        Ok(())
+    }
+}
@@ -0,0 +1,215 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Compression of the buffers of IPC message bodies
+//!
+//! Each compressed buffer is prefixed by its uncompressed length, as a 64-bit
+//! little-endian integer. A length of -1 indicates the buffer was left
+//! uncompressed, as compressing it did not reduce its size.
+
+use std::convert::TryFrom;
+
+use crate::buffer::Buffer;
+use crate::error::{ArrowError, Result};
+use crate::ipc;
+
+/// Length prefix indicating the buffer is not compressed
+const LENGTH_NO_COMPRESSED_DATA: i64 = -1;
+/// Size of the uncompressed length prefix of each buffer
+const LENGTH_OF_PREFIX_DATA: usize = 8;
+
+/// The codecs supported for compressing IPC buffers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionCodec {
+    Lz4Frame,
+    Zstd,
+}
+
+impl TryFrom<ipc::CompressionType> for CompressionCodec {
+    type Error = ArrowError;
+
+    fn try_from(compression_type: ipc::CompressionType) -> Result<Self> {
+        match compression_type {
+            ipc::CompressionType::LZ4_FRAME => Ok(CompressionCodec::Lz4Frame),
+            ipc::CompressionType::ZSTD => Ok(CompressionCodec::Zstd),
+            other => Err(ArrowError::NotYetImplemented(format!(
+                "IPC compression type {:?} not supported",
+                other
+            ))),
+        }
+    }
+}
+
+impl From<CompressionCodec> for ipc::CompressionType {
+    fn from(codec: CompressionCodec) -> Self {
+        match codec {
+            CompressionCodec::Lz4Frame => ipc::CompressionType::LZ4_FRAME,
+            CompressionCodec::Zstd => ipc::CompressionType::ZSTD,
+        }
+    }
+}
+
+impl CompressionCodec {
+    /// Compresses `input`, appending the uncompressed length and the compressed
+    /// data to `output`, and returns the number of bytes appended
+    pub(crate) fn compress_to_vec(
+        &self,
+        input: &[u8],
+        output: &mut Vec<u8>,
+    ) -> Result<usize> {
+        if input.is_empty() {
+            return Ok(0);
+        }
+
+        let start = output.len();
+        output.extend_from_slice(&(input.len() as i64).to_le_bytes());
+        self.compress(input, output)?;
+
+        let compressed_len = output.len() - start - LENGTH_OF_PREFIX_DATA;
+        if compressed_len >= input.len() {
+            // compression did not help, write the buffer as is
+            output.truncate(start);
+            output.extend_from_slice(&LENGTH_NO_COMPRESSED_DATA.to_le_bytes());
+            output.extend_from_slice(input);
+        }
+        Ok(output.len() - start)
+    }
+
+    /// Decompresses a buffer written by [`CompressionCodec::compress_to_vec`]
+    pub(crate) fn decompress_to_buffer(&self, input: &[u8]) -> Result<Buffer> {
+        if input.is_empty() {
+            return Ok(Buffer::from(input));
+        }
+        if input.len() < LENGTH_OF_PREFIX_DATA {
+            return Err(ArrowError::IoError(
+                "Compressed IPC buffer is too short to contain its length".to_string(),
+            ));
+        }
+
+        let mut prefix = [0u8; LENGTH_OF_PREFIX_DATA];
+        prefix.copy_from_slice(&input[..LENGTH_OF_PREFIX_DATA]);
+        let uncompressed_len = i64::from_le_bytes(prefix);
+        let data = &input[LENGTH_OF_PREFIX_DATA..];
+
+        if uncompressed_len == LENGTH_NO_COMPRESSED_DATA {
+            return Ok(Buffer::from(data));
+        }
+        if uncompressed_len < 0 {
+            return Err(ArrowError::IoError(format!(
+                "Invalid uncompressed length {} of compressed IPC buffer",
+                uncompressed_len
+            )));
+        }
+
+        let mut output = Vec::with_capacity(uncompressed_len as usize);
+        self.decompress(data, &mut output)?;
+        if output.len() != uncompressed_len as usize {
+            return Err(ArrowError::IoError(format!(
+                "Expected {} bytes after decompressing IPC buffer, got {}",
+                uncompressed_len,
+                output.len()
+            )));
+        }
+        Ok(Buffer::from(output))
+    }
+
+    #[cfg(feature = "ipc_compression")]
+    fn compress(&self, input: &[u8], output: &mut Vec<u8>) -> Result<()> {
+        use std::io::Write;
+
+        match self {
+            CompressionCodec::Lz4Frame => {
+                let mut encoder = lz4::EncoderBuilder::new().build(output)?;
+                encoder.write_all(input)?;
+                let (_, result) = encoder.finish();
+                result?;
+            }
+            CompressionCodec::Zstd => {
+                let mut encoder = zstd::Encoder::new(output, 0)?;
+                encoder.write_all(input)?;
+                encoder.finish()?;
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "ipc_compression")]
+    fn decompress(&self, input: &[u8], output: &mut Vec<u8>) -> Result<()> {
+        use std::io::Read;
+
+        match self {
+            CompressionCodec::Lz4Frame => {
+                let mut decoder = lz4::Decoder::new(input)?;
+                decoder.read_to_end(output)?;
+            }
+            CompressionCodec::Zstd => {
+                let mut decoder = zstd::Decoder::new(input)?;
+                decoder.read_to_end(output)?;
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "ipc_compression"))]
+    fn compress(&self, _input: &[u8], _output: &mut Vec<u8>) -> Result<()> {
+        Err(compression_disabled())
+    }
+
+    #[cfg(not(feature = "ipc_compression"))]
+    fn decompress(&self, _input: &[u8], _output: &mut Vec<u8>) -> Result<()> {
+        Err(compression_disabled())
+    }
+}
+
+#[cfg(not(feature = "ipc_compression"))]
+pub(crate) fn compression_disabled() -> ArrowError {
+    ArrowError::InvalidArgumentError(
+        "IPC compression requires the ipc_compression feature".to_string(),
+    )
+}
+
+#[cfg(all(test, feature = "ipc_compression"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let input: Vec<u8> = (0..1000).map(|i| (i % 7) as u8).collect();
+        for codec in &[CompressionCodec::Lz4Frame, CompressionCodec::Zstd] {
+            let mut output = vec![1, 2, 3];
+            let written = codec.compress_to_vec(&input, &mut output).unwrap();
+            assert_eq!(written, output.len() - 3);
+            assert!(written < input.len());
+
+            let decompressed = codec.decompress_to_buffer(&output[3..]).unwrap();
+            assert_eq!(decompressed.as_slice(), input.as_slice());
+        }
+    }
+
+    #[test]
+    fn test_incompressible() {
+        let input = vec![42u8];
+        let codec = CompressionCodec::Zstd;
+        let mut output = vec![];
+        codec.compress_to_vec(&input, &mut output).unwrap();
+        assert_eq!(&output[..8], &(-1i64).to_le_bytes());
+        assert_eq!(&output[8..], input.as_slice());
+
+        let decompressed = codec.decompress_to_buffer(&output).unwrap();
+        assert_eq!(decompressed.as_slice(), input.as_slice());
+    }
+}
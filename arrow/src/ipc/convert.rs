@@ -154,6 +154,46 @@ pub fn schema_from_bytes(bytes: &[u8]) -> Result<Schema> {
     }
 }
 
+/// Get the Arrow data type of the elements of a flatbuffer Tensor table
+pub(crate) fn get_tensor_data_type(tensor: ipc::Tensor) -> Result<DataType> {
+    match tensor.type_type() {
+        ipc::Type::Bool => Ok(DataType::Boolean),
+        ipc::Type::Int => {
+            let int = tensor.type_as_int().unwrap();
+            match (int.bitWidth(), int.is_signed()) {
+                (8, true) => Ok(DataType::Int8),
+                (8, false) => Ok(DataType::UInt8),
+                (16, true) => Ok(DataType::Int16),
+                (16, false) => Ok(DataType::UInt16),
+                (32, true) => Ok(DataType::Int32),
+                (32, false) => Ok(DataType::UInt32),
+                (64, true) => Ok(DataType::Int64),
+                (64, false) => Ok(DataType::UInt64),
+                z => Err(ArrowError::IoError(format!(
+                    "Int type with bit width of {} and signed of {} not supported",
+                    z.0, z.1
+                ))),
+            }
+        }
+        ipc::Type::FloatingPoint => {
+            let float = tensor.type_as_floating_point().unwrap();
+            match float.precision() {
+                ipc::Precision::HALF => Ok(DataType::Float16),
+                ipc::Precision::SINGLE => Ok(DataType::Float32),
+                ipc::Precision::DOUBLE => Ok(DataType::Float64),
+                z => Err(ArrowError::IoError(format!(
+                    "FloatingPoint type with precision of {:?} not supported",
+                    z
+                ))),
+            }
+        }
+        z => Err(ArrowError::IoError(format!(
+            "Tensor with elements of type {:?} not supported",
+            z
+        ))),
+    }
+}
+
 /// Get the Arrow data type from the flatbuffer Field table
 pub(crate) fn get_data_type(field: ipc::Field, may_be_dictionary: bool) -> DataType {
     if let Some(dictionary) = field.dictionary() {
@@ -308,6 +348,14 @@ pub(crate) fn get_data_type(field: ipc::Field, may_be_dictionary: bool) -> DataT
 
             DataType::Struct(fields)
         }
+        ipc::Type::Map => {
+            let map = field.type_as_map().unwrap();
+            let children = field.children().unwrap();
+            if children.len() != 1 {
+                panic!("expect a map to have one child")
+            }
+            DataType::Map(Box::new(children.get(0).into()), map.keysSorted())
+        }
         ipc::Type::Decimal => {
             let fsb = field.type_as_decimal().unwrap();
             DataType::Decimal(fsb.precision() as usize, fsb.scale() as usize)
@@ -602,21 +650,7 @@ pub(crate) fn get_fb_field_type<'a>(
             // struct's fields are children
             let mut children = vec![];
             for field in fields {
-                let inner_types =
-                    get_fb_field_type(field.data_type(), field.is_nullable(), fbb);
-                let field_name = fbb.create_string(field.name());
-                children.push(ipc::Field::create(
-                    fbb,
-                    &ipc::FieldArgs {
-                        name: Some(field_name),
-                        nullable: field.is_nullable(),
-                        type_type: inner_types.type_type,
-                        type_: Some(inner_types.type_),
-                        dictionary: None,
-                        children: inner_types.children,
-                        custom_metadata: None,
-                    },
-                ));
+                children.push(build_field(fbb, field));
             }
             FBFieldType {
                 type_type: ipc::Type::Struct_,
@@ -624,6 +658,16 @@ pub(crate) fn get_fb_field_type<'a>(
                 children: Some(fbb.create_vector(&children[..])),
             }
         }
+        Map(ref map_field, keys_sorted) => {
+            let child = build_field(fbb, map_field);
+            let mut builder = ipc::MapBuilder::new(fbb);
+            builder.add_keysSorted(*keys_sorted);
+            FBFieldType {
+                type_type: ipc::Type::Map,
+                type_: builder.finish().as_union_value(),
+                children: Some(fbb.create_vector(&[child])),
+            }
+        }
         Dictionary(_, value_type) => {
             // In this library, the dictionary "type" is a logical construct. Here we
             // pass through to the value type, as we've already captured the index
@@ -801,6 +845,20 @@ mod tests {
                     false,
                 ),
                 Field::new("struct<>", DataType::Struct(vec![]), true),
+                Field::new(
+                    "struct<dictionary<int32, utf8>>",
+                    DataType::Struct(vec![Field::new_dict(
+                        "dictionary<int32, utf8>",
+                        DataType::Dictionary(
+                            Box::new(DataType::Int32),
+                            Box::new(DataType::Utf8),
+                        ),
+                        false,
+                        124,
+                        false,
+                    )]),
+                    true,
+                ),
                 Field::new_dict(
                     "dictionary<int32, utf8>",
                     DataType::Dictionary(
@@ -20,18 +20,23 @@
 //! The `FileReader` and `StreamReader` have similar interfaces,
 //! however the `FileReader` expects a reader that supports `Seek`ing
 
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
 use std::io::{BufReader, Read, Seek, SeekFrom};
 use std::sync::Arc;
 
 use crate::array::*;
 use crate::buffer::Buffer;
-use crate::compute::cast;
-use crate::datatypes::{DataType, Field, IntervalUnit, Schema, SchemaRef};
+use crate::compute::{cast, concat};
+use crate::datatypes::{
+    ArrowPrimitiveType, DataType, Field, IntervalUnit, Schema, SchemaRef,
+};
 use crate::error::{ArrowError, Result};
 use crate::ipc;
 use crate::record_batch::{RecordBatch, RecordBatchReader};
+use crate::tensor::Tensor;
 
+use ipc::compression::CompressionCodec;
 use ipc::CONTINUATION_MARKER;
 use DataType::*;
 
@@ -52,12 +57,14 @@ fn read_buffer(buf: &ipc::Buffer, a_data: &[u8]) -> Buffer {
 ///     - check if the bit width of non-64-bit numbers is 64, and
 ///     - read the buffer as 64-bit (signed integer or float), and
 ///     - cast the 64-bit array to the appropriate data type
+///
+/// `dictionary` holds the dictionary values of the top-level field being read, if any.
 fn create_array(
     nodes: &[ipc::FieldNode],
     data_type: &DataType,
     data: &[u8],
     buffers: &[ipc::Buffer],
-    dictionaries: &[Option<ArrayRef>],
+    dictionary: Option<&ArrayRef>,
     mut node_index: usize,
     mut buffer_index: usize,
 ) -> (ArrayRef, usize, usize) {
@@ -89,7 +96,7 @@ fn create_array(
             buffer_index += 2;
             array
         }
-        List(ref list_field) | LargeList(ref list_field) => {
+        List(ref list_field) | LargeList(ref list_field) | Map(ref list_field, _) => {
             let list_node = &nodes[node_index];
             let list_buffers: Vec<Buffer> = buffers[buffer_index..buffer_index + 2]
                 .iter()
@@ -102,7 +109,7 @@ fn create_array(
                 list_field.data_type(),
                 data,
                 buffers,
-                dictionaries,
+                dictionary,
                 node_index,
                 buffer_index,
             );
@@ -124,7 +131,7 @@ fn create_array(
                 list_field.data_type(),
                 data,
                 buffers,
-                dictionaries,
+                dictionary,
                 node_index,
                 buffer_index,
             );
@@ -149,7 +156,7 @@ fn create_array(
                     struct_field.data_type(),
                     data,
                     buffers,
-                    dictionaries,
+                    dictionary,
                     node_index,
                     buffer_index,
                 );
@@ -173,7 +180,8 @@ fn create_array(
                 .iter()
                 .map(|buf| read_buffer(buf, data))
                 .collect();
-            let value_array = dictionaries[node_index].clone().unwrap();
+            // dictionaries are looked up by field, as nested fields take several nodes
+            let value_array = dictionary.unwrap().clone();
             node_index += 1;
             buffer_index += 2;
 
@@ -344,7 +352,7 @@ fn create_list_array(
     buffers: &[Buffer],
     child_array: ArrayRef,
 ) -> ArrayRef {
-    if let DataType::List(_) = *data_type {
+    if let DataType::List(_) | DataType::Map(_, _) = *data_type {
         let null_count = field_node.null_count() as usize;
         let mut builder = ArrayData::builder(data_type.clone())
             .len(field_node.length() as usize)
@@ -419,19 +427,27 @@ pub fn read_record_batch(
     let field_nodes = batch.nodes().ok_or_else(|| {
         ArrowError::IoError("Unable to get field nodes from IPC RecordBatch".to_string())
     })?;
+    let decompressed;
+    let (buf, buffers): (&[u8], &[ipc::Buffer]) = match batch.compression() {
+        Some(compression) => {
+            decompressed = decompress_buffers(buf, buffers, compression)?;
+            (&decompressed.0, &decompressed.1)
+        }
+        None => (buf, buffers),
+    };
     // keep track of buffer and node index, the functions that create arrays mutate these
     let mut buffer_index = 0;
     let mut node_index = 0;
     let mut arrays = vec![];
 
     // keep track of index as lists require more than one node
-    for field in schema.fields() {
+    for (i, field) in schema.fields().iter().enumerate() {
         let triple = create_array(
             field_nodes,
             field.data_type(),
             &buf,
             buffers,
-            dictionaries,
+            dictionaries.get(i).and_then(Option::as_ref),
             node_index,
             buffer_index,
         );
@@ -443,20 +459,80 @@ pub fn read_record_batch(
     RecordBatch::try_new(schema, arrays)
 }
 
+/// Decompresses the body buffers of a compressed batch into a contiguous vector,
+/// returning it along with the location of each uncompressed buffer
+fn decompress_buffers(
+    buf: &[u8],
+    buffers: &[ipc::Buffer],
+    compression: ipc::BodyCompression,
+) -> Result<(Vec<u8>, Vec<ipc::Buffer>)> {
+    if compression.method() != ipc::BodyCompressionMethod::BUFFER {
+        return Err(ArrowError::NotYetImplemented(format!(
+            "IPC body compression method {:?} not supported",
+            compression.method()
+        )));
+    }
+    let codec = CompressionCodec::try_from(compression.codec())?;
+
+    let mut data = Vec::with_capacity(buf.len());
+    let mut decompressed_buffers = Vec::with_capacity(buffers.len());
+    for buffer in buffers {
+        let start_offset = buffer.offset() as usize;
+        let end_offset = start_offset + buffer.length() as usize;
+        let decompressed = codec.decompress_to_buffer(&buf[start_offset..end_offset])?;
+        decompressed_buffers.push(ipc::Buffer::new(
+            data.len() as i64,
+            decompressed.len() as i64,
+        ));
+        data.extend_from_slice(decompressed.as_slice());
+    }
+    Ok((data, decompressed_buffers))
+}
+
+/// Creates a tensor from the `ipc::Tensor` header of a Tensor message and the body of the message.
+/// The dimension names of the tensor are borrowed from the message.
+pub fn read_tensor<'a, T: ArrowPrimitiveType>(
+    buf: &[u8],
+    tensor: ipc::Tensor<'a>,
+) -> Result<Tensor<'a, T>> {
+    let data_type = ipc::convert::get_tensor_data_type(tensor)?;
+    if data_type != T::DATA_TYPE {
+        return Err(ArrowError::IoError(format!(
+            "Expected a tensor of {:?}, found a tensor of {:?}",
+            T::DATA_TYPE,
+            data_type
+        )));
+    }
+
+    let dims = tensor.shape();
+    // a tensor without dimensions holds a single element
+    let (shape, strides, names) = if dims.is_empty() {
+        (None, None, None)
+    } else {
+        let shape = dims.iter().map(|dim| dim.size_() as usize).collect();
+        let strides = tensor
+            .strides()
+            .map(|strides| strides.iter().map(|stride| stride as usize).collect());
+        // names are only kept when all dimensions are named
+        let names = dims.iter().map(|dim| dim.name()).collect();
+        (Some(shape), strides, names)
+    };
+
+    let buffer = read_buffer(tensor.data(), buf);
+    Tensor::try_new(buffer, shape, strides, names)
+}
+
 /// Read the dictionary from the buffer and provided metadata,
 /// updating the `dictionaries_by_field` with the resulting dictionary
+///
+/// The values of a delta dictionary batch are appended to the dictionary read before
+/// with the same id.
 pub fn read_dictionary(
     buf: &[u8],
     batch: ipc::DictionaryBatch,
     schema: &Schema,
     dictionaries_by_field: &mut [Option<ArrayRef>],
 ) -> Result<()> {
-    if batch.isDelta() {
-        return Err(ArrowError::IoError(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
-
     let id = batch.id();
     let fields_using_this_dictionary = schema.fields_with_dict_id(id);
     let first_field = fields_using_this_dictionary.first().ok_or_else(|| {
@@ -488,6 +564,23 @@ pub fn read_dictionary(
         ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
     })?;
 
+    let dictionary_values = if batch.isDelta() {
+        let existing = schema
+            .fields()
+            .iter()
+            .position(|field| field.dict_id() == Some(id))
+            .and_then(|i| dictionaries_by_field[i].as_ref())
+            .ok_or_else(|| {
+                ArrowError::IoError(format!(
+                    "delta dictionary batch of id {} before its dictionary",
+                    id
+                ))
+            })?;
+        concat(&[existing.as_ref(), dictionary_values.as_ref()])?
+    } else {
+        dictionary_values
+    };
+
     // for all fields with this dictionary id, update the dictionaries vector
     // in the reader. Note that a dictionary batch may be shared between many fields.
     // We don't currently record the isOrdered field. This could be general
@@ -502,6 +595,31 @@ pub fn read_dictionary(
     Ok(())
 }
 
+/// Splits `batch` into zero-copy slices of about `batch_byte_size` bytes, which is
+/// estimated from the size of the buffers of its columns, each of at least one row.
+///
+/// The batches of IPC files and streams are read as they were written, so a batch is still
+/// read whole, but the batches returned to the caller are bounded.
+fn split_batch(
+    batch: RecordBatch,
+    batch_byte_size: Option<usize>,
+) -> VecDeque<RecordBatch> {
+    let num_rows = batch.num_rows();
+    let bytes: usize = batch
+        .columns()
+        .iter()
+        .map(|c| c.get_buffer_memory_size())
+        .sum();
+    let rows_per_batch = match batch_byte_size {
+        Some(size) if bytes > size && num_rows > 1 => (num_rows * size / bytes).max(1),
+        _ => return vec![batch].into(),
+    };
+    (0..num_rows)
+        .step_by(rows_per_batch)
+        .map(|offset| batch.slice(offset, rows_per_batch.min(num_rows - offset)))
+        .collect()
+}
+
 /// Arrow File reader
 pub struct FileReader<R: Read + Seek> {
     /// Buffered file reader that supports reading and seeking
@@ -528,6 +646,12 @@ pub struct FileReader<R: Read + Seek> {
 
     /// Metadata version
     metadata_version: ipc::MetadataVersion,
+
+    /// Optional approximate size in bytes of the batches
+    batch_byte_size: Option<usize>,
+
+    /// The slices of the last batch read that have not been returned
+    pending: VecDeque<RecordBatch>,
 }
 
 impl<R: Read + Seek> FileReader<R> {
@@ -630,9 +754,20 @@ impl<R: Read + Seek> FileReader<R> {
             total_blocks,
             dictionaries_by_field,
             metadata_version: footer.version(),
+            batch_byte_size: None,
+            pending: VecDeque::new(),
         })
     }
 
+    /// Set the approximate size in bytes of the batches returned by the reader.
+    ///
+    /// The batches of the file are read as they were written, and those whose buffers are
+    /// larger than `batch_byte_size` are returned as zero-copy slices of fewer rows.
+    pub fn with_batch_byte_size(mut self, batch_byte_size: usize) -> Self {
+        self.batch_byte_size = Some(batch_byte_size);
+        self
+    }
+
     /// Return the number of batches in the file
     pub fn num_batches(&self) -> usize {
         self.total_blocks
@@ -654,6 +789,7 @@ impl<R: Read + Seek> FileReader<R> {
             )))
         } else {
             self.current_block = index;
+            self.pending.clear();
             Ok(())
         }
     }
@@ -726,9 +862,16 @@ impl<R: Read + Seek> Iterator for FileReader<R> {
     type Item = Result<RecordBatch>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if let Some(batch) = self.pending.pop_front() {
+            return Some(Ok(batch));
+        }
         // get current block
         if self.current_block < self.total_blocks {
-            self.maybe_next().transpose()
+            let batch = self.maybe_next().transpose()?;
+            Some(batch.map(|batch| {
+                self.pending = split_batch(batch, self.batch_byte_size);
+                self.pending.pop_front().unwrap()
+            }))
         } else {
             None
         }
@@ -758,6 +901,19 @@ pub struct StreamReader<R: Read> {
     ///
     /// This value is set to `true` the first time the reader's `next()` returns `None`.
     finished: bool,
+
+    /// Optional approximate size in bytes of the batches
+    batch_byte_size: Option<usize>,
+
+    /// The slices of the last batch read that have not been returned
+    pending: VecDeque<RecordBatch>,
+
+    /// Whether to read a stream that follows the end of the current stream
+    concatenated_streams: bool,
+
+    /// Whether the current stream has ended, so that the next message is the schema of
+    /// a following stream
+    stream_ended: bool,
 }
 
 impl<R: Read> StreamReader<R> {
@@ -786,11 +942,7 @@ impl<R: Read> StreamReader<R> {
         let message = ipc::root_as_message(meta_buffer.as_slice()).map_err(|err| {
             ArrowError::IoError(format!("Unable to get root as message: {:?}", err))
         })?;
-        // message header is a Schema, so read it
-        let ipc_schema: ipc::Schema = message.header_as_schema().ok_or_else(|| {
-            ArrowError::IoError("Unable to read IPC message as schema".to_string())
-        })?;
-        let schema = ipc::convert::fb_to_schema(ipc_schema);
+        let schema = schema_from_message(&message)?;
 
         // Create an array of optional dictionary value arrays, one per field.
         let dictionaries_by_field = vec![None; schema.fields().len()];
@@ -800,9 +952,33 @@ impl<R: Read> StreamReader<R> {
             schema: Arc::new(schema),
             finished: false,
             dictionaries_by_field,
+            batch_byte_size: None,
+            pending: VecDeque::new(),
+            concatenated_streams: false,
+            stream_ended: false,
         })
     }
 
+    /// Set whether to continue reading after the end of the stream, if another stream
+    /// follows it, e.g. when several writers append their streams to the same pipe or
+    /// file.
+    ///
+    /// The batches of each stream are read with its own schema and dictionaries, and
+    /// [StreamReader::schema] returns the schema of the stream that is being read.
+    pub fn with_concatenated_streams(mut self, concatenated_streams: bool) -> Self {
+        self.concatenated_streams = concatenated_streams;
+        self
+    }
+
+    /// Set the approximate size in bytes of the batches returned by the reader.
+    ///
+    /// The batches of the stream are read as they were written, and those whose buffers are
+    /// larger than `batch_byte_size` are returned as zero-copy slices of fewer rows.
+    pub fn with_batch_byte_size(mut self, batch_byte_size: usize) -> Self {
+        self.batch_byte_size = Some(batch_byte_size);
+        self
+    }
+
     /// Return the schema of the stream
     pub fn schema(&self) -> SchemaRef {
         self.schema.clone()
@@ -845,6 +1021,11 @@ impl<R: Read> StreamReader<R> {
         };
 
         if meta_len == 0 {
+            if self.concatenated_streams && !self.stream_ended {
+                // the stream has ended, read the schema of the next one, if any
+                self.stream_ended = true;
+                return self.maybe_next();
+            }
             // the stream has ended, mark the reader as finished
             self.finished = true;
             return Ok(None);
@@ -858,6 +1039,15 @@ impl<R: Read> StreamReader<R> {
             ArrowError::IoError(format!("Unable to get root as message: {:?}", err))
         })?;
 
+        if self.stream_ended {
+            // a following stream starts with its schema
+            let schema = schema_from_message(&message)?;
+            self.dictionaries_by_field = vec![None; schema.fields().len()];
+            self.schema = Arc::new(schema);
+            self.stream_ended = false;
+            return self.maybe_next();
+        }
+
         match message.header_type() {
             ipc::MessageHeader::Schema => Err(ArrowError::IoError(
                 "Not expecting a schema when messages are read".to_string(),
@@ -901,11 +1091,27 @@ impl<R: Read> StreamReader<R> {
     }
 }
 
+/// Reads the schema of a schema message.
+fn schema_from_message(message: &ipc::Message) -> Result<Schema> {
+    // message header is a Schema, so read it
+    let ipc_schema: ipc::Schema = message.header_as_schema().ok_or_else(|| {
+        ArrowError::IoError("Unable to read IPC message as schema".to_string())
+    })?;
+    Ok(ipc::convert::fb_to_schema(ipc_schema))
+}
+
 impl<R: Read> Iterator for StreamReader<R> {
     type Item = Result<RecordBatch>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.maybe_next().transpose()
+        if let Some(batch) = self.pending.pop_front() {
+            return Some(Ok(batch));
+        }
+        let batch = self.maybe_next().transpose()?;
+        Some(batch.map(|batch| {
+            self.pending = split_batch(batch, self.batch_byte_size);
+            self.pending.pop_front().unwrap()
+        }))
     }
 }
 
@@ -923,6 +1129,7 @@ mod tests {
 
     use flate2::read::GzDecoder;
 
+    use crate::datatypes::Int32Type;
     use crate::util::integration_util::*;
 
     #[test]
@@ -1157,4 +1364,89 @@ mod tests {
         let arrow_json: ArrowJson = serde_json::from_str(&s).unwrap();
         arrow_json
     }
+
+    #[test]
+    fn test_batch_byte_size() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, false)]));
+        let values = Int64Array::from((0..1000).collect::<Vec<i64>>());
+        let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(values)]).unwrap();
+
+        let mut file = tempfile::tempfile().unwrap();
+        {
+            let mut writer =
+                crate::ipc::writer::FileWriter::try_new(&mut file, &schema).unwrap();
+            writer.write(&batch).unwrap();
+            let values = Int64Array::from((0..10).collect::<Vec<i64>>());
+            let small =
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(values)]).unwrap();
+            writer.write(&small).unwrap();
+            writer.finish().unwrap();
+        }
+        file.seek(SeekFrom::Start(0)).unwrap();
+        let reader = FileReader::try_new(file)
+            .unwrap()
+            .with_batch_byte_size(2048);
+        let rows = reader.map(|b| b.unwrap().num_rows()).collect::<Vec<_>>();
+        assert_eq!(rows.iter().sum::<usize>(), 1010);
+        assert!(rows[..rows.len() - 1].iter().all(|r| *r < 1000));
+        assert_eq!(rows.last(), Some(&10));
+
+        let mut stream = vec![];
+        {
+            let mut writer =
+                crate::ipc::writer::StreamWriter::try_new(&mut stream, &schema).unwrap();
+            writer.write(&batch).unwrap();
+            writer.finish().unwrap();
+        }
+        let reader = StreamReader::try_new(std::io::Cursor::new(stream))
+            .unwrap()
+            .with_batch_byte_size(2048);
+        let batches = reader.map(|b| b.unwrap()).collect::<Vec<_>>();
+        assert!(batches.len() > 1);
+        let values = batches
+            .iter()
+            .flat_map(|b| {
+                let a = b.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+                a.values().to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(values, (0..1000).collect::<Vec<i64>>());
+    }
+
+    #[test]
+    fn test_concatenated_streams() {
+        let a: DictionaryArray<Int32Type> = vec!["x", "y", "x"].into_iter().collect();
+        let first =
+            RecordBatch::try_from_iter(vec![("a", Arc::new(a) as ArrayRef)]).unwrap();
+        let b: DictionaryArray<Int32Type> = vec!["z"].into_iter().collect();
+        let c = Int64Array::from(vec![7]);
+        let second = RecordBatch::try_from_iter(vec![
+            ("b", Arc::new(b) as ArrayRef),
+            ("c", Arc::new(c) as ArrayRef),
+        ])
+        .unwrap();
+
+        let mut stream = vec![];
+        for batch in &[&first, &second] {
+            let mut writer =
+                crate::ipc::writer::StreamWriter::try_new(&mut stream, &batch.schema())
+                    .unwrap();
+            writer.write(batch).unwrap();
+            writer.finish().unwrap();
+        }
+
+        // by default, the reader stops at the end of the first stream
+        let reader = StreamReader::try_new(std::io::Cursor::new(stream.clone())).unwrap();
+        assert_eq!(reader.count(), 1);
+
+        let mut reader = StreamReader::try_new(std::io::Cursor::new(stream))
+            .unwrap()
+            .with_concatenated_streams(true);
+        assert_eq!(reader.next().unwrap().unwrap().columns(), first.columns());
+        assert_eq!(reader.schema().fields().len(), 1);
+        assert_eq!(reader.next().unwrap().unwrap().columns(), second.columns());
+        assert_eq!(reader.schema().field(1).name(), "c");
+        assert!(reader.next().is_none());
+        assert!(reader.is_finished());
+    }
 }
@@ -20,19 +20,24 @@
 //! The `FileWriter` and `StreamWriter` have similar interfaces,
 //! however the `FileWriter` expects a reader that supports `Seek`ing
 
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::io::{BufWriter, Write};
 
 use flatbuffers::FlatBufferBuilder;
 
-use crate::array::{ArrayData, ArrayRef};
+use crate::array::{make_array, ArrayData, ArrayRef, OffsetSizeTrait};
 use crate::buffer::{Buffer, MutableBuffer};
+use crate::compute::concat;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::ipc;
 use crate::record_batch::RecordBatch;
+use crate::tensor::Tensor;
 use crate::util::bit_util;
 
+use ipc::compression::CompressionCodec;
 use ipc::CONTINUATION_MARKER;
 
 /// IPC write options used to control the behaviour of the writer
@@ -52,6 +57,10 @@ pub struct IpcWriteOptions {
     /// version 2.0.0: V4, with legacy format enabled
     /// version 4.0.0: V5
     metadata_version: ipc::MetadataVersion,
+    /// Compression, if desired. Only supported when `ipc_compression` feature is enabled
+    batch_compression_type: Option<ipc::CompressionType>,
+    /// Whether the `StreamWriter` writes the values appended to a dictionary as a delta
+    dictionary_deltas: bool,
 }
 
 impl IpcWriteOptions {
@@ -76,6 +85,8 @@ impl IpcWriteOptions {
                 alignment,
                 write_legacy_ipc_format,
                 metadata_version,
+                batch_compression_type: None,
+                dictionary_deltas: false,
             }),
             ipc::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -88,12 +99,51 @@ impl IpcWriteOptions {
                         alignment,
                         write_legacy_ipc_format,
                         metadata_version,
+                        batch_compression_type: None,
+                        dictionary_deltas: false,
                     })
                 }
             }
             z => panic!("Unsupported ipc::MetadataVersion {:?}", z),
         }
     }
+
+    /// Configures compression of the buffers of record and dictionary batches.
+    ///
+    /// Compression requires metadata version 5 and the `ipc_compression` feature.
+    pub fn try_with_compression(
+        mut self,
+        batch_compression_type: Option<ipc::CompressionType>,
+    ) -> Result<Self> {
+        if let Some(compression_type) = batch_compression_type {
+            if self.metadata_version < ipc::MetadataVersion::V5 {
+                return Err(ArrowError::InvalidArgumentError(
+                    "Compression only supported in metadata v5 and above".to_string(),
+                ));
+            }
+            CompressionCodec::try_from(compression_type)?;
+            #[cfg(not(feature = "ipc_compression"))]
+            return Err(ipc::compression::compression_disabled());
+        }
+        self.batch_compression_type = batch_compression_type;
+        Ok(self)
+    }
+
+    /// The compression of the buffers of record and dictionary batches, if any
+    pub fn batch_compression_type(&self) -> Option<ipc::CompressionType> {
+        self.batch_compression_type
+    }
+
+    /// Configures whether the `StreamWriter` writes a dictionary whose values start with
+    /// the values of the dictionary of the same id it has already written as a delta
+    /// dictionary batch of only the new values, rather than as a replacement of the whole
+    /// dictionary.
+    ///
+    /// Deltas are disabled by default, the `FileWriter` never writes them.
+    pub fn with_dictionary_deltas(mut self, dictionary_deltas: bool) -> Self {
+        self.dictionary_deltas = dictionary_deltas;
+        self
+    }
 }
 
 impl Default for IpcWriteOptions {
@@ -102,6 +152,8 @@ impl Default for IpcWriteOptions {
             alignment: 8,
             write_legacy_ipc_format: false,
             metadata_version: ipc::MetadataVersion::V5,
+            batch_compression_type: None,
+            dictionary_deltas: false,
         }
     }
 }
@@ -157,36 +209,125 @@ impl IpcDataGenerator {
                 let dict_data = column.data();
                 let dict_values = &dict_data.child_data()[0];
 
-                let emit = dictionary_tracker.insert(dict_id, column)?;
-
-                if emit {
-                    encoded_dictionaries.push(self.dictionary_batch_to_bytes(
-                        dict_id,
-                        dict_values,
-                        write_options,
-                    ));
+                let allow_delta = write_options.dictionary_deltas
+                    && !dictionary_tracker.error_on_replacement;
+                match dictionary_tracker.update(dict_id, column, allow_delta)? {
+                    DictionaryUpdate::Unchanged => {}
+                    DictionaryUpdate::New => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            dict_values,
+                            false,
+                            write_options,
+                        )?);
+                    }
+                    DictionaryUpdate::Delta(values) => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            &values,
+                            true,
+                            write_options,
+                        )?);
+                    }
                 }
             }
         }
 
-        let encoded_message = self.record_batch_to_bytes(batch, write_options);
+        let encoded_message = self.record_batch_to_bytes(batch, write_options)?;
 
         Ok((encoded_dictionaries, encoded_message))
     }
 
+    /// Returns the encapsulated IPC message of `schema`, i.e. the bytes that a
+    /// [StreamWriter] writes for it, so that it can be sent over a custom transport.
+    pub fn encapsulated_schema(
+        &self,
+        schema: &Schema,
+        write_options: &IpcWriteOptions,
+    ) -> Result<Vec<u8>> {
+        self.schema_to_bytes(schema, write_options)
+            .into_encapsulated_bytes(write_options)
+    }
+
+    /// Returns the encapsulated IPC messages of the dictionaries of `batch` that need to
+    /// be sent, as tracked by `dictionary_tracker`, and of `batch` itself, i.e. the bytes
+    /// that a [StreamWriter] writes for it.
+    ///
+    /// # Example
+    /// ```
+    /// use std::io::Cursor;
+    /// use std::sync::Arc;
+    /// use arrow::array::{ArrayRef, Int32Array};
+    /// use arrow::ipc::reader::StreamReader;
+    /// use arrow::ipc::writer::{DictionaryTracker, IpcDataGenerator, IpcWriteOptions};
+    /// use arrow::record_batch::RecordBatch;
+    ///
+    /// # fn main() -> arrow::error::Result<()> {
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+    /// let batch = RecordBatch::try_from_iter(vec![("a", a)])?;
+    ///
+    /// let options = IpcWriteOptions::default();
+    /// let data_gen = IpcDataGenerator::default();
+    /// let mut tracker = DictionaryTracker::new(false);
+    ///
+    /// // frame the messages, e.g. to send them one by one
+    /// let mut messages = vec![data_gen.encapsulated_schema(&batch.schema(), &options)?];
+    /// let (dictionaries, message) =
+    ///     data_gen.encapsulated_batch(&batch, &mut tracker, &options)?;
+    /// messages.extend(dictionaries);
+    /// messages.push(message);
+    /// messages.push(data_gen.encapsulated_end_of_stream(&options)?);
+    ///
+    /// // the concatenated messages are an IPC stream
+    /// let mut reader = StreamReader::try_new(Cursor::new(messages.concat()))?;
+    /// assert_eq!(reader.next().unwrap()?.column(0), batch.column(0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn encapsulated_batch(
+        &self,
+        batch: &RecordBatch,
+        dictionary_tracker: &mut DictionaryTracker,
+        write_options: &IpcWriteOptions,
+    ) -> Result<(Vec<Vec<u8>>, Vec<u8>)> {
+        let (encoded_dictionaries, encoded_message) =
+            self.encoded_batch(batch, dictionary_tracker, write_options)?;
+        let dictionaries = encoded_dictionaries
+            .into_iter()
+            .map(|encoded| encoded.into_encapsulated_bytes(write_options))
+            .collect::<Result<Vec<_>>>()?;
+        let message = encoded_message.into_encapsulated_bytes(write_options)?;
+        Ok((dictionaries, message))
+    }
+
+    /// Returns the end-of-stream marker, i.e. the bytes that [StreamWriter::finish]
+    /// writes.
+    pub fn encapsulated_end_of_stream(
+        &self,
+        write_options: &IpcWriteOptions,
+    ) -> Result<Vec<u8>> {
+        let mut bytes = vec![];
+        write_continuation(&mut bytes, write_options, 0)?;
+        Ok(bytes)
+    }
+
     /// Write a `RecordBatch` into two sets of bytes, one for the header (ipc::Message) and the
     /// other for the batch's data
     fn record_batch_to_bytes(
         &self,
         batch: &RecordBatch,
         write_options: &IpcWriteOptions,
-    ) -> EncodedData {
+    ) -> Result<EncodedData> {
         let mut fbb = FlatBufferBuilder::new();
 
         let mut nodes: Vec<ipc::FieldNode> = vec![];
         let mut buffers: Vec<ipc::Buffer> = vec![];
         let mut arrow_data: Vec<u8> = vec![];
         let mut offset = 0;
+        let compression_codec = write_options
+            .batch_compression_type
+            .map(CompressionCodec::try_from)
+            .transpose()?;
         for array in batch.columns() {
             let array_data = array.data();
             offset = write_array_data(
@@ -197,18 +338,23 @@ impl IpcDataGenerator {
                 offset,
                 array.len(),
                 array.null_count(),
-            );
+                compression_codec,
+            )?;
         }
 
         // write data
         let buffers = fbb.create_vector(&buffers);
         let nodes = fbb.create_vector(&nodes);
+        let compression = body_compression(&mut fbb, write_options);
 
         let root = {
             let mut batch_builder = ipc::RecordBatchBuilder::new(&mut fbb);
             batch_builder.add_length(batch.num_rows() as i64);
             batch_builder.add_nodes(nodes);
             batch_builder.add_buffers(buffers);
+            if let Some(compression) = compression {
+                batch_builder.add_compression(compression);
+            }
             let b = batch_builder.finish();
             b.as_union_value()
         };
@@ -222,25 +368,30 @@ impl IpcDataGenerator {
         fbb.finish(root, None);
         let finished_data = fbb.finished_data();
 
-        EncodedData {
+        Ok(EncodedData {
             ipc_message: finished_data.to_vec(),
             arrow_data,
-        }
+        })
     }
 
     /// Write dictionary values into two sets of bytes, one for the header (ipc::Message) and the
-    /// other for the data
+    /// other for the data. A delta dictionary batch holds values appended to the dictionary.
     fn dictionary_batch_to_bytes(
         &self,
         dict_id: i64,
         array_data: &ArrayData,
+        is_delta: bool,
         write_options: &IpcWriteOptions,
-    ) -> EncodedData {
+    ) -> Result<EncodedData> {
         let mut fbb = FlatBufferBuilder::new();
 
         let mut nodes: Vec<ipc::FieldNode> = vec![];
         let mut buffers: Vec<ipc::Buffer> = vec![];
         let mut arrow_data: Vec<u8> = vec![];
+        let compression_codec = write_options
+            .batch_compression_type
+            .map(CompressionCodec::try_from)
+            .transpose()?;
 
         write_array_data(
             &array_data,
@@ -250,17 +401,22 @@ impl IpcDataGenerator {
             0,
             array_data.len(),
             array_data.null_count(),
-        );
+            compression_codec,
+        )?;
 
         // write data
         let buffers = fbb.create_vector(&buffers);
         let nodes = fbb.create_vector(&nodes);
+        let compression = body_compression(&mut fbb, write_options);
 
         let root = {
             let mut batch_builder = ipc::RecordBatchBuilder::new(&mut fbb);
             batch_builder.add_length(array_data.len() as i64);
             batch_builder.add_nodes(nodes);
             batch_builder.add_buffers(buffers);
+            if let Some(compression) = compression {
+                batch_builder.add_compression(compression);
+            }
             batch_builder.finish()
         };
 
@@ -268,6 +424,7 @@ impl IpcDataGenerator {
             let mut batch_builder = ipc::DictionaryBatchBuilder::new(&mut fbb);
             batch_builder.add_id(dict_id);
             batch_builder.add_data(root);
+            batch_builder.add_isDelta(is_delta);
             batch_builder.finish().as_union_value()
         };
 
@@ -283,11 +440,95 @@ impl IpcDataGenerator {
         fbb.finish(root, None);
         let finished_data = fbb.finished_data();
 
-        EncodedData {
+        Ok(EncodedData {
             ipc_message: finished_data.to_vec(),
             arrow_data,
-        }
+        })
     }
+
+    /// Write a `Tensor` into two sets of bytes, one for the header (ipc::Message) and the
+    /// other for the tensor's data
+    ///
+    /// Tensor messages cannot be compressed, so the compression of `write_options` is ignored.
+    pub fn tensor_to_bytes<T: ArrowPrimitiveType>(
+        &self,
+        tensor: &Tensor<T>,
+        write_options: &IpcWriteOptions,
+    ) -> Result<EncodedData> {
+        let mut fbb = FlatBufferBuilder::new();
+
+        let data = tensor.data();
+        let mut arrow_data = data.as_slice().to_vec();
+        arrow_data.extend_from_slice(&vec![0u8; pad_to_8(data.len() as u32)]);
+
+        let field_type =
+            ipc::convert::get_fb_field_type(tensor.data_type(), false, &mut fbb);
+
+        // a tensor without shape is written with an empty one
+        let empty_shape = vec![];
+        let sizes = tensor.shape().unwrap_or(&empty_shape);
+        let dims = sizes
+            .iter()
+            .enumerate()
+            .map(|(i, size)| {
+                let name = tensor.dim_name(i).map(|name| fbb.create_string(name));
+                ipc::TensorDim::create(
+                    &mut fbb,
+                    &ipc::TensorDimArgs {
+                        size_: *size as i64,
+                        name,
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+        let shape = fbb.create_vector(&dims);
+        let strides = tensor.strides().map(|strides| {
+            let strides = strides.iter().map(|s| *s as i64).collect::<Vec<_>>();
+            fbb.create_vector(&strides)
+        });
+
+        let root = ipc::Tensor::create(
+            &mut fbb,
+            &ipc::TensorArgs {
+                type_type: field_type.type_type,
+                type_: Some(field_type.type_),
+                shape: Some(shape),
+                strides,
+                // the tensor's data is the only buffer of the body
+                data: Some(&ipc::Buffer::new(0, data.len() as i64)),
+            },
+        );
+
+        let root = {
+            let mut message_builder = ipc::MessageBuilder::new(&mut fbb);
+            message_builder.add_version(write_options.metadata_version);
+            message_builder.add_header_type(ipc::MessageHeader::Tensor);
+            message_builder.add_bodyLength(arrow_data.len() as i64);
+            message_builder.add_header(root.as_union_value());
+            message_builder.finish()
+        };
+
+        fbb.finish(root, None);
+        let finished_data = fbb.finished_data();
+
+        Ok(EncodedData {
+            ipc_message: finished_data.to_vec(),
+            arrow_data,
+        })
+    }
+}
+
+/// Creates the `BodyCompression` of a batch written with `write_options`, if any
+fn body_compression<'a>(
+    fbb: &mut FlatBufferBuilder<'a>,
+    write_options: &IpcWriteOptions,
+) -> Option<flatbuffers::WIPOffset<ipc::BodyCompression<'a>>> {
+    write_options.batch_compression_type.map(|codec| {
+        let mut builder = ipc::BodyCompressionBuilder::new(fbb);
+        builder.add_method(ipc::BodyCompressionMethod::BUFFER);
+        builder.add_codec(codec);
+        builder.finish()
+    })
 }
 
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
@@ -316,14 +557,32 @@ impl DictionaryTracker {
     ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
     ///   inserted.
     pub fn insert(&mut self, dict_id: i64, column: &ArrayRef) -> Result<bool> {
+        let update = self.update(dict_id, column, false)?;
+        Ok(update != DictionaryUpdate::Unchanged)
+    }
+
+    /// Keep track of the dictionary with the given ID and values, as [`Self::insert`] does,
+    /// returning how it must be written.
+    ///
+    /// If `allow_delta` is true and the values of the dictionary start with the values of
+    /// the dictionary last written with this ID, followed by new values, returns
+    /// [`DictionaryUpdate::Delta`] of the new values.
+    pub fn update(
+        &mut self,
+        dict_id: i64,
+        column: &ArrayRef,
+        allow_delta: bool,
+    ) -> Result<DictionaryUpdate> {
         let dict_data = column.data();
         let dict_values = &dict_data.child_data()[0];
 
         // If a dictionary with this id was already emitted, check if it was the same.
+        let mut update = DictionaryUpdate::New;
         if let Some(last) = self.written.get(&dict_id) {
-            if last.data().child_data()[0] == *dict_values {
+            let last_values = &last.data().child_data()[0];
+            if last_values == dict_values {
                 // Same dictionary values => no need to emit it again
-                return Ok(false);
+                return Ok(DictionaryUpdate::Unchanged);
             } else if self.error_on_replacement {
                 return Err(ArrowError::InvalidArgumentError(
                     "Dictionary replacement detected when writing IPC file format. \
@@ -331,14 +590,36 @@ impl DictionaryTracker {
                      across all batches."
                         .to_string(),
                 ));
+            } else if allow_delta && dict_values.len() > last_values.len() {
+                let values = make_array(dict_values.clone());
+                let len = last_values.len();
+                if values.slice(0, len).data_ref() == last_values {
+                    // the new values are copied, as sliced arrays aren't written with
+                    // their offset
+                    let delta = values.slice(len, values.len() - len);
+                    let delta = concat(&[delta.as_ref()])?;
+                    update = DictionaryUpdate::Delta(delta.data().clone());
+                }
             }
         }
 
         self.written.insert(dict_id, column.clone());
-        Ok(true)
+        Ok(update)
     }
 }
 
+/// How a dictionary tracked by a [`DictionaryTracker`] must be written
+#[derive(Debug, Clone, PartialEq)]
+pub enum DictionaryUpdate {
+    /// The dictionary was already written
+    Unchanged,
+    /// The dictionary is written whole, either for the first time or as a replacement
+    New,
+    /// The values appended to the dictionary last written with the same ID are written
+    /// as a delta
+    Delta(ArrayData),
+}
+
 pub struct FileWriter<W: Write> {
     /// The object to write to
     writer: BufWriter<W>,
@@ -602,6 +883,20 @@ pub struct EncodedData {
     /// Arrow buffers to be written, should be an empty vec for schema messages
     pub arrow_data: Vec<u8>,
 }
+
+impl EncodedData {
+    /// Returns the encapsulated message of this data, as [write_message] writes it: the
+    /// length prefix, the padded `ipc_message` and the padded `arrow_data`.
+    pub fn into_encapsulated_bytes(
+        self,
+        write_options: &IpcWriteOptions,
+    ) -> Result<Vec<u8>> {
+        let mut bytes = vec![];
+        write_message(&mut bytes, self, write_options)?;
+        Ok(bytes)
+    }
+}
+
 /// Write a message's IPC data and buffers, returning metadata and buffer data lengths written
 pub fn write_message<W: Write>(
     mut writer: W,
@@ -702,6 +997,7 @@ fn write_continuation<W: Write>(
 }
 
 /// Write array data to a vector of bytes
+#[allow(clippy::too_many_arguments)]
 fn write_array_data(
     array_data: &ArrayData,
     mut buffers: &mut Vec<ipc::Buffer>,
@@ -710,7 +1006,8 @@ fn write_array_data(
     offset: i64,
     num_rows: usize,
     null_count: usize,
-) -> i64 {
+    compression_codec: Option<CompressionCodec>,
+) -> Result<i64> {
     let mut offset = offset;
     nodes.push(ipc::FieldNode::new(num_rows as i64, null_count as i64));
     // NullArray does not have any buffers, thus the null buffer is not generated
@@ -722,21 +1019,34 @@ fn write_array_data(
                 let num_bytes = bit_util::ceil(num_rows, 8);
                 let buffer = MutableBuffer::new(num_bytes);
                 let buffer = buffer.with_bitset(num_bytes, true);
-                buffer.into()
+                Cow::Owned(buffer.as_slice().to_vec())
             }
-            Some(buffer) => buffer.clone(),
+            Some(buffer) => bitmap_slice(buffer, array_data.offset(), num_rows),
         };
 
-        offset = write_buffer(&null_buffer, &mut buffers, &mut arrow_data, offset);
+        offset = write_buffer(
+            &null_buffer,
+            &mut buffers,
+            &mut arrow_data,
+            offset,
+            compression_codec,
+        )?;
     }
 
-    array_data.buffers().iter().for_each(|buffer| {
-        offset = write_buffer(buffer, &mut buffers, &mut arrow_data, offset);
-    });
+    let (sliced_buffers, child_data) = slice_buffers(array_data);
+    for buffer in &sliced_buffers {
+        offset = write_buffer(
+            buffer,
+            &mut buffers,
+            &mut arrow_data,
+            offset,
+            compression_codec,
+        )?;
+    }
 
     if !matches!(array_data.data_type(), DataType::Dictionary(_, _)) {
         // recursively write out nested structures
-        array_data.child_data().iter().for_each(|data_ref| {
+        for data_ref in &child_data {
             // write the nested data (e.g list data)
             offset = write_array_data(
                 data_ref,
@@ -746,28 +1056,173 @@ fn write_array_data(
                 offset,
                 data_ref.len(),
                 data_ref.null_count(),
-            );
-        });
+                compression_codec,
+            )?;
+        }
+    }
+
+    Ok(offset)
+}
+
+/// Returns the buffers and the child data of `array_data` for its slice, as the IPC
+/// format has no offsets.
+///
+/// The buffers are truncated to the values of the slice, copying them only when the
+/// offsets of variable-size values need to be rebased to start at 0, or when a bitmap
+/// doesn't start at a byte boundary. The child data is sliced to the values of the slice.
+fn slice_buffers(array_data: &ArrayData) -> (Vec<Cow<[u8]>>, Vec<ArrayData>) {
+    let offset = array_data.offset();
+    let len = array_data.len();
+    let buffers = array_data.buffers();
+    let child_data = array_data.child_data();
+    match array_data.data_type() {
+        DataType::Null => (vec![], vec![]),
+        DataType::Boolean => (vec![bitmap_slice(&buffers[0], offset, len)], vec![]),
+        DataType::Utf8 | DataType::Binary => {
+            let (offsets, start, end) = rebase_offsets::<i32>(&buffers[0], offset, len);
+            let values = Cow::Borrowed(&buffers[1].as_slice()[start..end]);
+            (vec![offsets, values], vec![])
+        }
+        DataType::LargeUtf8 | DataType::LargeBinary => {
+            let (offsets, start, end) = rebase_offsets::<i64>(&buffers[0], offset, len);
+            let values = Cow::Borrowed(&buffers[1].as_slice()[start..end]);
+            (vec![offsets, values], vec![])
+        }
+        DataType::List(_) | DataType::Map(_, _) => {
+            let (offsets, start, end) = rebase_offsets::<i32>(&buffers[0], offset, len);
+            (vec![offsets], vec![child_data[0].slice(start, end - start)])
+        }
+        DataType::LargeList(_) => {
+            let (offsets, start, end) = rebase_offsets::<i64>(&buffers[0], offset, len);
+            (vec![offsets], vec![child_data[0].slice(start, end - start)])
+        }
+        DataType::FixedSizeList(_, size) => {
+            let size = *size as usize;
+            (vec![], vec![child_data[0].slice(offset * size, len * size)])
+        }
+        DataType::Struct(_) => (
+            vec![],
+            child_data.iter().map(|c| c.slice(offset, len)).collect(),
+        ),
+        DataType::Union(_) => {
+            let type_ids = Cow::Borrowed(&buffers[0].as_slice()[offset..offset + len]);
+            match buffers.get(1) {
+                // the offsets of dense unions skip the null slots
+                Some(value_offsets) => {
+                    let (start, count) = match array_data.null_buffer() {
+                        Some(nulls) => (
+                            nulls.count_set_bits_offset(0, offset),
+                            nulls.count_set_bits_offset(offset, len),
+                        ),
+                        None => (offset, len),
+                    };
+                    let width = std::mem::size_of::<i32>();
+                    let value_offsets = Cow::Borrowed(
+                        &value_offsets.as_slice()[start * width..(start + count) * width],
+                    );
+                    (vec![type_ids, value_offsets], child_data.to_vec())
+                }
+                None => (
+                    vec![type_ids],
+                    child_data.iter().map(|c| c.slice(offset, len)).collect(),
+                ),
+            }
+        }
+        // the keys of a dictionary array are sliced, its values are written separately
+        DataType::Dictionary(key_type, _) => {
+            let width = fixed_width(key_type);
+            let keys = &buffers[0].as_slice()[offset * width..(offset + len) * width];
+            (vec![Cow::Borrowed(keys)], child_data.to_vec())
+        }
+        data_type => {
+            let width = fixed_width(data_type);
+            let values = &buffers[0].as_slice()[offset * width..(offset + len) * width];
+            (vec![Cow::Borrowed(values)], vec![])
+        }
+    }
+}
+
+/// Returns the bytes of the `len` bits of `buffer` from the bit `offset`
+fn bitmap_slice(buffer: &Buffer, offset: usize, len: usize) -> Cow<[u8]> {
+    let num_bytes = bit_util::ceil(len, 8);
+    if offset % 8 == 0 {
+        Cow::Borrowed(&buffer.as_slice()[offset / 8..offset / 8 + num_bytes])
+    } else {
+        Cow::Owned(buffer.bit_slice(offset, len).as_slice()[..num_bytes].to_vec())
     }
+}
 
-    offset
+/// Returns the `len + 1` offsets of `buffer` from `offset` rebased to start at 0, with the
+/// range of the values they point to.
+fn rebase_offsets<O: OffsetSizeTrait>(
+    buffer: &Buffer,
+    offset: usize,
+    len: usize,
+) -> (Cow<[u8]>, usize, usize) {
+    let offsets = unsafe { &buffer.typed_data::<O>()[offset..=offset + len] };
+    let start = offsets[0];
+    let end = offsets[len].to_usize().unwrap();
+    let offsets = if start.is_zero() {
+        Cow::Borrowed(offsets.to_byte_slice())
+    } else {
+        let rebased = offsets.iter().map(|o| *o - start).collect::<Vec<_>>();
+        Cow::Owned(rebased.to_byte_slice().to_vec())
+    };
+    (offsets, start.to_usize().unwrap(), end)
+}
+
+/// Returns the width in bytes of the values of a fixed-width data type
+fn fixed_width(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::Int8 | DataType::UInt8 => 1,
+        DataType::Int16 | DataType::UInt16 | DataType::Float16 => 2,
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => 4,
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => 8,
+        DataType::Decimal(_, _) => 16,
+        DataType::FixedSizeBinary(width) => *width as usize,
+        t => unreachable!("{:?} is not a fixed-width data type", t),
+    }
 }
 
 /// Write a buffer to a vector of bytes, and add its ipc::Buffer to a vector
+///
+/// If a compression codec is given, the buffer is compressed and its ipc::Buffer
+/// records the compressed length, excluding padding.
 fn write_buffer(
-    buffer: &Buffer,
+    buffer: &[u8],
     buffers: &mut Vec<ipc::Buffer>,
     arrow_data: &mut Vec<u8>,
     offset: i64,
-) -> i64 {
-    let len = buffer.len();
+    compression_codec: Option<CompressionCodec>,
+) -> Result<i64> {
+    let len = match compression_codec {
+        Some(codec) => codec.compress_to_vec(buffer, arrow_data)?,
+        None => {
+            arrow_data.extend_from_slice(buffer);
+            buffer.len()
+        }
+    };
     let pad_len = pad_to_8(len as u32);
     let total_len: i64 = (len + pad_len) as i64;
     // assert_eq!(len % 8, 0, "Buffer width not a multiple of 8 bytes");
-    buffers.push(ipc::Buffer::new(offset, total_len));
-    arrow_data.extend_from_slice(buffer.as_slice());
+    match compression_codec {
+        Some(_) => buffers.push(ipc::Buffer::new(offset, len as i64)),
+        None => buffers.push(ipc::Buffer::new(offset, total_len)),
+    }
     arrow_data.extend_from_slice(&vec![0u8; pad_len][..]);
-    offset + total_len
+    Ok(offset + total_len)
 }
 
 /// Calculate an 8-byte boundary and return the number of bytes needed to pad to 8 bytes
@@ -839,6 +1294,241 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "ipc_compression")]
+    fn test_write_compressed_stream() {
+        let schema = Schema::new(vec![
+            Field::new("ints", DataType::Int32, true),
+            Field::new("strings", DataType::Utf8, true),
+        ]);
+        let ints: Int32Array = (0..1000).map(|i| Some(i % 10)).collect();
+        let strings: StringArray = (0..1000)
+            .map(|i| match i % 3 {
+                0 => None,
+                _ => Some("arrow"),
+            })
+            .collect();
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(ints) as ArrayRef, Arc::new(strings) as ArrayRef],
+        )
+        .unwrap();
+
+        for compression in &[ipc::CompressionType::LZ4_FRAME, ipc::CompressionType::ZSTD]
+        {
+            let options = IpcWriteOptions::default()
+                .try_with_compression(Some(*compression))
+                .unwrap();
+            let mut buf = vec![];
+            {
+                let mut writer =
+                    StreamWriter::try_new_with_options(&mut buf, &schema, options)
+                        .unwrap();
+                writer.write(&batch).unwrap();
+                writer.finish().unwrap();
+            }
+
+            let mut reader = StreamReader::try_new(buf.as_slice()).unwrap();
+            let read_batch = reader.next().unwrap().unwrap();
+            assert_eq!(read_batch.schema(), batch.schema());
+            assert_eq!(read_batch.columns(), batch.columns());
+            assert!(reader.next().is_none());
+        }
+    }
+
+    #[test]
+    fn test_write_stream_dictionary_updates() {
+        let data_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let schema = Schema::new(vec![Field::new_dict(
+            "dict",
+            data_type.clone(),
+            true,
+            1,
+            false,
+        )]);
+        let arrays: Vec<DictionaryArray<Int32Type>> = vec![
+            vec![Some("a"), Some("b"), Some("a")].into_iter().collect(),
+            // the same dictionary, it isn't written again
+            vec![Some("a"), None, Some("b")].into_iter().collect(),
+            // the dictionary with an appended value
+            vec![Some("a"), Some("b"), Some("c")].into_iter().collect(),
+            // a replacement of the dictionary
+            vec![Some("d")].into_iter().collect(),
+        ];
+        let batches = arrays
+            .into_iter()
+            .map(|array| {
+                RecordBatch::try_new(Arc::new(schema.clone()), vec![Arc::new(array)])
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        for deltas in &[false, true] {
+            let options = IpcWriteOptions::default().with_dictionary_deltas(*deltas);
+            let mut buf = vec![];
+            let mut tracker = DictionaryTracker::new(false);
+            let mut dictionary_batches = vec![];
+            {
+                let mut writer =
+                    StreamWriter::try_new_with_options(&mut buf, &schema, options)
+                        .unwrap();
+                for batch in &batches {
+                    let column = batch.column(0);
+                    dictionary_batches.push(tracker.update(1, column, *deltas).unwrap());
+                    writer.write(batch).unwrap();
+                }
+                writer.finish().unwrap();
+            }
+            assert_eq!(dictionary_batches[0], DictionaryUpdate::New);
+            assert_eq!(dictionary_batches[1], DictionaryUpdate::Unchanged);
+            if *deltas {
+                let delta = StringArray::from(vec!["c"]);
+                assert_eq!(
+                    dictionary_batches[2],
+                    DictionaryUpdate::Delta(delta.data().clone())
+                );
+            } else {
+                assert_eq!(dictionary_batches[2], DictionaryUpdate::New);
+            }
+            assert_eq!(dictionary_batches[3], DictionaryUpdate::New);
+
+            let reader = StreamReader::try_new(buf.as_slice()).unwrap();
+            let read_batches = reader.collect::<Result<Vec<_>>>().unwrap();
+            assert_eq!(read_batches.len(), batches.len());
+            for (read_batch, batch) in read_batches.iter().zip(&batches) {
+                assert_eq!(read_batch.column(0).data_type(), &data_type);
+                assert_eq!(read_batch.column(0), batch.column(0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_write_sliced_batch() {
+        let list = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2)]),
+            None,
+            Some(vec![Some(3)]),
+            Some(vec![]),
+            Some(vec![None, Some(4)]),
+        ]);
+        let strings =
+            StringArray::from(vec![Some("a"), Some("bb"), None, Some("ccc"), Some("d")]);
+        let bools =
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true), None]);
+        let ints = Int64Array::from(vec![Some(1), Some(2), None, Some(4), Some(5)]);
+        let ints = Arc::new(ints) as ArrayRef;
+        let dict: DictionaryArray<Int8Type> =
+            vec![Some("x"), Some("y"), None, Some("x"), Some("z")]
+                .into_iter()
+                .collect();
+        let structs = StructArray::from(vec![(
+            Field::new("ints", DataType::Int64, true),
+            ints.clone(),
+        )]);
+        let schema = Schema::new(vec![
+            Field::new("list", list.data_type().clone(), true),
+            Field::new("strings", DataType::Utf8, true),
+            Field::new("bools", DataType::Boolean, true),
+            Field::new("ints", DataType::Int64, true),
+            Field::new_dict("dict", dict.data_type().clone(), true, 0, false),
+            Field::new("structs", structs.data_type().clone(), true),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![
+                Arc::new(list),
+                Arc::new(strings),
+                Arc::new(bools),
+                ints,
+                Arc::new(dict),
+                Arc::new(structs),
+            ],
+        )
+        .unwrap();
+
+        let write = |batch: &RecordBatch| {
+            let mut buf = vec![];
+            {
+                let mut writer =
+                    StreamWriter::try_new(&mut buf, &batch.schema()).unwrap();
+                writer.write(batch).unwrap();
+                writer.finish().unwrap();
+            }
+            buf
+        };
+        for (offset, length) in &[(0, 2), (1, 3), (3, 2), (5, 0)] {
+            let sliced = batch.slice(*offset, *length);
+            let buf = write(&sliced);
+            let reader = StreamReader::try_new(buf.as_slice()).unwrap();
+            let read_batches = reader.collect::<Result<Vec<_>>>().unwrap();
+            assert_eq!(read_batches[0].num_rows(), *length);
+            for (read, column) in read_batches[0].columns().iter().zip(sliced.columns()) {
+                assert_eq!(read, column);
+            }
+        }
+
+        // only the values of the slice are written
+        let ints: Int32Array = (0..1000).map(Some).collect();
+        let schema = Schema::new(vec![Field::new("ints", DataType::Int32, false)]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(ints)]).unwrap();
+        assert!(write(&batch.slice(990, 10)).len() < write(&batch).len() / 4);
+    }
+
+    #[test]
+    fn test_compression_requires_v5() {
+        let options = IpcWriteOptions::try_new(8, false, MetadataVersion::V4).unwrap();
+        let err = options
+            .try_with_compression(Some(ipc::CompressionType::ZSTD))
+            .unwrap_err();
+        assert!(err.to_string().contains("metadata v5"));
+    }
+
+    #[test]
+    fn test_write_tensor() {
+        use crate::ipc::reader::read_tensor;
+        use crate::tensor::Int32Tensor;
+
+        let buffer = Buffer::from_slice_ref(&(0..12).collect::<Vec<i32>>());
+        let tensor =
+            Int32Tensor::new_row_major(buffer, Some(vec![3, 4]), Some(vec!["a", "b"]))
+                .unwrap()
+                .transpose();
+
+        let options = IpcWriteOptions::default();
+        let encoded = IpcDataGenerator::default()
+            .tensor_to_bytes(&tensor, &options)
+            .unwrap();
+        let message = ipc::root_as_message(&encoded.ipc_message).unwrap();
+        assert_eq!(message.bodyLength(), 48);
+        let header = message.header_as_tensor().unwrap();
+        let read = read_tensor::<Int32Type>(&encoded.arrow_data, header).unwrap();
+        assert_eq!(read.shape(), Some(&vec![4, 3]));
+        assert_eq!(read.strides(), Some(&vec![4, 16]));
+        assert_eq!(read.names(), Some(&vec!["b", "a"]));
+        assert!(read.is_column_major().unwrap());
+        assert_eq!(read.data(), tensor.data());
+
+        // tensors without a shape hold a single element
+        let scalar =
+            Int32Tensor::try_new(Buffer::from_slice_ref(&[7i32]), None, None, None)
+                .unwrap();
+        let encoded = IpcDataGenerator::default()
+            .tensor_to_bytes(&scalar, &options)
+            .unwrap();
+        let message = ipc::root_as_message(&encoded.ipc_message).unwrap();
+        let header = message.header_as_tensor().unwrap();
+        let read = read_tensor::<Int32Type>(&encoded.arrow_data, header).unwrap();
+        assert_eq!(read.shape(), None);
+        assert_eq!(read.data().as_slice(), &7i32.to_le_bytes());
+
+        let err = read_tensor::<Float64Type>(&encoded.arrow_data, header).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Io error: Expected a tensor of Float64, found a tensor of Int32"
+        );
+    }
+
     fn write_null_file(options: IpcWriteOptions, suffix: &str) {
         let schema = Schema::new(vec![
             Field::new("nulls", DataType::Null, true),
@@ -1142,6 +1832,38 @@ mod tests {
         });
     }
 
+    #[test]
+    fn test_encapsulated_messages() {
+        let array: DictionaryArray<Int32Type> = vec!["a", "b", "a"].into_iter().collect();
+        let batch =
+            RecordBatch::try_from_iter(vec![("d", Arc::new(array) as ArrayRef)]).unwrap();
+        let options = IpcWriteOptions::default();
+
+        let data_gen = IpcDataGenerator::default();
+        let mut tracker = DictionaryTracker::new(false);
+        let mut messages = vec![data_gen
+            .encapsulated_schema(&batch.schema(), &options)
+            .unwrap()];
+        for expected_dictionaries in &[1, 0] {
+            let (dictionaries, message) = data_gen
+                .encapsulated_batch(&batch, &mut tracker, &options)
+                .unwrap();
+            assert_eq!(dictionaries.len(), *expected_dictionaries);
+            messages.extend(dictionaries);
+            messages.push(message);
+        }
+        messages.push(data_gen.encapsulated_end_of_stream(&options).unwrap());
+        for message in &messages {
+            assert_eq!(message.len() % 8, 0);
+        }
+
+        // the messages are what a stream writer writes
+        let mut writer = StreamWriter::try_new(vec![], &batch.schema()).unwrap();
+        writer.write(&batch).unwrap();
+        writer.write(&batch).unwrap();
+        assert_eq!(writer.into_inner().unwrap(), messages.concat());
+    }
+
     /// Read gzipped JSON file
     fn read_gzip_json(version: &str, path: &str) -> ArrowJson {
         let testdata = crate::util::test_util::arrow_test_data();
@@ -0,0 +1,186 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Asynchronous JSON Reader
+//!
+//! Reads line-delimited JSON from an [`AsyncRead`] source as a [`Stream`] of
+//! [`RecordBatch`]es. The schema must be provided, as inferring it would
+//! require the source to be read twice.
+//!
+//! Bytes are decoded by a [`RawDecoder`], which tokenizes them straight into
+//! arrays rather than parsing each record into a `serde_json::Value`.
+//!
+//! This module requires the `async` feature.
+
+use std::fmt;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::io::AsyncRead;
+use futures::ready;
+use futures::stream::Stream;
+
+use super::raw::RawDecoder;
+use crate::datatypes::SchemaRef;
+use crate::error::Result;
+use crate::record_batch::RecordBatch;
+
+/// Size of the buffer used for each read from the underlying source
+const READ_BUFFER_SIZE: usize = 8 * 1024;
+
+/// A [`Stream`] of [`RecordBatch`]es decoded from line-delimited JSON, read from
+/// an [`AsyncRead`] source.
+///
+/// Bytes are decoded as they arrive, so records may be split across reads
+/// at any position.
+pub struct AsyncReader<R> {
+    reader: R,
+    decoder: RawDecoder,
+    buffer: Vec<u8>,
+    /// The range of `buffer` that has been read but not yet decoded
+    start: usize,
+    end: usize,
+    /// Whether the underlying source has been exhausted
+    finished: bool,
+}
+
+impl<R: AsyncRead + Unpin> AsyncReader<R> {
+    /// Create a new asynchronous JSON reader from any value that implements
+    /// the `AsyncRead` trait.
+    pub fn new(
+        reader: R,
+        schema: SchemaRef,
+        batch_size: usize,
+        projection: Option<Vec<String>>,
+    ) -> Self {
+        Self {
+            reader,
+            decoder: RawDecoder::new(schema, batch_size, projection),
+            buffer: vec![0; READ_BUFFER_SIZE],
+            start: 0,
+            end: 0,
+            finished: false,
+        }
+    }
+
+    /// Returns the schema of the reader, useful for getting the schema without
+    /// reading record batches
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema()
+    }
+}
+
+impl<R> fmt::Debug for AsyncReader<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncReader")
+            .field("decoder", &self.decoder)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for AsyncReader<R> {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if this.start < this.end {
+                match this.decoder.decode(&this.buffer[this.start..this.end]) {
+                    Ok(read) => this.start += read,
+                    Err(e) => {
+                        // the rest of the source can't be read past invalid JSON
+                        this.start = this.end;
+                        this.finished = true;
+                        return Poll::Ready(Some(Err(e)));
+                    }
+                }
+            }
+            // the rest of the read is decoded after the batch is flushed
+            if this.decoder.has_full_batch() || this.finished {
+                return Poll::Ready(this.decoder.flush().transpose());
+            }
+            match ready!(Pin::new(&mut this.reader).poll_read(cx, &mut this.buffer)) {
+                Ok(0) => this.finished = true,
+                Ok(read) => {
+                    this.start = 0;
+                    this.end = read;
+                }
+                Err(e) => return Poll::Ready(Some(Err(e.into()))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Arc;
+
+    use futures::executor::block_on;
+    use futures::stream::TryStreamExt;
+
+    use crate::array::{Int64Array, StringArray};
+    use crate::datatypes::{DataType, Field, Schema};
+
+    #[test]
+    fn test_async_reader() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let mut content = String::new();
+        for i in 0..25 {
+            content.push_str(&format!("{{\"a\": {}, \"b\": \"{}\"}}\n", i, i * 2));
+        }
+        // use a small buffer so that records are split across reads
+        let mut reader = AsyncReader::new(content.as_bytes(), schema.clone(), 10, None);
+        reader.buffer = vec![0; 7];
+        assert_eq!(schema, reader.schema());
+
+        let batches: Vec<RecordBatch> = block_on(reader.try_collect()).unwrap();
+        assert_eq!(
+            vec![10, 10, 5],
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>()
+        );
+
+        let a = batches[2]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(20, a.value(0));
+        assert_eq!(24, a.value(4));
+        let b = batches[2]
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!("48", b.value(4));
+    }
+
+    #[test]
+    fn test_async_reader_incomplete_record() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        let content = b"{\"a\": 1}\n{\"a\": ";
+        let reader = AsyncReader::new(&content[..], schema, 10, None);
+
+        let result: Result<Vec<RecordBatch>> = block_on(reader.try_collect());
+        assert!(result.is_err());
+    }
+}
@@ -19,9 +19,18 @@
 //! line-delimited records. See the module level documentation for the
 //! [`reader`] and [`writer`] for usage examples.
 
+#[cfg(feature = "async")]
+pub mod async_reader;
+pub mod raw;
 pub mod reader;
+pub mod tape;
 pub mod writer;
 
+#[cfg(feature = "async")]
+pub use self::async_reader::AsyncReader;
+pub use self::raw::RawDecoder;
+pub use self::reader::Decoder;
+pub use self::reader::PushDecoder;
 pub use self::reader::Reader;
 pub use self::reader::ReaderBuilder;
 pub use self::writer::{ArrayWriter, LineDelimitedWriter, Writer};
@@ -0,0 +1,1040 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decodes JSON bytes directly into arrays
+//!
+//! Unlike the [`Decoder`](super::reader::Decoder), which reads records that have been
+//! parsed into `serde_json::Value`s, the [`RawDecoder`] tokenizes bytes into a
+//! [`Tape`] and decodes the arrays of each column from it, so that no value is allocated
+//! for each member of each record. The [`Reader`](super::reader::Reader) reads files
+//! through it.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use super::reader::{has_base64_encoding, parse_temporal_string};
+use super::tape::{Tape, TapeDecoder, TapeElement};
+use crate::array::*;
+use crate::buffer::Buffer;
+use crate::compute::kernels::cast::cast;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+use crate::util::decimal::parse_decimal;
+
+/// Decodes whitespace or newline delimited JSON objects into [`RecordBatch`]es of a
+/// schema, from bytes that may be split across buffers at any position.
+///
+/// Values are read like by the [`Decoder`](super::reader::Decoder), except that numbers
+/// are parsed from their text into the type of their column, so that integers aren't
+/// read through a floating point number.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+/// use arrow::datatypes::{DataType, Field, Schema};
+/// use arrow::json::RawDecoder;
+///
+/// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+/// let mut decoder = RawDecoder::new(schema, 1024, None);
+///
+/// decoder.decode(b"{\"a\": 1}\n{\"a\"").unwrap();
+/// decoder.decode(b": 2}\n").unwrap();
+/// let batch = decoder.flush().unwrap().unwrap();
+/// assert_eq!(2, batch.num_rows());
+/// ```
+#[derive(Debug)]
+pub struct RawDecoder {
+    tape_decoder: TapeDecoder,
+    /// The projected schema of the batches
+    schema: SchemaRef,
+    batch_size: usize,
+    /// The names of the binary fields whose strings are base64 encoded
+    base64_fields: Vec<String>,
+}
+
+impl RawDecoder {
+    /// Create a new decoder of batches of up to `batch_size` records, of the columns of
+    /// `schema` that are in `projection`, if any.
+    pub fn new(
+        schema: SchemaRef,
+        batch_size: usize,
+        projection: Option<Vec<String>>,
+    ) -> Self {
+        let schema = match projection {
+            Some(projection) => Arc::new(Schema::new(
+                schema
+                    .fields()
+                    .iter()
+                    .filter(|field| projection.contains(field.name()))
+                    .cloned()
+                    .collect(),
+            )),
+            None => schema,
+        };
+        Self {
+            tape_decoder: TapeDecoder::new(batch_size),
+            schema,
+            batch_size,
+            base64_fields: vec![],
+        }
+    }
+
+    /// Set the names of the `Binary` and `LargeBinary` columns whose strings are decoded
+    /// from base64, including those of the binary values nested in them.
+    ///
+    /// The strings of the binary fields at any depth whose metadata sets
+    /// [`BINARY_ENCODING_KEY`](super::reader::BINARY_ENCODING_KEY) to
+    /// [`BASE64_BINARY_ENCODING`](super::reader::BASE64_BINARY_ENCODING) are decoded from
+    /// base64 too. The UTF-8 bytes of the strings of other binary fields are used as
+    /// their values.
+    pub fn with_base64_fields(mut self, base64_fields: Vec<String>) -> Self {
+        self.base64_fields = base64_fields;
+        self
+    }
+
+    /// Set the approximate size in bytes of the batches, estimated from the strings and
+    /// numbers of the records, so that records with large values are split across more
+    /// batches. A batch ends with the first record that reaches it.
+    pub fn with_batch_byte_size(mut self, batch_byte_size: usize) -> Self {
+        self.tape_decoder = self.tape_decoder.with_batch_byte_size(batch_byte_size);
+        self
+    }
+
+    /// Returns the projected schema of the batches
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the maximum number of records the decoder reads into each batch
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Reads records from `buf`, returning the number of bytes read.
+    ///
+    /// Fewer bytes than the length of `buf` are read once the decoder has a full batch,
+    /// in which case the rest of `buf` should be decoded after the batch is flushed.
+    ///
+    /// Returns an error if `buf` isn't valid JSON, in which case the records that have
+    /// been read are discarded.
+    pub fn decode(&mut self, buf: &[u8]) -> Result<usize> {
+        let result = self.tape_decoder.decode(buf);
+        if result.is_err() {
+            self.tape_decoder.clear();
+        }
+        result
+    }
+
+    /// Returns the number of records that have been read and not yet flushed
+    pub fn len(&self) -> usize {
+        self.tape_decoder.num_rows()
+    }
+
+    /// Returns whether no records have been read since the last flush
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns whether the batch size of records have been read
+    pub fn has_full_batch(&self) -> bool {
+        self.tape_decoder.is_full()
+    }
+
+    /// Decodes the records that have been read into a batch, returning `None` if there
+    /// are none.
+    ///
+    /// Returns an error if the last record is incomplete, in which case the records that
+    /// have been read are discarded.
+    pub fn flush(&mut self) -> Result<Option<RecordBatch>> {
+        let result = self.tape_decoder.finish().and_then(|tape| {
+            if tape.num_rows() == 0 {
+                return Ok(None);
+            }
+            let base64_fields = &self.base64_fields;
+            let mut decoder = StructArrayDecoder::new(self.schema.fields(), |field| {
+                base64_fields.contains(field.name())
+            })?;
+            decoder.decode(&tape, &tape.rows()).map(Some)
+        });
+        self.tape_decoder.clear();
+
+        let data = match result? {
+            Some(data) => data,
+            None => return Ok(None),
+        };
+        let columns = data.child_data().iter().cloned().map(make_array).collect();
+        RecordBatch::try_new(self.schema.clone(), columns).map(Some)
+    }
+}
+
+/// Decodes the values at positions of a [`Tape`] into an array
+trait ArrayDecoder {
+    /// Decodes the values at `pos`, where position 0 is a missing value
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData>;
+}
+
+macro_rules! primitive_decoder {
+    ($t:ty, $data_type:expr) => {
+        Box::new(PrimitiveArrayDecoder::<$t>::new($data_type.clone()))
+    };
+}
+
+/// Returns a decoder of the values of `field`, whose binary strings are base64 decoded if
+/// `base64_binary` or if the metadata of `field` says so
+fn make_field_decoder(
+    field: &Field,
+    base64_binary: bool,
+) -> Result<Box<dyn ArrayDecoder>> {
+    make_decoder(
+        field.data_type(),
+        base64_binary || has_base64_encoding(field),
+    )
+}
+
+/// Returns a decoder of values of `data_type`, whose binary strings are base64 decoded if
+/// `base64_binary`
+fn make_decoder(
+    data_type: &DataType,
+    base64_binary: bool,
+) -> Result<Box<dyn ArrayDecoder>> {
+    let decoder: Box<dyn ArrayDecoder> = match data_type {
+        DataType::Null => Box::new(NullArrayDecoder),
+        DataType::Boolean => Box::new(BooleanArrayDecoder),
+        DataType::Int8 => primitive_decoder!(Int8Type, data_type),
+        DataType::Int16 => primitive_decoder!(Int16Type, data_type),
+        DataType::Int32 => primitive_decoder!(Int32Type, data_type),
+        DataType::Int64 => primitive_decoder!(Int64Type, data_type),
+        DataType::UInt8 => primitive_decoder!(UInt8Type, data_type),
+        DataType::UInt16 => primitive_decoder!(UInt16Type, data_type),
+        DataType::UInt32 => primitive_decoder!(UInt32Type, data_type),
+        DataType::UInt64 => primitive_decoder!(UInt64Type, data_type),
+        DataType::Float32 => primitive_decoder!(Float32Type, data_type),
+        DataType::Float64 => primitive_decoder!(Float64Type, data_type),
+        DataType::Date32 => primitive_decoder!(Date32Type, data_type),
+        DataType::Date64 => primitive_decoder!(Date64Type, data_type),
+        DataType::Time32(TimeUnit::Second) => {
+            primitive_decoder!(Time32SecondType, data_type)
+        }
+        DataType::Time32(TimeUnit::Millisecond) => {
+            primitive_decoder!(Time32MillisecondType, data_type)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            primitive_decoder!(Time64MicrosecondType, data_type)
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            primitive_decoder!(Time64NanosecondType, data_type)
+        }
+        DataType::Timestamp(TimeUnit::Second, _) => {
+            primitive_decoder!(TimestampSecondType, data_type)
+        }
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            primitive_decoder!(TimestampMillisecondType, data_type)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            primitive_decoder!(TimestampMicrosecondType, data_type)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            primitive_decoder!(TimestampNanosecondType, data_type)
+        }
+        DataType::Utf8 => Box::new(StringArrayDecoder::<i32>::new()),
+        DataType::LargeUtf8 => Box::new(StringArrayDecoder::<i64>::new()),
+        DataType::Binary => Box::new(BinaryArrayDecoder::<i32>::new(base64_binary)),
+        DataType::LargeBinary => Box::new(BinaryArrayDecoder::<i64>::new(base64_binary)),
+        DataType::Decimal(precision, scale) => Box::new(DecimalArrayDecoder {
+            precision: *precision,
+            scale: *scale,
+        }),
+        DataType::List(field) => {
+            let values = make_field_decoder(field, base64_binary)?;
+            Box::new(ListArrayDecoder::<i32>::new(data_type.clone(), values))
+        }
+        DataType::LargeList(field) => {
+            let values = make_field_decoder(field, base64_binary)?;
+            Box::new(ListArrayDecoder::<i64>::new(data_type.clone(), values))
+        }
+        DataType::Struct(fields) => {
+            Box::new(StructArrayDecoder::new(fields, |_| base64_binary)?)
+        }
+        DataType::Map(field, keys_sorted) => {
+            Box::new(MapArrayDecoder::new(field, *keys_sorted, base64_binary)?)
+        }
+        DataType::Dictionary(_, value_type) => {
+            match value_type.as_ref() {
+                DataType::Utf8
+                | DataType::Int8
+                | DataType::Int16
+                | DataType::Int32
+                | DataType::Int64
+                | DataType::UInt8
+                | DataType::UInt16
+                | DataType::UInt32
+                | DataType::UInt64
+                | DataType::Float32
+                | DataType::Float64 => {}
+                other => {
+                    return Err(ArrowError::JsonError(format!(
+                        "dictionary values of type {:?} not yet supported",
+                        other
+                    )))
+                }
+            }
+            let values = make_decoder(value_type, base64_binary)?;
+            Box::new(DictionaryArrayDecoder {
+                data_type: data_type.clone(),
+                values,
+            })
+        }
+        other => {
+            return Err(ArrowError::JsonError(format!(
+                "{:?} type is not supported",
+                other
+            )))
+        }
+    };
+    Ok(decoder)
+}
+
+struct NullArrayDecoder;
+
+impl ArrayDecoder for NullArrayDecoder {
+    fn decode(&mut self, _tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData> {
+        Ok(NullArray::new(pos.len()).data().clone())
+    }
+}
+
+/// Decodes `true` and `false`, and any other value as null
+struct BooleanArrayDecoder;
+
+impl ArrayDecoder for BooleanArrayDecoder {
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData> {
+        let mut builder = BooleanBuilder::new(pos.len());
+        for p in pos {
+            match tape.get(*p) {
+                TapeElement::True => builder.append_value(true)?,
+                TapeElement::False => builder.append_value(false)?,
+                _ => builder.append_null()?,
+            }
+        }
+        Ok(builder.finish().data().clone())
+    }
+}
+
+/// Decodes numbers, and dates or datetimes in strings for temporal types, and any other
+/// value as null
+struct PrimitiveArrayDecoder<T: ArrowPrimitiveType> {
+    /// The type of the arrays, which may have a timezone unlike `T::DATA_TYPE`
+    data_type: DataType,
+    phantom: PhantomData<T>,
+}
+
+impl<T: ArrowPrimitiveType> PrimitiveArrayDecoder<T> {
+    fn new(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> ArrayDecoder for PrimitiveArrayDecoder<T>
+where
+    T: ArrowPrimitiveType,
+    T::Native: num::NumCast,
+{
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData> {
+        let mut builder = PrimitiveBuilder::<T>::new(pos.len());
+        for p in pos {
+            let value = match tape.get(*p) {
+                TapeElement::Number(idx) => {
+                    let text = tape.get_string(idx);
+                    // parse integers exactly, before trying them as floating point
+                    text.parse::<T::Native>()
+                        .ok()
+                        .or_else(|| text.parse::<f64>().ok().and_then(num::cast::cast))
+                }
+                TapeElement::String(idx) => {
+                    parse_temporal_string(tape.get_string(idx), &self.data_type)
+                        .and_then(num::cast::cast)
+                }
+                _ => None,
+            };
+            builder.append_option(value)?;
+        }
+        let data = builder.finish().data().clone();
+        Ok(ArrayDataBuilder::new(self.data_type.clone())
+            .len(data.len())
+            .buffers(data.buffers().to_vec())
+            .nulls(data.nulls())
+            .build())
+    }
+}
+
+/// Decodes strings, and any other value but null as its JSON text
+struct StringArrayDecoder<O: StringOffsetSizeTrait> {
+    phantom: PhantomData<O>,
+}
+
+impl<O: StringOffsetSizeTrait> StringArrayDecoder<O> {
+    fn new() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<O: StringOffsetSizeTrait> ArrayDecoder for StringArrayDecoder<O> {
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData> {
+        let mut builder = GenericStringBuilder::<O>::with_capacity(pos.len(), 0);
+        let mut json = String::new();
+        for p in pos {
+            match tape.get(*p) {
+                TapeElement::String(idx) | TapeElement::Number(idx) => {
+                    builder.append_value(tape.get_string(idx))?
+                }
+                TapeElement::Null => builder.append_null()?,
+                _ => {
+                    json.clear();
+                    tape.serialize(&mut json, *p);
+                    builder.append_value(&json)?
+                }
+            }
+        }
+        Ok(builder.finish().data().clone())
+    }
+}
+
+/// Decodes strings, which are base64 decoded if enabled for the field
+struct BinaryArrayDecoder<O: BinaryOffsetSizeTrait> {
+    base64_binary: bool,
+    phantom: PhantomData<O>,
+}
+
+impl<O: BinaryOffsetSizeTrait> BinaryArrayDecoder<O> {
+    fn new(base64_binary: bool) -> Self {
+        Self {
+            base64_binary,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<O: BinaryOffsetSizeTrait> ArrayDecoder for BinaryArrayDecoder<O> {
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData> {
+        let values = pos
+            .iter()
+            .map(|p| match tape.get(*p) {
+                TapeElement::String(idx) if self.base64_binary => {
+                    base64::decode(tape.get_string(idx))
+                        .map(|bytes| Some(Cow::Owned(bytes)))
+                        .map_err(|e| {
+                            ArrowError::JsonError(format!(
+                                "Failed to decode base64 value: {}",
+                                e
+                            ))
+                        })
+                }
+                TapeElement::String(idx) => {
+                    Ok(Some(Cow::Borrowed(tape.get_string(idx).as_bytes())))
+                }
+                TapeElement::Null => Ok(None),
+                _ => Err(tape.error(*p, "a string for a binary field")),
+            })
+            .collect::<Result<Vec<Option<Cow<[u8]>>>>>()?;
+        let array: GenericBinaryArray<O> = values.into_iter().collect();
+        Ok(array.data().clone())
+    }
+}
+
+/// Decodes numbers or numeric strings from their text, so that no precision is lost to
+/// floating point
+struct DecimalArrayDecoder {
+    precision: usize,
+    scale: usize,
+}
+
+impl ArrayDecoder for DecimalArrayDecoder {
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData> {
+        let mut builder =
+            DecimalBuilder::with_capacity(pos.len(), self.precision, self.scale);
+        for p in pos {
+            match tape.get(*p) {
+                TapeElement::String(idx) | TapeElement::Number(idx) => {
+                    let value = parse_decimal(
+                        tape.get_string(idx),
+                        self.precision,
+                        self.scale,
+                        None,
+                    )?;
+                    builder.append_value(value)?
+                }
+                TapeElement::Null => builder.append_null()?,
+                _ => return Err(tape.error(*p, "a number for a decimal field")),
+            }
+        }
+        Ok(builder.finish().data().clone())
+    }
+}
+
+/// Decodes lists, any other value but null being read as a single-value list
+struct ListArrayDecoder<O: OffsetSizeTrait> {
+    data_type: DataType,
+    values: Box<dyn ArrayDecoder>,
+    phantom: PhantomData<O>,
+}
+
+impl<O: OffsetSizeTrait> ListArrayDecoder<O> {
+    fn new(data_type: DataType, values: Box<dyn ArrayDecoder>) -> Self {
+        Self {
+            data_type,
+            values,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<O: OffsetSizeTrait> ArrayDecoder for ListArrayDecoder<O> {
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData> {
+        let mut nulls = BooleanBufferBuilder::new(pos.len());
+        let mut offsets = Vec::with_capacity(pos.len() + 1);
+        offsets.push(O::zero());
+        let mut value_pos = Vec::with_capacity(pos.len());
+        for p in pos {
+            match tape.get(*p) {
+                TapeElement::StartList(end) => {
+                    let mut cur = *p + 1;
+                    while cur < end {
+                        value_pos.push(cur);
+                        cur = tape.next(cur);
+                    }
+                    nulls.append(true);
+                }
+                TapeElement::Null => nulls.append(false),
+                // we interpret a scalar as a single-value list to minimise data loss
+                _ => {
+                    value_pos.push(*p);
+                    nulls.append(true);
+                }
+            }
+            let offset = O::from_usize(value_pos.len()).ok_or_else(|| {
+                ArrowError::JsonError(format!(
+                    "List of {} values overflows {:?}",
+                    value_pos.len(),
+                    self.data_type
+                ))
+            })?;
+            offsets.push(offset);
+        }
+
+        let values = self.values.decode(tape, &value_pos)?;
+        Ok(ArrayDataBuilder::new(self.data_type.clone())
+            .len(pos.len())
+            .add_buffer(Buffer::from_slice_ref(&offsets))
+            .add_child_data(values)
+            .null_bit_buffer(nulls.finish())
+            .build())
+    }
+}
+
+/// Decodes objects, by the names of their members, any other value being read as null
+struct StructArrayDecoder {
+    data_type: DataType,
+    /// The index of each field by its name
+    field_indices: HashMap<String, usize>,
+    children: Vec<Box<dyn ArrayDecoder>>,
+}
+
+impl StructArrayDecoder {
+    /// Create a decoder of objects with `fields`, where `base64_binary` returns whether
+    /// the binary strings of a field are base64 decoded, in addition to those of the
+    /// fields whose metadata says so
+    fn new(fields: &[Field], base64_binary: impl Fn(&Field) -> bool) -> Result<Self> {
+        let children = fields
+            .iter()
+            .map(|field| make_field_decoder(field, base64_binary(field)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            data_type: DataType::Struct(fields.to_vec()),
+            field_indices: fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| (field.name().clone(), i))
+                .collect(),
+            children,
+        })
+    }
+}
+
+impl ArrayDecoder for StructArrayDecoder {
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData> {
+        let mut nulls = BooleanBufferBuilder::new(pos.len());
+        // the position of the value of each field in each object, or 0 if missing
+        let mut child_pos = vec![vec![0; pos.len()]; self.children.len()];
+        for (row, p) in pos.iter().enumerate() {
+            let end = match tape.get(*p) {
+                TapeElement::StartObject(end) => end,
+                _ => {
+                    nulls.append(false);
+                    continue;
+                }
+            };
+            let mut cur = *p + 1;
+            while cur < end {
+                let name = match tape.get(cur) {
+                    TapeElement::String(idx) => tape.get_string(idx),
+                    _ => return Err(tape.error(cur, "a member name")),
+                };
+                if let Some(i) = self.field_indices.get(name) {
+                    child_pos[*i][row] = cur + 1;
+                }
+                cur = tape.next(cur + 1);
+            }
+            nulls.append(true);
+        }
+
+        let child_data = self
+            .children
+            .iter_mut()
+            .zip(child_pos.iter())
+            .map(|(child, pos)| child.decode(tape, pos))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(ArrayDataBuilder::new(self.data_type.clone())
+            .len(pos.len())
+            .child_data(child_data)
+            .null_bit_buffer(nulls.finish())
+            .build())
+    }
+}
+
+/// Decodes the members of objects into the entries of maps with string keys, any other
+/// value being read as null
+struct MapArrayDecoder {
+    data_type: DataType,
+    entries_type: DataType,
+    keys: StringArrayDecoder<i32>,
+    values: Box<dyn ArrayDecoder>,
+}
+
+impl MapArrayDecoder {
+    fn new(map_field: &Field, keys_sorted: bool, base64_binary: bool) -> Result<Self> {
+        let entry_fields = match map_field.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => fields,
+            t => {
+                return Err(ArrowError::JsonError(format!(
+                    "Map entries should be a struct with 2 fields, found {:?}",
+                    t
+                )))
+            }
+        };
+        let (key_field, value_field) = (&entry_fields[0], &entry_fields[1]);
+        if key_field.data_type() != &DataType::Utf8 {
+            return Err(ArrowError::JsonError(format!(
+                "Map keys should be of type Utf8 when reading JSON, found {:?}",
+                key_field.data_type()
+            )));
+        }
+        Ok(Self {
+            data_type: DataType::Map(Box::new(map_field.clone()), keys_sorted),
+            entries_type: map_field.data_type().clone(),
+            keys: StringArrayDecoder::new(),
+            values: make_field_decoder(value_field, base64_binary)?,
+        })
+    }
+}
+
+impl ArrayDecoder for MapArrayDecoder {
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData> {
+        let mut nulls = BooleanBufferBuilder::new(pos.len());
+        let mut offsets = Vec::with_capacity(pos.len() + 1);
+        offsets.push(0i32);
+        let mut key_pos = Vec::new();
+        let mut value_pos = Vec::new();
+        for p in pos {
+            if let TapeElement::StartObject(end) = tape.get(*p) {
+                let mut cur = *p + 1;
+                while cur < end {
+                    key_pos.push(cur);
+                    value_pos.push(cur + 1);
+                    cur = tape.next(cur + 1);
+                }
+                nulls.append(true);
+            } else {
+                nulls.append(false);
+            }
+            offsets.push(key_pos.len() as i32);
+        }
+
+        let keys = self.keys.decode(tape, &key_pos)?;
+        let values = self.values.decode(tape, &value_pos)?;
+        let entries = ArrayDataBuilder::new(self.entries_type.clone())
+            .len(key_pos.len())
+            .child_data(vec![keys, values])
+            .build();
+        Ok(ArrayDataBuilder::new(self.data_type.clone())
+            .len(pos.len())
+            .add_buffer(Buffer::from_slice_ref(&offsets))
+            .add_child_data(entries)
+            .null_bit_buffer(nulls.finish())
+            .build())
+    }
+}
+
+/// Decodes the values of a dictionary like those of a column of the value type
+struct DictionaryArrayDecoder {
+    data_type: DataType,
+    values: Box<dyn ArrayDecoder>,
+}
+
+impl ArrayDecoder for DictionaryArrayDecoder {
+    fn decode(&mut self, tape: &Tape<'_>, pos: &[u32]) -> Result<ArrayData> {
+        let values = make_array(self.values.decode(tape, pos)?);
+        match &self.data_type {
+            // floating point values can't be cast to a dictionary
+            DataType::Dictionary(key_type, value_type) => match value_type.as_ref() {
+                DataType::Float32 => {
+                    float_dictionary::<Float32Type>(key_type.as_ref(), &values)
+                }
+                DataType::Float64 => {
+                    float_dictionary::<Float64Type>(key_type.as_ref(), &values)
+                }
+                _ => Ok(cast(&values, &self.data_type)?.data().clone()),
+            },
+            _ => Ok(cast(&values, &self.data_type)?.data().clone()),
+        }
+    }
+}
+
+/// Packs floating point `values` into a dictionary with keys of `key_type`
+fn float_dictionary<V: ArrowPrimitiveType>(
+    key_type: &DataType,
+    values: &ArrayRef,
+) -> Result<ArrayData> {
+    match key_type {
+        DataType::Int8 => pack_dictionary::<Int8Type, V>(values),
+        DataType::Int16 => pack_dictionary::<Int16Type, V>(values),
+        DataType::Int32 => pack_dictionary::<Int32Type, V>(values),
+        DataType::Int64 => pack_dictionary::<Int64Type, V>(values),
+        DataType::UInt8 => pack_dictionary::<UInt8Type, V>(values),
+        DataType::UInt16 => pack_dictionary::<UInt16Type, V>(values),
+        DataType::UInt32 => pack_dictionary::<UInt32Type, V>(values),
+        DataType::UInt64 => pack_dictionary::<UInt64Type, V>(values),
+        _ => Err(ArrowError::JsonError(
+            "unsupported dictionary key type".to_string(),
+        )),
+    }
+}
+
+fn pack_dictionary<K, V>(values: &ArrayRef) -> Result<ArrayData>
+where
+    K: ArrowPrimitiveType,
+    V: ArrowPrimitiveType,
+{
+    let values = values
+        .as_any()
+        .downcast_ref::<PrimitiveArray<V>>()
+        .ok_or_else(|| {
+            ArrowError::JsonError("dictionary values of unexpected type".to_string())
+        })?;
+    let mut builder = PrimitiveDictionaryBuilder::new(
+        PrimitiveBuilder::<K>::new(values.len()),
+        PrimitiveBuilder::<V>::new(values.len()),
+    );
+    for value in values.iter() {
+        match value {
+            Some(value) => builder.append(value).map(drop)?,
+            None => builder.append_null()?,
+        }
+    }
+    Ok(builder.finish().data().clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::reader::{BASE64_BINARY_ENCODING, BINARY_ENCODING_KEY};
+    use super::*;
+
+    fn decode(schema: Schema, batch_size: usize, json: &str) -> Vec<RecordBatch> {
+        let mut decoder = RawDecoder::new(Arc::new(schema), batch_size, None);
+        let mut batches = Vec::new();
+        // split the records at every position
+        let mut buf = json.as_bytes();
+        while !buf.is_empty() {
+            let read = decoder.decode(&buf[..1]).unwrap();
+            buf = &buf[read..];
+            if decoder.has_full_batch() {
+                batches.extend(decoder.flush().unwrap());
+            }
+        }
+        batches.extend(decoder.flush().unwrap());
+        batches
+    }
+
+    #[test]
+    fn test_raw_decoder_primitives() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Float64, true),
+            Field::new("c", DataType::Boolean, true),
+            Field::new("d", DataType::Utf8, true),
+            Field::new("e", DataType::Date32, true),
+            Field::new("f", DataType::Decimal(10, 2), true),
+        ]);
+        let json = r#"
+            {"a": 9007199254740993, "b": 1.5e2, "c": true, "d": "x\"y", "e": "2021-01-02", "f": 1.005e1}
+            {"a": 1.0, "b": "2", "c": 1, "d": [1, {"x": null}], "e": 3, "f": "-0.5"}
+            {"z": 1}
+        "#;
+        let batches = decode(schema, 1024, json);
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.num_rows(), 3);
+
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(a.value(0), 9007199254740993);
+        assert_eq!(a.value(1), 1);
+        assert!(a.is_null(2));
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(b.value(0), 150.0);
+        assert!(b.is_null(1));
+        let c = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .unwrap();
+        assert!(c.value(0));
+        assert!(c.is_null(1));
+        let d = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(d.value(0), "x\"y");
+        assert_eq!(d.value(1), r#"[1,{"x":null}]"#);
+        assert!(d.is_null(2));
+        let e = batch
+            .column(4)
+            .as_any()
+            .downcast_ref::<Date32Array>()
+            .unwrap();
+        assert_eq!(e.value(0), 18629);
+        assert_eq!(e.value(1), 3);
+        let f = batch
+            .column(5)
+            .as_any()
+            .downcast_ref::<DecimalArray>()
+            .unwrap();
+        assert_eq!(f.value(0), 1005);
+        assert_eq!(f.value(1), -50);
+        assert!(f.is_null(2));
+    }
+
+    #[test]
+    fn test_raw_decoder_nested() {
+        let entries = Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ]),
+            false,
+        );
+        let schema = Schema::new(vec![
+            Field::new(
+                "a",
+                DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+                true,
+            ),
+            Field::new(
+                "b",
+                DataType::Struct(vec![Field::new("c", DataType::Utf8, true)]),
+                true,
+            ),
+            Field::new("m", DataType::Map(Box::new(entries), false), true),
+            Field::new(
+                "d",
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+                true,
+            ),
+        ]);
+        let json = r#"
+            {"a": [1, null, 3], "b": {"c": "x", "e": [1]}, "m": {"k": 1, "l": 2}, "d": "u"}
+            {"a": 4, "b": null, "m": null, "d": "u"}
+            {"a": null, "b": {}, "d": "v"}
+        "#;
+        let batches = decode(schema, 2, json);
+        assert_eq!(
+            batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>(),
+            vec![2, 1]
+        );
+
+        let a = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(a.value_offsets(), &[0, 3, 4]);
+        let values = a.values();
+        let values = values.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(values.value(2), 3);
+        assert!(values.is_null(1));
+        assert_eq!(values.value(3), 4);
+
+        let b = batches[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert!(b.is_null(1));
+        let c = b.column(0).as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(c.value(0), "x");
+
+        let m = batches[0]
+            .column(2)
+            .as_any()
+            .downcast_ref::<MapArray>()
+            .unwrap();
+        assert_eq!(m.value_offsets(), &[0, 2, 2]);
+        assert!(m.is_null(1));
+        let keys = m.keys();
+        let keys = keys.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(keys.value(1), "l");
+
+        let d = batches[0].column(3);
+        let d = d
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        assert_eq!(d.keys().values(), &[0, 0]);
+
+        let a = batches[1].column(0);
+        assert!(a.is_null(0));
+        let b = batches[1]
+            .column(1)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert!(b.is_valid(0));
+        assert!(b.column(0).is_null(0));
+        assert!(batches[1].column(2).is_null(0));
+    }
+
+    #[test]
+    fn test_raw_decoder_base64_fields() {
+        let mut item = Field::new("item", DataType::Binary, true);
+        item.set_metadata(Some(
+            vec![(
+                BINARY_ENCODING_KEY.to_string(),
+                BASE64_BINARY_ENCODING.to_string(),
+            )]
+            .into_iter()
+            .collect(),
+        ));
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::List(Box::new(item)), true),
+            Field::new(
+                "b",
+                DataType::Struct(vec![Field::new("c", DataType::Binary, true)]),
+                true,
+            ),
+        ]);
+        let json = r#"{"a": ["aGVsbG8="], "b": {"c": "aGVsbG8="}}"#;
+
+        // the metadata of a nested field applies without naming its column
+        let batches = decode(schema.clone(), 16, json);
+        let a = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        let a = a.value(0);
+        let a = a.as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!(a.value(0), b"hello");
+        let b = batches[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        let c = b.column(0).as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!(c.value(0), b"aGVsbG8=");
+
+        // naming a column applies to the binary values nested in it
+        let mut decoder = RawDecoder::new(Arc::new(schema), 16, None)
+            .with_base64_fields(vec!["b".to_string()]);
+        decoder.decode(json.as_bytes()).unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        let c = b.column(0).as_any().downcast_ref::<BinaryArray>().unwrap();
+        assert_eq!(c.value(0), b"hello");
+    }
+
+    #[test]
+    fn test_raw_decoder_errors() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Binary, true)]));
+        let mut decoder = RawDecoder::new(schema.clone(), 16, None);
+        decoder.decode(b"{\"a\": 1}").unwrap();
+        let err = decoder.flush().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Expected a string for a binary field, got: 1"
+        );
+        assert!(decoder.is_empty());
+
+        decoder.decode(b"{\"a\": \"x\"} {\"a\"").unwrap();
+        assert_eq!(decoder.len(), 1);
+        assert!(decoder.flush().is_err());
+        assert!(decoder.flush().unwrap().is_none());
+
+        let mut decoder =
+            RawDecoder::new(schema, 16, None).with_base64_fields(vec!["a".to_string()]);
+        decoder.decode(b"{\"a\": \"aGVsbG8=\"}").unwrap();
+        let batch = decoder.flush().unwrap().unwrap();
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<BinaryArray>()
+            .unwrap();
+        assert_eq!(a.value(0), b"hello");
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            DataType::Interval(IntervalUnit::DayTime),
+            true,
+        )]));
+        let mut decoder = RawDecoder::new(schema, 16, None);
+        decoder.decode(b"{\"a\": 1}").unwrap();
+        let err = decoder.flush().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Interval(DayTime) type is not supported"
+        );
+    }
+}
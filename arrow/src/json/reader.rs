@@ -50,13 +50,28 @@ use indexmap::map::IndexMap as HashMap;
 use indexmap::set::IndexSet as HashSet;
 use serde_json::{map::Map as JsonMap, Value};
 
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+
 use crate::buffer::MutableBuffer;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
-use crate::util::bit_util;
+use crate::temporal_conversions;
+use crate::util::{bit_util, decimal};
 use crate::{array::*, buffer::Buffer};
 
+use super::raw::RawDecoder;
+
+lazy_static! {
+    static ref DATE_RE: Regex = Regex::new(r"^\d{4}-\d\d-\d\d$").unwrap();
+    static ref DATETIME_RE: Regex =
+        Regex::new(r"^\d{4}-\d\d-\d\d[T ]\d\d:\d\d:\d\d(\.\d{1,9})?(Z|[+-]\d\d:\d\d)?$")
+            .unwrap();
+}
+
 #[derive(Debug, Clone)]
 enum InferredType {
     Scalar(HashSet<DataType>),
@@ -96,12 +111,25 @@ impl InferredType {
                 other_inner_type.merge(s.clone())?;
                 *s = InferredType::Array(other_inner_type);
             }
-            // incompatible types
-            (s, o) => {
-                return Err(ArrowError::JsonError(format!(
-                    "Incompatible type found during schema inference: {:?} v.s. {:?}",
-                    s, o,
-                )));
+            // convert an object to a single-item object array type.
+            (
+                InferredType::Array(self_inner_type),
+                other_object @ InferredType::Object(_),
+            ) => {
+                self_inner_type.merge(other_object)?;
+            }
+            (s @ InferredType::Object(_), InferredType::Array(other_inner_type)) => {
+                // merge into the object so that its fields retain their order
+                let mut inner_type = s.clone();
+                inner_type.merge(*other_inner_type)?;
+                *s = InferredType::Array(Box::new(inner_type));
+            }
+            // objects and scalars are not compatible, read both as strings
+            (s @ InferredType::Object(_), InferredType::Scalar(_))
+            | (s @ InferredType::Scalar(_), InferredType::Object(_)) => {
+                let mut hs = HashSet::new();
+                hs.insert(DataType::Utf8);
+                *s = InferredType::Scalar(hs);
             }
         }
 
@@ -112,6 +140,7 @@ impl InferredType {
 /// Coerce data type during inference
 ///
 /// * `Int64` and `Float64` should be `Float64`
+/// * `Date32` and `Date64` should be `Date64`, and dates and timestamps should be timestamps
 /// * Lists and scalars are coerced to a list of a compatible scalar
 /// * All other types are coerced to `Utf8`
 fn coerce_data_type(dt: Vec<&DataType>) -> DataType {
@@ -124,6 +153,15 @@ fn coerce_data_type(dt: Vec<&DataType>) -> DataType {
         (DataType::Float64, DataType::Float64)
         | (DataType::Float64, DataType::Int64)
         | (DataType::Int64, DataType::Float64) => DataType::Float64,
+        (DataType::Date32, DataType::Date32) => DataType::Date32,
+        (DataType::Date64, DataType::Date64)
+        | (DataType::Date32, DataType::Date64)
+        | (DataType::Date64, DataType::Date32) => DataType::Date64,
+        (t @ DataType::Timestamp(_, _), DataType::Timestamp(_, _))
+        | (t @ DataType::Timestamp(_, _), DataType::Date32)
+        | (t @ DataType::Timestamp(_, _), DataType::Date64)
+        | (DataType::Date32, t @ DataType::Timestamp(_, _))
+        | (DataType::Date64, t @ DataType::Timestamp(_, _)) => t,
         (DataType::List(l), DataType::List(r)) => DataType::List(Box::new(Field::new(
             "item",
             coerce_data_type(vec![l.data_type(), r.data_type()]),
@@ -305,207 +343,86 @@ pub fn infer_json_schema<R: Read>(
     infer_json_schema_from_iterator(ValueIter::new(reader, max_read_records))
 }
 
-fn set_object_scalar_field_type(
-    field_types: &mut HashMap<String, InferredType>,
-    key: &str,
-    ftype: DataType,
-) -> Result<()> {
-    if !field_types.contains_key(key) {
-        field_types.insert(key.to_string(), InferredType::Scalar(HashSet::new()));
-    }
-
-    match field_types.get_mut(key).unwrap() {
-        InferredType::Scalar(hs) => {
-            hs.insert(ftype);
-            Ok(())
-        }
-        // in case of column contains both scalar type and scalar array type, we convert type of
-        // this column to scalar array.
-        scalar_array @ InferredType::Array(_) => {
-            let mut hs = HashSet::new();
-            hs.insert(ftype);
-            scalar_array.merge(InferredType::Scalar(hs))?;
-            Ok(())
-        }
-        t => Err(ArrowError::JsonError(format!(
-            "Expected scalar or scalar array JSON type, found: {:?}",
-            t,
-        ))),
-    }
+/// Infer the fields of a JSON file using the provided [`InferenceOptions`].
+///
+/// Like [`infer_json_schema`], this function will not seek back to the start of the `reader`.
+pub fn infer_json_schema_with_options<R: Read>(
+    reader: &mut BufReader<R>,
+    options: &InferenceOptions,
+) -> Result<Schema> {
+    infer_json_schema_from_iterator_with_options(
+        ValueIter::new(reader, options.max_read_records),
+        options,
+    )
 }
 
-fn infer_scalar_array_type(array: &[Value]) -> Result<InferredType> {
-    let mut hs = HashSet::new();
-
-    for v in array {
-        match v {
-            Value::Null => {}
-            Value::Number(n) => {
-                if n.is_i64() {
-                    hs.insert(DataType::Int64);
-                } else {
-                    hs.insert(DataType::Float64);
-                }
-            }
-            Value::Bool(_) => {
-                hs.insert(DataType::Boolean);
-            }
-            Value::String(_) => {
-                hs.insert(DataType::Utf8);
-            }
-            Value::Array(_) | Value::Object(_) => {
-                return Err(ArrowError::JsonError(format!(
-                    "Expected scalar value for scalar array, got: {:?}",
-                    v
-                )));
-            }
+/// Infer the type of a single JSON value, recursing into arrays and objects.
+///
+/// JSON nulls are inferred as [`InferredType::Any`], so that they can be refined by other values.
+fn infer_value_type(value: &Value, options: &InferenceOptions) -> Result<InferredType> {
+    Ok(match value {
+        Value::Null => InferredType::Any,
+        Value::Bool(_) => {
+            InferredType::Scalar(HashSet::from_iter(vec![DataType::Boolean]))
         }
-    }
-
-    Ok(InferredType::Scalar(hs))
-}
-
-fn infer_nested_array_type(array: &[Value]) -> Result<InferredType> {
-    let mut inner_ele_type = InferredType::Any;
-
-    for v in array {
-        match v {
-            Value::Array(inner_array) => {
-                inner_ele_type.merge(infer_array_element_type(inner_array)?)?;
-            }
-            x => {
-                return Err(ArrowError::JsonError(format!(
-                    "Got non array element in nested array: {:?}",
-                    x
-                )));
+        Value::Number(n) => {
+            if n.is_f64() {
+                InferredType::Scalar(HashSet::from_iter(vec![DataType::Float64]))
+            } else {
+                // default to i64
+                InferredType::Scalar(HashSet::from_iter(vec![DataType::Int64]))
             }
         }
-    }
-
-    Ok(InferredType::Array(Box::new(inner_ele_type)))
-}
-
-fn infer_struct_array_type(array: &[Value]) -> Result<InferredType> {
-    let mut field_types = HashMap::new();
-
-    for v in array {
-        match v {
-            Value::Object(map) => {
-                collect_field_types_from_object(&mut field_types, map)?;
-            }
-            _ => {
-                return Err(ArrowError::JsonError(format!(
-                    "Expected struct value for struct array, got: {:?}",
-                    v
-                )));
+        Value::String(string) => {
+            InferredType::Scalar(HashSet::from_iter(vec![infer_string_type(
+                string, options,
+            )]))
+        }
+        Value::Array(array) => {
+            let mut ele_type = InferredType::Any;
+            for v in array {
+                ele_type.merge(infer_value_type(v, options)?)?;
             }
+            InferredType::Array(Box::new(ele_type))
         }
-    }
-
-    Ok(InferredType::Object(field_types))
+        Value::Object(map) => {
+            let mut field_types = HashMap::new();
+            collect_field_types_from_object(&mut field_types, map, options)?;
+            InferredType::Object(field_types)
+        }
+    })
 }
 
-fn infer_array_element_type(array: &[Value]) -> Result<InferredType> {
-    match array.iter().take(1).next() {
-        None => Ok(InferredType::Any), // empty array, return any type that can be updated later
-        Some(a) => match a {
-            Value::Array(_) => infer_nested_array_type(array),
-            Value::Object(_) => infer_struct_array_type(array),
-            _ => infer_scalar_array_type(array),
-        },
+/// Infer the type of a JSON string, which is `Utf8` unless temporal types are
+/// inferred and the string is an ISO 8601 date or datetime.
+fn infer_string_type(string: &str, options: &InferenceOptions) -> DataType {
+    if !options.infer_temporal_types {
+        DataType::Utf8
+    } else if DATE_RE.is_match(string) {
+        DataType::Date32
+    } else if DATETIME_RE.is_match(string) {
+        DataType::Timestamp(TimeUnit::Nanosecond, None)
+    } else {
+        DataType::Utf8
     }
 }
 
 fn collect_field_types_from_object(
     field_types: &mut HashMap<String, InferredType>,
     map: &JsonMap<String, Value>,
+    options: &InferenceOptions,
 ) -> Result<()> {
     for (k, v) in map {
-        match v {
-            Value::Array(array) => {
-                let ele_type = infer_array_element_type(array)?;
-
-                if !field_types.contains_key(k) {
-                    match ele_type {
-                        InferredType::Scalar(_) => {
-                            field_types.insert(
-                                k.to_string(),
-                                InferredType::Array(Box::new(InferredType::Scalar(
-                                    HashSet::new(),
-                                ))),
-                            );
-                        }
-                        InferredType::Object(_) => {
-                            field_types.insert(
-                                k.to_string(),
-                                InferredType::Array(Box::new(InferredType::Object(
-                                    HashMap::new(),
-                                ))),
-                            );
-                        }
-                        InferredType::Any | InferredType::Array(_) => {
-                            // set inner type to any for nested array as well
-                            // so it can be updated properly from subsequent type merges
-                            field_types.insert(
-                                k.to_string(),
-                                InferredType::Array(Box::new(InferredType::Any)),
-                            );
-                        }
-                    }
-                }
-
-                match field_types.get_mut(k).unwrap() {
-                    InferredType::Array(inner_type) => {
-                        inner_type.merge(ele_type)?;
-                    }
-                    // in case of column contains both scalar type and scalar array type, we
-                    // convert type of this column to scalar array.
-                    field_type @ InferredType::Scalar(_) => {
-                        field_type.merge(ele_type)?;
-                        *field_type = InferredType::Array(Box::new(field_type.clone()));
-                    }
-                    t => {
-                        return Err(ArrowError::JsonError(format!(
-                            "Expected array json type, found: {:?}",
-                            t,
-                        )));
-                    }
-                }
-            }
-            Value::Bool(_) => {
-                set_object_scalar_field_type(field_types, k, DataType::Boolean)?;
-            }
-            Value::Null => {
-                // do nothing, we treat json as nullable by default when
-                // inferring
-            }
-            Value::Number(n) => {
-                if n.is_f64() {
-                    set_object_scalar_field_type(field_types, k, DataType::Float64)?;
-                } else {
-                    // default to i64
-                    set_object_scalar_field_type(field_types, k, DataType::Int64)?;
-                }
-            }
-            Value::String(_) => {
-                set_object_scalar_field_type(field_types, k, DataType::Utf8)?;
-            }
-            Value::Object(inner_map) => {
-                if !field_types.contains_key(k) {
-                    field_types
-                        .insert(k.to_string(), InferredType::Object(HashMap::new()));
-                }
-                match field_types.get_mut(k).unwrap() {
-                    InferredType::Object(inner_field_types) => {
-                        collect_field_types_from_object(inner_field_types, inner_map)?;
-                    }
-                    t => {
-                        return Err(ArrowError::JsonError(format!(
-                            "Expected object json type, found: {:?}",
-                            t,
-                        )));
-                    }
-                }
+        // we treat json as nullable by default when inferring, so nulls do not
+        // contribute a type
+        if v.is_null() {
+            continue;
+        }
+        let value_type = infer_value_type(v, options)?;
+        match field_types.get_mut(k) {
+            Some(field_type) => field_type.merge(value_type)?,
+            None => {
+                field_types.insert(k.to_string(), value_type);
             }
         }
     }
@@ -513,11 +430,67 @@ fn collect_field_types_from_object(
     Ok(())
 }
 
+/// Options to configure JSON schema inference
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use std::io::BufReader;
+/// use arrow::json::reader::{infer_json_schema_with_options, InferenceOptions};
+///
+/// let file = File::open("test/data/mixed_arrays.json").unwrap();
+/// let mut reader = BufReader::new(file);
+/// let options = InferenceOptions::new()
+///     .with_max_read_records(Some(100))
+///     .with_temporal_types(true);
+/// let inferred_schema = infer_json_schema_with_options(&mut reader, &options).unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InferenceOptions {
+    /// Maximum number of records to read, all records are read if `None`
+    max_read_records: Option<usize>,
+    /// Whether to infer `Date32` and `Timestamp(Nanosecond, None)` from strings
+    infer_temporal_types: bool,
+}
+
+impl InferenceOptions {
+    /// Create the default inference options, which read all records and do not
+    /// infer temporal types
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of records to read to infer the schema
+    pub fn with_max_read_records(mut self, max_read_records: Option<usize>) -> Self {
+        self.max_read_records = max_read_records;
+        self
+    }
+
+    /// Set whether strings formatted as ISO 8601 dates (`2021-01-31`) and datetimes
+    /// (`2021-01-31T12:34:56.789`) are inferred as `Date32` and
+    /// `Timestamp(Nanosecond, None)` respectively
+    pub fn with_temporal_types(mut self, infer_temporal_types: bool) -> Self {
+        self.infer_temporal_types = infer_temporal_types;
+        self
+    }
+
+    /// Returns the maximum number of records to read to infer the schema
+    pub fn max_read_records(&self) -> Option<usize> {
+        self.max_read_records
+    }
+
+    /// Returns whether temporal types are inferred from strings
+    pub fn infer_temporal_types(&self) -> bool {
+        self.infer_temporal_types
+    }
+}
+
 /// Infer the fields of a JSON file by reading all items from the JSON Value Iterator.
 ///
 /// The following type coercion logic is implemented:
 /// * `Int64` and `Float64` are converted to `Float64`
 /// * Lists and scalars are coerced to a list of a compatible scalar
+/// * Lists and objects are coerced to a list of a compatible object
 /// * All other cases are coerced to `Utf8` (String)
 ///
 /// Note that the above coercion logic is different from what Spark has, where it would default to
@@ -527,15 +500,28 @@ fn collect_field_types_from_object(
 /// interpreted as Strings. We should match Spark's behavior once we added more JSON parsing
 /// kernels in the future.
 pub fn infer_json_schema_from_iterator<I>(value_iter: I) -> Result<Schema>
+where
+    I: Iterator<Item = Result<Value>>,
+{
+    infer_json_schema_from_iterator_with_options(value_iter, &InferenceOptions::default())
+}
+
+/// Infer the fields of a JSON file from the JSON Value Iterator, using the provided
+/// [`InferenceOptions`]. See [`infer_json_schema_from_iterator`] for the coercion rules.
+pub fn infer_json_schema_from_iterator_with_options<I>(
+    value_iter: I,
+    options: &InferenceOptions,
+) -> Result<Schema>
 where
     I: Iterator<Item = Result<Value>>,
 {
     let mut field_types: HashMap<String, InferredType> = HashMap::new();
+    let max_read_records = options.max_read_records.unwrap_or(usize::MAX);
 
-    for record in value_iter {
+    for record in value_iter.take(max_read_records) {
         match record? {
             Value::Object(map) => {
-                collect_field_types_from_object(&mut field_types, &map)?;
+                collect_field_types_from_object(&mut field_types, &map, options)?;
             }
             value => {
                 return Err(ArrowError::JsonError(format!(
@@ -549,9 +535,29 @@ where
     generate_schema(field_types)
 }
 
+/// The key of the field metadata that sets the encoding of the strings of a `Binary` or
+/// `LargeBinary` field, such as [`BASE64_BINARY_ENCODING`]
+pub const BINARY_ENCODING_KEY: &str = "json.binary_encoding";
+
+/// The [`BINARY_ENCODING_KEY`] of binary fields whose strings are base64 encoded
+pub const BASE64_BINARY_ENCODING: &str = "base64";
+
+/// Returns whether the metadata of `field` sets its strings to be base64 encoded
+pub(crate) fn has_base64_encoding(field: &Field) -> bool {
+    field
+        .metadata()
+        .as_ref()
+        .and_then(|metadata| metadata.get(BINARY_ENCODING_KEY))
+        .map_or(false, |encoding| encoding == BASE64_BINARY_ENCODING)
+}
+
 /// JSON values to Arrow record batch decoder. Decoder's next_batch method takes a JSON Value
 /// iterator as input and outputs Arrow record batch.
 ///
+/// Fields of type `Dictionary(K, Utf8)`, including those nested in lists and structs,
+/// are read into dictionary arrays, building the dictionary of each batch as values
+/// are decoded instead of materializing a `Utf8` column first.
+///
 /// # Examples
 /// ```
 /// use arrow::json::reader::{Decoder, ValueIter, infer_json_schema};
@@ -580,6 +586,10 @@ pub struct Decoder {
     projection: Option<Vec<String>>,
     /// Batch size (number of records to load each time)
     batch_size: usize,
+    /// Optional approximate size in bytes of the batches
+    batch_byte_size: Option<usize>,
+    /// The names of the binary fields whose strings are base64 encoded
+    base64_fields: Vec<String>,
 }
 
 impl Decoder {
@@ -594,9 +604,31 @@ impl Decoder {
             schema,
             projection,
             batch_size,
+            batch_byte_size: None,
+            base64_fields: vec![],
         }
     }
 
+    /// Set the names of the `Binary` and `LargeBinary` fields whose strings are decoded
+    /// from base64, in addition to those whose metadata sets [`BINARY_ENCODING_KEY`] to
+    /// [`BASE64_BINARY_ENCODING`]. The UTF-8 bytes of the strings of other binary fields
+    /// are used as their values.
+    pub fn with_base64_fields(mut self, base64_fields: Vec<String>) -> Self {
+        self.base64_fields = base64_fields;
+        self
+    }
+
+    /// Set the approximate size in bytes of the batches, so that records with large values
+    /// produce batches of fewer records than the batch size.
+    ///
+    /// The size of each record is estimated from the length of its strings as it is read,
+    /// and a batch ends with the first record that reaches `batch_byte_size`. A batch has at
+    /// least one record, and at most the batch size of records.
+    pub fn with_batch_byte_size(mut self, batch_byte_size: usize) -> Self {
+        self.batch_byte_size = Some(batch_byte_size);
+        self
+    }
+
     /// Returns the schema of the reader, useful for getting the schema without reading
     /// record batches
     pub fn schema(&self) -> SchemaRef {
@@ -626,11 +658,18 @@ impl Decoder {
         I: Iterator<Item = Result<Value>>,
     {
         let mut rows: Vec<Value> = Vec::with_capacity(self.batch_size);
+        let mut bytes = 0;
 
         for value in value_iter.by_ref().take(self.batch_size) {
             let v = value?;
             match v {
-                Value::Object(_) => rows.push(v),
+                Value::Object(_) => {
+                    bytes += estimated_value_size(&v);
+                    rows.push(v);
+                    if self.is_full(bytes) {
+                        break;
+                    }
+                }
                 _ => {
                     return Err(ArrowError::JsonError(format!(
                         "Row needs to be of type object, got: {:?}",
@@ -644,7 +683,46 @@ impl Decoder {
             return Ok(None);
         }
 
-        let rows = &rows[..];
+        self.decode(&rows).map(Some)
+    }
+
+    /// Returns the maximum number of records the decoder reads into each batch
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Returns whether `bytes` reach the batch byte size.
+    fn is_full(&self, bytes: usize) -> bool {
+        matches!(self.batch_byte_size, Some(size) if bytes >= size)
+    }
+
+    /// Returns the number of the first of `rows` that make the next batch, and whether they
+    /// make a full batch, of the batch size or of the batch byte size.
+    fn next_batch_len(&self, rows: &[Value]) -> (usize, bool) {
+        let mut bytes = 0;
+        for (i, row) in rows.iter().take(self.batch_size).enumerate() {
+            bytes += estimated_value_size(row);
+            if self.is_full(bytes) {
+                return (i + 1, true);
+            }
+        }
+        let len = rows.len().min(self.batch_size);
+        (len, len == self.batch_size)
+    }
+
+    /// Decode a slice of JSON objects into a single record batch, regardless of the
+    /// decoder's batch size.
+    ///
+    /// This is useful when rows are received individually, such as from a message
+    /// queue, and are buffered by the caller. See also [`PushDecoder`].
+    pub fn decode(&self, rows: &[Value]) -> Result<RecordBatch> {
+        if let Some(v) = rows.iter().find(|v| !v.is_object()) {
+            return Err(ArrowError::JsonError(format!(
+                "Row needs to be of type object, got: {:?}",
+                v
+            )));
+        }
+
         let projection = self.projection.clone().unwrap_or_else(Vec::new);
         let arrays = self.build_struct_array(rows, self.schema.fields(), &projection);
 
@@ -661,7 +739,7 @@ impl Decoder {
 
         let projected_schema = Arc::new(Schema::new(projected_fields));
 
-        arrays.and_then(|arr| RecordBatch::try_new(projected_schema, arr).map(Some))
+        arrays.and_then(|arr| RecordBatch::try_new(projected_schema, arr))
     }
 
     fn build_wrapped_list_array(
@@ -746,7 +824,8 @@ impl Decoder {
     {
         let mut builder: Box<dyn ArrayBuilder> = match data_type {
             DataType::Utf8 => {
-                let values_builder = StringBuilder::new(rows.len() * 5);
+                let values_builder =
+                    StringBuilder::with_capacity(rows.len(), rows.len() * 5);
                 Box::new(ListBuilder::new(values_builder))
             }
             DataType::Dictionary(_, _) => {
@@ -850,41 +929,81 @@ impl Decoder {
         T: ArrowPrimitiveType + ArrowDictionaryKeyType,
     {
         let key_builder = PrimitiveBuilder::<T>::new(row_len);
-        let values_builder = StringBuilder::new(row_len * 5);
+        let values_builder = StringBuilder::with_capacity(row_len, row_len * 5);
         Ok(StringDictionaryBuilder::new(key_builder, values_builder))
     }
 
-    #[inline(always)]
-    fn build_string_dictionary_array(
+    /// Build a dictionary array of `key_type` keys and `value_type` values.
+    fn build_dictionary_column(
         &self,
         rows: &[Value],
         col_name: &str,
         key_type: &DataType,
         value_type: &DataType,
     ) -> Result<ArrayRef> {
+        match *key_type {
+            DataType::Int8 => {
+                self.build_dictionary_array::<Int8Type>(rows, col_name, value_type)
+            }
+            DataType::Int16 => {
+                self.build_dictionary_array::<Int16Type>(rows, col_name, value_type)
+            }
+            DataType::Int32 => {
+                self.build_dictionary_array::<Int32Type>(rows, col_name, value_type)
+            }
+            DataType::Int64 => {
+                self.build_dictionary_array::<Int64Type>(rows, col_name, value_type)
+            }
+            DataType::UInt8 => {
+                self.build_dictionary_array::<UInt8Type>(rows, col_name, value_type)
+            }
+            DataType::UInt16 => {
+                self.build_dictionary_array::<UInt16Type>(rows, col_name, value_type)
+            }
+            DataType::UInt32 => {
+                self.build_dictionary_array::<UInt32Type>(rows, col_name, value_type)
+            }
+            DataType::UInt64 => {
+                self.build_dictionary_array::<UInt64Type>(rows, col_name, value_type)
+            }
+            _ => Err(ArrowError::JsonError(
+                "unsupported dictionary key type".to_string(),
+            )),
+        }
+    }
+
+    /// Read the values of a list of string dictionaries into the dictionary's `ArrayData`.
+    fn build_string_dictionary_list_values(
+        &self,
+        rows: &[Value],
+        key_type: &DataType,
+        value_type: &DataType,
+    ) -> Result<ArrayData> {
         if let DataType::Utf8 = *value_type {
             match *key_type {
-                DataType::Int8 => self.build_dictionary_array::<Int8Type>(rows, col_name),
+                DataType::Int8 => {
+                    self.read_string_dictionary_list_values::<Int8Type>(rows)
+                }
                 DataType::Int16 => {
-                    self.build_dictionary_array::<Int16Type>(rows, col_name)
+                    self.read_string_dictionary_list_values::<Int16Type>(rows)
                 }
                 DataType::Int32 => {
-                    self.build_dictionary_array::<Int32Type>(rows, col_name)
+                    self.read_string_dictionary_list_values::<Int32Type>(rows)
                 }
                 DataType::Int64 => {
-                    self.build_dictionary_array::<Int64Type>(rows, col_name)
+                    self.read_string_dictionary_list_values::<Int64Type>(rows)
                 }
                 DataType::UInt8 => {
-                    self.build_dictionary_array::<UInt8Type>(rows, col_name)
+                    self.read_string_dictionary_list_values::<UInt8Type>(rows)
                 }
                 DataType::UInt16 => {
-                    self.build_dictionary_array::<UInt16Type>(rows, col_name)
+                    self.read_string_dictionary_list_values::<UInt16Type>(rows)
                 }
                 DataType::UInt32 => {
-                    self.build_dictionary_array::<UInt32Type>(rows, col_name)
+                    self.read_string_dictionary_list_values::<UInt32Type>(rows)
                 }
                 DataType::UInt64 => {
-                    self.build_dictionary_array::<UInt64Type>(rows, col_name)
+                    self.read_string_dictionary_list_values::<UInt64Type>(rows)
                 }
                 _ => Err(ArrowError::JsonError(
                     "unsupported dictionary key type".to_string(),
@@ -897,6 +1016,24 @@ impl Decoder {
         }
     }
 
+    /// Read the string values of a list into a dictionary with keys of type `T`,
+    /// building the dictionary as values are encountered.
+    fn read_string_dictionary_list_values<T>(&self, rows: &[Value]) -> Result<ArrayData>
+    where
+        T: ArrowPrimitiveType + ArrowDictionaryKeyType,
+    {
+        let values = flatten_json_string_values(rows);
+        let mut builder: StringDictionaryBuilder<T> =
+            self.build_string_dictionary_builder(values.len())?;
+        for value in values {
+            match value {
+                Some(v) => builder.append(&v).map(drop)?,
+                None => builder.append_null()?,
+            }
+        }
+        Ok(builder.finish().data().clone())
+    }
+
     fn build_boolean_array(&self, rows: &[Value], col_name: &str) -> Result<ArrayRef> {
         let mut builder = BooleanBuilder::new(rows.len());
         for row in rows {
@@ -934,6 +1071,97 @@ impl Decoder {
         ))
     }
 
+    /// Build a binary array from strings, which are base64 decoded if enabled for `field`
+    fn build_binary_array<OffsetSize: BinaryOffsetSizeTrait>(
+        &self,
+        rows: &[Value],
+        field: &Field,
+    ) -> Result<ArrayRef> {
+        let col_name = field.name();
+        let base64 = self.base64_fields.contains(col_name) || has_base64_encoding(field);
+        let values = rows
+            .iter()
+            .map(|row| match row.get(col_name) {
+                Some(Value::String(string)) if base64 => {
+                    base64::decode(string).map(Some).map_err(|e| {
+                        ArrowError::JsonError(format!(
+                            "Failed to decode base64 value of field {}: {}",
+                            col_name, e
+                        ))
+                    })
+                }
+                Some(Value::String(string)) => Ok(Some(string.as_bytes().to_vec())),
+                Some(Value::Null) | None => Ok(None),
+                Some(value) => Err(ArrowError::JsonError(format!(
+                    "Expected a string for binary field {}, got: {}",
+                    col_name, value
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Arc::new(
+            values
+                .into_iter()
+                .collect::<GenericBinaryArray<OffsetSize>>(),
+        ))
+    }
+
+    /// Build a decimal array from numbers or numeric strings. Values are parsed from
+    /// their textual representation, so no precision is lost to floating point.
+    fn build_decimal_array(
+        &self,
+        rows: &[Value],
+        col_name: &str,
+        precision: usize,
+        scale: usize,
+    ) -> Result<ArrayRef> {
+        let mut builder = DecimalBuilder::with_capacity(rows.len(), precision, scale);
+        for row in rows {
+            let text = match row.get(&col_name) {
+                Some(Value::Number(number)) => number.to_string(),
+                Some(Value::String(string)) => string.clone(),
+                Some(Value::Null) | None => {
+                    builder.append_null()?;
+                    continue;
+                }
+                Some(value) => {
+                    return Err(ArrowError::JsonError(format!(
+                        "Expected a number for decimal field {}, got: {}",
+                        col_name, value
+                    )))
+                }
+            };
+            builder
+                .append_value(decimal::parse_decimal(&text, precision, scale, None)?)?;
+        }
+        Ok(Arc::new(builder.finish()))
+    }
+
+    /// Build a date or timestamp array from numbers, which are read as the type's
+    /// native value, or from ISO 8601 formatted strings.
+    #[allow(clippy::unnecessary_wraps)]
+    fn build_temporal_array<T: ArrowPrimitiveType>(
+        &self,
+        rows: &[Value],
+        col_name: &str,
+    ) -> Result<ArrayRef>
+    where
+        T: ArrowNumericType,
+        T::Native: num::NumCast,
+    {
+        Ok(Arc::new(
+            rows.iter()
+                .map(|row| match row.get(&col_name) {
+                    Some(Value::String(string)) => {
+                        parse_temporal_string(string, &T::DATA_TYPE)
+                            .and_then(num::cast::cast)
+                    }
+                    Some(value) => value.as_f64().and_then(num::cast::cast),
+                    None => None,
+                })
+                .collect::<PrimitiveArray<T>>(),
+        ))
+    }
+
     /// Build a nested GenericListArray from a list of unnested `Value`s
     fn build_nested_list_array<OffsetSize: OffsetSizeTrait>(
         &self,
@@ -1039,35 +1267,23 @@ impl Decoder {
                 child.data().clone()
             }
             DataType::Struct(fields) => {
-                // extract list values, with non-lists converted to Value::Null
-                let array_item_count = rows
-                    .iter()
-                    .map(|row| match row {
-                        Value::Array(values) => values.len(),
-                        _ => 1,
-                    })
-                    .sum();
-                let num_bytes = bit_util::ceil(array_item_count, 8);
-                let mut null_buffer = MutableBuffer::from_len_zeroed(num_bytes);
-                let mut struct_index = 0;
+                // extract list values, with non-list objects treated as single-value lists,
+                // and null values contributing no items, consistent with the list offsets
                 let rows: Vec<Value> = rows
                     .iter()
-                    .flat_map(|row| {
-                        if let Value::Array(values) = row {
-                            values.iter().for_each(|_| {
-                                bit_util::set_bit(
-                                    null_buffer.as_slice_mut(),
-                                    struct_index,
-                                );
-                                struct_index += 1;
-                            });
-                            values.clone()
-                        } else {
-                            struct_index += 1;
-                            vec![Value::Null]
-                        }
+                    .flat_map(|row| match row {
+                        Value::Array(values) => values.clone(),
+                        Value::Null => vec![],
+                        other => vec![other.clone()],
                     })
                     .collect();
+                let num_bytes = bit_util::ceil(rows.len(), 8);
+                let mut null_buffer = MutableBuffer::from_len_zeroed(num_bytes);
+                rows.iter().enumerate().for_each(|(i, row)| {
+                    if row.is_object() {
+                        bit_util::set_bit(null_buffer.as_slice_mut(), i);
+                    }
+                });
                 let arrays =
                     self.build_struct_array(rows.as_slice(), fields.as_slice(), &[])?;
                 let data_type = DataType::Struct(fields.clone());
@@ -1078,6 +1294,17 @@ impl Decoder {
                     .child_data(arrays.into_iter().map(|a| a.data().clone()).collect())
                     .build()
             }
+            DataType::Dictionary(ref key_ty, ref val_ty) => {
+                self.build_string_dictionary_list_values(rows, key_ty, val_ty)?
+            }
+            DataType::Map(map_field, keys_sorted) => {
+                let child = self.build_map_array(
+                    &flatten_json_values(rows),
+                    map_field,
+                    *keys_sorted,
+                )?;
+                child.data().clone()
+            }
             datatype => {
                 return Err(ArrowError::JsonError(format!(
                     "Nested list of {:?} not supported",
@@ -1086,7 +1313,12 @@ impl Decoder {
             }
         };
         // build list
-        let list_data = ArrayData::builder(DataType::List(Box::new(list_field.clone())))
+        let list_data_type = if OffsetSize::is_large() {
+            DataType::LargeList(Box::new(list_field.clone()))
+        } else {
+            DataType::List(Box::new(list_field.clone()))
+        };
+        let list_data = ArrayData::builder(list_data_type)
             .len(list_len)
             .add_buffer(Buffer::from_slice_ref(&offsets))
             .add_child_data(array_data)
@@ -1151,31 +1383,31 @@ impl Decoder {
                     // TODO: this is incomplete
                     DataType::Timestamp(unit, _) => match unit {
                         TimeUnit::Second => self
-                            .build_primitive_array::<TimestampSecondType>(
+                            .build_temporal_array::<TimestampSecondType>(
                                 rows,
                                 field.name(),
                             ),
                         TimeUnit::Microsecond => self
-                            .build_primitive_array::<TimestampMicrosecondType>(
+                            .build_temporal_array::<TimestampMicrosecondType>(
                                 rows,
                                 field.name(),
                             ),
                         TimeUnit::Millisecond => self
-                            .build_primitive_array::<TimestampMillisecondType>(
+                            .build_temporal_array::<TimestampMillisecondType>(
                                 rows,
                                 field.name(),
                             ),
                         TimeUnit::Nanosecond => self
-                            .build_primitive_array::<TimestampNanosecondType>(
+                            .build_temporal_array::<TimestampNanosecondType>(
                                 rows,
                                 field.name(),
                             ),
                     },
                     DataType::Date64 => {
-                        self.build_primitive_array::<Date64Type>(rows, field.name())
+                        self.build_temporal_array::<Date64Type>(rows, field.name())
                     }
                     DataType::Date32 => {
-                        self.build_primitive_array::<Date32Type>(rows, field.name())
+                        self.build_temporal_array::<Date32Type>(rows, field.name())
                     }
                     DataType::Time64(unit) => match unit {
                         TimeUnit::Microsecond => self
@@ -1209,11 +1441,16 @@ impl Decoder {
                             t
                         ))),
                     },
+                    DataType::Binary => self.build_binary_array::<i32>(rows, field),
+                    DataType::LargeBinary => self.build_binary_array::<i64>(rows, field),
+                    DataType::Decimal(precision, scale) => {
+                        self.build_decimal_array(rows, field.name(), *precision, *scale)
+                    }
                     DataType::Utf8 => Ok(Arc::new(
                         rows.iter()
                             .map(|row| {
                                 let maybe_value = row.get(field.name());
-                                maybe_value.and_then(|value| value.as_str())
+                                maybe_value.and_then(json_value_as_string)
                             })
                             .collect::<StringArray>(),
                     ) as ArrayRef),
@@ -1239,13 +1476,34 @@ impl Decoder {
                             }
                         }
                     }
-                    DataType::Dictionary(ref key_ty, ref val_ty) => self
-                        .build_string_dictionary_array(
-                            rows,
-                            field.name(),
-                            key_ty,
-                            val_ty,
-                        ),
+                    DataType::LargeList(ref list_field) => {
+                        let extracted_rows = rows
+                            .iter()
+                            .map(|row| {
+                                row.get(field.name()).cloned().unwrap_or(Value::Null)
+                            })
+                            .collect::<Vec<Value>>();
+                        self.build_nested_list_array::<i64>(
+                            extracted_rows.as_slice(),
+                            list_field,
+                        )
+                    }
+                    DataType::Map(ref map_field, keys_sorted) => {
+                        let extracted_rows = rows
+                            .iter()
+                            .map(|row| {
+                                row.get(field.name()).cloned().unwrap_or(Value::Null)
+                            })
+                            .collect::<Vec<Value>>();
+                        self.build_map_array(
+                            extracted_rows.as_slice(),
+                            map_field,
+                            *keys_sorted,
+                        )
+                    }
+                    DataType::Dictionary(ref key_ty, ref val_ty) => {
+                        self.build_dictionary_column(rows, field.name(), key_ty, val_ty)
+                    }
                     DataType::Struct(fields) => {
                         let len = rows.len();
                         let num_bytes = bit_util::ceil(len, 8);
@@ -1293,17 +1551,160 @@ impl Decoder {
         arrays
     }
 
-    #[inline(always)]
-    fn build_dictionary_array<T>(
+    /// Build a `MapArray` from a list of JSON objects, where each object's members are
+    /// read as the map's entries. Values that are not objects are read as null maps.
+    ///
+    /// As JSON object keys are always strings, the map's key field must be `Utf8`.
+    fn build_map_array(
         &self,
         rows: &[Value],
-        col_name: &str,
-    ) -> Result<ArrayRef>
-    where
-        T::Native: num::NumCast,
-        T: ArrowPrimitiveType + ArrowDictionaryKeyType,
-    {
-        let mut builder: StringDictionaryBuilder<T> =
+        map_field: &Field,
+        keys_sorted: bool,
+    ) -> Result<ArrayRef> {
+        let entry_fields = match map_field.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => fields,
+            t => {
+                return Err(ArrowError::JsonError(format!(
+                    "Map entries should be a struct with 2 fields, found {:?}",
+                    t
+                )))
+            }
+        };
+        let (key_field, value_field) = (&entry_fields[0], &entry_fields[1]);
+        if key_field.data_type() != &DataType::Utf8 {
+            return Err(ArrowError::JsonError(format!(
+                "Map keys should be of type Utf8 when reading JSON, found {:?}",
+                key_field.data_type()
+            )));
+        }
+
+        let len = rows.len();
+        let num_bytes = bit_util::ceil(len, 8);
+        let mut null_buffer = MutableBuffer::from_len_zeroed(num_bytes);
+        let mut offsets = Vec::with_capacity(len + 1);
+        offsets.push(0i32);
+        // convert each member of each object into an entry object of {key, value}
+        let mut entries: Vec<Value> = Vec::new();
+        rows.iter().enumerate().for_each(|(i, row)| {
+            if let Value::Object(map) = row {
+                bit_util::set_bit(null_buffer.as_slice_mut(), i);
+                map.iter().for_each(|(k, v)| {
+                    let mut entry = JsonMap::with_capacity(2);
+                    entry.insert(key_field.name().clone(), Value::String(k.clone()));
+                    entry.insert(value_field.name().clone(), v.clone());
+                    entries.push(Value::Object(entry));
+                });
+            }
+            offsets.push(entries.len() as i32);
+        });
+
+        let arrays = self.build_struct_array(entries.as_slice(), entry_fields, &[])?;
+        let entries_data = ArrayDataBuilder::new(map_field.data_type().clone())
+            .len(entries.len())
+            .child_data(arrays.into_iter().map(|a| a.data().clone()).collect())
+            .build();
+        let map_data = ArrayDataBuilder::new(DataType::Map(
+            Box::new(map_field.clone()),
+            keys_sorted,
+        ))
+        .len(len)
+        .add_buffer(Buffer::from_slice_ref(&offsets))
+        .add_child_data(entries_data)
+        .null_bit_buffer(null_buffer.into())
+        .build();
+        Ok(make_array(map_data))
+    }
+
+    /// Build a dictionary array of `T` keys and `value_type` values, which are read like
+    /// the values of a column of `value_type`.
+    fn build_dictionary_array<T>(
+        &self,
+        rows: &[Value],
+        col_name: &str,
+        value_type: &DataType,
+    ) -> Result<ArrayRef>
+    where
+        T::Native: num::NumCast,
+        T: ArrowPrimitiveType + ArrowDictionaryKeyType,
+    {
+        match *value_type {
+            DataType::Utf8 => self.build_string_dictionary_array::<T>(rows, col_name),
+            DataType::Int8 => {
+                self.build_primitive_dictionary_array::<T, Int8Type>(rows, col_name)
+            }
+            DataType::Int16 => {
+                self.build_primitive_dictionary_array::<T, Int16Type>(rows, col_name)
+            }
+            DataType::Int32 => {
+                self.build_primitive_dictionary_array::<T, Int32Type>(rows, col_name)
+            }
+            DataType::Int64 => {
+                self.build_primitive_dictionary_array::<T, Int64Type>(rows, col_name)
+            }
+            DataType::UInt8 => {
+                self.build_primitive_dictionary_array::<T, UInt8Type>(rows, col_name)
+            }
+            DataType::UInt16 => {
+                self.build_primitive_dictionary_array::<T, UInt16Type>(rows, col_name)
+            }
+            DataType::UInt32 => {
+                self.build_primitive_dictionary_array::<T, UInt32Type>(rows, col_name)
+            }
+            DataType::UInt64 => {
+                self.build_primitive_dictionary_array::<T, UInt64Type>(rows, col_name)
+            }
+            DataType::Float32 => {
+                self.build_primitive_dictionary_array::<T, Float32Type>(rows, col_name)
+            }
+            DataType::Float64 => {
+                self.build_primitive_dictionary_array::<T, Float64Type>(rows, col_name)
+            }
+            ref other => Err(ArrowError::JsonError(format!(
+                "dictionary values of type {:?} not yet supported",
+                other
+            ))),
+        }
+    }
+
+    /// Build a dictionary array of numeric values.
+    fn build_primitive_dictionary_array<K, V>(
+        &self,
+        rows: &[Value],
+        col_name: &str,
+    ) -> Result<ArrayRef>
+    where
+        K: ArrowPrimitiveType + ArrowDictionaryKeyType,
+        V: ArrowNumericType,
+        V::Native: num::NumCast,
+    {
+        let mut builder = PrimitiveDictionaryBuilder::new(
+            PrimitiveBuilder::<K>::new(rows.len()),
+            PrimitiveBuilder::<V>::new(rows.len()),
+        );
+        for row in rows {
+            let value = row
+                .get(&col_name)
+                .and_then(|value| value.as_f64())
+                .and_then(num::cast::cast);
+            match value {
+                Some(value) => builder.append(value).map(drop)?,
+                None => builder.append_null()?,
+            }
+        }
+        Ok(Arc::new(builder.finish()) as ArrayRef)
+    }
+
+    #[inline(always)]
+    fn build_string_dictionary_array<T>(
+        &self,
+        rows: &[Value],
+        col_name: &str,
+    ) -> Result<ArrayRef>
+    where
+        T::Native: num::NumCast,
+        T: ArrowPrimitiveType + ArrowDictionaryKeyType,
+    {
+        let mut builder: StringDictionaryBuilder<T> =
             self.build_string_dictionary_builder(rows.len())?;
         for row in rows {
             if let Some(value) = row.get(&col_name) {
@@ -1367,8 +1768,49 @@ fn json_value_as_string(value: &Value) -> Option<String> {
     }
 }
 
+/// Parses an ISO 8601 formatted date or datetime string into the native value of a
+/// date or timestamp `data_type`, returning `None` if the string can't be parsed or if
+/// it overflows the type.
+///
+/// Datetimes without a timezone offset are read as UTC.
+pub(super) fn parse_temporal_string(string: &str, data_type: &DataType) -> Option<i64> {
+    match data_type {
+        DataType::Date32 => string
+            .parse::<NaiveDate>()
+            .ok()
+            .map(|date| temporal_conversions::date_to_date32(date) as i64),
+        DataType::Date64 => {
+            parse_datetime(string).map(temporal_conversions::datetime_to_date64)
+        }
+        DataType::Timestamp(unit, _) => parse_datetime(string).and_then(|datetime| {
+            temporal_conversions::datetime_to_timestamp_opt(datetime, unit)
+        }),
+        _ => None,
+    }
+}
+
+/// Parses an ISO 8601 formatted date or datetime string into a UTC datetime.
+fn parse_datetime(string: &str) -> Option<NaiveDateTime> {
+    if let Ok(date) = string.parse::<NaiveDate>() {
+        return Some(date.and_hms(0, 0, 0));
+    }
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(string) {
+        return Some(datetime.naive_utc());
+    }
+    if let Ok(datetime) = DateTime::parse_from_str(string, "%Y-%m-%d %H:%M:%S%.f%:z") {
+        return Some(datetime.naive_utc());
+    }
+    [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.fZ",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(string, format).ok())
+}
+
 /// Flattens a list of JSON values, by flattening lists, and treating all other values as
-/// single-value lists.
+/// single-value lists. Null values contribute no items, consistent with the list offsets.
 /// This is used to read into nested lists (list of list, list of struct) and non-dictionary lists.
 #[inline]
 fn flatten_json_values(values: &[Value]) -> Vec<Value> {
@@ -1378,7 +1820,7 @@ fn flatten_json_values(values: &[Value]) -> Vec<Value> {
             if let Value::Array(values) = row {
                 values.clone()
             } else if let Value::Null = row {
-                vec![Value::Null]
+                vec![]
             } else {
                 // we interpret a scalar as a single-value list to minimise data loss
                 vec![row.clone()]
@@ -1408,12 +1850,230 @@ fn flatten_json_string_values(values: &[Value]) -> Vec<Option<String>> {
         })
         .collect::<Vec<Option<_>>>()
 }
-/// JSON file reader
+
+/// A push-based JSON decoder, which buffers JSON records as they are received and
+/// decodes them into record batches on demand.
+///
+/// Unlike [`Reader`], which pulls records from a [`Read`] implementation, this allows
+/// records to be fed from sources such as message queues or chunked HTTP responses,
+/// either as `serde_json::Value`s or as raw bytes. Bytes can be split at arbitrary
+/// positions, as incomplete trailing records are retained until the next push.
+///
+/// # Example
+///
+/// ```
+/// use arrow::datatypes::{DataType, Field, Schema};
+/// use arrow::json::reader::{Decoder, PushDecoder};
+/// use std::sync::Arc;
+///
+/// let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+/// let mut decoder = PushDecoder::new(Decoder::new(Arc::new(schema), 2, None));
+///
+/// decoder.push_bytes(b"{\"a\": 1}\n{\"a\"").unwrap();
+/// assert!(!decoder.has_full_batch());
+/// decoder.push_bytes(b": 2}\n{\"a\": 3}\n").unwrap();
+/// assert!(decoder.has_full_batch());
+///
+/// let batch = decoder.flush().unwrap().unwrap();
+/// assert_eq!(2, batch.num_rows());
+/// let batch = decoder.finish().unwrap().unwrap();
+/// assert_eq!(1, batch.num_rows());
+/// assert!(decoder.finish().unwrap().is_none());
+/// ```
+#[derive(Debug)]
+pub struct PushDecoder {
+    decoder: Decoder,
+    /// Records that have been received but not yet decoded
+    rows: Vec<Value>,
+    /// Bytes of a record that has only been partially received
+    partial: Vec<u8>,
+    /// The scan of `partial` for the end of the record
+    scanner: RecordScanner,
+}
+
+impl PushDecoder {
+    /// Create a new push-based decoder, which uses `decoder` to convert records
+    pub fn new(decoder: Decoder) -> Self {
+        Self {
+            decoder,
+            rows: vec![],
+            partial: vec![],
+            scanner: RecordScanner::default(),
+        }
+    }
+
+    /// Returns the schema of the batches produced by this decoder
+    pub fn schema(&self) -> SchemaRef {
+        self.decoder.schema()
+    }
+
+    /// Buffer a single JSON record, which must be an object
+    pub fn push_value(&mut self, value: Value) -> Result<()> {
+        match value {
+            Value::Object(_) => {
+                self.rows.push(value);
+                Ok(())
+            }
+            v => Err(ArrowError::JsonError(format!(
+                "Row needs to be of type object, got: {:?}",
+                v
+            ))),
+        }
+    }
+
+    /// Buffer the JSON records contained in `bytes`, which are whitespace or newline
+    /// delimited. A record that is incomplete at the end of `bytes` is completed by
+    /// subsequent calls.
+    ///
+    /// Only the bytes of `bytes` are scanned, so the cost of pushing a record doesn't
+    /// depend on how many chunks it is split into. If a record is invalid, the records
+    /// before it remain buffered, and the rest of the pushed bytes are discarded.
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<()> {
+        self.partial.extend_from_slice(bytes);
+        let result = self.push_complete_records();
+        if result.is_err() {
+            // discard the invalid bytes, so that subsequent pushes can succeed
+            self.partial.clear();
+            self.scanner = RecordScanner::default();
+        }
+        result
+    }
+
+    /// Buffer the records of `partial` that have been completely received, and remove
+    /// their bytes from it.
+    fn push_complete_records(&mut self) -> Result<()> {
+        let mut start = 0;
+        while let Some(end) = self.scanner.scan(&self.partial)? {
+            let value = serde_json::from_slice(&self.partial[start..end])
+                .map_err(|e| ArrowError::JsonError(format!("Not valid JSON: {}", e)))?;
+            self.push_value(value)?;
+            start = end;
+        }
+        if self.scanner.depth == 0 {
+            // only whitespace follows the last record
+            start = self.scanner.offset;
+        }
+        self.partial.drain(..start);
+        self.scanner.offset -= start;
+        Ok(())
+    }
+
+    /// Returns the number of records that have been buffered
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns whether no records are buffered
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns whether at least a full batch of records is buffered, of the decoder's batch
+    /// size or batch byte size
+    pub fn has_full_batch(&self) -> bool {
+        self.decoder.next_batch_len(&self.rows).1
+    }
+
+    /// Decode up to the decoder's batch size or batch byte size of buffered records into a
+    /// record batch, returning `None` if no records are buffered.
+    pub fn flush(&mut self) -> Result<Option<RecordBatch>> {
+        if self.rows.is_empty() {
+            return Ok(None);
+        }
+        let (len, _) = self.decoder.next_batch_len(&self.rows);
+        let rows: Vec<Value> = self.rows.drain(..len).collect();
+        self.decoder.decode(&rows).map(Some)
+    }
+
+    /// Signal that no more bytes will be pushed, and decode the next batch of the
+    /// remaining records, returning an error if a record was only partially received.
+    ///
+    /// This should be called until it returns `None` to drain all buffered records.
+    pub fn finish(&mut self) -> Result<Option<RecordBatch>> {
+        if self.partial.iter().any(|b| !b.is_ascii_whitespace()) {
+            return Err(ArrowError::JsonError(format!(
+                "Incomplete JSON record at end of input: {}",
+                String::from_utf8_lossy(&self.partial).trim_start()
+            )));
+        }
+        self.partial.clear();
+        self.scanner = RecordScanner::default();
+        self.flush()
+    }
+}
+
+/// Finds the ends of the records of a stream of JSON objects, keeping its state between
+/// scans of a growing buffer so that each byte is only scanned once.
+#[derive(Debug, Default)]
+struct RecordScanner {
+    /// The offset of the next byte to scan
+    offset: usize,
+    /// The number of objects and arrays that the next byte is nested in
+    depth: usize,
+    /// Whether the next byte is in a string
+    in_string: bool,
+    /// Whether the next byte is escaped by a backslash in a string
+    escaped: bool,
+}
+
+impl RecordScanner {
+    /// Scans `bytes` from the offset of the previous scan, returning the offset after the
+    /// end of the next record, or `None` if `bytes` ends before the record does.
+    ///
+    /// Returns an error if a record doesn't start with `{` or `[`.
+    fn scan(&mut self, bytes: &[u8]) -> Result<Option<usize>> {
+        while let Some(&b) = bytes.get(self.offset) {
+            self.offset += 1;
+            if self.in_string {
+                match b {
+                    _ if self.escaped => self.escaped = false,
+                    b'\\' => self.escaped = true,
+                    b'"' => self.in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+            match b {
+                b'{' | b'[' => self.depth += 1,
+                b'}' | b']' if self.depth > 0 => {
+                    self.depth -= 1;
+                    if self.depth == 0 {
+                        return Ok(Some(self.offset));
+                    }
+                }
+                b'"' if self.depth > 0 => self.in_string = true,
+                _ if self.depth > 0 || b.is_ascii_whitespace() => {}
+                _ => {
+                    return Err(ArrowError::JsonError(format!(
+                        "Expected a JSON object at the start of a record, found {:?}",
+                        b as char
+                    )))
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Returns the approximate number of bytes a JSON value takes in a record batch: the bytes
+/// of its strings, plus 8 bytes per value for their offsets or fixed size values.
+fn estimated_value_size(value: &Value) -> usize {
+    match value {
+        Value::String(s) => s.len() + 8,
+        Value::Array(values) => {
+            values.iter().map(estimated_value_size).sum::<usize>() + 8
+        }
+        Value::Object(map) => map.values().map(estimated_value_size).sum(),
+        _ => 8,
+    }
+}
+
+/// JSON file reader, which decodes the bytes of the records with a [`RawDecoder`]
 #[derive(Debug)]
 pub struct Reader<R: Read> {
     reader: BufReader<R>,
-    /// JSON value decoder
-    decoder: Decoder,
+    /// JSON bytes decoder
+    decoder: RawDecoder,
 }
 
 impl<R: Read> Reader<R> {
@@ -1441,7 +2101,7 @@ impl<R: Read> Reader<R> {
     ) -> Self {
         Self {
             reader,
-            decoder: Decoder::new(schema, batch_size, projection),
+            decoder: RawDecoder::new(schema, batch_size, projection),
         }
     }
 
@@ -1454,8 +2114,19 @@ impl<R: Read> Reader<R> {
     /// Read the next batch of records
     #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Result<Option<RecordBatch>> {
-        self.decoder
-            .next_batch(&mut ValueIter::new(&mut self.reader, None))
+        loop {
+            let buf = self.reader.fill_buf()?;
+            if buf.is_empty() {
+                break;
+            }
+            let read = buf.len();
+            let decoded = self.decoder.decode(buf)?;
+            self.reader.consume(decoded);
+            if decoded != read || self.decoder.has_full_batch() {
+                break;
+            }
+        }
+        self.decoder.flush()
     }
 }
 
@@ -1471,12 +2142,18 @@ pub struct ReaderBuilder {
     ///
     /// If a number is not provided, all the records are read.
     max_records: Option<usize>,
+    /// Whether to infer date and timestamp types from strings during schema inference
+    infer_temporal_types: bool,
     /// Batch size (number of records to load each time)
     ///
     /// The default batch size when using the `ReaderBuilder` is 1024 records
     batch_size: usize,
+    /// Optional approximate size in bytes of each batch
+    batch_byte_size: Option<usize>,
     /// Optional projection for which columns to load (zero-based column indices)
     projection: Option<Vec<String>>,
+    /// The names of the binary fields whose strings are base64 encoded
+    base64_fields: Vec<String>,
 }
 
 impl Default for ReaderBuilder {
@@ -1484,8 +2161,11 @@ impl Default for ReaderBuilder {
         Self {
             schema: None,
             max_records: None,
+            infer_temporal_types: false,
             batch_size: 1024,
+            batch_byte_size: None,
             projection: None,
+            base64_fields: vec![],
         }
     }
 }
@@ -1532,18 +2212,39 @@ impl ReaderBuilder {
         self
     }
 
+    /// Set whether to infer date and timestamp types from ISO 8601 formatted strings
+    /// when inferring the schema. See [`InferenceOptions::with_temporal_types`].
+    pub fn infer_temporal_types(mut self, infer_temporal_types: bool) -> Self {
+        self.infer_temporal_types = infer_temporal_types;
+        self
+    }
+
     /// Set the batch size (number of records to load at one time)
     pub fn with_batch_size(mut self, batch_size: usize) -> Self {
         self.batch_size = batch_size;
         self
     }
 
+    /// Set the approximate size in bytes of the batches, see
+    /// [`RawDecoder::with_batch_byte_size`]
+    pub fn with_batch_byte_size(mut self, batch_byte_size: usize) -> Self {
+        self.batch_byte_size = Some(batch_byte_size);
+        self
+    }
+
     /// Set the reader's column projection
     pub fn with_projection(mut self, projection: Vec<String>) -> Self {
         self.projection = Some(projection);
         self
     }
 
+    /// Set the names of the `Binary` and `LargeBinary` fields whose strings are decoded
+    /// from base64, see [`RawDecoder::with_base64_fields`]
+    pub fn with_base64_fields(mut self, base64_fields: Vec<String>) -> Self {
+        self.base64_fields = base64_fields;
+        self
+    }
+
     /// Create a new `Reader` from the `ReaderBuilder`
     pub fn build<R>(self, source: R) -> Result<Reader<R>>
     where
@@ -1554,18 +2255,26 @@ impl ReaderBuilder {
         // check if schema should be inferred
         let schema = match self.schema {
             Some(schema) => schema,
-            None => Arc::new(infer_json_schema_from_seekable(
-                &mut buf_reader,
-                self.max_records,
-            )?),
+            None => {
+                let options = InferenceOptions::new()
+                    .with_max_read_records(self.max_records)
+                    .with_temporal_types(self.infer_temporal_types);
+                let schema = infer_json_schema_with_options(&mut buf_reader, &options);
+                // return the reader seek back to the start
+                buf_reader.seek(SeekFrom::Start(0))?;
+                Arc::new(schema?)
+            }
         };
 
-        Ok(Reader::from_buf_reader(
-            buf_reader,
-            schema,
-            self.batch_size,
-            self.projection,
-        ))
+        let mut decoder = RawDecoder::new(schema, self.batch_size, self.projection)
+            .with_base64_fields(self.base64_fields);
+        if let Some(batch_byte_size) = self.batch_byte_size {
+            decoder = decoder.with_batch_byte_size(batch_byte_size);
+        }
+        Ok(Reader {
+            reader: buf_reader,
+            decoder,
+        })
     }
 }
 
@@ -1876,7 +2585,7 @@ mod tests {
             .unwrap();
         assert_eq!(
             reader.next().err().unwrap().to_string(),
-            "Json error: Not valid JSON: expected value at line 1 column 1",
+            "Json error: Expected '{' at the start of a record in JSON, found 'c'",
         );
     }
 
@@ -1978,12 +2687,13 @@ mod tests {
             );
             let cc = cc.values();
             let cc = cc.as_any().downcast_ref::<BooleanArray>().unwrap();
+            // the scalar `true` of the last record is read as a list of one value
             let cc_expected = BooleanArray::from(vec![
                 Some(false),
                 Some(true),
                 Some(false),
                 None,
-                Some(false),
+                Some(true),
             ]);
             assert_eq!(cc.data_ref(), cc_expected.data_ref());
 
@@ -2094,10 +2804,9 @@ mod tests {
             Some("c_text"),
             Some("d_text"),
             None,
-            None,
         ]);
         let c = ArrayDataBuilder::new(c_field.data_type().clone())
-            .len(7)
+            .len(6)
             .add_child_data(d.data().clone())
             .null_bit_buffer(Buffer::from(vec![0b00111011]))
             .build();
@@ -2108,10 +2817,9 @@ mod tests {
             Some(true),
             None,
             Some(true),
-            None,
         ]);
         let a = ArrayDataBuilder::new(a_struct_field.data_type().clone())
-            .len(7)
+            .len(6)
             .add_child_data(b.data().clone())
             .add_child_data(c.clone())
             .null_bit_buffer(Buffer::from(vec![0b00111111]))
@@ -2149,10 +2857,10 @@ mod tests {
             .downcast_ref::<StructArray>()
             .unwrap();
 
-        assert_eq!(7, struct_array.len());
-        assert_eq!(1, struct_array.null_count());
-        assert_eq!(7, expected_struct_array.len());
-        assert_eq!(1, expected_struct_array.null_count());
+        assert_eq!(6, struct_array.len());
+        assert_eq!(0, struct_array.null_count());
+        assert_eq!(6, expected_struct_array.len());
+        assert_eq!(0, expected_struct_array.null_count());
         // test struct's nulls
         assert_eq!(
             struct_array.data().null_buffer(),
@@ -2555,6 +3263,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dictionary_from_json_primitive_values() {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            Dictionary(Box::new(DataType::UInt16), Box::new(DataType::Float64)),
+            true,
+        )]));
+        let content = r#"{"a": 1.5}
+{"a": null}
+{"a": 2}
+{"a": 1.5}
+{}"#;
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema.clone())
+            .build(Cursor::new(content))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(schema, batch.schema());
+
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<DictionaryArray<UInt16Type>>()
+            .unwrap();
+        assert_eq!(
+            a.keys(),
+            &UInt16Array::from(vec![Some(0), None, Some(1), Some(0), None])
+        );
+        assert_eq!(
+            a.values().as_ref(),
+            &Float64Array::from(vec![1.5, 2.0]) as &dyn Array
+        );
+    }
+
     #[test]
     fn test_with_multiple_batches() {
         let builder = ReaderBuilder::new()
@@ -2717,52 +3459,196 @@ mod tests {
     }
 
     #[test]
-    fn test_timestamp_from_json_seconds() {
-        let schema = Schema::new(vec![Field::new(
-            "a",
-            DataType::Timestamp(TimeUnit::Second, None),
-            true,
-        )]);
-
-        let builder = ReaderBuilder::new()
-            .with_schema(Arc::new(schema))
-            .with_batch_size(64);
-        let mut reader: Reader<File> = builder
-            .build::<File>(File::open("test/data/basic_nulls.json").unwrap())
-            .unwrap();
-        let batch = reader.next().unwrap().unwrap();
-
-        assert_eq!(1, batch.num_columns());
-        assert_eq!(12, batch.num_rows());
-
-        let schema = reader.schema();
-        let batch_schema = batch.schema();
-        assert_eq!(schema, batch_schema);
+    fn test_json_infer_schema_conflicting_types() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Utf8, true),
+            Field::new(
+                "c2",
+                DataType::List(Box::new(Field::new(
+                    "item",
+                    DataType::Struct(vec![
+                        Field::new("a", DataType::Int64, true),
+                        Field::new("b", DataType::Boolean, true),
+                    ]),
+                    true,
+                ))),
+                true,
+            ),
+            Field::new(
+                "c3",
+                DataType::List(Box::new(Field::new("item", DataType::Utf8, true))),
+                true,
+            ),
+        ]);
 
-        let a = schema.column_with_name("a").unwrap();
-        assert_eq!(
-            &DataType::Timestamp(TimeUnit::Second, None),
-            a.1.data_type()
-        );
+        let records = vec![
+            serde_json::json!({"c1": {"x": 1}, "c2": {"a": 1}, "c3": [1, {"y": true}]}),
+            serde_json::json!({"c1": 12, "c2": [{"b": true}, {"a": 2}], "c3": "z"}),
+        ];
+        let inferred_schema =
+            infer_json_schema_from_iterator(records.clone().into_iter().map(Ok)).unwrap();
+        assert_eq!(inferred_schema, schema);
 
-        let aa = batch
-            .column(a.0)
+        // conflicting values are read as strings
+        let decoder = Decoder::new(Arc::new(inferred_schema), 1024, None);
+        let batch = decoder
+            .next_batch(&mut records.into_iter().map(Ok))
+            .unwrap()
+            .unwrap();
+        let c1 = batch
+            .column(0)
             .as_any()
-            .downcast_ref::<TimestampSecondArray>()
+            .downcast_ref::<StringArray>()
             .unwrap();
-        assert_eq!(true, aa.is_valid(0));
-        assert_eq!(false, aa.is_valid(1));
-        assert_eq!(false, aa.is_valid(2));
-        assert_eq!(1, aa.value(0));
-        assert_eq!(1, aa.value(3));
-        assert_eq!(5, aa.value(7));
+        assert_eq!(r#"{"x":1}"#, c1.value(0));
+        assert_eq!("12", c1.value(1));
+        let c2 = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(&[0, 1, 3], c2.value_offsets());
     }
 
     #[test]
-    fn test_timestamp_from_json_milliseconds() {
-        let schema = Schema::new(vec![Field::new(
-            "a",
-            DataType::Timestamp(TimeUnit::Millisecond, None),
+    fn test_json_infer_schema_with_options() {
+        let records = vec![
+            serde_json::json!({"d": "2021-01-31", "t": "2021-01-31T12:34:56.789"}),
+            serde_json::json!({"d": "2021-02-01", "t": "2021-01-31"}),
+            serde_json::json!({"d": "not a date"}),
+        ];
+
+        let inferred_schema =
+            infer_json_schema_from_iterator(records.iter().cloned().map(Ok)).unwrap();
+        assert_eq!(&DataType::Utf8, inferred_schema.field(0).data_type());
+        assert_eq!(&DataType::Utf8, inferred_schema.field(1).data_type());
+
+        let options = InferenceOptions::new().with_temporal_types(true);
+        let inferred_schema = infer_json_schema_from_iterator_with_options(
+            records.iter().cloned().map(Ok),
+            &options,
+        )
+        .unwrap();
+        assert_eq!(&DataType::Utf8, inferred_schema.field(0).data_type());
+        assert_eq!(
+            &DataType::Timestamp(TimeUnit::Nanosecond, None),
+            inferred_schema.field(1).data_type()
+        );
+
+        let options = options.with_max_read_records(Some(2));
+        let inferred_schema = infer_json_schema_from_iterator_with_options(
+            records.iter().cloned().map(Ok),
+            &options,
+        )
+        .unwrap();
+        assert_eq!(&DataType::Date32, inferred_schema.field(0).data_type());
+    }
+
+    #[test]
+    fn test_json_read_inferred_temporal_types() {
+        let builder = ReaderBuilder::new()
+            .infer_schema(None)
+            .infer_temporal_types(true);
+        let json_content = r#"
+        {"d": "1970-01-02", "t": "1970-01-01T00:00:01.5", "n": 3}
+        {"d": null, "t": "1970-01-02", "n": 4}
+        "#;
+        let mut reader = builder.build(Cursor::new(json_content)).unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        let d = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Date32Array>()
+            .unwrap();
+        assert_eq!(1, d.value(0));
+        assert!(d.is_null(1));
+        let t = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(1_500_000_000, t.value(0));
+        assert_eq!(86_400_000_000_000, t.value(1));
+    }
+
+    #[test]
+    fn test_json_read_out_of_range_timestamp() {
+        let schema = Schema::new(vec![
+            Field::new("ns", DataType::Timestamp(TimeUnit::Nanosecond, None), true),
+            Field::new("us", DataType::Timestamp(TimeUnit::Microsecond, None), true),
+        ]);
+        let builder = ReaderBuilder::new().with_schema(Arc::new(schema));
+        let json_content = r#"
+        {"ns": "3000-01-01T00:00:00", "us": "3000-01-01T00:00:00"}
+        {"ns": "1600-01-01", "us": "1970-01-01T00:00:01"}
+        "#;
+        let mut reader = builder.build(Cursor::new(json_content)).unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        // nanoseconds overflow outside of the years 1677 to 2262
+        let ns = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(2, ns.null_count());
+        let us = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<TimestampMicrosecondArray>()
+            .unwrap();
+        assert_eq!(32_503_680_000_000_000, us.value(0));
+        assert_eq!(1_000_000, us.value(1));
+    }
+
+    #[test]
+    fn test_timestamp_from_json_seconds() {
+        let schema = Schema::new(vec![Field::new(
+            "a",
+            DataType::Timestamp(TimeUnit::Second, None),
+            true,
+        )]);
+
+        let builder = ReaderBuilder::new()
+            .with_schema(Arc::new(schema))
+            .with_batch_size(64);
+        let mut reader: Reader<File> = builder
+            .build::<File>(File::open("test/data/basic_nulls.json").unwrap())
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+
+        assert_eq!(1, batch.num_columns());
+        assert_eq!(12, batch.num_rows());
+
+        let schema = reader.schema();
+        let batch_schema = batch.schema();
+        assert_eq!(schema, batch_schema);
+
+        let a = schema.column_with_name("a").unwrap();
+        assert_eq!(
+            &DataType::Timestamp(TimeUnit::Second, None),
+            a.1.data_type()
+        );
+
+        let aa = batch
+            .column(a.0)
+            .as_any()
+            .downcast_ref::<TimestampSecondArray>()
+            .unwrap();
+        assert_eq!(true, aa.is_valid(0));
+        assert_eq!(false, aa.is_valid(1));
+        assert_eq!(false, aa.is_valid(2));
+        assert_eq!(1, aa.value(0));
+        assert_eq!(1, aa.value(3));
+        assert_eq!(5, aa.value(7));
+    }
+
+    #[test]
+    fn test_timestamp_from_json_milliseconds() {
+        let schema = Schema::new(vec![Field::new(
+            "a",
+            DataType::Timestamp(TimeUnit::Millisecond, None),
             true,
         )]);
 
@@ -2946,4 +3832,674 @@ mod tests {
         assert_eq!(batch.num_columns(), 1);
         assert_eq!(batch.num_rows(), 3);
     }
+
+    #[test]
+    fn test_json_read_list_of_structs_with_nulls() {
+        let schema = Schema::new(vec![Field::new(
+            "c1",
+            DataType::List(Box::new(Field::new(
+                "item",
+                DataType::Struct(vec![Field::new("a", DataType::Int64, true)]),
+                true,
+            ))),
+            true,
+        )]);
+
+        let decoder = Decoder::new(Arc::new(schema), 1024, None);
+        let batch = decoder
+            .next_batch(
+                &mut vec![
+                    Ok(serde_json::json!({
+                        "c1": null,
+                    })),
+                    Ok(serde_json::json!({
+                        "c1": [{"a": 1}, null, {"a": 2}],
+                    })),
+                    Ok(serde_json::json!({})),
+                    Ok(serde_json::json!({
+                        "c1": [{"a": 3}],
+                    })),
+                ]
+                .into_iter(),
+            )
+            .unwrap()
+            .unwrap();
+
+        let list = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(4, list.len());
+        assert!(list.is_null(0));
+        assert!(list.is_null(2));
+        assert_eq!(&[0, 0, 3, 3, 4], list.value_offsets());
+
+        let structs = list.values();
+        let structs = structs.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(4, structs.len());
+        assert!(structs.is_valid(0));
+        assert!(structs.is_null(1));
+        let a = structs
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(1, a.value(0));
+        assert_eq!(2, a.value(2));
+        assert_eq!(3, a.value(3));
+    }
+
+    #[test]
+    fn test_json_read_deeply_nested_structs() {
+        // struct<b: list<struct<c: struct<d: int64>, e: large_list<utf8>>>>
+        let inner = DataType::Struct(vec![
+            Field::new(
+                "c",
+                DataType::Struct(vec![Field::new("d", DataType::Int64, true)]),
+                true,
+            ),
+            Field::new(
+                "e",
+                DataType::LargeList(Box::new(Field::new("item", DataType::Utf8, true))),
+                true,
+            ),
+        ]);
+        let schema = Schema::new(vec![Field::new(
+            "a",
+            DataType::Struct(vec![Field::new(
+                "b",
+                DataType::List(Box::new(Field::new("item", inner, true))),
+                true,
+            )]),
+            true,
+        )]);
+
+        let decoder = Decoder::new(Arc::new(schema), 1024, None);
+        let batch = decoder
+            .next_batch(
+                &mut vec![
+                    Ok(serde_json::json!({
+                        "a": {"b": [{"c": {"d": 1}, "e": ["x", "y"]}, {"c": null}]},
+                    })),
+                    Ok(serde_json::json!({
+                        "a": {"b": [{"c": {"d": 2}, "e": []}]},
+                    })),
+                    Ok(serde_json::json!({
+                        "a": null,
+                    })),
+                ]
+                .into_iter(),
+            )
+            .unwrap()
+            .unwrap();
+
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert!(a.is_null(2));
+        let b = a.column(0).as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(&[0, 2, 3, 3], b.value_offsets());
+        let items = b.values();
+        let items = items.as_any().downcast_ref::<StructArray>().unwrap();
+        let c = items
+            .column(0)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert!(c.is_valid(0));
+        assert!(c.is_null(1));
+        let d = c.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(1, d.value(0));
+        assert_eq!(2, d.value(2));
+        let e = items
+            .column(1)
+            .as_any()
+            .downcast_ref::<LargeListArray>()
+            .unwrap();
+        assert_eq!(&[0, 2, 2, 2], e.value_offsets());
+        let e_values = e.values();
+        let e_values = e_values.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!("x", e_values.value(0));
+        assert_eq!("y", e_values.value(1));
+    }
+
+    #[test]
+    fn test_json_read_map() {
+        let entries = Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new(
+                    "value",
+                    DataType::List(Box::new(Field::new("item", DataType::Int64, true))),
+                    true,
+                ),
+            ]),
+            false,
+        );
+        let schema = Schema::new(vec![Field::new(
+            "m",
+            DataType::Map(Box::new(entries), false),
+            true,
+        )]);
+
+        let decoder = Decoder::new(Arc::new(schema), 1024, None);
+        let batch = decoder
+            .next_batch(
+                &mut vec![
+                    Ok(serde_json::json!({
+                        "m": {"a": [1, 2], "b": null},
+                    })),
+                    Ok(serde_json::json!({
+                        "m": null,
+                    })),
+                    Ok(serde_json::json!({
+                        "m": {"c": [3]},
+                    })),
+                ]
+                .into_iter(),
+            )
+            .unwrap()
+            .unwrap();
+
+        let map = batch.column(0).as_any().downcast_ref::<MapArray>().unwrap();
+        assert_eq!(3, map.len());
+        assert!(map.is_null(1));
+        assert_eq!(&[0, 2, 2, 3], map.value_offsets());
+
+        let keys = map.keys();
+        let keys = keys.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(
+            vec![Some("a"), Some("b"), Some("c")],
+            keys.iter().collect::<Vec<_>>()
+        );
+        let values = map.values();
+        let values = values.as_any().downcast_ref::<ListArray>().unwrap();
+        assert!(values.is_valid(0));
+        assert!(values.is_null(1));
+        assert_eq!(&[0, 2, 2, 3], values.value_offsets());
+    }
+
+    #[test]
+    fn test_json_read_list_of_maps_with_nulls() {
+        let entries = Field::new(
+            "entries",
+            DataType::Struct(vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int64, true),
+            ]),
+            false,
+        );
+        let schema = Schema::new(vec![Field::new(
+            "l",
+            DataType::List(Box::new(Field::new(
+                "item",
+                DataType::Map(Box::new(entries), false),
+                true,
+            ))),
+            true,
+        )]);
+
+        let decoder = Decoder::new(Arc::new(schema), 1024, None);
+        let batch = decoder
+            .next_batch(
+                &mut vec![
+                    Ok(serde_json::json!({
+                        "l": null,
+                    })),
+                    Ok(serde_json::json!({
+                        "l": [{"a": 1}, {"b": 2, "c": 3}],
+                    })),
+                ]
+                .into_iter(),
+            )
+            .unwrap()
+            .unwrap();
+
+        let list = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(2, list.len());
+        assert!(list.is_null(0));
+        assert_eq!(&[0, 0, 2], list.value_offsets());
+
+        let maps = list.values();
+        let maps = maps.as_any().downcast_ref::<MapArray>().unwrap();
+        assert_eq!(2, maps.len());
+        assert_eq!(&[0, 1, 3], maps.value_offsets());
+        let keys = maps.keys();
+        let keys = keys.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(
+            vec![Some("a"), Some("b"), Some("c")],
+            keys.iter().collect::<Vec<_>>()
+        );
+        let values = maps.values();
+        let values = values.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(
+            vec![Some(1), Some(2), Some(3)],
+            values.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_json_read_nested_list_of_string_dictionary() {
+        let dict_type = Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let schema = Schema::new(vec![
+            Field::new("d", dict_type.clone(), true),
+            Field::new(
+                "l",
+                DataType::LargeList(Box::new(Field::new(
+                    "item",
+                    dict_type.clone(),
+                    true,
+                ))),
+                true,
+            ),
+            Field::new(
+                "s",
+                DataType::Struct(vec![Field::new(
+                    "ll",
+                    DataType::List(Box::new(Field::new(
+                        "item",
+                        DataType::List(Box::new(Field::new(
+                            "item",
+                            dict_type.clone(),
+                            true,
+                        ))),
+                        true,
+                    ))),
+                    true,
+                )]),
+                true,
+            ),
+        ]);
+
+        let decoder = Decoder::new(Arc::new(schema), 1024, None);
+        let batch = decoder
+            .next_batch(
+                &mut vec![
+                    Ok(serde_json::json!({
+                        "d": "a", "l": ["x", "y", "x"], "s": {"ll": [["p"], ["q", "p"]]},
+                    })),
+                    Ok(serde_json::json!({
+                        "d": "b", "l": null, "s": {"ll": [null, ["q"]]},
+                    })),
+                    Ok(serde_json::json!({
+                        "d": "a", "l": ["y", null],
+                    })),
+                ]
+                .into_iter(),
+            )
+            .unwrap()
+            .unwrap();
+
+        let d = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32DictionaryArray>()
+            .unwrap();
+        assert_eq!(2, d.values().len());
+        assert_eq!(
+            vec![Some(0), Some(1), Some(0)],
+            d.keys().iter().collect::<Vec<_>>()
+        );
+
+        let l = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<LargeListArray>()
+            .unwrap();
+        assert!(l.is_null(1));
+        assert_eq!(&[0, 3, 3, 5], l.value_offsets());
+        let l_values = l.values();
+        let l_values = l_values
+            .as_any()
+            .downcast_ref::<Int32DictionaryArray>()
+            .unwrap();
+        assert_eq!(2, l_values.values().len());
+        assert_eq!(
+            vec![Some(0), Some(1), Some(0), Some(1), None],
+            l_values.keys().iter().collect::<Vec<_>>()
+        );
+
+        let s = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        let ll = s.column(0).as_any().downcast_ref::<ListArray>().unwrap();
+        assert_eq!(&[0, 2, 4, 4], ll.value_offsets());
+        let inner = ll.values();
+        let inner = inner.as_any().downcast_ref::<ListArray>().unwrap();
+        assert!(inner.is_null(2));
+        let inner_values = inner.values();
+        let inner_values = inner_values
+            .as_any()
+            .downcast_ref::<Int32DictionaryArray>()
+            .unwrap();
+        assert_eq!(
+            &Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            inner_values.data_type()
+        );
+        assert_eq!(
+            vec![Some(0), Some(1), Some(0), Some(1)],
+            inner_values.keys().iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_push_decoder() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let mut decoder = PushDecoder::new(Decoder::new(Arc::new(schema), 3, None));
+        assert!(decoder.flush().unwrap().is_none());
+
+        // split a record across chunks at arbitrary positions
+        let content =
+            b"{\"a\": 1, \"b\": \"x\"}\n{\"a\": 2}\n\n{\"b\": \"y z\"}\n{\"a\": 4}";
+        for chunk in content.chunks(5) {
+            decoder.push_bytes(chunk).unwrap();
+        }
+        decoder
+            .push_value(serde_json::json!({"a": 5, "b": "w"}))
+            .unwrap();
+        assert_eq!(5, decoder.len());
+        assert!(decoder.has_full_batch());
+
+        let batch = decoder.flush().unwrap().unwrap();
+        assert_eq!(3, batch.num_rows());
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(vec![Some(1), Some(2), None], a.iter().collect::<Vec<_>>());
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(
+            vec![Some("x"), None, Some("y z")],
+            b.iter().collect::<Vec<_>>()
+        );
+
+        assert!(!decoder.has_full_batch());
+        let batch = decoder.finish().unwrap().unwrap();
+        assert_eq!(2, batch.num_rows());
+        assert!(decoder.finish().unwrap().is_none());
+        assert!(decoder.is_empty());
+    }
+
+    #[test]
+    fn test_json_batch_byte_size() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let large = "x".repeat(1000);
+        let content = format!(
+            "{{\"a\": 1}}\n{{\"a\": 2, \"b\": \"{}\"}}\n{{\"a\": 3}}\n{{\"a\": 4}}\n{{\"a\": 5}}",
+            large
+        );
+
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema.clone())
+            .with_batch_size(3)
+            .with_batch_byte_size(100)
+            .build(Cursor::new(content))
+            .unwrap();
+        // the batch ends with the large record, and is otherwise limited by the batch size
+        assert_eq!(2, reader.next().unwrap().unwrap().num_rows());
+        assert_eq!(3, reader.next().unwrap().unwrap().num_rows());
+        assert!(reader.next().unwrap().is_none());
+
+        let decoder = Decoder::new(schema, 3, None).with_batch_byte_size(100);
+        let mut decoder = PushDecoder::new(decoder);
+        decoder.push_value(serde_json::json!({"a": 1})).unwrap();
+        assert!(!decoder.has_full_batch());
+        decoder
+            .push_value(serde_json::json!({"a": 2, "b": large}))
+            .unwrap();
+        decoder.push_value(serde_json::json!({"a": 3})).unwrap();
+        assert!(decoder.has_full_batch());
+        assert_eq!(2, decoder.flush().unwrap().unwrap().num_rows());
+        assert!(!decoder.has_full_batch());
+        assert_eq!(1, decoder.finish().unwrap().unwrap().num_rows());
+    }
+
+    #[test]
+    fn test_push_decoder_errors() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let mut decoder = PushDecoder::new(Decoder::new(Arc::new(schema), 3, None));
+
+        let err = decoder.push_value(serde_json::json!([1])).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Json error: Row needs to be of type object"));
+        assert!(decoder.push_bytes(b"{\"a\": }").is_err());
+        // invalid bytes are discarded
+        decoder.push_bytes(b"{\"a\": 1}\n{\"a\"").unwrap();
+        assert_eq!(1, decoder.len());
+
+        let err = decoder.finish().unwrap_err();
+        assert_eq!(
+            "Json error: Incomplete JSON record at end of input: {\"a\"",
+            err.to_string()
+        );
+
+        // the records before an invalid record are kept
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let mut decoder = PushDecoder::new(Decoder::new(Arc::new(schema), 3, None));
+        let err = decoder
+            .push_bytes(b"{\"a\": 1}\n{\"a\": }\n{\"a\": 3}")
+            .unwrap_err();
+        assert!(err.to_string().starts_with("Json error: Not valid JSON"));
+        assert_eq!(1, decoder.len());
+        let err = decoder.push_bytes(b"{\"a\": 4} 5 {\"a\": 6}").unwrap_err();
+        assert_eq!(
+            "Json error: Expected a JSON object at the start of a record, found '5'",
+            err.to_string()
+        );
+        assert_eq!(2, decoder.len());
+        assert_eq!(2, decoder.finish().unwrap().unwrap().num_rows());
+    }
+
+    #[test]
+    fn test_push_decoder_single_bytes() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new(
+                "c",
+                DataType::List(Box::new(Field::new("item", DataType::Int64, true))),
+                true,
+            ),
+        ]);
+        let mut decoder = PushDecoder::new(Decoder::new(Arc::new(schema), 10, None));
+
+        // strings with escapes and brackets don't end records
+        let content = r#"{"a": 1, "b": "}\"{"}  {"b": "[\\", "c": [1, 2]}
+            {"c": [], "a": 3}
+        "#;
+        let ends = [
+            content.find("}  ").unwrap(),
+            content.find("2]}").unwrap() + 2,
+            content.find("3}").unwrap() + 1,
+        ];
+        for (i, b) in content.bytes().enumerate() {
+            decoder.push_bytes(&[b]).unwrap();
+            // records are buffered as soon as they end
+            let expected = ends.iter().filter(|end| **end <= i).count();
+            assert_eq!(expected, decoder.len());
+        }
+        assert!(decoder.partial.is_empty());
+
+        let batch = decoder.finish().unwrap().unwrap();
+        assert_eq!(3, batch.num_rows());
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(vec![Some(1), None, Some(3)], a.iter().collect::<Vec<_>>());
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(
+            vec![Some("}\"{"), Some("[\\"), None],
+            b.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_json_read_binary() {
+        let mut b = Field::new("b", DataType::LargeBinary, true);
+        b.set_metadata(Some(
+            vec![(
+                BINARY_ENCODING_KEY.to_string(),
+                BASE64_BINARY_ENCODING.to_string(),
+            )]
+            .into_iter()
+            .collect(),
+        ));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Binary, true),
+            b,
+            Field::new("c", DataType::Binary, true),
+        ]));
+        let content = r#"{"a": "aGVsbG8=", "b": "d29ybGQ=", "c": "aGVsbG8="}
+{"a": null}
+{"b": ""}"#;
+
+        // "a" is base64 decoded by name, and "b" by its metadata
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema.clone())
+            .with_base64_fields(vec!["a".to_string()])
+            .build(Cursor::new(content))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<BinaryArray>()
+            .unwrap();
+        assert_eq!(b"hello", a.value(0));
+        assert!(a.is_null(1));
+        assert!(a.is_null(2));
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<LargeBinaryArray>()
+            .unwrap();
+        assert_eq!(b"world", b.value(0));
+        assert!(b.is_null(1));
+        assert_eq!(b"", b.value(2));
+        // without base64 decoding, the string's bytes are used
+        let c = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<BinaryArray>()
+            .unwrap();
+        assert_eq!(b"aGVsbG8=", c.value(0));
+
+        // the decoder of JSON values reads the fields the same way
+        let decoder = Decoder::new(schema.clone(), 1024, None)
+            .with_base64_fields(vec!["a".to_string()]);
+        let batch2 = decoder
+            .next_batch(
+                &mut content
+                    .lines()
+                    .map(|line| Ok(serde_json::from_str(line).unwrap())),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(batch.columns(), batch2.columns());
+
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema)
+            .build(Cursor::new(r#"{"b": "not base64!"}"#))
+            .unwrap();
+        assert!(reader.next().is_err());
+    }
+
+    #[test]
+    fn test_json_read_decimal() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Decimal(10, 2), true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let content = r#"{"a": 1.25, "b": 12345678901234567890}
+{"a": "-3.1", "b": 1.5}
+{"a": 4e2}
+{"a": null}
+{"a": 18446744073709551615}"#;
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema.clone())
+            .with_batch_size(4)
+            .build(Cursor::new(content))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<DecimalArray>()
+            .unwrap();
+        assert_eq!(125, a.value(0));
+        assert_eq!(-310, a.value(1));
+        assert_eq!(40000, a.value(2));
+        assert!(a.is_null(3));
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!("12345678901234567890", b.value(0));
+        assert_eq!("1.5", b.value(1));
+
+        // the value exceeds the decimal's precision
+        let err = reader.next().unwrap_err();
+        assert_eq!(
+            "Parser error: Cannot parse 18446744073709551615 as Decimal(10, 2)",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn test_json_read_arbitrary_precision() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Decimal(38, 0), true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        // numbers are read from their text, so those that overflow an i64 or f64 are
+        // read exactly into the decimal and string fields
+        let content =
+            r#"{"a": 123456789012345678901234567890, "b": 1.00000000000000000000001}"#;
+        let mut reader = ReaderBuilder::new()
+            .with_schema(schema)
+            .build(Cursor::new(content))
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<DecimalArray>()
+            .unwrap();
+        assert_eq!(123456789012345678901234567890, a.value(0));
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!("1.00000000000000000000001", b.value(0));
+    }
 }
@@ -0,0 +1,694 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Tokenizes JSON records into a [`Tape`], a flat list of [`TapeElement`]s from which the
+//! [`RawDecoder`](super::raw::RawDecoder) decodes arrays without materializing a
+//! `serde_json::Value` for each record.
+
+use crate::error::{ArrowError, Result};
+
+/// An element of a [`Tape`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TapeElement {
+    /// The start of an object, with the index of its [`TapeElement::EndObject`]
+    StartObject(u32),
+    /// The end of an object, with the index of its [`TapeElement::StartObject`]
+    EndObject(u32),
+    /// The start of a list, with the index of its [`TapeElement::EndList`]
+    StartList(u32),
+    /// The end of a list, with the index of its [`TapeElement::StartList`]
+    EndList(u32),
+    /// A string, whose contents are at the index of [`Tape::get_string`]
+    String(u32),
+    /// A number, whose text is at the index of [`Tape::get_string`]
+    Number(u32),
+    /// `true`
+    True,
+    /// `false`
+    False,
+    /// `null`
+    Null,
+}
+
+/// JSON records tokenized by a [`TapeDecoder`], as a flat list of [`TapeElement`]s in
+/// which the members of an object alternate between their name and their value.
+///
+/// The element at index 0 is a [`TapeElement::Null`], so that index 0 can stand for a
+/// missing value, and the records follow it.
+#[derive(Debug)]
+pub struct Tape<'a> {
+    elements: &'a [TapeElement],
+    strings: &'a str,
+    string_offsets: &'a [usize],
+    num_rows: usize,
+}
+
+impl<'a> Tape<'a> {
+    /// Returns the element at `idx`
+    #[inline]
+    pub fn get(&self, idx: u32) -> TapeElement {
+        self.elements[idx as usize]
+    }
+
+    /// Returns the contents of a string, or the text of a number, at `idx`
+    #[inline]
+    pub fn get_string(&self, idx: u32) -> &'a str {
+        let idx = idx as usize;
+        &self.strings[self.string_offsets[idx]..self.string_offsets[idx + 1]]
+    }
+
+    /// Returns the index of the element after the value at `idx`
+    #[inline]
+    pub fn next(&self, idx: u32) -> u32 {
+        match self.get(idx) {
+            TapeElement::StartObject(end) | TapeElement::StartList(end) => end + 1,
+            _ => idx + 1,
+        }
+    }
+
+    /// Returns the number of records of this tape
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns the indices of the records of this tape
+    pub fn rows(&self) -> Vec<u32> {
+        let mut idx = 1;
+        (0..self.num_rows)
+            .map(|_| {
+                let row = idx;
+                idx = self.next(idx);
+                row
+            })
+            .collect()
+    }
+
+    /// Returns an error for the value at `idx`, which is not the `expected` value
+    pub(crate) fn error(&self, idx: u32, expected: &str) -> ArrowError {
+        let mut json = String::new();
+        self.serialize(&mut json, idx);
+        ArrowError::JsonError(format!("Expected {}, got: {}", expected, json))
+    }
+
+    /// Writes the value at `idx` as JSON to `out`, returning the index after it
+    pub(crate) fn serialize(&self, out: &mut String, idx: u32) -> u32 {
+        match self.get(idx) {
+            TapeElement::StartObject(end) => {
+                out.push('{');
+                let mut cur = idx + 1;
+                while cur < end {
+                    if cur != idx + 1 {
+                        out.push(',');
+                    }
+                    cur = self.serialize(out, cur);
+                    out.push(':');
+                    cur = self.serialize(out, cur);
+                }
+                out.push('}');
+                end + 1
+            }
+            TapeElement::StartList(end) => {
+                out.push('[');
+                let mut cur = idx + 1;
+                while cur < end {
+                    if cur != idx + 1 {
+                        out.push(',');
+                    }
+                    cur = self.serialize(out, cur);
+                }
+                out.push(']');
+                end + 1
+            }
+            TapeElement::String(s) => {
+                // serializing a string can't fail
+                out.push_str(&serde_json::to_string(self.get_string(s)).unwrap());
+                idx + 1
+            }
+            TapeElement::Number(s) => {
+                out.push_str(self.get_string(s));
+                idx + 1
+            }
+            TapeElement::True => {
+                out.push_str("true");
+                idx + 1
+            }
+            TapeElement::False => {
+                out.push_str("false");
+                idx + 1
+            }
+            TapeElement::Null => {
+                out.push_str("null");
+                idx + 1
+            }
+            TapeElement::EndObject(_) | TapeElement::EndList(_) => idx + 1,
+        }
+    }
+}
+
+/// What a [`TapeDecoder`] expects next in a value it is tokenizing
+#[derive(Debug, Copy, Clone)]
+enum DecoderState {
+    /// A value
+    Value,
+    /// The name of a member or the end of the object that starts at the index
+    Object(u32),
+    /// A value or the end of the list that starts at the index
+    List(u32),
+    /// The contents or the end of a string
+    String,
+    /// The character after a `\` in a string
+    Escape,
+    /// The hex digits of a `\u` escape in a string, with the high surrogate that the code
+    /// unit completes, if any, the code unit read so far, and its number of digits
+    Unicode {
+        high: Option<u16>,
+        unit: u16,
+        digits: u8,
+    },
+    /// The `\` of the low surrogate after a high surrogate, or its `u` after the `\`
+    Surrogate { high: u16, backslash: bool },
+    /// The rest of a literal, of which a number of bytes have been read
+    Literal(&'static [u8], usize),
+    /// The rest of a number
+    Number,
+    /// The `:` after the name of a member
+    Colon,
+}
+
+/// Tokenizes whitespace or newline delimited JSON objects into a [`Tape`].
+///
+/// The decoder keeps its state between calls to [`TapeDecoder::decode`], so that records
+/// can be split across buffers at any position, and each byte is only read once. Strings
+/// are unescaped as they are read, and numbers are kept as text to be parsed into the
+/// type of their column.
+#[derive(Debug)]
+pub struct TapeDecoder {
+    elements: Vec<TapeElement>,
+    /// The contents of the strings and the text of the numbers
+    bytes: Vec<u8>,
+    /// The offsets of the strings and numbers in `bytes`
+    string_offsets: Vec<usize>,
+    /// The states of the values being tokenized, innermost last
+    stack: Vec<DecoderState>,
+    num_rows: usize,
+    batch_size: usize,
+    /// The approximate size in bytes of the batches, if any
+    batch_byte_size: Option<usize>,
+}
+
+impl TapeDecoder {
+    /// Creates a decoder of up to `batch_size` records
+    pub fn new(batch_size: usize) -> Self {
+        let mut elements = Vec::with_capacity(batch_size * 8);
+        elements.push(TapeElement::Null);
+        Self {
+            elements,
+            bytes: Vec::with_capacity(batch_size * 16),
+            string_offsets: vec![0],
+            stack: Vec::with_capacity(16),
+            num_rows: 0,
+            batch_size,
+            batch_byte_size: None,
+        }
+    }
+
+    /// Sets the approximate size in bytes of the batches, so that a batch ends with the
+    /// first record that reaches it, see [`TapeDecoder::estimated_size`]
+    pub fn with_batch_byte_size(mut self, batch_byte_size: usize) -> Self {
+        self.batch_byte_size = Some(batch_byte_size);
+        self
+    }
+
+    /// Returns the number of records that have been completely tokenized
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns whether the batch size of records, or the batch byte size, have been
+    /// tokenized
+    pub fn is_full(&self) -> bool {
+        self.num_rows >= self.batch_size
+            || matches!(self.batch_byte_size, Some(size) if self.estimated_size() >= size)
+    }
+
+    /// Returns the approximate number of bytes the tokenized records take in a record
+    /// batch: the bytes of their strings and numbers, plus 8 bytes for each of them for
+    /// their offsets or fixed size values.
+    pub fn estimated_size(&self) -> usize {
+        self.bytes.len() + 8 * (self.string_offsets.len() - 1)
+    }
+
+    /// Returns whether a record has only been partially tokenized
+    pub fn has_partial_record(&self) -> bool {
+        !self.stack.is_empty()
+    }
+
+    /// Tokenizes the records of `buf`, returning the number of bytes read, which is less
+    /// than the length of `buf` once the batch size of records have been tokenized.
+    ///
+    /// A record that is incomplete at the end of `buf` is completed by subsequent calls.
+    pub fn decode(&mut self, buf: &[u8]) -> Result<usize> {
+        let mut i = 0;
+        while i < buf.len() {
+            let state = match self.stack.last() {
+                Some(state) => *state,
+                None => {
+                    // between records
+                    i += skip_whitespace(&buf[i..], false);
+                    if i == buf.len() || self.is_full() {
+                        break;
+                    }
+                    if buf[i] != b'{' {
+                        return Err(unexpected(buf[i], "'{' at the start of a record"));
+                    }
+                    self.stack.push(DecoderState::Value);
+                    continue;
+                }
+            };
+            match state {
+                DecoderState::Value => {
+                    i += skip_whitespace(&buf[i..], false);
+                    let b = match buf.get(i) {
+                        Some(b) => *b,
+                        None => break,
+                    };
+                    let idx = self.elements.len() as u32;
+                    let next = match b {
+                        b'"' => {
+                            i += 1;
+                            self.elements.push(TapeElement::String(self.string_idx()));
+                            DecoderState::String
+                        }
+                        b'{' => {
+                            i += 1;
+                            self.elements.push(TapeElement::StartObject(u32::MAX));
+                            DecoderState::Object(idx)
+                        }
+                        b'[' => {
+                            i += 1;
+                            self.elements.push(TapeElement::StartList(u32::MAX));
+                            DecoderState::List(idx)
+                        }
+                        b't' => {
+                            i += 1;
+                            self.elements.push(TapeElement::True);
+                            DecoderState::Literal(b"true", 1)
+                        }
+                        b'f' => {
+                            i += 1;
+                            self.elements.push(TapeElement::False);
+                            DecoderState::Literal(b"false", 1)
+                        }
+                        b'n' => {
+                            i += 1;
+                            self.elements.push(TapeElement::Null);
+                            DecoderState::Literal(b"null", 1)
+                        }
+                        b'-' | b'0'..=b'9' => {
+                            self.elements.push(TapeElement::Number(self.string_idx()));
+                            DecoderState::Number
+                        }
+                        b => return Err(unexpected(b, "a value")),
+                    };
+                    self.replace_state(next);
+                }
+                DecoderState::Object(start) => {
+                    i += skip_whitespace(&buf[i..], true);
+                    let b = match buf.get(i) {
+                        Some(b) => *b,
+                        None => break,
+                    };
+                    i += 1;
+                    match b {
+                        b'"' => {
+                            self.elements.push(TapeElement::String(self.string_idx()));
+                            self.stack.extend_from_slice(&[
+                                DecoderState::Value,
+                                DecoderState::Colon,
+                                DecoderState::String,
+                            ]);
+                        }
+                        b'}' => {
+                            let end = self.elements.len() as u32;
+                            self.elements[start as usize] = TapeElement::StartObject(end);
+                            self.elements.push(TapeElement::EndObject(start));
+                            self.stack.pop();
+                            if self.stack.is_empty() {
+                                self.num_rows += 1;
+                            }
+                        }
+                        b => {
+                            return Err(unexpected(b, "a member or the end of an object"))
+                        }
+                    }
+                }
+                DecoderState::List(start) => {
+                    i += skip_whitespace(&buf[i..], true);
+                    match buf.get(i) {
+                        Some(b']') => {
+                            i += 1;
+                            let end = self.elements.len() as u32;
+                            self.elements[start as usize] = TapeElement::StartList(end);
+                            self.elements.push(TapeElement::EndList(start));
+                            self.stack.pop();
+                        }
+                        Some(_) => self.stack.push(DecoderState::Value),
+                        None => break,
+                    }
+                }
+                DecoderState::String => {
+                    let rest = &buf[i..];
+                    let len = rest
+                        .iter()
+                        .position(|b| *b == b'"' || *b == b'\\')
+                        .unwrap_or(rest.len());
+                    self.bytes.extend_from_slice(&rest[..len]);
+                    i += len;
+                    match buf.get(i) {
+                        Some(b'"') => {
+                            i += 1;
+                            self.string_offsets.push(self.bytes.len());
+                            self.stack.pop();
+                        }
+                        Some(_) => {
+                            i += 1;
+                            self.stack.push(DecoderState::Escape);
+                        }
+                        None => break,
+                    }
+                }
+                DecoderState::Escape => {
+                    let b = buf[i];
+                    i += 1;
+                    let unescaped = match b {
+                        b'"' => b'"',
+                        b'\\' => b'\\',
+                        b'/' => b'/',
+                        b'b' => 8,
+                        b'f' => 12,
+                        b'n' => b'\n',
+                        b'r' => b'\r',
+                        b't' => b'\t',
+                        b'u' => {
+                            self.replace_state(DecoderState::Unicode {
+                                high: None,
+                                unit: 0,
+                                digits: 0,
+                            });
+                            continue;
+                        }
+                        b => return Err(unexpected(b, "an escaped character")),
+                    };
+                    self.bytes.push(unescaped);
+                    self.stack.pop();
+                }
+                DecoderState::Unicode { high, unit, digits } => {
+                    let digit = match (buf[i] as char).to_digit(16) {
+                        Some(digit) => digit as u16,
+                        None => return Err(unexpected(buf[i], "a hex digit")),
+                    };
+                    i += 1;
+                    let unit = (unit << 4) | digit;
+                    if digits < 3 {
+                        self.replace_state(DecoderState::Unicode {
+                            high,
+                            unit,
+                            digits: digits + 1,
+                        });
+                        continue;
+                    }
+                    match (high, unit) {
+                        (None, 0xD800..=0xDBFF) => {
+                            self.replace_state(DecoderState::Surrogate {
+                                high: unit,
+                                backslash: false,
+                            });
+                        }
+                        (Some(high), 0xDC00..=0xDFFF) => {
+                            let high = (high - 0xD800) as u32;
+                            let low = (unit - 0xDC00) as u32;
+                            self.push_char(0x10000 + (high << 10) + low);
+                            self.stack.pop();
+                        }
+                        (None, 0xDC00..=0xDFFF) | (Some(_), _) => {
+                            return Err(ArrowError::JsonError(
+                                "Invalid UTF-16 surrogate pair in a JSON string"
+                                    .to_string(),
+                            ))
+                        }
+                        (None, unit) => {
+                            self.push_char(unit as u32);
+                            self.stack.pop();
+                        }
+                    }
+                }
+                DecoderState::Surrogate { high, backslash } => {
+                    let expected = if backslash { b'u' } else { b'\\' };
+                    if buf[i] != expected {
+                        return Err(ArrowError::JsonError(
+                            "Invalid UTF-16 surrogate pair in a JSON string".to_string(),
+                        ));
+                    }
+                    i += 1;
+                    self.replace_state(if backslash {
+                        DecoderState::Unicode {
+                            high: Some(high),
+                            unit: 0,
+                            digits: 0,
+                        }
+                    } else {
+                        DecoderState::Surrogate {
+                            high,
+                            backslash: true,
+                        }
+                    });
+                }
+                DecoderState::Literal(literal, read) => {
+                    if buf[i] != literal[read] {
+                        return Err(unexpected(
+                            buf[i],
+                            std::str::from_utf8(literal).unwrap(),
+                        ));
+                    }
+                    i += 1;
+                    if read + 1 == literal.len() {
+                        self.stack.pop();
+                    } else {
+                        self.replace_state(DecoderState::Literal(literal, read + 1));
+                    }
+                }
+                DecoderState::Number => {
+                    let rest = &buf[i..];
+                    let len = rest
+                        .iter()
+                        .position(|b| {
+                            !matches!(b, b'0'..=b'9' | b'-' | b'+' | b'.' | b'e' | b'E')
+                        })
+                        .unwrap_or(rest.len());
+                    self.bytes.extend_from_slice(&rest[..len]);
+                    i += len;
+                    // the number ends with the first byte that isn't part of it
+                    if i < buf.len() {
+                        self.string_offsets.push(self.bytes.len());
+                        self.stack.pop();
+                    }
+                }
+                DecoderState::Colon => {
+                    i += skip_whitespace(&buf[i..], false);
+                    match buf.get(i) {
+                        Some(b':') => {
+                            i += 1;
+                            self.stack.pop();
+                        }
+                        Some(b) => return Err(unexpected(*b, "':' after a member name")),
+                        None => break,
+                    }
+                }
+            }
+        }
+        Ok(i)
+    }
+
+    /// Returns the tape of the tokenized records.
+    ///
+    /// Returns an error if a record has only been partially tokenized, or if a string
+    /// isn't valid UTF-8.
+    pub fn finish(&self) -> Result<Tape<'_>> {
+        if self.has_partial_record() {
+            return Err(ArrowError::JsonError(format!(
+                "Truncated JSON record after {} records",
+                self.num_rows
+            )));
+        }
+        let strings = std::str::from_utf8(&self.bytes)
+            .ok()
+            .filter(|strings| {
+                self.string_offsets
+                    .iter()
+                    .all(|offset| strings.is_char_boundary(*offset))
+            })
+            .ok_or_else(|| {
+                ArrowError::JsonError("Invalid UTF-8 in a JSON string".to_string())
+            })?;
+        Ok(Tape {
+            elements: &self.elements,
+            strings,
+            string_offsets: &self.string_offsets,
+            num_rows: self.num_rows,
+        })
+    }
+
+    /// Removes the tokenized records, and any partially tokenized record, keeping the
+    /// allocated memory.
+    pub fn clear(&mut self) {
+        self.stack.clear();
+        self.elements.truncate(1);
+        self.bytes.clear();
+        self.string_offsets.truncate(1);
+        self.num_rows = 0;
+    }
+
+    /// Returns the index of the next string or number
+    fn string_idx(&self) -> u32 {
+        (self.string_offsets.len() - 1) as u32
+    }
+
+    fn replace_state(&mut self, state: DecoderState) {
+        *self.stack.last_mut().unwrap() = state;
+    }
+
+    /// Appends the UTF-8 encoding of the code point `c`, which isn't a surrogate
+    fn push_char(&mut self, c: u32) {
+        let c = char::from_u32(c).unwrap();
+        let mut encoded = [0; 4];
+        self.bytes
+            .extend_from_slice(c.encode_utf8(&mut encoded).as_bytes());
+    }
+}
+
+/// Returns the number of whitespace bytes, and commas if `commas`, at the start of `buf`
+fn skip_whitespace(buf: &[u8], commas: bool) -> usize {
+    buf.iter()
+        .take_while(|b| {
+            matches!(b, b' ' | b'\n' | b'\r' | b'\t') || (commas && **b == b',')
+        })
+        .count()
+}
+
+fn unexpected(b: u8, expected: &str) -> ArrowError {
+    ArrowError::JsonError(format!(
+        "Expected {} in JSON, found {:?}",
+        expected, b as char
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tape_decoder() {
+        let mut decoder = TapeDecoder::new(16);
+        let json = br#"{"a": [1, -2.5e3, {}], "b\n\u00e9\ud83d\ude00": "x\"y"}
+            {"c": true, "d": [false, null]}"#;
+        // split the records at every position
+        for chunk in json.chunks(1) {
+            assert_eq!(decoder.decode(chunk).unwrap(), 1);
+        }
+        assert!(!decoder.has_partial_record());
+
+        let tape = decoder.finish().unwrap();
+        assert_eq!(tape.num_rows(), 2);
+        assert_eq!(tape.rows(), vec![1, 12]);
+        assert_eq!(tape.get(1), TapeElement::StartObject(11));
+        assert_eq!(tape.get(2), TapeElement::String(0));
+        assert_eq!(tape.get_string(0), "a");
+        assert_eq!(tape.get(3), TapeElement::StartList(8));
+        assert_eq!(tape.get(5), TapeElement::Number(2));
+        assert_eq!(tape.get_string(2), "-2.5e3");
+        assert_eq!(tape.get(6), TapeElement::StartObject(7));
+        assert_eq!(tape.next(3), 9);
+        assert_eq!(tape.get_string(3), "b\n\u{e9}\u{1f600}");
+        assert_eq!(tape.get_string(4), "x\"y");
+
+        let mut json = String::new();
+        tape.serialize(&mut json, 12);
+        assert_eq!(json, r#"{"c":true,"d":[false,null]}"#);
+
+        decoder.clear();
+        assert_eq!(decoder.finish().unwrap().num_rows(), 0);
+    }
+
+    #[test]
+    fn test_tape_decoder_batch_size() {
+        let mut decoder = TapeDecoder::new(2);
+        let json = b"{\"a\": 1} {\"a\": 2}\n{\"a\": 3}";
+        let read = decoder.decode(json).unwrap();
+        assert_eq!(&json[read..], b"{\"a\": 3}");
+        assert!(decoder.is_full());
+        assert_eq!(decoder.finish().unwrap().num_rows(), 2);
+
+        decoder.clear();
+        assert_eq!(decoder.decode(&json[read..]).unwrap(), json.len() - read);
+        assert_eq!(decoder.num_rows(), 1);
+    }
+
+    #[test]
+    fn test_tape_decoder_batch_byte_size() {
+        let mut decoder = TapeDecoder::new(16).with_batch_byte_size(24);
+        let json = b"{\"a\": 1} {\"bc\": \"d\"} {\"a\": 3}";
+        let read = decoder.decode(json).unwrap();
+        // the second record reaches the batch byte size
+        assert_eq!(&json[read..], b"{\"a\": 3}");
+        assert_eq!(decoder.num_rows(), 2);
+        assert_eq!(decoder.estimated_size(), 37);
+        assert!(decoder.is_full());
+    }
+
+    #[test]
+    fn test_tape_decoder_errors() {
+        let mut decoder = TapeDecoder::new(16);
+        decoder.decode(b"{\"a\": [1, ").unwrap();
+        let err = decoder.finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Truncated JSON record after 0 records"
+        );
+
+        for json in [
+            &b"[1]"[..],
+            b"{\"a\" 1}",
+            b"{\"a\": tru}",
+            b"{\"a\": \"\\x\"}",
+            b"{\"a\": \"\\ud83d\"}",
+            b"{1: 2}",
+        ]
+        .iter()
+        {
+            let mut decoder = TapeDecoder::new(16);
+            assert!(decoder.decode(json).is_err(), "{:?}", json);
+        }
+
+        let mut decoder = TapeDecoder::new(16);
+        decoder.decode(b"{\"a\": \"\xff\"}").unwrap();
+        let err = decoder.finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Invalid UTF-8 in a JSON string"
+        );
+    }
+}
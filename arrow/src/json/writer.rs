@@ -112,6 +112,7 @@ use crate::array::*;
 use crate::datatypes::*;
 use crate::error::Result;
 use crate::record_batch::RecordBatch;
+use crate::util::display::{interval_day_time_to_string, interval_year_month_to_string};
 
 fn primitive_array_to_json<T: ArrowPrimitiveType>(array: &ArrayRef) -> Vec<Value> {
     as_primitive_array::<T>(array)
@@ -237,6 +238,25 @@ macro_rules! set_temporal_column_by_array_type {
     };
 }
 
+fn set_column_by_interval_type<T: ArrowPrimitiveType>(
+    rows: &mut [JsonMap<String, Value>],
+    row_count: usize,
+    array: &ArrayRef,
+    col_name: &str,
+    to_string: fn(T::Native) -> String,
+) {
+    let interval_arr = as_primitive_array::<T>(array);
+
+    rows.iter_mut()
+        .zip(interval_arr.iter())
+        .take(row_count)
+        .for_each(|(row, maybe_value)| {
+            if let Some(v) = maybe_value {
+                row.insert(col_name.to_string(), to_string(v).into());
+            }
+        });
+}
+
 fn set_column_by_primitive_type<T: ArrowPrimitiveType>(
     rows: &mut [JsonMap<String, Value>],
     row_count: usize,
@@ -442,6 +462,24 @@ fn set_column_for_json_rows(
                 value_as_duration
             );
         }
+        DataType::Interval(IntervalUnit::YearMonth) => {
+            set_column_by_interval_type::<IntervalYearMonthType>(
+                rows,
+                row_count,
+                array,
+                col_name,
+                interval_year_month_to_string,
+            );
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            set_column_by_interval_type::<IntervalDayTimeType>(
+                rows,
+                row_count,
+                array,
+                col_name,
+                interval_day_time_to_string,
+            );
+        }
         DataType::Struct(_) => {
             let inner_objs =
                 struct_array_to_jsonmap_array(as_struct_array(array), row_count);
@@ -821,6 +859,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_intervals() {
+        let arr_ym = IntervalYearMonthArray::from(vec![Some(-3), None]);
+        let arr_dt = IntervalDayTimeArray::from(vec![Some((1 << 32) | 1500), None]);
+        let arr_names = StringArray::from(vec![Some("a"), Some("b")]);
+
+        let schema = Schema::new(vec![
+            Field::new("ym", arr_ym.data_type().clone(), true),
+            Field::new("dt", arr_dt.data_type().clone(), true),
+            Field::new("name", arr_names.data_type().clone(), false),
+        ]);
+        let schema = Arc::new(schema);
+
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(arr_ym), Arc::new(arr_dt), Arc::new(arr_names)],
+        )
+        .unwrap();
+
+        let mut buf = Vec::new();
+        {
+            let mut writer = LineDelimitedWriter::new(&mut buf);
+            writer.write_batches(&[batch]).unwrap();
+        }
+
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            r#"{"ym":"0 years -3 mons 0 days 0 hours 0 mins 0.000 secs","dt":"0 years 0 mons 1 days 0 hours 0 mins 1.500 secs","name":"a"}
+{"name":"b"}
+"#
+        );
+    }
+
     #[test]
     fn write_dates() {
         let ts_string = "2018-11-13T17:11:10.011375885995";
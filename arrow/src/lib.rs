@@ -119,6 +119,7 @@
 //! Finally, this crate implements some readers and writers to different formats:
 //!
 //! * json: [reader](json::reader::Reader)
+//! * avro: [reader](avro::reader::Reader), with the `avro` feature
 //! * csv: [reader](csv::reader::Reader) and [writer](csv::writer::Writer)
 //! * ipc: [reader](ipc::reader::StreamReader) and [writer](ipc::writer::FileWriter)
 //!
@@ -145,19 +146,26 @@
 pub mod alloc;
 mod arch;
 pub mod array;
+#[cfg(feature = "avro")]
+pub mod avro;
 pub mod bitmap;
 pub mod buffer;
 mod bytes;
+pub mod chunked_array;
 pub mod compute;
 #[cfg(feature = "csv")]
 pub mod csv;
 pub mod datatypes;
 pub mod error;
 pub mod ffi;
+pub mod ffi_stream;
 #[cfg(feature = "ipc")]
 pub mod ipc;
 pub mod json;
+#[cfg(feature = "pyarrow")]
+pub mod pyarrow;
 pub mod record_batch;
+pub mod table;
 pub mod temporal_conversions;
 pub mod tensor;
 pub mod util;
@@ -0,0 +1,217 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Conversions between arrow-rs and [pyarrow](https://arrow.apache.org/docs/python/)
+//! objects through the [C Data Interface](https://arrow.apache.org/docs/format/CDataInterface.html),
+//! available with the `pyarrow` feature.
+//!
+//! Types implementing [`FromPyArrow`] can be created from the corresponding pyarrow
+//! object, and types implementing [`ToPyArrow`] exported as one:
+//!
+//! | arrow-rs | pyarrow |
+//! |----------|---------|
+//! | [`DataType`] | `pyarrow.DataType` |
+//! | [`Field`] | `pyarrow.Field` |
+//! | [`Schema`] | `pyarrow.Schema` |
+//! | [`ArrayData`] | `pyarrow.Array` |
+//! | [`RecordBatch`] | `pyarrow.RecordBatch` |
+//!
+//! ```ignore
+//! use arrow::array::{make_array, ArrayData};
+//! use arrow::compute::kernels;
+//! use arrow::pyarrow::{FromPyArrow, ToPyArrow};
+//! use pyo3::prelude::*;
+//!
+//! #[pyfunction]
+//! fn double(array: &PyAny, py: Python) -> PyResult<PyObject> {
+//!     let array = make_array(ArrayData::from_pyarrow(array)?);
+//!     let array = kernels::concat::concat(&[array.as_ref(), array.as_ref()])?;
+//!     array.data().to_pyarrow(py)
+//! }
+//! ```
+
+use std::convert::TryFrom;
+use std::sync::Arc;
+
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
+
+use crate::array::{Array, ArrayData, StructArray};
+use crate::datatypes::{DataType, Field, Schema};
+use crate::error::ArrowError;
+use crate::ffi::{self, ArrowArray, FFI_ArrowSchema};
+use crate::record_batch::RecordBatch;
+
+impl From<ArrowError> for PyErr {
+    fn from(err: ArrowError) -> PyErr {
+        PyException::new_err(err.to_string())
+    }
+}
+
+/// Creates a value from the equivalent pyarrow object
+pub trait FromPyArrow: Sized {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self>;
+}
+
+/// Converts a value into the equivalent pyarrow object
+pub trait ToPyArrow {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject>;
+}
+
+fn pyarrow(py: Python) -> PyResult<&PyModule> {
+    py.import("pyarrow")
+}
+
+/// Imports the schema exported by `value._export_to_c`, as a field
+fn field_from_pyarrow(value: &PyAny) -> PyResult<Field> {
+    let c_schema = Box::new(FFI_ArrowSchema::empty());
+    let c_schema_ptr = Box::into_raw(c_schema);
+    let result = value.call_method1("_export_to_c", (c_schema_ptr as usize,));
+    // take back ownership, releasing the exported schema when dropped
+    let c_schema = unsafe { Box::from_raw(c_schema_ptr) };
+    result?;
+    Ok(ffi::to_field(&c_schema)?)
+}
+
+/// Exports `field` to the pyarrow `class`, by calling its `_import_from_c`
+fn field_to_pyarrow(field: Field, class: &str, py: Python) -> PyResult<PyObject> {
+    let c_schema_ptr = Box::into_raw(Box::new(FFI_ArrowSchema::try_new(field)?));
+    let result = pyarrow(py)?
+        .getattr(class)?
+        .call_method1("_import_from_c", (c_schema_ptr as usize,));
+    // pyarrow moves the schema out of the struct, which only needs to be freed
+    drop(unsafe { Box::from_raw(c_schema_ptr) });
+    Ok(result?.to_object(py))
+}
+
+impl FromPyArrow for DataType {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        Ok(field_from_pyarrow(value)?.data_type().clone())
+    }
+}
+
+impl ToPyArrow for DataType {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        field_to_pyarrow(Field::new("", self.clone(), true), "DataType", py)
+    }
+}
+
+impl FromPyArrow for Field {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        field_from_pyarrow(value)
+    }
+}
+
+impl ToPyArrow for Field {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        field_to_pyarrow(self.clone(), "Field", py)
+    }
+}
+
+impl FromPyArrow for Schema {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        // schemas are exported as struct fields
+        match field_from_pyarrow(value)?.data_type() {
+            DataType::Struct(fields) => Ok(Schema::new(fields.clone())),
+            other => Err(ArrowError::CDataInterface(format!(
+                "Expected a struct schema, got {:?}",
+                other
+            ))
+            .into()),
+        }
+    }
+}
+
+impl ToPyArrow for Schema {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        let field = Field::new("", DataType::Struct(self.fields().clone()), false);
+        field_to_pyarrow(field, "Schema", py)
+    }
+}
+
+/// Imports the array exported by `value._export_to_c`
+fn array_from_pyarrow(value: &PyAny) -> PyResult<ArrayData> {
+    // prepare pointers to receive the array and its schema
+    let (array_ptr, schema_ptr) = ArrowArray::into_raw(unsafe { ArrowArray::empty() });
+
+    let result =
+        value.call_method1("_export_to_c", (array_ptr as usize, schema_ptr as usize));
+    // take back ownership, releasing the exported array when dropped
+    let array = unsafe { ArrowArray::try_from_raw(array_ptr, schema_ptr) }?;
+    result?;
+    Ok(ArrayData::try_from(array)?)
+}
+
+/// Exports `data` to the pyarrow `class`, by calling its `_import_from_c`
+fn array_to_pyarrow(data: ArrayData, class: &str, py: Python) -> PyResult<PyObject> {
+    let array = ArrowArray::try_from(data)?;
+    let (array_ptr, schema_ptr) = ArrowArray::into_raw(array);
+
+    let result = pyarrow(py)?
+        .getattr(class)?
+        .call_method1("_import_from_c", (array_ptr as usize, schema_ptr as usize));
+    // pyarrow moves the array out of the structs, which only need to be freed
+    drop(unsafe { ArrowArray::try_from_raw(array_ptr, schema_ptr) }?);
+    Ok(result?.to_object(py))
+}
+
+impl FromPyArrow for ArrayData {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        array_from_pyarrow(value)
+    }
+}
+
+impl ToPyArrow for ArrayData {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        array_to_pyarrow(self.clone(), "Array", py)
+    }
+}
+
+impl FromPyArrow for RecordBatch {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        // record batches are exported as struct arrays
+        let data = array_from_pyarrow(value)?;
+        match data.data_type() {
+            DataType::Struct(_) => Ok(RecordBatch::from(&StructArray::from(data))),
+            other => Err(ArrowError::CDataInterface(format!(
+                "Expected a struct array, got {:?}",
+                other
+            ))
+            .into()),
+        }
+    }
+}
+
+impl ToPyArrow for RecordBatch {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        let struct_array = StructArray::from(self.clone());
+        array_to_pyarrow(struct_array.data().clone(), "RecordBatch", py)
+    }
+}
+
+impl<T: FromPyArrow> FromPyArrow for Arc<T> {
+    fn from_pyarrow(value: &PyAny) -> PyResult<Self> {
+        Ok(Arc::new(T::from_pyarrow(value)?))
+    }
+}
+
+impl<T: ToPyArrow> ToPyArrow for Arc<T> {
+    fn to_pyarrow(&self, py: Python) -> PyResult<PyObject> {
+        self.as_ref().to_pyarrow(py)
+    }
+}
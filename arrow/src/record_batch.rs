@@ -18,11 +18,14 @@
 //! A two-dimensional batch of column-oriented data with a defined
 //! [schema](crate::datatypes::Schema).
 
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
 use std::sync::Arc;
 
 use crate::array::*;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
+use crate::ffi::{self, ArrowArrayRef};
 
 /// A two-dimensional batch of column-oriented data with a defined
 /// [schema](crate::datatypes::Schema).
@@ -40,13 +43,16 @@ use crate::error::{ArrowError, Result};
 pub struct RecordBatch {
     schema: SchemaRef,
     columns: Vec<Arc<Array>>,
+    /// The number of rows, which is stored separately to support batches without columns
+    row_count: usize,
 }
 
 impl RecordBatch {
     /// Creates a `RecordBatch` from a schema and columns.
     ///
     /// Expects the following:
-    ///  * the vec of columns to not be empty
+    ///  * the vec of columns to not be empty, see [`RecordBatch::try_new_with_options`]
+    ///    to create a batch without columns
     ///  * the schema and column data types to have equal lengths
     ///    and match
     ///  * each array in columns to have the same length
@@ -75,22 +81,47 @@ impl RecordBatch {
     /// # }
     /// ```
     pub fn try_new(schema: SchemaRef, columns: Vec<ArrayRef>) -> Result<Self> {
-        let options = RecordBatchOptions::default();
-        Self::validate_new_batch(&schema, columns.as_slice(), &options)?;
-        Ok(RecordBatch { schema, columns })
+        Self::try_new_with_options(schema, columns, &RecordBatchOptions::default())
     }
 
     /// Creates a `RecordBatch` from a schema and columns, with additional options,
     /// such as whether to strictly validate field names.
     ///
-    /// See [`RecordBatch::try_new`] for the expected conditions.
+    /// See [`RecordBatch::try_new`] for the expected conditions. The columns may be
+    /// empty if [`RecordBatchOptions::row_count`] is set, e.g. for the result of a
+    /// `SELECT COUNT(*)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow::datatypes::Schema;
+    /// use arrow::record_batch::{RecordBatch, RecordBatchOptions};
+    ///
+    /// # fn main() -> arrow::error::Result<()> {
+    /// let options = RecordBatchOptions {
+    ///     row_count: Some(10),
+    ///     ..Default::default()
+    /// };
+    /// let batch =
+    ///     RecordBatch::try_new_with_options(Arc::new(Schema::empty()), vec![], &options)?;
+    ///
+    /// assert_eq!(batch.num_columns(), 0);
+    /// assert_eq!(batch.num_rows(), 10);
+    /// # Ok(())
+    /// # }
+    /// ```
     pub fn try_new_with_options(
         schema: SchemaRef,
         columns: Vec<ArrayRef>,
         options: &RecordBatchOptions,
     ) -> Result<Self> {
-        Self::validate_new_batch(&schema, columns.as_slice(), options)?;
-        Ok(RecordBatch { schema, columns })
+        let row_count = Self::validate_new_batch(&schema, columns.as_slice(), options)?;
+        Ok(RecordBatch {
+            schema,
+            columns,
+            row_count,
+        })
     }
 
     /// Creates a new empty [`RecordBatch`].
@@ -100,23 +131,32 @@ impl RecordBatch {
             .iter()
             .map(|field| new_empty_array(field.data_type()))
             .collect();
-        RecordBatch { schema, columns }
+        RecordBatch {
+            schema,
+            columns,
+            row_count: 0,
+        }
     }
 
-    /// Validate the schema and columns using [`RecordBatchOptions`]. Returns an error
-    /// if any validation check fails.
+    /// Validate the schema and columns using [`RecordBatchOptions`], returning the
+    /// number of rows of the batch. Returns an error if any validation check fails.
     fn validate_new_batch(
         schema: &SchemaRef,
         columns: &[ArrayRef],
         options: &RecordBatchOptions,
-    ) -> Result<()> {
-        // check that there are some columns
-        if columns.is_empty() {
-            return Err(ArrowError::InvalidArgumentError(
-                "at least one column must be defined to create a record batch"
-                    .to_string(),
-            ));
-        }
+    ) -> Result<usize> {
+        // check that there are some columns, or that the row count is specified
+        let len = match (options.row_count, columns.first()) {
+            (Some(row_count), _) => row_count,
+            (None, Some(column)) => column.len(),
+            (None, None) => {
+                return Err(ArrowError::InvalidArgumentError(
+                    "at least one column must be defined to create a record batch, \
+                     unless the row count is specified"
+                        .to_string(),
+                ))
+            }
+        };
         // check that number of fields in schema match column length
         if schema.fields().len() != columns.len() {
             return Err(ArrowError::InvalidArgumentError(format!(
@@ -126,17 +166,20 @@ impl RecordBatch {
             )));
         }
         // check that all columns have the same row count, and match the schema
-        let len = columns[0].data().len();
+        if !options.skip_length_validation {
+            if let Some(column) = columns.iter().find(|column| column.len() != len) {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "all columns in a record batch must have the same length, \
+                     expected {} but found {}",
+                    len,
+                    column.len()
+                )));
+            }
+        }
 
         // This is a bit repetitive, but it is better to check the condition outside the loop
         if options.match_field_names {
             for (i, column) in columns.iter().enumerate() {
-                if column.len() != len {
-                    return Err(ArrowError::InvalidArgumentError(
-                        "all columns in a record batch must have the same length"
-                            .to_string(),
-                    ));
-                }
                 if column.data_type() != schema.field(i).data_type() {
                     return Err(ArrowError::InvalidArgumentError(format!(
                         "column types must match schema types, expected {:?} but found {:?} at column index {}",
@@ -147,12 +190,6 @@ impl RecordBatch {
             }
         } else {
             for (i, column) in columns.iter().enumerate() {
-                if column.len() != len {
-                    return Err(ArrowError::InvalidArgumentError(
-                        "all columns in a record batch must have the same length"
-                            .to_string(),
-                    ));
-                }
                 if !column
                     .data_type()
                     .equals_datatype(schema.field(i).data_type())
@@ -166,7 +203,7 @@ impl RecordBatch {
             }
         }
 
-        Ok(())
+        Ok(len)
     }
 
     /// Returns the [`Schema`](crate::datatypes::Schema) of the record batch.
@@ -202,10 +239,6 @@ impl RecordBatch {
 
     /// Returns the number of rows in each column.
     ///
-    /// # Panics
-    ///
-    /// Panics if the `RecordBatch` contains no columns.
-    ///
     /// # Example
     ///
     /// ```
@@ -227,7 +260,7 @@ impl RecordBatch {
     /// # }
     /// ```
     pub fn num_rows(&self) -> usize {
-        self.columns[0].data().len()
+        self.row_count
     }
 
     /// Get a reference to a column's array by index.
@@ -244,6 +277,28 @@ impl RecordBatch {
         &self.columns[..]
     }
 
+    /// Returns a zero-copy slice of this batch, with the `length` rows from `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + length` is greater than the number of rows.
+    pub fn slice(&self, offset: usize, length: usize) -> RecordBatch {
+        assert!(
+            offset + length <= self.num_rows(),
+            "the offset and length of the slice of a record batch must be in bounds"
+        );
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| column.slice(offset, length))
+            .collect();
+        Self {
+            schema: self.schema.clone(),
+            columns,
+            row_count: length,
+        }
+    }
+
     /// Create a `RecordBatch` from an iterable list of pairs of the
     /// form `(field_name, array)`, with the same requirements on
     /// fields and arrays as [`RecordBatch::try_new`]. This method is
@@ -328,6 +383,153 @@ impl RecordBatch {
         let schema = Arc::new(Schema::new(fields));
         RecordBatch::try_new(schema, columns)
     }
+
+    /// Returns a `RecordBatch` whose struct columns are replaced by their fields,
+    /// recursively. Each field becomes a column named after the path to it, joined
+    /// by dots (e.g. `a.b`), and is nullable when any of its parents is.
+    ///
+    /// The nulls of a struct are merged into the nulls of its fields, see
+    /// [`StructArray::flatten`]; the values of the columns are not copied.
+    ///
+    /// Example:
+    /// ```
+    /// use std::convert::TryFrom;
+    /// use std::sync::Arc;
+    /// use arrow::array::{ArrayRef, Int32Array, StructArray};
+    /// use arrow::record_batch::RecordBatch;
+    ///
+    /// let b: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    /// let a: ArrayRef = Arc::new(StructArray::try_from(vec![("b", b)]).unwrap());
+    ///
+    /// let batch = RecordBatch::try_from_iter(vec![("a", a)]).unwrap().flatten().unwrap();
+    /// assert_eq!(batch.schema().field(0).name(), "a.b");
+    /// ```
+    pub fn flatten(&self) -> Result<Self> {
+        let mut fields = vec![];
+        let mut columns = vec![];
+        for (field, column) in self.schema.fields().iter().zip(&self.columns) {
+            flatten_column(
+                field.name(),
+                field,
+                field.is_nullable(),
+                column.clone(),
+                &mut fields,
+                &mut columns,
+            );
+        }
+        let schema = Schema::new_with_metadata(fields, self.schema.metadata().clone());
+        let options = RecordBatchOptions {
+            row_count: Some(self.row_count),
+            ..Default::default()
+        };
+        RecordBatch::try_new_with_options(Arc::new(schema), columns, &options)
+    }
+
+    /// Exports this `RecordBatch` to the C Data Interface as a single struct array,
+    /// whose fields are the columns of this batch.
+    ///
+    /// The metadata of the batch's schema is exported as the metadata of the struct.
+    /// See [`ffi::ArrowArray::into_raw`] to obtain the pointers to hand to a consumer.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow::array::{ArrayRef, Int32Array};
+    /// use arrow::ffi::ArrowArray;
+    /// use arrow::record_batch::RecordBatch;
+    ///
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    /// let batch = RecordBatch::try_from_iter(vec![("a", a)]).unwrap();
+    ///
+    /// // export it
+    /// let (array, schema) = ArrowArray::into_raw(batch.to_ffi().unwrap());
+    ///
+    /// // import it
+    /// let imported = unsafe { ArrowArray::try_from_raw(array, schema) }.unwrap();
+    /// let imported = RecordBatch::from_ffi(imported).unwrap();
+    /// assert_eq!(imported.schema(), batch.schema());
+    /// ```
+    pub fn to_ffi(&self) -> Result<ffi::ArrowArray> {
+        let metadata = self
+            .schema
+            .metadata()
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect::<BTreeMap<_, _>>();
+        let struct_array = StructArray::from(self.clone());
+        let mut field = Field::new("", struct_array.data_type().clone(), false);
+        field.set_metadata(Some(metadata));
+
+        unsafe { ffi::ArrowArray::try_new_with_field(struct_array.data().clone(), field) }
+    }
+
+    /// Imports a `RecordBatch` from a struct array of the C Data Interface, such as
+    /// one exported by [`RecordBatch::to_ffi`].
+    ///
+    /// The metadata of the struct becomes the metadata of the batch's schema.
+    pub fn from_ffi(array: ffi::ArrowArray) -> Result<Self> {
+        let field = ffi::to_field(array.schema())?;
+        let fields = match field.data_type() {
+            DataType::Struct(fields) => fields.clone(),
+            other => {
+                return Err(ArrowError::CDataInterface(format!(
+                    "Expected a struct array to import a RecordBatch, got {:?}",
+                    other
+                )))
+            }
+        };
+        let metadata = field
+            .metadata()
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .collect::<HashMap<_, _>>();
+
+        let struct_array = StructArray::from(ArrayData::try_from(array)?);
+        let columns = (0..struct_array.num_columns())
+            .map(|i| struct_array.column(i).clone())
+            .collect();
+        let schema = Schema::new_with_metadata(fields, metadata);
+        RecordBatch::try_new(Arc::new(schema), columns)
+    }
+}
+
+/// Appends the leaves of `column`, named `name`, to `fields` and `columns`, descending
+/// into structs.
+fn flatten_column(
+    name: &str,
+    field: &Field,
+    nullable: bool,
+    column: ArrayRef,
+    fields: &mut Vec<Field>,
+    columns: &mut Vec<ArrayRef>,
+) {
+    match field.data_type() {
+        DataType::Struct(children) => {
+            let struct_array = column.as_any().downcast_ref::<StructArray>().unwrap();
+            for (child, child_column) in children.iter().zip(struct_array.flatten()) {
+                flatten_column(
+                    &format!("{}.{}", name, child.name()),
+                    child,
+                    nullable || child.is_nullable(),
+                    child_column,
+                    fields,
+                    columns,
+                );
+            }
+        }
+        data_type => {
+            let mut flat_field = Field::new_dict(
+                name,
+                data_type.clone(),
+                nullable,
+                field.dict_id().unwrap_or_default(),
+                field.dict_is_ordered().unwrap_or_default(),
+            );
+            flat_field.set_metadata(field.metadata().clone());
+            fields.push(flat_field);
+            columns.push(column);
+        }
+    }
 }
 
 /// Options that control the behaviour used when creating a [`RecordBatch`].
@@ -335,12 +537,20 @@ impl RecordBatch {
 pub struct RecordBatchOptions {
     /// Match field names of structs and lists. If set to `true`, the names must match.
     pub match_field_names: bool,
+    /// The number of rows of the batch, which allows creating a batch without columns.
+    /// If set, the columns must have this many rows.
+    pub row_count: Option<usize>,
+    /// Skip checking that all columns have the same length. If set to `true`, the
+    /// caller must guarantee that they do, as other methods rely on it.
+    pub skip_length_validation: bool,
 }
 
 impl Default for RecordBatchOptions {
     fn default() -> Self {
         Self {
             match_field_names: true,
+            row_count: None,
+            skip_length_validation: false,
         }
     }
 }
@@ -357,6 +567,7 @@ impl From<&StructArray> for RecordBatch {
             RecordBatch {
                 schema: Arc::new(schema),
                 columns,
+                row_count: struct_array.len(),
             }
         } else {
             unreachable!("unable to get datatype as struct")
@@ -520,11 +731,64 @@ mod tests {
         // creating the batch without field name validation should pass
         let options = RecordBatchOptions {
             match_field_names: false,
+            ..Default::default()
         };
         let batch = RecordBatch::try_new_with_options(schema, vec![a], &options);
         assert!(batch.is_ok());
     }
 
+    #[test]
+    fn create_record_batch_with_row_count() {
+        let schema = Arc::new(Schema::empty());
+        assert!(RecordBatch::try_new(schema.clone(), vec![]).is_err());
+
+        let options = RecordBatchOptions {
+            row_count: Some(3),
+            ..Default::default()
+        };
+        let batch = RecordBatch::try_new_with_options(schema, vec![], &options).unwrap();
+        assert_eq!(0, batch.num_columns());
+        assert_eq!(3, batch.num_rows());
+        assert_eq!(1, batch.slice(1, 1).num_rows());
+
+        // the columns must have the specified row count
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let err =
+            RecordBatch::try_new_with_options(schema.clone(), vec![a.clone()], &options)
+                .unwrap_err();
+        assert_eq!(
+            "Invalid argument error: all columns in a record batch must have the same length, expected 3 but found 2",
+            err.to_string()
+        );
+
+        let options = RecordBatchOptions {
+            row_count: Some(2),
+            ..Default::default()
+        };
+        let batch = RecordBatch::try_new_with_options(schema, vec![a], &options).unwrap();
+        assert_eq!(2, batch.num_rows());
+    }
+
+    #[test]
+    fn create_record_batch_skip_length_validation() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let columns = vec![a, b];
+        assert!(RecordBatch::try_new(schema.clone(), columns.clone()).is_err());
+
+        let options = RecordBatchOptions {
+            skip_length_validation: true,
+            ..Default::default()
+        };
+        let batch = RecordBatch::try_new_with_options(schema, columns, &options).unwrap();
+        assert_eq!(2, batch.num_rows());
+    }
+
     #[test]
     fn create_record_batch_record_mismatch() {
         let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
@@ -562,4 +826,91 @@ mod tests {
         assert_eq!(batch.column(0).as_ref(), boolean.as_ref());
         assert_eq!(batch.column(1).as_ref(), int.as_ref());
     }
+
+    #[test]
+    fn test_ffi_round_trip() {
+        let mut metadata = HashMap::new();
+        metadata.insert("source".to_string(), "test".to_string());
+        let schema = Schema::new_with_metadata(
+            vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Utf8, true),
+            ],
+            metadata,
+        );
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let b = StringArray::from(vec![Some("a"), None, Some("c")]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a), Arc::new(b)])
+                .unwrap();
+
+        let (array, schema) = ffi::ArrowArray::into_raw(batch.to_ffi().unwrap());
+        let imported = unsafe { ffi::ArrowArray::try_from_raw(array, schema) }.unwrap();
+        let imported = RecordBatch::from_ffi(imported).unwrap();
+
+        assert_eq!(imported.schema(), batch.schema());
+        assert_eq!(imported.columns(), batch.columns());
+    }
+
+    #[test]
+    fn test_ffi_import_non_struct() {
+        let array = Int32Array::from(vec![1, 2, 3]);
+        let array = ffi::ArrowArray::try_from(array.data().clone()).unwrap();
+        assert!(RecordBatch::from_ffi(array).is_err());
+    }
+
+    #[test]
+    fn test_flatten() {
+        let c: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3]));
+        let d: ArrayRef = Arc::new(StringArray::from(vec![Some("x"), None, Some("z")]));
+        let inner = StructArray::from(vec![
+            (Field::new("c", DataType::Int32, false), c),
+            (Field::new("d", DataType::Utf8, true), d),
+        ]);
+        let e: ArrayRef = Arc::new(BooleanArray::from(vec![true, false, true]));
+        let outer: ArrayRef = Arc::new(StructArray::from((
+            vec![
+                (
+                    Field::new("b", inner.data_type().clone(), false),
+                    Arc::new(inner) as ArrayRef,
+                ),
+                (Field::new("e", DataType::Boolean, false), e),
+            ],
+            Buffer::from(&[0b00000101]),
+        )));
+        let id: ArrayRef = Arc::new(Int32Array::from(vec![10, 20, 30]));
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("a", outer.data_type().clone(), true),
+        ]);
+        // the columns are sliced to check that the struct offset reaches the fields
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![id.slice(1, 2), outer.slice(1, 2)],
+        )
+        .unwrap();
+
+        let flat = batch.flatten().unwrap();
+
+        let expected = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("a.b.c", DataType::Int32, true),
+            Field::new("a.b.d", DataType::Utf8, true),
+            Field::new("a.e", DataType::Boolean, true),
+        ]);
+        assert_eq!(flat.schema().as_ref(), &expected);
+        assert_eq!(flat.column(0).as_ref(), id.slice(1, 2).as_ref());
+        assert_eq!(
+            flat.column(1).as_ref(),
+            &Int32Array::from(vec![None, Some(3)])
+        );
+        assert_eq!(
+            flat.column(2).as_ref(),
+            &StringArray::from(vec![None, Some("z")])
+        );
+        assert_eq!(
+            flat.column(3).as_ref(),
+            &BooleanArray::from(vec![None, Some(true)])
+        );
+    }
 }
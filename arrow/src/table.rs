@@ -0,0 +1,350 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A two-dimensional dataset of [ChunkedArray] columns, which, unlike a [RecordBatch],
+//! doesn't require its columns to be contiguous arrays.
+
+use crate::chunked_array::ChunkedArray;
+use crate::datatypes::SchemaRef;
+use crate::error::{ArrowError, Result};
+use crate::record_batch::RecordBatch;
+
+/// A two-dimensional dataset of a schema and [ChunkedArray] columns of the same length,
+/// e.g. the rows of several [RecordBatch]es, whose columns don't need to be chunked the
+/// same way.
+///
+/// # Example
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::Int32Array;
+/// use arrow::datatypes::{DataType, Field, Schema};
+/// use arrow::record_batch::RecordBatch;
+/// use arrow::table::Table;
+///
+/// # fn main() -> arrow::error::Result<()> {
+/// let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+/// let batches = vec![
+///     RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![1, 2]))])?,
+///     RecordBatch::try_new(schema.clone(), vec![Arc::new(Int32Array::from(vec![3]))])?,
+/// ];
+///
+/// let table = Table::try_from_batches(schema, &batches)?;
+/// assert_eq!(table.num_rows(), 3);
+/// assert_eq!(table.column(0).num_chunks(), 2);
+///
+/// let batch = table.slice(1, 2).concat()?;
+/// assert_eq!(batch.column(0).as_ref(), &Int32Array::from(vec![2, 3]));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Table {
+    schema: SchemaRef,
+    columns: Vec<ChunkedArray>,
+    num_rows: usize,
+}
+
+impl Table {
+    /// Creates a table from a schema and its columns.
+    ///
+    /// Returns an error if the number or the data types of the columns don't match the
+    /// schema, or if the columns don't have the same length.
+    pub fn try_new(schema: SchemaRef, columns: Vec<ChunkedArray>) -> Result<Self> {
+        if schema.fields().len() != columns.len() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "The number of columns of a table ({}) must match the number of fields ({}) of its schema",
+                columns.len(),
+                schema.fields().len()
+            )));
+        }
+        for (field, column) in schema.fields().iter().zip(&columns) {
+            if field.data_type() != column.data_type() {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "The column \"{}\" of a table must be of type {:?}, found {:?}",
+                    field.name(),
+                    field.data_type(),
+                    column.data_type()
+                )));
+            }
+        }
+        let num_rows = columns.first().map(|c| c.len()).unwrap_or(0);
+        if columns.iter().any(|c| c.len() != num_rows) {
+            return Err(ArrowError::InvalidArgumentError(
+                "All columns of a table must have the same length".to_string(),
+            ));
+        }
+        Ok(Self {
+            schema,
+            columns,
+            num_rows,
+        })
+    }
+
+    /// Creates a table of the rows of `batches`, whose columns are the chunks of the
+    /// columns of the table.
+    ///
+    /// Returns an error if the columns of a batch don't match `schema`.
+    pub fn try_from_batches(schema: SchemaRef, batches: &[RecordBatch]) -> Result<Self> {
+        let num_columns = schema.fields().len();
+        if let Some(batch) = batches.iter().find(|b| b.num_columns() != num_columns) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Can't create a table of {} columns from a record batch of {} columns",
+                num_columns,
+                batch.num_columns()
+            )));
+        }
+        let columns = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let chunks = batches.iter().map(|b| b.column(i).clone()).collect();
+                ChunkedArray::try_new_with_type(field.data_type().clone(), chunks)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::try_new(schema, columns)
+    }
+
+    /// Creates a table of `schema` without rows.
+    pub fn new_empty(schema: SchemaRef) -> Self {
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|field| ChunkedArray::new_empty(field.data_type().clone()))
+            .collect();
+        Self {
+            schema,
+            columns,
+            num_rows: 0,
+        }
+    }
+
+    /// Returns the schema of this table.
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Returns the number of columns of this table.
+    pub fn num_columns(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns the number of rows of this table.
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Returns the column at `index`.
+    pub fn column(&self, index: usize) -> &ChunkedArray {
+        &self.columns[index]
+    }
+
+    /// Returns the columns of this table.
+    pub fn columns(&self) -> &[ChunkedArray] {
+        &self.columns
+    }
+
+    /// Returns a zero-copy slice of this table, with `length` rows from `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + length` is greater than the number of rows.
+    pub fn slice(&self, offset: usize, length: usize) -> Self {
+        assert!(
+            offset + length <= self.num_rows,
+            "the offset and length of the slice of a table must be in bounds"
+        );
+        Self {
+            schema: self.schema.clone(),
+            columns: self
+                .columns
+                .iter()
+                .map(|c| c.slice(offset, length))
+                .collect(),
+            num_rows: length,
+        }
+    }
+
+    /// Returns the rows of this table where `predicate` is true, as
+    /// [ChunkedArray::filter] does for each column.
+    pub fn filter(&self, predicate: &ChunkedArray) -> Result<Self> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| c.filter(predicate))
+            .collect::<Result<Vec<_>>>()?;
+        let num_rows = match columns.first() {
+            Some(column) => column.len(),
+            None => 0,
+        };
+        Ok(Self {
+            schema: self.schema.clone(),
+            columns,
+            num_rows,
+        })
+    }
+
+    /// Returns the rows of this table as a single record batch, concatenating the chunks
+    /// of each column.
+    pub fn concat(&self) -> Result<RecordBatch> {
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| c.concat())
+            .collect::<Result<Vec<_>>>()?;
+        RecordBatch::try_new(self.schema.clone(), columns)
+    }
+
+    /// Returns the rows of this table as record batches without copying them, split
+    /// wherever a chunk of any column starts.
+    pub fn to_batches(&self) -> Result<Vec<RecordBatch>> {
+        if self.columns.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut boundaries = vec![0, self.num_rows];
+        for column in &self.columns {
+            boundaries.extend(column.chunks().iter().scan(0, |end, chunk| {
+                *end += chunk.len();
+                Some(*end)
+            }));
+        }
+        boundaries.sort_unstable();
+        boundaries.dedup();
+        boundaries
+            .windows(2)
+            .map(|w| {
+                // each column of the slice has a single chunk
+                let columns = self
+                    .columns
+                    .iter()
+                    .map(|c| c.slice(w[0], w[1] - w[0]).concat())
+                    .collect::<Result<Vec<_>>>()?;
+                RecordBatch::try_new(self.schema.clone(), columns)
+            })
+            .collect()
+    }
+}
+
+impl From<RecordBatch> for Table {
+    fn from(batch: RecordBatch) -> Self {
+        Self {
+            schema: batch.schema(),
+            num_rows: batch.num_rows(),
+            columns: batch
+                .columns()
+                .iter()
+                .map(|c| ChunkedArray::from(c.clone()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::array::{ArrayRef, BooleanArray, Int32Array, StringArray};
+    use crate::datatypes::{DataType, Field, Schema};
+
+    fn table() -> Table {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let a = ChunkedArray::try_new(vec![
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+            Arc::new(Int32Array::from(vec![4])) as ArrayRef,
+        ])
+        .unwrap();
+        let b = ChunkedArray::try_new(vec![
+            Arc::new(StringArray::from(vec![Some("a")])) as ArrayRef,
+            Arc::new(StringArray::from(vec![Some("b"), None, Some("d")])) as ArrayRef,
+        ])
+        .unwrap();
+        Table::try_new(schema, vec![a, b]).unwrap()
+    }
+
+    #[test]
+    fn test_table_invalid() {
+        let table = table();
+        let schema = table.schema();
+        let columns = table.columns().to_vec();
+
+        assert!(Table::try_new(schema.clone(), columns[..1].to_vec()).is_err());
+        let swapped = vec![columns[1].clone(), columns[0].clone()];
+        assert!(Table::try_new(schema.clone(), swapped).is_err());
+        let sliced = vec![columns[0].clone(), columns[1].slice(0, 2)];
+        assert!(Table::try_new(schema, sliced).is_err());
+    }
+
+    #[test]
+    fn test_table_slice_concat() {
+        let table = table();
+        assert_eq!(table.num_rows(), 4);
+        assert_eq!(table.num_columns(), 2);
+
+        let batch = table.slice(1, 3).concat().unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.column(0).as_ref(), &Int32Array::from(vec![2, 3, 4]));
+        assert_eq!(
+            batch.column(1).as_ref(),
+            &StringArray::from(vec![Some("b"), None, Some("d")])
+        );
+
+        let empty = Table::new_empty(table.schema());
+        assert_eq!(empty.concat().unwrap().num_rows(), 0);
+        assert!(empty.to_batches().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_table_filter() {
+        let table = table();
+        let predicate: ArrayRef =
+            Arc::new(BooleanArray::from(vec![false, true, false, true]));
+
+        let filtered = table.filter(&ChunkedArray::from(predicate)).unwrap();
+        assert_eq!(filtered.num_rows(), 2);
+        let batch = filtered.concat().unwrap();
+        assert_eq!(batch.column(0).as_ref(), &Int32Array::from(vec![2, 4]));
+        assert_eq!(
+            batch.column(1).as_ref(),
+            &StringArray::from(vec![Some("b"), Some("d")])
+        );
+    }
+
+    #[test]
+    fn test_table_to_batches() {
+        let table = table();
+
+        let batches = table.to_batches().unwrap();
+        let lengths = batches.iter().map(|b| b.num_rows()).collect::<Vec<_>>();
+        assert_eq!(lengths, vec![1, 2, 1]);
+
+        let round_trip = Table::try_from_batches(table.schema(), &batches).unwrap();
+        assert_eq!(round_trip.column(0).num_chunks(), 3);
+        assert_eq!(
+            round_trip.concat().unwrap().column(1).as_ref(),
+            &StringArray::from(vec![Some("a"), Some("b"), None, Some("d")])
+        );
+
+        let batch = batches[1].clone();
+        let table = Table::from(batch);
+        assert_eq!(table.num_rows(), 2);
+        assert_eq!(table.column(1).num_chunks(), 1);
+    }
+}
@@ -16,17 +16,65 @@
 // under the License.
 
 //! Conversion methods for dates and times.
+//!
+//! Arrow represents temporal values as integers relative to the UNIX epoch
+//! (1970-01-01T00:00:00), in the unit of their [`DataType`](crate::datatypes::DataType).
+//! The functions of this module convert between these integers and [`chrono`] types,
+//! so that readers and kernels share the same epoch arithmetic. Values before the epoch
+//! are rounded towards negative infinity, e.g. `-1` millisecond is
+//! `1969-12-31T23:59:59.999`.
+//!
+//! The conversions to [`chrono`] types panic if the value is out of the range of the
+//! type, e.g. a time of more than 24 hours or a timestamp beyond the year 262143. Their
+//! `_opt` variants return `None` instead, for values that haven't been validated.
 
-use chrono::{Duration, NaiveDateTime, NaiveTime};
+use std::convert::TryFrom;
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+use crate::datatypes::TimeUnit;
 
 /// Number of seconds in a day
-const SECONDS_IN_DAY: i64 = 86_400;
+pub const SECONDS_IN_DAY: i64 = 86_400;
 /// Number of milliseconds in a second
-const MILLISECONDS: i64 = 1_000;
+pub const MILLISECONDS: i64 = 1_000;
 /// Number of microseconds in a second
-const MICROSECONDS: i64 = 1_000_000;
+pub const MICROSECONDS: i64 = 1_000_000;
 /// Number of nanoseconds in a second
-const NANOSECONDS: i64 = 1_000_000_000;
+pub const NANOSECONDS: i64 = 1_000_000_000;
+/// Number of milliseconds in a day
+pub const MILLISECONDS_IN_DAY: i64 = SECONDS_IN_DAY * MILLISECONDS;
+/// Number of days between 0001-01-01 and 1970-01-01
+pub const EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+/// Returns the number of `unit`s in a second
+#[inline]
+pub const fn time_unit_multiple(unit: &TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => MILLISECONDS,
+        TimeUnit::Microsecond => MICROSECONDS,
+        TimeUnit::Nanosecond => NANOSECONDS,
+    }
+}
+
+/// Splits `v`, a number of `1 / multiple` seconds, into seconds and nanoseconds,
+/// rounding the seconds towards negative infinity so that the nanoseconds are positive
+#[inline]
+fn split_second(v: i64, multiple: i64) -> (i64, u32) {
+    (
+        v.div_euclid(multiple),
+        (v.rem_euclid(multiple) * (NANOSECONDS / multiple)) as u32,
+    )
+}
+
+/// Converts `v`, a number of `1 / multiple` seconds since midnight, to a [`NaiveTime`],
+/// or returns `None` if it is negative or not less than a day
+#[inline]
+fn split_second_to_time(v: i64, multiple: i64) -> Option<NaiveTime> {
+    let (secs, nanos) = split_second(v, multiple);
+    NaiveTime::from_num_seconds_from_midnight_opt(u32::try_from(secs).ok()?, nanos)
+}
 
 /// converts a `i32` representing a `date32` to [`NaiveDateTime`]
 #[inline]
@@ -34,15 +82,37 @@ pub fn date32_to_datetime(v: i32) -> NaiveDateTime {
     NaiveDateTime::from_timestamp(v as i64 * SECONDS_IN_DAY, 0)
 }
 
+/// converts a `i32` representing a `date32` to [`NaiveDateTime`], or returns `None` if
+/// it is out of range
+#[inline]
+pub fn date32_to_datetime_opt(v: i32) -> Option<NaiveDateTime> {
+    NaiveDateTime::from_timestamp_opt(v as i64 * SECONDS_IN_DAY, 0)
+}
+
+/// converts a `i32` representing a `date32` to [`NaiveDate`]
+#[inline]
+pub fn date32_to_date(v: i32) -> NaiveDate {
+    NaiveDate::from_num_days_from_ce(v + EPOCH_DAYS_FROM_CE)
+}
+
+/// converts a `i32` representing a `date32` to [`NaiveDate`], or returns `None` if it is
+/// out of range
+#[inline]
+pub fn date32_to_date_opt(v: i32) -> Option<NaiveDate> {
+    NaiveDate::from_num_days_from_ce_opt(v.checked_add(EPOCH_DAYS_FROM_CE)?)
+}
+
 /// converts a `i64` representing a `date64` to [`NaiveDateTime`]
 #[inline]
 pub fn date64_to_datetime(v: i64) -> NaiveDateTime {
-    NaiveDateTime::from_timestamp(
-        // extract seconds from milliseconds
-        v / MILLISECONDS,
-        // discard extracted seconds and convert milliseconds to nanoseconds
-        (v % MILLISECONDS * MICROSECONDS) as u32,
-    )
+    timestamp_ms_to_datetime(v)
+}
+
+/// converts a `i64` representing a `date64` to [`NaiveDateTime`], or returns `None` if
+/// it is out of range
+#[inline]
+pub fn date64_to_datetime_opt(v: i64) -> Option<NaiveDateTime> {
+    timestamp_ms_to_datetime_opt(v)
 }
 
 /// converts a `i32` representing a `time32(s)` to [`NaiveDateTime`]
@@ -51,40 +121,53 @@ pub fn time32s_to_time(v: i32) -> NaiveTime {
     NaiveTime::from_num_seconds_from_midnight(v as u32, 0)
 }
 
+/// converts a `i32` representing a `time32(s)` to [`NaiveTime`], or returns `None` if it
+/// is negative or not less than a day
+#[inline]
+pub fn time32s_to_time_opt(v: i32) -> Option<NaiveTime> {
+    NaiveTime::from_num_seconds_from_midnight_opt(u32::try_from(v).ok()?, 0)
+}
+
 /// converts a `i32` representing a `time32(ms)` to [`NaiveDateTime`]
 #[inline]
 pub fn time32ms_to_time(v: i32) -> NaiveTime {
-    let v = v as i64;
-    NaiveTime::from_num_seconds_from_midnight(
-        // extract seconds from milliseconds
-        (v / MILLISECONDS) as u32,
-        // discard extracted seconds and convert milliseconds to
-        // nanoseconds
-        (v % MILLISECONDS * MICROSECONDS) as u32,
-    )
+    let (secs, nanos) = split_second(v as i64, MILLISECONDS);
+    NaiveTime::from_num_seconds_from_midnight(secs as u32, nanos)
+}
+
+/// converts a `i32` representing a `time32(ms)` to [`NaiveTime`], or returns `None` if it
+/// is negative or not less than a day
+#[inline]
+pub fn time32ms_to_time_opt(v: i32) -> Option<NaiveTime> {
+    split_second_to_time(v as i64, MILLISECONDS)
 }
 
 /// converts a `i64` representing a `time64(us)` to [`NaiveDateTime`]
 #[inline]
 pub fn time64us_to_time(v: i64) -> NaiveTime {
-    NaiveTime::from_num_seconds_from_midnight(
-        // extract seconds from microseconds
-        (v / MICROSECONDS) as u32,
-        // discard extracted seconds and convert microseconds to
-        // nanoseconds
-        (v % MICROSECONDS * MILLISECONDS) as u32,
-    )
+    let (secs, nanos) = split_second(v, MICROSECONDS);
+    NaiveTime::from_num_seconds_from_midnight(secs as u32, nanos)
+}
+
+/// converts a `i64` representing a `time64(us)` to [`NaiveTime`], or returns `None` if it
+/// is negative or not less than a day
+#[inline]
+pub fn time64us_to_time_opt(v: i64) -> Option<NaiveTime> {
+    split_second_to_time(v, MICROSECONDS)
 }
 
 /// converts a `i64` representing a `time64(ns)` to [`NaiveDateTime`]
 #[inline]
 pub fn time64ns_to_time(v: i64) -> NaiveTime {
-    NaiveTime::from_num_seconds_from_midnight(
-        // extract seconds from nanoseconds
-        (v / NANOSECONDS) as u32,
-        // discard extracted seconds
-        (v % NANOSECONDS) as u32,
-    )
+    let (secs, nanos) = split_second(v, NANOSECONDS);
+    NaiveTime::from_num_seconds_from_midnight(secs as u32, nanos)
+}
+
+/// converts a `i64` representing a `time64(ns)` to [`NaiveTime`], or returns `None` if it
+/// is negative or not less than a day
+#[inline]
+pub fn time64ns_to_time_opt(v: i64) -> Option<NaiveTime> {
+    split_second_to_time(v, NANOSECONDS)
 }
 
 /// converts a `i64` representing a `timestamp(s)` to [`NaiveDateTime`]
@@ -93,37 +176,76 @@ pub fn timestamp_s_to_datetime(v: i64) -> NaiveDateTime {
     NaiveDateTime::from_timestamp(v, 0)
 }
 
+/// converts a `i64` representing a `timestamp(s)` to [`NaiveDateTime`], or returns
+/// `None` if it is out of range
+#[inline]
+pub fn timestamp_s_to_datetime_opt(v: i64) -> Option<NaiveDateTime> {
+    NaiveDateTime::from_timestamp_opt(v, 0)
+}
+
 /// converts a `i64` representing a `timestamp(ms)` to [`NaiveDateTime`]
 #[inline]
 pub fn timestamp_ms_to_datetime(v: i64) -> NaiveDateTime {
-    NaiveDateTime::from_timestamp(
-        // extract seconds from milliseconds
-        v / MILLISECONDS,
-        // discard extracted seconds and convert milliseconds to nanoseconds
-        (v % MILLISECONDS * MICROSECONDS) as u32,
-    )
+    let (secs, nanos) = split_second(v, MILLISECONDS);
+    NaiveDateTime::from_timestamp(secs, nanos)
+}
+
+/// converts a `i64` representing a `timestamp(ms)` to [`NaiveDateTime`], or returns
+/// `None` if it is out of range
+#[inline]
+pub fn timestamp_ms_to_datetime_opt(v: i64) -> Option<NaiveDateTime> {
+    let (secs, nanos) = split_second(v, MILLISECONDS);
+    NaiveDateTime::from_timestamp_opt(secs, nanos)
 }
 
 /// converts a `i64` representing a `timestamp(us)` to [`NaiveDateTime`]
 #[inline]
 pub fn timestamp_us_to_datetime(v: i64) -> NaiveDateTime {
-    NaiveDateTime::from_timestamp(
-        // extract seconds from microseconds
-        v / MICROSECONDS,
-        // discard extracted seconds and convert microseconds to nanoseconds
-        (v % MICROSECONDS * MILLISECONDS) as u32,
-    )
+    let (secs, nanos) = split_second(v, MICROSECONDS);
+    NaiveDateTime::from_timestamp(secs, nanos)
+}
+
+/// converts a `i64` representing a `timestamp(us)` to [`NaiveDateTime`], or returns
+/// `None` if it is out of range
+#[inline]
+pub fn timestamp_us_to_datetime_opt(v: i64) -> Option<NaiveDateTime> {
+    let (secs, nanos) = split_second(v, MICROSECONDS);
+    NaiveDateTime::from_timestamp_opt(secs, nanos)
 }
 
 /// converts a `i64` representing a `timestamp(ns)` to [`NaiveDateTime`]
 #[inline]
 pub fn timestamp_ns_to_datetime(v: i64) -> NaiveDateTime {
-    NaiveDateTime::from_timestamp(
-        // extract seconds from nanoseconds
-        v / NANOSECONDS,
-        // discard extracted seconds
-        (v % NANOSECONDS) as u32,
-    )
+    let (secs, nanos) = split_second(v, NANOSECONDS);
+    NaiveDateTime::from_timestamp(secs, nanos)
+}
+
+/// converts a `i64` representing a `timestamp(ns)` to [`NaiveDateTime`]
+///
+/// Every `i64` nanoseconds is in range, but this is provided for consistency.
+#[inline]
+pub fn timestamp_ns_to_datetime_opt(v: i64) -> Option<NaiveDateTime> {
+    let (secs, nanos) = split_second(v, NANOSECONDS);
+    NaiveDateTime::from_timestamp_opt(secs, nanos)
+}
+
+/// converts a `i64` representing a `timestamp` of the given `unit` to [`NaiveDateTime`]
+#[inline]
+pub fn timestamp_to_datetime(v: i64, unit: &TimeUnit) -> NaiveDateTime {
+    match unit {
+        TimeUnit::Second => timestamp_s_to_datetime(v),
+        TimeUnit::Millisecond => timestamp_ms_to_datetime(v),
+        TimeUnit::Microsecond => timestamp_us_to_datetime(v),
+        TimeUnit::Nanosecond => timestamp_ns_to_datetime(v),
+    }
+}
+
+/// converts a `i64` representing a `timestamp` of the given `unit` to [`NaiveDateTime`],
+/// or returns `None` if it is out of range
+#[inline]
+pub fn timestamp_to_datetime_opt(v: i64, unit: &TimeUnit) -> Option<NaiveDateTime> {
+    let (secs, nanos) = split_second(v, time_unit_multiple(unit));
+    NaiveDateTime::from_timestamp_opt(secs, nanos)
 }
 
 /// converts a `i64` representing a `duration(s)` to [`Duration`]
@@ -132,6 +254,15 @@ pub fn duration_s_to_duration(v: i64) -> Duration {
     Duration::seconds(v)
 }
 
+/// converts a `i64` representing a `duration(s)` to [`Duration`], or returns `None` if it
+/// is out of range
+///
+/// Durations of the other units are always in range.
+#[inline]
+pub fn duration_s_to_duration_opt(v: i64) -> Option<Duration> {
+    v.checked_mul(MILLISECONDS).map(Duration::milliseconds)
+}
+
 /// converts a `i64` representing a `duration(ms)` to [`Duration`]
 #[inline]
 pub fn duration_ms_to_duration(v: i64) -> Duration {
@@ -149,3 +280,209 @@ pub fn duration_us_to_duration(v: i64) -> Duration {
 pub fn duration_ns_to_duration(v: i64) -> Duration {
     Duration::nanoseconds(v)
 }
+
+/// converts a [`NaiveDate`] to a `i32` representing a `date32`
+#[inline]
+pub fn date_to_date32(v: NaiveDate) -> i32 {
+    v.num_days_from_ce() - EPOCH_DAYS_FROM_CE
+}
+
+/// converts a [`NaiveDateTime`] to a `i64` representing a `date64`
+///
+/// The time of `v` is kept, use [`NaiveDateTime::date`] beforehand to discard it.
+#[inline]
+pub fn datetime_to_date64(v: NaiveDateTime) -> i64 {
+    datetime_to_timestamp_ms(v)
+}
+
+/// converts a [`NaiveTime`] to a `i32` representing a `time32(s)`
+#[inline]
+pub fn time_to_time32s(v: NaiveTime) -> i32 {
+    v.num_seconds_from_midnight() as i32
+}
+
+/// converts a [`NaiveTime`] to a `i32` representing a `time32(ms)`
+#[inline]
+pub fn time_to_time32ms(v: NaiveTime) -> i32 {
+    (v.num_seconds_from_midnight() as i64 * MILLISECONDS
+        + v.nanosecond() as i64 / (NANOSECONDS / MILLISECONDS)) as i32
+}
+
+/// converts a [`NaiveTime`] to a `i64` representing a `time64(us)`
+#[inline]
+pub fn time_to_time64us(v: NaiveTime) -> i64 {
+    v.num_seconds_from_midnight() as i64 * MICROSECONDS
+        + v.nanosecond() as i64 / (NANOSECONDS / MICROSECONDS)
+}
+
+/// converts a [`NaiveTime`] to a `i64` representing a `time64(ns)`
+#[inline]
+pub fn time_to_time64ns(v: NaiveTime) -> i64 {
+    v.num_seconds_from_midnight() as i64 * NANOSECONDS + v.nanosecond() as i64
+}
+
+/// converts a [`NaiveDateTime`] to a `i64` representing a `timestamp(s)`
+#[inline]
+pub fn datetime_to_timestamp_s(v: NaiveDateTime) -> i64 {
+    v.timestamp()
+}
+
+/// converts a [`NaiveDateTime`] to a `i64` representing a `timestamp(ms)`
+#[inline]
+pub fn datetime_to_timestamp_ms(v: NaiveDateTime) -> i64 {
+    v.timestamp() * MILLISECONDS + v.timestamp_subsec_millis() as i64
+}
+
+/// converts a [`NaiveDateTime`] to a `i64` representing a `timestamp(us)`
+#[inline]
+pub fn datetime_to_timestamp_us(v: NaiveDateTime) -> i64 {
+    v.timestamp() * MICROSECONDS + v.timestamp_subsec_micros() as i64
+}
+
+/// converts a [`NaiveDateTime`] to a `i64` representing a `timestamp(ns)`
+#[inline]
+pub fn datetime_to_timestamp_ns(v: NaiveDateTime) -> i64 {
+    v.timestamp_nanos()
+}
+
+/// converts a [`NaiveDateTime`] to a `i64` representing a `timestamp` of the given `unit`
+#[inline]
+pub fn datetime_to_timestamp(v: NaiveDateTime, unit: &TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => datetime_to_timestamp_s(v),
+        TimeUnit::Millisecond => datetime_to_timestamp_ms(v),
+        TimeUnit::Microsecond => datetime_to_timestamp_us(v),
+        TimeUnit::Nanosecond => datetime_to_timestamp_ns(v),
+    }
+}
+
+/// converts a [`NaiveDateTime`] to a `i64` representing a `timestamp` of the given `unit`,
+/// or returns `None` if it overflows, e.g. nanoseconds outside of the years 1677 to 2262
+#[inline]
+pub fn datetime_to_timestamp_opt(v: NaiveDateTime, unit: &TimeUnit) -> Option<i64> {
+    let multiple = time_unit_multiple(unit);
+    let subsec = v.timestamp_subsec_nanos() as i64 / (NANOSECONDS / multiple);
+    v.timestamp().checked_mul(multiple)?.checked_add(subsec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dates() {
+        let date = NaiveDate::from_ymd(2021, 7, 4);
+        assert_eq!(date_to_date32(date), 18812);
+        assert_eq!(date32_to_date(18812), date);
+        assert_eq!(date32_to_datetime(18812), date.and_hms(0, 0, 0));
+
+        let before_epoch = NaiveDate::from_ymd(1969, 12, 31);
+        assert_eq!(date_to_date32(before_epoch), -1);
+        assert_eq!(date32_to_date(-1), before_epoch);
+
+        let datetime = date.and_hms_milli(12, 30, 0, 5);
+        assert_eq!(datetime_to_date64(datetime), 1625401800005);
+        assert_eq!(date64_to_datetime(1625401800005), datetime);
+    }
+
+    #[test]
+    fn test_times() {
+        let time = NaiveTime::from_hms_nano(13, 5, 10, 123_456_789);
+        assert_eq!(time_to_time32s(time), 47110);
+        assert_eq!(time_to_time32ms(time), 47110123);
+        assert_eq!(time_to_time64us(time), 47110123456);
+        assert_eq!(time_to_time64ns(time), 47110123456789);
+
+        assert_eq!(time32s_to_time(47110), NaiveTime::from_hms(13, 5, 10));
+        assert_eq!(
+            time32ms_to_time(47110123),
+            NaiveTime::from_hms_milli(13, 5, 10, 123)
+        );
+        assert_eq!(
+            time64us_to_time(47110123456),
+            NaiveTime::from_hms_micro(13, 5, 10, 123_456)
+        );
+        assert_eq!(time64ns_to_time(47110123456789), time);
+    }
+
+    #[test]
+    fn test_timestamps() {
+        let datetime = NaiveDate::from_ymd(2021, 7, 4).and_hms_nano(1, 2, 3, 456_789_012);
+        for (unit, v, truncated) in [
+            (
+                TimeUnit::Second,
+                1625360523,
+                NaiveDate::from_ymd(2021, 7, 4).and_hms(1, 2, 3),
+            ),
+            (
+                TimeUnit::Millisecond,
+                1625360523456,
+                NaiveDate::from_ymd(2021, 7, 4).and_hms_milli(1, 2, 3, 456),
+            ),
+            (
+                TimeUnit::Microsecond,
+                1625360523456789,
+                NaiveDate::from_ymd(2021, 7, 4).and_hms_micro(1, 2, 3, 456_789),
+            ),
+            (TimeUnit::Nanosecond, 1625360523456789012, datetime),
+        ]
+        .iter()
+        {
+            assert_eq!(datetime_to_timestamp(datetime, unit), *v);
+            assert_eq!(timestamp_to_datetime(*v, unit), *truncated);
+        }
+    }
+
+    #[test]
+    fn test_timestamps_before_epoch() {
+        let datetime = NaiveDate::from_ymd(1969, 12, 31).and_hms_milli(23, 59, 59, 999);
+        assert_eq!(timestamp_ms_to_datetime(-1), datetime);
+        assert_eq!(datetime_to_timestamp_ms(datetime), -1);
+        assert_eq!(timestamp_us_to_datetime(-1000), datetime);
+        assert_eq!(datetime_to_timestamp_us(datetime), -1000);
+        assert_eq!(timestamp_ns_to_datetime(-1_000_000), datetime);
+        assert_eq!(datetime_to_timestamp_ns(datetime), -1_000_000);
+        assert_eq!(date64_to_datetime(-1), datetime);
+
+        // microseconds represent dates that overflow nanoseconds
+        let datetime = NaiveDate::from_ymd(1500, 1, 1).and_hms_micro(0, 0, 0, 1);
+        let v = datetime_to_timestamp_us(datetime);
+        assert_eq!(timestamp_us_to_datetime(v), datetime);
+        assert_eq!(
+            datetime_to_timestamp_opt(datetime, &TimeUnit::Microsecond),
+            Some(v)
+        );
+        assert_eq!(
+            datetime_to_timestamp_opt(datetime, &TimeUnit::Nanosecond),
+            None
+        );
+    }
+
+    #[test]
+    fn test_out_of_range() {
+        assert_eq!(timestamp_s_to_datetime_opt(i64::MAX), None);
+        assert_eq!(timestamp_ms_to_datetime_opt(i64::MIN), None);
+        assert_eq!(timestamp_us_to_datetime_opt(i64::MAX), None);
+        assert_eq!(timestamp_to_datetime_opt(i64::MAX, &TimeUnit::Second), None);
+        assert_eq!(
+            timestamp_to_datetime_opt(-1, &TimeUnit::Millisecond),
+            Some(timestamp_ms_to_datetime(-1))
+        );
+        assert!(timestamp_ns_to_datetime_opt(i64::MAX).is_some());
+        assert_eq!(date32_to_datetime_opt(i32::MAX), None);
+        assert_eq!(date32_to_date_opt(i32::MAX), None);
+        assert_eq!(date64_to_datetime_opt(i64::MAX), None);
+
+        assert_eq!(time32s_to_time_opt(100_000), None);
+        assert_eq!(time32s_to_time_opt(-1), None);
+        assert_eq!(time32ms_to_time_opt(-1), None);
+        assert_eq!(time64us_to_time_opt(86_400_000_000), None);
+        assert_eq!(
+            time64ns_to_time_opt(86_399_999_999_999),
+            Some(NaiveTime::from_hms_nano(23, 59, 59, 999_999_999))
+        );
+
+        assert_eq!(duration_s_to_duration_opt(i64::MAX), None);
+        assert_eq!(duration_s_to_duration_opt(60), Some(Duration::minutes(1)));
+    }
+}
@@ -31,9 +31,12 @@ fn compute_row_major_strides<T: ArrowPrimitiveType>(
     shape: &[usize],
 ) -> Result<Vec<usize>> {
     let mut remaining_bytes = mem::size_of::<T::Native>();
+    let mut strides = vec![0; shape.len()];
 
-    for i in shape {
-        if let Some(val) = remaining_bytes.checked_mul(*i) {
+    for (i, dim) in shape.iter().enumerate().rev() {
+        strides[i] = remaining_bytes;
+
+        if let Some(val) = remaining_bytes.checked_mul(*dim) {
             remaining_bytes = val;
         } else {
             return Err(ArrowError::ComputeError(
@@ -42,12 +45,6 @@ fn compute_row_major_strides<T: ArrowPrimitiveType>(
         }
     }
 
-    let mut strides = Vec::<usize>::new();
-    for i in shape {
-        remaining_bytes /= *i;
-        strides.push(remaining_bytes);
-    }
-
     Ok(strides)
 }
 
@@ -96,8 +93,38 @@ pub type UInt64Tensor<'a> = Tensor<'a, UInt64Type>;
 pub type Float32Tensor<'a> = Tensor<'a, Float32Type>;
 pub type Float64Tensor<'a> = Tensor<'a, Float64Type>;
 
+/// Computes the number of bytes of a buffer spanned by the elements of a tensor with
+/// the given shape and strides, or `None` if the tensor has no elements
+fn compute_extent<T: ArrowPrimitiveType>(
+    shape: &[usize],
+    strides: &[usize],
+) -> Result<Option<usize>> {
+    if shape.contains(&0) {
+        return Ok(None);
+    }
+    shape
+        .iter()
+        .zip(strides)
+        .try_fold(mem::size_of::<T::Native>(), |extent, (dim, stride)| {
+            (dim - 1)
+                .checked_mul(*stride)
+                .and_then(|offset| offset.checked_add(extent))
+        })
+        .map(Some)
+        .ok_or_else(|| {
+            ArrowError::ComputeError(
+                "overflow occurred when computing the extent of the tensor.".to_string(),
+            )
+        })
+}
+
 impl<'a, T: ArrowPrimitiveType> Tensor<'a, T> {
     /// Creates a new `Tensor`
+    ///
+    /// When `strides` is `None`, the buffer must hold exactly the elements of the tensor
+    /// in row major order. Otherwise, the strides can be arbitrary, e.g. to view a
+    /// transposed or sliced tensor without copying it, as long as they are multiples of
+    /// the size of `T` and all the elements of the tensor are within the buffer.
     pub fn try_new(
         buffer: Buffer,
         shape: Option<Vec<usize>>,
@@ -127,14 +154,6 @@ impl<'a, T: ArrowPrimitiveType> Tensor<'a, T> {
             }
 
             Some(ref s) => {
-                if let Some(ref st) = strides {
-                    if st.len() != s.len() {
-                        return Err(ArrowError::InvalidArgumentError(
-                            "shape and stride dimensions differ".to_string(),
-                        ));
-                    }
-                }
-
                 if let Some(ref n) = names {
                     if n.len() != s.len() {
                         return Err(ArrowError::InvalidArgumentError(
@@ -144,39 +163,48 @@ impl<'a, T: ArrowPrimitiveType> Tensor<'a, T> {
                     }
                 }
 
-                let total_elements: usize = s.iter().product();
-                if total_elements != (buffer.len() / mem::size_of::<T::Native>()) {
-                    return Err(ArrowError::InvalidArgumentError(
-                        "number of elements in buffer does not match dimensions"
-                            .to_string(),
-                    ));
+                match strides {
+                    Some(ref st) => {
+                        if st.len() != s.len() {
+                            return Err(ArrowError::InvalidArgumentError(
+                                "shape and stride dimensions differ".to_string(),
+                            ));
+                        }
+
+                        if st.iter().any(|x| x % mem::size_of::<T::Native>() != 0) {
+                            return Err(ArrowError::InvalidArgumentError(
+                                "strides must be multiples of the size of the elements"
+                                    .to_string(),
+                            ));
+                        }
+
+                        if let Some(extent) = compute_extent::<T>(s, st)? {
+                            if extent > buffer.len() {
+                                return Err(ArrowError::InvalidArgumentError(
+                                    "the elements of the tensor exceed the buffer"
+                                        .to_string(),
+                                ));
+                            }
+                        }
+                    }
+                    None => {
+                        let total_elements: usize = s.iter().product();
+                        if total_elements != (buffer.len() / mem::size_of::<T::Native>())
+                        {
+                            return Err(ArrowError::InvalidArgumentError(
+                                "number of elements in buffer does not match dimensions"
+                                    .to_string(),
+                            ));
+                        }
+                    }
                 }
             }
         };
 
-        // Checking that the tensor strides used for construction are correct
-        // otherwise a row major stride is calculated and used as value for the tensor
-        let tensor_strides = {
-            if let Some(st) = strides {
-                if let Some(ref s) = shape {
-                    if compute_row_major_strides::<T>(s)? == st
-                        || compute_column_major_strides::<T>(s)? == st
-                    {
-                        Some(st)
-                    } else {
-                        return Err(ArrowError::InvalidArgumentError(
-                            "the input stride does not match the selected shape"
-                                .to_string(),
-                        ));
-                    }
-                } else {
-                    Some(st)
-                }
-            } else if let Some(ref s) = shape {
-                Some(compute_row_major_strides::<T>(s)?)
-            } else {
-                None
-            }
+        // a row major stride is calculated when none is given
+        let tensor_strides = match (strides, &shape) {
+            (None, Some(s)) => Some(compute_row_major_strides::<T>(s)?),
+            (strides, _) => strides,
         };
 
         Ok(Self {
@@ -269,6 +297,23 @@ impl<'a, T: ArrowPrimitiveType> Tensor<'a, T> {
         }
     }
 
+    /// Returns a view of this tensor with its dimensions in reverse order, sharing its
+    /// buffer. The transpose of a row major tensor is column major, and vice-versa.
+    pub fn transpose(&self) -> Self {
+        let reversed = |v: &Vec<usize>| v.iter().rev().copied().collect();
+        Self {
+            data_type: self.data_type.clone(),
+            buffer: self.buffer.clone(),
+            shape: self.shape.as_ref().map(reversed),
+            strides: self.strides.as_ref().map(reversed),
+            names: self
+                .names
+                .as_ref()
+                .map(|names| names.iter().rev().copied().collect()),
+            _marker: PhantomData,
+        }
+    }
+
     /// Indicates if the data is laid out contiguously in memory
     pub fn is_contiguous(&self) -> Result<bool> {
         Ok(self.is_row_major()? || self.is_column_major()?)
@@ -293,6 +338,51 @@ impl<'a, T: ArrowPrimitiveType> Tensor<'a, T> {
     }
 }
 
+#[cfg(feature = "ndarray")]
+impl<'a, T: ArrowPrimitiveType> Tensor<'a, T>
+where
+    T::Native: num::Num,
+{
+    /// Returns an [`ndarray::ArrayViewD`] over the elements of this tensor, without
+    /// copying them. Errors if the buffer is not aligned for `T`.
+    pub fn to_ndarray_view(&self) -> Result<ndarray::ArrayViewD<'_, T::Native>> {
+        use ndarray::{ArrayViewD, IxDyn, ShapeBuilder};
+
+        let (prefix, values, _) =
+            unsafe { self.buffer.as_slice().align_to::<T::Native>() };
+        if !prefix.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "the buffer of the tensor is not aligned".to_string(),
+            ));
+        }
+        let (shape, strides) = match (&self.shape, &self.strides) {
+            (Some(shape), Some(strides)) => (
+                shape.clone(),
+                strides
+                    .iter()
+                    .map(|stride| stride / mem::size_of::<T::Native>())
+                    .collect(),
+            ),
+            _ => (vec![], vec![]),
+        };
+        ArrayViewD::from_shape(IxDyn(&shape).strides(IxDyn(&strides)), values)
+            .map_err(|e| ArrowError::InvalidArgumentError(e.to_string()))
+    }
+
+    /// Creates a row major tensor from the elements of an [`ndarray::ArrayBase`], copying them.
+    pub fn from_ndarray<S, D>(array: &ndarray::ArrayBase<S, D>) -> Result<Self>
+    where
+        S: ndarray::Data<Elem = T::Native>,
+        D: ndarray::Dimension,
+    {
+        let buffer: Buffer = array.iter().copied().collect();
+        match array.ndim() {
+            0 => Self::try_new(buffer, None, None, None),
+            _ => Self::new_row_major(buffer, Some(array.shape().to_vec()), None),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -492,4 +582,88 @@ mod tests {
             panic!("the input stride does not match the selected shape")
         }
     }
+
+    #[test]
+    fn test_arbitrary_strides() {
+        let buf = Buffer::from_slice_ref(&(0..16).collect::<Vec<i32>>());
+
+        // every other column of a 4x4 row major tensor
+        let tensor =
+            Int32Tensor::try_new(buf.clone(), Some(vec![4, 2]), Some(vec![16, 8]), None)
+                .unwrap();
+        assert_eq!(8, tensor.size());
+        assert_eq!(Some(vec![16_usize, 8]).as_ref(), tensor.strides());
+        assert_eq!(false, tensor.is_contiguous().unwrap());
+
+        // a row broadcast along the first dimension
+        let tensor =
+            Int32Tensor::try_new(buf.clone(), Some(vec![3, 16]), Some(vec![0, 4]), None)
+                .unwrap();
+        assert_eq!(48, tensor.size());
+
+        let err =
+            Int32Tensor::try_new(buf.clone(), Some(vec![4, 4]), Some(vec![20, 4]), None)
+                .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid argument error: the elements of the tensor exceed the buffer"
+        );
+
+        // no element is read from a tensor with an empty dimension
+        let tensor =
+            Int32Tensor::try_new(buf, Some(vec![0, 100]), Some(vec![400, 4]), None)
+                .unwrap();
+        assert_eq!(0, tensor.size());
+    }
+
+    #[test]
+    fn test_empty_dimension() {
+        let buf = Buffer::from_slice_ref(&Vec::<i32>::new());
+        let tensor = Int32Tensor::new_row_major(buf, Some(vec![2, 0, 3]), None).unwrap();
+        assert_eq!(0, tensor.size());
+        assert_eq!(Some(vec![0_usize, 12, 4]).as_ref(), tensor.strides());
+    }
+
+    #[test]
+    fn test_transpose() {
+        let buf = Buffer::from_slice_ref(&(0..8).collect::<Vec<i64>>());
+        let names = vec!["Dim 1", "Dim 2"];
+        let tensor = Int64Tensor::new_row_major(buf, Some(vec![2, 4]), Some(names))
+            .unwrap()
+            .transpose();
+        assert_eq!(Some(vec![4_usize, 2]).as_ref(), tensor.shape());
+        assert_eq!(Some(vec![8_usize, 32]).as_ref(), tensor.strides());
+        assert_eq!("Dim 2", tensor.dim_name(0).unwrap());
+        assert_eq!(true, tensor.is_column_major().unwrap());
+        assert_eq!(tensor.transpose().strides(), Some(&vec![32, 8]));
+    }
+
+    #[test]
+    #[cfg(feature = "ndarray")]
+    fn test_ndarray() {
+        let buf = Buffer::from_slice_ref(&(0..6).collect::<Vec<i32>>());
+        let tensor = Int32Tensor::new_row_major(buf, Some(vec![2, 3]), None).unwrap();
+
+        let view = tensor.to_ndarray_view().unwrap();
+        assert_eq!(view.shape(), &[2, 3]);
+        assert_eq!(view[[1, 0]], 3);
+
+        let transposed = tensor.transpose();
+        let view = transposed.to_ndarray_view().unwrap();
+        assert_eq!(view.shape(), &[3, 2]);
+        assert_eq!(view[[0, 1]], 3);
+
+        let copy = Int32Tensor::from_ndarray(&view).unwrap();
+        assert_eq!(true, copy.is_row_major().unwrap());
+        assert_eq!(
+            unsafe { copy.data().typed_data::<i32>() },
+            &[0, 3, 1, 4, 2, 5]
+        );
+
+        let scalar = Int32Tensor::from_ndarray(&ndarray::arr0(7)).unwrap();
+        assert_eq!(None, scalar.shape());
+        let view = scalar.to_ndarray_view().unwrap();
+        assert_eq!(view.ndim(), 0);
+        assert_eq!(view.iter().collect::<Vec<_>>(), vec![&7]);
+    }
 }
@@ -30,7 +30,7 @@ pub struct BitChunks<'a> {
 
 impl<'a> BitChunks<'a> {
     pub fn new(buffer: &'a [u8], offset: usize, len: usize) -> Self {
-        assert!(ceil(offset + len, 8) <= buffer.len() * 8);
+        assert!(ceil(offset + len, 8) <= buffer.len());
 
         let byte_offset = offset / 8;
         let bit_offset = offset % 8;
@@ -140,8 +140,15 @@ impl Iterator for BitChunkIterator<'_> {
         let combined = if self.bit_offset == 0 {
             current
         } else {
-            let next =
-                unsafe { std::ptr::read_unaligned(raw_data.add(index + 1)).to_le() };
+            // only the lowest `bit_offset` bits of the next chunk are needed, which are
+            // all in its first byte; reading more could go past the end of the buffer
+            let next = unsafe {
+                std::ptr::read(
+                    self.buffer
+                        .as_ptr()
+                        .add((index + 1) * std::mem::size_of::<u64>()),
+                )
+            } as u64;
 
             current >> self.bit_offset
                 | (next & ((1 << self.bit_offset) - 1)) << (64 - self.bit_offset)
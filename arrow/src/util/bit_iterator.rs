@@ -0,0 +1,372 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Iterators over the bits of a bitmap, such as a validity bitmap.
+//!
+//! All iterators take the bitmap as a byte slice together with the `offset` and `len`, in
+//! bits, of the region to iterate over, and report positions relative to `offset`.
+
+use crate::util::bit_chunk_iterator::{BitChunkIterator, BitChunks};
+use crate::util::bit_util::{ceil, get_bit};
+
+/// An iterator over the bits `[offset, offset + len)` of a bitmap, as booleans.
+///
+/// # Example
+/// ```
+/// # use arrow::util::bit_iterator::BitIterator;
+/// let bits: Vec<bool> = BitIterator::new(&[0b00001101], 1, 4).collect();
+/// assert_eq!(bits, vec![false, true, true, false]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitIterator<'a> {
+    buffer: &'a [u8],
+    current_offset: usize,
+    end_offset: usize,
+}
+
+impl<'a> BitIterator<'a> {
+    /// Creates an iterator over the bits `[offset, offset + len)` of `buffer`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` holds fewer than `offset + len` bits.
+    pub fn new(buffer: &'a [u8], offset: usize, len: usize) -> Self {
+        assert_bounds(buffer, offset, len);
+        Self {
+            buffer,
+            current_offset: offset,
+            end_offset: offset + len,
+        }
+    }
+}
+
+impl Iterator for BitIterator<'_> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<bool> {
+        if self.current_offset == self.end_offset {
+            return None;
+        }
+        // bounds are checked when the iterator is created
+        let v = get_bit(self.buffer, self.current_offset);
+        self.current_offset += 1;
+        Some(v)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end_offset - self.current_offset;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for BitIterator<'_> {}
+
+impl DoubleEndedIterator for BitIterator<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<bool> {
+        if self.current_offset == self.end_offset {
+            return None;
+        }
+        self.end_offset -= 1;
+        Some(get_bit(self.buffer, self.end_offset))
+    }
+}
+
+/// An iterator over the indices of the set bits of the region `[offset, offset + len)`
+/// of a bitmap, in ascending order, relative to `offset`.
+///
+/// This reads the bitmap 64 bits at a time and so is considerably faster than testing
+/// each bit individually when few bits are set.
+///
+/// # Example
+/// ```
+/// # use arrow::util::bit_iterator::BitIndexIterator;
+/// let indices: Vec<usize> = BitIndexIterator::new(&[0b00001101, 0b1], 1, 8).collect();
+/// assert_eq!(indices, vec![1, 2, 7]);
+/// ```
+#[derive(Debug)]
+pub struct BitIndexIterator<'a> {
+    words: BitWords<'a>,
+    /// the bits of the current word that were not yielded yet
+    current: u64,
+    /// the index of the first bit of the current word
+    current_offset: usize,
+    /// the number of bits of the current word
+    current_len: usize,
+}
+
+impl<'a> BitIndexIterator<'a> {
+    /// Creates an iterator over the indices of the set bits of `buffer` in the region
+    /// `[offset, offset + len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` holds fewer than `offset + len` bits.
+    pub fn new(buffer: &'a [u8], offset: usize, len: usize) -> Self {
+        Self {
+            words: BitWords::new(buffer, offset, len),
+            current: 0,
+            current_offset: 0,
+            current_len: 0,
+        }
+    }
+}
+
+impl Iterator for BitIndexIterator<'_> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        while self.current == 0 {
+            let (word, len) = self.words.next()?;
+            self.current_offset += self.current_len;
+            self.current = word;
+            self.current_len = len;
+        }
+        let bit = self.current.trailing_zeros() as usize;
+        // clear the lowest set bit
+        self.current &= self.current - 1;
+        Some(self.current_offset + bit)
+    }
+}
+
+/// An iterator over the runs of consecutive set bits of the region `[offset, offset + len)`
+/// of a bitmap, yielding the `(start, end)` of each run, with `end` exclusive, relative
+/// to `offset`.
+///
+/// This is useful to process the valid slots of an array in contiguous slices, e.g.
+/// to copy them with [`MutableBuffer::extend_from_slice`](crate::buffer::MutableBuffer::extend_from_slice).
+///
+/// # Example
+/// ```
+/// # use arrow::util::bit_iterator::BitSliceIterator;
+/// let slices: Vec<(usize, usize)> =
+///     BitSliceIterator::new(&[0b11001110, 0b11], 1, 9).collect();
+/// assert_eq!(slices, vec![(0, 3), (5, 9)]);
+/// ```
+#[derive(Debug)]
+pub struct BitSliceIterator<'a> {
+    words: BitWords<'a>,
+    /// the bits of the current word that were not consumed yet
+    current: u64,
+    /// the index of the first bit of the current word
+    current_offset: usize,
+    /// the number of bits of the current word
+    current_len: usize,
+}
+
+impl<'a> BitSliceIterator<'a> {
+    /// Creates an iterator over the runs of set bits of `buffer` in the region
+    /// `[offset, offset + len)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` holds fewer than `offset + len` bits.
+    pub fn new(buffer: &'a [u8], offset: usize, len: usize) -> Self {
+        Self {
+            words: BitWords::new(buffer, offset, len),
+            current: 0,
+            current_offset: 0,
+            current_len: 0,
+        }
+    }
+
+    /// Moves to the next word, returning `false` if there is none.
+    #[inline]
+    fn advance(&mut self) -> bool {
+        match self.words.next() {
+            Some((word, len)) => {
+                self.current_offset += self.current_len;
+                self.current = word;
+                self.current_len = len;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Iterator for BitSliceIterator<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        // find the start of the next run
+        while self.current == 0 {
+            if !self.advance() {
+                return None;
+            }
+        }
+        let start_bit = self.current.trailing_zeros() as usize;
+        let start = self.current_offset + start_bit;
+
+        // mark the bits before the run as set, so that the run is made of trailing ones
+        self.current |= (1 << start_bit) - 1;
+        loop {
+            // bits past the end of the last word are never set, so this is at most
+            // `current_len`, and equal to it if the run continues into the next word
+            let ones = (!self.current).trailing_zeros() as usize;
+            if ones < self.current_len {
+                // clear the run, keeping the rest of the word for the next call
+                self.current &= !((1 << ones) - 1);
+                return Some((start, self.current_offset + ones));
+            }
+            let end = self.current_offset + self.current_len;
+            if !self.advance() {
+                self.current = 0;
+                return Some((start, end));
+            }
+            if self.current & 1 == 0 {
+                // the run ended exactly at the end of the previous word
+                return Some((start, end));
+            }
+        }
+    }
+}
+
+/// The words of 64 bits of a region of a bitmap, paired with the number of bits of
+/// the region they hold, which is smaller than 64 for the last word only. Bits past
+/// the end of the region are unset.
+#[derive(Debug)]
+struct BitWords<'a> {
+    chunks: BitChunkIterator<'a>,
+    remainder: Option<(u64, usize)>,
+}
+
+impl<'a> BitWords<'a> {
+    fn new(buffer: &'a [u8], offset: usize, len: usize) -> Self {
+        assert_bounds(buffer, offset, len);
+        let chunks = BitChunks::new(buffer, offset, len);
+        let remainder = match chunks.remainder_len() {
+            0 => None,
+            remainder_len => Some((chunks.remainder_bits(), remainder_len)),
+        };
+        Self {
+            chunks: chunks.iter(),
+            remainder,
+        }
+    }
+}
+
+impl Iterator for BitWords<'_> {
+    type Item = (u64, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<(u64, usize)> {
+        match self.chunks.next() {
+            Some(chunk) => Some((chunk, 64)),
+            None => self.remainder.take(),
+        }
+    }
+}
+
+#[inline]
+fn assert_bounds(buffer: &[u8], offset: usize, len: usize) {
+    assert!(
+        ceil(offset + len, 8) <= buffer.len(),
+        "bitmap of {} bytes is too small for {} bits at offset {}",
+        buffer.len(),
+        len,
+        offset
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::test_util::seedable_rng;
+    use rand::Rng;
+
+    /// The runs of set bits, computed bit by bit.
+    fn naive_slices(bits: &[bool]) -> Vec<(usize, usize)> {
+        let mut slices = vec![];
+        let mut start = None;
+        for (i, v) in bits.iter().enumerate() {
+            match (start, v) {
+                (None, true) => start = Some(i),
+                (Some(s), false) => {
+                    slices.push((s, i));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(s) = start {
+            slices.push((s, bits.len()));
+        }
+        slices
+    }
+
+    #[test]
+    fn test_bit_iterators() {
+        let mut rng = seedable_rng();
+        for &density in &[0.0, 0.1, 0.5, 0.9, 1.0] {
+            let buffer: Vec<u8> = (0..40)
+                .map(|_| {
+                    (0..8).fold(0u8, |acc, i| acc | ((rng.gen_bool(density) as u8) << i))
+                })
+                .collect();
+            for &offset in &[0, 1, 7, 8, 63, 64, 65] {
+                for &len in &[0, 1, 63, 64, 65, 128, 200, 320 - offset] {
+                    let bits: Vec<bool> =
+                        BitIterator::new(&buffer, offset, len).collect();
+                    let expected: Vec<bool> =
+                        (0..len).map(|i| get_bit(&buffer, offset + i)).collect();
+                    assert_eq!(bits, expected);
+
+                    let mut reversed: Vec<bool> =
+                        BitIterator::new(&buffer, offset, len).rev().collect();
+                    reversed.reverse();
+                    assert_eq!(reversed, expected);
+
+                    let indices: Vec<usize> =
+                        BitIndexIterator::new(&buffer, offset, len).collect();
+                    let expected_indices: Vec<usize> =
+                        (0..len).filter(|i| expected[*i]).collect();
+                    assert_eq!(indices, expected_indices, "{} {}", offset, len);
+
+                    let slices: Vec<(usize, usize)> =
+                        BitSliceIterator::new(&buffer, offset, len).collect();
+                    assert_eq!(slices, naive_slices(&expected), "{} {}", offset, len);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bit_slice_iterator_word_boundaries() {
+        // a run ending exactly at the end of the first word, one spanning the
+        // second and third words, and one ending at the end of the region
+        let mut buffer = [0u8; 24];
+        (60..64).chain(100..150).chain(190..192).for_each(|i| {
+            crate::util::bit_util::set_bit(&mut buffer, i);
+        });
+        let slices: Vec<_> = BitSliceIterator::new(&buffer, 0, 192).collect();
+        assert_eq!(slices, vec![(60, 64), (100, 150), (190, 192)]);
+
+        let buffer = [255u8; 24];
+        let slices: Vec<_> = BitSliceIterator::new(&buffer, 3, 150).collect();
+        assert_eq!(slices, vec![(0, 150)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "bitmap of 2 bytes is too small for 10 bits at offset 7")]
+    fn test_bit_iterator_out_of_bounds() {
+        BitIterator::new(&[0, 0], 7, 10);
+    }
+}
@@ -17,6 +17,8 @@
 
 //! Utils for working with bits
 
+use std::convert::TryInto;
+
 #[cfg(feature = "simd")]
 use packed_simd::u8x64;
 
@@ -107,6 +109,238 @@ pub fn ceil(value: usize, divisor: usize) -> usize {
     }
 }
 
+/// Packs `bools` into the bits `[offset, offset + bools.len())` of the bitmap `data`,
+/// overwriting their previous values.
+///
+/// Whole bytes of the bitmap are written 64 bits at a time, which is considerably
+/// faster than setting each bit individually. With the `simd` feature this uses BMI2
+/// on x86_64 CPUs that support it, detected at runtime, and NEON on aarch64.
+///
+/// # Panics
+///
+/// Panics if `data` holds fewer than `offset + bools.len()` bits.
+pub fn pack_bools(data: &mut [u8], offset: usize, bools: &[bool]) {
+    let end = offset + bools.len();
+    assert!(
+        ceil(end, 8) <= data.len(),
+        "bitmap of {} bytes is too small to pack {} bits at offset {}",
+        data.len(),
+        bools.len(),
+        offset
+    );
+    // bits before the first byte boundary
+    let head = std::cmp::min((8 - offset % 8) % 8, bools.len());
+    bools[..head]
+        .iter()
+        .enumerate()
+        .for_each(|(i, v)| write_bit(data, offset + i, *v));
+    let bools = &bools[head..];
+    let data = &mut data[ceil(offset, 8)..];
+
+    // a `bool` is a byte holding either 0 or 1, so eight of them form a `u64`
+    // with one bit set per byte at most
+    let bytes =
+        unsafe { std::slice::from_raw_parts(bools.as_ptr() as *const u8, bools.len()) };
+
+    let words = bytes.chunks_exact(64);
+    let remainder = words.remainder();
+    let mut out = data.chunks_exact_mut(8);
+    for (word, out) in words.zip(&mut out) {
+        out.copy_from_slice(&pack_word(word).to_le_bytes());
+    }
+
+    let byte_offset = (bytes.len() - remainder.len()) / 8;
+    let chunks = remainder.chunks_exact(8);
+    let tail = chunks.remainder();
+    let byte_offset = chunks.fold(byte_offset, |i, bytes| {
+        data[i] = pack_byte(u64::from_le_bytes(bytes.try_into().unwrap()));
+        i + 1
+    });
+    tail.iter()
+        .enumerate()
+        .for_each(|(i, v)| write_bit(&mut data[byte_offset..], i, *v != 0));
+}
+
+/// Unpacks the bits `[offset, offset + out.len())` of the bitmap `data` into `out`,
+/// writing `1` for every set bit and `0` for every unset bit.
+///
+/// Whole bytes of the bitmap are unpacked 64 bits at a time, using the same
+/// instructions as [`pack_bools`].
+///
+/// # Panics
+///
+/// Panics if `data` holds fewer than `offset + out.len()` bits.
+pub fn unpack_bits(data: &[u8], offset: usize, out: &mut [u8]) {
+    let end = offset + out.len();
+    assert!(
+        ceil(end, 8) <= data.len(),
+        "bitmap of {} bytes is too small to unpack {} bits at offset {}",
+        data.len(),
+        out.len(),
+        offset
+    );
+    // bits before the first byte boundary
+    let head = std::cmp::min((8 - offset % 8) % 8, out.len());
+    let (head_out, out) = out.split_at_mut(head);
+    head_out
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, v)| *v = get_bit(data, offset + i) as u8);
+    let data = &data[ceil(offset, 8)..];
+
+    let (words_out, out) = out.split_at_mut(out.len() / 64 * 64);
+    for (out, bytes) in words_out.chunks_exact_mut(64).zip(data.chunks_exact(8)) {
+        unpack_word(u64::from_le_bytes(bytes.try_into().unwrap()), out);
+    }
+    let data = &data[words_out.len() / 8..];
+
+    let whole_bytes = out.len() / 8;
+    let mut chunks = out.chunks_exact_mut(8);
+    for (out, byte) in (&mut chunks).zip(data) {
+        out.copy_from_slice(&unpack_byte(*byte).to_le_bytes());
+    }
+    let tail = chunks.into_remainder();
+    if !tail.is_empty() {
+        let byte = data[whole_bytes];
+        tail.iter_mut()
+            .enumerate()
+            .for_each(|(i, v)| *v = (byte >> i) & 1);
+    }
+}
+
+#[inline]
+fn write_bit(data: &mut [u8], i: usize, v: bool) {
+    if v {
+        set_bit(data, i)
+    } else {
+        unset_bit(data, i)
+    }
+}
+
+/// Packs the 64 bytes of `word`, each either 0 or 1, into the bits of a `u64`, the
+/// first byte into its least significant bit.
+#[cfg(not(all(feature = "simd", target_arch = "aarch64")))]
+#[inline]
+fn pack_word(word: &[u8]) -> u64 {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if is_x86_feature_detected!("bmi2") {
+        return unsafe { bmi2::pack_word(word) };
+    }
+    word.chunks_exact(8).enumerate().fold(0, |acc, (i, bytes)| {
+        acc | (pack_byte(u64::from_le_bytes(bytes.try_into().unwrap())) as u64) << (i * 8)
+    })
+}
+
+#[cfg(all(feature = "simd", target_arch = "aarch64"))]
+#[inline]
+fn pack_word(word: &[u8]) -> u64 {
+    unsafe { neon::pack_word(word) }
+}
+
+/// The inverse of [`pack_word`]: writes each of the 64 bits of `bits` to a byte of `out`.
+#[cfg(not(all(feature = "simd", target_arch = "aarch64")))]
+#[inline]
+fn unpack_word(bits: u64, out: &mut [u8]) {
+    #[cfg(all(feature = "simd", target_arch = "x86_64"))]
+    if is_x86_feature_detected!("bmi2") {
+        return unsafe { bmi2::unpack_word(bits, out) };
+    }
+    for (i, out) in out.chunks_exact_mut(8).enumerate() {
+        out.copy_from_slice(&unpack_byte((bits >> (i * 8)) as u8).to_le_bytes());
+    }
+}
+
+#[cfg(all(feature = "simd", target_arch = "aarch64"))]
+#[inline]
+fn unpack_word(bits: u64, out: &mut [u8]) {
+    unsafe { neon::unpack_word(bits, out) }
+}
+
+/// Gathers the least significant bit of each byte of `bytes` into a byte, the first
+/// (least significant) byte into its least significant bit.
+#[inline]
+fn pack_byte(bytes: u64) -> u8 {
+    // every byte contributes its bit to a distinct position of the top byte,
+    // so the multiplication produces no carries into it
+    (bytes.wrapping_mul(0x0102_0408_1020_4080) >> 56) as u8
+}
+
+/// The inverse of [`pack_byte`]: spreads the bits of `byte` into the least significant
+/// bit of each byte of a `u64`.
+#[inline]
+fn unpack_byte(byte: u8) -> u64 {
+    // broadcast the byte, keep bit `i` in byte `i`, and turn every non-zero byte into 1
+    let bits = (byte as u64 * 0x0101_0101_0101_0101) & 0x8040_2010_0804_0201;
+    ((bits + 0x7f7f_7f7f_7f7f_7f7f) >> 7) & 0x0101_0101_0101_0101
+}
+
+/// [`pack_word`] and [`unpack_word`] using the BMI2 bit gather and scatter instructions.
+/// Callers must check that the CPU supports BMI2.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+mod bmi2 {
+    use std::arch::x86_64::{_pdep_u64, _pext_u64};
+    use std::convert::TryInto;
+
+    /// The least significant bit of each byte
+    const LSB_MASK: u64 = 0x0101_0101_0101_0101;
+
+    #[target_feature(enable = "bmi2")]
+    pub(super) unsafe fn pack_word(word: &[u8]) -> u64 {
+        let mut packed = 0;
+        for (i, bytes) in word.chunks_exact(8).enumerate() {
+            let bytes = u64::from_le_bytes(bytes.try_into().unwrap());
+            packed |= _pext_u64(bytes, LSB_MASK) << (i * 8);
+        }
+        packed
+    }
+
+    #[target_feature(enable = "bmi2")]
+    pub(super) unsafe fn unpack_word(bits: u64, out: &mut [u8]) {
+        for (i, out) in out.chunks_exact_mut(8).enumerate() {
+            let bytes = _pdep_u64((bits >> (i * 8)) & 0xff, LSB_MASK);
+            out.copy_from_slice(&bytes.to_le_bytes());
+        }
+    }
+}
+
+/// [`pack_word`] and [`unpack_word`] using NEON, which every aarch64 CPU supports.
+#[cfg(all(feature = "simd", target_arch = "aarch64"))]
+mod neon {
+    use std::arch::aarch64::*;
+
+    /// Shifts each lane to the position of its bit within its half of the vector
+    const SHIFTS: [i8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7];
+    /// Selects the bit of each lane within its half of the vector
+    const BITS: [u8; 16] = [1, 2, 4, 8, 16, 32, 64, 128, 1, 2, 4, 8, 16, 32, 64, 128];
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn pack_word(word: &[u8]) -> u64 {
+        let shifts = vld1q_s8(SHIFTS.as_ptr());
+        let mut packed = 0;
+        for (i, bytes) in word.chunks_exact(16).enumerate() {
+            // the shifted bits are distinct, so adding them sets each of them
+            let bits = vshlq_u8(vld1q_u8(bytes.as_ptr()), shifts);
+            let low = vaddv_u8(vget_low_u8(bits)) as u64;
+            let high = vaddv_u8(vget_high_u8(bits)) as u64;
+            packed |= (low | high << 8) << (i * 16);
+        }
+        packed
+    }
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn unpack_word(bits: u64, out: &mut [u8]) {
+        let mask = vld1q_u8(BITS.as_ptr());
+        let one = vdupq_n_u8(1);
+        for (i, out) in out.chunks_exact_mut(16).enumerate() {
+            let low = vdup_n_u8((bits >> (i * 16)) as u8);
+            let high = vdup_n_u8((bits >> (i * 16 + 8)) as u8);
+            // lanes whose bit is set become all ones
+            let set = vtstq_u8(vcombine_u8(low, high), mask);
+            vst1q_u8(out.as_mut_ptr(), vandq_u8(set, one));
+        }
+    }
+}
+
 /// Performs SIMD bitwise binary operations.
 ///
 /// # Safety
@@ -280,6 +514,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pack_unpack_byte() {
+        for byte in 0..=255u8 {
+            let unpacked = unpack_byte(byte);
+            let expected: Vec<u8> = (0..8).map(|i| (byte >> i) & 1).collect();
+            assert_eq!(unpacked.to_le_bytes().to_vec(), expected);
+            assert_eq!(pack_byte(unpacked), byte);
+        }
+    }
+
+    #[test]
+    fn test_pack_unpack_bits() {
+        let mut rng = seedable_rng();
+        for &len in &[0, 1, 7, 8, 9, 63, 64, 65, 130, 1000] {
+            for &offset in &[0, 1, 3, 8, 13, 64, 67] {
+                let bools: Vec<bool> = (0..len).map(|_| rng.gen()).collect();
+                let bitmap_len = ceil(offset + len, 8) + 1;
+                let original: Vec<u8> = (0..bitmap_len).map(|_| rng.gen()).collect();
+
+                let mut expected = original.clone();
+                bools.iter().enumerate().for_each(|(i, v)| {
+                    if *v {
+                        set_bit(&mut expected, offset + i)
+                    } else {
+                        unset_bit(&mut expected, offset + i)
+                    }
+                });
+                let mut packed = original.clone();
+                pack_bools(&mut packed, offset, &bools);
+                assert_eq!(packed, expected, "len {} offset {}", len, offset);
+
+                let mut unpacked = vec![2u8; len];
+                unpack_bits(&packed, offset, &mut unpacked);
+                let unpacked: Vec<bool> = unpacked.iter().map(|v| *v == 1).collect();
+                assert_eq!(unpacked, bools, "len {} offset {}", len, offset);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "too small to pack 9 bits at offset 0")]
+    fn test_pack_bools_out_of_bounds() {
+        pack_bools(&mut [0u8], 0, &[true; 9]);
+    }
+
     #[test]
     #[cfg(all(any(target_arch = "x86", target_arch = "x86_64")))]
     fn test_ceil() {
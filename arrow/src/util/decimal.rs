@@ -0,0 +1,183 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parsing of decimal strings shared by the text readers and the cast kernels, so that
+//! they all accept the same numbers.
+
+use crate::compute::kernels::cast::RoundingMode;
+use crate::error::{ArrowError, Result};
+
+/// Parses `text`, e.g. `"-1.25"` or `" 125e-2 "`, as the integer value of a decimal of
+/// `precision` and `scale`, or returns an error if it isn't a number or it doesn't fit
+/// in `precision` digits.
+///
+/// Significant digits below `scale` are rounded with `rounding`, or are an error if it
+/// is `None`. Whitespace around the number is ignored.
+pub(crate) fn parse_decimal(
+    text: &str,
+    precision: usize,
+    scale: usize,
+    rounding: Option<RoundingMode>,
+) -> Result<i128> {
+    let error = || {
+        ArrowError::ParseError(format!(
+            "Cannot parse {} as Decimal({}, {})",
+            text, precision, scale
+        ))
+    };
+    let number = text.trim();
+    let (mantissa, exponent) = match number.find(|c| c == 'e' || c == 'E') {
+        Some(pos) => (
+            &number[..pos],
+            number[pos + 1..].parse::<i64>().map_err(|_| error())?,
+        ),
+        None => (number, 0),
+    };
+    let (negative, mantissa) = match mantissa.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+    };
+    let (integer, fraction) = match mantissa.find('.') {
+        Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+        None => (mantissa, ""),
+    };
+    if integer.is_empty() && fraction.is_empty()
+        || !integer
+            .bytes()
+            .chain(fraction.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return Err(error());
+    }
+
+    // the digits without leading zeros, and the power of ten that they are scaled by
+    let digits = format!("{}{}", integer, fraction);
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        // zero is zero whatever its exponent
+        return Ok(0);
+    }
+    let shift = exponent
+        .checked_sub(fraction.len() as i64)
+        .and_then(|shift| shift.checked_add(scale as i64))
+        .ok_or_else(error)?;
+
+    // split off the digits below the scale, which are all below it with implicit
+    // leading zeros if there are more of them than digits
+    let (kept, dropped, implicit_zeros) = match digits.len() as i64 + shift.min(0) {
+        len if len < 0 => ("", digits, true),
+        len => {
+            let (kept, dropped) = digits.split_at(len as usize);
+            (kept, dropped, false)
+        }
+    };
+    let shift = shift.max(0);
+    let round_away = if dropped.bytes().all(|b| b == b'0') {
+        false
+    } else {
+        let (first, tail) = dropped.as_bytes().split_first().unwrap();
+        match rounding {
+            None => return Err(error()),
+            Some(RoundingMode::Truncate) => false,
+            Some(_) if implicit_zeros => false,
+            Some(RoundingMode::HalfUp) => *first >= b'5',
+            Some(RoundingMode::HalfEven) => {
+                let odd = kept.bytes().last().map_or(false, |b| (b - b'0') % 2 == 1);
+                *first > b'5'
+                    || (*first == b'5' && (tail.iter().any(|b| *b != b'0') || odd))
+            }
+        }
+    };
+
+    match (kept.len() as i64).checked_add(shift) {
+        Some(len) if len <= precision as i64 => {}
+        _ => return Err(error()),
+    }
+    let value = kept
+        .bytes()
+        .map(|b| (b - b'0') as i128)
+        .chain(std::iter::repeat(0).take(shift as usize))
+        .try_fold(0i128, |acc, digit| acc.checked_mul(10)?.checked_add(digit))
+        .and_then(|value| value.checked_add(round_away as i128))
+        .ok_or_else(error)?;
+    // rounding away from zero may carry into another digit
+    if Some(value) == 10i128.checked_pow(precision as u32) {
+        return Err(error());
+    }
+    Ok(if negative { -value } else { value })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(12345, parse_decimal("123.45", 5, 2, None).unwrap());
+        assert_eq!(-12300, parse_decimal("-123", 5, 2, None).unwrap());
+        assert_eq!(120, parse_decimal("1.2E0", 5, 2, None).unwrap());
+        assert_eq!(5, parse_decimal("0.0500", 5, 2, None).unwrap());
+        assert_eq!(1, parse_decimal("1e-2", 5, 2, None).unwrap());
+        assert!(parse_decimal("0.001", 5, 2, None).is_err());
+        assert!(parse_decimal("1234", 5, 2, None).is_err());
+        assert!(parse_decimal("abc", 5, 2, None).is_err());
+        assert!(parse_decimal(".", 5, 2, None).is_err());
+        assert!(parse_decimal("- 1", 5, 2, None).is_err());
+    }
+
+    #[test]
+    fn test_parse_decimal_whitespace() {
+        assert_eq!(-150, parse_decimal(" -1.5\t", 5, 2, None).unwrap());
+        assert_eq!(100, parse_decimal("\n1e0 ", 5, 2, None).unwrap());
+        assert!(parse_decimal("  ", 5, 2, None).is_err());
+    }
+
+    #[test]
+    fn test_parse_decimal_extreme_exponents() {
+        assert_eq!(0, parse_decimal("0e400", 5, 2, None).unwrap());
+        assert_eq!(0, parse_decimal("-0.00e-400", 5, 2, None).unwrap());
+        let err = parse_decimal("1e9223372036854775807", 38, 2, None).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parser error: Cannot parse 1e9223372036854775807 as Decimal(38, 2)"
+        );
+        assert!(parse_decimal("1e-9223372036854775808", 38, 2, None).is_err());
+        assert!(parse_decimal("1.5e-9223372036854775808", 38, 2, None).is_err());
+        assert!(parse_decimal("1e9223372036854775806", 38, 0, None).is_err());
+        let rounding = Some(RoundingMode::HalfUp);
+        assert_eq!(
+            0,
+            parse_decimal("1e-9223372036854775808", 38, 2, rounding).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_decimal_rounding() {
+        let parse = |text, rounding| parse_decimal(text, 5, 2, Some(rounding)).ok();
+        assert_eq!(Some(12), parse("0.125", RoundingMode::Truncate));
+        assert_eq!(Some(13), parse("0.125", RoundingMode::HalfUp));
+        assert_eq!(Some(12), parse("0.125", RoundingMode::HalfEven));
+        assert_eq!(Some(14), parse("0.135", RoundingMode::HalfEven));
+        assert_eq!(Some(13), parse("0.1250001", RoundingMode::HalfEven));
+        assert_eq!(Some(-13), parse("-12.5e-2", RoundingMode::HalfUp));
+        assert_eq!(Some(1), parse("5e-3", RoundingMode::HalfUp));
+        assert_eq!(Some(0), parse("5e-4", RoundingMode::HalfUp));
+        assert_eq!(Some(99999), parse("999.99499", RoundingMode::HalfUp));
+        // rounding carries into a digit beyond the precision
+        assert_eq!(None, parse("999.995", RoundingMode::HalfUp));
+    }
+}
@@ -54,14 +54,7 @@ macro_rules! make_string_interval_year_month {
         let s = if array.is_null($row) {
             "NULL".to_string()
         } else {
-            let interval = array.value($row) as f64;
-            let years = (interval / 12_f64).floor();
-            let month = interval - (years * 12_f64);
-
-            format!(
-                "{} years {} mons 0 days 0 hours 0 mins 0.00 secs",
-                years, month,
-            )
+            interval_year_month_to_string(array.value($row))
         };
 
         Ok(s)
@@ -78,32 +71,52 @@ macro_rules! make_string_interval_day_time {
         let s = if array.is_null($row) {
             "NULL".to_string()
         } else {
-            let value: u64 = array.value($row) as u64;
-
-            let days_parts: i32 = ((value & 0xFFFFFFFF00000000) >> 32) as i32;
-            let milliseconds_part: i32 = (value & 0xFFFFFFFF) as i32;
-
-            let secs = milliseconds_part / 1000;
-            let mins = secs / 60;
-            let hours = mins / 60;
-
-            let secs = secs - (mins * 60);
-            let mins = mins - (hours * 60);
-
-            format!(
-                "0 years 0 mons {} days {} hours {} mins {}.{:02} secs",
-                days_parts,
-                hours,
-                mins,
-                secs,
-                (milliseconds_part % 1000),
-            )
+            interval_day_time_to_string(array.value($row))
         };
 
         Ok(s)
     }};
 }
 
+/// Formats the value of an `Interval(YearMonth)`, a number of months, as
+/// `{} years {} mons 0 days 0 hours 0 mins 0.000 secs`.
+///
+/// The result can be parsed back with
+/// [`string_to_interval_year_month`](crate::compute::kernels::cast_utils::string_to_interval_year_month).
+pub fn interval_year_month_to_string(months: i32) -> String {
+    format!(
+        "{} years {} mons 0 days 0 hours 0 mins 0.000 secs",
+        months / 12,
+        months % 12
+    )
+}
+
+/// Formats the value of an `Interval(DayTime)`, a number of days and of
+/// milliseconds, as `0 years 0 mons {} days {} hours {} mins {}.{:03} secs`.
+///
+/// The result can be parsed back with
+/// [`string_to_interval_day_time`](crate::compute::kernels::cast_utils::string_to_interval_day_time).
+pub fn interval_day_time_to_string(value: i64) -> String {
+    let days = (value >> 32) as i32;
+    let millis = value as i32;
+
+    let hours = millis / 3_600_000;
+    let mins = millis / 60_000 % 60;
+    let secs_millis = millis % 60_000;
+    let sign = if secs_millis < 0 { "-" } else { "" };
+    let secs_millis = secs_millis.abs();
+
+    format!(
+        "0 years 0 mons {} days {} hours {} mins {}{}.{:03} secs",
+        days,
+        hours,
+        mins,
+        sign,
+        secs_millis / 1000,
+        secs_millis % 1000,
+    )
+}
+
 macro_rules! make_string_date {
     ($array_type:ty, $column: ident, $row: ident) => {{
         let array = $column.as_any().downcast_ref::<$array_type>().unwrap();
@@ -17,15 +17,53 @@
 
 //! Utils for JSON integration testing
 //!
-//! These utilities define structs that read the integration JSON format for integration testing purposes.
+//! These utilities define structs that read and write the Arrow integration JSON format,
+//! which the Arrow implementations use to exchange test data. [ArrowJson::try_new] converts
+//! a schema and record batches to it, and [ArrowJson::to_batches] converts them back.
+//!
+//! # Example
+//! ```
+//! use std::sync::Arc;
+//! use arrow::array::{Int32Array, StringArray};
+//! use arrow::datatypes::{DataType, Field, Schema};
+//! use arrow::record_batch::RecordBatch;
+//! use arrow::util::integration_util::ArrowJson;
+//!
+//! # fn main() -> arrow::error::Result<()> {
+//! let schema = Schema::new(vec![
+//!     Field::new("a", DataType::Int32, true),
+//!     Field::new("b", DataType::Utf8, true),
+//! ]);
+//! let batch = RecordBatch::try_new(
+//!     Arc::new(schema.clone()),
+//!     vec![
+//!         Arc::new(Int32Array::from(vec![Some(1), None])),
+//!         Arc::new(StringArray::from(vec![Some("x"), None])),
+//!     ],
+//! )?;
+//!
+//! let json = serde_json::to_string(&ArrowJson::try_new(&schema, &[batch.clone()])?)?;
+//!
+//! let arrow_json: ArrowJson = serde_json::from_str(&json)?;
+//! assert_eq!(arrow_json.to_schema()?, schema);
+//! let batches = arrow_json.to_batches()?;
+//! assert_eq!(batches[0].column(1).as_ref(), batch.column(1).as_ref());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
 
 use serde_derive::{Deserialize, Serialize};
-use serde_json::{Map as SJMap, Number as VNumber, Value};
+use serde_json::{json, Number as VNumber, Value};
 
 use crate::array::*;
+use crate::buffer::{Buffer, MutableBuffer};
 use crate::datatypes::*;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 use crate::record_batch::{RecordBatch, RecordBatchReader};
+use crate::util::bit_util;
 
 /// A struct that represents an Arrow file with a schema and record batches
 #[derive(Deserialize, Serialize, Debug)]
@@ -42,6 +80,8 @@ pub struct ArrowJson {
 #[derive(Deserialize, Serialize, Debug)]
 pub struct ArrowJsonSchema {
     pub fields: Vec<ArrowJsonField>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
 }
 
 /// Fields are left as JSON `Value` as they vary by `DataType`
@@ -60,34 +100,53 @@ pub struct ArrowJsonField {
 
 impl From<&Field> for ArrowJsonField {
     fn from(field: &Field) -> Self {
-        let metadata_value = match field.metadata() {
-            Some(kv_list) => {
-                let mut array = Vec::new();
-                for (k, v) in kv_list {
-                    let mut kv_map = SJMap::new();
-                    kv_map.insert(k.clone(), Value::String(v.clone()));
-                    array.push(Value::Object(kv_map));
-                }
-                if !array.is_empty() {
-                    Some(Value::Array(array))
-                } else {
-                    None
-                }
+        let (data_type, dictionary) = match field.data_type() {
+            DataType::Dictionary(key_type, value_type) => {
+                let dictionary = ArrowJsonFieldDictionary {
+                    id: field.dict_id().unwrap_or_default(),
+                    index_type: serde_json::from_value(key_type.to_json())
+                        .expect("dictionary keys are integers"),
+                    is_ordered: field.dict_is_ordered().unwrap_or_default(),
+                };
+                (value_type.as_ref(), Some(dictionary))
             }
-            _ => None,
+            data_type => (data_type, None),
+        };
+        let children = match data_type {
+            DataType::Struct(fields) => fields.iter().map(Self::from).collect(),
+            DataType::List(field)
+            | DataType::LargeList(field)
+            | DataType::FixedSizeList(field, _)
+            | DataType::Map(field, _) => vec![Self::from(field.as_ref())],
+            _ => vec![],
         };
 
         Self {
             name: field.name().to_string(),
-            field_type: field.data_type().to_json(),
+            field_type: data_type.to_json(),
             nullable: field.is_nullable(),
-            children: vec![],
-            dictionary: None, // TODO: not enough info
-            metadata: metadata_value,
+            children,
+            dictionary,
+            metadata: field.metadata().as_ref().and_then(metadata_to_json),
         }
     }
 }
 
+/// Converts metadata to the JSON array of key-value objects of the Arrow JSON format
+fn metadata_to_json<'a>(
+    metadata: impl IntoIterator<Item = (&'a String, &'a String)>,
+) -> Option<Value> {
+    let values = metadata
+        .into_iter()
+        .map(|(key, value)| json!({ "key": key, "value": value }))
+        .collect::<Vec<_>>();
+    if values.is_empty() {
+        None
+    } else {
+        Some(Value::Array(values))
+    }
+}
+
 #[derive(Deserialize, Serialize, Debug)]
 pub struct ArrowJsonFieldDictionary {
     pub id: i64,
@@ -107,14 +166,14 @@ pub struct DictionaryIndexType {
 }
 
 /// A struct that partially reads the Arrow JSON record batch
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct ArrowJsonBatch {
     count: usize,
     pub columns: Vec<ArrowJsonColumn>,
 }
 
 /// A struct that partially reads the Arrow JSON dictionary batch
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug)]
 #[allow(non_snake_case)]
 pub struct ArrowJsonDictionaryBatch {
     pub id: i64,
@@ -126,16 +185,79 @@ pub struct ArrowJsonDictionaryBatch {
 pub struct ArrowJsonColumn {
     name: String,
     pub count: usize,
-    #[serde(rename = "VALIDITY")]
+    #[serde(rename = "VALIDITY", skip_serializing_if = "Option::is_none")]
     pub validity: Option<Vec<u8>>,
-    #[serde(rename = "DATA")]
+    #[serde(rename = "DATA", skip_serializing_if = "Option::is_none")]
     pub data: Option<Vec<Value>>,
-    #[serde(rename = "OFFSET")]
+    #[serde(rename = "OFFSET", skip_serializing_if = "Option::is_none")]
     pub offset: Option<Vec<Value>>, // leaving as Value as 64-bit offsets are strings
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub children: Option<Vec<ArrowJsonColumn>>,
 }
 
 impl ArrowJson {
+    /// Converts a schema and its record batches to the Arrow JSON format, with the
+    /// dictionaries of their dictionary columns.
+    ///
+    /// Returns an error if a column is of a type the format doesn't support, or if
+    /// dictionary columns with the same dictionary id have different dictionaries.
+    pub fn try_new(schema: &Schema, batches: &[RecordBatch]) -> Result<Self> {
+        let mut dictionaries = JsonDictionaries::new();
+        let batches = batches
+            .iter()
+            .map(|batch| batch_to_json(batch, &mut dictionaries))
+            .collect::<Result<_>>()?;
+        let dictionaries = dictionaries
+            .into_iter()
+            .map(|(id, (field, values))| {
+                let column =
+                    array_to_json(&field, values.as_ref(), &mut JsonDictionaries::new())?;
+                Ok(ArrowJsonDictionaryBatch {
+                    id,
+                    data: ArrowJsonBatch {
+                        count: values.len(),
+                        columns: vec![column],
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            schema: ArrowJsonSchema {
+                fields: schema.fields().iter().map(ArrowJsonField::from).collect(),
+                metadata: metadata_to_json(schema.metadata()),
+            },
+            batches,
+            dictionaries: if dictionaries.is_empty() {
+                None
+            } else {
+                Some(dictionaries)
+            },
+        })
+    }
+
+    /// Returns the Arrow schema of the Arrow JSON
+    pub fn to_schema(&self) -> Result<Schema> {
+        Schema::from(&serde_json::to_value(&self.schema)?)
+    }
+
+    /// Converts the record batches of the Arrow JSON to Arrow record batches
+    pub fn to_batches(&self) -> Result<Vec<RecordBatch>> {
+        let schema = self.to_schema()?;
+        let dictionaries = self
+            .dictionaries
+            .iter()
+            .flatten()
+            .map(|dictionary| (dictionary.id, dictionary.clone()))
+            .collect::<HashMap<_, _>>();
+        self.batches
+            .iter()
+            .map(|batch| {
+                record_batch_from_json(&schema, batch.clone(), Some(&dictionaries))
+            })
+            .collect()
+    }
+
     /// Compare the Arrow JSON with a record batch reader
     pub fn equals_reader(&self, reader: &mut dyn RecordBatchReader) -> bool {
         if !self.schema.equals_schema(&reader.schema()) {
@@ -434,53 +556,12 @@ impl ArrowJsonBatch {
             })
     }
 
-    pub fn from_batch(batch: &RecordBatch) -> ArrowJsonBatch {
-        let mut json_batch = ArrowJsonBatch {
-            count: batch.num_rows(),
-            columns: Vec::with_capacity(batch.num_columns()),
-        };
-
-        for (col, field) in batch.columns().iter().zip(batch.schema().fields.iter()) {
-            let json_col = match field.data_type() {
-                DataType::Int8 => {
-                    let col = col.as_any().downcast_ref::<Int8Array>().unwrap();
-
-                    let mut validity: Vec<u8> = Vec::with_capacity(col.len());
-                    let mut data: Vec<Value> = Vec::with_capacity(col.len());
-
-                    for i in 0..col.len() {
-                        if col.is_null(i) {
-                            validity.push(1);
-                            data.push(0i8.into());
-                        } else {
-                            validity.push(0);
-                            data.push(col.value(i).into());
-                        }
-                    }
-
-                    ArrowJsonColumn {
-                        name: field.name().clone(),
-                        count: col.len(),
-                        validity: Some(validity),
-                        data: Some(data),
-                        offset: None,
-                        children: None,
-                    }
-                }
-                _ => ArrowJsonColumn {
-                    name: field.name().clone(),
-                    count: col.len(),
-                    validity: None,
-                    data: None,
-                    offset: None,
-                    children: None,
-                },
-            };
-
-            json_batch.columns.push(json_col);
-        }
-
-        json_batch
+    /// Converts a record batch to the Arrow JSON format.
+    ///
+    /// The columns of dictionary arrays hold their keys, use [ArrowJson::try_new] to also
+    /// convert their dictionaries.
+    pub fn from_batch(batch: &RecordBatch) -> Result<ArrowJsonBatch> {
+        batch_to_json(batch, &mut JsonDictionaries::new())
     }
 }
 
@@ -649,6 +730,847 @@ fn json_from_fixed_size_list_col(
     values
 }
 
+/// Converts a record batch of the Arrow JSON format to a `RecordBatch` of `schema`,
+/// looking up the values of its dictionary columns in `json_dictionaries`
+pub fn record_batch_from_json(
+    schema: &Schema,
+    json_batch: ArrowJsonBatch,
+    json_dictionaries: Option<&HashMap<i64, ArrowJsonDictionaryBatch>>,
+) -> Result<RecordBatch> {
+    let mut columns = vec![];
+
+    for (field, json_col) in schema.fields().iter().zip(json_batch.columns) {
+        let col = array_from_json(field, json_col, json_dictionaries)?;
+        columns.push(col);
+    }
+
+    RecordBatch::try_new(Arc::new(schema.clone()), columns)
+}
+
+/// Construct an Arrow array from a partially typed JSON column
+///
+/// The values of a dictionary array are looked up in `dictionaries` by the dictionary id
+/// of `field`.
+pub fn array_from_json(
+    field: &Field,
+    json_col: ArrowJsonColumn,
+    dictionaries: Option<&HashMap<i64, ArrowJsonDictionaryBatch>>,
+) -> Result<ArrayRef> {
+    match field.data_type() {
+        DataType::Null => Ok(Arc::new(NullArray::new(json_col.count))),
+        DataType::Boolean => {
+            let mut b = BooleanBuilder::new(json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(value.as_bool().unwrap()),
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::Int8 => {
+            let mut b = Int8Builder::new(json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(value.as_i64().ok_or_else(|| {
+                        ArrowError::JsonError(format!(
+                            "Unable to get {:?} as int64",
+                            value
+                        ))
+                    })? as i8),
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::Int16 => {
+            let mut b = Int16Builder::new(json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(value.as_i64().unwrap() as i16),
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::Int32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => {
+            let mut b = Int32Builder::new(json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(value.as_i64().unwrap() as i32),
+                    _ => b.append_null(),
+                }?;
+            }
+            let array = Arc::new(b.finish()) as ArrayRef;
+            crate::compute::cast(&array, field.data_type())
+        }
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => {
+            let mut b = Int64Builder::new(json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(match value {
+                        Value::Number(n) => n.as_i64().unwrap(),
+                        Value::String(s) => {
+                            s.parse().expect("Unable to parse string as i64")
+                        }
+                        _ => panic!("Unable to parse {:?} as number", value),
+                    }),
+                    _ => b.append_null(),
+                }?;
+            }
+            let array = Arc::new(b.finish()) as ArrayRef;
+            crate::compute::cast(&array, field.data_type())
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            let mut b = IntervalDayTimeBuilder::new(json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => {
+                        // the days are the lower and the milliseconds the upper 32 bits
+                        let days = value["days"].as_i64().unwrap() as i32;
+                        let milliseconds = value["milliseconds"].as_i64().unwrap() as i32;
+                        b.append_value(
+                            ((milliseconds as i64) << 32) | (days as u32 as i64),
+                        )
+                    }
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::UInt8 => {
+            let mut b = UInt8Builder::new(json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(value.as_u64().unwrap() as u8),
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::UInt16 => {
+            let mut b = UInt16Builder::new(json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(value.as_u64().unwrap() as u16),
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::UInt32 => {
+            let mut b = UInt32Builder::new(json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(value.as_u64().unwrap() as u32),
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::UInt64 => {
+            let mut b = UInt64Builder::new(json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(match value {
+                        Value::Number(n) => n.as_u64().unwrap(),
+                        Value::String(s) => {
+                            s.parse().expect("Unable to parse string as u64")
+                        }
+                        _ => panic!("Unable to parse {:?} as number", value),
+                    }),
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::Float32 => {
+            let mut b = Float32Builder::new(json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(value.as_f64().unwrap() as f32),
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::Float64 => {
+            let mut b = Float64Builder::new(json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(value.as_f64().unwrap()),
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::Binary => {
+            let mut b = BinaryBuilder::with_capacity(json_col.count, json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => {
+                        let v = hex::decode(value.as_str().unwrap()).unwrap();
+                        b.append_value(&v)
+                    }
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::LargeBinary => {
+            let mut b = LargeBinaryBuilder::with_capacity(json_col.count, json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => {
+                        let v = hex::decode(value.as_str().unwrap()).unwrap();
+                        b.append_value(&v)
+                    }
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::Utf8 => {
+            let mut b = StringBuilder::with_capacity(json_col.count, json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(value.as_str().unwrap()),
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::LargeUtf8 => {
+            let mut b = LargeStringBuilder::with_capacity(json_col.count, json_col.count);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(value.as_str().unwrap()),
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::FixedSizeBinary(len) => {
+            let mut b = FixedSizeBinaryBuilder::with_capacity(json_col.count, *len);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => {
+                        let v = hex::decode(value.as_str().unwrap()).unwrap();
+                        b.append_value(&v)
+                    }
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::Decimal(precision, scale) => {
+            let mut b = DecimalBuilder::with_capacity(json_col.count, *precision, *scale);
+            for (is_valid, value) in json_col
+                .validity
+                .as_ref()
+                .unwrap()
+                .iter()
+                .zip(json_col.data.unwrap())
+            {
+                match is_valid {
+                    1 => b.append_value(
+                        value
+                            .as_str()
+                            .unwrap()
+                            .parse()
+                            .expect("Unable to parse string as i128"),
+                    ),
+                    _ => b.append_null(),
+                }?;
+            }
+            Ok(Arc::new(b.finish()))
+        }
+        DataType::List(child_field) => {
+            let null_buf = create_null_buf(&json_col);
+            let children = json_col.children.clone().unwrap();
+            let child_array = array_from_json(
+                &child_field,
+                children.get(0).unwrap().clone(),
+                dictionaries,
+            )?;
+            let offsets: Vec<i32> = json_col
+                .offset
+                .unwrap()
+                .iter()
+                .map(|v| v.as_i64().unwrap() as i32)
+                .collect();
+            let list_data = ArrayData::builder(field.data_type().clone())
+                .len(json_col.count)
+                .offset(0)
+                .add_buffer(Buffer::from(&offsets.to_byte_slice()))
+                .add_child_data(child_array.data().clone())
+                .null_bit_buffer(null_buf)
+                .build();
+            Ok(Arc::new(ListArray::from(list_data)))
+        }
+        DataType::LargeList(child_field) => {
+            let null_buf = create_null_buf(&json_col);
+            let children = json_col.children.clone().unwrap();
+            let child_array = array_from_json(
+                &child_field,
+                children.get(0).unwrap().clone(),
+                dictionaries,
+            )?;
+            let offsets: Vec<i64> = json_col
+                .offset
+                .unwrap()
+                .iter()
+                .map(|v| match v {
+                    Value::Number(n) => n.as_i64().unwrap(),
+                    Value::String(s) => s.parse::<i64>().unwrap(),
+                    _ => panic!("64-bit offset must be either string or number"),
+                })
+                .collect();
+            let list_data = ArrayData::builder(field.data_type().clone())
+                .len(json_col.count)
+                .offset(0)
+                .add_buffer(Buffer::from(&offsets.to_byte_slice()))
+                .add_child_data(child_array.data().clone())
+                .null_bit_buffer(null_buf)
+                .build();
+            Ok(Arc::new(LargeListArray::from(list_data)))
+        }
+        DataType::FixedSizeList(child_field, _) => {
+            let children = json_col.children.clone().unwrap();
+            let child_array = array_from_json(
+                &child_field,
+                children.get(0).unwrap().clone(),
+                dictionaries,
+            )?;
+            let null_buf = create_null_buf(&json_col);
+            let list_data = ArrayData::builder(field.data_type().clone())
+                .len(json_col.count)
+                .add_child_data(child_array.data().clone())
+                .null_bit_buffer(null_buf)
+                .build();
+            Ok(Arc::new(FixedSizeListArray::from(list_data)))
+        }
+        DataType::Struct(fields) => {
+            // construct struct with null data
+            let null_buf = create_null_buf(&json_col);
+            let mut array_data = ArrayData::builder(field.data_type().clone())
+                .len(json_col.count)
+                .null_bit_buffer(null_buf);
+
+            for (field, col) in fields.iter().zip(json_col.children.unwrap()) {
+                let array = array_from_json(field, col, dictionaries)?;
+                array_data = array_data.add_child_data(array.data().clone());
+            }
+
+            let array = StructArray::from(array_data.build());
+            Ok(Arc::new(array))
+        }
+        DataType::Dictionary(key_type, value_type) => {
+            let dict_id = field.dict_id().ok_or_else(|| {
+                ArrowError::JsonError(format!(
+                    "Unable to find dict_id for field {:?}",
+                    field
+                ))
+            })?;
+            // find dictionary
+            let dictionary = dictionaries
+                .ok_or_else(|| {
+                    ArrowError::JsonError(format!(
+                        "Unable to find any dictionaries for field {:?}",
+                        field
+                    ))
+                })?
+                .get(&dict_id);
+            match dictionary {
+                Some(dictionary) => dictionary_array_from_json(
+                    field, json_col, key_type, value_type, dictionary,
+                ),
+                None => Err(ArrowError::JsonError(format!(
+                    "Unable to find dictionary for field {:?}",
+                    field
+                ))),
+            }
+        }
+        t => Err(ArrowError::JsonError(format!(
+            "data type {:?} not supported",
+            t
+        ))),
+    }
+}
+
+fn dictionary_array_from_json(
+    field: &Field,
+    json_col: ArrowJsonColumn,
+    dict_key: &DataType,
+    dict_value: &DataType,
+    dictionary: &ArrowJsonDictionaryBatch,
+) -> Result<ArrayRef> {
+    match dict_key {
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64 => {
+            let null_buf = create_null_buf(&json_col);
+
+            // build the key data into a buffer, then construct values separately
+            let key_field = Field::new_dict(
+                "key",
+                dict_key.clone(),
+                field.is_nullable(),
+                field
+                    .dict_id()
+                    .expect("Dictionary fields must have a dict_id value"),
+                field
+                    .dict_is_ordered()
+                    .expect("Dictionary fields must have a dict_is_ordered value"),
+            );
+            let keys = array_from_json(&key_field, json_col, None)?;
+            // note: not enough info on nullability of dictionary
+            let value_field = Field::new("value", dict_value.clone(), true);
+            let values =
+                array_from_json(&value_field, dictionary.data.columns[0].clone(), None)?;
+
+            // convert key and value to dictionary data
+            let dict_data = ArrayData::builder(field.data_type().clone())
+                .len(keys.len())
+                .add_buffer(keys.data().buffers()[0].clone())
+                .null_bit_buffer(null_buf)
+                .add_child_data(values.data().clone())
+                .build();
+
+            let array = match dict_key {
+                DataType::Int8 => {
+                    Arc::new(Int8DictionaryArray::from(dict_data)) as ArrayRef
+                }
+                DataType::Int16 => Arc::new(Int16DictionaryArray::from(dict_data)),
+                DataType::Int32 => Arc::new(Int32DictionaryArray::from(dict_data)),
+                DataType::Int64 => Arc::new(Int64DictionaryArray::from(dict_data)),
+                DataType::UInt8 => Arc::new(UInt8DictionaryArray::from(dict_data)),
+                DataType::UInt16 => Arc::new(UInt16DictionaryArray::from(dict_data)),
+                DataType::UInt32 => Arc::new(UInt32DictionaryArray::from(dict_data)),
+                DataType::UInt64 => Arc::new(UInt64DictionaryArray::from(dict_data)),
+                _ => unreachable!(),
+            };
+            Ok(array)
+        }
+        _ => Err(ArrowError::JsonError(format!(
+            "Dictionary key type {:?} not supported",
+            dict_key
+        ))),
+    }
+}
+
+/// A helper to create a null buffer from a Vec<bool>
+fn create_null_buf(json_col: &ArrowJsonColumn) -> Buffer {
+    let num_bytes = bit_util::ceil(json_col.count, 8);
+    let mut null_buf = MutableBuffer::new(num_bytes).with_bitset(num_bytes, false);
+    json_col
+        .validity
+        .clone()
+        .unwrap()
+        .iter()
+        .enumerate()
+        .for_each(|(i, v)| {
+            let null_slice = null_buf.as_slice_mut();
+            if *v != 0 {
+                bit_util::set_bit(null_slice, i);
+            }
+        });
+    null_buf.into()
+}
+
+/// The dictionaries of the dictionary arrays converted to the Arrow JSON format, by
+/// dictionary id, with the field of their values
+type JsonDictionaries = BTreeMap<i64, (Field, ArrayRef)>;
+
+/// Converts a record batch to the Arrow JSON format, adding the dictionaries of its
+/// dictionary columns to `dictionaries`
+fn batch_to_json(
+    batch: &RecordBatch,
+    dictionaries: &mut JsonDictionaries,
+) -> Result<ArrowJsonBatch> {
+    let schema = batch.schema();
+    let columns = schema
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, array)| array_to_json(field, array.as_ref(), dictionaries))
+        .collect::<Result<_>>()?;
+    Ok(ArrowJsonBatch {
+        count: batch.num_rows(),
+        columns,
+    })
+}
+
+/// Converts an Arrow array of `field` to a column of the Arrow JSON format.
+///
+/// The column of a dictionary array holds its keys, its values are added to
+/// `dictionaries` under the dictionary id of `field`.
+fn array_to_json(
+    field: &Field,
+    array: &dyn Array,
+    dictionaries: &mut JsonDictionaries,
+) -> Result<ArrowJsonColumn> {
+    let mut json_col = ArrowJsonColumn {
+        name: field.name().clone(),
+        count: array.len(),
+        validity: Some((0..array.len()).map(|i| array.is_valid(i) as u8).collect()),
+        data: None,
+        offset: None,
+        children: None,
+    };
+    match field.data_type() {
+        DataType::Null => json_col.validity = None,
+        DataType::Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            json_col.data =
+                Some((0..array.len()).map(|i| array.value(i).into()).collect());
+        }
+        DataType::Int8 => json_col.data = Some(primitive_to_json::<Int8Type>(array)),
+        DataType::Int16 => json_col.data = Some(primitive_to_json::<Int16Type>(array)),
+        DataType::Int32
+        | DataType::Date32
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => {
+            json_col.data = Some(primitive_to_json::<Int32Type>(array))
+        }
+        DataType::UInt8 => json_col.data = Some(primitive_to_json::<UInt8Type>(array)),
+        DataType::UInt16 => json_col.data = Some(primitive_to_json::<UInt16Type>(array)),
+        DataType::UInt32 => json_col.data = Some(primitive_to_json::<UInt32Type>(array)),
+        DataType::Float32 => {
+            json_col.data = Some(primitive_to_json::<Float32Type>(array))
+        }
+        DataType::Float64 => {
+            json_col.data = Some(primitive_to_json::<Float64Type>(array))
+        }
+        // 64-bit integers are strings, as JSON numbers can't represent all of them
+        DataType::Int64
+        | DataType::Date64
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_) => {
+            let array = Int64Array::from(array.data().clone());
+            json_col.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(array.value(i).to_string()))
+                    .collect(),
+            );
+        }
+        DataType::UInt64 => {
+            let array = array.as_any().downcast_ref::<UInt64Array>().unwrap();
+            json_col.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(array.value(i).to_string()))
+                    .collect(),
+            );
+        }
+        DataType::Interval(IntervalUnit::DayTime) => {
+            let array = IntervalDayTimeArray::from(array.data().clone());
+            json_col.data = Some(
+                (0..array.len())
+                    .map(|i| {
+                        // the days are the lower and the milliseconds the upper 32 bits
+                        let value = array.value(i);
+                        json!({
+                            "days": value as i32,
+                            "milliseconds": (value >> 32) as i32,
+                        })
+                    })
+                    .collect(),
+            );
+        }
+        DataType::Decimal(_, _) => {
+            let array = array.as_any().downcast_ref::<DecimalArray>().unwrap();
+            json_col.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(array.value(i).to_string()))
+                    .collect(),
+            );
+        }
+        DataType::Binary => {
+            let array = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+            json_col.offset = Some(offsets_to_json(array.value_offsets()));
+            json_col.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(hex::encode_upper(array.value(i))))
+                    .collect(),
+            );
+        }
+        DataType::LargeBinary => {
+            let array = array.as_any().downcast_ref::<LargeBinaryArray>().unwrap();
+            json_col.offset = Some(offsets_to_json(array.value_offsets()));
+            json_col.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(hex::encode_upper(array.value(i))))
+                    .collect(),
+            );
+        }
+        DataType::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            json_col.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(hex::encode_upper(array.value(i))))
+                    .collect(),
+            );
+        }
+        DataType::Utf8 => {
+            let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+            json_col.offset = Some(offsets_to_json(array.value_offsets()));
+            json_col.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(array.value(i).to_string()))
+                    .collect(),
+            );
+        }
+        DataType::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
+            json_col.offset = Some(offsets_to_json(array.value_offsets()));
+            json_col.data = Some(
+                (0..array.len())
+                    .map(|i| Value::String(array.value(i).to_string()))
+                    .collect(),
+            );
+        }
+        DataType::List(child_field) => {
+            let array = array.as_any().downcast_ref::<ListArray>().unwrap();
+            let offsets = array.value_offsets();
+            let values = array.values().slice(
+                offsets[0] as usize,
+                (offsets[array.len()] - offsets[0]) as usize,
+            );
+            json_col.offset = Some(offsets_to_json(offsets));
+            json_col.children = Some(vec![array_to_json(
+                child_field,
+                values.as_ref(),
+                dictionaries,
+            )?]);
+        }
+        DataType::LargeList(child_field) => {
+            let array = array.as_any().downcast_ref::<LargeListArray>().unwrap();
+            let offsets = array.value_offsets();
+            let values = array.values().slice(
+                offsets[0] as usize,
+                (offsets[array.len()] - offsets[0]) as usize,
+            );
+            json_col.offset = Some(offsets_to_json(offsets));
+            json_col.children = Some(vec![array_to_json(
+                child_field,
+                values.as_ref(),
+                dictionaries,
+            )?]);
+        }
+        DataType::FixedSizeList(child_field, size) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = array
+                .values()
+                .slice(array.value_offset(0) as usize, array.len() * *size as usize);
+            json_col.children = Some(vec![array_to_json(
+                child_field,
+                values.as_ref(),
+                dictionaries,
+            )?]);
+        }
+        DataType::Struct(fields) => {
+            let children = fields
+                .iter()
+                .zip(array.data().child_data())
+                .map(|(field, data)| {
+                    let child =
+                        make_array(data.clone()).slice(array.offset(), array.len());
+                    array_to_json(field, child.as_ref(), dictionaries)
+                })
+                .collect::<Result<_>>()?;
+            json_col.children = Some(children);
+        }
+        DataType::Dictionary(key_type, value_type) => {
+            let dict_id = field.dict_id().ok_or_else(|| {
+                ArrowError::JsonError(format!(
+                    "Unable to find dict_id for field {:?}",
+                    field
+                ))
+            })?;
+            let data = array.data();
+            let values = make_array(data.child_data()[0].clone());
+            match dictionaries.get(&dict_id) {
+                Some((_, existing)) if existing.as_ref() != values.as_ref() => {
+                    return Err(ArrowError::JsonError(format!(
+                        "The JSON format doesn't support replacing the dictionary with id {}",
+                        dict_id
+                    )));
+                }
+                Some(_) => {}
+                None => {
+                    let value_field =
+                        Field::new(field.name(), value_type.as_ref().clone(), true);
+                    dictionaries.insert(dict_id, (value_field, values));
+                }
+            }
+
+            let keys = make_array(ArrayData::new(
+                key_type.as_ref().clone(),
+                data.len(),
+                None,
+                data.null_buffer().cloned(),
+                data.offset(),
+                vec![data.buffers()[0].clone()],
+                vec![],
+            ));
+            let key_field =
+                Field::new(field.name(), key_type.as_ref().clone(), field.is_nullable());
+            return array_to_json(&key_field, keys.as_ref(), dictionaries);
+        }
+        t => {
+            return Err(ArrowError::JsonError(format!(
+                "data type {:?} not supported",
+                t
+            )))
+        }
+    }
+    Ok(json_col)
+}
+
+/// Converts the values of a primitive array to JSON numbers
+fn primitive_to_json<T>(array: &dyn Array) -> Vec<Value>
+where
+    T: ArrowPrimitiveType,
+    T::Native: Into<Value>,
+{
+    let array = PrimitiveArray::<T>::from(array.data().clone());
+    (0..array.len()).map(|i| array.value(i).into()).collect()
+}
+
+/// Converts offsets to JSON, starting at 0. 64-bit offsets are strings.
+fn offsets_to_json<O: OffsetSizeTrait>(offsets: &[O]) -> Vec<Value> {
+    let start = offsets[0].to_usize().unwrap();
+    offsets
+        .iter()
+        .map(|offset| {
+            let offset = offset.to_usize().unwrap() - start;
+            if O::is_large() {
+                Value::String(offset.to_string())
+            } else {
+                Value::from(offset)
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -955,4 +1877,102 @@ mod tests {
         // test record batch
         assert!(arrow_json.batches[0].equals_batch(&record_batch));
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // running forever
+    fn test_arrow_json_to_batches() {
+        let mut file = File::open("test/data/integration.json").unwrap();
+        let mut json = String::new();
+        file.read_to_string(&mut json).unwrap();
+        let arrow_json: ArrowJson = serde_json::from_str(&json).unwrap();
+
+        let schema = arrow_json.to_schema().unwrap();
+        let batches = arrow_json.to_batches().unwrap();
+        assert!(arrow_json.schema.equals_schema(&schema));
+        assert!(arrow_json.batches[0].equals_batch(&batches[0]));
+
+        let written = ArrowJson::try_new(&schema, &batches).unwrap();
+        assert!(written.schema.equals_schema(&schema));
+        assert!(written.batches[0].equals_batch(&batches[0]));
+        assert!(written.dictionaries.is_none());
+    }
+
+    #[test]
+    fn test_arrow_json_round_trip() {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let schema = Schema::new(vec![
+            Field::new_dict("dict", dict_type, true, 1, false),
+            Field::new("decimal", DataType::Decimal(10, 2), true),
+            Field::new("binary", DataType::LargeBinary, true),
+            Field::new("interval", DataType::Interval(IntervalUnit::DayTime), true),
+        ]);
+
+        let dict: DictionaryArray<Int8Type> = vec![Some("a"), None, Some("b"), Some("a")]
+            .into_iter()
+            .collect();
+        let mut decimal = DecimalBuilder::with_capacity(4, 10, 2);
+        decimal.append_value(12345).unwrap();
+        decimal.append_null().unwrap();
+        decimal.append_value(-1).unwrap();
+        decimal.append_value(0).unwrap();
+        let binary = LargeBinaryArray::from(vec![
+            Some(&b"\x01\xff"[..]),
+            None,
+            Some(&b""[..]),
+            Some(&b"ab"[..]),
+        ]);
+        // 1 day, -1 millisecond, 3 days and 5 milliseconds
+        let interval = IntervalDayTimeArray::from(vec![
+            Some(1),
+            None,
+            Some(-(1 << 32)),
+            Some(5 << 32 | 3),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![
+                Arc::new(dict),
+                Arc::new(decimal.finish()),
+                Arc::new(binary),
+                Arc::new(interval),
+            ],
+        )
+        .unwrap();
+        let batches = vec![batch.clone(), batch.slice(1, 3)];
+
+        let arrow_json = ArrowJson::try_new(&schema, &batches).unwrap();
+        let json = serde_json::to_string(&arrow_json).unwrap();
+        let arrow_json: ArrowJson = serde_json::from_str(&json).unwrap();
+        assert_eq!(arrow_json.dictionaries.as_ref().unwrap().len(), 1);
+        assert_eq!(arrow_json.to_schema().unwrap(), schema);
+
+        let read = arrow_json.to_batches().unwrap();
+        assert_eq!(read.len(), 2);
+        for (read, batch) in read.iter().zip(&batches) {
+            assert_eq!(read.num_rows(), batch.num_rows());
+            for (read, column) in read.columns().iter().zip(batch.columns()) {
+                assert_eq!(read.as_ref(), column.as_ref());
+            }
+        }
+    }
+
+    #[test]
+    fn test_arrow_json_dictionary_replacement() {
+        let dict_type =
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+        let schema = Arc::new(Schema::new(vec![Field::new_dict(
+            "dict", dict_type, false, 0, false,
+        )]));
+        let batch = |values: Vec<&str>| {
+            let dict: Int32DictionaryArray = values.into_iter().collect();
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(dict)]).unwrap()
+        };
+
+        let batches = vec![batch(vec!["a", "b"]), batch(vec!["b"])];
+        assert!(ArrowJson::try_new(&schema, &batches).is_err());
+        // without its dictionary, a batch can't be read back
+        let json_batch = ArrowJsonBatch::from_batch(&batches[0]).unwrap();
+        assert!(record_batch_from_json(&schema, json_batch, None).is_err());
+    }
 }
@@ -17,12 +17,14 @@
 
 pub mod bench_util;
 pub mod bit_chunk_iterator;
+pub mod bit_iterator;
 pub mod bit_util;
 pub mod data_gen;
 pub mod display;
 pub mod integration_util;
 #[cfg(feature = "prettyprint")]
 pub mod pretty;
+pub(crate) mod decimal;
 pub(crate) mod serialization;
 pub mod string_writer;
 pub mod test_util;
@@ -29,7 +29,16 @@ use super::display::array_value_to_string;
 
 ///! Create a visual representation of record batches
 pub fn pretty_format_batches(results: &[RecordBatch]) -> Result<String> {
-    Ok(create_table(results)?.to_string())
+    Ok(create_table(results, None)?.to_string())
+}
+
+///! Create a visual representation of record batches that fits in `width`
+///! characters, truncating the widest values with an ellipsis
+pub fn pretty_format_batches_with_width(
+    results: &[RecordBatch],
+    width: usize,
+) -> Result<String> {
+    Ok(create_table(results, Some(width))?.to_string())
 }
 
 ///! Create a visual representation of columns
@@ -39,7 +48,24 @@ pub fn pretty_format_columns(col_name: &str, results: &[ArrayRef]) -> Result<Str
 
 ///! Prints a visual representation of record batches to stdout
 pub fn print_batches(results: &[RecordBatch]) -> Result<()> {
-    create_table(results)?.printstd();
+    create_table(results, None)?.printstd();
+    Ok(())
+}
+
+///! Prints a visual representation of record batches to stdout that fits in
+///! `width` characters, or in the width of the terminal given by the `COLUMNS`
+///! environment variable if `None`, and 80 characters if it is not set
+pub fn print_batches_with_width(
+    results: &[RecordBatch],
+    width: Option<usize>,
+) -> Result<()> {
+    let width = width.unwrap_or_else(|| {
+        std::env::var("COLUMNS")
+            .ok()
+            .and_then(|columns| columns.parse().ok())
+            .unwrap_or(DEFAULT_TERMINAL_WIDTH)
+    });
+    create_table(results, Some(width))?.printstd();
     Ok(())
 }
 
@@ -49,34 +75,171 @@ pub fn print_columns(col_name: &str, results: &[ArrayRef]) -> Result<()> {
     Ok(())
 }
 
-///! Convert a series of record batches into a table
-fn create_table(results: &[RecordBatch]) -> Result<Table> {
-    let mut table = Table::new();
-    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+///! Create a Markdown table of record batches
+pub fn record_batches_to_markdown(results: &[RecordBatch]) -> Result<String> {
+    let mut markdown = String::new();
+    if results.is_empty() {
+        return Ok(markdown);
+    }
+
+    let (header, rows) = format_cells(results)?;
+    let escape = |value: &str| value.replace('|', "\\|").replace('\n', "<br>");
+    let write_row = |markdown: &mut String, cells: &[String]| {
+        markdown.push('|');
+        for cell in cells {
+            markdown.push(' ');
+            markdown.push_str(&escape(cell));
+            markdown.push_str(" |");
+        }
+        markdown.push('\n');
+    };
 
+    write_row(&mut markdown, &header);
+    markdown.push('|');
+    for _ in &header {
+        markdown.push_str("---|");
+    }
+    markdown.push('\n');
+    for row in &rows {
+        write_row(&mut markdown, row);
+    }
+    Ok(markdown)
+}
+
+///! Create an HTML table of record batches
+pub fn record_batches_to_html(results: &[RecordBatch]) -> Result<String> {
+    let mut html = String::new();
     if results.is_empty() {
-        return Ok(table);
+        return Ok(html);
     }
 
-    let schema = results[0].schema();
+    let (header, rows) = format_cells(results)?;
+    let write_row = |html: &mut String, cells: &[String], tag: &str| {
+        html.push_str("<tr>");
+        for cell in cells {
+            html.push_str(&format!("<{}>{}</{}>", tag, escape_html(cell), tag));
+        }
+        html.push_str("</tr>\n");
+    };
 
-    let mut header = Vec::new();
-    for field in schema.fields() {
-        header.push(Cell::new(&field.name()));
+    html.push_str("<table>\n<thead>\n");
+    write_row(&mut html, &header, "th");
+    html.push_str("</thead>\n<tbody>\n");
+    for row in &rows {
+        write_row(&mut html, row, "td");
     }
-    table.set_titles(Row::new(header));
+    html.push_str("</tbody>\n</table>\n");
+    Ok(html)
+}
+
+const DEFAULT_TERMINAL_WIDTH: usize = 80;
+
+const ELLIPSIS: char = '…';
+
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
 
+///! Formats the names of the columns and the values of the rows of record batches
+fn format_cells(results: &[RecordBatch]) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    let header = results[0]
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.name().clone())
+        .collect();
+
+    let mut rows = Vec::new();
     for batch in results {
         for row in 0..batch.num_rows() {
-            let mut cells = Vec::new();
-            for col in 0..batch.num_columns() {
-                let column = batch.column(col);
-                cells.push(Cell::new(&array_value_to_string(&column, row)?));
+            let cells = batch
+                .columns()
+                .iter()
+                .map(|column| array_value_to_string(column, row))
+                .collect::<Result<Vec<_>>>()?;
+            rows.push(cells);
+        }
+    }
+    Ok((header, rows))
+}
+
+///! Returns the largest number of characters of the values of each column such
+///! that the table fits in `width` characters, given the number of characters of
+///! the widest value of each column in `widths`
+fn column_widths(widths: &[usize], width: usize) -> Vec<usize> {
+    // each column has a separator and a space on each side, and the table a final separator
+    let available = width.saturating_sub(3 * widths.len() + 1);
+    let fits =
+        |max: usize| widths.iter().map(|w| (*w).min(max)).sum::<usize>() <= available;
+
+    // the largest width of a column that fits, truncating at least to a character
+    // and the ellipsis
+    let (mut low, mut high) = (2, widths.iter().copied().max().unwrap_or(0).max(2));
+    while low < high {
+        let mid = (low + high + 1) / 2;
+        if fits(mid) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    widths.iter().map(|w| (*w).min(low)).collect()
+}
+
+///! Truncates `value` to `width` characters, replacing its end with an ellipsis
+fn truncate(value: &str, width: usize) -> String {
+    if value.chars().count() <= width {
+        value.to_string()
+    } else {
+        let mut truncated: String = value.chars().take(width - 1).collect();
+        truncated.push(ELLIPSIS);
+        truncated
+    }
+}
+
+///! Convert a series of record batches into a table, truncating the values to
+///! fit in `width` characters if set
+fn create_table(results: &[RecordBatch], width: Option<usize>) -> Result<Table> {
+    let mut table = Table::new();
+    table.set_format(*format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+
+    if results.is_empty() {
+        return Ok(table);
+    }
+
+    let (mut header, mut rows) = format_cells(results)?;
+
+    if let Some(width) = width {
+        let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+        for row in &rows {
+            for (w, cell) in widths.iter_mut().zip(row) {
+                *w = (*w).max(cell.chars().count());
+            }
+        }
+        let widths = column_widths(&widths, width);
+        for row in std::iter::once(&mut header).chain(rows.iter_mut()) {
+            for (cell, w) in row.iter_mut().zip(&widths) {
+                *cell = truncate(cell, *w);
             }
-            table.add_row(Row::new(cells));
         }
     }
 
+    table.set_titles(Row::new(header.iter().map(|h| Cell::new(h)).collect()));
+    for row in rows {
+        table.add_row(Row::new(row.iter().map(|c| Cell::new(c)).collect()));
+    }
+
     Ok(table)
 }
 
@@ -165,6 +328,93 @@ mod tests {
         Ok(())
     }
 
+    fn markup_batch() -> Result<RecordBatch> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("name", DataType::Utf8, true),
+            Field::new("id", DataType::Int32, true),
+        ]));
+        RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(array::StringArray::from(vec![
+                    Some("a very long description"),
+                    Some("<a|b>"),
+                    None,
+                ])),
+                Arc::new(array::Int32Array::from(vec![Some(1), Some(2), None])),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_pretty_format_batches_with_width() -> Result<()> {
+        let table = pretty_format_batches_with_width(&[markup_batch()?], 20)?;
+
+        let expected = vec![
+            "+-------------+----+",
+            "| name        | id |",
+            "+-------------+----+",
+            "| a very lon… | 1  |",
+            "| <a|b>       | 2  |",
+            "|             |    |",
+            "+-------------+----+",
+        ];
+
+        let actual: Vec<&str> = table.lines().collect();
+
+        assert_eq!(expected, actual, "Actual result:\n{}", table);
+
+        // values are not truncated if the table fits
+        let table = pretty_format_batches_with_width(&[markup_batch()?], 80)?;
+        assert_eq!(table, pretty_format_batches(&[markup_batch()?])?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_batches_to_markdown() -> Result<()> {
+        let markdown = record_batches_to_markdown(&[markup_batch()?])?;
+
+        let expected = vec![
+            "| name | id |",
+            "|---|---|",
+            "| a very long description | 1 |",
+            "| <a\\|b> | 2 |",
+            "|  |  |",
+        ];
+
+        let actual: Vec<&str> = markdown.lines().collect();
+
+        assert_eq!(expected, actual, "Actual result:\n{}", markdown);
+        assert_eq!(record_batches_to_markdown(&[])?, "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_batches_to_html() -> Result<()> {
+        let html = record_batches_to_html(&[markup_batch()?])?;
+
+        let expected = vec![
+            "<table>",
+            "<thead>",
+            "<tr><th>name</th><th>id</th></tr>",
+            "</thead>",
+            "<tbody>",
+            "<tr><td>a very long description</td><td>1</td></tr>",
+            "<tr><td>&lt;a|b&gt;</td><td>2</td></tr>",
+            "<tr><td></td><td></td></tr>",
+            "</tbody>",
+            "</table>",
+        ];
+
+        let actual: Vec<&str> = html.lines().collect();
+
+        assert_eq!(expected, actual, "Actual result:\n{}", html);
+
+        Ok(())
+    }
+
     #[test]
     fn test_pretty_format_columns() -> Result<()> {
         let columns = vec![
@@ -235,7 +485,7 @@ mod tests {
         let schema = Arc::new(Schema::new(vec![Field::new("d1", field_type, true)]));
 
         let keys_builder = PrimitiveBuilder::<Int32Type>::new(10);
-        let values_builder = StringBuilder::new(10);
+        let values_builder = StringBuilder::default();
         let mut builder = StringDictionaryBuilder::new(keys_builder, values_builder);
 
         builder.append("one")?;
@@ -433,13 +683,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_interval_display() -> Result<()> {
+        let array = Arc::new(array::IntervalYearMonthArray::from(vec![
+            Some(14),
+            Some(-3),
+            None,
+        ])) as ArrayRef;
+        assert_eq!(
+            array_value_to_string(&array, 0)?,
+            "1 years 2 mons 0 days 0 hours 0 mins 0.000 secs"
+        );
+        assert_eq!(
+            array_value_to_string(&array, 1)?,
+            "0 years -3 mons 0 days 0 hours 0 mins 0.000 secs"
+        );
+        assert_eq!(array_value_to_string(&array, 2)?, "NULL");
+
+        let array = Arc::new(array::IntervalDayTimeArray::from(vec![
+            (3 << 32) | 14_706_042,
+            -1250_i32 as u32 as i64,
+        ])) as ArrayRef;
+        assert_eq!(
+            array_value_to_string(&array, 0)?,
+            "0 years 0 mons 3 days 4 hours 5 mins 6.042 secs"
+        );
+        assert_eq!(
+            array_value_to_string(&array, 1)?,
+            "0 years 0 mons 0 days 0 hours 0 mins -1.250 secs"
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_decimal_display() -> Result<()> {
         let capacity = 10;
         let precision = 10;
         let scale = 2;
 
-        let mut builder = DecimalBuilder::new(capacity, precision, scale);
+        let mut builder = DecimalBuilder::with_capacity(capacity, precision, scale);
         builder.append_value(101).unwrap();
         builder.append_null().unwrap();
         builder.append_value(200).unwrap();
@@ -480,7 +762,7 @@ mod tests {
         let precision = 5;
         let scale = 0;
 
-        let mut builder = DecimalBuilder::new(capacity, precision, scale);
+        let mut builder = DecimalBuilder::with_capacity(capacity, precision, scale);
         builder.append_value(101).unwrap();
         builder.append_null().unwrap();
         builder.append_value(200).unwrap();
@@ -20,6 +20,9 @@
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use std::{env, error::Error, fs, io::Write, path::PathBuf};
 
+use crate::array::ArrayEq;
+use crate::record_batch::RecordBatch;
+
 /// Returns a vector of size `n`, filled with randomly generated bytes.
 pub fn random_bytes(n: usize) -> Vec<u8> {
     let mut result = vec![];
@@ -104,6 +107,78 @@ pub fn parquet_test_data() -> String {
     }
 }
 
+/// Asserts that `actual` equals `expected`, panicking with the first batch and column that
+/// differ otherwise.
+///
+/// Example:
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::Int32Array;
+/// use arrow::record_batch::RecordBatch;
+/// use arrow::util::test_util::assert_batches_eq;
+///
+/// let batch = RecordBatch::try_from_iter(vec![
+///     ("a", Arc::new(Int32Array::from(vec![1, 2])) as _),
+/// ]).unwrap();
+/// assert_batches_eq(&[batch.clone()], &[batch]);
+/// ```
+pub fn assert_batches_eq(expected: &[RecordBatch], actual: &[RecordBatch]) {
+    assert_batches_eq_with(expected, actual, &ArrayEq::new())
+}
+
+/// Asserts that `actual` equals `expected` according to `eq`, e.g. comparing floating
+/// point values approximately or ignoring the metadata of the schemas.
+///
+/// Example:
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayEq, Float64Array};
+/// use arrow::record_batch::RecordBatch;
+/// use arrow::util::test_util::assert_batches_eq_with;
+///
+/// let expected = RecordBatch::try_from_iter(vec![
+///     ("a", Arc::new(Float64Array::from(vec![0.3])) as _),
+/// ]).unwrap();
+/// let actual = RecordBatch::try_from_iter(vec![
+///     ("a", Arc::new(Float64Array::from(vec![0.1 + 0.2])) as _),
+/// ]).unwrap();
+/// assert_batches_eq_with(&[expected], &[actual], &ArrayEq::new().with_epsilon(1e-9));
+/// ```
+pub fn assert_batches_eq_with(
+    expected: &[RecordBatch],
+    actual: &[RecordBatch],
+    eq: &ArrayEq,
+) {
+    assert_eq!(
+        expected.len(),
+        actual.len(),
+        "expected {} batches, got {}",
+        expected.len(),
+        actual.len()
+    );
+    for (i, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+        assert!(
+            eq.equal_schemas(expected.schema().as_ref(), actual.schema().as_ref()),
+            "the schemas of the batches {} differ:\nexpected: {:?}\nactual: {:?}",
+            i,
+            expected.schema(),
+            actual.schema()
+        );
+        for (j, (expected_column, actual_column)) in
+            expected.columns().iter().zip(actual.columns()).enumerate()
+        {
+            assert!(
+                eq.equal(expected_column.as_ref(), actual_column.as_ref()),
+                "the column \"{}\" of the batches {} differs:\nexpected: {:?}\nactual: {:?}",
+                expected.schema().field(j).name(),
+                i,
+                expected_column,
+                actual_column
+            );
+        }
+    }
+}
+
 /// Returns a directory path for finding test data.
 ///
 /// udf_env: name of an environment variable
@@ -26,13 +26,16 @@ use std::vec::Vec;
 use arrow::array::{
     new_empty_array, Array, ArrayData, ArrayDataBuilder, ArrayRef, BinaryArray,
     BinaryBuilder, BooleanArray, BooleanBufferBuilder, BooleanBuilder, DecimalBuilder,
-    FixedSizeBinaryArray, FixedSizeBinaryBuilder, GenericListArray, Int16BufferBuilder,
-    Int32Array, Int64Array, OffsetSizeTrait, PrimitiveArray, PrimitiveBuilder,
-    StringArray, StringBuilder, StructArray,
+    DictionaryArray, FixedSizeBinaryArray, FixedSizeBinaryBuilder, FixedSizeListArray,
+    GenericListArray, Int16BufferBuilder, Int32Array, Int64Array, MapArray,
+    MutableArrayData, OffsetSizeTrait, PrimitiveArray, PrimitiveBuilder, StringArray,
+    StringBuilder, StructArray, UInt32Array,
 };
 use arrow::buffer::{Buffer, MutableBuffer};
+use arrow::compute::parse_fixed_offset;
 use arrow::datatypes::{
-    ArrowPrimitiveType, BooleanType as ArrowBooleanType, DataType as ArrowType,
+    ArrowDictionaryKeyType, ArrowNativeType, ArrowPrimitiveType,
+    BooleanType as ArrowBooleanType, DataType as ArrowType,
     Date32Type as ArrowDate32Type, Date64Type as ArrowDate64Type,
     DurationMicrosecondType as ArrowDurationMicrosecondType,
     DurationMillisecondType as ArrowDurationMillisecondType,
@@ -55,28 +58,35 @@ use arrow::datatypes::{
 use arrow::util::bit_util;
 
 use crate::arrow::converter::{
-    BinaryArrayConverter, BinaryConverter, Converter, DecimalArrayConverter,
-    DecimalConverter, FixedLenBinaryConverter, FixedSizeArrayConverter,
-    Int96ArrayConverter, Int96Converter, IntervalDayTimeArrayConverter,
+    BinaryArrayConverter, BinaryConverter, ByteArrayDecimalConverter, Converter,
+    DecimalArrayConverter, DecimalConverter, FixedLenBinaryConverter,
+    FixedSizeArrayConverter, Int96ArrayConverter, IntervalDayTimeArrayConverter,
     IntervalDayTimeConverter, IntervalYearMonthArrayConverter,
     IntervalYearMonthConverter, LargeBinaryArrayConverter, LargeBinaryConverter,
     LargeUtf8ArrayConverter, LargeUtf8Converter, Utf8ArrayConverter, Utf8Converter,
 };
 use crate::arrow::record_reader::RecordReader;
 use crate::arrow::schema::parquet_to_arrow_field;
-use crate::basic::{ConvertedType, Repetition, Type as PhysicalType};
-use crate::column::page::PageIterator;
-use crate::column::reader::ColumnReaderImpl;
+use crate::arrow::ProjectionMask;
+use crate::basic::{
+    ConvertedType, Encoding, LogicalType, Repetition, Type as PhysicalType,
+};
+use crate::column::page::{Page, PageIterator, PageReader};
+use crate::column::reader::{count_skipped_levels, ColumnReaderImpl, SKIP_BATCH_SIZE};
 use crate::data_type::{
-    BoolType, ByteArrayType, DataType, DoubleType, FixedLenByteArrayType, FloatType,
-    Int32Type, Int64Type, Int96Type,
+    BoolType, ByteArray, ByteArrayType, DataType, DoubleType, FixedLenByteArrayType,
+    FloatType, Int32Type, Int64Type, Int96Type,
 };
+use crate::encodings::decoding::{get_decoder, Decoder, PlainDecoder};
+use crate::encodings::levels::LevelDecoder;
+use crate::encodings::rle::RleDecoder;
 use crate::errors::{ParquetError, ParquetError::ArrowError, Result};
 use crate::file::reader::{FilePageIterator, FileReader};
 use crate::schema::types::{
     ColumnDescPtr, ColumnDescriptor, ColumnPath, SchemaDescPtr, Type, TypePtr,
 };
 use crate::schema::visitor::TypeVisitor;
+use crate::util::memory::ByteBufferPtr;
 use std::any::Any;
 
 /// Array reader reads parquet data into arrow array.
@@ -89,6 +99,10 @@ pub trait ArrayReader {
     /// Reads at most `batch_size` records into an arrow array and return it.
     fn next_batch(&mut self, batch_size: usize) -> Result<ArrayRef>;
 
+    /// Skips over at most `num_records` records without decoding them, returning the
+    /// number of records skipped.
+    fn skip_records(&mut self, num_records: usize) -> Result<usize>;
+
     /// Returns the definition levels of data from last call of `next_batch`.
     /// The result is used by parent array reader to calculate its own definition
     /// levels and repetition levels, so that its parent can calculate null bitmap.
@@ -178,6 +192,31 @@ impl<T: DataType> ArrayReader for NullArrayReader<T> {
         Ok(Arc::new(array))
     }
 
+    /// Skips over at most `num_records` records without decoding their values.
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        let mut records_skipped = 0usize;
+        while records_skipped < num_records {
+            let records_to_skip = num_records - records_skipped;
+
+            let records_skipped_once =
+                self.record_reader.skip_records(records_to_skip)?;
+            records_skipped += records_skipped_once;
+
+            // Record reader exhausted
+            if records_skipped_once < records_to_skip {
+                if let Some(page_reader) = self.pages.next() {
+                    // Skip from new page reader
+                    self.record_reader.set_page_reader(page_reader?)?;
+                } else {
+                    // Page reader also exhausted
+                    break;
+                }
+            }
+        }
+
+        Ok(records_skipped)
+    }
+
     fn get_def_levels(&self) -> Option<&[i16]> {
         self.def_levels_buffer
             .as_ref()
@@ -369,7 +408,7 @@ impl<T: DataType> ArrayReader for PrimitiveArrayReader<T> {
                 arrow::compute::cast(&a, &target_type)?
             }
             ArrowType::Decimal(p, s) => {
-                let mut builder = DecimalBuilder::new(array.len(), p, s);
+                let mut builder = DecimalBuilder::with_capacity(array.len(), p, s);
                 match array.data_type() {
                     ArrowType::Int32 => {
                         let values = array.as_any().downcast_ref::<Int32Array>().unwrap();
@@ -408,6 +447,31 @@ impl<T: DataType> ArrayReader for PrimitiveArrayReader<T> {
         Ok(array)
     }
 
+    /// Skips over at most `num_records` records without decoding their values.
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        let mut records_skipped = 0usize;
+        while records_skipped < num_records {
+            let records_to_skip = num_records - records_skipped;
+
+            let records_skipped_once =
+                self.record_reader.skip_records(records_to_skip)?;
+            records_skipped += records_skipped_once;
+
+            // Record reader exhausted
+            if records_skipped_once < records_to_skip {
+                if let Some(page_reader) = self.pages.next() {
+                    // Skip from new page reader
+                    self.record_reader.set_page_reader(page_reader?)?;
+                } else {
+                    // Page reader also exhausted
+                    break;
+                }
+            }
+        }
+
+        Ok(records_skipped)
+    }
+
     fn get_def_levels(&self) -> Option<&[i16]> {
         self.def_levels_buffer
             .as_ref()
@@ -556,6 +620,31 @@ where
         Ok(array)
     }
 
+    /// Skips over at most `num_records` records without decoding their values.
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        // Try to initialize column reader
+        if self.column_reader.is_none() {
+            self.next_column_reader()?;
+        }
+
+        let mut records_skipped = 0;
+        while self.column_reader.is_some() && records_skipped < num_records {
+            let records_to_skip = num_records - records_skipped;
+            let records_skipped_once = self
+                .column_reader
+                .as_mut()
+                .unwrap()
+                .skip_records(records_to_skip)?;
+            records_skipped += records_skipped_once;
+            // current page exhausted && page iterator exhausted
+            if records_skipped_once < records_to_skip && !self.next_column_reader()? {
+                break;
+            }
+        }
+
+        Ok(records_skipped)
+    }
+
     fn get_def_levels(&self) -> Option<&[i16]> {
         self.def_levels_buffer.as_deref()
     }
@@ -608,6 +697,610 @@ where
     }
 }
 
+/// Array reader for dictionary encoded `BYTE_ARRAY` columns read into a
+/// [`DictionaryArray`].
+///
+/// Instead of materializing every value and re-encoding the result, the values
+/// of the Parquet dictionary page are used as the Arrow dictionary and the
+/// encoded indices as its keys. Column chunks have separate dictionaries, so a
+/// batch that spans several column chunks uses the concatenation of their
+/// dictionaries.
+///
+/// Pages that are not dictionary encoded, e.g. because the writer fell back to
+/// plain encoding once the dictionary grew too large, are decoded and the batch
+/// is cast to the dictionary type instead.
+pub struct ByteArrayDictionaryReader<K, C>
+where
+    K: ArrowDictionaryKeyType,
+    C: Converter<Vec<Option<ByteArray>>, ArrayRef> + 'static,
+{
+    data_type: ArrowType,
+    pages: Box<dyn PageIterator>,
+    column_desc: ColumnDescPtr,
+    converter: C,
+    /// The pages of the current column chunk
+    page_reader: Option<Box<dyn PageReader>>,
+    /// The index of the current column chunk
+    column_chunk: usize,
+    /// The values of the current column chunk's dictionary page
+    dictionary: Option<ArrayRef>,
+    /// The decoder of the current data page
+    page_decoder: Option<DictionaryPageDecoder>,
+    def_levels_buffer: Option<Vec<i16>>,
+    rep_levels_buffer: Option<Vec<i16>>,
+    _key_marker: PhantomData<K>,
+}
+
+/// The levels and values of a data page read by a [`ByteArrayDictionaryReader`]
+struct DictionaryPageDecoder {
+    /// The number of levels that are yet to be read
+    levels_left: usize,
+    def_level_decoder: Option<LevelDecoder>,
+    rep_level_decoder: Option<LevelDecoder>,
+    values: DictionaryPageValues,
+}
+
+enum DictionaryPageValues {
+    /// Indices into the dictionary of the column chunk
+    Indices(RleDecoder),
+    /// Values of a page that is not dictionary encoded
+    Values(Box<dyn Decoder<ByteArrayType>>),
+}
+
+impl DictionaryPageDecoder {
+    fn try_new(page: Page, column_desc: &ColumnDescPtr) -> Result<Self> {
+        let max_def_level = column_desc.max_def_level();
+        let max_rep_level = column_desc.max_rep_level();
+        let mut def_level_decoder = None;
+        let mut rep_level_decoder = None;
+
+        let (num_values, encoding, buffer) = match page {
+            Page::DataPage {
+                buf,
+                num_values,
+                encoding,
+                def_level_encoding,
+                rep_level_encoding,
+                statistics: _,
+            } => {
+                let mut buffer = buf;
+                if max_rep_level > 0 {
+                    let mut decoder = LevelDecoder::v1(rep_level_encoding, max_rep_level);
+                    let bytes_read = decoder.set_data(num_values as usize, buffer.all());
+                    buffer = buffer.start_from(bytes_read);
+                    rep_level_decoder = Some(decoder);
+                }
+                if max_def_level > 0 {
+                    let mut decoder = LevelDecoder::v1(def_level_encoding, max_def_level);
+                    let bytes_read = decoder.set_data(num_values as usize, buffer.all());
+                    buffer = buffer.start_from(bytes_read);
+                    def_level_decoder = Some(decoder);
+                }
+                (num_values as usize, encoding, buffer)
+            }
+            Page::DataPageV2 {
+                buf,
+                num_values,
+                encoding,
+                def_levels_byte_len,
+                rep_levels_byte_len,
+                ..
+            } => {
+                let mut offset = 0;
+                if max_rep_level > 0 {
+                    let mut decoder = LevelDecoder::v2(max_rep_level);
+                    offset += decoder.set_data_range(
+                        num_values as usize,
+                        &buf,
+                        offset,
+                        rep_levels_byte_len as usize,
+                    );
+                    rep_level_decoder = Some(decoder);
+                }
+                if max_def_level > 0 {
+                    let mut decoder = LevelDecoder::v2(max_def_level);
+                    offset += decoder.set_data_range(
+                        num_values as usize,
+                        &buf,
+                        offset,
+                        def_levels_byte_len as usize,
+                    );
+                    def_level_decoder = Some(decoder);
+                }
+                (num_values as usize, encoding, buf.start_from(offset))
+            }
+            Page::DictionaryPage { .. } => {
+                return Err(general_err!("Column cannot have more than one dictionary"))
+            }
+        };
+
+        let values = match encoding {
+            Encoding::PLAIN_DICTIONARY | Encoding::RLE_DICTIONARY => {
+                // First byte in the buffer is the bit width of the indices
+                let bit_width = buffer.as_ref()[0];
+                let mut decoder = RleDecoder::new(bit_width);
+                decoder.set_data(buffer.start_from(1));
+                DictionaryPageValues::Indices(decoder)
+            }
+            encoding => {
+                let mut decoder =
+                    get_decoder::<ByteArrayType>(column_desc.clone(), encoding)?;
+                decoder.set_data(buffer, num_values)?;
+                DictionaryPageValues::Values(decoder)
+            }
+        };
+
+        Ok(Self {
+            levels_left: num_values,
+            def_level_decoder,
+            rep_level_decoder,
+            values,
+        })
+    }
+}
+
+/// The dictionary values and keys of a batch read by a [`ByteArrayDictionaryReader`]
+#[derive(Default)]
+struct DictionaryBatch {
+    /// Arrays whose concatenation is the dictionary of the batch
+    dictionaries: Vec<ArrayRef>,
+    /// The combined length of `dictionaries`
+    len: usize,
+    /// The column chunk whose dictionary was last added to the batch, and the
+    /// offset of that dictionary
+    current: Option<(usize, usize)>,
+    /// Values of pages that are not dictionary encoded, yet to be converted
+    plain_values: Vec<Option<ByteArray>>,
+    /// Whether any values of pages that are not dictionary encoded were read
+    has_plain_values: bool,
+    /// The keys of the non-null values of the batch
+    keys: Vec<usize>,
+}
+
+impl DictionaryBatch {
+    /// Append the keys of dictionary encoded values, adding `dictionary`, the
+    /// dictionary of `column_chunk`, to the batch if it is not the current one
+    fn append_indices<C>(
+        &mut self,
+        column_chunk: usize,
+        dictionary: &ArrayRef,
+        indices: &[i32],
+        converter: &C,
+    ) -> Result<()>
+    where
+        C: Converter<Vec<Option<ByteArray>>, ArrayRef>,
+    {
+        let offset = match &self.current {
+            Some((current, offset)) if *current == column_chunk => *offset,
+            _ => {
+                self.flush_plain_values(converter)?;
+                let offset = self.len;
+                self.dictionaries.push(dictionary.clone());
+                self.len += dictionary.len();
+                self.current = Some((column_chunk, offset));
+                offset
+            }
+        };
+        for index in indices {
+            let index = *index as usize;
+            if index >= dictionary.len() {
+                return Err(general_err!(
+                    "Dictionary index {} out of bounds for dictionary of length {}",
+                    index,
+                    dictionary.len()
+                ));
+            }
+            self.keys.push(offset + index);
+        }
+        Ok(())
+    }
+
+    /// Append values of a page that is not dictionary encoded
+    fn append_values(&mut self, values: Vec<ByteArray>) {
+        let start = self.len + self.plain_values.len();
+        self.keys.extend(start..start + values.len());
+        self.plain_values.extend(values.into_iter().map(Some));
+        self.has_plain_values = true;
+    }
+
+    fn flush_plain_values<C>(&mut self, converter: &C) -> Result<()>
+    where
+        C: Converter<Vec<Option<ByteArray>>, ArrayRef>,
+    {
+        if !self.plain_values.is_empty() {
+            let values = converter.convert(std::mem::take(&mut self.plain_values))?;
+            self.len += values.len();
+            self.dictionaries.push(values);
+        }
+        Ok(())
+    }
+
+    /// Build the dictionary array of the batch, where the value at a level is
+    /// null unless it is `max_def_level`
+    fn finish<K, C>(
+        mut self,
+        data_type: &ArrowType,
+        def_levels: Option<&[i16]>,
+        num_levels: usize,
+        max_def_level: i16,
+        converter: &C,
+    ) -> Result<ArrayRef>
+    where
+        K: ArrowDictionaryKeyType,
+        C: Converter<Vec<Option<ByteArray>>, ArrayRef>,
+    {
+        self.flush_plain_values(converter)?;
+        let values = match self.dictionaries.len() {
+            0 => converter.convert(vec![])?,
+            1 => self.dictionaries[0].clone(),
+            _ => {
+                let dictionaries: Vec<&dyn Array> =
+                    self.dictionaries.iter().map(|a| a.as_ref()).collect();
+                arrow::compute::concat(&dictionaries)?
+            }
+        };
+
+        let mut keys = self.keys.into_iter();
+        let mut next_key = |valid: bool| if valid { keys.next() } else { None };
+        let keys: Vec<Option<usize>> = match def_levels {
+            Some(def_levels) => def_levels
+                .iter()
+                .map(|level| next_key(*level == max_def_level))
+                .collect(),
+            None => (0..num_levels).map(|_| next_key(true)).collect(),
+        };
+
+        // the keys must fit the key type, which they may not if values that are
+        // not dictionary encoded were read
+        let keys_fit =
+            values.is_empty() || K::Native::from_usize(values.len() - 1).is_some();
+        if self.has_plain_values || !keys_fit {
+            // materialize the values of the batch, and dictionary encode them
+            let indices: UInt32Array =
+                keys.iter().map(|key| key.map(|key| key as u32)).collect();
+            let array = arrow::compute::take(values.as_ref(), &indices, None)?;
+            return Ok(arrow::compute::cast(&array, data_type)?);
+        }
+
+        let keys: PrimitiveArray<K> = keys
+            .into_iter()
+            .map(|key| key.map(|key| K::Native::from_usize(key).unwrap()))
+            .collect();
+        let keys = keys.data();
+        let mut builder = ArrayDataBuilder::new(data_type.clone())
+            .len(keys.len())
+            .add_buffer(keys.buffers()[0].clone())
+            .add_child_data(values.data().clone());
+        if let Some(buffer) = keys.null_buffer() {
+            builder = builder.null_bit_buffer(buffer.clone());
+        }
+        Ok(Arc::new(DictionaryArray::<K>::from(builder.build())))
+    }
+}
+
+impl<K, C> ArrayReader for ByteArrayDictionaryReader<K, C>
+where
+    K: ArrowDictionaryKeyType,
+    C: Converter<Vec<Option<ByteArray>>, ArrayRef> + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn next_batch(&mut self, batch_size: usize) -> Result<ArrayRef> {
+        let max_def_level = self.column_desc.max_def_level();
+        let mut def_levels = if max_def_level > 0 {
+            Some(Vec::with_capacity(batch_size))
+        } else {
+            None
+        };
+        let mut rep_levels = if self.column_desc.max_rep_level() > 0 {
+            Some(Vec::with_capacity(batch_size))
+        } else {
+            None
+        };
+
+        let mut batch = DictionaryBatch::default();
+        let mut num_levels = 0;
+
+        while num_levels < batch_size && self.next_data_page()? {
+            let page = self.page_decoder.as_mut().unwrap();
+            let levels_to_read = min(batch_size - num_levels, page.levels_left);
+
+            let mut num_values = levels_to_read;
+            if let Some(def_levels) = def_levels.as_mut() {
+                let start = def_levels.len();
+                def_levels.resize(start + levels_to_read, 0);
+                read_levels(
+                    page.def_level_decoder.as_mut().unwrap(),
+                    &mut def_levels[start..],
+                )?;
+                num_values = def_levels[start..]
+                    .iter()
+                    .filter(|level| **level == max_def_level)
+                    .count();
+            }
+            if let Some(rep_levels) = rep_levels.as_mut() {
+                let start = rep_levels.len();
+                rep_levels.resize(start + levels_to_read, 0);
+                read_levels(
+                    page.rep_level_decoder.as_mut().unwrap(),
+                    &mut rep_levels[start..],
+                )?;
+            }
+            page.levels_left -= levels_to_read;
+            num_levels += levels_to_read;
+
+            match &mut page.values {
+                DictionaryPageValues::Indices(decoder) => {
+                    let dictionary = self.dictionary.as_ref().ok_or_else(|| {
+                        general_err!(
+                            "Missing dictionary page for dictionary encoded column"
+                        )
+                    })?;
+                    let mut indices = vec![0; num_values];
+                    let read = decoder.get_batch::<i32>(&mut indices)?;
+                    if read != num_values {
+                        return Err(eof_err!(
+                            "Expected to read {} dictionary indices, read {}",
+                            num_values,
+                            read
+                        ));
+                    }
+                    batch.append_indices(
+                        self.column_chunk,
+                        dictionary,
+                        &indices,
+                        &self.converter,
+                    )?;
+                }
+                DictionaryPageValues::Values(decoder) => {
+                    let mut values = vec![ByteArray::default(); num_values];
+                    let read = decoder.get(&mut values)?;
+                    if read != num_values {
+                        return Err(eof_err!(
+                            "Expected to read {} values, read {}",
+                            num_values,
+                            read
+                        ));
+                    }
+                    batch.append_values(values);
+                }
+            }
+        }
+
+        let array = batch.finish::<K, C>(
+            &self.data_type,
+            def_levels.as_deref(),
+            num_levels,
+            max_def_level,
+            &self.converter,
+        )?;
+        self.def_levels_buffer = def_levels;
+        self.rep_levels_buffer = rep_levels;
+        Ok(array)
+    }
+
+    /// Skips over at most `num_records` records without decoding their values.
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        let max_def_level = self.column_desc.max_def_level();
+        let repeated = self.column_desc.max_rep_level() > 0;
+        let mut remaining = num_records;
+
+        // Whether a level of the record that is being skipped has been skipped, for a
+        // repeated column
+        let mut in_record = false;
+
+        while remaining > 0 || repeated {
+            let page_done = self
+                .page_decoder
+                .as_ref()
+                .map_or(true, |page| page.levels_left == 0);
+            if page_done && !repeated {
+                // Skip the data pages that only hold records to skip without reading
+                // them, which is only known for columns that aren't repeated
+                if let Some(page_reader) = self.page_reader.as_mut() {
+                    match page_reader.peek_next_page()? {
+                        Some(metadata)
+                            if !metadata.is_dict && metadata.num_levels <= remaining =>
+                        {
+                            page_reader.skip_next_page()?;
+                            remaining -= metadata.num_levels;
+                            continue;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            if !self.next_data_page()? {
+                break;
+            }
+
+            let page = self.page_decoder.as_mut().unwrap();
+            let mut levels = vec![0; min(page.levels_left, SKIP_BATCH_SIZE)];
+
+            let (num_levels, done) = match page.rep_level_decoder.as_mut() {
+                Some(decoder) => {
+                    // Find the end of the records on a copy of the decoder, so that
+                    // the levels of the next record aren't consumed
+                    let read = decoder.clone().get(&mut levels)?;
+                    let (num_levels, done) = count_skipped_levels(
+                        &levels[..read],
+                        &mut remaining,
+                        &mut in_record,
+                    );
+                    read_levels(decoder, &mut levels[..num_levels])?;
+                    (num_levels, done)
+                }
+                None => {
+                    let num_levels = min(remaining, levels.len());
+                    remaining -= num_levels;
+                    (num_levels, false)
+                }
+            };
+
+            let mut num_values = num_levels;
+            if let Some(decoder) = page.def_level_decoder.as_mut() {
+                read_levels(decoder, &mut levels[..num_levels])?;
+                num_values = levels[..num_levels]
+                    .iter()
+                    .filter(|level| **level == max_def_level)
+                    .count();
+            }
+            page.levels_left -= num_levels;
+
+            let skipped = match &mut page.values {
+                DictionaryPageValues::Indices(decoder) => decoder.skip(num_values)?,
+                DictionaryPageValues::Values(decoder) => decoder.skip(num_values)?,
+            };
+            if skipped != num_values {
+                return Err(eof_err!(
+                    "Expected to skip {} values, skipped {}",
+                    num_values,
+                    skipped
+                ));
+            }
+
+            if done {
+                break;
+            }
+        }
+
+        // The last record of the column is complete at its end
+        if in_record {
+            remaining -= 1;
+        }
+
+        Ok(num_records - remaining)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.def_levels_buffer.as_deref()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.rep_levels_buffer.as_deref()
+    }
+}
+
+impl<K, C> ByteArrayDictionaryReader<K, C>
+where
+    K: ArrowDictionaryKeyType,
+    C: Converter<Vec<Option<ByteArray>>, ArrayRef> + 'static,
+{
+    /// Construct a dictionary array reader, where `data_type` is the
+    /// [`ArrowType::Dictionary`] to read and `converter` converts the values
+    /// of the dictionary
+    pub fn new(
+        pages: Box<dyn PageIterator>,
+        column_desc: ColumnDescPtr,
+        converter: C,
+        data_type: ArrowType,
+    ) -> Result<Self> {
+        Ok(Self {
+            data_type,
+            pages,
+            column_desc,
+            converter,
+            page_reader: None,
+            column_chunk: 0,
+            dictionary: None,
+            page_decoder: None,
+            def_levels_buffer: None,
+            rep_levels_buffer: None,
+            _key_marker: PhantomData,
+        })
+    }
+
+    /// Move to the next data page with levels left to read, reading the
+    /// dictionary page of every column chunk on the way. Returns `false` once
+    /// all pages have been read.
+    fn next_data_page(&mut self) -> Result<bool> {
+        loop {
+            if let Some(page_decoder) = &self.page_decoder {
+                if page_decoder.levels_left > 0 {
+                    return Ok(true);
+                }
+                self.page_decoder = None;
+            }
+
+            if self.page_reader.is_none() {
+                match self.pages.next() {
+                    Some(page_reader) => {
+                        self.page_reader = Some(page_reader?);
+                        self.column_chunk += 1;
+                        self.dictionary = None;
+                    }
+                    None => return Ok(false),
+                }
+            }
+
+            match self.page_reader.as_mut().unwrap().get_next_page()? {
+                Some(Page::DictionaryPage {
+                    buf,
+                    num_values,
+                    encoding,
+                    ..
+                }) => {
+                    if self.dictionary.is_some() {
+                        return Err(general_err!(
+                            "Column cannot have more than one dictionary"
+                        ));
+                    }
+                    self.dictionary =
+                        Some(self.read_dictionary(buf, num_values as usize, encoding)?);
+                }
+                Some(page) => {
+                    self.page_decoder =
+                        Some(DictionaryPageDecoder::try_new(page, &self.column_desc)?);
+                }
+                None => self.page_reader = None,
+            }
+        }
+    }
+
+    /// Decode the values of a dictionary page
+    fn read_dictionary(
+        &self,
+        buf: ByteBufferPtr,
+        num_values: usize,
+        encoding: Encoding,
+    ) -> Result<ArrayRef> {
+        match encoding {
+            Encoding::PLAIN | Encoding::PLAIN_DICTIONARY | Encoding::RLE_DICTIONARY => {
+                let mut decoder =
+                    PlainDecoder::<ByteArrayType>::new(self.column_desc.type_length());
+                decoder.set_data(buf, num_values)?;
+                let mut values = vec![ByteArray::default(); num_values];
+                decoder.get(&mut values)?;
+                self.converter
+                    .convert(values.into_iter().map(Some).collect())
+            }
+            encoding => Err(nyi_err!(
+                "Invalid/Unsupported encoding type for dictionary: {}",
+                encoding
+            )),
+        }
+    }
+}
+
+/// Read levels from `decoder`, filling `buffer`
+fn read_levels(decoder: &mut LevelDecoder, buffer: &mut [i16]) -> Result<()> {
+    let read = decoder.get(buffer)?;
+    if read != buffer.len() {
+        return Err(eof_err!(
+            "Expected to read {} levels, read {}",
+            buffer.len(),
+            read
+        ));
+    }
+    Ok(())
+}
+
 /// Implementation of list array reader.
 pub struct ListArrayReader<OffsetSize: OffsetSizeTrait> {
     item_reader: Box<dyn ArrayReader>,
@@ -695,7 +1388,7 @@ macro_rules! remove_fixed_size_binary_array_indices {
             Some(a) => a,
             _ => return Err(ParquetError::General(format!("Error generating next batch for ListArray: {:?} cannot be downcast to PrimitiveArray", $arr))),
         };
-        let mut builder = FixedSizeBinaryBuilder::new(array_data.len(), $len);
+        let mut builder = FixedSizeBinaryBuilder::with_capacity(array_data.len(), $len);
         for i in 0..array_data.len() {
             if !$indices.contains(&i) {
                 if array_data.is_null(i) {
@@ -911,6 +1604,10 @@ impl<OffsetSize: OffsetSizeTrait> ArrayReader for ListArrayReader<OffsetSize> {
         Ok(Arc::new(result_array))
     }
 
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.item_reader.skip_records(num_records)
+    }
+
     fn get_def_levels(&self) -> Option<&[i16]> {
         self.def_level_buffer
             .as_ref()
@@ -924,6 +1621,238 @@ impl<OffsetSize: OffsetSizeTrait> ArrayReader for ListArrayReader<OffsetSize> {
     }
 }
 
+/// Implementation of fixed size list array reader.
+///
+/// Fixed size lists are stored in Parquet like any other list, so this reads a
+/// [`ListArrayReader`]'s output and checks that every non-null list has the
+/// expected length.
+pub struct FixedSizeListArrayReader {
+    list_reader: Box<dyn ArrayReader>,
+    data_type: ArrowType,
+    list_size: usize,
+}
+
+impl FixedSizeListArrayReader {
+    /// Construct fixed size list array reader.
+    pub fn new(
+        list_reader: Box<dyn ArrayReader>,
+        data_type: ArrowType,
+        list_size: usize,
+    ) -> Self {
+        Self {
+            list_reader,
+            data_type,
+            list_size,
+        }
+    }
+}
+
+impl ArrayReader for FixedSizeListArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// Returns data type.
+    /// This must be a FixedSizeList.
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn next_batch(&mut self, batch_size: usize) -> Result<ArrayRef> {
+        let next_batch_array = self.list_reader.next_batch(batch_size)?;
+        let list = next_batch_array
+            .as_any()
+            .downcast_ref::<GenericListArray<i32>>()
+            .ok_or_else(|| general_err!("Expected a list array"))?;
+
+        // null slots of a fixed size list still occupy `list_size` values
+        let values = list.values();
+        let mut child =
+            MutableArrayData::new(vec![values.data()], true, list.len() * self.list_size);
+        let mut null_buf = BooleanBufferBuilder::new(list.len());
+        for i in 0..list.len() {
+            if list.is_null(i) {
+                child.extend_nulls(self.list_size);
+                null_buf.append(false);
+                continue;
+            }
+            let start = list.value_offsets()[i] as usize;
+            let len = list.value_length(i) as usize;
+            if len != self.list_size {
+                return Err(general_err!(
+                    "Expected list of length {}, but found list of length {}",
+                    self.list_size,
+                    len
+                ));
+            }
+            child.extend(0, start, start + len);
+            null_buf.append(true);
+        }
+
+        let list_data = ArrayData::builder(self.data_type.clone())
+            .len(list.len())
+            .add_child_data(child.freeze())
+            .null_bit_buffer(null_buf.finish())
+            .build();
+
+        Ok(Arc::new(FixedSizeListArray::from(list_data)))
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        self.list_reader.skip_records(num_records)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        self.list_reader.get_def_levels()
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        self.list_reader.get_rep_levels()
+    }
+}
+
+/// Implementation of map array reader.
+pub struct MapArrayReader {
+    key_reader: Box<dyn ArrayReader>,
+    value_reader: Box<dyn ArrayReader>,
+    data_type: ArrowType,
+    map_def_level: i16,
+    map_rep_level: i16,
+    map_empty_def_level: i16,
+}
+
+impl MapArrayReader {
+    /// Construct map array reader.
+    pub fn new(
+        key_reader: Box<dyn ArrayReader>,
+        value_reader: Box<dyn ArrayReader>,
+        data_type: ArrowType,
+        def_level: i16,
+        rep_level: i16,
+        map_empty_def_level: i16,
+    ) -> Self {
+        Self {
+            key_reader,
+            value_reader,
+            data_type,
+            map_def_level: def_level,
+            map_rep_level: rep_level,
+            map_empty_def_level,
+        }
+    }
+}
+
+impl ArrayReader for MapArrayReader {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    /// Returns data type.
+    /// This must be a Map.
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn next_batch(&mut self, batch_size: usize) -> Result<ArrayRef> {
+        let key_array = self.key_reader.next_batch(batch_size)?;
+        let value_array = self.value_reader.next_batch(batch_size)?;
+
+        let def_levels = self
+            .key_reader
+            .get_def_levels()
+            .ok_or_else(|| ArrowError("key_reader def levels are None.".to_string()))?;
+        let rep_levels = self
+            .key_reader
+            .get_rep_levels()
+            .ok_or_else(|| ArrowError("key_reader rep levels are None.".to_string()))?;
+
+        if !((def_levels.len() == rep_levels.len())
+            && (rep_levels.len() == key_array.len())
+            && (key_array.len() == value_array.len()))
+        {
+            return Err(ArrowError(
+                "Expected map key and value readers to return the same number of levels and values".to_string(),
+            ));
+        }
+
+        // Map definitions can be encoded as 3 values:
+        // - below the empty level: the map slot is null
+        // - at the empty level: the map slot is not null, but is empty (i.e. {})
+        // - at or above the map level: the slot has an entry
+        let mut offsets = vec![];
+        let mut null_buf = BooleanBufferBuilder::new(key_array.len());
+        let mut entries = Vec::with_capacity(key_array.len());
+        let mut cur_offset = 0i32;
+        for (d, r) in def_levels.iter().zip(rep_levels) {
+            if *r < self.map_rep_level {
+                offsets.push(cur_offset);
+                null_buf.append(*d >= self.map_empty_def_level);
+            }
+            let is_entry = *d >= self.map_def_level;
+            if is_entry {
+                cur_offset += 1;
+            }
+            entries.push(is_entry);
+        }
+        offsets.push(cur_offset);
+
+        let entries = BooleanArray::from(entries);
+        let keys = arrow::compute::filter(key_array.as_ref(), &entries)?;
+        let values = arrow::compute::filter(value_array.as_ref(), &entries)?;
+
+        let (key_field, value_field) = match &self.data_type {
+            ArrowType::Map(entries_field, _) => match entries_field.data_type() {
+                ArrowType::Struct(fields) if fields.len() == 2 => {
+                    (fields[0].clone(), fields[1].clone())
+                }
+                t => {
+                    return Err(ArrowError(format!(
+                        "Expected map entries to be a struct of 2 fields, found {:?}",
+                        t
+                    )))
+                }
+            },
+            t => {
+                return Err(ArrowError(format!(
+                    "Expected a map data type, found {:?}",
+                    t
+                )))
+            }
+        };
+        let entries = StructArray::from(vec![(key_field, keys), (value_field, values)]);
+
+        let map_data = ArrayData::builder(self.data_type.clone())
+            .len(offsets.len() - 1)
+            .add_buffer(Buffer::from(offsets.to_byte_slice()))
+            .add_child_data(entries.data().clone())
+            .null_bit_buffer(null_buf.finish())
+            .build();
+
+        Ok(Arc::new(MapArray::from(map_data)))
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        let key_skipped = self.key_reader.skip_records(num_records)?;
+        let value_skipped = self.value_reader.skip_records(num_records)?;
+        if key_skipped != value_skipped {
+            return Err(general_err!(
+                "MapArrayReader out of sync, skipped {} keys and {} values",
+                key_skipped,
+                value_skipped
+            ));
+        }
+        Ok(key_skipped)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        None
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        None
+    }
+}
+
 /// Implementation of struct array reader.
 pub struct StructArrayReader {
     children: Vec<Box<dyn ArrayReader>>,
@@ -1078,6 +2007,24 @@ impl ArrayReader for StructArrayReader {
         Ok(Arc::new(StructArray::from(array_data)))
     }
 
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        let mut skipped = None;
+        for child in self.children.iter_mut() {
+            let child_skipped = child.skip_records(num_records)?;
+            match skipped {
+                Some(expected) if expected != child_skipped => {
+                    return Err(general_err!(
+                        "StructArrayReader out of sync, expected {} skipped, got {}",
+                        expected,
+                        child_skipped
+                    ));
+                }
+                _ => skipped = Some(child_skipped),
+            }
+        }
+        Ok(skipped.unwrap_or(0))
+    }
+
     fn get_def_levels(&self) -> Option<&[i16]> {
         self.def_level_buffer
             .as_ref()
@@ -1092,20 +2039,17 @@ impl ArrayReader for StructArrayReader {
 }
 
 /// Create array reader from parquet schema, column indices, and parquet file reader.
-pub fn build_array_reader<T>(
+pub fn build_array_reader(
     parquet_schema: SchemaDescPtr,
     arrow_schema: Schema,
-    column_indices: T,
+    mask: ProjectionMask,
     file_reader: Arc<dyn FileReader>,
-) -> Result<Box<dyn ArrayReader>>
-where
-    T: IntoIterator<Item = usize>,
-{
+) -> Result<Box<dyn ArrayReader>> {
     let mut leaves = HashMap::<*const Type, usize>::new();
 
     let mut filtered_root_names = HashSet::<String>::new();
 
-    for c in column_indices {
+    for c in (0..parquet_schema.num_columns()).filter(|c| mask.leaf_included(*c)) {
         let column = parquet_schema.column(c).self_type() as *const Type;
 
         leaves.insert(column, c);
@@ -1251,15 +2195,92 @@ impl<'a> TypeVisitor<Option<Box<dyn ArrayReader>>, &'a ArrayReaderBuilderContext
     }
 
     /// Build array reader for map type.
-    /// Currently this is not supported.
     fn visit_map(
         &mut self,
-        _cur_type: Arc<Type>,
-        _context: &'a ArrayReaderBuilderContext,
+        map_type: Arc<Type>,
+        context: &'a ArrayReaderBuilderContext,
     ) -> Result<Option<Box<dyn ArrayReader>>> {
-        Err(ArrowError(
-            "Reading parquet map array into arrow is not supported yet!".to_string(),
-        ))
+        let key_value = match map_type.get_fields() {
+            [key_value]
+                if key_value.is_group()
+                    && key_value.get_basic_info().repetition() == Repetition::REPEATED
+                    && key_value.get_fields().len() == 2 =>
+            {
+                key_value
+            }
+            _ => {
+                return Err(ArrowError(format!(
+                    "Map {} must have a single repeated key_value group with a key and a value",
+                    map_type.name()
+                )))
+            }
+        };
+
+        let mut new_context = context.clone();
+        new_context.path.append(vec![
+            map_type.name().to_string(),
+            key_value.name().to_string(),
+        ]);
+
+        // If the map's root is nullable
+        if let Repetition::OPTIONAL = map_type.get_basic_info().repetition() {
+            new_context.def_level += 1;
+        }
+        let map_empty_def = new_context.def_level;
+
+        // the repeated key_value group
+        new_context.def_level += 1;
+        new_context.rep_level += 1;
+
+        let key_type = key_value.get_fields()[0].clone();
+        let value_type = key_value.get_fields()[1].clone();
+        let key_reader = self.dispatch(key_type.clone(), &new_context)?;
+        let value_reader = self.dispatch(value_type.clone(), &new_context)?;
+
+        match (key_reader, value_reader) {
+            (Some(key_reader), Some(value_reader)) => {
+                let arrow_type = self
+                    .arrow_schema
+                    .field_with_name(map_type.name())
+                    .ok()
+                    .map(|f| f.data_type().to_owned())
+                    .filter(|t| matches!(t, ArrowType::Map(_, _)))
+                    .unwrap_or_else(|| {
+                        let key_field = Field::new(
+                            key_type.name(),
+                            key_reader.get_data_type().clone(),
+                            false,
+                        );
+                        let value_field = Field::new(
+                            value_type.name(),
+                            value_reader.get_data_type().clone(),
+                            value_type.is_optional(),
+                        );
+                        ArrowType::Map(
+                            Box::new(Field::new(
+                                key_value.name(),
+                                ArrowType::Struct(vec![key_field, value_field]),
+                                false,
+                            )),
+                            false,
+                        )
+                    });
+
+                Ok(Some(Box::new(MapArrayReader::new(
+                    key_reader,
+                    value_reader,
+                    arrow_type,
+                    new_context.def_level,
+                    new_context.rep_level,
+                    map_empty_def,
+                ))))
+            }
+            (None, None) => Ok(None),
+            _ => Err(ArrowError(format!(
+                "Reading map {} requires both its keys and values to be selected",
+                map_type.name()
+            ))),
+        }
     }
 
     /// Build array reader for list type.
@@ -1355,7 +2376,22 @@ impl<'a> TypeVisitor<Option<Box<dyn ArrayReader>>, &'a ArrayReaderBuilderContext
                         list_null_def,
                         list_empty_def,
                     )),
-
+                    ArrowType::FixedSizeList(ref item, size) => {
+                        let list_reader = Box::new(ListArrayReader::<i32>::new(
+                            item_reader,
+                            ArrowType::List(item.clone()),
+                            item_reader_type,
+                            new_context.def_level,
+                            new_context.rep_level,
+                            list_null_def,
+                            list_empty_def,
+                        ));
+                        Box::new(FixedSizeListArrayReader::new(
+                            list_reader,
+                            arrow_type.clone(),
+                            size as usize,
+                        ))
+                    }
                     _ => {
                         return Err(ArrowError(format!(
                         "creating ListArrayReader with type {:?} should be unreachable",
@@ -1450,21 +2486,24 @@ impl<'a> ArrayReaderBuilder {
                 arrow_type,
             )?)),
             PhysicalType::INT96 => {
-                // get the optional timezone information from arrow type
-                let timezone = arrow_type
-                    .as_ref()
-                    .map(|data_type| {
-                        if let ArrowType::Timestamp(_, tz) = data_type {
-                            tz.clone()
-                        } else {
-                            None
-                        }
-                    })
-                    .flatten();
-                let converter = Int96Converter::new(Int96ArrayConverter { timezone });
+                // get the resolution and optional timezone information from arrow type
+                let (unit, timezone) = match &arrow_type {
+                    Some(ArrowType::Timestamp(unit, tz)) => (unit.clone(), tz.clone()),
+                    _ => (ArrowTimeUnit::Nanosecond, None),
+                };
+                // the values are the local wall clock time in the timezone, if any
+                let utc_offset = match &timezone {
+                    Some(tz) => parse_fixed_offset(tz)?.local_minus_utc() as i64,
+                    None => 0,
+                };
+                let converter = Int96ArrayConverter {
+                    unit,
+                    timezone,
+                    utc_offset,
+                };
                 Ok(Box::new(ComplexObjectArrayReader::<
                     Int96Type,
-                    Int96Converter,
+                    Int96ArrayConverter,
                 >::new(
                     page_iterator,
                     column_desc,
@@ -1484,6 +2523,29 @@ impl<'a> ArrayReaderBuilder {
                     arrow_type,
                 )?))
             }
+            PhysicalType::BYTE_ARRAY if is_byte_array_dictionary(arrow_type.as_ref()) => {
+                build_byte_array_dictionary_reader(
+                    page_iterator,
+                    column_desc,
+                    arrow_type.unwrap(),
+                )
+            }
+            PhysicalType::BYTE_ARRAY if is_decimal_type(&cur_type) => {
+                let converter =
+                    ByteArrayDecimalConverter::new(DecimalArrayConverter::new(
+                        cur_type.get_precision(),
+                        cur_type.get_scale(),
+                    ));
+                Ok(Box::new(ComplexObjectArrayReader::<
+                    ByteArrayType,
+                    ByteArrayDecimalConverter,
+                >::new(
+                    page_iterator,
+                    column_desc,
+                    converter,
+                    arrow_type,
+                )?))
+            }
             PhysicalType::BYTE_ARRAY => {
                 if cur_type.get_basic_info().converted_type() == ConvertedType::UTF8 {
                     if let Some(ArrowType::LargeUtf8) = arrow_type {
@@ -1535,10 +2597,7 @@ impl<'a> ArrayReaderBuilder {
                     )?))
                 }
             }
-            PhysicalType::FIXED_LEN_BYTE_ARRAY
-                if cur_type.get_basic_info().converted_type()
-                    == ConvertedType::DECIMAL =>
-            {
+            PhysicalType::FIXED_LEN_BYTE_ARRAY if is_decimal_type(&cur_type) => {
                 let converter = DecimalConverter::new(DecimalArrayConverter::new(
                     cur_type.get_precision(),
                     cur_type.get_scale(),
@@ -1660,6 +2719,20 @@ impl<'a> ArrayReaderBuilder {
                 // from child_reader above, by making child_reader carry its `Field`
                 struct_context.path.append(vec![child.name().to_string()]);
                 let field = match self.get_arrow_field(child, &struct_context) {
+                    // a struct with some of its fields projected out only reads the
+                    // remaining fields
+                    Some(f)
+                        if matches!(f.data_type(), ArrowType::Struct(_))
+                            && f.data_type() != child_reader.get_data_type() =>
+                    {
+                        let mut field = Field::new(
+                            f.name(),
+                            child_reader.get_data_type().clone(),
+                            f.is_nullable(),
+                        );
+                        field.set_metadata(f.metadata().clone());
+                        field
+                    }
                     Some(f) => f.clone(),
                     _ => Field::new(
                         child.name(),
@@ -1725,6 +2798,111 @@ impl<'a> ArrayReaderBuilder {
     }
 }
 
+/// Returns true if `arrow_type` is a dictionary of string or binary values, which
+/// can be read by a [`ByteArrayDictionaryReader`]
+fn is_byte_array_dictionary(arrow_type: Option<&ArrowType>) -> bool {
+    match arrow_type {
+        Some(ArrowType::Dictionary(_, value_type)) => matches!(
+            value_type.as_ref(),
+            ArrowType::Utf8
+                | ArrowType::LargeUtf8
+                | ArrowType::Binary
+                | ArrowType::LargeBinary
+        ),
+        _ => false,
+    }
+}
+
+/// Returns true if `parquet_type` is annotated as a DECIMAL, either via its logical
+/// type or via the legacy converted type
+fn is_decimal_type(parquet_type: &Type) -> bool {
+    let info = parquet_type.get_basic_info();
+    matches!(info.logical_type(), Some(LogicalType::DECIMAL(_)))
+        || info.converted_type() == ConvertedType::DECIMAL
+}
+
+/// Creates a [`ByteArrayDictionaryReader`] for the dictionary `data_type`
+fn build_byte_array_dictionary_reader(
+    pages: Box<dyn PageIterator>,
+    column_desc: ColumnDescPtr,
+    data_type: ArrowType,
+) -> Result<Box<dyn ArrayReader>> {
+    let value_type = match &data_type {
+        ArrowType::Dictionary(_, value_type) => value_type.as_ref().clone(),
+        _ => unreachable!(),
+    };
+    match value_type {
+        ArrowType::Utf8 => build_byte_array_dictionary_reader_with_converter(
+            pages,
+            column_desc,
+            Utf8Converter::new(Utf8ArrayConverter {}),
+            data_type,
+        ),
+        ArrowType::LargeUtf8 => build_byte_array_dictionary_reader_with_converter(
+            pages,
+            column_desc,
+            LargeUtf8Converter::new(LargeUtf8ArrayConverter {}),
+            data_type,
+        ),
+        ArrowType::Binary => build_byte_array_dictionary_reader_with_converter(
+            pages,
+            column_desc,
+            BinaryConverter::new(BinaryArrayConverter {}),
+            data_type,
+        ),
+        ArrowType::LargeBinary => build_byte_array_dictionary_reader_with_converter(
+            pages,
+            column_desc,
+            LargeBinaryConverter::new(LargeBinaryArrayConverter {}),
+            data_type,
+        ),
+        value_type => Err(ArrowError(format!(
+            "Unsupported dictionary value type {:?}",
+            value_type
+        ))),
+    }
+}
+
+fn build_byte_array_dictionary_reader_with_converter<C>(
+    pages: Box<dyn PageIterator>,
+    column_desc: ColumnDescPtr,
+    converter: C,
+    data_type: ArrowType,
+) -> Result<Box<dyn ArrayReader>>
+where
+    C: Converter<Vec<Option<ByteArray>>, ArrayRef> + 'static,
+{
+    macro_rules! reader {
+        ($key_type:ty) => {
+            Ok(Box::new(ByteArrayDictionaryReader::<$key_type, C>::new(
+                pages,
+                column_desc,
+                converter,
+                data_type,
+            )?))
+        };
+    }
+
+    let key_type = match &data_type {
+        ArrowType::Dictionary(key_type, _) => key_type.as_ref().clone(),
+        _ => unreachable!(),
+    };
+    match key_type {
+        ArrowType::Int8 => reader!(ArrowInt8Type),
+        ArrowType::Int16 => reader!(ArrowInt16Type),
+        ArrowType::Int32 => reader!(ArrowInt32Type),
+        ArrowType::Int64 => reader!(ArrowInt64Type),
+        ArrowType::UInt8 => reader!(ArrowUInt8Type),
+        ArrowType::UInt16 => reader!(ArrowUInt16Type),
+        ArrowType::UInt32 => reader!(ArrowUInt32Type),
+        ArrowType::UInt64 => reader!(ArrowUInt64Type),
+        key_type => Err(ArrowError(format!(
+            "Unsupported dictionary key type {:?}",
+            key_type
+        ))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1733,10 +2911,12 @@ mod tests {
     use crate::basic::{Encoding, Type as PhysicalType};
     use crate::column::page::{Page, PageReader};
     use crate::data_type::{ByteArray, DataType, Int32Type, Int64Type};
+    use crate::encodings::encoding::{DictEncoder, Encoder};
     use crate::errors::Result;
     use crate::file::reader::{FileReader, SerializedFileReader};
     use crate::schema::parser::parse_message_type;
-    use crate::schema::types::{ColumnDescPtr, SchemaDescriptor};
+    use crate::schema::types::{ColumnDescPtr, SchemaDescPtr, SchemaDescriptor};
+    use crate::util::memory::MemTracker;
     use crate::util::test_common::page_util::{
         DataPageBuilder, DataPageBuilderImpl, InMemoryPageIterator,
     };
@@ -2323,6 +3503,10 @@ mod tests {
             Ok(self.array.clone())
         }
 
+        fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+            Ok(min(num_records, self.array.len()))
+        }
+
         fn get_def_levels(&self) -> Option<&[i16]> {
             self.def_levels.as_deref()
         }
@@ -2361,6 +3545,177 @@ mod tests {
         }
     }
 
+    /// Creates the pages of a column chunk with a dictionary page, where each
+    /// page is described by its definition levels, its non-null values and
+    /// whether it should be dictionary encoded
+    fn make_dictionary_chunk(
+        column_desc: ColumnDescPtr,
+        pages: &[(&[i16], &[&str], bool)],
+    ) -> Vec<Page> {
+        let mut dict_encoder = DictEncoder::<ByteArrayType>::new(
+            column_desc.clone(),
+            Arc::new(MemTracker::new()),
+        );
+        let mut data_pages = Vec::new();
+        for (def_levels, values, dictionary_encoded) in pages {
+            let values: Vec<ByteArray> =
+                values.iter().map(|v| ByteArray::from(*v)).collect();
+            let mut pb = DataPageBuilderImpl::new(
+                column_desc.clone(),
+                def_levels.len() as u32,
+                false,
+            );
+            pb.add_def_levels(1, def_levels);
+            if *dictionary_encoded {
+                dict_encoder.put(&values).unwrap();
+                pb.add_indices(dict_encoder.write_indices().unwrap());
+            } else {
+                pb.add_values::<ByteArrayType>(Encoding::PLAIN, &values);
+            }
+            data_pages.push(pb.consume());
+        }
+
+        let dict_page = Page::DictionaryPage {
+            buf: dict_encoder.write_dict().unwrap(),
+            num_values: dict_encoder.num_entries() as u32,
+            encoding: Encoding::RLE_DICTIONARY,
+            is_sorted: false,
+        };
+        std::iter::once(dict_page).chain(data_pages).collect()
+    }
+
+    fn dictionary_test_schema() -> SchemaDescPtr {
+        let message_type = "
+        message test_schema {
+            OPTIONAL BYTE_ARRAY leaf (UTF8);
+        }
+        ";
+        parse_message_type(message_type)
+            .map(|t| Arc::new(SchemaDescriptor::new(Arc::new(t))))
+            .unwrap()
+    }
+
+    fn make_dictionary_reader(
+        schema: SchemaDescPtr,
+        pages: Vec<Vec<Page>>,
+    ) -> ByteArrayDictionaryReader<ArrowInt32, Utf8Converter> {
+        let column_desc = schema.column(0);
+        let page_iterator = InMemoryPageIterator::new(schema, column_desc.clone(), pages);
+
+        ByteArrayDictionaryReader::<ArrowInt32, Utf8Converter>::new(
+            Box::new(page_iterator),
+            column_desc,
+            Utf8Converter::new(Utf8ArrayConverter {}),
+            ArrowType::Dictionary(Box::new(ArrowType::Int32), Box::new(ArrowType::Utf8)),
+        )
+        .unwrap()
+    }
+
+    fn dictionary_values(array: &ArrayRef) -> Vec<Option<String>> {
+        let values = arrow::compute::cast(array, &ArrowType::Utf8).unwrap();
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+        values.iter().map(|v| v.map(|v| v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_byte_array_dictionary_reader() {
+        let schema = dictionary_test_schema();
+        let column_desc = schema.column(0);
+
+        let pages = vec![
+            make_dictionary_chunk(
+                column_desc.clone(),
+                &[
+                    (&[1, 0, 1, 1], &["a", "b", "a"], true),
+                    (&[1, 1, 0], &["c", "b"], true),
+                ],
+            ),
+            make_dictionary_chunk(
+                column_desc,
+                &[(&[0, 1, 1, 1], &["d", "e", "d"], true)],
+            ),
+        ];
+        let mut array_reader = make_dictionary_reader(schema, pages);
+
+        // a batch within the first column chunk uses its dictionary as is
+        let array = array_reader.next_batch(5).unwrap();
+        assert_eq!(
+            &ArrowType::Dictionary(Box::new(ArrowType::Int32), Box::new(ArrowType::Utf8)),
+            array.data_type()
+        );
+        let dictionary = array
+            .as_any()
+            .downcast_ref::<DictionaryArray<ArrowInt32>>()
+            .unwrap();
+        let keys: Vec<Option<i32>> = dictionary.keys().iter().collect();
+        assert_eq!(vec![Some(0), None, Some(1), Some(0), Some(2)], keys);
+        let values = dictionary.values();
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(
+            vec![Some("a"), Some("b"), Some("c")],
+            values.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(Some(&[1, 0, 1, 1, 1][..]), array_reader.get_def_levels());
+
+        // a batch spanning both column chunks concatenates their dictionaries
+        let array = array_reader.next_batch(10).unwrap();
+        let dictionary = array
+            .as_any()
+            .downcast_ref::<DictionaryArray<ArrowInt32>>()
+            .unwrap();
+        let keys: Vec<Option<i32>> = dictionary.keys().iter().collect();
+        assert_eq!(vec![Some(1), None, None, Some(3), Some(4), Some(3)], keys);
+        assert_eq!(5, dictionary.values().len());
+        assert_eq!(
+            vec![
+                Some("b".to_string()),
+                None,
+                None,
+                Some("d".to_string()),
+                Some("e".to_string()),
+                Some("d".to_string())
+            ],
+            dictionary_values(&array)
+        );
+        assert_eq!(Some(&[1, 0, 0, 1, 1, 1][..]), array_reader.get_def_levels());
+
+        let array = array_reader.next_batch(10).unwrap();
+        assert_eq!(0, array.len());
+    }
+
+    #[test]
+    fn test_byte_array_dictionary_reader_plain_fallback() {
+        let schema = dictionary_test_schema();
+        let column_desc = schema.column(0);
+
+        // the writer falls back to plain encoding after the first page
+        let pages = vec![make_dictionary_chunk(
+            column_desc,
+            &[
+                (&[1, 1, 0], &["a", "b"], true),
+                (&[1, 0, 1], &["c", "a"], false),
+            ],
+        )];
+        let mut array_reader = make_dictionary_reader(schema, pages);
+
+        let array = array_reader.next_batch(10).unwrap();
+        assert_eq!(
+            &ArrowType::Dictionary(Box::new(ArrowType::Int32), Box::new(ArrowType::Utf8)),
+            array.data_type()
+        );
+        assert_eq!(
+            vec![
+                Some("a".to_string()),
+                Some("b".to_string()),
+                None,
+                Some("c".to_string()),
+                None,
+                Some("a".to_string())
+            ],
+            dictionary_values(&array)
+        );
+    }
+
     #[test]
     fn test_struct_array_reader() {
         let array_1 = Arc::new(PrimitiveArray::<ArrowInt32>::from(vec![1, 2, 3, 4, 5]));
@@ -2422,11 +3777,12 @@ mod tests {
             file_metadata.key_value_metadata(),
         )
         .unwrap();
+        let mask = ProjectionMask::leaves(file_metadata.schema_descr(), vec![0]);
 
         let array_reader = build_array_reader(
             file_reader.metadata().file_metadata().schema_descr_ptr(),
             arrow_schema,
-            vec![0usize].into_iter(),
+            mask,
             file_reader,
         )
         .unwrap();
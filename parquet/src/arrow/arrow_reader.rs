@@ -17,18 +17,35 @@
 
 //! Contains reader which reads parquet data into arrow array.
 
-use crate::arrow::array_reader::{build_array_reader, ArrayReader, StructArrayReader};
-use crate::arrow::schema::parquet_to_arrow_schema;
+use crate::arrow::array_reader::{build_array_reader, ArrayReader};
+#[cfg(feature = "parallel")]
+use crate::arrow::parallel_reader::{DecodePool, ParallelRowGroupReader};
 use crate::arrow::schema::{
     parquet_to_arrow_schema_by_columns, parquet_to_arrow_schema_by_root_columns,
+    parquet_to_arrow_schema_with_int96_type,
 };
+pub use crate::arrow::selection::{RowSelection, RowSelector};
+use crate::arrow::ProjectionMask;
+use crate::bloom_filter::Sbbf;
+use crate::data_type::AsBytes;
+#[cfg(feature = "encryption")]
+use crate::encryption::decrypt::FileDecryptionProperties;
 use crate::errors::{ParquetError, Result};
+use crate::file::footer;
 use crate::file::metadata::ParquetMetaData;
-use crate::file::reader::FileReader;
-use arrow::datatypes::{DataType as ArrowType, Schema, SchemaRef};
+use crate::file::page_index::index_reader;
+use crate::file::reader::{ChunkReader, FileReader};
+use crate::file::serialized_reader::SerializedFileReader;
+use arrow::compute::{can_cast_types, cast, concat, parse_fixed_offset};
+use arrow::datatypes::{DataType as ArrowType, Schema, SchemaRef, TimeUnit};
 use arrow::error::Result as ArrowResult;
 use arrow::record_batch::{RecordBatch, RecordBatchReader};
-use arrow::{array::StructArray, error::ArrowError};
+use arrow::{
+    array::{ArrayRef, BooleanArray, StructArray},
+    error::ArrowError,
+};
+use std::collections::VecDeque;
+use std::fmt;
 use std::sync::Arc;
 
 /// Arrow reader api.
@@ -76,8 +93,64 @@ pub trait ArrowReader {
         T: IntoIterator<Item = usize>;
 }
 
+/// A predicate that is evaluated on a subset of the columns of a parquet file while
+/// it is read, returning which rows should be kept.
+pub trait ArrowPredicate: Send + 'static {
+    /// The leaf columns required to evaluate this predicate
+    fn projection(&self) -> &[usize];
+
+    /// Evaluate the predicate on a batch of the columns in [`Self::projection`],
+    /// returning a [`BooleanArray`] of the same length. Rows for which it is `true`
+    /// are kept, rows for which it is `false` or null are removed.
+    fn evaluate(&mut self, batch: RecordBatch) -> ArrowResult<BooleanArray>;
+}
+
+/// An [`ArrowPredicate`] created from a closure
+pub struct ArrowPredicateFn<F> {
+    projection: Vec<usize>,
+    f: F,
+}
+
+impl<F> ArrowPredicateFn<F>
+where
+    F: FnMut(RecordBatch) -> ArrowResult<BooleanArray> + Send + 'static,
+{
+    /// Create a new predicate that evaluates `f` on the leaf columns in `projection`
+    pub fn new(projection: Vec<usize>, f: F) -> Self {
+        Self { projection, f }
+    }
+}
+
+impl<F> ArrowPredicate for ArrowPredicateFn<F>
+where
+    F: FnMut(RecordBatch) -> ArrowResult<BooleanArray> + Send + 'static,
+{
+    fn projection(&self) -> &[usize] {
+        &self.projection
+    }
+
+    fn evaluate(&mut self, batch: RecordBatch) -> ArrowResult<BooleanArray> {
+        (self.f)(batch)
+    }
+}
+
+/// A sequence of [`ArrowPredicate`]s that filter the rows read by a
+/// [`ParquetRecordBatchReader`]. A row is kept if all the predicates keep it.
+pub struct RowFilter {
+    predicates: Vec<Box<dyn ArrowPredicate>>,
+}
+
+impl RowFilter {
+    /// Create a new filter from `predicates`, which are evaluated in order
+    pub fn new(predicates: Vec<Box<dyn ArrowPredicate>>) -> Self {
+        Self { predicates }
+    }
+}
+
 pub struct ParquetFileArrowReader {
     file_reader: Arc<dyn FileReader>,
+    /// The arrow type INT96 columns are decoded into
+    pub(crate) int96_type: ArrowType,
 }
 
 impl ArrowReader for ParquetFileArrowReader {
@@ -85,9 +158,10 @@ impl ArrowReader for ParquetFileArrowReader {
 
     fn get_schema(&mut self) -> Result<Schema> {
         let file_metadata = self.file_reader.metadata().file_metadata();
-        parquet_to_arrow_schema(
+        parquet_to_arrow_schema_with_int96_type(
             file_metadata.schema_descr(),
             file_metadata.key_value_metadata(),
+            &self.int96_type,
         )
     }
 
@@ -137,28 +211,613 @@ impl ArrowReader for ParquetFileArrowReader {
     where
         T: IntoIterator<Item = usize>,
     {
+        let mask = ProjectionMask::leaves(
+            self.file_reader.metadata().file_metadata().schema_descr(),
+            column_indices,
+        );
+        self.get_record_reader_by_mask(mask, batch_size)
+    }
+}
+
+impl ParquetFileArrowReader {
+    pub fn new(file_reader: Arc<dyn FileReader>) -> Self {
+        Self {
+            file_reader,
+            int96_type: ArrowType::Timestamp(TimeUnit::Nanosecond, None),
+        }
+    }
+
+    // Expose the reader metadata
+    pub fn get_metadata(&mut self) -> ParquetMetaData {
+        self.file_reader.metadata().clone()
+    }
+
+    /// Returns record batch reader whose record batch contains the columns selected
+    /// by `mask`. Structs only contain the fields that are selected by `mask`.
+    pub fn get_record_reader_by_mask(
+        &mut self,
+        mask: ProjectionMask,
+        batch_size: usize,
+    ) -> Result<ParquetRecordBatchReader> {
         let array_reader = build_array_reader(
             self.file_reader
                 .metadata()
                 .file_metadata()
                 .schema_descr_ptr(),
             self.get_schema()?,
-            column_indices,
+            mask,
             self.file_reader.clone(),
         )?;
 
         ParquetRecordBatchReader::try_new(batch_size, array_reader)
     }
+
+    /// Returns record batch reader whose record batch contains columns identified by
+    /// `column_indices`, only including the rows that are kept by `filter`.
+    ///
+    /// The columns of each predicate are decoded first, and the output columns are
+    /// only decoded for the rows kept by all the predicates, see
+    /// [`ParquetRecordBatchReaderBuilder::with_row_filter`].
+    pub fn get_record_reader_with_filter<T>(
+        &mut self,
+        column_indices: T,
+        batch_size: usize,
+        filter: RowFilter,
+    ) -> Result<ParquetRecordBatchReader>
+    where
+        T: IntoIterator<Item = usize>,
+    {
+        let mut reader = self.get_record_reader_by_columns(column_indices, batch_size)?;
+        self.add_predicates(&mut reader, filter)?;
+        Ok(reader)
+    }
+
+    /// Adds a [`PredicateReader`] to `reader` for each of the predicates in `filter`
+    fn add_predicates(
+        &mut self,
+        reader: &mut ParquetRecordBatchReader,
+        filter: RowFilter,
+    ) -> Result<()> {
+        let schema = self.get_schema()?;
+        let schema_descr = self
+            .file_reader
+            .metadata()
+            .file_metadata()
+            .schema_descr_ptr();
+        for predicate in filter.predicates {
+            let mask =
+                ProjectionMask::leaves(&schema_descr, predicate.projection().to_vec());
+            let array_reader = build_array_reader(
+                schema_descr.clone(),
+                schema.clone(),
+                mask,
+                self.file_reader.clone(),
+            )?;
+            reader
+                .predicates
+                .push(PredicateReader::try_new(predicate, array_reader)?);
+        }
+        Ok(())
+    }
 }
 
-impl ParquetFileArrowReader {
-    pub fn new(file_reader: Arc<dyn FileReader>) -> Self {
-        Self { file_reader }
+/// Options that control how the metadata of a parquet file is read by a
+/// [`ParquetRecordBatchReaderBuilder`]
+#[derive(Debug, Clone, Default)]
+pub struct ArrowReaderOptions {
+    page_index: bool,
+    int96_timestamp_unit: Option<TimeUnit>,
+    int96_timezone: Option<String>,
+    #[cfg(feature = "encryption")]
+    file_decryption_properties: Option<FileDecryptionProperties>,
+}
+
+impl ArrowReaderOptions {
+    /// Create a new [`ArrowReaderOptions`] with the default settings
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    // Expose the reader metadata
-    pub fn get_metadata(&mut self) -> ParquetMetaData {
-        self.file_reader.metadata().clone()
+    /// Also read the page index of each column chunk, making the statistics and
+    /// location of every data page available through
+    /// [`ParquetMetaData::page_indexes`] and [`ParquetMetaData::offset_indexes`]
+    pub fn with_page_index(mut self, page_index: bool) -> Self {
+        self.page_index = page_index;
+        self
+    }
+
+    /// Decode INT96 timestamps, as written by legacy versions of Spark and Impala,
+    /// into [`ArrowType::Timestamp`] of resolution `unit` instead of nanoseconds.
+    ///
+    /// Nanosecond timestamps can only represent the years 1677 to 2262, a coarser
+    /// resolution avoids overflow for dates outside of this range.
+    pub fn with_int96_timestamp_unit(mut self, unit: TimeUnit) -> Self {
+        self.int96_timestamp_unit = Some(unit);
+        self
+    }
+
+    /// Read INT96 timestamps as the local wall clock time in `timezone`, as written by
+    /// Impala, converting them to instants in UTC, and set `timezone` as the timezone of
+    /// the [`ArrowType::Timestamp`] they are decoded into.
+    ///
+    /// Only fixed offsets such as `"+01:00"` are supported. Without a timezone, which is
+    /// the default, INT96 values are read as instants in UTC, as written by Spark.
+    pub fn with_int96_timezone(mut self, timezone: Option<String>) -> Self {
+        self.int96_timezone = timezone;
+        self
+    }
+
+    /// The arrow type INT96 columns are decoded into
+    fn int96_type(&self) -> ArrowType {
+        ArrowType::Timestamp(
+            self.int96_timestamp_unit
+                .clone()
+                .unwrap_or(TimeUnit::Nanosecond),
+            self.int96_timezone.clone(),
+        )
+    }
+
+    /// Decrypt the file with `file_decryption_properties` if it is encrypted
+    #[cfg(feature = "encryption")]
+    pub fn with_file_decryption_properties(
+        mut self,
+        file_decryption_properties: FileDecryptionProperties,
+    ) -> Self {
+        self.file_decryption_properties = Some(file_decryption_properties);
+        self
+    }
+}
+
+/// A builder used to construct a [`ParquetRecordBatchReader`] for a parquet file.
+///
+/// The statistics of each row group and, if read with
+/// [`ArrowReaderOptions::with_page_index`], the page index of each column chunk are
+/// available from [`Self::metadata`] before any data is decoded, allowing callers
+/// to prune the row groups that cannot match a predicate:
+///
+/// ```no_run
+/// # use std::fs::File;
+/// # use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+/// # use parquet::file::statistics::Statistics;
+/// let file = File::open("data.parquet").unwrap();
+/// let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+///
+/// // Only read the row groups that may contain values of column 0 greater than 10
+/// let row_groups = builder
+///     .metadata()
+///     .row_groups()
+///     .iter()
+///     .enumerate()
+///     .filter(|(_, row_group)| match row_group.column(0).statistics() {
+///         Some(Statistics::Int32(stats)) if stats.has_min_max_set() => *stats.max() > 10,
+///         _ => true,
+///     })
+///     .map(|(idx, _)| idx)
+///     .collect();
+///
+/// let reader = builder.with_row_groups(row_groups).build().unwrap();
+/// ```
+pub struct ParquetRecordBatchReaderBuilder<T> {
+    input: T,
+    metadata: Arc<ParquetMetaData>,
+    schema: SchemaRef,
+    batch_size: usize,
+    row_groups: Option<Vec<usize>>,
+    projection: ProjectionMask,
+    filter: Option<RowFilter>,
+    selection: Option<RowSelection>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+    int96_type: ArrowType,
+    output_schema: Option<SchemaRef>,
+    #[cfg(feature = "parallel")]
+    decode_pool: Option<DecodePool>,
+}
+
+impl<T> fmt::Debug for ParquetRecordBatchReaderBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParquetRecordBatchReaderBuilder")
+            .field("metadata", &self.metadata)
+            .field("schema", &self.schema)
+            .field("batch_size", &self.batch_size)
+            .field("row_groups", &self.row_groups)
+            .field("projection", &self.projection)
+            .field("selection", &self.selection)
+            .field("limit", &self.limit)
+            .field("offset", &self.offset)
+            .field("output_schema", &self.output_schema)
+            .finish()
+    }
+}
+
+impl<T: ChunkReader + 'static> ParquetRecordBatchReaderBuilder<T> {
+    /// Create a new builder, reading the metadata of the parquet file from `input`
+    pub fn try_new(input: T) -> Result<Self> {
+        Self::try_new_with_options(input, ArrowReaderOptions::default())
+    }
+
+    /// Create a new builder, reading the metadata of the parquet file from `input`
+    /// as configured by `options`
+    pub fn try_new_with_options(input: T, options: ArrowReaderOptions) -> Result<Self> {
+        #[cfg(feature = "encryption")]
+        let mut metadata = match &options.file_decryption_properties {
+            Some(properties) => {
+                footer::parse_metadata_with_decryption(&input, properties)?
+            }
+            None => footer::parse_metadata(&input)?,
+        };
+        #[cfg(not(feature = "encryption"))]
+        let mut metadata = footer::parse_metadata(&input)?;
+        if options.page_index {
+            metadata = index_reader::read_page_index(&input, metadata)?;
+        }
+
+        if let Some(timezone) = &options.int96_timezone {
+            parse_fixed_offset(timezone)?;
+        }
+        let int96_type = options.int96_type();
+        let file_metadata = metadata.file_metadata();
+        let schema = Arc::new(parquet_to_arrow_schema_with_int96_type(
+            file_metadata.schema_descr(),
+            file_metadata.key_value_metadata(),
+            &int96_type,
+        )?);
+
+        Ok(Self {
+            input,
+            metadata: Arc::new(metadata),
+            schema,
+            batch_size: 1024,
+            row_groups: None,
+            projection: ProjectionMask::all(),
+            filter: None,
+            selection: None,
+            limit: None,
+            offset: None,
+            int96_type,
+            output_schema: None,
+            #[cfg(feature = "parallel")]
+            decode_pool: None,
+        })
+    }
+
+    /// Returns the metadata of the parquet file, including the page index if
+    /// it was requested with [`ArrowReaderOptions::with_page_index`]
+    pub fn metadata(&self) -> &Arc<ParquetMetaData> {
+        &self.metadata
+    }
+
+    /// Returns the arrow schema of the whole parquet file
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Reads the bloom filter of leaf column `column` in row group `row_group`, or
+    /// returns `None` if the file contains no bloom filter for that column chunk.
+    ///
+    /// See [`Self::with_bloom_filter_pruning`] to skip the row groups that cannot
+    /// contain a value.
+    pub fn get_row_group_column_bloom_filter(
+        &self,
+        row_group: usize,
+        column: usize,
+    ) -> Result<Option<Sbbf>> {
+        let num_row_groups = self.metadata.num_row_groups();
+        if row_group >= num_row_groups {
+            return Err(ParquetError::IndexOutOfBound(row_group, num_row_groups));
+        }
+        let row_group = self.metadata.row_group(row_group);
+        if column >= row_group.num_columns() {
+            return Err(ParquetError::IndexOutOfBound(
+                column,
+                row_group.num_columns(),
+            ));
+        }
+        row_group.column(column).read_bloom_filter(&self.input)
+    }
+
+    /// Set the size of the record batches, the default is 1024
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size, ..self }
+    }
+
+    /// Only read the row groups with the given indices, in the given order
+    pub fn with_row_groups(self, row_groups: Vec<usize>) -> Self {
+        Self {
+            row_groups: Some(row_groups),
+            ..self
+        }
+    }
+
+    /// Only read the row groups whose bloom filter for leaf column `column` may
+    /// contain `value`, out of the row groups selected so far. Row groups without a
+    /// bloom filter for the column are always read.
+    ///
+    /// `value` is checked in its plain encoded form, so it must be of the physical
+    /// type of the column, e.g. `i32` for `INT32` or `str` for `BYTE_ARRAY` columns.
+    /// The bloom filters are read by this call, and only prune row groups: rows of the
+    /// remaining row groups are not filtered.
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// # use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    /// let file = File::open("data.parquet").unwrap();
+    /// let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+    ///     .unwrap()
+    ///     // Only read the row groups that may contain the value 42 in column 0
+    ///     .with_bloom_filter_pruning(0, &42_i32)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_bloom_filter_pruning<V: AsBytes + ?Sized>(
+        self,
+        column: usize,
+        value: &V,
+    ) -> Result<Self> {
+        let candidates = match &self.row_groups {
+            Some(row_groups) => row_groups.clone(),
+            None => (0..self.metadata.num_row_groups()).collect(),
+        };
+        let mut row_groups = Vec::with_capacity(candidates.len());
+        for idx in candidates {
+            match self.get_row_group_column_bloom_filter(idx, column)? {
+                Some(bloom_filter) if !bloom_filter.check(value) => {}
+                _ => row_groups.push(idx),
+            }
+        }
+        Ok(self.with_row_groups(row_groups))
+    }
+
+    /// Only read the columns selected by `mask`
+    pub fn with_projection(self, mask: ProjectionMask) -> Self {
+        Self {
+            projection: mask,
+            ..self
+        }
+    }
+
+    /// Only return the rows that are kept by `filter`
+    ///
+    /// For each batch, the columns of the predicates are decoded first, each for the
+    /// rows kept by the previous predicates only. The output columns are then
+    /// decoded for the rows kept by all the predicates, and the other rows skipped.
+    pub fn with_row_filter(self, filter: RowFilter) -> Self {
+        Self {
+            filter: Some(filter),
+            ..self
+        }
+    }
+
+    /// Only read the rows selected by `selection`, which applies to the rows of the
+    /// row groups that are read, in order.
+    ///
+    /// Row groups without any selected rows are not read, and if the page index was
+    /// read with [`ArrowReaderOptions::with_page_index`], neither are the data pages
+    /// that only contain skipped rows, where the page boundaries of the columns allow.
+    pub fn with_row_selection(self, selection: RowSelection) -> Self {
+        Self {
+            selection: Some(selection),
+            ..self
+        }
+    }
+
+    /// Only return the first `limit` rows, after any rows skipped by
+    /// [`Self::with_offset`].
+    ///
+    /// The limit applies to the rows that remain after the row groups, the row
+    /// selection and the row filter have been applied, and no more rows are
+    /// decoded once it has been reached.
+    pub fn with_limit(self, limit: usize) -> Self {
+        Self {
+            limit: Some(limit),
+            ..self
+        }
+    }
+
+    /// Skip the first `offset` rows.
+    ///
+    /// The offset applies to the rows that remain after the row groups, the row
+    /// selection and the row filter have been applied, and the skipped rows are not
+    /// decoded, except for the columns of the row filter. Without a row filter, the
+    /// skipped rows are known up front, so that row groups, and pages if the page
+    /// index was read, that only contain skipped rows are not read.
+    pub fn with_offset(self, offset: usize) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Return batches of `schema`, which must have a field of the same name for each
+    /// projected column, in the same order.
+    ///
+    /// The columns whose type differs from the type of their field in `schema` are
+    /// cast to it as they are read, e.g. an `Int32` column to `Int64` or a `Utf8`
+    /// column to a `Dictionary`, so that files with slightly different schemas can
+    /// be read into batches of a common schema. Values that can't be represented in
+    /// the requested type are read as nulls.
+    ///
+    /// [`Self::build`] returns an error if a column can't be cast to the type of its
+    /// field.
+    pub fn with_schema(self, schema: SchemaRef) -> Self {
+        Self {
+            output_schema: Some(schema),
+            ..self
+        }
+    }
+
+    /// Decode the projected columns of each row group in parallel on the global
+    /// [rayon](https://docs.rs/rayon) thread pool.
+    ///
+    /// Each row group is read into memory and decoded in full before its rows
+    /// are returned, trading memory for the time spent decoding wide projections.
+    #[cfg(feature = "parallel")]
+    pub fn with_parallel_decoding(self, parallel: bool) -> Self {
+        Self {
+            decode_pool: if parallel {
+                Some(DecodePool::Global)
+            } else {
+                None
+            },
+            ..self
+        }
+    }
+
+    /// Decode the projected columns of each row group in parallel on `pool`, as
+    /// described in [`Self::with_parallel_decoding`]
+    #[cfg(feature = "parallel")]
+    pub fn with_thread_pool(self, pool: Arc<rayon::ThreadPool>) -> Self {
+        Self {
+            decode_pool: Some(DecodePool::Custom(pool)),
+            ..self
+        }
+    }
+
+    /// Build a new [`ParquetRecordBatchReader`]
+    pub fn build(mut self) -> Result<ParquetRecordBatchReader> {
+        let num_row_groups = self.metadata.num_row_groups();
+        let mut row_groups = match self.row_groups.take() {
+            Some(row_groups) => {
+                if let Some(idx) = row_groups.iter().find(|idx| **idx >= num_row_groups) {
+                    return Err(ParquetError::IndexOutOfBound(*idx, num_row_groups));
+                }
+                row_groups
+            }
+            None => (0..num_row_groups).collect(),
+        };
+
+        // Without a row filter, the offset and limit select the rows to read,
+        // otherwise they apply to the rows kept by the filter as they are read
+        let (selection, offset, limit) = match (&self.filter, self.offset, self.limit) {
+            (None, None, None) | (Some(_), _, _) => {
+                (self.selection, self.offset.unwrap_or(0), self.limit)
+            }
+            (None, offset, limit) => {
+                let total_rows: usize = row_groups
+                    .iter()
+                    .map(|idx| self.metadata.row_group(*idx).num_rows() as usize)
+                    .sum();
+                let mut selectors: Vec<RowSelector> = self
+                    .selection
+                    .map(|selection| selection.iter().copied().collect())
+                    .unwrap_or_default();
+                // the rows past the end of the selection are selected
+                let covered: usize = selectors.iter().map(|s| s.row_count).sum();
+                if covered < total_rows {
+                    selectors.push(RowSelector::select(total_rows - covered));
+                }
+                let mut selection = RowSelection::from(selectors);
+                if let Some(offset) = offset {
+                    selection = selection.offset(offset);
+                }
+                if let Some(limit) = limit {
+                    selection = selection.limit(limit);
+                }
+                (Some(selection), 0, None)
+            }
+        };
+
+        let mut skipped_rows = vec![];
+        let selection = match selection {
+            Some(mut selection) => {
+                let mut selectors = vec![];
+                let mut selected_row_groups = vec![];
+                for idx in row_groups {
+                    let num_rows = self.metadata.row_group(idx).num_rows() as usize;
+                    let mut rg_selection = selection.split_off(num_rows);
+                    // the rows past the end of the selection are selected
+                    let covered: usize = rg_selection.iter().map(|s| s.row_count).sum();
+                    if covered < num_rows {
+                        let remaining = RowSelector::select(num_rows - covered);
+                        let selectors =
+                            rg_selection.iter().copied().chain(Some(remaining));
+                        rg_selection = RowSelection::from(selectors.collect::<Vec<_>>());
+                    }
+                    if !rg_selection.selects_any() {
+                        continue;
+                    }
+
+                    let skipped = match self.metadata.offset_indexes() {
+                        Some(offset_indexes) => rg_selection
+                            .skipped_page_ranges(&offset_indexes[idx], num_rows),
+                        None => vec![],
+                    };
+                    let rg_selection = rg_selection.remove_skipped(&skipped);
+                    selectors.extend(rg_selection.iter().copied());
+                    skipped_rows.push(skipped);
+                    selected_row_groups.push(idx);
+                }
+                row_groups = selected_row_groups;
+                // reading stops at the end of the selection, so trailing skipped
+                // rows need not be skipped
+                while matches!(selectors.last(), Some(selector) if selector.skip) {
+                    selectors.pop();
+                }
+                Some(RowSelection::from(selectors))
+            }
+            None => None,
+        };
+
+        let metadata = self.metadata.select_row_groups(&row_groups);
+        let mut file_reader =
+            SerializedFileReader::new_with_metadata(self.input, metadata);
+        file_reader.set_skipped_rows(skipped_rows);
+
+        let file_reader = Arc::new(file_reader);
+        let mut arrow_reader = ParquetFileArrowReader::new(file_reader.clone());
+        arrow_reader.int96_type = self.int96_type.clone();
+        #[cfg(feature = "parallel")]
+        let mut reader = match self.decode_pool {
+            Some(pool) => {
+                let array_reader = ParallelRowGroupReader::try_new(
+                    file_reader,
+                    self.projection,
+                    self.int96_type,
+                    pool,
+                )?;
+                ParquetRecordBatchReader::try_new(
+                    self.batch_size,
+                    Box::new(array_reader),
+                )?
+            }
+            None => arrow_reader
+                .get_record_reader_by_mask(self.projection, self.batch_size)?,
+        };
+        #[cfg(not(feature = "parallel"))]
+        let mut reader =
+            arrow_reader.get_record_reader_by_mask(self.projection, self.batch_size)?;
+        if let Some(filter) = self.filter {
+            arrow_reader.add_predicates(&mut reader, filter)?;
+        }
+        if let Some(schema) = self.output_schema {
+            reader.set_output_schema(schema)?;
+        }
+        reader.selection = selection.map(VecDeque::from);
+        reader.offset = offset;
+        reader.limit = limit;
+        Ok(reader)
+    }
+}
+
+/// Reads the columns of an [`ArrowPredicate`] alongside a [`ParquetRecordBatchReader`]
+struct PredicateReader {
+    predicate: Box<dyn ArrowPredicate>,
+    array_reader: Box<dyn ArrayReader>,
+    schema: SchemaRef,
+}
+
+impl PredicateReader {
+    fn try_new(
+        predicate: Box<dyn ArrowPredicate>,
+        array_reader: Box<dyn ArrayReader>,
+    ) -> Result<Self> {
+        let schema = struct_reader_schema(array_reader.as_ref())?;
+        Ok(Self {
+            predicate,
+            array_reader,
+            schema,
+        })
     }
 }
 
@@ -166,44 +825,95 @@ pub struct ParquetRecordBatchReader {
     batch_size: usize,
     array_reader: Box<dyn ArrayReader>,
     schema: SchemaRef,
+    /// Predicates that filter the rows of each batch
+    predicates: Vec<PredicateReader>,
+    /// The rows that remain to be selected or skipped, `None` to read all rows
+    selection: Option<VecDeque<RowSelector>>,
+    /// The number of rows that remain to be skipped from the filtered batches
+    offset: usize,
+    /// The number of rows that remain to be returned, `None` for no limit
+    limit: Option<usize>,
+    /// The schema the columns are cast to, `None` to return them as they are read
+    output_schema: Option<SchemaRef>,
 }
 
 impl Iterator for ParquetRecordBatchReader {
     type Item = ArrowResult<RecordBatch>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.array_reader.next_batch(self.batch_size) {
-            Err(error) => Some(Err(error.into())),
-            Ok(array) => {
-                let struct_array =
-                    array.as_any().downcast_ref::<StructArray>().ok_or_else(|| {
-                        ArrowError::ParquetError(
-                            "Struct array reader should return struct array".to_string(),
-                        )
-                    });
-                match struct_array {
-                    Err(err) => Some(Err(err)),
-                    Ok(e) => {
-                        match RecordBatch::try_new(self.schema.clone(), e.columns_ref()) {
-                            Err(err) => Some(Err(err)),
-                            Ok(record_batch) => {
-                                if record_batch.num_rows() > 0 {
-                                    Some(Ok(record_batch))
-                                } else {
-                                    None
-                                }
-                            }
-                        }
-                    }
-                }
+        loop {
+            if self.limit == Some(0) {
+                return None;
+            }
+            match self.read_filtered() {
+                Err(err) => return Some(Err(err)),
+                Ok(None) => return None,
+                // no rows were selected or all were skipped by the offset, move on
+                // to the next batch
+                Ok(Some(batch)) if batch.num_rows() == 0 => {}
+                Ok(Some(batch)) => return Some(self.cast_batch(batch)),
             }
         }
     }
 }
 
+/// Converts the arrays read by a struct array reader into a [`RecordBatch`]
+/// with `schema`
+fn to_record_batch(arrays: &[ArrayRef], schema: &SchemaRef) -> ArrowResult<RecordBatch> {
+    let array = match arrays {
+        [array] => array.clone(),
+        _ => concat(&arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>())?,
+    };
+    let struct_array = array
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .ok_or_else(|| {
+            ArrowError::ParquetError(
+                "Struct array reader should return struct array".to_string(),
+            )
+        })?;
+    RecordBatch::try_new(schema.clone(), struct_array.columns_ref())
+}
+
+/// Reads the rows selected by `selection` from `array_reader` and skips the others,
+/// returning the arrays read and the number of rows read or skipped, which is less
+/// than the rows of `selection` once the end of the data has been reached
+fn read_selection(
+    array_reader: &mut dyn ArrayReader,
+    selection: &RowSelection,
+) -> Result<(Vec<ArrayRef>, usize)> {
+    let mut arrays = vec![];
+    let mut num_rows = 0;
+    for selector in selection.iter() {
+        let read = if selector.skip {
+            array_reader.skip_records(selector.row_count)?
+        } else {
+            let array = array_reader.next_batch(selector.row_count)?;
+            let len = array.len();
+            if len > 0 {
+                arrays.push(array);
+            }
+            len
+        };
+        num_rows += read;
+        if read < selector.row_count {
+            break;
+        }
+    }
+    Ok((arrays, num_rows))
+}
+
+/// Returns the schema of the batches read by a struct array reader
+fn struct_reader_schema(array_reader: &dyn ArrayReader) -> Result<SchemaRef> {
+    match array_reader.get_data_type() {
+        ArrowType::Struct(ref fields) => Ok(Arc::new(Schema::new(fields.clone()))),
+        _ => Err(general_err!("The input must be struct array reader!")),
+    }
+}
+
 impl RecordBatchReader for ParquetRecordBatchReader {
     fn schema(&self) -> SchemaRef {
-        self.schema.clone()
+        self.output_schema.as_ref().unwrap_or(&self.schema).clone()
     }
 }
 
@@ -212,23 +922,166 @@ impl ParquetRecordBatchReader {
         batch_size: usize,
         array_reader: Box<dyn ArrayReader>,
     ) -> Result<Self> {
-        // Check that array reader is struct array reader
-        array_reader
-            .as_any()
-            .downcast_ref::<StructArrayReader>()
-            .ok_or_else(|| general_err!("The input must be struct array reader!"))?;
-
-        let schema = match array_reader.get_data_type() {
-            ArrowType::Struct(ref fields) => Schema::new(fields.clone()),
-            _ => unreachable!("Struct array reader's data type is not struct!"),
-        };
+        let schema = struct_reader_schema(array_reader.as_ref())?;
 
         Ok(Self {
             batch_size,
             array_reader,
-            schema: Arc::new(schema),
+            schema,
+            predicates: vec![],
+            selection: None,
+            offset: 0,
+            limit: None,
+            output_schema: None,
         })
     }
+
+    /// Casts the columns of the returned batches to the types of the fields of
+    /// `schema`, returning an error if it doesn't have a field of the same name
+    /// for each column, in the same order, or a column can't be cast to its type
+    fn set_output_schema(&mut self, schema: SchemaRef) -> Result<()> {
+        let fields = self.schema.fields();
+        if fields.len() != schema.fields().len() {
+            return Err(general_err!(
+                "The requested schema has {} fields, but {} columns are read",
+                schema.fields().len(),
+                fields.len()
+            ));
+        }
+        for (field, requested) in fields.iter().zip(schema.fields()) {
+            if field.name() != requested.name() {
+                return Err(general_err!(
+                    "The requested schema has a field \"{}\" where the column \"{}\" is read",
+                    requested.name(),
+                    field.name()
+                ));
+            }
+            if !can_cast_types(field.data_type(), requested.data_type()) {
+                return Err(general_err!(
+                    "The column \"{}\" of type {:?} can't be read as {:?}",
+                    field.name(),
+                    field.data_type(),
+                    requested.data_type()
+                ));
+            }
+        }
+        self.output_schema = Some(schema);
+        Ok(())
+    }
+
+    /// Casts the columns of `batch` to the types of the output schema, if any
+    fn cast_batch(&self, batch: RecordBatch) -> ArrowResult<RecordBatch> {
+        let schema = match &self.output_schema {
+            Some(schema) => schema,
+            None => return Ok(batch),
+        };
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(schema.fields())
+            .map(|(column, field)| cast(column, field.data_type()))
+            .collect::<ArrowResult<Vec<_>>>()?;
+        RecordBatch::try_new(schema.clone(), columns)
+    }
+
+    /// Takes the selectors of up to the next `batch_size` selected rows from the
+    /// remaining selection, returning `None` once all rows have been selected
+    fn next_selection(&mut self) -> Option<RowSelection> {
+        let selection = match self.selection.as_mut() {
+            None => return Some(vec![RowSelector::select(self.batch_size)].into()),
+            Some(selection) => selection,
+        };
+        let mut selectors = vec![];
+        let mut num_selected = 0;
+        while num_selected < self.batch_size {
+            match selection.pop_front() {
+                None => break,
+                Some(selector) if selector.skip => selectors.push(selector),
+                Some(selector) => {
+                    let to_read = selector.row_count.min(self.batch_size - num_selected);
+                    if to_read < selector.row_count {
+                        let remaining = selector.row_count - to_read;
+                        selection.push_front(RowSelector::select(remaining));
+                    }
+                    selectors.push(RowSelector::select(to_read));
+                    num_selected += to_read;
+                }
+            }
+        }
+        if selectors.is_empty() {
+            None
+        } else {
+            Some(selectors.into())
+        }
+    }
+
+    /// Reads the next batch of up to `batch_size` selected rows, removing the rows
+    /// that aren't kept by the predicates, returning `None` once all rows have been
+    /// read
+    ///
+    /// The columns of each predicate are decoded first, for the rows kept by the
+    /// previous predicates only, and the rows they keep narrow down the selection.
+    /// The output columns are then decoded for the rows kept by all the predicates
+    /// that are within the offset and limit, and the other rows are skipped.
+    fn read_filtered(&mut self) -> ArrowResult<Option<RecordBatch>> {
+        let mut selection = match self.next_selection() {
+            Some(selection) => selection,
+            None => return Ok(None),
+        };
+        let mut num_rows: usize = selection.iter().map(|s| s.row_count).sum();
+        for reader in self.predicates.iter_mut() {
+            let (arrays, read) =
+                match read_selection(reader.array_reader.as_mut(), &selection) {
+                    Ok(read) => read,
+                    Err(error) => return Err(error.into()),
+                };
+            if read < num_rows {
+                // the end of the data was reached
+                selection = selection.split_off(read);
+                num_rows = read;
+            }
+            if !selection.selects_any() {
+                continue;
+            }
+
+            let batch = to_record_batch(&arrays, &reader.schema)?;
+            let num_selected = batch.num_rows();
+            let mask = reader.predicate.evaluate(batch)?;
+            if mask.len() != num_selected {
+                return Err(ArrowError::ParquetError(format!(
+                    "Predicate returned {} values, expected {}",
+                    mask.len(),
+                    num_selected
+                )));
+            }
+            selection = selection.and_then(&RowSelection::from_filters(&[mask]));
+        }
+
+        // the rows kept by the predicates that are skipped by the remaining offset or
+        // are past the remaining limit are skipped rather than decoded
+        let num_selected = selection.row_count();
+        let offset = self.offset.min(num_selected);
+        self.offset -= offset;
+        selection = selection.offset(offset);
+        if let Some(limit) = self.limit.as_mut() {
+            selection = selection.limit(*limit);
+            *limit -= (num_selected - offset).min(*limit);
+        }
+
+        let array_reader = self.array_reader.as_mut();
+        let (arrays, read) = match read_selection(array_reader, &selection) {
+            Ok(read) => read,
+            Err(error) => return Err(error.into()),
+        };
+        if read == 0 {
+            return Ok(None);
+        }
+        if arrays.is_empty() {
+            // no rows were selected
+            return Ok(Some(RecordBatch::new_empty(self.schema.clone())));
+        }
+        to_record_batch(&arrays, &self.schema).map(Some)
+    }
 }
 
 #[cfg(test)]
@@ -316,6 +1169,721 @@ mod tests {
         compare_batch_json(&mut record_batch_reader, projected_json_values, max_len);
     }
 
+    #[test]
+    fn test_arrow_reader_row_filter() {
+        use crate::arrow::arrow_reader::{ArrowPredicateFn, RowFilter};
+        use crate::arrow::ArrowWriter;
+        use crate::util::cursor::{InMemoryWriteableCursor, SliceableCursor};
+        use arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
+        use arrow::record_batch::RecordBatch;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", ArrowDataType::Int32, true),
+            Field::new("b", ArrowDataType::Utf8, false),
+            Field::new("c", ArrowDataType::Int32, false),
+        ]));
+        let a: Int32Array = (0..100)
+            .map(|v| if v == 4 { None } else { Some(v) })
+            .collect();
+        let b = StringArray::from_iter_values((0..100).map(|v| format!("value_{}", v)));
+        let c = Int32Array::from_iter_values(0..100);
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(a), Arc::new(b), Arc::new(c)],
+        )
+        .unwrap();
+
+        let cursor = InMemoryWriteableCursor::default();
+        let mut writer = ArrowWriter::try_new(cursor.clone(), schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let reader =
+            SerializedFileReader::new(SliceableCursor::new(cursor.data())).unwrap();
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(reader));
+
+        // keep even values of `a`, and values of `c` below 50
+        let even = ArrowPredicateFn::new(vec![0], |batch| {
+            let a = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            Ok(a.iter().map(|v| v.map(|v| v % 2 == 0)).collect())
+        });
+        let evaluated = Arc::new(AtomicUsize::new(0));
+        let below_evaluated = evaluated.clone();
+        let below = ArrowPredicateFn::new(vec![2], move |batch| {
+            below_evaluated.fetch_add(batch.num_rows(), Ordering::SeqCst);
+            let c = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            Ok(c.iter().map(|v| v.map(|v| v < 50)).collect())
+        });
+        let filter = RowFilter::new(vec![Box::new(even), Box::new(below)]);
+
+        let batches = arrow_reader
+            .get_record_reader_with_filter(vec![1], 10, filter)
+            .unwrap()
+            .collect::<arrow::error::Result<Vec<_>>>()
+            .unwrap();
+
+        // the batches of rows 50 to 99 have no selected rows
+        assert_eq!(5, batches.len());
+        let values: Vec<String> = batches
+            .iter()
+            .flat_map(|batch| {
+                let b = batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<StringArray>()
+                    .unwrap();
+                b.iter().map(|v| v.unwrap().to_string()).collect::<Vec<_>>()
+            })
+            .collect();
+        let expected: Vec<String> = (0..50)
+            .filter(|v| v % 2 == 0 && *v != 4)
+            .map(|v| format!("value_{}", v))
+            .collect();
+        assert_eq!(expected, values);
+        // the second predicate is only evaluated on the rows kept by the first
+        assert_eq!(49, evaluated.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_arrow_reader_projection_mask() {
+        use crate::arrow::{ArrowWriter, ProjectionMask};
+        use crate::util::cursor::{InMemoryWriteableCursor, SliceableCursor};
+        use arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
+        use arrow::record_batch::RecordBatch;
+
+        let address_fields = vec![
+            Field::new("street", ArrowDataType::Utf8, true),
+            Field::new("city", ArrowDataType::Utf8, true),
+        ];
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", ArrowDataType::Int32, false),
+            Field::new(
+                "address",
+                ArrowDataType::Struct(address_fields.clone()),
+                false,
+            ),
+        ]));
+        let id = Int32Array::from(vec![1, 2, 3]);
+        let street = StringArray::from(vec![Some("Main St"), None, Some("High St")]);
+        let city = StringArray::from(vec![Some("Springfield"), Some("Paris"), None]);
+        let city = Arc::new(city) as ArrayRef;
+        let address = StructArray::from(vec![
+            (address_fields[0].clone(), Arc::new(street) as ArrayRef),
+            (address_fields[1].clone(), city.clone()),
+        ]);
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(id), Arc::new(address)])
+                .unwrap();
+
+        let cursor = InMemoryWriteableCursor::default();
+        let mut writer = ArrowWriter::try_new(cursor.clone(), schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let reader =
+            SerializedFileReader::new(SliceableCursor::new(cursor.data())).unwrap();
+        let schema_descr = reader.metadata().file_metadata().schema_descr_ptr();
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(reader));
+
+        // only read the city of the address
+        let mask = ProjectionMask::columns(&schema_descr, vec!["address.city"]);
+        let batches = arrow_reader
+            .get_record_reader_by_mask(mask, 10)
+            .unwrap()
+            .collect::<arrow::error::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(1, batches.len());
+
+        let expected_fields = vec![Field::new("city", ArrowDataType::Utf8, true)];
+        let expected_schema = Schema::new(vec![Field::new(
+            "address",
+            ArrowDataType::Struct(expected_fields.clone()),
+            false,
+        )]);
+        assert_eq!(&expected_schema, batches[0].schema().as_ref());
+        let address = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(1, address.num_columns());
+        assert_eq!(city.data(), address.column(0).data());
+
+        // roots select every field of a struct
+        let mask = ProjectionMask::roots(&schema_descr, vec![1]);
+        let batches = arrow_reader
+            .get_record_reader_by_mask(mask, 10)
+            .unwrap()
+            .collect::<arrow::error::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(batch.column(1).data(), batches[0].column(0).data());
+    }
+
+    #[test]
+    fn test_arrow_reader_builder() {
+        use crate::arrow::arrow_reader::{
+            ArrowReaderOptions, ParquetRecordBatchReaderBuilder,
+        };
+        use crate::arrow::{ArrowWriter, ProjectionMask};
+        use crate::errors::ParquetError;
+        use crate::file::page_index::index::Index;
+        use crate::file::statistics::Statistics;
+        use crate::util::cursor::{InMemoryWriteableCursor, SliceableCursor};
+        use arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
+        use arrow::record_batch::RecordBatch;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", ArrowDataType::Int32, false),
+            Field::new("b", ArrowDataType::Utf8, true),
+        ]));
+
+        // write a row group for each of rows 0..4, 4..8 and 8..10
+        let cursor = InMemoryWriteableCursor::default();
+        let mut writer =
+            ArrowWriter::try_new(cursor.clone(), schema.clone(), None).unwrap();
+        for rows in vec![0..4, 4..8, 8..10] {
+            let a = Int32Array::from(rows.clone().collect::<Vec<_>>());
+            let b = StringArray::from_iter_values(rows.map(|i| i.to_string()));
+            let batch =
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)])
+                    .unwrap();
+            writer.write(&batch).unwrap();
+        }
+        writer.close().unwrap();
+        let data = cursor.data();
+
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new(SliceableCursor::new(data.clone()))
+                .unwrap();
+        assert_eq!(3, builder.metadata().num_row_groups());
+        assert!(builder.metadata().page_indexes().is_none());
+
+        // prune the row groups whose maximum value of `a` is less than 5
+        let row_groups = builder
+            .metadata()
+            .row_groups()
+            .iter()
+            .enumerate()
+            .filter(|(_, row_group)| match row_group.column(0).statistics() {
+                Some(Statistics::Int32(stats)) => *stats.max() >= 5,
+                _ => true,
+            })
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![1, 2], row_groups);
+
+        let mask = ProjectionMask::leaves(
+            builder.metadata().file_metadata().schema_descr(),
+            vec![0],
+        );
+        let batches = builder
+            .with_row_groups(row_groups)
+            .with_projection(mask)
+            .with_batch_size(10)
+            .build()
+            .unwrap()
+            .collect::<arrow::error::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(1, batches.len());
+        assert_eq!(1, batches[0].num_columns());
+        let a = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(a, &Int32Array::from(vec![4, 5, 6, 7, 8, 9]));
+
+        // invalid row groups are rejected
+        let err =
+            ParquetRecordBatchReaderBuilder::try_new(SliceableCursor::new(data.clone()))
+                .unwrap()
+                .with_row_groups(vec![3])
+                .build()
+                .err()
+                .unwrap();
+        assert_eq!(ParquetError::IndexOutOfBound(3, 3), err);
+
+        // the page index is read when requested, this file does not have one
+        let options = ArrowReaderOptions::new().with_page_index(true);
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            SliceableCursor::new(data),
+            options,
+        )
+        .unwrap();
+        let page_indexes = builder.metadata().page_indexes().unwrap();
+        assert_eq!(3, page_indexes.len());
+        assert_eq!(&vec![Index::NONE, Index::NONE], &page_indexes[0]);
+        let offset_indexes = builder.metadata().offset_indexes().unwrap();
+        assert!(offset_indexes[0][0].is_empty());
+    }
+
+    #[test]
+    fn test_arrow_reader_with_schema() {
+        use crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+        use crate::arrow::ArrowWriter;
+        use crate::util::cursor::{InMemoryWriteableCursor, SliceableCursor};
+        use arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
+        use arrow::record_batch::RecordBatch;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", ArrowDataType::Int32, false),
+            Field::new("b", ArrowDataType::Utf8, true),
+        ]));
+        let a = Int32Array::from(vec![1, 2, 3]);
+        let b = StringArray::from(vec![Some("x"), None, Some("x")]);
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)]).unwrap();
+
+        let cursor = InMemoryWriteableCursor::default();
+        let mut writer = ArrowWriter::try_new(cursor.clone(), schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+        let data = cursor.data();
+
+        let dictionary_type = ArrowDataType::Dictionary(
+            Box::new(ArrowDataType::Int32),
+            Box::new(ArrowDataType::Utf8),
+        );
+        let requested = Arc::new(Schema::new(vec![
+            Field::new("a", ArrowDataType::Int64, false),
+            Field::new("b", dictionary_type.clone(), true),
+        ]));
+        let reader =
+            ParquetRecordBatchReaderBuilder::try_new(SliceableCursor::new(data.clone()))
+                .unwrap()
+                .with_schema(requested.clone())
+                .build()
+                .unwrap();
+        assert_eq!(reader.schema(), requested);
+
+        let batches = reader.collect::<arrow::error::Result<Vec<_>>>().unwrap();
+        assert_eq!(1, batches.len());
+        assert_eq!(batches[0].schema(), requested);
+        let a = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(a, &Int64Array::from(vec![1, 2, 3]));
+        let b = batches[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<DictionaryArray<arrow::datatypes::Int32Type>>()
+            .unwrap();
+        assert_eq!(b.values().len(), 1);
+        assert_eq!(b.keys(), &Int32Array::from(vec![Some(0), None, Some(0)]));
+
+        // the fields must match the projected columns and be castable
+        let invalid_schemas = vec![
+            Schema::new(vec![Field::new("a", ArrowDataType::Int64, false)]),
+            Schema::new(vec![
+                Field::new("b", ArrowDataType::Utf8, true),
+                Field::new("a", ArrowDataType::Int32, false),
+            ]),
+            Schema::new(vec![
+                Field::new("a", ArrowDataType::Int32, false),
+                Field::new("b", ArrowDataType::Boolean, true),
+            ]),
+        ];
+        for schema in invalid_schemas {
+            let result = ParquetRecordBatchReaderBuilder::try_new(SliceableCursor::new(
+                data.clone(),
+            ))
+            .unwrap()
+            .with_schema(Arc::new(schema))
+            .build();
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn test_arrow_reader_bloom_filter() {
+        use crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+        use crate::arrow::ArrowWriter;
+        use crate::errors::ParquetError;
+        use crate::file::properties::WriterProperties;
+        use crate::schema::types::ColumnPath;
+        use crate::util::cursor::{InMemoryWriteableCursor, SliceableCursor};
+        use arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
+        use arrow::record_batch::RecordBatch;
+
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", ArrowDataType::Int32, false),
+            Field::new("b", ArrowDataType::Utf8, true),
+        ]));
+        let props = WriterProperties::builder()
+            .set_column_bloom_filter_enabled(ColumnPath::from("b"), true)
+            .set_bloom_filter_ndv(10)
+            .build();
+
+        // write a row group for each of rows 0..4, 4..8 and 8..10
+        let cursor = InMemoryWriteableCursor::default();
+        let mut writer =
+            ArrowWriter::try_new(cursor.clone(), schema.clone(), Some(props)).unwrap();
+        for rows in vec![0..4, 4..8, 8..10] {
+            let a = Int32Array::from(rows.clone().collect::<Vec<_>>());
+            let b = StringArray::from_iter_values(rows.map(|i| i.to_string()));
+            let batch =
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(a), Arc::new(b)])
+                    .unwrap();
+            writer.write(&batch).unwrap();
+        }
+        writer.close().unwrap();
+        let data = cursor.data();
+
+        let new_builder = || {
+            ParquetRecordBatchReaderBuilder::try_new(SliceableCursor::new(data.clone()))
+                .unwrap()
+        };
+        let builder = new_builder();
+
+        // column a has no bloom filter
+        assert!(builder
+            .get_row_group_column_bloom_filter(0, 0)
+            .unwrap()
+            .is_none());
+        let err = builder.get_row_group_column_bloom_filter(3, 0).unwrap_err();
+        assert_eq!(ParquetError::IndexOutOfBound(3, 3), err);
+
+        // row groups without a bloom filter are kept
+        let builder = builder.with_bloom_filter_pruning(0, &5_i32).unwrap();
+        assert_eq!(builder.row_groups, Some(vec![0, 1, 2]));
+
+        // only the previously selected row groups are checked
+        let builder = new_builder()
+            .with_row_groups(vec![0, 2])
+            .with_bloom_filter_pruning(1, "5")
+            .unwrap();
+        assert_eq!(builder.row_groups, Some(vec![]));
+
+        // prune the row groups that cannot contain "5" in column b
+        let builder = new_builder().with_bloom_filter_pruning(1, "5").unwrap();
+        assert_eq!(builder.row_groups, Some(vec![1]));
+
+        let batches = builder
+            .build()
+            .unwrap()
+            .collect::<arrow::error::Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(1, batches.len());
+        let b = batches[0]
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(b.value(1), "5");
+    }
+
+    #[test]
+    fn test_arrow_reader_row_selection() {
+        use crate::arrow::arrow_reader::{
+            ArrowPredicateFn, ParquetRecordBatchReaderBuilder, RowFilter, RowSelection,
+            RowSelector,
+        };
+        use crate::arrow::ArrowWriter;
+        use crate::util::cursor::{InMemoryWriteableCursor, SliceableCursor};
+        use arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
+        use arrow::record_batch::RecordBatch;
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            ArrowDataType::Int32,
+            false,
+        )]));
+
+        // write a row group for each of rows 0..4, 4..8 and 8..10
+        let cursor = InMemoryWriteableCursor::default();
+        let mut writer =
+            ArrowWriter::try_new(cursor.clone(), schema.clone(), None).unwrap();
+        for rows in vec![0..4, 4..8, 8..10] {
+            let a = Int32Array::from(rows.collect::<Vec<_>>());
+            let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a)]).unwrap();
+            writer.write(&batch).unwrap();
+        }
+        writer.close().unwrap();
+        let data = cursor.data();
+
+        let read = |selection: RowSelection, filter: Option<RowFilter>| {
+            let mut builder = ParquetRecordBatchReaderBuilder::try_new(
+                SliceableCursor::new(data.clone()),
+            )
+            .unwrap()
+            .with_batch_size(3)
+            .with_row_selection(selection);
+            if let Some(filter) = filter {
+                builder = builder.with_row_filter(filter);
+            }
+            builder
+                .build()
+                .unwrap()
+                .map(|batch| {
+                    let batch = batch.unwrap();
+                    let a = batch
+                        .column(0)
+                        .as_any()
+                        .downcast_ref::<Int32Array>()
+                        .unwrap();
+                    a.values().to_vec()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // the selection spans row groups and does not cover the last row
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(2),
+            RowSelector::select(3),
+            RowSelector::skip(4),
+        ]);
+        assert_eq!(read(selection.clone(), None), vec![vec![2, 3, 4], vec![9]]);
+
+        // the second row group is not selected at all
+        let skip_row_group = RowSelection::from(vec![
+            RowSelector::skip(3),
+            RowSelector::select(1),
+            RowSelector::skip(4),
+            RowSelector::select(1),
+        ]);
+        assert_eq!(read(skip_row_group, None), vec![vec![3, 8, 9]]);
+
+        // predicates are evaluated on the selected rows
+        let even = ArrowPredicateFn::new(vec![0], |batch| {
+            let a = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            Ok(a.iter().map(|v| v.map(|v| v % 2 == 0)).collect())
+        });
+        let filter = RowFilter::new(vec![Box::new(even)]);
+        assert_eq!(read(selection, Some(filter)), vec![vec![2, 4]]);
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_arrow_reader_parallel_decoding() {
+        use crate::arrow::arrow_reader::{
+            ParquetRecordBatchReaderBuilder, RowSelection, RowSelector,
+        };
+        use crate::arrow::{ArrowWriter, ProjectionMask};
+        use crate::util::cursor::{InMemoryWriteableCursor, SliceableCursor};
+        use arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
+        use arrow::record_batch::RecordBatch;
+
+        let struct_fields = vec![
+            Field::new("d", ArrowDataType::Int64, true),
+            Field::new("e", ArrowDataType::Float64, false),
+        ];
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", ArrowDataType::Int32, false),
+            Field::new("b", ArrowDataType::Utf8, true),
+            Field::new("c", ArrowDataType::Struct(struct_fields.clone()), false),
+        ]));
+
+        // write a row group for each of rows 0..7, 7..20 and 20..25
+        let cursor = InMemoryWriteableCursor::default();
+        let mut writer =
+            ArrowWriter::try_new(cursor.clone(), schema.clone(), None).unwrap();
+        for rows in vec![0..7, 7..20, 20..25] {
+            let a = Int32Array::from_iter_values(rows.clone());
+            let b: StringArray = rows
+                .clone()
+                .map(|i| {
+                    if i % 3 == 0 {
+                        None
+                    } else {
+                        Some(i.to_string())
+                    }
+                })
+                .collect();
+            let d: Int64Array = rows
+                .clone()
+                .map(|i| {
+                    if i % 4 == 0 {
+                        None
+                    } else {
+                        Some(i as i64 * 10)
+                    }
+                })
+                .collect();
+            let e = Float64Array::from_iter_values(rows.map(|i| i as f64 / 2.0));
+            let c = StructArray::from(vec![
+                (struct_fields[0].clone(), Arc::new(d) as ArrayRef),
+                (struct_fields[1].clone(), Arc::new(e) as ArrayRef),
+            ]);
+            let batch = RecordBatch::try_new(
+                schema.clone(),
+                vec![Arc::new(a), Arc::new(b), Arc::new(c)],
+            )
+            .unwrap();
+            writer.write(&batch).unwrap();
+        }
+        writer.close().unwrap();
+        let data = cursor.data();
+
+        let builder = || {
+            ParquetRecordBatchReaderBuilder::try_new(SliceableCursor::new(data.clone()))
+                .unwrap()
+                .with_batch_size(6)
+        };
+        let read = |builder: ParquetRecordBatchReaderBuilder<SliceableCursor>| {
+            builder
+                .build()
+                .unwrap()
+                .map(|batch| batch.unwrap().columns().to_vec())
+                .collect::<Vec<_>>()
+        };
+
+        // batches span the row group boundaries
+        let expected = read(builder());
+        assert_eq!(expected.len(), 5);
+        assert_eq!(read(builder().with_parallel_decoding(true)), expected);
+
+        // projected leaves and a row selection, on a user provided thread pool
+        let pool = Arc::new(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(2)
+                .build()
+                .unwrap(),
+        );
+        let configure = |builder: ParquetRecordBatchReaderBuilder<SliceableCursor>| {
+            let mask = ProjectionMask::leaves(
+                builder.metadata().file_metadata().schema_descr(),
+                [0, 3],
+            );
+            builder
+                .with_projection(mask)
+                .with_row_selection(RowSelection::from(vec![
+                    RowSelector::skip(3),
+                    RowSelector::select(10),
+                    RowSelector::skip(9),
+                    RowSelector::select(2),
+                ]))
+        };
+        let expected = read(configure(builder()));
+        assert_eq!(expected.iter().map(|c| c[0].len()).sum::<usize>(), 12);
+        assert_eq!(read(configure(builder()).with_thread_pool(pool)), expected);
+    }
+
+    #[test]
+    fn test_arrow_reader_limit_offset() {
+        use crate::arrow::arrow_reader::{
+            ArrowPredicateFn, ParquetRecordBatchReaderBuilder, RowFilter, RowSelection,
+            RowSelector,
+        };
+        use crate::arrow::ArrowWriter;
+        use crate::util::cursor::{InMemoryWriteableCursor, SliceableCursor};
+        use arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
+        use arrow::record_batch::RecordBatch;
+
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "a",
+            ArrowDataType::Int32,
+            false,
+        )]));
+
+        // write a row group for each of rows 0..4, 4..8 and 8..10
+        let cursor = InMemoryWriteableCursor::default();
+        let mut writer =
+            ArrowWriter::try_new(cursor.clone(), schema.clone(), None).unwrap();
+        for rows in vec![0..4, 4..8, 8..10] {
+            let a = Int32Array::from(rows.collect::<Vec<_>>());
+            let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a)]).unwrap();
+            writer.write(&batch).unwrap();
+        }
+        writer.close().unwrap();
+        let data = cursor.data();
+
+        let even = || {
+            let predicate = ArrowPredicateFn::new(vec![0], |batch| {
+                let a = batch
+                    .column(0)
+                    .as_any()
+                    .downcast_ref::<Int32Array>()
+                    .unwrap();
+                Ok(a.iter().map(|v| v.map(|v| v % 2 == 0)).collect())
+            });
+            RowFilter::new(vec![Box::new(predicate)])
+        };
+
+        let read = |offset: Option<usize>,
+                    limit: Option<usize>,
+                    selection: Option<RowSelection>,
+                    filter: Option<RowFilter>| {
+            let mut builder = ParquetRecordBatchReaderBuilder::try_new(
+                SliceableCursor::new(data.clone()),
+            )
+            .unwrap()
+            .with_batch_size(3);
+            if let Some(offset) = offset {
+                builder = builder.with_offset(offset);
+            }
+            if let Some(limit) = limit {
+                builder = builder.with_limit(limit);
+            }
+            if let Some(selection) = selection {
+                builder = builder.with_row_selection(selection);
+            }
+            if let Some(filter) = filter {
+                builder = builder.with_row_filter(filter);
+            }
+            builder
+                .build()
+                .unwrap()
+                .map(|batch| {
+                    let batch = batch.unwrap();
+                    let a = batch
+                        .column(0)
+                        .as_any()
+                        .downcast_ref::<Int32Array>()
+                        .unwrap();
+                    a.values().to_vec()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            read(Some(5), None, None, None),
+            vec![vec![5, 6, 7], vec![8, 9]]
+        );
+        assert_eq!(
+            read(None, Some(4), None, None),
+            vec![vec![0, 1, 2], vec![3]]
+        );
+        assert_eq!(
+            read(Some(3), Some(4), None, None),
+            vec![vec![3, 4, 5], vec![6]]
+        );
+        assert_eq!(read(Some(20), None, None, None), Vec::<Vec<i32>>::new());
+        assert_eq!(read(None, Some(0), None, None), Vec::<Vec<i32>>::new());
+
+        // the offset and limit apply to the selected rows
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(2),
+            RowSelector::select(3),
+            RowSelector::skip(4),
+        ]);
+        assert_eq!(
+            read(Some(1), Some(3), Some(selection), None),
+            vec![vec![3, 4, 9]]
+        );
+
+        // and to the rows kept by the filter
+        assert_eq!(
+            read(Some(1), Some(2), None, Some(even())),
+            vec![vec![2], vec![4]]
+        );
+        assert_eq!(read(Some(3), None, None, Some(even())), vec![vec![6, 8]]);
+    }
+
     #[test]
     fn test_bool_single_column_reader_test() {
         let message_type = "
@@ -434,6 +2002,135 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_read_byte_array_decimal() {
+        // Some writers store decimals as variable length, big-endian two's complement
+        let message_type = "
+        message test_schema {
+            REQUIRED BYTE_ARRAY leaf (DECIMAL(20, 2));
+        }
+        ";
+        let expected: Vec<i128> = vec![0, 1, -1, 12_345, -128, 255, i64::MAX as i128 * 7];
+        let values: Vec<ByteArray> = expected
+            .iter()
+            .map(|v| {
+                let bytes = v.to_be_bytes();
+                // trim redundant sign extension bytes, keeping at least one byte
+                let mut start = 0;
+                while start < 15
+                    && ((bytes[start] == 0 && bytes[start + 1] & 0x80 == 0)
+                        || (bytes[start] == 0xFF && bytes[start + 1] & 0x80 != 0))
+                {
+                    start += 1;
+                }
+                ByteArray::from(bytes[start..].to_vec())
+            })
+            .collect();
+
+        let path = get_temp_filename();
+        let schema = parse_message_type(message_type).map(Arc::new).unwrap();
+        generate_single_column_file_with_data::<ByteArrayType>(
+            &[values],
+            path.as_path(),
+            schema,
+        )
+        .unwrap();
+
+        let parquet_reader =
+            SerializedFileReader::try_from(File::open(&path).unwrap()).unwrap();
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(parquet_reader));
+        let mut record_reader = arrow_reader.get_record_reader(32).unwrap();
+
+        let batch = record_reader.next().unwrap().unwrap();
+        let col = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<DecimalArray>()
+            .unwrap();
+
+        assert_eq!(col.precision(), 20);
+        assert_eq!(col.scale(), 2);
+        let actual: Vec<i128> = (0..col.len()).map(|i| col.value(i)).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_read_int96_timestamp_options() {
+        use crate::arrow::arrow_reader::{
+            ArrowReaderOptions, ParquetRecordBatchReaderBuilder,
+        };
+        use crate::data_type::{Int96, Int96Type};
+        use arrow::datatypes::{DataType as ArrowDataType, TimeUnit};
+
+        let message_type = "
+        message test_schema {
+            REQUIRED INT96 leaf;
+        }
+        ";
+        let values = vec![
+            // 1970-01-02T00:00:01.500000123
+            Int96::from(vec![1_500_000_123, 0, 2_440_589]),
+            // 0001-01-01T00:00:00, which overflows a nanosecond timestamp
+            Int96::from(vec![0, 0, 1_721_426]),
+        ];
+
+        let path = get_temp_filename();
+        let schema = parse_message_type(message_type).map(Arc::new).unwrap();
+        generate_single_column_file_with_data::<Int96Type>(
+            &[values],
+            path.as_path(),
+            schema,
+        )
+        .unwrap();
+
+        // nanosecond timestamps without a timezone by default
+        let builder =
+            ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap()).unwrap();
+        assert_eq!(
+            builder.schema().field(0).data_type(),
+            &ArrowDataType::Timestamp(TimeUnit::Nanosecond, None)
+        );
+        let batch = builder.build().unwrap().next().unwrap().unwrap();
+        let col = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(col.value(0), 86_401_500_000_123);
+
+        let options = ArrowReaderOptions::new()
+            .with_int96_timestamp_unit(TimeUnit::Microsecond)
+            .with_int96_timezone(Some("+01:00".to_string()));
+        let builder = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            File::open(&path).unwrap(),
+            options,
+        )
+        .unwrap();
+        let expected_type =
+            ArrowDataType::Timestamp(TimeUnit::Microsecond, Some("+01:00".to_string()));
+        assert_eq!(builder.schema().field(0).data_type(), &expected_type);
+
+        let batch = builder.build().unwrap().next().unwrap().unwrap();
+        assert_eq!(batch.column(0).data_type(), &expected_type);
+        let col = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<TimestampMicrosecondArray>()
+            .unwrap();
+        // the values are the wall clock time an hour ahead of UTC
+        assert_eq!(col.value(0), 82_801_500_000);
+        assert_eq!(col.value(1), -62_135_600_400_000_000);
+
+        let options = ArrowReaderOptions::new()
+            .with_int96_timezone(Some("Europe/Paris".to_string()));
+        let err = ParquetRecordBatchReaderBuilder::try_new_with_options(
+            File::open(&path).unwrap(),
+            options,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Unsupported time zone"));
+    }
+
     /// Parameters for single_column_reader_test
     #[derive(Debug)]
     struct TestOptions {
@@ -20,11 +20,14 @@
 use std::sync::Arc;
 
 use arrow::array as arrow_array;
-use arrow::datatypes::{DataType as ArrowDataType, IntervalUnit, SchemaRef};
+use arrow::datatypes::{
+    ArrowDictionaryKeyType, ArrowNativeType, DataType as ArrowDataType, IntervalUnit,
+    SchemaRef,
+};
 use arrow::record_batch::RecordBatch;
 use arrow_array::Array;
 
-use super::levels::LevelInfo;
+use super::levels::{calculate_array_levels, LevelInfo};
 use super::schema::{
     add_encoded_arrow_schema_to_metadata, decimal_length_from_precision,
 };
@@ -39,14 +42,34 @@ use crate::{
 
 /// Arrow writer
 ///
-/// Writes Arrow `RecordBatch`es to a Parquet writer
+/// Writes Arrow `RecordBatch`es to a Parquet writer.
+///
+/// By default each record batch is written as soon as it is received, as one row
+/// group or, if it has more than `max_row_group_size` rows, as several row groups.
+/// If a target row group size (`max_row_group_bytes`) or a buffered memory limit
+/// (`max_buffered_memory`) is set in the [`WriterProperties`], the rows of the
+/// record batches are instead buffered and written as a row group once the limits
+/// are reached. The size of the buffered rows is estimated from the in-memory size
+/// of the record batches they come from, see [`ArrowWriter::in_progress_size`].
 pub struct ArrowWriter<W: ParquetWriter> {
     /// Underlying Parquet writer
     writer: SerializedFileWriter<W>,
+    /// The arrays of each column buffered until they are written as a row group
+    buffer: Vec<Vec<arrow_array::ArrayRef>>,
+    /// The number of buffered rows
+    buffered_rows: usize,
+    /// The estimated size in bytes of the buffered rows
+    buffered_bytes: usize,
     /// A copy of the Arrow schema.
     ///
     /// The schema is used to verify that each record batch written has the correct schema
     arrow_schema: SchemaRef,
+    /// The max number of rows in a row group
+    max_row_group_size: usize,
+    /// The target size in bytes of a row group
+    max_row_group_bytes: Option<usize>,
+    /// The max size in bytes of the buffered rows
+    max_buffered_memory: Option<usize>,
 }
 
 impl<W: 'static + ParquetWriter> ArrowWriter<W> {
@@ -65,6 +88,10 @@ impl<W: 'static + ParquetWriter> ArrowWriter<W> {
         let mut props = props.unwrap_or_else(|| WriterProperties::builder().build());
         add_encoded_arrow_schema_to_metadata(&arrow_schema, &mut props);
 
+        let max_row_group_size = props.max_row_group_size().max(1);
+        let max_row_group_bytes = props.max_row_group_bytes();
+        let max_buffered_memory = props.max_buffered_memory();
+
         let file_writer = SerializedFileWriter::new(
             writer.try_clone()?,
             schema.root_schema_ptr(),
@@ -73,12 +100,22 @@ impl<W: 'static + ParquetWriter> ArrowWriter<W> {
 
         Ok(Self {
             writer: file_writer,
+            buffer: vec![Vec::new(); arrow_schema.fields().len()],
+            buffered_rows: 0,
+            buffered_bytes: 0,
             arrow_schema,
+            max_row_group_size,
+            max_row_group_bytes,
+            max_buffered_memory,
         })
     }
 
     /// Write a RecordBatch to writer
     ///
+    /// The rows of the batch are written as row groups of at most
+    /// `max_row_group_size` rows, either immediately or, if buffering is enabled in
+    /// the writer properties, once enough rows have been buffered.
+    ///
     /// *NOTE:* The writer currently does not support all Arrow data types
     pub fn write(&mut self, batch: &RecordBatch) -> Result<()> {
         // validate batch schema against writer's supplied schema
@@ -87,23 +124,110 @@ impl<W: 'static + ParquetWriter> ArrowWriter<W> {
                 "Record batch schema does not match writer schema".to_string(),
             ));
         }
-        // compute the definition and repetition levels of the batch
-        let batch_level = LevelInfo::new_from_batch(batch);
+
+        // estimate the size of a row from the in-memory size of the batch
+        let num_rows = batch.num_rows();
+        let row_bytes = if num_rows == 0 {
+            0
+        } else {
+            let batch_bytes = batch
+                .columns()
+                .iter()
+                .map(|array| array.get_array_memory_size())
+                .sum::<usize>();
+            (batch_bytes + num_rows - 1) / num_rows
+        };
+
+        let mut offset = 0;
+        loop {
+            let mut length = (num_rows - offset)
+                .min(self.max_row_group_size.saturating_sub(self.buffered_rows));
+            if let Some(max_row_group_bytes) = self.max_row_group_bytes {
+                if row_bytes > 0 {
+                    let remaining_bytes =
+                        max_row_group_bytes.saturating_sub(self.buffered_bytes);
+                    let remaining_rows = remaining_bytes / row_bytes
+                        + usize::from(remaining_bytes % row_bytes != 0);
+                    length = length.min(remaining_rows.max(1));
+                }
+            }
+            if length > 0 || !self.is_buffering() {
+                for (arrays, array) in self.buffer.iter_mut().zip(batch.columns()) {
+                    arrays.push(array.slice(offset, length));
+                }
+                self.buffered_rows += length;
+                self.buffered_bytes += length * row_bytes;
+                offset += length;
+            }
+            if offset == num_rows {
+                break;
+            }
+            // the row group is full
+            self.flush()?;
+        }
+
+        let row_group_full = self.buffered_rows >= self.max_row_group_size
+            || self
+                .max_row_group_bytes
+                .map_or(false, |max| self.buffered_bytes >= max);
+        let memory_exceeded = self
+            .max_buffered_memory
+            .map_or(false, |max| self.buffered_bytes > max);
+        if !self.is_buffering() || row_group_full || memory_exceeded {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Writes the buffered rows, if any, as a row group.
+    pub fn flush(&mut self) -> Result<()> {
+        if self.buffer.iter().all(|arrays| arrays.is_empty()) {
+            return Ok(());
+        }
+
         let mut row_group_writer = self.writer.next_row_group()?;
-        for (array, field) in batch.columns().iter().zip(batch.schema().fields()) {
-            let mut levels = batch_level.calculate_array_levels(array, field);
-            // Reverse levels as we pop() them when writing arrays
-            levels.reverse();
-            write_leaves(&mut row_group_writer, array, &mut levels)?;
+        for (arrays, field) in self.buffer.iter_mut().zip(self.arrow_schema.fields()) {
+            let arrays = std::mem::take(arrays);
+            // compute the definition and repetition levels of the column's leaves
+            let mut levels = arrays
+                .iter()
+                .map(|array| {
+                    let mut levels = calculate_array_levels(array, field)?;
+                    // Reverse levels as we pop() them when writing arrays
+                    levels.reverse();
+                    Ok(levels)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            write_leaves(&mut row_group_writer, &arrays, &mut levels)?;
         }
+        self.buffered_rows = 0;
+        self.buffered_bytes = 0;
 
         self.writer.close_row_group(row_group_writer)
     }
 
-    /// Close and finalize the underlying Parquet writer
+    /// Returns the estimated size in bytes of the rows buffered and not yet
+    /// written as a row group.
+    pub fn in_progress_size(&self) -> usize {
+        self.buffered_bytes
+    }
+
+    /// Returns the number of rows buffered and not yet written as a row group.
+    pub fn in_progress_rows(&self) -> usize {
+        self.buffered_rows
+    }
+
+    /// Close and finalize the underlying Parquet writer, writing the buffered rows
+    /// first
     pub fn close(&mut self) -> Result<parquet_format::FileMetaData> {
+        self.flush()?;
         self.writer.close()
     }
+
+    /// Returns `true` if rows are buffered across record batches.
+    fn is_buffering(&self) -> bool {
+        self.max_row_group_bytes.is_some() || self.max_buffered_memory.is_some()
+    }
 }
 
 /// Convenience method to get the next ColumnWriter from the RowGroupWriter
@@ -118,13 +242,16 @@ fn get_col_writer(
     Ok(col_writer)
 }
 
+/// Writes the leaves of `arrays`, which are parts of the same column, along with the
+/// levels of each array.
 #[allow(clippy::borrowed_box)]
 fn write_leaves(
     mut row_group_writer: &mut Box<dyn RowGroupWriter>,
-    array: &arrow_array::ArrayRef,
-    mut levels: &mut Vec<LevelInfo>,
+    arrays: &[arrow_array::ArrayRef],
+    levels: &mut [Vec<LevelInfo>],
 ) -> Result<()> {
-    match array.data_type() {
+    let data_type = arrays.first().expect("No arrays to write").data_type();
+    match data_type {
         ArrowDataType::Null
         | ArrowDataType::Boolean
         | ArrowDataType::Int8
@@ -151,52 +278,88 @@ fn write_leaves(
         | ArrowDataType::Decimal(_, _)
         | ArrowDataType::FixedSizeBinary(_) => {
             let mut col_writer = get_col_writer(&mut row_group_writer)?;
-            write_leaf(
-                &mut col_writer,
-                array,
-                levels.pop().expect("Levels exhausted"),
-            )?;
+            for (array, levels) in arrays.iter().zip(levels.iter_mut()) {
+                write_leaf(
+                    &mut col_writer,
+                    array,
+                    levels.pop().expect("Levels exhausted"),
+                )?;
+            }
             row_group_writer.close_column(col_writer)?;
             Ok(())
         }
-        ArrowDataType::List(_) | ArrowDataType::LargeList(_) => {
-            // write the child list
-            let data = array.data();
-            let child_array = arrow_array::make_array(data.child_data()[0].clone());
-            write_leaves(&mut row_group_writer, &child_array, &mut levels)?;
+        ArrowDataType::List(_)
+        | ArrowDataType::LargeList(_)
+        | ArrowDataType::FixedSizeList(_, _)
+        | ArrowDataType::Map(_, _) => {
+            // write the child lists, or the key-value entries of a map
+            let child_arrays = arrays
+                .iter()
+                .map(|array| arrow_array::make_array(array.data().child_data()[0].clone()))
+                .collect::<Vec<_>>();
+            write_leaves(&mut row_group_writer, &child_arrays, levels)?;
             Ok(())
         }
-        ArrowDataType::Struct(_) => {
-            let struct_array: &arrow_array::StructArray = array
-                .as_any()
-                .downcast_ref::<arrow_array::StructArray>()
-                .expect("Unable to get struct array");
-            for field in struct_array.columns() {
-                write_leaves(&mut row_group_writer, field, &mut levels)?;
+        ArrowDataType::Struct(fields) => {
+            let struct_arrays = arrays
+                .iter()
+                .map(|array| {
+                    array
+                        .as_any()
+                        .downcast_ref::<arrow_array::StructArray>()
+                        .expect("Unable to get struct array")
+                })
+                .collect::<Vec<_>>();
+            for i in 0..fields.len() {
+                let field_arrays = struct_arrays
+                    .iter()
+                    .map(|array| array.column(i).clone())
+                    .collect::<Vec<_>>();
+                write_leaves(&mut row_group_writer, &field_arrays, levels)?;
             }
             Ok(())
         }
-        ArrowDataType::Dictionary(_, value_type) => {
-            // cast dictionary to a primitive
-            let array = arrow::compute::cast(array, value_type)?;
-
+        ArrowDataType::Dictionary(key_type, value_type) => {
             let mut col_writer = get_col_writer(&mut row_group_writer)?;
-            write_leaf(
-                &mut col_writer,
-                &array,
-                levels.pop().expect("Levels exhausted"),
-            )?;
+            for (array, levels) in arrays.iter().zip(levels.iter_mut()) {
+                let levels = levels.pop().expect("Levels exhausted");
+                match (&mut col_writer, value_type.as_ref()) {
+                    (
+                        ColumnWriter::ByteArrayColumnWriter(typed),
+                        ArrowDataType::Utf8
+                        | ArrowDataType::LargeUtf8
+                        | ArrowDataType::Binary
+                        | ArrowDataType::LargeBinary,
+                    ) => {
+                        // write the keys and values of the dictionary without
+                        // expanding it
+                        let keys = get_dictionary_keys(array, key_type, &levels)?;
+                        let dictionary = get_dictionary_values(array)?;
+                        typed.write_batch_dictionary(
+                            dictionary.as_slice(),
+                            keys.as_slice(),
+                            levels.definition.as_deref(),
+                            levels.repetition.as_deref(),
+                        )?;
+                    }
+                    _ => {
+                        // cast dictionary to a primitive
+                        let array = arrow::compute::cast(array, value_type)?;
+                        write_leaf(&mut col_writer, &array, levels)?;
+                    }
+                }
+            }
             row_group_writer.close_column(col_writer)?;
             Ok(())
         }
         ArrowDataType::Float16 => Err(ParquetError::ArrowError(
             "Float16 arrays not supported".to_string(),
         )),
-        ArrowDataType::FixedSizeList(_, _) | ArrowDataType::Union(_) => {
+        ArrowDataType::Union(_) => {
             Err(ParquetError::NYI(
                 format!(
                     "Attempting to write an Arrow type {:?} to parquet that is not yet implemented", 
-                    array.data_type()
+                    data_type
                 )
             ))
         }
@@ -208,7 +371,7 @@ fn write_leaf(
     column: &arrow_array::ArrayRef,
     levels: LevelInfo,
 ) -> Result<i64> {
-    let indices = levels.filter_array_indices();
+    let indices = levels.non_null_indices();
     let written = match writer {
         ColumnWriter::Int32ColumnWriter(ref mut typed) => {
             let values = match column.data_type() {
@@ -239,6 +402,14 @@ fn write_leaf(
                     );
                     get_numeric_array_slice::<Int32Type, _>(&array, &indices)
                 }
+                ArrowDataType::Decimal(_, _) => {
+                    // decimals with a precision of at most 9 are stored as INT32
+                    let array = column
+                        .as_any()
+                        .downcast_ref::<arrow_array::DecimalArray>()
+                        .expect("Unable to get decimal array");
+                    indices.iter().map(|i| array.value(*i) as i32).collect()
+                }
                 _ => {
                     let array = arrow::compute::cast(column, &ArrowDataType::Int32)?;
                     let array = array
@@ -250,7 +421,7 @@ fn write_leaf(
             };
             typed.write_batch(
                 values.as_slice(),
-                Some(levels.definition.as_slice()),
+                levels.definition.as_deref(),
                 levels.repetition.as_deref(),
             )?
         }
@@ -261,7 +432,7 @@ fn write_leaf(
                 .expect("Unable to get boolean array");
             typed.write_batch(
                 get_bool_array_slice(&array, &indices).as_slice(),
-                Some(levels.definition.as_slice()),
+                levels.definition.as_deref(),
                 levels.repetition.as_deref(),
             )?
         }
@@ -287,6 +458,14 @@ fn write_leaf(
                     );
                     get_numeric_array_slice::<Int64Type, _>(&array, &indices)
                 }
+                ArrowDataType::Decimal(_, _) => {
+                    // decimals with a precision of at most 18 are stored as INT64
+                    let array = column
+                        .as_any()
+                        .downcast_ref::<arrow_array::DecimalArray>()
+                        .expect("Unable to get decimal array");
+                    indices.iter().map(|i| array.value(*i) as i64).collect()
+                }
                 _ => {
                     let array = arrow::compute::cast(column, &ArrowDataType::Int64)?;
                     let array = array
@@ -298,7 +477,7 @@ fn write_leaf(
             };
             typed.write_batch(
                 values.as_slice(),
-                Some(levels.definition.as_slice()),
+                levels.definition.as_deref(),
                 levels.repetition.as_deref(),
             )?
         }
@@ -312,7 +491,7 @@ fn write_leaf(
                 .expect("Unable to get Float32 array");
             typed.write_batch(
                 get_numeric_array_slice::<FloatType, _>(&array, &indices).as_slice(),
-                Some(levels.definition.as_slice()),
+                levels.definition.as_deref(),
                 levels.repetition.as_deref(),
             )?
         }
@@ -323,7 +502,7 @@ fn write_leaf(
                 .expect("Unable to get Float64 array");
             typed.write_batch(
                 get_numeric_array_slice::<DoubleType, _>(&array, &indices).as_slice(),
-                Some(levels.definition.as_slice()),
+                levels.definition.as_deref(),
                 levels.repetition.as_deref(),
             )?
         }
@@ -334,8 +513,8 @@ fn write_leaf(
                     .downcast_ref::<arrow_array::BinaryArray>()
                     .expect("Unable to get BinaryArray array");
                 typed.write_batch(
-                    get_binary_array(&array).as_slice(),
-                    Some(levels.definition.as_slice()),
+                    get_binary_array(&array, &indices).as_slice(),
+                    levels.definition.as_deref(),
                     levels.repetition.as_deref(),
                 )?
             }
@@ -345,8 +524,8 @@ fn write_leaf(
                     .downcast_ref::<arrow_array::StringArray>()
                     .expect("Unable to get LargeBinaryArray array");
                 typed.write_batch(
-                    get_string_array(&array).as_slice(),
-                    Some(levels.definition.as_slice()),
+                    get_string_array(&array, &indices).as_slice(),
+                    levels.definition.as_deref(),
                     levels.repetition.as_deref(),
                 )?
             }
@@ -356,8 +535,8 @@ fn write_leaf(
                     .downcast_ref::<arrow_array::LargeBinaryArray>()
                     .expect("Unable to get LargeBinaryArray array");
                 typed.write_batch(
-                    get_large_binary_array(&array).as_slice(),
-                    Some(levels.definition.as_slice()),
+                    get_large_binary_array(&array, &indices).as_slice(),
+                    levels.definition.as_deref(),
                     levels.repetition.as_deref(),
                 )?
             }
@@ -367,8 +546,8 @@ fn write_leaf(
                     .downcast_ref::<arrow_array::LargeStringArray>()
                     .expect("Unable to get LargeUtf8 array");
                 typed.write_batch(
-                    get_large_string_array(&array).as_slice(),
-                    Some(levels.definition.as_slice()),
+                    get_large_string_array(&array, &indices).as_slice(),
+                    levels.definition.as_deref(),
                     levels.repetition.as_deref(),
                 )?
             }
@@ -415,7 +594,7 @@ fn write_leaf(
             };
             typed.write_batch(
                 bytes.as_slice(),
-                Some(levels.definition.as_slice()),
+                levels.definition.as_deref(),
                 levels.repetition.as_deref(),
             )?
         }
@@ -425,14 +604,11 @@ fn write_leaf(
 
 macro_rules! def_get_binary_array_fn {
     ($name:ident, $ty:ty) => {
-        fn $name(array: &$ty) -> Vec<ByteArray> {
-            let mut values = Vec::with_capacity(array.len() - array.null_count());
-            for i in 0..array.len() {
-                if array.is_valid(i) {
-                    let bytes: Vec<u8> = array.value(i).into();
-                    let bytes = ByteArray::from(bytes);
-                    values.push(bytes);
-                }
+        fn $name(array: &$ty, indices: &[usize]) -> Vec<ByteArray> {
+            let mut values = Vec::with_capacity(indices.len());
+            for i in indices {
+                let bytes: Vec<u8> = array.value(*i).into();
+                values.push(ByteArray::from(bytes));
             }
             values
         }
@@ -444,6 +620,114 @@ def_get_binary_array_fn!(get_string_array, arrow_array::StringArray);
 def_get_binary_array_fn!(get_large_binary_array, arrow_array::LargeBinaryArray);
 def_get_binary_array_fn!(get_large_string_array, arrow_array::LargeStringArray);
 
+/// Get the keys of the non-null values of a dictionary array
+fn get_dictionary_keys(
+    array: &arrow_array::ArrayRef,
+    key_type: &ArrowDataType,
+    levels: &LevelInfo,
+) -> Result<Vec<usize>> {
+    fn keys<K: ArrowDictionaryKeyType>(
+        array: &arrow_array::ArrayRef,
+        indices: &[usize],
+    ) -> Result<Vec<usize>> {
+        let keys = array
+            .as_any()
+            .downcast_ref::<arrow_array::DictionaryArray<K>>()
+            .expect("Unable to get dictionary array")
+            .keys();
+        indices
+            .iter()
+            .map(|i| {
+                keys.value(*i).to_usize().ok_or_else(|| {
+                    ParquetError::ArrowError(format!(
+                        "Invalid dictionary key {:?}",
+                        keys.value(*i)
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    let indices = levels.non_null_indices();
+    match key_type {
+        ArrowDataType::Int8 => keys::<arrow::datatypes::Int8Type>(array, indices),
+        ArrowDataType::Int16 => keys::<arrow::datatypes::Int16Type>(array, indices),
+        ArrowDataType::Int32 => keys::<arrow::datatypes::Int32Type>(array, indices),
+        ArrowDataType::Int64 => keys::<arrow::datatypes::Int64Type>(array, indices),
+        ArrowDataType::UInt8 => keys::<arrow::datatypes::UInt8Type>(array, indices),
+        ArrowDataType::UInt16 => keys::<arrow::datatypes::UInt16Type>(array, indices),
+        ArrowDataType::UInt32 => keys::<arrow::datatypes::UInt32Type>(array, indices),
+        ArrowDataType::UInt64 => keys::<arrow::datatypes::UInt64Type>(array, indices),
+        _ => Err(ParquetError::ArrowError(format!(
+            "Unsupported dictionary key type {:?}",
+            key_type
+        ))),
+    }
+}
+
+macro_rules! def_get_binary_dictionary_fn {
+    ($name:ident, $ty:ty) => {
+        fn $name(array: &$ty) -> Vec<ByteArray> {
+            (0..array.len())
+                .map(|i| {
+                    // a null value is written as empty, as it is only referenced by
+                    // keys that are null themselves
+                    let bytes: Vec<u8> = if array.is_valid(i) {
+                        array.value(i).into()
+                    } else {
+                        vec![]
+                    };
+                    ByteArray::from(bytes)
+                })
+                .collect()
+        }
+    };
+}
+
+def_get_binary_dictionary_fn!(get_binary_dictionary, arrow_array::BinaryArray);
+def_get_binary_dictionary_fn!(get_string_dictionary, arrow_array::StringArray);
+def_get_binary_dictionary_fn!(get_large_binary_dictionary, arrow_array::LargeBinaryArray);
+def_get_binary_dictionary_fn!(get_large_string_dictionary, arrow_array::LargeStringArray);
+
+/// Get every value of the dictionary of a dictionary array of binary or string
+/// values, including those that are not referenced by any key
+fn get_dictionary_values(array: &arrow_array::ArrayRef) -> Result<Vec<ByteArray>> {
+    let values = arrow_array::make_array(array.data().child_data()[0].clone());
+    let values = match values.data_type() {
+        ArrowDataType::Binary => get_binary_dictionary(
+            values
+                .as_any()
+                .downcast_ref::<arrow_array::BinaryArray>()
+                .expect("Unable to get BinaryArray array"),
+        ),
+        ArrowDataType::Utf8 => get_string_dictionary(
+            values
+                .as_any()
+                .downcast_ref::<arrow_array::StringArray>()
+                .expect("Unable to get StringArray array"),
+        ),
+        ArrowDataType::LargeBinary => get_large_binary_dictionary(
+            values
+                .as_any()
+                .downcast_ref::<arrow_array::LargeBinaryArray>()
+                .expect("Unable to get LargeBinaryArray array"),
+        ),
+        ArrowDataType::LargeUtf8 => get_large_string_dictionary(
+            values
+                .as_any()
+                .downcast_ref::<arrow_array::LargeStringArray>()
+                .expect("Unable to get LargeStringArray array"),
+        ),
+        value_type => {
+            return Err(ParquetError::ArrowError(format!(
+                "Unsupported dictionary value type {:?}",
+                value_type
+            )))
+        }
+    };
+    Ok(values)
+}
+
 /// Get the underlying numeric array slice, skipping any null values.
 /// If there are no null values, it might be quicker to get the slice directly instead of
 /// calling this function.
@@ -546,11 +830,13 @@ mod tests {
     use arrow::{array::*, buffer::Buffer};
 
     use crate::arrow::{ArrowReader, ParquetFileArrowReader};
+    use crate::basic::{Encoding, LogicalType, Type as PhysicalType};
     use crate::file::{
         reader::{FileReader, SerializedFileReader},
         statistics::Statistics,
         writer::InMemoryWriteableCursor,
     };
+    use crate::schema::types::ColumnPath;
     use crate::util::test_common::get_temp_file;
 
     #[test]
@@ -625,6 +911,157 @@ mod tests {
         }
     }
 
+    /// Returns a batch of 10 rows whose arrays have the same size for any `offset`
+    fn row_group_limits_batch(offset: i32) -> RecordBatch {
+        let a = Int32Array::from_iter_values(offset..offset + 10);
+        let b = ListArray::from_iter_primitive::<arrow::datatypes::Int32Type, _, _>(
+            (0..10).map(|i| {
+                if i % 3 == 0 {
+                    None
+                } else {
+                    Some(vec![Some(offset + i), Some(offset + i + 1)])
+                }
+            }),
+        );
+        RecordBatch::try_from_iter(vec![
+            ("a", Arc::new(a) as ArrayRef),
+            ("b", Arc::new(b) as ArrayRef),
+        ])
+        .unwrap()
+    }
+
+    /// Writes `batches` with `props`, checks that their rows are read back, and
+    /// returns the number of rows of each row group
+    fn write_with_row_group_limits(
+        batches: &[RecordBatch],
+        props: WriterProperties,
+    ) -> Vec<i64> {
+        let schema = batches[0].schema();
+        let cursor = InMemoryWriteableCursor::default();
+        {
+            let mut writer =
+                ArrowWriter::try_new(cursor.clone(), schema.clone(), Some(props))
+                    .unwrap();
+            for batch in batches {
+                writer.write(batch).unwrap();
+            }
+            writer.close().unwrap();
+        }
+
+        let buffer = cursor.into_inner().unwrap();
+        let cursor = crate::file::serialized_reader::SliceableCursor::new(buffer);
+        let reader = SerializedFileReader::new(cursor).unwrap();
+        let row_groups = reader
+            .metadata()
+            .row_groups()
+            .iter()
+            .map(|row_group| row_group.num_rows())
+            .collect();
+
+        let num_rows = batches.iter().map(|batch| batch.num_rows()).sum();
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(reader));
+        let actual = arrow_reader
+            .get_record_reader(num_rows)
+            .unwrap()
+            .next()
+            .expect("No batch found")
+            .expect("Unable to get batch");
+        assert_eq!(actual.num_rows(), num_rows);
+        for i in 0..schema.fields().len() {
+            let arrays = batches
+                .iter()
+                .map(|batch| batch.column(i).as_ref())
+                .collect::<Vec<_>>();
+            let expected = arrow::compute::concat(&arrays).unwrap();
+            assert_eq!(expected.data(), actual.column(i).data());
+        }
+        row_groups
+    }
+
+    #[test]
+    fn arrow_writer_row_group_limits() {
+        let batches = (0..10)
+            .map(|i| row_group_limits_batch(i * 10))
+            .collect::<Vec<_>>();
+        let batch_bytes = batches[0]
+            .columns()
+            .iter()
+            .map(|array| array.get_array_memory_size())
+            .sum::<usize>();
+        let row_bytes = (batch_bytes + 9) / 10;
+
+        // without buffering, each batch is split by number of rows
+        let props = WriterProperties::builder()
+            .set_max_row_group_size(4)
+            .build();
+        assert_eq!(
+            write_with_row_group_limits(&batches[..2], props),
+            vec![4, 4, 2, 4, 4, 2]
+        );
+
+        // row groups of a target size span several batches
+        let props = WriterProperties::builder()
+            .set_max_row_group_bytes(25 * row_bytes)
+            .build();
+        assert_eq!(
+            write_with_row_group_limits(&batches, props),
+            vec![25, 25, 25, 25]
+        );
+
+        // the max number of rows still applies
+        let props = WriterProperties::builder()
+            .set_max_row_group_bytes(100 * row_bytes)
+            .set_max_row_group_size(15)
+            .build();
+        assert_eq!(
+            write_with_row_group_limits(&batches[..4], props),
+            vec![15, 15, 10]
+        );
+
+        // the buffered rows are written once they exceed the memory limit
+        let props = WriterProperties::builder()
+            .set_max_buffered_memory(15 * row_bytes)
+            .build();
+        assert_eq!(
+            write_with_row_group_limits(&batches[..3], props),
+            vec![20, 10]
+        );
+    }
+
+    #[test]
+    fn arrow_writer_in_progress_size() {
+        let batch = row_group_limits_batch(0);
+        let props = WriterProperties::builder()
+            .set_max_row_group_bytes(usize::MAX)
+            .build();
+        let cursor = InMemoryWriteableCursor::default();
+        let mut writer =
+            ArrowWriter::try_new(cursor.clone(), batch.schema(), Some(props)).unwrap();
+        assert_eq!(writer.in_progress_rows(), 0);
+        assert_eq!(writer.in_progress_size(), 0);
+
+        writer.write(&batch).unwrap();
+        writer.write(&batch).unwrap();
+        assert_eq!(writer.in_progress_rows(), 20);
+        let batch_bytes = batch
+            .columns()
+            .iter()
+            .map(|array| array.get_array_memory_size())
+            .sum::<usize>();
+        assert_eq!(writer.in_progress_size(), 2 * ((batch_bytes + 9) / 10) * 10);
+
+        writer.flush().unwrap();
+        assert_eq!(writer.in_progress_rows(), 0);
+        assert_eq!(writer.in_progress_size(), 0);
+        writer.close().unwrap();
+
+        let buffer = cursor.data();
+        let cursor = crate::file::serialized_reader::SliceableCursor::new(buffer);
+        let reader = SerializedFileReader::new(cursor).unwrap();
+        assert_eq!(reader.metadata().num_row_groups(), 1);
+        assert_eq!(reader.metadata().row_group(0).num_rows(), 20);
+    }
+
     #[test]
     fn arrow_writer_non_null() {
         // define schema
@@ -686,6 +1123,81 @@ mod tests {
         writer.close().unwrap();
     }
 
+    #[test]
+    fn arrow_writer_fixed_size_list() {
+        // [[1, 2], null, [3, null], [4, 5]]
+        let field = Field::new(
+            "a",
+            DataType::FixedSizeList(
+                Box::new(Field::new("item", DataType::Int32, true)),
+                2,
+            ),
+            true,
+        );
+        let values = Int32Array::from(vec![
+            Some(1),
+            Some(2),
+            None,
+            None,
+            Some(3),
+            None,
+            Some(4),
+            Some(5),
+        ]);
+        let list_data = ArrayData::builder(field.data_type().clone())
+            .len(4)
+            .add_child_data(values.data().clone())
+            .null_bit_buffer(Buffer::from(vec![0b00001101]))
+            .build();
+        let a = FixedSizeListArray::from(list_data);
+
+        let schema = Arc::new(Schema::new(vec![field]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(a)]).unwrap();
+        roundtrip("test_arrow_writer_fixed_size_list.parquet", batch);
+    }
+
+    #[test]
+    fn arrow_writer_map() {
+        // [{a: 1, b: null}, null, {}, {c: 3}]
+        let key_field = Field::new("key", DataType::Utf8, false);
+        let value_field = Field::new("value", DataType::Int32, true);
+        let field = Field::new(
+            "a",
+            DataType::Map(
+                Box::new(Field::new(
+                    "key_value",
+                    DataType::Struct(vec![key_field.clone(), value_field.clone()]),
+                    false,
+                )),
+                false,
+            ),
+            true,
+        );
+
+        let keys: ArrayRef = Arc::new(StringArray::from(vec!["a", "b", "c"]));
+        let values: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+        let entries = StructArray::from(vec![(key_field, keys), (value_field, values)]);
+        let map_data = ArrayData::builder(field.data_type().clone())
+            .len(4)
+            .add_buffer(Buffer::from([0i32, 2, 2, 2, 3].to_byte_slice()))
+            .add_child_data(entries.data().clone())
+            .null_bit_buffer(Buffer::from(vec![0b00001101]))
+            .build();
+        let a = MapArray::from(map_data);
+
+        let schema = Arc::new(Schema::new(vec![field]));
+        let batch = RecordBatch::try_new(schema, vec![Arc::new(a)]).unwrap();
+        let file = roundtrip("test_arrow_writer_map.parquet", batch);
+
+        // the map is written with the MAP logical type
+        let reader = SerializedFileReader::new(file).unwrap();
+        let schema = reader.metadata().file_metadata().schema();
+        assert_eq!(
+            schema.get_fields()[0].get_basic_info().logical_type(),
+            Some(LogicalType::MAP(Default::default()))
+        );
+    }
+
     #[test]
     fn arrow_writer_binary() {
         let string_field = Field::new("a", DataType::Utf8, false);
@@ -747,7 +1259,7 @@ mod tests {
         let decimal_field = Field::new("a", DataType::Decimal(5, 2), false);
         let schema = Schema::new(vec![decimal_field]);
 
-        let mut dec_builder = DecimalBuilder::new(4, 5, 2);
+        let mut dec_builder = DecimalBuilder::with_capacity(4, 5, 2);
         dec_builder.append_value(10_000).unwrap();
         dec_builder.append_value(50_000).unwrap();
         dec_builder.append_value(0).unwrap();
@@ -785,6 +1297,84 @@ mod tests {
         }
     }
 
+    #[test]
+    fn arrow_writer_decimal_physical_types() {
+        let cases = vec![
+            (
+                5,
+                PhysicalType::INT32,
+                vec![Some(99_999), None, Some(-99_999)],
+            ),
+            (
+                12,
+                PhysicalType::INT64,
+                vec![Some(999_999_999_999), Some(0), None],
+            ),
+            (
+                30,
+                PhysicalType::FIXED_LEN_BYTE_ARRAY,
+                vec![None, Some(10_i128.pow(29)), Some(-(10_i128.pow(30) - 1))],
+            ),
+        ];
+
+        for (precision, physical_type, values) in cases {
+            let mut builder = DecimalBuilder::with_capacity(values.len(), precision, 2);
+            for value in &values {
+                match value {
+                    Some(v) => builder.append_value(*v).unwrap(),
+                    None => builder.append_null().unwrap(),
+                }
+            }
+            let file = one_column_roundtrip(
+                &format!("test_arrow_writer_decimal_{}.parquet", precision),
+                Arc::new(builder.finish()),
+                true,
+            );
+
+            let reader = SerializedFileReader::new(file).unwrap();
+            let column = reader.metadata().row_group(0).column(0);
+            assert_eq!(column.column_descr().physical_type(), physical_type);
+        }
+    }
+
+    #[test]
+    fn arrow_writer_sliced() {
+        let mut lists = ListBuilder::new(StringBuilder::default());
+        lists.append_value(vec![Some("a"), Some("bb")]).unwrap();
+        lists.append(false).unwrap();
+        lists.append_value(vec![None, Some("c")]).unwrap();
+        lists.append_value(Vec::<Option<&str>>::new()).unwrap();
+        lists.append_value(vec![Some("dd")]).unwrap();
+        let lists = lists.finish();
+        let strings =
+            StringArray::from(vec![Some("a"), None, Some("b"), Some("cc"), None]);
+        let ints = Int32Array::from(vec![Some(1), None, Some(3), Some(4), Some(5)]);
+        let ints = Arc::new(ints) as ArrayRef;
+        let structs = StructArray::from(vec![(
+            Field::new("ints", DataType::Int32, true),
+            ints.clone(),
+        )]);
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("lists", lists.data_type().clone(), true),
+            Field::new("strings", DataType::Utf8, true),
+            Field::new("ints", DataType::Int32, true),
+            Field::new("structs", structs.data_type().clone(), true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(lists), Arc::new(strings), ints, Arc::new(structs)],
+        )
+        .unwrap();
+
+        // only the values of the slices are written
+        for (offset, length) in &[(0, 2), (1, 3), (2, 3), (4, 1)] {
+            roundtrip(
+                "test_arrow_writer_sliced.parquet",
+                batch.slice(*offset, *length),
+            );
+        }
+    }
+
     #[test]
     fn arrow_writer_complex() {
         // define schema
@@ -1001,6 +1591,163 @@ mod tests {
         roundtrip("test_arrow_writer_2_level_struct_mixed_null.parquet", batch);
     }
 
+    #[test]
+    fn arrow_writer_list_of_struct() {
+        // tests writing <list<struct<primitive, primitive>>>
+        let field_a = Field::new("a", DataType::Int32, true);
+        let field_b = Field::new("b", DataType::Utf8, false);
+        let item_field = Field::new(
+            "item",
+            DataType::Struct(vec![field_a.clone(), field_b.clone()]),
+            true,
+        );
+        let list_field = Field::new("list", DataType::List(Box::new(item_field)), true);
+        let schema = Schema::new(vec![list_field.clone()]);
+
+        // create data
+        // [[{a: 1, b: "a"}], null, [], [{a: null, b: "b"}, null, {a: 3, b: "c"}]]
+        let a = Int32Array::from(vec![Some(1), None, None, Some(3)]);
+        let b = StringArray::from(vec!["a", "b", "", "c"]);
+        let items = StructArray::from((
+            vec![
+                (field_a, Arc::new(a) as ArrayRef),
+                (field_b, Arc::new(b) as ArrayRef),
+            ],
+            Buffer::from(vec![0b00001011]),
+        ));
+        let list_data = ArrayDataBuilder::new(list_field.data_type().clone())
+            .len(4)
+            .add_buffer(Buffer::from(&[0, 1, 1, 1, 4].to_byte_slice()))
+            .null_bit_buffer(Buffer::from(vec![0b00001101]))
+            .add_child_data(items.data().clone())
+            .build();
+        let list = ListArray::from(list_data);
+
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(list)]).unwrap();
+        let file = write_batch("test_arrow_writer_list_of_struct.parquet", &batch);
+
+        assert_eq!(
+            read_rows(file),
+            vec![
+                "{list: [{a: 1, b: \"a\"}]}",
+                "{list: null}",
+                "{list: []}",
+                "{list: [{a: null, b: \"b\"}, null, {a: 3, b: \"c\"}]}",
+            ]
+        );
+    }
+
+    #[test]
+    fn arrow_writer_nested_list() {
+        // tests writing <list<list<primitive>>>
+        let inner_field = Field::new(
+            "item",
+            DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+            true,
+        );
+        let list_field =
+            Field::new("list", DataType::List(Box::new(inner_field.clone())), true);
+        let schema = Schema::new(vec![list_field.clone()]);
+
+        // create data
+        // [[[1], [2, null]], null, [[], null, [3]], []]
+        let values = Int32Array::from(vec![Some(1), Some(2), None, Some(3)]);
+        let inner_data = ArrayDataBuilder::new(inner_field.data_type().clone())
+            .len(5)
+            .add_buffer(Buffer::from(&[0, 1, 3, 3, 3, 4].to_byte_slice()))
+            .null_bit_buffer(Buffer::from(vec![0b00010111]))
+            .add_child_data(values.data().clone())
+            .build();
+        let list_data = ArrayDataBuilder::new(list_field.data_type().clone())
+            .len(4)
+            .add_buffer(Buffer::from(&[0, 2, 2, 5, 5].to_byte_slice()))
+            .null_bit_buffer(Buffer::from(vec![0b00001101]))
+            .add_child_data(inner_data)
+            .build();
+        let list = ListArray::from(list_data);
+
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(list)]).unwrap();
+        let file = write_batch("test_arrow_writer_nested_list.parquet", &batch);
+
+        assert_eq!(
+            read_rows(file),
+            vec![
+                "{list: [[1], [2, null]]}",
+                "{list: null}",
+                "{list: [[], null, [3]]}",
+                "{list: []}",
+            ]
+        );
+    }
+
+    #[test]
+    fn arrow_writer_sliced_list() {
+        // [[1], [2, 3], null, [4, 5, 6], [7, 8, 9, 10]]
+        let list_field = Field::new(
+            "list",
+            DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+            true,
+        );
+        let values = Int32Array::from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        let list_data = ArrayDataBuilder::new(list_field.data_type().clone())
+            .len(5)
+            .add_buffer(Buffer::from(&[0, 1, 3, 3, 6, 10].to_byte_slice()))
+            .null_bit_buffer(Buffer::from(vec![0b00011011]))
+            .add_child_data(values.data().clone())
+            .build();
+        let list: ArrayRef = Arc::new(ListArray::from(list_data));
+
+        // [[2, 3], null, [4, 5, 6]]
+        let sliced = list.slice(1, 3);
+        one_column_roundtrip("test_arrow_writer_sliced_list.parquet", sliced, true);
+    }
+
+    #[test]
+    fn arrow_writer_sliced_struct_list() {
+        // tests writing a sliced <struct<list<primitive>>>
+        let list_field = Field::new(
+            "list",
+            DataType::List(Box::new(Field::new("item", DataType::Int32, false))),
+            true,
+        );
+        let values = Int32Array::from(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        let list_data = ArrayDataBuilder::new(list_field.data_type().clone())
+            .len(5)
+            .add_buffer(Buffer::from(&[0, 1, 3, 3, 6, 10].to_byte_slice()))
+            .null_bit_buffer(Buffer::from(vec![0b00011011]))
+            .add_child_data(values.data().clone())
+            .build();
+        let list = ListArray::from(list_data);
+        let struct_array: ArrayRef = Arc::new(StructArray::from((
+            vec![(list_field, Arc::new(list) as ArrayRef)],
+            Buffer::from(vec![0b00011101]),
+        )));
+
+        // [{list: null}, {list: null}, {list: [4, 5, 6]}]
+        let sliced = struct_array.slice(1, 3);
+        let file = write_batch(
+            "test_arrow_writer_sliced_struct_list.parquet",
+            &RecordBatch::try_new(
+                Arc::new(Schema::new(vec![Field::new(
+                    "struct",
+                    sliced.data_type().clone(),
+                    true,
+                )])),
+                vec![sliced],
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(
+            read_rows(file),
+            vec![
+                "{struct: null}",
+                "{struct: {list: null}}",
+                "{struct: {list: [4, 5, 6]}}"
+            ]
+        );
+    }
+
     const SMALL_SIZE: usize = 4;
 
     fn roundtrip(filename: &str, expected_batch: RecordBatch) -> File {
@@ -1037,6 +1784,28 @@ mod tests {
         file
     }
 
+    /// Write `batch` to a temporary file, returning the file
+    fn write_batch(filename: &str, batch: &RecordBatch) -> File {
+        let file = get_temp_file(filename, &[]);
+        let mut writer =
+            ArrowWriter::try_new(file.try_clone().unwrap(), batch.schema(), None)
+                .expect("Unable to write file");
+        writer.write(batch).unwrap();
+        writer.close().unwrap();
+        file
+    }
+
+    /// Read the rows of a file using the row-based record reader, which
+    /// supports arbitrarily nested types
+    fn read_rows(file: File) -> Vec<String> {
+        let reader = SerializedFileReader::new(file).unwrap();
+        reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(|row| row.to_string())
+            .collect()
+    }
+
     fn one_column_roundtrip(filename: &str, values: ArrayRef, nullable: bool) -> File {
         let schema = Schema::new(vec![Field::new(
             "col",
@@ -1329,7 +2098,7 @@ mod tests {
 
     #[test]
     fn fixed_size_binary_single_column() {
-        let mut builder = FixedSizeBinaryBuilder::new(16, 4);
+        let mut builder = FixedSizeBinaryBuilder::with_capacity(16, 4);
         builder.append_value(b"0123").unwrap();
         builder.append_null().unwrap();
         builder.append_value(b"8910").unwrap();
@@ -1443,6 +2212,160 @@ mod tests {
         );
     }
 
+    #[test]
+    fn arrow_writer_string_dictionary_fallback() {
+        // define schema
+        let schema = Arc::new(Schema::new(vec![Field::new_dict(
+            "dictionary",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            true,
+            42,
+            true,
+        )]));
+
+        // create some data, sliced so that one of the dictionary values is not used
+        let d: Int32DictionaryArray = [
+            Some("alpha"),
+            Some("unused"),
+            None,
+            Some("beta"),
+            Some("alpha"),
+            Some("gamma"),
+        ]
+        .iter()
+        .copied()
+        .collect();
+        let d = d.slice(2, 4);
+
+        // build a record batch
+        let expected_batch = RecordBatch::try_new(schema, vec![d]).unwrap();
+
+        roundtrip(
+            "test_arrow_writer_string_dictionary_sliced.parquet",
+            expected_batch.clone(),
+        );
+
+        // fall back to plain encoding once the dictionary page size is exceeded
+        let props = WriterProperties::builder()
+            .set_dictionary_pagesize_limit(1)
+            .set_write_batch_size(1)
+            .build();
+        let file =
+            get_temp_file("test_arrow_writer_string_dictionary_fallback.parquet", &[]);
+        let mut writer = ArrowWriter::try_new(
+            file.try_clone().unwrap(),
+            expected_batch.schema(),
+            Some(props),
+        )
+        .unwrap();
+        writer.write(&expected_batch).unwrap();
+        writer.close().unwrap();
+
+        let reader = SerializedFileReader::new(file).unwrap();
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(reader));
+        let actual_batch = arrow_reader
+            .get_record_reader(1024)
+            .unwrap()
+            .next()
+            .expect("No batch found")
+            .expect("Unable to get batch");
+        assert_eq!(
+            expected_batch.column(0).data(),
+            actual_batch.column(0).data()
+        );
+    }
+
+    #[test]
+    fn arrow_writer_delta_encodings() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("int32", DataType::Int32, false),
+            Field::new("int64", DataType::Int64, true),
+            Field::new("utf8", DataType::Utf8, true),
+            Field::new("binary", DataType::Binary, false),
+        ]));
+
+        let len = 2000;
+        let int32 = Int32Array::from_iter_values((0..len).map(|i| i * 3 - 1000));
+        // alternate between extremes so deltas need more than 32 bits
+        let int64 = (0..len as i64)
+            .map(|i| {
+                (i % 7 != 0).then(|| {
+                    if i % 2 == 0 {
+                        i64::MAX - i
+                    } else {
+                        i64::MIN + i
+                    }
+                })
+            })
+            .collect::<Int64Array>();
+        let utf8 = (0..len)
+            .map(|i| (i % 5 != 0).then(|| format!("prefix_{:06}", i)))
+            .collect::<StringArray>();
+        let binary_values: Vec<Vec<u8>> =
+            (0..len).map(|i| vec![b'b'; i as usize % 17]).collect();
+        let binary = BinaryArray::from(
+            binary_values
+                .iter()
+                .map(|v| v.as_slice())
+                .collect::<Vec<_>>(),
+        );
+        let expected_batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(int32),
+                Arc::new(int64),
+                Arc::new(utf8),
+                Arc::new(binary),
+            ],
+        )
+        .unwrap();
+
+        let encodings = [
+            ("int32", Encoding::DELTA_BINARY_PACKED),
+            ("int64", Encoding::DELTA_BINARY_PACKED),
+            ("utf8", Encoding::DELTA_BYTE_ARRAY),
+            ("binary", Encoding::DELTA_LENGTH_BYTE_ARRAY),
+        ];
+        let props = encodings
+            .iter()
+            .fold(
+                WriterProperties::builder()
+                    .set_dictionary_enabled(false)
+                    .set_data_pagesize_limit(256)
+                    .set_write_batch_size(100),
+                |builder, (col, encoding)| {
+                    builder.set_column_encoding(ColumnPath::from(*col), *encoding)
+                },
+            )
+            .build();
+
+        let file = get_temp_file("test_arrow_writer_delta_encodings.parquet", &[]);
+        let mut writer =
+            ArrowWriter::try_new(file.try_clone().unwrap(), schema, Some(props)).unwrap();
+        writer.write(&expected_batch).unwrap();
+        writer.close().unwrap();
+
+        let reader = SerializedFileReader::new(file).unwrap();
+        let row_group = reader.metadata().row_group(0);
+        for (column, (_, encoding)) in row_group.columns().iter().zip(&encodings) {
+            assert!(column.encodings().contains(encoding));
+        }
+
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(reader));
+        let actual_batch = arrow_reader
+            .get_record_reader(len as usize)
+            .unwrap()
+            .next()
+            .expect("No batch found")
+            .expect("Unable to get batch");
+        for i in 0..expected_batch.num_columns() {
+            assert_eq!(
+                expected_batch.column(i).data(),
+                actual_batch.column(i).data()
+            );
+        }
+    }
+
     #[test]
     fn arrow_writer_primitive_dictionary() {
         // define schema
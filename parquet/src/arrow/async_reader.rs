@@ -0,0 +1,450 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains an asynchronous reader which reads parquet data into arrow record batches.
+//!
+//! Unlike [`ParquetFileArrowReader`], which requires a
+//! [`ChunkReader`](crate::file::reader::ChunkReader) over the whole
+//! file, [`ParquetRecordBatchStream`] fetches the file metadata and then only the
+//! byte ranges of the projected column chunks of each row group, through the
+//! [`AsyncFileReader`] trait. This makes it suitable for reading from object stores.
+//!
+//! This module requires the `async` feature.
+//!
+//! # Example
+//!
+//! ```rust, no_run
+//! # async fn example() -> parquet::errors::Result<()> {
+//! use futures::TryStreamExt;
+//! use parquet::arrow::async_reader::ParquetRecordBatchStreamBuilder;
+//!
+//! // anything that implements `AsyncRead + AsyncSeek`, or `AsyncFileReader`
+//! let file = futures::io::Cursor::new(std::fs::read("data.parquet").unwrap());
+//!
+//! let builder = ParquetRecordBatchStreamBuilder::new(file).await?;
+//! println!("Converted arrow schema is: {}", builder.schema());
+//!
+//! let stream = builder.with_projection(vec![0, 2]).with_batch_size(1024).build()?;
+//! let batches = stream.try_collect::<Vec<_>>().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::io::SeekFrom;
+use std::ops::Range;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::future::{BoxFuture, FutureExt};
+use futures::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+use futures::ready;
+use futures::stream::Stream;
+
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+
+use crate::arrow::arrow_reader::{
+    ArrowReader, ParquetFileArrowReader, ParquetRecordBatchReader,
+};
+use crate::arrow::in_memory::InMemoryColumnChunks;
+use crate::arrow::schema::{parquet_to_arrow_schema, parquet_to_arrow_schema_by_columns};
+use crate::errors::{ParquetError, Result};
+use crate::file::footer::{decode_footer, decode_metadata};
+use crate::file::metadata::ParquetMetaData;
+use crate::file::reader::SerializedFileReader;
+use crate::file::FOOTER_SIZE;
+use crate::util::cursor::SliceableCursor;
+
+/// The asynchronous interface used by [`ParquetRecordBatchStream`] to read parquet
+/// files.
+///
+/// It is implemented for all types that implement [`AsyncRead`] and [`AsyncSeek`],
+/// and can be implemented for other sources, such as object stores, that support
+/// ranged reads.
+pub trait AsyncFileReader {
+    /// Retrieve the bytes in `range`
+    fn get_bytes(&mut self, range: Range<usize>) -> BoxFuture<'_, Result<Vec<u8>>>;
+
+    /// Retrieve the [`ParquetMetaData`] of the file
+    fn get_metadata(&mut self) -> BoxFuture<'_, Result<Arc<ParquetMetaData>>>;
+}
+
+impl<T: AsyncRead + AsyncSeek + Unpin + Send> AsyncFileReader for T {
+    fn get_bytes(&mut self, range: Range<usize>) -> BoxFuture<'_, Result<Vec<u8>>> {
+        async move {
+            self.seek(SeekFrom::Start(range.start as u64)).await?;
+
+            let mut buffer = vec![0; range.end - range.start];
+            self.read_exact(&mut buffer).await?;
+            Ok(buffer)
+        }
+        .boxed()
+    }
+
+    fn get_metadata(&mut self) -> BoxFuture<'_, Result<Arc<ParquetMetaData>>> {
+        async move {
+            self.seek(SeekFrom::End(-(FOOTER_SIZE as i64))).await?;
+
+            let mut footer = [0; FOOTER_SIZE];
+            self.read_exact(&mut footer).await?;
+            let metadata_len = decode_footer(&footer)?;
+
+            self.seek(SeekFrom::End(-((FOOTER_SIZE + metadata_len) as i64)))
+                .await?;
+            let mut buffer = vec![0; metadata_len];
+            self.read_exact(&mut buffer).await?;
+
+            Ok(Arc::new(decode_metadata(buffer.as_slice())?))
+        }
+        .boxed()
+    }
+}
+
+/// A builder used to construct a [`ParquetRecordBatchStream`] for a parquet file
+pub struct ParquetRecordBatchStreamBuilder<T> {
+    input: T,
+    metadata: Arc<ParquetMetaData>,
+    schema: SchemaRef,
+    batch_size: usize,
+    row_groups: Option<Vec<usize>>,
+    projection: Option<Vec<usize>>,
+}
+
+impl<T> fmt::Debug for ParquetRecordBatchStreamBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParquetRecordBatchStreamBuilder")
+            .field("metadata", &self.metadata)
+            .field("schema", &self.schema)
+            .field("batch_size", &self.batch_size)
+            .field("row_groups", &self.row_groups)
+            .field("projection", &self.projection)
+            .finish()
+    }
+}
+
+impl<T: AsyncFileReader + Unpin + Send + 'static> ParquetRecordBatchStreamBuilder<T> {
+    /// Create a new builder, reading the metadata of the parquet file from `input`
+    pub async fn new(mut input: T) -> Result<Self> {
+        let metadata = input.get_metadata().await?;
+        let file_metadata = metadata.file_metadata();
+        let schema = Arc::new(parquet_to_arrow_schema(
+            file_metadata.schema_descr(),
+            file_metadata.key_value_metadata(),
+        )?);
+
+        Ok(Self {
+            input,
+            metadata,
+            schema,
+            batch_size: 1024,
+            row_groups: None,
+            projection: None,
+        })
+    }
+
+    /// Returns the metadata of the parquet file
+    pub fn metadata(&self) -> &Arc<ParquetMetaData> {
+        &self.metadata
+    }
+
+    /// Returns the arrow schema of the whole parquet file
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Set the size of the record batches, the default is 1024
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size, ..self }
+    }
+
+    /// Only read the row groups with the given indices, in the given order
+    pub fn with_row_groups(self, row_groups: Vec<usize>) -> Self {
+        Self {
+            row_groups: Some(row_groups),
+            ..self
+        }
+    }
+
+    /// Only read the leaf columns with the given indices
+    pub fn with_projection(self, projection: Vec<usize>) -> Self {
+        Self {
+            projection: Some(projection),
+            ..self
+        }
+    }
+
+    /// Build a new [`ParquetRecordBatchStream`]
+    pub fn build(self) -> Result<ParquetRecordBatchStream<T>> {
+        let num_row_groups = self.metadata.num_row_groups();
+        let row_groups = match self.row_groups {
+            Some(row_groups) => {
+                if let Some(idx) = row_groups.iter().find(|idx| **idx >= num_row_groups) {
+                    return Err(ParquetError::IndexOutOfBound(*idx, num_row_groups));
+                }
+                row_groups.into()
+            }
+            None => (0..num_row_groups).collect(),
+        };
+
+        let file_metadata = self.metadata.file_metadata();
+        let num_columns = file_metadata.schema_descr().num_columns();
+        let (columns, schema) = match self.projection {
+            Some(projection) => {
+                if let Some(idx) = projection.iter().find(|idx| **idx >= num_columns) {
+                    return Err(ParquetError::IndexOutOfBound(*idx, num_columns));
+                }
+                let schema = parquet_to_arrow_schema_by_columns(
+                    file_metadata.schema_descr(),
+                    projection.clone(),
+                    file_metadata.key_value_metadata(),
+                )?;
+                (projection, Arc::new(schema))
+            }
+            None => ((0..num_columns).collect(), self.schema),
+        };
+
+        Ok(ParquetRecordBatchStream {
+            metadata: self.metadata,
+            schema,
+            batch_size: self.batch_size,
+            columns,
+            row_groups,
+            input: Some(self.input),
+            state: StreamState::Init,
+        })
+    }
+}
+
+/// The column chunks fetched for a single row group
+type RowGroupFuture<T> = BoxFuture<'static, Result<(T, usize, InMemoryColumnChunks)>>;
+
+enum StreamState<T> {
+    /// At the start of a new row group, or the end of the stream
+    Init,
+    /// Decoding the batches of a row group
+    Decoding(ParquetRecordBatchReader),
+    /// Fetching the column chunks of a row group
+    Reading(RowGroupFuture<T>),
+    /// An error was returned, no further batches are produced
+    Error,
+}
+
+impl<T> fmt::Debug for StreamState<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamState::Init => write!(f, "StreamState::Init"),
+            StreamState::Decoding(_) => write!(f, "StreamState::Decoding"),
+            StreamState::Reading(_) => write!(f, "StreamState::Reading"),
+            StreamState::Error => write!(f, "StreamState::Error"),
+        }
+    }
+}
+
+/// An asynchronous [`Stream`] of [`RecordBatch`] for a parquet file, created with
+/// [`ParquetRecordBatchStreamBuilder`].
+///
+/// The column chunks of each row group are fetched in turn, and then decoded.
+pub struct ParquetRecordBatchStream<T> {
+    metadata: Arc<ParquetMetaData>,
+    schema: SchemaRef,
+    batch_size: usize,
+    /// The leaf columns to read
+    columns: Vec<usize>,
+    /// The row groups that have not been read yet
+    row_groups: VecDeque<usize>,
+    /// The input, which is moved into the future while a row group is fetched
+    input: Option<T>,
+    state: StreamState<T>,
+}
+
+impl<T> fmt::Debug for ParquetRecordBatchStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParquetRecordBatchStream")
+            .field("metadata", &self.metadata)
+            .field("schema", &self.schema)
+            .field("batch_size", &self.batch_size)
+            .field("columns", &self.columns)
+            .field("row_groups", &self.row_groups)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<T: AsyncFileReader + Unpin + Send + 'static> ParquetRecordBatchStream<T> {
+    /// Returns the arrow schema of the record batches in this stream
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Returns a future that fetches the projected column chunks of a row group
+    fn fetch_row_group(&mut self, row_group_idx: usize) -> RowGroupFuture<T> {
+        let mut input = self.input.take().expect("input should be available");
+        let metadata = self.metadata.clone();
+        let columns = self.columns.clone();
+
+        async move {
+            let row_group_metadata = metadata.row_group(row_group_idx);
+            let mut chunks = Vec::with_capacity(columns.len());
+            for idx in columns {
+                let (start, length) = row_group_metadata.column(idx).byte_range();
+                let data = input
+                    .get_bytes(start as usize..(start + length) as usize)
+                    .await?;
+                chunks.push((start, SliceableCursor::new(data)));
+            }
+            Ok((input, row_group_idx, InMemoryColumnChunks { chunks }))
+        }
+        .boxed()
+    }
+
+    /// Creates a synchronous reader over the fetched column chunks of a row group
+    fn decode_row_group(
+        &self,
+        row_group_idx: usize,
+        chunks: InMemoryColumnChunks,
+    ) -> Result<ParquetRecordBatchReader> {
+        let metadata = ParquetMetaData::new(
+            self.metadata.file_metadata().clone(),
+            vec![self.metadata.row_group(row_group_idx).clone()],
+        );
+        let file_reader = SerializedFileReader::new_with_metadata(chunks, metadata);
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(file_reader));
+        arrow_reader.get_record_reader_by_columns(self.columns.clone(), self.batch_size)
+    }
+}
+
+impl<T: AsyncFileReader + Unpin + Send + 'static> Stream for ParquetRecordBatchStream<T> {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            match &mut this.state {
+                StreamState::Decoding(batch_reader) => match batch_reader.next() {
+                    Some(Ok(batch)) => return Poll::Ready(Some(Ok(batch))),
+                    Some(Err(e)) => {
+                        this.state = StreamState::Error;
+                        return Poll::Ready(Some(Err(e.into())));
+                    }
+                    None => this.state = StreamState::Init,
+                },
+                StreamState::Init => {
+                    let row_group_idx = match this.row_groups.pop_front() {
+                        Some(idx) => idx,
+                        None => return Poll::Ready(None),
+                    };
+                    this.state =
+                        StreamState::Reading(this.fetch_row_group(row_group_idx));
+                }
+                StreamState::Reading(f) => {
+                    let result = ready!(f.poll_unpin(cx));
+                    let decoded = result.and_then(|(input, row_group_idx, chunks)| {
+                        this.input = Some(input);
+                        this.decode_row_group(row_group_idx, chunks)
+                    });
+                    match decoded {
+                        Ok(batch_reader) => {
+                            this.state = StreamState::Decoding(batch_reader)
+                        }
+                        Err(e) => {
+                            this.state = StreamState::Error;
+                            return Poll::Ready(Some(Err(e)));
+                        }
+                    }
+                }
+                StreamState::Error => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::executor::block_on;
+    use futures::TryStreamExt;
+
+    use crate::util::test_common::get_test_path;
+
+    fn get_test_input() -> futures::io::Cursor<Vec<u8>> {
+        let path = get_test_path("alltypes_plain.parquet");
+        futures::io::Cursor::new(std::fs::read(path).unwrap())
+    }
+
+    #[test]
+    fn test_async_reader() {
+        let builder =
+            block_on(ParquetRecordBatchStreamBuilder::new(get_test_input())).unwrap();
+        let metadata = builder.metadata().clone();
+        let stream = builder
+            .with_projection(vec![1, 2])
+            .with_batch_size(3)
+            .build()
+            .unwrap();
+        let schema = stream.schema().clone();
+        let batches: Vec<RecordBatch> = block_on(stream.try_collect()).unwrap();
+
+        // compare against the synchronous reader
+        let file_reader = SerializedFileReader::new(SliceableCursor::new(
+            std::fs::read(get_test_path("alltypes_plain.parquet")).unwrap(),
+        ))
+        .unwrap();
+        assert_eq!(
+            metadata.file_metadata().num_rows(),
+            file_reader.metadata().file_metadata().num_rows()
+        );
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(file_reader));
+        let expected = arrow_reader
+            .get_record_reader_by_columns(vec![1, 2], 3)
+            .unwrap()
+            .collect::<arrow::error::Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(2, schema.fields().len());
+        assert_eq!(expected.len(), batches.len());
+        for (expected, actual) in expected.iter().zip(&batches) {
+            assert_eq!(expected.schema(), actual.schema());
+            assert_eq!(expected.columns(), actual.columns());
+        }
+    }
+
+    #[test]
+    fn test_async_reader_invalid_row_group() {
+        let builder =
+            block_on(ParquetRecordBatchStreamBuilder::new(get_test_input())).unwrap();
+        let num_row_groups = builder.metadata().num_row_groups();
+        let err = builder
+            .with_row_groups(vec![num_row_groups])
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            ParquetError::IndexOutOfBound(num_row_groups, num_row_groups),
+            err
+        );
+    }
+
+    #[test]
+    fn test_async_reader_corrupt_footer() {
+        let input = futures::io::Cursor::new(vec![0; 16]);
+        let err = block_on(ParquetRecordBatchStreamBuilder::new(input)).unwrap_err();
+        assert_eq!(general_err!("Invalid Parquet file. Corrupt footer"), err);
+    }
+}
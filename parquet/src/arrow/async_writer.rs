@@ -0,0 +1,260 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains an asynchronous writer which writes arrow record batches to parquet.
+//!
+//! [`AsyncArrowWriter`] encodes each record batch into a row group with an
+//! [`ArrowWriter`], buffering the encoded row groups in memory until they exceed a
+//! configurable size, and then writing them to an [`AsyncWrite`] sink. This allows
+//! writing to object stores without first writing to a temporary file.
+//!
+//! This module requires the `async` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use std::sync::Arc;
+//! # use arrow::array::{ArrayRef, Int64Array};
+//! # use arrow::record_batch::RecordBatch;
+//! # use futures::executor::block_on;
+//! # use parquet::arrow::async_writer::AsyncArrowWriter;
+//! # block_on(async {
+//! let col = Arc::new(Int64Array::from_iter_values([1, 2, 3])) as ArrayRef;
+//! let batch = RecordBatch::try_from_iter([("col", col)]).unwrap();
+//!
+//! let mut buffer = futures::io::Cursor::new(Vec::new());
+//! let mut writer =
+//!     AsyncArrowWriter::try_new(&mut buffer, batch.schema(), 1024, None).unwrap();
+//! writer.write(&batch).await.unwrap();
+//! writer.close().await.unwrap();
+//! # });
+//! ```
+
+use std::io::{Seek, SeekFrom, Write};
+use std::sync::{Arc, Mutex};
+
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use futures::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::arrow::ArrowWriter;
+use crate::errors::Result;
+use crate::file::properties::WriterProperties;
+use crate::file::writer::TryClone;
+
+/// Writes arrow record batches to an [`AsyncWrite`] sink as parquet.
+///
+/// Record batches are written as row groups as by [`ArrowWriter`]. The encoded
+/// row groups are buffered in memory until their size reaches `buffer_size`
+/// bytes, and then written to the sink.
+pub struct AsyncArrowWriter<W> {
+    /// The writer encoding record batches into `shared_buffer`
+    sync_writer: ArrowWriter<SharedBuffer>,
+    /// The sink the encoded bytes are written to
+    async_writer: W,
+    /// The bytes encoded but not yet written to the sink
+    shared_buffer: SharedBuffer,
+    /// The number of bytes to buffer before writing to the sink
+    buffer_size: usize,
+}
+
+impl<W: AsyncWrite + Unpin + Send> AsyncArrowWriter<W> {
+    /// Try to create a new async Arrow writer, which writes to `writer` whenever
+    /// more than `buffer_size` bytes have been encoded.
+    ///
+    /// Fails for the same reasons as [`ArrowWriter::try_new`].
+    pub fn try_new(
+        writer: W,
+        arrow_schema: SchemaRef,
+        buffer_size: usize,
+        props: Option<WriterProperties>,
+    ) -> Result<Self> {
+        let shared_buffer = SharedBuffer::default();
+        let sync_writer =
+            ArrowWriter::try_new(shared_buffer.try_clone()?, arrow_schema, props)?;
+
+        Ok(Self {
+            sync_writer,
+            async_writer: writer,
+            shared_buffer,
+            buffer_size,
+        })
+    }
+
+    /// Write a RecordBatch, writing the buffered bytes to the sink if they exceed
+    /// the buffer size.
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        self.sync_writer.write(batch)?;
+        self.try_flush(false).await
+    }
+
+    /// Finalize the parquet file, writing all the remaining bytes to the sink
+    /// and closing it.
+    pub async fn close(mut self) -> Result<parquet_format::FileMetaData> {
+        let metadata = self.sync_writer.close()?;
+        self.try_flush(true).await?;
+        self.async_writer.close().await?;
+        Ok(metadata)
+    }
+
+    /// Writes the buffered bytes to the sink if `force` is set or they exceed
+    /// the buffer size.
+    async fn try_flush(&mut self, force: bool) -> Result<()> {
+        let bytes = {
+            let mut inner = self.shared_buffer.inner.lock().unwrap();
+            if inner.buffer.is_empty()
+                || (!force && inner.buffer.len() < self.buffer_size)
+            {
+                return Ok(());
+            }
+            inner.flushed += inner.buffer.len() as u64;
+            std::mem::take(&mut inner.buffer)
+        };
+
+        self.async_writer.write_all(&bytes).await?;
+        self.async_writer.flush().await?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+struct SharedBufferInner {
+    /// The bytes written but not yet taken
+    buffer: Vec<u8>,
+    /// The number of bytes taken from the start of the buffer
+    flushed: u64,
+}
+
+/// An in-memory buffer whose bytes can be taken as they are written, while still
+/// reporting the position within everything written to it.
+///
+/// The [`SerializedFileWriter`](crate::file::writer::SerializedFileWriter) only
+/// seeks to query its position, so this is the only seek supported.
+#[derive(Debug, Default, Clone)]
+struct SharedBuffer {
+    inner: Arc<Mutex<SharedBufferInner>>,
+}
+
+impl TryClone for SharedBuffer {
+    fn try_clone(&self) -> std::io::Result<Self> {
+        Ok(self.clone())
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut inner = self.inner.lock().unwrap();
+        inner.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for SharedBuffer {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        match pos {
+            SeekFrom::Current(0) | SeekFrom::End(0) => {
+                let inner = self.inner.lock().unwrap();
+                Ok(inner.flushed + inner.buffer.len() as u64)
+            }
+            _ => Err(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                "SharedBuffer only supports querying its position",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{ArrayRef, Int64Array, StringArray};
+    use futures::executor::block_on;
+
+    use crate::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use crate::util::cursor::SliceableCursor;
+
+    fn get_test_batch(offset: i64) -> RecordBatch {
+        let ints = Int64Array::from_iter_values(offset..offset + 100);
+        let strings = StringArray::from_iter_values(
+            (offset..offset + 100).map(|i| format!("value {}", i)),
+        );
+        RecordBatch::try_from_iter([
+            ("int", Arc::new(ints) as ArrayRef),
+            ("string", Arc::new(strings) as ArrayRef),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_async_writer() {
+        let batches: Vec<_> = (0..5).map(|i| get_test_batch(i * 100)).collect();
+
+        // Only write to the sink every few row groups
+        let mut sink = futures::io::Cursor::new(Vec::new());
+        let mut writer =
+            AsyncArrowWriter::try_new(&mut sink, batches[0].schema(), 4096, None)
+                .unwrap();
+        for batch in &batches {
+            block_on(writer.write(batch)).unwrap();
+        }
+        block_on(writer.close()).unwrap();
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(SliceableCursor::new(
+            sink.into_inner(),
+        ))
+        .unwrap()
+        .with_batch_size(100)
+        .build()
+        .unwrap();
+        let read: Vec<_> = reader.collect::<arrow::error::Result<_>>().unwrap();
+        assert_eq!(read.len(), batches.len());
+        for (read, batch) in read.iter().zip(batches.iter()) {
+            assert_eq!(read.columns(), batch.columns());
+        }
+    }
+
+    #[test]
+    fn test_async_writer_buffer_size() {
+        let batch = get_test_batch(0);
+        let mut sink = futures::io::Cursor::new(Vec::new());
+        let mut writer =
+            AsyncArrowWriter::try_new(&mut sink, batch.schema(), 1 << 20, None).unwrap();
+
+        // Nothing is written to the sink until the buffer size is reached
+        block_on(writer.write(&batch)).unwrap();
+        assert!(writer.async_writer.get_ref().is_empty());
+
+        block_on(writer.close()).unwrap();
+        assert_eq!(&sink.get_ref()[..4], b"PAR1");
+    }
+
+    #[test]
+    fn test_shared_buffer_position() {
+        let mut buffer = SharedBuffer::default();
+        buffer.write_all(b"abc").unwrap();
+        buffer.inner.lock().unwrap().buffer.clear();
+        buffer.inner.lock().unwrap().flushed = 3;
+        buffer.write_all(b"de").unwrap();
+        assert_eq!(buffer.seek(SeekFrom::Current(0)).unwrap(), 5);
+        assert!(buffer.seek(SeekFrom::Start(0)).is_err());
+    }
+}
@@ -28,11 +28,12 @@ use std::convert::{From, TryInto};
 use std::sync::Arc;
 
 use crate::errors::Result;
-use arrow::datatypes::{ArrowDictionaryKeyType, ArrowPrimitiveType};
+use arrow::datatypes::{ArrowDictionaryKeyType, ArrowPrimitiveType, TimeUnit};
 
 use arrow::array::{
     BinaryArray, DecimalArray, DictionaryArray, FixedSizeBinaryArray, LargeBinaryArray,
-    LargeStringArray, PrimitiveArray, StringArray, TimestampNanosecondArray,
+    LargeStringArray, PrimitiveArray, StringArray, TimestampMicrosecondArray,
+    TimestampMillisecondArray, TimestampNanosecondArray, TimestampSecondArray,
 };
 use std::marker::PhantomData;
 
@@ -65,7 +66,8 @@ impl Converter<Vec<Option<FixedLenByteArray>>, FixedSizeBinaryArray>
         &self,
         source: Vec<Option<FixedLenByteArray>>,
     ) -> Result<FixedSizeBinaryArray> {
-        let mut builder = FixedSizeBinaryBuilder::new(source.len(), self.byte_width);
+        let mut builder =
+            FixedSizeBinaryBuilder::with_capacity(source.len(), self.byte_width);
         for v in source {
             match v {
                 Some(array) => builder.append_value(array.data()),
@@ -89,6 +91,9 @@ impl DecimalArrayConverter {
 
     fn from_bytes_to_i128(b: &[u8]) -> i128 {
         assert!(b.len() <= 16, "DecimalArray supports only up to size 16");
+        if b.is_empty() {
+            return 0;
+        }
         let first_bit = b[0] & 128u8 == 128u8;
         let mut result = if first_bit { [255u8; 16] } else { [0u8; 16] };
         for (i, v) in b.iter().enumerate() {
@@ -100,7 +105,27 @@ impl DecimalArrayConverter {
 
 impl Converter<Vec<Option<FixedLenByteArray>>, DecimalArray> for DecimalArrayConverter {
     fn convert(&self, source: Vec<Option<FixedLenByteArray>>) -> Result<DecimalArray> {
-        let mut builder = DecimalBuilder::new(
+        let mut builder = DecimalBuilder::with_capacity(
+            source.len(),
+            self.precision as usize,
+            self.scale as usize,
+        );
+        for v in source {
+            match v {
+                Some(array) => {
+                    builder.append_value(Self::from_bytes_to_i128(array.data()))
+                }
+                None => builder.append_null(),
+            }?
+        }
+
+        Ok(builder.finish())
+    }
+}
+
+impl Converter<Vec<Option<ByteArray>>, DecimalArray> for DecimalArrayConverter {
+    fn convert(&self, source: Vec<Option<ByteArray>>) -> Result<DecimalArray> {
+        let mut builder = DecimalBuilder::with_capacity(
             source.len(),
             self.precision as usize,
             self.scale as usize,
@@ -117,6 +142,7 @@ impl Converter<Vec<Option<FixedLenByteArray>>, DecimalArray> for DecimalArrayCon
         Ok(builder.finish())
     }
 }
+
 /// An Arrow Interval converter, which reads the first 4 bytes of a Parquet interval,
 /// and interprets it as an i32 value representing the Arrow YearMonth value
 pub struct IntervalYearMonthArrayConverter {}
@@ -167,19 +193,53 @@ impl Converter<Vec<Option<FixedLenByteArray>>, IntervalDayTimeArray>
     }
 }
 
+/// Converts INT96 timestamps to an Arrow timestamp array of resolution `unit`
 pub struct Int96ArrayConverter {
+    pub unit: TimeUnit,
     pub timezone: Option<String>,
+    /// The offset in seconds from UTC of the local wall clock time that the INT96
+    /// values are in, which is subtracted from them to get instants in UTC
+    pub utc_offset: i64,
 }
 
-impl Converter<Vec<Option<Int96>>, TimestampNanosecondArray> for Int96ArrayConverter {
-    fn convert(&self, source: Vec<Option<Int96>>) -> Result<TimestampNanosecondArray> {
-        Ok(TimestampNanosecondArray::from_opt_vec(
-            source
-                .into_iter()
-                .map(|int96| int96.map(|val| val.to_i64() * 1_000_000))
-                .collect(),
-            self.timezone.clone(),
-        ))
+impl Converter<Vec<Option<Int96>>, ArrayRef> for Int96ArrayConverter {
+    fn convert(&self, source: Vec<Option<Int96>>) -> Result<ArrayRef> {
+        let timezone = self.timezone.clone();
+        let offset = self.utc_offset;
+        let array: ArrayRef = match self.unit {
+            TimeUnit::Second => Arc::new(TimestampSecondArray::from_opt_vec(
+                source
+                    .iter()
+                    .map(|v| v.as_ref().map(|v| v.to_seconds() - offset))
+                    .collect(),
+                timezone,
+            )),
+            TimeUnit::Millisecond => Arc::new(TimestampMillisecondArray::from_opt_vec(
+                source
+                    .iter()
+                    .map(|v| v.as_ref().map(|v| v.to_i64() - offset * 1_000))
+                    .collect(),
+                timezone,
+            )),
+            TimeUnit::Microsecond => Arc::new(TimestampMicrosecondArray::from_opt_vec(
+                source
+                    .iter()
+                    .map(|v| v.as_ref().map(|v| v.to_micros() - offset * 1_000_000))
+                    .collect(),
+                timezone,
+            )),
+            TimeUnit::Nanosecond => Arc::new(TimestampNanosecondArray::from_opt_vec(
+                source
+                    .iter()
+                    .map(|v| {
+                        v.as_ref()
+                            .map(|v| v.to_nanos().wrapping_sub(offset * 1_000_000_000))
+                    })
+                    .collect(),
+                timezone,
+            )),
+        };
+        Ok(array)
     }
 }
 
@@ -229,7 +289,7 @@ pub struct BinaryArrayConverter {}
 
 impl Converter<Vec<Option<ByteArray>>, BinaryArray> for BinaryArrayConverter {
     fn convert(&self, source: Vec<Option<ByteArray>>) -> Result<BinaryArray> {
-        let mut builder = BinaryBuilder::new(source.len());
+        let mut builder = BinaryBuilder::with_capacity(source.len(), source.len());
         for v in source {
             match v {
                 Some(array) => builder.append_value(array.data()),
@@ -245,7 +305,7 @@ pub struct LargeBinaryArrayConverter {}
 
 impl Converter<Vec<Option<ByteArray>>, LargeBinaryArray> for LargeBinaryArrayConverter {
     fn convert(&self, source: Vec<Option<ByteArray>>) -> Result<LargeBinaryArray> {
-        let mut builder = LargeBinaryBuilder::new(source.len());
+        let mut builder = LargeBinaryBuilder::with_capacity(source.len(), source.len());
         for v in source {
             match v {
                 Some(array) => builder.append_value(array.data()),
@@ -370,9 +430,6 @@ pub type PrimitiveDictionaryConverter<K, V> = ArrayRefConverter<
     DictionaryArrayConverter<Int32Type, V, ParquetInt32Type>,
 >;
 
-pub type Int96Converter =
-    ArrayRefConverter<Vec<Option<Int96>>, TimestampNanosecondArray, Int96ArrayConverter>;
-
 pub type FixedLenBinaryConverter = ArrayRefConverter<
     Vec<Option<FixedLenByteArray>>,
     FixedSizeBinaryArray,
@@ -394,6 +451,8 @@ pub type DecimalConverter = ArrayRefConverter<
     DecimalArray,
     DecimalArrayConverter,
 >;
+pub type ByteArrayDecimalConverter =
+    ArrayRefConverter<Vec<Option<ByteArray>>, DecimalArray, DecimalArrayConverter>;
 
 pub struct FromConverter<S, T> {
     _source: PhantomData<S>,
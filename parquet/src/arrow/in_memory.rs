@@ -0,0 +1,59 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains a [`ChunkReader`] over column chunks that have been read into memory.
+
+use crate::errors::{ParquetError, Result};
+use crate::file::reader::{ChunkReader, Length};
+use crate::util::cursor::SliceableCursor;
+
+/// A [`ChunkReader`] over the column chunks of a row group that have been fetched
+/// into memory, each identified by its offset in the file
+pub(crate) struct InMemoryColumnChunks {
+    pub(crate) chunks: Vec<(u64, SliceableCursor)>,
+}
+
+impl Length for InMemoryColumnChunks {
+    fn len(&self) -> u64 {
+        self.chunks
+            .iter()
+            .map(|(start, chunk)| start + chunk.len())
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl ChunkReader for InMemoryColumnChunks {
+    type T = SliceableCursor;
+
+    fn get_read(&self, start: u64, length: usize) -> Result<Self::T> {
+        self.chunks
+            .iter()
+            .find(|(chunk_start, chunk)| {
+                start >= *chunk_start
+                    && start + length as u64 <= *chunk_start + chunk.len()
+            })
+            .ok_or_else(|| {
+                general_err!("Column chunk at offset {} has not been fetched", start)
+            })
+            .and_then(|(chunk_start, chunk)| {
+                chunk
+                    .slice(start - chunk_start, length)
+                    .map_err(|e| e.into())
+            })
+    }
+}
@@ -52,18 +52,201 @@
 pub(in crate::arrow) mod array_reader;
 pub mod arrow_reader;
 pub mod arrow_writer;
+#[cfg(feature = "async")]
+pub mod async_reader;
+#[cfg(feature = "async")]
+pub mod async_writer;
 pub(in crate::arrow) mod converter;
+#[cfg(any(feature = "async", feature = "parallel"))]
+pub(in crate::arrow) mod in_memory;
 pub(in crate::arrow) mod levels;
+#[cfg(feature = "parallel")]
+pub(in crate::arrow) mod parallel_reader;
 pub(in crate::arrow) mod record_reader;
 pub mod schema;
+mod selection;
 
 pub use self::arrow_reader::ArrowReader;
 pub use self::arrow_reader::ParquetFileArrowReader;
+pub use self::arrow_reader::{
+    ArrowPredicate, ArrowPredicateFn, ArrowReaderOptions,
+    ParquetRecordBatchReaderBuilder, RowFilter, RowSelection, RowSelector,
+};
 pub use self::arrow_writer::ArrowWriter;
 pub use self::schema::{
     arrow_to_parquet_schema, parquet_to_arrow_schema, parquet_to_arrow_schema_by_columns,
     parquet_to_arrow_schema_by_root_columns,
 };
 
+use std::sync::Arc;
+
+use crate::schema::types::SchemaDescriptor;
+
 /// Schema metadata key used to store serialized Arrow IPC schema
 pub const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+
+/// A [`ProjectionMask`] identifies the leaf columns of a, potentially nested, parquet
+/// schema to read.
+///
+/// Only the selected fields of a struct are read, so that projecting `address.city`
+/// of a struct `address` with the fields `city` and `street` reads `address` as a
+/// struct with the single field `city`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProjectionMask {
+    /// Whether each leaf column is included, or `None` if all columns are
+    mask: Option<Vec<bool>>,
+}
+
+impl ProjectionMask {
+    /// Create a [`ProjectionMask`] which selects all columns
+    pub fn all() -> Self {
+        Self { mask: None }
+    }
+
+    /// Create a [`ProjectionMask`] which selects the leaf columns with the given
+    /// indices in `schema`
+    ///
+    /// The nested fields of a struct, e.g. `a.b.c`, are leaf columns of their own, so
+    /// selecting some but not all of them reads a struct with only those fields.
+    pub fn leaves(
+        schema: &SchemaDescriptor,
+        indices: impl IntoIterator<Item = usize>,
+    ) -> Self {
+        let mut mask = vec![false; schema.num_columns()];
+        for idx in indices {
+            mask[idx] = true;
+        }
+        Self { mask: Some(mask) }
+    }
+
+    /// Create a [`ProjectionMask`] which selects the root columns with the given
+    /// indices in `schema`, along with all the leaf columns nested within them
+    pub fn roots(
+        schema: &SchemaDescriptor,
+        indices: impl IntoIterator<Item = usize>,
+    ) -> Self {
+        let root_fields = schema.root_schema().get_fields();
+        let mut roots = vec![false; root_fields.len()];
+        for idx in indices {
+            roots[idx] = true;
+        }
+
+        let mask = (0..schema.num_columns())
+            .map(|leaf| {
+                let root = schema.get_column_root_ptr(leaf);
+                root_fields
+                    .iter()
+                    .position(|field| Arc::ptr_eq(field, &root))
+                    .map_or(false, |idx| roots[idx])
+            })
+            .collect();
+        Self { mask: Some(mask) }
+    }
+
+    /// Create a [`ProjectionMask`] which selects the columns with the given dot
+    /// separated paths in `schema`, such as `address.city`, along with all the leaf
+    /// columns nested within them
+    pub fn columns<'a>(
+        schema: &SchemaDescriptor,
+        paths: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        let paths: Vec<Vec<&str>> = paths
+            .into_iter()
+            .map(|path| path.split('.').collect())
+            .collect();
+
+        let mask = schema
+            .columns()
+            .iter()
+            .map(|column| {
+                let parts = column.path().parts();
+                paths.iter().any(|path| {
+                    path.len() <= parts.len()
+                        && path.iter().zip(parts).all(|(a, b)| *a == b.as_str())
+                })
+            })
+            .collect();
+        Self { mask: Some(mask) }
+    }
+
+    /// Returns true if the leaf column with index `leaf_idx` is selected
+    pub fn leaf_included(&self, leaf_idx: usize) -> bool {
+        self.mask.as_ref().map_or(true, |mask| mask[leaf_idx])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::schema::parser::parse_message_type;
+
+    fn test_schema() -> SchemaDescriptor {
+        let message_type = "
+        message test_schema {
+            REQUIRED INT32 id;
+            OPTIONAL group address {
+                OPTIONAL BYTE_ARRAY street (UTF8);
+                OPTIONAL group location {
+                    REQUIRED DOUBLE lat;
+                    REQUIRED DOUBLE lon;
+                }
+                OPTIONAL BYTE_ARRAY city (UTF8);
+            }
+            OPTIONAL BYTE_ARRAY name (UTF8);
+        }
+        ";
+        SchemaDescriptor::new(Arc::new(parse_message_type(message_type).unwrap()))
+    }
+
+    fn included(mask: &ProjectionMask, num_columns: usize) -> Vec<usize> {
+        (0..num_columns)
+            .filter(|idx| mask.leaf_included(*idx))
+            .collect()
+    }
+
+    #[test]
+    fn test_projection_mask() {
+        let schema = test_schema();
+        assert_eq!(6, schema.num_columns());
+
+        assert_eq!(
+            vec![0, 1, 2, 3, 4, 5],
+            included(&ProjectionMask::all(), schema.num_columns())
+        );
+        assert_eq!(
+            vec![0, 4],
+            included(&ProjectionMask::leaves(&schema, vec![4, 0]), 6)
+        );
+        assert_eq!(
+            vec![1, 2, 3, 4, 5],
+            included(&ProjectionMask::roots(&schema, vec![1, 2]), 6)
+        );
+        assert_eq!(
+            vec![0],
+            included(&ProjectionMask::roots(&schema, vec![0]), 6)
+        );
+        assert_eq!(
+            vec![2, 3, 4],
+            included(
+                &ProjectionMask::columns(
+                    &schema,
+                    vec!["address.location", "address.city"]
+                ),
+                6
+            )
+        );
+        assert_eq!(
+            vec![0, 3],
+            included(
+                &ProjectionMask::columns(&schema, vec!["id", "address.location.lon"]),
+                6
+            )
+        );
+        // a partial name does not match a column
+        assert_eq!(
+            Vec::<usize>::new(),
+            included(&ProjectionMask::columns(&schema, vec!["address.loc"]), 6)
+        );
+    }
+}
@@ -0,0 +1,279 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains an [`ArrayReader`] that decodes the projected columns of each row group
+//! on multiple threads.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::io::Read;
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::array::{new_empty_array, Array, ArrayRef, StructArray};
+use arrow::compute::concat;
+use arrow::datatypes::{DataType as ArrowType, Field};
+use arrow::record_batch::RecordBatchReader;
+use rayon::prelude::*;
+use rayon::ThreadPool;
+
+use crate::arrow::array_reader::ArrayReader;
+use crate::arrow::arrow_reader::ParquetFileArrowReader;
+use crate::arrow::in_memory::InMemoryColumnChunks;
+use crate::arrow::ProjectionMask;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::ParquetMetaData;
+use crate::file::reader::{ChunkReader, FileReader, SerializedFileReader};
+use crate::util::cursor::SliceableCursor;
+
+/// The thread pool that the columns of a row group are decoded on
+#[derive(Debug, Clone)]
+pub(crate) enum DecodePool {
+    /// The global rayon thread pool
+    Global,
+    /// A thread pool provided by the user
+    Custom(Arc<ThreadPool>),
+}
+
+impl DecodePool {
+    fn install<OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        match self {
+            DecodePool::Global => op(),
+            DecodePool::Custom(pool) => pool.install(op),
+        }
+    }
+}
+
+/// An [`ArrayReader`] that reads a struct array of the projected root columns.
+///
+/// Each row group is decoded in full before any of its rows are returned, with the
+/// column chunks of every projected root column read into memory and then decoded
+/// on a thread of the [`DecodePool`]. The decoded row group is then sliced into
+/// batches.
+pub(crate) struct ParallelRowGroupReader<T: ChunkReader> {
+    file_reader: Arc<SerializedFileReader<T>>,
+    /// The projected leaf columns of each projected root column, in schema order
+    root_leaves: Vec<Vec<usize>>,
+    int96_type: ArrowType,
+    pool: DecodePool,
+    data_type: ArrowType,
+    /// The index of the next row group of `file_reader` to decode
+    next_row_group: usize,
+    /// The decoded row group that is being returned, and the number of its rows
+    /// that have been returned so far
+    current: Option<(ArrayRef, usize)>,
+}
+
+impl<T: 'static + ChunkReader> ParallelRowGroupReader<T> {
+    pub(crate) fn try_new(
+        file_reader: Arc<SerializedFileReader<T>>,
+        mask: ProjectionMask,
+        int96_type: ArrowType,
+        pool: DecodePool,
+    ) -> Result<Self> {
+        let schema_descr = file_reader.metadata().file_metadata().schema_descr_ptr();
+        let root_fields = schema_descr.root_schema().get_fields();
+
+        let mut root_leaves: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for leaf in (0..schema_descr.num_columns()).filter(|i| mask.leaf_included(*i)) {
+            let root = schema_descr.get_column_root_ptr(leaf);
+            let root_idx = root_fields
+                .iter()
+                .position(|field| Arc::ptr_eq(field, &root))
+                .expect("leaf column must have a root column");
+            root_leaves.entry(root_idx).or_default().push(leaf);
+        }
+
+        // The data type of the batches is that of a reader over no row groups, which
+        // does not read any data
+        let mut empty_reader = ParquetFileArrowReader::new(Arc::new(
+            SerializedFileReader::new_with_metadata(
+                InMemoryColumnChunks { chunks: vec![] },
+                file_reader.metadata().select_row_groups(&[]),
+            ),
+        ));
+        empty_reader.int96_type = int96_type.clone();
+        let schema = empty_reader.get_record_reader_by_mask(mask, 1)?.schema();
+        let data_type = ArrowType::Struct(schema.fields().clone());
+
+        Ok(Self {
+            file_reader,
+            root_leaves: root_leaves.into_values().collect(),
+            int96_type,
+            pool,
+            data_type,
+            next_row_group: 0,
+            current: None,
+        })
+    }
+
+    /// Reads the projected column chunks of row group `idx` into memory, and decodes
+    /// each projected root column on a thread of the pool
+    fn decode_row_group(&self, idx: usize) -> Result<ArrayRef> {
+        let metadata = self.file_reader.metadata();
+        let schema_descr = metadata.file_metadata().schema_descr();
+        let row_group = metadata.row_group(idx);
+        let num_rows = row_group.num_rows() as usize;
+        let skipped_rows = self
+            .file_reader
+            .skipped_rows()
+            .get(idx)
+            .cloned()
+            .unwrap_or_default();
+
+        let mut tasks = Vec::with_capacity(self.root_leaves.len());
+        for leaves in &self.root_leaves {
+            let mut chunks = Vec::with_capacity(leaves.len());
+            for leaf in leaves {
+                let (start, length) = row_group.column(*leaf).byte_range();
+                let mut data = Vec::with_capacity(length as usize);
+                self.file_reader
+                    .chunk_reader()
+                    .get_read(start, length as usize)?
+                    .read_to_end(&mut data)?;
+                chunks.push((start, SliceableCursor::new(data)));
+            }
+            let mask = ProjectionMask::leaves(schema_descr, leaves.iter().copied());
+            tasks.push(DecodeTask {
+                metadata: metadata.select_row_groups(&[idx]),
+                chunks: InMemoryColumnChunks { chunks },
+                skipped_rows: skipped_rows.clone(),
+                mask,
+                int96_type: self.int96_type.clone(),
+                num_rows,
+            });
+        }
+
+        let columns = self.pool.install(|| {
+            tasks
+                .into_par_iter()
+                .map(DecodeTask::decode)
+                .collect::<Result<Vec<_>>>()
+        })?;
+
+        let fields = match &self.data_type {
+            ArrowType::Struct(fields) => fields,
+            _ => unreachable!("Parallel row group reader's data type is not struct!"),
+        };
+        let columns: Vec<(Field, ArrayRef)> = fields
+            .iter()
+            .zip(columns)
+            .map(|(field, column)| {
+                let column = column.unwrap_or_else(|| new_empty_array(field.data_type()));
+                (field.clone(), column)
+            })
+            .collect();
+        Ok(Arc::new(StructArray::from(columns)))
+    }
+
+    /// Moves on to the next row group if all rows of the current one have been
+    /// returned, returning false if there are no more rows
+    fn advance(&mut self) -> Result<bool> {
+        loop {
+            match &self.current {
+                Some((array, offset)) if *offset < array.len() => return Ok(true),
+                _ => {}
+            }
+            if self.next_row_group == self.file_reader.metadata().num_row_groups() {
+                return Ok(false);
+            }
+            let array = self.decode_row_group(self.next_row_group)?;
+            self.next_row_group += 1;
+            self.current = Some((array, 0));
+        }
+    }
+}
+
+impl<T: 'static + ChunkReader> ArrayReader for ParallelRowGroupReader<T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_data_type(&self) -> &ArrowType {
+        &self.data_type
+    }
+
+    fn next_batch(&mut self, batch_size: usize) -> Result<ArrayRef> {
+        let mut arrays = vec![];
+        let mut remaining = batch_size;
+        while remaining > 0 && self.advance()? {
+            let (array, offset) = self.current.as_mut().unwrap();
+            let len = remaining.min(array.len() - *offset);
+            arrays.push(array.slice(*offset, len));
+            *offset += len;
+            remaining -= len;
+        }
+
+        match arrays.as_slice() {
+            [] => Ok(new_empty_array(&self.data_type)),
+            [array] => Ok(array.clone()),
+            _ => Ok(concat(
+                &arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>(),
+            )?),
+        }
+    }
+
+    fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        let mut skipped = 0;
+        while skipped < num_records && self.advance()? {
+            let (array, offset) = self.current.as_mut().unwrap();
+            let len = (num_records - skipped).min(array.len() - *offset);
+            *offset += len;
+            skipped += len;
+        }
+        Ok(skipped)
+    }
+
+    fn get_def_levels(&self) -> Option<&[i16]> {
+        None
+    }
+
+    fn get_rep_levels(&self) -> Option<&[i16]> {
+        None
+    }
+}
+
+/// Decodes a root column of a row group from its column chunks in memory
+struct DecodeTask {
+    metadata: ParquetMetaData,
+    chunks: InMemoryColumnChunks,
+    skipped_rows: Vec<Range<usize>>,
+    mask: ProjectionMask,
+    int96_type: ArrowType,
+    num_rows: usize,
+}
+
+impl DecodeTask {
+    /// Returns the decoded column, or `None` if all of its rows were skipped
+    fn decode(self) -> Result<Option<ArrayRef>> {
+        let mut file_reader =
+            SerializedFileReader::new_with_metadata(self.chunks, self.metadata);
+        file_reader.set_skipped_rows(vec![self.skipped_rows]);
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(file_reader));
+        arrow_reader.int96_type = self.int96_type;
+        let mut reader =
+            arrow_reader.get_record_reader_by_mask(self.mask, self.num_rows.max(1))?;
+        match reader.next() {
+            Some(batch) => Ok(Some(batch?.column(0).clone())),
+            None => Ok(None),
+        }
+    }
+}
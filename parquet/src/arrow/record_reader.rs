@@ -137,6 +137,52 @@ impl<T: DataType> RecordReader<T> {
         Ok(records_read)
     }
 
+    /// Try to skip `num_records` of column data, without decoding the values that
+    /// weren't read into internal buffer yet.
+    ///
+    /// Must only be called when there are no records in buffer, i.e. after the records
+    /// read have been consumed and the reader reset.
+    ///
+    /// # Returns
+    ///
+    /// Number of actual records skipped.
+    pub fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        if self.column_reader.is_none() {
+            return Ok(0);
+        }
+        if self.num_records != 0 {
+            return Err(general_err!(
+                "Records read into the buffer must be consumed before skipping"
+            ));
+        }
+
+        // Values that were read ahead into the buffer are discarded first, including
+        // those of the record they end in, whose remaining values are then skipped by
+        // the column reader
+        let mut records_skipped = self.split_records(num_records)?;
+        if records_skipped < num_records && self.in_middle_of_record {
+            self.num_records += 1;
+            self.num_values = self.values_seen;
+            records_skipped += 1;
+        }
+        if self.num_values > 0 {
+            self.consume_def_levels()?;
+            self.consume_rep_levels()?;
+            self.consume_record_data()?;
+            self.consume_bitmap_buffer()?;
+        }
+        self.reset();
+
+        // The column reader is behind the values left in the buffer, if any
+        if self.values_written == 0 {
+            let column_reader = self.column_reader.as_mut().unwrap();
+            records_skipped +=
+                column_reader.skip_records(num_records - records_skipped)?;
+        }
+
+        Ok(records_skipped)
+    }
+
     /// Returns number of records stored in buffer.
     pub fn num_records(&self) -> usize {
         self.num_records
@@ -436,7 +482,7 @@ mod tests {
     use super::RecordReader;
     use crate::basic::Encoding;
     use crate::column::page::Page;
-    use crate::column::page::PageReader;
+    use crate::column::page::{PageMetadata, PageReader};
     use crate::data_type::Int32Type;
     use crate::errors::Result;
     use crate::schema::parser::parse_message_type;
@@ -444,16 +490,18 @@ mod tests {
     use crate::util::test_common::page_util::{DataPageBuilder, DataPageBuilderImpl};
     use arrow::array::{BooleanBufferBuilder, Int16BufferBuilder, Int32BufferBuilder};
     use arrow::bitmap::Bitmap;
+    use std::iter::Peekable;
     use std::sync::Arc;
+    use std::vec::IntoIter;
 
     struct TestPageReader {
-        pages: Box<dyn Iterator<Item = Page>>,
+        pages: Peekable<IntoIter<Page>>,
     }
 
     impl TestPageReader {
         pub fn new(pages: Vec<Page>) -> Self {
             Self {
-                pages: Box::new(pages.into_iter()),
+                pages: pages.into_iter().peekable(),
             }
         }
     }
@@ -462,6 +510,15 @@ mod tests {
         fn get_next_page(&mut self) -> Result<Option<Page>> {
             Ok(self.pages.next())
         }
+
+        fn peek_next_page(&mut self) -> Result<Option<PageMetadata>> {
+            Ok(self.pages.peek().map(PageMetadata::from))
+        }
+
+        fn skip_next_page(&mut self) -> Result<()> {
+            self.pages.next();
+            Ok(())
+        }
     }
 
     #[test]
@@ -118,6 +118,45 @@ pub fn parquet_to_arrow_schema_by_columns<T>(
     column_indices: T,
     key_value_metadata: &Option<Vec<KeyValue>>,
 ) -> Result<Schema>
+where
+    T: IntoIterator<Item = usize>,
+{
+    parquet_to_arrow_schema_by_columns_inner(
+        parquet_schema,
+        column_indices,
+        key_value_metadata,
+        &DEFAULT_INT96_TYPE,
+    )
+}
+
+/// The arrow type INT96 columns are converted to, unless configured otherwise
+const DEFAULT_INT96_TYPE: DataType = DataType::Timestamp(TimeUnit::Nanosecond, None);
+
+/// Convert Parquet schema to Arrow schema including optional metadata, converting
+/// INT96 columns to `int96_type` instead of nanosecond timestamps
+pub(crate) fn parquet_to_arrow_schema_with_int96_type(
+    parquet_schema: &SchemaDescriptor,
+    key_value_metadata: &Option<Vec<KeyValue>>,
+    int96_type: &DataType,
+) -> Result<Schema> {
+    let mut metadata = parse_key_value_metadata(key_value_metadata).unwrap_or_default();
+    match metadata.remove(super::ARROW_SCHEMA_META_KEY) {
+        Some(encoded) => get_arrow_schema_from_metadata(&encoded),
+        None => parquet_to_arrow_schema_by_columns_inner(
+            parquet_schema,
+            0..parquet_schema.columns().len(),
+            key_value_metadata,
+            int96_type,
+        ),
+    }
+}
+
+fn parquet_to_arrow_schema_by_columns_inner<T>(
+    parquet_schema: &SchemaDescriptor,
+    column_indices: T,
+    key_value_metadata: &Option<Vec<KeyValue>>,
+    int96_type: &DataType,
+) -> Result<Schema>
 where
     T: IntoIterator<Item = usize>,
 {
@@ -138,50 +177,117 @@ where
     let mut base_nodes_set = HashSet::new();
     let mut leaves = HashSet::new();
 
-    enum FieldType<'a> {
-        Parquet(&'a Type),
-        Arrow(Field),
-    }
-
     for c in column_indices {
-        let column = parquet_schema.column(c);
-        let name = column.name();
-
-        if let Some(field) = arrow_schema_metadata
-            .as_ref()
-            .and_then(|schema| schema.field_with_name(name).ok().cloned())
-        {
-            base_nodes.push(FieldType::Arrow(field));
-        } else {
-            let column = column.self_type() as *const Type;
-            let root = parquet_schema.get_column_root(c);
-            let root_raw_ptr = root as *const Type;
-
-            leaves.insert(column);
-            if !base_nodes_set.contains(&root_raw_ptr) {
-                base_nodes.push(FieldType::Parquet(root));
-                base_nodes_set.insert(root_raw_ptr);
-            }
+        let column = parquet_schema.column(c).self_type() as *const Type;
+        let root = parquet_schema.get_column_root(c);
+        let root_raw_ptr = root as *const Type;
+
+        leaves.insert(column);
+        if !base_nodes_set.contains(&root_raw_ptr) {
+            base_nodes.push(root);
+            base_nodes_set.insert(root_raw_ptr);
         }
     }
 
     base_nodes
         .into_iter()
-        .map(|t| match t {
-            FieldType::Parquet(t) => ParquetTypeConverter::new(t, &leaves).to_field(),
-            FieldType::Arrow(f) => Ok(Some(f)),
+        .map(|root| {
+            // The embedded Arrow schema is keyed by root field name, and is only
+            // taken verbatim if all of the root's leaves have been selected
+            let hint = arrow_schema_metadata
+                .as_ref()
+                .and_then(|schema| schema.field_with_name(root.name()).ok());
+            match hint {
+                Some(hint) if is_fully_selected(parquet_schema, root, &leaves) => {
+                    Ok(Some(hint.clone()))
+                }
+                Some(hint) => ParquetTypeConverter::new(root, &leaves, int96_type)
+                    .to_field()
+                    .map(|field| field.map(|field| apply_arrow_hint(field, hint))),
+                None => ParquetTypeConverter::new(root, &leaves, int96_type).to_field(),
+            }
         })
         .collect::<Result<Vec<Option<Field>>>>()
         .map(|result| result.into_iter().flatten().collect::<Vec<Field>>())
         .map(|fields| Schema::new_with_metadata(fields, metadata))
 }
 
+/// Returns true if every leaf column below `root` is contained in `leaves`
+fn is_fully_selected(
+    parquet_schema: &SchemaDescriptor,
+    root: &Type,
+    leaves: &HashSet<*const Type>,
+) -> bool {
+    (0..parquet_schema.num_columns())
+        .filter(|i| std::ptr::eq(parquet_schema.get_column_root(*i), root))
+        .all(|i| leaves.contains(&(parquet_schema.column(i).self_type() as *const Type)))
+}
+
+/// Applies the type information of `hint`, a field of the embedded Arrow schema, to
+/// `field`, a field converted from a partially selected Parquet group.
+///
+/// Struct children are matched by name, so that children that have been projected
+/// out are omitted while the remaining children keep their Arrow types, such as
+/// dictionaries, timezones and unsigned integers, as well as their metadata.
+fn apply_arrow_hint(field: Field, hint: &Field) -> Field {
+    let data_type = match (field.data_type(), hint.data_type()) {
+        (DataType::Struct(children), DataType::Struct(hint_children)) => {
+            DataType::Struct(
+                children
+                    .iter()
+                    .map(|child| {
+                        match hint_children.iter().find(|h| h.name() == child.name()) {
+                            Some(hint_child) => {
+                                apply_arrow_hint(child.clone(), hint_child)
+                            }
+                            None => child.clone(),
+                        }
+                    })
+                    .collect(),
+            )
+        }
+        (DataType::List(item), DataType::List(hint_item)) => {
+            DataType::List(Box::new(apply_arrow_hint(item.as_ref().clone(), hint_item)))
+        }
+        (DataType::List(item), DataType::LargeList(hint_item)) => DataType::LargeList(
+            Box::new(apply_arrow_hint(item.as_ref().clone(), hint_item)),
+        ),
+        (DataType::List(item), DataType::FixedSizeList(hint_item, size)) => {
+            DataType::FixedSizeList(
+                Box::new(apply_arrow_hint(item.as_ref().clone(), hint_item)),
+                *size,
+            )
+        }
+        (DataType::Map(entries, _), DataType::Map(hint_entries, sorted)) => {
+            DataType::Map(
+                Box::new(apply_arrow_hint(entries.as_ref().clone(), hint_entries)),
+                *sorted,
+            )
+        }
+        // a nested Parquet type that doesn't match the hint is kept as converted
+        (DataType::Struct(_), _) | (DataType::List(_), _) | (DataType::Map(_, _), _) => {
+            field.data_type().clone()
+        }
+        (_, hint_type) => hint_type.clone(),
+    };
+
+    let mut result = Field::new_dict(
+        hint.name(),
+        data_type,
+        hint.is_nullable(),
+        hint.dict_id().unwrap_or_default(),
+        hint.dict_is_ordered().unwrap_or_default(),
+    );
+    result.set_metadata(hint.metadata().clone());
+    result
+}
+
 /// Try to convert Arrow schema metadata into a schema
 fn get_arrow_schema_from_metadata(encoded_meta: &str) -> Result<Schema> {
     let decoded = base64::decode(encoded_meta);
     match decoded {
         Ok(bytes) => {
-            let slice = if bytes[0..4] == [255u8; 4] {
+            let slice = if bytes.len() > 8 && bytes[0..4] == [255u8; 4] {
                 &bytes[8..]
             } else {
                 bytes.as_slice()
@@ -304,13 +410,14 @@ pub fn parquet_to_arrow_field(parquet_column: &ColumnDescriptor) -> Result<Field
     let mut leaves = HashSet::new();
     leaves.insert(parquet_column.self_type() as *const Type);
 
-    ParquetTypeConverter::new(schema, &leaves)
+    ParquetTypeConverter::new(schema, &leaves, &DEFAULT_INT96_TYPE)
         .to_field()
         .map(|opt| opt.unwrap())
 }
 
 pub fn decimal_length_from_precision(precision: usize) -> usize {
-    (10.0_f64.powi(precision as i32).log2() / 8.0).ceil() as usize
+    // one extra bit is needed for the sign of the two's complement representation
+    ((10.0_f64.powi(precision as i32).log2() + 1.0) / 8.0).ceil() as usize
 }
 
 /// Convert an arrow field to a parquet `Type`
@@ -449,22 +556,21 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
                 .build()
         }
         DataType::Decimal(precision, scale) => {
-            // Decimal precision determines the Parquet physical type to use.
-            // TODO(ARROW-12018): Enable the below after ARROW-10818 Decimal support
-            //
-            // let (physical_type, length) = if *precision > 1 && *precision <= 9 {
-            //     (PhysicalType::INT32, -1)
-            // } else if *precision <= 18 {
-            //     (PhysicalType::INT64, -1)
-            // } else {
-            //     (
-            //         PhysicalType::FIXED_LEN_BYTE_ARRAY,
-            //         decimal_length_from_precision(*precision) as i32,
-            //     )
-            // };
-            Type::primitive_type_builder(name, PhysicalType::FIXED_LEN_BYTE_ARRAY)
+            // Decimal precision determines the Parquet physical type to use,
+            // following the narrowest type recommended by the Parquet format
+            let (physical_type, length) = if *precision <= 9 {
+                (PhysicalType::INT32, -1)
+            } else if *precision <= 18 {
+                (PhysicalType::INT64, -1)
+            } else {
+                (
+                    PhysicalType::FIXED_LEN_BYTE_ARRAY,
+                    decimal_length_from_precision(*precision) as i32,
+                )
+            };
+            Type::primitive_type_builder(name, physical_type)
                 .with_repetition(repetition)
-                .with_length(decimal_length_from_precision(*precision) as i32)
+                .with_length(length)
                 .with_logical_type(Some(LogicalType::DECIMAL(DecimalType {
                     scale: *scale as i32,
                     precision: *precision as i32,
@@ -508,6 +614,31 @@ fn arrow_to_parquet_type(field: &Field) -> Result<Type> {
                 .build()
         }
         DataType::Union(_) => unimplemented!("See ARROW-8817."),
+        DataType::Map(entries, _) => match entries.data_type() {
+            DataType::Struct(fields) if fields.len() == 2 => {
+                if fields[0].is_nullable() {
+                    return Err(ArrowError(
+                        "Parquet map keys must not be nullable".to_string(),
+                    ));
+                }
+                Type::group_type_builder(name)
+                    .with_fields(&mut vec![Arc::new(
+                        Type::group_type_builder(entries.name())
+                            .with_fields(&mut vec![
+                                Arc::new(arrow_to_parquet_type(&fields[0])?),
+                                Arc::new(arrow_to_parquet_type(&fields[1])?),
+                            ])
+                            .with_repetition(Repetition::REPEATED)
+                            .build()?,
+                    )])
+                    .with_logical_type(Some(LogicalType::MAP(Default::default())))
+                    .with_repetition(repetition)
+                    .build()
+            }
+            _ => Err(ArrowError(
+                "Arrow map entries must be a struct of a key and a value".to_string(),
+            )),
+        },
         DataType::Dictionary(_, ref value) => {
             // Dictionary encoding not handled at the schema level
             let dict_field = Field::new(name, *value.clone(), field.is_nullable());
@@ -521,13 +652,20 @@ struct ParquetTypeConverter<'a> {
     schema: &'a Type,
     /// This is the columns that need to be converted to arrow schema.
     columns_to_convert: &'a HashSet<*const Type>,
+    /// The arrow type INT96 columns are converted to.
+    int96_type: &'a DataType,
 }
 
 impl<'a> ParquetTypeConverter<'a> {
-    fn new(schema: &'a Type, columns_to_convert: &'a HashSet<*const Type>) -> Self {
+    fn new(
+        schema: &'a Type,
+        columns_to_convert: &'a HashSet<*const Type>,
+        int96_type: &'a DataType,
+    ) -> Self {
         Self {
             schema,
             columns_to_convert,
+            int96_type,
         }
     }
 
@@ -535,6 +673,7 @@ impl<'a> ParquetTypeConverter<'a> {
         Self {
             schema: other,
             columns_to_convert: self.columns_to_convert,
+            int96_type: self.int96_type,
         }
     }
 }
@@ -625,7 +764,7 @@ impl ParquetTypeConverter<'_> {
             PhysicalType::BOOLEAN => Ok(DataType::Boolean),
             PhysicalType::INT32 => self.from_int32(),
             PhysicalType::INT64 => self.from_int64(),
-            PhysicalType::INT96 => Ok(DataType::Timestamp(TimeUnit::Nanosecond, None)),
+            PhysicalType::INT96 => Ok(self.int96_type.clone()),
             PhysicalType::FLOAT => Ok(DataType::Float32),
             PhysicalType::DOUBLE => Ok(DataType::Float64),
             PhysicalType::BYTE_ARRAY => self.from_byte_array(),
@@ -778,6 +917,8 @@ impl ParquetTypeConverter<'_> {
             (None, ConvertedType::BSON) => Ok(DataType::Binary),
             (None, ConvertedType::ENUM) => Ok(DataType::Binary),
             (None, ConvertedType::UTF8) => Ok(DataType::Utf8),
+            (Some(LogicalType::DECIMAL(_)), _) => Ok(self.to_decimal()),
+            (None, ConvertedType::DECIMAL) => Ok(self.to_decimal()),
             (logical, converted) => Err(ArrowError(format!(
                 "Unable to convert parquet BYTE_ARRAY logical type {:?} or converted type {}",
                 logical, converted
@@ -808,6 +949,10 @@ impl ParquetTypeConverter<'_> {
             ) {
                 (Some(LogicalType::LIST(_)), _) => self.to_list(),
                 (None, ConvertedType::LIST) => self.to_list(),
+                (Some(LogicalType::MAP(_)), _) => self.to_map(),
+                (None, ConvertedType::MAP) | (None, ConvertedType::MAP_KEY_VALUE) => {
+                    self.to_map()
+                }
                 _ => self.to_struct(),
             }
         }
@@ -838,6 +983,42 @@ impl ParquetTypeConverter<'_> {
         }
     }
 
+    /// Converts a parquet map to arrow map.
+    ///
+    /// A map's repeated key_value group is converted to the map's entries. If
+    /// only one of the key and the value is converted, or the group doesn't
+    /// follow the map layout, the map is converted like any other group.
+    fn to_map(&self) -> Result<Option<DataType>> {
+        let key_value = match self.schema.get_fields() {
+            [key_value]
+                if key_value.is_group()
+                    && key_value.get_basic_info().repetition()
+                        == Repetition::REPEATED
+                    && key_value.get_fields().len() == 2 =>
+            {
+                key_value
+            }
+            _ => return self.to_struct(),
+        };
+
+        match self.clone_with_schema(key_value).to_struct()? {
+            Some(DataType::Struct(mut fields)) if fields.len() == 2 => {
+                // map keys are never null
+                fields[0] =
+                    Field::new(fields[0].name(), fields[0].data_type().clone(), false);
+                Ok(Some(DataType::Map(
+                    Box::new(Field::new(
+                        key_value.name(),
+                        DataType::Struct(fields),
+                        false,
+                    )),
+                    false,
+                )))
+            }
+            _ => self.to_struct(),
+        }
+    }
+
     /// Converts a parquet list to arrow list.
     ///
     /// To fully understand this algorithm, please refer to
@@ -1349,6 +1530,68 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parquet_map() {
+        let message_type = "
+        message test_schema {
+          OPTIONAL GROUP my_map (MAP) {
+            REPEATED GROUP key_value {
+              REQUIRED BINARY key (STRING);
+              OPTIONAL INT32 value;
+            }
+          }
+        }
+        ";
+        let parquet_group_type = parse_message_type(message_type).unwrap();
+        let parquet_schema = SchemaDescriptor::new(Arc::new(parquet_group_type));
+
+        let map_field = Field::new(
+            "my_map",
+            DataType::Map(
+                Box::new(Field::new(
+                    "key_value",
+                    DataType::Struct(vec![
+                        Field::new("key", DataType::Utf8, false),
+                        Field::new("value", DataType::Int32, true),
+                    ]),
+                    false,
+                )),
+                false,
+            ),
+            true,
+        );
+
+        let converted_arrow_schema =
+            parquet_to_arrow_schema(&parquet_schema, &None).unwrap();
+        assert_eq!(converted_arrow_schema.fields(), &vec![map_field.clone()]);
+
+        // converting the Arrow map back produces the same Parquet schema
+        let converted_parquet_schema =
+            arrow_to_parquet_schema(&Schema::new(vec![map_field])).unwrap();
+        assert_eq!(
+            converted_parquet_schema.root_schema().get_fields(),
+            parquet_schema.root_schema().get_fields()
+        );
+
+        // a map whose keys are not selected is converted like a struct
+        let converted_arrow_schema =
+            parquet_to_arrow_schema_by_columns(&parquet_schema, vec![1], &None).unwrap();
+        let expected = Field::new(
+            "my_map",
+            DataType::Struct(vec![Field::new(
+                "key_value",
+                DataType::List(Box::new(Field::new(
+                    "key_value",
+                    DataType::Struct(vec![Field::new("value", DataType::Int32, true)]),
+                    true,
+                ))),
+                true,
+            )]),
+            true,
+        );
+        assert_eq!(converted_arrow_schema.fields(), &vec![expected]);
+    }
+
     #[test]
     fn test_nested_schema_partial() {
         let mut arrow_fields = Vec::new();
@@ -1870,6 +2113,63 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_arrow_schema_roundtrip_nested_projection() -> Result<()> {
+        let mut count = Field::new("count", DataType::UInt32, true);
+        count.set_metadata(Some(
+            [("unit".to_string(), "items".to_string())]
+                .iter()
+                .cloned()
+                .collect(),
+        ));
+        let tag = Field::new_dict(
+            "tag",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            true,
+            7,
+            false,
+        );
+        let ts = Field::new(
+            "ts",
+            DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".to_string())),
+            false,
+        );
+        let mut nested = Field::new(
+            "nested",
+            DataType::Struct(vec![tag, ts.clone(), count.clone()]),
+            true,
+        );
+        nested.set_metadata(Some(
+            [("origin".to_string(), "sensor".to_string())]
+                .iter()
+                .cloned()
+                .collect(),
+        ));
+        let id = Field::new("id", DataType::UInt64, false);
+        let schema = Schema::new(vec![id.clone(), nested.clone()]);
+
+        let file = get_temp_file("test_arrow_schema_roundtrip_nested.parquet", &[]);
+        let mut writer = ArrowWriter::try_new(
+            file.try_clone().unwrap(),
+            Arc::new(schema.clone()),
+            None,
+        )?;
+        writer.close()?;
+
+        let parquet_reader = SerializedFileReader::try_from(file)?;
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(parquet_reader));
+        assert_eq!(schema, arrow_reader.get_schema()?);
+
+        // project out `nested.tag`, the remaining children keep their arrow types
+        let read_schema = arrow_reader.get_schema_by_columns(vec![0, 2, 3], true)?;
+        let mut expected_nested =
+            Field::new("nested", DataType::Struct(vec![ts, count]), true);
+        expected_nested.set_metadata(nested.metadata().clone());
+        assert_eq!(read_schema, Schema::new(vec![id, expected_nested]));
+
+        Ok(())
+    }
+
     #[test]
     #[ignore = "Roundtrip of lists currently fails because we don't check their types correctly in the Arrow schema"]
     fn test_arrow_schema_roundtrip_lists() -> Result<()> {
@@ -0,0 +1,543 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains [`RowSelection`], which describes the rows of a parquet file to read.
+
+use std::collections::VecDeque;
+use std::ops::Range;
+
+use arrow::array::{Array, BooleanArray};
+
+use crate::file::page_index::PageLocation;
+
+/// A run of rows that are either all selected or all skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowSelector {
+    /// The number of rows in the run
+    pub row_count: usize,
+    /// Whether the rows are skipped rather than selected
+    pub skip: bool,
+}
+
+impl RowSelector {
+    /// Select the next `row_count` rows
+    pub fn select(row_count: usize) -> Self {
+        Self {
+            row_count,
+            skip: false,
+        }
+    }
+
+    /// Skip the next `row_count` rows
+    pub fn skip(row_count: usize) -> Self {
+        Self {
+            row_count,
+            skip: true,
+        }
+    }
+}
+
+/// The rows to read from a parquet file, as a sequence of [`RowSelector`]s that
+/// select or skip consecutive runs of rows.
+///
+/// A [`RowSelection`] may be shorter than the data it is applied to, in which case
+/// the remaining rows are selected. It can be created from the results of an
+/// external index, or from the page index of the file, see
+/// [`crate::file::page_index`].
+///
+/// ```
+/// # use parquet::arrow::arrow_reader::{RowSelection, RowSelector};
+/// let selection = RowSelection::from(vec![
+///     RowSelector::skip(10),
+///     RowSelector::select(5),
+///     RowSelector::select(5),
+///     RowSelector::skip(0),
+/// ]);
+/// // adjacent selectors of the same kind are merged and empty ones removed
+/// let expected = vec![RowSelector::skip(10), RowSelector::select(10)];
+/// assert_eq!(selection.iter().copied().collect::<Vec<_>>(), expected);
+/// assert_eq!(selection.row_count(), 10);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RowSelection {
+    selectors: Vec<RowSelector>,
+}
+
+impl RowSelection {
+    /// Creates a selection of the rows for which `filters` are true, where the
+    /// filters are the concatenation of consecutive rows. Nulls are not selected.
+    pub fn from_filters(filters: &[BooleanArray]) -> Self {
+        let mut selectors: Vec<RowSelector> = Vec::new();
+        for filter in filters {
+            for idx in 0..filter.len() {
+                let skip = filter.is_null(idx) || !filter.value(idx);
+                push_selector(&mut selectors, RowSelector { row_count: 1, skip });
+            }
+        }
+        Self { selectors }
+    }
+
+    /// Creates a selection of the rows in `ranges`, out of a total of `total_rows`
+    /// rows. The ranges must be sorted and must not overlap.
+    pub fn from_consecutive_ranges<I: IntoIterator<Item = Range<usize>>>(
+        ranges: I,
+        total_rows: usize,
+    ) -> Self {
+        let mut selectors = Vec::new();
+        let mut last_end = 0;
+        for range in ranges {
+            assert!(
+                range.start >= last_end && range.end <= total_rows,
+                "ranges must be sorted, non-overlapping and within the total rows"
+            );
+            push_selector(&mut selectors, RowSelector::skip(range.start - last_end));
+            push_selector(&mut selectors, RowSelector::select(range.end - range.start));
+            last_end = range.end;
+        }
+        push_selector(&mut selectors, RowSelector::skip(total_rows - last_end));
+        Self { selectors }
+    }
+
+    /// Splits off the first `row_count` rows of this selection, returning them and
+    /// leaving the remaining rows in `self`.
+    pub fn split_off(&mut self, row_count: usize) -> Self {
+        let mut total = 0;
+        let mut split = self.selectors.len();
+        for (idx, selector) in self.selectors.iter().enumerate() {
+            if total >= row_count {
+                split = idx;
+                break;
+            }
+            total += selector.row_count;
+        }
+
+        let mut remaining = self.selectors.split_off(split);
+        if total > row_count {
+            let last = self.selectors.last_mut().unwrap();
+            let overflow = total - row_count;
+            last.row_count -= overflow;
+            remaining.insert(
+                0,
+                RowSelector {
+                    row_count: overflow,
+                    skip: last.skip,
+                },
+            );
+        }
+        Self {
+            selectors: std::mem::replace(&mut self.selectors, remaining),
+        }
+    }
+
+    /// Returns `true` if this selection selects any rows
+    pub fn selects_any(&self) -> bool {
+        self.selectors.iter().any(|selector| !selector.skip)
+    }
+
+    /// Returns the number of rows selected
+    pub fn row_count(&self) -> usize {
+        self.selectors
+            .iter()
+            .filter(|selector| !selector.skip)
+            .map(|selector| selector.row_count)
+            .sum()
+    }
+
+    /// Skips the first `offset` rows selected by this selection
+    pub fn offset(self, offset: usize) -> Self {
+        let mut remaining = offset;
+        let mut selectors = Vec::with_capacity(self.selectors.len() + 1);
+        for selector in self.selectors {
+            if selector.skip || remaining == 0 {
+                push_selector(&mut selectors, selector);
+            } else if selector.row_count <= remaining {
+                remaining -= selector.row_count;
+                push_selector(&mut selectors, RowSelector::skip(selector.row_count));
+            } else {
+                push_selector(&mut selectors, RowSelector::skip(remaining));
+                push_selector(
+                    &mut selectors,
+                    RowSelector::select(selector.row_count - remaining),
+                );
+                remaining = 0;
+            }
+        }
+        Self { selectors }
+    }
+
+    /// Skips the rows selected by this selection after the first `limit` selected
+    /// rows
+    pub fn limit(self, limit: usize) -> Self {
+        let mut remaining = limit;
+        let mut selectors = Vec::with_capacity(self.selectors.len() + 1);
+        for selector in self.selectors {
+            if selector.skip || selector.row_count <= remaining {
+                if !selector.skip {
+                    remaining -= selector.row_count;
+                }
+                push_selector(&mut selectors, selector);
+            } else {
+                push_selector(&mut selectors, RowSelector::select(remaining));
+                push_selector(
+                    &mut selectors,
+                    RowSelector::skip(selector.row_count - remaining),
+                );
+                remaining = 0;
+            }
+        }
+        Self { selectors }
+    }
+
+    /// Returns the selection of the rows selected by `other`, where `other` applies
+    /// to the rows selected by this selection rather than to all rows. Selected rows
+    /// past the end of `other` remain selected.
+    pub fn and_then(&self, other: &Self) -> Self {
+        let mut selectors = Vec::with_capacity(self.selectors.len());
+        let mut others = other.selectors.iter().copied();
+        let mut current = others.next();
+        for selector in &self.selectors {
+            if selector.skip {
+                push_selector(&mut selectors, *selector);
+                continue;
+            }
+            let mut remaining = selector.row_count;
+            while remaining > 0 {
+                match current.as_mut() {
+                    None => {
+                        push_selector(&mut selectors, RowSelector::select(remaining));
+                        remaining = 0;
+                    }
+                    Some(next) => {
+                        let row_count = next.row_count.min(remaining);
+                        push_selector(
+                            &mut selectors,
+                            RowSelector {
+                                row_count,
+                                skip: next.skip,
+                            },
+                        );
+                        remaining -= row_count;
+                        next.row_count -= row_count;
+                        if next.row_count == 0 {
+                            current = others.next();
+                        }
+                    }
+                }
+            }
+        }
+        Self { selectors }
+    }
+
+    /// Returns an iterator over the [`RowSelector`]s of this selection
+    pub fn iter(&self) -> impl Iterator<Item = &RowSelector> {
+        self.selectors.iter()
+    }
+
+    /// Returns the ranges of rows that are skipped and that start and end on a page
+    /// boundary of every column chunk in `page_locations`, allowing the pages
+    /// containing them to be skipped without affecting the alignment of the columns.
+    ///
+    /// `num_rows` is the number of rows in the row group the pages belong to.
+    pub(crate) fn skipped_page_ranges(
+        &self,
+        page_locations: &[Vec<PageLocation>],
+        num_rows: usize,
+    ) -> Vec<Range<usize>> {
+        if page_locations.is_empty() || page_locations.iter().any(|p| p.is_empty()) {
+            return vec![];
+        }
+
+        // the row offsets at which every column starts a new page
+        let mut boundaries: Vec<usize> = page_locations[0]
+            .iter()
+            .map(|page| page.first_row_index as usize)
+            .chain(std::iter::once(num_rows))
+            .collect();
+        for pages in &page_locations[1..] {
+            boundaries.retain(|boundary| {
+                *boundary == num_rows
+                    || pages
+                        .binary_search_by(|page| {
+                            (page.first_row_index as usize).cmp(boundary)
+                        })
+                        .is_ok()
+            });
+        }
+
+        let skipped = self.skipped_ranges();
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        for window in boundaries.windows(2) {
+            let (start, end) = (window[0], window[1]);
+            let is_skipped = skipped
+                .iter()
+                .any(|range| range.start <= start && end <= range.end);
+            if !is_skipped {
+                continue;
+            }
+            match ranges.last_mut() {
+                Some(last) if last.end == start => last.end = end,
+                _ => ranges.push(start..end),
+            }
+        }
+        ranges
+    }
+
+    /// Returns the ranges of rows skipped by this selection
+    fn skipped_ranges(&self) -> Vec<Range<usize>> {
+        let mut ranges = Vec::new();
+        let mut offset = 0;
+        for selector in &self.selectors {
+            if selector.skip {
+                ranges.push(offset..offset + selector.row_count);
+            }
+            offset += selector.row_count;
+        }
+        ranges
+    }
+
+    /// Returns the selection of the rows that remain once the rows in `ranges`, which
+    /// must be sorted and skipped by this selection, have been removed.
+    pub(crate) fn remove_skipped(&self, ranges: &[Range<usize>]) -> Self {
+        let mut selectors = Vec::with_capacity(self.selectors.len());
+        let mut offset = 0;
+        for selector in &self.selectors {
+            let end = offset + selector.row_count;
+            let mut row_count = selector.row_count;
+            if selector.skip {
+                for range in ranges {
+                    let overlap_start = range.start.max(offset);
+                    let overlap_end = range.end.min(end);
+                    if overlap_start < overlap_end {
+                        row_count -= overlap_end - overlap_start;
+                    }
+                }
+            }
+            push_selector(
+                &mut selectors,
+                RowSelector {
+                    row_count,
+                    skip: selector.skip,
+                },
+            );
+            offset = end;
+        }
+        Self { selectors }
+    }
+}
+
+impl From<Vec<RowSelector>> for RowSelection {
+    fn from(selectors: Vec<RowSelector>) -> Self {
+        let mut merged = Vec::with_capacity(selectors.len());
+        for selector in selectors {
+            push_selector(&mut merged, selector);
+        }
+        Self { selectors: merged }
+    }
+}
+
+impl From<RowSelection> for VecDeque<RowSelector> {
+    fn from(selection: RowSelection) -> Self {
+        selection.selectors.into()
+    }
+}
+
+/// Appends `selector` to `selectors`, merging it with the last selector if they
+/// are of the same kind, and dropping it if it is empty
+fn push_selector(selectors: &mut Vec<RowSelector>, selector: RowSelector) {
+    if selector.row_count == 0 {
+        return;
+    }
+    match selectors.last_mut() {
+        Some(last) if last.skip == selector.skip => last.row_count += selector.row_count,
+        _ => selectors.push(selector),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(first_row_index: i64) -> PageLocation {
+        PageLocation {
+            offset: 0,
+            compressed_page_size: 0,
+            first_row_index,
+        }
+    }
+
+    #[test]
+    fn test_from_filters() {
+        let filters = vec![
+            BooleanArray::from(vec![false, false, true, true]),
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]),
+        ];
+        let selection = RowSelection::from_filters(&filters);
+        assert_eq!(
+            selection.iter().copied().collect::<Vec<_>>(),
+            vec![
+                RowSelector::skip(2),
+                RowSelector::select(3),
+                RowSelector::skip(2),
+                RowSelector::select(1),
+            ]
+        );
+        assert_eq!(selection.row_count(), 4);
+        assert!(selection.selects_any());
+        assert!(!RowSelection::from(vec![RowSelector::skip(5)]).selects_any());
+    }
+
+    #[test]
+    fn test_from_consecutive_ranges() {
+        let selection = RowSelection::from_consecutive_ranges(vec![2..4, 4..6, 8..9], 10);
+        assert_eq!(
+            selection,
+            RowSelection::from(vec![
+                RowSelector::skip(2),
+                RowSelector::select(4),
+                RowSelector::skip(2),
+                RowSelector::select(1),
+                RowSelector::skip(1),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut selection = RowSelection::from(vec![
+            RowSelector::skip(5),
+            RowSelector::select(10),
+            RowSelector::skip(5),
+        ]);
+
+        let empty = selection.split_off(0);
+        assert_eq!(empty, RowSelection::default());
+        assert!(!empty.selects_any());
+
+        let first = selection.split_off(5);
+        assert_eq!(first, RowSelection::from(vec![RowSelector::skip(5)]));
+
+        let second = selection.split_off(4);
+        assert_eq!(second, RowSelection::from(vec![RowSelector::select(4)]));
+
+        let third = selection.split_off(100);
+        assert_eq!(
+            third,
+            RowSelection::from(vec![RowSelector::select(6), RowSelector::skip(5)])
+        );
+        assert_eq!(selection, RowSelection::default());
+    }
+
+    #[test]
+    fn test_offset_limit() {
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(5),
+            RowSelector::select(10),
+            RowSelector::skip(5),
+            RowSelector::select(10),
+        ]);
+
+        assert_eq!(
+            selection.clone().offset(12),
+            RowSelection::from(vec![RowSelector::skip(22), RowSelector::select(8)])
+        );
+        assert_eq!(
+            selection.clone().limit(12),
+            RowSelection::from(vec![
+                RowSelector::skip(5),
+                RowSelector::select(10),
+                RowSelector::skip(5),
+                RowSelector::select(2),
+                RowSelector::skip(8),
+            ])
+        );
+        assert_eq!(
+            selection.clone().offset(8).limit(5),
+            RowSelection::from(vec![
+                RowSelector::skip(13),
+                RowSelector::select(2),
+                RowSelector::skip(5),
+                RowSelector::select(3),
+                RowSelector::skip(7),
+            ])
+        );
+        assert_eq!(selection.clone().offset(20).row_count(), 0);
+        assert_eq!(selection.clone().limit(0).row_count(), 0);
+        assert_eq!(selection.clone().limit(100), selection);
+    }
+
+    #[test]
+    fn test_and_then() {
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(5),
+            RowSelector::select(10),
+            RowSelector::skip(5),
+            RowSelector::select(10),
+        ]);
+        let other = RowSelection::from(vec![
+            RowSelector::select(3),
+            RowSelector::skip(4),
+            RowSelector::select(5),
+            RowSelector::skip(6),
+        ]);
+
+        assert_eq!(
+            selection.and_then(&other),
+            RowSelection::from(vec![
+                RowSelector::skip(5),
+                RowSelector::select(3),
+                RowSelector::skip(4),
+                RowSelector::select(3),
+                RowSelector::skip(5),
+                RowSelector::select(2),
+                RowSelector::skip(6),
+                RowSelector::select(2),
+            ])
+        );
+        assert_eq!(selection.and_then(&RowSelection::default()), selection);
+    }
+
+    #[test]
+    fn test_skipped_page_ranges() {
+        let selection = RowSelection::from(vec![
+            RowSelector::skip(25),
+            RowSelector::select(10),
+            RowSelector::skip(65),
+        ]);
+
+        // both columns have pages starting at rows 0, 20, 40 and 80
+        let pages = vec![
+            vec![page(0), page(10), page(20), page(40), page(80)],
+            vec![page(0), page(20), page(30), page(40), page(60), page(80)],
+        ];
+        let ranges = selection.skipped_page_ranges(&pages, 100);
+        assert_eq!(ranges, vec![0..20, 40..100]);
+
+        let remaining = selection.remove_skipped(&ranges);
+        assert_eq!(
+            remaining,
+            RowSelection::from(vec![
+                RowSelector::skip(5),
+                RowSelector::select(10),
+                RowSelector::skip(5),
+            ])
+        );
+
+        // a column without an offset index prevents page skipping
+        let pages = vec![vec![page(0), page(50)], vec![]];
+        assert!(selection.skipped_page_ranges(&pages, 100).is_empty());
+    }
+}
@@ -250,6 +250,13 @@ pub enum Encoding {
     ///
     /// The ids are encoded using the RLE encoding.
     RLE_DICTIONARY,
+
+    /// Encoding for floating-point data.
+    ///
+    /// K byte-streams are created where K is the size in bytes of the data type.
+    /// The individual bytes of a value are scattered to the corresponding stream and
+    /// the streams are concatenated.
+    BYTE_STREAM_SPLIT,
 }
 
 // ----------------------------------------------------------------------
@@ -701,6 +708,7 @@ impl convert::From<parquet::Encoding> for Encoding {
             parquet::Encoding::DeltaLengthByteArray => Encoding::DELTA_LENGTH_BYTE_ARRAY,
             parquet::Encoding::DeltaByteArray => Encoding::DELTA_BYTE_ARRAY,
             parquet::Encoding::RleDictionary => Encoding::RLE_DICTIONARY,
+            parquet::Encoding::ByteStreamSplit => Encoding::BYTE_STREAM_SPLIT,
         }
     }
 }
@@ -716,6 +724,7 @@ impl convert::From<Encoding> for parquet::Encoding {
             Encoding::DELTA_LENGTH_BYTE_ARRAY => parquet::Encoding::DeltaLengthByteArray,
             Encoding::DELTA_BYTE_ARRAY => parquet::Encoding::DeltaByteArray,
             Encoding::RLE_DICTIONARY => parquet::Encoding::RleDictionary,
+            Encoding::BYTE_STREAM_SPLIT => parquet::Encoding::ByteStreamSplit,
         }
     }
 }
@@ -1580,6 +1589,7 @@ mod tests {
         );
         assert_eq!(Encoding::DELTA_BYTE_ARRAY.to_string(), "DELTA_BYTE_ARRAY");
         assert_eq!(Encoding::RLE_DICTIONARY.to_string(), "RLE_DICTIONARY");
+        assert_eq!(Encoding::BYTE_STREAM_SPLIT.to_string(), "BYTE_STREAM_SPLIT");
     }
 
     #[test]
@@ -1606,6 +1616,10 @@ mod tests {
             Encoding::from(parquet::Encoding::DeltaByteArray),
             Encoding::DELTA_BYTE_ARRAY
         );
+        assert_eq!(
+            Encoding::from(parquet::Encoding::ByteStreamSplit),
+            Encoding::BYTE_STREAM_SPLIT
+        );
     }
 
     #[test]
@@ -1629,6 +1643,10 @@ mod tests {
             parquet::Encoding::DeltaByteArray,
             Encoding::DELTA_BYTE_ARRAY.into()
         );
+        assert_eq!(
+            parquet::Encoding::ByteStreamSplit,
+            Encoding::BYTE_STREAM_SPLIT.into()
+        );
     }
 
     #[test]
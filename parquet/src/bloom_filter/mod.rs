@@ -0,0 +1,387 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Split block bloom filters, as described in the
+//! [Parquet specification](https://github.com/apache/parquet-format/blob/master/BloomFilter.md).
+//!
+//! A bloom filter is written for a column chunk if enabled in the
+//! [`WriterProperties`](crate::file::properties::WriterProperties), and can be read
+//! with [`ColumnChunkMetaData::read_bloom_filter`] to check whether a value may be
+//! contained in the column chunk without reading its data pages. The arrow reader
+//! uses them to skip row groups, see
+//! `ParquetRecordBatchReaderBuilder::with_bloom_filter_pruning`.
+
+use std::hash::Hasher;
+use std::io::{Read, Write};
+
+use byteorder::{ByteOrder, LittleEndian};
+use parquet_format::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+use thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol};
+use twox_hash::XxHash64;
+
+use crate::data_type::AsBytes;
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::ColumnChunkMetaData;
+use crate::file::reader::ChunkReader;
+
+/// Salt values used to compute the bit mask of a block, as defined by the
+/// specification.
+const SALT: [u32; 8] = [
+    0x47b6137b_u32,
+    0x44974d91_u32,
+    0x8824ad5b_u32,
+    0xa2b7289d_u32,
+    0x705495c7_u32,
+    0x2df1424b_u32,
+    0x9efc4947_u32,
+    0x5c6bfb31_u32,
+];
+
+/// Minimum size of the bitset in bytes.
+const BITSET_MIN_LENGTH: usize = 32;
+/// Maximum size of the bitset in bytes.
+const BITSET_MAX_LENGTH: usize = 128 * 1024 * 1024;
+
+/// Upper bound of the size of a serialized [`BloomFilterHeader`], which is read
+/// before its actual size is known.
+const SBBF_HEADER_SIZE_ESTIMATE: usize = 20;
+
+/// Seed of the xxHash64 hash function, as defined by the specification.
+const SEED: u64 = 0;
+
+/// A block of 256 bits, as 8 words of 32 bits.
+type Block = [u32; 8];
+
+/// Returns the mask of the bits to set or check within a block for `x`.
+fn block_mask(x: u32) -> Block {
+    let mut result = [0_u32; 8];
+    for (mask, salt) in result.iter_mut().zip(SALT.iter()) {
+        let y = x.wrapping_mul(*salt);
+        *mask = 1 << (y >> 27);
+    }
+    result
+}
+
+/// A split block bloom filter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sbbf {
+    blocks: Vec<Block>,
+}
+
+impl Sbbf {
+    /// Creates a new, empty bloom filter with a bitset of `num_bytes` bytes, rounded
+    /// to a power of two within the limits of the specification.
+    pub fn new_with_num_of_bytes(num_bytes: usize) -> Self {
+        let num_bytes = optimal_num_of_bytes(num_bytes);
+        Self {
+            blocks: vec![[0_u32; 8]; num_bytes / std::mem::size_of::<Block>()],
+        }
+    }
+
+    /// Creates a new, empty bloom filter sized to hold `ndv` distinct values with a
+    /// false positive probability of `fpp`.
+    ///
+    /// Returns an error if `fpp` is not in the range `(0.0, 1.0)`.
+    pub fn new_with_ndv_fpp(ndv: u64, fpp: f64) -> Result<Self> {
+        if fpp <= 0.0 || fpp >= 1.0 {
+            return Err(general_err!(
+                "False positive probability must be between 0.0 and 1.0, got {}",
+                fpp
+            ));
+        }
+        let num_bits = num_of_bits_from_ndv_fpp(ndv, fpp);
+        Ok(Self::new_with_num_of_bytes(num_bits / 8))
+    }
+
+    /// Creates a bloom filter from the little-endian bytes of its bitset.
+    fn from_bitset(bitset: &[u8]) -> Self {
+        let blocks = bitset
+            .chunks_exact(std::mem::size_of::<Block>())
+            .map(|chunk| {
+                let mut block = [0_u32; 8];
+                LittleEndian::read_u32_into(chunk, &mut block);
+                block
+            })
+            .collect();
+        Self { blocks }
+    }
+
+    /// Reads the bloom filter of a column chunk from `reader`, returns `None` if the
+    /// column chunk has no bloom filter.
+    pub fn read_from_column_chunk<R: ChunkReader>(
+        column_metadata: &ColumnChunkMetaData,
+        reader: &R,
+    ) -> Result<Option<Self>> {
+        let offset = match column_metadata.bloom_filter_offset() {
+            Some(offset) if offset >= 0 => offset as u64,
+            Some(offset) => {
+                return Err(general_err!("Invalid bloom filter offset {}", offset))
+            }
+            None => return Ok(None),
+        };
+
+        // The size of the header is only known once it has been decoded
+        let remaining = reader.len().saturating_sub(offset) as usize;
+        let mut buffer = vec![0; SBBF_HEADER_SIZE_ESTIMATE.min(remaining)];
+        reader
+            .get_read(offset, buffer.len())?
+            .read_exact(&mut buffer)?;
+
+        let mut cursor = buffer.as_slice();
+        let header = {
+            let mut protocol = TCompactInputProtocol::new(&mut cursor);
+            BloomFilterHeader::read_from_in_protocol(&mut protocol)?
+        };
+        let bitset_offset = offset + (buffer.len() - cursor.len()) as u64;
+
+        // check the size before allocating, as it is read from the file
+        let num_bytes = check_bitset_size(
+            header.num_bytes,
+            reader.len().saturating_sub(bitset_offset),
+        )?;
+        let mut bitset = vec![0; num_bytes];
+        reader
+            .get_read(bitset_offset, bitset.len())?
+            .read_exact(&mut bitset)?;
+
+        Ok(Some(Self::from_bitset(&bitset)))
+    }
+
+    /// Writes the header and bitset of this bloom filter to `writer`.
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<()> {
+        {
+            let mut protocol = TCompactOutputProtocol::new(&mut writer);
+            self.header().write_to_out_protocol(&mut protocol)?;
+            protocol.flush()?;
+        }
+        let mut buffer = vec![0; self.num_bytes()];
+        for (block, chunk) in self
+            .blocks
+            .iter()
+            .zip(buffer.chunks_exact_mut(std::mem::size_of::<Block>()))
+        {
+            LittleEndian::write_u32_into(block, chunk);
+        }
+        writer.write_all(&buffer)?;
+        Ok(())
+    }
+
+    /// Returns the thrift header of this bloom filter.
+    fn header(&self) -> BloomFilterHeader {
+        BloomFilterHeader {
+            num_bytes: self.num_bytes() as i32,
+            algorithm: BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+            hash: BloomFilterHash::XXHASH(XxHash {}),
+            compression: BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+        }
+    }
+
+    /// Returns the size of the bitset in bytes.
+    pub fn num_bytes(&self) -> usize {
+        self.blocks.len() * std::mem::size_of::<Block>()
+    }
+
+    /// Returns the index of the block that `hash` is mapped to.
+    fn block_index(&self, hash: u64) -> usize {
+        (((hash >> 32) * self.blocks.len() as u64) >> 32) as usize
+    }
+
+    /// Inserts the plain encoded bytes of `value` into the filter.
+    pub fn insert<T: AsBytes + ?Sized>(&mut self, value: &T) {
+        self.insert_hash(hash_as_bytes(value))
+    }
+
+    /// Inserts a hash into the filter.
+    pub fn insert_hash(&mut self, hash: u64) {
+        let index = self.block_index(hash);
+        let mask = block_mask(hash as u32);
+        for (word, mask) in self.blocks[index].iter_mut().zip(mask.iter()) {
+            *word |= *mask;
+        }
+    }
+
+    /// Returns `false` if `value` is definitely not in the filter, and `true` if it
+    /// may be.
+    pub fn check<T: AsBytes + ?Sized>(&self, value: &T) -> bool {
+        self.check_hash(hash_as_bytes(value))
+    }
+
+    /// Returns `false` if `hash` is definitely not in the filter, and `true` if it
+    /// may be.
+    pub fn check_hash(&self, hash: u64) -> bool {
+        let index = self.block_index(hash);
+        let mask = block_mask(hash as u32);
+        self.blocks[index]
+            .iter()
+            .zip(mask.iter())
+            .all(|(word, mask)| word & mask != 0)
+    }
+}
+
+/// Hashes the plain encoded bytes of `value` with xxHash64.
+fn hash_as_bytes<T: AsBytes + ?Sized>(value: &T) -> u64 {
+    let mut hasher = XxHash64::with_seed(SEED);
+    hasher.write(value.as_bytes());
+    hasher.finish()
+}
+
+/// Returns the size of a bitset read from a [`BloomFilterHeader`], or an error if it is
+/// not a power of two within the limits of the specification, or exceeds the
+/// `available` bytes of the file.
+fn check_bitset_size(num_bytes: i32, available: u64) -> Result<usize> {
+    let valid = num_bytes >= BITSET_MIN_LENGTH as i32
+        && num_bytes as usize <= BITSET_MAX_LENGTH
+        && (num_bytes as usize).is_power_of_two();
+    if !valid {
+        return Err(general_err!(
+            "Invalid bloom filter bitset size {}, expected a power of two in {}..={}",
+            num_bytes,
+            BITSET_MIN_LENGTH,
+            BITSET_MAX_LENGTH
+        ));
+    }
+    if num_bytes as u64 > available {
+        return Err(general_err!(
+            "Bloom filter bitset size {} exceeds the {} remaining bytes of the file",
+            num_bytes,
+            available
+        ));
+    }
+    Ok(num_bytes as usize)
+}
+
+/// Rounds `num_bytes` up to a power of two, within the limits of the specification.
+fn optimal_num_of_bytes(num_bytes: usize) -> usize {
+    let num_bytes = num_bytes.min(BITSET_MAX_LENGTH);
+    let num_bytes = num_bytes.max(BITSET_MIN_LENGTH);
+    num_bytes.next_power_of_two()
+}
+
+/// Returns the number of bits required to hold `ndv` distinct values with a false
+/// positive probability of `fpp`.
+fn num_of_bits_from_ndv_fpp(ndv: u64, fpp: f64) -> usize {
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    num_bits as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::data_type::ByteArray;
+
+    #[test]
+    fn test_block_mask() {
+        // each word of the mask has exactly one bit set
+        for x in [0_u32, 1, 42, u32::MAX].iter() {
+            let mask = block_mask(*x);
+            assert!(mask.iter().all(|word| word.count_ones() == 1));
+        }
+        assert_eq!(block_mask(0), [1; 8]);
+    }
+
+    #[test]
+    fn test_optimal_num_of_bytes() {
+        assert_eq!(optimal_num_of_bytes(0), BITSET_MIN_LENGTH);
+        assert_eq!(optimal_num_of_bytes(32), 32);
+        assert_eq!(optimal_num_of_bytes(33), 64);
+        assert_eq!(optimal_num_of_bytes(1000), 1024);
+        assert_eq!(optimal_num_of_bytes(usize::MAX / 2), BITSET_MAX_LENGTH);
+    }
+
+    #[test]
+    fn test_check_bitset_size() {
+        assert_eq!(check_bitset_size(32, 32).unwrap(), 32);
+        assert_eq!(check_bitset_size(1024, 5000).unwrap(), 1024);
+        for num_bytes in [-32, 0, 16, 96, 1000, i32::MAX].iter() {
+            assert!(check_bitset_size(*num_bytes, u64::MAX).is_err());
+        }
+        let err = check_bitset_size(256 * 1024 * 1024, u64::MAX).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid bloom filter bitset size 268435456, expected a power of two in 32..=134217728"
+        );
+        let err = check_bitset_size(1024, 100).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Bloom filter bitset size 1024 exceeds the 100 remaining bytes of the file"
+        );
+    }
+
+    #[test]
+    fn test_num_of_bits_from_ndv_fpp() {
+        for (fpp, ndv, num_bits) in [
+            (0.1, 10, 57),
+            (0.01, 10, 96),
+            (0.001, 10, 146),
+            (0.1, 100, 577),
+            (0.01, 100, 968),
+            (0.001, 100, 1460),
+        ]
+        .iter()
+        {
+            assert_eq!(*num_bits, num_of_bits_from_ndv_fpp(*ndv, *fpp) as u64);
+        }
+    }
+
+    #[test]
+    fn test_new_with_ndv_fpp() {
+        assert!(Sbbf::new_with_ndv_fpp(10, 0.0).is_err());
+        assert!(Sbbf::new_with_ndv_fpp(10, 1.0).is_err());
+        let filter = Sbbf::new_with_ndv_fpp(1000, 0.01).unwrap();
+        assert_eq!(filter.num_bytes(), 2048);
+    }
+
+    #[test]
+    fn test_insert_and_check() {
+        let mut filter = Sbbf::new_with_num_of_bytes(1024);
+        for i in 0..100_i32 {
+            filter.insert(&i);
+        }
+        for i in 0..100_i32 {
+            assert!(filter.check(&i));
+        }
+
+        let value = ByteArray::from("hello");
+        filter.insert(&value);
+        assert!(filter.check(&value));
+        assert!(filter.check("hello"));
+    }
+
+    #[test]
+    fn test_write_and_read_bitset() {
+        let mut filter = Sbbf::new_with_num_of_bytes(64);
+        for i in 0..10_i64 {
+            filter.insert(&i);
+        }
+
+        let mut buffer = vec![];
+        filter.write(&mut buffer).unwrap();
+
+        let mut cursor = buffer.as_slice();
+        let header = {
+            let mut protocol = TCompactInputProtocol::new(&mut cursor);
+            BloomFilterHeader::read_from_in_protocol(&mut protocol).unwrap()
+        };
+        assert_eq!(header.num_bytes, 64);
+        assert_eq!(cursor.len(), 64);
+        assert_eq!(Sbbf::from_bitset(cursor), filter);
+    }
+}
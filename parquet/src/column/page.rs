@@ -186,12 +186,52 @@ impl PageWriteSpec {
     }
 }
 
+/// Metadata of a page, known from its header without decompressing or decoding it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PageMetadata {
+    /// The number of rows of a data page, if known, which is the case of data page v2
+    pub num_rows: Option<usize>,
+    /// The number of values of the page, including nulls, i.e. its number of levels
+    /// for a data page
+    pub num_levels: usize,
+    /// Whether the page is a dictionary page
+    pub is_dict: bool,
+}
+
+impl From<&Page> for PageMetadata {
+    fn from(page: &Page) -> Self {
+        match page {
+            Page::DataPageV2 {
+                num_values,
+                num_rows,
+                ..
+            } => Self {
+                num_rows: Some(*num_rows as usize),
+                num_levels: *num_values as usize,
+                is_dict: false,
+            },
+            page => Self {
+                num_rows: None,
+                num_levels: page.num_values() as usize,
+                is_dict: page.page_type() == PageType::DICTIONARY_PAGE,
+            },
+        }
+    }
+}
+
 /// API for reading pages from a column chunk.
 /// This offers a iterator like API to get the next page.
 pub trait PageReader {
     /// Gets the next page in the column chunk associated with this reader.
     /// Returns `None` if there are no pages left.
     fn get_next_page(&mut self) -> Result<Option<Page>>;
+
+    /// Gets the metadata of the next page without reading the page, so that it can be
+    /// skipped with `skip_next_page`. Returns `None` if there are no pages left.
+    fn peek_next_page(&mut self) -> Result<Option<PageMetadata>>;
+
+    /// Skips the next page without decompressing or decoding it.
+    fn skip_next_page(&mut self) -> Result<()>;
 }
 
 /// API for writing pages in a column chunk.
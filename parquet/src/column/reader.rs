@@ -33,6 +33,9 @@ use crate::errors::{ParquetError, Result};
 use crate::schema::types::ColumnDescPtr;
 use crate::util::memory::ByteBufferPtr;
 
+/// The maximum number of levels decoded at once when skipping records.
+pub(crate) const SKIP_BATCH_SIZE: usize = 1024;
+
 /// Column reader for a Parquet type.
 pub enum ColumnReader {
     BoolColumnReader(ColumnReaderImpl<BoolType>),
@@ -264,6 +267,109 @@ impl<T: DataType> ColumnReaderImpl<T> {
         Ok((values_read, levels_read))
     }
 
+    /// Skips at most `num_records` records, returning the number of records skipped.
+    ///
+    /// A record starts at a repetition level of 0. If the reader is in the middle of a
+    /// record of a repeated column, its remaining levels are skipped first, and are
+    /// not counted as a record.
+    ///
+    /// The levels of the skipped records are decoded to delimit the records and to
+    /// count their non-null values, but the values themselves are skipped by the value
+    /// decoder, which only decodes them if the encoding requires it. The data pages
+    /// of a column that isn't repeated are skipped without being decompressed if all
+    /// their records are skipped.
+    pub fn skip_records(&mut self, num_records: usize) -> Result<usize> {
+        let max_rep_level = self.descr.max_rep_level();
+        let mut remaining = num_records;
+
+        // Whether a level of the record that is being skipped has been skipped, for a
+        // repeated column. The record is only known to be complete at the next record.
+        let mut in_record = false;
+
+        while remaining > 0 || max_rep_level > 0 {
+            if self.num_buffered_values == self.num_decoded_values {
+                match self.page_reader.peek_next_page()? {
+                    None => break,
+                    Some(metadata) if metadata.is_dict => {
+                        if let Some(page) = self.page_reader.get_next_page()? {
+                            self.configure_dictionary(page)?;
+                        }
+                        continue;
+                    }
+                    Some(metadata)
+                        if max_rep_level == 0 && metadata.num_levels <= remaining =>
+                    {
+                        self.page_reader.skip_next_page()?;
+                        remaining -= metadata.num_levels;
+                        continue;
+                    }
+                    Some(_) => {}
+                }
+                if !self.read_new_page()? {
+                    break;
+                }
+                continue;
+            }
+
+            let levels_left =
+                (self.num_buffered_values - self.num_decoded_values) as usize;
+            let mut levels = vec![0; min(levels_left, SKIP_BATCH_SIZE)];
+
+            // The number of levels to skip, and whether the last record to skip ends
+            // within them
+            let (num_levels, done) = if max_rep_level > 0 {
+                // Find the end of the records on a copy of the decoder, so that the
+                // levels of the next record aren't consumed
+                let mut rep_level_decoder = self
+                    .rep_level_decoder
+                    .clone()
+                    .expect("rep_level_decoder be set");
+                let read = rep_level_decoder.get(&mut levels)?;
+                count_skipped_levels(&levels[..read], &mut remaining, &mut in_record)
+            } else {
+                (min(remaining, levels.len()), false)
+            };
+
+            if max_rep_level > 0 {
+                self.read_rep_levels(&mut levels[..num_levels])?;
+            } else {
+                remaining -= num_levels;
+            }
+
+            let num_values = if self.descr.max_def_level() > 0 {
+                let read = self.read_def_levels(&mut levels[..num_levels])?;
+                let max_def_level = self.descr.max_def_level();
+                levels[..read]
+                    .iter()
+                    .filter(|level| **level == max_def_level)
+                    .count()
+            } else {
+                num_levels
+            };
+
+            let skipped = self.skip_values(num_values)?;
+            if skipped != num_values {
+                return Err(general_err!(
+                    "Expected to skip {} values, skipped {}",
+                    num_values,
+                    skipped
+                ));
+            }
+            self.num_decoded_values += num_levels as u32;
+
+            if done {
+                break;
+            }
+        }
+
+        // The last record of the column chunk is complete at its end
+        if in_record {
+            remaining -= 1;
+        }
+
+        Ok(num_records - remaining)
+    }
+
     /// Reads a new page and set up the decoders for levels, values or dictionary.
     /// Returns false if there's no page left.
     fn read_new_page(&mut self) -> Result<bool> {
@@ -471,6 +577,18 @@ impl<T: DataType> ColumnReaderImpl<T> {
         current_decoder.get(buffer)
     }
 
+    #[inline]
+    fn skip_values(&mut self, num_values: usize) -> Result<usize> {
+        let encoding = self
+            .current_encoding
+            .expect("current_encoding should be set");
+        let current_decoder = self
+            .decoders
+            .get_mut(&encoding)
+            .unwrap_or_else(|| panic!("decoder for encoding {} should be set", encoding));
+        current_decoder.skip(num_values)
+    }
+
     #[inline]
     fn configure_dictionary(&mut self, page: Page) -> Result<bool> {
         let mut encoding = page.encoding();
@@ -500,17 +618,47 @@ impl<T: DataType> ColumnReaderImpl<T> {
     }
 }
 
+/// Counts the leading levels of `rep_levels` that belong to the next `remaining`
+/// records of a repeated column, decrementing `remaining` for every record that ends
+/// within them. `in_record` is whether a level of the current record was counted, and
+/// if it isn't, the levels before the next record belong to a record that isn't
+/// counted.
+///
+/// Returns the number of levels, and whether the last record ends within them.
+pub(crate) fn count_skipped_levels(
+    rep_levels: &[i16],
+    remaining: &mut usize,
+    in_record: &mut bool,
+) -> (usize, bool) {
+    let mut num_levels = 0;
+    for level in rep_levels {
+        if *level == 0 {
+            if *in_record {
+                *remaining -= 1;
+                *in_record = false;
+            }
+            if *remaining == 0 {
+                return (num_levels, true);
+            }
+            *in_record = true;
+        }
+        num_levels += 1;
+    }
+    (num_levels, false)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use rand::distributions::uniform::SampleUniform;
-    use std::{collections::VecDeque, sync::Arc, vec::IntoIter};
+    use std::{collections::VecDeque, iter::Peekable, sync::Arc, vec::IntoIter};
 
     use crate::basic::Type as PhysicalType;
-    use crate::column::page::Page;
+    use crate::column::page::{Page, PageMetadata};
     use crate::schema::types::{ColumnDescriptor, ColumnPath, Type as SchemaType};
     use crate::util::test_common::make_pages;
+    use crate::util::test_common::page_util::{DataPageBuilder, DataPageBuilderImpl};
 
     const NUM_LEVELS: usize = 128;
     const NUM_PAGES: usize = 2;
@@ -976,6 +1124,98 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_skip_records_does_not_decode_skipped_pages() {
+        let desc = Arc::new(ColumnDescriptor::new(
+            Arc::new(get_test_int32_type()),
+            0,
+            0,
+            ColumnPath::new(Vec::new()),
+        ));
+
+        let mut pages = vec![];
+        for values in [[1, 2, 3], [4, 5, 6]] {
+            let mut pb = DataPageBuilderImpl::new(desc.clone(), 3, false);
+            pb.add_values::<Int32Type>(Encoding::PLAIN, &values);
+            pages.push(pb.consume());
+        }
+        // a page of values that can't be decoded, which must only be skipped
+        pages.insert(
+            1,
+            Page::DataPage {
+                buf: ByteBufferPtr::new(vec![0xFF]),
+                num_values: 3,
+                encoding: Encoding::PLAIN,
+                def_level_encoding: Encoding::RLE,
+                rep_level_encoding: Encoding::RLE,
+                statistics: None,
+            },
+        );
+
+        let page_reader = Box::new(TestPageReader::new(pages));
+        let mut reader = ColumnReaderImpl::<Int32Type>::new(desc, page_reader);
+        let mut values = [0; 3];
+
+        assert_eq!(reader.skip_records(1).unwrap(), 1);
+        assert_eq!(
+            reader.read_batch(2, None, None, &mut values).unwrap(),
+            (2, 0)
+        );
+        assert_eq!(&values[..2], &[2, 3]);
+
+        assert_eq!(reader.skip_records(3).unwrap(), 3);
+        assert_eq!(
+            reader.read_batch(3, None, None, &mut values).unwrap(),
+            (3, 0)
+        );
+        assert_eq!(&values, &[4, 5, 6]);
+
+        assert_eq!(reader.skip_records(1).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_skip_records_repeated() {
+        let desc = Arc::new(ColumnDescriptor::new(
+            Arc::new(get_test_int32_type()),
+            1,
+            1,
+            ColumnPath::new(Vec::new()),
+        ));
+
+        // records [1, 2], [null, 4, 5], [6] and [7, 8], the second of which spans
+        // both pages
+        let page_levels: [(&[i16], &[i16], &[i32]); 2] = [
+            (&[0, 1, 0, 1], &[1, 1, 0, 1], &[1, 2, 4]),
+            (&[1, 0, 0, 1], &[1, 1, 1, 1], &[5, 6, 7, 8]),
+        ];
+        let pages = page_levels
+            .iter()
+            .map(|(rep_levels, def_levels, values)| {
+                let mut pb = DataPageBuilderImpl::new(desc.clone(), 4, false);
+                pb.add_rep_levels(1, rep_levels);
+                pb.add_def_levels(1, def_levels);
+                pb.add_values::<Int32Type>(Encoding::PLAIN, values);
+                pb.consume()
+            })
+            .collect();
+
+        let page_reader = Box::new(TestPageReader::new(pages));
+        let mut reader = ColumnReaderImpl::<Int32Type>::new(desc, page_reader);
+        let mut values = [0; 4];
+        let mut def_levels = [0; 4];
+        let mut rep_levels = [0; 4];
+
+        assert_eq!(reader.skip_records(2).unwrap(), 2);
+        let (values_read, levels_read) = reader
+            .read_batch(4, Some(&mut def_levels), Some(&mut rep_levels), &mut values)
+            .unwrap();
+        assert_eq!((values_read, levels_read), (3, 3));
+        assert_eq!(&values[..3], &[6, 7, 8]);
+        assert_eq!(&rep_levels[..3], &[0, 0, 1]);
+
+        assert_eq!(reader.skip_records(1).unwrap(), 0);
+    }
+
     // ----------------------------------------------------------------------
     // Helper methods to make pages and test
     //
@@ -1337,13 +1577,13 @@ mod tests {
     }
 
     struct TestPageReader {
-        pages: IntoIter<Page>,
+        pages: Peekable<IntoIter<Page>>,
     }
 
     impl TestPageReader {
         pub fn new(pages: Vec<Page>) -> Self {
             Self {
-                pages: pages.into_iter(),
+                pages: pages.into_iter().peekable(),
             }
         }
     }
@@ -1352,5 +1592,14 @@ mod tests {
         fn get_next_page(&mut self) -> Result<Option<Page>> {
             Ok(self.pages.next())
         }
+
+        fn peek_next_page(&mut self) -> Result<Option<PageMetadata>> {
+            Ok(self.pages.peek().map(PageMetadata::from))
+        }
+
+        fn skip_next_page(&mut self) -> Result<()> {
+            self.pages.next();
+            Ok(())
+        }
     }
 }
@@ -16,11 +16,15 @@
 // under the License.
 
 //! Contains column writer API.
-use std::{cmp, collections::VecDeque, convert::TryFrom, marker::PhantomData, sync::Arc};
+use std::{
+    cmp, collections::VecDeque, convert::TryFrom, marker::PhantomData, ops::Range,
+    sync::Arc,
+};
 
-use crate::basic::{Compression, Encoding, LogicalType, PageType, Type};
+use crate::basic::{Compression, ConvertedType, Encoding, LogicalType, PageType, Type};
+use crate::bloom_filter::Sbbf;
 use crate::column::page::{CompressedPage, Page, PageWriteSpec, PageWriter};
-use crate::compression::{create_codec, Codec};
+use crate::compression::{create_codec_with_level, Codec};
 use crate::data_type::private::ParquetValueType;
 use crate::data_type::AsBytes;
 use crate::data_type::*;
@@ -31,12 +35,15 @@ use crate::encodings::{
 use crate::errors::{ParquetError, Result};
 use crate::file::statistics::Statistics;
 use crate::file::{
-    metadata::ColumnChunkMetaData,
-    properties::{WriterProperties, WriterPropertiesPtr, WriterVersion},
+    metadata::{ColumnChunkMetaData, ColumnIndexBuilder, OffsetIndexBuilder},
+    properties::{
+        EnabledStatistics, WriterProperties, WriterPropertiesPtr, WriterVersion,
+    },
 };
 use crate::schema::types::ColumnDescPtr;
 use crate::util::bit_util::FromBytes;
 use crate::util::memory::{ByteBufferPtr, MemTracker};
+use parquet_format::{ColumnIndex, OffsetIndex};
 
 /// Column writer for a Parquet type.
 pub enum ColumnWriter {
@@ -162,6 +169,68 @@ pub fn get_typed_column_writer_mut<T: DataType>(
     })
 }
 
+/// The values of a batch written by a [`ColumnWriterImpl`]
+enum BatchValues<'a, T: DataType> {
+    /// The values to write
+    Plain(&'a [T::T]),
+    /// The values to write, as keys into `dictionary`
+    Dictionary {
+        dictionary: &'a [T::T],
+        keys: &'a [usize],
+    },
+}
+
+// Implemented manually, as deriving would require `T: Copy`
+impl<T: DataType> Clone for BatchValues<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: DataType> Copy for BatchValues<'_, T> {}
+
+impl<'a, T: DataType> BatchValues<'a, T> {
+    fn len(&self) -> usize {
+        match *self {
+            BatchValues::Plain(values) => values.len(),
+            BatchValues::Dictionary { keys, .. } => keys.len(),
+        }
+    }
+
+    fn slice(&self, range: Range<usize>) -> Self {
+        match *self {
+            BatchValues::Plain(values) => BatchValues::Plain(&values[range]),
+            BatchValues::Dictionary { dictionary, keys } => BatchValues::Dictionary {
+                dictionary,
+                keys: &keys[range],
+            },
+        }
+    }
+
+    fn value(&self, i: usize) -> &'a T::T {
+        match *self {
+            BatchValues::Plain(values) => &values[i],
+            BatchValues::Dictionary { dictionary, keys } => &dictionary[keys[i]],
+        }
+    }
+}
+
+/// Result of closing a column writer with [`ColumnWriterImpl::close_with_page_index`].
+pub struct ColumnCloseResult {
+    /// Total number of bytes written.
+    pub bytes_written: u64,
+    /// Total number of rows written.
+    pub rows_written: u64,
+    /// Metadata of the column chunk.
+    pub metadata: ColumnChunkMetaData,
+    /// Column index of the column chunk, if written.
+    pub column_index: Option<ColumnIndex>,
+    /// Offset index of the column chunk, if written.
+    pub offset_index: Option<OffsetIndex>,
+    /// Bloom filter of the column chunk, if enabled.
+    pub bloom_filter: Option<Sbbf>,
+}
+
 /// Typed column writer for a primitive column.
 pub struct ColumnWriterImpl<T: DataType> {
     // Column writer properties
@@ -173,6 +242,7 @@ pub struct ColumnWriterImpl<T: DataType> {
     encoder: Box<dyn Encoder<T>>,
     codec: Compression,
     compressor: Option<Box<dyn Codec>>,
+    statistics_enabled: EnabledStatistics,
     // Metrics per page
     num_buffered_values: u32,
     num_buffered_encoded_values: u32,
@@ -197,6 +267,15 @@ pub struct ColumnWriterImpl<T: DataType> {
     def_levels_sink: Vec<i16>,
     rep_levels_sink: Vec<i16>,
     data_pages: VecDeque<CompressedPage>,
+    // Index in `dict_encoder` of each value of the dictionary passed to
+    // `write_batch_dictionary()`, if the value has been inserted
+    dictionary_indices: Vec<Option<i32>>,
+    // Page index, only collected if enabled in the writer properties
+    column_index_builder: Option<ColumnIndexBuilder>,
+    offset_index_builder: Option<OffsetIndexBuilder>,
+    // Bloom filter of all non-null values, only collected if enabled in the writer
+    // properties
+    bloom_filter: Option<Sbbf>,
     _phantom: PhantomData<T>,
 }
 
@@ -207,7 +286,8 @@ impl<T: DataType> ColumnWriterImpl<T> {
         page_writer: Box<dyn PageWriter>,
     ) -> Self {
         let codec = props.compression(descr.path());
-        let compressor = create_codec(codec).unwrap();
+        let level = props.compression_level(descr.path());
+        let compressor = create_codec_with_level(codec, level).unwrap();
 
         // Optionally set dictionary encoder.
         let dict_encoder = if props.dictionary_enabled(descr.path())
@@ -231,6 +311,19 @@ impl<T: DataType> ColumnWriterImpl<T> {
         )
         .unwrap();
 
+        let statistics_enabled = props.statistics_enabled(descr.path());
+
+        // The column index can only be written if there are page statistics
+        let column_index_builder = (props.page_index_enabled()
+            && statistics_enabled == EnabledStatistics::Page)
+            .then(ColumnIndexBuilder::new);
+        let offset_index_builder =
+            props.page_index_enabled().then(OffsetIndexBuilder::new);
+
+        let bloom_filter = props
+            .bloom_filter_properties(descr.path())
+            .map(|bf| Sbbf::new_with_ndv_fpp(bf.ndv, bf.fpp).unwrap());
+
         Self {
             descr,
             props,
@@ -240,6 +333,7 @@ impl<T: DataType> ColumnWriterImpl<T> {
             encoder: fallback_encoder,
             codec,
             compressor,
+            statistics_enabled,
             num_buffered_values: 0,
             num_buffered_encoded_values: 0,
             num_buffered_rows: 0,
@@ -253,6 +347,7 @@ impl<T: DataType> ColumnWriterImpl<T> {
             def_levels_sink: vec![],
             rep_levels_sink: vec![],
             data_pages: VecDeque::new(),
+            dictionary_indices: vec![],
             min_page_value: None,
             max_page_value: None,
             num_page_nulls: 0,
@@ -261,13 +356,16 @@ impl<T: DataType> ColumnWriterImpl<T> {
             max_column_value: None,
             num_column_nulls: 0,
             column_distinct_count: None,
+            column_index_builder,
+            offset_index_builder,
+            bloom_filter,
             _phantom: PhantomData,
         }
     }
 
     fn write_batch_internal(
         &mut self,
-        values: &[T::T],
+        values: BatchValues<'_, T>,
         def_levels: Option<&[i16]>,
         rep_levels: Option<&[i16]>,
         min: &Option<T::T>,
@@ -299,6 +397,12 @@ impl<T: DataType> ColumnWriterImpl<T> {
         let num_batches = min_len / write_batch_size;
 
         // Process pre-calculated statistics
+        let (min, max, null_count, distinct_count) =
+            if self.statistics_enabled == EnabledStatistics::None {
+                (&None, &None, None, None)
+            } else {
+                (min, max, null_count, distinct_count)
+            };
         match (min, max) {
             (Some(min), Some(max)) => {
                 if self
@@ -331,7 +435,8 @@ impl<T: DataType> ColumnWriterImpl<T> {
             self.num_column_nulls += nulls;
         }
 
-        let calculate_page_stats = (min.is_none() || max.is_none())
+        let calculate_page_stats = self.statistics_enabled != EnabledStatistics::None
+            && (min.is_none() || max.is_none())
             && null_count.is_none()
             && distinct_count.is_none();
 
@@ -339,7 +444,7 @@ impl<T: DataType> ColumnWriterImpl<T> {
         let mut levels_offset = 0;
         for _ in 0..num_batches {
             values_offset += self.write_mini_batch(
-                &values[values_offset..values_offset + write_batch_size],
+                values.slice(values_offset..values_offset + write_batch_size),
                 def_levels.map(|lv| &lv[levels_offset..levels_offset + write_batch_size]),
                 rep_levels.map(|lv| &lv[levels_offset..levels_offset + write_batch_size]),
                 calculate_page_stats,
@@ -348,7 +453,7 @@ impl<T: DataType> ColumnWriterImpl<T> {
         }
 
         values_offset += self.write_mini_batch(
-            &values[values_offset..],
+            values.slice(values_offset..values.len()),
             def_levels.map(|lv| &lv[levels_offset..]),
             rep_levels.map(|lv| &lv[levels_offset..]),
             calculate_page_stats,
@@ -377,7 +482,13 @@ impl<T: DataType> ColumnWriterImpl<T> {
         rep_levels: Option<&[i16]>,
     ) -> Result<usize> {
         self.write_batch_internal(
-            values, def_levels, rep_levels, &None, &None, None, None,
+            BatchValues::Plain(values),
+            def_levels,
+            rep_levels,
+            &None,
+            &None,
+            None,
+            None,
         )
     }
 
@@ -395,7 +506,7 @@ impl<T: DataType> ColumnWriterImpl<T> {
         distinct_count: Option<u64>,
     ) -> Result<usize> {
         self.write_batch_internal(
-            values,
+            BatchValues::Plain(values),
             def_levels,
             rep_levels,
             min,
@@ -405,6 +516,42 @@ impl<T: DataType> ColumnWriterImpl<T> {
         )
     }
 
+    /// Writes batch of values given as `keys` into `dictionary`, i.e. the value at
+    /// position `i` is `dictionary[keys[i]]`, along with definition and repetition
+    /// levels. Returns number of values processed (written).
+    ///
+    /// This behaves like [`Self::write_batch`] with the values looked up from
+    /// `dictionary`, but if the column is dictionary encoded each value of
+    /// `dictionary` is only added to the column's dictionary once, and only if it
+    /// is used by one of `keys`. If the column's dictionary grows too large, the
+    /// writer falls back to the column's fallback encoding as usual.
+    pub fn write_batch_dictionary(
+        &mut self,
+        dictionary: &[T::T],
+        keys: &[usize],
+        def_levels: Option<&[i16]>,
+        rep_levels: Option<&[i16]>,
+    ) -> Result<usize> {
+        if let Some(key) = keys.iter().find(|key| **key >= dictionary.len()) {
+            return Err(general_err!(
+                "Dictionary key {} out of bounds for dictionary of length {}",
+                key,
+                dictionary.len()
+            ));
+        }
+        self.dictionary_indices.clear();
+        self.dictionary_indices.resize(dictionary.len(), None);
+        self.write_batch_internal(
+            BatchValues::Dictionary { dictionary, keys },
+            def_levels,
+            rep_levels,
+            &None,
+            &None,
+            None,
+            None,
+        )
+    }
+
     /// Returns total number of bytes written by this column writer so far.
     /// This value is also returned when column writer is closed.
     pub fn get_total_bytes_written(&self) -> u64 {
@@ -419,7 +566,15 @@ impl<T: DataType> ColumnWriterImpl<T> {
 
     /// Finalises writes and closes the column writer.
     /// Returns total bytes written, total rows written and column chunk metadata.
-    pub fn close(mut self) -> Result<(u64, u64, ColumnChunkMetaData)> {
+    pub fn close(self) -> Result<(u64, u64, ColumnChunkMetaData)> {
+        let result = self.close_with_page_index()?;
+        Ok((result.bytes_written, result.rows_written, result.metadata))
+    }
+
+    /// Finalises writes and closes the column writer.
+    /// In addition to [`Self::close`], returns the column index and offset index of
+    /// the column chunk if writing of the page index is enabled.
+    pub fn close_with_page_index(mut self) -> Result<ColumnCloseResult> {
         if self.dict_encoder.is_some() {
             self.write_dictionary_page()?;
         }
@@ -428,7 +583,25 @@ impl<T: DataType> ColumnWriterImpl<T> {
         self.dict_encoder = None;
         self.page_writer.close()?;
 
-        Ok((self.total_bytes_written, self.total_rows_written, metadata))
+        // The column index is only written if statistics were collected for every page
+        let column_index = self
+            .column_index_builder
+            .take()
+            .filter(|builder| builder.valid())
+            .map(|builder| builder.build_to_thrift());
+        let offset_index = self
+            .offset_index_builder
+            .take()
+            .map(|builder| builder.build_to_thrift());
+
+        Ok(ColumnCloseResult {
+            bytes_written: self.total_bytes_written,
+            rows_written: self.total_rows_written,
+            metadata,
+            column_index,
+            offset_index,
+            bloom_filter: self.bloom_filter.take(),
+        })
     }
 
     /// Writes mini batch of values, definition and repetition levels.
@@ -436,7 +609,7 @@ impl<T: DataType> ColumnWriterImpl<T> {
     /// page size.
     fn write_mini_batch(
         &mut self,
-        values: &[T::T],
+        values: BatchValues<'_, T>,
         def_levels: Option<&[i16]>,
         rep_levels: Option<&[i16]>,
         calculate_page_stats: bool,
@@ -502,17 +675,31 @@ impl<T: DataType> ColumnWriterImpl<T> {
         }
 
         // Check that we have enough values to write.
-        let values_to_write = values.get(0..values_to_write).ok_or_else(|| {
-            general_err!(
+        if values_to_write > values.len() {
+            return Err(general_err!(
                 "Expected to write {} values, but have only {}",
                 values_to_write,
                 values.len()
-            )
-        })?;
+            ));
+        }
+        let values_to_write = values.slice(0..values_to_write);
+
+        // Page statistics provided by the caller are not known for every page
+        if !calculate_page_stats {
+            if let Some(builder) = self.column_index_builder.as_mut() {
+                builder.to_invalid();
+            }
+        }
 
         if calculate_page_stats {
-            for val in values_to_write {
-                self.update_page_min_max(val);
+            for i in 0..values_to_write.len() {
+                self.update_page_min_max(values_to_write.value(i));
+            }
+        }
+
+        if let Some(bloom_filter) = self.bloom_filter.as_mut() {
+            for i in 0..values_to_write.len() {
+                bloom_filter.insert(values_to_write.value(i));
             }
         }
 
@@ -543,10 +730,29 @@ impl<T: DataType> ColumnWriterImpl<T> {
     }
 
     #[inline]
-    fn write_values(&mut self, values: &[T::T]) -> Result<()> {
-        match self.dict_encoder {
-            Some(ref mut encoder) => encoder.put(values),
-            None => self.encoder.put(values),
+    fn write_values(&mut self, values: BatchValues<'_, T>) -> Result<()> {
+        match (values, &mut self.dict_encoder) {
+            (BatchValues::Plain(values), Some(encoder)) => encoder.put(values),
+            (BatchValues::Plain(values), None) => self.encoder.put(values),
+            (BatchValues::Dictionary { dictionary, keys }, Some(encoder)) => {
+                for key in keys.iter() {
+                    let index = match self.dictionary_indices[*key] {
+                        Some(index) => index,
+                        None => {
+                            let index = encoder.insert(&dictionary[*key]);
+                            self.dictionary_indices[*key] = Some(index);
+                            index
+                        }
+                    };
+                    encoder.put_index(index);
+                }
+                Ok(())
+            }
+            (BatchValues::Dictionary { .. }, None) => {
+                let values: Vec<T::T> =
+                    (0..values.len()).map(|i| values.value(i).clone()).collect();
+                self.encoder.put(&values)
+            }
         }
     }
 
@@ -611,6 +817,7 @@ impl<T: DataType> ColumnWriterImpl<T> {
             self.update_column_min_max();
             self.num_column_nulls += self.num_page_nulls;
             Some(self.make_page_statistics())
+                .filter(|_| self.statistics_enabled == EnabledStatistics::Page)
         } else {
             None
         };
@@ -706,6 +913,8 @@ impl<T: DataType> ColumnWriterImpl<T> {
             }
         };
 
+        self.update_page_index();
+
         // Check if we need to buffer data page or flush it to the sink directly.
         if self.dict_encoder.is_some() {
             self.data_pages.push_back(compressed_page);
@@ -730,6 +939,28 @@ impl<T: DataType> ColumnWriterImpl<T> {
         Ok(())
     }
 
+    /// Appends the statistics and row count of the current page to the page index.
+    fn update_page_index(&mut self) {
+        if let Some(builder) = self.column_index_builder.as_mut() {
+            if self.num_buffered_encoded_values == 0 {
+                builder.append(true, vec![], vec![], self.num_page_nulls as i64);
+            } else {
+                match (&self.min_page_value, &self.max_page_value) {
+                    (Some(min), Some(max)) => builder.append(
+                        false,
+                        min.as_bytes().to_vec(),
+                        max.as_bytes().to_vec(),
+                        self.num_page_nulls as i64,
+                    ),
+                    _ => builder.to_invalid(),
+                }
+            }
+        }
+        if let Some(builder) = self.offset_index_builder.as_mut() {
+            builder.append_row_count(self.num_buffered_rows as i64);
+        }
+    }
+
     /// Finalises any outstanding data pages and flushes buffered data pages from
     /// dictionary encoding into underlying sink.
     #[inline]
@@ -777,8 +1008,7 @@ impl<T: DataType> ColumnWriterImpl<T> {
         // We use only RLE level encoding for data page v1 and data page v2.
         encodings.push(Encoding::RLE);
 
-        let statistics = self.make_column_statistics();
-        let metadata = ColumnChunkMetaData::builder(self.descr.clone())
+        let mut builder = ColumnChunkMetaData::builder(self.descr.clone())
             .set_compression(self.codec)
             .set_encodings(encodings)
             .set_file_offset(file_offset)
@@ -786,9 +1016,11 @@ impl<T: DataType> ColumnWriterImpl<T> {
             .set_total_uncompressed_size(total_uncompressed_size)
             .set_num_values(num_values)
             .set_data_page_offset(data_page_offset)
-            .set_dictionary_page_offset(dict_page_offset)
-            .set_statistics(statistics)
-            .build()?;
+            .set_dictionary_page_offset(dict_page_offset);
+        if self.statistics_enabled != EnabledStatistics::None {
+            builder = builder.set_statistics(self.make_column_statistics());
+        }
+        let metadata = builder.build()?;
 
         self.page_writer.write_metadata(&metadata)?;
 
@@ -823,6 +1055,12 @@ impl<T: DataType> ColumnWriterImpl<T> {
     #[inline]
     fn write_data_page(&mut self, page: CompressedPage) -> Result<()> {
         let page_spec = self.page_writer.write_page(page)?;
+        if let Some(builder) = self.offset_index_builder.as_mut() {
+            builder.append_offset_and_size(
+                page_spec.offset as i64,
+                page_spec.compressed_size as i32,
+            );
+        }
         self.update_metrics_for_page(page_spec);
         Ok(())
     }
@@ -923,13 +1161,60 @@ impl<T: DataType> ColumnWriterImpl<T> {
             Type::FLOAT => gen_stats_section!(f32, float, min, max, distinct, nulls),
             Type::DOUBLE => gen_stats_section!(f64, double, min, max, distinct, nulls),
             Type::BYTE_ARRAY | Type::FIXED_LEN_BYTE_ARRAY => {
-                let min = min.as_ref().map(|v| ByteArray::from(v.as_bytes().to_vec()));
-                let max = max.as_ref().map(|v| ByteArray::from(v.as_bytes().to_vec()));
-                Statistics::byte_array(min, max, distinct, nulls, false)
+                let (min, max) = self.truncate_min_max(
+                    min.map(|v| v.as_bytes()),
+                    max.map(|v| v.as_bytes()),
+                );
+                Statistics::byte_array(
+                    min.map(ByteArray::from),
+                    max.map(ByteArray::from),
+                    distinct,
+                    nulls,
+                    false,
+                )
             }
         }
     }
 
+    /// Truncates binary min and max values to the configured truncate length, and
+    /// drops them if either of them is still larger than the max statistics size.
+    ///
+    /// Decimal values are never truncated, as they are not compared byte-wise.
+    fn truncate_min_max(
+        &self,
+        min: Option<&[u8]>,
+        max: Option<&[u8]>,
+    ) -> (Option<Vec<u8>>, Option<Vec<u8>>) {
+        let converted_type = self.descr.converted_type();
+        let truncate_length = self
+            .props
+            .statistics_truncate_length(self.descr.path())
+            .filter(|_| converted_type != ConvertedType::DECIMAL);
+        let is_utf8 = matches!(
+            converted_type,
+            ConvertedType::UTF8 | ConvertedType::ENUM | ConvertedType::JSON
+        );
+
+        let (min, max) = match truncate_length {
+            Some(length) => (
+                min.map(|v| truncate_min_value(v, length, is_utf8)),
+                max.map(|v| {
+                    truncate_max_value(v, length, is_utf8).unwrap_or_else(|| v.to_vec())
+                }),
+            ),
+            None => (min.map(|v| v.to_vec()), max.map(|v| v.to_vec())),
+        };
+
+        let max_size = self.props.max_statistics_size(self.descr.path());
+        let exceeds_max_size =
+            |v: &Option<Vec<u8>>| v.as_ref().map_or(false, |v| v.len() > max_size);
+        if exceeds_max_size(&min) || exceeds_max_size(&max) {
+            (None, None)
+        } else {
+            (min, max)
+        }
+    }
+
     #[allow(clippy::eq_op)]
     fn update_page_min_max(&mut self, val: &T::T) {
         // simple "isNaN" check that works for all types
@@ -952,20 +1237,25 @@ impl<T: DataType> ColumnWriterImpl<T> {
     }
 
     fn update_column_min_max(&mut self) {
-        let update_min = self.min_column_value.as_ref().map_or(true, |min| {
-            let page_value = self.min_page_value.as_ref().unwrap();
-            self.compare_greater(min, page_value)
-        });
-        if update_min {
-            self.min_column_value = self.min_page_value.clone();
+        // a page of nulls has no min and max values
+        if let Some(page_value) = self.min_page_value.as_ref() {
+            let update_min = self
+                .min_column_value
+                .as_ref()
+                .map_or(true, |min| self.compare_greater(min, page_value));
+            if update_min {
+                self.min_column_value = Some(page_value.clone());
+            }
         }
 
-        let update_max = self.max_column_value.as_ref().map_or(true, |max| {
-            let page_value = self.max_page_value.as_ref().unwrap();
-            self.compare_greater(page_value, max)
-        });
-        if update_max {
-            self.max_column_value = self.max_page_value.clone();
+        if let Some(page_value) = self.max_page_value.as_ref() {
+            let update_max = self
+                .max_column_value
+                .as_ref()
+                .map_or(true, |max| self.compare_greater(page_value, max));
+            if update_max {
+                self.max_column_value = Some(page_value.clone());
+            }
         }
     }
 
@@ -981,6 +1271,76 @@ impl<T: DataType> ColumnWriterImpl<T> {
     }
 }
 
+/// Truncates `data` to at most `length` bytes, such that the result is less than or
+/// equal to `data`. UTF-8 values are truncated at a character boundary.
+fn truncate_min_value(data: &[u8], length: usize, is_utf8: bool) -> Vec<u8> {
+    if data.len() <= length {
+        return data.to_vec();
+    }
+    match truncate_utf8(data, length, is_utf8) {
+        // a value starting with a character longer than `length` is kept as is
+        Some("") => data.to_vec(),
+        Some(truncated) => truncated.as_bytes().to_vec(),
+        None => data[..length].to_vec(),
+    }
+}
+
+/// Truncates `data` to at most `length` bytes and increments the result, such that
+/// it is greater than `data`. Returns `None` if the truncated value cannot be
+/// incremented, e.g. all its bytes are `0xFF`.
+fn truncate_max_value(data: &[u8], length: usize, is_utf8: bool) -> Option<Vec<u8>> {
+    if data.len() <= length {
+        return Some(data.to_vec());
+    }
+    if let Some(truncated) = truncate_utf8(data, length, is_utf8) {
+        return increment_utf8(truncated).map(String::into_bytes);
+    }
+    let mut truncated = data[..length].to_vec();
+    while let Some(byte) = truncated.pop() {
+        if byte < u8::MAX {
+            truncated.push(byte + 1);
+            return Some(truncated);
+        }
+    }
+    None
+}
+
+/// Returns the longest prefix of the UTF-8 encoded `data` that is at most `length`
+/// bytes long and ends at a character boundary, or `None` if `data` is not a UTF-8
+/// value, in which case it is truncated as binary.
+fn truncate_utf8(data: &[u8], length: usize, is_utf8: bool) -> Option<&str> {
+    if !is_utf8 {
+        return None;
+    }
+    let data = std::str::from_utf8(data).ok()?;
+    let mut end = length.min(data.len());
+    while !data.is_char_boundary(end) {
+        end -= 1;
+    }
+    Some(&data[..end])
+}
+
+/// Increments the last character of `data` that is not the max character, dropping
+/// all characters following it.
+fn increment_utf8(data: &str) -> Option<String> {
+    let mut chars: Vec<char> = data.chars().collect();
+    while let Some(c) = chars.pop() {
+        // the code points after U+D7FF are surrogates, which are not valid characters
+        let next = char::from_u32(c as u32 + 1).or_else(|| {
+            if c == '\u{D7FF}' {
+                Some('\u{E000}')
+            } else {
+                None
+            }
+        });
+        if let Some(next) = next {
+            chars.push(next);
+            return Some(chars.into_iter().collect());
+        }
+    }
+    None
+}
+
 // ----------------------------------------------------------------------
 // Encoding support for column writer.
 // This mirrors parquet-mr default encodings for writes. See:
@@ -1316,6 +1676,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_column_writer_byte_stream_split() {
+        let props = || {
+            WriterProperties::builder()
+                .set_dictionary_enabled(false)
+                .set_encoding(Encoding::BYTE_STREAM_SPLIT)
+                .build()
+        };
+        let meta = column_write_and_get_metadata::<DoubleType>(props(), &[1.0, 2.0]);
+        assert_eq!(
+            meta.encodings(),
+            &[Encoding::BYTE_STREAM_SPLIT, Encoding::RLE]
+        );
+
+        column_roundtrip_random::<FloatType>(
+            "test_column_writer_byte_stream_split_float",
+            props(),
+            1024,
+            -1e6,
+            1e6,
+            10,
+            0,
+        );
+        column_roundtrip_random::<DoubleType>(
+            "test_column_writer_byte_stream_split_double",
+            props(),
+            1024,
+            -1e6,
+            1e6,
+            10,
+            0,
+        );
+    }
+
     #[test]
     fn test_column_writer_default_encoding_support_double() {
         check_encoding_write_support::<DoubleType>(
@@ -1490,6 +1884,104 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_column_writer_statistics_enabled() {
+        let write = |level: EnabledStatistics| {
+            let props = Arc::new(
+                WriterProperties::builder()
+                    .set_page_index_enabled(true)
+                    .set_column_statistics_enabled(ColumnPath::from("col"), level)
+                    .build(),
+            );
+            let mut writer =
+                get_test_column_writer::<Int32Type>(get_test_page_writer(), 0, 0, props);
+            writer.write_batch(&[1, 2, 3, 4], None, None).unwrap();
+            writer.close_with_page_index().unwrap()
+        };
+
+        let result = write(EnabledStatistics::None);
+        assert!(result.metadata.statistics().is_none());
+        assert!(result.column_index.is_none());
+        assert!(result.offset_index.is_some());
+
+        let result = write(EnabledStatistics::Chunk);
+        match result.metadata.statistics() {
+            Some(Statistics::Int32(stats)) => {
+                assert_eq!(stats.min(), &1);
+                assert_eq!(stats.max(), &4);
+            }
+            _ => panic!("expecting Statistics::Int32"),
+        }
+        assert!(result.column_index.is_none());
+        assert!(result.offset_index.is_some());
+
+        let result = write(EnabledStatistics::Page);
+        assert!(result.metadata.statistics().is_some());
+        assert!(result.column_index.is_some());
+    }
+
+    #[test]
+    fn test_column_writer_statistics_truncation() {
+        let write = |props: WriterProperties, values: &[&[u8]]| {
+            let values: Vec<ByteArray> =
+                values.iter().map(|v| ByteArray::from(v.to_vec())).collect();
+            let mut writer = get_test_column_writer::<ByteArrayType>(
+                get_test_page_writer(),
+                0,
+                0,
+                Arc::new(props),
+            );
+            writer.write_batch(&values, None, None).unwrap();
+            let (_, _, metadata) = writer.close().unwrap();
+            metadata.statistics().cloned().unwrap()
+        };
+
+        let props = WriterProperties::builder()
+            .set_statistics_truncate_length(4)
+            .build();
+        let stats = write(props, &[&b"abcdefgh"[..], &b"abcdzzzz"[..]]);
+        assert_eq!(stats.min_bytes(), b"abcd");
+        assert_eq!(stats.max_bytes(), b"abce");
+
+        // trailing 0xFF bytes can't be incremented and are dropped
+        let props = WriterProperties::builder()
+            .set_statistics_truncate_length(4)
+            .build();
+        let stats = write(props, &[&b"a"[..], &[b'a', 0xFF, 0xFF, 0xFF, 0x01]]);
+        assert_eq!(stats.min_bytes(), b"a");
+        assert_eq!(stats.max_bytes(), b"b");
+
+        // values larger than the max statistics size are not written
+        let props = WriterProperties::builder()
+            .set_max_statistics_size(3)
+            .build();
+        let stats = write(props, &[&b"abcdefgh"[..], &b"ab"[..]]);
+        assert!(!stats.has_min_max_set());
+        assert_eq!(stats.null_count(), 0);
+    }
+
+    #[test]
+    fn test_truncate_utf8_statistics() {
+        // the first character is 2 bytes long, and can't be split
+        assert_eq!(
+            truncate_min_value("éa".as_bytes(), 1, true),
+            "éa".as_bytes()
+        );
+        assert_eq!(truncate_min_value("aéb".as_bytes(), 2, true), b"a");
+        assert_eq!(
+            truncate_max_value("aéb".as_bytes(), 3, true),
+            Some("aê".as_bytes().to_vec())
+        );
+        assert_eq!(
+            truncate_max_value("a\u{10FFFF}b".as_bytes(), 5, true),
+            Some(b"b".to_vec())
+        );
+        assert_eq!(
+            truncate_max_value("\u{D7FF}b".as_bytes(), 3, true),
+            Some("\u{E000}".as_bytes().to_vec())
+        );
+    }
+
     #[test]
     fn test_column_writer_empty_column_roundtrip() {
         let props = WriterProperties::builder().build();
@@ -1555,6 +2047,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_column_writer_write_batch_dictionary() {
+        let dictionary: Vec<ByteArray> =
+            vec!["a".into(), "b".into(), "unused".into(), "c".into()];
+        let keys = [3, 0, 0, 1, 3, 3];
+        let def_levels = [1, 1, 0, 1, 1, 0, 1, 1];
+        let values: Vec<ByteArray> =
+            keys.iter().map(|key| dictionary[*key].clone()).collect();
+
+        let small_dictionary = WriterProperties::builder()
+            .set_dictionary_pagesize_limit(2)
+            .set_write_batch_size(2)
+            .build();
+        let dictionary_disabled = WriterProperties::builder()
+            .set_dictionary_enabled(false)
+            .build();
+        for props in vec![
+            WriterProperties::builder().build(),
+            small_dictionary,
+            dictionary_disabled,
+        ] {
+            column_roundtrip_with::<ByteArrayType, _>(
+                "test_col_writer_dictionary",
+                props,
+                &values,
+                Some(&def_levels),
+                None,
+                |writer| {
+                    writer.write_batch_dictionary(
+                        &dictionary,
+                        &keys,
+                        Some(&def_levels),
+                        None,
+                    )
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn test_column_writer_write_batch_dictionary_invalid_key() {
+        let page_writer = get_test_page_writer();
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut writer = get_test_column_writer::<Int32Type>(page_writer, 0, 0, props);
+        let res = writer.write_batch_dictionary(&[1, 2], &[0, 2], None, None);
+        assert!(res.is_err());
+        if let Err(err) = res {
+            assert_eq!(
+                format!("{}", err),
+                "Parquet error: Dictionary key 2 out of bounds for dictionary of length 2"
+            );
+        }
+    }
+
     #[test]
     fn test_column_writer_small_write_batch_size() {
         for i in &[1usize, 2, 5, 10, 11, 1023] {
@@ -1799,6 +2345,28 @@ mod tests {
         def_levels: Option<&[i16]>,
         rep_levels: Option<&[i16]>,
     ) {
+        column_roundtrip_with::<T, _>(
+            file_name,
+            props,
+            values,
+            def_levels,
+            rep_levels,
+            |writer| writer.write_batch(values, def_levels, rep_levels),
+        )
+    }
+
+    /// Performs write-read roundtrip, writing the column with `write`, and asserts
+    /// that `values` and the levels were written.
+    fn column_roundtrip_with<'a, T: DataType, F>(
+        file_name: &'a str,
+        props: WriterProperties,
+        values: &[T::T],
+        def_levels: Option<&[i16]>,
+        rep_levels: Option<&[i16]>,
+        write: F,
+    ) where
+        F: FnOnce(&mut ColumnWriterImpl<T>) -> Result<usize>,
+    {
         let file = get_temp_file(file_name, &[]);
         let sink = FileSink::new(&file);
         let page_writer = Box::new(SerializedPageWriter::new(sink));
@@ -1828,7 +2396,7 @@ mod tests {
             Arc::new(props),
         );
 
-        let values_written = writer.write_batch(values, def_levels, rep_levels).unwrap();
+        let values_written = write(&mut writer).unwrap();
         assert_eq!(values_written, values.len());
         let (bytes_written, rows_written, column_metadata) = writer.close().unwrap();
 
@@ -40,6 +40,8 @@
 //! assert_eq!(output, data);
 //! ```
 
+use std::ops::RangeInclusive;
+
 use crate::basic::Compression as CodecType;
 use crate::errors::{ParquetError, Result};
 
@@ -61,22 +63,67 @@ pub trait Codec {
 /// bytes for the compression type.
 /// This returns `None` if the codec type is `UNCOMPRESSED`.
 pub fn create_codec(codec: CodecType) -> Result<Option<Box<dyn Codec>>> {
+    create_codec_with_level(codec, None)
+}
+
+/// Given the compression type `codec` and an optional compression `level`, returns a
+/// codec used to compress and decompress bytes for the compression type.
+///
+/// The level is ignored by codecs that don't support compression levels, see
+/// [`compression_level_range`]. If no level is given, the codec's default level is
+/// used.
+/// This returns `None` if the codec type is `UNCOMPRESSED`.
+pub fn create_codec_with_level(
+    codec: CodecType,
+    level: Option<u32>,
+) -> Result<Option<Box<dyn Codec>>> {
+    check_compression_level(codec, level)?;
+    let level = level.filter(|_| compression_level_range(codec).is_some());
+
     match codec {
         #[cfg(any(feature = "brotli", test))]
-        CodecType::BROTLI => Ok(Some(Box::new(BrotliCodec::new()))),
+        CodecType::BROTLI => Ok(Some(Box::new(BrotliCodec::new(level)))),
         #[cfg(any(feature = "flate2", test))]
-        CodecType::GZIP => Ok(Some(Box::new(GZipCodec::new()))),
+        CodecType::GZIP => Ok(Some(Box::new(GZipCodec::new(level)))),
         #[cfg(any(feature = "snap", test))]
         CodecType::SNAPPY => Ok(Some(Box::new(SnappyCodec::new()))),
         #[cfg(any(feature = "lz4", test))]
         CodecType::LZ4 => Ok(Some(Box::new(LZ4Codec::new()))),
         #[cfg(any(feature = "zstd", test))]
-        CodecType::ZSTD => Ok(Some(Box::new(ZSTDCodec::new()))),
+        CodecType::ZSTD => Ok(Some(Box::new(ZSTDCodec::new(level)))),
         CodecType::UNCOMPRESSED => Ok(None),
         _ => Err(nyi_err!("The codec type {} is not supported yet", codec)),
     }
 }
 
+/// Returns the range of compression levels supported by `codec`, or `None` if the
+/// codec doesn't support compression levels.
+pub fn compression_level_range(codec: CodecType) -> Option<RangeInclusive<u32>> {
+    match codec {
+        CodecType::GZIP => Some(0..=9),
+        CodecType::BROTLI => Some(0..=11),
+        CodecType::ZSTD => Some(1..=22),
+        _ => None,
+    }
+}
+
+/// Returns an error if `level` is not a valid compression level for `codec`. Codecs that
+/// don't support compression levels accept any level.
+pub(crate) fn check_compression_level(
+    codec: CodecType,
+    level: Option<u32>,
+) -> Result<()> {
+    match (level, compression_level_range(codec)) {
+        (Some(level), Some(range)) if !range.contains(&level) => Err(general_err!(
+            "Invalid compression level {} for codec {}, expected a level in {:?}",
+            level,
+            codec,
+            range
+        )),
+        _ => Ok(()),
+    }
+}
+
 #[cfg(any(feature = "snap", test))]
 mod snappy_codec {
     use snap::raw::{decompress_len, max_compress_len, Decoder, Encoder};
@@ -139,12 +186,17 @@ mod gzip_codec {
     use crate::errors::Result;
 
     /// Codec for GZIP compression algorithm.
-    pub struct GZipCodec {}
+    pub struct GZipCodec {
+        level: Compression,
+    }
 
     impl GZipCodec {
-        /// Creates new GZIP compression codec.
-        pub(crate) fn new() -> Self {
-            Self {}
+        /// Creates new GZIP compression codec, compressing with `level` if set or
+        /// the default level otherwise.
+        pub(crate) fn new(level: Option<u32>) -> Self {
+            Self {
+                level: level.map(Compression::new).unwrap_or_default(),
+            }
         }
     }
 
@@ -159,7 +211,7 @@ mod gzip_codec {
         }
 
         fn compress(&mut self, input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<()> {
-            let mut encoder = write::GzEncoder::new(output_buf, Compression::default());
+            let mut encoder = write::GzEncoder::new(output_buf, self.level);
             encoder.write_all(input_buf)?;
             encoder.try_finish().map_err(|e| e.into())
         }
@@ -177,16 +229,21 @@ mod brotli_codec {
     use crate::errors::Result;
 
     const BROTLI_DEFAULT_BUFFER_SIZE: usize = 4096;
-    const BROTLI_DEFAULT_COMPRESSION_QUALITY: u32 = 1; // supported levels 0-9
+    const BROTLI_DEFAULT_COMPRESSION_QUALITY: u32 = 1; // supported levels 0-11
     const BROTLI_DEFAULT_LG_WINDOW_SIZE: u32 = 22; // recommended between 20-22
 
     /// Codec for Brotli compression algorithm.
-    pub struct BrotliCodec {}
+    pub struct BrotliCodec {
+        quality: u32,
+    }
 
     impl BrotliCodec {
-        /// Creates new Brotli compression codec.
-        pub(crate) fn new() -> Self {
-            Self {}
+        /// Creates new Brotli compression codec, compressing with quality `level` if
+        /// set or the default quality otherwise.
+        pub(crate) fn new(level: Option<u32>) -> Self {
+            Self {
+                quality: level.unwrap_or(BROTLI_DEFAULT_COMPRESSION_QUALITY),
+            }
         }
     }
 
@@ -205,7 +262,7 @@ mod brotli_codec {
             let mut encoder = brotli::CompressorWriter::new(
                 output_buf,
                 BROTLI_DEFAULT_BUFFER_SIZE,
-                BROTLI_DEFAULT_COMPRESSION_QUALITY,
+                self.quality,
                 BROTLI_DEFAULT_LG_WINDOW_SIZE,
             );
             encoder.write_all(input_buf)?;
@@ -281,17 +338,23 @@ mod zstd_codec {
     use crate::errors::Result;
 
     /// Codec for Zstandard compression algorithm.
-    pub struct ZSTDCodec {}
+    pub struct ZSTDCodec {
+        level: i32,
+    }
 
     impl ZSTDCodec {
-        /// Creates new Zstandard compression codec.
-        pub(crate) fn new() -> Self {
-            Self {}
+        /// Creates new Zstandard compression codec, compressing with `level` if set or
+        /// the default level otherwise.
+        pub(crate) fn new(level: Option<u32>) -> Self {
+            Self {
+                level: level.map_or(ZSTD_DEFAULT_COMPRESSION_LEVEL, |l| l as i32),
+            }
         }
     }
 
-    /// Compression level (1-21) for ZSTD. Choose 1 here for better compression speed.
-    const ZSTD_COMPRESSION_LEVEL: i32 = 1;
+    /// Default compression level (1-22) for ZSTD. Choose 1 here for better compression
+    /// speed.
+    const ZSTD_DEFAULT_COMPRESSION_LEVEL: i32 = 1;
 
     impl Codec for ZSTDCodec {
         fn decompress(
@@ -307,7 +370,7 @@ mod zstd_codec {
         }
 
         fn compress(&mut self, input_buf: &[u8], output_buf: &mut Vec<u8>) -> Result<()> {
-            let mut encoder = zstd::Encoder::new(output_buf, ZSTD_COMPRESSION_LEVEL)?;
+            let mut encoder = zstd::Encoder::new(output_buf, self.level)?;
             encoder.write_all(input_buf)?;
             match encoder.finish() {
                 Ok(_) => Ok(()),
@@ -325,9 +388,9 @@ mod tests {
 
     use crate::util::test_common::*;
 
-    fn test_roundtrip(c: CodecType, data: &[u8]) {
-        let mut c1 = create_codec(c).unwrap().unwrap();
-        let mut c2 = create_codec(c).unwrap().unwrap();
+    fn test_roundtrip(c: CodecType, level: Option<u32>, data: &[u8]) {
+        let mut c1 = create_codec_with_level(c, level).unwrap().unwrap();
+        let mut c2 = create_codec_with_level(c, level).unwrap().unwrap();
 
         // Compress with c1
         let mut compressed = Vec::new();
@@ -359,10 +422,14 @@ mod tests {
     }
 
     fn test_codec(c: CodecType) {
+        test_codec_with_level(c, None)
+    }
+
+    fn test_codec_with_level(c: CodecType, level: Option<u32>) {
         let sizes = vec![100, 10000, 100000];
         for size in sizes {
             let data = random_bytes(size);
-            test_roundtrip(c, &data);
+            test_roundtrip(c, level, &data);
         }
     }
 
@@ -390,4 +457,26 @@ mod tests {
     fn test_codec_zstd() {
         test_codec(CodecType::ZSTD);
     }
+
+    #[test]
+    fn test_codec_compression_levels() {
+        test_codec_with_level(CodecType::GZIP, Some(9));
+        test_codec_with_level(CodecType::BROTLI, Some(11));
+        test_codec_with_level(CodecType::ZSTD, Some(22));
+        // codecs without compression levels ignore the level
+        test_codec_with_level(CodecType::SNAPPY, Some(100));
+    }
+
+    #[test]
+    fn test_invalid_compression_level() {
+        let err = create_codec_with_level(CodecType::GZIP, Some(10))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid compression level 10 for codec GZIP, expected a level in 0..=9"
+        );
+        assert!(create_codec_with_level(CodecType::ZSTD, Some(0)).is_err());
+        assert!(create_codec_with_level(CodecType::BROTLI, Some(12)).is_err());
+    }
 }
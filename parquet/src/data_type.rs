@@ -63,18 +63,55 @@ impl Int96 {
 
     /// Converts this INT96 into an i64 representing the number of MILLISECONDS since Epoch
     pub fn to_i64(&self) -> i64 {
+        let (seconds, nanoseconds) = self.to_seconds_and_nanos();
+        seconds * MILLIS_PER_SECOND + nanoseconds / NANOS_PER_MILLI
+    }
+
+    /// Converts this INT96 into an i64 representing the number of SECONDS since Epoch
+    pub fn to_seconds(&self) -> i64 {
+        self.to_seconds_and_nanos().0
+    }
+
+    /// Converts this INT96 into an i64 representing the number of MICROSECONDS since Epoch
+    pub fn to_micros(&self) -> i64 {
+        let (seconds, nanoseconds) = self.to_seconds_and_nanos();
+        seconds * MICROS_PER_SECOND + nanoseconds / NANOS_PER_MICRO
+    }
+
+    /// Converts this INT96 into an i64 representing the number of NANOSECONDS since Epoch
+    ///
+    /// Timestamps outside of the years 1677 to 2262 cannot be represented and wrap
+    /// around, use a coarser resolution such as [`Self::to_micros`] for these
+    pub fn to_nanos(&self) -> i64 {
+        let (seconds, nanoseconds) = self.to_seconds_and_nanos();
+        seconds
+            .wrapping_mul(NANOS_PER_SECOND)
+            .wrapping_add(nanoseconds)
+    }
+
+    /// Splits this INT96 into the number of whole seconds since Epoch and the
+    /// nanoseconds within that second
+    fn to_seconds_and_nanos(&self) -> (i64, i64) {
         const JULIAN_DAY_OF_EPOCH: i64 = 2_440_588;
         const SECONDS_PER_DAY: i64 = 86_400;
-        const MILLIS_PER_SECOND: i64 = 1_000;
 
         let day = self.data()[2] as i64;
         let nanoseconds = ((self.data()[1] as i64) << 32) + self.data()[0] as i64;
         let seconds = (day - JULIAN_DAY_OF_EPOCH) * SECONDS_PER_DAY;
 
-        seconds * MILLIS_PER_SECOND + nanoseconds / 1_000_000
+        (
+            seconds + nanoseconds / NANOS_PER_SECOND,
+            nanoseconds % NANOS_PER_SECOND,
+        )
     }
 }
 
+const MILLIS_PER_SECOND: i64 = 1_000;
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+const NANOS_PER_MILLI: i64 = 1_000_000;
+const NANOS_PER_MICRO: i64 = 1_000;
+
 impl Default for Int96 {
     fn default() -> Self {
         Self { value: None }
@@ -624,6 +661,10 @@ pub(crate) mod private {
             decoder: &mut PlainDecoderDetails,
         ) -> Result<usize>;
 
+        /// Skip at most `num_values` values of a given buffer for a higher level
+        /// decoder, without decoding them
+        fn skip(decoder: &mut PlainDecoderDetails, num_values: usize) -> Result<usize>;
+
         /// Return the encoded size for a type
         fn dict_encoding_size(&self) -> (usize, usize) {
             (std::mem::size_of::<Self>(), 1)
@@ -689,6 +730,15 @@ pub(crate) mod private {
             Ok(values_read)
         }
 
+        #[inline]
+        fn skip(decoder: &mut PlainDecoderDetails, num_values: usize) -> Result<usize> {
+            let bit_reader = decoder.bit_reader.as_mut().unwrap();
+            let num_values = std::cmp::min(num_values, decoder.num_values);
+            let values_skipped = bit_reader.skip(num_values, 1);
+            decoder.num_values -= values_skipped;
+            Ok(values_skipped)
+        }
+
         #[inline]
         fn as_i64(&self) -> Result<i64> {
             Ok(*self as i64)
@@ -763,6 +813,23 @@ pub(crate) mod private {
                     Ok(num_values)
                 }
 
+                #[inline]
+                fn skip(decoder: &mut PlainDecoderDetails, num_values: usize) -> Result<usize> {
+                    let data = decoder.data.as_ref().expect("set_data should have been called");
+                    let num_values = std::cmp::min(num_values, decoder.num_values);
+                    let bytes_left = data.len() - decoder.start;
+                    let bytes_to_skip = std::mem::size_of::<Self>() * num_values;
+
+                    if bytes_left < bytes_to_skip {
+                        return Err(eof_err!("Not enough bytes to skip"));
+                    }
+
+                    decoder.start += bytes_to_skip;
+                    decoder.num_values -= num_values;
+
+                    Ok(num_values)
+                }
+
                 #[inline]
                 fn as_i64(&$self) -> Result<i64> {
                     $as_i64
@@ -857,6 +924,26 @@ pub(crate) mod private {
             Ok(num_values)
         }
 
+        #[inline]
+        fn skip(decoder: &mut PlainDecoderDetails, num_values: usize) -> Result<usize> {
+            let data = decoder
+                .data
+                .as_ref()
+                .expect("set_data should have been called");
+            let num_values = std::cmp::min(num_values, decoder.num_values);
+            let bytes_left = data.len() - decoder.start;
+            let bytes_to_skip = 12 * num_values;
+
+            if bytes_left < bytes_to_skip {
+                return Err(eof_err!("Not enough bytes to skip"));
+            }
+
+            decoder.start += bytes_to_skip;
+            decoder.num_values -= num_values;
+
+            Ok(num_values)
+        }
+
         #[inline]
         fn as_any(&self) -> &dyn std::any::Any {
             self
@@ -940,6 +1027,30 @@ pub(crate) mod private {
             Ok(num_values)
         }
 
+        #[inline]
+        fn skip(decoder: &mut PlainDecoderDetails, num_values: usize) -> Result<usize> {
+            let data = decoder
+                .data
+                .as_mut()
+                .expect("set_data should have been called");
+            let num_values = std::cmp::min(num_values, decoder.num_values);
+            for _ in 0..num_values {
+                let len: usize =
+                    read_num_bytes!(u32, 4, data.start_from(decoder.start).as_ref())
+                        as usize;
+                decoder.start += std::mem::size_of::<u32>();
+
+                if data.len() < decoder.start + len {
+                    return Err(eof_err!("Not enough bytes to skip"));
+                }
+
+                decoder.start += len;
+            }
+            decoder.num_values -= num_values;
+
+            Ok(num_values)
+        }
+
         #[inline]
         fn dict_encoding_size(&self) -> (usize, usize) {
             (std::mem::size_of::<u32>(), self.len())
@@ -1010,6 +1121,27 @@ pub(crate) mod private {
             Ok(num_values)
         }
 
+        #[inline]
+        fn skip(decoder: &mut PlainDecoderDetails, num_values: usize) -> Result<usize> {
+            assert!(decoder.type_length > 0);
+
+            let data = decoder
+                .data
+                .as_mut()
+                .expect("set_data should have been called");
+            let num_values = std::cmp::min(num_values, decoder.num_values);
+            let bytes_to_skip = decoder.type_length as usize * num_values;
+
+            if data.len() < decoder.start + bytes_to_skip {
+                return Err(eof_err!("Not enough bytes to skip"));
+            }
+
+            decoder.start += bytes_to_skip;
+            decoder.num_values -= num_values;
+
+            Ok(num_values)
+        }
+
         #[inline]
         fn dict_encoding_size(&self) -> (usize, usize) {
             (std::mem::size_of::<u32>(), self.len())
@@ -1308,6 +1440,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_int96_to_timestamp() {
+        // 1970-01-02T00:00:01.500000123
+        let value = Int96::from(vec![1_500_000_123, 0, 2_440_589]);
+        assert_eq!(value.to_seconds(), 86_401);
+        assert_eq!(value.to_i64(), 86_401_500);
+        assert_eq!(value.to_micros(), 86_401_500_000);
+        assert_eq!(value.to_nanos(), 86_401_500_000_123);
+
+        // 0001-01-01T00:00:00 is only representable with a coarser resolution
+        let value = Int96::from(vec![0, 0, 1_721_426]);
+        assert_eq!(value.to_seconds(), -62_135_596_800);
+        assert_eq!(value.to_micros(), -62_135_596_800_000_000);
+    }
+
     #[test]
     fn test_byte_array_from() {
         assert_eq!(
@@ -27,8 +27,8 @@ use crate::data_type::*;
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::ColumnDescPtr;
 use crate::util::{
-    bit_util::{self, BitReader, FromBytes},
-    memory::{ByteBuffer, ByteBufferPtr},
+    bit_util::{self, BitReader},
+    memory::ByteBufferPtr,
 };
 
 // ----------------------------------------------------------------------
@@ -94,6 +94,13 @@ pub trait Decoder<T: DataType> {
         Ok(num_values)
     }
 
+    /// Consumes at most `num_values` values from this decoder without writing them
+    /// anywhere, decoding them only where the encoding requires it.
+    ///
+    /// Returns the actual number of values skipped, which should be equal to
+    /// `num_values` unless the remaining number of values is less than `num_values`.
+    fn skip(&mut self, num_values: usize) -> Result<usize>;
+
     /// Returns the number of values left in this decoder stream.
     fn values_left(&self) -> usize;
 
@@ -120,6 +127,15 @@ pub fn get_decoder<T: DataType>(
         Encoding::DELTA_BINARY_PACKED => Box::new(DeltaBitPackDecoder::new()),
         Encoding::DELTA_LENGTH_BYTE_ARRAY => Box::new(DeltaLengthByteArrayDecoder::new()),
         Encoding::DELTA_BYTE_ARRAY => Box::new(DeltaByteArrayDecoder::new()),
+        Encoding::BYTE_STREAM_SPLIT => match T::get_physical_type() {
+            Type::FLOAT | Type::DOUBLE => Box::new(ByteStreamSplitDecoder::new()),
+            t => {
+                return Err(nyi_err!(
+                    "Encoding BYTE_STREAM_SPLIT is not supported for type {}",
+                    t
+                ))
+            }
+        },
         e => return Err(nyi_err!("Encoding {} is not supported", e)),
     };
     Ok(decoder)
@@ -196,6 +212,11 @@ impl<T: DataType> Decoder<T> for PlainDecoder<T> {
     fn get(&mut self, buffer: &mut [T::T]) -> Result<usize> {
         T::T::decode(buffer, &mut self.inner)
     }
+
+    #[inline]
+    fn skip(&mut self, num_values: usize) -> Result<usize> {
+        T::T::skip(&mut self.inner, num_values)
+    }
 }
 
 // ----------------------------------------------------------------------
@@ -260,6 +281,15 @@ impl<T: DataType> Decoder<T> for DictDecoder<T> {
         rle.get_batch_with_dict(&self.dictionary[..], buffer, num_values)
     }
 
+    fn skip(&mut self, num_values: usize) -> Result<usize> {
+        assert!(self.rle_decoder.is_some());
+        assert!(self.has_dictionary, "Must call set_dict() first!");
+
+        let rle = self.rle_decoder.as_mut().unwrap();
+        let num_values = cmp::min(num_values, self.num_values);
+        rle.skip(num_values)
+    }
+
     /// Number of values left in this decoder stream
     fn values_left(&self) -> usize {
         self.num_values
@@ -324,6 +354,14 @@ impl<T: DataType> Decoder<T> for RleValueDecoder<T> {
         self.values_left -= values_read;
         Ok(values_read)
     }
+
+    #[inline]
+    fn skip(&mut self, num_values: usize) -> Result<usize> {
+        let num_values = cmp::min(num_values, self.values_left);
+        let values_skipped = self.decoder.skip(num_values)?;
+        self.values_left -= values_skipped;
+        Ok(values_skipped)
+    }
 }
 
 // ----------------------------------------------------------------------
@@ -333,27 +371,38 @@ impl<T: DataType> Decoder<T> for RleValueDecoder<T> {
 /// Supports INT32 and INT64 types.
 /// See [`DeltaBitPackEncoder`](crate::encoding::DeltaBitPackEncoder) for more
 /// information.
+///
+/// Deltas are unpacked a mini block at a time, rather than value by value.
 pub struct DeltaBitPackDecoder<T: DataType> {
     bit_reader: BitReader,
     initialized: bool,
 
     // Header info
-    num_values: usize,
-    num_mini_blocks: i64,
+    /// The number of values that are yet to be read
+    values_left: usize,
+    /// The number of mini blocks in each block
+    mini_blocks_per_block: usize,
+    /// The number of values in each mini block
     values_per_mini_block: usize,
-    values_current_mini_block: usize,
-    first_value: i64,
-    first_value_read: bool,
 
     // Per block info
+    /// The minimum delta of the current block
     min_delta: i64,
+    /// The bit widths of the mini blocks of the current block
+    mini_block_bit_widths: Vec<u8>,
+    /// The index of the current mini block within the current block
     mini_block_idx: usize,
-    delta_bit_width: u8,
-    delta_bit_widths: ByteBuffer,
-    deltas_in_mini_block: Vec<T::T>, // eagerly loaded deltas for a mini block
-    use_batch: bool,
-
-    current_value: i64,
+    /// The number of values left in the current mini block
+    mini_block_remaining: usize,
+    /// The byte offset of the end of the current mini block
+    mini_block_end_offset: usize,
+
+    /// The first value of the page, if it is yet to be read
+    first_value: Option<i64>,
+    /// The last value read, which the next delta is added to
+    last_value: i64,
+    /// Buffer of unpacked deltas
+    deltas: Vec<u64>,
 
     _phantom: PhantomData<T>,
 }
@@ -364,30 +413,33 @@ impl<T: DataType> DeltaBitPackDecoder<T> {
         Self {
             bit_reader: BitReader::from(vec![]),
             initialized: false,
-            num_values: 0,
-            num_mini_blocks: 0,
+            values_left: 0,
+            mini_blocks_per_block: 0,
             values_per_mini_block: 0,
-            values_current_mini_block: 0,
-            first_value: 0,
-            first_value_read: false,
             min_delta: 0,
+            mini_block_bit_widths: vec![],
             mini_block_idx: 0,
-            delta_bit_width: 0,
-            delta_bit_widths: ByteBuffer::new(),
-            deltas_in_mini_block: vec![],
-            use_batch: mem::size_of::<T::T>() == 4,
-            current_value: 0,
+            mini_block_remaining: 0,
+            mini_block_end_offset: 0,
+            first_value: None,
+            last_value: 0,
+            deltas: vec![],
             _phantom: PhantomData,
         }
     }
 
-    /// Returns underlying bit reader offset.
+    /// Returns the byte offset of the end of the encoded values.
+    ///
+    /// This is only the end of the encoded data once all values have been read.
     pub fn get_offset(&self) -> usize {
         assert!(self.initialized, "Bit reader is not initialized");
-        self.bit_reader.get_byte_offset()
+        cmp::max(
+            self.bit_reader.get_byte_offset(),
+            self.mini_block_end_offset,
+        )
     }
 
-    /// Initializes new mini block.
+    /// Reads the header of the next block.
     #[inline]
     fn init_block(&mut self) -> Result<()> {
         self.min_delta = self
@@ -395,47 +447,70 @@ impl<T: DataType> DeltaBitPackDecoder<T> {
             .get_zigzag_vlq_int()
             .ok_or_else(|| eof_err!("Not enough data to decode 'min_delta'"))?;
 
-        self.delta_bit_widths.clear();
-        for _ in 0..self.num_mini_blocks {
+        self.mini_block_bit_widths.clear();
+        for _ in 0..self.mini_blocks_per_block {
             let w = self
                 .bit_reader
                 .get_aligned::<u8>(1)
                 .ok_or_else(|| eof_err!("Not enough data to decode 'width'"))?;
-            self.delta_bit_widths.push(w);
+            self.mini_block_bit_widths.push(w);
         }
 
         self.mini_block_idx = 0;
-        self.delta_bit_width = self.delta_bit_widths.data()[0];
-        self.values_current_mini_block = self.values_per_mini_block;
         Ok(())
     }
 
-    /// Loads delta into mini block.
-    #[inline]
-    fn load_deltas_in_mini_block(&mut self) -> Result<()>
-    where
-        T::T: FromBytes,
-    {
-        if self.use_batch {
-            self.deltas_in_mini_block
-                .resize(self.values_current_mini_block, T::T::default());
-            let loaded = self.bit_reader.get_batch::<T::T>(
-                &mut self.deltas_in_mini_block[..],
-                self.delta_bit_width as usize,
-            );
-            assert!(loaded == self.values_current_mini_block);
+    /// Moves to the next mini block, reading the header of the next block if
+    /// all mini blocks of the current block have been read.
+    fn next_mini_block(&mut self) -> Result<()> {
+        if self.mini_block_idx + 1 < self.mini_block_bit_widths.len() {
+            self.mini_block_idx += 1;
+        } else {
+            self.init_block()?;
+        }
+
+        let bit_width = self.mini_block_bit_widths[self.mini_block_idx] as usize;
+        if bit_width > mem::size_of::<T::T>() * 8 {
+            return Err(general_err!(
+                "Invalid delta bit width {} which is larger than expected {}",
+                bit_width,
+                mem::size_of::<T::T>() * 8
+            ));
+        }
+
+        // Mini blocks hold a multiple of 8 values, and so start and end on a
+        // byte boundary
+        self.mini_block_remaining = self.values_per_mini_block;
+        self.mini_block_end_offset = self.bit_reader.get_byte_offset()
+            + bit_width * self.values_per_mini_block / 8;
+        Ok(())
+    }
+
+    /// Unpacks the next `len` deltas of the current mini block into `deltas`.
+    fn read_deltas(&mut self, len: usize) -> Result<()> {
+        let bit_width = self.mini_block_bit_widths[self.mini_block_idx] as usize;
+
+        // `get_batch` only writes the low 32 bits of wider types
+        self.deltas.clear();
+        self.deltas.resize(len, 0);
+        let read = if bit_width <= 32 {
+            self.bit_reader.get_batch(&mut self.deltas, bit_width)
         } else {
-            self.deltas_in_mini_block.clear();
-            for _ in 0..self.values_current_mini_block {
-                // TODO: load one batch at a time similar to int32
-                let delta = self
-                    .bit_reader
-                    .get_value::<T::T>(self.delta_bit_width as usize)
-                    .ok_or_else(|| eof_err!("Not enough data to decode 'delta'"))?;
-                self.deltas_in_mini_block.push(delta);
+            let mut read = 0;
+            for delta in self.deltas.iter_mut() {
+                match self.bit_reader.get_value::<u64>(bit_width) {
+                    Some(value) => *delta = value,
+                    None => break,
+                }
+                read += 1;
             }
+            read
+        };
+        if read != len {
+            return Err(eof_err!("Not enough data to decode 'delta'"));
         }
 
+        self.mini_block_remaining -= len;
         Ok(())
     }
 }
@@ -450,29 +525,51 @@ impl<T: DataType> Decoder<T> for DeltaBitPackDecoder<T> {
         let block_size = self
             .bit_reader
             .get_vlq_int()
-            .ok_or_else(|| eof_err!("Not enough data to decode 'block_size'"))?;
-        self.num_mini_blocks = self
+            .ok_or_else(|| eof_err!("Not enough data to decode 'block_size'"))?
+            as usize;
+        self.mini_blocks_per_block = self
             .bit_reader
             .get_vlq_int()
-            .ok_or_else(|| eof_err!("Not enough data to decode 'num_mini_blocks'"))?;
-        self.num_values = self
+            .ok_or_else(|| eof_err!("Not enough data to decode 'num_mini_blocks'"))?
+            as usize;
+        self.values_left = self
             .bit_reader
             .get_vlq_int()
             .ok_or_else(|| eof_err!("Not enough data to decode 'num_values'"))?
             as usize;
-        self.first_value = self
+        let first_value = self
             .bit_reader
             .get_zigzag_vlq_int()
             .ok_or_else(|| eof_err!("Not enough data to decode 'first_value'"))?;
 
+        if self.mini_blocks_per_block == 0 || block_size % self.mini_blocks_per_block != 0
+        {
+            return Err(general_err!(
+                "'block_size' must be a multiple of 'num_mini_blocks', got {} and {}",
+                block_size,
+                self.mini_blocks_per_block
+            ));
+        }
+        self.values_per_mini_block = block_size / self.mini_blocks_per_block;
+        if self.values_per_mini_block % 8 != 0 {
+            return Err(general_err!(
+                "Number of values in a mini block must be a multiple of 8, got {}",
+                self.values_per_mini_block
+            ));
+        }
+
         // Reset decoding state
-        self.first_value_read = false;
+        self.min_delta = 0;
+        self.mini_block_bit_widths.clear();
         self.mini_block_idx = 0;
-        self.delta_bit_widths.clear();
-        self.values_current_mini_block = 0;
-
-        self.values_per_mini_block = (block_size / self.num_mini_blocks) as usize;
-        assert!(self.values_per_mini_block % 8 == 0);
+        self.mini_block_remaining = 0;
+        self.mini_block_end_offset = self.bit_reader.get_byte_offset();
+        self.first_value = if self.values_left > 0 {
+            Some(first_value)
+        } else {
+            None
+        };
+        self.last_value = first_value;
 
         Ok(())
     }
@@ -480,47 +577,49 @@ impl<T: DataType> Decoder<T> for DeltaBitPackDecoder<T> {
     fn get(&mut self, buffer: &mut [T::T]) -> Result<usize> {
         assert!(self.initialized, "Bit reader is not initialized");
 
-        let num_values = cmp::min(buffer.len(), self.num_values);
-        for i in 0..num_values {
-            if !self.first_value_read {
-                self.set_decoded_value(buffer, i, self.first_value);
-                self.current_value = self.first_value;
-                self.first_value_read = true;
-                continue;
+        let to_read = cmp::min(buffer.len(), self.values_left);
+        let mut read = 0;
+
+        if to_read > 0 {
+            if let Some(value) = self.first_value.take() {
+                Self::set_decoded_value(&mut buffer[0], value);
+                read += 1;
             }
+        }
 
-            if self.values_current_mini_block == 0 {
-                self.mini_block_idx += 1;
-                if self.mini_block_idx < self.delta_bit_widths.size() {
-                    self.delta_bit_width =
-                        self.delta_bit_widths.data()[self.mini_block_idx];
-                    self.values_current_mini_block = self.values_per_mini_block;
-                } else {
-                    self.init_block()?;
-                }
-                self.load_deltas_in_mini_block()?;
+        while read < to_read {
+            if self.mini_block_remaining == 0 {
+                self.next_mini_block()?;
             }
 
-            // we decrement values in current mini block, so we need to invert index for
-            // delta
-            let delta = self.get_delta(
-                self.deltas_in_mini_block.len() - self.values_current_mini_block,
-            );
+            let batch_len = cmp::min(to_read - read, self.mini_block_remaining);
+            self.read_deltas(batch_len)?;
+
             // It is OK for deltas to contain "overflowed" values after encoding,
             // e.g. i64::MAX - i64::MIN, so we use `wrapping_add` to "overflow" again and
             // restore original value.
-            self.current_value = self.current_value.wrapping_add(self.min_delta);
-            self.current_value = self.current_value.wrapping_add(delta as i64);
-            self.set_decoded_value(buffer, i, self.current_value);
-            self.values_current_mini_block -= 1;
+            self.last_value = Self::apply_deltas(
+                &mut buffer[read..read + batch_len],
+                &self.deltas,
+                self.min_delta,
+                self.last_value,
+            );
+            read += batch_len;
         }
 
-        self.num_values -= num_values;
-        Ok(num_values)
+        self.values_left -= to_read;
+        Ok(to_read)
+    }
+
+    fn skip(&mut self, num_values: usize) -> Result<usize> {
+        // Each value is relative to the previous one, so the deltas of the skipped
+        // values are still unpacked, only a mini block at a time
+        let mut buffer = vec![T::T::default(); cmp::min(num_values, self.values_left)];
+        self.get(&mut buffer)
     }
 
     fn values_left(&self) -> usize {
-        self.num_values
+        self.values_left
     }
 
     fn encoding(&self) -> Encoding {
@@ -531,37 +630,49 @@ impl<T: DataType> Decoder<T> for DeltaBitPackDecoder<T> {
 /// Helper trait to define specific conversions when decoding values
 trait DeltaBitPackDecoderConversion<T: DataType> {
     /// Sets decoded value based on type `T`.
-    fn get_delta(&self, index: usize) -> i64;
+    fn set_decoded_value(slot: &mut T::T, value: i64);
 
-    fn set_decoded_value(&self, buffer: &mut [T::T], index: usize, value: i64);
+    /// Decodes `deltas` into `buffer`, starting from `last_value`, and returns
+    /// the last decoded value.
+    fn apply_deltas(
+        buffer: &mut [T::T],
+        deltas: &[u64],
+        min_delta: i64,
+        last_value: i64,
+    ) -> i64;
 }
 
 impl<T: DataType> DeltaBitPackDecoderConversion<T> for DeltaBitPackDecoder<T> {
     #[inline]
-    fn get_delta(&self, index: usize) -> i64 {
-        ensure_phys_ty!(
-            Type::INT32 | Type::INT64,
-            "DeltaBitPackDecoder only supports Int32Type and Int64Type"
-        );
-        self.deltas_in_mini_block[index].as_i64().unwrap()
-    }
-
-    #[inline]
-    fn set_decoded_value(&self, buffer: &mut [T::T], index: usize, value: i64) {
+    fn set_decoded_value(slot: &mut T::T, value: i64) {
         match T::get_physical_type() {
             Type::INT32 => {
-                let val = buffer[index].as_mut_any().downcast_mut::<i32>().unwrap();
-
-                *val = value as i32;
-            }
-            Type::INT64 => {
-                let val = buffer[index].as_mut_any().downcast_mut::<i64>().unwrap();
-
-                *val = value;
+                *slot.as_mut_any().downcast_mut::<i32>().unwrap() = value as i32
             }
+            Type::INT64 => *slot.as_mut_any().downcast_mut::<i64>().unwrap() = value,
             _ => panic!("DeltaBitPackDecoder only supports Int32Type and Int64Type"),
         };
     }
+
+    #[inline]
+    fn apply_deltas(
+        buffer: &mut [T::T],
+        deltas: &[u64],
+        min_delta: i64,
+        mut last_value: i64,
+    ) -> i64 {
+        ensure_phys_ty!(
+            Type::INT32 | Type::INT64,
+            "DeltaBitPackDecoder only supports Int32Type and Int64Type"
+        );
+        for (slot, delta) in buffer.iter_mut().zip(deltas) {
+            last_value = last_value
+                .wrapping_add(min_delta)
+                .wrapping_add(*delta as i64);
+            Self::set_decoded_value(slot, last_value);
+        }
+        last_value
+    }
 }
 
 // ----------------------------------------------------------------------
@@ -658,6 +769,26 @@ impl<T: DataType> Decoder<T> for DeltaLengthByteArrayDecoder<T> {
         }
     }
 
+    fn skip(&mut self, num_values: usize) -> Result<usize> {
+        match T::get_physical_type() {
+            Type::BYTE_ARRAY => {
+                let num_values = cmp::min(num_values, self.num_values);
+                let end_idx = self.current_idx + num_values;
+                self.offset += self.lengths[self.current_idx..end_idx]
+                    .iter()
+                    .map(|len| *len as usize)
+                    .sum::<usize>();
+
+                self.current_idx = end_idx;
+                self.num_values -= num_values;
+                Ok(num_values)
+            }
+            _ => Err(general_err!(
+                "DeltaLengthByteArrayDecoder only support ByteArrayType"
+            )),
+        }
+    }
+
     fn values_left(&self) -> usize {
         self.num_values
     }
@@ -688,7 +819,7 @@ pub struct DeltaByteArrayDecoder<T: DataType> {
     suffix_decoder: Option<DeltaLengthByteArrayDecoder<ByteArrayType>>,
 
     // The last byte array, used to derive the current prefix
-    previous_value: Vec<u8>,
+    previous_value: ByteArray,
 
     // Number of values left
     num_values: usize,
@@ -704,7 +835,7 @@ impl<T: DataType> DeltaByteArrayDecoder<T> {
             prefix_lengths: vec![],
             current_idx: 0,
             suffix_decoder: None,
-            previous_value: vec![],
+            previous_value: ByteArray::from(vec![]),
             num_values: 0,
             _phantom: PhantomData,
         }
@@ -727,7 +858,7 @@ impl<'m, T: DataType> Decoder<T> for DeltaByteArrayDecoder<T> {
                 self.suffix_decoder = Some(suffix_decoder);
                 self.num_values = num_prefixes;
                 self.current_idx = 0;
-                self.previous_value.clear();
+                self.previous_value = ByteArray::from(vec![]);
                 Ok(())
             }
             _ => {
@@ -742,39 +873,58 @@ impl<'m, T: DataType> Decoder<T> for DeltaByteArrayDecoder<T> {
         match T::get_physical_type() {
             ty @ Type::BYTE_ARRAY | ty @ Type::FIXED_LEN_BYTE_ARRAY => {
                 let num_values = cmp::min(buffer.len(), self.num_values);
-                let mut v: [ByteArray; 1] = [ByteArray::new(); 1];
-                for i in 0..num_values {
-                    // Process suffix
-                    // TODO: this is awkward - maybe we should add a non-vectorized API?
-                    let suffix_decoder = self.suffix_decoder.as_mut().expect("decoder not initialized");
-                    suffix_decoder.get(&mut v[..])?;
-                    let suffix = v[0].data();
 
+                // Decode all suffixes of the batch at once
+                let mut suffixes = vec![ByteArray::new(); num_values];
+                let suffix_decoder =
+                    self.suffix_decoder.as_mut().expect("decoder not initialized");
+                let read = suffix_decoder.get(&mut suffixes)?;
+                if read != num_values {
+                    return Err(eof_err!(
+                        "Expected to read {} suffixes, read {}",
+                        num_values,
+                        read
+                    ));
+                }
+
+                for (slot, suffix) in buffer.iter_mut().zip(suffixes) {
                     // Extract current prefix length, can be 0
                     let prefix_len = self.prefix_lengths[self.current_idx] as usize;
-
-                    // Concatenate prefix with suffix
-                    let mut result = Vec::new();
-                    result.extend_from_slice(&self.previous_value[0..prefix_len]);
-                    result.extend_from_slice(suffix);
-
-                    let data = ByteBufferPtr::new(result.clone());
+                    let previous = self.previous_value.data();
+                    if prefix_len > previous.len() {
+                        return Err(general_err!(
+                            "Prefix length {} exceeds the length {} of the previous value",
+                            prefix_len,
+                            previous.len()
+                        ));
+                    }
+
+                    // Values without a shared prefix are the suffix itself, and
+                    // need not be copied
+                    let value = if prefix_len == 0 {
+                        suffix
+                    } else {
+                        let mut result = Vec::with_capacity(prefix_len + suffix.len());
+                        result.extend_from_slice(&previous[..prefix_len]);
+                        result.extend_from_slice(suffix.data());
+                        ByteArray::from(result)
+                    };
 
                     match ty {
-                        Type::BYTE_ARRAY => buffer[i]
-                            .as_mut_any()
-                            .downcast_mut::<ByteArray>()
-                            .unwrap()
-                            .set_data(data),
-                        Type::FIXED_LEN_BYTE_ARRAY => buffer[i]
-                            .as_mut_any()
-                            .downcast_mut::<FixedLenByteArray>()
-                            .unwrap()
-                            .set_data(data),
+                        Type::BYTE_ARRAY => {
+                            *slot.as_mut_any().downcast_mut::<ByteArray>().unwrap() =
+                                value.clone()
+                        }
+                        Type::FIXED_LEN_BYTE_ARRAY => {
+                            *slot
+                                .as_mut_any()
+                                .downcast_mut::<FixedLenByteArray>()
+                                .unwrap() = value.clone().into()
+                        }
                         _ => unreachable!(),
                     };
 
-                    self.previous_value = result;
+                    self.previous_value = value;
                     self.current_idx += 1;
                 }
 
@@ -789,6 +939,13 @@ impl<'m, T: DataType> Decoder<T> for DeltaByteArrayDecoder<T> {
         }
     }
 
+    fn skip(&mut self, num_values: usize) -> Result<usize> {
+        // Each value shares a prefix with the previous one, so the skipped values
+        // are still decoded to derive the value that follows them
+        let mut buffer = vec![T::T::default(); cmp::min(num_values, self.num_values)];
+        self.get(&mut buffer)
+    }
+
     fn values_left(&self) -> usize {
         self.num_values
     }
@@ -798,6 +955,105 @@ impl<'m, T: DataType> Decoder<T> for DeltaByteArrayDecoder<T> {
     }
 }
 
+// ----------------------------------------------------------------------
+// BYTE_STREAM_SPLIT Decoding
+
+/// Byte stream split decoder.
+/// Supports FLOAT and DOUBLE types.
+/// See [`ByteStreamSplitEncoder`](crate::encoding::ByteStreamSplitEncoder) for more
+/// information.
+pub struct ByteStreamSplitDecoder<T: DataType> {
+    // The encoded byte streams
+    data: Option<ByteBufferPtr>,
+
+    // The number of values in each byte stream
+    num_values: usize,
+
+    // The number of values read so far
+    values_read: usize,
+
+    // Placeholder to allow `T` as generic parameter
+    _phantom: PhantomData<T>,
+}
+
+impl<T: DataType> ByteStreamSplitDecoder<T> {
+    /// Creates new byte stream split decoder.
+    pub fn new() -> Self {
+        Self {
+            data: None,
+            num_values: 0,
+            values_read: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: DataType> Decoder<T> for ByteStreamSplitDecoder<T> {
+    fn set_data(&mut self, data: ByteBufferPtr, _num_values: usize) -> Result<()> {
+        match T::get_physical_type() {
+            Type::FLOAT | Type::DOUBLE => {
+                // the number of values passed in includes nulls, so derive the
+                // number of encoded values from the length of the streams
+                let type_size = mem::size_of::<T::T>();
+                if data.len() % type_size != 0 {
+                    return Err(general_err!(
+                        "Data length {} is not a multiple of the type size {}",
+                        data.len(),
+                        type_size
+                    ));
+                }
+                self.num_values = data.len() / type_size;
+                self.values_read = 0;
+                self.data = Some(data);
+                Ok(())
+            }
+            _ => Err(general_err!(
+                "ByteStreamSplitDecoder only supports FloatType and DoubleType"
+            )),
+        }
+    }
+
+    fn get(&mut self, buffer: &mut [T::T]) -> Result<usize> {
+        ensure_phys_ty!(
+            Type::FLOAT | Type::DOUBLE,
+            "ByteStreamSplitDecoder only supports FloatType and DoubleType"
+        );
+        let data = self
+            .data
+            .as_ref()
+            .expect("set_data should have been called");
+        let data = data.as_ref();
+        let type_size = mem::size_of::<T::T>();
+        let num_values = cmp::min(buffer.len(), self.values_left());
+
+        // SAFETY: any bit pattern is a valid FLOAT or DOUBLE
+        let bytes = unsafe { T::T::slice_as_bytes_mut(&mut buffer[..num_values]) };
+        for (i, value) in bytes.chunks_exact_mut(type_size).enumerate() {
+            let index = self.values_read + i;
+            for (stream, byte) in value.iter_mut().enumerate() {
+                *byte = data[stream * self.num_values + index];
+            }
+        }
+
+        self.values_read += num_values;
+        Ok(num_values)
+    }
+
+    fn skip(&mut self, num_values: usize) -> Result<usize> {
+        let num_values = cmp::min(num_values, self.values_left());
+        self.values_read += num_values;
+        Ok(num_values)
+    }
+
+    fn values_left(&self) -> usize {
+        self.num_values - self.values_read
+    }
+
+    fn encoding(&self) -> Encoding {
+        Encoding::BYTE_STREAM_SPLIT
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::approx_constant)]
 mod tests {
@@ -820,6 +1076,8 @@ mod tests {
         create_and_check_decoder::<Int32Type>(Encoding::DELTA_LENGTH_BYTE_ARRAY, None);
         create_and_check_decoder::<Int32Type>(Encoding::DELTA_BYTE_ARRAY, None);
         create_and_check_decoder::<BoolType>(Encoding::RLE, None);
+        create_and_check_decoder::<FloatType>(Encoding::BYTE_STREAM_SPLIT, None);
+        create_and_check_decoder::<DoubleType>(Encoding::BYTE_STREAM_SPLIT, None);
 
         // error when initializing
         create_and_check_decoder::<Int32Type>(
@@ -840,6 +1098,12 @@ mod tests {
             Encoding::BIT_PACKED,
             Some(nyi_err!("Encoding BIT_PACKED is not supported")),
         );
+        create_and_check_decoder::<Int32Type>(
+            Encoding::BYTE_STREAM_SPLIT,
+            Some(nyi_err!(
+                "Encoding BYTE_STREAM_SPLIT is not supported for type INT32"
+            )),
+        );
     }
 
     #[test]
@@ -1192,6 +1456,101 @@ mod tests {
         assert_eq!(result, vec![29, 43, 89]);
     }
 
+    #[test]
+    fn test_delta_bit_packed_int64_wide_deltas() {
+        // alternating extremes need deltas of the full 64 bit width
+        let data = vec![(0..300)
+            .map(|i| {
+                if i % 2 == 0 {
+                    i64::MAX - i
+                } else {
+                    i64::MIN + i
+                }
+            })
+            .collect()];
+        test_delta_bit_packed_decode::<Int64Type>(data);
+    }
+
+    #[test]
+    fn test_delta_bit_packed_get_in_batches() {
+        let values: Vec<i32> = (0..1000).map(|i| (i * i) % 977 - 500).collect();
+        let mut encoder = DeltaBitPackEncoder::<Int32Type>::new();
+        encoder.put(&values).unwrap();
+        let data = encoder.flush_buffer().unwrap();
+
+        for batch_size in [1, 7, 31, 32, 33, 129, 1000] {
+            let mut decoder = DeltaBitPackDecoder::<Int32Type>::new();
+            decoder.set_data(data.all(), values.len()).unwrap();
+
+            let mut result = vec![];
+            let mut buffer = vec![0; batch_size];
+            loop {
+                let read = decoder.get(&mut buffer).unwrap();
+                if read == 0 {
+                    break;
+                }
+                result.extend_from_slice(&buffer[..read]);
+            }
+            assert_eq!(result, values);
+            assert_eq!(decoder.values_left(), 0);
+            assert_eq!(decoder.get_offset(), data.len());
+        }
+    }
+
+    #[test]
+    fn test_delta_bit_packed_invalid_bit_width() {
+        // block of 8 values in one mini block, whose bit width of 33 is too wide
+        // for INT32
+        let data = vec![8, 1, 2, 0, 0, 33, 0, 0, 0, 0, 0];
+        let mut decoder = DeltaBitPackDecoder::<Int32Type>::new();
+        decoder.set_data(ByteBufferPtr::new(data), 2).unwrap();
+        let mut buffer = vec![0; 2];
+        let err = decoder.get(&mut buffer).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid delta bit width 33 which is larger than expected 32"
+        );
+    }
+
+    #[test]
+    fn test_byte_stream_split_multiple_f32() {
+        let data = vec![
+            vec![
+                f32::from_le_bytes([0xAA, 0xBB, 0xCC, 0xDD]),
+                f32::from_le_bytes([0x00, 0x11, 0x22, 0x33]),
+            ],
+            vec![f32::from_le_bytes([0xA3, 0xB4, 0xC5, 0xD6])],
+        ];
+        test_byte_stream_split_decode::<FloatType>(data);
+    }
+
+    #[test]
+    fn test_byte_stream_split_f64() {
+        let data = vec![vec![
+            f64::from_le_bytes([0, 1, 2, 3, 4, 5, 6, 7]),
+            f64::from_le_bytes([8, 9, 10, 11, 12, 13, 14, 15]),
+        ]];
+        test_byte_stream_split_decode::<DoubleType>(data);
+    }
+
+    #[test]
+    fn test_byte_stream_split_random() {
+        test_byte_stream_split_decode::<FloatType>(vec![FloatType::gen_vec(-1, 1000)]);
+        test_byte_stream_split_decode::<DoubleType>(vec![DoubleType::gen_vec(-1, 1000)]);
+    }
+
+    #[test]
+    fn test_byte_stream_split_invalid_length() {
+        let mut decoder = ByteStreamSplitDecoder::<FloatType>::new();
+        let err = decoder
+            .set_data(ByteBufferPtr::new(vec![0; 7]), 2)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Data length 7 is not a multiple of the type size 4"
+        );
+    }
+
     #[test]
     fn test_delta_byte_array_same_arrays() {
         let data = vec![
@@ -1227,6 +1586,85 @@ mod tests {
         test_delta_byte_array_decode(data);
     }
 
+    #[test]
+    fn test_skip_plain() {
+        test_skip::<Int32Type>((0..100).collect(), Encoding::PLAIN, 17);
+        test_skip::<Int96Type>(vec![Int96::from(vec![1, 2, 3]); 10], Encoding::PLAIN, 3);
+        test_skip::<BoolType>(
+            (0..100).map(|i| i % 3 == 0).collect(),
+            Encoding::PLAIN,
+            71,
+        );
+        let data = (0..20)
+            .map(|i| ByteArray::from(vec![i; i as usize]))
+            .collect();
+        test_skip::<ByteArrayType>(data, Encoding::PLAIN, 7);
+    }
+
+    #[test]
+    fn test_skip_rle_value() {
+        test_skip::<BoolType>((0..100).map(|i| i < 40).collect(), Encoding::RLE, 37);
+    }
+
+    #[test]
+    fn test_skip_delta_bit_packed() {
+        test_skip::<Int64Type>(
+            (0..300).map(|i| i * i).collect(),
+            Encoding::DELTA_BINARY_PACKED,
+            150,
+        );
+    }
+
+    #[test]
+    fn test_skip_delta_length_byte_array() {
+        let data = (0..20)
+            .map(|i| ByteArray::from(vec![i; i as usize]))
+            .collect();
+        test_skip::<ByteArrayType>(data, Encoding::DELTA_LENGTH_BYTE_ARRAY, 12);
+    }
+
+    #[test]
+    fn test_skip_delta_byte_array() {
+        let data = (0..20)
+            .map(|i| ByteArray::from(format!("prefix{}", i).as_str()))
+            .collect();
+        test_skip::<ByteArrayType>(data, Encoding::DELTA_BYTE_ARRAY, 12);
+    }
+
+    #[test]
+    fn test_skip_byte_stream_split() {
+        let data = (0..20).map(|i| i as f64 / 3.0).collect();
+        test_skip::<DoubleType>(data, Encoding::BYTE_STREAM_SPLIT, 5);
+    }
+
+    // Skips the first `num_skipped` values of `data`, and checks the remaining values
+    // are decoded unaffected
+    fn test_skip<T: DataType>(data: Vec<T::T>, encoding: Encoding, num_skipped: usize) {
+        let col_descr = create_test_col_desc_ptr(-1, T::get_physical_type());
+
+        let mut encoder =
+            get_encoder::<T>(col_descr.clone(), encoding, Arc::new(MemTracker::new()))
+                .expect("get encoder");
+        encoder.put(&data).expect("ok to encode");
+        let bytes = encoder.flush_buffer().expect("ok to flush buffer");
+
+        let mut decoder = get_decoder::<T>(col_descr, encoding).expect("get decoder");
+        decoder.set_data(bytes, data.len()).expect("ok to set data");
+
+        assert_eq!(decoder.skip(num_skipped).expect("ok to skip"), num_skipped);
+        assert_eq!(decoder.values_left(), data.len() - num_skipped);
+
+        let mut result = vec![T::T::default(); data.len() - num_skipped];
+        assert_eq!(
+            decoder.get(&mut result).expect("ok to decode"),
+            result.len()
+        );
+        assert_eq!(result, &data[num_skipped..]);
+
+        // Skipping past the end stops at the last value
+        assert_eq!(decoder.skip(1).expect("ok to skip"), 0);
+    }
+
     fn test_rle_value_decode<T: DataType>(data: Vec<Vec<T::T>>) {
         test_encode_decode::<T>(data, Encoding::RLE);
     }
@@ -1235,6 +1673,10 @@ mod tests {
         test_encode_decode::<T>(data, Encoding::DELTA_BINARY_PACKED);
     }
 
+    fn test_byte_stream_split_decode<T: DataType>(data: Vec<Vec<T::T>>) {
+        test_encode_decode::<T>(data, Encoding::BYTE_STREAM_SPLIT);
+    }
+
     fn test_delta_byte_array_decode(data: Vec<Vec<ByteArray>>) {
         test_encode_decode::<ByteArrayType>(data, Encoding::DELTA_BYTE_ARRAY);
     }
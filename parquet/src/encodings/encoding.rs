@@ -17,7 +17,7 @@
 
 //! Contains all supported encoders for Parquet.
 
-use std::{cmp, io::Write, marker::PhantomData};
+use std::{cmp, io::Write, marker::PhantomData, mem};
 
 use crate::basic::*;
 use crate::data_type::private::ParquetValueType;
@@ -89,6 +89,15 @@ pub fn get_encoder<T: DataType>(
         Encoding::DELTA_BINARY_PACKED => Box::new(DeltaBitPackEncoder::new()),
         Encoding::DELTA_LENGTH_BYTE_ARRAY => Box::new(DeltaLengthByteArrayEncoder::new()),
         Encoding::DELTA_BYTE_ARRAY => Box::new(DeltaByteArrayEncoder::new()),
+        Encoding::BYTE_STREAM_SPLIT => match T::get_physical_type() {
+            Type::FLOAT | Type::DOUBLE => Box::new(ByteStreamSplitEncoder::new()),
+            t => {
+                return Err(nyi_err!(
+                    "Encoding BYTE_STREAM_SPLIT is not supported for type {}",
+                    t
+                ))
+            }
+        },
         e => return Err(nyi_err!("Encoding {} is not supported", e)),
     };
     Ok(encoder)
@@ -269,9 +278,13 @@ impl<T: DataType> DictEncoder<T> {
         Ok(ByteBufferPtr::new(encoder.consume()?))
     }
 
+    /// Adds `value` to the dictionary, if not already present, and returns its index
+    /// in the dictionary.
+    ///
+    /// Unlike [`Encoder::put`], the index is not buffered to be written out by
+    /// `write_indices()`, see [`Self::put_index`].
     #[inline]
-    #[allow(clippy::unnecessary_wraps)]
-    fn put_one(&mut self, value: &T::T) -> Result<()> {
+    pub fn insert(&mut self, value: &T::T) -> i32 {
         let mut j = (hash_util::hash(value, 0) & self.mod_bitmask) as usize;
         let mut index = self.hash_slots[j];
 
@@ -287,6 +300,21 @@ impl<T: DataType> DictEncoder<T> {
             index = self.insert_fresh_slot(j, value.clone());
         }
 
+        index
+    }
+
+    /// Buffers `index`, as returned by [`Self::insert`], to be written out by
+    /// `write_indices()`.
+    #[inline]
+    pub fn put_index(&mut self, index: i32) {
+        debug_assert!((index as usize) < self.uniques.size());
+        self.buffered_indices.push(index);
+    }
+
+    #[inline]
+    #[allow(clippy::unnecessary_wraps)]
+    fn put_one(&mut self, value: &T::T) -> Result<()> {
+        let index = self.insert(value);
         self.buffered_indices.push(index);
         Ok(())
     }
@@ -916,6 +944,72 @@ impl<T: DataType> Encoder<T> for DeltaByteArrayEncoder<T> {
     }
 }
 
+// ----------------------------------------------------------------------
+// BYTE_STREAM_SPLIT encoding
+
+/// Byte stream split encoder.
+/// Scatters the bytes of each value into a stream per byte position, so that
+/// e.g. the first bytes of all values are stored together, followed by the
+/// second bytes of all values.
+///
+/// This does not reduce the size of the data by itself, but improves how well
+/// floating point values with a similar sign and exponent compress.
+///
+/// Supports only FLOAT and DOUBLE.
+pub struct ByteStreamSplitEncoder<T: DataType> {
+    buffer: Vec<u8>,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: DataType> ByteStreamSplitEncoder<T> {
+    /// Creates new byte stream split encoder.
+    pub fn new() -> Self {
+        ensure_phys_ty!(
+            Type::FLOAT | Type::DOUBLE,
+            "ByteStreamSplitEncoder only supports FloatType and DoubleType"
+        );
+        Self {
+            buffer: vec![],
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: DataType> Encoder<T> for ByteStreamSplitEncoder<T> {
+    fn put(&mut self, values: &[T::T]) -> Result<()> {
+        self.buffer
+            .extend_from_slice(<T::T as SliceAsBytes>::slice_as_bytes(values));
+        Ok(())
+    }
+
+    // Performance Note:
+    // As far as can be seen these functions are rarely called and as such we can hint to the
+    // compiler that they dont need to be folded into hot locations in the final output.
+    #[cold]
+    fn encoding(&self) -> Encoding {
+        Encoding::BYTE_STREAM_SPLIT
+    }
+
+    fn estimated_data_encoded_size(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn flush_buffer(&mut self) -> Result<ByteBufferPtr> {
+        let type_size = mem::size_of::<T::T>();
+        let num_values = self.buffer.len() / type_size;
+
+        let mut encoded = vec![0; self.buffer.len()];
+        for (i, value) in self.buffer.chunks_exact(type_size).enumerate() {
+            for (stream, byte) in value.iter().enumerate() {
+                encoded[stream * num_values + i] = *byte;
+            }
+        }
+        self.buffer.clear();
+
+        Ok(ByteBufferPtr::new(encoded))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -941,6 +1035,8 @@ mod tests {
         create_and_check_encoder::<Int32Type>(Encoding::DELTA_LENGTH_BYTE_ARRAY, None);
         create_and_check_encoder::<Int32Type>(Encoding::DELTA_BYTE_ARRAY, None);
         create_and_check_encoder::<BoolType>(Encoding::RLE, None);
+        create_and_check_encoder::<FloatType>(Encoding::BYTE_STREAM_SPLIT, None);
+        create_and_check_encoder::<DoubleType>(Encoding::BYTE_STREAM_SPLIT, None);
 
         // error when initializing
         create_and_check_encoder::<Int32Type>(
@@ -994,12 +1090,34 @@ mod tests {
     fn test_float() {
         FloatType::test(Encoding::PLAIN, TEST_SET_SIZE, -1);
         FloatType::test(Encoding::PLAIN_DICTIONARY, TEST_SET_SIZE, -1);
+        FloatType::test(Encoding::BYTE_STREAM_SPLIT, TEST_SET_SIZE, -1);
     }
 
     #[test]
     fn test_double() {
         DoubleType::test(Encoding::PLAIN, TEST_SET_SIZE, -1);
         DoubleType::test(Encoding::PLAIN_DICTIONARY, TEST_SET_SIZE, -1);
+        DoubleType::test(Encoding::BYTE_STREAM_SPLIT, TEST_SET_SIZE, -1);
+    }
+
+    #[test]
+    fn test_byte_stream_split_layout() {
+        let mut encoder = ByteStreamSplitEncoder::<FloatType>::new();
+        encoder
+            .put(&[
+                f32::from_le_bytes([0xAA, 0xBB, 0xCC, 0xDD]),
+                f32::from_le_bytes([0x00, 0x11, 0x22, 0x33]),
+            ])
+            .unwrap();
+        encoder
+            .put(&[f32::from_le_bytes([0xA3, 0xB4, 0xC5, 0xD6])])
+            .unwrap();
+        let encoded = encoder.flush_buffer().unwrap();
+        assert_eq!(
+            encoded.data(),
+            &[0xAA, 0x00, 0xA3, 0xBB, 0x11, 0xB4, 0xCC, 0x22, 0xC5, 0xDD, 0x33, 0xD6]
+        );
+        assert_eq!(encoder.estimated_data_encoded_size(), 0);
     }
 
     #[test]
@@ -1099,6 +1217,9 @@ mod tests {
         values.extend_from_slice(&[false; 16]);
         run_test::<BoolType>(Encoding::RLE, -1, &values, 0, 2, 0);
 
+        // BYTE_STREAM_SPLIT
+        run_test::<DoubleType>(Encoding::BYTE_STREAM_SPLIT, -1, &[1.5; 16], 0, 128, 0);
+
         // DELTA_LENGTH_BYTE_ARRAY
         run_test::<ByteArrayType>(
             Encoding::DELTA_LENGTH_BYTE_ARRAY,
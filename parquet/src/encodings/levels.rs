@@ -149,6 +149,7 @@ impl LevelEncoder {
 /// Decoder for definition/repetition levels.
 /// Currently only supports RLE and BIT_PACKED encoding for Data Page v1 and
 /// RLE for Data Page v2.
+#[derive(Clone)]
 pub enum LevelDecoder {
     RLE(Option<usize>, RleDecoder),
     RLE_V2(Option<usize>, RleDecoder),
@@ -311,6 +311,7 @@ impl RleEncoder {
 }
 
 /// A RLE/Bit-Packing hybrid decoder.
+#[derive(Clone)]
 pub struct RleDecoder {
     // Number of bits used to encode the value. Must be between [0, 64].
     bit_width: u8,
@@ -426,6 +427,37 @@ impl RleDecoder {
         Ok(values_read)
     }
 
+    /// Skips at most `num_values` values without decoding them, returning the number
+    /// of values skipped.
+    #[inline(never)]
+    pub fn skip(&mut self, num_values: usize) -> Result<usize> {
+        let mut values_skipped = 0;
+        while values_skipped < num_values {
+            if self.rle_left > 0 {
+                let num_values =
+                    cmp::min(num_values - values_skipped, self.rle_left as usize);
+                self.rle_left -= num_values as u32;
+                values_skipped += num_values;
+            } else if self.bit_packed_left > 0 {
+                let num_values =
+                    cmp::min(num_values - values_skipped, self.bit_packed_left as usize);
+                let bit_reader =
+                    self.bit_reader.as_mut().expect("bit_reader should be set");
+
+                let num_values = bit_reader.skip(num_values, self.bit_width as usize);
+                if num_values == 0 {
+                    return Err(eof_err!("Not enough data to skip 'bit_packed_value'"));
+                }
+                self.bit_packed_left -= num_values as u32;
+                values_skipped += num_values;
+            } else if !self.reload() {
+                break;
+            }
+        }
+
+        Ok(values_skipped)
+    }
+
     #[inline(never)]
     pub fn get_batch_with_dict<T>(
         &mut self,
@@ -524,6 +556,23 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn test_rle_skip() {
+        // RLE run of 50 1s followed by a bit-packed run of 0-7 with bit width 3
+        let data = ByteBufferPtr::new(vec![0x64, 0x01, 0x03, 0x88, 0xC6, 0xFA]);
+        let mut decoder: RleDecoder = RleDecoder::new(3);
+        decoder.set_data(data);
+        assert_eq!(decoder.skip(48).unwrap(), 48);
+        let mut buffer = vec![0; 3];
+        assert_eq!(decoder.get_batch::<i32>(&mut buffer).unwrap(), 3);
+        assert_eq!(buffer, vec![1, 1, 0]);
+        assert_eq!(decoder.skip(4).unwrap(), 4);
+        let mut buffer = vec![0; 4];
+        assert_eq!(decoder.get_batch::<i32>(&mut buffer).unwrap(), 3);
+        assert_eq!(buffer[..3], [5, 6, 7]);
+        assert_eq!(decoder.skip(1).unwrap(), 0);
+    }
+
     #[test]
     fn test_rle_consume_flush_buffer() {
         let data = vec![1, 1, 1, 2, 2, 3, 3, 3];
@@ -0,0 +1,193 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! AES-GCM block ciphers used to encrypt and decrypt parquet modules.
+
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_128_GCM, AES_256_GCM};
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::errors::{ParquetError, Result};
+
+/// Length of the little endian length prefix of an encrypted module.
+pub(crate) const SIZE_LEN: usize = 4;
+/// Length of the AES-GCM nonce.
+pub(crate) const NONCE_LEN: usize = 12;
+/// Length of the AES-GCM authentication tag.
+pub(crate) const TAG_LEN: usize = 16;
+
+fn make_key(key: &[u8]) -> Result<LessSafeKey> {
+    let algorithm = match key.len() {
+        16 => &AES_128_GCM,
+        32 => &AES_256_GCM,
+        n => {
+            return Err(general_err!(
+                "Invalid encryption key length {}, expected 16 or 32 bytes",
+                n
+            ))
+        }
+    };
+    let key = UnboundKey::new(algorithm, key)
+        .map_err(|_| general_err!("Failed to create AES-GCM key"))?;
+    Ok(LessSafeKey::new(key))
+}
+
+/// Encrypts modules with AES-GCM.
+///
+/// An encrypted module is laid out as the length of the remainder of the module
+/// (4 byte little endian), followed by a random nonce, the ciphertext and the
+/// authentication tag.
+pub(crate) struct GcmBlockEncryptor {
+    key: LessSafeKey,
+    rng: SystemRandom,
+}
+
+impl GcmBlockEncryptor {
+    pub(crate) fn try_new(key: &[u8]) -> Result<Self> {
+        Ok(Self {
+            key: make_key(key)?,
+            rng: SystemRandom::new(),
+        })
+    }
+
+    /// Encrypts `plaintext`, authenticating it with `aad`.
+    pub(crate) fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let mut nonce = [0; NONCE_LEN];
+        self.rng
+            .fill(&mut nonce)
+            .map_err(|_| general_err!("Failed to generate encryption nonce"))?;
+
+        let ciphertext_len = NONCE_LEN + plaintext.len() + TAG_LEN;
+        let mut result = Vec::with_capacity(SIZE_LEN + ciphertext_len);
+        result.extend_from_slice(&(ciphertext_len as u32).to_le_bytes());
+        result.extend_from_slice(&nonce);
+        result.extend_from_slice(plaintext);
+
+        let tag = self
+            .key
+            .seal_in_place_separate_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::from(aad),
+                &mut result[SIZE_LEN + NONCE_LEN..],
+            )
+            .map_err(|_| general_err!("Failed to encrypt module"))?;
+        result.extend_from_slice(tag.as_ref());
+        Ok(result)
+    }
+}
+
+/// Decrypts modules encrypted by [`GcmBlockEncryptor`].
+pub(crate) struct GcmBlockDecryptor {
+    key: LessSafeKey,
+}
+
+impl GcmBlockDecryptor {
+    pub(crate) fn try_new(key: &[u8]) -> Result<Self> {
+        Ok(Self {
+            key: make_key(key)?,
+        })
+    }
+
+    /// Decrypts a whole encrypted module, including its length prefix, verifying
+    /// it against `aad`.
+    pub(crate) fn decrypt(&self, module: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        if module.len() < SIZE_LEN + NONCE_LEN + TAG_LEN {
+            return Err(eof_err!("Encrypted module is too short"));
+        }
+        let mut size = [0; SIZE_LEN];
+        size.copy_from_slice(&module[..SIZE_LEN]);
+        let size = u32::from_le_bytes(size) as usize;
+        if size != module.len() - SIZE_LEN {
+            return Err(general_err!(
+                "Encrypted module length {} does not match buffer length {}",
+                size,
+                module.len() - SIZE_LEN
+            ));
+        }
+
+        let nonce =
+            Nonce::try_assume_unique_for_key(&module[SIZE_LEN..SIZE_LEN + NONCE_LEN])
+                .map_err(|_| general_err!("Invalid encryption nonce"))?;
+        let mut buffer = module[SIZE_LEN + NONCE_LEN..].to_vec();
+        let plaintext_len = self
+            .key
+            .open_in_place(nonce, Aad::from(aad), &mut buffer)
+            .map_err(|_| {
+                general_err!("Failed to decrypt module, the key or AAD may be wrong")
+            })?
+            .len();
+        buffer.truncate(plaintext_len);
+        Ok(buffer)
+    }
+}
+
+/// Returns the total length of the encrypted module starting at `buf`, given its
+/// 4 byte length prefix.
+pub(crate) fn encrypted_module_len(buf: &[u8]) -> Result<usize> {
+    if buf.len() < SIZE_LEN {
+        return Err(eof_err!("Encrypted module is too short"));
+    }
+    let mut size = [0; SIZE_LEN];
+    size.copy_from_slice(&buf[..SIZE_LEN]);
+    Ok(SIZE_LEN + u32::from_le_bytes(size) as usize)
+}
+
+/// Generates `len` random bytes.
+pub(crate) fn random_bytes(len: usize) -> Result<Vec<u8>> {
+    let mut bytes = vec![0; len];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| general_err!("Failed to generate random bytes"))?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for key in [vec![1u8; 16], vec![2u8; 32]].iter() {
+            let encryptor = GcmBlockEncryptor::try_new(key).unwrap();
+            let decryptor = GcmBlockDecryptor::try_new(key).unwrap();
+
+            let encrypted = encryptor.encrypt(b"hello parquet", b"aad").unwrap();
+            assert_eq!(encrypted.len(), SIZE_LEN + NONCE_LEN + 13 + TAG_LEN);
+            assert_eq!(encrypted_module_len(&encrypted).unwrap(), encrypted.len());
+
+            let decrypted = decryptor.decrypt(&encrypted, b"aad").unwrap();
+            assert_eq!(decrypted, b"hello parquet");
+        }
+    }
+
+    #[test]
+    fn test_wrong_aad_or_key() {
+        let encryptor = GcmBlockEncryptor::try_new(&[1; 16]).unwrap();
+        let encrypted = encryptor.encrypt(b"hello parquet", b"aad").unwrap();
+
+        let decryptor = GcmBlockDecryptor::try_new(&[1; 16]).unwrap();
+        assert!(decryptor.decrypt(&encrypted, b"other").is_err());
+
+        let decryptor = GcmBlockDecryptor::try_new(&[2; 16]).unwrap();
+        assert!(decryptor.decrypt(&encrypted, b"aad").is_err());
+    }
+
+    #[test]
+    fn test_invalid_key_length() {
+        assert!(GcmBlockEncryptor::try_new(&[1; 15]).is_err());
+        assert!(GcmBlockDecryptor::try_new(&[1; 24]).is_err());
+    }
+}
@@ -0,0 +1,450 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Configuration and state for reading encrypted parquet files.
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::sync::Arc;
+
+use parquet_format as parquet;
+use parquet_format::{ColumnCryptoMetaData, EncryptionAlgorithm, FileCryptoMetaData};
+use thrift::protocol::TCompactInputProtocol;
+
+use crate::encryption::ciphers::GcmBlockDecryptor;
+use crate::encryption::modules::{create_footer_aad, create_module_aad, ModuleType};
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::ColumnPath;
+
+/// Retrieves encryption keys from their metadata stored in a parquet file, e.g.
+/// by unwrapping them with a master key held by a key management service.
+pub trait KeyRetriever: Send + Sync {
+    /// Returns the key identified by `key_metadata`.
+    fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Properties for decrypting a parquet file.
+///
+/// Keys can be set explicitly, or retrieved from the key metadata stored in the
+/// file with a [`KeyRetriever`]. Explicitly set keys take precedence.
+///
+/// Use [`FileDecryptionProperties::builder`] to create these properties.
+#[derive(Clone)]
+pub struct FileDecryptionProperties {
+    footer_key: Option<Vec<u8>>,
+    column_keys: HashMap<ColumnPath, Vec<u8>>,
+    key_retriever: Option<Arc<dyn KeyRetriever>>,
+    aad_prefix: Option<Vec<u8>>,
+}
+
+impl FileDecryptionProperties {
+    /// Returns a builder for decryption properties.
+    pub fn builder() -> FileDecryptionPropertiesBuilder {
+        FileDecryptionPropertiesBuilder::default()
+    }
+
+    /// Returns the AAD prefix supplied by the reader, if any.
+    pub fn aad_prefix(&self) -> Option<&[u8]> {
+        self.aad_prefix.as_deref()
+    }
+
+    fn footer_key(&self, key_metadata: Option<&[u8]>) -> Result<Vec<u8>> {
+        if let Some(key) = &self.footer_key {
+            return Ok(key.clone());
+        }
+        match (&self.key_retriever, key_metadata) {
+            (Some(retriever), Some(key_metadata)) => retriever.retrieve_key(key_metadata),
+            _ => Err(general_err!("No key to decrypt the footer")),
+        }
+    }
+
+    fn column_key(
+        &self,
+        col: &ColumnPath,
+        key_metadata: Option<&[u8]>,
+    ) -> Result<Vec<u8>> {
+        if let Some(key) = self.column_keys.get(col) {
+            return Ok(key.clone());
+        }
+        match (&self.key_retriever, key_metadata) {
+            (Some(retriever), Some(key_metadata)) => retriever.retrieve_key(key_metadata),
+            _ => Err(general_err!("No key to decrypt column {}", col.string())),
+        }
+    }
+}
+
+impl fmt::Debug for FileDecryptionProperties {
+    // Keys are deliberately left out
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut columns: Vec<_> = self.column_keys.keys().map(|c| c.string()).collect();
+        columns.sort();
+        f.debug_struct("FileDecryptionProperties")
+            .field("has_footer_key", &self.footer_key.is_some())
+            .field("column_keys", &columns)
+            .field("has_key_retriever", &self.key_retriever.is_some())
+            .field("aad_prefix", &self.aad_prefix)
+            .finish()
+    }
+}
+
+/// Builder for [`FileDecryptionProperties`].
+#[derive(Default)]
+pub struct FileDecryptionPropertiesBuilder {
+    footer_key: Option<Vec<u8>>,
+    column_keys: HashMap<ColumnPath, Vec<u8>>,
+    key_retriever: Option<Arc<dyn KeyRetriever>>,
+    aad_prefix: Option<Vec<u8>>,
+}
+
+impl FileDecryptionPropertiesBuilder {
+    /// Sets the key used to decrypt the footer, and the columns encrypted with it.
+    pub fn set_footer_key(mut self, value: Vec<u8>) -> Self {
+        self.footer_key = Some(value);
+        self
+    }
+
+    /// Sets the key used to decrypt the column at `col`.
+    pub fn set_column_key(mut self, col: ColumnPath, key: Vec<u8>) -> Self {
+        self.column_keys.insert(col, key);
+        self
+    }
+
+    /// Sets the retriever of the keys that are not set explicitly.
+    pub fn set_key_retriever(mut self, value: Arc<dyn KeyRetriever>) -> Self {
+        self.key_retriever = Some(value);
+        self
+    }
+
+    /// Sets the AAD prefix, which is required to read files that were written
+    /// without storing it.
+    pub fn set_aad_prefix(mut self, value: Vec<u8>) -> Self {
+        self.aad_prefix = Some(value);
+        self
+    }
+
+    /// Finalizes the configuration.
+    pub fn build(self) -> Result<FileDecryptionProperties> {
+        if self.footer_key.is_none() && self.key_retriever.is_none() {
+            return Err(general_err!(
+                "Either a footer key or a key retriever is required for decryption"
+            ));
+        }
+        Ok(FileDecryptionProperties {
+            footer_key: self.footer_key,
+            column_keys: self.column_keys,
+            key_retriever: self.key_retriever,
+            aad_prefix: self.aad_prefix,
+        })
+    }
+}
+
+/// Decrypts the modules of a single file.
+pub(crate) struct FileDecryptor {
+    file_aad: Vec<u8>,
+    footer_decryptor: Arc<GcmBlockDecryptor>,
+    /// The decryptor of each encrypted column, columns not in this map are
+    /// stored in plaintext
+    column_decryptors: HashMap<ColumnPath, Arc<GcmBlockDecryptor>>,
+}
+
+impl FileDecryptor {
+    pub(crate) fn try_new(
+        properties: &FileDecryptionProperties,
+        crypto_metadata: &FileCryptoMetaData,
+    ) -> Result<Self> {
+        let algorithm = match &crypto_metadata.encryption_algorithm {
+            EncryptionAlgorithm::AESGCMV1(algorithm) => algorithm,
+            EncryptionAlgorithm::AESGCMCTRV1(_) => {
+                return Err(nyi_err!(
+                    "The AES_GCM_CTR_V1 encryption algorithm is not supported yet"
+                ))
+            }
+        };
+
+        let aad_prefix = match (&algorithm.aad_prefix, &properties.aad_prefix) {
+            (Some(stored), Some(supplied)) if stored != supplied => {
+                return Err(general_err!(
+                    "The supplied AAD prefix does not match the AAD prefix in the file"
+                ))
+            }
+            (Some(prefix), _) | (None, Some(prefix)) => prefix.clone(),
+            (None, None) if algorithm.supply_aad_prefix == Some(true) => {
+                return Err(general_err!(
+                    "The file was written without its AAD prefix, which must be supplied"
+                ))
+            }
+            (None, None) => vec![],
+        };
+        let mut file_aad = aad_prefix;
+        file_aad.extend_from_slice(algorithm.aad_file_unique.as_deref().unwrap_or(&[]));
+
+        let footer_key =
+            properties.footer_key(crypto_metadata.key_metadata.as_deref())?;
+        Ok(Self {
+            file_aad,
+            footer_decryptor: Arc::new(GcmBlockDecryptor::try_new(&footer_key)?),
+            column_decryptors: HashMap::new(),
+        })
+    }
+
+    pub(crate) fn file_aad(&self) -> &[u8] {
+        &self.file_aad
+    }
+
+    /// Decrypts the thrift encoded `FileMetaData`.
+    pub(crate) fn decrypt_footer(&self, module: &[u8]) -> Result<Vec<u8>> {
+        self.footer_decryptor
+            .decrypt(module, &create_footer_aad(&self.file_aad))
+    }
+
+    /// Returns the decryptor of the column at `col`, or `None` if it is stored
+    /// in plaintext.
+    pub(crate) fn column_decryptor(
+        &self,
+        col: &ColumnPath,
+    ) -> Option<Arc<GcmBlockDecryptor>> {
+        self.column_decryptors.get(col).cloned()
+    }
+
+    /// Prepares the decryption of the column chunks of `row_groups`, decrypting
+    /// the metadata of the column chunks that are encrypted with a column key.
+    ///
+    /// Sets the ordinal of each row group that does not record it, as it is part
+    /// of the AAD of its modules.
+    pub(crate) fn decrypt_row_groups(
+        &mut self,
+        properties: &FileDecryptionProperties,
+        row_groups: &mut [parquet::RowGroup],
+    ) -> Result<()> {
+        for (rg_idx, row_group) in row_groups.iter_mut().enumerate() {
+            let rg_ordinal = match row_group.ordinal {
+                Some(ordinal) => ordinal as usize,
+                None => {
+                    let ordinal = i16::try_from(rg_idx).map_err(|_| {
+                        general_err!(
+                            "Encrypted row group ordinal {} exceeds the maximum of {}",
+                            rg_idx,
+                            i16::MAX
+                        )
+                    })?;
+                    row_group.ordinal = Some(ordinal);
+                    ordinal as usize
+                }
+            };
+            for (col_idx, column) in row_group.columns.iter_mut().enumerate() {
+                let column_key = match &column.crypto_metadata {
+                    None => continue,
+                    Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_)) => {
+                        if let Some(meta_data) = &column.meta_data {
+                            let col = ColumnPath::new(meta_data.path_in_schema.clone());
+                            self.column_decryptors
+                                .insert(col, self.footer_decryptor.clone());
+                        }
+                        continue;
+                    }
+                    Some(ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(column_key)) => {
+                        column_key
+                    }
+                };
+
+                let col = ColumnPath::new(column_key.path_in_schema.clone());
+                let decryptor = match self.column_decryptors.get(&col) {
+                    Some(decryptor) => decryptor.clone(),
+                    None => {
+                        let key = properties
+                            .column_key(&col, column_key.key_metadata.as_deref())?;
+                        let decryptor = Arc::new(GcmBlockDecryptor::try_new(&key)?);
+                        self.column_decryptors.insert(col, decryptor.clone());
+                        decryptor
+                    }
+                };
+
+                if let Some(encrypted) = &column.encrypted_column_metadata {
+                    let aad = create_module_aad(
+                        &self.file_aad,
+                        ModuleType::ColumnMetaData,
+                        rg_ordinal,
+                        col_idx,
+                        None,
+                    )?;
+                    let decrypted = decryptor.decrypt(encrypted, &aad)?;
+                    let mut prot = TCompactInputProtocol::new(decrypted.as_slice());
+                    column.meta_data =
+                        Some(parquet::ColumnMetaData::read_from_in_protocol(&mut prot)?);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for FileDecryptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut columns: Vec<_> =
+            self.column_decryptors.keys().map(|c| c.string()).collect();
+        columns.sort();
+        f.debug_struct("FileDecryptor")
+            .field("encrypted_columns", &columns)
+            .finish()
+    }
+}
+
+/// Decrypts the pages of a single column chunk, tracking the page ordinal used
+/// in the AAD of each page.
+pub(crate) struct PageDecryptor {
+    decryptor: Arc<GcmBlockDecryptor>,
+    file_aad: Vec<u8>,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+    page_ordinal: usize,
+    /// Whether the next page is the dictionary page of the column chunk
+    dictionary_page_pending: bool,
+}
+
+impl PageDecryptor {
+    pub(crate) fn new(
+        file_decryptor: &FileDecryptor,
+        decryptor: Arc<GcmBlockDecryptor>,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+        has_dictionary_page: bool,
+    ) -> Self {
+        Self {
+            decryptor,
+            file_aad: file_decryptor.file_aad().to_vec(),
+            row_group_ordinal,
+            column_ordinal,
+            page_ordinal: 0,
+            dictionary_page_pending: has_dictionary_page,
+        }
+    }
+
+    fn aad(&self, module_type: ModuleType) -> Result<Vec<u8>> {
+        create_module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group_ordinal,
+            self.column_ordinal,
+            Some(self.page_ordinal),
+        )
+    }
+
+    /// Decrypts the thrift encoded header of the next page.
+    pub(crate) fn decrypt_page_header(&self, module: &[u8]) -> Result<Vec<u8>> {
+        let module_type = if self.dictionary_page_pending {
+            ModuleType::DictionaryPageHeader
+        } else {
+            ModuleType::DataPageHeader
+        };
+        self.decryptor.decrypt(module, &self.aad(module_type)?)
+    }
+
+    /// Decrypts the body of the page whose header was last decrypted.
+    pub(crate) fn decrypt_page(
+        &mut self,
+        page_type: parquet::PageType,
+        module: &[u8],
+    ) -> Result<Vec<u8>> {
+        let module_type = match page_type {
+            parquet::PageType::DictionaryPage => ModuleType::DictionaryPage,
+            _ => ModuleType::DataPage,
+        };
+        let decrypted = self.decryptor.decrypt(module, &self.aad(module_type)?)?;
+        self.finish_page(page_type);
+        Ok(decrypted)
+    }
+
+    /// Advances past the page whose header was last decrypted, without
+    /// decrypting its body.
+    pub(crate) fn finish_page(&mut self, page_type: parquet::PageType) {
+        if page_type == parquet::PageType::DictionaryPage {
+            self.dictionary_page_pending = false;
+        } else {
+            self.page_ordinal += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::encrypt::{FileEncryptionProperties, FileEncryptor};
+
+    struct TestKeyRetriever;
+
+    impl KeyRetriever for TestKeyRetriever {
+        fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>> {
+            match key_metadata {
+                b"kf" => Ok(vec![0; 16]),
+                b"kc" => Ok(vec![1; 16]),
+                _ => Err(general_err!("Unknown key")),
+            }
+        }
+    }
+
+    #[test]
+    fn test_decryption_properties() {
+        assert!(FileDecryptionProperties::builder().build().is_err());
+
+        let props = FileDecryptionProperties::builder()
+            .set_key_retriever(Arc::new(TestKeyRetriever))
+            .set_aad_prefix(b"file".to_vec())
+            .build()
+            .unwrap();
+        assert_eq!(props.aad_prefix(), Some(&b"file"[..]));
+        assert_eq!(props.footer_key(Some(&b"kf"[..])).unwrap(), vec![0; 16]);
+        assert!(props.footer_key(None).is_err());
+        assert!(props
+            .column_key(&ColumnPath::from("a"), Some(&b"x"[..]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_footer_round_trip() {
+        let encryptor = FileEncryptor::try_new(
+            FileEncryptionProperties::builder(vec![0; 16])
+                .set_footer_key_metadata(b"kf".to_vec())
+                .set_aad_prefix(b"file".to_vec())
+                .set_store_aad_prefix(false)
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+        let crypto_metadata = encryptor.file_crypto_metadata();
+        let encrypted = encryptor.encrypt_footer(b"footer").unwrap();
+
+        let props = FileDecryptionProperties::builder()
+            .set_key_retriever(Arc::new(TestKeyRetriever))
+            .build()
+            .unwrap();
+        let err = FileDecryptor::try_new(&props, &crypto_metadata).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: The file was written without its AAD prefix, which must be supplied"
+        );
+
+        let props = FileDecryptionProperties::builder()
+            .set_key_retriever(Arc::new(TestKeyRetriever))
+            .set_aad_prefix(b"file".to_vec())
+            .build()
+            .unwrap();
+        let decryptor = FileDecryptor::try_new(&props, &crypto_metadata).unwrap();
+        assert_eq!(decryptor.file_aad(), encryptor.file_aad());
+        assert_eq!(decryptor.decrypt_footer(&encrypted).unwrap(), b"footer");
+    }
+}
@@ -0,0 +1,459 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Configuration and state for writing encrypted parquet files.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use parquet_format as parquet;
+use parquet_format::{
+    AesGcmV1, ColumnCryptoMetaData, EncryptionAlgorithm, EncryptionWithColumnKey,
+    EncryptionWithFooterKey, FileCryptoMetaData,
+};
+
+use crate::encryption::ciphers::{random_bytes, GcmBlockEncryptor};
+use crate::encryption::modules::{create_footer_aad, create_module_aad, ModuleType};
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::ColumnPath;
+
+/// Length of the random part of the AAD that is unique to each file.
+const AAD_FILE_UNIQUE_LEN: usize = 8;
+
+#[derive(Clone)]
+struct ColumnKey {
+    key: Vec<u8>,
+    key_metadata: Option<Vec<u8>>,
+}
+
+/// Properties for encrypting a parquet file.
+///
+/// If no column keys are set, all columns are encrypted with the footer key.
+/// Otherwise only the columns with a key are encrypted, and all other columns are
+/// written in plaintext.
+///
+/// Use [`FileEncryptionProperties::builder`] to create these properties, and
+/// [`WriterPropertiesBuilder::set_file_encryption_properties`] to write an
+/// encrypted file.
+///
+/// [`WriterPropertiesBuilder::set_file_encryption_properties`]:
+///  crate::file::properties::WriterPropertiesBuilder::set_file_encryption_properties
+#[derive(Clone)]
+pub struct FileEncryptionProperties {
+    footer_key: Vec<u8>,
+    footer_key_metadata: Option<Vec<u8>>,
+    column_keys: HashMap<ColumnPath, ColumnKey>,
+    aad_prefix: Option<Vec<u8>>,
+    store_aad_prefix: bool,
+}
+
+impl FileEncryptionProperties {
+    /// Returns a builder for encryption properties using `footer_key`, an AES key
+    /// of 16 or 32 bytes.
+    pub fn builder(footer_key: Vec<u8>) -> FileEncryptionPropertiesBuilder {
+        FileEncryptionPropertiesBuilder::new(footer_key)
+    }
+
+    /// Returns the metadata stored with the footer key, if any.
+    pub fn footer_key_metadata(&self) -> Option<&[u8]> {
+        self.footer_key_metadata.as_deref()
+    }
+
+    /// Returns the AAD prefix, if any.
+    pub fn aad_prefix(&self) -> Option<&[u8]> {
+        self.aad_prefix.as_deref()
+    }
+
+    /// Returns `true` if the AAD prefix is stored in the file.
+    pub fn store_aad_prefix(&self) -> bool {
+        self.store_aad_prefix
+    }
+
+    /// Returns `true` if all columns are encrypted with the footer key.
+    pub fn encrypt_all_columns(&self) -> bool {
+        self.column_keys.is_empty()
+    }
+
+    /// Returns `true` if the column at `col` is encrypted.
+    pub fn is_column_encrypted(&self, col: &ColumnPath) -> bool {
+        self.encrypt_all_columns() || self.column_keys.contains_key(col)
+    }
+}
+
+impl fmt::Debug for FileEncryptionProperties {
+    // Keys are deliberately left out
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut encrypted_columns: Vec<_> =
+            self.column_keys.keys().map(|c| c.string()).collect();
+        encrypted_columns.sort();
+        f.debug_struct("FileEncryptionProperties")
+            .field("footer_key_metadata", &self.footer_key_metadata)
+            .field("encrypted_columns", &encrypted_columns)
+            .field("aad_prefix", &self.aad_prefix)
+            .field("store_aad_prefix", &self.store_aad_prefix)
+            .finish()
+    }
+}
+
+/// Builder for [`FileEncryptionProperties`].
+pub struct FileEncryptionPropertiesBuilder {
+    footer_key: Vec<u8>,
+    footer_key_metadata: Option<Vec<u8>>,
+    column_keys: HashMap<ColumnPath, ColumnKey>,
+    aad_prefix: Option<Vec<u8>>,
+    store_aad_prefix: bool,
+}
+
+impl FileEncryptionPropertiesBuilder {
+    fn new(footer_key: Vec<u8>) -> Self {
+        Self {
+            footer_key,
+            footer_key_metadata: None,
+            column_keys: HashMap::new(),
+            aad_prefix: None,
+            store_aad_prefix: true,
+        }
+    }
+
+    /// Sets the metadata stored with the footer key, e.g. an identifier a key
+    /// management service can use to retrieve the key when reading.
+    pub fn set_footer_key_metadata(mut self, value: Vec<u8>) -> Self {
+        self.footer_key_metadata = Some(value);
+        self
+    }
+
+    /// Encrypts the column at `col` with its own key.
+    pub fn set_column_key(mut self, col: ColumnPath, key: Vec<u8>) -> Self {
+        self.column_keys.insert(
+            col,
+            ColumnKey {
+                key,
+                key_metadata: None,
+            },
+        );
+        self
+    }
+
+    /// Encrypts the column at `col` with its own key, storing `key_metadata` with
+    /// it in the file.
+    pub fn set_column_key_with_metadata(
+        mut self,
+        col: ColumnPath,
+        key: Vec<u8>,
+        key_metadata: Vec<u8>,
+    ) -> Self {
+        self.column_keys.insert(
+            col,
+            ColumnKey {
+                key,
+                key_metadata: Some(key_metadata),
+            },
+        );
+        self
+    }
+
+    /// Sets the AAD prefix, which is authenticated along with every module of the
+    /// file, e.g. to bind the file to its name.
+    pub fn set_aad_prefix(mut self, value: Vec<u8>) -> Self {
+        self.aad_prefix = Some(value);
+        self
+    }
+
+    /// Sets whether the AAD prefix is stored in the file. If not, readers need to
+    /// supply it. Defaults to `true`.
+    pub fn set_store_aad_prefix(mut self, value: bool) -> Self {
+        self.store_aad_prefix = value;
+        self
+    }
+
+    /// Finalizes the configuration, checking the lengths of all keys.
+    pub fn build(self) -> Result<FileEncryptionProperties> {
+        check_key_length(&self.footer_key, "footer")?;
+        for (col, column_key) in &self.column_keys {
+            check_key_length(&column_key.key, &col.string())?;
+        }
+        Ok(FileEncryptionProperties {
+            footer_key: self.footer_key,
+            footer_key_metadata: self.footer_key_metadata,
+            column_keys: self.column_keys,
+            aad_prefix: self.aad_prefix,
+            store_aad_prefix: self.store_aad_prefix,
+        })
+    }
+}
+
+fn check_key_length(key: &[u8], name: &str) -> Result<()> {
+    match key.len() {
+        16 | 32 => Ok(()),
+        n => Err(general_err!(
+            "Invalid length {} of key for {}, expected 16 or 32 bytes",
+            n,
+            name
+        )),
+    }
+}
+
+/// Encrypts the modules of a single file.
+pub(crate) struct FileEncryptor {
+    properties: FileEncryptionProperties,
+    aad_file_unique: Vec<u8>,
+    file_aad: Vec<u8>,
+    footer_encryptor: Arc<GcmBlockEncryptor>,
+    column_encryptors: HashMap<ColumnPath, Arc<GcmBlockEncryptor>>,
+}
+
+impl FileEncryptor {
+    pub(crate) fn try_new(properties: FileEncryptionProperties) -> Result<Self> {
+        let aad_file_unique = random_bytes(AAD_FILE_UNIQUE_LEN)?;
+        let mut file_aad = properties.aad_prefix.clone().unwrap_or_default();
+        file_aad.extend_from_slice(&aad_file_unique);
+
+        let footer_encryptor =
+            Arc::new(GcmBlockEncryptor::try_new(&properties.footer_key)?);
+        let column_encryptors = properties
+            .column_keys
+            .iter()
+            .map(|(col, column_key)| {
+                let encryptor = GcmBlockEncryptor::try_new(&column_key.key)?;
+                Ok((col.clone(), Arc::new(encryptor)))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            properties,
+            aad_file_unique,
+            file_aad,
+            footer_encryptor,
+            column_encryptors,
+        })
+    }
+
+    pub(crate) fn file_aad(&self) -> &[u8] {
+        &self.file_aad
+    }
+
+    /// Returns the encryptor of the column at `col`, or `None` if it is written
+    /// in plaintext.
+    pub(crate) fn column_encryptor(
+        &self,
+        col: &ColumnPath,
+    ) -> Option<Arc<GcmBlockEncryptor>> {
+        if self.properties.encrypt_all_columns() {
+            return Some(self.footer_encryptor.clone());
+        }
+        self.column_encryptors.get(col).cloned()
+    }
+
+    /// Returns the crypto metadata of the column at `col`, or `None` if it is
+    /// written in plaintext.
+    pub(crate) fn column_crypto_metadata(
+        &self,
+        col: &ColumnPath,
+    ) -> Option<ColumnCryptoMetaData> {
+        if self.properties.encrypt_all_columns() {
+            return Some(ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(
+                EncryptionWithFooterKey {},
+            ));
+        }
+        self.properties.column_keys.get(col).map(|column_key| {
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey {
+                path_in_schema: col.parts().to_vec(),
+                key_metadata: column_key.key_metadata.clone(),
+            })
+        })
+    }
+
+    /// Returns `true` if the metadata of the column at `col` is encrypted
+    /// separately from the footer, with its own key.
+    pub(crate) fn has_column_key(&self, col: &ColumnPath) -> bool {
+        self.column_encryptors.contains_key(col)
+    }
+
+    /// Encrypts the thrift encoded `ColumnMetaData` of a column chunk with a
+    /// column key.
+    pub(crate) fn encrypt_column_metadata(
+        &self,
+        col: &ColumnPath,
+        metadata: &[u8],
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> Result<Vec<u8>> {
+        let encryptor = self.column_encryptors.get(col).ok_or_else(|| {
+            general_err!("No encryption key for column {}", col.string())
+        })?;
+        let aad = create_module_aad(
+            &self.file_aad,
+            ModuleType::ColumnMetaData,
+            row_group_ordinal,
+            column_ordinal,
+            None,
+        )?;
+        encryptor.encrypt(metadata, &aad)
+    }
+
+    /// Returns the crypto metadata written before the encrypted footer.
+    pub(crate) fn file_crypto_metadata(&self) -> FileCryptoMetaData {
+        FileCryptoMetaData {
+            encryption_algorithm: self.encryption_algorithm(),
+            key_metadata: self.properties.footer_key_metadata.clone(),
+        }
+    }
+
+    fn encryption_algorithm(&self) -> EncryptionAlgorithm {
+        let supply_aad_prefix = self
+            .properties
+            .aad_prefix
+            .as_ref()
+            .map(|_| !self.properties.store_aad_prefix);
+        let aad_prefix = if self.properties.store_aad_prefix {
+            self.properties.aad_prefix.clone()
+        } else {
+            None
+        };
+        EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+            aad_prefix,
+            aad_file_unique: Some(self.aad_file_unique.clone()),
+            supply_aad_prefix,
+        })
+    }
+
+    /// Encrypts the thrift encoded `FileMetaData`.
+    pub(crate) fn encrypt_footer(&self, metadata: &[u8]) -> Result<Vec<u8>> {
+        let aad = create_footer_aad(&self.file_aad);
+        self.footer_encryptor.encrypt(metadata, &aad)
+    }
+}
+
+/// Encrypts the pages of a single column chunk, tracking the page ordinal used
+/// in the AAD of each page.
+pub(crate) struct PageEncryptor {
+    encryptor: Arc<GcmBlockEncryptor>,
+    file_aad: Vec<u8>,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+    page_ordinal: usize,
+}
+
+impl PageEncryptor {
+    pub(crate) fn new(
+        file_encryptor: &FileEncryptor,
+        encryptor: Arc<GcmBlockEncryptor>,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> Self {
+        Self {
+            encryptor,
+            file_aad: file_encryptor.file_aad().to_vec(),
+            row_group_ordinal,
+            column_ordinal,
+            page_ordinal: 0,
+        }
+    }
+
+    fn aad(&self, module_type: ModuleType) -> Result<Vec<u8>> {
+        create_module_aad(
+            &self.file_aad,
+            module_type,
+            self.row_group_ordinal,
+            self.column_ordinal,
+            Some(self.page_ordinal),
+        )
+    }
+
+    /// Encrypts the (compressed) body of a page.
+    pub(crate) fn encrypt_page(
+        &self,
+        page_type: parquet::PageType,
+        body: &[u8],
+    ) -> Result<Vec<u8>> {
+        let module_type = match page_type {
+            parquet::PageType::DictionaryPage => ModuleType::DictionaryPage,
+            _ => ModuleType::DataPage,
+        };
+        self.encryptor.encrypt(body, &self.aad(module_type)?)
+    }
+
+    /// Encrypts the thrift encoded header of a page. Must be called after
+    /// [`Self::encrypt_page`] for the same page, as this advances the ordinal
+    /// of data pages.
+    pub(crate) fn encrypt_page_header(
+        &mut self,
+        page_type: parquet::PageType,
+        header: &[u8],
+    ) -> Result<Vec<u8>> {
+        let module_type = match page_type {
+            parquet::PageType::DictionaryPage => ModuleType::DictionaryPageHeader,
+            _ => ModuleType::DataPageHeader,
+        };
+        let encrypted = self.encryptor.encrypt(header, &self.aad(module_type)?)?;
+        if module_type == ModuleType::DataPageHeader {
+            self.page_ordinal += 1;
+        }
+        Ok(encrypted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encryption_properties() {
+        let props = FileEncryptionProperties::builder(vec![0; 16])
+            .set_footer_key_metadata(b"kf".to_vec())
+            .set_column_key(ColumnPath::from("a"), vec![1; 32])
+            .set_aad_prefix(b"file".to_vec())
+            .set_store_aad_prefix(false)
+            .build()
+            .unwrap();
+
+        assert_eq!(props.footer_key_metadata(), Some(&b"kf"[..]));
+        assert_eq!(props.aad_prefix(), Some(&b"file"[..]));
+        assert!(!props.store_aad_prefix());
+        assert!(!props.encrypt_all_columns());
+        assert!(props.is_column_encrypted(&ColumnPath::from("a")));
+        assert!(!props.is_column_encrypted(&ColumnPath::from("b")));
+        assert!(!format!("{:?}", props).contains("[1, 1"));
+
+        let encryptor = FileEncryptor::try_new(props).unwrap();
+        assert_eq!(encryptor.file_aad().len(), 4 + AAD_FILE_UNIQUE_LEN);
+        assert!(encryptor.column_encryptor(&ColumnPath::from("b")).is_none());
+        match encryptor.file_crypto_metadata().encryption_algorithm {
+            EncryptionAlgorithm::AESGCMV1(algorithm) => {
+                assert_eq!(algorithm.aad_prefix, None);
+                assert_eq!(algorithm.supply_aad_prefix, Some(true));
+            }
+            _ => panic!("unexpected encryption algorithm"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_key_length() {
+        let err = FileEncryptionProperties::builder(vec![0; 16])
+            .set_column_key(ColumnPath::from("a"), vec![1; 10])
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid length 10 of key for a, expected 16 or 32 bytes"
+        );
+        assert!(FileEncryptionProperties::builder(vec![0; 8])
+            .build()
+            .is_err());
+    }
+}
@@ -0,0 +1,39 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parquet modular encryption, as described in the
+//! [Parquet specification](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+//!
+//! Files are encrypted with the `AES_GCM_V1` algorithm in encrypted footer mode: the
+//! file metadata is encrypted with a footer key, and the pages and metadata of each
+//! column chunk are encrypted with either the footer key or a key of that column.
+//!
+//! Encryption is configured with [`FileEncryptionProperties`] in the
+//! [`WriterProperties`](crate::file::properties::WriterProperties), and decryption
+//! with [`FileDecryptionProperties`], whose keys are either provided explicitly or
+//! obtained from a key management service through a [`KeyRetriever`].
+//!
+//! The page index and bloom filters are not yet supported in encrypted files.
+//!
+//! [`FileEncryptionProperties`]: encrypt::FileEncryptionProperties
+//! [`FileDecryptionProperties`]: decrypt::FileDecryptionProperties
+//! [`KeyRetriever`]: decrypt::KeyRetriever
+
+pub(crate) mod ciphers;
+pub mod decrypt;
+pub mod encrypt;
+pub(crate) mod modules;
@@ -0,0 +1,129 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Module types and the additional authenticated data (AAD) of each encrypted module.
+
+use std::convert::TryFrom;
+
+use crate::errors::{ParquetError, Result};
+
+/// The type of an encrypted parquet module, as defined by the specification.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+    ColumnIndex = 6,
+    OffsetIndex = 7,
+    BloomFilterHeader = 8,
+    BloomFilterBitset = 9,
+}
+
+/// Returns the AAD of the file footer.
+pub(crate) fn create_footer_aad(file_aad: &[u8]) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 1);
+    aad.extend_from_slice(file_aad);
+    aad.push(ModuleType::Footer as u8);
+    aad
+}
+
+/// Returns the AAD of a module of a column chunk.
+///
+/// `page_ordinal` is only used for data pages and data page headers, the only
+/// modules of which a column chunk can contain more than one.
+///
+/// Returns an error if an ordinal does not fit in the 16 bits the specification
+/// allows for it.
+pub(crate) fn create_module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+    page_ordinal: Option<usize>,
+) -> Result<Vec<u8>> {
+    if module_type == ModuleType::Footer {
+        return Ok(create_footer_aad(file_aad));
+    }
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module_type as u8);
+    aad.extend_from_slice(&ordinal_to_le_bytes("row group", row_group_ordinal)?);
+    aad.extend_from_slice(&ordinal_to_le_bytes("column", column_ordinal)?);
+    if let ModuleType::DataPage | ModuleType::DataPageHeader = module_type {
+        let page_ordinal = page_ordinal.unwrap_or(0);
+        aad.extend_from_slice(&ordinal_to_le_bytes("page", page_ordinal)?);
+    }
+    Ok(aad)
+}
+
+fn ordinal_to_le_bytes(name: &str, ordinal: usize) -> Result<[u8; 2]> {
+    let ordinal = i16::try_from(ordinal).map_err(|_| {
+        general_err!(
+            "Encrypted {} ordinal {} exceeds the maximum of {}",
+            name,
+            ordinal,
+            i16::MAX
+        )
+    })?;
+    Ok(ordinal.to_le_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_aad() {
+        assert_eq!(create_footer_aad(&[9, 9]), vec![9, 9, 0]);
+        assert_eq!(
+            create_module_aad(&[9], ModuleType::ColumnMetaData, 1, 2, None).unwrap(),
+            vec![9, 1, 1, 0, 2, 0]
+        );
+        assert_eq!(
+            create_module_aad(&[9], ModuleType::DataPage, 1, 2, Some(3)).unwrap(),
+            vec![9, 2, 1, 0, 2, 0, 3, 0]
+        );
+        assert_eq!(
+            create_module_aad(&[9], ModuleType::DictionaryPageHeader, 0, 1, Some(3))
+                .unwrap(),
+            vec![9, 5, 0, 0, 1, 0]
+        );
+    }
+
+    #[test]
+    fn test_module_aad_ordinal_overflow() {
+        let err = create_module_aad(&[9], ModuleType::ColumnMetaData, 40_000, 0, None)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Encrypted row group ordinal 40000 exceeds the maximum of 32767"
+        );
+        let max = i16::MAX as usize;
+        assert!(create_module_aad(&[9], ModuleType::DataPage, 0, 0, Some(max)).is_ok());
+        assert!(
+            create_module_aad(&[9], ModuleType::DataPage, 0, 0, Some(max + 1)).is_err()
+        );
+        // the page ordinal is not part of the AAD of other modules
+        assert!(
+            create_module_aad(&[9], ModuleType::DictionaryPage, 0, 0, Some(max + 1))
+                .is_ok()
+        );
+    }
+}
@@ -22,15 +22,19 @@ use std::{
 };
 
 use byteorder::{ByteOrder, LittleEndian};
+#[cfg(feature = "encryption")]
+use parquet_format::FileCryptoMetaData;
 use parquet_format::{ColumnOrder as TColumnOrder, FileMetaData as TFileMetaData};
-use thrift::protocol::TCompactInputProtocol;
+use thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol};
 
 use crate::basic::ColumnOrder;
 
+#[cfg(feature = "encryption")]
+use crate::encryption::decrypt::{FileDecryptionProperties, FileDecryptor};
 use crate::errors::{ParquetError, Result};
 use crate::file::{
     metadata::*, reader::ChunkReader, DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE,
-    PARQUET_MAGIC,
+    PARQUET_MAGIC, PARQUET_MAGIC_ENCR_FOOTER,
 };
 
 use crate::schema::types::{self, SchemaDescriptor};
@@ -44,6 +48,80 @@ use crate::schema::types::{self, SchemaDescriptor};
 /// The reader first reads DEFAULT_FOOTER_SIZE bytes from the end of the file.
 /// If it is not enough according to the length indicated in the footer, it reads more bytes.
 pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaData> {
+    let (metadata_read, encrypted_footer) = read_metadata(chunk_reader)?;
+    if encrypted_footer {
+        return Err(encrypted_footer_err());
+    }
+    decode_metadata(metadata_read)
+}
+
+/// Parses the metadata of a Parquet file from `bytes`, which must end with the footer
+/// of the file and contain the whole metadata that precedes it, e.g. the last bytes
+/// of a file fetched from an object store.
+///
+/// Returns an `EOF` error if `bytes` does not contain the whole metadata, in which
+/// case more bytes should be read from the end of the file.
+pub fn parse_metadata_from_bytes(bytes: &[u8]) -> Result<ParquetMetaData> {
+    if bytes.len() < FOOTER_SIZE {
+        return Err(general_err!(
+            "Invalid Parquet file. Size is smaller than footer"
+        ));
+    }
+    let metadata_end = bytes.len() - FOOTER_SIZE;
+    let metadata_len = decode_footer(&bytes[metadata_end..])?;
+    if metadata_len > metadata_end {
+        return Err(eof_err!(
+            "Expected {} bytes of metadata, but only {} bytes are available",
+            metadata_len,
+            metadata_end
+        ));
+    }
+    decode_metadata(&bytes[metadata_end - metadata_len..metadata_end])
+}
+
+/// Parses the metadata of a Parquet file like [`parse_metadata`], decrypting it with
+/// `decryption_properties` if the file has an encrypted footer.
+///
+/// The returned metadata holds the state needed to decrypt the column chunks of the
+/// file, so should be used to read them.
+#[cfg(feature = "encryption")]
+pub fn parse_metadata_with_decryption<R: ChunkReader>(
+    chunk_reader: &R,
+    decryption_properties: &FileDecryptionProperties,
+) -> Result<ParquetMetaData> {
+    let (mut metadata_read, encrypted_footer) = read_metadata(chunk_reader)?;
+    if !encrypted_footer {
+        return decode_metadata(metadata_read);
+    }
+
+    let mut buf = Vec::new();
+    metadata_read.read_to_end(&mut buf)?;
+    let mut cursor = Cursor::new(buf.as_slice());
+    let crypto_metadata = {
+        let mut prot = TCompactInputProtocol::new(&mut cursor);
+        FileCryptoMetaData::read_from_in_protocol(&mut prot).map_err(|e| {
+            ParquetError::General(format!("Could not parse crypto metadata: {}", e))
+        })?
+    };
+    let mut file_decryptor =
+        FileDecryptor::try_new(decryption_properties, &crypto_metadata)?;
+    let decrypted = file_decryptor.decrypt_footer(&buf[cursor.position() as usize..])?;
+
+    let mut prot = TCompactInputProtocol::new(decrypted.as_slice());
+    let mut t_file_metadata: TFileMetaData =
+        TFileMetaData::read_from_in_protocol(&mut prot).map_err(|e| {
+            ParquetError::General(format!("Could not parse metadata: {}", e))
+        })?;
+    file_decryptor
+        .decrypt_row_groups(decryption_properties, &mut t_file_metadata.row_groups)?;
+
+    Ok(metadata_from_thrift(t_file_metadata)?
+        .with_file_decryptor(Arc::new(file_decryptor)))
+}
+
+/// Reads the footer of a Parquet file, returning a reader of the metadata that
+/// precedes it and whether the footer is encrypted.
+fn read_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<(Box<dyn Read>, bool)> {
     // check file is large enough to hold footer
     let file_size = chunk_reader.len();
     if file_size < (FOOTER_SIZE as u64) {
@@ -54,27 +132,13 @@ pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaDat
 
     // read and cache up to DEFAULT_FOOTER_READ_SIZE bytes from the end and process the footer
     let default_end_len = min(DEFAULT_FOOTER_READ_SIZE, chunk_reader.len() as usize);
-    let mut default_end_reader = chunk_reader
-        .get_read(chunk_reader.len() - default_end_len as u64, default_end_len)?;
-    let mut default_len_end_buf = vec![0; default_end_len];
-    default_end_reader.read_exact(&mut default_len_end_buf)?;
+    let default_len_end_buf =
+        chunk_reader.get_bytes(file_size - default_end_len as u64, default_end_len)?;
 
-    // check this is indeed a parquet file
-    if default_len_end_buf[default_end_len - 4..] != PARQUET_MAGIC {
-        return Err(general_err!("Invalid Parquet file. Corrupt footer"));
-    }
-
-    // get the metadata length from the footer
-    let metadata_len = LittleEndian::read_i32(
-        &default_len_end_buf[default_end_len - 8..default_end_len - 4],
-    ) as i64;
-    if metadata_len < 0 {
-        return Err(general_err!(
-            "Invalid Parquet file. Metadata length is less than zero ({})",
-            metadata_len
-        ));
-    }
-    let footer_metadata_len = FOOTER_SIZE + metadata_len as usize;
+    // check this is indeed a parquet file and get the metadata length from the footer
+    let (metadata_len, encrypted_footer) =
+        decode_footer_magic(&default_len_end_buf[default_end_len - FOOTER_SIZE..])?;
+    let footer_metadata_len = FOOTER_SIZE + metadata_len;
 
     // build up the reader covering the entire metadata
     let mut default_end_cursor = Cursor::new(default_len_end_buf);
@@ -87,20 +151,93 @@ pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaDat
     } else if footer_metadata_len < DEFAULT_FOOTER_READ_SIZE {
         // the whole metadata is in the bytes we already read
         default_end_cursor.seek(SeekFrom::End(-(footer_metadata_len as i64)))?;
-        metadata_read = Box::new(default_end_cursor);
+        metadata_read = Box::new(default_end_cursor.take(metadata_len as u64));
     } else {
         // the end of file read by default is not long enough, read missing bytes
-        let complementary_end_read = chunk_reader.get_read(
+        let complementary_end_buf = chunk_reader.get_bytes(
             file_size - footer_metadata_len as u64,
-            FOOTER_SIZE + metadata_len as usize - default_end_len,
+            FOOTER_SIZE + metadata_len - default_end_len,
         )?;
-        metadata_read = Box::new(complementary_end_read.chain(default_end_cursor));
+        metadata_read = Box::new(
+            Cursor::new(complementary_end_buf)
+                .chain(default_end_cursor)
+                .take(metadata_len as u64),
+        );
+    }
+
+    Ok((metadata_read, encrypted_footer))
+}
+
+/// Decodes the footer, which is the last [`FOOTER_SIZE`] bytes of a Parquet file,
+/// returning the length of the metadata that precedes it.
+///
+/// Returns an error if the footer does not end with the Parquet magic number.
+pub fn decode_footer(footer: &[u8]) -> Result<usize> {
+    match decode_footer_magic(footer)? {
+        (_, true) => Err(encrypted_footer_err()),
+        (metadata_len, false) => Ok(metadata_len),
+    }
+}
+
+/// Decodes the footer like [`decode_footer`], also returning whether it ends with
+/// the magic number of files with an encrypted footer.
+fn decode_footer_magic(footer: &[u8]) -> Result<(usize, bool)> {
+    if footer.len() != FOOTER_SIZE {
+        return Err(general_err!("Invalid Parquet file. Corrupt footer"));
+    }
+    let encrypted_footer = if footer[4..] == PARQUET_MAGIC {
+        false
+    } else if footer[4..] == PARQUET_MAGIC_ENCR_FOOTER {
+        true
+    } else {
+        return Err(general_err!("Invalid Parquet file. Corrupt footer"));
+    };
+
+    let metadata_len = LittleEndian::read_i32(&footer[..4]);
+    if metadata_len < 0 {
+        return Err(general_err!(
+            "Invalid Parquet file. Metadata length is less than zero ({})",
+            metadata_len
+        ));
     }
+    Ok((metadata_len as usize, encrypted_footer))
+}
+
+fn encrypted_footer_err() -> ParquetError {
+    general_err!(
+        "Parquet file has an encrypted footer, which requires decryption properties"
+    )
+}
 
+/// Decodes [`ParquetMetaData`] from the Thrift encoded file metadata, which can be
+/// read separately from the rest of the file, e.g. using [`decode_footer`] to locate it.
+pub fn decode_metadata<R: Read>(metadata_read: R) -> Result<ParquetMetaData> {
     // TODO: row group filtering
     let mut prot = TCompactInputProtocol::new(metadata_read);
     let t_file_metadata: TFileMetaData = TFileMetaData::read_from_in_protocol(&mut prot)
         .map_err(|e| ParquetError::General(format!("Could not parse metadata: {}", e)))?;
+    metadata_from_thrift(t_file_metadata)
+}
+
+/// Encodes `metadata` as Thrift file metadata, which can be decoded with
+/// [`decode_metadata`].
+///
+/// This allows to cache the metadata of a file, or to send it to another process,
+/// and to read the file later with
+/// [`SerializedFileReader::new_with_metadata`](crate::file::serialized_reader::SerializedFileReader::new_with_metadata)
+/// without parsing its footer again. The page index is not encoded.
+pub fn encode_metadata(metadata: &ParquetMetaData) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    {
+        let mut protocol = TCompactOutputProtocol::new(&mut buf);
+        metadata.to_thrift()?.write_to_out_protocol(&mut protocol)?;
+        protocol.flush()?;
+    }
+    Ok(buf)
+}
+
+/// Converts the Thrift file metadata to [`ParquetMetaData`].
+fn metadata_from_thrift(t_file_metadata: TFileMetaData) -> Result<ParquetMetaData> {
     let schema = types::from_thrift(&t_file_metadata.schema)?;
     let schema_descr = Arc::new(SchemaDescriptor::new(schema));
     let mut row_groups = Vec::new();
@@ -160,7 +297,7 @@ mod tests {
     use crate::basic::SortOrder;
     use crate::basic::Type;
     use crate::schema::types::Type as SchemaType;
-    use crate::util::test_common::get_temp_file;
+    use crate::util::test_common::{get_temp_file, get_test_file};
     use parquet_format::TypeDefinedOrder;
 
     #[test]
@@ -185,6 +322,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_metadata_encrypted_footer() {
+        let test_file = get_temp_file(
+            "encrypted-footer.parquet",
+            &[0, 0, 0, 0, b'P', b'A', b'R', b'E'],
+        );
+        let reader_result = parse_metadata(&test_file);
+        assert_eq!(
+            reader_result.err().unwrap(),
+            general_err!(
+                "Parquet file has an encrypted footer, which requires decryption properties"
+            )
+        );
+    }
+
     #[test]
     fn test_parse_metadata_invalid_length() {
         let test_file =
@@ -211,6 +363,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_encode_and_parse_metadata_from_bytes() {
+        let mut bytes = Vec::new();
+        get_test_file("alltypes_plain.parquet")
+            .read_to_end(&mut bytes)
+            .unwrap();
+        let metadata = parse_metadata(&get_test_file("alltypes_plain.parquet")).unwrap();
+
+        // the metadata can be parsed from the end of the file
+        let metadata_len = decode_footer(&bytes[bytes.len() - FOOTER_SIZE..]).unwrap();
+        let tail = &bytes[bytes.len() - FOOTER_SIZE - metadata_len..];
+        for bytes in &[&bytes[..], tail] {
+            let parsed = parse_metadata_from_bytes(bytes).unwrap();
+            assert_eq!(parsed.to_thrift().unwrap(), metadata.to_thrift().unwrap());
+        }
+        let res = parse_metadata_from_bytes(&tail[1..]);
+        assert_eq!(
+            res.unwrap_err(),
+            eof_err!(
+                "Expected {} bytes of metadata, but only {} bytes are available",
+                metadata_len,
+                metadata_len - 1
+            )
+        );
+
+        // the encoded metadata decodes to the same metadata
+        let encoded = encode_metadata(&metadata).unwrap();
+        let decoded = decode_metadata(encoded.as_slice()).unwrap();
+        assert_eq!(decoded.to_thrift().unwrap(), metadata.to_thrift().unwrap());
+        assert_eq!(decoded.num_row_groups(), metadata.num_row_groups());
+        assert_eq!(
+            decoded.file_metadata().num_rows(),
+            metadata.file_metadata().num_rows()
+        );
+        assert_eq!(
+            decoded.file_metadata().schema(),
+            metadata.file_metadata().schema()
+        );
+        assert_eq!(
+            decoded.file_metadata().column_orders(),
+            metadata.file_metadata().column_orders()
+        );
+    }
+
     #[test]
     fn test_metadata_column_orders_parse() {
         // Define simple schema, we do not need to provide logical types.
@@ -33,23 +33,41 @@
 //! [`ColumnChunkMetaData`](struct.ColumnChunkMetaData.html) has information about column
 //! chunk (primitive leaf column), including encoding/compression, number of values, etc.
 
-use std::sync::Arc;
+use std::{ops::Range, sync::Arc};
 
-use parquet_format::{ColumnChunk, ColumnMetaData, RowGroup};
+use parquet_format::{
+    BoundaryOrder, ColumnChunk, ColumnIndex, ColumnMetaData, ColumnOrder as TColumnOrder,
+    FileMetaData as TFileMetaData, OffsetIndex, PageLocation, RowGroup, TypeDefinedOrder,
+};
 
 use crate::basic::{ColumnOrder, Compression, Encoding, Type};
+use crate::bloom_filter::Sbbf;
+#[cfg(feature = "encryption")]
+use crate::encryption::decrypt::FileDecryptor;
 use crate::errors::{ParquetError, Result};
+use crate::file::page_index::index::Index;
+use crate::file::reader::ChunkReader;
 use crate::file::statistics::{self, Statistics};
 use crate::schema::types::{
-    ColumnDescPtr, ColumnDescriptor, ColumnPath, SchemaDescPtr, SchemaDescriptor,
+    self, ColumnDescPtr, ColumnDescriptor, ColumnPath, SchemaDescPtr, SchemaDescriptor,
     Type as SchemaType,
 };
 
+/// The [`Index`] of each column chunk, indexed by row group and then column.
+pub type ParquetColumnIndex = Vec<Vec<Index>>;
+
+/// The [`PageLocation`]s of each column chunk, indexed by row group and then column.
+pub type ParquetOffsetIndex = Vec<Vec<Vec<PageLocation>>>;
+
 /// Global Parquet metadata.
 #[derive(Debug, Clone)]
 pub struct ParquetMetaData {
     file_metadata: FileMetaData,
     row_groups: Vec<RowGroupMetaData>,
+    page_indexes: Option<ParquetColumnIndex>,
+    offset_indexes: Option<ParquetOffsetIndex>,
+    #[cfg(feature = "encryption")]
+    file_decryptor: Option<Arc<FileDecryptor>>,
 }
 
 impl ParquetMetaData {
@@ -59,6 +77,28 @@ impl ParquetMetaData {
         ParquetMetaData {
             file_metadata,
             row_groups,
+            page_indexes: None,
+            offset_indexes: None,
+            #[cfg(feature = "encryption")]
+            file_decryptor: None,
+        }
+    }
+
+    /// Creates Parquet metadata that also includes the page index of each column
+    /// chunk, see [`crate::file::page_index`].
+    pub fn new_with_page_index(
+        file_metadata: FileMetaData,
+        row_groups: Vec<RowGroupMetaData>,
+        page_indexes: Option<ParquetColumnIndex>,
+        offset_indexes: Option<ParquetOffsetIndex>,
+    ) -> Self {
+        ParquetMetaData {
+            file_metadata,
+            row_groups,
+            page_indexes,
+            offset_indexes,
+            #[cfg(feature = "encryption")]
+            file_decryptor: None,
         }
     }
 
@@ -82,6 +122,80 @@ impl ParquetMetaData {
     pub fn row_groups(&self) -> &[RowGroupMetaData] {
         &self.row_groups
     }
+
+    /// Returns the column index of each column chunk, if the page index was read.
+    pub fn page_indexes(&self) -> Option<&ParquetColumnIndex> {
+        self.page_indexes.as_ref()
+    }
+
+    /// Returns the page locations of each column chunk, if the page index was read.
+    pub fn offset_indexes(&self) -> Option<&ParquetOffsetIndex> {
+        self.offset_indexes.as_ref()
+    }
+
+    /// Returns a copy of this metadata that only contains the row groups at
+    /// `row_groups`, in the given order, along with their page index if present.
+    pub fn select_row_groups(&self, row_groups: &[usize]) -> Self {
+        fn select<T: Clone>(values: &[T], indices: &[usize]) -> Vec<T> {
+            indices.iter().map(|i| values[*i].clone()).collect()
+        }
+
+        ParquetMetaData {
+            file_metadata: self.file_metadata.clone(),
+            row_groups: select(&self.row_groups, row_groups),
+            page_indexes: self.page_indexes.as_ref().map(|v| select(v, row_groups)),
+            offset_indexes: self.offset_indexes.as_ref().map(|v| select(v, row_groups)),
+            #[cfg(feature = "encryption")]
+            file_decryptor: self.file_decryptor.clone(),
+        }
+    }
+
+    /// Method to convert to Thrift file metadata, which can be serialized and later
+    /// decoded with [`decode_metadata`](crate::file::footer::decode_metadata).
+    ///
+    /// The page index is not part of the file metadata, and is not converted.
+    pub fn to_thrift(&self) -> Result<TFileMetaData> {
+        let file_metadata = &self.file_metadata;
+        // Thrift only has type defined column orders, which are set for all columns
+        let column_orders = file_metadata.column_orders().and_then(|orders| {
+            orders
+                .iter()
+                .map(|order| match order {
+                    ColumnOrder::TYPE_DEFINED_ORDER(_) => {
+                        Some(TColumnOrder::TYPEORDER(TypeDefinedOrder::new()))
+                    }
+                    ColumnOrder::UNDEFINED => None,
+                })
+                .collect()
+        });
+        Ok(TFileMetaData {
+            version: file_metadata.version(),
+            schema: types::to_thrift(file_metadata.schema())?,
+            num_rows: file_metadata.num_rows(),
+            row_groups: self.row_groups.iter().map(|rg| rg.to_thrift()).collect(),
+            key_value_metadata: file_metadata.key_value_metadata().clone(),
+            created_by: file_metadata.created_by().clone(),
+            column_orders,
+            encryption_algorithm: None,
+            footer_signing_key_metadata: None,
+        })
+    }
+
+    /// Sets the decryptor used to read the column chunks of an encrypted file.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn with_file_decryptor(
+        mut self,
+        file_decryptor: Arc<FileDecryptor>,
+    ) -> Self {
+        self.file_decryptor = Some(file_decryptor);
+        self
+    }
+
+    /// Returns the decryptor of an encrypted file.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn file_decryptor(&self) -> Option<&Arc<FileDecryptor>> {
+        self.file_decryptor.as_ref()
+    }
 }
 
 pub type KeyValue = parquet_format::KeyValue;
@@ -193,6 +307,7 @@ pub struct RowGroupMetaData {
     num_rows: i64,
     total_byte_size: i64,
     schema_descr: SchemaDescPtr,
+    ordinal: Option<i16>,
 }
 
 impl RowGroupMetaData {
@@ -231,6 +346,12 @@ impl RowGroupMetaData {
         self.columns.iter().map(|c| c.total_compressed_size).sum()
     }
 
+    /// Returns the position of this row group in the file, if it was recorded
+    /// by the writer.
+    pub fn ordinal(&self) -> Option<i16> {
+        self.ordinal
+    }
+
     /// Returns reference to a schema descriptor.
     pub fn schema_descr(&self) -> &SchemaDescriptor {
         self.schema_descr.as_ref()
@@ -249,6 +370,7 @@ impl RowGroupMetaData {
         assert_eq!(schema_descr.num_columns(), rg.columns.len());
         let total_byte_size = rg.total_byte_size;
         let num_rows = rg.num_rows;
+        let ordinal = rg.ordinal;
         let mut columns = vec![];
         for (c, d) in rg.columns.drain(0..).zip(schema_descr.columns()) {
             let cc = ColumnChunkMetaData::from_thrift(d.clone(), c)?;
@@ -259,6 +381,7 @@ impl RowGroupMetaData {
             num_rows,
             total_byte_size,
             schema_descr,
+            ordinal,
         })
     }
 
@@ -269,6 +392,9 @@ impl RowGroupMetaData {
             total_byte_size: self.total_byte_size,
             num_rows: self.num_rows,
             sorting_columns: None,
+            file_offset: None,
+            total_compressed_size: None,
+            ordinal: self.ordinal,
         }
     }
 }
@@ -279,6 +405,7 @@ pub struct RowGroupMetaDataBuilder {
     schema_descr: SchemaDescPtr,
     num_rows: i64,
     total_byte_size: i64,
+    ordinal: Option<i16>,
 }
 
 impl RowGroupMetaDataBuilder {
@@ -289,6 +416,7 @@ impl RowGroupMetaDataBuilder {
             schema_descr,
             num_rows: 0,
             total_byte_size: 0,
+            ordinal: None,
         }
     }
 
@@ -310,6 +438,12 @@ impl RowGroupMetaDataBuilder {
         self
     }
 
+    /// Sets the position of this row group in the file.
+    pub fn set_ordinal(mut self, value: i16) -> Self {
+        self.ordinal = Some(value);
+        self
+    }
+
     /// Builds row group metadata.
     pub fn build(self) -> Result<RowGroupMetaData> {
         if self.schema_descr.num_columns() != self.columns.len() {
@@ -325,6 +459,7 @@ impl RowGroupMetaDataBuilder {
             num_rows: self.num_rows,
             total_byte_size: self.total_byte_size,
             schema_descr: self.schema_descr,
+            ordinal: self.ordinal,
         })
     }
 }
@@ -346,6 +481,11 @@ pub struct ColumnChunkMetaData {
     index_page_offset: Option<i64>,
     dictionary_page_offset: Option<i64>,
     statistics: Option<Statistics>,
+    bloom_filter_offset: Option<i64>,
+    offset_index_offset: Option<i64>,
+    offset_index_length: Option<i32>,
+    column_index_offset: Option<i64>,
+    column_index_length: Option<i32>,
 }
 
 /// Represents common operations for a column chunk.
@@ -459,6 +599,65 @@ impl ColumnChunkMetaData {
         self.statistics.as_ref()
     }
 
+    /// Returns the offset for the bloom filter of this column chunk, if any.
+    pub fn bloom_filter_offset(&self) -> Option<i64> {
+        self.bloom_filter_offset
+    }
+
+    /// Reads the bloom filter of this column chunk from `reader`, which must read
+    /// from the file containing this column chunk.
+    ///
+    /// Returns `None` if no bloom filter was written for this column chunk.
+    pub fn read_bloom_filter<R: ChunkReader>(&self, reader: &R) -> Result<Option<Sbbf>> {
+        Sbbf::read_from_column_chunk(self, reader)
+    }
+
+    /// Returns the offset for the offset index of this column chunk, if any.
+    pub fn offset_index_offset(&self) -> Option<i64> {
+        self.offset_index_offset
+    }
+
+    /// Returns the length in bytes of the offset index of this column chunk, if any.
+    pub fn offset_index_length(&self) -> Option<i32> {
+        self.offset_index_length
+    }
+
+    /// Returns the byte range of the offset index of this column chunk within the file,
+    /// if any.
+    pub fn offset_index_range(&self) -> Option<Range<u64>> {
+        index_range(self.offset_index_offset, self.offset_index_length)
+    }
+
+    /// Returns the offset for the column index of this column chunk, if any.
+    pub fn column_index_offset(&self) -> Option<i64> {
+        self.column_index_offset
+    }
+
+    /// Returns the length in bytes of the column index of this column chunk, if any.
+    pub fn column_index_length(&self) -> Option<i32> {
+        self.column_index_length
+    }
+
+    /// Returns the byte range of the column index of this column chunk within the file,
+    /// if any.
+    pub fn column_index_range(&self) -> Option<Range<u64>> {
+        index_range(self.column_index_offset, self.column_index_length)
+    }
+
+    /// Returns the byte range of the dictionary page of this column chunk within the
+    /// file, including its header, if any.
+    ///
+    /// The dictionary page is the first page of the column chunk, so it ends where the
+    /// first data page starts.
+    pub fn dictionary_page_range(&self) -> Option<Range<u64>> {
+        match self.dictionary_page_offset {
+            Some(offset) if offset >= 0 && offset <= self.data_page_offset => {
+                Some(offset as u64..self.data_page_offset as u64)
+            }
+            _ => None,
+        }
+    }
+
     /// Method to convert from Thrift.
     pub fn from_thrift(column_descr: ColumnDescPtr, cc: ColumnChunk) -> Result<Self> {
         if cc.meta_data.is_none() {
@@ -482,6 +681,11 @@ impl ColumnChunkMetaData {
         let index_page_offset = col_metadata.index_page_offset;
         let dictionary_page_offset = col_metadata.dictionary_page_offset;
         let statistics = statistics::from_thrift(column_type, col_metadata.statistics);
+        let bloom_filter_offset = col_metadata.bloom_filter_offset;
+        let offset_index_offset = cc.offset_index_offset;
+        let offset_index_length = cc.offset_index_length;
+        let column_index_offset = cc.column_index_offset;
+        let column_index_length = cc.column_index_length;
         let result = ColumnChunkMetaData {
             column_type,
             column_path,
@@ -497,6 +701,11 @@ impl ColumnChunkMetaData {
             index_page_offset,
             dictionary_page_offset,
             statistics,
+            bloom_filter_offset,
+            offset_index_offset,
+            offset_index_length,
+            column_index_offset,
+            column_index_length,
         };
         Ok(result)
     }
@@ -517,20 +726,34 @@ impl ColumnChunkMetaData {
             dictionary_page_offset: self.dictionary_page_offset,
             statistics: statistics::to_thrift(self.statistics.as_ref()),
             encoding_stats: None,
+            bloom_filter_offset: self.bloom_filter_offset,
         };
 
         ColumnChunk {
             file_path: self.file_path().cloned(),
             file_offset: self.file_offset,
             meta_data: Some(column_metadata),
-            offset_index_offset: None,
-            offset_index_length: None,
-            column_index_offset: None,
-            column_index_length: None,
+            offset_index_offset: self.offset_index_offset,
+            offset_index_length: self.offset_index_length,
+            column_index_offset: self.column_index_offset,
+            column_index_length: self.column_index_length,
+            crypto_metadata: None,
+            encrypted_column_metadata: None,
         }
     }
 }
 
+/// Returns the byte range of a page index at `offset` of `length` bytes, if both are set
+/// and valid.
+fn index_range(offset: Option<i64>, length: Option<i32>) -> Option<Range<u64>> {
+    match (offset, length) {
+        (Some(offset), Some(length)) if offset >= 0 && length >= 0 => {
+            Some(offset as u64..offset as u64 + length as u64)
+        }
+        _ => None,
+    }
+}
+
 /// Builder for column chunk metadata.
 pub struct ColumnChunkMetaDataBuilder {
     column_descr: ColumnDescPtr,
@@ -545,6 +768,11 @@ pub struct ColumnChunkMetaDataBuilder {
     index_page_offset: Option<i64>,
     dictionary_page_offset: Option<i64>,
     statistics: Option<Statistics>,
+    bloom_filter_offset: Option<i64>,
+    offset_index_offset: Option<i64>,
+    offset_index_length: Option<i32>,
+    column_index_offset: Option<i64>,
+    column_index_length: Option<i32>,
 }
 
 impl ColumnChunkMetaDataBuilder {
@@ -563,6 +791,11 @@ impl ColumnChunkMetaDataBuilder {
             index_page_offset: None,
             dictionary_page_offset: None,
             statistics: None,
+            bloom_filter_offset: None,
+            offset_index_offset: None,
+            offset_index_length: None,
+            column_index_offset: None,
+            column_index_length: None,
         }
     }
 
@@ -620,6 +853,12 @@ impl ColumnChunkMetaDataBuilder {
         self
     }
 
+    /// Sets optional bloom filter offset in bytes.
+    pub fn set_bloom_filter_offset(mut self, value: Option<i64>) -> Self {
+        self.bloom_filter_offset = value;
+        self
+    }
+
     /// Sets optional index page offset in bytes.
     pub fn set_index_page_offset(mut self, value: Option<i64>) -> Self {
         self.index_page_offset = value;
@@ -632,6 +871,30 @@ impl ColumnChunkMetaDataBuilder {
         self
     }
 
+    /// Sets optional offset index offset in bytes.
+    pub fn set_offset_index_offset(mut self, value: Option<i64>) -> Self {
+        self.offset_index_offset = value;
+        self
+    }
+
+    /// Sets optional offset index length in bytes.
+    pub fn set_offset_index_length(mut self, value: Option<i32>) -> Self {
+        self.offset_index_length = value;
+        self
+    }
+
+    /// Sets optional column index offset in bytes.
+    pub fn set_column_index_offset(mut self, value: Option<i64>) -> Self {
+        self.column_index_offset = value;
+        self
+    }
+
+    /// Sets optional column index length in bytes.
+    pub fn set_column_index_length(mut self, value: Option<i32>) -> Self {
+        self.column_index_length = value;
+        self
+    }
+
     /// Builds column chunk metadata.
     pub fn build(self) -> Result<ColumnChunkMetaData> {
         Ok(ColumnChunkMetaData {
@@ -649,10 +912,144 @@ impl ColumnChunkMetaDataBuilder {
             index_page_offset: self.index_page_offset,
             dictionary_page_offset: self.dictionary_page_offset,
             statistics: self.statistics,
+            bloom_filter_offset: self.bloom_filter_offset,
+            offset_index_offset: self.offset_index_offset,
+            offset_index_length: self.offset_index_length,
+            column_index_offset: self.column_index_offset,
+            column_index_length: self.column_index_length,
         })
     }
 }
 
+/// Builder for the column index of a column chunk.
+///
+/// Collects the min/max values and null count of every data page. If the statistics
+/// of any page are unavailable, the whole index is marked invalid and should not be
+/// written.
+pub struct ColumnIndexBuilder {
+    null_pages: Vec<bool>,
+    min_values: Vec<Vec<u8>>,
+    max_values: Vec<Vec<u8>>,
+    null_counts: Vec<i64>,
+    boundary_order: BoundaryOrder,
+    valid: bool,
+}
+
+impl Default for ColumnIndexBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ColumnIndexBuilder {
+    /// Creates new empty column index builder.
+    pub fn new() -> Self {
+        ColumnIndexBuilder {
+            null_pages: Vec::new(),
+            min_values: Vec::new(),
+            max_values: Vec::new(),
+            null_counts: Vec::new(),
+            boundary_order: BoundaryOrder::Unordered,
+            valid: true,
+        }
+    }
+
+    /// Appends statistics of the next data page.
+    /// For a page that only contains nulls, `min_value` and `max_value` are empty.
+    pub fn append(
+        &mut self,
+        null_page: bool,
+        min_value: Vec<u8>,
+        max_value: Vec<u8>,
+        null_count: i64,
+    ) {
+        self.null_pages.push(null_page);
+        self.min_values.push(min_value);
+        self.max_values.push(max_value);
+        self.null_counts.push(null_count);
+    }
+
+    /// Marks the column index as invalid, e.g. because statistics of a page are
+    /// missing.
+    pub fn to_invalid(&mut self) {
+        self.valid = false;
+    }
+
+    /// Returns `true` if the column index can be written.
+    pub fn valid(&self) -> bool {
+        self.valid
+    }
+
+    /// Builds the thrift column index.
+    pub fn build_to_thrift(self) -> ColumnIndex {
+        ColumnIndex {
+            null_pages: self.null_pages,
+            min_values: self.min_values,
+            max_values: self.max_values,
+            boundary_order: self.boundary_order,
+            null_counts: Some(self.null_counts),
+        }
+    }
+}
+
+/// Builder for the offset index of a column chunk.
+pub struct OffsetIndexBuilder {
+    offset_array: Vec<i64>,
+    compressed_page_size_array: Vec<i32>,
+    first_row_index_array: Vec<i64>,
+    current_first_row_index: i64,
+}
+
+impl Default for OffsetIndexBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OffsetIndexBuilder {
+    /// Creates new empty offset index builder.
+    pub fn new() -> Self {
+        OffsetIndexBuilder {
+            offset_array: Vec::new(),
+            compressed_page_size_array: Vec::new(),
+            first_row_index_array: Vec::new(),
+            current_first_row_index: 0,
+        }
+    }
+
+    /// Appends the number of rows of the next data page.
+    pub fn append_row_count(&mut self, row_count: i64) {
+        let current_page_row_index = self.current_first_row_index;
+        self.first_row_index_array.push(current_page_row_index);
+        self.current_first_row_index += row_count;
+    }
+
+    /// Appends the file offset and the compressed size, including the page header,
+    /// of the next data page.
+    pub fn append_offset_and_size(&mut self, offset: i64, compressed_page_size: i32) {
+        self.offset_array.push(offset);
+        self.compressed_page_size_array.push(compressed_page_size);
+    }
+
+    /// Builds the thrift offset index.
+    pub fn build_to_thrift(self) -> OffsetIndex {
+        let locations = self
+            .offset_array
+            .iter()
+            .zip(self.compressed_page_size_array.iter())
+            .zip(self.first_row_index_array.iter())
+            .map(|((offset, size), row_index)| PageLocation {
+                offset: *offset,
+                compressed_page_size: *size,
+                first_row_index: *row_index,
+            })
+            .collect::<Vec<_>>();
+        OffsetIndex {
+            page_locations: locations,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -670,10 +1067,12 @@ mod tests {
             .set_num_rows(1000)
             .set_total_byte_size(2000)
             .set_column_metadata(columns)
+            .set_ordinal(3)
             .build()
             .unwrap();
 
         let row_group_exp = row_group_meta.to_thrift();
+        assert_eq!(row_group_exp.ordinal, Some(3));
         let row_group_res =
             RowGroupMetaData::from_thrift(schema_descr, row_group_exp.clone())
                 .unwrap()
@@ -711,6 +1110,11 @@ mod tests {
             .set_total_uncompressed_size(3000)
             .set_data_page_offset(4000)
             .set_dictionary_page_offset(Some(5000))
+            .set_bloom_filter_offset(Some(5500))
+            .set_offset_index_offset(Some(6000))
+            .set_offset_index_length(Some(25))
+            .set_column_index_offset(Some(7000))
+            .set_column_index_length(Some(25))
             .build()
             .unwrap();
 
@@ -724,6 +1128,29 @@ mod tests {
         assert_eq!(col_chunk_res, col_chunk_exp);
     }
 
+    #[test]
+    fn test_column_chunk_metadata_byte_ranges() {
+        let column_descr = get_test_schema_descr().column(0);
+
+        let col_metadata = ColumnChunkMetaData::builder(column_descr.clone())
+            .set_total_compressed_size(2000)
+            .set_data_page_offset(4100)
+            .set_dictionary_page_offset(Some(4000))
+            .set_offset_index_offset(Some(6000))
+            .set_offset_index_length(Some(25))
+            .set_column_index_offset(Some(7000))
+            .build()
+            .unwrap();
+        assert_eq!(col_metadata.byte_range(), (4000, 2000));
+        assert_eq!(col_metadata.dictionary_page_range(), Some(4000..4100));
+        assert_eq!(col_metadata.offset_index_range(), Some(6000..6025));
+        assert_eq!(col_metadata.column_index_range(), None);
+
+        let col_metadata = ColumnChunkMetaData::builder(column_descr).build().unwrap();
+        assert_eq!(col_metadata.dictionary_page_range(), None);
+        assert_eq!(col_metadata.offset_index_range(), None);
+    }
+
     #[test]
     fn test_column_chunk_metadata_thrift_conversion_empty() {
         let column_descr = get_test_schema_descr().column(0);
@@ -766,6 +1193,50 @@ mod tests {
         assert_eq!(compressed_size_res, compressed_size_exp);
     }
 
+    #[test]
+    fn test_offset_index_builder() {
+        let mut builder = OffsetIndexBuilder::new();
+        builder.append_row_count(10);
+        builder.append_offset_and_size(4, 100);
+        builder.append_row_count(5);
+        builder.append_offset_and_size(104, 50);
+
+        let offset_index = builder.build_to_thrift();
+        assert_eq!(
+            offset_index.page_locations,
+            vec![
+                PageLocation {
+                    offset: 4,
+                    compressed_page_size: 100,
+                    first_row_index: 0,
+                },
+                PageLocation {
+                    offset: 104,
+                    compressed_page_size: 50,
+                    first_row_index: 10,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_column_index_builder() {
+        let mut builder = ColumnIndexBuilder::new();
+        builder.append(false, vec![1], vec![5], 0);
+        builder.append(true, vec![], vec![], 3);
+        assert!(builder.valid());
+
+        let column_index = builder.build_to_thrift();
+        assert_eq!(column_index.null_pages, vec![false, true]);
+        assert_eq!(column_index.min_values, vec![vec![1], vec![]]);
+        assert_eq!(column_index.max_values, vec![vec![5], vec![]]);
+        assert_eq!(column_index.null_counts, Some(vec![0, 3]));
+
+        let mut builder = ColumnIndexBuilder::new();
+        builder.to_invalid();
+        assert!(!builder.valid());
+    }
+
     /// Returns sample schema descriptor so we can create column metadata.
     fn get_test_schema_descr() -> SchemaDescPtr {
         let schema = SchemaType::group_type_builder("schema")
@@ -97,14 +97,20 @@
 //! ```
 pub mod footer;
 pub mod metadata;
+pub mod page_index;
 pub mod properties;
+pub mod range_reader;
 pub mod reader;
 pub mod serialized_reader;
 pub mod statistics;
 pub mod writer;
 
-const FOOTER_SIZE: usize = 8;
+/// The size of the footer at the end of a Parquet file: the length of the metadata
+/// followed by the magic number
+pub const FOOTER_SIZE: usize = 8;
 const PARQUET_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'1'];
+/// The magic number of files whose footer is encrypted.
+const PARQUET_MAGIC_ENCR_FOOTER: [u8; 4] = [b'P', b'A', b'R', b'E'];
 
 /// The number of bytes read at the end of the parquet file on first read
 const DEFAULT_FOOTER_READ_SIZE: usize = 64 * 1024;
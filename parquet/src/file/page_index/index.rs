@@ -0,0 +1,263 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Typed statistics of the data pages of a column chunk, decoded from the Parquet
+//! [`ColumnIndex`].
+
+use byteorder::{ByteOrder, LittleEndian};
+use parquet_format::ColumnIndex;
+
+use crate::basic::Type;
+use crate::data_type::{ByteArray, FixedLenByteArray, Int96};
+use crate::errors::{ParquetError, Result};
+use crate::util::bit_util::from_ne_slice;
+
+/// The sort order of the page min/max values of a column chunk.
+pub type BoundaryOrder = parquet_format::BoundaryOrder;
+
+/// The statistics of a single data page of a column chunk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageIndex<T> {
+    /// The minimum value of the page, `None` if the page only contains nulls
+    pub min: Option<T>,
+    /// The maximum value of the page, `None` if the page only contains nulls
+    pub max: Option<T>,
+    /// The number of nulls in the page, if known
+    pub null_count: Option<i64>,
+}
+
+impl<T> PageIndex<T> {
+    /// Returns the minimum value of the page, if any.
+    pub fn min(&self) -> Option<&T> {
+        self.min.as_ref()
+    }
+
+    /// Returns the maximum value of the page, if any.
+    pub fn max(&self) -> Option<&T> {
+        self.max.as_ref()
+    }
+
+    /// Returns the number of nulls in the page, if known.
+    pub fn null_count(&self) -> Option<i64> {
+        self.null_count
+    }
+}
+
+/// The page statistics of a column chunk, in the order the pages are stored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NativeIndex<T> {
+    /// The statistics of each page
+    pub indexes: Vec<PageIndex<T>>,
+    /// Whether the min/max values are ordered across the pages
+    pub boundary_order: BoundaryOrder,
+}
+
+impl<T> NativeIndex<T> {
+    /// Creates an index from its Thrift definition, decoding the min/max values of
+    /// every page that is not entirely null with `decode`.
+    fn try_new<F>(index: ColumnIndex, decode: F) -> Result<Self>
+    where
+        F: Fn(&[u8]) -> Result<T>,
+    {
+        let len = index.null_pages.len();
+        if index.min_values.len() != len || index.max_values.len() != len {
+            return Err(general_err!(
+                "Invalid column index: {} null pages, {} min values and {} max values",
+                len,
+                index.min_values.len(),
+                index.max_values.len()
+            ));
+        }
+
+        let null_counts = match index.null_counts {
+            Some(counts) if counts.len() != len => {
+                return Err(general_err!(
+                    "Invalid column index: {} null pages and {} null counts",
+                    len,
+                    counts.len()
+                ))
+            }
+            Some(counts) => counts.into_iter().map(Some).collect(),
+            None => vec![None; len],
+        };
+
+        let indexes = index
+            .null_pages
+            .iter()
+            .zip(index.min_values.iter().zip(index.max_values.iter()))
+            .zip(null_counts.into_iter())
+            .map(|((is_null, (min, max)), null_count)| {
+                let (min, max) = if *is_null {
+                    (None, None)
+                } else {
+                    (Some(decode(min)?), Some(decode(max)?))
+                };
+                Ok(PageIndex {
+                    min,
+                    max,
+                    null_count,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            indexes,
+            boundary_order: index.boundary_order,
+        })
+    }
+}
+
+/// The column index of a column chunk, with min/max values decoded according
+/// to the physical type of the column.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(non_camel_case_types)]
+pub enum Index {
+    /// The column chunk does not have a column index
+    NONE,
+    BOOLEAN(NativeIndex<bool>),
+    INT32(NativeIndex<i32>),
+    INT64(NativeIndex<i64>),
+    INT96(NativeIndex<Int96>),
+    FLOAT(NativeIndex<f32>),
+    DOUBLE(NativeIndex<f64>),
+    BYTE_ARRAY(NativeIndex<ByteArray>),
+    FIXED_LEN_BYTE_ARRAY(NativeIndex<FixedLenByteArray>),
+}
+
+impl Index {
+    /// Converts the Thrift definition of the column index of a column with
+    /// `physical_type` into an [`Index`].
+    pub fn try_new(physical_type: Type, index: ColumnIndex) -> Result<Self> {
+        Ok(match physical_type {
+            Type::BOOLEAN => Index::BOOLEAN(NativeIndex::try_new(index, |data| {
+                check_len(data, 1)?;
+                Ok(data[0] != 0)
+            })?),
+            Type::INT32 => Index::INT32(NativeIndex::try_new(index, |data| {
+                check_len(data, 4)?;
+                Ok(LittleEndian::read_i32(data))
+            })?),
+            Type::INT64 => Index::INT64(NativeIndex::try_new(index, |data| {
+                check_len(data, 8)?;
+                Ok(LittleEndian::read_i64(data))
+            })?),
+            Type::INT96 => Index::INT96(NativeIndex::try_new(index, |data| {
+                check_len(data, 12)?;
+                Ok(from_ne_slice::<Int96>(data))
+            })?),
+            Type::FLOAT => Index::FLOAT(NativeIndex::try_new(index, |data| {
+                check_len(data, 4)?;
+                Ok(LittleEndian::read_f32(data))
+            })?),
+            Type::DOUBLE => Index::DOUBLE(NativeIndex::try_new(index, |data| {
+                check_len(data, 8)?;
+                Ok(LittleEndian::read_f64(data))
+            })?),
+            Type::BYTE_ARRAY => Index::BYTE_ARRAY(NativeIndex::try_new(index, |data| {
+                Ok(ByteArray::from(data.to_vec()))
+            })?),
+            Type::FIXED_LEN_BYTE_ARRAY => {
+                Index::FIXED_LEN_BYTE_ARRAY(NativeIndex::try_new(index, |data| {
+                    Ok(FixedLenByteArray::from(ByteArray::from(data.to_vec())))
+                })?)
+            }
+        })
+    }
+
+    /// Returns the number of pages in the column index, or `None` if there is
+    /// no column index.
+    pub fn num_pages(&self) -> Option<usize> {
+        match self {
+            Index::NONE => None,
+            Index::BOOLEAN(index) => Some(index.indexes.len()),
+            Index::INT32(index) => Some(index.indexes.len()),
+            Index::INT64(index) => Some(index.indexes.len()),
+            Index::INT96(index) => Some(index.indexes.len()),
+            Index::FLOAT(index) => Some(index.indexes.len()),
+            Index::DOUBLE(index) => Some(index.indexes.len()),
+            Index::BYTE_ARRAY(index) => Some(index.indexes.len()),
+            Index::FIXED_LEN_BYTE_ARRAY(index) => Some(index.indexes.len()),
+        }
+    }
+}
+
+/// Returns an error if an encoded min/max value does not have `expected` bytes
+fn check_len(data: &[u8], expected: usize) -> Result<()> {
+    if data.len() != expected {
+        return Err(general_err!(
+            "Invalid column index: expected a value of {} bytes, got {}",
+            expected,
+            data.len()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_try_new() {
+        let thrift = ColumnIndex {
+            null_pages: vec![false, true, false],
+            min_values: vec![
+                1i32.to_le_bytes().to_vec(),
+                vec![],
+                8i32.to_le_bytes().to_vec(),
+            ],
+            max_values: vec![
+                5i32.to_le_bytes().to_vec(),
+                vec![],
+                9i32.to_le_bytes().to_vec(),
+            ],
+            boundary_order: BoundaryOrder::Ascending,
+            null_counts: Some(vec![0, 10, 2]),
+        };
+
+        let index = Index::try_new(Type::INT32, thrift).unwrap();
+        assert_eq!(index.num_pages(), Some(3));
+        match index {
+            Index::INT32(index) => {
+                assert_eq!(index.boundary_order, BoundaryOrder::Ascending);
+                assert_eq!(index.indexes[0].min(), Some(&1));
+                assert_eq!(index.indexes[0].max(), Some(&5));
+                assert_eq!(index.indexes[1].min(), None);
+                assert_eq!(index.indexes[1].null_count(), Some(10));
+                assert_eq!(index.indexes[2].max(), Some(&9));
+            }
+            _ => panic!("expected an INT32 index"),
+        }
+    }
+
+    #[test]
+    fn test_index_try_new_invalid() {
+        let thrift = ColumnIndex {
+            null_pages: vec![false],
+            min_values: vec![vec![1, 2]],
+            max_values: vec![vec![3, 4]],
+            boundary_order: BoundaryOrder::Unordered,
+            null_counts: None,
+        };
+
+        let err = Index::try_new(Type::INT64, thrift).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid column index: expected a value of 8 bytes, got 2"
+        );
+    }
+}
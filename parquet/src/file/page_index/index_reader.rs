@@ -0,0 +1,151 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Reads the [`Index`] and [`PageLocation`]s of column chunks from a Parquet file.
+
+use std::ops::Range;
+
+use parquet_format::{ColumnIndex, OffsetIndex};
+use thrift::protocol::TCompactInputProtocol;
+
+use crate::errors::{ParquetError, Result};
+use crate::file::metadata::{ColumnChunkMetaData, ParquetMetaData};
+use crate::file::page_index::index::Index;
+use crate::file::page_index::PageLocation;
+use crate::file::reader::ChunkReader;
+
+/// Reads the page index of every column chunk in `metadata`, returning the metadata
+/// with the page index attached, see [`ParquetMetaData::page_indexes`] and
+/// [`ParquetMetaData::offset_indexes`].
+pub fn read_page_index<R: ChunkReader>(
+    reader: &R,
+    metadata: ParquetMetaData,
+) -> Result<ParquetMetaData> {
+    let mut page_indexes = Vec::with_capacity(metadata.num_row_groups());
+    let mut offset_indexes = Vec::with_capacity(metadata.num_row_groups());
+    for row_group in metadata.row_groups() {
+        page_indexes.push(read_columns_indexes(reader, row_group.columns())?);
+        offset_indexes.push(read_pages_locations(reader, row_group.columns())?);
+    }
+
+    let result = ParquetMetaData::new_with_page_index(
+        metadata.file_metadata().clone(),
+        metadata.row_groups().to_vec(),
+        Some(page_indexes),
+        Some(offset_indexes),
+    );
+    #[cfg(feature = "encryption")]
+    let result = match metadata.file_decryptor() {
+        Some(file_decryptor) => result.with_file_decryptor(file_decryptor.clone()),
+        None => result,
+    };
+    Ok(result)
+}
+
+/// Reads the column index of each of `chunks`, returning [`Index::NONE`] for the
+/// column chunks that do not have one.
+///
+/// The column indexes of a row group are stored next to each other, so they are
+/// fetched with a single read.
+pub fn read_columns_indexes<R: ChunkReader>(
+    reader: &R,
+    chunks: &[ColumnChunkMetaData],
+) -> Result<Vec<Index>> {
+    let locations = chunks
+        .iter()
+        .map(|chunk| (chunk.column_index_offset(), chunk.column_index_length()))
+        .collect::<Vec<_>>();
+    let (data, ranges) = read_ranges(reader, &locations)?;
+
+    chunks
+        .iter()
+        .zip(ranges)
+        .map(|(chunk, range)| match range {
+            Some(range) => {
+                let mut prot = TCompactInputProtocol::new(&data[range]);
+                let index = ColumnIndex::read_from_in_protocol(&mut prot)?;
+                Index::try_new(chunk.column_type(), index)
+            }
+            None => Ok(Index::NONE),
+        })
+        .collect()
+}
+
+/// Reads the page locations of each of `chunks` from their offset index, returning
+/// an empty list for the column chunks that do not have one.
+///
+/// The offset indexes of a row group are stored next to each other, so they are
+/// fetched with a single read.
+pub fn read_pages_locations<R: ChunkReader>(
+    reader: &R,
+    chunks: &[ColumnChunkMetaData],
+) -> Result<Vec<Vec<PageLocation>>> {
+    let locations = chunks
+        .iter()
+        .map(|chunk| (chunk.offset_index_offset(), chunk.offset_index_length()))
+        .collect::<Vec<_>>();
+    let (data, ranges) = read_ranges(reader, &locations)?;
+
+    ranges
+        .into_iter()
+        .map(|range| match range {
+            Some(range) => {
+                let mut prot = TCompactInputProtocol::new(&data[range]);
+                let index = OffsetIndex::read_from_in_protocol(&mut prot)?;
+                Ok(index.page_locations)
+            }
+            None => Ok(vec![]),
+        })
+        .collect()
+}
+
+/// Reads the byte range spanning all of `locations`, given as offset and length
+/// pairs, returning the bytes read and the range of each location within them
+fn read_ranges<R: ChunkReader>(
+    reader: &R,
+    locations: &[(Option<i64>, Option<i32>)],
+) -> Result<(Vec<u8>, Vec<Option<Range<usize>>>)> {
+    let ranges = locations
+        .iter()
+        .map(|location| match *location {
+            (Some(offset), Some(length)) if offset < 0 || length < 0 => {
+                Err(general_err!(
+                    "Invalid page index location: offset {}, length {}",
+                    offset,
+                    length
+                ))
+            }
+            (Some(offset), Some(length)) => {
+                Ok(Some(offset as u64..offset as u64 + length as u64))
+            }
+            _ => Ok(None),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let start = ranges.iter().flatten().map(|r| r.start).min();
+    let end = ranges.iter().flatten().map(|r| r.end).max();
+    let (start, data) = match start.zip(end) {
+        Some((start, end)) => (start, reader.get_bytes(start, (end - start) as usize)?),
+        None => (0, vec![]),
+    };
+
+    let ranges = ranges
+        .into_iter()
+        .map(|r| r.map(|r| (r.start - start) as usize..(r.end - start) as usize))
+        .collect();
+    Ok((data, ranges))
+}
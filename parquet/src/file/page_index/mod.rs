@@ -0,0 +1,28 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for reading the [Parquet page index], which stores the statistics (column
+//! index) and location (offset index) of the data pages of each column chunk, allowing
+//! readers to skip pages without decoding them.
+//!
+//! [Parquet page index]: https://github.com/apache/parquet-format/blob/master/PageIndex.md
+
+pub mod index;
+pub mod index_reader;
+
+/// The location and first row index of a data page within a column chunk.
+pub type PageLocation = parquet_format::PageLocation;
@@ -51,6 +51,10 @@
 use std::{collections::HashMap, sync::Arc};
 
 use crate::basic::{Compression, Encoding};
+use crate::compression::check_compression_level;
+#[cfg(feature = "encryption")]
+use crate::encryption::encrypt::FileEncryptionProperties;
+use crate::errors::Result;
 use crate::file::metadata::KeyValue;
 use crate::schema::types::ColumnPath;
 
@@ -60,9 +64,16 @@ const DEFAULT_WRITER_VERSION: WriterVersion = WriterVersion::PARQUET_1_0;
 const DEFAULT_COMPRESSION: Compression = Compression::UNCOMPRESSED;
 const DEFAULT_DICTIONARY_ENABLED: bool = true;
 const DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT: usize = DEFAULT_PAGE_SIZE;
-const DEFAULT_STATISTICS_ENABLED: bool = true;
+const DEFAULT_STATISTICS_ENABLED: EnabledStatistics = EnabledStatistics::Page;
 const DEFAULT_MAX_STATISTICS_SIZE: usize = 4096;
+const DEFAULT_STATISTICS_TRUNCATE_LENGTH: Option<usize> = None;
 const DEFAULT_MAX_ROW_GROUP_SIZE: usize = 128 * 1024 * 1024;
+const DEFAULT_MAX_ROW_GROUP_BYTES: Option<usize> = None;
+const DEFAULT_MAX_BUFFERED_MEMORY: Option<usize> = None;
+const DEFAULT_PAGE_INDEX_ENABLED: bool = false;
+const DEFAULT_BLOOM_FILTER_ENABLED: bool = false;
+const DEFAULT_BLOOM_FILTER_FPP: f64 = 0.05;
+const DEFAULT_BLOOM_FILTER_NDV: u64 = 1_000_000;
 const DEFAULT_CREATED_BY: &str = env!("PARQUET_CREATED_BY");
 
 /// Parquet writer version.
@@ -84,6 +95,27 @@ impl WriterVersion {
     }
 }
 
+/// Level of the statistics written for a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnabledStatistics {
+    /// No statistics are written.
+    None,
+    /// Statistics are only written for the column chunk, in its metadata.
+    Chunk,
+    /// Statistics are written for the column chunk and for each data page, in its
+    /// header and, if enabled, in the page index.
+    Page,
+}
+
+/// Sizing of the bloom filter of a column chunk.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BloomFilterProperties {
+    /// False positive probability, must be between 0.0 and 1.0.
+    pub fpp: f64,
+    /// Expected number of distinct values in a column chunk.
+    pub ndv: u64,
+}
+
 /// Reference counted writer properties.
 pub type WriterPropertiesPtr = Arc<WriterProperties>;
 
@@ -97,11 +129,16 @@ pub struct WriterProperties {
     dictionary_pagesize_limit: usize,
     write_batch_size: usize,
     max_row_group_size: usize,
+    max_row_group_bytes: Option<usize>,
+    max_buffered_memory: Option<usize>,
+    page_index_enabled: bool,
     writer_version: WriterVersion,
     created_by: String,
     pub(crate) key_value_metadata: Option<Vec<KeyValue>>,
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
+    #[cfg(feature = "encryption")]
+    file_encryption_properties: Option<FileEncryptionProperties>,
 }
 
 impl WriterProperties {
@@ -129,11 +166,31 @@ impl WriterProperties {
         self.write_batch_size
     }
 
-    /// Returns max size for a row group.
+    /// Returns max number of rows in a row group.
     pub fn max_row_group_size(&self) -> usize {
         self.max_row_group_size
     }
 
+    /// Returns the target size in bytes of a row group, if set.
+    ///
+    /// The size of a row group is estimated from the in-memory size of the Arrow
+    /// data written to it by the `ArrowWriter`.
+    pub fn max_row_group_bytes(&self) -> Option<usize> {
+        self.max_row_group_bytes
+    }
+
+    /// Returns the max number of bytes of data buffered by a writer before it is
+    /// written as a row group, if set.
+    pub fn max_buffered_memory(&self) -> Option<usize> {
+        self.max_buffered_memory
+    }
+
+    /// Returns `true` if the column index and offset index of each column chunk
+    /// should be written to the file.
+    pub fn page_index_enabled(&self) -> bool {
+        self.page_index_enabled
+    }
+
     /// Returns configured writer version.
     pub fn writer_version(&self) -> WriterVersion {
         self.writer_version
@@ -149,6 +206,12 @@ impl WriterProperties {
         &self.key_value_metadata
     }
 
+    /// Returns the properties used to encrypt the file, if it is encrypted.
+    #[cfg(feature = "encryption")]
+    pub fn file_encryption_properties(&self) -> Option<&FileEncryptionProperties> {
+        self.file_encryption_properties.as_ref()
+    }
+
     /// Returns encoding for a data page, when dictionary encoding is enabled.
     /// This is not configurable.
     #[inline]
@@ -188,6 +251,18 @@ impl WriterProperties {
             .unwrap_or(DEFAULT_COMPRESSION)
     }
 
+    /// Returns compression level for a column, or `None` if the codec's default
+    /// level is used.
+    ///
+    /// The level is always the one set together with the codec returned by
+    /// [`Self::compression`].
+    pub fn compression_level(&self, col: &ColumnPath) -> Option<u32> {
+        match self.column_properties.get(col) {
+            Some(props) if props.compression().is_some() => props.compression_level(),
+            _ => self.default_column_properties.compression_level(),
+        }
+    }
+
     /// Returns `true` if dictionary encoding is enabled for a column.
     pub fn dictionary_enabled(&self, col: &ColumnPath) -> bool {
         self.column_properties
@@ -197,8 +272,8 @@ impl WriterProperties {
             .unwrap_or(DEFAULT_DICTIONARY_ENABLED)
     }
 
-    /// Returns `true` if statistics are enabled for a column.
-    pub fn statistics_enabled(&self, col: &ColumnPath) -> bool {
+    /// Returns the level of the statistics written for a column.
+    pub fn statistics_enabled(&self, col: &ColumnPath) -> EnabledStatistics {
         self.column_properties
             .get(col)
             .and_then(|c| c.statistics_enabled())
@@ -215,6 +290,42 @@ impl WriterProperties {
             .or_else(|| self.default_column_properties.max_statistics_size())
             .unwrap_or(DEFAULT_MAX_STATISTICS_SIZE)
     }
+
+    /// Returns the length in bytes that min and max statistics of binary columns are
+    /// truncated to, or `None` if they are not truncated.
+    /// Only applicable if statistics are enabled.
+    pub fn statistics_truncate_length(&self, col: &ColumnPath) -> Option<usize> {
+        self.column_properties
+            .get(col)
+            .and_then(|c| c.statistics_truncate_length())
+            .or_else(|| self.default_column_properties.statistics_truncate_length())
+            .or(DEFAULT_STATISTICS_TRUNCATE_LENGTH)
+    }
+
+    /// Returns the sizing of the bloom filter for a column, or `None` if bloom
+    /// filters are not enabled for this column.
+    pub fn bloom_filter_properties(
+        &self,
+        col: &ColumnPath,
+    ) -> Option<BloomFilterProperties> {
+        let column_properties = self.column_properties.get(col);
+        let enabled = column_properties
+            .and_then(|c| c.bloom_filter_enabled())
+            .or_else(|| self.default_column_properties.bloom_filter_enabled())
+            .unwrap_or(DEFAULT_BLOOM_FILTER_ENABLED);
+        if !enabled {
+            return None;
+        }
+        let fpp = column_properties
+            .and_then(|c| c.bloom_filter_fpp())
+            .or_else(|| self.default_column_properties.bloom_filter_fpp())
+            .unwrap_or(DEFAULT_BLOOM_FILTER_FPP);
+        let ndv = column_properties
+            .and_then(|c| c.bloom_filter_ndv())
+            .or_else(|| self.default_column_properties.bloom_filter_ndv())
+            .unwrap_or(DEFAULT_BLOOM_FILTER_NDV);
+        Some(BloomFilterProperties { fpp, ndv })
+    }
 }
 
 /// Writer properties builder.
@@ -223,11 +334,16 @@ pub struct WriterPropertiesBuilder {
     dictionary_pagesize_limit: usize,
     write_batch_size: usize,
     max_row_group_size: usize,
+    max_row_group_bytes: Option<usize>,
+    max_buffered_memory: Option<usize>,
+    page_index_enabled: bool,
     writer_version: WriterVersion,
     created_by: String,
     key_value_metadata: Option<Vec<KeyValue>>,
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
+    #[cfg(feature = "encryption")]
+    file_encryption_properties: Option<FileEncryptionProperties>,
 }
 
 impl WriterPropertiesBuilder {
@@ -238,11 +354,16 @@ impl WriterPropertiesBuilder {
             dictionary_pagesize_limit: DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT,
             write_batch_size: DEFAULT_WRITE_BATCH_SIZE,
             max_row_group_size: DEFAULT_MAX_ROW_GROUP_SIZE,
+            max_row_group_bytes: DEFAULT_MAX_ROW_GROUP_BYTES,
+            max_buffered_memory: DEFAULT_MAX_BUFFERED_MEMORY,
+            page_index_enabled: DEFAULT_PAGE_INDEX_ENABLED,
             writer_version: DEFAULT_WRITER_VERSION,
             created_by: DEFAULT_CREATED_BY.to_string(),
             key_value_metadata: None,
             default_column_properties: ColumnProperties::new(),
             column_properties: HashMap::new(),
+            #[cfg(feature = "encryption")]
+            file_encryption_properties: None,
         }
     }
 
@@ -253,11 +374,16 @@ impl WriterPropertiesBuilder {
             dictionary_pagesize_limit: self.dictionary_pagesize_limit,
             write_batch_size: self.write_batch_size,
             max_row_group_size: self.max_row_group_size,
+            max_row_group_bytes: self.max_row_group_bytes,
+            max_buffered_memory: self.max_buffered_memory,
+            page_index_enabled: self.page_index_enabled,
             writer_version: self.writer_version,
             created_by: self.created_by,
             key_value_metadata: self.key_value_metadata,
             default_column_properties: self.default_column_properties,
             column_properties: self.column_properties,
+            #[cfg(feature = "encryption")]
+            file_encryption_properties: self.file_encryption_properties,
         }
     }
 
@@ -288,12 +414,39 @@ impl WriterPropertiesBuilder {
         self
     }
 
-    /// Sets max size for a row group.
+    /// Sets max number of rows in a row group.
     pub fn set_max_row_group_size(mut self, value: usize) -> Self {
         self.max_row_group_size = value;
         self
     }
 
+    /// Sets the target size in bytes of a row group.
+    ///
+    /// When set, writers buffer the data written to them and write a row group
+    /// whenever the buffered data reaches this size.
+    pub fn set_max_row_group_bytes(mut self, value: usize) -> Self {
+        self.max_row_group_bytes = Some(value);
+        self
+    }
+
+    /// Sets the max number of bytes of data buffered by a writer.
+    ///
+    /// When set, writers buffer the data written to them and write all of it as a
+    /// row group whenever the buffered data exceeds this size.
+    pub fn set_max_buffered_memory(mut self, value: usize) -> Self {
+        self.max_buffered_memory = Some(value);
+        self
+    }
+
+    /// Sets flag to enable/disable writing of the page index.
+    ///
+    /// When enabled, a `ColumnIndex` with per-page min/max values and null counts
+    /// and an `OffsetIndex` with page locations are written for every column chunk.
+    pub fn set_page_index_enabled(mut self, value: bool) -> Self {
+        self.page_index_enabled = value;
+        self
+    }
+
     /// Sets "created by" property.
     pub fn set_created_by(mut self, value: String) -> Self {
         self.created_by = value;
@@ -306,6 +459,18 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the properties used to encrypt the file.
+    ///
+    /// Encryption cannot yet be combined with the page index or bloom filters.
+    #[cfg(feature = "encryption")]
+    pub fn set_file_encryption_properties(
+        mut self,
+        value: FileEncryptionProperties,
+    ) -> Self {
+        self.file_encryption_properties = Some(value);
+        self
+    }
+
     // ----------------------------------------------------------------------
     // Setters for any column (global)
 
@@ -322,12 +487,28 @@ impl WriterPropertiesBuilder {
         self
     }
 
-    /// Sets compression codec for any column.
+    /// Sets compression codec for any column, compressing with the codec's default
+    /// level.
     pub fn set_compression(mut self, value: Compression) -> Self {
         self.default_column_properties.set_compression(value);
         self
     }
 
+    /// Sets compression codec and compression level for any column.
+    ///
+    /// The supported levels depend on the compression codec: 0-9 for GZIP, 0-11 for
+    /// BROTLI and 1-22 for ZSTD. Other codecs ignore the compression level.
+    /// Returns an error if the level is not supported by the codec.
+    pub fn set_compression_with_level(
+        mut self,
+        codec: Compression,
+        level: u32,
+    ) -> Result<Self> {
+        self.default_column_properties
+            .set_compression_with_level(codec, level)?;
+        Ok(self)
+    }
+
     /// Sets flag to enable/disable dictionary encoding for any column.
     ///
     /// Use this method to set dictionary encoding, instead of explicitly specifying
@@ -337,8 +518,8 @@ impl WriterPropertiesBuilder {
         self
     }
 
-    /// Sets flag to enable/disable statistics for any column.
-    pub fn set_statistics_enabled(mut self, value: bool) -> Self {
+    /// Sets the level of the statistics written for any column.
+    pub fn set_statistics_enabled(mut self, value: EnabledStatistics) -> Self {
         self.default_column_properties.set_statistics_enabled(value);
         self
     }
@@ -351,6 +532,40 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets the length in bytes that min and max statistics of binary columns are
+    /// truncated to for any column. The truncated min is a prefix of the actual min,
+    /// and the truncated max is incremented so that it remains an upper bound.
+    /// Applicable only if statistics are enabled.
+    pub fn set_statistics_truncate_length(mut self, value: usize) -> Self {
+        self.default_column_properties
+            .set_statistics_truncate_length(value);
+        self
+    }
+
+    /// Sets flag to enable/disable writing of a bloom filter for any column.
+    pub fn set_bloom_filter_enabled(mut self, value: bool) -> Self {
+        self.default_column_properties
+            .set_bloom_filter_enabled(value);
+        self
+    }
+
+    /// Sets the false positive probability of the bloom filter for any column.
+    /// Applicable only if bloom filters are enabled.
+    ///
+    /// Panics if the value is not between 0.0 and 1.0.
+    pub fn set_bloom_filter_fpp(mut self, value: f64) -> Self {
+        self.default_column_properties.set_bloom_filter_fpp(value);
+        self
+    }
+
+    /// Sets the expected number of distinct values in a column chunk, used to size
+    /// the bloom filter for any column.
+    /// Applicable only if bloom filters are enabled.
+    pub fn set_bloom_filter_ndv(mut self, value: u64) -> Self {
+        self.default_column_properties.set_bloom_filter_ndv(value);
+        self
+    }
+
     // ----------------------------------------------------------------------
     // Setters for a specific column
 
@@ -384,6 +599,20 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets compression codec and compression level for a column, see
+    /// [`Self::set_compression_with_level`].
+    /// Takes precedence over globally defined settings.
+    pub fn set_column_compression_with_level(
+        mut self,
+        col: ColumnPath,
+        codec: Compression,
+        level: u32,
+    ) -> Result<Self> {
+        self.get_mut_props(col)
+            .set_compression_with_level(codec, level)?;
+        Ok(self)
+    }
+
     /// Sets flag to enable/disable dictionary encoding for a column.
     /// Takes precedence over globally defined settings.
     pub fn set_column_dictionary_enabled(mut self, col: ColumnPath, value: bool) -> Self {
@@ -391,9 +620,13 @@ impl WriterPropertiesBuilder {
         self
     }
 
-    /// Sets flag to enable/disable statistics for a column.
+    /// Sets the level of the statistics written for a column.
     /// Takes precedence over globally defined settings.
-    pub fn set_column_statistics_enabled(mut self, col: ColumnPath, value: bool) -> Self {
+    pub fn set_column_statistics_enabled(
+        mut self,
+        col: ColumnPath,
+        value: EnabledStatistics,
+    ) -> Self {
         self.get_mut_props(col).set_statistics_enabled(value);
         self
     }
@@ -408,6 +641,47 @@ impl WriterPropertiesBuilder {
         self.get_mut_props(col).set_max_statistics_size(value);
         self
     }
+
+    /// Sets the length in bytes that min and max statistics of a binary column are
+    /// truncated to.
+    /// Takes precedence over globally defined settings.
+    pub fn set_column_statistics_truncate_length(
+        mut self,
+        col: ColumnPath,
+        value: usize,
+    ) -> Self {
+        self.get_mut_props(col)
+            .set_statistics_truncate_length(value);
+        self
+    }
+
+    /// Sets flag to enable/disable writing of a bloom filter for a column.
+    /// Takes precedence over globally defined settings.
+    pub fn set_column_bloom_filter_enabled(
+        mut self,
+        col: ColumnPath,
+        value: bool,
+    ) -> Self {
+        self.get_mut_props(col).set_bloom_filter_enabled(value);
+        self
+    }
+
+    /// Sets the false positive probability of the bloom filter for a column.
+    /// Takes precedence over globally defined settings.
+    ///
+    /// Panics if the value is not between 0.0 and 1.0.
+    pub fn set_column_bloom_filter_fpp(mut self, col: ColumnPath, value: f64) -> Self {
+        self.get_mut_props(col).set_bloom_filter_fpp(value);
+        self
+    }
+
+    /// Sets the expected number of distinct values in a column chunk, used to size
+    /// the bloom filter for a column.
+    /// Takes precedence over globally defined settings.
+    pub fn set_column_bloom_filter_ndv(mut self, col: ColumnPath, value: u64) -> Self {
+        self.get_mut_props(col).set_bloom_filter_ndv(value);
+        self
+    }
 }
 
 /// Container for column properties that can be changed as part of writer.
@@ -418,9 +692,14 @@ impl WriterPropertiesBuilder {
 struct ColumnProperties {
     encoding: Option<Encoding>,
     codec: Option<Compression>,
+    compression_level: Option<u32>,
     dictionary_enabled: Option<bool>,
-    statistics_enabled: Option<bool>,
+    statistics_enabled: Option<EnabledStatistics>,
     max_statistics_size: Option<usize>,
+    statistics_truncate_length: Option<usize>,
+    bloom_filter_enabled: Option<bool>,
+    bloom_filter_fpp: Option<f64>,
+    bloom_filter_ndv: Option<u64>,
 }
 
 impl ColumnProperties {
@@ -429,9 +708,14 @@ impl ColumnProperties {
         Self {
             encoding: None,
             codec: None,
+            compression_level: None,
             dictionary_enabled: None,
             statistics_enabled: None,
             max_statistics_size: None,
+            statistics_truncate_length: None,
+            bloom_filter_enabled: None,
+            bloom_filter_fpp: None,
+            bloom_filter_ndv: None,
         }
     }
 
@@ -451,9 +735,22 @@ impl ColumnProperties {
         self.encoding = Some(value);
     }
 
-    /// Sets compression codec for this column.
+    /// Sets compression codec for this column, with the codec's default level.
     fn set_compression(&mut self, value: Compression) {
         self.codec = Some(value);
+        self.compression_level = None;
+    }
+
+    /// Sets compression codec and compression level for this column.
+    fn set_compression_with_level(
+        &mut self,
+        codec: Compression,
+        level: u32,
+    ) -> Result<()> {
+        check_compression_level(codec, Some(level))?;
+        self.codec = Some(codec);
+        self.compression_level = Some(level);
+        Ok(())
     }
 
     /// Sets whether or not dictionary encoding is enabled for this column.
@@ -461,8 +758,8 @@ impl ColumnProperties {
         self.dictionary_enabled = Some(enabled);
     }
 
-    /// Sets whether or not statistics are enabled for this column.
-    fn set_statistics_enabled(&mut self, enabled: bool) {
+    /// Sets the level of the statistics written for this column.
+    fn set_statistics_enabled(&mut self, enabled: EnabledStatistics) {
         self.statistics_enabled = Some(enabled);
     }
 
@@ -471,6 +768,35 @@ impl ColumnProperties {
         self.max_statistics_size = Some(value);
     }
 
+    /// Sets the length that binary min and max statistics are truncated to for this
+    /// column.
+    fn set_statistics_truncate_length(&mut self, value: usize) {
+        self.statistics_truncate_length = Some(value);
+    }
+
+    /// Sets whether or not a bloom filter is written for this column.
+    fn set_bloom_filter_enabled(&mut self, enabled: bool) {
+        self.bloom_filter_enabled = Some(enabled);
+    }
+
+    /// Sets the false positive probability of the bloom filter for this column.
+    ///
+    /// Panics if the value is not between 0.0 and 1.0.
+    fn set_bloom_filter_fpp(&mut self, value: f64) {
+        if !(value > 0.0 && value < 1.0) {
+            panic!(
+                "False positive probability must be between 0.0 and 1.0, got {}",
+                value
+            );
+        }
+        self.bloom_filter_fpp = Some(value);
+    }
+
+    /// Sets the expected number of distinct values for this column.
+    fn set_bloom_filter_ndv(&mut self, value: u64) {
+        self.bloom_filter_ndv = Some(value);
+    }
+
     /// Returns optional encoding for this column.
     fn encoding(&self) -> Option<Encoding> {
         self.encoding
@@ -481,6 +807,11 @@ impl ColumnProperties {
         self.codec
     }
 
+    /// Returns optional compression level for this column.
+    fn compression_level(&self) -> Option<u32> {
+        self.compression_level
+    }
+
     /// Returns `Some(true)` if dictionary encoding is enabled for this column, if
     /// disabled then returns `Some(false)`. If result is `None`, then no setting has
     /// been provided.
@@ -488,9 +819,9 @@ impl ColumnProperties {
         self.dictionary_enabled
     }
 
-    /// Returns `Some(true)` if statistics are enabled for this column, if disabled then
-    /// returns `Some(false)`. If result is `None`, then no setting has been provided.
-    fn statistics_enabled(&self) -> Option<bool> {
+    /// Returns the level of the statistics written for this column. If result is
+    /// `None`, then no setting has been provided.
+    fn statistics_enabled(&self) -> Option<EnabledStatistics> {
         self.statistics_enabled
     }
 
@@ -498,6 +829,28 @@ impl ColumnProperties {
     fn max_statistics_size(&self) -> Option<usize> {
         self.max_statistics_size
     }
+
+    /// Returns optional length in bytes that binary statistics are truncated to.
+    fn statistics_truncate_length(&self) -> Option<usize> {
+        self.statistics_truncate_length
+    }
+
+    /// Returns `Some(true)` if a bloom filter is written for this column, if disabled
+    /// then returns `Some(false)`. If result is `None`, then no setting has been
+    /// provided.
+    fn bloom_filter_enabled(&self) -> Option<bool> {
+        self.bloom_filter_enabled
+    }
+
+    /// Returns optional false positive probability of the bloom filter.
+    fn bloom_filter_fpp(&self) -> Option<f64> {
+        self.bloom_filter_fpp
+    }
+
+    /// Returns optional expected number of distinct values for the bloom filter.
+    fn bloom_filter_ndv(&self) -> Option<u64> {
+        self.bloom_filter_ndv
+    }
 }
 
 #[cfg(test)]
@@ -520,6 +873,9 @@ mod tests {
         );
         assert_eq!(props.write_batch_size(), DEFAULT_WRITE_BATCH_SIZE);
         assert_eq!(props.max_row_group_size(), DEFAULT_MAX_ROW_GROUP_SIZE);
+        assert_eq!(props.max_row_group_bytes(), DEFAULT_MAX_ROW_GROUP_BYTES);
+        assert_eq!(props.max_buffered_memory(), DEFAULT_MAX_BUFFERED_MEMORY);
+        assert_eq!(props.page_index_enabled(), DEFAULT_PAGE_INDEX_ENABLED);
         assert_eq!(props.writer_version(), DEFAULT_WRITER_VERSION);
         assert_eq!(props.created_by(), DEFAULT_CREATED_BY);
         assert_eq!(props.key_value_metadata(), &None);
@@ -528,6 +884,7 @@ mod tests {
             props.compression(&ColumnPath::from("col")),
             DEFAULT_COMPRESSION
         );
+        assert_eq!(props.compression_level(&ColumnPath::from("col")), None);
         assert_eq!(
             props.dictionary_enabled(&ColumnPath::from("col")),
             DEFAULT_DICTIONARY_ENABLED
@@ -540,6 +897,14 @@ mod tests {
             props.max_statistics_size(&ColumnPath::from("col")),
             DEFAULT_MAX_STATISTICS_SIZE
         );
+        assert_eq!(
+            props.statistics_truncate_length(&ColumnPath::from("col")),
+            DEFAULT_STATISTICS_TRUNCATE_LENGTH
+        );
+        assert_eq!(
+            props.bloom_filter_properties(&ColumnPath::from("col")),
+            None
+        );
     }
 
     #[test]
@@ -603,6 +968,9 @@ mod tests {
             .set_dictionary_pagesize_limit(20)
             .set_write_batch_size(30)
             .set_max_row_group_size(40)
+            .set_max_row_group_bytes(1000)
+            .set_max_buffered_memory(2000)
+            .set_page_index_enabled(true)
             .set_created_by("default".to_owned())
             .set_key_value_metadata(Some(vec![KeyValue::new(
                 "key".to_string(),
@@ -610,16 +978,27 @@ mod tests {
             )]))
             // global column settings
             .set_encoding(Encoding::DELTA_BINARY_PACKED)
-            .set_compression(Compression::GZIP)
+            .set_compression_with_level(Compression::GZIP, 9)
+            .unwrap()
             .set_dictionary_enabled(false)
-            .set_statistics_enabled(false)
+            .set_statistics_enabled(EnabledStatistics::None)
             .set_max_statistics_size(50)
+            .set_statistics_truncate_length(16)
             // specific column settings
             .set_column_encoding(ColumnPath::from("col"), Encoding::RLE)
-            .set_column_compression(ColumnPath::from("col"), Compression::SNAPPY)
+            .set_column_compression_with_level(
+                ColumnPath::from("col"),
+                Compression::ZSTD,
+                15,
+            )
+            .unwrap()
             .set_column_dictionary_enabled(ColumnPath::from("col"), true)
-            .set_column_statistics_enabled(ColumnPath::from("col"), true)
+            .set_column_statistics_enabled(
+                ColumnPath::from("col"),
+                EnabledStatistics::Chunk,
+            )
             .set_column_max_statistics_size(ColumnPath::from("col"), 123)
+            .set_column_statistics_truncate_length(ColumnPath::from("col"), 8)
             .build();
 
         assert_eq!(props.writer_version(), WriterVersion::PARQUET_2_0);
@@ -627,6 +1006,9 @@ mod tests {
         assert_eq!(props.dictionary_pagesize_limit(), 20);
         assert_eq!(props.write_batch_size(), 30);
         assert_eq!(props.max_row_group_size(), 40);
+        assert_eq!(props.max_row_group_bytes(), Some(1000));
+        assert_eq!(props.max_buffered_memory(), Some(2000));
+        assert!(props.page_index_enabled());
         assert_eq!(props.created_by(), "default");
         assert_eq!(
             props.key_value_metadata(),
@@ -638,9 +1020,17 @@ mod tests {
             Some(Encoding::DELTA_BINARY_PACKED)
         );
         assert_eq!(props.compression(&ColumnPath::from("a")), Compression::GZIP);
+        assert_eq!(props.compression_level(&ColumnPath::from("a")), Some(9));
         assert_eq!(props.dictionary_enabled(&ColumnPath::from("a")), false);
-        assert_eq!(props.statistics_enabled(&ColumnPath::from("a")), false);
+        assert_eq!(
+            props.statistics_enabled(&ColumnPath::from("a")),
+            EnabledStatistics::None
+        );
         assert_eq!(props.max_statistics_size(&ColumnPath::from("a")), 50);
+        assert_eq!(
+            props.statistics_truncate_length(&ColumnPath::from("a")),
+            Some(16)
+        );
 
         assert_eq!(
             props.encoding(&ColumnPath::from("col")),
@@ -648,11 +1038,48 @@ mod tests {
         );
         assert_eq!(
             props.compression(&ColumnPath::from("col")),
-            Compression::SNAPPY
+            Compression::ZSTD
         );
+        assert_eq!(props.compression_level(&ColumnPath::from("col")), Some(15));
         assert_eq!(props.dictionary_enabled(&ColumnPath::from("col")), true);
-        assert_eq!(props.statistics_enabled(&ColumnPath::from("col")), true);
+        assert_eq!(
+            props.statistics_enabled(&ColumnPath::from("col")),
+            EnabledStatistics::Chunk
+        );
         assert_eq!(props.max_statistics_size(&ColumnPath::from("col")), 123);
+        assert_eq!(
+            props.statistics_truncate_length(&ColumnPath::from("col")),
+            Some(8)
+        );
+    }
+
+    #[test]
+    fn test_writer_properties_compression_level() {
+        let err = WriterProperties::builder()
+            .set_compression_with_level(Compression::GZIP, 15)
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Parquet error: Invalid compression level 15 for codec GZIP, expected a level in 0..=9"
+        );
+
+        // the global level only applies to the global codec
+        let props = WriterProperties::builder()
+            .set_compression_with_level(Compression::ZSTD, 15)
+            .unwrap()
+            .set_column_compression(ColumnPath::from("col"), Compression::GZIP)
+            .build();
+        assert_eq!(props.compression_level(&ColumnPath::from("a")), Some(15));
+        assert_eq!(props.compression_level(&ColumnPath::from("col")), None);
+
+        // setting the codec alone resets the level to the codec's default
+        let props = WriterProperties::builder()
+            .set_compression_with_level(Compression::ZSTD, 15)
+            .unwrap()
+            .set_compression(Compression::GZIP)
+            .build();
+        assert_eq!(props.compression_level(&ColumnPath::from("a")), None);
     }
 
     #[test]
@@ -676,4 +1103,30 @@ mod tests {
             DEFAULT_DICTIONARY_ENABLED
         );
     }
+
+    #[test]
+    fn test_writer_properties_bloom_filter() {
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .set_bloom_filter_ndv(100)
+            .set_column_bloom_filter_fpp(ColumnPath::from("col"), 0.1)
+            .set_column_bloom_filter_enabled(ColumnPath::from("other"), false)
+            .build();
+
+        assert_eq!(
+            props.bloom_filter_properties(&ColumnPath::from("col")),
+            Some(BloomFilterProperties { fpp: 0.1, ndv: 100 })
+        );
+        assert_eq!(
+            props.bloom_filter_properties(&ColumnPath::from("a")),
+            Some(BloomFilterProperties {
+                fpp: DEFAULT_BLOOM_FILTER_FPP,
+                ndv: 100
+            })
+        );
+        assert_eq!(
+            props.bloom_filter_properties(&ColumnPath::from("other")),
+            None
+        );
+    }
 }
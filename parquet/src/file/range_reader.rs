@@ -0,0 +1,219 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains a [`ChunkReader`] over a source of byte ranges, such as an object in an
+//! object store.
+//!
+//! Each read of the Parquet reader is mapped to a single range request: the metadata
+//! is read with one request from the end of the file (two if it is larger than the
+//! initial read), the page index with one request per row group, and each column
+//! chunk with one request, so the file never has to be copied locally.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::ops::Range;
+//!
+//! use parquet::errors::Result;
+//! use parquet::file::range_reader::{RangeChunkReader, RangeRead};
+//! use parquet::file::reader::{FileReader, SerializedFileReader};
+//!
+//! struct Object {
+//!     data: Vec<u8>,
+//! }
+//!
+//! impl RangeRead for Object {
+//!     fn size(&self) -> Result<u64> {
+//!         Ok(self.data.len() as u64)
+//!     }
+//!
+//!     fn read_range(&self, range: Range<u64>) -> Result<Vec<u8>> {
+//!         Ok(self.data[range.start as usize..range.end as usize].to_vec())
+//!     }
+//! }
+//!
+//! let object = Object { data: std::fs::read("data.parquet").unwrap() };
+//! let reader = SerializedFileReader::new(RangeChunkReader::try_new(object).unwrap())
+//!     .unwrap();
+//! println!("{} rows", reader.metadata().file_metadata().num_rows());
+//! ```
+
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::errors::{ParquetError, Result};
+use crate::file::reader::{ChunkReader, Length};
+use crate::util::cursor::SliceableCursor;
+
+/// A source that can fetch byte ranges, such as an object in an object store.
+pub trait RangeRead {
+    /// Returns the size of the source in bytes.
+    fn size(&self) -> Result<u64>;
+
+    /// Fetches the bytes in `range`, which is within the size of the source.
+    fn read_range(&self, range: Range<u64>) -> Result<Vec<u8>>;
+}
+
+impl<R: RangeRead + ?Sized> RangeRead for Arc<R> {
+    fn size(&self) -> Result<u64> {
+        self.as_ref().size()
+    }
+
+    fn read_range(&self, range: Range<u64>) -> Result<Vec<u8>> {
+        self.as_ref().read_range(range)
+    }
+}
+
+impl<R: RangeRead + ?Sized> RangeRead for Box<R> {
+    fn size(&self) -> Result<u64> {
+        self.as_ref().size()
+    }
+
+    fn read_range(&self, range: Range<u64>) -> Result<Vec<u8>> {
+        self.as_ref().read_range(range)
+    }
+}
+
+/// A [`ChunkReader`] that maps every read to a single range request of a
+/// [`RangeRead`].
+pub struct RangeChunkReader<R: RangeRead> {
+    inner: R,
+    size: u64,
+}
+
+impl<R: RangeRead> RangeChunkReader<R> {
+    /// Creates a new reader of `inner`, fetching its size.
+    pub fn try_new(inner: R) -> Result<Self> {
+        let size = inner.size()?;
+        Ok(Self { inner, size })
+    }
+
+    /// Creates a new reader of `inner`, which is `size` bytes long. Use this if the
+    /// size is already known, e.g. from listing the object store, to save a request.
+    pub fn with_size(inner: R, size: u64) -> Self {
+        Self { inner, size }
+    }
+
+    /// Returns the underlying source.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: RangeRead> Length for RangeChunkReader<R> {
+    fn len(&self) -> u64 {
+        self.size
+    }
+}
+
+impl<R: RangeRead> ChunkReader for RangeChunkReader<R> {
+    type T = SliceableCursor;
+
+    fn get_read(&self, start: u64, length: usize) -> Result<Self::T> {
+        self.get_bytes(start, length).map(SliceableCursor::new)
+    }
+
+    fn get_bytes(&self, start: u64, length: usize) -> Result<Vec<u8>> {
+        let end = start + length as u64;
+        if end > self.size {
+            return Err(eof_err!(
+                "Range {}..{} is out of bounds of a source of {} bytes",
+                start,
+                end,
+                self.size
+            ));
+        }
+        let data = self.inner.read_range(start..end)?;
+        if data.len() != length {
+            return Err(general_err!(
+                "Expected {} bytes for range {}..{}, got {}",
+                length,
+                start,
+                end,
+                data.len()
+            ));
+        }
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Read;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::file::reader::{FileReader, SerializedFileReader};
+    use crate::util::test_common::get_test_file;
+
+    struct CountingObject {
+        data: Vec<u8>,
+        requests: AtomicUsize,
+    }
+
+    impl RangeRead for CountingObject {
+        fn size(&self) -> Result<u64> {
+            Ok(self.data.len() as u64)
+        }
+
+        fn read_range(&self, range: Range<u64>) -> Result<Vec<u8>> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            Ok(self.data[range.start as usize..range.end as usize].to_vec())
+        }
+    }
+
+    #[test]
+    fn test_range_chunk_reader() {
+        let mut data = vec![];
+        get_test_file("alltypes_plain.parquet")
+            .read_to_end(&mut data)
+            .unwrap();
+        let object = Arc::new(CountingObject {
+            data,
+            requests: AtomicUsize::new(0),
+        });
+
+        let reader =
+            SerializedFileReader::new(RangeChunkReader::try_new(object.clone()).unwrap())
+                .unwrap();
+        // the whole metadata is read with a single request
+        assert_eq!(object.requests.load(Ordering::SeqCst), 1);
+
+        let num_columns = reader.metadata().row_group(0).num_columns();
+        assert_eq!(reader.get_row_iter(None).unwrap().count(), 8);
+        // each column chunk is read with a single request
+        assert_eq!(object.requests.load(Ordering::SeqCst), 1 + num_columns);
+    }
+
+    #[test]
+    fn test_range_chunk_reader_out_of_bounds() {
+        let object = CountingObject {
+            data: vec![0; 10],
+            requests: AtomicUsize::new(0),
+        };
+        let reader = RangeChunkReader::try_new(object).unwrap();
+        assert_eq!(reader.get_bytes(2, 4).unwrap(), vec![0; 4]);
+
+        let err = reader.get_bytes(8, 4).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "EOF: Range 8..12 is out of bounds of a source of 10 bytes"
+        );
+        assert_eq!(reader.into_inner().requests.load(Ordering::SeqCst), 1);
+    }
+}
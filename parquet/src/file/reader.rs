@@ -48,6 +48,28 @@ pub trait ChunkReader: Length {
     /// get a serialy readeable slice of the current reader
     /// This should fail if the slice exceeds the current bounds
     fn get_read(&self, start: u64, length: usize) -> Result<Self::T>;
+
+    /// Returns the `length` bytes starting at `start`.
+    ///
+    /// The metadata and page index are read with this method, which by default reads
+    /// the bytes from [`get_read`](Self::get_read). Implementations backed by an object
+    /// store may override it to fetch the bytes with a single range request.
+    fn get_bytes(&self, start: u64, length: usize) -> Result<Vec<u8>> {
+        let mut buffer = Vec::with_capacity(length);
+        let read = self
+            .get_read(start, length)?
+            .take(length as u64)
+            .read_to_end(&mut buffer)?;
+        if read != length {
+            return Err(eof_err!(
+                "Expected to read {} bytes at offset {}, read only {}",
+                length,
+                start,
+                read
+            ));
+        }
+        Ok(buffer)
+    }
 }
 
 // ----------------------------------------------------------------------
@@ -18,16 +18,34 @@
 //! Contains implementations of the reader traits FileReader, RowGroupReader and PageReader
 //! Also contains implementations of the ChunkReader for files (with buffering) and byte arrays (RAM)
 
-use std::{convert::TryFrom, fs::File, io::Read, path::Path, sync::Arc};
+use std::{
+    collections::VecDeque,
+    convert::TryFrom,
+    fs::File,
+    io::{self, Read},
+    ops::Range,
+    path::Path,
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread,
+};
 
 use parquet_format::{PageHeader, PageType};
 use thrift::protocol::TCompactInputProtocol;
 
 use crate::basic::{Compression, Encoding, Type};
-use crate::column::page::{Page, PageReader};
+use crate::column::page::{Page, PageMetadata, PageReader};
 use crate::compression::{create_codec, Codec};
+#[cfg(feature = "encryption")]
+use crate::encryption::{
+    ciphers::{encrypted_module_len, SIZE_LEN},
+    decrypt::{FileDecryptionProperties, FileDecryptor, PageDecryptor},
+};
 use crate::errors::{ParquetError, Result};
-use crate::file::{footer, metadata::*, reader::*, statistics};
+use crate::file::page_index::PageLocation;
+use crate::file::{footer, metadata::*, page_index::index_reader, reader::*, statistics};
 use crate::record::reader::RowIter;
 use crate::record::Row;
 use crate::schema::types::Type as SchemaType;
@@ -125,6 +143,66 @@ impl IntoIterator for SerializedFileReader<File> {
 pub struct SerializedFileReader<R: ChunkReader> {
     chunk_reader: Arc<R>,
     metadata: ParquetMetaData,
+    /// The rows of each row group whose data pages are skipped
+    skipped_rows: Vec<Vec<Range<usize>>>,
+    /// The threads that decompress the pages read ahead by the page readers, if any
+    prefetch_pool: Option<PagePrefetchPool>,
+}
+
+/// Options of a [`SerializedFileReader`], created with [`ReadOptions::builder`].
+#[derive(Debug, Clone, Default)]
+pub struct ReadOptions {
+    page_index_enabled: bool,
+    prefetch_threads: usize,
+}
+
+impl ReadOptions {
+    /// Returns a builder of read options, with the default values.
+    pub fn builder() -> ReadOptionsBuilder {
+        ReadOptionsBuilder::default()
+    }
+
+    /// Returns whether the page index of each column chunk is read into the metadata.
+    pub fn page_index_enabled(&self) -> bool {
+        self.page_index_enabled
+    }
+
+    /// Returns the number of background threads that decompress the pages read ahead
+    /// by the page readers, `0` if the pages aren't read ahead.
+    pub fn prefetch_threads(&self) -> usize {
+        self.prefetch_threads
+    }
+}
+
+/// Builder of [`ReadOptions`].
+#[derive(Debug, Default)]
+pub struct ReadOptionsBuilder {
+    options: ReadOptions,
+}
+
+impl ReadOptionsBuilder {
+    /// Finalizes the configuration and returns the read options.
+    pub fn build(self) -> ReadOptions {
+        self.options
+    }
+
+    /// Sets whether the page index of each column chunk is read into the metadata, see
+    /// [`ParquetMetaData::page_indexes`] (defaults to `false`).
+    pub fn set_page_index_enabled(mut self, value: bool) -> Self {
+        self.options.page_index_enabled = value;
+        self
+    }
+
+    /// Sets the number of background threads that decompress the pages the page readers
+    /// read ahead, while the current pages are decoded (defaults to `0`, which disables
+    /// reading ahead).
+    ///
+    /// The threads are shared by all the page readers of the file, see
+    /// [`PagePrefetchPool`].
+    pub fn set_prefetch_threads(mut self, value: usize) -> Self {
+        self.options.prefetch_threads = value;
+        self
+    }
 }
 
 impl<R: 'static + ChunkReader> SerializedFileReader<R> {
@@ -135,6 +213,69 @@ impl<R: 'static + ChunkReader> SerializedFileReader<R> {
         Ok(Self {
             chunk_reader: Arc::new(chunk_reader),
             metadata,
+            skipped_rows: vec![],
+            prefetch_pool: None,
+        })
+    }
+
+    /// Creates file reader from a Parquet file, also reading the page index of each
+    /// column chunk into its metadata, see [`ParquetMetaData::page_indexes`].
+    /// Returns error if Parquet file does not exist or is corrupt.
+    pub fn new_with_page_index(chunk_reader: R) -> Result<Self> {
+        let metadata = footer::parse_metadata(&chunk_reader)?;
+        let metadata = index_reader::read_page_index(&chunk_reader, metadata)?;
+        Ok(Self {
+            chunk_reader: Arc::new(chunk_reader),
+            metadata,
+            skipped_rows: vec![],
+            prefetch_pool: None,
+        })
+    }
+
+    /// Creates file reader from a Parquet file that may be encrypted, decrypting it
+    /// with `decryption_properties`.
+    /// Returns error if Parquet file does not exist, is corrupt or cannot be decrypted.
+    #[cfg(feature = "encryption")]
+    pub fn new_with_decryption(
+        chunk_reader: R,
+        decryption_properties: &FileDecryptionProperties,
+    ) -> Result<Self> {
+        let metadata =
+            footer::parse_metadata_with_decryption(&chunk_reader, decryption_properties)?;
+        Ok(Self {
+            chunk_reader: Arc::new(chunk_reader),
+            metadata,
+            skipped_rows: vec![],
+            prefetch_pool: None,
+        })
+    }
+
+    /// Creates file reader from a Parquet file whose metadata has already been read,
+    /// e.g. when `chunk_reader` only contains some of the file's column chunks.
+    pub fn new_with_metadata(chunk_reader: R, metadata: ParquetMetaData) -> Self {
+        Self {
+            chunk_reader: Arc::new(chunk_reader),
+            metadata,
+            skipped_rows: vec![],
+            prefetch_pool: None,
+        }
+    }
+
+    /// Creates file reader from a Parquet file, with `options`.
+    /// Returns error if Parquet file does not exist or is corrupt.
+    pub fn new_with_options(chunk_reader: R, options: ReadOptions) -> Result<Self> {
+        let mut metadata = footer::parse_metadata(&chunk_reader)?;
+        if options.page_index_enabled {
+            metadata = index_reader::read_page_index(&chunk_reader, metadata)?;
+        }
+        Ok(Self {
+            chunk_reader: Arc::new(chunk_reader),
+            metadata,
+            skipped_rows: vec![],
+            prefetch_pool: match options.prefetch_threads {
+                0 => None,
+                num_threads => Some(PagePrefetchPool::new(num_threads)),
+            },
         })
     }
 
@@ -144,16 +285,32 @@ impl<R: 'static + ChunkReader> SerializedFileReader<R> {
         &mut self,
         predicate: &dyn Fn(&RowGroupMetaData, usize) -> bool,
     ) {
-        let mut filtered_row_groups = Vec::<RowGroupMetaData>::new();
-        for (i, row_group_metadata) in self.metadata.row_groups().iter().enumerate() {
-            if predicate(row_group_metadata, i) {
-                filtered_row_groups.push(row_group_metadata.clone());
-            }
-        }
-        self.metadata = ParquetMetaData::new(
-            self.metadata.file_metadata().clone(),
-            filtered_row_groups,
-        );
+        let filtered_row_groups: Vec<usize> = (0..self.metadata.num_row_groups())
+            .filter(|i| predicate(self.metadata.row_group(*i), *i))
+            .collect();
+        self.metadata = self.metadata.select_row_groups(&filtered_row_groups);
+    }
+
+    /// Skips the data pages of each row group that only contain rows in
+    /// `skipped_rows`, using the offset index in the metadata to locate them.
+    ///
+    /// The ranges must start and end on a page boundary of every column chunk of
+    /// the row group, so that all columns skip the same rows.
+    #[cfg(any(feature = "arrow", test))]
+    pub(crate) fn set_skipped_rows(&mut self, skipped_rows: Vec<Vec<Range<usize>>>) {
+        self.skipped_rows = skipped_rows;
+    }
+
+    /// Returns the reader of the file's bytes
+    #[cfg(feature = "parallel")]
+    pub(crate) fn chunk_reader(&self) -> &R {
+        &self.chunk_reader
+    }
+
+    /// Returns the rows of each row group whose data pages are skipped
+    #[cfg(feature = "parallel")]
+    pub(crate) fn skipped_rows(&self) -> &[Vec<Range<usize>>] {
+        &self.skipped_rows
     }
 }
 
@@ -170,10 +327,19 @@ impl<R: 'static + ChunkReader> FileReader for SerializedFileReader<R> {
         let row_group_metadata = self.metadata.row_group(i);
         // Row groups should be processed sequentially.
         let f = Arc::clone(&self.chunk_reader);
-        Ok(Box::new(SerializedRowGroupReader::new(
-            f,
-            row_group_metadata,
-        )))
+        let mut row_group_reader = SerializedRowGroupReader::new(f, row_group_metadata);
+        row_group_reader.prefetch_pool = self.prefetch_pool.as_ref();
+        if let (Some(skipped_rows), Some(offset_indexes)) =
+            (self.skipped_rows.get(i), self.metadata.offset_indexes())
+        {
+            row_group_reader.skipped_pages =
+                Some((skipped_rows.as_slice(), offset_indexes[i].as_slice()));
+        }
+        #[cfg(feature = "encryption")]
+        {
+            row_group_reader.file_decryptor = self.metadata.file_decryptor().cloned();
+        }
+        Ok(Box::new(row_group_reader))
     }
 
     fn get_row_iter(&self, projection: Option<SchemaType>) -> Result<RowIter> {
@@ -185,6 +351,13 @@ impl<R: 'static + ChunkReader> FileReader for SerializedFileReader<R> {
 pub struct SerializedRowGroupReader<'a, R: ChunkReader> {
     chunk_reader: Arc<R>,
     metadata: &'a RowGroupMetaData,
+    /// The skipped rows and the page locations of each column
+    skipped_pages: Option<(&'a [Range<usize>], &'a [Vec<PageLocation>])>,
+    /// The threads that decompress the pages read ahead by the page readers, if any
+    prefetch_pool: Option<&'a PagePrefetchPool>,
+    /// The decryptor of an encrypted file
+    #[cfg(feature = "encryption")]
+    file_decryptor: Option<Arc<FileDecryptor>>,
 }
 
 impl<'a, R: ChunkReader> SerializedRowGroupReader<'a, R> {
@@ -193,6 +366,10 @@ impl<'a, R: ChunkReader> SerializedRowGroupReader<'a, R> {
         Self {
             chunk_reader,
             metadata,
+            skipped_pages: None,
+            prefetch_pool: None,
+            #[cfg(feature = "encryption")]
+            file_decryptor: None,
         }
     }
 }
@@ -211,12 +388,38 @@ impl<'a, R: 'static + ChunkReader> RowGroupReader for SerializedRowGroupReader<'
         let col = self.metadata.column(i);
         let (col_start, col_length) = col.byte_range();
         let file_chunk = self.chunk_reader.get_read(col_start, col_length as usize)?;
-        let page_reader = SerializedPageReader::new(
+        let mut page_reader = SerializedPageReader::new(
             file_chunk,
             col.num_values(),
             col.compression(),
             col.column_descr().physical_type(),
         )?;
+        if let Some((skipped_rows, page_locations)) = self.skipped_pages {
+            page_reader.skipped_pages = page_locations[i]
+                .iter()
+                .enumerate()
+                .filter(|(_, page)| {
+                    let row = page.first_row_index as usize;
+                    skipped_rows.iter().any(|range| range.contains(&row))
+                })
+                .map(|(idx, _)| idx)
+                .collect();
+        }
+        #[cfg(feature = "encryption")]
+        if let Some(file_decryptor) = &self.file_decryptor {
+            if let Some(decryptor) = file_decryptor.column_decryptor(col.column_path()) {
+                page_reader.page_decryptor = Some(PageDecryptor::new(
+                    file_decryptor,
+                    decryptor,
+                    self.metadata.ordinal().unwrap_or_default() as usize,
+                    i,
+                    col.has_dictionary_page(),
+                ));
+            }
+        }
+        if let Some(pool) = self.prefetch_pool {
+            page_reader.enable_prefetch(pool);
+        }
         Ok(Box::new(page_reader))
     }
 
@@ -231,6 +434,9 @@ pub struct SerializedPageReader<T: Read> {
     // to be read by this page reader.
     buf: T,
 
+    // The compression of this column chunk.
+    compression: Compression,
+
     // The compression codec for this column chunk. Only set for non-PLAIN codec.
     decompressor: Option<Box<dyn Codec>>,
 
@@ -242,6 +448,22 @@ pub struct SerializedPageReader<T: Read> {
 
     // Column chunk type.
     physical_type: Type,
+
+    // The number of data pages we have seen so far.
+    seen_num_data_pages: usize,
+
+    // The indices of the data pages to skip without decompressing them, in order.
+    skipped_pages: VecDeque<usize>,
+
+    // The header of the next page, if it was peeked at but the page wasn't read yet.
+    next_page_header: Option<PageHeader>,
+
+    // The pages read ahead and decompressed on background threads, if enabled.
+    prefetcher: Option<PagePrefetcher>,
+
+    // The decryptor of the pages of an encrypted column chunk.
+    #[cfg(feature = "encryption")]
+    page_decryptor: Option<PageDecryptor>,
 }
 
 impl<T: Read> SerializedPageReader<T> {
@@ -257,136 +479,460 @@ impl<T: Read> SerializedPageReader<T> {
             buf,
             total_num_values,
             seen_num_values: 0,
+            compression,
             decompressor,
             physical_type,
+            seen_num_data_pages: 0,
+            skipped_pages: VecDeque::new(),
+            next_page_header: None,
+            prefetcher: None,
+            #[cfg(feature = "encryption")]
+            page_decryptor: None,
         };
         Ok(result)
     }
 
     /// Reads Page header from Thrift.
     fn read_page_header(&mut self) -> Result<PageHeader> {
+        #[cfg(feature = "encryption")]
+        if let Some(page_decryptor) = &self.page_decryptor {
+            let module = read_encrypted_module(&mut self.buf)?;
+            let header = page_decryptor.decrypt_page_header(&module)?;
+            let mut prot = TCompactInputProtocol::new(header.as_slice());
+            return Ok(PageHeader::read_from_in_protocol(&mut prot)?);
+        }
+
         let mut prot = TCompactInputProtocol::new(&mut self.buf);
         let page_header = PageHeader::read_from_in_protocol(&mut prot)?;
         Ok(page_header)
     }
-}
 
-impl<T: Read> PageReader for SerializedPageReader<T> {
-    fn get_next_page(&mut self) -> Result<Option<Page>> {
-        while self.seen_num_values < self.total_num_values {
-            let page_header = self.read_page_header()?;
+    /// Reads the pages ahead and decompresses them on the threads of `pool`, so that
+    /// the next page is decompressed while the current one is decoded.
+    pub fn enable_prefetch(&mut self, pool: &PagePrefetchPool) {
+        if self.prefetcher.is_none() {
+            self.prefetcher = Some(pool.prefetcher(self.compression, self.physical_type));
+        }
+    }
 
-            // When processing data page v2, depending on enabled compression for the
-            // page, we should account for uncompressed data ('offset') of
-            // repetition and definition levels.
-            //
-            // We always use 0 offset for other pages other than v2, `true` flag means
-            // that compression will be applied if decompressor is defined
-            let mut offset: usize = 0;
-            let mut can_decompress = true;
-
-            if let Some(ref header_v2) = page_header.data_page_header_v2 {
-                offset = (header_v2.definition_levels_byte_length
-                    + header_v2.repetition_levels_byte_length)
-                    as usize;
-                // When is_compressed flag is missing the page is considered compressed
-                can_decompress = header_v2.is_compressed.unwrap_or(true);
-            }
+    /// Skips the page with `page_header` without decompressing it.
+    fn skip_page(&mut self, page_header: &PageHeader) -> Result<()> {
+        #[cfg(feature = "encryption")]
+        if let Some(page_decryptor) = self.page_decryptor.as_mut() {
+            page_decryptor.finish_page(page_header.type_);
+        }
 
-            let compressed_len = page_header.compressed_page_size as usize - offset;
-            let uncompressed_len = page_header.uncompressed_page_size as usize - offset;
-            // We still need to read all bytes from buffered stream
-            let mut buffer = vec![0; offset + compressed_len];
-            self.buf.read_exact(&mut buffer)?;
-
-            // TODO: page header could be huge because of statistics. We should set a
-            // maximum page header size and abort if that is exceeded.
-            if let Some(decompressor) = self.decompressor.as_mut() {
-                if can_decompress {
-                    let mut decompressed_buffer = Vec::with_capacity(uncompressed_len);
-                    let decompressed_size = decompressor
-                        .decompress(&buffer[offset..], &mut decompressed_buffer)?;
-                    if decompressed_size != uncompressed_len {
-                        return Err(general_err!(
-              "Actual decompressed size doesn't match the expected one ({} vs {})",
-              decompressed_size,
-              uncompressed_len
+        let len = page_header.compressed_page_size as u64;
+        let skipped = io::copy(&mut (&mut self.buf).take(len), &mut io::sink())?;
+        if skipped != len {
+            return Err(eof_err!(
+                "Expected to skip {} bytes of page data, skipped {}",
+                len,
+                skipped
             ));
-                    }
-                    if offset == 0 {
-                        buffer = decompressed_buffer;
-                    } else {
-                        // Prepend saved offsets to the buffer
-                        buffer.truncate(offset);
-                        buffer.append(&mut decompressed_buffer);
-                    }
-                }
-            }
+        }
+        Ok(())
+    }
 
-            let result = match page_header.type_ {
-                PageType::DictionaryPage => {
-                    assert!(page_header.dictionary_page_header.is_some());
-                    let dict_header =
-                        page_header.dictionary_page_header.as_ref().unwrap();
-                    let is_sorted = dict_header.is_sorted.unwrap_or(false);
-                    Page::DictionaryPage {
-                        buf: ByteBufferPtr::new(buffer),
-                        num_values: dict_header.num_values as u32,
-                        encoding: Encoding::from(dict_header.encoding),
-                        is_sorted,
-                    }
-                }
-                PageType::DataPage => {
-                    assert!(page_header.data_page_header.is_some());
-                    let header = page_header.data_page_header.unwrap();
-                    self.seen_num_values += header.num_values as i64;
-                    Page::DataPage {
-                        buf: ByteBufferPtr::new(buffer),
-                        num_values: header.num_values as u32,
-                        encoding: Encoding::from(header.encoding),
-                        def_level_encoding: Encoding::from(
-                            header.definition_level_encoding,
-                        ),
-                        rep_level_encoding: Encoding::from(
-                            header.repetition_level_encoding,
-                        ),
-                        statistics: statistics::from_thrift(
-                            self.physical_type,
-                            header.statistics,
-                        ),
-                    }
-                }
-                PageType::DataPageV2 => {
-                    assert!(page_header.data_page_header_v2.is_some());
-                    let header = page_header.data_page_header_v2.unwrap();
-                    let is_compressed = header.is_compressed.unwrap_or(true);
-                    self.seen_num_values += header.num_values as i64;
-                    Page::DataPageV2 {
-                        buf: ByteBufferPtr::new(buffer),
-                        num_values: header.num_values as u32,
-                        encoding: Encoding::from(header.encoding),
-                        num_nulls: header.num_nulls as u32,
-                        num_rows: header.num_rows as u32,
-                        def_levels_byte_len: header.definition_levels_byte_length as u32,
-                        rep_levels_byte_len: header.repetition_levels_byte_length as u32,
-                        is_compressed,
-                        statistics: statistics::from_thrift(
-                            self.physical_type,
-                            header.statistics,
-                        ),
+    /// Returns the header of the next page that isn't skipped, reading it if it wasn't
+    /// peeked at yet, or `None` at the end of the column chunk.
+    fn peek_page_header(&mut self) -> Result<Option<&PageHeader>> {
+        while self.next_page_header.is_none()
+            && self.seen_num_values < self.total_num_values
+        {
+            let page_header = self.read_page_header()?;
+
+            match page_header.type_ {
+                PageType::DataPage | PageType::DataPageV2 => {
+                    self.seen_num_values += data_page_num_values(&page_header)? as i64;
+                    let page_idx = self.seen_num_data_pages;
+                    self.seen_num_data_pages += 1;
+                    if self.skipped_pages.front() == Some(&page_idx) {
+                        self.skipped_pages.pop_front();
+                        self.skip_page(&page_header)?;
+                        continue;
                     }
                 }
+                PageType::DictionaryPage => {}
+                // Pages of other types, e.g. INDEX_PAGE, are not read
                 _ => {
-                    // For unknown page type (e.g., INDEX_PAGE), skip and read next.
+                    self.skip_page(&page_header)?;
                     continue;
                 }
-            };
-            return Ok(Some(result));
+            }
+
+            self.next_page_header = Some(page_header);
         }
 
-        // We are at the end of this column chunk and no more page left. Return None.
+        // We are at the end of this column chunk if no page header is left.
+        Ok(self.next_page_header.as_ref())
+    }
+
+    /// Reads the header and the compressed bytes of the next page that isn't skipped,
+    /// or returns `None` at the end of the column chunk.
+    fn read_next_page(&mut self) -> Result<Option<(PageHeader, Vec<u8>)>> {
+        if self.peek_page_header()?.is_none() {
+            return Ok(None);
+        }
+        let page_header = self.next_page_header.take().unwrap();
+
+        // We still need to read all bytes from buffered stream
+        let mut buffer = vec![0; page_header.compressed_page_size as usize];
+        self.buf.read_exact(&mut buffer)?;
+
+        #[cfg(feature = "encryption")]
+        if let Some(page_decryptor) = self.page_decryptor.as_mut() {
+            buffer = page_decryptor.decrypt_page(page_header.type_, &buffer)?;
+        }
+        Ok(Some((page_header, buffer)))
+    }
+
+    /// Returns the next page decompressed by the prefetcher, reading the pages after it
+    /// ahead so that they are decompressed in the meantime.
+    fn get_next_prefetched_page(&mut self) -> Result<Option<Page>> {
+        loop {
+            while self.prefetcher.as_ref().unwrap().pending.len() < PREFETCHED_PAGES {
+                match self.read_next_page()? {
+                    Some((page_header, buffer)) => self
+                        .prefetcher
+                        .as_mut()
+                        .unwrap()
+                        .send(page_header, buffer)?,
+                    None => break,
+                }
+            }
+            let prefetcher = self.prefetcher.as_mut().unwrap();
+            if prefetcher.pending.is_empty() {
+                return Ok(None);
+            }
+            if let Some(page) = prefetcher.recv()? {
+                return Ok(Some(page));
+            }
+        }
+    }
+}
+
+impl<T: Read> PageReader for SerializedPageReader<T> {
+    fn get_next_page(&mut self) -> Result<Option<Page>> {
+        if self.prefetcher.is_some() {
+            return self.get_next_prefetched_page();
+        }
+        while let Some((page_header, buffer)) = self.read_next_page()? {
+            let decompressor = self.decompressor.as_deref_mut();
+            if let Some(page) =
+                decode_page(page_header, buffer, decompressor, self.physical_type)?
+            {
+                return Ok(Some(page));
+            }
+        }
         Ok(None)
     }
+
+    fn peek_next_page(&mut self) -> Result<Option<PageMetadata>> {
+        if self.prefetcher.is_some() {
+            if self.prefetcher.as_ref().unwrap().pending.is_empty() {
+                if let Some((page_header, buffer)) = self.read_next_page()? {
+                    self.prefetcher
+                        .as_mut()
+                        .unwrap()
+                        .send(page_header, buffer)?;
+                }
+            }
+            let prefetcher = self.prefetcher.as_ref().unwrap();
+            return Ok(prefetcher
+                .pending
+                .front()
+                .map(|(metadata, _)| metadata.clone()));
+        }
+        self.peek_page_header()?.map(page_metadata).transpose()
+    }
+
+    fn skip_next_page(&mut self) -> Result<()> {
+        // A page that was read ahead is dropped once the prefetcher decompressed it
+        if let Some(prefetcher) = self.prefetcher.as_mut() {
+            if prefetcher.pending.pop_front().is_some() {
+                return Ok(());
+            }
+        }
+        if self.peek_page_header()?.is_some() {
+            let page_header = self.next_page_header.take().unwrap();
+            self.skip_page(&page_header)?;
+        }
+        Ok(())
+    }
+}
+
+/// The number of pages read ahead of the page returned by a [`SerializedPageReader`]
+/// that prefetches its pages, including that page.
+const PREFETCHED_PAGES: usize = 2;
+
+/// A bounded pool of background threads that decompress and decode the pages read
+/// ahead by [`SerializedPageReader`]s, shared by the page readers of a file so that
+/// the number of threads doesn't depend on the number of columns read.
+///
+/// The threads stop once the pool and the page readers using it have been dropped.
+pub struct PagePrefetchPool {
+    sender: Mutex<Sender<PrefetchTask>>,
+}
+
+/// A page sent to a [`PagePrefetchPool`], and the channel its result is sent to
+struct PrefetchTask {
+    page_header: PageHeader,
+    buffer: Vec<u8>,
+    compression: Compression,
+    physical_type: Type,
+    result: Sender<Result<Option<Page>>>,
+}
+
+impl PagePrefetchPool {
+    /// Creates a pool of `num_threads` threads, or of a single thread if it is `0`.
+    pub fn new(num_threads: usize) -> Self {
+        let (sender, tasks) = channel::<PrefetchTask>();
+        let tasks = Arc::new(Mutex::new(tasks));
+        for _ in 0..num_threads.max(1) {
+            let tasks = tasks.clone();
+            thread::spawn(move || {
+                // the codecs can't be sent between threads, so each thread creates its
+                // own, once for each compression
+                let mut codecs: Vec<(Compression, Option<Box<dyn Codec>>)> = vec![];
+                loop {
+                    let task = match tasks.lock() {
+                        Ok(tasks) => tasks.recv(),
+                        Err(_) => break,
+                    };
+                    let task = match task {
+                        Ok(task) => task,
+                        // the pool and all its page readers have been dropped
+                        Err(_) => break,
+                    };
+                    let idx = codecs.iter().position(|(c, _)| *c == task.compression);
+                    let idx = match idx {
+                        Some(idx) => idx,
+                        None => match create_codec(task.compression) {
+                            Ok(codec) => {
+                                codecs.push((task.compression, codec));
+                                codecs.len() - 1
+                            }
+                            Err(e) => {
+                                let _ = task.result.send(Err(e));
+                                continue;
+                            }
+                        },
+                    };
+                    let page = decode_page(
+                        task.page_header,
+                        task.buffer,
+                        codecs[idx].1.as_deref_mut(),
+                        task.physical_type,
+                    );
+                    // the page reader may have been dropped in the meantime
+                    let _ = task.result.send(page);
+                }
+            });
+        }
+        Self {
+            sender: Mutex::new(sender),
+        }
+    }
+
+    /// Returns a prefetcher of the pages of a column chunk with `compression` and
+    /// `physical_type`, which sends them to this pool.
+    fn prefetcher(
+        &self,
+        compression: Compression,
+        physical_type: Type,
+    ) -> PagePrefetcher {
+        let sender = match self.sender.lock() {
+            Ok(sender) => sender.clone(),
+            Err(poisoned) => poisoned.into_inner().clone(),
+        };
+        PagePrefetcher {
+            sender,
+            compression,
+            physical_type,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+/// Sends the pages of a [`SerializedPageReader`] to a [`PagePrefetchPool`], and
+/// receives them decompressed in the order they were sent.
+struct PagePrefetcher {
+    sender: Sender<PrefetchTask>,
+    compression: Compression,
+    physical_type: Type,
+    // The metadata and the results of the pages sent to the pool that weren't received
+    // yet, in order.
+    pending: VecDeque<(PageMetadata, Receiver<Result<Option<Page>>>)>,
+}
+
+impl PagePrefetcher {
+    fn send(&mut self, page_header: PageHeader, buffer: Vec<u8>) -> Result<()> {
+        let metadata = page_metadata(&page_header)?;
+        let (result, receiver) = channel();
+        let task = PrefetchTask {
+            page_header,
+            buffer,
+            compression: self.compression,
+            physical_type: self.physical_type,
+            result,
+        };
+        self.sender
+            .send(task)
+            .map_err(|_| general_err!("The page prefetching threads have stopped"))?;
+        self.pending.push_back((metadata, receiver));
+        Ok(())
+    }
+
+    fn recv(&mut self) -> Result<Option<Page>> {
+        let receiver = match self.pending.pop_front() {
+            Some((_, receiver)) => receiver,
+            None => return Ok(None),
+        };
+        receiver
+            .recv()
+            .map_err(|_| general_err!("The page prefetching threads have stopped"))?
+    }
+}
+
+/// Returns the number of values of the data page with `page_header`.
+pub(crate) fn data_page_num_values(page_header: &PageHeader) -> Result<i32> {
+    match (
+        &page_header.data_page_header,
+        &page_header.data_page_header_v2,
+    ) {
+        (Some(header), _) => Ok(header.num_values),
+        (_, Some(header)) => Ok(header.num_values),
+        _ => Err(general_err!("Data page is missing its header")),
+    }
+}
+
+/// Returns the metadata of the page with `page_header`.
+fn page_metadata(page_header: &PageHeader) -> Result<PageMetadata> {
+    if page_header.type_ == PageType::DictionaryPage {
+        let header = page_header
+            .dictionary_page_header
+            .as_ref()
+            .ok_or_else(|| general_err!("Dictionary page is missing its header"))?;
+        return Ok(PageMetadata {
+            num_rows: None,
+            num_levels: header.num_values as usize,
+            is_dict: true,
+        });
+    }
+    let num_rows = page_header
+        .data_page_header_v2
+        .as_ref()
+        .map(|header| header.num_rows as usize);
+    Ok(PageMetadata {
+        num_rows,
+        num_levels: data_page_num_values(page_header)? as usize,
+        is_dict: false,
+    })
+}
+
+/// Decompresses the bytes `buffer` of the page with `page_header` and returns the page,
+/// or `None` for an unknown page type (e.g., INDEX_PAGE), which is skipped.
+fn decode_page(
+    page_header: PageHeader,
+    mut buffer: Vec<u8>,
+    decompressor: Option<&mut (dyn Codec + 'static)>,
+    physical_type: Type,
+) -> Result<Option<Page>> {
+    // When processing data page v2, depending on enabled compression for the
+    // page, we should account for uncompressed data ('offset') of
+    // repetition and definition levels.
+    //
+    // We always use 0 offset for other pages other than v2, `true` flag means
+    // that compression will be applied if decompressor is defined
+    let mut offset: usize = 0;
+    let mut can_decompress = true;
+
+    if let Some(ref header_v2) = page_header.data_page_header_v2 {
+        offset = (header_v2.definition_levels_byte_length
+            + header_v2.repetition_levels_byte_length) as usize;
+        // When is_compressed flag is missing the page is considered compressed
+        can_decompress = header_v2.is_compressed.unwrap_or(true);
+    }
+
+    // TODO: page header could be huge because of statistics. We should set a
+    // maximum page header size and abort if that is exceeded.
+    if let Some(decompressor) = decompressor {
+        if can_decompress {
+            let uncompressed_len = page_header.uncompressed_page_size as usize - offset;
+            let mut decompressed_buffer = Vec::with_capacity(uncompressed_len);
+            let decompressed_size =
+                decompressor.decompress(&buffer[offset..], &mut decompressed_buffer)?;
+            if decompressed_size != uncompressed_len {
+                return Err(general_err!(
+                    "Actual decompressed size doesn't match the expected one ({} vs {})",
+                    decompressed_size,
+                    uncompressed_len
+                ));
+            }
+            if offset == 0 {
+                buffer = decompressed_buffer;
+            } else {
+                // Prepend saved offsets to the buffer
+                buffer.truncate(offset);
+                buffer.append(&mut decompressed_buffer);
+            }
+        }
+    }
+
+    let page = match page_header.type_ {
+        PageType::DictionaryPage => {
+            assert!(page_header.dictionary_page_header.is_some());
+            let dict_header = page_header.dictionary_page_header.as_ref().unwrap();
+            let is_sorted = dict_header.is_sorted.unwrap_or(false);
+            Page::DictionaryPage {
+                buf: ByteBufferPtr::new(buffer),
+                num_values: dict_header.num_values as u32,
+                encoding: Encoding::from(dict_header.encoding),
+                is_sorted,
+            }
+        }
+        PageType::DataPage => {
+            assert!(page_header.data_page_header.is_some());
+            let header = page_header.data_page_header.unwrap();
+            Page::DataPage {
+                buf: ByteBufferPtr::new(buffer),
+                num_values: header.num_values as u32,
+                encoding: Encoding::from(header.encoding),
+                def_level_encoding: Encoding::from(header.definition_level_encoding),
+                rep_level_encoding: Encoding::from(header.repetition_level_encoding),
+                statistics: statistics::from_thrift(physical_type, header.statistics),
+            }
+        }
+        PageType::DataPageV2 => {
+            assert!(page_header.data_page_header_v2.is_some());
+            let header = page_header.data_page_header_v2.unwrap();
+            let is_compressed = header.is_compressed.unwrap_or(true);
+            Page::DataPageV2 {
+                buf: ByteBufferPtr::new(buffer),
+                num_values: header.num_values as u32,
+                encoding: Encoding::from(header.encoding),
+                num_nulls: header.num_nulls as u32,
+                num_rows: header.num_rows as u32,
+                def_levels_byte_len: header.definition_levels_byte_length as u32,
+                rep_levels_byte_len: header.repetition_levels_byte_length as u32,
+                is_compressed,
+                statistics: statistics::from_thrift(physical_type, header.statistics),
+            }
+        }
+        _ => return Ok(None),
+    };
+    Ok(Some(page))
+}
+
+/// Reads an encrypted module, whose length is given by its first bytes.
+#[cfg(feature = "encryption")]
+fn read_encrypted_module<T: Read>(buf: &mut T) -> Result<Vec<u8>> {
+    let mut module = vec![0; SIZE_LEN];
+    buf.read_exact(&mut module)?;
+    module.resize(encrypted_module_len(&module)?, 0);
+    buf.read_exact(&mut module[SIZE_LEN..])?;
+    Ok(module)
 }
 
 #[cfg(test)]
@@ -726,6 +1272,23 @@ mod tests {
         assert!(page.is_none());
     }
 
+    #[test]
+    fn test_file_reader_prefetch_pages() {
+        for file in &["datapage_v2.snappy.parquet", "nested_maps.snappy.parquet"] {
+            let reader = SerializedFileReader::new(get_test_file(file)).unwrap();
+            let options = ReadOptions::builder().set_prefetch_threads(2).build();
+            assert_eq!(options.prefetch_threads(), 2);
+            let prefetching =
+                SerializedFileReader::new_with_options(get_test_file(file), options)
+                    .unwrap();
+
+            let rows = reader.get_row_iter(None).unwrap().collect::<Vec<_>>();
+            let prefetched = prefetching.get_row_iter(None).unwrap().collect::<Vec<_>>();
+            assert!(!rows.is_empty());
+            assert_eq!(rows, prefetched);
+        }
+    }
+
     #[test]
     fn test_file_reader_key_value_metadata() {
         let file = get_test_file("binary.parquet");
@@ -36,14 +36,41 @@
 //!     _ => {}
 //! }
 //! ```
+//!
+//! Alternatively, min and max values can be interpreted according to the logical type
+//! of their column and converted into a Rust type that is able to represent them, see
+//! [`FromStatisticsValue`]:
+//!
+//! ```rust
+//! use std::sync::Arc;
+//!
+//! use parquet::basic::{ConvertedType, Type};
+//! use parquet::file::statistics::Statistics;
+//! use parquet::schema::types::{ColumnDescriptor, ColumnPath, Type as SchemaType};
+//!
+//! let column_type = SchemaType::primitive_type_builder("col", Type::INT32)
+//!     .with_converted_type(ConvertedType::UINT_32)
+//!     .build()
+//!     .unwrap();
+//! let path = ColumnPath::from("col");
+//! let descr = ColumnDescriptor::new(Arc::new(column_type), 0, 0, path);
+//!
+//! let stats = Statistics::int32(Some(1), Some(-1), None, 3, true);
+//! assert_eq!(stats.min_as::<i64>(&descr).unwrap(), Some(1));
+//! assert_eq!(stats.max_as::<u32>(&descr).unwrap(), Some(u32::MAX));
+//! assert!(stats.max_as::<i32>(&descr).is_err());
+//! ```
 
 use std::{cmp, fmt};
 
 use byteorder::{ByteOrder, LittleEndian};
+use chrono::NaiveDate;
 use parquet_format::Statistics as TStatistics;
 
-use crate::basic::Type;
+use crate::basic::{ConvertedType, Type};
 use crate::data_type::*;
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::ColumnDescriptor;
 use crate::util::bit_util::from_ne_slice;
 
 // Macro to generate methods create Statistics.
@@ -310,6 +337,115 @@ impl Statistics {
         statistics_enum_func![self, max_bytes]
     }
 
+    /// Returns min value interpreted according to the logical type of the column
+    /// described by `descr`, or `None` if min value is not set.
+    ///
+    /// Returns an error if a decimal value is longer than 16 bytes.
+    pub fn min_value(
+        &self,
+        descr: &ColumnDescriptor,
+    ) -> Result<Option<StatisticsValue<'_>>> {
+        self.value(true, descr)
+    }
+
+    /// Returns max value interpreted according to the logical type of the column
+    /// described by `descr`, or `None` if max value is not set.
+    ///
+    /// Returns an error if a decimal value is longer than 16 bytes.
+    pub fn max_value(
+        &self,
+        descr: &ColumnDescriptor,
+    ) -> Result<Option<StatisticsValue<'_>>> {
+        self.value(false, descr)
+    }
+
+    /// Returns min value of the column described by `descr` converted into `V`, or
+    /// `None` if min value is not set.
+    ///
+    /// Returns an error if the value can't be converted into `V`, see
+    /// [`FromStatisticsValue`] for the supported conversions.
+    pub fn min_as<V: FromStatisticsValue>(
+        &self,
+        descr: &ColumnDescriptor,
+    ) -> Result<Option<V>> {
+        self.min_value(descr)?
+            .map(V::from_statistics_value)
+            .transpose()
+    }
+
+    /// Returns max value of the column described by `descr` converted into `V`, or
+    /// `None` if max value is not set.
+    ///
+    /// Returns an error if the value can't be converted into `V`, see
+    /// [`FromStatisticsValue`] for the supported conversions.
+    pub fn max_as<V: FromStatisticsValue>(
+        &self,
+        descr: &ColumnDescriptor,
+    ) -> Result<Option<V>> {
+        self.max_value(descr)?
+            .map(V::from_statistics_value)
+            .transpose()
+    }
+
+    fn value(
+        &self,
+        is_min: bool,
+        descr: &ColumnDescriptor,
+    ) -> Result<Option<StatisticsValue<'_>>> {
+        let converted_type = descr.converted_type();
+        let is_unsigned = matches!(
+            converted_type,
+            ConvertedType::UINT_8
+                | ConvertedType::UINT_16
+                | ConvertedType::UINT_32
+                | ConvertedType::UINT_64
+        );
+        let decimal = |unscaled| StatisticsValue::Decimal {
+            unscaled,
+            scale: descr.type_scale(),
+        };
+        let value = match self {
+            Statistics::Boolean(typed) => {
+                typed.value(is_min).map(|v| StatisticsValue::Boolean(*v))
+            }
+            Statistics::Int32(typed) => {
+                typed.value(is_min).map(|v| match converted_type {
+                    ConvertedType::DECIMAL => decimal(*v as i128),
+                    _ if is_unsigned => StatisticsValue::UInt32(*v as u32),
+                    _ => StatisticsValue::Int32(*v),
+                })
+            }
+            Statistics::Int64(typed) => {
+                typed.value(is_min).map(|v| match converted_type {
+                    ConvertedType::DECIMAL => decimal(*v as i128),
+                    _ if is_unsigned => StatisticsValue::UInt64(*v as u64),
+                    _ => StatisticsValue::Int64(*v),
+                })
+            }
+            Statistics::Int96(typed) => typed.value(is_min).map(StatisticsValue::Int96),
+            Statistics::Float(typed) => {
+                typed.value(is_min).map(|v| StatisticsValue::Float(*v))
+            }
+            Statistics::Double(typed) => {
+                typed.value(is_min).map(|v| StatisticsValue::Double(*v))
+            }
+            Statistics::ByteArray(typed) => typed
+                .value(is_min)
+                .map(|v| StatisticsValue::Bytes(v.as_bytes())),
+            Statistics::FixedLenByteArray(typed) => typed
+                .value(is_min)
+                .map(|v| StatisticsValue::Bytes(v.as_bytes())),
+        };
+        match value {
+            Some(StatisticsValue::Bytes(v))
+                if converted_type == ConvertedType::DECIMAL =>
+            {
+                Ok(Some(decimal(decimal_from_be_bytes(v)?)))
+            }
+            _ => Ok(value),
+        }
+    }
+
     /// Returns physical type associated with statistics.
     pub fn physical_type(&self) -> Type {
         match self {
@@ -401,6 +537,15 @@ impl<T: DataType> TypedStatistics<T> {
         self.max().as_bytes()
     }
 
+    /// Returns min value if `is_min` is true, max value otherwise.
+    fn value(&self, is_min: bool) -> Option<&T::T> {
+        if is_min {
+            self.min.as_ref()
+        } else {
+            self.max.as_ref()
+        }
+    }
+
     /// Whether or not min and max values are set.
     /// Normally both min/max values will be set to `Some(value)` or `None`.
     fn has_min_max_set(&self) -> bool {
@@ -472,10 +617,208 @@ impl<T: DataType> cmp::PartialEq for TypedStatistics<T> {
     }
 }
 
+/// Min or max value of [`Statistics`], interpreted according to the logical type of
+/// its column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatisticsValue<'a> {
+    Boolean(bool),
+    Int32(i32),
+    Int64(i64),
+    /// Value of an unsigned `INT32` column.
+    UInt32(u32),
+    /// Value of an unsigned `INT64` column.
+    UInt64(u64),
+    /// Value of a `DECIMAL` column, which is `unscaled * 10^-scale`.
+    Decimal {
+        unscaled: i128,
+        scale: i32,
+    },
+    Int96(&'a Int96),
+    Float(f32),
+    Double(f64),
+    /// Value of a `BYTE_ARRAY` or `FIXED_LEN_BYTE_ARRAY` column.
+    Bytes(&'a [u8]),
+}
+
+/// Returns the value of a big-endian two's complement byte array, which is how
+/// unscaled decimal values are stored in byte arrays.
+fn decimal_from_be_bytes(v: &[u8]) -> Result<i128> {
+    if v.is_empty() || v.len() > 16 {
+        return Err(general_err!(
+            "Cannot read a decimal value from {} bytes, expected 1 to 16 bytes",
+            v.len()
+        ));
+    }
+    // sign extend the big-endian two's complement value
+    let fill = if v[0] & 0x80 != 0 { 0xFF } else { 0 };
+    let mut bytes = [fill; 16];
+    bytes[16 - v.len()..].copy_from_slice(v);
+    Ok(i128::from_be_bytes(bytes))
+}
+
+/// Conversion of a [`StatisticsValue`] into a Rust type, used by
+/// [`Statistics::min_as`] and [`Statistics::max_as`].
+///
+/// The following conversions are supported:
+///
+/// - `bool` from `Boolean`
+/// - `i32` from `Int32`, `i64` from `Int32`, `Int64` and `UInt32`
+/// - `u32` from `UInt32`, `u64` from `UInt32` and `UInt64`
+/// - `i128` from `Int32`, `Int64`, `UInt32` and `UInt64`
+/// - `f32` from `Float`, `f64` from `Float`, `Double` and `Decimal`, the latter
+///   possibly rounded
+/// - [`NaiveDate`] from `Int32`, interpreted as days since the Unix epoch
+/// - [`Int96`] from `Int96`
+/// - `Vec<u8>` and `String` from `Bytes`, the latter must be valid UTF-8
+///
+/// Decimal values are not converted into integers, as that would drop their scale,
+/// match on [`StatisticsValue::Decimal`] to read them exactly.
+pub trait FromStatisticsValue: Sized {
+    /// Converts `value` into `Self`, returns an error if `value` can't be represented
+    /// as `Self`.
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self>;
+}
+
+fn unsupported_conversion<V>(value: StatisticsValue<'_>) -> ParquetError {
+    general_err!(
+        "Cannot convert statistics value {:?} into {}",
+        value,
+        std::any::type_name::<V>()
+    )
+}
+
+impl FromStatisticsValue for bool {
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self> {
+        match value {
+            StatisticsValue::Boolean(v) => Ok(v),
+            _ => Err(unsupported_conversion::<Self>(value)),
+        }
+    }
+}
+
+impl FromStatisticsValue for i32 {
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self> {
+        match value {
+            StatisticsValue::Int32(v) => Ok(v),
+            _ => Err(unsupported_conversion::<Self>(value)),
+        }
+    }
+}
+
+impl FromStatisticsValue for i64 {
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self> {
+        match value {
+            StatisticsValue::Int32(v) => Ok(v as i64),
+            StatisticsValue::Int64(v) => Ok(v),
+            StatisticsValue::UInt32(v) => Ok(v as i64),
+            _ => Err(unsupported_conversion::<Self>(value)),
+        }
+    }
+}
+
+impl FromStatisticsValue for u32 {
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self> {
+        match value {
+            StatisticsValue::UInt32(v) => Ok(v),
+            _ => Err(unsupported_conversion::<Self>(value)),
+        }
+    }
+}
+
+impl FromStatisticsValue for u64 {
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self> {
+        match value {
+            StatisticsValue::UInt32(v) => Ok(v as u64),
+            StatisticsValue::UInt64(v) => Ok(v),
+            _ => Err(unsupported_conversion::<Self>(value)),
+        }
+    }
+}
+
+impl FromStatisticsValue for i128 {
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self> {
+        match value {
+            StatisticsValue::Int32(v) => Ok(v as i128),
+            StatisticsValue::Int64(v) => Ok(v as i128),
+            StatisticsValue::UInt32(v) => Ok(v as i128),
+            StatisticsValue::UInt64(v) => Ok(v as i128),
+            _ => Err(unsupported_conversion::<Self>(value)),
+        }
+    }
+}
+
+impl FromStatisticsValue for f32 {
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self> {
+        match value {
+            StatisticsValue::Float(v) => Ok(v),
+            _ => Err(unsupported_conversion::<Self>(value)),
+        }
+    }
+}
+
+impl FromStatisticsValue for f64 {
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self> {
+        match value {
+            StatisticsValue::Float(v) => Ok(v as f64),
+            StatisticsValue::Double(v) => Ok(v),
+            StatisticsValue::Decimal { unscaled, scale } => {
+                Ok(unscaled as f64 / 10f64.powi(scale))
+            }
+            _ => Err(unsupported_conversion::<Self>(value)),
+        }
+    }
+}
+
+impl FromStatisticsValue for NaiveDate {
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self> {
+        // number of days between 0001-01-01 and 1970-01-01
+        const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+        match value {
+            StatisticsValue::Int32(v) => v
+                .checked_add(UNIX_EPOCH_DAYS_FROM_CE)
+                .and_then(NaiveDate::from_num_days_from_ce_opt)
+                .ok_or_else(|| general_err!("Date {} is out of range", v)),
+            _ => Err(unsupported_conversion::<Self>(value)),
+        }
+    }
+}
+
+impl FromStatisticsValue for Int96 {
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self> {
+        match value {
+            StatisticsValue::Int96(v) => Ok(v.clone()),
+            _ => Err(unsupported_conversion::<Self>(value)),
+        }
+    }
+}
+
+impl FromStatisticsValue for Vec<u8> {
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self> {
+        match value {
+            StatisticsValue::Bytes(v) => Ok(v.to_vec()),
+            _ => Err(unsupported_conversion::<Self>(value)),
+        }
+    }
+}
+
+impl FromStatisticsValue for String {
+    fn from_statistics_value(value: StatisticsValue<'_>) -> Result<Self> {
+        match value {
+            StatisticsValue::Bytes(v) => String::from_utf8(v.to_vec())
+                .map_err(|e| general_err!("Statistics value is not UTF-8: {}", e)),
+            _ => Err(unsupported_conversion::<Self>(value)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use std::sync::Arc;
+
+    use crate::schema::types::{ColumnPath, Type as SchemaType};
+
     #[test]
     fn test_statistics_min_max_bytes() {
         let stats = Statistics::int32(Some(-123), Some(234), None, 1, false);
@@ -495,6 +838,149 @@ mod tests {
         assert_eq!(stats.max_bytes(), &[3, 4, 5]);
     }
 
+    fn column_descr(
+        physical_type: Type,
+        converted_type: ConvertedType,
+        length: i32,
+        precision: i32,
+        scale: i32,
+    ) -> ColumnDescriptor {
+        let column_type = SchemaType::primitive_type_builder("col", physical_type)
+            .with_converted_type(converted_type)
+            .with_length(length)
+            .with_precision(precision)
+            .with_scale(scale)
+            .build()
+            .unwrap();
+        ColumnDescriptor::new(Arc::new(column_type), 0, 0, ColumnPath::from("col"))
+    }
+
+    #[test]
+    fn test_statistics_min_max_as() {
+        let descr = column_descr(Type::INT32, ConvertedType::NONE, -1, -1, -1);
+        let stats = Statistics::int32(Some(-3), Some(18000), None, 0, false);
+        assert_eq!(stats.min_as::<i32>(&descr).unwrap(), Some(-3));
+        assert_eq!(stats.max_as::<i64>(&descr).unwrap(), Some(18000));
+        assert_eq!(stats.min_as::<i128>(&descr).unwrap(), Some(-3));
+        assert_eq!(
+            stats.max_as::<NaiveDate>(&descr).unwrap(),
+            Some(NaiveDate::from_ymd(2019, 4, 14))
+        );
+        assert_eq!(
+            stats.min_as::<f64>(&descr).unwrap_err().to_string(),
+            "Parquet error: Cannot convert statistics value Int32(-3) into f64"
+        );
+        assert!(stats.min_as::<u32>(&descr).is_err());
+
+        let descr = column_descr(Type::BYTE_ARRAY, ConvertedType::UTF8, -1, -1, -1);
+        let stats = Statistics::byte_array(
+            Some(ByteArray::from("apple")),
+            Some(ByteArray::from("pear")),
+            None,
+            0,
+            false,
+        );
+        assert_eq!(
+            stats.min_as::<String>(&descr).unwrap(),
+            Some("apple".to_string())
+        );
+        assert_eq!(
+            stats.max_value(&descr).unwrap(),
+            Some(StatisticsValue::Bytes(b"pear"))
+        );
+
+        let descr = column_descr(Type::DOUBLE, ConvertedType::NONE, -1, -1, -1);
+        let stats = Statistics::double(None, None, None, 5, false);
+        assert_eq!(stats.min_as::<f64>(&descr).unwrap(), None);
+        assert_eq!(stats.max_value(&descr).unwrap(), None);
+    }
+
+    #[test]
+    fn test_statistics_unsigned_as() {
+        // unsigned values are stored as their signed bit pattern
+        let descr = column_descr(Type::INT32, ConvertedType::UINT_32, -1, -1, -1);
+        let stats = Statistics::int32(Some(7), Some(-1), None, 0, false);
+        assert_eq!(stats.min_as::<u32>(&descr).unwrap(), Some(7));
+        assert_eq!(stats.max_as::<u32>(&descr).unwrap(), Some(u32::MAX));
+        assert_eq!(stats.max_as::<i64>(&descr).unwrap(), Some(u32::MAX as i64));
+        assert_eq!(
+            stats.max_value(&descr).unwrap(),
+            Some(StatisticsValue::UInt32(u32::MAX))
+        );
+        assert_eq!(
+            stats.max_as::<i32>(&descr).unwrap_err().to_string(),
+            "Parquet error: Cannot convert statistics value UInt32(4294967295) into i32"
+        );
+
+        let descr = column_descr(Type::INT64, ConvertedType::UINT_64, -1, -1, -1);
+        let stats = Statistics::int64(Some(7), Some(i64::MIN), None, 0, false);
+        assert_eq!(stats.min_as::<u64>(&descr).unwrap(), Some(7));
+        assert_eq!(stats.max_as::<u64>(&descr).unwrap(), Some(1 << 63));
+        assert_eq!(stats.max_as::<i128>(&descr).unwrap(), Some(1 << 63));
+        assert!(stats.max_as::<i64>(&descr).is_err());
+    }
+
+    #[test]
+    fn test_statistics_decimal_as() {
+        let descr = column_descr(Type::INT32, ConvertedType::DECIMAL, -1, 9, 2);
+        let stats = Statistics::int32(Some(-1250), Some(500), None, 0, false);
+        assert_eq!(
+            stats.min_value(&descr).unwrap(),
+            Some(StatisticsValue::Decimal {
+                unscaled: -1250,
+                scale: 2
+            })
+        );
+        assert_eq!(stats.min_as::<f64>(&descr).unwrap(), Some(-12.5));
+        assert_eq!(stats.max_as::<f64>(&descr).unwrap(), Some(5.0));
+        // the scale would be lost
+        assert!(stats.max_as::<i32>(&descr).is_err());
+        assert!(stats.max_as::<i128>(&descr).is_err());
+
+        let descr = column_descr(Type::INT64, ConvertedType::DECIMAL, -1, 18, 3);
+        let stats = Statistics::int64(Some(-1), Some(123_456), None, 0, false);
+        assert_eq!(
+            stats.max_value(&descr).unwrap(),
+            Some(StatisticsValue::Decimal {
+                unscaled: 123_456,
+                scale: 3
+            })
+        );
+        assert_eq!(stats.min_as::<f64>(&descr).unwrap(), Some(-0.001));
+
+        // decimals are stored as big-endian two's complement values
+        let descr =
+            column_descr(Type::FIXED_LEN_BYTE_ARRAY, ConvertedType::DECIMAL, 3, 6, 2);
+        let stats = Statistics::fixed_len_byte_array(
+            Some(ByteArray::from(vec![0xFF, 0xFE, 0x0C]).into()),
+            Some(ByteArray::from(vec![0x00, 0x01, 0xF4]).into()),
+            None,
+            0,
+            false,
+        );
+        assert_eq!(
+            stats.min_value(&descr).unwrap(),
+            Some(StatisticsValue::Decimal {
+                unscaled: -500,
+                scale: 2
+            })
+        );
+        assert_eq!(stats.max_as::<f64>(&descr).unwrap(), Some(5.0));
+        assert!(stats.max_as::<Vec<u8>>(&descr).is_err());
+
+        let stats = Statistics::fixed_len_byte_array(
+            Some(ByteArray::from(vec![0; 17]).into()),
+            None,
+            None,
+            0,
+            false,
+        );
+        assert_eq!(
+            stats.min_value(&descr).unwrap_err().to_string(),
+            "Parquet error: Cannot read a decimal value from 17 bytes, expected 1 to 16 bytes"
+        );
+    }
+
     #[test]
     #[should_panic(expected = "Statistics null count is negative (-10)")]
     fn test_statistics_negative_null_count() {
@@ -19,26 +19,42 @@
 //! using row group writers and column writers respectively.
 
 use std::{
+    convert::TryFrom,
     io::{Seek, SeekFrom, Write},
+    ops::Range,
     sync::Arc,
 };
 
 use byteorder::{ByteOrder, LittleEndian};
 use parquet_format as parquet;
-use thrift::protocol::{TCompactOutputProtocol, TOutputProtocol};
+use thrift::protocol::{TCompactInputProtocol, TCompactOutputProtocol, TOutputProtocol};
 
-use crate::basic::PageType;
+use crate::basic::{Compression, PageType};
+use crate::bloom_filter::Sbbf;
 use crate::column::{
-    page::{CompressedPage, Page, PageWriteSpec, PageWriter},
-    writer::{get_column_writer, ColumnWriter},
+    page::{CompressedPage, Page, PageReader, PageWriteSpec, PageWriter},
+    writer::{get_column_writer, ColumnCloseResult, ColumnWriter},
 };
+use crate::compression::{create_codec, Codec};
+#[cfg(feature = "encryption")]
+use crate::encryption::encrypt::{FileEncryptor, PageEncryptor};
 use crate::errors::{ParquetError, Result};
+#[cfg(feature = "encryption")]
+use crate::file::PARQUET_MAGIC_ENCR_FOOTER;
 use crate::file::{
-    metadata::*, properties::WriterPropertiesPtr,
-    statistics::to_thrift as statistics_to_thrift, FOOTER_SIZE, PARQUET_MAGIC,
+    metadata::*,
+    page_index::PageLocation,
+    properties::WriterPropertiesPtr,
+    reader::ChunkReader,
+    serialized_reader::{data_page_num_values, SerializedPageReader},
+    statistics::to_thrift as statistics_to_thrift,
+    FOOTER_SIZE, PARQUET_MAGIC,
+};
+use crate::schema::types::{
+    self, ColumnDescPtr, SchemaDescPtr, SchemaDescriptor, TypePtr,
 };
-use crate::schema::types::{self, SchemaDescPtr, SchemaDescriptor, TypePtr};
 use crate::util::io::{FileSink, Position};
+use crate::util::memory::ByteBufferPtr;
 
 // Exposed publically so client code can implement [`ParquetWriter`]
 pub use crate::util::io::TryClone;
@@ -118,6 +134,28 @@ pub trait RowGroupWriter {
     /// Can be called multiple times. In subsequent calls will result in no-op and return
     /// already created row group metadata.
     fn close(&mut self) -> Result<RowGroupMetaDataPtr>;
+
+    /// Takes the column indexes and offset indexes of the closed columns, in column
+    /// order. An entry is `None` if no index was written for that column.
+    ///
+    /// Used by the file writer to write the page index once the row group is closed.
+    fn take_page_index(
+        &mut self,
+    ) -> (
+        Vec<Option<parquet::ColumnIndex>>,
+        Vec<Option<parquet::OffsetIndex>>,
+    ) {
+        (vec![], vec![])
+    }
+
+    /// Takes the bloom filters of the closed columns, in column order. An entry is
+    /// `None` if no bloom filter was collected for that column.
+    ///
+    /// Used by the file writer to write the bloom filters once the row group is
+    /// closed.
+    fn take_bloom_filters(&mut self) -> Vec<Option<Sbbf>> {
+        vec![]
+    }
 }
 
 // ----------------------------------------------------------------------
@@ -135,8 +173,13 @@ pub struct SerializedFileWriter<W: ParquetWriter> {
     props: WriterPropertiesPtr,
     total_num_rows: i64,
     row_groups: Vec<RowGroupMetaDataPtr>,
+    column_indexes: Vec<Vec<Option<parquet::ColumnIndex>>>,
+    offset_indexes: Vec<Vec<Option<parquet::OffsetIndex>>>,
+    bloom_filters: Vec<Vec<Option<Sbbf>>>,
     previous_writer_closed: bool,
     is_closed: bool,
+    #[cfg(feature = "encryption")]
+    file_encryptor: Option<Arc<FileEncryptor>>,
 }
 
 impl<W: ParquetWriter> SerializedFileWriter<W> {
@@ -146,22 +189,324 @@ impl<W: ParquetWriter> SerializedFileWriter<W> {
         schema: TypePtr,
         properties: WriterPropertiesPtr,
     ) -> Result<Self> {
-        Self::start_file(&mut buf)?;
+        let descr = Arc::new(SchemaDescriptor::new(schema.clone()));
+        #[cfg(feature = "encryption")]
+        let file_encryptor = Self::create_file_encryptor(&descr, &properties)?;
+        #[cfg(feature = "encryption")]
+        let magic = match file_encryptor {
+            Some(_) => PARQUET_MAGIC_ENCR_FOOTER,
+            None => PARQUET_MAGIC,
+        };
+        #[cfg(not(feature = "encryption"))]
+        let magic = PARQUET_MAGIC;
+
+        buf.write_all(&magic)?;
         Ok(Self {
             buf,
-            schema: schema.clone(),
-            descr: Arc::new(SchemaDescriptor::new(schema)),
+            schema,
+            descr,
             props: properties,
             total_num_rows: 0,
             row_groups: Vec::new(),
+            column_indexes: Vec::new(),
+            offset_indexes: Vec::new(),
+            bloom_filters: Vec::new(),
             previous_writer_closed: true,
             is_closed: false,
+            #[cfg(feature = "encryption")]
+            file_encryptor,
         })
     }
 
-    /// Writes magic bytes at the beginning of the file.
-    fn start_file(buf: &mut W) -> Result<()> {
-        buf.write_all(&PARQUET_MAGIC)?;
+    /// Creates the encryptor of the file if encryption is enabled in `properties`.
+    #[cfg(feature = "encryption")]
+    fn create_file_encryptor(
+        descr: &SchemaDescriptor,
+        properties: &WriterPropertiesPtr,
+    ) -> Result<Option<Arc<FileEncryptor>>> {
+        let encryption_properties = match properties.file_encryption_properties() {
+            Some(encryption_properties) => encryption_properties,
+            None => return Ok(None),
+        };
+        if properties.page_index_enabled() {
+            return Err(nyi_err!(
+                "Writing the page index of encrypted files is not supported yet"
+            ));
+        }
+        if descr
+            .columns()
+            .iter()
+            .any(|c| properties.bloom_filter_properties(c.path()).is_some())
+        {
+            return Err(nyi_err!(
+                "Writing bloom filters to encrypted files is not supported yet"
+            ));
+        }
+        let file_encryptor = FileEncryptor::try_new(encryption_properties.clone())?;
+        Ok(Some(Arc::new(file_encryptor)))
+    }
+
+    /// Encrypts the metadata of the column chunks of `row_groups` that are
+    /// encrypted with a column key, and records the crypto metadata of every
+    /// encrypted column chunk.
+    #[cfg(feature = "encryption")]
+    fn encrypt_column_metadata(
+        &self,
+        file_encryptor: &FileEncryptor,
+        row_groups: &mut [parquet::RowGroup],
+    ) -> Result<()> {
+        for (rg_idx, row_group) in row_groups.iter_mut().enumerate() {
+            for (col_idx, column) in row_group.columns.iter_mut().enumerate() {
+                let col = self.descr.column(col_idx);
+                column.crypto_metadata =
+                    file_encryptor.column_crypto_metadata(col.path());
+                if !file_encryptor.has_column_key(col.path()) {
+                    continue;
+                }
+                if let Some(meta_data) = column.meta_data.take() {
+                    let mut buf = Vec::new();
+                    {
+                        let mut protocol = TCompactOutputProtocol::new(&mut buf);
+                        meta_data.write_to_out_protocol(&mut protocol)?;
+                        protocol.flush()?;
+                    }
+                    column.encrypted_column_metadata =
+                        Some(file_encryptor.encrypt_column_metadata(
+                            col.path(),
+                            &buf,
+                            rg_idx,
+                            col_idx,
+                        )?);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the crypto metadata and the encrypted file metadata, returning the
+    /// number of bytes written.
+    #[cfg(feature = "encryption")]
+    fn write_encrypted_footer(
+        &mut self,
+        file_encryptor: &FileEncryptor,
+        file_metadata: &parquet::FileMetaData,
+    ) -> Result<u64> {
+        let start_pos = self.buf.seek(SeekFrom::Current(0))?;
+        {
+            let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
+            file_encryptor
+                .file_crypto_metadata()
+                .write_to_out_protocol(&mut protocol)?;
+            protocol.flush()?;
+        }
+        let mut buf = Vec::new();
+        {
+            let mut protocol = TCompactOutputProtocol::new(&mut buf);
+            file_metadata.write_to_out_protocol(&mut protocol)?;
+            protocol.flush()?;
+        }
+        self.buf.write_all(&file_encryptor.encrypt_footer(&buf)?)?;
+        let end_pos = self.buf.seek(SeekFrom::Current(0))?;
+        Ok(end_pos - start_pos)
+    }
+
+    /// Appends the row group described by `metadata` to this file, copying its
+    /// column chunks from `reader` without decoding them.
+    ///
+    /// The leaf columns of the row group must match the leaf columns of the schema of
+    /// this writer. If `compression` is `Some` and differs from the codec of a column
+    /// chunk, the pages of that column chunk are decompressed and compressed again with
+    /// the new codec; otherwise the encoded pages are copied verbatim.
+    ///
+    /// This allows to concatenate files, or to move row groups between files, without
+    /// a decode and encode round trip. The page index and the bloom filters of the
+    /// copied column chunks are not carried over.
+    ///
+    /// ```rust,no_run
+    /// # use std::{fs::File, sync::Arc};
+    /// # use parquet::file::properties::WriterProperties;
+    /// # use parquet::file::reader::{FileReader, SerializedFileReader};
+    /// # use parquet::file::writer::{FileWriter, SerializedFileWriter};
+    /// let inputs = vec![File::open("a.parquet").unwrap(), File::open("b.parquet").unwrap()];
+    /// let schema = SerializedFileReader::new(inputs[0].try_clone().unwrap())
+    ///     .unwrap()
+    ///     .metadata()
+    ///     .file_metadata()
+    ///     .schema_descr()
+    ///     .root_schema_ptr();
+    /// let output = File::create("merged.parquet").unwrap();
+    /// let props = Arc::new(WriterProperties::builder().build());
+    /// let mut writer = SerializedFileWriter::new(output, schema, props).unwrap();
+    /// for input in inputs {
+    ///     let reader = SerializedFileReader::new(input.try_clone().unwrap()).unwrap();
+    ///     for row_group in reader.metadata().row_groups() {
+    ///         writer.append_row_group(&input, row_group, None).unwrap();
+    ///     }
+    /// }
+    /// writer.close().unwrap();
+    /// ```
+    pub fn append_row_group<R: ChunkReader>(
+        &mut self,
+        reader: &R,
+        metadata: &RowGroupMetaData,
+        compression: Option<Compression>,
+    ) -> Result<()> {
+        self.assert_closed()?;
+        self.assert_previous_writer_closed()?;
+        #[cfg(feature = "encryption")]
+        if self.file_encryptor.is_some() {
+            return Err(nyi_err!(
+                "Copying row groups to encrypted files is not supported yet"
+            ));
+        }
+        self.check_columns_match(metadata.schema_descr())?;
+
+        let num_columns = metadata.num_columns();
+        let mut column_chunks = Vec::with_capacity(num_columns);
+        let mut total_byte_size = 0;
+        for (i, column) in metadata.columns().iter().enumerate() {
+            let descr = self.descr.column(i);
+            let mut page_writer = SerializedPageWriter::new(FileSink::new(&self.buf));
+            let column_chunk = match compression {
+                Some(codec) if codec != column.compression() => recompress_column_chunk(
+                    reader,
+                    column,
+                    descr,
+                    codec,
+                    &mut page_writer,
+                )?,
+                _ => copy_column_chunk(reader, column, descr, &mut page_writer)?,
+            };
+            page_writer.write_metadata(&column_chunk)?;
+            page_writer.close()?;
+            total_byte_size += column_chunk.compressed_size();
+            column_chunks.push(column_chunk);
+        }
+
+        let row_group_metadata = RowGroupMetaData::builder(self.descr.clone())
+            .set_column_metadata(column_chunks)
+            .set_total_byte_size(total_byte_size)
+            .set_num_rows(metadata.num_rows())
+            .set_ordinal(self.row_groups.len() as i16)
+            .build()?;
+        self.total_num_rows += row_group_metadata.num_rows();
+        self.row_groups.push(Arc::new(row_group_metadata));
+        self.column_indexes.push(vec![None; num_columns]);
+        self.offset_indexes.push(vec![None; num_columns]);
+        self.bloom_filters
+            .push(std::iter::repeat_with(|| None).take(num_columns).collect());
+        Ok(())
+    }
+
+    /// Appends the rows `rows` of the row group with `metadata` from `reader` to this file
+    /// as a new row group, copying the raw compressed pages that contain them, and the
+    /// dictionary page of each column chunk, without decoding them.
+    ///
+    /// `page_locations` are the locations of the data pages of each column chunk of the
+    /// row group, see [`ParquetMetaData::offset_indexes`], and the locations of the
+    /// copied pages are written to the offset index of the new row group. `rows` must
+    /// start and end on a page boundary of every column chunk.
+    ///
+    /// The new column chunks have neither statistics nor a column index, as those of
+    /// `metadata` describe all the rows of the row group.
+    pub fn append_row_group_pages<R: ChunkReader>(
+        &mut self,
+        reader: &R,
+        metadata: &RowGroupMetaData,
+        page_locations: &[Vec<PageLocation>],
+        rows: Range<usize>,
+    ) -> Result<()> {
+        self.assert_closed()?;
+        self.assert_previous_writer_closed()?;
+        #[cfg(feature = "encryption")]
+        if self.file_encryptor.is_some() {
+            return Err(nyi_err!(
+                "Copying pages to encrypted files is not supported yet"
+            ));
+        }
+        self.check_columns_match(metadata.schema_descr())?;
+        let num_rows = metadata.num_rows() as usize;
+        if rows.start >= rows.end || rows.end > num_rows {
+            return Err(general_err!(
+                "Invalid rows {:?} of a row group of {} rows",
+                rows,
+                num_rows
+            ));
+        }
+        if page_locations.len() != metadata.num_columns() {
+            return Err(general_err!(
+                "Expected the page locations of {} columns, got {}",
+                metadata.num_columns(),
+                page_locations.len()
+            ));
+        }
+
+        let num_columns = metadata.num_columns();
+        let mut column_chunks = Vec::with_capacity(num_columns);
+        let mut offset_indexes = Vec::with_capacity(num_columns);
+        let mut total_byte_size = 0;
+        for (i, (column, locations)) in
+            metadata.columns().iter().zip(page_locations).enumerate()
+        {
+            let descr = self.descr.column(i);
+            let mut page_writer = SerializedPageWriter::new(FileSink::new(&self.buf));
+            let (column_chunk, locations) = copy_column_chunk_pages(
+                reader,
+                column,
+                descr,
+                locations,
+                &rows,
+                num_rows,
+                &mut page_writer,
+            )?;
+            page_writer.write_metadata(&column_chunk)?;
+            page_writer.close()?;
+            total_byte_size += column_chunk.compressed_size();
+            column_chunks.push(column_chunk);
+            offset_indexes.push(Some(parquet::OffsetIndex {
+                page_locations: locations,
+            }));
+        }
+
+        let row_group_metadata = RowGroupMetaData::builder(self.descr.clone())
+            .set_column_metadata(column_chunks)
+            .set_total_byte_size(total_byte_size)
+            .set_num_rows(rows.len() as i64)
+            .set_ordinal(self.row_groups.len() as i16)
+            .build()?;
+        self.total_num_rows += row_group_metadata.num_rows();
+        self.row_groups.push(Arc::new(row_group_metadata));
+        self.column_indexes.push(vec![None; num_columns]);
+        self.offset_indexes.push(offset_indexes);
+        self.bloom_filters
+            .push(std::iter::repeat_with(|| None).take(num_columns).collect());
+        Ok(())
+    }
+
+    /// Checks that the leaf columns of `schema` match the leaf columns of the schema
+    /// of this writer.
+    fn check_columns_match(&self, schema: &SchemaDescriptor) -> Result<()> {
+        if schema.num_columns() != self.descr.num_columns() {
+            return Err(general_err!(
+                "Expected {} columns, but the row group has {} columns",
+                self.descr.num_columns(),
+                schema.num_columns()
+            ));
+        }
+        for (expected, actual) in self.descr.columns().iter().zip(schema.columns()) {
+            if expected.path() != actual.path()
+                || expected.physical_type() != actual.physical_type()
+                || expected.type_length() != actual.type_length()
+                || expected.max_def_level() != actual.max_def_level()
+                || expected.max_rep_level() != actual.max_rep_level()
+            {
+                return Err(general_err!(
+                    "Column {} of the row group does not match column {} of the schema",
+                    actual.path().string(),
+                    expected.path().string()
+                ));
+            }
+        }
         Ok(())
     }
 
@@ -171,29 +516,141 @@ impl<W: ParquetWriter> SerializedFileWriter<W> {
         mut row_group_writer: Box<dyn RowGroupWriter>,
     ) -> Result<()> {
         let row_group_metadata = row_group_writer.close()?;
+        let (column_indexes, offset_indexes) = row_group_writer.take_page_index();
         self.total_num_rows += row_group_metadata.num_rows();
         self.row_groups.push(row_group_metadata);
+        self.column_indexes.push(column_indexes);
+        self.offset_indexes.push(offset_indexes);
+        self.bloom_filters
+            .push(row_group_writer.take_bloom_filters());
+        Ok(())
+    }
+
+    /// Serializes all the bloom filters to the file, and records their locations in
+    /// the column chunks of `row_groups`.
+    fn write_bloom_filters(
+        &mut self,
+        row_groups: &mut [parquet::RowGroup],
+    ) -> Result<()> {
+        for (row_group, bloom_filters) in
+            row_groups.iter_mut().zip(self.bloom_filters.iter())
+        {
+            for (column, bloom_filter) in
+                row_group.columns.iter_mut().zip(bloom_filters.iter())
+            {
+                if let Some(bloom_filter) = bloom_filter {
+                    let start_pos = self.buf.seek(SeekFrom::Current(0))?;
+                    bloom_filter.write(&mut self.buf)?;
+                    if let Some(metadata) = column.meta_data.as_mut() {
+                        metadata.bloom_filter_offset = Some(start_pos as i64);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Serializes all the column indexes to the file, and records their locations in
+    /// the column chunks of `row_groups`.
+    fn write_column_indexes(
+        &mut self,
+        row_groups: &mut [parquet::RowGroup],
+    ) -> Result<()> {
+        for (row_group, column_indexes) in
+            row_groups.iter_mut().zip(self.column_indexes.iter())
+        {
+            for (column, column_index) in
+                row_group.columns.iter_mut().zip(column_indexes.iter())
+            {
+                if let Some(column_index) = column_index {
+                    let start_pos = self.buf.seek(SeekFrom::Current(0))?;
+                    {
+                        let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
+                        column_index.write_to_out_protocol(&mut protocol)?;
+                        protocol.flush()?;
+                    }
+                    let end_pos = self.buf.seek(SeekFrom::Current(0))?;
+                    column.column_index_offset = Some(start_pos as i64);
+                    column.column_index_length = Some((end_pos - start_pos) as i32);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Serializes all the offset indexes to the file, and records their locations in
+    /// the column chunks of `row_groups`.
+    fn write_offset_indexes(
+        &mut self,
+        row_groups: &mut [parquet::RowGroup],
+    ) -> Result<()> {
+        for (row_group, offset_indexes) in
+            row_groups.iter_mut().zip(self.offset_indexes.iter())
+        {
+            for (column, offset_index) in
+                row_group.columns.iter_mut().zip(offset_indexes.iter())
+            {
+                if let Some(offset_index) = offset_index {
+                    let start_pos = self.buf.seek(SeekFrom::Current(0))?;
+                    {
+                        let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
+                        offset_index.write_to_out_protocol(&mut protocol)?;
+                        protocol.flush()?;
+                    }
+                    let end_pos = self.buf.seek(SeekFrom::Current(0))?;
+                    column.offset_index_offset = Some(start_pos as i64);
+                    column.offset_index_length = Some((end_pos - start_pos) as i32);
+                }
+            }
+        }
         Ok(())
     }
 
     /// Assembles and writes metadata at the end of the file.
     fn write_metadata(&mut self) -> Result<parquet::FileMetaData> {
+        let mut row_groups = self
+            .row_groups
+            .as_slice()
+            .iter()
+            .map(|v| v.to_thrift())
+            .collect::<Vec<_>>();
+
+        // Write the bloom filters and the page index before the file metadata
+        self.write_bloom_filters(&mut row_groups)?;
+        self.write_column_indexes(&mut row_groups)?;
+        self.write_offset_indexes(&mut row_groups)?;
+
+        #[cfg(feature = "encryption")]
+        let file_encryptor = self.file_encryptor.clone();
+        #[cfg(feature = "encryption")]
+        if let Some(file_encryptor) = &file_encryptor {
+            self.encrypt_column_metadata(file_encryptor, &mut row_groups)?;
+        }
+
         let file_metadata = parquet::FileMetaData {
             version: self.props.writer_version().as_num(),
             schema: types::to_thrift(self.schema.as_ref())?,
             num_rows: self.total_num_rows as i64,
-            row_groups: self
-                .row_groups
-                .as_slice()
-                .iter()
-                .map(|v| v.to_thrift())
-                .collect(),
+            row_groups,
             key_value_metadata: self.props.key_value_metadata().to_owned(),
             created_by: Some(self.props.created_by().to_owned()),
             column_orders: None,
+            encryption_algorithm: None,
+            footer_signing_key_metadata: None,
         };
 
         // Write file metadata
+        #[cfg(feature = "encryption")]
+        if let Some(file_encryptor) = &file_encryptor {
+            let metadata_len =
+                self.write_encrypted_footer(file_encryptor, &file_metadata)?;
+            let mut footer_buffer: [u8; FOOTER_SIZE] = [0; FOOTER_SIZE];
+            LittleEndian::write_i32(&mut footer_buffer, metadata_len as i32);
+            (&mut footer_buffer[4..]).write_all(&PARQUET_MAGIC_ENCR_FOOTER)?;
+            self.buf.write_all(&footer_buffer)?;
+            return Ok(file_metadata);
+        }
+
         let start_pos = self.buf.seek(SeekFrom::Current(0))?;
         {
             let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
@@ -235,11 +692,24 @@ impl<W: 'static + ParquetWriter> FileWriter for SerializedFileWriter<W> {
     fn next_row_group(&mut self) -> Result<Box<dyn RowGroupWriter>> {
         self.assert_closed()?;
         self.assert_previous_writer_closed()?;
-        let row_group_writer = SerializedRowGroupWriter::new(
+        let mut row_group_writer = SerializedRowGroupWriter::new(
             self.descr.clone(),
             self.props.clone(),
             &self.buf,
         );
+        // the ordinal is only recorded while it fits in the thrift field
+        row_group_writer.ordinal = i16::try_from(self.row_groups.len()).ok();
+        #[cfg(feature = "encryption")]
+        {
+            if self.file_encryptor.is_some() && row_group_writer.ordinal.is_none() {
+                return Err(general_err!(
+                    "Encrypted row group ordinal {} exceeds the maximum of {}",
+                    self.row_groups.len(),
+                    i16::MAX
+                ));
+            }
+            row_group_writer.file_encryptor = self.file_encryptor.clone();
+        }
         self.previous_writer_closed = false;
         Ok(Box::new(row_group_writer))
     }
@@ -265,6 +735,269 @@ impl<W: 'static + ParquetWriter> FileWriter for SerializedFileWriter<W> {
     }
 }
 
+/// Copies the encoded pages of `column` from `reader` verbatim to the sink of
+/// `page_writer`, and returns the metadata of the copied column chunk.
+fn copy_column_chunk<R: ChunkReader, T: Write + Position>(
+    reader: &R,
+    column: &ColumnChunkMetaData,
+    descr: ColumnDescPtr,
+    page_writer: &mut SerializedPageWriter<T>,
+) -> Result<ColumnChunkMetaData> {
+    let (start, length) = column.byte_range();
+    let data = reader.get_bytes(start, length as usize)?;
+    // All the offsets of the column chunk are shifted by the same amount
+    let shift = page_writer.sink.pos() as i64 - start as i64;
+    page_writer.sink.write_all(&data)?;
+
+    let mut builder = ColumnChunkMetaData::builder(descr)
+        .set_compression(column.compression())
+        .set_encodings(column.encodings().clone())
+        .set_file_offset(page_writer.sink.pos() as i64)
+        .set_total_compressed_size(column.compressed_size())
+        .set_total_uncompressed_size(column.uncompressed_size())
+        .set_num_values(column.num_values())
+        .set_data_page_offset(column.data_page_offset() + shift)
+        .set_dictionary_page_offset(column.dictionary_page_offset().map(|v| v + shift))
+        .set_index_page_offset(column.index_page_offset().map(|v| v + shift));
+    if let Some(statistics) = column.statistics() {
+        builder = builder.set_statistics(statistics.clone());
+    }
+    builder.build()
+}
+
+/// Copies the dictionary page of `column` and its data pages with `locations` that
+/// contain `rows` of the `num_rows` rows of the row group from `reader` to
+/// `page_writer`, and returns the metadata of the copied column chunk along with the
+/// locations of its data pages.
+fn copy_column_chunk_pages<R: ChunkReader, T: Write + Position>(
+    reader: &R,
+    column: &ColumnChunkMetaData,
+    descr: ColumnDescPtr,
+    locations: &[PageLocation],
+    rows: &Range<usize>,
+    num_rows: usize,
+    page_writer: &mut SerializedPageWriter<T>,
+) -> Result<(ColumnChunkMetaData, Vec<PageLocation>)> {
+    let page_starting_at = |row: usize| {
+        locations
+            .iter()
+            .position(|l| l.first_row_index as usize == row)
+    };
+    let end = if rows.end == num_rows {
+        Some(locations.len())
+    } else {
+        page_starting_at(rows.end)
+    };
+    let pages = match (page_starting_at(rows.start), end) {
+        (Some(start), Some(end)) if start < end => &locations[start..end],
+        _ => {
+            return Err(general_err!(
+                "The rows {:?} do not start and end on a page boundary of column {}",
+                rows,
+                column.column_path().string()
+            ))
+        }
+    };
+
+    let mut total_compressed_size = 0;
+    let mut total_uncompressed_size = 0;
+    let mut dictionary_page_offset = None;
+    if let Some(range) = column.dictionary_page_range() {
+        let data = reader.get_bytes(range.start, (range.end - range.start) as usize)?;
+        let (header, header_len) = read_page_header(&data)?;
+        dictionary_page_offset = Some(page_writer.sink.pos() as i64);
+        page_writer.sink.write_all(&data)?;
+        total_compressed_size += data.len() as i64;
+        total_uncompressed_size +=
+            (header_len + header.uncompressed_page_size as usize) as i64;
+    }
+
+    // The data pages are next to each other, so they are read at once
+    let start = pages[0].offset;
+    let last = &pages[pages.len() - 1];
+    let length = last.offset + last.compressed_page_size as i64 - start;
+    let data = reader.get_bytes(start as u64, length as usize)?;
+    // All the offsets of the data pages are shifted by the same amount
+    let shift = page_writer.sink.pos() as i64 - start;
+    page_writer.sink.write_all(&data)?;
+
+    let mut num_values = 0;
+    let mut copied = Vec::with_capacity(pages.len());
+    for page in pages {
+        let page_start = (page.offset - start) as usize;
+        let (header, header_len) = read_page_header(&data[page_start..])?;
+        num_values += data_page_num_values(&header)? as i64;
+        total_uncompressed_size +=
+            (header_len + header.uncompressed_page_size as usize) as i64;
+        copied.push(PageLocation {
+            offset: page.offset + shift,
+            compressed_page_size: page.compressed_page_size,
+            first_row_index: page.first_row_index - rows.start as i64,
+        });
+    }
+    total_compressed_size += data.len() as i64;
+
+    let column_chunk = ColumnChunkMetaData::builder(descr)
+        .set_compression(column.compression())
+        .set_encodings(column.encodings().clone())
+        .set_file_offset(page_writer.sink.pos() as i64)
+        .set_total_compressed_size(total_compressed_size)
+        .set_total_uncompressed_size(total_uncompressed_size)
+        .set_num_values(num_values)
+        .set_data_page_offset(copied[0].offset)
+        .set_dictionary_page_offset(dictionary_page_offset)
+        .build()?;
+    Ok((column_chunk, copied))
+}
+
+/// Reads the page header at the start of `data`, returning it along with its length in
+/// bytes.
+fn read_page_header(data: &[u8]) -> Result<(parquet::PageHeader, usize)> {
+    let mut remaining = data;
+    let header = {
+        let mut prot = TCompactInputProtocol::new(&mut remaining);
+        parquet::PageHeader::read_from_in_protocol(&mut prot)?
+    };
+    Ok((header, data.len() - remaining.len()))
+}
+
+/// Copies the pages of `column` from `reader` to `page_writer`, compressing them
+/// again with `codec`, and returns the metadata of the copied column chunk.
+fn recompress_column_chunk<R: ChunkReader, T: Write + Position>(
+    reader: &R,
+    column: &ColumnChunkMetaData,
+    descr: ColumnDescPtr,
+    codec: Compression,
+    page_writer: &mut SerializedPageWriter<T>,
+) -> Result<ColumnChunkMetaData> {
+    let (start, length) = column.byte_range();
+    let mut page_reader = SerializedPageReader::new(
+        reader.get_read(start, length as usize)?,
+        column.num_values(),
+        column.compression(),
+        descr.physical_type(),
+    )?;
+    let mut compressor = create_codec(codec)?;
+
+    let mut total_compressed_size = 0;
+    let mut total_uncompressed_size = 0;
+    let mut data_page_offset = None;
+    let mut dictionary_page_offset = None;
+    while let Some(page) = page_reader.get_next_page()? {
+        let page = compress_page(page, compressor.as_mut())?;
+        let page_spec = page_writer.write_page(page)?;
+        total_compressed_size += page_spec.compressed_size as i64;
+        total_uncompressed_size += page_spec.uncompressed_size as i64;
+        match page_spec.page_type {
+            PageType::DICTIONARY_PAGE => {
+                dictionary_page_offset = Some(page_spec.offset as i64)
+            }
+            _ => {
+                if data_page_offset.is_none() {
+                    data_page_offset = Some(page_spec.offset as i64);
+                }
+            }
+        }
+    }
+
+    let mut builder = ColumnChunkMetaData::builder(descr)
+        .set_compression(codec)
+        .set_encodings(column.encodings().clone())
+        .set_file_offset(page_writer.sink.pos() as i64)
+        .set_total_compressed_size(total_compressed_size)
+        .set_total_uncompressed_size(total_uncompressed_size)
+        .set_num_values(column.num_values())
+        .set_data_page_offset(data_page_offset.unwrap_or(0))
+        .set_dictionary_page_offset(dictionary_page_offset);
+    if let Some(statistics) = column.statistics() {
+        builder = builder.set_statistics(statistics.clone());
+    }
+    builder.build()
+}
+
+/// Compresses the buffer of a decompressed page with `compressor`, or leaves it
+/// uncompressed if `compressor` is `None`.
+fn compress_page(
+    page: Page,
+    compressor: Option<&mut Box<dyn Codec>>,
+) -> Result<CompressedPage> {
+    let uncompressed_size = page.buffer().len();
+    let is_compressed = compressor.is_some();
+    let mut compressor = compressor;
+    let mut compress = |data: &[u8], output: &mut Vec<u8>| match compressor {
+        Some(ref mut cmpr) => cmpr.compress(data, output),
+        None => {
+            output.extend_from_slice(data);
+            Ok(())
+        }
+    };
+
+    let page = match page {
+        Page::DataPage {
+            buf,
+            num_values,
+            encoding,
+            def_level_encoding,
+            rep_level_encoding,
+            statistics,
+        } => {
+            let mut output = Vec::with_capacity(uncompressed_size);
+            compress(buf.data(), &mut output)?;
+            Page::DataPage {
+                buf: ByteBufferPtr::new(output),
+                num_values,
+                encoding,
+                def_level_encoding,
+                rep_level_encoding,
+                statistics,
+            }
+        }
+        Page::DataPageV2 {
+            buf,
+            num_values,
+            encoding,
+            num_nulls,
+            num_rows,
+            def_levels_byte_len,
+            rep_levels_byte_len,
+            statistics,
+            ..
+        } => {
+            // Data Page v2 compresses values only.
+            let levels_byte_len = (def_levels_byte_len + rep_levels_byte_len) as usize;
+            let mut output = buf.data()[..levels_byte_len].to_vec();
+            compress(&buf.data()[levels_byte_len..], &mut output)?;
+            Page::DataPageV2 {
+                buf: ByteBufferPtr::new(output),
+                num_values,
+                encoding,
+                num_nulls,
+                num_rows,
+                def_levels_byte_len,
+                rep_levels_byte_len,
+                is_compressed,
+                statistics,
+            }
+        }
+        Page::DictionaryPage {
+            buf,
+            num_values,
+            encoding,
+            is_sorted,
+        } => {
+            let mut output = Vec::with_capacity(uncompressed_size);
+            compress(buf.data(), &mut output)?;
+            Page::DictionaryPage {
+                buf: ByteBufferPtr::new(output),
+                num_values,
+                encoding,
+                is_sorted,
+            }
+        }
+    };
+    Ok(CompressedPage::new(page, uncompressed_size))
+}
+
 /// A serialized implementation for Parquet [`RowGroupWriter`].
 /// Coordinates writing of a row group with column writers.
 /// See documentation on row group writer for more information.
@@ -278,6 +1011,12 @@ pub struct SerializedRowGroupWriter<W: ParquetWriter> {
     previous_writer_closed: bool,
     row_group_metadata: Option<RowGroupMetaDataPtr>,
     column_chunks: Vec<ColumnChunkMetaData>,
+    column_indexes: Vec<Option<parquet::ColumnIndex>>,
+    offset_indexes: Vec<Option<parquet::OffsetIndex>>,
+    bloom_filters: Vec<Option<Sbbf>>,
+    ordinal: Option<i16>,
+    #[cfg(feature = "encryption")]
+    file_encryptor: Option<Arc<FileEncryptor>>,
 }
 
 impl<W: 'static + ParquetWriter> SerializedRowGroupWriter<W> {
@@ -297,25 +1036,45 @@ impl<W: 'static + ParquetWriter> SerializedRowGroupWriter<W> {
             previous_writer_closed: true,
             row_group_metadata: None,
             column_chunks: Vec::with_capacity(num_columns),
+            column_indexes: Vec::with_capacity(num_columns),
+            offset_indexes: Vec::with_capacity(num_columns),
+            bloom_filters: Vec::with_capacity(num_columns),
+            ordinal: None,
+            #[cfg(feature = "encryption")]
+            file_encryptor: None,
         }
     }
 
     /// Checks and finalises current column writer.
     fn finalise_column_writer(&mut self, writer: ColumnWriter) -> Result<()> {
-        let (bytes_written, rows_written, metadata) = match writer {
-            ColumnWriter::BoolColumnWriter(typed) => typed.close()?,
-            ColumnWriter::Int32ColumnWriter(typed) => typed.close()?,
-            ColumnWriter::Int64ColumnWriter(typed) => typed.close()?,
-            ColumnWriter::Int96ColumnWriter(typed) => typed.close()?,
-            ColumnWriter::FloatColumnWriter(typed) => typed.close()?,
-            ColumnWriter::DoubleColumnWriter(typed) => typed.close()?,
-            ColumnWriter::ByteArrayColumnWriter(typed) => typed.close()?,
-            ColumnWriter::FixedLenByteArrayColumnWriter(typed) => typed.close()?,
+        let ColumnCloseResult {
+            bytes_written,
+            rows_written,
+            metadata,
+            column_index,
+            offset_index,
+            bloom_filter,
+        } = match writer {
+            ColumnWriter::BoolColumnWriter(typed) => typed.close_with_page_index()?,
+            ColumnWriter::Int32ColumnWriter(typed) => typed.close_with_page_index()?,
+            ColumnWriter::Int64ColumnWriter(typed) => typed.close_with_page_index()?,
+            ColumnWriter::Int96ColumnWriter(typed) => typed.close_with_page_index()?,
+            ColumnWriter::FloatColumnWriter(typed) => typed.close_with_page_index()?,
+            ColumnWriter::DoubleColumnWriter(typed) => typed.close_with_page_index()?,
+            ColumnWriter::ByteArrayColumnWriter(typed) => {
+                typed.close_with_page_index()?
+            }
+            ColumnWriter::FixedLenByteArrayColumnWriter(typed) => {
+                typed.close_with_page_index()?
+            }
         };
 
         // Update row group writer metrics
         self.total_bytes_written += bytes_written;
         self.column_chunks.push(metadata);
+        self.column_indexes.push(column_index);
+        self.offset_indexes.push(offset_index);
+        self.bloom_filters.push(bloom_filter);
         if let Some(rows) = self.total_rows_written {
             if rows != rows_written {
                 return Err(general_err!(
@@ -360,12 +1119,22 @@ impl<W: 'static + ParquetWriter> RowGroupWriter for SerializedRowGroupWriter<W>
             return Ok(None);
         }
         let sink = FileSink::new(&self.buf);
-        let page_writer = Box::new(SerializedPageWriter::new(sink));
-        let column_writer = get_column_writer(
-            self.descr.column(self.column_index),
-            self.props.clone(),
-            page_writer,
-        );
+        #[cfg_attr(not(feature = "encryption"), allow(unused_mut))]
+        let mut page_writer = SerializedPageWriter::new(sink);
+        let descr = self.descr.column(self.column_index);
+        #[cfg(feature = "encryption")]
+        if let Some(file_encryptor) = &self.file_encryptor {
+            if let Some(encryptor) = file_encryptor.column_encryptor(descr.path()) {
+                page_writer.page_encryptor = Some(PageEncryptor::new(
+                    file_encryptor,
+                    encryptor,
+                    self.ordinal.unwrap_or_default() as usize,
+                    self.column_index,
+                ));
+            }
+        }
+        let column_writer =
+            get_column_writer(descr, self.props.clone(), Box::new(page_writer));
         self.column_index += 1;
         self.previous_writer_closed = false;
 
@@ -385,11 +1154,14 @@ impl<W: 'static + ParquetWriter> RowGroupWriter for SerializedRowGroupWriter<W>
             self.assert_previous_writer_closed()?;
 
             let column_chunks = std::mem::take(&mut self.column_chunks);
-            let row_group_metadata = RowGroupMetaData::builder(self.descr.clone())
+            let mut builder = RowGroupMetaData::builder(self.descr.clone())
                 .set_column_metadata(column_chunks)
                 .set_total_byte_size(self.total_bytes_written as i64)
-                .set_num_rows(self.total_rows_written.unwrap_or(0) as i64)
-                .build()?;
+                .set_num_rows(self.total_rows_written.unwrap_or(0) as i64);
+            if let Some(ordinal) = self.ordinal {
+                builder = builder.set_ordinal(ordinal);
+            }
+            let row_group_metadata = builder.build()?;
 
             self.row_group_metadata = Some(Arc::new(row_group_metadata));
         }
@@ -397,6 +1169,22 @@ impl<W: 'static + ParquetWriter> RowGroupWriter for SerializedRowGroupWriter<W>
         let metadata = self.row_group_metadata.as_ref().unwrap().clone();
         Ok(metadata)
     }
+
+    fn take_page_index(
+        &mut self,
+    ) -> (
+        Vec<Option<parquet::ColumnIndex>>,
+        Vec<Option<parquet::OffsetIndex>>,
+    ) {
+        (
+            std::mem::take(&mut self.column_indexes),
+            std::mem::take(&mut self.offset_indexes),
+        )
+    }
+
+    fn take_bloom_filters(&mut self) -> Vec<Option<Sbbf>> {
+        std::mem::take(&mut self.bloom_filters)
+    }
 }
 
 /// A serialized implementation for Parquet [`PageWriter`].
@@ -405,12 +1193,49 @@ impl<W: 'static + ParquetWriter> RowGroupWriter for SerializedRowGroupWriter<W>
 /// `SerializedPageWriter` should not be used after calling `close()`.
 pub struct SerializedPageWriter<T: Write + Position> {
     sink: T,
+    #[cfg(feature = "encryption")]
+    page_encryptor: Option<PageEncryptor>,
 }
 
 impl<T: Write + Position> SerializedPageWriter<T> {
     /// Creates new page writer.
     pub fn new(sink: T) -> Self {
-        Self { sink }
+        Self {
+            sink,
+            #[cfg(feature = "encryption")]
+            page_encryptor: None,
+        }
+    }
+
+    /// Writes the page header followed by the page data, encrypting both if the
+    /// column chunk is encrypted.
+    /// Returns the number of bytes of the header and of the data written.
+    fn write_page_and_header(
+        &mut self,
+        header: parquet::PageHeader,
+        data: &[u8],
+    ) -> Result<(usize, usize)> {
+        #[cfg(feature = "encryption")]
+        if let Some(page_encryptor) = self.page_encryptor.as_mut() {
+            let mut header = header;
+            let data = page_encryptor.encrypt_page(header.type_, data)?;
+            header.compressed_page_size = data.len() as i32;
+            let mut header_buf = Vec::new();
+            {
+                let mut protocol = TCompactOutputProtocol::new(&mut header_buf);
+                header.write_to_out_protocol(&mut protocol)?;
+                protocol.flush()?;
+            }
+            let header_buf =
+                page_encryptor.encrypt_page_header(header.type_, &header_buf)?;
+            self.sink.write_all(&header_buf)?;
+            self.sink.write_all(&data)?;
+            return Ok((header_buf.len(), data.len()));
+        }
+
+        let header_size = self.serialize_page_header(header)?;
+        self.sink.write_all(data)?;
+        Ok((header_size, data.len()))
     }
 
     /// Serializes page header into Thrift.
@@ -506,13 +1331,13 @@ impl<T: Write + Position> PageWriter for SerializedPageWriter<T> {
 
         let start_pos = self.sink.pos();
 
-        let header_size = self.serialize_page_header(page_header)?;
-        self.sink.write_all(page.data())?;
+        let (header_size, data_size) =
+            self.write_page_and_header(page_header, page.data())?;
 
         let mut spec = PageWriteSpec::new();
         spec.page_type = page_type;
         spec.uncompressed_size = uncompressed_size + header_size;
-        spec.compressed_size = compressed_size + header_size;
+        spec.compressed_size = data_size + header_size;
         spec.offset = start_pos;
         spec.bytes_written = self.sink.pos() - start_pos;
         // Number of values is incremented for data pages only
@@ -524,6 +1349,11 @@ impl<T: Write + Position> PageWriter for SerializedPageWriter<T> {
     }
 
     fn write_metadata(&mut self, metadata: &ColumnChunkMetaData) -> Result<()> {
+        // The metadata of encrypted column chunks is only written to the footer
+        #[cfg(feature = "encryption")]
+        if self.page_encryptor.is_some() {
+            return Ok(());
+        }
         self.serialize_column_chunk(metadata.to_thrift())
     }
 
@@ -543,12 +1373,17 @@ mod tests {
     use crate::column::page::PageReader;
     use crate::compression::{create_codec, Codec};
     use crate::file::{
+        page_index::index::Index,
         properties::{WriterProperties, WriterVersion},
         reader::{FileReader, SerializedFileReader, SerializedPageReader},
         statistics::{from_thrift, to_thrift, Statistics},
     };
     use crate::record::RowAccessor;
-    use crate::util::{memory::ByteBufferPtr, test_common::get_temp_file};
+    use crate::schema::types::ColumnPath;
+    use crate::util::{
+        memory::ByteBufferPtr,
+        test_common::{get_temp_file, get_test_file},
+    };
 
     #[test]
     fn test_file_writer_error_after_close() {
@@ -1075,6 +1910,488 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_file_writer_append_row_group() {
+        let source = get_test_file("alltypes_plain.parquet");
+        for compression in &[None, Some(Compression::SNAPPY), Some(Compression::GZIP)] {
+            test_append_row_groups(&source, *compression);
+        }
+    }
+
+    #[test]
+    fn test_file_writer_append_row_group_v2_pages() {
+        let schema = Arc::new(
+            types::Type::group_type_builder("schema")
+                .with_fields(&mut vec![Arc::new(
+                    types::Type::primitive_type_builder("col1", Type::INT32)
+                        .with_repetition(Repetition::OPTIONAL)
+                        .build()
+                        .unwrap(),
+                )])
+                .build()
+                .unwrap(),
+        );
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_writer_version(WriterVersion::PARQUET_2_0)
+                .set_compression(Compression::SNAPPY)
+                .build(),
+        );
+        let source = get_temp_file("test_file_writer_append_row_group_v2_pages", &[]);
+        let mut writer =
+            SerializedFileWriter::new(source.try_clone().unwrap(), schema, props)
+                .unwrap();
+        for _ in 0..2 {
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+            if let ColumnWriter::Int32ColumnWriter(ref mut typed) = col_writer {
+                typed
+                    .write_batch(&[1, 2, 1, 3], Some(&[1, 0, 1, 1, 0, 1]), None)
+                    .unwrap();
+            }
+            row_group_writer.close_column(col_writer).unwrap();
+            writer.close_row_group(row_group_writer).unwrap();
+        }
+        writer.close().unwrap();
+
+        for compression in &[None, Some(Compression::UNCOMPRESSED)] {
+            test_append_row_groups(&source, *compression);
+        }
+
+        // A row group with different columns cannot be appended
+        let reader = SerializedFileReader::new(source.try_clone().unwrap()).unwrap();
+        let other_schema = Arc::new(
+            types::Type::group_type_builder("schema")
+                .with_fields(&mut vec![Arc::new(
+                    types::Type::primitive_type_builder("col1", Type::INT64)
+                        .with_repetition(Repetition::OPTIONAL)
+                        .build()
+                        .unwrap(),
+                )])
+                .build()
+                .unwrap(),
+        );
+        let output = get_temp_file("test_file_writer_append_row_group_mismatch", &[]);
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut writer = SerializedFileWriter::new(output, other_schema, props).unwrap();
+        let res = writer.append_row_group(&source, reader.metadata().row_group(0), None);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Parquet error: Column col1 of the row group does not match column col1 of \
+             the schema"
+        );
+    }
+
+    /// Appends all the row groups of `source` twice to a new file, and checks that the
+    /// new file contains the rows of `source` twice.
+    fn test_append_row_groups(source: &File, compression: Option<Compression>) {
+        let reader = SerializedFileReader::new(source.try_clone().unwrap()).unwrap();
+        let metadata = reader.metadata();
+        let schema = metadata.file_metadata().schema_descr().root_schema_ptr();
+        let expected = reader.get_row_iter(None).unwrap().collect::<Vec<_>>();
+
+        let output = get_temp_file("test_append_row_groups", &[]);
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut writer =
+            SerializedFileWriter::new(output.try_clone().unwrap(), schema, props)
+                .unwrap();
+        for _ in 0..2 {
+            for row_group in metadata.row_groups() {
+                writer
+                    .append_row_group(source, row_group, compression)
+                    .unwrap();
+            }
+        }
+        writer.close().unwrap();
+
+        let copy = SerializedFileReader::new(output).unwrap();
+        assert_eq!(copy.num_row_groups(), 2 * metadata.num_row_groups());
+        assert_eq!(
+            copy.metadata().file_metadata().num_rows(),
+            2 * metadata.file_metadata().num_rows()
+        );
+        for (i, row_group) in copy.metadata().row_groups().iter().enumerate() {
+            let source_row_group = metadata.row_group(i % metadata.num_row_groups());
+            assert_eq!(row_group.num_rows(), source_row_group.num_rows());
+            for (column, source_column) in
+                row_group.columns().iter().zip(source_row_group.columns())
+            {
+                let expected_compression =
+                    compression.unwrap_or_else(|| source_column.compression());
+                assert_eq!(column.compression(), expected_compression);
+                assert_eq!(column.num_values(), source_column.num_values());
+                assert_eq!(column.statistics(), source_column.statistics());
+            }
+        }
+        let rows = copy.get_row_iter(None).unwrap().collect::<Vec<_>>();
+        assert_eq!(rows.len(), 2 * expected.len());
+        assert_eq!(&rows[..expected.len()], &expected[..]);
+        assert_eq!(&rows[expected.len()..], &expected[..]);
+    }
+
+    #[test]
+    fn test_file_writer_with_page_index() {
+        let cursor = InMemoryWriteableCursor::default();
+
+        let schema = Arc::new(
+            types::Type::group_type_builder("schema")
+                .with_fields(&mut vec![Arc::new(
+                    types::Type::primitive_type_builder("col1", Type::INT32)
+                        .with_repetition(Repetition::OPTIONAL)
+                        .build()
+                        .unwrap(),
+                )])
+                .build()
+                .unwrap(),
+        );
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_page_index_enabled(true)
+                .set_dictionary_enabled(false)
+                .set_data_pagesize_limit(1)
+                .set_write_batch_size(2)
+                .build(),
+        );
+
+        {
+            let mut writer =
+                SerializedFileWriter::new(cursor.clone(), schema, props).unwrap();
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+            if let ColumnWriter::Int32ColumnWriter(ref mut typed) = col_writer {
+                // Pages: [1, 2], [null, null, 3, 4], [null, null]
+                typed
+                    .write_batch(&[1, 2, 3, 4], Some(&[1, 1, 0, 0, 1, 1]), None)
+                    .unwrap();
+                typed.write_batch(&[], Some(&[0, 0]), None).unwrap();
+            } else {
+                unreachable!();
+            }
+            row_group_writer.close_column(col_writer).unwrap();
+            writer.close_row_group(row_group_writer).unwrap();
+            writer.close().unwrap();
+        }
+
+        let buffer = cursor.into_inner().unwrap();
+        let reading_cursor = crate::file::serialized_reader::SliceableCursor::new(buffer);
+        let reader = SerializedFileReader::new_with_page_index(reading_cursor).unwrap();
+        let metadata = reader.metadata();
+
+        let column = metadata.row_group(0).column(0);
+        assert!(column.column_index_offset().is_some());
+        assert!(column.offset_index_offset().is_some());
+
+        let page_indexes = metadata.page_indexes().unwrap();
+        match &page_indexes[0][0] {
+            Index::INT32(index) => {
+                let pages = &index.indexes;
+                assert_eq!(pages.len(), 3);
+                assert_eq!(pages[0].min(), Some(&1));
+                assert_eq!(pages[0].max(), Some(&2));
+                assert_eq!(pages[0].null_count(), Some(0));
+                assert_eq!(pages[1].min(), Some(&3));
+                assert_eq!(pages[1].max(), Some(&4));
+                assert_eq!(pages[1].null_count(), Some(2));
+                assert_eq!(pages[2].min(), None);
+                assert_eq!(pages[2].max(), None);
+                assert_eq!(pages[2].null_count(), Some(2));
+            }
+            _ => panic!("expected an INT32 column index"),
+        }
+
+        let locations = &metadata.offset_indexes().unwrap()[0][0];
+        let first_row_indexes: Vec<_> =
+            locations.iter().map(|l| l.first_row_index).collect();
+        assert_eq!(first_row_indexes, vec![0, 2, 6]);
+        assert_eq!(locations[0].offset, column.data_page_offset());
+        for pair in locations.windows(2) {
+            assert_eq!(
+                pair[0].offset + pair[0].compressed_page_size as i64,
+                pair[1].offset
+            );
+        }
+    }
+
+    #[test]
+    fn test_file_writer_append_row_group_pages() {
+        let cursor = InMemoryWriteableCursor::default();
+        let schema = Arc::new(
+            types::Type::group_type_builder("schema")
+                .with_fields(&mut vec![Arc::new(
+                    types::Type::primitive_type_builder("col1", Type::INT32)
+                        .with_repetition(Repetition::OPTIONAL)
+                        .build()
+                        .unwrap(),
+                )])
+                .build()
+                .unwrap(),
+        );
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_page_index_enabled(true)
+                .set_data_pagesize_limit(1)
+                .set_write_batch_size(2)
+                .build(),
+        );
+        {
+            let mut writer =
+                SerializedFileWriter::new(cursor.clone(), schema.clone(), props).unwrap();
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+            if let ColumnWriter::Int32ColumnWriter(ref mut typed) = col_writer {
+                typed
+                    .write_batch(&[1, 2, 3, 4], Some(&[1, 1, 0, 0, 1, 1]), None)
+                    .unwrap();
+                typed.write_batch(&[1], Some(&[0, 1]), None).unwrap();
+            } else {
+                unreachable!();
+            }
+            row_group_writer.close_column(col_writer).unwrap();
+            writer.close_row_group(row_group_writer).unwrap();
+            writer.close().unwrap();
+        }
+
+        let buffer = cursor.into_inner().unwrap();
+        let source = crate::file::serialized_reader::SliceableCursor::new(buffer.clone());
+        let reader = SerializedFileReader::new_with_page_index(
+            crate::file::serialized_reader::SliceableCursor::new(buffer),
+        )
+        .unwrap();
+        let metadata = reader.metadata();
+        let row_group = metadata.row_group(0);
+        assert!(row_group.column(0).has_dictionary_page());
+        let locations = &metadata.offset_indexes().unwrap()[0];
+        assert!(locations[0].len() > 1);
+        let rows =
+            locations[0][1].first_row_index as usize..row_group.num_rows() as usize;
+        let expected = reader
+            .get_row_iter(None)
+            .unwrap()
+            .skip(rows.start)
+            .collect::<Vec<_>>();
+
+        let output = InMemoryWriteableCursor::default();
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut writer =
+            SerializedFileWriter::new(output.clone(), schema, props).unwrap();
+        // the first two rows are written to the same page
+        let res =
+            writer.append_row_group_pages(&source, row_group, locations, 1..rows.end);
+        assert!(res.is_err());
+        writer
+            .append_row_group_pages(&source, row_group, locations, rows.clone())
+            .unwrap();
+        writer.close().unwrap();
+
+        let copy = SerializedFileReader::new_with_page_index(
+            crate::file::serialized_reader::SliceableCursor::new(output.data()),
+        )
+        .unwrap();
+        let copy_metadata = copy.metadata();
+        assert_eq!(copy_metadata.file_metadata().num_rows(), rows.len() as i64);
+        let column = copy_metadata.row_group(0).column(0);
+        assert!(column.has_dictionary_page());
+        assert!(column.statistics().is_none());
+        let copied_locations = &copy_metadata.offset_indexes().unwrap()[0][0];
+        assert_eq!(copied_locations.len(), locations[0].len() - 1);
+        assert_eq!(copied_locations[0].first_row_index, 0);
+        assert_eq!(copied_locations[0].offset, column.data_page_offset());
+        assert_eq!(
+            copy.get_row_iter(None).unwrap().collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_file_writer_with_bloom_filter() {
+        let cursor = InMemoryWriteableCursor::default();
+
+        let schema = Arc::new(
+            types::Type::group_type_builder("schema")
+                .with_fields(&mut vec![
+                    Arc::new(
+                        types::Type::primitive_type_builder("col1", Type::INT32)
+                            .with_repetition(Repetition::REQUIRED)
+                            .build()
+                            .unwrap(),
+                    ),
+                    Arc::new(
+                        types::Type::primitive_type_builder("col2", Type::INT32)
+                            .with_repetition(Repetition::REQUIRED)
+                            .build()
+                            .unwrap(),
+                    ),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_column_bloom_filter_enabled(ColumnPath::from("col1"), true)
+                .set_bloom_filter_ndv(100)
+                .build(),
+        );
+        let data: Vec<i32> = (0..100).map(|i| i * 2).collect();
+
+        {
+            let mut writer =
+                SerializedFileWriter::new(cursor.clone(), schema, props).unwrap();
+            let mut row_group_writer = writer.next_row_group().unwrap();
+            while let Some(mut col_writer) = row_group_writer.next_column().unwrap() {
+                if let ColumnWriter::Int32ColumnWriter(ref mut typed) = col_writer {
+                    typed.write_batch(&data, None, None).unwrap();
+                } else {
+                    unreachable!();
+                }
+                row_group_writer.close_column(col_writer).unwrap();
+            }
+            writer.close_row_group(row_group_writer).unwrap();
+            writer.close().unwrap();
+        }
+
+        let buffer = cursor.into_inner().unwrap();
+        let reading_cursor = crate::file::serialized_reader::SliceableCursor::new(buffer);
+        let metadata = crate::file::footer::parse_metadata(&reading_cursor).unwrap();
+        let row_group = metadata.row_group(0);
+
+        let bloom_filter = row_group
+            .column(0)
+            .read_bloom_filter(&reading_cursor)
+            .unwrap()
+            .unwrap();
+        for value in &data {
+            assert!(bloom_filter.check(value));
+        }
+        let false_positives = (1000..2000)
+            .filter(|value: &i32| bloom_filter.check(value))
+            .count();
+        assert!(false_positives < 200, "{} false positives", false_positives);
+
+        assert!(row_group.column(1).bloom_filter_offset().is_none());
+        assert!(row_group
+            .column(1)
+            .read_bloom_filter(&reading_cursor)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "encryption")]
+    fn test_file_writer_with_encryption() {
+        use crate::encryption::decrypt::{FileDecryptionProperties, KeyRetriever};
+        use crate::encryption::encrypt::FileEncryptionProperties;
+
+        struct TestKeyRetriever;
+
+        impl KeyRetriever for TestKeyRetriever {
+            fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>> {
+                match key_metadata {
+                    b"kf" => Ok(b"0123456789012345".to_vec()),
+                    b"kc" => Ok(b"1234567890123450".to_vec()),
+                    _ => Err(general_err!("Unknown key")),
+                }
+            }
+        }
+
+        let cursor = InMemoryWriteableCursor::default();
+        let schema = Arc::new(
+            types::Type::group_type_builder("schema")
+                .with_fields(&mut vec![
+                    Arc::new(
+                        types::Type::primitive_type_builder("col1", Type::INT32)
+                            .with_repetition(Repetition::REQUIRED)
+                            .build()
+                            .unwrap(),
+                    ),
+                    Arc::new(
+                        types::Type::primitive_type_builder("col2", Type::INT32)
+                            .with_repetition(Repetition::REQUIRED)
+                            .build()
+                            .unwrap(),
+                    ),
+                ])
+                .build()
+                .unwrap(),
+        );
+        let encryption_properties =
+            FileEncryptionProperties::builder(b"0123456789012345".to_vec())
+                .set_footer_key_metadata(b"kf".to_vec())
+                .set_column_key_with_metadata(
+                    ColumnPath::from("col1"),
+                    b"1234567890123450".to_vec(),
+                    b"kc".to_vec(),
+                )
+                .build()
+                .unwrap();
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_file_encryption_properties(encryption_properties)
+                .set_data_pagesize_limit(64)
+                .build(),
+        );
+        let data: Vec<Vec<i32>> = vec![(0..100).collect(), (100..150).collect()];
+
+        {
+            let mut writer =
+                SerializedFileWriter::new(cursor.clone(), schema, props).unwrap();
+            for values in &data {
+                let mut row_group_writer = writer.next_row_group().unwrap();
+                while let Some(mut col_writer) = row_group_writer.next_column().unwrap() {
+                    if let ColumnWriter::Int32ColumnWriter(ref mut typed) = col_writer {
+                        typed.write_batch(values, None, None).unwrap();
+                    } else {
+                        unreachable!();
+                    }
+                    row_group_writer.close_column(col_writer).unwrap();
+                }
+                writer.close_row_group(row_group_writer).unwrap();
+            }
+            writer.close().unwrap();
+        }
+
+        let buffer = cursor.into_inner().unwrap();
+        assert_eq!(&buffer[..4], b"PARE");
+        assert_eq!(&buffer[buffer.len() - 4..], b"PARE");
+
+        let reading_cursor =
+            crate::file::serialized_reader::SliceableCursor::new(buffer.clone());
+        assert!(SerializedFileReader::new(reading_cursor).is_err());
+        let reading_cursor =
+            crate::file::serialized_reader::SliceableCursor::new(buffer.clone());
+
+        let decryption_properties = FileDecryptionProperties::builder()
+            .set_key_retriever(Arc::new(TestKeyRetriever))
+            .build()
+            .unwrap();
+        let reader = SerializedFileReader::new_with_decryption(
+            reading_cursor,
+            &decryption_properties,
+        )
+        .unwrap();
+        assert_eq!(reader.num_row_groups(), 2);
+        assert_eq!(reader.metadata().row_group(1).ordinal(), Some(1));
+
+        let expected: Vec<i32> = data.iter().flatten().copied().collect();
+        let rows: Vec<_> = reader.get_row_iter(None).unwrap().collect();
+        assert_eq!(rows.len(), expected.len());
+        for (row, value) in rows.iter().zip(expected.iter()) {
+            assert_eq!(row.get_int(0).unwrap(), *value);
+            assert_eq!(row.get_int(1).unwrap(), *value);
+        }
+
+        // The footer can only be decrypted with the right key
+        let decryption_properties = FileDecryptionProperties::builder()
+            .set_footer_key(b"1234567890123450".to_vec())
+            .build()
+            .unwrap();
+        let reading_cursor = crate::file::serialized_reader::SliceableCursor::new(buffer);
+        assert!(SerializedFileReader::new_with_decryption(
+            reading_cursor,
+            &decryption_properties
+        )
+        .is_err());
+    }
+
     fn assert_send<T: Send>(t: T) -> T {
         t
     }
@@ -49,9 +49,12 @@ pub use self::util::memory;
 mod util;
 #[cfg(any(feature = "arrow", test))]
 pub mod arrow;
+pub mod bloom_filter;
 pub mod column;
 pub mod compression;
 mod encodings;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod file;
 pub mod record;
 pub mod schema;
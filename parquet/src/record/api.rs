@@ -22,7 +22,9 @@ use std::fmt;
 use chrono::{TimeZone, Utc};
 use num_bigint::{BigInt, Sign};
 
-use crate::basic::{ConvertedType, Type as PhysicalType};
+use crate::basic::{
+    ConvertedType, LogicalType, TimeUnit, TimestampType, Type as PhysicalType,
+};
 use crate::data_type::{ByteArray, Decimal, Int96};
 use crate::errors::{ParquetError, Result};
 use crate::schema::types::ColumnDescPtr;
@@ -124,6 +126,8 @@ pub trait RowAccessor {
     fn get_double(&self, i: usize) -> Result<f64>;
     fn get_timestamp_millis(&self, i: usize) -> Result<u64>;
     fn get_timestamp_micros(&self, i: usize) -> Result<u64>;
+    fn get_timestamp_nanos(&self, i: usize) -> Result<u64>;
+    fn get_date(&self, i: usize) -> Result<u32>;
     fn get_decimal(&self, i: usize) -> Result<&Decimal>;
     fn get_string(&self, i: usize) -> Result<&String>;
     fn get_bytes(&self, i: usize) -> Result<&ByteArray>;
@@ -205,6 +209,10 @@ impl RowAccessor for Row {
 
     row_primitive_accessor!(get_timestamp_micros, TimestampMicros, u64);
 
+    row_primitive_accessor!(get_timestamp_nanos, TimestampNanos, u64);
+
+    row_primitive_accessor!(get_date, Date, u32);
+
     row_complex_accessor!(get_decimal, Decimal, Decimal);
 
     row_complex_accessor!(get_string, Str, String);
@@ -279,6 +287,8 @@ pub trait ListAccessor {
     fn get_double(&self, i: usize) -> Result<f64>;
     fn get_timestamp_millis(&self, i: usize) -> Result<u64>;
     fn get_timestamp_micros(&self, i: usize) -> Result<u64>;
+    fn get_timestamp_nanos(&self, i: usize) -> Result<u64>;
+    fn get_date(&self, i: usize) -> Result<u32>;
     fn get_decimal(&self, i: usize) -> Result<&Decimal>;
     fn get_string(&self, i: usize) -> Result<&String>;
     fn get_bytes(&self, i: usize) -> Result<&ByteArray>;
@@ -348,6 +358,10 @@ impl ListAccessor for List {
 
     list_primitive_accessor!(get_timestamp_micros, TimestampMicros, u64);
 
+    list_primitive_accessor!(get_timestamp_nanos, TimestampNanos, u64);
+
+    list_primitive_accessor!(get_date, Date, u32);
+
     list_complex_accessor!(get_decimal, Decimal, Decimal);
 
     list_complex_accessor!(get_string, Str, String);
@@ -439,6 +453,10 @@ impl<'a> ListAccessor for MapList<'a> {
 
     map_list_primitive_accessor!(get_timestamp_micros, TimestampMicros, u64);
 
+    map_list_primitive_accessor!(get_timestamp_nanos, TimestampNanos, u64);
+
+    map_list_primitive_accessor!(get_date, Date, u32);
+
     list_complex_accessor!(get_decimal, Decimal, Decimal);
 
     list_complex_accessor!(get_string, Str, String);
@@ -509,6 +527,8 @@ pub enum Field {
     TimestampMillis(u64),
     /// Microseconds from the Unix epoch, 1 Janiary 1970.
     TimestampMicros(u64),
+    /// Nanoseconds from the Unix epoch, 1 January 1970.
+    TimestampNanos(u64),
 
     // ----------------------------------------------------------------------
     // Complex types
@@ -542,6 +562,7 @@ impl Field {
             Field::Bytes(_) => "Bytes",
             Field::TimestampMillis(_) => "TimestampMillis",
             Field::TimestampMicros(_) => "TimestampMicros",
+            Field::TimestampNanos(_) => "TimestampNanos",
             Field::Group(_) => "Group",
             Field::ListInternal(_) => "ListInternal",
             Field::MapInternal(_) => "MapInternal",
@@ -586,7 +607,7 @@ impl Field {
     #[inline]
     pub fn convert_int64(descr: &ColumnDescPtr, value: i64) -> Self {
         match descr.converted_type() {
-            ConvertedType::INT_64 | ConvertedType::NONE => Field::Long(value),
+            ConvertedType::INT_64 => Field::Long(value),
             ConvertedType::UINT_64 => Field::ULong(value as u64),
             ConvertedType::TIMESTAMP_MILLIS => Field::TimestampMillis(value as u64),
             ConvertedType::TIMESTAMP_MICROS => Field::TimestampMicros(value as u64),
@@ -595,6 +616,14 @@ impl Field {
                 descr.type_precision(),
                 descr.type_scale(),
             )),
+            ConvertedType::NONE => match descr.logical_type() {
+                // Nanosecond timestamps have no equivalent converted type
+                Some(LogicalType::TIMESTAMP(TimestampType {
+                    unit: TimeUnit::NANOS(_),
+                    ..
+                })) => Field::TimestampNanos(value as u64),
+                _ => Field::Long(value),
+            },
             _ => nyi!(descr, value),
         }
     }
@@ -678,6 +707,9 @@ impl Field {
             Field::TimestampMicros(ts) => {
                 Value::String(convert_timestamp_micros_to_string(*ts))
             }
+            Field::TimestampNanos(ts) => {
+                Value::String(convert_timestamp_nanos_to_string(*ts))
+            }
             Field::Group(row) => row.to_json_value(),
             Field::ListInternal(fields) => {
                 Value::Array(fields.elements.iter().map(|f| f.to_json_value()).collect())
@@ -738,6 +770,9 @@ impl fmt::Display for Field {
             Field::TimestampMicros(value) => {
                 write!(f, "{}", convert_timestamp_micros_to_string(value))
             }
+            Field::TimestampNanos(value) => {
+                write!(f, "{}", convert_timestamp_nanos_to_string(value))
+            }
             Field::Group(ref fields) => write!(f, "{}", fields),
             Field::ListInternal(ref list) => {
                 let elems = &list.elements;
@@ -794,6 +829,14 @@ fn convert_timestamp_micros_to_string(value: u64) -> String {
     convert_timestamp_millis_to_string(value / 1000)
 }
 
+/// Helper method to convert Parquet timestamp into a string.
+/// Input `value` is a number of nanoseconds since the epoch in UTC.
+/// Datetime is displayed in local timezone.
+#[inline]
+fn convert_timestamp_nanos_to_string(value: u64) -> String {
+    convert_timestamp_millis_to_string(value / 1_000_000)
+}
+
 /// Helper method to convert Parquet decimal into a string.
 /// We assert that `scale >= 0` and `precision > scale`, but this will be enforced
 /// when constructing Parquet schema.
@@ -946,6 +989,22 @@ mod tests {
             make_column_descr![PhysicalType::INT64, ConvertedType::DECIMAL, 0, 8, 2];
         let row = Field::convert_int64(&descr, 3333);
         assert_eq!(row, Field::Decimal(Decimal::from_i64(3333, 8, 2)));
+
+        let tpe = PrimitiveTypeBuilder::new("col", PhysicalType::INT64)
+            .with_logical_type(Some(LogicalType::TIMESTAMP(TimestampType {
+                is_adjusted_to_u_t_c: true,
+                unit: TimeUnit::NANOS(Default::default()),
+            })))
+            .build()
+            .unwrap();
+        let descr = Arc::new(ColumnDescriptor::new(
+            Arc::new(tpe),
+            0,
+            0,
+            ColumnPath::from("col"),
+        ));
+        let row = Field::convert_int64(&descr, 1541186529153123456);
+        assert_eq!(row, Field::TimestampNanos(1541186529153123456));
     }
 
     #[test]
@@ -1369,6 +1428,8 @@ mod tests {
                 Field::Bytes(ByteArray::from(vec![1, 2, 3, 4, 5])),
             ),
             ("o".to_string(), Field::Decimal(Decimal::from_i32(4, 7, 2))),
+            ("p".to_string(), Field::Date(14611)),
+            ("q".to_string(), Field::TimestampNanos(1262391174000000000)),
         ]);
 
         assert_eq!(false, row.get_bool(1).unwrap());
@@ -1385,6 +1446,8 @@ mod tests {
         assert_eq!("abc", row.get_string(12).unwrap());
         assert_eq!(5, row.get_bytes(13).unwrap().len());
         assert_eq!(7, row.get_decimal(14).unwrap().precision());
+        assert_eq!(14611, row.get_date(15).unwrap());
+        assert_eq!(1262391174000000000, row.get_timestamp_nanos(16).unwrap());
     }
 
     #[test]
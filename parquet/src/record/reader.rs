@@ -136,7 +136,19 @@ impl TreeBuilder {
                 .column_descr_ptr();
             let col_reader = row_group_reader.get_column_reader(orig_index).unwrap();
             let column = TripletIter::new(col_descr, col_reader, self.batch_size);
-            Reader::PrimitiveReader(field, column)
+            let reader = Reader::PrimitiveReader(field.clone(), column);
+            if repetition == Repetition::REPEATED {
+                // A repeated primitive field that is not annotated by `LIST` is
+                // interpreted as a required list of required elements
+                Reader::RepeatedReader(
+                    field,
+                    curr_def_level - 1,
+                    curr_rep_level - 1,
+                    Box::new(reader),
+                )
+            } else {
+                reader
+            }
         } else {
             match field.get_basic_info().converted_type() {
                 // List types
@@ -167,12 +179,22 @@ impl TreeBuilder {
                             row_group_reader,
                         );
 
-                        Reader::RepeatedReader(
-                            field,
-                            curr_def_level,
-                            curr_rep_level,
-                            Box::new(reader),
-                        )
+                        // The element reader is already a repeated reader, only the
+                        // type information is replaced by the one of the list, so
+                        // that the elements are not wrapped in a list twice
+                        match reader {
+                            Reader::RepeatedReader(_, def_level, rep_level, reader) => {
+                                Reader::RepeatedReader(
+                                    field, def_level, rep_level, reader,
+                                )
+                            }
+                            reader => Reader::RepeatedReader(
+                                field,
+                                curr_def_level,
+                                curr_rep_level,
+                                Box::new(reader),
+                            ),
+                        }
                     } else {
                         let child_field = repeated_field.get_fields()[0].clone();
 
@@ -285,6 +307,9 @@ impl TreeBuilder {
                         row_group_reader,
                     );
 
+                    // Restore the path, it is popped once this field is processed
+                    path.push(String::from(field.name()));
+
                     Reader::RepeatedReader(
                         field,
                         curr_def_level - 1,
@@ -818,11 +843,14 @@ impl Iterator for ReaderIter {
 mod tests {
     use super::*;
 
+    use crate::column::writer::ColumnWriter;
     use crate::errors::{ParquetError, Result};
+    use crate::file::properties::WriterProperties;
     use crate::file::reader::{FileReader, SerializedFileReader};
+    use crate::file::writer::{FileWriter, SerializedFileWriter};
     use crate::record::api::{Field, Row, RowAccessor, RowFormatter};
     use crate::schema::parser::parse_message_type;
-    use crate::util::test_common::{get_test_file, get_test_path};
+    use crate::util::test_common::{get_temp_file, get_test_file, get_test_path};
     use std::convert::TryFrom;
 
     // Convenient macros to assemble row, list, map, and group.
@@ -1648,6 +1676,126 @@ mod tests {
         assert_eq!(rows, expected_rows);
     }
 
+    #[test]
+    fn test_tree_reader_handle_repeated_fields_edge_cases() {
+        let schema = "
+          message schema {
+            REQUIRED INT32 id;
+            OPTIONAL group points (LIST) {
+              REPEATED group element {
+                REQUIRED INT32 x;
+                REQUIRED INT32 y;
+              }
+            }
+            REPEATED INT32 values;
+            REQUIRED group nested {
+              REPEATED group item {
+                REQUIRED INT32 v;
+              }
+              REQUIRED INT32 count;
+            }
+          }
+        ";
+        let schema = Arc::new(parse_message_type(schema).unwrap());
+        let columns: Vec<(Vec<i32>, Option<Vec<i16>>, Option<Vec<i16>>)> = vec![
+            (vec![1, 2, 3], None, None),
+            (vec![1, 3], Some(vec![2, 2, 0, 1]), Some(vec![0, 1, 0, 0])),
+            (vec![2, 4], Some(vec![2, 2, 0, 1]), Some(vec![0, 1, 0, 0])),
+            (
+                vec![1, 2, 3],
+                Some(vec![1, 1, 0, 1]),
+                Some(vec![0, 1, 0, 0]),
+            ),
+            (
+                vec![1, 2, 3],
+                Some(vec![1, 0, 1, 1]),
+                Some(vec![0, 0, 0, 1]),
+            ),
+            (vec![1, 0, 2], None, None),
+        ];
+
+        let file = get_temp_file("test_repeated_fields_edge_cases.parquet", &[]);
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut writer =
+            SerializedFileWriter::new(file.try_clone().unwrap(), schema, props).unwrap();
+        let mut row_group_writer = writer.next_row_group().unwrap();
+        for (values, def_levels, rep_levels) in &columns {
+            let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+            if let ColumnWriter::Int32ColumnWriter(ref mut typed) = col_writer {
+                typed
+                    .write_batch(values, def_levels.as_deref(), rep_levels.as_deref())
+                    .unwrap();
+            }
+            row_group_writer.close_column(col_writer).unwrap();
+        }
+        writer.close_row_group(row_group_writer).unwrap();
+        writer.close().unwrap();
+
+        let file_reader = SerializedFileReader::new(file).unwrap();
+        let rows: Vec<Row> = file_reader.get_row_iter(None).unwrap().collect();
+        let expected_rows = vec![
+            row![
+                ("id".to_string(), Field::Int(1)),
+                (
+                    "points".to_string(),
+                    list![
+                        group![
+                            ("x".to_string(), Field::Int(1)),
+                            ("y".to_string(), Field::Int(2))
+                        ],
+                        group![
+                            ("x".to_string(), Field::Int(3)),
+                            ("y".to_string(), Field::Int(4))
+                        ]
+                    ]
+                ),
+                ("values".to_string(), list![Field::Int(1), Field::Int(2)]),
+                (
+                    "nested".to_string(),
+                    group![
+                        (
+                            "item".to_string(),
+                            list![group![("v".to_string(), Field::Int(1))]]
+                        ),
+                        ("count".to_string(), Field::Int(1))
+                    ]
+                )
+            ],
+            row![
+                ("id".to_string(), Field::Int(2)),
+                ("points".to_string(), Field::Null),
+                ("values".to_string(), list![]),
+                (
+                    "nested".to_string(),
+                    group![
+                        ("item".to_string(), list![]),
+                        ("count".to_string(), Field::Int(0))
+                    ]
+                )
+            ],
+            row![
+                ("id".to_string(), Field::Int(3)),
+                ("points".to_string(), list![]),
+                ("values".to_string(), list![Field::Int(3)]),
+                (
+                    "nested".to_string(),
+                    group![
+                        (
+                            "item".to_string(),
+                            list![
+                                group![("v".to_string(), Field::Int(2))],
+                                group![("v".to_string(), Field::Int(3))]
+                            ]
+                        ),
+                        ("count".to_string(), Field::Int(2))
+                    ]
+                )
+            ],
+        ];
+
+        assert_eq!(rows, expected_rows);
+    }
+
     fn test_file_reader_rows(file_name: &str, schema: Option<Type>) -> Result<Vec<Row>> {
         let file = get_test_file(file_name);
         let file_reader: Box<dyn FileReader> = Box::new(SerializedFileReader::new(file)?);
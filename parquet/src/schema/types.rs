@@ -462,18 +462,17 @@ impl<'a> PrimitiveTypeBuilder<'a> {
             ));
         }
 
-        // Scale must be zero or a positive integer less than the precision.
+        // Scale must be zero or a positive integer less than or equal to the precision.
         if self.scale < 0 {
             return Err(general_err!("Invalid DECIMAL scale: {}", self.scale));
         }
 
-        if self.scale >= self.precision {
+        if self.scale > self.precision {
             return Err(general_err!(
-            "Invalid DECIMAL: scale ({}) cannot be greater than or equal to precision \
-             ({})",
-            self.scale,
-            self.precision
-        ));
+                "Invalid DECIMAL: scale ({}) cannot be greater than precision ({})",
+                self.scale,
+                self.precision
+            ));
         }
 
         // Check precision and scale based on physical type limitations.
@@ -1343,7 +1342,7 @@ mod tests {
         if let Err(e) = result {
             assert_eq!(
                 format!("{}", e),
-                "Parquet error: Invalid DECIMAL: scale (2) cannot be greater than or equal to precision (1)"
+                "Parquet error: Invalid DECIMAL: scale (2) cannot be greater than precision (1)"
             );
         }
 
@@ -430,6 +430,7 @@ impl BitWriter {
 /// MAX_VLQ_BYTE_LEN = 5 for i32, and MAX_VLQ_BYTE_LEN = 10 for i64
 pub const MAX_VLQ_BYTE_LEN: usize = 10;
 
+#[derive(Clone)]
 pub struct BitReader {
     // The byte buffer to read from, passed in by client
     buffer: ByteBufferPtr,
@@ -588,6 +589,28 @@ impl BitReader {
         values_to_read
     }
 
+    /// Skips at most `num_values` values of size `num_bits` without decoding them.
+    ///
+    /// Returns the number of values skipped, which is less than `num_values` only if
+    /// there's not enough data left.
+    pub fn skip(&mut self, num_values: usize, num_bits: usize) -> usize {
+        assert!(num_bits <= 64);
+
+        let remaining_bits = (self.total_bytes - self.byte_offset) * 8 - self.bit_offset;
+        let values_to_skip = if num_bits == 0 {
+            num_values
+        } else {
+            cmp::min(num_values, remaining_bits / num_bits)
+        };
+
+        let end_bit = self.bit_offset + values_to_skip * num_bits;
+        self.byte_offset += end_bit / 8;
+        self.bit_offset = end_bit % 8;
+        self.reload_buffer_values();
+
+        values_to_skip
+    }
+
     /// Reads a `num_bytes`-sized value from this buffer and return it.
     /// `T` needs to be a little-endian native type. The value is assumed to be byte
     /// aligned so the bit reader will be advanced to the start of the next byte before
@@ -723,6 +746,19 @@ mod tests {
         assert_eq!(bit_reader.get_value::<i64>(16), Some(40));
     }
 
+    #[test]
+    fn test_bit_reader_skip() {
+        let buffer = vec![10, 0, 0, 0, 20, 0, 30, 0, 0, 0, 40, 0];
+        let mut bit_reader = BitReader::from(buffer);
+        assert_eq!(bit_reader.skip(1, 32), 1);
+        assert_eq!(bit_reader.get_value::<i64>(16), Some(20));
+        assert_eq!(bit_reader.skip(1, 4), 1);
+        assert_eq!(bit_reader.get_value::<i64>(4), Some(1));
+        assert_eq!(bit_reader.skip(3, 8), 3);
+        assert_eq!(bit_reader.get_value::<i64>(8), Some(40));
+        assert_eq!(bit_reader.skip(2, 8), 1);
+    }
+
     #[test]
     fn test_bit_reader_get_aligned() {
         // 01110101 11001011
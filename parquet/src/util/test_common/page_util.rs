@@ -17,7 +17,7 @@
 
 use crate::basic::Encoding;
 use crate::column::page::PageReader;
-use crate::column::page::{Page, PageIterator};
+use crate::column::page::{Page, PageIterator, PageMetadata};
 use crate::data_type::DataType;
 use crate::encodings::encoding::{get_encoder, DictEncoder, Encoder};
 use crate::encodings::levels::max_buffer_size;
@@ -30,6 +30,7 @@ use crate::util::memory::MemTrackerPtr;
 use crate::util::test_common::random_numbers_range;
 use rand::distributions::uniform::SampleUniform;
 use std::collections::VecDeque;
+use std::iter::Peekable;
 use std::mem;
 use std::sync::Arc;
 use std::vec::IntoIter;
@@ -164,13 +165,13 @@ impl DataPageBuilder for DataPageBuilderImpl {
 
 /// A utility page reader which stores pages in memory.
 pub struct InMemoryPageReader {
-    pages: Box<dyn Iterator<Item = Page>>,
+    pages: Peekable<IntoIter<Page>>,
 }
 
 impl InMemoryPageReader {
     pub fn new(pages: Vec<Page>) -> Self {
         Self {
-            pages: Box::new(pages.into_iter()),
+            pages: pages.into_iter().peekable(),
         }
     }
 }
@@ -179,6 +180,15 @@ impl PageReader for InMemoryPageReader {
     fn get_next_page(&mut self) -> Result<Option<Page>> {
         Ok(self.pages.next())
     }
+
+    fn peek_next_page(&mut self) -> Result<Option<PageMetadata>> {
+        Ok(self.pages.peek().map(PageMetadata::from))
+    }
+
+    fn skip_next_page(&mut self) -> Result<()> {
+        self.pages.next();
+        Ok(())
+    }
 }
 
 /// A utility page iterator which stores page readers in memory, used for tests.